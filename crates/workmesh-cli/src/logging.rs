@@ -0,0 +1,121 @@
+//! Diagnostics for the CLI. Command output goes to stdout; everything here goes to stderr so
+//! `--json` output can always be piped into `jq`.
+
+use std::fmt::Display;
+use std::io::Write;
+use std::sync::OnceLock;
+
+use chrono::Local;
+use clap::ValueEnum;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "error",
+            LogLevel::Warn => "warn",
+            LogLevel::Info => "info",
+            LogLevel::Debug => "debug",
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    /// `warning: ...` style lines
+    Text,
+    /// One JSON object per line: `{"ts","level","message"}`
+    Json,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Logger {
+    level: LogLevel,
+    format: LogFormat,
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+/// Configure the process-wide logger. Messages logged before this use info/text.
+pub fn init(level: LogLevel, format: LogFormat) {
+    let _ = LOGGER.set(Logger { level, format });
+}
+
+pub fn error(message: impl Display) {
+    emit(LogLevel::Error, &message.to_string());
+}
+
+pub fn warn(message: impl Display) {
+    emit(LogLevel::Warn, &message.to_string());
+}
+
+pub fn info(message: impl Display) {
+    emit(LogLevel::Info, &message.to_string());
+}
+
+pub fn debug(message: impl Display) {
+    emit(LogLevel::Debug, &message.to_string());
+}
+
+fn emit(level: LogLevel, message: &str) {
+    let logger = LOGGER.get().copied().unwrap_or(Logger {
+        level: LogLevel::Info,
+        format: LogFormat::Text,
+    });
+    if level > logger.level {
+        return;
+    }
+    let line = format_line(logger.format, level, message);
+    let _ = writeln!(std::io::stderr().lock(), "{}", line);
+}
+
+fn format_line(format: LogFormat, level: LogLevel, message: &str) -> String {
+    match format {
+        LogFormat::Json => serde_json::json!({
+            "ts": Local::now().to_rfc3339(),
+            "level": level.as_str(),
+            "message": message,
+        })
+        .to_string(),
+        LogFormat::Text => match level {
+            LogLevel::Error | LogLevel::Info => message.to_string(),
+            LogLevel::Warn => format!("warning: {}", message),
+            LogLevel::Debug => format!("debug: {}", message),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_line_text_and_json() {
+        assert_eq!(
+            format_line(LogFormat::Text, LogLevel::Warn, "index is stale"),
+            "warning: index is stale"
+        );
+        assert_eq!(
+            format_line(LogFormat::Text, LogLevel::Error, "Task not found: task-001"),
+            "Task not found: task-001"
+        );
+        let line = format_line(LogFormat::Json, LogLevel::Warn, "index is stale");
+        let value: serde_json::Value = serde_json::from_str(&line).expect("json");
+        assert_eq!(value["level"], "warn");
+        assert_eq!(value["message"], "index is stale");
+        assert!(value["ts"].is_string());
+    }
+
+    #[test]
+    fn levels_are_ordered_by_verbosity() {
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Info < LogLevel::Debug);
+    }
+}
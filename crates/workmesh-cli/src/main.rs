@@ -2,34 +2,91 @@ use std::collections::HashSet;
 use std::ffi::OsString;
 use std::io::{self, IsTerminal, Read};
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use anyhow::Result;
 use chrono::{Duration, Local, NaiveDate};
-use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use clap::{ArgAction, Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
 
+mod logging;
+mod picker;
+mod progress;
+mod self_update;
 mod version;
+mod watch;
 
-use workmesh_core::archive::{archive_tasks, ArchiveOptions};
-use workmesh_core::audit::{append_audit_event, AuditEvent};
+use logging::{LogFormat, LogLevel};
+use progress::ProgressFormat;
+
+use workmesh_core::activity::{activity_report, ActivityBucket};
+use workmesh_core::aliases::{expand_alias, resolve_aliases};
+use workmesh_core::api_tokens::{
+    create_token, list_tokens, parse_expiry, revoke_token, tokens_dir,
+};
+use workmesh_core::archive::{
+    parse_archive_before, plan_archive, resume_archive, run_archive, verify_archive, ArchiveError,
+    ArchiveOptions, ArchiveProgress, ArchiveVerification, DEFAULT_ARCHIVE_CHUNK_SIZE,
+};
+use workmesh_core::assignees::rename_assignee;
+use workmesh_core::attachments::{
+    attach_file, configured_store, delete_archived_objects, fetch_attachment, find_orphan_objects,
+    list_attachments, resolve_attachments_config, Attachment,
+};
+use workmesh_core::audit::{
+    actor_stats, aliased_actor, append_audit_event, audit_log_len, read_audit_events,
+    read_audit_events_from, AuditEvent,
+};
+use workmesh_core::audit_signing::{
+    generate_audit_key, parse_public_key, verify_audit_log, AuditKey, AuditKeyAlgorithm,
+    AUDIT_KEY_ENV,
+};
+use workmesh_core::autotag::{autotag_tasks, compile_rules, resolve_autotag_config, AutotagReport};
 use workmesh_core::backlog::{locate_backlog_dir, resolve_backlog, BacklogResolution};
+use workmesh_core::backlog_diff::{diff_backlog_paths, BacklogDiff};
+use workmesh_core::bench::{
+    bench_sizes, run_bench, BenchReport, BENCH_STEPS, DEFAULT_BENCH_RUNS, DEFAULT_BENCH_TASKS,
+};
+use workmesh_core::blocked_time::{blocked_time_for_task, blocked_time_report};
 use workmesh_core::bootstrap::{bootstrap_repo, BootstrapOptions};
+use workmesh_core::budget::{budget_report, resolve_budgets_config, BudgetScope, BudgetUnit};
+use workmesh_core::bundle::{
+    annotate_bundle, apply_bundle, bundle_tasks, create_bundle, extract_attachments, read_bundle,
+    select_bundle_tasks, write_bundle, Annotation, BundleSelection,
+};
+use workmesh_core::calibration::{calibration_report, CalibrationGroup};
+use workmesh_core::capabilities::output_schemas;
+use workmesh_core::changes::changes_since;
+use workmesh_core::checkpoint_triggers;
+use workmesh_core::codeowners::{apply_ownership, ownership_report};
+use workmesh_core::confidential::{generate_key, read_task_text, CONFIDENTIAL_KEY_ENV};
 use workmesh_core::config::{
     global_config_path, load_config, load_config_with_path, load_global_config,
-    load_global_config_with_path, resolve_auto_session_default,
-    resolve_auto_session_default_with_source, resolve_task_validation_rules,
-    resolve_task_validation_rules_with_source, resolve_worktrees_default,
-    resolve_worktrees_default_with_source, resolve_worktrees_dir_with_source,
+    load_global_config_with_path, resolve_actor_aliases, resolve_auto_session_default,
+    resolve_auto_session_default_with_source, resolve_default_priority, resolve_note_autolink,
+    resolve_note_autolink_with_source, resolve_task_validation_rules,
+    resolve_task_validation_rules_with_source, resolve_teams, resolve_time_display,
+    resolve_time_display_with_source, resolve_worktrees_default,
+    resolve_worktrees_default_with_source, resolve_worktrees_dir_with_source, update_actor_alias,
     update_do_not_migrate, write_config, write_global_config,
 };
 use workmesh_core::context::{
     clear_context, context_path, extract_task_id_from_branch, infer_project_id, load_context,
     save_context, ContextScope, ContextScopeMode, ContextState,
 };
+use workmesh_core::context_pack::{
+    build_context_pack, parse_token_budget, render_context_pack_markdown, ContextPackOptions,
+};
+use workmesh_core::cycle_time::{cycle_time_report, CycleTimeGroup, DurationStats};
 use workmesh_core::doctor::{doctor_report, doctor_report_with_options};
+use workmesh_core::extract::{extract_backlog, ExtractOptions};
 use workmesh_core::fix::{backfill_missing_uids, fix_dependencies, fix_task_filenames, FixerKind};
 use workmesh_core::focus::load_focus;
+use workmesh_core::freeze::{
+    freeze_scope, load_freezes, unfreeze_scope, Freeze, FreezeKind, FrozenSet,
+};
 use workmesh_core::gantt::{
-    plantuml_gantt, render_plantuml_svg, write_text_file, PlantumlRenderError,
+    critical_path, mermaid_gantt, plantuml_gantt, render_plantuml_svg, write_text_file,
+    PlantumlRenderError,
 };
 use workmesh_core::global_sessions::{
     append_session_saved, load_sessions_latest_fast, new_session_id, now_rfc3339,
@@ -37,24 +94,82 @@ use workmesh_core::global_sessions::{
     set_current_session, verify_sessions_index, AgentSession, CheckpointRef, GitSnapshot,
     WorktreeBinding,
 };
+use workmesh_core::goals::{
+    add_key_result, create_goal, ensure_key_result, goal_status, load_goal, load_goals,
+    next_goal_id, parse_key_result_ref, report_metric, task_key_results, Goal, GoalStatus,
+    KEY_RESULTS_FIELD,
+};
+use workmesh_core::grab::{grab_tasks, GrabOptions, DEFAULT_GRAB_COUNT};
+use workmesh_core::graph_diff::{dependency_edges, graph_diff, render_graph_diff};
+use workmesh_core::health::{health_report, HealthOptions};
 use workmesh_core::id_fix::{fix_duplicate_task_ids, FixIdsOptions};
-use workmesh_core::index::{rebuild_index, refresh_index, verify_index};
+use workmesh_core::import::jira::{import_jira, parse_jira_csv, resolve_jira_config};
+use workmesh_core::inbox::{
+    build_inbox, mark_inbox_all_read, mark_inbox_read, normalize_user, task_watchers,
+    WATCHERS_FIELD,
+};
+use workmesh_core::index::{
+    find_archived_task, rebuild_index_with_progress, refresh_archive_index, refresh_index,
+    search_archive, verify_index,
+};
+use workmesh_core::init::{init_repo, InitEntry, InitOptions};
 use workmesh_core::initiative::{
-    best_effort_git_branch as core_git_branch, ensure_branch_initiative, next_namespaced_task_id,
+    best_effort_git_branch as core_git_branch, ensure_branch_initiative, reserve_namespaced_task_id,
 };
+use workmesh_core::lint::{lint_tasks, LintOptions};
+use workmesh_core::mentions::{mentions_for, resolve_mentions};
+use workmesh_core::merge::{merge_backlog_with_progress, MergeBacklogOptions};
+use workmesh_core::metrics::{collect_backlog_metrics, render_prometheus};
 use workmesh_core::migration::{migrate_backlog, MigrationError};
 use workmesh_core::migration_audit::{
     apply_migration_plan, audit_deprecations, plan_migrations, MigrationApplyOptions,
     MigrationPlanOptions,
 };
+use workmesh_core::milestone::{
+    milestone_report, MilestoneProgress, MilestoneReport, VelocitySource,
+    DEFAULT_VELOCITY_WINDOW_DAYS,
+};
+use workmesh_core::obsidian::export_obsidian;
+use workmesh_core::onboarding::{
+    create_onboarding, onboarding_status, resolve_onboarding_config, OnboardingError,
+};
+use workmesh_core::policies::{resolve_policies, run_policies, PolicyRunReport};
+use workmesh_core::postmerge::{conflict_report, ConflictReport};
+use workmesh_core::progress::ProgressEvent;
 use workmesh_core::project::{ensure_project_docs, repo_root_from_backlog};
+use workmesh_core::proposals::{
+    apply_proposed_change, decide_proposal, ensure_pending, load_proposal, load_proposals,
+    parse_proposed_changes, preview_proposal, submit_proposal, ChangePreview, Proposal,
+    ProposalState, ProposedChange,
+};
 use workmesh_core::quickstart::{quickstart, QuickstartOptions};
+use workmesh_core::recurrence::{recurring_series, run_recurrences};
 use workmesh_core::rekey::{
-    parse_rekey_request, rekey_apply, render_rekey_prompt, RekeyApplyOptions, RekeyPromptOptions,
+    move_task, parse_rekey_request, rekey_apply_with_progress, render_rekey_prompt,
+    MoveTaskOptions, RekeyApplyOptions, RekeyPromptOptions,
+};
+use workmesh_core::related::{related_reasons, related_tasks, DEFAULT_RELATED_LIMIT};
+use workmesh_core::reminders::{
+    context_due_reminders, due_reminders, session_due_reminders, DEFAULT_DUE_SOON_DAYS,
+};
+use workmesh_core::remote::fetch_remote_backlog;
+use workmesh_core::report::{task_html_page, write_html_report, HtmlOptions};
+use workmesh_core::roles::{token_hash, Role};
+use workmesh_core::self_update::UpdateChannel;
+use workmesh_core::selftest::{
+    bundled_fixtures, load_fixtures, run_selftest, SelftestReport, StepOutcome,
+};
+#[cfg(feature = "semsearch")]
+use workmesh_core::semsearch::{provider_from_config, resolve_semsearch_config, semantic_search};
+use workmesh_core::service::{
+    install_service, render_definition, service_spec, service_status, uninstall_service,
+    ServicePlatform,
 };
 use workmesh_core::session::{
-    append_session_journal, diff_since_checkpoint, render_diff, render_resume, resolve_project_id,
-    resume_summary, task_summary, write_checkpoint, write_working_set, CheckpointOptions,
+    append_session_journal, diff_since_checkpoint, filter_session_journal, journal_line,
+    load_checkpoint, parse_since, read_session_journal, refresh_working_set, render_diff,
+    render_resume, render_session_journal_markdown, resolve_project_id, resume_summary,
+    task_summary, working_set_status, write_checkpoint, write_working_set, CheckpointOptions,
 };
 use workmesh_core::skills::{
     detect_user_agents, embedded_skill_ids, install_embedded_skill_global_auto_report,
@@ -62,16 +177,44 @@ use workmesh_core::skills::{
     uninstall_embedded_skill_report, SkillAgent, SkillInstallReport, SkillScope,
     SkillUninstallReport,
 };
-use workmesh_core::task::{load_tasks, load_tasks_with_archive, tasks_dir_for_root, Lease, Task};
+use workmesh_core::split::{split_task, SplitError};
+use workmesh_core::stress::{
+    render_stress_report, run_stress, DEFAULT_STRESS_ITERATIONS, DEFAULT_STRESS_WRITERS,
+};
+use workmesh_core::sync::github::{
+    detect_github_repo, export_github, import_github, resolve_github_config, GithubSyncItem,
+    GithubSyncReport, HttpGithubApi,
+};
+use workmesh_core::task::{
+    archive_root_for_root, load_tasks, load_tasks_with_archive, tasks_dir_for_root, Lease, Task,
+};
 use workmesh_core::task_ops::{
-    append_note, create_task_file_with_sections, ensure_can_set_status_with_rules, filter_tasks,
-    graph_export, is_lease_active, now_timestamp, ready_tasks_with_rules,
-    recommend_next_tasks_with_context_and_rules, render_task_line, replace_section, set_list_field,
-    sort_tasks, status_counts, task_to_json_value, tasks_to_json, tasks_to_jsonl,
-    timestamp_plus_minutes, update_body, update_lease_fields, update_task_field,
-    update_task_field_or_section, validate_task_creation_with_rules, validate_tasks_with_rules,
-    FieldValue, TaskSectionContent,
+    append_task_note, create_epic_file, create_task_file_with_sections, descendant_tasks,
+    dot_graph, edit_list_field, ensure_can_set_status_with_rules, filter_tasks,
+    filter_tasks_by_dates, filter_tasks_by_fields, graph_export, group_counts, is_lease_active,
+    link_note_references, mermaid_graph, now_timestamp, ready_tasks_with_rules,
+    recommend_next_tasks_with_context_and_rules, rename_task_file_for_title, render_task_line,
+    replace_section, set_list_field, soft_dependency_warnings, sort_tasks, status_counts,
+    subtask_rollups, task_to_json_value, tasks_to_json, tasks_to_jsonl, timestamp_plus_minutes,
+    update_body, update_lease_fields, update_task_field, update_task_field_or_section,
+    validate_epic_creation_with_rules, validate_task_creation_with_rules,
+    validate_tasks_with_rules, DateFilter, EpicSectionContent, FieldFilter, FieldValue, GroupBy,
+    TaskSectionContent, SOFT_DEPENDENCIES_FIELD,
+};
+use workmesh_core::taskwarrior::{
+    export_taskwarrior, import_taskwarrior, parse_taskwarrior_export, resolve_taskwarrior_config,
+};
+use workmesh_core::telemetry::{resolve_otlp_exporter, CommandSpan, OtlpExporter};
+use workmesh_core::template::{
+    create_task_from_template, default_template_body, list_templates, load_template,
+    template_from_task, templates_dir_for_root, write_template, TaskTemplate, TemplateOverrides,
 };
+use workmesh_core::timestamps::TimeDisplay;
+use workmesh_core::timewindow::{TimeWindow, TIME_REF_FORMATS};
+use workmesh_core::title_lint::{lint_titles, resolve_title_lint_config, TitleLintRules};
+use workmesh_core::todos::scan_todos_report;
+use workmesh_core::transitions::{block_reason_counts, record_status_transition, task_transitions};
+use workmesh_core::trash::{remove_task, RemoveError, RemoveMode};
 use workmesh_core::truth::{
     accept_truth, apply_truth_migration, list_truths, propose_truth, reject_truth, show_truth,
     supersede_truth, truth_migration_audit, truth_migration_plan, validate_truth_store,
@@ -79,8 +222,11 @@ use workmesh_core::truth::{
     TruthSupersedeInput, TruthTransitionInput,
 };
 use workmesh_core::views::{
-    blockers_report_with_context, board_lanes, scope_ids_from_context, BoardBy,
+    blockers_report_with_context, board_lanes, epic_tree, scope_ids_from_context, toposort_report,
+    BoardBy, TreeNode,
 };
+use workmesh_core::watch::DEFAULT_WATCH_INTERVAL_MS;
+use workmesh_core::workflow::{render_dot, render_mermaid, resolve_workflow, workflow_diagram};
 use workmesh_core::workstreams::{
     build_workstream_restore_plan, derive_unique_workstream_key,
     find_workstream_for_repo_by_worktree_path, list_workstreams_for_repo,
@@ -95,14 +241,18 @@ use workmesh_core::worktrees::{
 use workmesh_render::dispatch_tool as render_dispatch_tool;
 use workmesh_tools::{
     build_tool_info_payload, placeholder_tool_definition, render_tool_info_text,
-    resolve_cli_repo_root,
+    resolve_cli_repo_root, resolve_cli_root, ROOT_ENV,
 };
 
 #[derive(Parser)]
 #[command(name = "workmesh", version = version::FULL, about = "WorkMesh CLI (WIP)")]
 struct Cli {
-    /// Path to repo root or backlog directory
-    #[arg(long, required = true)]
+    /// Path to repo root or backlog directory (default: $WORKMESH_ROOT, else discovered by walking
+    /// up from the current directory)
+    #[arg(long = "root", value_name = "ROOT")]
+    root_arg: Option<PathBuf>,
+    /// Effective root, filled in by `resolve_cli_root` after parsing.
+    #[arg(skip)]
     root: PathBuf,
     /// Automatically write a checkpoint after mutating commands
     #[arg(long, action = ArgAction::SetTrue, global = true)]
@@ -118,6 +268,19 @@ struct Cli {
         conflicts_with = "auto_session_save"
     )]
     no_auto_session_save: bool,
+    /// Minimum level for diagnostics on stderr (stdout carries only command output)
+    #[arg(long, value_enum, global = true, default_value_t = LogLevel::Info)]
+    log_level: LogLevel,
+    /// Diagnostic line format on stderr
+    #[arg(long, value_enum, global = true, default_value_t = LogFormat::Text)]
+    log_format: LogFormat,
+    /// Allow mutating tasks inside a frozen epic or phase
+    #[arg(long, action = ArgAction::SetTrue, global = true)]
+    force_frozen: bool,
+    /// Report progress of long operations (rekey, archive, fix all, index rebuild, merges) on
+    /// stderr
+    #[arg(long, value_enum, global = true)]
+    progress: Option<ProgressFormat>,
     #[command(subcommand)]
     command: Command,
 }
@@ -129,12 +292,29 @@ enum Command {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
+    /// Generate a key for confidential tasks (export it as WORKMESH_KEY)
+    ConfidentialKey {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Print the `sha256:<hex>` hash of a bearer token for `[roles.tokens]`
+    TokenHash { token: String },
+    /// Manage scoped API tokens stored hashed under `~/.workmesh/tokens`
+    Token {
+        #[command(subcommand)]
+        command: TokenCommand,
+    },
     /// Show MCP tool metadata for a WorkMesh command/tool
     ToolInfo {
         name: String,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
+    /// List subcommands, flags, accepted values, and JSON output schemas
+    Capabilities {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
     /// Return skill content (defaults to workmesh)
     SkillContent {
         #[arg(long)]
@@ -153,6 +333,25 @@ enum Command {
     Doctor {
         #[arg(long, action = ArgAction::SetTrue)]
         fix_storage: bool,
+        /// Also ask GitHub releases whether a newer workmesh exists
+        #[arg(long, action = ArgAction::SetTrue)]
+        check_updates: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Run `index-watch` in the background as a systemd/launchd/Windows logon service
+    Daemon {
+        #[command(subcommand)]
+        command: DaemonCommand,
+    },
+    /// Replace this binary with the newest GitHub release (checksum-verified, atomic swap)
+    SelfUpdate {
+        /// Release channel; defaults to `[self_update] channel` in the global config, else stable
+        #[arg(long, value_enum)]
+        channel: Option<UpdateChannelArg>,
+        /// Only report whether a newer release exists
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
@@ -212,6 +411,30 @@ enum Command {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
+    /// Interactive terminal board: move tasks between statuses, claim/release, view details
+    #[cfg(feature = "tui")]
+    Ui {
+        /// Lease owner for claims (defaults to $USER)
+        #[arg(long)]
+        owner: Option<String>,
+        /// Lease length in minutes for claims
+        #[arg(long)]
+        minutes: Option<i64>,
+    },
+    /// Serve a local JSON API over the backlog (and the web board with --ui)
+    Serve {
+        /// Address to listen on
+        #[arg(long, default_value = workmesh_serve::DEFAULT_HOST)]
+        host: String,
+        #[arg(long, default_value_t = workmesh_serve::DEFAULT_PORT)]
+        port: u16,
+        /// Also serve the embedded web board at `/`
+        #[arg(long, action = ArgAction::SetTrue)]
+        ui: bool,
+        /// Extra `Host` names clients may use (for example the LAN address when binding 0.0.0.0)
+        #[arg(long = "allowed-host", value_delimiter = ',')]
+        allowed_hosts: Vec<String>,
+    },
     /// Show blocked work and the top blockers (scoped to context epic by default)
     Blockers {
         /// Include archived tasks under `workmesh/archive/` (recursively)
@@ -246,10 +469,39 @@ enum Command {
         blocked: bool,
         #[arg(long)]
         search: Option<String>,
-        #[arg(long, value_enum, default_value_t = SortKey::Id)]
-        sort: SortKey,
+        /// Compare a front-matter field: `estimate>=5`, `score<2.5`, `severity=S1` (repeatable)
+        #[arg(long = "field", action = ArgAction::Append, value_name = "expr")]
+        fields: Vec<String>,
+        /// Created on/after this (YYYY-MM-DD, or relative: 7d, 12h, 2w)
+        #[arg(long, value_name = "date")]
+        created_after: Option<String>,
+        /// Created before this (YYYY-MM-DD, or relative: 7d, 12h, 2w)
+        #[arg(long, value_name = "date")]
+        created_before: Option<String>,
+        /// Updated on/after this (YYYY-MM-DD, or relative: 7d, 12h, 2w)
+        #[arg(long, value_name = "date")]
+        updated_after: Option<String>,
+        /// Updated before this (YYYY-MM-DD, or relative: 7d, 12h, 2w)
+        #[arg(long, value_name = "date")]
+        updated_before: Option<String>,
+        /// Updated on/after this; shorthand for --updated-after (7d, yesterday, RFC3339, ...)
+        #[arg(long, value_name = "when", conflicts_with = "updated_after")]
+        since: Option<String>,
+        /// Updated before this; shorthand for --updated-before
+        #[arg(long, value_name = "when", conflicts_with = "updated_before")]
+        until: Option<String>,
+        /// Comma-separated sort keys; prefix with `-` for descending
+        /// (e.g. `priority,-updated_date,due_date`). Any front-matter field works.
+        #[arg(long, default_value = "id", value_name = "keys")]
+        sort: String,
         #[arg(long)]
         limit: Option<usize>,
+        /// Print the number of matching tasks instead of rows
+        #[arg(long, action = ArgAction::SetTrue)]
+        count: bool,
+        /// Print counts per group instead of rows (JSON: group -> count)
+        #[arg(long, value_enum)]
+        group_by: Option<GroupByArg>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
@@ -272,11 +524,42 @@ enum Command {
         #[arg(long)]
         limit: Option<usize>,
     },
+    /// Pick a weighted-random handful of small, old ready tasks for filler work
+    Grab {
+        /// Only tasks with this label (repeatable; all must match)
+        #[arg(long = "label")]
+        labels: Vec<String>,
+        #[arg(long, default_value_t = DEFAULT_GRAB_COUNT)]
+        count: usize,
+        /// Seed for a reproducible draw
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Lease the picked tasks right away
+        #[arg(long, action = ArgAction::SetTrue)]
+        claim: bool,
+        /// Lease owner for --claim (defaults to $USER)
+        #[arg(long)]
+        owner: Option<String>,
+        /// Lease length for --claim
+        #[arg(long)]
+        minutes: Option<i64>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
     /// Show a task
     Show {
         task_id: String,
         #[arg(long, action = ArgAction::SetTrue)]
         full: bool,
+        /// `html` prints a standalone page with the body rendered, diagrams and images included
+        #[arg(long, value_enum, default_value_t = ShowFormatArg::Text)]
+        format: ShowFormatArg,
+        /// Fall back to the archive index when the task is not in the active backlog
+        #[arg(long, action = ArgAction::SetTrue)]
+        include_archive: bool,
+        /// List tasks sharing labels, files, or wording
+        #[arg(long, action = ArgAction::SetTrue)]
+        related: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
@@ -323,779 +606,1477 @@ enum Command {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Export task graph as JSON
-    GraphExport {
+    /// Merge another backlog (tasks, archive, audit) into this one, rekeying colliding ids.
+    MergeBacklog {
+        /// Other repo root (or backlog directory) to import from
+        #[arg(long)]
+        from: PathBuf,
+        /// Namespace inserted into every imported id (e.g. `other-` -> `task-other-001`)
+        #[arg(long)]
+        prefix: Option<String>,
+        /// Apply changes (otherwise dry-run)
         #[arg(long, action = ArgAction::SetTrue)]
-        pretty: bool,
-    },
-    /// Export tasks as JSON
-    Export {
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
-        pretty: bool,
+        json: bool,
     },
-    /// Export tasks as JSONL
-    IssuesExport {
+    /// Move an epic subtree (or labelled tasks) into a fresh backlog, leaving tombstones behind.
+    Extract {
+        /// Epic task id (the epic plus all descendants are moved)
         #[arg(long)]
-        output: Option<PathBuf>,
+        epic: Option<String>,
+        /// Move every task carrying this label
+        #[arg(long)]
+        label: Option<String>,
+        /// Destination repo root (a fresh `workmesh/` layout is created there)
+        #[arg(long)]
+        dest: PathBuf,
+        /// Apply changes (otherwise dry-run)
         #[arg(long, action = ArgAction::SetTrue)]
-        include_body: bool,
-    },
-    /// Rebuild JSONL task index
-    IndexRebuild {
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Refresh JSONL task index
-    IndexRefresh {
+    /// Subscribe to a task's status changes, comments, and new blockers (via the inbox)
+    Watch {
+        task_id: String,
+        /// User to subscribe (defaults to $USER)
+        #[arg(long = "as")]
+        as_user: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Verify JSONL task index
-    IndexVerify {
+    /// Stop watching a task
+    Unwatch {
+        task_id: String,
+        /// User to unsubscribe (defaults to $USER)
+        #[arg(long = "as")]
+        as_user: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Write a session checkpoint (JSON + Markdown)
-    Checkpoint {
-        #[arg(long)]
-        project: Option<String>,
-        #[arg(long)]
-        id: Option<String>,
+    /// Show the per-user inbox (assignments, review requests, answers, cleared blockers).
+    Inbox {
+        /// User to show the inbox for (defaults to $USER)
+        #[arg(long = "for")]
+        for_user: Option<String>,
+        /// Only show unread items
+        #[arg(long, action = ArgAction::SetTrue)]
+        unread: bool,
+        /// Mark the given item ids as read (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        mark_read: Vec<String>,
+        /// Mark every current item as read
+        #[arg(long, action = ArgAction::SetTrue)]
+        mark_all_read: bool,
         #[arg(long)]
-        audit_limit: Option<usize>,
+        limit: Option<usize>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Resume from the latest checkpoint
-    Resume {
-        #[arg(long)]
-        project: Option<String>,
+    /// List notes where a user was @mentioned recently.
+    Mentions {
+        /// User to search for (defaults to $USER)
+        #[arg(long = "for")]
+        for_user: Option<String>,
+        /// Only include mentions from the last N days (0 = all)
+        #[arg(long, default_value_t = 30)]
+        days: u32,
         #[arg(long)]
-        id: Option<String>,
+        limit: Option<usize>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Write the working set file
-    WorkingSet {
-        #[arg(long)]
-        project: Option<String>,
-        #[arg(long)]
-        tasks: Option<String>,
-        #[arg(long)]
-        note: Option<String>,
+    /// Evaluate configured task policies (`[policies]` in config)
+    Policies {
+        #[command(subcommand)]
+        command: PoliciesCommand,
+    },
+    /// Compare estimated vs actual effort on done tasks and project the bias onto open work
+    Calibration {
+        /// Group by assignee or label (default: one overall bucket)
+        #[arg(long, value_enum)]
+        by: Option<CalibrationByArg>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Append a session journal entry
-    SessionJournal {
-        #[arg(long)]
-        project: Option<String>,
-        #[arg(long)]
+    /// Flow metrics derived from the audit log
+    Analytics {
+        #[command(subcommand)]
+        command: AnalyticsCommand,
+    },
+    /// Time each task spent blocked vs ready vs in progress (from the audit log)
+    BlockedTime {
+        #[command(subcommand)]
+        command: BlockedTimeCommand,
+    },
+    /// Audit events per day/week (heatmap-ready) for a task, an epic subtree, or everything
+    Activity {
+        #[arg(long, conflicts_with = "epic")]
         task: Option<String>,
+        /// Epic id; includes all descendants
         #[arg(long)]
-        next: Option<String>,
+        epic: Option<String>,
+        #[arg(long, value_enum, default_value_t = ActivityByArg::Day)]
+        by: ActivityByArg,
+        /// Only events at/after this (7d, 12h, yesterday, YYYY-MM-DD, RFC3339)
         #[arg(long)]
-        note: Option<String>,
+        since: Option<String>,
+        /// Only events before this
+        #[arg(long)]
+        until: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Global agent sessions (cross-repo continuity)
-    Session {
-        #[command(subcommand)]
-        command: SessionCommand,
-    },
-    /// Feature-level truth ledger (decisions/constraints/contracts)
-    Truth {
-        #[command(subcommand)]
-        command: TruthCommand,
-    },
-    /// Workstream orchestration for parallel feature streams
-    Workstream {
+    /// Handle changes across assignees, lease owners, and watchers
+    Assignee {
         #[command(subcommand)]
-        command: WorkstreamCommand,
+        command: AssigneeCommand,
     },
-    /// Git worktree runtime orchestration for parallel agent workflows
-    Worktree {
+    /// Ownership-based assignment (CODEOWNERS)
+    Assign {
         #[command(subcommand)]
-        command: WorktreeCommand,
+        command: AssignCommand,
     },
-    /// Repo-local context (project/objective/scope)
-    Context {
+    /// Status workflow (`[workflow]` in config) as a state diagram
+    Workflow {
         #[command(subcommand)]
-        command: ContextCommand,
+        command: WorkflowCommand,
     },
-    /// Manage agent skills (show/install/uninstall)
-    Skill {
-        #[command(subcommand)]
-        command: SkillCommand,
+    /// Composite health score per epic and initiative
+    Health {
+        /// Open tasks not updated for this many days count as stale
+        #[arg(long, default_value_t = 30)]
+        stale_days: i64,
+        /// Audit events from the last N days count as churn
+        #[arg(long, default_value_t = 14)]
+        churn_days: i64,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-    /// Show changes since a checkpoint
-    CheckpointDiff {
+    /// Print backlog metrics in Prometheus text format (textfile collector friendly)
+    Metrics {
+        /// Count audit mutations at/after this (7d, 12h, yesterday, YYYY-MM-DD, RFC3339)
         #[arg(long)]
-        project: Option<String>,
+        since: Option<String>,
+        /// Count audit mutations before this
         #[arg(long)]
-        id: Option<String>,
+        until: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Set task status
-    SetStatus {
-        task_id: String,
-        status: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Repair the backlog after a git merge: fix duplicate ids, drop dangling dependencies,
+    /// rebuild the index, and report front matter that diverged across the merge parents
+    Postmerge {
+        /// Apply repairs (otherwise dry-run); the conflict report never edits files
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
-    },
-    /// Claim a task (lease)
-    Claim {
-        task_id: String,
-        owner: String,
+        apply: bool,
+        /// Merge commit to inspect (default: the in-progress merge, else HEAD)
         #[arg(long)]
-        minutes: Option<i64>,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        merge: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Release a task lease
-    Release {
-        task_id: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Semantic diff of two backlog trees or task files (usable as a git difftool)
+    Difftool {
+        /// Old side: a backlog/tasks directory or a task file (missing paths count as empty)
+        old: PathBuf,
+        /// New side: a backlog/tasks directory or a task file (missing paths count as empty)
+        new: PathBuf,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
-    },
-    /// Bulk operations (alias group)
-    Bulk {
-        #[command(subcommand)]
-        command: BulkCommand,
+        json: bool,
     },
-    /// Bulk set status for tasks
-    BulkSetStatus {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Run the read pipeline (load, filter, board, blockers, gantt) over fixture backlogs and
+    /// compare against stored golden outputs; exits 1 on any mismatch
+    Selftest {
+        /// Fixture directory, or a directory of fixtures (default: the bundled fixture)
         #[arg(long)]
-        status: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        against: Option<PathBuf>,
+        /// Rewrite the golden outputs from the current build (requires --against)
+        #[arg(long, action = ArgAction::SetTrue, requires = "against")]
+        update: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk set a front matter field for tasks
-    BulkSetField {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Time load, filter, index rebuild and status changes on synthetic backlogs up to
+    /// `--tasks` in size and print a comparison table
+    Bench {
+        /// Largest backlog; smaller runs use a tenth and a hundredth of it
+        #[arg(long, default_value_t = DEFAULT_BENCH_TASKS)]
+        tasks: usize,
+        /// Timed runs per step (the median is reported)
+        #[arg(long, default_value_t = DEFAULT_BENCH_RUNS)]
+        runs: usize,
+        /// Scratch directory for the generated backlogs (default: a temp dir, removed after)
         #[arg(long)]
-        field: String,
+        dir: Option<PathBuf>,
+        /// A saved `bench --json` report to show changes against
         #[arg(long)]
-        value: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        baseline: Option<PathBuf>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk add label to tasks
-    BulkLabelAdd {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Run concurrent writers against a scratch backlog and check for lost updates and
+    /// corrupted front matter; exits 1 on any failure
+    Stress {
+        /// Concurrent writer threads
+        #[arg(long, default_value_t = DEFAULT_STRESS_WRITERS)]
+        writers: usize,
+        /// Writes per writer
+        #[arg(long, default_value_t = DEFAULT_STRESS_ITERATIONS)]
+        iterations: usize,
+        /// Directory to create the scratch backlog in (default: the system temp dir); the scratch
+        /// backlog always goes in a new subdirectory, removed after a passing run
         #[arg(long)]
-        label: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        dir: Option<PathBuf>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk remove label from tasks
-    BulkLabelRemove {
+    /// Turn TODO/FIXME comments into tasks and report TODO tasks whose comment is gone
+    ScanTodos {
+        /// Files or directories to scan, relative to the repo root (default: whole repo)
         #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
-        #[arg(long)]
-        label: String,
+        paths: Vec<PathBuf>,
+        /// Create tasks and insert `workmesh:<id>` markers (otherwise dry-run)
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk add dependency to tasks
-    BulkDepAdd {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
-        #[arg(long)]
-        dependency: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+    /// Find semantically similar tasks via cached embeddings (see [semsearch] config)
+    #[cfg(feature = "semsearch")]
+    Semsearch {
+        query: String,
+        #[arg(long, default_value_t = 10)]
+        limit: usize,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk remove dependency from tasks
-    BulkDepRemove {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
-        #[arg(long)]
-        dependency: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Import from / export to Taskwarrior (`task export` / `task import` JSON)
+    Taskwarrior {
+        #[command(subcommand)]
+        command: TaskwarriorCommand,
+    },
+    /// Import an existing backlog from another tracker (dry-run unless --apply)
+    Import {
+        /// Export file (default: stdin)
+        input: Option<PathBuf>,
+        #[arg(long, value_enum)]
+        format: ImportFormatArg,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk append a note to tasks
-    BulkNote {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Sync tasks with GitHub Issues (labels, assignees, status; `github_issue` links them)
+    Github {
+        #[command(subcommand)]
+        command: GithubCommand,
+    },
+    /// Attach a file to a task (uploaded to object storage when `[attachments]` selects one)
+    Attach {
+        task_id: String,
+        file: PathBuf,
+        /// Attachment name (default: the file name)
         #[arg(long)]
-        note: String,
-        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
-        section: NoteSection,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        name: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
+    },
+    /// List, download, and clean up task attachments
+    Attachments {
+        #[command(subcommand)]
+        command: AttachmentsCommand,
+    },
+    /// Recurring tasks (`recurrence: weekly` or an RRULE in front matter)
+    Recur {
+        #[command(subcommand)]
+        command: RecurCommand,
+    },
+    /// Reusable task templates under `templates/` (front matter defaults + body sections)
+    Template {
+        #[command(subcommand)]
+        command: TemplateCommand,
+    },
+    /// Epic documents (Problem / Outcomes / Scope / Non-goals body)
+    Epic {
+        #[command(subcommand)]
+        command: EpicCommand,
+    },
+    /// Print the task hierarchy anchored on epic documents
+    Tree {
+        /// Root the tree at this task instead of every top-level epic
+        #[arg(long, value_name = "task-id")]
+        epic: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Set a front matter field
-    SetField {
+    /// List a task's subtasks (tasks whose `parent` is it) with a done/total roll-up
+    Children {
         task_id: String,
-        field: String,
-        value: String,
+        /// Include every level below the task, indented by depth
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        recursive: bool,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Add label to task
-    LabelAdd {
-        task_id: String,
-        label: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Goals with measurable key results, linked to tasks
+    Goal {
+        #[command(subcommand)]
+        command: GoalCommand,
+    },
+    /// Milestones named by the `milestone` front matter field, with projected finish dates
+    Milestone {
+        #[command(subcommand)]
+        command: MilestoneCommand,
+    },
+    /// Point and hour budgets per initiative and phase (`[budgets]` config)
+    Budget {
+        #[command(subcommand)]
+        command: BudgetCommand,
+    },
+    /// Onboarding checklists for new contributors (`[onboarding]` config)
+    Onboarding {
+        #[command(subcommand)]
+        command: OnboardingCommand,
+    },
+    /// Read-only list/board/blockers against another repo's backlog (sparse fetch, no clone)
+    Remote {
+        #[command(subcommand)]
+        command: RemoteCommand,
+    },
+    /// Single-file review bundles for reviewers without access to the repo
+    Bundle {
+        #[command(subcommand)]
+        command: BundleCommand,
+    },
+    /// Queue agent-proposed task edits (JSONL) for human review instead of writing them
+    Propose {
+        /// JSONL change file (`-` reads stdin)
+        #[arg(long)]
+        file: PathBuf,
+        /// One-line description shown in the review queue
+        #[arg(long)]
+        summary: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Remove label from task
-    LabelRemove {
-        task_id: String,
-        label: String,
+    /// Review queued proposals: list, preview, accept, reject
+    #[command(args_conflicts_with_subcommands = true)]
+    Proposals {
+        #[command(subcommand)]
+        command: Option<ProposalsCommand>,
+        /// Include accepted and rejected proposals
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        all: bool,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Add dependency to task
-    DepAdd {
-        task_id: String,
-        dependency: String,
+    /// Audit log reports
+    Audit {
+        #[command(subcommand)]
+        command: AuditCommand,
+    },
+    /// Print a snippet making a root the default for this shell: `eval "$(workmesh use .)"`
+    Use {
+        /// Repo root or backlog directory (defaults to the current root)
+        path: Option<PathBuf>,
+        #[arg(long, value_enum, default_value_t = ShellKind::Sh)]
+        shell: ShellKind,
+        /// Print the snippet that clears the per-shell default instead
+        #[arg(long, action = ArgAction::SetTrue, conflicts_with = "path")]
+        unset: bool,
+    },
+    /// Fuzzy multi-select tasks, then print their ids or run a bulk action on them
+    Pick {
+        #[arg(long, value_delimiter = ',', action = ArgAction::Append)]
+        status: Vec<String>,
+        #[arg(long, value_delimiter = ',', action = ArgAction::Append)]
+        label: Vec<String>,
+        /// Initial fuzzy filter over id, status, title, and labels
+        #[arg(long)]
+        query: Option<String>,
+        /// Select every match without prompting (scripts, CI)
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        all: bool,
+        /// Bulk action for the selection, e.g. `--then set-status Done`
+        #[arg(long, num_args = 1.., value_name = "ACTION")]
+        then: Vec<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Remove dependency from task
-    DepRemove {
-        task_id: String,
-        dependency: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Flag probable secrets, pasted logs, and prompt-injection text in task bodies
+    Lint {
+        /// Limit to these task ids (default: every task)
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        /// Check titles instead of bodies: length, sentence case, imperative mood, duplicates
+        #[arg(
+            long,
+            action = ArgAction::SetTrue,
+            conflicts_with_all = ["redact", "max_block_lines", "max_line_chars"]
+        )]
+        titles: bool,
+        /// Propose labels for untagged tasks from the `[autotag]` keyword rules
+        #[arg(
+            long,
+            action = ArgAction::SetTrue,
+            conflicts_with_all = ["titles", "redact", "max_block_lines", "max_line_chars"]
+        )]
+        autotag: bool,
+        /// With --titles, rewrite titles to their suggestions; with --autotag, apply the labels
+        #[arg(long, action = ArgAction::SetTrue)]
+        fix: bool,
+        /// Rewrite flagged bodies: redact secrets and injection text, trim long pastes
+        #[arg(long, action = ArgAction::SetTrue)]
+        redact: bool,
+        /// Fenced blocks longer than this count as pasted logs (default 80)
+        #[arg(long)]
+        max_block_lines: Option<usize>,
+        /// Lines longer than this count as pasted blobs (default 2000)
+        #[arg(long)]
+        max_line_chars: Option<usize>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Append a note to a task
-    Note {
-        task_id: String,
-        note: String,
-        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
-        section: NoteSection,
+    /// Label untagged tasks from `[autotag]` keyword rules (dry run unless --apply)
+    Autotag {
+        /// Limit to these task ids (default: every task)
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        /// Write the proposed labels
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
     },
-    /// Replace task body (all content after front matter)
-    SetBody {
-        task_id: String,
+    /// Audit events and task diffs since a cursor from a previous call (cheap polling)
+    ChangesSince {
+        /// Cursor from a previous call; omit to get a baseline cursor
         #[arg(long)]
-        text: Option<String>,
+        cursor: Option<String>,
+        /// Only report these task ids
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Dependency-ordered execution waves of open tasks, for schedulers and orchestrators
+    Toposort {
+        /// Limit to an epic and its descendants
         #[arg(long)]
-        file: Option<PathBuf>,
+        epic: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        json: bool,
+    },
+    /// Overdue and due-soon open tasks (by `due` / `due_date`) in the context scope
+    Reminders {
+        /// Include tasks due within this many days
+        #[arg(long, default_value_t = DEFAULT_DUE_SOON_DAYS)]
+        within_days: i64,
+        /// Ignore the context scope and check the whole backlog
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-    /// Replace a named section in the task body
-    SetSection {
-        task_id: String,
-        section: String,
-        #[arg(long)]
-        text: Option<String>,
-        #[arg(long)]
-        file: Option<PathBuf>,
+    /// Export task graph as JSON, a Mermaid flowchart, or Graphviz DOT
+    GraphExport {
+        #[arg(long, value_enum, default_value_t = GraphFormatArg::Json)]
+        format: GraphFormatArg,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        pretty: bool,
+    },
+    /// Export tasks as JSON (or to other formats via subcommands)
+    Export {
+        #[command(subcommand)]
+        command: Option<ExportCommand>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        pretty: bool,
     },
-    /// Create a new task
-    Add {
-        #[arg(long, value_name = "task-id")]
-        id: Option<String>,
-        #[arg(long)]
-        title: String,
-        #[arg(long)]
-        description: Option<String>,
-        #[arg(long)]
-        acceptance_criteria: Option<String>,
+    /// Write a static HTML report (or other report formats via subcommands)
+    Report {
+        #[command(subcommand)]
+        command: ReportCommand,
+    },
+    /// Export tasks as JSONL
+    IssuesExport {
         #[arg(long)]
-        definition_of_done: Option<String>,
+        output: Option<PathBuf>,
         #[arg(long, action = ArgAction::SetTrue)]
-        draft: bool,
-        #[arg(long, default_value = "To Do")]
-        status: String,
-        #[arg(long, default_value = "P2")]
-        priority: String,
-        #[arg(long, default_value = "Phase1")]
-        phase: String,
-        #[arg(long, default_value = "")]
-        labels: String,
-        #[arg(long, default_value = "")]
-        dependencies: String,
-        #[arg(long, default_value = "")]
-        assignee: String,
+        include_body: bool,
+    },
+    /// Rebuild JSONL task index
+    IndexRebuild {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Create a task discovered from another task
-    AddDiscovered {
-        #[arg(long)]
-        from: String,
-        #[arg(long, value_name = "task-id")]
-        id: Option<String>,
-        #[arg(long)]
-        title: String,
-        #[arg(long)]
-        description: Option<String>,
-        #[arg(long)]
-        acceptance_criteria: Option<String>,
-        #[arg(long)]
-        definition_of_done: Option<String>,
+    /// Refresh JSONL task index
+    IndexRefresh {
+        /// Report how many files were skipped, rehashed, and reparsed
         #[arg(long, action = ArgAction::SetTrue)]
-        draft: bool,
-        #[arg(long, default_value = "To Do")]
-        status: String,
-        #[arg(long, default_value = "P2")]
-        priority: String,
-        #[arg(long, default_value = "Phase1")]
-        phase: String,
-        #[arg(long, default_value = "")]
-        labels: String,
-        #[arg(long, default_value = "")]
-        dependencies: String,
-        #[arg(long, default_value = "")]
-        assignee: String,
+        stats: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Create project docs scaffold
-    ProjectInit {
-        project_id: String,
-        #[arg(long)]
-        name: Option<String>,
+    /// Verify JSONL task index
+    IndexVerify {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-    /// Bootstrap WorkMesh by auto-detecting repo state and applying setup/migration
-    Bootstrap {
-        /// Project id to use when initializing a new repo or seeding missing context
+    /// Keep the task index fresh while files change, streaming change events
+    IndexWatch {
+        /// Milliseconds between checks of the tasks directory
+        #[arg(long, default_value_t = DEFAULT_WATCH_INTERVAL_MS)]
+        interval_ms: u64,
+        /// Serve events as JSON lines on this Unix socket instead of stdout
         #[arg(long)]
-        project_id: Option<String>,
-        /// Feature phrase for seed task initiative naming
+        socket: Option<PathBuf>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Write a session checkpoint (JSON + Markdown)
+    Checkpoint {
         #[arg(long)]
-        feature: Option<String>,
-        /// Objective to set when context is missing
+        project: Option<String>,
         #[arg(long)]
-        objective: Option<String>,
-        /// Repo-relative or absolute path for task files when initializing a new repo
+        id: Option<String>,
         #[arg(long)]
-        tasks_root: Option<String>,
-        /// Repo-relative or absolute path for repo-local WorkMesh state when initializing a new repo
+        audit_limit: Option<usize>,
+        /// Extra section(s), e.g. epics, questions, working_set, decisions. Repeatable.
+        #[arg(long, action = ArgAction::Append)]
+        include: Vec<String>,
+        /// Section(s) to leave out, e.g. git_detail or audit. Repeatable.
+        #[arg(long, action = ArgAction::Append)]
+        exclude: Vec<String>,
+        /// Markdown template with {{section}} placeholders (overrides [checkpoint].template)
         #[arg(long)]
-        state_root: Option<String>,
+        template: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Quickstart: scaffold docs + backlog + seed task
-    Quickstart {
-        project_id: String,
-        #[arg(long)]
-        name: Option<String>,
-        /// Feature/project phrase used to seed initiative acronym for the first task id
-        #[arg(long)]
-        feature: Option<String>,
-        /// Repo-relative or absolute path for task files
+    /// Resume from the latest checkpoint
+    Resume {
         #[arg(long)]
-        tasks_root: Option<String>,
-        /// Repo-relative or absolute path for repo-local WorkMesh state
+        project: Option<String>,
         #[arg(long)]
-        state_root: Option<String>,
-        #[arg(long, action = ArgAction::SetTrue)]
-        agents_snippet: bool,
+        id: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Migrate legacy/deprecated structures (audit -> plan -> apply)
-    Migrate {
+    /// Write the working set file
+    #[command(args_conflicts_with_subcommands = true)]
+    WorkingSet {
         #[command(subcommand)]
-        command: Option<MigrateCommand>,
-        /// Legacy migration target (compat mode when no subcommand is provided)
+        command: Option<WorkingSetCommand>,
         #[arg(long)]
-        to: Option<String>,
-        /// Legacy non-interactive confirm (compat mode when no subcommand is provided)
-        #[arg(long, action = ArgAction::SetTrue)]
-        yes: bool,
-    },
-    /// Archive terminal tasks into date-based folders (defaults: Done, Cancelled, Canceled, Won't Do, Wont Do)
-    Archive {
-        #[arg(long, default_value = "30d")]
-        before: String,
-        /// Status filter(s). Repeat or comma-separate for multiple values.
-        /// When omitted, defaults to terminal statuses: Done, Cancelled, Canceled, Won't Do, Wont Do.
-        #[arg(long, action = ArgAction::Append)]
-        status: Vec<String>,
+        project: Option<String>,
+        #[arg(long)]
+        tasks: Option<String>,
+        #[arg(long)]
+        note: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Validate task files
-    Validate {
+    /// Append a session journal entry
+    SessionJournal {
+        #[arg(long)]
+        project: Option<String>,
+        #[arg(long)]
+        task: Option<String>,
+        #[arg(long)]
+        next: Option<String>,
+        #[arg(long)]
+        note: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Render structured data using the native WorkMesh renderers
-    Render {
+    /// Read the session journal
+    Journal {
         #[command(subcommand)]
-        command: RenderCommand,
+        command: JournalCommand,
     },
-    /// Show backlog best practices
-    BestPractices,
-    /// Render PlantUML gantt text
-    Gantt {
+    /// Global agent sessions (cross-repo continuity)
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
+    },
+    /// Feature-level truth ledger (decisions/constraints/contracts)
+    Truth {
+        #[command(subcommand)]
+        command: TruthCommand,
+    },
+    /// Workstream orchestration for parallel feature streams
+    Workstream {
+        #[command(subcommand)]
+        command: WorkstreamCommand,
+    },
+    /// Git worktree runtime orchestration for parallel agent workflows
+    Worktree {
+        #[command(subcommand)]
+        command: WorktreeCommand,
+    },
+    /// Repo-local context (project/objective/scope)
+    Context {
+        #[command(subcommand)]
+        command: ContextCommand,
+    },
+    /// Manage agent skills (show/install/uninstall)
+    Skill {
+        #[command(subcommand)]
+        command: SkillCommand,
+    },
+    /// Show changes since a checkpoint
+    CheckpointDiff {
         #[arg(long)]
-        start: Option<String>,
-        #[arg(long, default_value_t = 3)]
-        zoom: i32,
+        project: Option<String>,
+        #[arg(long)]
+        id: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-    /// Write PlantUML gantt to a file
-    GanttFile {
+    /// Show dependency edges added/removed between two checkpoints (or a checkpoint and now)
+    GraphDiff {
         #[arg(long)]
-        start: Option<String>,
-        #[arg(long, default_value_t = 3)]
-        zoom: i32,
+        project: Option<String>,
+        /// Checkpoint id or path to compare from
         #[arg(long)]
-        output: PathBuf,
+        from: String,
+        /// Checkpoint id or path to compare to (defaults to the current backlog)
+        #[arg(long)]
+        to: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-    /// Render gantt SVG via PlantUML
-    GanttSvg {
+    /// Set task status
+    SetStatus {
+        task_id: String,
+        status: String,
+        /// Why the status changed (e.g. what blocks it); kept in the task's `transitions:` list
         #[arg(long)]
-        start: Option<String>,
-        #[arg(long, default_value_t = 3)]
-        zoom: i32,
+        reason: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Claim a task (lease)
+    Claim {
+        task_id: String,
+        owner: String,
         #[arg(long)]
-        output: Option<PathBuf>,
+        minutes: Option<i64>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Release a task lease
+    Release {
+        task_id: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Freeze an epic subtree or a phase: its tasks reject mutations and leave next/ready
+    Freeze {
+        #[arg(long, required_unless_present = "phase", conflicts_with = "phase")]
+        epic: Option<String>,
         #[arg(long)]
-        plantuml_cmd: Option<String>,
+        phase: Option<String>,
         #[arg(long)]
-        plantuml_jar: Option<PathBuf>,
+        reason: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
     },
-}
-
-#[derive(Subcommand)]
-enum SkillCommand {
-    /// Show a skill's SKILL.md content (reads repo skill dirs, falls back to embedded default)
-    Show {
-        /// Skill name (defaults to workmesh)
+    /// Lift a freeze set by `freeze`
+    Unfreeze {
+        #[arg(long, required_unless_present = "phase", conflicts_with = "phase")]
+        epic: Option<String>,
         #[arg(long)]
-        name: Option<String>,
+        phase: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Install the embedded WorkMesh skill into agent skill directories
-    Install {
-        /// Skill name (defaults to workmesh)
+    /// Bulk operations (alias group)
+    Bulk {
+        #[command(subcommand)]
+        command: BulkCommand,
+    },
+    /// Bulk set status for tasks
+    BulkSetStatus {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
         #[arg(long)]
-        name: Option<String>,
-        /// Install to user-level (~/.codex/skills, ~/.claude/skills, ~/.cursor/skills) or
-        /// project-level (<repo>/.agents/skills for Codex/Cursor, <repo>/.claude/skills for
-        /// Claude)
-        #[arg(long, value_enum, default_value_t = SkillScopeArg::User)]
-        scope: SkillScopeArg,
-        /// Which agent(s) to install for
-        #[arg(long, value_enum, default_value_t = SkillAgentArg::All)]
-        agent: SkillAgentArg,
-        /// Overwrite existing SKILL.md files
+        status: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        force: bool,
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Uninstall the embedded WorkMesh skill from agent skill directories
-    Uninstall {
-        /// Skill name (defaults to workmesh)
+    /// Bulk set a front matter field for tasks
+    BulkSetField {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
         #[arg(long)]
-        name: Option<String>,
-        /// Uninstall from user-level (~/.codex/skills, ~/.claude/skills, ~/.cursor/skills) or
-        /// project-level (<repo>/.agents/skills for Codex/Cursor, <repo>/.claude/skills for
-        /// Claude)
-        #[arg(long, value_enum, default_value_t = SkillScopeArg::User)]
-        scope: SkillScopeArg,
-        /// Which agent(s) to uninstall for
-        #[arg(long, value_enum, default_value_t = SkillAgentArg::All)]
-        agent: SkillAgentArg,
+        field: String,
+        #[arg(long)]
+        value: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Install the embedded skill globally for detected agents under your home directory
-    ///
-    /// This only installs for agents that already have a home folder (e.g. ~/.codex, ~/.claude, ~/.cursor).
-    InstallGlobal {
-        /// Skill name (defaults to workmesh)
+    /// Bulk add label to tasks
+    BulkLabelAdd {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
         #[arg(long)]
-        name: Option<String>,
-        /// Overwrite existing SKILL.md files
+        label: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        force: bool,
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Uninstall the embedded skill globally for detected agents under your home directory
-    UninstallGlobal {
-        /// Skill name (defaults to workmesh)
+    /// Bulk remove label from tasks
+    BulkLabelRemove {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
         #[arg(long)]
-        name: Option<String>,
+        label: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-}
-
-#[derive(Subcommand)]
-enum RenderCommand {
-    /// Render a table from array/object data
-    Table(RenderInputArgs),
-    /// Render a key/value view
-    Kv(RenderInputArgs),
-    /// Render compact stats
-    Stats(RenderInputArgs),
-    /// Render a list
-    List(RenderInputArgs),
-    /// Render progress output
-    Progress(RenderInputArgs),
-    /// Render a tree
-    Tree(RenderInputArgs),
-    /// Render a diff
-    Diff(RenderInputArgs),
-    /// Render logs
-    Logs(RenderInputArgs),
-    /// Render alerts
-    Alerts(RenderInputArgs),
-    /// Render a bar chart
-    ChartBar(RenderInputArgs),
-    /// Render a sparkline
-    Sparkline(RenderInputArgs),
-    /// Render a timeline
-    Timeline(RenderInputArgs),
-}
-
-#[derive(Args, Clone, Debug)]
-struct RenderInputArgs {
-    /// Inline data payload. If valid JSON, it is parsed as JSON; otherwise it is treated as a string.
-    #[arg(long, conflicts_with_all = ["data_file", "stdin"])]
-    data: Option<String>,
-    /// Read data payload from a file.
-    #[arg(long, value_name = "path", conflicts_with_all = ["data", "stdin"])]
-    data_file: Option<PathBuf>,
-    /// Read data payload from stdin.
-    #[arg(long, action = ArgAction::SetTrue, conflicts_with_all = ["data", "data_file"])]
-    stdin: bool,
-    /// Optional renderer format hint.
-    #[arg(long)]
-    format: Option<String>,
-    /// Inline configuration JSON for the renderer.
-    #[arg(long, conflicts_with = "config_file")]
-    configuration: Option<String>,
-    /// Read configuration JSON from a file.
-    #[arg(long, value_name = "path", conflicts_with = "configuration")]
-    config_file: Option<PathBuf>,
-}
-
-#[derive(Subcommand)]
-enum FixCommand {
-    /// List available fixers
-    List {
+    /// Bulk add dependency to tasks
+    BulkDepAdd {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        dependency: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Run all fixers (optionally scoped by --only/--exclude)
-    All {
-        /// Apply changes (default is check/dry-run)
+    /// Bulk remove dependency from tasks
+    BulkDepRemove {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        dependency: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        apply: bool,
-        /// Explicitly run in check mode (default if --apply is not set)
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
         #[arg(long, action = ArgAction::SetTrue)]
-        check: bool,
-        /// Comma-separated list of fixers to include (uid,deps,ids,filenames)
-        #[arg(long, value_delimiter = ',', value_enum)]
-        only: Vec<FixTargetArg>,
-        /// Comma-separated list of fixers to exclude (uid,deps,ids,filenames)
-        #[arg(long, value_delimiter = ',', value_enum)]
-        exclude: Vec<FixTargetArg>,
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Backfill missing task uid values
-    Uid {
-        /// Apply changes (default is check/dry-run)
+    /// Bulk append a note to tasks
+    BulkNote {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        note: String,
+        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
+        section: NoteSection,
         #[arg(long, action = ArgAction::SetTrue)]
-        apply: bool,
-        /// Explicitly run in check mode (default if --apply is not set)
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
         #[arg(long, action = ArgAction::SetTrue)]
-        check: bool,
+        no_touch: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Remove missing/duplicate dependencies from task dependency lists
-    Deps {
-        /// Apply changes (default is check/dry-run)
+    /// Set a front matter field
+    SetField {
+        task_id: String,
+        field: String,
+        value: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        apply: bool,
-        /// Explicitly run in check mode (default if --apply is not set)
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
         #[arg(long, action = ArgAction::SetTrue)]
-        check: bool,
+        no_touch: bool,
+    },
+    /// Add label to task
+    LabelAdd {
+        task_id: String,
+        label: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        json: bool,
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
     },
-    /// Detect/rekey duplicate task ids
-    Ids {
-        /// Apply changes (default is check/dry-run)
+    /// Remove label from task
+    LabelRemove {
+        task_id: String,
+        label: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        apply: bool,
-        /// Explicitly run in check mode (default if --apply is not set)
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
         #[arg(long, action = ArgAction::SetTrue)]
-        check: bool,
+        no_touch: bool,
+    },
+    /// Add dependency to task
+    DepAdd {
+        task_id: String,
+        dependency: String,
+        /// Edit `soft_dependencies` (ordering hints that warn instead of block)
         #[arg(long, action = ArgAction::SetTrue)]
-        json: bool,
+        soft: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
     },
-    /// Normalize task filenames from task id/title/uid metadata
-    Filenames {
-        /// Apply changes (default is check/dry-run)
+    /// Remove dependency from task
+    DepRemove {
+        task_id: String,
+        dependency: String,
+        /// Edit `soft_dependencies` (ordering hints that warn instead of block)
         #[arg(long, action = ArgAction::SetTrue)]
-        apply: bool,
-        /// Explicitly run in check mode (default if --apply is not set)
+        soft: bool,
         #[arg(long, action = ArgAction::SetTrue)]
-        check: bool,
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Append a note to a task
+    Note {
+        task_id: String,
+        note: String,
+        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
+        section: NoteSection,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Replace task body (all content after front matter)
+    SetBody {
+        task_id: String,
+        #[arg(long)]
+        text: Option<String>,
+        #[arg(long)]
+        file: Option<PathBuf>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Replace a named section in the task body
+    SetSection {
+        task_id: String,
+        section: String,
+        #[arg(long)]
+        text: Option<String>,
+        #[arg(long)]
+        file: Option<PathBuf>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+    },
+    /// Create a new task
+    Add {
+        #[arg(long, value_name = "task-id")]
+        id: Option<String>,
+        #[arg(long)]
+        title: String,
+        /// Start from `templates/<name>.md` (its defaults and sections; flags below override)
+        #[arg(long, value_name = "name")]
+        template: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        acceptance_criteria: Option<String>,
+        #[arg(long)]
+        definition_of_done: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        draft: bool,
+        /// Default: the template's, else "To Do"
+        #[arg(long)]
+        status: Option<String>,
+        /// Default: the template's, else `default_priority` from config (P2)
+        #[arg(long)]
+        priority: Option<String>,
+        /// Default: the template's, else Phase1
+        #[arg(long)]
+        phase: Option<String>,
+        #[arg(long, default_value = "")]
+        labels: String,
+        #[arg(long, default_value = "")]
+        dependencies: String,
+        #[arg(long, default_value = "")]
+        assignee: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Create a task discovered from another task
+    AddDiscovered {
+        #[arg(long)]
+        from: String,
+        #[arg(long, value_name = "task-id")]
+        id: Option<String>,
+        #[arg(long)]
+        title: String,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        acceptance_criteria: Option<String>,
+        #[arg(long)]
+        definition_of_done: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        draft: bool,
+        #[arg(long, default_value = "To Do")]
+        status: String,
+        #[arg(long, default_value = "P2")]
+        priority: String,
+        #[arg(long, default_value = "Phase1")]
+        phase: String,
+        #[arg(long, default_value = "")]
+        labels: String,
+        #[arg(long, default_value = "")]
+        dependencies: String,
+        #[arg(long, default_value = "")]
+        assignee: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Create project docs scaffold
+    ProjectInit {
+        project_id: String,
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// Bootstrap WorkMesh by auto-detecting repo state and applying setup/migration
+    Bootstrap {
+        /// Project id to use when initializing a new repo or seeding missing context
+        #[arg(long)]
+        project_id: Option<String>,
+        /// Feature phrase for seed task initiative naming
+        #[arg(long)]
+        feature: Option<String>,
+        /// Objective to set when context is missing
+        #[arg(long)]
+        objective: Option<String>,
+        /// Repo-relative or absolute path for task files when initializing a new repo
+        #[arg(long)]
+        tasks_root: Option<String>,
+        /// Repo-relative or absolute path for repo-local WorkMesh state when initializing a new repo
+        #[arg(long)]
+        state_root: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// First-run setup wizard: config, git hook, skills, and a seeded backlog (prompts on a terminal)
+    Init {
+        /// Project id for `docs/projects/<id>` (default: the repo directory name)
+        project_id: Option<String>,
+        #[arg(long)]
+        name: Option<String>,
+        /// Workflow statuses in order, comma-separated (e.g. "To Do,In Progress,Review,Done")
+        #[arg(long)]
+        statuses: Option<String>,
+        /// Priority `add` uses when none is given
+        #[arg(long)]
+        default_priority: Option<String>,
+        /// Initiative key for task ids on the current branch (`task-<key>-001`)
+        #[arg(long)]
+        initiative: Option<String>,
+        /// Task filename template (`[task_files] template`), e.g. "{id} - {title}"
+        #[arg(long)]
+        filename_template: Option<String>,
+        /// Install a pre-commit hook that runs `workmesh validate` and `workmesh lint`
+        #[arg(long, action = ArgAction::SetTrue)]
+        hooks: bool,
+        /// Install the WorkMesh agent skill into this project
+        #[arg(long, action = ArgAction::SetTrue)]
+        skills: bool,
+        /// Skip project docs, task roots, and the seed task
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_seed: bool,
+        /// Do not prompt; use the flags as given
+        #[arg(long, action = ArgAction::SetTrue)]
+        yes: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Quickstart: scaffold docs + backlog + seed task
+    Quickstart {
+        project_id: String,
+        #[arg(long)]
+        name: Option<String>,
+        /// Feature/project phrase used to seed initiative acronym for the first task id
+        #[arg(long)]
+        feature: Option<String>,
+        /// Repo-relative or absolute path for task files
+        #[arg(long)]
+        tasks_root: Option<String>,
+        /// Repo-relative or absolute path for repo-local WorkMesh state
+        #[arg(long)]
+        state_root: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        agents_snippet: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Migrate legacy/deprecated structures (audit -> plan -> apply)
+    Migrate {
+        #[command(subcommand)]
+        command: Option<MigrateCommand>,
+        /// Legacy migration target (compat mode when no subcommand is provided)
+        #[arg(long)]
+        to: Option<String>,
+        /// Legacy non-interactive confirm (compat mode when no subcommand is provided)
+        #[arg(long, action = ArgAction::SetTrue)]
+        yes: bool,
+    },
+    /// Archive terminal tasks into date-based folders (defaults: Done, Cancelled, Canceled, Won't Do, Wont Do)
+    #[command(args_conflicts_with_subcommands = true)]
+    Archive {
+        #[command(subcommand)]
+        command: Option<ArchiveCommand>,
+        #[arg(long, default_value = "30d")]
+        before: String,
+        /// Status filter(s). Repeat or comma-separate for multiple values.
+        /// When omitted, defaults to terminal statuses: Done, Cancelled, Canceled, Won't Do, Wont Do.
+        #[arg(long, action = ArgAction::Append)]
+        status: Vec<String>,
+        /// Moves per chunk; progress is reported and journaled after each chunk
+        #[arg(long, default_value_t = DEFAULT_ARCHIVE_CHUNK_SIZE)]
+        chunk_size: usize,
+        /// Finish an interrupted run from its journal instead of planning a new one
+        #[arg(long, action = ArgAction::SetTrue)]
+        resume: bool,
+        /// Skip the post-run check that moved files parse and the indexes are consistent
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_verify: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Remove a task: move it to `trash/` with a tombstone (or mark it Cancelled) and strip it
+    /// from other tasks' dependency lists
+    Remove {
+        task_id: String,
+        /// Keep the file and set status Cancelled instead of moving it to the trash
+        #[arg(long, action = ArgAction::SetTrue)]
+        cancel: bool,
+        /// Show what would change without writing anything
+        #[arg(long, action = ArgAction::SetTrue)]
+        dry_run: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Rename a task id (and its file), rewriting dependencies and relationships that point at it
+    Move {
+        old_id: String,
+        new_id: String,
+        /// Also rewrite free-text mentions of the old id in task bodies
+        #[arg(long, action = ArgAction::SetTrue)]
+        body: bool,
+        /// Show the files that would change without writing anything
+        #[arg(long, action = ArgAction::SetTrue)]
+        dry_run: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Split a task into subtasks that inherit its fields; the original becomes their epic
+    Split {
+        task_id: String,
+        /// Titles of the new subtasks
+        #[arg(long, num_args = 1.., required = true)]
+        into: Vec<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Validate task files
+    Validate {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Render structured data using the native WorkMesh renderers
+    Render {
+        #[command(subcommand)]
+        command: RenderCommand,
+    },
+    /// Show backlog best practices
+    BestPractices,
+    /// Render gantt text (PlantUML or Mermaid)
+    Gantt {
+        #[arg(long)]
+        start: Option<String>,
+        /// PlantUML scale; ignored for Mermaid
+        #[arg(long, default_value_t = 3)]
+        zoom: i32,
+        #[arg(long, value_enum, default_value_t = GanttFormatArg::Plantuml)]
+        format: GanttFormatArg,
+    },
+    /// Write gantt text (PlantUML or Mermaid) to a file
+    GanttFile {
+        #[arg(long)]
+        start: Option<String>,
+        /// PlantUML scale; ignored for Mermaid
+        #[arg(long, default_value_t = 3)]
+        zoom: i32,
+        #[arg(long, value_enum, default_value_t = GanttFormatArg::Plantuml)]
+        format: GanttFormatArg,
+        #[arg(long)]
+        output: PathBuf,
+    },
+    /// Render gantt SVG via PlantUML
+    GanttSvg {
+        #[arg(long)]
+        start: Option<String>,
+        #[arg(long, default_value_t = 3)]
+        zoom: i32,
+        #[arg(long)]
+        output: Option<PathBuf>,
+        #[arg(long)]
+        plantuml_cmd: Option<String>,
+        #[arg(long)]
+        plantuml_jar: Option<PathBuf>,
+    },
+    /// Schedule open tasks by `estimate_days` and list the critical chain with per-task slack
+    CriticalPath {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
 }
 
-#[derive(Debug, Copy, Clone, ValueEnum, PartialEq, Eq, Hash)]
-enum FixTargetArg {
-    Uid,
-    Deps,
-    Ids,
-    Filenames,
-}
-
-#[derive(Debug, Copy, Clone, ValueEnum)]
-enum SkillScopeArg {
-    User,
-    Project,
-}
-
-#[derive(Debug, Copy, Clone, ValueEnum)]
-enum SkillAgentArg {
-    Codex,
-    Claude,
-    Cursor,
-    All,
-}
-
-#[derive(Debug, Copy, Clone, ValueEnum)]
-enum SkillProfileArg {
-    /// Hybrid router skill (auto-select CLI or MCP mode)
-    Hybrid,
-    /// CLI-only profile
-    Cli,
-    /// MCP-only profile
-    Mcp,
-    /// Install all profiles (hybrid + cli + mcp)
-    All,
-}
-
-impl From<SkillScopeArg> for SkillScope {
-    fn from(value: SkillScopeArg) -> Self {
-        match value {
-            SkillScopeArg::User => SkillScope::User,
-            SkillScopeArg::Project => SkillScope::Project,
-        }
-    }
-}
-
-fn skill_names_for_profile(profile: SkillProfileArg) -> Vec<&'static str> {
-    match profile {
-        SkillProfileArg::Hybrid => vec!["workmesh"],
-        SkillProfileArg::Cli => vec!["workmesh-cli"],
-        SkillProfileArg::Mcp => vec!["workmesh-mcp"],
-        SkillProfileArg::All => embedded_skill_ids(),
-    }
-}
-
-fn merge_install_report(report: &mut SkillInstallReport, partial: SkillInstallReport) {
-    report.written.extend(partial.written);
-    report.skipped.extend(partial.skipped);
-}
-
-fn merge_uninstall_report(report: &mut SkillUninstallReport, partial: SkillUninstallReport) {
-    report.removed.extend(partial.removed);
-    report.missing.extend(partial.missing);
+#[derive(Subcommand)]
+enum SkillCommand {
+    /// Show a skill's SKILL.md content (reads repo skill dirs, falls back to embedded default)
+    Show {
+        /// Skill name (defaults to workmesh)
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Install the embedded WorkMesh skill into agent skill directories
+    Install {
+        /// Skill name (defaults to workmesh)
+        #[arg(long)]
+        name: Option<String>,
+        /// Install to user-level (~/.codex/skills, ~/.claude/skills, ~/.cursor/skills) or
+        /// project-level (<repo>/.agents/skills for Codex/Cursor, <repo>/.claude/skills for
+        /// Claude)
+        #[arg(long, value_enum, default_value_t = SkillScopeArg::User)]
+        scope: SkillScopeArg,
+        /// Which agent(s) to install for
+        #[arg(long, value_enum, default_value_t = SkillAgentArg::All)]
+        agent: SkillAgentArg,
+        /// Overwrite existing SKILL.md files
+        #[arg(long, action = ArgAction::SetTrue)]
+        force: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Uninstall the embedded WorkMesh skill from agent skill directories
+    Uninstall {
+        /// Skill name (defaults to workmesh)
+        #[arg(long)]
+        name: Option<String>,
+        /// Uninstall from user-level (~/.codex/skills, ~/.claude/skills, ~/.cursor/skills) or
+        /// project-level (<repo>/.agents/skills for Codex/Cursor, <repo>/.claude/skills for
+        /// Claude)
+        #[arg(long, value_enum, default_value_t = SkillScopeArg::User)]
+        scope: SkillScopeArg,
+        /// Which agent(s) to uninstall for
+        #[arg(long, value_enum, default_value_t = SkillAgentArg::All)]
+        agent: SkillAgentArg,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Install the embedded skill globally for detected agents under your home directory
+    ///
+    /// This only installs for agents that already have a home folder (e.g. ~/.codex, ~/.claude, ~/.cursor).
+    InstallGlobal {
+        /// Skill name (defaults to workmesh)
+        #[arg(long)]
+        name: Option<String>,
+        /// Overwrite existing SKILL.md files
+        #[arg(long, action = ArgAction::SetTrue)]
+        force: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Uninstall the embedded skill globally for detected agents under your home directory
+    UninstallGlobal {
+        /// Skill name (defaults to workmesh)
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn print_install_report(report: SkillInstallReport) {
-    if report.written.is_empty() {
-        println!("(no files written)");
-    } else {
-        for path in report.written {
-            println!("{}", path.display());
-        }
-    }
-    if !report.skipped.is_empty() {
-        println!("(skipped existing; use --force to overwrite)");
+#[derive(Subcommand)]
+enum RenderCommand {
+    /// Render a table from array/object data
+    Table(RenderInputArgs),
+    /// Render a key/value view
+    Kv(RenderInputArgs),
+    /// Render compact stats
+    Stats(RenderInputArgs),
+    /// Render a list
+    List(RenderInputArgs),
+    /// Render progress output
+    Progress(RenderInputArgs),
+    /// Render a tree
+    Tree(RenderInputArgs),
+    /// Render a diff
+    Diff(RenderInputArgs),
+    /// Render logs
+    Logs(RenderInputArgs),
+    /// Render alerts
+    Alerts(RenderInputArgs),
+    /// Render a bar chart
+    ChartBar(RenderInputArgs),
+    /// Render a sparkline
+    Sparkline(RenderInputArgs),
+    /// Render a timeline
+    Timeline(RenderInputArgs),
+}
+
+#[derive(Args, Clone, Debug)]
+struct RenderInputArgs {
+    /// Inline data payload. If valid JSON, it is parsed as JSON; otherwise it is treated as a string.
+    #[arg(long, conflicts_with_all = ["data_file", "stdin"])]
+    data: Option<String>,
+    /// Read data payload from a file.
+    #[arg(long, value_name = "path", conflicts_with_all = ["data", "stdin"])]
+    data_file: Option<PathBuf>,
+    /// Read data payload from stdin.
+    #[arg(long, action = ArgAction::SetTrue, conflicts_with_all = ["data", "data_file"])]
+    stdin: bool,
+    /// Optional renderer format hint.
+    #[arg(long)]
+    format: Option<String>,
+    /// Inline configuration JSON for the renderer.
+    #[arg(long, conflicts_with = "config_file")]
+    configuration: Option<String>,
+    /// Read configuration JSON from a file.
+    #[arg(long, value_name = "path", conflicts_with = "configuration")]
+    config_file: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum FixCommand {
+    /// List available fixers
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Run all fixers (optionally scoped by --only/--exclude)
+    All {
+        /// Apply changes (default is check/dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Explicitly run in check mode (default if --apply is not set)
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
+        /// Comma-separated list of fixers to include (uid,deps,ids,filenames)
+        #[arg(long, value_delimiter = ',', value_enum)]
+        only: Vec<FixTargetArg>,
+        /// Comma-separated list of fixers to exclude (uid,deps,ids,filenames)
+        #[arg(long, value_delimiter = ',', value_enum)]
+        exclude: Vec<FixTargetArg>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Backfill missing task uid values
+    Uid {
+        /// Apply changes (default is check/dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Explicitly run in check mode (default if --apply is not set)
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Remove missing/duplicate dependencies from task dependency lists
+    Deps {
+        /// Apply changes (default is check/dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Explicitly run in check mode (default if --apply is not set)
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Detect/rekey duplicate task ids
+    Ids {
+        /// Apply changes (default is check/dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Explicitly run in check mode (default if --apply is not set)
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Normalize task filenames from task id/title/uid metadata
+    Filenames {
+        /// Apply changes (default is check/dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Explicitly run in check mode (default if --apply is not set)
+        #[arg(long, action = ArgAction::SetTrue)]
+        check: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum, PartialEq, Eq, Hash)]
+enum FixTargetArg {
+    Uid,
+    Deps,
+    Ids,
+    Filenames,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum ImportFormatArg {
+    /// Jira "Export CSV (all fields)"
+    JiraCsv,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum GanttFormatArg {
+    Plantuml,
+    /// Mermaid `gantt`, rendered natively in GitHub Markdown
+    Mermaid,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum GraphFormatArg {
+    Json,
+    /// Mermaid `flowchart`, rendered natively in GitHub Markdown
+    Mermaid,
+    /// Graphviz DOT, for `dot -Tsvg` and other Graphviz tooling
+    Dot,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum ShowFormatArg {
+    Text,
+    /// Standalone HTML page; Mermaid, PlantUML and images in the body are rendered
+    Html,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum UpdateChannelArg {
+    /// Latest published release
+    Stable,
+    /// Newest release including pre-releases
+    Nightly,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum AuditKeyAlgorithmArg {
+    /// Shared secret; verifying needs the same key
+    Hmac,
+    /// Key pair; anyone with the public key can verify
+    Ed25519,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum SkillScopeArg {
+    User,
+    Project,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum SkillAgentArg {
+    Codex,
+    Claude,
+    Cursor,
+    All,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum SkillProfileArg {
+    /// Hybrid router skill (auto-select CLI or MCP mode)
+    Hybrid,
+    /// CLI-only profile
+    Cli,
+    /// MCP-only profile
+    Mcp,
+    /// Install all profiles (hybrid + cli + mcp)
+    All,
+}
+
+impl From<SkillScopeArg> for SkillScope {
+    fn from(value: SkillScopeArg) -> Self {
+        match value {
+            SkillScopeArg::User => SkillScope::User,
+            SkillScopeArg::Project => SkillScope::Project,
+        }
+    }
+}
+
+fn skill_names_for_profile(profile: SkillProfileArg) -> Vec<&'static str> {
+    match profile {
+        SkillProfileArg::Hybrid => vec!["workmesh"],
+        SkillProfileArg::Cli => vec!["workmesh-cli"],
+        SkillProfileArg::Mcp => vec!["workmesh-mcp"],
+        SkillProfileArg::All => embedded_skill_ids(),
+    }
+}
+
+fn merge_install_report(report: &mut SkillInstallReport, partial: SkillInstallReport) {
+    report.written.extend(partial.written);
+    report.skipped.extend(partial.skipped);
+}
+
+fn merge_uninstall_report(report: &mut SkillUninstallReport, partial: SkillUninstallReport) {
+    report.removed.extend(partial.removed);
+    report.missing.extend(partial.missing);
+}
+
+fn print_install_report(report: SkillInstallReport) {
+    if report.written.is_empty() {
+        println!("(no files written)");
+    } else {
+        for path in report.written {
+            println!("{}", path.display());
+        }
+    }
+    if !report.skipped.is_empty() {
+        println!("(skipped existing; use --force to overwrite)");
         for path in report.skipped {
             println!("{}", path.display());
         }
@@ -1193,10 +2174,117 @@ fn print_fix_report(report: &FixRunReport, apply: bool) {
         report.skipped
     );
     for warning in &report.warnings {
-        println!("  warning: {}", warning);
+        logging::warn(warning);
+    }
+}
+
+fn print_github_report(report: &GithubSyncReport) {
+    let verb = if report.direction == "export" {
+        "issue"
+    } else {
+        "task"
+    };
+    for item in &report.created {
+        let target = match report.direction.as_str() {
+            "export" => item.issue.map(|number| format!("#{}", number)),
+            _ => item.task_id.clone(),
+        };
+        let source = match report.direction.as_str() {
+            "export" => item.task_id.clone().unwrap_or_default(),
+            _ => item.issue.map(|n| format!("#{}", n)).unwrap_or_default(),
+        };
+        println!(
+            "new {}: {} -> {} | {}",
+            verb,
+            source,
+            target.as_deref().unwrap_or("(new)"),
+            item.title
+        );
+    }
+    let label = |item: &GithubSyncItem| {
+        format!(
+            "{} <-> {} | {} | {}",
+            item.task_id.as_deref().unwrap_or("-"),
+            item.issue.map(|n| format!("#{}", n)).unwrap_or_default(),
+            item.title,
+            item.fields.join(", ")
+        )
+    };
+    for item in &report.updated {
+        println!("update: {}", label(item));
+    }
+    for item in &report.stale {
+        println!("stale: {}", label(item));
+    }
+    for item in &report.conflicts {
+        println!("conflict: {}", label(item));
+    }
+    println!(
+        "{}: new: {}, updated: {}, unchanged: {}, stale: {}, conflicts: {}",
+        report.repo,
+        report.created.len(),
+        report.updated.len(),
+        report.unchanged,
+        report.stale.len(),
+        report.conflicts.len()
+    );
+    if !report.stale.is_empty() {
+        let other = if report.direction == "export" {
+            "import"
+        } else {
+            "export"
+        };
+        println!(
+            "Stale items changed only on the other side: run `github {}`.",
+            other
+        );
+    }
+    if !report.conflicts.is_empty() {
+        println!("Conflicts changed on both sides: resolve by hand or re-run with --force.");
+    }
+    if !report.apply {
+        println!("Dry-run: re-run with --apply to write changes.");
+    }
+}
+
+fn print_conflict_report(report: &ConflictReport) {
+    match &report.parents {
+        Some(parents) => println!(
+            "conflicts | ours={} theirs={} base={}{}",
+            short_sha(&parents.ours),
+            short_sha(&parents.theirs),
+            short_sha(&parents.base),
+            if parents.in_progress {
+                " (merge in progress)"
+            } else {
+                ""
+            }
+        ),
+        None => println!("conflicts | no merge to inspect"),
+    }
+    for conflict in &report.conflicts {
+        println!(
+            "  {} {}: ours={} theirs={} current={}",
+            conflict.task_id,
+            conflict.field,
+            conflict.ours.as_deref().unwrap_or("(none)"),
+            conflict.theirs.as_deref().unwrap_or("(none)"),
+            conflict.current.as_deref().unwrap_or("(none)")
+        );
+        println!("    suggestion: {}", conflict.suggestion);
+    }
+    for path in &report.unresolved_markers {
+        println!("  unresolved conflict markers: {}", path.display());
+    }
+    for warning in &report.warnings {
+        logging::warn(warning);
     }
 }
 
+fn short_sha(sha: &str) -> &str {
+    sha.get(..8).unwrap_or(sha)
+}
+
 fn rewrite_cli_args(args: Vec<OsString>) -> Vec<OsString> {
     if args.len() <= 1 {
         return args;
@@ -1207,6 +2295,7 @@ fn rewrite_cli_args(args: Vec<OsString>) -> Vec<OsString> {
 
     let mut command_seen = false;
     let mut skip_next_value = false;
+    let mut root: Option<PathBuf> = None;
 
     for arg in args.into_iter().skip(1) {
         if command_seen {
@@ -1216,6 +2305,7 @@ fn rewrite_cli_args(args: Vec<OsString>) -> Vec<OsString> {
 
         let value = arg.to_string_lossy().to_string();
         if skip_next_value {
+            root = Some(PathBuf::from(&arg));
             rewritten.push(arg);
             skip_next_value = false;
             continue;
@@ -1226,6 +2316,9 @@ fn rewrite_cli_args(args: Vec<OsString>) -> Vec<OsString> {
             skip_next_value = true;
             continue;
         }
+        if let Some(path) = value.strip_prefix("--root=") {
+            root = Some(PathBuf::from(path));
+        }
 
         if value.starts_with('-') {
             rewritten.push(arg);
@@ -1234,13 +2327,36 @@ fn rewrite_cli_args(args: Vec<OsString>) -> Vec<OsString> {
 
         command_seen = true;
         let normalized = value.replace('_', "-");
-        let alias = command_alias(&normalized).unwrap_or_else(|| vec![normalized]);
+        let alias = command_alias(&normalized)
+            .or_else(|| user_alias(&value, root.as_deref()))
+            .unwrap_or_else(|| vec![normalized]);
         rewritten.extend(alias.into_iter().map(OsString::from));
     }
 
     rewritten
 }
 
+/// Expand a built-in or `[aliases]` short form. Real subcommands always win over aliases.
+fn user_alias(name: &str, root: Option<&Path>) -> Option<Vec<String>> {
+    if Cli::command()
+        .find_subcommand(name.replace('_', "-"))
+        .is_some()
+    {
+        return None;
+    }
+    let root = resolve_cli_root(
+        root.map(Path::to_path_buf),
+        std::env::var(ROOT_ENV).ok().as_deref(),
+        &std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+    );
+    let aliases = resolve_aliases(&resolve_cli_repo_root(&root));
+    let mut words = expand_alias(&aliases, name).unwrap_or_else(|err| die(&err.to_string()))?;
+    let command = words.remove(0).replace('_', "-");
+    let mut expanded = command_alias(&command).unwrap_or_else(|| vec![command]);
+    expanded.extend(words);
+    Some(expanded)
+}
+
 fn command_alias(command: &str) -> Option<Vec<String>> {
     let alias = match command {
         "help" => vec!["--help"],
@@ -1254,6 +2370,7 @@ fn command_alias(command: &str) -> Option<Vec<String>> {
         "config-unset" => vec!["config", "unset"],
         "context-show" => vec!["context", "show"],
         "context-set" => vec!["context", "set"],
+        "context-pack" => vec!["context", "pack"],
         "context-clear" => vec!["context", "clear"],
         "workstream-list" => vec!["workstream", "list"],
         "workstream-create" => vec!["workstream", "create"],
@@ -1278,6 +2395,8 @@ fn command_alias(command: &str) -> Option<Vec<String>> {
         "truth-supersede" => vec!["truth", "supersede"],
         "truth-show" => vec!["truth", "show"],
         "truth-list" => vec!["truth", "list"],
+        "assign-auto" => vec!["assign", "auto"],
+        "workflow-show" => vec!["workflow", "show"],
         "truth-validate" => vec!["truth", "validate"],
         "truth-migrate-audit" => vec!["truth", "migrate", "audit"],
         "truth-migrate-plan" => vec!["truth", "migrate", "plan"],
@@ -1296,6 +2415,7 @@ fn command_alias(command: &str) -> Option<Vec<String>> {
         "ready-tasks" => vec!["ready"],
         "export-tasks" => vec!["export"],
         "archive-tasks" => vec!["archive"],
+        "archive-search" => vec!["archive", "search"],
         "claim-task" => vec!["claim"],
         "release-task" => vec!["release"],
         "add-label" => vec!["label-add"],
@@ -1311,6 +2431,7 @@ fn command_alias(command: &str) -> Option<Vec<String>> {
         "add-discovered" => vec!["add-discovered"],
         "working-set" => vec!["working-set"],
         "session-journal" => vec!["session-journal"],
+        "journal-list" => vec!["journal", "list"],
         "checkpoint-diff" => vec!["checkpoint-diff"],
         "graph-export" => vec!["graph-export"],
         "issues-export" => vec!["issues-export"],
@@ -1455,6 +2576,17 @@ enum ContextCommand {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
+    /// Assemble a token-budgeted Markdown/JSON pack of context, ready tasks, blockers, decisions
+    Pack {
+        /// Approximate token budget: 4000, 4000-tokens, or 4k
+        #[arg(long, default_value = "4000-tokens")]
+        budget: String,
+        /// Scope to an epic subtree (defaults to the context epic scope)
+        #[arg(long)]
+        epic: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1910,1497 +3042,5037 @@ enum TruthCommand {
 }
 
 #[derive(Subcommand)]
-enum BulkCommand {
-    /// Bulk set status for tasks
-    SetStatus {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+enum ExportCommand {
+    /// Write an Obsidian vault: wiki-linked task notes plus a Dataview dashboard
+    Obsidian {
         #[arg(long)]
-        status: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
-        #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        output: PathBuf,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk set a front matter field for tasks
-    SetField {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum ReportCommand {
+    /// Write a self-contained static site: board, blockers, stats, epic progress, task pages
+    Html {
         #[arg(long)]
-        field: String,
+        output: PathBuf,
+        /// Include archived tasks under `workmesh/archive/` (recursively)
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        /// PlantUML command for ```plantuml blocks in task bodies (as for gantt-svg)
         #[arg(long)]
-        value: String,
+        plantuml_cmd: Option<String>,
+        #[arg(long)]
+        plantuml_jar: Option<PathBuf>,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        json: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum TaskwarriorCommand {
+    /// Create tasks from `task export` JSON (dry-run unless --apply; re-imports are skipped)
+    Import {
+        /// Path to the `task export` output (default: stdin)
+        input: Option<PathBuf>,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk add label to tasks
-    LabelAdd {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Write tasks as JSON for `task import`
+    Export {
         #[arg(long)]
-        label: String,
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DaemonCommand {
+    /// Install and start a per-user service running `index-watch` for this repo
+    InstallService {
+        /// Milliseconds between checks of the tasks directory
+        #[arg(long, default_value_t = DEFAULT_WATCH_INTERVAL_MS)]
+        interval_ms: u64,
+        /// Register the service without starting it now (it starts at next login)
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        no_start: bool,
+        /// Print the service definition instead of installing it
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        dry_run: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk remove label from tasks
-    LabelRemove {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
-        #[arg(long)]
-        label: String,
-        #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+    /// Stop and remove this repo's service (the log is kept)
+    UninstallService {
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
+    },
+    /// Whether this repo's service is installed and running, with its log location
+    Status {
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk add dependency to tasks
-    DepAdd {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+}
+
+#[derive(Subcommand)]
+enum GithubCommand {
+    /// Create issues for unlinked tasks and push task changes (dry-run unless --apply)
+    Export {
+        /// `owner/name` (default: `[github] repo`, then the origin remote)
         #[arg(long)]
-        dependency: String,
+        repo: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        apply: bool,
+        /// Overwrite issues that also changed on GitHub since the last sync
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        force: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk remove dependency from tasks
-    DepRemove {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
+    /// Create tasks for unlinked open issues and pull issue changes (dry-run unless --apply)
+    Import {
+        /// `owner/name` (default: `[github] repo`, then the origin remote)
         #[arg(long)]
-        dependency: String,
+        repo: Option<String>,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        apply: bool,
+        /// Overwrite tasks that also changed locally since the last sync
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        force: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
-    /// Bulk append a note to tasks
-    Note {
-        #[arg(long, value_delimiter = ',', num_args = 1..)]
-        tasks: Vec<String>,
-        #[arg(long)]
-        note: String,
-        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
-        section: NoteSection,
+}
+
+#[derive(Subcommand)]
+enum AttachmentsCommand {
+    /// Local and remote attachments of a task
+    List {
+        task_id: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        touch: bool,
-        /// Do not update `updated_date` (default behavior touches on all mutations)
+        json: bool,
+    },
+    /// Print the local path of an attachment, downloading remote ones into the cache
+    Fetch {
+        task_id: String,
+        name: String,
         #[arg(long, action = ArgAction::SetTrue)]
-        no_touch: bool,
+        json: bool,
+    },
+    /// Delete remote objects of deleted tasks, and of archived ones with `delete_on_archive`
+    /// (dry-run unless --apply)
+    Gc {
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
         #[arg(long, action = ArgAction::SetTrue)]
         json: bool,
     },
 }
 
-#[derive(Clone, Copy, Debug, ValueEnum)]
-enum SortKey {
-    Id,
-    Title,
-    Kind,
-    Status,
-    Phase,
-    Priority,
+#[derive(Subcommand)]
+enum RecurCommand {
+    /// List recurring tasks with their rule, last materialized date, and next occurrence
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Create a task for every due occurrence that does not have one yet
+    Run {
+        /// Also create occurrences due within this many days
+        #[arg(long, default_value_t = 0)]
+        ahead: i64,
+        /// Show what would be created without writing anything
+        #[arg(long, action = ArgAction::SetTrue)]
+        dry_run: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-impl SortKey {
-    fn as_str(self) -> &'static str {
-        match self {
-            SortKey::Id => "id",
-            SortKey::Title => "title",
-            SortKey::Kind => "kind",
-            SortKey::Status => "status",
-            SortKey::Phase => "phase",
-            SortKey::Priority => "priority",
-        }
-    }
+#[derive(Subcommand)]
+enum TemplateCommand {
+    /// List templates
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Show a template's defaults and body
+    Show {
+        name: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Create a template, blank or copied from an existing task
+    Add {
+        name: String,
+        /// Seed priority, phase, labels, and body from this task
+        #[arg(long, value_name = "task-id")]
+        from_task: Option<String>,
+        /// One-line summary shown by `template list`
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long)]
+        priority: Option<String>,
+        #[arg(long)]
+        phase: Option<String>,
+        #[arg(long, default_value = "")]
+        labels: String,
+        #[arg(long, default_value = "")]
+        assignee: String,
+        /// Overwrite an existing template
+        #[arg(long, action = ArgAction::SetTrue)]
+        force: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Create a task from a template (same as `add --template <name>`)
+    Apply {
+        name: String,
+        #[arg(long, value_name = "task-id")]
+        id: Option<String>,
+        #[arg(long)]
+        title: String,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        acceptance_criteria: Option<String>,
+        #[arg(long)]
+        definition_of_done: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        draft: bool,
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long)]
+        priority: Option<String>,
+        #[arg(long)]
+        phase: Option<String>,
+        #[arg(long, default_value = "")]
+        labels: String,
+        #[arg(long, default_value = "")]
+        dependencies: String,
+        #[arg(long, default_value = "")]
+        assignee: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-#[derive(Clone, Copy, Debug, ValueEnum)]
-enum BoardByArg {
-    Status,
-    Phase,
-    Priority,
+#[derive(Subcommand)]
+enum EpicCommand {
+    /// Create an epic; prompts for any missing section when run in a terminal
+    New {
+        #[arg(long, value_name = "task-id")]
+        id: Option<String>,
+        #[arg(long)]
+        title: Option<String>,
+        #[arg(long)]
+        problem: Option<String>,
+        #[arg(long)]
+        outcomes: Option<String>,
+        #[arg(long)]
+        scope: Option<String>,
+        #[arg(long)]
+        non_goals: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        draft: bool,
+        #[arg(long, default_value = "To Do")]
+        status: String,
+        #[arg(long, default_value = "P2")]
+        priority: String,
+        #[arg(long, default_value = "Phase1")]
+        phase: String,
+        #[arg(long, default_value = "")]
+        labels: String,
+        #[arg(long, default_value = "")]
+        assignee: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-impl BoardByArg {
-    fn to_core(self) -> BoardBy {
-        match self {
-            BoardByArg::Status => BoardBy::Status,
-            BoardByArg::Phase => BoardBy::Phase,
-            BoardByArg::Priority => BoardBy::Priority,
-        }
-    }
+#[derive(Subcommand)]
+enum MilestoneCommand {
+    /// Every milestone with its task count and completion
+    List {
+        /// Include archived tasks
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// One milestone's tasks, completion, and projected finish
+    Show {
+        name: String,
+        /// Days of completions used for velocity
+        #[arg(long, default_value_t = DEFAULT_VELOCITY_WINDOW_DAYS)]
+        window_days: i64,
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Completion, velocity, and projected finish per milestone
+    Progress {
+        /// Only this milestone
+        name: Option<String>,
+        /// Days of completions used for velocity
+        #[arg(long, default_value_t = DEFAULT_VELOCITY_WINDOW_DAYS)]
+        window_days: i64,
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-#[derive(Clone, Copy, Debug, ValueEnum)]
-enum NoteSection {
-    Notes,
-    Impl,
+#[derive(Subcommand)]
+enum BudgetCommand {
+    /// Consumed and planned points/hours against each budget, with projected overrun dates
+    Status {
+        /// Only this initiative's budget
+        #[arg(long, conflicts_with = "phase")]
+        initiative: Option<String>,
+        /// Only this phase's budget
+        #[arg(long)]
+        phase: Option<String>,
+        /// Include archived tasks
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-impl NoteSection {
-    fn as_str(self) -> &'static str {
-        match self {
-            NoteSection::Notes => "notes",
-            NoteSection::Impl => "impl",
-        }
-    }
-}
+#[derive(Subcommand)]
+enum OnboardingCommand {
+    /// Create an onboarding epic with checklist tasks assigned to the newcomer
+    New {
+        /// Newcomer's handle
+        #[arg(long = "for", value_name = "user")]
+        user: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Checklist progress per newcomer
+    Status {
+        /// Only this newcomer
+        #[arg(long = "for", value_name = "user")]
+        user: Option<String>,
+        /// Include archived tasks
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-fn is_done_status(status: &str) -> bool {
-    status.eq_ignore_ascii_case("done")
+#[derive(Subcommand)]
+enum RemoteCommand {
+    /// `list` against the remote backlog; trailing flags are passed through
+    List(RemoteQueryArgs),
+    /// `board` against the remote backlog; trailing flags are passed through
+    Board(RemoteQueryArgs),
+    /// `blockers` against the remote backlog; trailing flags are passed through
+    Blockers(RemoteQueryArgs),
 }
 
-fn is_status_field(field: &str) -> bool {
-    field.trim().eq_ignore_ascii_case("status")
+#[derive(Args, Clone, Debug)]
+struct RemoteQueryArgs {
+    /// Remote repository (any URL `git clone` accepts)
+    #[arg(long)]
+    repo: String,
+    /// Branch or tag (default: the remote's default branch)
+    #[arg(long = "ref", value_name = "ref")]
+    git_ref: Option<String>,
+    /// Use the cached checkout under ~/.workmesh/remotes/ without fetching
+    #[arg(long, action = ArgAction::SetTrue)]
+    offline: bool,
+    /// Flags for the local command, after --repo/--ref (e.g. `--status "To Do" --json`)
+    #[arg(
+        trailing_var_arg = true,
+        allow_hyphen_values = true,
+        value_name = "ARGS"
+    )]
+    args: Vec<String>,
 }
 
-fn effective_touch(touch: bool, no_touch: bool) -> bool {
-    if no_touch {
-        return false;
-    }
-    // Back-compat: `--touch` is still accepted, but touching is now the default on mutations.
-    if touch {
-        return true;
-    }
-    true
+#[derive(Subcommand)]
+enum GoalCommand {
+    /// Create a goal under <backlog>/goals/
+    New {
+        #[arg(long)]
+        title: String,
+        #[arg(long, value_name = "goal-id")]
+        id: Option<String>,
+        #[arg(long)]
+        description: Option<String>,
+        #[arg(long)]
+        owner: Option<String>,
+        /// Target date (YYYY-MM-DD)
+        #[arg(long)]
+        due: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Add a key result to a goal
+    AddKr {
+        goal: String,
+        #[arg(long)]
+        title: String,
+        /// Metric value that means "achieved"
+        #[arg(long)]
+        target: Option<f64>,
+        /// Metric baseline (defaults to 0)
+        #[arg(long)]
+        start: Option<f64>,
+        #[arg(long)]
+        unit: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Link a task to a key result (<goal-id>/<kr-id>)
+    Link {
+        task_id: String,
+        key_result: String,
+        /// Remove the link instead
+        #[arg(long, action = ArgAction::SetTrue)]
+        unlink: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Record a measured metric value for a key result
+    Report {
+        key_result: String,
+        value: f64,
+        #[arg(long)]
+        note: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// List goals
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Progress per goal and key result
+    Status {
+        goal: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn best_effort_git_snapshot(repo_root: &Path) -> GitSnapshot {
-    let branch = std::process::Command::new("git")
-        .arg("-C")
-        .arg(repo_root)
-        .arg("rev-parse")
-        .arg("--abbrev-ref")
-        .arg("HEAD")
-        .output()
-        .ok()
-        .and_then(|out| {
-            if out.status.success() {
-                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
-            } else {
-                None
-            }
-        })
-        .filter(|value| !value.trim().is_empty());
+#[derive(Subcommand)]
+enum BundleCommand {
+    /// Package selected tasks with their history and attachments into one file
+    Create {
+        #[arg(long)]
+        output: PathBuf,
+        /// Task ids (comma-separated or repeated)
+        #[arg(long = "task", value_name = "task-id")]
+        tasks: Vec<String>,
+        /// Statuses (comma-separated or repeated)
+        #[arg(long)]
+        status: Vec<String>,
+        /// Labels (comma-separated or repeated)
+        #[arg(long)]
+        label: Vec<String>,
+        /// Include this epic and everything beneath it
+        #[arg(long, value_name = "task-id")]
+        epic: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Open a bundle read-only (no backlog required)
+    View {
+        file: PathBuf,
+        /// Show one task in full (body, history, attachments, annotations)
+        #[arg(long, value_name = "task-id")]
+        task: Option<String>,
+        /// Write the shown task's attachments under this directory
+        #[arg(long, value_name = "dir", requires = "task")]
+        extract: Option<PathBuf>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Add a reviewer note to a bundled task (stored in the bundle file)
+    Annotate {
+        file: PathBuf,
+        task_id: String,
+        note: String,
+        /// Reviewer name (defaults to $USER)
+        #[arg(long = "as")]
+        as_user: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Merge a bundle's annotations back into the backlog as task notes
+    Apply {
+        file: PathBuf,
+        /// Write changes (default is a dry-run preview)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    let head_sha = std::process::Command::new("git")
-        .arg("-C")
-        .arg(repo_root)
-        .arg("rev-parse")
-        .arg("HEAD")
-        .output()
-        .ok()
-        .and_then(|out| {
-            if out.status.success() {
-                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
-            } else {
-                None
-            }
-        })
-        .filter(|value| !value.trim().is_empty());
+#[derive(Subcommand)]
+enum ProposalsCommand {
+    /// Preview a proposal's changes against the current tasks
+    Show {
+        proposal_id: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Apply every change in the proposal (all-or-nothing validation first)
+    Accept {
+        proposal_id: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Reject a proposal without touching any task
+    Reject {
+        proposal_id: String,
+        #[arg(long)]
+        reason: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    let dirty = std::process::Command::new("git")
-        .arg("-C")
-        .arg(repo_root)
-        .arg("status")
-        .arg("--porcelain")
-        .output()
-        .ok()
-        .and_then(|out| {
-            if out.status.success() {
-                Some(!String::from_utf8_lossy(&out.stdout).trim().is_empty())
-            } else {
-                None
-            }
-        });
+#[derive(Subcommand)]
+enum AuditCommand {
+    /// Changes per actor (CLI user or `mcp:<client>`), with actions and MCP connections
+    Actors {
+        /// Only count events at/after this (7d, 12h, yesterday, YYYY-MM-DD, RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only count events before this
+        #[arg(long)]
+        until: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Check the audit log hash chain and event signatures; exits 1 on any break
+    VerifySignatures {
+        /// Base64 Ed25519 public key, for verifying without the signing key
+        #[arg(long)]
+        public_key: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Generate an audit signing key (export it as WORKMESH_AUDIT_KEY)
+    Keygen {
+        #[arg(long, value_enum, default_value_t = AuditKeyAlgorithmArg::Ed25519)]
+        algorithm: AuditKeyAlgorithmArg,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    GitSnapshot {
-        branch,
-        head_sha,
-        dirty,
-    }
+#[derive(Subcommand)]
+enum ArchiveCommand {
+    /// Search archived tasks by title/body without loading the active backlog
+    Search {
+        query: String,
+        #[arg(long)]
+        limit: Option<usize>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn best_effort_git_branch(repo_root: &Path) -> Option<String> {
-    std::process::Command::new("git")
-        .arg("-C")
-        .arg(repo_root)
-        .arg("rev-parse")
-        .arg("--abbrev-ref")
-        .arg("HEAD")
-        .output()
-        .ok()
-        .and_then(|out| {
-            if out.status.success() {
-                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
-            } else {
-                None
-            }
-        })
-        .filter(|value| !value.trim().is_empty())
+#[derive(Subcommand)]
+enum WorkingSetCommand {
+    /// Compare the working set with current statuses and leases; flag stale entries
+    Status {
+        #[arg(long)]
+        project: Option<String>,
+        /// Rewrite the file without stale entries (notes are kept)
+        #[arg(long, action = ArgAction::SetTrue)]
+        refresh: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn render_session_line(session: &AgentSession) -> String {
-    format!(
-        "{} | {} | {} | {}",
-        session.id, session.updated_at, session.cwd, session.objective
-    )
+#[derive(Subcommand)]
+enum JournalCommand {
+    /// List journal entries, oldest first
+    List {
+        #[arg(long)]
+        project: Option<String>,
+        /// Only entries for this task
+        #[arg(long)]
+        task: Option<String>,
+        /// Only entries at/after this (7d, 12h, yesterday, YYYY-MM-DD, RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only entries before this
+        #[arg(long)]
+        until: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Render entries as a chronological narrative
+    Render {
+        #[arg(long)]
+        project: Option<String>,
+        #[arg(long)]
+        task: Option<String>,
+        #[arg(long)]
+        since: Option<String>,
+        #[arg(long)]
+        until: Option<String>,
+        /// Markdown grouped by day (otherwise one plain line per entry)
+        #[arg(long, action = ArgAction::SetTrue)]
+        markdown: bool,
+    },
 }
 
-fn render_session_detail(session: &AgentSession) -> String {
-    let mut lines = Vec::new();
-    lines.push(format!("id: {}", session.id));
-    lines.push(format!("updated_at: {}", session.updated_at));
-    lines.push(format!("cwd: {}", session.cwd));
-    if let Some(repo_root) = session.repo_root.as_deref() {
-        lines.push(format!("repo_root: {}", repo_root));
-    }
-    if let Some(project_id) = session.project_id.as_deref() {
-        lines.push(format!("project_id: {}", project_id));
-    }
-    if let Some(epic_id) = session.epic_id.as_deref() {
-        lines.push(format!("epic_id: {}", epic_id));
-    }
-    lines.push(format!("objective: {}", session.objective));
-    if !session.working_set.is_empty() {
-        lines.push(format!("working_set: {}", session.working_set.join(", ")));
-    }
-    if let Some(notes) = session.notes.as_deref() {
-        if !notes.trim().is_empty() {
-            lines.push(format!("notes: {}", notes));
-        }
-    }
-    if let Some(git) = session.git.as_ref() {
-        if git.branch.is_some() || git.head_sha.is_some() || git.dirty.is_some() {
-            lines.push(format!(
-                "git: branch={} sha={} dirty={}",
-                git.branch.clone().unwrap_or_else(|| "-".to_string()),
-                git.head_sha.clone().unwrap_or_else(|| "-".to_string()),
-                git.dirty
-                    .map(|value| value.to_string())
-                    .unwrap_or_else(|| "-".to_string())
-            ));
-        }
-    }
-    if let Some(checkpoint) = session.checkpoint.as_ref() {
-        lines.push(format!("checkpoint: {}", checkpoint.path));
-    }
-    if let Some(worktree) = session.worktree.as_ref() {
-        lines.push(format!("worktree.path: {}", worktree.path));
-        if let Some(branch) = worktree.branch.as_deref() {
-            lines.push(format!("worktree.branch: {}", branch));
-        }
-        if let Some(id) = worktree.id.as_deref() {
-            lines.push(format!("worktree.id: {}", id));
-        }
-    }
-    if let Some(handoff) = session.handoff.as_ref() {
-        if !handoff.completed.is_empty() {
-            lines.push(format!(
-                "handoff.completed: {}",
-                handoff.completed.join(" | ")
-            ));
-        }
-        if !handoff.remaining.is_empty() {
-            lines.push(format!(
-                "handoff.remaining: {}",
-                handoff.remaining.join(" | ")
-            ));
-        }
-        if !handoff.decisions.is_empty() {
-            lines.push(format!(
-                "handoff.decisions: {}",
-                handoff.decisions.join(" | ")
-            ));
-        }
-        if !handoff.unknowns.is_empty() {
-            lines.push(format!(
-                "handoff.unknowns: {}",
-                handoff.unknowns.join(" | ")
-            ));
-        }
-        if let Some(next_step) = handoff.next_step.as_deref() {
-            if !next_step.trim().is_empty() {
-                lines.push(format!("handoff.next_step: {}", next_step));
-            }
-        }
-    }
-    if !session.truth_refs.is_empty() {
-        lines.push(format!("truth_refs: {}", session.truth_refs.join(", ")));
-    }
-    lines.join("\n")
+#[derive(Subcommand)]
+enum AssigneeCommand {
+    /// Replace a handle everywhere tasks name it (dry run unless --apply)
+    Rename {
+        from: String,
+        to: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        /// Also record `from -> to` in `[actor_aliases]` so later CLI audit events use the new handle
+        #[arg(long, action = ArgAction::SetTrue)]
+        alias_actor: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn resume_script(session: &AgentSession) -> Vec<String> {
-    let mut lines = Vec::new();
-    if let Some(worktree) = session.worktree.as_ref() {
-        lines.push(format!("cd {}", worktree.path));
-    } else {
-        lines.push(format!("cd {}", session.cwd));
-    }
-
-    if let Some(repo_root) = session.repo_root.as_deref() {
-        lines.push(format!("workmesh --root {} context show", repo_root));
-        let mut truth_line = format!("workmesh --root {} truth list --state accepted", repo_root);
-        if let Some(project_id) = session.project_id.as_deref() {
-            truth_line.push_str(&format!(" --project {}", project_id));
-        }
-        if let Some(epic_id) = session.epic_id.as_deref() {
-            truth_line.push_str(&format!(" --epic {}", epic_id));
-        }
-        if let Some(worktree) = session.worktree.as_ref() {
-            truth_line.push_str(&format!(" --worktree-path {}", worktree.path));
-        }
-        truth_line.push_str(" --limit 10");
-        lines.push(truth_line);
-    }
+#[derive(Subcommand)]
+enum AssignCommand {
+    /// Label and assign open tasks from the CODEOWNERS entries of the files they reference
+    Auto {
+        /// Write labels and assignees (otherwise dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    if let (Some(repo_root), Some(project_id)) =
-        (session.repo_root.as_deref(), session.project_id.as_deref())
-    {
-        lines.push(format!(
-            "workmesh --root {} resume --project {}",
-            repo_root, project_id
-        ));
-        lines.push(format!("workmesh --root {} ready", repo_root));
-    }
+#[derive(Subcommand)]
+enum WorkflowCommand {
+    /// Render the workflow with task counts per status and average dwell time from audit data
+    Show {
+        #[arg(long, value_enum, default_value_t = WorkflowFormatArg::Mermaid)]
+        format: WorkflowFormatArg,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    if let Some(repo_root) = session.repo_root.as_deref() {
-        if let Some(task_id) = session.working_set.first() {
-            lines.push(format!("workmesh --root {} show {}", repo_root, task_id));
-            lines.push(format!(
-                "workmesh --root {} claim {} you",
-                repo_root, task_id
-            ));
-        }
-    }
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum CalibrationByArg {
+    Assignee,
+    Label,
+}
 
-    if !session.truth_refs.is_empty() {
-        lines.push(format!(
-            "# accepted truths: {}",
-            session.truth_refs.join(", ")
-        ));
-    }
+#[derive(Subcommand)]
+enum TokenCommand {
+    /// Generate a token; it is printed once and only its hash is stored
+    Create {
+        #[arg(long, value_enum)]
+        role: RoleArg,
+        /// Task id whose subtree the token may change (repeatable; default: unrestricted)
+        #[arg(long = "scope")]
+        scopes: Vec<String>,
+        /// Lifetime (12h, 30d, 8w) or an expiry date (YYYY-MM-DD, RFC3339)
+        #[arg(long)]
+        expires: Option<String>,
+        /// Label shown by `token list`
+        #[arg(long)]
+        name: Option<String>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// List stored tokens (ids and metadata, never the tokens)
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Delete a token by id
+    Revoke {
+        id: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    lines
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum RoleArg {
+    Viewer,
+    Contributor,
+    Maintainer,
 }
 
-fn parse_truth_states(values: &[String]) -> Result<Vec<TruthState>> {
-    let mut states = Vec::new();
-    let mut seen = HashSet::new();
-    for value in split_list(values) {
-        let Some(state) = TruthState::parse(&value) else {
-            die(&format!(
-                "Invalid truth state: {} (expected proposed|accepted|rejected|superseded)",
-                value
-            ));
-        };
-        if seen.insert(state) {
-            states.push(state);
-        }
-    }
-    Ok(states)
+#[derive(Subcommand)]
+enum AnalyticsCommand {
+    /// Lead time (created -> done) and cycle time (started -> done) percentiles of done tasks
+    CycleTime {
+        /// Group by label, phase, or priority (default: one overall bucket)
+        #[arg(long, value_enum)]
+        by: Option<CycleTimeByArg>,
+        /// Only tasks done at/after this (7d, 12h, yesterday, YYYY-MM-DD, RFC3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only tasks done before this
+        #[arg(long)]
+        until: Option<String>,
+        /// Include archived tasks
+        #[arg(long, action = ArgAction::SetTrue)]
+        all: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn render_truth_line(record: &workmesh_core::truth::TruthRecord) -> String {
-    let feature = record
-        .context
-        .feature
-        .clone()
-        .or(record.context.epic_id.clone())
-        .unwrap_or_else(|| "-".to_string());
-    format!(
-        "{} | {} | {} | feature={} | v{}",
-        record.id,
-        record.state.as_str(),
-        record.title,
-        feature,
-        record.version
-    )
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum CycleTimeByArg {
+    Label,
+    Phase,
+    Priority,
 }
 
-fn truth_refs_for_scope(
-    backlog_dir: &Path,
-    project_id: Option<&str>,
-    epic_id: Option<&str>,
-    worktree_path: Option<&str>,
-    limit: usize,
-) -> Vec<String> {
-    let query = TruthQuery {
-        states: vec![TruthState::Accepted],
-        project_id: project_id.map(|value| value.to_string()),
-        epic_id: epic_id.map(|value| value.to_string()),
-        feature: epic_id.map(|value| value.to_string()),
-        workstream_id: None,
-        session_id: None,
-        worktree_id: None,
-        worktree_path: worktree_path.map(|value| value.to_string()),
-        tags: Vec::new(),
-        limit: Some(limit),
-    };
-    list_truths(backlog_dir, &query)
-        .map(|records| records.into_iter().map(|record| record.id).collect())
-        .unwrap_or_default()
+#[derive(Subcommand)]
+enum BlockedTimeCommand {
+    /// Per-task blocked/ready/in-progress hours, most blocked first
+    Report {
+        /// Only tasks that are not Done/Cancelled
+        #[arg(long, action = ArgAction::SetTrue)]
+        open: bool,
+        #[arg(long)]
+        limit: Option<usize>,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
 }
 
-fn auto_update_current_session(backlog_dir: &Path) -> Result<()> {
-    let home = resolve_workmesh_home()?;
-    let Some(current_id) = read_current_session_id(&home) else {
-        return Ok(());
-    };
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum ActivityByArg {
+    Day,
+    Week,
+}
 
-    let sessions = load_sessions_latest_fast(&home)?;
-    let Some(existing) = sessions.into_iter().find(|s| s.id == current_id) else {
-        return Ok(());
-    };
+#[derive(Debug, Copy, Clone, ValueEnum)]
+enum WorkflowFormatArg {
+    Mermaid,
+    Dot,
+}
 
-    let rr = repo_root_from_backlog(backlog_dir);
-    let repo_root = rr.to_string_lossy().to_string();
-    let repo_tasks = load_tasks(backlog_dir);
-    let context_state = load_context_state(backlog_dir);
-    let project_id = context_state
-        .as_ref()
-        .and_then(|c| c.project_id.clone())
-        .unwrap_or_else(|| resolve_project_id(&rr, &repo_tasks, None));
-    let epic_id = context_state
-        .as_ref()
-        .and_then(|c| c.scope.epic_id.clone())
-        .or_else(|| best_effort_git_branch(&rr).and_then(|b| extract_task_id_from_branch(&b)));
+#[derive(Subcommand)]
+enum PoliciesCommand {
+    /// Evaluate every policy rule (dry-run unless --apply)
+    Run {
+        /// Apply changes (otherwise dry-run)
+        #[arg(long, action = ArgAction::SetTrue)]
+        apply: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Show configured policy rules
+    List {
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    let working_set: Vec<String> = repo_tasks
-        .iter()
-        .filter(|task| task.status.eq_ignore_ascii_case("in progress") || is_lease_active(task))
-        .map(|task| task.id.clone())
-        .collect();
+#[derive(Subcommand)]
+enum BulkCommand {
+    /// Bulk set status for tasks
+    SetStatus {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        status: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk set a front matter field for tasks
+    SetField {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        field: String,
+        #[arg(long)]
+        value: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk add label to tasks
+    LabelAdd {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        label: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk remove label from tasks
+    LabelRemove {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        label: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk add dependency to tasks
+    DepAdd {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        dependency: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk remove dependency from tasks
+    DepRemove {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        dependency: String,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+    /// Bulk append a note to tasks
+    Note {
+        #[arg(long, value_delimiter = ',', num_args = 1..)]
+        tasks: Vec<String>,
+        #[arg(long)]
+        note: String,
+        #[arg(long, value_enum, default_value_t = NoteSection::Notes)]
+        section: NoteSection,
+        #[arg(long, action = ArgAction::SetTrue)]
+        touch: bool,
+        /// Do not update `updated_date` (default behavior touches on all mutations)
+        #[arg(long, action = ArgAction::SetTrue)]
+        no_touch: bool,
+        #[arg(long, action = ArgAction::SetTrue)]
+        json: bool,
+    },
+}
 
-    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    let cwd_str = cwd.to_string_lossy().to_string();
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum ShellKind {
+    Sh,
+    Fish,
+    Powershell,
+}
 
-    let mut checkpoint: Option<CheckpointRef> = None;
-    let mut recent_changes: Option<workmesh_core::global_sessions::RecentChanges> = None;
-    if let Ok(Some(summary)) = resume_summary(&rr, &project_id, None) {
-        checkpoint = Some(CheckpointRef {
-            path: summary.checkpoint_path.to_string_lossy().to_string(),
-            timestamp: Some(summary.snapshot.generated_at.clone()),
-        });
-        recent_changes = Some(workmesh_core::global_sessions::RecentChanges {
-            dirs: summary.snapshot.top_level_dirs.clone(),
-            files: summary.snapshot.changed_files.clone(),
-        });
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum BoardByArg {
+    Status,
+    Phase,
+    Priority,
+    Epic,
+}
+
+impl BoardByArg {
+    fn to_core(self) -> BoardBy {
+        match self {
+            BoardByArg::Status => BoardBy::Status,
+            BoardByArg::Phase => BoardBy::Phase,
+            BoardByArg::Priority => BoardBy::Priority,
+            BoardByArg::Epic => BoardBy::Epic,
+        }
     }
+}
 
-    let now = now_rfc3339();
-    let worktree = best_effort_worktree_binding(&home, &cwd, Some(repo_root.as_str()))
-        .or(existing.worktree.clone());
-    let truth_refs = truth_refs_for_scope(
-        backlog_dir,
-        Some(project_id.as_str()),
-        epic_id.as_deref(),
-        worktree.as_ref().map(|binding| binding.path.as_str()),
-        10,
-    );
-    let updated = AgentSession {
-        id: existing.id.clone(),
-        created_at: existing.created_at.clone(),
-        updated_at: now,
-        cwd: cwd_str,
-        repo_root: Some(repo_root),
-        project_id: Some(project_id),
-        epic_id: epic_id.or(existing.epic_id.clone()),
-        objective: existing.objective.clone(),
-        working_set,
-        notes: existing.notes.clone(),
-        git: Some(best_effort_git_snapshot(&rr)),
-        checkpoint,
-        recent_changes,
-        handoff: existing.handoff.clone(),
-        worktree,
-        truth_refs,
-    };
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum GroupByArg {
+    Status,
+    Label,
+    Assignee,
+}
 
-    append_session_saved(&home, updated.clone())?;
-    set_current_session(&home, &updated.id)?;
-    Ok(())
+impl GroupByArg {
+    fn to_core(self) -> GroupBy {
+        match self {
+            GroupByArg::Status => GroupBy::Status,
+            GroupByArg::Label => GroupBy::Label,
+            GroupByArg::Assignee => GroupBy::Assignee,
+        }
+    }
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse_from(rewrite_cli_args(std::env::args_os().collect()));
-    if let Command::Readme { json } = &cli.command {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let path = repo_root.join("README.json");
-        let raw = std::fs::read_to_string(&path)?;
-        let parsed: serde_json::Value = serde_json::from_str(&raw)?;
-        if *json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&serde_json::json!({
-                    "ok": true,
-                    "path": path,
-                    "readme": parsed,
-                }))?
-            );
-        } else {
-            println!("{}", raw);
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum NoteSection {
+    Notes,
+    Impl,
+}
+
+impl NoteSection {
+    fn as_str(self) -> &'static str {
+        match self {
+            NoteSection::Notes => "notes",
+            NoteSection::Impl => "impl",
+        }
+    }
+}
+
+fn is_done_status(status: &str) -> bool {
+    status.eq_ignore_ascii_case("done")
+}
+
+fn is_status_field(field: &str) -> bool {
+    field.trim().eq_ignore_ascii_case("status")
+}
+
+fn effective_touch(touch: bool, no_touch: bool) -> bool {
+    if no_touch {
+        return false;
+    }
+    // Back-compat: `--touch` is still accepted, but touching is now the default on mutations.
+    if touch {
+        return true;
+    }
+    true
+}
+
+fn best_effort_git_snapshot(repo_root: &Path) -> GitSnapshot {
+    let branch = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("rev-parse")
+        .arg("--abbrev-ref")
+        .arg("HEAD")
+        .output()
+        .ok()
+        .and_then(|out| {
+            if out.status.success() {
+                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+            } else {
+                None
+            }
+        })
+        .filter(|value| !value.trim().is_empty());
+
+    let head_sha = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("rev-parse")
+        .arg("HEAD")
+        .output()
+        .ok()
+        .and_then(|out| {
+            if out.status.success() {
+                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+            } else {
+                None
+            }
+        })
+        .filter(|value| !value.trim().is_empty());
+
+    let dirty = std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("status")
+        .arg("--porcelain")
+        .output()
+        .ok()
+        .and_then(|out| {
+            if out.status.success() {
+                Some(!String::from_utf8_lossy(&out.stdout).trim().is_empty())
+            } else {
+                None
+            }
+        });
+
+    GitSnapshot {
+        branch,
+        head_sha,
+        dirty,
+    }
+}
+
+fn best_effort_git_branch(repo_root: &Path) -> Option<String> {
+    std::process::Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .arg("rev-parse")
+        .arg("--abbrev-ref")
+        .arg("HEAD")
+        .output()
+        .ok()
+        .and_then(|out| {
+            if out.status.success() {
+                Some(String::from_utf8_lossy(&out.stdout).trim().to_string())
+            } else {
+                None
+            }
+        })
+        .filter(|value| !value.trim().is_empty())
+}
+
+fn render_session_line(session: &AgentSession) -> String {
+    format!(
+        "{} | {} | {} | {}",
+        session.id, session.updated_at, session.cwd, session.objective
+    )
+}
+
+fn render_session_detail(session: &AgentSession) -> String {
+    let mut lines = Vec::new();
+    lines.push(format!("id: {}", session.id));
+    lines.push(format!("updated_at: {}", session.updated_at));
+    lines.push(format!("cwd: {}", session.cwd));
+    if let Some(repo_root) = session.repo_root.as_deref() {
+        lines.push(format!("repo_root: {}", repo_root));
+    }
+    if let Some(project_id) = session.project_id.as_deref() {
+        lines.push(format!("project_id: {}", project_id));
+    }
+    if let Some(epic_id) = session.epic_id.as_deref() {
+        lines.push(format!("epic_id: {}", epic_id));
+    }
+    lines.push(format!("objective: {}", session.objective));
+    if !session.working_set.is_empty() {
+        lines.push(format!("working_set: {}", session.working_set.join(", ")));
+    }
+    if let Some(notes) = session.notes.as_deref() {
+        if !notes.trim().is_empty() {
+            lines.push(format!("notes: {}", notes));
+        }
+    }
+    if let Some(git) = session.git.as_ref() {
+        if git.branch.is_some() || git.head_sha.is_some() || git.dirty.is_some() {
+            lines.push(format!(
+                "git: branch={} sha={} dirty={}",
+                git.branch.clone().unwrap_or_else(|| "-".to_string()),
+                git.head_sha.clone().unwrap_or_else(|| "-".to_string()),
+                git.dirty
+                    .map(|value| value.to_string())
+                    .unwrap_or_else(|| "-".to_string())
+            ));
+        }
+    }
+    if let Some(checkpoint) = session.checkpoint.as_ref() {
+        lines.push(format!("checkpoint: {}", checkpoint.path));
+    }
+    if let Some(worktree) = session.worktree.as_ref() {
+        lines.push(format!("worktree.path: {}", worktree.path));
+        if let Some(branch) = worktree.branch.as_deref() {
+            lines.push(format!("worktree.branch: {}", branch));
+        }
+        if let Some(id) = worktree.id.as_deref() {
+            lines.push(format!("worktree.id: {}", id));
+        }
+    }
+    if let Some(handoff) = session.handoff.as_ref() {
+        if !handoff.completed.is_empty() {
+            lines.push(format!(
+                "handoff.completed: {}",
+                handoff.completed.join(" | ")
+            ));
+        }
+        if !handoff.remaining.is_empty() {
+            lines.push(format!(
+                "handoff.remaining: {}",
+                handoff.remaining.join(" | ")
+            ));
+        }
+        if !handoff.decisions.is_empty() {
+            lines.push(format!(
+                "handoff.decisions: {}",
+                handoff.decisions.join(" | ")
+            ));
+        }
+        if !handoff.unknowns.is_empty() {
+            lines.push(format!(
+                "handoff.unknowns: {}",
+                handoff.unknowns.join(" | ")
+            ));
+        }
+        if let Some(next_step) = handoff.next_step.as_deref() {
+            if !next_step.trim().is_empty() {
+                lines.push(format!("handoff.next_step: {}", next_step));
+            }
+        }
+    }
+    if !session.truth_refs.is_empty() {
+        lines.push(format!("truth_refs: {}", session.truth_refs.join(", ")));
+    }
+    lines.join("\n")
+}
+
+fn resume_script(session: &AgentSession) -> Vec<String> {
+    let mut lines = Vec::new();
+    if let Some(worktree) = session.worktree.as_ref() {
+        lines.push(format!("cd {}", worktree.path));
+    } else {
+        lines.push(format!("cd {}", session.cwd));
+    }
+
+    if let Some(repo_root) = session.repo_root.as_deref() {
+        lines.push(format!("workmesh --root {} context show", repo_root));
+        let mut truth_line = format!("workmesh --root {} truth list --state accepted", repo_root);
+        if let Some(project_id) = session.project_id.as_deref() {
+            truth_line.push_str(&format!(" --project {}", project_id));
+        }
+        if let Some(epic_id) = session.epic_id.as_deref() {
+            truth_line.push_str(&format!(" --epic {}", epic_id));
+        }
+        if let Some(worktree) = session.worktree.as_ref() {
+            truth_line.push_str(&format!(" --worktree-path {}", worktree.path));
+        }
+        truth_line.push_str(" --limit 10");
+        lines.push(truth_line);
+    }
+
+    if let (Some(repo_root), Some(project_id)) =
+        (session.repo_root.as_deref(), session.project_id.as_deref())
+    {
+        lines.push(format!(
+            "workmesh --root {} resume --project {}",
+            repo_root, project_id
+        ));
+        lines.push(format!("workmesh --root {} ready", repo_root));
+    }
+
+    if let Some(repo_root) = session.repo_root.as_deref() {
+        if let Some(task_id) = session.working_set.first() {
+            lines.push(format!("workmesh --root {} show {}", repo_root, task_id));
+            lines.push(format!(
+                "workmesh --root {} claim {} you",
+                repo_root, task_id
+            ));
+        }
+    }
+
+    if !session.truth_refs.is_empty() {
+        lines.push(format!(
+            "# accepted truths: {}",
+            session.truth_refs.join(", ")
+        ));
+    }
+
+    lines
+}
+
+fn parse_truth_states(values: &[String]) -> Result<Vec<TruthState>> {
+    let mut states = Vec::new();
+    let mut seen = HashSet::new();
+    for value in split_list(values) {
+        let Some(state) = TruthState::parse(&value) else {
+            die(&format!(
+                "Invalid truth state: {} (expected proposed|accepted|rejected|superseded)",
+                value
+            ));
+        };
+        if seen.insert(state) {
+            states.push(state);
+        }
+    }
+    Ok(states)
+}
+
+fn render_truth_line(record: &workmesh_core::truth::TruthRecord) -> String {
+    let feature = record
+        .context
+        .feature
+        .clone()
+        .or(record.context.epic_id.clone())
+        .unwrap_or_else(|| "-".to_string());
+    format!(
+        "{} | {} | {} | feature={} | v{}",
+        record.id,
+        record.state.as_str(),
+        record.title,
+        feature,
+        record.version
+    )
+}
+
+fn truth_refs_for_scope(
+    backlog_dir: &Path,
+    project_id: Option<&str>,
+    epic_id: Option<&str>,
+    worktree_path: Option<&str>,
+    limit: usize,
+) -> Vec<String> {
+    let query = TruthQuery {
+        states: vec![TruthState::Accepted],
+        project_id: project_id.map(|value| value.to_string()),
+        epic_id: epic_id.map(|value| value.to_string()),
+        feature: epic_id.map(|value| value.to_string()),
+        workstream_id: None,
+        session_id: None,
+        worktree_id: None,
+        worktree_path: worktree_path.map(|value| value.to_string()),
+        tags: Vec::new(),
+        limit: Some(limit),
+    };
+    list_truths(backlog_dir, &query)
+        .map(|records| records.into_iter().map(|record| record.id).collect())
+        .unwrap_or_default()
+}
+
+fn auto_update_current_session(backlog_dir: &Path) -> Result<()> {
+    let home = resolve_workmesh_home()?;
+    let Some(current_id) = read_current_session_id(&home) else {
+        return Ok(());
+    };
+
+    let sessions = load_sessions_latest_fast(&home)?;
+    let Some(existing) = sessions.into_iter().find(|s| s.id == current_id) else {
+        return Ok(());
+    };
+
+    let rr = repo_root_from_backlog(backlog_dir);
+    let repo_root = rr.to_string_lossy().to_string();
+    let repo_tasks = load_tasks(backlog_dir);
+    let context_state = load_context_state(backlog_dir);
+    let project_id = context_state
+        .as_ref()
+        .and_then(|c| c.project_id.clone())
+        .unwrap_or_else(|| resolve_project_id(&rr, &repo_tasks, None));
+    let epic_id = context_state
+        .as_ref()
+        .and_then(|c| c.scope.epic_id.clone())
+        .or_else(|| best_effort_git_branch(&rr).and_then(|b| extract_task_id_from_branch(&b)));
+
+    let working_set: Vec<String> = repo_tasks
+        .iter()
+        .filter(|task| task.status.eq_ignore_ascii_case("in progress") || is_lease_active(task))
+        .map(|task| task.id.clone())
+        .collect();
+
+    let cwd = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let cwd_str = cwd.to_string_lossy().to_string();
+
+    let mut checkpoint: Option<CheckpointRef> = None;
+    let mut recent_changes: Option<workmesh_core::global_sessions::RecentChanges> = None;
+    if let Ok(Some(summary)) = resume_summary(&rr, &project_id, None) {
+        checkpoint = Some(CheckpointRef {
+            path: summary.checkpoint_path.to_string_lossy().to_string(),
+            timestamp: Some(summary.snapshot.generated_at.clone()),
+        });
+        recent_changes = Some(workmesh_core::global_sessions::RecentChanges {
+            dirs: summary.snapshot.top_level_dirs.clone(),
+            files: summary.snapshot.changed_files.clone(),
+        });
+    }
+
+    let now = now_rfc3339();
+    let worktree = best_effort_worktree_binding(&home, &cwd, Some(repo_root.as_str()))
+        .or(existing.worktree.clone());
+    let truth_refs = truth_refs_for_scope(
+        backlog_dir,
+        Some(project_id.as_str()),
+        epic_id.as_deref(),
+        worktree.as_ref().map(|binding| binding.path.as_str()),
+        10,
+    );
+    let updated = AgentSession {
+        id: existing.id.clone(),
+        created_at: existing.created_at.clone(),
+        updated_at: now,
+        cwd: cwd_str,
+        repo_root: Some(repo_root),
+        project_id: Some(project_id),
+        epic_id: epic_id.or(existing.epic_id.clone()),
+        objective: existing.objective.clone(),
+        working_set,
+        notes: existing.notes.clone(),
+        git: Some(best_effort_git_snapshot(&rr)),
+        checkpoint,
+        recent_changes,
+        handoff: existing.handoff.clone(),
+        worktree,
+        truth_refs,
+        client: existing.client.clone(),
+    };
+
+    append_session_saved(&home, updated.clone())?;
+    set_current_session(&home, &updated.id)?;
+    Ok(())
+}
+
+fn main() {
+    let matches = Cli::command().get_matches_from(rewrite_cli_args(std::env::args_os().collect()));
+    let command_name = matches.subcommand_name().unwrap_or("workmesh").to_string();
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    cli.root = resolve_cli_root(
+        cli.root_arg.take(),
+        std::env::var(ROOT_ENV).ok().as_deref(),
+        &std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")),
+    );
+    logging::init(cli.log_level, cli.log_format);
+    progress::init(cli.progress);
+    let telemetry = start_cli_telemetry(&cli.root);
+    let result = run(cli);
+    if let Some(telemetry) = telemetry {
+        telemetry.finish(&command_name, result.is_ok());
+    }
+    if let Err(err) = result {
+        logging::error(format!("Error: {:#}", err));
+        std::process::exit(1);
+    }
+}
+
+/// OTLP export for one CLI invocation: a command span plus the audit events it appended.
+struct CliTelemetry {
+    exporter: OtlpExporter,
+    backlog_dir: Option<PathBuf>,
+    audit_offset: u64,
+    started: SystemTime,
+}
+
+fn start_cli_telemetry(root: &Path) -> Option<CliTelemetry> {
+    let exporter = resolve_otlp_exporter(&resolve_cli_repo_root(root))?;
+    let backlog_dir = resolve_backlog(root)
+        .ok()
+        .map(|resolution| resolution.state_root);
+    let audit_offset = backlog_dir.as_deref().map(audit_log_len).unwrap_or(0);
+    Some(CliTelemetry {
+        exporter,
+        backlog_dir,
+        audit_offset,
+        started: SystemTime::now(),
+    })
+}
+
+impl CliTelemetry {
+    fn finish(self, command: &str, ok: bool) {
+        let events = self
+            .backlog_dir
+            .as_deref()
+            .map(|dir| read_audit_events_from(dir, self.audit_offset))
+            .unwrap_or_default();
+        let span = CommandSpan {
+            name: format!("workmesh {}", command),
+            start: self.started,
+            end: SystemTime::now(),
+            ok,
+            attributes: vec![
+                ("workmesh.command".to_string(), command.to_string()),
+                (
+                    "workmesh.audit_events".to_string(),
+                    events.len().to_string(),
+                ),
+            ],
+        };
+        let exported = self
+            .exporter
+            .export_spans(&[span])
+            .and_then(|_| self.exporter.export_audit_events(&events));
+        if let Err(err) = exported {
+            logging::warn(err);
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    if let Command::Readme { json } = &cli.command {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let path = repo_root.join("README.json");
+        let raw = std::fs::read_to_string(&path)?;
+        let parsed: serde_json::Value = serde_json::from_str(&raw)?;
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "ok": true,
+                    "path": path,
+                    "readme": parsed,
+                }))?
+            );
+        } else {
+            println!("{}", raw);
+        }
+        return Ok(());
+    }
+
+    if let Command::Use { path, shell, unset } = &cli.command {
+        if *unset {
+            println!("{}", shell_root_snippet(*shell, None));
+            return Ok(());
+        }
+        let root = path.clone().unwrap_or_else(|| cli.root.clone());
+        let resolution = resolve_backlog(&root)?;
+        let root = resolution
+            .repo_root
+            .canonicalize()
+            .unwrap_or(resolution.repo_root);
+        println!(
+            "{}",
+            shell_root_snippet(*shell, Some(&root.to_string_lossy()))
+        );
+        return Ok(());
+    }
+
+    if let Command::ConfidentialKey { json } = &cli.command {
+        let key = generate_key()?;
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "ok": true,
+                    "env": CONFIDENTIAL_KEY_ENV,
+                    "key": key,
+                }))?
+            );
+        } else {
+            println!("{}={}", CONFIDENTIAL_KEY_ENV, key);
+        }
+        return Ok(());
+    }
+
+    if let Command::Token { command } = &cli.command {
+        let dir = tokens_dir().unwrap_or_else(|| die("WorkMesh home directory not found"));
+        match command {
+            TokenCommand::Create {
+                role,
+                scopes,
+                expires,
+                name,
+                json,
+            } => {
+                let role = match role {
+                    RoleArg::Viewer => Role::Viewer,
+                    RoleArg::Contributor => Role::Contributor,
+                    RoleArg::Maintainer => Role::Maintainer,
+                };
+                let expires_at = expires
+                    .as_deref()
+                    .map(|value| parse_expiry(value, Local::now()))
+                    .transpose()
+                    .unwrap_or_else(|err| die(&err.to_string()));
+                let (token, secret) =
+                    create_token(&dir, role, scopes, expires_at, name.as_deref())?;
+                if *json {
+                    let mut payload = serde_json::to_value(&token)?;
+                    payload["token"] = serde_json::Value::String(secret);
+                    println!("{}", serde_json::to_string_pretty(&payload)?);
+                } else {
+                    println!("{}", secret);
+                    eprintln!(
+                        "Created {} ({}); the token is shown only once.",
+                        token.id, token.role
+                    );
+                }
+            }
+            TokenCommand::List { json } => {
+                let tokens = list_tokens(&dir);
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&tokens)?);
+                } else if tokens.is_empty() {
+                    println!("No tokens.");
+                } else {
+                    let now = Local::now();
+                    for token in &tokens {
+                        let scope = if token.scopes.is_empty() {
+                            "*".to_string()
+                        } else {
+                            token.scopes.join(",")
+                        };
+                        let expires = match token.expires_at.as_deref() {
+                            Some(_) if token.is_expired(now) => "expired".to_string(),
+                            Some(at) => format!("expires {}", TimeDisplay::default().render(at)),
+                            None => "no expiry".to_string(),
+                        };
+                        println!(
+                            "{} | {} | scope {} | {} | {}",
+                            token.id,
+                            token.role,
+                            scope,
+                            expires,
+                            token.name.as_deref().unwrap_or("-")
+                        );
+                    }
+                }
+            }
+            TokenCommand::Revoke { id, json } => {
+                let token = revoke_token(&dir, id).unwrap_or_else(|err| die(&err.to_string()));
+                if *json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "revoked": token.id,
+                        }))?
+                    );
+                } else {
+                    println!("Revoked {}", token.id);
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::Audit {
+        command: AuditCommand::Keygen { algorithm, json },
+    } = &cli.command
+    {
+        let algorithm = match algorithm {
+            AuditKeyAlgorithmArg::Hmac => AuditKeyAlgorithm::Hmac,
+            AuditKeyAlgorithmArg::Ed25519 => AuditKeyAlgorithm::Ed25519,
+        };
+        let key = generate_audit_key(algorithm)?;
+        let public_key = AuditKey::parse(&key)?.public_key();
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "ok": true,
+                    "env": AUDIT_KEY_ENV,
+                    "key": key,
+                    "algorithm": algorithm.as_str(),
+                    "public_key": public_key,
+                }))?
+            );
+        } else {
+            println!("{}={}", AUDIT_KEY_ENV, key);
+            if let Some(public_key) = public_key {
+                println!(
+                    "# public key (for verify-signatures --public-key): {}",
+                    public_key
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::TokenHash { token } = &cli.command {
+        println!("{}", token_hash(token));
+        return Ok(());
+    }
+
+    if let Command::ToolInfo { name, json } = &cli.command {
+        let Some(info) = build_tool_info_payload(name, placeholder_tool_definition(name)) else {
+            die(&format!("Unknown tool: {}", name));
+        };
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&info)?);
+        } else {
+            println!("{}", render_tool_info_text(name, &info));
+        }
+        return Ok(());
+    }
+
+    if let Command::Capabilities { json } = &cli.command {
+        let capabilities = cli_capabilities(&resolve_cli_repo_root(&cli.root));
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&capabilities)?);
+        } else {
+            print!("{}", render_capabilities_text(&capabilities));
+        }
+        return Ok(());
+    }
+
+    if let Command::Difftool { old, new, json } = &cli.command {
+        let diff = diff_backlog_paths(old, new);
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&diff)?);
+        } else {
+            print!("{}", render_backlog_diff(&diff));
+        }
+        return Ok(());
+    }
+
+    if let Command::Selftest {
+        against,
+        update,
+        json,
+    } = &cli.command
+    {
+        let fixtures = match against {
+            Some(dir) => load_fixtures(dir),
+            None => bundled_fixtures(),
+        }
+        .unwrap_or_else(|err| die(&err.to_string()));
+        let report = run_selftest(&fixtures, *update).unwrap_or_else(|err| die(&err.to_string()));
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{}", render_selftest_report(&report));
+        }
+        if !report.ok {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Command::Bench {
+        tasks,
+        runs,
+        dir,
+        baseline,
+        json,
+    } = &cli.command
+    {
+        let baseline: Option<BenchReport> = match baseline {
+            Some(path) => Some(
+                serde_json::from_str(&std::fs::read_to_string(path)?).unwrap_or_else(|err| {
+                    die(&format!(
+                        "{} is not a bench --json report: {}",
+                        path.display(),
+                        err
+                    ))
+                }),
+            ),
+            None => None,
+        };
+        let work_dir = dir.clone().unwrap_or_else(|| {
+            std::env::temp_dir().join(format!("workmesh-bench-{}", std::process::id()))
+        });
+        let sizes = bench_sizes(*tasks);
+        let mut finished = 0;
+        let result = run_bench(&work_dir, &sizes, *runs, |_| {
+            finished += 1;
+            progress::emit(&ProgressEvent {
+                op: "bench",
+                phase: "size",
+                done: finished,
+                total: sizes.len(),
+            });
+        });
+        if dir.is_none() {
+            let _ = std::fs::remove_dir_all(&work_dir);
+        }
+        let report = result.unwrap_or_else(|err| die(&err.to_string()));
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{}", render_bench_report(&report, baseline.as_ref()));
+        }
+        return Ok(());
+    }
+
+    if let Command::Stress {
+        writers,
+        iterations,
+        dir,
+        json,
+    } = &cli.command
+    {
+        let work_dir = dir.clone().unwrap_or_else(std::env::temp_dir);
+        let report = run_stress(&work_dir, *writers, *iterations)
+            .unwrap_or_else(|err| die(&err.to_string()));
+        if report.ok() {
+            let _ = std::fs::remove_dir_all(&report.scratch_dir);
+        }
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            print!("{}", render_stress_report(&report));
+        }
+        if !report.ok() {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Command::SkillContent { name, json } = &cli.command {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let skill_name = name
+            .as_deref()
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .unwrap_or("workmesh");
+        let skill = load_skill_content(Some(&repo_root), skill_name)
+            .or_else(|| load_skill_content(None, skill_name))
+            .unwrap_or_else(|| die(&format!("Skill not found: {}", skill_name)));
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "name": skill_name,
+                    "source": skill.source,
+                    "content": skill.content,
+                }))?
+            );
+        } else {
+            println!("{}", skill.content);
+        }
+        return Ok(());
+    }
+
+    if let Command::ProjectManagementSkill { name, json } = &cli.command {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let skill_name = name
+            .as_deref()
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+            .unwrap_or("workmesh");
+        let skill = load_skill_content(Some(&repo_root), skill_name)
+            .or_else(|| load_skill_content(None, skill_name))
+            .unwrap_or_else(|| die(&format!("Skill not found: {}", skill_name)));
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "summary": "workmesh project management skill",
+                    "name": skill_name,
+                    "source": skill.source,
+                    "content": skill.content,
+                }))?
+            );
+        } else {
+            println!("{}", skill.content);
+        }
+        return Ok(());
+    }
+
+    if let Command::Bootstrap {
+        project_id,
+        feature,
+        objective,
+        tasks_root,
+        state_root,
+        json,
+    } = &cli.command
+    {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let result = bootstrap_repo(
+            &repo_root,
+            &BootstrapOptions {
+                project_id: project_id.clone(),
+                project_name: None,
+                feature: feature.clone(),
+                objective: objective.clone(),
+                agents_snippet: true,
+                tasks_root: tasks_root.clone(),
+                state_root: state_root.clone(),
+            },
+        )?;
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        } else {
+            println!("Bootstrap state: {}", result.state.as_str());
+            println!("Repo root: {}", result.repo_root.display());
+            println!("State root: {}", result.state_root.display());
+            println!("Tasks root: {}", result.tasks_root.display());
+            println!("Project: {}", result.project_id);
+            if result.quickstart.is_some() {
+                println!("Initialized WorkMesh layout and seed task.");
+            }
+            if !result.migration_applied.is_empty() {
+                println!("Applied migration steps:");
+                for step in &result.migration_applied {
+                    println!("  - {}", step);
+                }
+            }
+            for warning in &result.migration_warnings {
+                logging::warn(warning);
+            }
+            if result.context_seeded {
+                println!("Seeded context: {}", result.context_path.display());
+            } else {
+                println!("Context already present: {}", result.context_path.display());
+            }
+            if !result.next_task_ids.is_empty() {
+                println!("Next tasks: {}", result.next_task_ids.join(", "));
+            }
+            for hint in &result.recommendations {
+                println!("hint: {}", hint);
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::Init {
+        project_id,
+        name,
+        statuses,
+        default_priority,
+        initiative,
+        filename_template,
+        hooks,
+        skills,
+        no_seed,
+        yes,
+        json,
+    } = &cli.command
+    {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let interactive = !yes && !prompts_disabled() && io::stdin().is_terminal();
+        let ask = |label: &str, value: &Option<String>| -> Result<Option<String>> {
+            let answer = if interactive {
+                wizard_field(label, value.clone())?
+            } else {
+                value.clone().unwrap_or_default()
+            };
+            let answer = answer.trim().to_string();
+            Ok((!answer.is_empty()).then_some(answer))
+        };
+        let default_project = repo_root
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or("project")
+            .to_string();
+        let project_id = ask(&format!("Project id [{}]", default_project), project_id)?
+            .unwrap_or(default_project);
+        let name = ask("Project name (optional)", name)?;
+        let statuses = ask(
+            "Workflow statuses, comma-separated (blank keeps To Do, In Progress, Done)",
+            statuses,
+        )?;
+        let default_priority = ask("Default priority (blank keeps P2)", default_priority)?;
+        let initiative = ask(
+            "Initiative key for task ids (blank derives one from the branch)",
+            initiative,
+        )?;
+        let filename_template = ask(
+            "Task filename template (blank keeps \"{id} - {title}\")",
+            filename_template,
+        )?;
+        let hooks =
+            *hooks || (interactive && wizard_confirm("Install git pre-commit hook?", true)?);
+        let skills = *skills || (interactive && wizard_confirm("Install agent skills?", false)?);
+        let seed = if *no_seed {
+            false
+        } else if interactive {
+            wizard_confirm("Seed project docs and a first task?", true)?
+        } else {
+            true
+        };
+
+        let mut report = init_repo(
+            &repo_root,
+            &InitOptions {
+                project_id,
+                name,
+                statuses: statuses.as_deref().map(split_csv).unwrap_or_default(),
+                default_priority,
+                initiative,
+                filename_template,
+                hooks,
+                seed,
+            },
+        )?;
+        if skills {
+            let installed = install_embedded_skill_report(
+                Some(&repo_root),
+                SkillScope::Project,
+                SkillAgent::All,
+                "workmesh",
+                false,
+            )?;
+            report
+                .written
+                .extend(installed.written.into_iter().map(|path| InitEntry {
+                    path,
+                    what: "agent skill".to_string(),
+                }));
+            report
+                .skipped
+                .extend(installed.skipped.into_iter().map(|path| InitEntry {
+                    path,
+                    what: "agent skill (already installed)".to_string(),
+                }));
+        }
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            if report.written.is_empty() {
+                println!("Nothing written.");
+            } else {
+                println!("Wrote:");
+            }
+            for entry in &report.written {
+                println!("  {} | {}", entry.what, entry.path.display());
+            }
+            if !report.skipped.is_empty() {
+                println!("Skipped:");
+            }
+            for entry in &report.skipped {
+                println!("  {} | {}", entry.what, entry.path.display());
+            }
+            if let Some(initiative) = report.initiative.as_deref() {
+                println!(
+                    "Task ids on this branch: task-{}-001, task-{}-002, ...",
+                    initiative, initiative
+                );
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::Quickstart {
+        project_id,
+        name,
+        feature,
+        tasks_root,
+        state_root,
+        agents_snippet,
+        json,
+    } = &cli.command
+    {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let result = quickstart(
+            &repo_root,
+            project_id,
+            name.as_deref(),
+            feature.as_deref(),
+            &QuickstartOptions {
+                agents_snippet: *agents_snippet,
+                tasks_root: tasks_root.clone(),
+                state_root: state_root.clone(),
+            },
+        )?;
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&result)?);
+        } else {
+            println!("Docs: {}", result.project_dir.display());
+            println!("State root: {}", result.state_root.display());
+            println!("Tasks root: {}", result.tasks_root.display());
+            if let Some(task_path) = result.created_task.as_ref() {
+                println!("Seed task: {}", task_path.display());
+            }
+            if result.agents_snippet_written {
+                println!("AGENTS.md updated");
+            }
+            if result.worktrees_default {
+                println!(
+                    "Worktree defaults: enabled (source: {}).",
+                    result.worktrees_default_source
+                );
+                if let Some(hint) = result.worktree_hint.as_deref() {
+                    println!("Recommended for parallel streams:");
+                    println!("  {}", hint);
+                }
+                if let Some(path) = global_config_path() {
+                    println!(
+                        "Disable globally by setting `worktrees_default = false` in {}",
+                        path.display()
+                    );
+                } else {
+                    println!(
+                        "Disable globally by setting `worktrees_default = false` in $WORKMESH_HOME/config.toml"
+                    );
+                }
+                println!(
+                    "Per-repo override: set `worktrees_default = true|false` in `.workmesh.toml`."
+                );
+            } else {
+                println!(
+                    "Worktree defaults: disabled (source: {}).",
+                    result.worktrees_default_source
+                );
+                println!("Enable for this repo with `.workmesh.toml`: `worktrees_default = true`.");
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::Install {
+        skills,
+        profile,
+        scope,
+        agent,
+        force,
+        json,
+    } = &cli.command
+    {
+        if !skills {
+            die("install currently supports only --skills");
+        }
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let mut report = SkillInstallReport::default();
+        let names = skill_names_for_profile(*profile);
+        for name in names.iter() {
+            let partial = install_embedded_skill_report(
+                Some(&repo_root),
+                (*scope).into(),
+                (*agent).into(),
+                name,
+                *force,
+            )?;
+            merge_install_report(&mut report, partial);
+        }
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "ok": true,
+                    "profile": format!("{:?}", profile).to_lowercase(),
+                    "scope": format!("{:?}", scope).to_lowercase(),
+                    "agent": format!("{:?}", agent).to_lowercase(),
+                    "skills": names,
+                    "written": report.written,
+                    "skipped": report.skipped
+                }))?
+            );
+        } else {
+            println!(
+                "Installed profile={} scope={} agent={} skills={}",
+                format!("{:?}", profile).to_lowercase(),
+                format!("{:?}", scope).to_lowercase(),
+                format!("{:?}", agent).to_lowercase(),
+                names.join(", ")
+            );
+            print_install_report(report);
+        }
+        return Ok(());
+    }
+
+    if let Command::Uninstall {
+        skills,
+        profile,
+        scope,
+        agent,
+        json,
+    } = &cli.command
+    {
+        if !skills {
+            die("uninstall currently supports only --skills");
+        }
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        let mut report = SkillUninstallReport::default();
+        let names = skill_names_for_profile(*profile);
+        for name in names.iter() {
+            let partial = uninstall_embedded_skill_report(
+                Some(&repo_root),
+                (*scope).into(),
+                (*agent).into(),
+                name,
+            )?;
+            merge_uninstall_report(&mut report, partial);
+        }
+        if *json {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&serde_json::json!({
+                    "ok": true,
+                    "profile": format!("{:?}", profile).to_lowercase(),
+                    "scope": format!("{:?}", scope).to_lowercase(),
+                    "agent": format!("{:?}", agent).to_lowercase(),
+                    "skills": names,
+                    "removed": report.removed,
+                    "missing": report.missing
+                }))?
+            );
+        } else {
+            println!(
+                "Uninstalled profile={} scope={} agent={} skills={}",
+                format!("{:?}", profile).to_lowercase(),
+                format!("{:?}", scope).to_lowercase(),
+                format!("{:?}", agent).to_lowercase(),
+                names.join(", ")
+            );
+            print_uninstall_report(report);
+        }
+        return Ok(());
+    }
+
+    if let Command::SelfUpdate {
+        channel,
+        check,
+        json,
+    } = &cli.command
+    {
+        let channel = channel.map(|channel| match channel {
+            UpdateChannelArg::Stable => UpdateChannel::Stable,
+            UpdateChannelArg::Nightly => UpdateChannel::Nightly,
+        });
+        self_update::run(channel, *check, *json)?;
+        return Ok(());
+    }
+
+    if let Command::Doctor {
+        json,
+        fix_storage,
+        check_updates,
+    } = &cli.command
+    {
+        let mut report = if *fix_storage {
+            doctor_report_with_options(&cli.root, "workmesh", true)
+        } else {
+            doctor_report(&cli.root, "workmesh")
+        };
+        if *check_updates {
+            report["update"] = self_update::check_report();
+        }
+        if *json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else {
+            println!("root: {}", report["root"].as_str().unwrap_or(""));
+            println!("repo_root: {}", report["repo_root"].as_str().unwrap_or(""));
+            println!(
+                "backlog_dir: {}",
+                report["backlog_dir"].as_str().unwrap_or("")
+            );
+            println!("layout: {}", report["layout"].as_str().unwrap_or(""));
+            if !report["context"].is_null() {
+                let epic = report["context"]["scope"]["epic_id"].as_str().unwrap_or("");
+                let project = report["context"]["project_id"].as_str().unwrap_or("");
+                let mode = report["context"]["scope"]["mode"]
+                    .as_str()
+                    .unwrap_or("none");
+                println!(
+                    "context: project_id={} scope_mode={} epic_id={}",
+                    project, mode, epic
+                );
+            } else {
+                println!("context: (none)");
+            }
+            let present = report["index"]["present"].as_bool().unwrap_or(false);
+            let entries = report["index"]["entries"].as_i64().unwrap_or(0);
+            println!("index: present={} entries={}", present, entries);
+            if !report["truth"].is_null() {
+                println!(
+                    "truth: events={} records={} validation_ok={}",
+                    report["truth"]["event_count"].as_i64().unwrap_or(0),
+                    report["truth"]["record_count"].as_i64().unwrap_or(0),
+                    report["truth"]["validation_ok"].as_bool().unwrap_or(true)
+                );
+            }
+            for freeze in report["freezes"].as_array().into_iter().flatten() {
+                println!(
+                    "freeze: {} {} tasks={} by={} at={}{}",
+                    freeze["kind"].as_str().unwrap_or(""),
+                    freeze["scope"].as_str().unwrap_or(""),
+                    freeze["tasks"].as_i64().unwrap_or(0),
+                    freeze["by"].as_str().unwrap_or("-"),
+                    freeze["at"].as_str().unwrap_or(""),
+                    freeze["reason"]
+                        .as_str()
+                        .map(|reason| format!(" reason={}", reason))
+                        .unwrap_or_default()
+                );
+            }
+            if !report["storage"].is_null() {
+                println!(
+                    "storage: ok={} malformed_total={} projection_mismatches={}",
+                    report["storage"]["ok"].as_bool().unwrap_or(false),
+                    report["storage"]["jsonl"]["malformed_total"]
+                        .as_i64()
+                        .unwrap_or(0),
+                    report["storage"]["truth_projection"]["projection_mismatches"]
+                        .as_array()
+                        .map(|items| items.len())
+                        .unwrap_or(0)
+                );
+                if *fix_storage {
+                    println!(
+                        "storage_fix: ok={} sessions_trimmed={} truth_trimmed={}",
+                        report["storage"]["fix"]["ok"].as_bool().unwrap_or(false),
+                        report["storage"]["fix"]["sessions_trimmed"]
+                            .as_i64()
+                            .unwrap_or(0),
+                        report["storage"]["fix"]["truth_trimmed"]
+                            .as_i64()
+                            .unwrap_or(0),
+                    );
+                }
+            }
+            if report["service"]["installed"].as_bool().unwrap_or(false) {
+                let service = &report["service"];
+                println!(
+                    "service: {} running={} log={}",
+                    service["name"].as_str().unwrap_or(""),
+                    service["running"]
+                        .as_bool()
+                        .map(|running| running.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    service["log_path"].as_str().unwrap_or("")
+                );
+            }
+            println!(
+                "versions: workmesh={} workmesh-mcp={}",
+                report["versions"]["workmesh"].as_str().unwrap_or(""),
+                report["versions"]["workmesh_mcp"].as_str().unwrap_or("")
+            );
+            if *check_updates {
+                let update = &report["update"];
+                match update["error"].as_str() {
+                    Some(err) => println!("update: check failed: {}", err),
+                    None => println!(
+                        "update: available={} latest={} channel={}",
+                        update["update_available"].as_bool().unwrap_or(false),
+                        update["latest"].as_str().unwrap_or(""),
+                        update["channel"].as_str().unwrap_or("")
+                    ),
+                }
+            }
+        }
+        return Ok(());
+    }
+
+    if let Command::Daemon { command } = &cli.command {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        handle_daemon_command(&repo_root, command)?;
+        return Ok(());
+    }
+
+    if let Command::Config { command } = &cli.command {
+        let repo_root = resolve_cli_repo_root(&cli.root);
+        handle_config_command(&repo_root, command)?;
+        return Ok(());
+    }
+
+    if let Command::Migrate { command, to, yes } = &cli.command {
+        if let Some(migrate_cmd) = command {
+            handle_migrate_workflow(&cli.root, migrate_cmd)?;
+        } else {
+            let resolution = resolve_backlog(&cli.root)?;
+            let target = to.as_deref().unwrap_or("workmesh");
+            handle_migrate_command(&resolution, target, *yes)?;
+        }
+        return Ok(());
+    }
+
+    if let Command::Remote { command } = &cli.command {
+        return handle_remote_command(command);
+    }
+
+    if let Command::Render { command } = &cli.command {
+        handle_render_command(command)?;
+        return Ok(());
+    }
+
+    if let Command::Bundle { command } = &cli.command {
+        if matches!(
+            command,
+            BundleCommand::View { .. } | BundleCommand::Annotate { .. }
+        ) {
+            handle_bundle_review_command(command)?;
+            return Ok(());
+        }
+    }
+
+    let resolution = resolve_backlog(&cli.root)?;
+    let backlog_dir = maybe_prompt_migration(&resolution)?;
+    let repo_root = repo_root_from_backlog(&backlog_dir);
+    if !matches!(cli.command, Command::Policies { .. }) {
+        maybe_auto_apply_policies(&backlog_dir, &repo_root)?;
+    }
+    let tasks = load_tasks(&backlog_dir);
+    let task_rules = resolve_task_validation_rules(&repo_root);
+    let time_display = resolve_time_display(&repo_root);
+    let auto_checkpoint = auto_checkpoint_enabled(&cli);
+    let auto_session = auto_session_enabled(&cli, &resolution.repo_root);
+    let frozen = FrozenSet::load(&backlog_dir, &tasks);
+    if !cli.force_frozen {
+        if let Err(err) = frozen.check(&mutation_task_ids(&cli.command)) {
+            die(&format!("{} (pass --force-frozen to override)", err));
+        }
+    }
+
+    match cli.command {
+        Command::Readme { .. }
+        | Command::Use { .. }
+        | Command::ConfidentialKey { .. }
+        | Command::TokenHash { .. }
+        | Command::Token { .. }
+        | Command::ToolInfo { .. }
+        | Command::Capabilities { .. }
+        | Command::Difftool { .. }
+        | Command::Selftest { .. }
+        | Command::Bench { .. }
+        | Command::Stress { .. }
+        | Command::SkillContent { .. }
+        | Command::ProjectManagementSkill { .. } => {
+            unreachable!("handled before backlog resolution")
+        }
+        Command::Board {
+            all,
+            by,
+            focus,
+            json,
+        } => {
+            let tasks = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                load_tasks(&backlog_dir)
+            };
+            let context_state = if focus {
+                load_context_state(&backlog_dir)
+            } else {
+                None
+            };
+            let scope_ids = context_state
+                .as_ref()
+                .and_then(|c| scope_ids_from_context(&tasks, c));
+            let lanes = board_lanes(&tasks, by.to_core(), scope_ids.as_ref());
+            let rollups = subtask_rollups(&tasks);
+
+            if json {
+                let payload: Vec<serde_json::Value> = lanes
+                    .into_iter()
+                    .map(|(key, lane_tasks)| {
+                        let tasks_json: Vec<serde_json::Value> = lane_tasks
+                            .into_iter()
+                            .map(|t| {
+                                let mut value = task_to_json_value(t, false);
+                                if frozen.contains(&t.id) {
+                                    value["frozen"] = serde_json::Value::Bool(true);
+                                }
+                                if let Some(rollup) = rollups.get(&t.id.to_lowercase()) {
+                                    value["subtasks"] = serde_json::json!(rollup);
+                                }
+                                value
+                            })
+                            .collect();
+                        serde_json::json!({
+                            "lane": key,
+                            "count": tasks_json.len(),
+                            "tasks": tasks_json,
+                        })
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+                return Ok(());
+            }
+
+            let freezes = load_freezes(&backlog_dir);
+            if !freezes.is_empty() {
+                let scopes: Vec<String> = freezes.iter().map(Freeze::describe).collect();
+                println!("Frozen: {}", scopes.join(", "));
+                println!();
+            }
+            for (key, lane_tasks) in lanes {
+                println!("## {} ({})", key, lane_tasks.len());
+                for task in lane_tasks {
+                    let mut line = render_task_line(task);
+                    if let Some(rollup) = rollups.get(&task.id.to_lowercase()) {
+                        line.push_str(&format!(" [{}]", rollup.label()));
+                    }
+                    if frozen.contains(&task.id) {
+                        line.push_str(" [frozen]");
+                    }
+                    println!("{}", line);
+                }
+                println!();
+            }
+        }
+        Command::Tree { epic, json } => {
+            let tree = epic_tree(&tasks, epic.as_deref());
+            if epic.is_some() && tree.roots.is_empty() {
+                die(&format!("Task not found: {}", epic.unwrap_or_default()));
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&tree)?);
+                return Ok(());
+            }
+            for root in &tree.roots {
+                print_tree_node(root, 0);
+            }
+            if !tree.unanchored.is_empty() {
+                println!("(no epic): {}", tree.unanchored.join(", "));
+            }
+        }
+        Command::Children {
+            task_id,
+            recursive,
+            json,
+        } => {
+            let task = find_task(&tasks, &task_id).unwrap_or_else(|| {
+                die(&format!("Task not found: {}", task_id));
+            });
+            let subtasks: Vec<(usize, &Task)> = descendant_tasks(&tasks, &task.id)
+                .into_iter()
+                .filter(|(depth, _)| recursive || *depth == 1)
+                .collect();
+            let rollup = subtask_rollups(&tasks).remove(&task.id.to_lowercase());
+            if json {
+                let children: Vec<serde_json::Value> = subtasks
+                    .iter()
+                    .map(|(depth, child)| {
+                        let mut value = task_to_json_value(child, false);
+                        value["depth"] = serde_json::json!(depth);
+                        value
+                    })
+                    .collect();
+                let payload = serde_json::json!({
+                    "id": task.id,
+                    "rollup": rollup,
+                    "children": children,
+                });
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+                return Ok(());
+            }
+            match rollup {
+                Some(rollup) => println!("{} {}: {}", task.id, task.title, rollup.label()),
+                None => println!("{} {}: no subtasks", task.id, task.title),
+            }
+            for (depth, child) in subtasks {
+                println!("{}{}", "  ".repeat(depth), render_task_line(child));
+            }
+        }
+        Command::Blockers { all, epic_id, json } => {
+            let tasks = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                load_tasks(&backlog_dir)
+            };
+            let context_state = load_context_state(&backlog_dir);
+            let report =
+                blockers_report_with_context(&tasks, context_state.as_ref(), epic_id.as_deref());
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+
+            println!("Scope: {}", report.scope);
+            for warning in &report.warnings {
+                logging::warn(warning);
+            }
+            if report.blocked_tasks.is_empty() {
+                println!("Blocked tasks: (none)");
+            } else {
+                println!("Blocked tasks:");
+                for entry in report.blocked_tasks.iter() {
+                    let mut parts = Vec::new();
+                    if !entry.blockers.is_empty() {
+                        parts.push(format!("blocked_by=[{}]", entry.blockers.join(", ")));
+                    }
+                    if !entry.missing_refs.is_empty() {
+                        parts.push(format!("missing_refs=[{}]", entry.missing_refs.join(", ")));
+                    }
+                    println!(
+                        "- {}: {} ({}) {}",
+                        entry.id,
+                        entry.title,
+                        entry.status,
+                        parts.join(" ")
+                    );
+                }
+            }
+            if report.top_blockers.is_empty() {
+                println!("Top blockers: (none)");
+            } else {
+                println!("Top blockers:");
+                for b in report.top_blockers.iter().take(10) {
+                    println!("- {} blocks {}", b.id, b.blocked_count);
+                }
+            }
+            if !report.soft_blocked.is_empty() {
+                println!("Soft dependencies (not blocking):");
+                for entry in &report.soft_blocked {
+                    println!(
+                        "- {}: {} ({}) waits_on=[{}]",
+                        entry.id,
+                        entry.title,
+                        entry.status,
+                        entry.soft_dependencies.join(", ")
+                    );
+                }
+            }
+        }
+        Command::List {
+            all,
+            status,
+            kind,
+            phase,
+            priority,
+            label,
+            depends_on,
+            deps_satisfied,
+            blocked,
+            search,
+            fields,
+            created_after,
+            created_before,
+            updated_after,
+            updated_before,
+            since,
+            until,
+            sort,
+            limit,
+            count,
+            group_by,
+            json,
+        } => {
+            let now = Local::now().naive_local();
+            let bound = |flag: &str, value: Option<String>| {
+                value.map(|value| {
+                    parse_since(&value, now).unwrap_or_else(|| {
+                        die(&format!(
+                            "Invalid --{}: {} (expected {})",
+                            flag, value, TIME_REF_FORMATS
+                        ))
+                    })
+                })
+            };
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let field_filters: Vec<FieldFilter> = fields
+                .iter()
+                .map(|raw| {
+                    FieldFilter::parse(raw).unwrap_or_else(|| {
+                        die(&format!(
+                            "Invalid --field: {} (expected <field><op><value>, op: = != > >= < <=)",
+                            raw
+                        ))
+                    })
+                })
+                .collect();
+            let dates = DateFilter {
+                created_after: bound("created-after", created_after),
+                created_before: bound("created-before", created_before),
+                updated_after: bound("updated-after", updated_after).or(window.since),
+                updated_before: bound("updated-before", updated_before).or(window.until),
+            };
+            let tasks = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                load_tasks(&backlog_dir)
+            };
+            let filtered = filter_tasks(
+                &tasks,
+                to_list(status.as_slice()).as_deref(),
+                to_list(kind.as_slice()).as_deref(),
+                to_list(phase.as_slice()).as_deref(),
+                to_list(priority.as_slice()).as_deref(),
+                to_list(label.as_slice()).as_deref(),
+                depends_on.as_deref(),
+                if deps_satisfied { Some(true) } else { None },
+                if blocked { Some(true) } else { None },
+                search.as_deref(),
+            );
+            let filtered = filter_tasks_by_dates(filtered, &dates);
+            let filtered = filter_tasks_by_fields(filtered, &field_filters);
+            if let Some(group_by) = group_by {
+                let groups = group_counts(&filtered, group_by.to_core());
+                if json {
+                    let map: serde_json::Map<String, serde_json::Value> = groups
+                        .into_iter()
+                        .map(|(key, count)| (key, serde_json::Value::from(count as u64)))
+                        .collect();
+                    println!("{}", serde_json::to_string_pretty(&map)?);
+                } else {
+                    for (key, count) in groups {
+                        println!("{}: {}", key, count);
+                    }
+                }
+                return Ok(());
+            }
+            if count {
+                if json {
+                    println!("{}", serde_json::json!({ "count": filtered.len() }));
+                } else {
+                    println!("{}", filtered.len());
+                }
+                return Ok(());
+            }
+            let mut sorted = sort_tasks(filtered, &sort);
+            if let Some(limit) = limit {
+                sorted.truncate(limit);
+            }
+            if json {
+                let payload: Vec<_> = sorted.iter().map(|task| (*task).clone()).collect();
+                println!("{}", tasks_to_json(&payload, false));
+                return Ok(());
+            }
+            for task in sorted {
+                println!("{}", render_task_line(task));
+            }
+        }
+        Command::Next { json } => {
+            let context = load_context_state(&backlog_dir);
+            let mut recommended =
+                recommend_next_tasks_with_context_and_rules(&tasks, context.as_ref(), &task_rules);
+            recommended.retain(|task| !frozen.contains(&task.id));
+            let task = recommended.first().map(|t| (*t).clone());
+            if json {
+                if let Some(task) = task {
+                    let value = task_to_json_value(&task, false);
+                    println!("{}", serde_json::to_string_pretty(&value)?);
+                } else {
+                    println!("{}", "{}");
+                }
+            } else if let Some(task) = task {
+                println!("{}", render_task_line(&task));
+            }
+        }
+        Command::NextTasks { json, limit } => {
+            let context = load_context_state(&backlog_dir);
+            let mut recommended =
+                recommend_next_tasks_with_context_and_rules(&tasks, context.as_ref(), &task_rules);
+            recommended.retain(|task| !frozen.contains(&task.id));
+            if let Some(limit) = limit {
+                recommended.truncate(limit);
+            }
+            if json {
+                let payload: Vec<_> = recommended
+                    .iter()
+                    .map(|task| task_to_json_value(task, false))
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+                return Ok(());
+            }
+            for task in recommended {
+                println!("{}", render_task_line(task));
+            }
+        }
+        Command::Ready { json, limit } => {
+            let mut ready = ready_tasks_with_rules(&tasks, &task_rules);
+            ready.retain(|task| !frozen.contains(&task.id));
+            if let Some(limit) = limit {
+                ready.truncate(limit);
+            }
+            let soft_warnings = soft_dependency_warnings(&tasks);
+            if json {
+                let payload: Vec<_> = ready
+                    .iter()
+                    .map(|task| {
+                        let mut value = task_to_json_value(task, false);
+                        if let Some(unmet) = soft_warnings.get(&task.id.to_lowercase()) {
+                            value["soft_warnings"] = serde_json::json!(unmet);
+                        }
+                        value
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+                return Ok(());
+            }
+            for task in ready {
+                println!("{}", render_task_line(task));
+                if let Some(unmet) = soft_warnings.get(&task.id.to_lowercase()) {
+                    println!("    soft dependency not done: {}", unmet.join(", "));
+                }
+            }
+        }
+        Command::Grab {
+            labels,
+            count,
+            seed,
+            claim,
+            owner,
+            minutes,
+            json,
+        } => {
+            let options = GrabOptions {
+                labels,
+                count,
+                seed,
+                exclude: frozen.ids(),
+            };
+            let result = grab_tasks(&tasks, &task_rules, &options, Local::now().date_naive());
+            let mut claimed_by = None;
+            if claim && !result.picks.is_empty() {
+                let owner = owner
+                    .or_else(|| std::env::var("USER").ok())
+                    .unwrap_or_else(|| die("--claim needs --owner when $USER is not set"));
+                for pick in &result.picks {
+                    let task = find_task(&tasks, &pick.id)
+                        .unwrap_or_else(|| die(&format!("Task not found: {}", pick.id)));
+                    claim_task(&backlog_dir, task, &owner, minutes, true)?;
+                }
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                claimed_by = Some(owner);
+            }
+            if json {
+                let mut payload = serde_json::to_value(&result)?;
+                payload["claimed_by"] = serde_json::json!(claimed_by);
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else if result.picks.is_empty() {
+                println!("No ready tasks to grab");
+            } else {
+                for pick in &result.picks {
+                    let estimate = pick
+                        .estimate_hours
+                        .map_or("?".to_string(), |hours| format!("{}h", hours));
+                    println!(
+                        "{} | {} | {}d old | est {} | {}",
+                        pick.id, pick.priority, pick.age_days, estimate, pick.title
+                    );
+                }
+                if let Some(owner) = claimed_by {
+                    println!("Claimed {} task(s) for {}", result.picks.len(), owner);
+                }
+            }
+        }
+        Command::Show {
+            task_id,
+            full,
+            format,
+            include_archive,
+            related,
+            json,
+        } => {
+            let archived = if include_archive && find_task(&tasks, &task_id).is_none() {
+                find_archived_task(&backlog_dir, &task_id)?
+            } else {
+                None
+            };
+            let task = find_task(&tasks, &task_id)
+                .or(archived.as_ref())
+                .unwrap_or_else(|| {
+                    die(&format!("Task not found: {}", task_id));
+                });
+            let related = if related {
+                let repo_root = repo_root_from_backlog(&backlog_dir);
+                Some(related_tasks(
+                    &repo_root,
+                    &tasks,
+                    task,
+                    DEFAULT_RELATED_LIMIT,
+                ))
+            } else {
+                None
+            };
+            if format == ShowFormatArg::Html {
+                let options = HtmlOptions {
+                    time_display,
+                    ..HtmlOptions::default()
+                };
+                print!("{}", task_html_page(&tasks, task, &options));
+                return Ok(());
+            }
+            if json {
+                let mut value = task_to_json_value(task, true);
+                if let Some(map) = value.as_object_mut() {
+                    if let Some(related) = &related {
+                        map.insert("related".to_string(), serde_json::to_value(related)?);
+                    }
+                    if let Some(rollup) = subtask_rollups(&tasks).get(&task.id.to_lowercase()) {
+                        map.insert("subtasks".to_string(), serde_json::to_value(rollup)?);
+                    }
+                    if let Some(timing) = blocked_time_for_task(&backlog_dir, &tasks, task) {
+                        map.insert(
+                            "time_in_state".to_string(),
+                            serde_json::to_value(&timing.time)?,
+                        );
+                    }
+                }
+                println!("{}", serde_json::to_string_pretty(&value)?);
+                return Ok(());
+            }
+            let mut printed = false;
+            if full {
+                if let Some(path) = &task.file_path {
+                    println!("{}", read_task_text(path)?);
+                    printed = true;
+                }
+            }
+            if !printed {
+                println!("{}", render_task_line(task));
+                if let Some(parent) = task.relationships.parent.first() {
+                    println!("Parent: {}", parent);
+                }
+                if let Some(rollup) = subtask_rollups(&tasks).get(&task.id.to_lowercase()) {
+                    println!("Subtasks: {}", rollup.label());
+                }
+                let transitions = task_transitions(task);
+                if !transitions.is_empty() {
+                    println!("Transitions:");
+                    for transition in &transitions {
+                        println!(
+                            "- {} {} -> {}{}{}",
+                            time_display.render(&transition.at),
+                            transition.from,
+                            transition.to,
+                            transition
+                                .actor
+                                .as_ref()
+                                .map(|actor| format!(" by {}", actor))
+                                .unwrap_or_default(),
+                            transition
+                                .reason
+                                .as_ref()
+                                .map(|reason| format!(": {}", reason))
+                                .unwrap_or_default()
+                        );
+                    }
+                }
+            }
+            if let Some(related) = related {
+                if related.is_empty() {
+                    println!("Related: none");
+                } else {
+                    println!("Related:");
+                    for item in &related {
+                        println!(
+                            "- {} | {} | {} ({})",
+                            item.id,
+                            item.status,
+                            item.title,
+                            related_reasons(item)
+                        );
+                    }
+                }
+            }
         }
-        return Ok(());
-    }
-
-    if let Command::ToolInfo { name, json } = &cli.command {
-        let Some(info) = build_tool_info_payload(name, placeholder_tool_definition(name)) else {
-            die(&format!("Unknown tool: {}", name));
-        };
-        if *json {
-            println!("{}", serde_json::to_string_pretty(&info)?);
-        } else {
-            println!("{}", render_tool_info_text(name, &info));
+        Command::Stats { json } => {
+            let stats = status_counts(&tasks);
+            if json {
+                let mut map = serde_json::Map::new();
+                for (key, value) in stats {
+                    map.insert(key, serde_json::Value::from(value as u64));
+                }
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::Value::Object(map))?
+                );
+            } else {
+                for (key, value) in stats {
+                    println!("{}: {}", key, value);
+                }
+            }
         }
-        return Ok(());
-    }
+        Command::Fix { command } => match command {
+            FixCommand::List { json } => {
+                let fixers = all_fix_targets()
+                    .into_iter()
+                    .map(as_fixer_kind)
+                    .map(|kind| kind.as_str().to_string())
+                    .collect::<Vec<_>>();
+                if json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "fixers": fixers,
+                        }))?
+                    );
+                } else {
+                    for fixer in fixers {
+                        println!("{}", fixer);
+                    }
+                }
+            }
+            FixCommand::All {
+                apply,
+                check,
+                only,
+                exclude,
+                json,
+            } => {
+                let apply_mode = parse_fix_mode(apply, check)?;
+                let targets = select_fix_targets(&only, &exclude);
+                if targets.is_empty() {
+                    die("No fixers selected. Adjust --only/--exclude.");
+                }
+                let mut runs = Vec::new();
+                for (done, target) in targets.iter().enumerate() {
+                    let run = run_fix_target(&backlog_dir, *target, apply_mode)?;
+                    progress::emit(&ProgressEvent {
+                        op: "fix",
+                        phase: as_fixer_kind(*target).as_str(),
+                        done: done + 1,
+                        total: targets.len(),
+                    });
+                    runs.push(run);
+                }
 
-    if let Command::SkillContent { name, json } = &cli.command {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let skill_name = name
-            .as_deref()
-            .map(|value| value.trim())
-            .filter(|value| !value.is_empty())
-            .unwrap_or("workmesh");
-        let skill = load_skill_content(Some(&repo_root), skill_name)
-            .or_else(|| load_skill_content(None, skill_name))
-            .unwrap_or_else(|| die(&format!("Skill not found: {}", skill_name)));
-        if *json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&serde_json::json!({
-                    "name": skill_name,
-                    "source": skill.source,
-                    "content": skill.content,
-                }))?
-            );
-        } else {
-            println!("{}", skill.content);
-        }
-        return Ok(());
-    }
+                let total_detected: usize = runs.iter().map(|run| run.detected).sum();
+                let total_fixed: usize = runs.iter().map(|run| run.fixed).sum();
+                let total_skipped: usize = runs.iter().map(|run| run.skipped).sum();
+                let runs_json: Vec<serde_json::Value> = runs.iter().map(fix_run_to_json).collect();
 
-    if let Command::ProjectManagementSkill { name, json } = &cli.command {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let skill_name = name
-            .as_deref()
-            .map(|value| value.trim())
-            .filter(|value| !value.is_empty())
-            .unwrap_or("workmesh");
-        let skill = load_skill_content(Some(&repo_root), skill_name)
-            .or_else(|| load_skill_content(None, skill_name))
-            .unwrap_or_else(|| die(&format!("Skill not found: {}", skill_name)));
-        if *json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&serde_json::json!({
-                    "summary": "workmesh project management skill",
-                    "name": skill_name,
-                    "source": skill.source,
-                    "content": skill.content,
-                }))?
-            );
-        } else {
-            println!("{}", skill.content);
-        }
-        return Ok(());
-    }
+                if apply_mode {
+                    audit_event(
+                        &backlog_dir,
+                        "fix_all",
+                        None,
+                        serde_json::json!({
+                            "runs": runs_json.clone(),
+                            "fixed": total_fixed,
+                        }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
 
-    if let Command::Bootstrap {
-        project_id,
-        feature,
-        objective,
-        tasks_root,
-        state_root,
-        json,
-    } = &cli.command
-    {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let result = bootstrap_repo(
-            &repo_root,
-            &BootstrapOptions {
-                project_id: project_id.clone(),
-                project_name: None,
-                feature: feature.clone(),
-                objective: objective.clone(),
-                agents_snippet: true,
-                tasks_root: tasks_root.clone(),
-                state_root: state_root.clone(),
-            },
-        )?;
-        if *json {
-            println!("{}", serde_json::to_string_pretty(&result)?);
-        } else {
-            println!("Bootstrap state: {}", result.state.as_str());
-            println!("Repo root: {}", result.repo_root.display());
-            println!("State root: {}", result.state_root.display());
-            println!("Tasks root: {}", result.tasks_root.display());
-            println!("Project: {}", result.project_id);
-            if result.quickstart.is_some() {
-                println!("Initialized WorkMesh layout and seed task.");
+                if json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "mode": if apply_mode { "apply" } else { "check" },
+                            "runs": runs_json,
+                            "totals": {
+                                "detected": total_detected,
+                                "fixed": total_fixed,
+                                "skipped": total_skipped,
+                            }
+                        }))?
+                    );
+                } else {
+                    for run in &runs {
+                        print_fix_report(run, apply_mode);
+                    }
+                    if !apply_mode {
+                        println!("Dry-run: re-run with --apply to write changes.");
+                    }
+                }
+            }
+            FixCommand::Uid { apply, check, json } => {
+                let apply_mode = parse_fix_mode(apply, check)?;
+                let run = run_fix_target(&backlog_dir, FixTargetArg::Uid, apply_mode)?;
+                if apply_mode {
+                    audit_event(
+                        &backlog_dir,
+                        "fix_uid",
+                        None,
+                        serde_json::json!({ "fixed": run.fixed }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    let run_json = fix_run_to_json(&run);
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "mode": if apply_mode { "apply" } else { "check" },
+                            "run": run_json
+                        }))?
+                    );
+                } else {
+                    print_fix_report(&run, apply_mode);
+                    if !apply_mode {
+                        println!("Dry-run: re-run with --apply to write changes.");
+                    }
+                }
+            }
+            FixCommand::Deps { apply, check, json } => {
+                let apply_mode = parse_fix_mode(apply, check)?;
+                let run = run_fix_target(&backlog_dir, FixTargetArg::Deps, apply_mode)?;
+                if apply_mode {
+                    audit_event(
+                        &backlog_dir,
+                        "fix_deps",
+                        None,
+                        serde_json::json!({ "fixed": run.fixed }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    let run_json = fix_run_to_json(&run);
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "mode": if apply_mode { "apply" } else { "check" },
+                            "run": run_json
+                        }))?
+                    );
+                } else {
+                    print_fix_report(&run, apply_mode);
+                    if !apply_mode {
+                        println!("Dry-run: re-run with --apply to write changes.");
+                    }
+                }
             }
-            if !result.migration_applied.is_empty() {
-                println!("Applied migration steps:");
-                for step in &result.migration_applied {
-                    println!("  - {}", step);
+            FixCommand::Ids { apply, check, json } => {
+                let apply_mode = parse_fix_mode(apply, check)?;
+                let run = run_fix_target(&backlog_dir, FixTargetArg::Ids, apply_mode)?;
+                if apply_mode {
+                    audit_event(
+                        &backlog_dir,
+                        "fix_ids",
+                        None,
+                        serde_json::json!({ "fixed": run.fixed }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    let run_json = fix_run_to_json(&run);
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "mode": if apply_mode { "apply" } else { "check" },
+                            "run": run_json
+                        }))?
+                    );
+                } else {
+                    print_fix_report(&run, apply_mode);
+                    if !apply_mode {
+                        println!("Dry-run: re-run with --apply to write changes.");
+                    }
                 }
             }
-            for warning in &result.migration_warnings {
-                println!("warning: {}", warning);
+            FixCommand::Filenames { apply, check, json } => {
+                let apply_mode = parse_fix_mode(apply, check)?;
+                let run = run_fix_target(&backlog_dir, FixTargetArg::Filenames, apply_mode)?;
+                if apply_mode {
+                    audit_event(
+                        &backlog_dir,
+                        "fix_filenames",
+                        None,
+                        serde_json::json!({ "fixed": run.fixed }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    let run_json = fix_run_to_json(&run);
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "ok": true,
+                            "mode": if apply_mode { "apply" } else { "check" },
+                            "run": run_json
+                        }))?
+                    );
+                } else {
+                    print_fix_report(&run, apply_mode);
+                    if !apply_mode {
+                        println!("Dry-run: re-run with --apply to write changes.");
+                    }
+                }
             }
-            if result.context_seeded {
-                println!("Seeded context: {}", result.context_path.display());
+        },
+        Command::RekeyPrompt {
+            all,
+            include_body,
+            limit,
+            json,
+        } => {
+            let prompt = render_rekey_prompt(
+                &backlog_dir,
+                RekeyPromptOptions {
+                    include_body,
+                    include_archive: all,
+                    limit,
+                },
+            );
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "ok": true,
+                        "prompt": prompt,
+                    }))?
+                );
             } else {
-                println!("Context already present: {}", result.context_path.display());
+                println!("{}", prompt);
             }
-            if !result.next_task_ids.is_empty() {
-                println!("Next tasks: {}", result.next_task_ids.join(", "));
+        }
+        Command::RekeyApply {
+            mapping,
+            apply,
+            all,
+            strict,
+            non_strict,
+            json,
+        } => {
+            let mapping_text = read_content(None, mapping.as_deref())?;
+            let mut request = parse_rekey_request(&mapping_text)?;
+            if strict && non_strict {
+                die("Invalid flags: use either --strict or --non-strict (or neither).");
             }
-            for hint in &result.recommendations {
-                println!("hint: {}", hint);
+            if strict {
+                request.strict = true;
+            }
+            if non_strict {
+                request.strict = false;
+            }
+            let report = rekey_apply_with_progress(
+                &backlog_dir,
+                &request,
+                RekeyApplyOptions {
+                    apply,
+                    strict: request.strict,
+                    include_archive: all,
+                },
+                progress::emit,
+            )?;
+            if apply {
+                audit_event(
+                    &backlog_dir,
+                    "rekey_apply",
+                    None,
+                    serde_json::json!({ "changes": report.changes.len(), "strict": request.strict }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::to_value(&report)?)?
+                );
+            } else if report.changes.is_empty() {
+                println!("No tasks matched the mapping.");
+            } else {
+                for warning in &report.warnings {
+                    logging::warn(warning);
+                }
+                for change in &report.changes {
+                    if let Some(new_path) = &change.new_path {
+                        println!(
+                            "{} -> {} ({})",
+                            change.old_id,
+                            change.new_id,
+                            new_path.display()
+                        );
+                    } else {
+                        println!("{} -> {}", change.old_id, change.new_id);
+                    }
+                }
+                if !apply {
+                    println!("Dry-run: re-run with --apply to write changes.");
+                }
             }
         }
-        return Ok(());
-    }
-
-    if let Command::Quickstart {
-        project_id,
-        name,
-        feature,
-        tasks_root,
-        state_root,
-        agents_snippet,
-        json,
-    } = &cli.command
-    {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let result = quickstart(
-            &repo_root,
-            project_id,
-            name.as_deref(),
-            feature.as_deref(),
-            &QuickstartOptions {
-                agents_snippet: *agents_snippet,
-                tasks_root: tasks_root.clone(),
-                state_root: state_root.clone(),
-            },
-        )?;
-        if *json {
-            println!("{}", serde_json::to_string_pretty(&result)?);
-        } else {
-            println!("Docs: {}", result.project_dir.display());
-            println!("State root: {}", result.state_root.display());
-            println!("Tasks root: {}", result.tasks_root.display());
-            if let Some(task_path) = result.created_task.as_ref() {
-                println!("Seed task: {}", task_path.display());
+        Command::MergeBacklog {
+            from,
+            prefix,
+            apply,
+            json,
+        } => {
+            let report = merge_backlog_with_progress(
+                &backlog_dir,
+                &from,
+                &MergeBacklogOptions { prefix, apply },
+                progress::emit,
+            )?;
+            if apply {
+                audit_event(
+                    &backlog_dir,
+                    "merge_backlog",
+                    None,
+                    serde_json::json!({
+                        "from": report.source_state_root,
+                        "imported": report.tasks.len(),
+                        "rekeyed": report.mapping.len(),
+                        "audit_events": report.audit_events,
+                    }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             }
-            if result.agents_snippet_written {
-                println!("AGENTS.md updated");
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::to_value(&report)?)?
+                );
+            } else {
+                for warning in &report.warnings {
+                    logging::warn(warning);
+                }
+                for skipped in &report.skipped {
+                    logging::info(format!("skipped: {}", skipped));
+                }
+                if report.tasks.is_empty() {
+                    println!(
+                        "No tasks to import from {}.",
+                        report.source_tasks_root.display()
+                    );
+                }
+                for task in &report.tasks {
+                    if task.rekeyed {
+                        println!(
+                            "{} -> {} ({})",
+                            task.old_id,
+                            task.new_id,
+                            task.target_path.display()
+                        );
+                    } else {
+                        println!("{} ({})", task.new_id, task.target_path.display());
+                    }
+                }
+                println!(
+                    "Tasks: {} (rekeyed: {}), audit events: {}",
+                    report.tasks.len(),
+                    report.mapping.len(),
+                    report.audit_events
+                );
+                if !apply {
+                    println!("Dry-run: re-run with --apply to write changes.");
+                }
+            }
+        }
+        Command::Extract {
+            epic,
+            label,
+            dest,
+            apply,
+            json,
+        } => {
+            let report = extract_backlog(
+                &backlog_dir,
+                &ExtractOptions {
+                    epic,
+                    label,
+                    dest,
+                    apply,
+                },
+            )?;
+            if apply {
+                audit_event(
+                    &backlog_dir,
+                    "extract",
+                    None,
+                    serde_json::json!({
+                        "selector": report.selector,
+                        "dest": report.dest_state_root,
+                        "tasks": report.tasks.iter().map(|t| t.id.clone()).collect::<Vec<_>>(),
+                    }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             }
-            if result.worktrees_default {
+            if json {
                 println!(
-                    "Worktree defaults: enabled (source: {}).",
-                    result.worktrees_default_source
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::to_value(&report)?)?
                 );
-                if let Some(hint) = result.worktree_hint.as_deref() {
-                    println!("Recommended for parallel streams:");
-                    println!("  {}", hint);
+            } else {
+                for warning in &report.warnings {
+                    logging::warn(warning);
                 }
-                if let Some(path) = global_config_path() {
-                    println!(
-                        "Disable globally by setting `worktrees_default = false` in {}",
-                        path.display()
-                    );
-                } else {
+                for task in &report.tasks {
+                    println!("{} -> {}", task.id, task.target_path.display());
+                }
+                if !report.referenced_by.is_empty() {
                     println!(
-                        "Disable globally by setting `worktrees_default = false` in $WORKMESH_HOME/config.toml"
+                        "Tombstones keep references from: {}",
+                        report.referenced_by.join(", ")
                     );
                 }
                 println!(
-                    "Per-repo override: set `worktrees_default = true|false` in `.workmesh.toml`."
+                    "Tasks: {}, audit events: {}",
+                    report.tasks.len(),
+                    report.audit_events
                 );
+                if !apply {
+                    println!("Dry-run: re-run with --apply to write changes.");
+                }
+            }
+        }
+        Command::ScanTodos { paths, apply, json } => {
+            let initiative = if apply {
+                let repo_root = repo_root_from_backlog(&backlog_dir);
+                let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                ensure_branch_initiative(&repo_root, &branch)?
+            } else {
+                String::new()
+            };
+            let report = scan_todos_report(&backlog_dir, &tasks, &paths, &initiative, apply)?;
+            for created in &report.created {
+                audit_event(
+                    &backlog_dir,
+                    "add_task",
+                    Some(&created.task_id),
+                    serde_json::json!({ "status": "To Do", "todo_source": created.source }),
+                )?;
+            }
+            if !report.created.is_empty() {
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
             } else {
+                for comment in &report.untracked {
+                    let created = report
+                        .created
+                        .iter()
+                        .find(|task| task.source == format!("{}:{}", comment.path, comment.line));
+                    match created {
+                        Some(task) => println!(
+                            "{}:{} {} {} -> {}",
+                            comment.path, comment.line, comment.kind, comment.text, task.task_id
+                        ),
+                        None => println!(
+                            "{}:{} {} {}",
+                            comment.path, comment.line, comment.kind, comment.text
+                        ),
+                    }
+                }
+                for resolved in &report.resolved {
+                    println!(
+                        "resolved: {} {} [{}] ({})",
+                        resolved.task_id, resolved.title, resolved.status, resolved.source
+                    );
+                }
                 println!(
-                    "Worktree defaults: disabled (source: {}).",
-                    result.worktrees_default_source
+                    "Untracked: {}, tracked: {}, resolved: {}",
+                    report.untracked.len(),
+                    report.tracked.len(),
+                    report.resolved.len()
                 );
-                println!("Enable for this repo with `.workmesh.toml`: `worktrees_default = true`.");
+                if !apply {
+                    println!("Dry-run: re-run with --apply to write changes.");
+                }
             }
         }
-        return Ok(());
-    }
-
-    if let Command::Install {
-        skills,
-        profile,
-        scope,
-        agent,
-        force,
-        json,
-    } = &cli.command
-    {
-        if !skills {
-            die("install currently supports only --skills");
-        }
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let mut report = SkillInstallReport::default();
-        let names = skill_names_for_profile(*profile);
-        for name in names.iter() {
-            let partial = install_embedded_skill_report(
-                Some(&repo_root),
-                (*scope).into(),
-                (*agent).into(),
-                name,
-                *force,
-            )?;
-            merge_install_report(&mut report, partial);
-        }
-        if *json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&serde_json::json!({
-                    "ok": true,
-                    "profile": format!("{:?}", profile).to_lowercase(),
-                    "scope": format!("{:?}", scope).to_lowercase(),
-                    "agent": format!("{:?}", agent).to_lowercase(),
-                    "skills": names,
-                    "written": report.written,
-                    "skipped": report.skipped
-                }))?
-            );
-        } else {
-            println!(
-                "Installed profile={} scope={} agent={} skills={}",
-                format!("{:?}", profile).to_lowercase(),
-                format!("{:?}", scope).to_lowercase(),
-                format!("{:?}", agent).to_lowercase(),
-                names.join(", ")
-            );
-            print_install_report(report);
-        }
-        return Ok(());
-    }
-
-    if let Command::Uninstall {
-        skills,
-        profile,
-        scope,
-        agent,
-        json,
-    } = &cli.command
-    {
-        if !skills {
-            die("uninstall currently supports only --skills");
-        }
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        let mut report = SkillUninstallReport::default();
-        let names = skill_names_for_profile(*profile);
-        for name in names.iter() {
-            let partial = uninstall_embedded_skill_report(
-                Some(&repo_root),
-                (*scope).into(),
-                (*agent).into(),
-                name,
-            )?;
-            merge_uninstall_report(&mut report, partial);
-        }
-        if *json {
-            println!(
-                "{}",
-                serde_json::to_string_pretty(&serde_json::json!({
-                    "ok": true,
-                    "profile": format!("{:?}", profile).to_lowercase(),
-                    "scope": format!("{:?}", scope).to_lowercase(),
-                    "agent": format!("{:?}", agent).to_lowercase(),
-                    "skills": names,
-                    "removed": report.removed,
-                    "missing": report.missing
-                }))?
-            );
-        } else {
-            println!(
-                "Uninstalled profile={} scope={} agent={} skills={}",
-                format!("{:?}", profile).to_lowercase(),
-                format!("{:?}", scope).to_lowercase(),
-                format!("{:?}", agent).to_lowercase(),
-                names.join(", ")
-            );
-            print_uninstall_report(report);
-        }
-        return Ok(());
-    }
-
-    if let Command::Doctor { json, fix_storage } = &cli.command {
-        let report = if *fix_storage {
-            doctor_report_with_options(&cli.root, "workmesh", true)
-        } else {
-            doctor_report(&cli.root, "workmesh")
-        };
-        if *json {
-            println!("{}", serde_json::to_string_pretty(&report)?);
-        } else {
-            println!("root: {}", report["root"].as_str().unwrap_or(""));
-            println!("repo_root: {}", report["repo_root"].as_str().unwrap_or(""));
-            println!(
-                "backlog_dir: {}",
-                report["backlog_dir"].as_str().unwrap_or("")
+        Command::Goal { command } => match command {
+            GoalCommand::New {
+                title,
+                id,
+                description,
+                owner,
+                due,
+                json,
+            } => {
+                if let Some(due) = due.as_deref() {
+                    if NaiveDate::parse_from_str(due, "%Y-%m-%d").is_err() {
+                        die(&format!(
+                            "Invalid --due date (expected YYYY-MM-DD): {}",
+                            due
+                        ));
+                    }
+                }
+                let goal = Goal {
+                    id: id
+                        .map(|id| id.trim().to_lowercase())
+                        .unwrap_or_else(|| next_goal_id(&backlog_dir)),
+                    title,
+                    description,
+                    owner,
+                    due,
+                    key_results: Vec::new(),
+                };
+                let path = create_goal(&backlog_dir, &goal)?;
+                audit_event(
+                    &backlog_dir,
+                    "goal_new",
+                    None,
+                    serde_json::json!({ "goal": goal.id, "title": goal.title }),
+                )?;
+                if json {
+                    let payload = serde_json::json!({"path": path, "id": goal.id});
+                    println!("{}", serde_json::to_string_pretty(&payload)?);
+                } else {
+                    println!("Created goal {} -> {}", goal.id, path.display());
+                }
+            }
+            GoalCommand::AddKr {
+                goal,
+                title,
+                target,
+                start,
+                unit,
+                json,
+            } => {
+                let kr_id = add_key_result(&backlog_dir, &goal, &title, target, start, unit)?;
+                let reference = format!("{}/{}", goal.trim().to_lowercase(), kr_id);
+                audit_event(
+                    &backlog_dir,
+                    "goal_add_kr",
+                    None,
+                    serde_json::json!({ "key_result": reference, "title": title }),
+                )?;
+                if json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({ "id": reference }))?
+                    );
+                } else {
+                    println!("Added key result {}", reference);
+                }
+            }
+            GoalCommand::Link {
+                task_id,
+                key_result,
+                unlink,
+                json,
+            } => {
+                let task = find_task(&tasks, &task_id)
+                    .unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+                let path = task
+                    .file_path
+                    .as_ref()
+                    .unwrap_or_else(|| die(&format!("Task file missing: {}", task.id)));
+                let (goal_id, kr_id) = parse_key_result_ref(&key_result)?;
+                let reference = format!("{}/{}", goal_id, kr_id);
+                let mut links = task_key_results(task);
+                if unlink {
+                    links.retain(|link| *link != reference);
+                } else {
+                    ensure_key_result(&backlog_dir, &reference)?;
+                    if !links.contains(&reference) {
+                        links.push(reference.clone());
+                    }
+                }
+                set_list_field(path, KEY_RESULTS_FIELD, links.clone())?;
+                audit_event(
+                    &backlog_dir,
+                    if unlink { "goal_unlink" } else { "goal_link" },
+                    Some(&task.id),
+                    serde_json::json!({ "key_result": reference }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                if json {
+                    let payload = serde_json::json!({"id": task.id, "key_results": links});
+                    println!("{}", serde_json::to_string_pretty(&payload)?);
+                } else if unlink {
+                    println!("Unlinked {} from {}", task.id, reference);
+                } else {
+                    println!("Linked {} -> {}", task.id, reference);
+                }
+            }
+            GoalCommand::Report {
+                key_result,
+                value,
+                note,
+                json,
+            } => {
+                let updated = report_metric(&backlog_dir, &key_result, value, note)?;
+                audit_event(
+                    &backlog_dir,
+                    "goal_report",
+                    None,
+                    serde_json::json!({ "key_result": key_result, "value": value }),
+                )?;
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&updated)?);
+                } else {
+                    println!(
+                        "{} = {}{}",
+                        key_result,
+                        value,
+                        updated.unit.as_deref().unwrap_or("")
+                    );
+                }
+            }
+            GoalCommand::List { json } => {
+                let goals = load_goals(&backlog_dir);
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&goals)?);
+                } else if goals.is_empty() {
+                    println!("No goals.");
+                } else {
+                    for goal in &goals {
+                        println!(
+                            "{} | {} | {} key results",
+                            goal.id,
+                            goal.title,
+                            goal.key_results.len()
+                        );
+                    }
+                }
+            }
+            GoalCommand::Status { goal, json } => {
+                let goals = match goal {
+                    Some(goal_id) => vec![load_goal(&backlog_dir, &goal_id)?],
+                    None => load_goals(&backlog_dir),
+                };
+                let statuses: Vec<GoalStatus> =
+                    goals.iter().map(|goal| goal_status(goal, &tasks)).collect();
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&statuses)?);
+                } else if statuses.is_empty() {
+                    println!("No goals.");
+                } else {
+                    for status in &statuses {
+                        println!(
+                            "{} | {} | {}",
+                            status.id,
+                            status.title,
+                            format_progress(status.progress)
+                        );
+                        for kr in &status.key_results {
+                            let mut detail = format!("tasks {}/{}", kr.tasks_done, kr.tasks_total);
+                            if let (Some(current), Some(target)) = (kr.current, kr.target) {
+                                detail.push_str(&format!(
+                                    ", metric {} of {}{}",
+                                    current,
+                                    target,
+                                    kr.unit.as_deref().unwrap_or("")
+                                ));
+                            }
+                            println!(
+                                "  {} | {} | {} ({})",
+                                kr.id,
+                                kr.title,
+                                format_progress(kr.progress),
+                                detail
+                            );
+                        }
+                    }
+                }
+            }
+        },
+        Command::Milestone { command } => {
+            let (name, window_days, all, json) = match &command {
+                MilestoneCommand::List { all, json } => {
+                    (None, DEFAULT_VELOCITY_WINDOW_DAYS, *all, *json)
+                }
+                MilestoneCommand::Show {
+                    name,
+                    window_days,
+                    all,
+                    json,
+                } => (Some(name.clone()), *window_days, *all, *json),
+                MilestoneCommand::Progress {
+                    name,
+                    window_days,
+                    all,
+                    json,
+                } => (name.clone(), *window_days, *all, *json),
+            };
+            let scoped = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                tasks.clone()
+            };
+            let mut report = milestone_report(
+                &backlog_dir,
+                &scoped,
+                window_days,
+                Local::now().naive_local(),
             );
-            println!("layout: {}", report["layout"].as_str().unwrap_or(""));
-            if !report["context"].is_null() {
-                let epic = report["context"]["scope"]["epic_id"].as_str().unwrap_or("");
-                let project = report["context"]["project_id"].as_str().unwrap_or("");
-                let mode = report["context"]["scope"]["mode"]
-                    .as_str()
-                    .unwrap_or("none");
+            if let Some(name) = name.as_deref() {
+                report
+                    .milestones
+                    .retain(|milestone| milestone.name.eq_ignore_ascii_case(name.trim()));
+                if report.milestones.is_empty() {
+                    die(&format!("Milestone not found: {}", name));
+                }
+            }
+            if !matches!(command, MilestoneCommand::Show { .. }) {
+                for milestone in &mut report.milestones {
+                    milestone.tasks.clear();
+                }
+            }
+            if json {
+                match &command {
+                    MilestoneCommand::Show { .. } => {
+                        println!("{}", serde_json::to_string_pretty(&report.milestones[0])?)
+                    }
+                    _ => println!("{}", serde_json::to_string_pretty(&report)?),
+                }
+            } else {
+                print_milestones(&command, &report);
+            }
+        }
+        Command::Budget {
+            command:
+                BudgetCommand::Status {
+                    initiative,
+                    phase,
+                    all,
+                    json,
+                },
+        } => {
+            let config = resolve_budgets_config(&repo_root_from_backlog(&backlog_dir));
+            let scoped = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                tasks.clone()
+            };
+            let mut report = budget_report(&config, &scoped, Local::now().naive_local());
+            let wanted = initiative
+                .as_deref()
+                .map(|name| (BudgetScope::Initiative, name))
+                .or(phase.as_deref().map(|name| (BudgetScope::Phase, name)));
+            if let Some((scope, name)) = wanted {
+                report.budgets.retain(|budget| {
+                    budget.scope == scope && budget.name.eq_ignore_ascii_case(name)
+                });
+                if report.budgets.is_empty() {
+                    die(&format!(
+                        "No {} budget configured: {}",
+                        scope.as_str(),
+                        name
+                    ));
+                }
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            for warning in &report.warnings {
+                logging::warn(warning);
+            }
+            if report.budgets.is_empty() {
+                println!("No budgets configured ([budgets] in .workmesh.toml)");
+            }
+            for budget in &report.budgets {
                 println!(
-                    "context: project_id={} scope_mode={} epic_id={}",
-                    project, mode, epic
+                    "{} {} | {} tasks | since {}",
+                    budget.scope.as_str(),
+                    budget.name,
+                    budget.tasks,
+                    budget.start.as_deref().unwrap_or("-")
                 );
-            } else {
-                println!("context: (none)");
+                for metric in &budget.metrics {
+                    let unit = match metric.unit {
+                        BudgetUnit::Points => "pts",
+                        BudgetUnit::Hours => "h",
+                    };
+                    let overrun = metric
+                        .projected_overrun
+                        .as_deref()
+                        .map(|date| format!(" | overrun ~{}", date))
+                        .unwrap_or_default();
+                    println!(
+                        "  {}: {}/{}{} used ({}%), {}{} planned, {}{}/week | {}{}",
+                        unit,
+                        metric.consumed,
+                        metric.budget,
+                        unit,
+                        metric.percent_used,
+                        metric.planned,
+                        unit,
+                        metric.burn_per_week,
+                        unit,
+                        metric.health.as_str(),
+                        overrun
+                    );
+                }
             }
-            let present = report["index"]["present"].as_bool().unwrap_or(false);
-            let entries = report["index"]["entries"].as_i64().unwrap_or(0);
-            println!("index: present={} entries={}", present, entries);
-            if !report["truth"].is_null() {
+        }
+        Command::Onboarding {
+            command: OnboardingCommand::New { user, json },
+        } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let config = resolve_onboarding_config(&repo_root);
+            let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+            let initiative = ensure_branch_initiative(&repo_root, &branch)?;
+            let all_tasks = load_tasks_with_archive(&backlog_dir);
+            let plan =
+                match create_onboarding(&backlog_dir, &all_tasks, &initiative, &config, &user) {
+                    Ok(plan) => plan,
+                    Err(err @ (OnboardingError::Task(_) | OnboardingError::Io(_))) => {
+                        return Err(err.into())
+                    }
+                    Err(err) => die(&err.to_string()),
+                };
+            audit_event(
+                &backlog_dir,
+                "add_task",
+                Some(&plan.epic_id),
+                serde_json::json!({
+                    "title": plan.epic_title,
+                    "status": "To Do",
+                    "kind": "epic",
+                    "onboarding": plan.user,
+                }),
+            )?;
+            for item in &plan.items {
+                audit_event(
+                    &backlog_dir,
+                    "add_task",
+                    Some(&item.id),
+                    serde_json::json!({
+                        "title": item.title,
+                        "status": "To Do",
+                        "onboarding": plan.user,
+                    }),
+                )?;
+            }
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&plan)?);
+            } else {
                 println!(
-                    "truth: events={} records={} validation_ok={}",
-                    report["truth"]["event_count"].as_i64().unwrap_or(0),
-                    report["truth"]["record_count"].as_i64().unwrap_or(0),
-                    report["truth"]["validation_ok"].as_bool().unwrap_or(true)
+                    "Created epic {} -> {}",
+                    plan.epic_id,
+                    plan.epic_path.display()
                 );
+                for item in &plan.items {
+                    let after = if item.dependencies.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" (after {})", item.dependencies.join(", "))
+                    };
+                    println!("  {} | {}{}", item.id, item.title, after);
+                }
             }
-            if !report["storage"].is_null() {
+        }
+        Command::Onboarding {
+            command: OnboardingCommand::Status { user, all, json },
+        } => {
+            let scoped = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                tasks.clone()
+            };
+            let statuses = onboarding_status(&scoped, user.as_deref());
+            if json {
+                println!("{}", serde_json::to_string_pretty(&statuses)?);
+                return Ok(());
+            }
+            if statuses.is_empty() {
+                match user.as_deref() {
+                    Some(user) => println!("No onboarding checklist for {}", user),
+                    None => println!("No onboarding checklists"),
+                }
+            }
+            for status in &statuses {
                 println!(
-                    "storage: ok={} malformed_total={} projection_mismatches={}",
-                    report["storage"]["ok"].as_bool().unwrap_or(false),
-                    report["storage"]["jsonl"]["malformed_total"]
-                        .as_i64()
-                        .unwrap_or(0),
-                    report["storage"]["truth_projection"]["projection_mismatches"]
-                        .as_array()
-                        .map(|items| items.len())
-                        .unwrap_or(0)
+                    "{} | {} | {}/{} done ({}%) | {}",
+                    status.user,
+                    status.epic_id,
+                    status.done,
+                    status.total,
+                    status.percent,
+                    status.epic_status
                 );
-                if *fix_storage {
+                for item in &status.items {
+                    let mark = if item.done {
+                        "x"
+                    } else if item.ready {
+                        ">"
+                    } else {
+                        " "
+                    };
                     println!(
-                        "storage_fix: ok={} sessions_trimmed={} truth_trimmed={}",
-                        report["storage"]["fix"]["ok"].as_bool().unwrap_or(false),
-                        report["storage"]["fix"]["sessions_trimmed"]
-                            .as_i64()
-                            .unwrap_or(0),
-                        report["storage"]["fix"]["truth_trimmed"]
-                            .as_i64()
-                            .unwrap_or(0),
+                        "  [{}] {} | {} | {}",
+                        mark, item.id, item.status, item.title
                     );
                 }
             }
-            println!(
-                "versions: workmesh={} workmesh-mcp={}",
-                report["versions"]["workmesh"].as_str().unwrap_or(""),
-                report["versions"]["workmesh_mcp"].as_str().unwrap_or("")
-            );
-        }
-        return Ok(());
-    }
-
-    if let Command::Config { command } = &cli.command {
-        let repo_root = resolve_cli_repo_root(&cli.root);
-        handle_config_command(&repo_root, command)?;
-        return Ok(());
-    }
-
-    if let Command::Migrate { command, to, yes } = &cli.command {
-        if let Some(migrate_cmd) = command {
-            handle_migrate_workflow(&cli.root, migrate_cmd)?;
-        } else {
-            let resolution = resolve_backlog(&cli.root)?;
-            let target = to.as_deref().unwrap_or("workmesh");
-            handle_migrate_command(&resolution, target, *yes)?;
-        }
-        return Ok(());
-    }
-
-    if let Command::Render { command } = &cli.command {
-        handle_render_command(command)?;
-        return Ok(());
-    }
-
-    let resolution = resolve_backlog(&cli.root)?;
-    let backlog_dir = maybe_prompt_migration(&resolution)?;
-    let tasks = load_tasks(&backlog_dir);
-    let repo_root = repo_root_from_backlog(&backlog_dir);
-    let task_rules = resolve_task_validation_rules(&repo_root);
-    let auto_checkpoint = auto_checkpoint_enabled(&cli);
-    let auto_session = auto_session_enabled(&cli, &resolution.repo_root);
-
-    match cli.command {
-        Command::Readme { .. }
-        | Command::ToolInfo { .. }
-        | Command::SkillContent { .. }
-        | Command::ProjectManagementSkill { .. } => {
-            unreachable!("handled before backlog resolution")
         }
-        Command::Board {
-            all,
-            by,
-            focus,
+        Command::Propose {
+            file,
+            summary,
             json,
         } => {
-            let tasks = if all {
-                load_tasks_with_archive(&backlog_dir)
+            let raw = if file.as_os_str() == "-" {
+                let mut raw = String::new();
+                io::stdin().read_to_string(&mut raw)?;
+                raw
             } else {
-                load_tasks(&backlog_dir)
+                std::fs::read_to_string(&file)?
             };
-            let context_state = if focus {
-                load_context_state(&backlog_dir)
+            let changes = parse_proposed_changes(&raw)?;
+            let proposal = submit_proposal(
+                &backlog_dir,
+                changes,
+                std::env::var("USER").ok(),
+                (file.as_os_str() != "-").then(|| file.display().to_string()),
+                summary,
+            )?;
+            audit_event(
+                &backlog_dir,
+                "proposal_submit",
+                None,
+                serde_json::json!({
+                    "proposal": proposal.id,
+                    "changes": proposal.changes.len(),
+                }),
+            )?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&proposal)?);
             } else {
-                None
-            };
-            let scope_ids = context_state
-                .as_ref()
-                .and_then(|c| scope_ids_from_context(&tasks, c));
-            let lanes = board_lanes(&tasks, by.to_core(), scope_ids.as_ref());
-
+                println!(
+                    "Queued {} ({} change(s)); review with `workmesh proposals show {}`",
+                    proposal.id,
+                    proposal.changes.len(),
+                    proposal.id
+                );
+            }
+        }
+        Command::Proposals {
+            command: None,
+            all,
+            json,
+        } => {
+            let proposals: Vec<Proposal> = load_proposals(&backlog_dir)
+                .into_iter()
+                .filter(|proposal| all || proposal.state == ProposalState::Proposed)
+                .collect();
             if json {
-                let payload: Vec<serde_json::Value> = lanes
-                    .into_iter()
-                    .map(|(key, lane_tasks)| {
-                        let tasks_json: Vec<serde_json::Value> = lane_tasks
-                            .into_iter()
-                            .map(|t| task_to_json_value(t, false))
-                            .collect();
-                        serde_json::json!({
-                            "lane": key,
-                            "count": tasks_json.len(),
-                            "tasks": tasks_json,
-                        })
-                    })
-                    .collect();
+                println!("{}", serde_json::to_string_pretty(&proposals)?);
+            } else if proposals.is_empty() {
+                println!("No pending proposals.");
+            } else {
+                for proposal in &proposals {
+                    println!(
+                        "{} | {} | {} | {} change(s) | {}",
+                        proposal.id,
+                        proposal.state.as_str(),
+                        proposal.actor.as_deref().unwrap_or("-"),
+                        proposal.changes.len(),
+                        proposal.summary.as_deref().unwrap_or("")
+                    );
+                }
+            }
+        }
+        Command::Proposals {
+            command: Some(ProposalsCommand::Show { proposal_id, json }),
+            ..
+        } => {
+            let proposal = load_proposal(&backlog_dir, &proposal_id)?;
+            let preview = preview_proposal(&tasks, &proposal, &task_rules);
+            if json {
+                let payload = serde_json::json!({"proposal": proposal, "preview": preview});
                 println!("{}", serde_json::to_string_pretty(&payload)?);
-                return Ok(());
+            } else {
+                println!(
+                    "{} ({}) by {} at {}",
+                    proposal.id,
+                    proposal.state.as_str(),
+                    proposal.actor.as_deref().unwrap_or("-"),
+                    time_display.render(&proposal.created_at)
+                );
+                print_change_previews(&preview);
             }
-
-            for (key, lane_tasks) in lanes {
-                println!("## {} ({})", key, lane_tasks.len());
-                for task in lane_tasks {
-                    println!("{}", render_task_line(task));
+        }
+        Command::Proposals {
+            command: Some(ProposalsCommand::Accept { proposal_id, json }),
+            ..
+        } => {
+            let proposal = load_proposal(&backlog_dir, &proposal_id)?;
+            ensure_pending(&proposal)?;
+            let preview = preview_proposal(&tasks, &proposal, &task_rules);
+            if preview.iter().any(|entry| entry.error.is_some()) {
+                print_change_previews(&preview);
+                die(&format!(
+                    "{} cannot be applied as-is; reject it or ask for a revised proposal",
+                    proposal.id
+                ));
+            }
+            let mut applied = Vec::new();
+            for (idx, change) in proposal.changes.iter().enumerate() {
+                // Reload so later changes see earlier ones (several edits to one task).
+                let current = load_tasks(&backlog_dir);
+                let entry = apply_proposed_change(&current, idx + 1, change, &task_rules)?;
+                let mut details = match change {
+                    ProposedChange::AddNote { note, section, .. } => {
+                        note_audit_details(&backlog_dir, &current, section, note)
+                    }
+                    _ => entry.details.clone(),
+                };
+                if let Some(map) = details.as_object_mut() {
+                    map.insert("proposal".to_string(), serde_json::json!(proposal.id));
                 }
-                println!();
+                audit_event(&backlog_dir, entry.action, Some(&entry.task_id), details)?;
+                applied.push(entry);
+            }
+            let decided = decide_proposal(
+                &backlog_dir,
+                &proposal.id,
+                ProposalState::Accepted,
+                std::env::var("USER").ok(),
+                None,
+            )?;
+            audit_event(
+                &backlog_dir,
+                "proposal_accept",
+                None,
+                serde_json::json!({ "proposal": decided.id, "changes": applied.len() }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                let payload = serde_json::json!({"proposal": decided, "applied": applied});
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                println!(
+                    "Accepted {} ({} change(s) applied)",
+                    decided.id,
+                    applied.len()
+                );
+            }
+        }
+        Command::Proposals {
+            command:
+                Some(ProposalsCommand::Reject {
+                    proposal_id,
+                    reason,
+                    json,
+                }),
+            ..
+        } => {
+            let decided = decide_proposal(
+                &backlog_dir,
+                &proposal_id,
+                ProposalState::Rejected,
+                std::env::var("USER").ok(),
+                reason,
+            )?;
+            audit_event(
+                &backlog_dir,
+                "proposal_reject",
+                None,
+                serde_json::json!({ "proposal": decided.id, "reason": decided.reason }),
+            )?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&decided)?);
+            } else {
+                println!("Rejected {}", decided.id);
             }
         }
-        Command::Blockers { all, epic_id, json } => {
-            let tasks = if all {
-                load_tasks_with_archive(&backlog_dir)
+        Command::Audit {
+            command: AuditCommand::Actors { since, until, json },
+        } => {
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let stats = actor_stats(&read_audit_events(&backlog_dir), &window);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else if stats.is_empty() {
+                println!("No audit events.");
             } else {
-                load_tasks(&backlog_dir)
-            };
-            let context_state = load_context_state(&backlog_dir);
-            let report =
-                blockers_report_with_context(&tasks, context_state.as_ref(), epic_id.as_deref());
-
+                for entry in &stats {
+                    let actions: Vec<String> = entry
+                        .actions
+                        .iter()
+                        .map(|(action, count)| format!("{}={}", action, count))
+                        .collect();
+                    println!(
+                        "{} | {} events | {} tasks | {} sessions | {} .. {} | {}",
+                        entry.actor,
+                        entry.events,
+                        entry.tasks,
+                        entry.sessions,
+                        time_display.render(&entry.first_at),
+                        time_display.render(&entry.last_at),
+                        actions.join(", ")
+                    );
+                }
+            }
+        }
+        Command::Audit {
+            command: AuditCommand::VerifySignatures { public_key, json },
+        } => {
+            let key = AuditKey::from_env()?;
+            let public_key = public_key.as_deref().map(parse_public_key).transpose()?;
+            let report = verify_audit_log(&backlog_dir, key.as_ref(), public_key.as_deref());
             if json {
                 println!("{}", serde_json::to_string_pretty(&report)?);
-                return Ok(());
+            } else {
+                println!(
+                    "{} events: {} chained, {} legacy; signatures {} verified, {} unchecked",
+                    report.events,
+                    report.chained,
+                    report.legacy,
+                    report.verified,
+                    report.unverified
+                );
+                if let Some(head) = &report.head {
+                    println!("Head: {}", head);
+                }
+                for issue in &report.issues {
+                    println!("line {}: {}: {}", issue.line, issue.kind, issue.message);
+                }
+                if report.ok {
+                    println!("Audit log intact");
+                }
             }
-
-            println!("Scope: {}", report.scope);
-            if !report.warnings.is_empty() {
-                println!("Warnings:");
-                for w in report.warnings.iter() {
-                    println!("- {}", w);
+            if !report.ok {
+                std::process::exit(1);
+            }
+        }
+        Command::Audit {
+            command: AuditCommand::Keygen { .. },
+        } => {
+            unreachable!("audit keygen handled before backlog resolution");
+        }
+        Command::Bundle { command } => match command {
+            BundleCommand::Create {
+                output,
+                tasks: ids,
+                status,
+                label,
+                epic,
+                json,
+            } => {
+                let csv = |values: Vec<String>| -> Vec<String> {
+                    values.iter().flat_map(|value| split_csv(value)).collect()
+                };
+                let selection = BundleSelection {
+                    ids: csv(ids),
+                    statuses: csv(status),
+                    labels: csv(label),
+                    epic,
+                };
+                let selected = select_bundle_tasks(&tasks, &selection);
+                let bundle = create_bundle(&backlog_dir, &selected, std::env::var("USER").ok())?;
+                write_bundle(&output, &bundle)?;
+                let ids: Vec<&str> = bundle.tasks.iter().map(|task| task.id.as_str()).collect();
+                audit_event(
+                    &backlog_dir,
+                    "bundle_create",
+                    None,
+                    serde_json::json!({ "output": output, "tasks": ids }),
+                )?;
+                if json {
+                    let payload = serde_json::json!({"path": output, "tasks": ids});
+                    println!("{}", serde_json::to_string_pretty(&payload)?);
+                } else {
+                    println!("Bundled {} task(s) -> {}", ids.len(), output.display());
                 }
             }
-            if report.blocked_tasks.is_empty() {
-                println!("Blocked tasks: (none)");
-            } else {
-                println!("Blocked tasks:");
-                for entry in report.blocked_tasks.iter() {
-                    let mut parts = Vec::new();
-                    if !entry.blockers.is_empty() {
-                        parts.push(format!("blocked_by=[{}]", entry.blockers.join(", ")));
+            BundleCommand::Apply { file, apply, json } => {
+                let bundle = read_bundle(&file)?;
+                let report = apply_bundle(&backlog_dir, &tasks, &bundle, apply)?;
+                let applied = report
+                    .entries
+                    .iter()
+                    .filter(|entry| entry.state == "applied")
+                    .count();
+                if applied > 0 {
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&report)?);
+                } else if report.entries.is_empty() {
+                    println!("No annotations in {}.", file.display());
+                } else {
+                    for entry in &report.entries {
+                        let changed = if entry.task_changed {
+                            " (task changed since bundling)"
+                        } else {
+                            ""
+                        };
+                        println!(
+                            "{} | {} | {}{}",
+                            entry.annotation_id, entry.task_id, entry.state, changed
+                        );
                     }
-                    if !entry.missing_refs.is_empty() {
-                        parts.push(format!("missing_refs=[{}]", entry.missing_refs.join(", ")));
+                    if !apply {
+                        println!("Dry run; re-run with --apply to write notes.");
                     }
-                    println!(
-                        "- {}: {} ({}) {}",
-                        entry.id,
-                        entry.title,
-                        entry.status,
-                        parts.join(" ")
-                    );
                 }
             }
-            if report.top_blockers.is_empty() {
-                println!("Top blockers: (none)");
-            } else {
-                println!("Top blockers:");
-                for b in report.top_blockers.iter().take(10) {
-                    println!("- {} blocks {}", b.id, b.blocked_count);
+            BundleCommand::View { .. } | BundleCommand::Annotate { .. } => {
+                unreachable!("bundle review handled before backlog resolution")
+            }
+        },
+        Command::Taskwarrior { command } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let config = resolve_taskwarrior_config(&repo_root);
+            match command {
+                TaskwarriorCommand::Import { input, apply, json } => {
+                    let raw = match input {
+                        Some(path) => std::fs::read_to_string(&path)?,
+                        None => {
+                            let mut raw = String::new();
+                            io::stdin().read_to_string(&mut raw)?;
+                            raw
+                        }
+                    };
+                    let items = parse_taskwarrior_export(&raw)?;
+                    let initiative = if apply {
+                        let branch =
+                            core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                        ensure_branch_initiative(&repo_root, &branch)?
+                    } else {
+                        String::new()
+                    };
+                    let report = import_taskwarrior(
+                        &backlog_dir,
+                        &tasks,
+                        &items,
+                        &config,
+                        &initiative,
+                        apply,
+                    )?;
+                    for item in &report.imported {
+                        if let Some(task_id) = item.task_id.as_deref() {
+                            audit_event(
+                                &backlog_dir,
+                                "add_task",
+                                Some(task_id),
+                                serde_json::json!({
+                                    "status": item.status,
+                                    "taskwarrior_uuid": item.uuid,
+                                }),
+                            )?;
+                        }
+                    }
+                    if apply && !report.imported.is_empty() {
+                        refresh_index_best_effort(&backlog_dir);
+                        maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                    }
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&report)?);
+                    } else {
+                        for item in &report.imported {
+                            println!(
+                                "{} -> {} | {} | {} | {}",
+                                item.uuid,
+                                item.task_id.as_deref().unwrap_or("(new)"),
+                                item.status,
+                                item.priority,
+                                item.title
+                            );
+                        }
+                        println!(
+                            "New: {}, existing: {}, skipped: {}",
+                            report.imported.len(),
+                            report.existing.len(),
+                            report.skipped.len()
+                        );
+                        if !apply {
+                            println!("Dry-run: re-run with --apply to write changes.");
+                        }
+                    }
+                }
+                TaskwarriorCommand::Export { output } => {
+                    let payload =
+                        serde_json::to_string_pretty(&export_taskwarrior(&tasks, &config))?;
+                    if let Some(output) = output {
+                        std::fs::write(&output, format!("{}\n", payload))?;
+                        println!("{}", output.display());
+                    } else {
+                        println!("{}", payload);
+                    }
                 }
             }
         }
-        Command::List {
-            all,
-            status,
-            kind,
-            phase,
-            priority,
-            label,
-            depends_on,
-            deps_satisfied,
-            blocked,
-            search,
-            sort,
-            limit,
+        Command::Import {
+            input,
+            format: ImportFormatArg::JiraCsv,
+            apply,
             json,
         } => {
-            let tasks = if all {
-                load_tasks_with_archive(&backlog_dir)
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let config = resolve_jira_config(&repo_root);
+            let raw = match input {
+                Some(path) => std::fs::read_to_string(&path)?,
+                None => {
+                    let mut raw = String::new();
+                    io::stdin().read_to_string(&mut raw)?;
+                    raw
+                }
+            };
+            let issues = parse_jira_csv(&raw)?;
+            let initiative = if apply {
+                let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                ensure_branch_initiative(&repo_root, &branch)?
             } else {
-                load_tasks(&backlog_dir)
+                String::new()
             };
-            let filtered = filter_tasks(
-                &tasks,
-                to_list(status.as_slice()).as_deref(),
-                to_list(kind.as_slice()).as_deref(),
-                to_list(phase.as_slice()).as_deref(),
-                to_list(priority.as_slice()).as_deref(),
-                to_list(label.as_slice()).as_deref(),
-                depends_on.as_deref(),
-                if deps_satisfied { Some(true) } else { None },
-                if blocked { Some(true) } else { None },
-                search.as_deref(),
-            );
-            let mut sorted = sort_tasks(filtered, sort.as_str());
-            if let Some(limit) = limit {
-                sorted.truncate(limit);
+            let report = import_jira(&backlog_dir, &tasks, &issues, &config, &initiative, apply)?;
+            for item in &report.imported {
+                if let Some(task_id) = item.task_id.as_deref() {
+                    audit_event(
+                        &backlog_dir,
+                        "add_task",
+                        Some(task_id),
+                        serde_json::json!({
+                            "status": item.status,
+                            "jira_key": item.key,
+                        }),
+                    )?;
+                }
+            }
+            if apply && !report.imported.is_empty() {
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             }
             if json {
-                let payload: Vec<_> = sorted.iter().map(|task| (*task).clone()).collect();
-                println!("{}", tasks_to_json(&payload, false));
-                return Ok(());
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                for warning in &report.warnings {
+                    logging::warn(warning);
+                }
+                for item in &report.imported {
+                    println!(
+                        "{} -> {} | {} | {} | {} | {}",
+                        item.key,
+                        item.task_id.as_deref().unwrap_or("(new)"),
+                        item.issue_type,
+                        item.status,
+                        item.priority,
+                        item.title
+                    );
+                }
+                println!(
+                    "New: {}, existing: {}",
+                    report.imported.len(),
+                    report.existing.len()
+                );
+                if !apply {
+                    println!("Dry-run: re-run with --apply to write changes.");
+                }
             }
-            for task in sorted {
-                println!("{}", render_task_line(task));
+        }
+        Command::Github { command } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let config = resolve_github_config(&repo_root);
+            let (export, repo, apply, force, json) = match command {
+                GithubCommand::Export {
+                    repo,
+                    apply,
+                    force,
+                    json,
+                } => (true, repo, apply, force, json),
+                GithubCommand::Import {
+                    repo,
+                    apply,
+                    force,
+                    json,
+                } => (false, repo, apply, force, json),
+            };
+            let Some(repo) = repo
+                .or_else(|| config.repo.clone())
+                .or_else(|| detect_github_repo(&repo_root))
+            else {
+                die(
+                    "No GitHub repo: pass --repo owner/name or set [github] repo in .workmesh.toml",
+                );
+            };
+            let api = HttpGithubApi::new(&config, &repo);
+            if apply && export && api.token.is_none() {
+                die(&format!(
+                    "GitHub export needs a token in ${}",
+                    config.token_env()
+                ));
+            }
+            let report = if export {
+                export_github(&tasks, &api, &config, &repo, apply, force)?
+            } else {
+                let initiative = if apply {
+                    let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                    ensure_branch_initiative(&repo_root, &branch)?
+                } else {
+                    String::new()
+                };
+                import_github(
+                    &backlog_dir,
+                    &tasks,
+                    &api,
+                    &config,
+                    &repo,
+                    &initiative,
+                    apply,
+                    force,
+                )?
+            };
+            if apply {
+                let action = format!("github_{}", report.direction);
+                let created = report.created.iter().map(|item| (item, !export));
+                let updated = report.updated.iter().map(|item| (item, false));
+                for (item, new_task) in created.chain(updated) {
+                    let Some(task_id) = item.task_id.as_deref() else {
+                        continue;
+                    };
+                    audit_event(
+                        &backlog_dir,
+                        if new_task { "add_task" } else { &action },
+                        Some(task_id),
+                        serde_json::json!({
+                            "repo": report.repo,
+                            "github_issue": item.issue,
+                            "fields": item.fields,
+                        }),
+                    )?;
+                }
+                if !report.created.is_empty() || !report.updated.is_empty() {
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                print_github_report(&report);
             }
         }
-        Command::Next { json } => {
-            let context = load_context_state(&backlog_dir);
-            let recommended =
-                recommend_next_tasks_with_context_and_rules(&tasks, context.as_ref(), &task_rules);
-            let task = recommended.first().map(|t| (*t).clone());
+        Command::Attach {
+            task_id,
+            file,
+            name,
+            json,
+        } => {
+            let task = find_task(&tasks, &task_id)
+                .unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+            let config = resolve_attachments_config(&repo_root_from_backlog(&backlog_dir));
+            let store = configured_store(&config)?;
+            let attachment = attach_file(
+                &backlog_dir,
+                task,
+                &file,
+                name.as_deref(),
+                store.as_deref(),
+                config.min_size_bytes()?,
+            )?;
+            audit_event(
+                &backlog_dir,
+                "attach",
+                Some(&task.id),
+                serde_json::json!({
+                    "name": attachment.name,
+                    "backend": attachment.backend,
+                    "size": attachment.size,
+                    "url": attachment.url,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             if json {
-                if let Some(task) = task {
-                    let value = task_to_json_value(&task, false);
-                    println!("{}", serde_json::to_string_pretty(&value)?);
-                } else {
-                    println!("{}", "{}");
+                println!("{}", serde_json::to_string_pretty(&attachment)?);
+            } else {
+                println!(
+                    "Attached {} to {} ({})",
+                    attachment.name,
+                    task.id,
+                    attachment_location(&attachment)
+                );
+            }
+        }
+        Command::Attachments { command } => {
+            let config = resolve_attachments_config(&repo_root_from_backlog(&backlog_dir));
+            match command {
+                AttachmentsCommand::List { task_id, json } => {
+                    let task = find_task(&tasks, &task_id)
+                        .unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+                    let attachments = list_attachments(&backlog_dir, task);
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&attachments)?);
+                    } else if attachments.is_empty() {
+                        println!("No attachments for {}", task.id);
+                    } else {
+                        for attachment in &attachments {
+                            println!(
+                                "{} | {} | {} bytes | {}",
+                                attachment.name,
+                                attachment.backend,
+                                attachment.size,
+                                attachment_location(attachment)
+                            );
+                        }
+                    }
+                }
+                AttachmentsCommand::Fetch {
+                    task_id,
+                    name,
+                    json,
+                } => {
+                    let task = find_task(&tasks, &task_id)
+                        .unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+                    let store = configured_store(&config)?;
+                    let fetched = fetch_attachment(&backlog_dir, task, &name, store.as_deref())?;
+                    if json {
+                        println!("{}", serde_json::to_string_pretty(&fetched)?);
+                    } else {
+                        println!("{}", fetched.path.display());
+                    }
+                }
+                AttachmentsCommand::Gc { apply, json } => {
+                    let Some(store) = configured_store(&config)? else {
+                        die("attachments gc needs [attachments] backend = \"s3\"");
+                    };
+                    let all_tasks = load_tasks_with_archive(&backlog_dir);
+                    let archived: Vec<String> = all_tasks
+                        .iter()
+                        .filter(|task| find_task(&tasks, &task.id).is_none())
+                        .map(|task| task.id.clone())
+                        .collect();
+                    let orphans = find_orphan_objects(
+                        store.as_ref(),
+                        &all_tasks,
+                        &archived,
+                        config.delete_on_archive.unwrap_or(false),
+                    )?;
+                    if apply {
+                        for orphan in &orphans {
+                            store.delete(&orphan.key)?;
+                        }
+                    }
+                    if json {
+                        println!(
+                            "{}",
+                            serde_json::to_string_pretty(&serde_json::json!({
+                                "apply": apply,
+                                "objects": orphans,
+                            }))?
+                        );
+                    } else if orphans.is_empty() {
+                        println!("No orphaned attachment objects");
+                    } else {
+                        for orphan in &orphans {
+                            println!("{} | {} | {}", orphan.key, orphan.task_id, orphan.reason);
+                        }
+                        if apply {
+                            println!("Deleted {} object(s)", orphans.len());
+                        } else {
+                            println!(
+                                "Dry run: pass --apply to delete {} object(s)",
+                                orphans.len()
+                            );
+                        }
+                    }
                 }
-            } else if let Some(task) = task {
-                println!("{}", render_task_line(&task));
             }
         }
-        Command::NextTasks { json, limit } => {
-            let context = load_context_state(&backlog_dir);
-            let mut recommended =
-                recommend_next_tasks_with_context_and_rules(&tasks, context.as_ref(), &task_rules);
+        Command::Watch {
+            task_id,
+            as_user,
+            json,
+        } => {
+            update_watchers(&backlog_dir, &tasks, &task_id, as_user, true, json)?;
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+        }
+        Command::Unwatch {
+            task_id,
+            as_user,
+            json,
+        } => {
+            update_watchers(&backlog_dir, &tasks, &task_id, as_user, false, json)?;
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+        }
+        Command::Inbox {
+            for_user,
+            unread,
+            mark_read,
+            mark_all_read,
+            limit,
+            json,
+        } => {
+            let user = for_user
+                .or_else(|| std::env::var("USER").ok())
+                .filter(|value| !value.trim().is_empty())
+                .unwrap_or_else(|| die("--for is required when $USER is not set"));
+            if mark_all_read {
+                mark_inbox_all_read(&backlog_dir, &user)?;
+            } else if !mark_read.is_empty() {
+                mark_inbox_read(&backlog_dir, &user, &mark_read)?;
+            }
+            let all_tasks = load_tasks_with_archive(&backlog_dir);
+            let mut items = build_inbox(&backlog_dir, &user, &all_tasks);
+            let unread_count = items.iter().filter(|item| !item.read).count();
+            if unread {
+                items.retain(|item| !item.read);
+            }
             if let Some(limit) = limit {
-                recommended.truncate(limit);
+                items.truncate(limit);
             }
             if json {
-                let payload: Vec<_> = recommended
-                    .iter()
-                    .map(|task| task_to_json_value(task, false))
-                    .collect();
-                println!("{}", serde_json::to_string_pretty(&payload)?);
-                return Ok(());
-            }
-            for task in recommended {
-                println!("{}", render_task_line(task));
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "user": normalize_user(&user),
+                        "unread": unread_count,
+                        "items": items,
+                    }))?
+                );
+            } else if items.is_empty() {
+                println!("Inbox empty for {}.", normalize_user(&user));
+            } else {
+                for item in &items {
+                    println!(
+                        "[{}] {} {} {} | {} | {}",
+                        if item.read { "x" } else { " " },
+                        item.id,
+                        item.kind.as_str(),
+                        item.task_id,
+                        time_display.render(&item.timestamp),
+                        item.summary
+                    );
+                }
+                println!("Unread: {}", unread_count);
             }
         }
-        Command::Ready { json, limit } => {
-            let mut ready = ready_tasks_with_rules(&tasks, &task_rules);
+        Command::Mentions {
+            for_user,
+            days,
+            limit,
+            json,
+        } => {
+            let user = for_user
+                .or_else(|| std::env::var("USER").ok())
+                .filter(|value| !value.trim().is_empty())
+                .unwrap_or_else(|| die("--for is required when $USER is not set"));
+            let since =
+                (days > 0).then(|| Local::now().naive_local() - Duration::days(days as i64));
+            let all_tasks = load_tasks_with_archive(&backlog_dir);
+            let mut hits = mentions_for(&backlog_dir, &user, &all_tasks, since);
             if let Some(limit) = limit {
-                ready.truncate(limit);
+                hits.truncate(limit);
             }
             if json {
-                let payload: Vec<_> = ready.iter().map(|task| (*task).clone()).collect();
-                println!("{}", tasks_to_json(&payload, false));
-                return Ok(());
-            }
-            for task in ready {
-                println!("{}", render_task_line(task));
+                println!("{}", serde_json::to_string_pretty(&hits)?);
+            } else if hits.is_empty() {
+                println!("No mentions of @{}.", normalize_user(&user));
+            } else {
+                for hit in &hits {
+                    println!(
+                        "{} | {} | {} | {} | {}",
+                        time_display.render(&hit.timestamp),
+                        hit.task_id,
+                        hit.title.as_deref().unwrap_or(""),
+                        hit.actor.as_deref().unwrap_or("-"),
+                        hit.note
+                    );
+                }
             }
         }
-        Command::Show {
-            task_id,
-            full,
-            json,
-        } => {
-            let task = find_task(&tasks, &task_id).unwrap_or_else(|| {
-                die(&format!("Task not found: {}", task_id));
-            });
+        Command::Calibration { by, json } => {
+            let group = match by {
+                None => CalibrationGroup::Overall,
+                Some(CalibrationByArg::Assignee) => CalibrationGroup::Assignee,
+                Some(CalibrationByArg::Label) => CalibrationGroup::Label,
+            };
+            let report = calibration_report(&backlog_dir, &tasks, group);
             if json {
-                let value = task_to_json_value(task, true);
-                println!("{}", serde_json::to_string_pretty(&value)?);
-                return Ok(());
-            }
-            if full {
-                if let Some(path) = &task.file_path {
-                    let content = std::fs::read_to_string(path)?;
-                    println!("{}", content);
-                    return Ok(());
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.buckets.is_empty() {
+                println!("No estimated tasks (set `estimate: 4h|2d|1w` in task front matter).");
+            } else {
+                for bucket in &report.buckets {
+                    println!(
+                        "{} | done={} estimate={:.1}h actual={:.1}h bias={:.2} median={:.2} | \
+                         open={:.1}h adjusted={:.1}h",
+                        bucket.key,
+                        bucket.samples.len(),
+                        bucket.estimate_hours,
+                        bucket.actual_hours,
+                        bucket.bias,
+                        bucket.median_ratio,
+                        bucket.open_estimate_hours,
+                        bucket.adjusted_open_hours
+                    );
+                }
+                if !report.skipped.is_empty() {
+                    println!("skipped (done, no actual): {}", report.skipped.join(", "));
                 }
             }
-            println!("{}", render_task_line(task));
         }
-        Command::Stats { json } => {
-            let stats = status_counts(&tasks);
+        Command::Analytics {
+            command:
+                AnalyticsCommand::CycleTime {
+                    by,
+                    since,
+                    until,
+                    all,
+                    json,
+                },
+        } => {
+            let group = match by {
+                None => CycleTimeGroup::Overall,
+                Some(CycleTimeByArg::Label) => CycleTimeGroup::Label,
+                Some(CycleTimeByArg::Phase) => CycleTimeGroup::Phase,
+                Some(CycleTimeByArg::Priority) => CycleTimeGroup::Priority,
+            };
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let tasks = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                tasks
+            };
+            let report = cycle_time_report(&backlog_dir, &tasks, group, &window);
             if json {
-                let mut map = serde_json::Map::new();
-                for (key, value) in stats {
-                    map.insert(key, serde_json::Value::from(value as u64));
-                }
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&serde_json::Value::Object(map))?
-                );
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.buckets.is_empty() {
+                println!("No done tasks with a recorded move to Done.");
             } else {
-                for (key, value) in stats {
-                    println!("{}: {}", key, value);
+                for bucket in &report.buckets {
+                    println!(
+                        "{} | done={} | lead {} | cycle {}",
+                        bucket.key,
+                        bucket.tasks,
+                        duration_stats_text(bucket.lead.as_ref()),
+                        duration_stats_text(bucket.cycle.as_ref())
+                    );
+                }
+                if !report.untracked.is_empty() {
+                    println!(
+                        "untracked (done, no audit history): {}",
+                        report.untracked.join(", ")
+                    );
                 }
             }
         }
-        Command::Fix { command } => match command {
-            FixCommand::List { json } => {
-                let fixers = all_fix_targets()
-                    .into_iter()
-                    .map(as_fixer_kind)
-                    .map(|kind| kind.as_str().to_string())
-                    .collect::<Vec<_>>();
-                if json {
+        Command::BlockedTime {
+            command: BlockedTimeCommand::Report { open, limit, json },
+        } => {
+            let mut report = blocked_time_report(&backlog_dir, &tasks);
+            if open {
+                report.retain(|entry| entry.end.is_none());
+            }
+            if let Some(limit) = limit {
+                report.truncate(limit);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.is_empty() {
+                println!("No tasks with audit history.");
+            } else {
+                for entry in &report {
                     println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "fixers": fixers,
-                        }))?
+                        "{} | blocked {:.1}h | ready {:.1}h | in progress {:.1}h | {} | {}",
+                        entry.task_id,
+                        entry.time.blocked_hours,
+                        entry.time.ready_hours,
+                        entry.time.in_progress_hours,
+                        entry.status,
+                        entry.title
                     );
-                } else {
-                    for fixer in fixers {
-                        println!("{}", fixer);
+                }
+                let reasons = block_reason_counts(&tasks);
+                if !reasons.is_empty() {
+                    println!("Top block reasons:");
+                    for entry in reasons.iter().take(10) {
+                        println!(
+                            "- {}x {} ({})",
+                            entry.count,
+                            entry.reason,
+                            entry.tasks.join(", ")
+                        );
                     }
                 }
             }
-            FixCommand::All {
-                apply,
-                check,
-                only,
-                exclude,
-                json,
-            } => {
-                let apply_mode = parse_fix_mode(apply, check)?;
-                let targets = select_fix_targets(&only, &exclude);
-                if targets.is_empty() {
-                    die("No fixers selected. Adjust --only/--exclude.");
+        }
+        Command::Activity {
+            task,
+            epic,
+            by,
+            since,
+            until,
+            json,
+        } => {
+            let bucket = match by {
+                ActivityByArg::Day => ActivityBucket::Day,
+                ActivityByArg::Week => ActivityBucket::Week,
+            };
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let report = activity_report(
+                &backlog_dir,
+                &tasks,
+                task.as_deref(),
+                epic.as_deref(),
+                bucket,
+                &window,
+            );
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.points.is_empty() {
+                println!("No audit events in scope.");
+            } else {
+                println!(
+                    "{} events, {} to {}",
+                    report.total,
+                    report.points[0].period,
+                    report.points[report.points.len() - 1].period
+                );
+                println!("{}", report.sparkline);
+                if let (Some(last), Some(idle)) = (&report.last_event, report.idle_days) {
+                    println!("last activity: {} ({} days ago)", last, idle);
                 }
-                let mut runs = Vec::new();
-                for target in targets {
-                    runs.push(run_fix_target(&backlog_dir, target, apply_mode)?);
+                for point in report.points.iter().filter(|point| point.count > 0) {
+                    println!("{} {}", point.period, point.count);
                 }
-
-                let total_detected: usize = runs.iter().map(|run| run.detected).sum();
-                let total_fixed: usize = runs.iter().map(|run| run.fixed).sum();
-                let total_skipped: usize = runs.iter().map(|run| run.skipped).sum();
-                let runs_json: Vec<serde_json::Value> = runs.iter().map(fix_run_to_json).collect();
-
-                if apply_mode {
+            }
+        }
+        Command::Assignee {
+            command:
+                AssigneeCommand::Rename {
+                    from,
+                    to,
+                    apply,
+                    alias_actor,
+                    json,
+                },
+        } => {
+            if to.trim().trim_start_matches('@').trim().is_empty() {
+                die("New handle must not be empty");
+            }
+            let report = rename_assignee(&tasks, &from, &to, apply)?;
+            let alias_path = if apply && alias_actor {
+                let repo_root = repo_root_from_backlog(&backlog_dir);
+                Some(update_actor_alias(&repo_root, &report.from, &report.to)?)
+            } else {
+                None
+            };
+            if apply {
+                for change in &report.changes {
                     audit_event(
                         &backlog_dir,
-                        "fix_all",
-                        None,
+                        "assignee_rename",
+                        Some(&change.id),
                         serde_json::json!({
-                            "runs": runs_json.clone(),
-                            "fixed": total_fixed,
+                            "from": report.from,
+                            "to": report.to,
+                            "fields": change.fields,
                         }),
                     )?;
+                }
+                if !report.changes.is_empty() {
                     refresh_index_best_effort(&backlog_dir);
                     maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
                 }
-
-                if json {
+            }
+            if json {
+                let mut payload = serde_json::to_value(&report)?;
+                payload["actor_alias"] = serde_json::json!(alias_path);
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                for change in &report.changes {
                     println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "ok": true,
-                            "mode": if apply_mode { "apply" } else { "check" },
-                            "runs": runs_json,
-                            "totals": {
-                                "detected": total_detected,
-                                "fixed": total_fixed,
-                                "skipped": total_skipped,
-                            }
-                        }))?
+                        "{} {} [{}]",
+                        change.id,
+                        change.title,
+                        change.fields.join(", ")
                     );
-                } else {
-                    for run in &runs {
-                        print_fix_report(run, apply_mode);
-                    }
-                    if !apply_mode {
-                        println!("Dry-run: re-run with --apply to write changes.");
-                    }
                 }
-            }
-            FixCommand::Uid { apply, check, json } => {
-                let apply_mode = parse_fix_mode(apply, check)?;
-                let run = run_fix_target(&backlog_dir, FixTargetArg::Uid, apply_mode)?;
-                if apply_mode {
-                    audit_event(
-                        &backlog_dir,
-                        "fix_uid",
-                        None,
-                        serde_json::json!({ "fixed": run.fixed }),
-                    )?;
-                    refresh_index_best_effort(&backlog_dir);
-                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                let verb = if apply { "Renamed" } else { "Would rename" };
+                println!(
+                    "{} {} -> {} in {} task(s)",
+                    verb,
+                    report.from,
+                    report.to,
+                    report.changes.len()
+                );
+                if let Some(path) = alias_path {
+                    println!("Audit actor alias recorded in {}", path.display());
+                } else if !apply {
+                    println!("Dry run; pass --apply to write changes");
                 }
+            }
+        }
+        Command::Assign { command } => match command {
+            AssignCommand::Auto { apply, json } => {
+                let report = ownership_report(&backlog_dir, &tasks);
+                let updated = if apply {
+                    let updated = apply_ownership(&tasks, &report)?;
+                    for suggestion in report.suggestions.iter().filter(|s| s.has_changes()) {
+                        audit_event(
+                            &backlog_dir,
+                            "assign_auto",
+                            Some(&suggestion.task_id),
+                            serde_json::json!({
+                                "owners": suggestion.owners,
+                                "add_labels": suggestion.add_labels,
+                                "assignee": suggestion.assignee,
+                            }),
+                        )?;
+                    }
+                    if updated > 0 {
+                        refresh_index_best_effort(&backlog_dir);
+                        maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                    }
+                    updated
+                } else {
+                    0
+                };
                 if json {
-                    let run_json = fix_run_to_json(&run);
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "ok": true,
-                            "mode": if apply_mode { "apply" } else { "check" },
-                            "run": run_json
-                        }))?
-                    );
+                    let mut payload = serde_json::to_value(&report)?;
+                    payload["apply"] = serde_json::Value::Bool(apply);
+                    payload["updated"] = serde_json::json!(updated);
+                    println!("{}", serde_json::to_string_pretty(&payload)?);
                 } else {
-                    print_fix_report(&run, apply_mode);
-                    if !apply_mode {
+                    let Some(codeowners) = report.codeowners.as_ref() else {
+                        die("No CODEOWNERS file found (.github/, repo root, or docs/)");
+                    };
+                    println!("CODEOWNERS: {}", codeowners.display());
+                    for suggestion in &report.suggestions {
+                        println!(
+                            "{} {} <- {}",
+                            suggestion.task_id,
+                            suggestion.title,
+                            suggestion.owners.join(", ")
+                        );
+                        println!("  files: {}", suggestion.files.join(", "));
+                        if !suggestion.add_labels.is_empty() {
+                            println!("  + labels: {}", suggestion.add_labels.join(", "));
+                        }
+                        if let Some(assignee) = suggestion.assignee.as_ref() {
+                            println!("  + assignee: {}", assignee);
+                        }
+                    }
+                    if !report.unowned.is_empty() {
+                        println!("No owner: {}", report.unowned.join(", "));
+                    }
+                    if apply {
+                        println!("Updated {} task(s).", updated);
+                    } else {
                         println!("Dry-run: re-run with --apply to write changes.");
                     }
                 }
             }
-            FixCommand::Deps { apply, check, json } => {
-                let apply_mode = parse_fix_mode(apply, check)?;
-                let run = run_fix_target(&backlog_dir, FixTargetArg::Deps, apply_mode)?;
-                if apply_mode {
-                    audit_event(
-                        &backlog_dir,
-                        "fix_deps",
-                        None,
-                        serde_json::json!({ "fixed": run.fixed }),
-                    )?;
-                    refresh_index_best_effort(&backlog_dir);
-                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
-                }
+        },
+        Command::Workflow { command } => match command {
+            WorkflowCommand::Show { format, json } => {
+                let config = resolve_workflow(&repo_root);
+                let diagram = workflow_diagram(&backlog_dir, &tasks, &config);
                 if json {
-                    let run_json = fix_run_to_json(&run);
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "ok": true,
-                            "mode": if apply_mode { "apply" } else { "check" },
-                            "run": run_json
-                        }))?
-                    );
+                    println!("{}", serde_json::to_string_pretty(&diagram)?);
                 } else {
-                    print_fix_report(&run, apply_mode);
-                    if !apply_mode {
-                        println!("Dry-run: re-run with --apply to write changes.");
+                    match format {
+                        WorkflowFormatArg::Mermaid => print!("{}", render_mermaid(&diagram)),
+                        WorkflowFormatArg::Dot => print!("{}", render_dot(&diagram)),
                     }
                 }
             }
-            FixCommand::Ids { apply, check, json } => {
-                let apply_mode = parse_fix_mode(apply, check)?;
-                let run = run_fix_target(&backlog_dir, FixTargetArg::Ids, apply_mode)?;
-                if apply_mode {
-                    audit_event(
-                        &backlog_dir,
-                        "fix_ids",
-                        None,
-                        serde_json::json!({ "fixed": run.fixed }),
-                    )?;
+        },
+        Command::Policies { command } => match command {
+            PoliciesCommand::Run { apply, json } => {
+                let config = resolve_policies(&repo_root);
+                let report = run_policies(
+                    &backlog_dir,
+                    &config.rules,
+                    Local::now().naive_local(),
+                    apply,
+                )?;
+                if apply && !report.actions.is_empty() {
+                    audit_policy_actions(&backlog_dir, &report)?;
                     refresh_index_best_effort(&backlog_dir);
                     maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
                 }
                 if json {
-                    let run_json = fix_run_to_json(&run);
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "ok": true,
-                            "mode": if apply_mode { "apply" } else { "check" },
-                            "run": run_json
-                        }))?
-                    );
+                    println!("{}", serde_json::to_string_pretty(&report)?);
                 } else {
-                    print_fix_report(&run, apply_mode);
-                    if !apply_mode {
+                    if report.rules == 0 {
+                        println!("No policies configured ([policies] in .workmesh.toml).");
+                    }
+                    for action in &report.actions {
+                        let mut changes = Vec::new();
+                        if !action.add_labels.is_empty() {
+                            changes.push(format!("+labels {}", action.add_labels.join(",")));
+                        }
+                        if !action.remove_labels.is_empty() {
+                            changes.push(format!("-labels {}", action.remove_labels.join(",")));
+                        }
+                        if let Some(status) = action.set_status.as_deref() {
+                            changes.push(format!("status -> {}", status));
+                        }
+                        println!(
+                            "{} [{}] {} ({})",
+                            action.task_id,
+                            action.policy,
+                            changes.join("; "),
+                            action.reason
+                        );
+                    }
+                    println!("Actions: {}", report.actions.len());
+                    if !apply {
                         println!("Dry-run: re-run with --apply to write changes.");
                     }
                 }
             }
-            FixCommand::Filenames { apply, check, json } => {
-                let apply_mode = parse_fix_mode(apply, check)?;
-                let run = run_fix_target(&backlog_dir, FixTargetArg::Filenames, apply_mode)?;
-                if apply_mode {
-                    audit_event(
-                        &backlog_dir,
-                        "fix_filenames",
-                        None,
-                        serde_json::json!({ "fixed": run.fixed }),
-                    )?;
-                    refresh_index_best_effort(&backlog_dir);
-                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
-                }
+            PoliciesCommand::List { json } => {
+                let config = resolve_policies(&repo_root);
                 if json {
-                    let run_json = fix_run_to_json(&run);
-                    println!(
-                        "{}",
-                        serde_json::to_string_pretty(&serde_json::json!({
-                            "ok": true,
-                            "mode": if apply_mode { "apply" } else { "check" },
-                            "run": run_json
-                        }))?
-                    );
+                    println!("{}", serde_json::to_string_pretty(&config)?);
+                } else if config.rules.is_empty() {
+                    println!("No policies configured ([policies] in .workmesh.toml).");
                 } else {
-                    print_fix_report(&run, apply_mode);
-                    if !apply_mode {
-                        println!("Dry-run: re-run with --apply to write changes.");
+                    println!("auto: {}", config.auto.unwrap_or(false));
+                    for rule in &config.rules {
+                        println!("{}", rule.name);
                     }
                 }
             }
         },
-        Command::RekeyPrompt {
-            all,
-            include_body,
-            limit,
+        Command::Health {
+            stale_days,
+            churn_days,
             json,
         } => {
-            let prompt = render_rekey_prompt(
+            let report = health_report(
                 &backlog_dir,
-                RekeyPromptOptions {
-                    include_body,
-                    include_archive: all,
-                    limit,
+                &tasks,
+                Local::now().naive_local(),
+                HealthOptions {
+                    stale_days,
+                    churn_days,
                 },
             );
             if json {
-                println!(
-                    "{}",
-                    serde_json::to_string_pretty(&serde_json::json!({
-                        "ok": true,
-                        "prompt": prompt,
-                    }))?
-                );
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else if report.groups.is_empty() {
+                println!("No epics or initiatives found.");
             } else {
-                println!("{}", prompt);
+                for group in &report.groups {
+                    let f = &group.factors;
+                    println!(
+                        "{:<6} {:>3} {} {}{} | blocked {}/{} stale {} overdue {} churn {} unestimated {}",
+                        group.level.as_str().to_uppercase(),
+                        group.score,
+                        group.kind,
+                        group.key,
+                        group
+                            .title
+                            .as_deref()
+                            .map(|title| format!(" ({})", title))
+                            .unwrap_or_default(),
+                        f.blocked,
+                        f.open,
+                        f.stale,
+                        f.overdue,
+                        f.churn,
+                        f.unestimated
+                    );
+                }
             }
         }
-        Command::RekeyApply {
-            mapping,
-            apply,
-            all,
-            strict,
-            non_strict,
-            json,
-        } => {
-            let mapping_text = read_content(None, mapping.as_deref())?;
-            let mut request = parse_rekey_request(&mapping_text)?;
-            if strict && non_strict {
-                die("Invalid flags: use either --strict or --non-strict (or neither).");
-            }
-            if strict {
-                request.strict = true;
-            }
-            if non_strict {
-                request.strict = false;
+        Command::Metrics { since, until, json } => {
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let metrics = collect_backlog_metrics(&backlog_dir, &tasks, &task_rules, &window);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&metrics)?);
+            } else {
+                print!("{}", render_prometheus(&metrics));
             }
-            let report = rekey_apply(
-                &backlog_dir,
-                &request,
-                RekeyApplyOptions {
-                    apply,
-                    strict: request.strict,
-                    include_archive: all,
-                },
-            )?;
+        }
+        Command::Postmerge { apply, merge, json } => {
+            // Report first: the id fixer renames files the report diffs against.
+            let conflicts = conflict_report(
+                &repo_root,
+                &tasks_dir_for_root(&backlog_dir),
+                merge.as_deref(),
+            );
+            let runs = vec![
+                run_fix_target(&backlog_dir, FixTargetArg::Ids, apply)?,
+                run_fix_target(&backlog_dir, FixTargetArg::Deps, apply)?,
+            ];
+            let runs_json: Vec<serde_json::Value> = runs.iter().map(fix_run_to_json).collect();
+            let index = if apply {
+                Some(rebuild_index_with_progress(&backlog_dir, progress::emit)?)
+            } else {
+                None
+            };
             if apply {
                 audit_event(
                     &backlog_dir,
-                    "rekey_apply",
+                    "postmerge",
                     None,
-                    serde_json::json!({ "changes": report.changes.len(), "strict": request.strict }),
+                    serde_json::json!({
+                        "runs": runs_json.clone(),
+                        "conflicts": conflicts.conflicts.len(),
+                        "unresolved_markers": conflicts.unresolved_markers.len(),
+                    }),
                 )?;
-                refresh_index_best_effort(&backlog_dir);
                 maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             }
+
             if json {
                 println!(
                     "{}",
-                    serde_json::to_string_pretty(&serde_json::to_value(&report)?)?
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "ok": true,
+                        "mode": if apply { "apply" } else { "check" },
+                        "runs": runs_json,
+                        "index": index,
+                        "conflicts": conflicts,
+                    }))?
                 );
-            } else if report.changes.is_empty() {
-                println!("No tasks matched the mapping.");
             } else {
-                for warning in &report.warnings {
-                    eprintln!("warning: {}", warning);
+                for run in &runs {
+                    print_fix_report(run, apply);
                 }
-                for change in &report.changes {
-                    if let Some(new_path) = &change.new_path {
-                        println!(
-                            "{} -> {} ({})",
-                            change.old_id,
-                            change.new_id,
-                            new_path.display()
-                        );
-                    } else {
-                        println!("{} -> {}", change.old_id, change.new_id);
-                    }
+                if let Some(index) = &index {
+                    println!("index | rebuilt {} ({} entries)", index.path, index.entries);
                 }
+                print_conflict_report(&conflicts);
                 if !apply {
                     println!("Dry-run: re-run with --apply to write changes.");
                 }
             }
         }
-        Command::GraphExport { pretty } => {
-            let graph = graph_export(&tasks);
-            if pretty {
-                println!("{}", serde_json::to_string_pretty(&graph)?);
+        #[cfg(feature = "tui")]
+        Command::Ui { owner, minutes } => {
+            let owner = owner
+                .or_else(|| std::env::var("USER").ok())
+                .unwrap_or_default();
+            workmesh_tui::run(
+                &backlog_dir,
+                workmesh_tui::UiOptions {
+                    owner,
+                    lease_minutes: minutes,
+                    force_frozen: cli.force_frozen,
+                },
+            )?;
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+        }
+        Command::Serve {
+            host,
+            port,
+            ui,
+            allowed_hosts,
+        } => {
+            let server = workmesh_serve::WorkmeshServer::bind(
+                &backlog_dir,
+                &workmesh_serve::ServeOptions {
+                    host,
+                    port,
+                    ui,
+                    allowed_hosts,
+                },
+            )?;
+            if let Some(addr) = server.local_addr() {
+                eprintln!(
+                    "Serving {} on http://{}{}",
+                    backlog_dir.display(),
+                    addr,
+                    if ui { "/" } else { "/api/board" }
+                );
+            }
+            server.run();
+        }
+        #[cfg(feature = "semsearch")]
+        Command::Semsearch { query, limit, json } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let provider = provider_from_config(&resolve_semsearch_config(&repo_root))?;
+            let hits = semantic_search(&backlog_dir, &tasks, provider.as_ref(), &query, limit)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&hits)?);
+            } else if hits.is_empty() {
+                println!("No similar tasks");
+            } else {
+                for hit in &hits {
+                    println!(
+                        "{:.3} | {} | {} | {}",
+                        hit.score, hit.id, hit.status, hit.title
+                    );
+                }
+            }
+        }
+        Command::GraphExport { format, pretty } => match format {
+            GraphFormatArg::Mermaid => print!("{}", mermaid_graph(&tasks)),
+            GraphFormatArg::Dot => print!("{}", dot_graph(&tasks)),
+            GraphFormatArg::Json => {
+                let graph = graph_export(&tasks);
+                if pretty {
+                    println!("{}", serde_json::to_string_pretty(&graph)?);
+                } else {
+                    println!("{}", serde_json::to_string(&graph)?);
+                }
+            }
+        },
+        Command::Export {
+            command: Some(ExportCommand::Obsidian { output, json }),
+            ..
+        } => {
+            let summary = export_obsidian(&tasks, &output)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&summary)?);
             } else {
-                println!("{}", serde_json::to_string(&graph)?);
+                println!(
+                    "Exported {} notes to {} (dashboard: {})",
+                    summary.notes, summary.output, summary.dashboard
+                );
             }
         }
-        Command::Export { pretty } => {
+        Command::Export {
+            command: None,
+            pretty,
+        } => {
             let payload = serde_json::from_str::<serde_json::Value>(&tasks_to_json(&tasks, true))?;
             if pretty {
                 println!("{}", serde_json::to_string_pretty(&payload)?);
@@ -3408,6 +8080,36 @@ fn main() -> Result<()> {
                 println!("{}", serde_json::to_string(&payload)?);
             }
         }
+        Command::Report {
+            command:
+                ReportCommand::Html {
+                    output,
+                    all,
+                    plantuml_cmd,
+                    plantuml_jar,
+                    json,
+                },
+        } => {
+            let tasks = if all {
+                load_tasks_with_archive(&backlog_dir)
+            } else {
+                tasks
+            };
+            let options = HtmlOptions {
+                time_display,
+                plantuml_cmd: parse_plantuml_cmd(plantuml_cmd)?,
+                plantuml_jar,
+            };
+            let summary = write_html_report(&tasks, &output, &options)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+            } else {
+                println!(
+                    "Wrote {} task pages to {} (index: {})",
+                    summary.task_pages, summary.output, summary.index
+                );
+            }
+        }
         Command::IssuesExport {
             output,
             include_body,
@@ -3421,7 +8123,7 @@ fn main() -> Result<()> {
             }
         }
         Command::IndexRebuild { json } => {
-            let summary = rebuild_index(&backlog_dir)?;
+            let summary = rebuild_index_with_progress(&backlog_dir, progress::emit)?;
             if json {
                 println!("{}", serde_json::to_string_pretty(&summary)?);
             } else {
@@ -3431,7 +8133,7 @@ fn main() -> Result<()> {
                 );
             }
         }
-        Command::IndexRefresh { json } => {
+        Command::IndexRefresh { stats, json } => {
             let summary = refresh_index(&backlog_dir)?;
             if json {
                 println!("{}", serde_json::to_string_pretty(&summary)?);
@@ -3440,8 +8142,25 @@ fn main() -> Result<()> {
                     "Index refresh -> {} ({} entries)",
                     summary.path, summary.entries
                 );
+                if let Some(counts) = summary.stats.filter(|_| stats) {
+                    println!(
+                        "Scanned {} files: {} skipped, {} rehashed, {} reparsed, {} removed",
+                        counts.scanned,
+                        counts.skipped,
+                        counts.rehashed,
+                        counts.reparsed,
+                        counts.removed
+                    );
+                }
             }
         }
+        Command::IndexWatch {
+            interval_ms,
+            socket,
+            json,
+        } => {
+            watch::run(&backlog_dir, interval_ms, socket, json)?;
+        }
         Command::IndexVerify { json } => {
             let report = verify_index(&backlog_dir)?;
             if json {
@@ -3465,12 +8184,18 @@ fn main() -> Result<()> {
             project,
             id,
             audit_limit,
+            include,
+            exclude,
+            template,
             json,
         } => {
             let options = CheckpointOptions {
                 project_id: project.clone(),
                 checkpoint_id: id.clone(),
-                audit_limit: audit_limit.unwrap_or(20),
+                include: split_list(&include),
+                exclude: split_list(&exclude),
+                template,
+                ..CheckpointOptions::new(audit_limit.unwrap_or(20))
             };
             let result = write_checkpoint(&backlog_dir, &tasks, &options)?;
             if json {
@@ -3486,19 +8211,88 @@ fn main() -> Result<()> {
             let project_id = resolve_project_id(&repo_root, &tasks, project.as_deref());
             let summary = resume_summary(&repo_root, &project_id, id.as_deref())?;
             match summary {
-                Some(summary) => {
+                Some(mut summary) => {
+                    // Due dates are relative to today, not to when the checkpoint was written.
+                    let today = Local::now().date_naive();
+                    summary.snapshot.due =
+                        context_due_reminders(&backlog_dir, &tasks, today, DEFAULT_DUE_SOON_DAYS);
                     if json {
                         println!("{}", serde_json::to_string_pretty(&summary.snapshot)?);
                     } else {
                         println!("{}", render_resume(&summary));
                     }
-                }
-                None => {
-                    println!("No checkpoint found");
+                }
+                None => {
+                    println!("No checkpoint found");
+                }
+            }
+        }
+        Command::WorkingSet {
+            command:
+                Some(WorkingSetCommand::Status {
+                    project,
+                    refresh,
+                    json,
+                }),
+            ..
+        } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let project_id = resolve_project_id(&repo_root, &tasks, project.as_deref());
+            let Some(status) = working_set_status(&repo_root, &project_id, &tasks)? else {
+                die(&format!("No working set for project {}", project_id));
+            };
+            let refreshed = if refresh {
+                Some(refresh_working_set(&repo_root, &project_id, &status)?)
+            } else {
+                None
+            };
+            if json {
+                let mut payload = serde_json::to_value(&status)?;
+                payload["stale"] = serde_json::json!(status.stale_count());
+                payload["refreshed"] = serde_json::json!(refreshed.is_some());
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                println!("Working set: {}", status.path.display());
+                for entry in &status.entries {
+                    let current = entry
+                        .current
+                        .as_ref()
+                        .map(|task| task.line())
+                        .unwrap_or_else(|| format!("{} | (missing)", entry.id));
+                    let flags: Vec<&str> = entry.flags.iter().map(|flag| flag.as_str()).collect();
+                    let state = if entry.is_stale() {
+                        "stale"
+                    } else if flags.is_empty() {
+                        "ok"
+                    } else {
+                        "changed"
+                    };
+                    if flags.is_empty() {
+                        println!("  {:<7} {}", state, current);
+                    } else {
+                        println!("  {:<7} {} [{}]", state, current, flags.join(", "));
+                    }
+                }
+                match refreshed {
+                    Some(path) => println!(
+                        "Refreshed {}: dropped {} stale entr{}",
+                        path.display(),
+                        status.stale_count(),
+                        if status.stale_count() == 1 {
+                            "y"
+                        } else {
+                            "ies"
+                        }
+                    ),
+                    None if status.stale_count() > 0 => {
+                        println!("Re-run with --refresh to drop stale entries.")
+                    }
+                    None => {}
                 }
             }
         }
         Command::WorkingSet {
+            command: None,
             project,
             tasks: task_list,
             note,
@@ -3552,6 +8346,51 @@ fn main() -> Result<()> {
                 println!("{}", path.display());
             }
         }
+        Command::Journal { command } => {
+            let (project, task, since, until) = match &command {
+                JournalCommand::List {
+                    project,
+                    task,
+                    since,
+                    until,
+                    ..
+                }
+                | JournalCommand::Render {
+                    project,
+                    task,
+                    since,
+                    until,
+                    ..
+                } => (project.clone(), task.clone(), since.clone(), until.clone()),
+            };
+            let window = parse_time_window(since.as_deref(), until.as_deref());
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let project_id = resolve_project_id(&repo_root, &tasks, project.as_deref());
+            let entries = filter_session_journal(
+                read_session_journal(&repo_root, &project_id)?,
+                task.as_deref(),
+                &window,
+            );
+            match command {
+                JournalCommand::List { json: true, .. } => {
+                    println!("{}", serde_json::to_string_pretty(&entries)?);
+                }
+                JournalCommand::List { .. }
+                | JournalCommand::Render {
+                    markdown: false, ..
+                } => {
+                    if entries.is_empty() {
+                        println!("No journal entries");
+                    }
+                    for entry in &entries {
+                        println!("{}", journal_line(entry));
+                    }
+                }
+                JournalCommand::Render { markdown: true, .. } => {
+                    println!("{}", render_session_journal_markdown(&entries));
+                }
+            }
+        }
         Command::Session { command } => {
             let home = resolve_workmesh_home()?;
             match command {
@@ -3674,6 +8513,7 @@ fn main() -> Result<()> {
                         handoff: None,
                         worktree,
                         truth_refs,
+                        client: None,
                     };
 
                     append_session_saved(&home, session.clone())?;
@@ -3763,15 +8603,27 @@ fn main() -> Result<()> {
                         .find(|s| s.id == id)
                         .unwrap_or_else(|| die(&format!("Session not found: {}", id)));
                     let script = resume_script(&session);
+                    let due = session_due_reminders(
+                        &session,
+                        Local::now().date_naive(),
+                        DEFAULT_DUE_SOON_DAYS,
+                    );
                     if json {
-                        println!(
-                            "{}",
-                            serde_json::to_string_pretty(
-                                &serde_json::json!({ "session": session, "resume_script": script })
-                            )?
-                        );
+                        let payload = serde_json::json!({
+                            "session": session,
+                            "resume_script": script,
+                            "due": due,
+                        });
+                        println!("{}", serde_json::to_string_pretty(&payload)?);
                     } else {
                         println!("{}", render_session_detail(&session));
+                        if !due.is_empty() {
+                            println!();
+                            println!("Due soon / overdue:");
+                            for item in &due {
+                                println!("- {}", item.line());
+                            }
+                        }
                         println!();
                         println!("Suggested resume:");
                         for line in script {
@@ -4076,7 +8928,7 @@ fn main() -> Result<()> {
                     } else {
                         println!("legacy candidates: {}", report.candidates.len());
                         for warning in report.warnings {
-                            println!("warning: {}", warning);
+                            logging::warn(warning);
                         }
                     }
                 }
@@ -4089,7 +8941,7 @@ fn main() -> Result<()> {
                         println!("to_create: {}", plan.to_create.len());
                         println!("skipped: {}", plan.skipped.len());
                         for warning in plan.warnings {
-                            println!("warning: {}", warning);
+                            logging::warn(warning);
                         }
                     }
                 }
@@ -4295,9 +9147,43 @@ fn main() -> Result<()> {
                 println!("{}", render_diff(&report));
             }
         }
+        Command::GraphDiff {
+            project,
+            from,
+            to,
+            json,
+        } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let project_id = resolve_project_id(&repo_root, &tasks, project.as_deref());
+            let checkpoint_edges = |id: &str| {
+                let Some((snapshot, _)) = load_checkpoint(&repo_root, &project_id, Some(id))?
+                else {
+                    die(&format!("Checkpoint not found: {}", id));
+                };
+                let Some(edges) = snapshot.edges else {
+                    die(&format!(
+                        "Checkpoint {} has no dependency edges (written before graph-diff support)",
+                        snapshot.checkpoint_id
+                    ));
+                };
+                Ok::<_, anyhow::Error>((snapshot.checkpoint_id, edges))
+            };
+            let (from_label, from_edges) = checkpoint_edges(&from)?;
+            let (to_label, to_edges) = match to.as_deref() {
+                Some(id) => checkpoint_edges(id)?,
+                None => ("current".to_string(), dependency_edges(&tasks)),
+            };
+            let report = graph_diff(&from_label, &from_edges, &to_label, &to_edges);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!("{}", render_graph_diff(&report));
+            }
+        }
         Command::SetStatus {
             task_id,
             status,
+            reason,
             touch,
             no_touch,
         } => {
@@ -4312,15 +9198,24 @@ fn main() -> Result<()> {
             });
             let touch = effective_touch(touch, no_touch);
             update_task_field(path, "status", Some(status.clone().into()))?;
+            if reason.is_some() || !task.status.eq_ignore_ascii_case(&status) {
+                let actor = std::env::var("USER").ok();
+                record_status_transition(
+                    path,
+                    &task.status,
+                    &status,
+                    actor.as_deref(),
+                    reason.as_deref(),
+                )?;
+            }
             if touch || is_done_status(&status) {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
             }
-            audit_event(
-                &backlog_dir,
-                "set_status",
-                Some(&task.id),
-                serde_json::json!({ "status": status.clone() }),
-            )?;
+            let mut details = serde_json::json!({ "status": status.clone() });
+            if let Some(reason) = &reason {
+                details["reason"] = serde_json::Value::String(reason.clone());
+            }
+            audit_event(&backlog_dir, "set_status", Some(&task.id), details)?;
             refresh_index_best_effort(&backlog_dir);
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             println!("Updated {} status -> {}", task.id, status);
@@ -4335,33 +9230,12 @@ fn main() -> Result<()> {
             let task = find_task(&tasks, &task_id).unwrap_or_else(|| {
                 die(&format!("Task not found: {}", task_id));
             });
-            let path = task.file_path.as_ref().unwrap_or_else(|| {
-                die(&format!("Task not found: {}", task_id));
-            });
-            let touch = effective_touch(touch, no_touch);
-            let mut assignee = task.assignee.clone();
-            if !assignee.iter().any(|value| value == &owner) {
-                assignee.push(owner.clone());
-                set_list_field(path, "assignee", assignee)?;
-            }
-            let expires_at = minutes.map(timestamp_plus_minutes);
-            let lease = Lease {
-                owner,
-                acquired_at: Some(now_timestamp()),
-                expires_at,
-            };
-            update_lease_fields(path, Some(&lease))?;
-            if touch {
-                update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
-            }
-            audit_event(
+            let lease = claim_task(
                 &backlog_dir,
-                "claim",
-                Some(&task.id),
-                serde_json::json!({
-                    "owner": lease.owner.clone(),
-                    "expires_at": lease.expires_at.clone(),
-                }),
+                task,
+                &owner,
+                minutes,
+                effective_touch(touch, no_touch),
             )?;
             refresh_index_best_effort(&backlog_dir);
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
@@ -4393,126 +9267,405 @@ fn main() -> Result<()> {
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             println!("Released {} lease", task.id);
         }
-        Command::Bulk { command } => match command {
-            BulkCommand::SetStatus {
-                tasks: task_ids,
-                status,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_set_status(
-                &backlog_dir,
-                &tasks,
-                &task_rules,
-                task_ids,
-                status,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::SetField {
-                tasks: task_ids,
-                field,
-                value,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_set_field(
-                &backlog_dir,
-                &tasks,
-                &task_rules,
-                task_ids,
-                field,
-                value,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::LabelAdd {
-                tasks: task_ids,
-                label,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_label_add(
-                &backlog_dir,
-                &tasks,
-                task_ids,
-                label,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::LabelRemove {
-                tasks: task_ids,
-                label,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_label_remove(
-                &backlog_dir,
-                &tasks,
-                task_ids,
-                label,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::DepAdd {
-                tasks: task_ids,
-                dependency,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_dep_add(
-                &backlog_dir,
-                &tasks,
-                task_ids,
-                dependency,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::DepRemove {
-                tasks: task_ids,
-                dependency,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_dep_remove(
+        Command::Freeze {
+            epic,
+            phase,
+            reason,
+            json,
+        } => {
+            let (kind, scope) = freeze_target(epic, phase);
+            let scope = match kind {
+                FreezeKind::Epic => find_task(&tasks, &scope)
+                    .map(|task| task.id.clone())
+                    .unwrap_or_else(|| die(&format!("Task not found: {}", scope))),
+                FreezeKind::Phase => scope,
+            };
+            let by = std::env::var("USER").ok();
+            let freeze =
+                freeze_scope(&backlog_dir, kind, &scope, reason.as_deref(), by.as_deref())?;
+            let count = FrozenSet::load(&backlog_dir, &tasks).count_for(&freeze);
+            audit_event(
                 &backlog_dir,
-                &tasks,
-                task_ids,
-                dependency,
-                effective_touch(touch, no_touch),
-                json,
-                auto_checkpoint,
-                auto_session,
-            )?,
-            BulkCommand::Note {
-                tasks: task_ids,
-                note,
-                section,
-                touch,
-                no_touch,
-                json,
-            } => handle_bulk_note(
+                "freeze",
+                None,
+                serde_json::json!({
+                    "kind": kind.as_str(),
+                    "scope": freeze.scope,
+                    "reason": freeze.reason,
+                    "tasks": count,
+                }),
+            )?;
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                let mut payload = serde_json::to_value(&freeze)?;
+                payload["tasks"] = serde_json::json!(count);
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                println!("Froze {} ({} task(s))", freeze.describe(), count);
+            }
+        }
+        Command::Unfreeze { epic, phase, json } => {
+            let (kind, scope) = freeze_target(epic, phase);
+            let lifted = unfreeze_scope(&backlog_dir, kind, &scope)?;
+            if let Some(freeze) = lifted.as_ref() {
+                audit_event(
+                    &backlog_dir,
+                    "unfreeze",
+                    None,
+                    serde_json::json!({ "kind": kind.as_str(), "scope": freeze.scope }),
+                )?;
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "kind": kind.as_str(),
+                        "scope": scope,
+                        "lifted": lifted,
+                    }))?
+                );
+            } else if let Some(freeze) = lifted {
+                println!("Unfroze {}", freeze.describe());
+            } else {
+                println!("Not frozen: {} {}", kind.as_str(), scope);
+            }
+        }
+        Command::Toposort { epic, json } => {
+            let report = toposort_report(&tasks, epic.as_deref());
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            for warning in &report.warnings {
+                logging::warn(warning);
+            }
+            if report.order.is_empty() {
+                println!("No open tasks to order");
+            }
+            for (level, ids) in report.levels.iter().enumerate() {
+                println!("Level {} ({} parallel):", level, ids.len());
+                for entry in report.order.iter().filter(|entry| entry.level == level) {
+                    let waits = if entry.waits_on.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" waits_on=[{}]", entry.waits_on.join(", "))
+                    };
+                    println!(
+                        "  {} | {} | {} | {}{}",
+                        entry.id, entry.status, entry.priority, entry.title, waits
+                    );
+                }
+            }
+            if !report.cyclic.is_empty() {
+                println!("Unordered (cycle): {}", report.cyclic.join(", "));
+            }
+        }
+        Command::Reminders {
+            within_days,
+            all,
+            json,
+        } => {
+            let today = Local::now().date_naive();
+            let reminders = if all {
+                due_reminders(&tasks, None, today, within_days)
+            } else {
+                context_due_reminders(&backlog_dir, &tasks, today, within_days)
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&reminders)?);
+            } else if reminders.is_empty() {
+                println!("Nothing overdue or due in the next {} day(s)", within_days);
+            } else {
+                for item in &reminders {
+                    println!("{}", item.line());
+                }
+            }
+        }
+        Command::ChangesSince {
+            cursor,
+            tasks: task_ids,
+            json,
+        } => {
+            let feed = match changes_since(&backlog_dir, cursor.as_deref(), &task_ids) {
+                Ok(feed) => feed,
+                Err(err) => die(&err.to_string()),
+            };
+            if json {
+                println!("{}", serde_json::to_string_pretty(&feed)?);
+                return Ok(());
+            }
+            if feed.baseline {
+                println!("Baseline recorded; poll with --cursor {}", feed.next_cursor);
+                return Ok(());
+            }
+            if feed.is_empty() {
+                println!("No changes since {}", feed.since.as_deref().unwrap_or("-"));
+            }
+            for event in &feed.events {
+                println!(
+                    "{} {} {} ({})",
+                    time_display.render(&event.timestamp),
+                    event.action,
+                    event.task_id.as_deref().unwrap_or("-"),
+                    event.actor.as_deref().unwrap_or("-")
+                );
+            }
+            for task in &feed.diff.added {
+                println!("+ {} | {} | {}", task.id, task.status, task.title);
+            }
+            for task in &feed.diff.removed {
+                println!("- {} | {} | {}", task.id, task.status, task.title);
+            }
+            for task in &feed.diff.changed {
+                for change in &task.changes {
+                    println!(
+                        "~ {} {}: {} -> {}",
+                        task.id, change.field, change.old, change.new
+                    );
+                }
+            }
+            for edge in &feed.diff.dependencies_added {
+                println!("+ dep {} -> {}", edge.task_id, edge.depends_on);
+            }
+            for edge in &feed.diff.dependencies_removed {
+                println!("- dep {} -> {}", edge.task_id, edge.depends_on);
+            }
+            println!("next_cursor: {}", feed.next_cursor);
+        }
+        Command::Lint {
+            tasks: task_ids,
+            titles,
+            autotag,
+            fix,
+            redact,
+            max_block_lines,
+            max_line_chars,
+            json,
+        } => {
+            if fix && !titles && !autotag {
+                die("--fix needs --titles or --autotag");
+            }
+            let selected: Vec<Task> = if task_ids.is_empty() {
+                tasks.clone()
+            } else {
+                task_ids
+                    .iter()
+                    .map(|id| match find_task(&tasks, id) {
+                        Some(task) => task.clone(),
+                        None => die(&format!("Task not found: {}", id)),
+                    })
+                    .collect()
+            };
+            if autotag {
+                let report = run_autotag(
+                    &backlog_dir,
+                    &selected,
+                    fix,
+                    json,
+                    auto_checkpoint,
+                    auto_session,
+                )?;
+                if !report.proposals.is_empty() && (!fix || !report.warnings.is_empty()) {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            if titles {
+                let repo_root = repo_root_from_backlog(&backlog_dir);
+                let rules = TitleLintRules::from_config(&resolve_title_lint_config(&repo_root))?;
+                let report = lint_titles(&selected, &rules, fix)?;
+                if !report.fixed.is_empty() {
+                    audit_event(
+                        &backlog_dir,
+                        "lint_fix_titles",
+                        None,
+                        serde_json::json!({ "tasks": report.fixed }),
+                    )?;
+                    refresh_index_best_effort(&backlog_dir);
+                    maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+                }
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&report)?);
+                } else {
+                    for finding in &report.findings {
+                        let suggestion = finding
+                            .suggestion
+                            .as_deref()
+                            .map(|title| format!(" -> {}", title))
+                            .unwrap_or_default();
+                        println!(
+                            "{} title/{} {}: {}{}",
+                            finding.task_id,
+                            finding.rule.as_str(),
+                            finding.message,
+                            finding.title,
+                            suggestion
+                        );
+                    }
+                    for warning in &report.warnings {
+                        println!("WARN: {}", warning);
+                    }
+                    println!(
+                        "lint: {} title(s) scanned, {} finding(s), {} fixed",
+                        report.scanned,
+                        report.findings.len(),
+                        report.fixed.len()
+                    );
+                }
+                if !report.findings.is_empty() && (!fix || report.unresolved > 0) {
+                    std::process::exit(1);
+                }
+                return Ok(());
+            }
+            let defaults = LintOptions::default();
+            let options = LintOptions {
+                max_block_lines: max_block_lines.unwrap_or(defaults.max_block_lines),
+                max_line_chars: max_line_chars.unwrap_or(defaults.max_line_chars),
+            };
+            let report = lint_tasks(&selected, &options, redact)?;
+            if !report.redacted.is_empty() {
+                audit_event(
+                    &backlog_dir,
+                    "lint_redact",
+                    None,
+                    serde_json::json!({ "tasks": report.redacted }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                for finding in &report.findings {
+                    println!(
+                        "{}:{} {}/{} {}",
+                        finding.task_id,
+                        finding.line,
+                        finding.rule.as_str(),
+                        finding.kind,
+                        finding.excerpt
+                    );
+                }
+                for warning in &report.warnings {
+                    println!("WARN: {}", warning);
+                }
+                println!(
+                    "lint: {} task(s) scanned, {} finding(s), {} redacted",
+                    report.scanned,
+                    report.findings.len(),
+                    report.redacted.len()
+                );
+            }
+            // Non-zero exit keeps unredacted findings out of commits when run as a hook.
+            if !report.findings.is_empty() && (!redact || !report.warnings.is_empty()) {
+                std::process::exit(1);
+            }
+        }
+        Command::Autotag {
+            tasks: task_ids,
+            apply,
+            json,
+        } => {
+            let selected: Vec<Task> = if task_ids.is_empty() {
+                tasks.clone()
+            } else {
+                task_ids
+                    .iter()
+                    .map(|id| match find_task(&tasks, id) {
+                        Some(task) => task.clone(),
+                        None => die(&format!("Task not found: {}", id)),
+                    })
+                    .collect()
+            };
+            run_autotag(
                 &backlog_dir,
-                &tasks,
-                task_ids,
-                note,
-                section,
-                effective_touch(touch, no_touch),
+                &selected,
+                apply,
                 json,
                 auto_checkpoint,
                 auto_session,
-            )?,
-        },
+            )?;
+        }
+        Command::Pick {
+            status,
+            label,
+            query,
+            all,
+            then,
+            json,
+        } => {
+            let statuses: Vec<String> = split_list(&status)
+                .into_iter()
+                .map(|value| value.to_lowercase())
+                .collect();
+            let labels = split_list(&label);
+            let candidates: Vec<&Task> = tasks
+                .iter()
+                .filter(|task| {
+                    statuses.is_empty() || statuses.contains(&task.status.to_lowercase())
+                })
+                .filter(|task| labels.iter().all(|label| task.labels.contains(label)))
+                .collect();
+            let items: Vec<picker::PickItem> = candidates
+                .iter()
+                .map(|task| picker::PickItem {
+                    id: task.id.clone(),
+                    label: pick_label(task),
+                })
+                .collect();
+            let query = query.unwrap_or_default();
+            let selected = if all {
+                let mut picked = picker::filter_items(&items, &query);
+                picked.sort_unstable();
+                picked
+                    .into_iter()
+                    .map(|idx| items[idx].id.clone())
+                    .collect()
+            } else {
+                if prompts_disabled() || !io::stdin().is_terminal() {
+                    die("pick needs an interactive terminal; pass --all to select every match");
+                }
+                let stdin = io::stdin();
+                let mut input = stdin.lock();
+                match picker::run_picker(&items, &query, &mut input, &mut io::stderr())? {
+                    Some(selected) => selected,
+                    None => {
+                        eprintln!("Cancelled");
+                        return Ok(());
+                    }
+                }
+            };
+            if then.is_empty() {
+                if json {
+                    println!("{}", serde_json::to_string_pretty(&selected)?);
+                } else {
+                    for id in &selected {
+                        println!("{}", id);
+                    }
+                }
+            } else if selected.is_empty() {
+                eprintln!("Nothing selected");
+            } else {
+                let command = pick_then_command(&then, selected, json);
+                handle_bulk_command(
+                    &backlog_dir,
+                    &tasks,
+                    &task_rules,
+                    command,
+                    auto_checkpoint,
+                    auto_session,
+                )?;
+            }
+        }
+        Command::Bulk { command } => handle_bulk_command(
+            &backlog_dir,
+            &tasks,
+            &task_rules,
+            command,
+            auto_checkpoint,
+            auto_session,
+        )?,
         Command::BulkSetStatus {
             tasks: task_ids,
             status,
@@ -4670,6 +9823,9 @@ fn main() -> Result<()> {
             if touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
             }
+            if field == "title" {
+                rename_task_file_for_title(path, &task.title)?;
+            }
             audit_event(
                 &backlog_dir,
                 "set_field",
@@ -4717,6 +9873,7 @@ fn main() -> Result<()> {
         Command::DepAdd {
             task_id,
             dependency,
+            soft,
             touch,
             no_touch,
         } => {
@@ -4724,7 +9881,7 @@ fn main() -> Result<()> {
                 &backlog_dir,
                 &tasks,
                 &task_id,
-                "dependencies",
+                dependency_field(soft),
                 &dependency,
                 true,
                 effective_touch(touch, no_touch),
@@ -4734,6 +9891,7 @@ fn main() -> Result<()> {
         Command::DepRemove {
             task_id,
             dependency,
+            soft,
             touch,
             no_touch,
         } => {
@@ -4741,7 +9899,7 @@ fn main() -> Result<()> {
                 &backlog_dir,
                 &tasks,
                 &task_id,
-                "dependencies",
+                dependency_field(soft),
                 &dependency,
                 false,
                 effective_touch(touch, no_touch),
@@ -4762,20 +9920,22 @@ fn main() -> Result<()> {
                 die(&format!("Task not found: {}", task_id));
             });
             let touch = effective_touch(touch, no_touch);
-            let new_body = append_note(&task.body, &note, section.as_str());
-            update_body(path, &new_body)?;
+            append_task_note(path, &note, section.as_str())?;
             if touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
             }
-            audit_event(
-                &backlog_dir,
-                "note",
-                Some(&task.id),
-                serde_json::json!({ "section": section.as_str(), "note": note }),
-            )?;
+            let linked = autolink_note(&backlog_dir, &tasks, task, &note)?;
+            let mut details = note_audit_details(&backlog_dir, &tasks, section.as_str(), &note);
+            if !linked.is_empty() {
+                details["relates_to"] = serde_json::json!(linked);
+            }
+            audit_event(&backlog_dir, "note", Some(&task.id), details)?;
             refresh_index_best_effort(&backlog_dir);
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
             println!("Added note to {}", task.id);
+            if !linked.is_empty() {
+                println!("Related {} to {}", task.id, linked.join(", "));
+            }
         }
         Command::SetBody {
             task_id,
@@ -4827,66 +9987,419 @@ fn main() -> Result<()> {
             if touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
             }
-            audit_event(
-                &backlog_dir,
-                "set_section",
-                Some(&task.id),
-                serde_json::json!({ "section": section.clone(), "length": content.len() }),
-            )?;
-            refresh_index_best_effort(&backlog_dir);
-            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
-            println!("Updated section {} for {}", section, task.id);
+            audit_event(
+                &backlog_dir,
+                "set_section",
+                Some(&task.id),
+                serde_json::json!({ "section": section.clone(), "length": content.len() }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            println!("Updated section {} for {}", section, task.id);
+        }
+        Command::Add {
+            id,
+            title,
+            template: Some(template),
+            description,
+            acceptance_criteria,
+            definition_of_done,
+            draft,
+            status,
+            priority,
+            phase,
+            labels,
+            dependencies,
+            assignee,
+            json,
+        }
+        | Command::Template {
+            command:
+                TemplateCommand::Apply {
+                    name: template,
+                    id,
+                    title,
+                    description,
+                    acceptance_criteria,
+                    definition_of_done,
+                    draft,
+                    status,
+                    priority,
+                    phase,
+                    labels,
+                    dependencies,
+                    assignee,
+                    json,
+                },
+        } => {
+            let template =
+                load_template(&backlog_dir, &template).unwrap_or_else(|err| die(&err.to_string()));
+            let overrides = TemplateOverrides {
+                status,
+                priority,
+                phase,
+                labels: split_csv(&labels),
+                assignee: split_csv(&assignee),
+                dependencies: split_csv(&dependencies),
+                description,
+                acceptance_criteria,
+                definition_of_done,
+                draft,
+            };
+            let task_id = match id {
+                Some(value) => value,
+                None => {
+                    let repo_root = repo_root_from_backlog(&backlog_dir);
+                    let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                    let initiative = ensure_branch_initiative(&repo_root, &branch)?;
+                    reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)?
+                }
+            };
+            let created = create_task_from_template(
+                &backlog_dir,
+                &template,
+                &task_id,
+                &title,
+                &overrides,
+                &task_rules,
+            )
+            .unwrap_or_else(|err| die(&err.to_string()));
+            audit_event(
+                &backlog_dir,
+                "add_task",
+                Some(&task_id),
+                serde_json::json!({
+                    "title": title,
+                    "status": created.status,
+                    "template": created.template,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&created)?);
+            } else {
+                println!(
+                    "Created {} from template {} -> {}",
+                    task_id,
+                    created.template,
+                    created.path.display()
+                );
+            }
+        }
+        Command::Add {
+            id,
+            title,
+            template: None,
+            description,
+            acceptance_criteria,
+            definition_of_done,
+            draft,
+            status,
+            priority,
+            phase,
+            labels,
+            dependencies,
+            assignee,
+            json,
+        } => {
+            let tasks_dir = tasks_dir_for_root(&backlog_dir);
+            let task_id = match id {
+                Some(value) => value,
+                None => {
+                    let repo_root = repo_root_from_backlog(&backlog_dir);
+                    let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+                    let initiative = ensure_branch_initiative(&repo_root, &branch)?;
+                    reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)?
+                }
+            };
+            let labels = split_csv(&labels);
+            let dependencies = split_csv(&dependencies);
+            let assignee = split_csv(&assignee);
+            let sections =
+                build_task_sections(description, acceptance_criteria, definition_of_done);
+            let status = status.unwrap_or_else(|| "To Do".to_string());
+            let priority = priority
+                .unwrap_or_else(|| resolve_default_priority(&repo_root_from_backlog(&backlog_dir)));
+            let effective_status =
+                validate_task_creation_with_rules(&status, draft, &sections, &task_rules)
+                    .unwrap_or_else(|err| die(&err));
+            let path = create_task_file_with_sections(
+                &tasks_dir,
+                &task_id,
+                &title,
+                &effective_status,
+                &priority,
+                phase.as_deref().unwrap_or("Phase1"),
+                &dependencies,
+                &labels,
+                &assignee,
+                &sections,
+            )?;
+            audit_event(
+                &backlog_dir,
+                "add_task",
+                Some(&task_id),
+                serde_json::json!({ "title": title, "status": effective_status }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                let payload = serde_json::json!({"path": path, "id": task_id});
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else {
+                println!("Created {} -> {}", task_id, path.display());
+            }
+        }
+        Command::Recur {
+            command: RecurCommand::List { json },
+        } => {
+            let (series, invalid) = recurring_series(&tasks, Local::now().date_naive());
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "series": series,
+                        "invalid": invalid,
+                    }))?
+                );
+            } else {
+                if series.is_empty() && invalid.is_empty() {
+                    println!("No recurring tasks");
+                }
+                for entry in &series {
+                    println!(
+                        "{} | {} | last {} | next {} | {}",
+                        entry.id,
+                        entry.rule,
+                        entry.last.map_or("-".to_string(), |date| date.to_string()),
+                        entry.next.map_or("-".to_string(), |date| date.to_string()),
+                        entry.title
+                    );
+                }
+                for entry in &invalid {
+                    logging::warn(format!("{}: {}", entry.id, entry.error));
+                }
+            }
+        }
+        Command::Recur {
+            command:
+                RecurCommand::Run {
+                    ahead,
+                    dry_run,
+                    json,
+                },
+        } => {
+            let through = Local::now().date_naive() + chrono::Duration::days(ahead.max(0));
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+            let initiative = ensure_branch_initiative(&repo_root, &branch)?;
+            let run = run_recurrences(&backlog_dir, &tasks, &initiative, through, dry_run)
+                .unwrap_or_else(|err| die(&err.to_string()));
+            if !dry_run && !run.created.is_empty() {
+                for instance in &run.created {
+                    audit_event(
+                        &backlog_dir,
+                        "add_task",
+                        instance.id.as_deref(),
+                        serde_json::json!({
+                            "title": instance.title,
+                            "status": "To Do",
+                            "recurrence_of": instance.series,
+                            "recurrence_date": instance.date.to_string(),
+                        }),
+                    )?;
+                }
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&run)?);
+            } else {
+                let verb = if dry_run { "Would create" } else { "Created" };
+                for instance in &run.created {
+                    println!(
+                        "{} {} for {} ({})",
+                        verb,
+                        instance.id.as_deref().unwrap_or("task"),
+                        instance.series,
+                        instance.date
+                    );
+                }
+                for entry in &run.invalid {
+                    logging::warn(format!("{}: {}", entry.id, entry.error));
+                }
+                if run.created.is_empty() {
+                    println!("No recurring occurrences due through {}", run.through);
+                }
+            }
+        }
+        Command::Template {
+            command: TemplateCommand::List { json },
+        } => {
+            let templates = list_templates(&backlog_dir);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&templates)?);
+            } else if templates.is_empty() {
+                println!(
+                    "No templates in {}",
+                    templates_dir_for_root(&backlog_dir).display()
+                );
+            } else {
+                for template in &templates {
+                    println!(
+                        "{} - {}",
+                        template.name,
+                        template.description.as_deref().unwrap_or("")
+                    );
+                }
+            }
+        }
+        Command::Template {
+            command: TemplateCommand::Show { name, json },
+        } => {
+            let template =
+                load_template(&backlog_dir, &name).unwrap_or_else(|err| die(&err.to_string()));
+            if json {
+                println!("{}", serde_json::to_string_pretty(&template)?);
+            } else {
+                println!("Template: {}", template.name);
+                if let Some(description) = template.description.as_deref() {
+                    println!("Description: {}", description);
+                }
+                for (key, value) in [
+                    ("Status", &template.status),
+                    ("Priority", &template.priority),
+                    ("Phase", &template.phase),
+                ] {
+                    if let Some(value) = value.as_deref() {
+                        println!("{}: {}", key, value);
+                    }
+                }
+                if !template.labels.is_empty() {
+                    println!("Labels: {}", template.labels.join(", "));
+                }
+                if !template.assignee.is_empty() {
+                    println!("Assignee: {}", template.assignee.join(", "));
+                }
+                println!();
+                println!("{}", template.body.trim_end());
+            }
+        }
+        Command::Template {
+            command:
+                TemplateCommand::Add {
+                    name,
+                    from_task,
+                    description,
+                    status,
+                    priority,
+                    phase,
+                    labels,
+                    assignee,
+                    force,
+                    json,
+                },
+        } => {
+            let mut template = match from_task.as_deref() {
+                Some(task_id) => {
+                    let task = find_task(&tasks, task_id)
+                        .unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+                    template_from_task(&name, task)
+                }
+                None => TaskTemplate {
+                    name: name.clone(),
+                    body: default_template_body(),
+                    ..TaskTemplate::default()
+                },
+            };
+            template.description = description.or(template.description);
+            template.status = status.or(template.status);
+            template.priority = priority.or(template.priority);
+            template.phase = phase.or(template.phase);
+            let labels = split_csv(&labels);
+            if !labels.is_empty() {
+                template.labels = labels;
+            }
+            let assignee = split_csv(&assignee);
+            if !assignee.is_empty() {
+                template.assignee = assignee;
+            }
+            let path = write_template(&backlog_dir, &template, force)
+                .unwrap_or_else(|err| die(&err.to_string()));
+            if json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "ok": true,
+                        "name": template.name,
+                        "path": path,
+                    }))?
+                );
+            } else {
+                println!("Wrote template {} -> {}", template.name, path.display());
+            }
         }
-        Command::Add {
-            id,
-            title,
-            description,
-            acceptance_criteria,
-            definition_of_done,
-            draft,
-            status,
-            priority,
-            phase,
-            labels,
-            dependencies,
-            assignee,
-            json,
+        Command::Epic {
+            command:
+                EpicCommand::New {
+                    id,
+                    title,
+                    problem,
+                    outcomes,
+                    scope,
+                    non_goals,
+                    draft,
+                    status,
+                    priority,
+                    phase,
+                    labels,
+                    assignee,
+                    json,
+                },
         } => {
-            let tasks_dir = tasks_dir_for_root(&backlog_dir);
+            let title = wizard_field("Title", title)?;
+            if title.trim().is_empty() {
+                die("Epic title is required");
+            }
+            let sections = EpicSectionContent {
+                problem: wizard_field("Problem", problem)?,
+                outcomes: wizard_field("Outcomes", outcomes)?,
+                scope: wizard_field("Scope", scope)?,
+                non_goals: wizard_field("Non-goals", non_goals)?,
+            };
+            let effective_status =
+                validate_epic_creation_with_rules(&status, draft, &sections, &task_rules)
+                    .unwrap_or_else(|err| die(&err));
             let task_id = match id {
                 Some(value) => value,
                 None => {
                     let repo_root = repo_root_from_backlog(&backlog_dir);
                     let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
                     let initiative = ensure_branch_initiative(&repo_root, &branch)?;
-                    next_namespaced_task_id(&tasks, &initiative)
+                    reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)?
                 }
             };
-            let labels = split_csv(&labels);
-            let dependencies = split_csv(&dependencies);
-            let assignee = split_csv(&assignee);
-            let sections =
-                build_task_sections(description, acceptance_criteria, definition_of_done);
-            let effective_status =
-                validate_task_creation_with_rules(&status, draft, &sections, &task_rules)
-                    .unwrap_or_else(|err| die(&err));
-            let path = create_task_file_with_sections(
-                &tasks_dir,
+            let path = create_epic_file(
+                &tasks_dir_for_root(&backlog_dir),
                 &task_id,
                 &title,
                 &effective_status,
                 &priority,
                 &phase,
-                &dependencies,
-                &labels,
-                &assignee,
+                &split_csv(&labels),
+                &split_csv(&assignee),
                 &sections,
             )?;
             audit_event(
                 &backlog_dir,
                 "add_task",
                 Some(&task_id),
-                serde_json::json!({ "title": title, "status": effective_status }),
+                serde_json::json!({
+                    "title": title,
+                    "status": effective_status,
+                    "kind": "epic",
+                }),
             )?;
             refresh_index_best_effort(&backlog_dir);
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
@@ -4894,7 +10407,7 @@ fn main() -> Result<()> {
                 let payload = serde_json::json!({"path": path, "id": task_id});
                 println!("{}", serde_json::to_string_pretty(&payload)?);
             } else {
-                println!("Created {} -> {}", task_id, path.display());
+                println!("Created epic {} -> {}", task_id, path.display());
             }
         }
         Command::AddDiscovered {
@@ -4920,7 +10433,7 @@ fn main() -> Result<()> {
                     let repo_root = repo_root_from_backlog(&backlog_dir);
                     let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
                     let initiative = ensure_branch_initiative(&repo_root, &branch)?;
-                    next_namespaced_task_id(&tasks, &initiative)
+                    reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)?
                 }
             };
             let labels = split_csv(&labels);
@@ -5022,16 +10535,23 @@ fn main() -> Result<()> {
         Command::Render { .. } => {
             unreachable!("render handled before backlog resolution");
         }
-        Command::Gantt { start, zoom } => {
-            let text = plantuml_gantt(&tasks, start.as_deref(), None, zoom, None, true);
-            print!("{}", text);
+        Command::Remote { .. } => {
+            unreachable!("remote handled before backlog resolution");
+        }
+        Command::Gantt {
+            start,
+            zoom,
+            format,
+        } => {
+            print!("{}", gantt_text(&tasks, start.as_deref(), zoom, format));
         }
         Command::GanttFile {
             start,
             zoom,
+            format,
             output,
         } => {
-            let text = plantuml_gantt(&tasks, start.as_deref(), None, zoom, None, true);
+            let text = gantt_text(&tasks, start.as_deref(), zoom, format);
             let path = write_text_file(&output, &text)?;
             println!("{}", path.display());
         }
@@ -5043,23 +10563,7 @@ fn main() -> Result<()> {
             plantuml_jar,
         } => {
             let text = plantuml_gantt(&tasks, start.as_deref(), None, zoom, None, true);
-            let cmd = match plantuml_cmd {
-                Some(cmd) => {
-                    // `shell_words` is Unix-shell oriented and treats backslashes as escapes,
-                    // which breaks Windows strings like `cmd /C C:\path\plantuml.cmd`.
-                    // On Windows, keep parsing simple and predictable: whitespace-split.
-                    if cfg!(windows) {
-                        Some(
-                            cmd.split_whitespace()
-                                .map(|part| part.to_string())
-                                .collect(),
-                        )
-                    } else {
-                        Some(shell_words::split(&cmd).map_err(anyhow::Error::msg)?)
-                    }
-                }
-                None => None,
-            };
+            let cmd = parse_plantuml_cmd(plantuml_cmd)?;
             let svg =
                 render_plantuml_svg(&text, cmd, plantuml_jar.as_deref(), None).map_err(|err| {
                     match err {
@@ -5074,6 +10578,43 @@ fn main() -> Result<()> {
                 print!("{}", svg);
             }
         }
+        Command::CriticalPath { json } => {
+            let report = critical_path(&tasks, None);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            if report.tasks.is_empty() {
+                println!("No open tasks to schedule");
+            } else {
+                println!(
+                    "Finish in {} days: {}",
+                    format_days(report.total_days),
+                    report.critical_path.join(" -> ")
+                );
+                for task in &report.tasks {
+                    println!(
+                        "{} {:<10} start {:>5} finish {:>5} slack {:>5}{} | {}",
+                        if task.critical { "*" } else { " " },
+                        task.id,
+                        format_days(task.earliest_start),
+                        format_days(task.earliest_finish),
+                        format_days(task.slack),
+                        if task.estimated { "" } else { " (no estimate)" },
+                        task.title
+                    );
+                }
+            }
+            if !report.unscheduled.is_empty() {
+                println!(
+                    "Unscheduled (dependency cycle): {}",
+                    report.unscheduled.join(", ")
+                );
+            }
+        }
+        Command::Init { .. } => {
+            unreachable!("init handled before backlog resolution");
+        }
         Command::Quickstart { .. } => {
             unreachable!("quickstart handled before backlog resolution");
         }
@@ -5092,26 +10633,296 @@ fn main() -> Result<()> {
         Command::Doctor { .. } => {
             unreachable!("doctor handled before backlog resolution");
         }
+        Command::SelfUpdate { .. } => {
+            unreachable!("self-update handled before backlog resolution");
+        }
+        Command::Daemon { .. } => {
+            unreachable!("daemon handled before backlog resolution");
+        }
         Command::Migrate { .. } => {
             unreachable!("migrate handled before backlog resolution");
         }
         Command::Archive {
+            command: Some(ArchiveCommand::Search { query, limit, json }),
+            ..
+        } => {
+            let mut matches = search_archive(&backlog_dir, &query)?;
+            if let Some(limit) = limit {
+                matches.truncate(limit);
+            }
+            if json {
+                let payload: Vec<serde_json::Value> = matches
+                    .iter()
+                    .map(|entry| {
+                        serde_json::json!({
+                            "id": entry.id,
+                            "title": entry.title,
+                            "status": entry.status,
+                            "priority": entry.priority,
+                            "labels": entry.labels,
+                            "assignee": entry.assignee,
+                            "updated_date": entry.updated_date,
+                            "path": archive_root_for_root(&backlog_dir).join(&entry.path),
+                        })
+                    })
+                    .collect();
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+            } else if matches.is_empty() {
+                println!("No archived tasks match '{}'", query);
+            } else {
+                for entry in &matches {
+                    println!(
+                        "{} | {} | {} | archive/{}",
+                        entry.id, entry.status, entry.title, entry.path
+                    );
+                }
+            }
+        }
+        Command::Remove {
+            task_id,
+            cancel,
+            dry_run,
+            json,
+        } => {
+            let mode = if cancel {
+                RemoveMode::Cancel
+            } else {
+                RemoveMode::Trash
+            };
+            let all_tasks = load_tasks_with_archive(&backlog_dir);
+            let report = match remove_task(&backlog_dir, &all_tasks, &task_id, mode, !dry_run) {
+                Ok(report) => report,
+                Err(err @ (RemoveError::NotFound(_) | RemoveError::AlreadyTrashed(..))) => {
+                    die(&err.to_string())
+                }
+                Err(err) => return Err(err.into()),
+            };
+            if report.applied {
+                for edit in &report.stripped {
+                    audit_event(
+                        &backlog_dir,
+                        "dependency_remove",
+                        Some(&edit.task_id),
+                        serde_json::json!({
+                            "field": edit.field,
+                            "value": report.task_id,
+                            "add": false,
+                            "reason": "task_remove",
+                        }),
+                    )?;
+                }
+                audit_event(
+                    &backlog_dir,
+                    "task_remove",
+                    Some(&report.task_id),
+                    serde_json::json!({
+                        "mode": mode.as_str(),
+                        "path": report.path,
+                        "trash_path": report.trash_path,
+                    }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                let (moved, cancelled, removed) = if report.applied {
+                    ("Moved", "Cancelled", "Removed")
+                } else {
+                    ("Would move", "Would cancel", "Would remove")
+                };
+                match &report.trash_path {
+                    Some(trash_path) => {
+                        println!("{} {} to {}", moved, report.task_id, trash_path.display())
+                    }
+                    None => println!("{} {}", cancelled, report.task_id),
+                }
+                for edit in &report.stripped {
+                    println!(
+                        "{} {} from {} {}",
+                        removed, report.task_id, edit.task_id, edit.field
+                    );
+                }
+                if !report.referenced_by.is_empty() {
+                    logging::warn(format!(
+                        "{} is still referenced by relationships on: {}",
+                        report.task_id,
+                        report.referenced_by.join(", ")
+                    ));
+                }
+            }
+        }
+        Command::Move {
+            old_id,
+            new_id,
+            body,
+            dry_run,
+            json,
+        } => {
+            let report = move_task(
+                &backlog_dir,
+                &old_id,
+                &new_id,
+                MoveTaskOptions {
+                    apply: !dry_run,
+                    rewrite_body: body,
+                },
+            )?;
+            if report.apply {
+                audit_event(
+                    &backlog_dir,
+                    "task_move",
+                    Some(&report.new_id),
+                    serde_json::json!({
+                        "from": report.old_id,
+                        "to": report.new_id,
+                        "path": report.new_path.as_ref().unwrap_or(&report.path),
+                        "touched": report.touched.len(),
+                        "body": report.rewrite_body,
+                    }),
+                )?;
+                refresh_index_best_effort(&backlog_dir);
+                maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            }
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                let (moved, rewrote) = if report.apply {
+                    ("Moved", "Rewrote")
+                } else {
+                    ("Would move", "Would rewrite")
+                };
+                println!("{} {} -> {}", moved, report.old_id, report.new_id);
+                match &report.new_path {
+                    Some(new_path) => {
+                        println!("  {} -> {}", report.path.display(), new_path.display())
+                    }
+                    None => println!("  {}", report.path.display()),
+                }
+                if let Some(dir) = &report.attachments_dir {
+                    println!("  attachments -> {}", dir.display());
+                }
+                for touched in &report.touched {
+                    println!(
+                        "{} {} ({})",
+                        rewrote,
+                        touched.task_id,
+                        touched.path.display()
+                    );
+                }
+                if !report.apply {
+                    println!("Dry-run: re-run without --dry-run to write changes.");
+                }
+            }
+        }
+        Command::Split {
+            task_id,
+            into,
+            json,
+        } => {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+            let initiative = ensure_branch_initiative(&repo_root, &branch)?;
+            let all_tasks = load_tasks_with_archive(&backlog_dir);
+            let report = match split_task(&backlog_dir, &all_tasks, &task_id, &initiative, &into) {
+                Ok(report) => report,
+                Err(err @ (SplitError::Task(_) | SplitError::Io(_))) => return Err(err.into()),
+                Err(err) => die(&err.to_string()),
+            };
+            for part in &report.parts {
+                audit_event(
+                    &backlog_dir,
+                    "add_task",
+                    Some(&part.id),
+                    serde_json::json!({
+                        "title": part.title,
+                        "status": "To Do",
+                        "split_from": report.task_id,
+                    }),
+                )?;
+            }
+            audit_event(
+                &backlog_dir,
+                "task_split",
+                Some(&report.task_id),
+                serde_json::json!({
+                    "parts": report.parts.iter().map(|part| &part.id).collect::<Vec<_>>(),
+                    "previous_kind": report.previous_kind,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+            } else {
+                println!(
+                    "Split {} into {} subtasks (now an epic)",
+                    report.task_id,
+                    report.parts.len()
+                );
+                for part in &report.parts {
+                    println!("  {} | {} -> {}", part.id, part.title, part.path.display());
+                }
+            }
+        }
+        Command::Archive {
+            command: None,
             before,
             status,
+            chunk_size,
+            resume,
+            no_verify,
             json,
         } => {
             let before_date = parse_before_date(&before)?;
             let statuses = split_list(status.as_slice());
-            let result = archive_tasks(
-                &backlog_dir,
-                &tasks,
-                &ArchiveOptions {
-                    before: before_date,
-                    statuses: statuses.clone(),
-                },
-            )?;
+            // Progress goes to stderr so stdout keeps the final report: `--progress` events when
+            // asked for, otherwise one info line per chunk (JSON with `--log-format json`).
+            let progress = |step: &ArchiveProgress| {
+                if progress::enabled() {
+                    progress::emit(&ProgressEvent {
+                        op: "archive",
+                        phase: "move",
+                        done: step.done,
+                        total: step.total,
+                    });
+                } else {
+                    logging::info(format!(
+                        "archive: chunk {}/{} ({}/{} tasks, {} archived, {} skipped)",
+                        step.chunk, step.chunks, step.done, step.total, step.archived, step.skipped
+                    ));
+                }
+            };
+            let result = if resume {
+                match resume_archive(&backlog_dir, chunk_size, progress)? {
+                    Some(result) => result,
+                    None => die("No interrupted archive run to resume"),
+                }
+            } else {
+                let moves = plan_archive(
+                    &backlog_dir,
+                    &tasks,
+                    &ArchiveOptions {
+                        before: before_date,
+                        statuses: statuses.clone(),
+                    },
+                )?;
+                match run_archive(&backlog_dir, moves, chunk_size, progress) {
+                    Ok(result) => result,
+                    Err(err @ ArchiveError::Pending(_)) => die(&err.to_string()),
+                    Err(err) => return Err(err.into()),
+                }
+            };
             refresh_index_best_effort(&backlog_dir);
+            refresh_archive_index_best_effort(&backlog_dir);
             maybe_auto_checkpoint(&backlog_dir, auto_checkpoint, auto_session);
+            let cleanup = delete_archived_objects(&backlog_dir, &result.archived);
+            for warning in &cleanup.warnings {
+                logging::warn(warning);
+            }
+            let attachments_deleted = cleanup.deleted;
+            let verification = (!no_verify).then(|| verify_archive(&backlog_dir, &result));
             if json {
                 let payload = serde_json::json!({
                     "archived": result.archived,
@@ -5124,11 +10935,16 @@ fn main() -> Result<()> {
                             .collect::<Vec<_>>()
                     } else {
                         statuses
-                    }
+                    },
+                    "verification": verification,
+                    "attachments_deleted": attachments_deleted,
                 });
                 println!("{}", serde_json::to_string_pretty(&payload)?);
             } else {
                 println!("Archived {} tasks", result.archived.len());
+                if !attachments_deleted.is_empty() {
+                    println!("Deleted {} attachment object(s)", attachments_deleted.len());
+                }
                 if !result.skipped.is_empty() {
                     println!("Skipped: {}", result.skipped.join(", "));
                 }
@@ -5139,18 +10955,149 @@ fn main() -> Result<()> {
                     );
                 } else {
                     println!(
-                        "Status filter: {}",
-                        split_list(status.as_slice()).join(", ")
+                        "Status filter: {}",
+                        split_list(status.as_slice()).join(", ")
+                    );
+                }
+                println!("Archive: {}", result.archive_dir.display());
+                if let Some(verification) = verification.as_ref() {
+                    if verification.ok {
+                        println!("Verified {} moved file(s)", verification.checked);
+                    } else {
+                        print_archive_verification(verification);
+                    }
+                }
+            }
+            if verification
+                .as_ref()
+                .is_some_and(|verification| !verification.ok)
+            {
+                std::process::exit(1);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn attachment_location(attachment: &Attachment) -> String {
+    match (&attachment.url, &attachment.path) {
+        (Some(url), _) => url.clone(),
+        (None, Some(path)) => path.display().to_string(),
+        (None, None) => String::new(),
+    }
+}
+
+fn handle_daemon_command(repo_root: &Path, command: &DaemonCommand) -> Result<()> {
+    let platform = ServicePlatform::current();
+    let program = std::env::current_exe()?;
+    match command {
+        DaemonCommand::InstallService {
+            interval_ms,
+            no_start,
+            dry_run,
+            json,
+        } => {
+            let spec = service_spec(platform, repo_root, &program, *interval_ms)?;
+            if *dry_run {
+                if *json {
+                    println!(
+                        "{}",
+                        serde_json::to_string_pretty(&serde_json::json!({
+                            "service": spec,
+                            "definition": render_definition(&spec),
+                        }))?
+                    );
+                } else {
+                    println!("# {}", spec.definition_path.display());
+                    print!("{}", render_definition(&spec));
+                }
+                return Ok(());
+            }
+            let commands = install_service(&spec, !*no_start)?;
+            if *json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "service": spec,
+                        "started": !*no_start,
+                        "commands": commands,
+                    }))?
+                );
+            } else {
+                println!("Installed {} ({})", spec.name, spec.platform.as_str());
+                println!("Definition: {}", spec.definition_path.display());
+                println!("Log: {}", spec.log_path.display());
+            }
+        }
+        DaemonCommand::UninstallService { json } => {
+            let spec = service_spec(platform, repo_root, &program, DEFAULT_WATCH_INTERVAL_MS)?;
+            let removed = uninstall_service(&spec)?;
+            if *json {
+                println!(
+                    "{}",
+                    serde_json::to_string_pretty(&serde_json::json!({
+                        "name": spec.name,
+                        "removed": removed,
+                    }))?
+                );
+            } else if removed {
+                println!("Removed {}", spec.name);
+            } else {
+                println!("{} is not installed", spec.name);
+            }
+        }
+        DaemonCommand::Status { json } => {
+            let spec = service_spec(platform, repo_root, &program, DEFAULT_WATCH_INTERVAL_MS)?;
+            let status = service_status(&spec);
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&status)?);
+            } else if !status.installed {
+                println!("{} is not installed", status.name);
+            } else {
+                println!(
+                    "{}: {}",
+                    status.name,
+                    match status.running {
+                        Some(true) => "running",
+                        Some(false) => "stopped",
+                        None => "unknown",
+                    }
+                );
+                println!("Definition: {}", status.definition_path.display());
+                println!("Log: {}", status.log_path.display());
+                if let Some(line) = status.last_log_line.as_deref() {
+                    println!(
+                        "Last log line ({}): {}",
+                        status.log_updated.as_deref().unwrap_or("-"),
+                        line
                     );
                 }
-                println!("Archive: {}", result.archive_dir.display());
             }
         }
     }
-
     Ok(())
 }
 
+fn print_archive_verification(verification: &ArchiveVerification) {
+    println!("Verification failed:");
+    for path in &verification.unparsable {
+        println!("- unparsable: {}", path);
+    }
+    for id in &verification.missing {
+        println!("- missing from archive: {}", id);
+    }
+    for id in &verification.left_behind {
+        println!("- still in tasks/: {}", id);
+    }
+    for id in &verification.not_indexed {
+        println!("- not in archive index: {}", id);
+    }
+    for issue in &verification.index_issues {
+        println!("- index {}", issue);
+    }
+}
+
 fn to_list(values: &[String]) -> Option<Vec<String>> {
     let items = split_list(values);
     if items.is_empty() {
@@ -5179,6 +11126,24 @@ fn split_csv(value: &str) -> Vec<String> {
         .collect()
 }
 
+/// `--since` / `--until` as a window; exits with the parse error on bad input.
+fn duration_stats_text(stats: Option<&DurationStats>) -> String {
+    match stats {
+        Some(stats) => format!(
+            "p50={:.1}d p90={:.1}d (n={})",
+            stats.p50_hours / 24.0,
+            stats.p90_hours / 24.0,
+            stats.count
+        ),
+        None => "-".to_string(),
+    }
+}
+
+fn parse_time_window(since: Option<&str>, until: Option<&str>) -> TimeWindow {
+    TimeWindow::parse(since, until, Local::now().naive_local())
+        .unwrap_or_else(|err| die(&err.to_string()))
+}
+
 fn build_task_sections(
     description: Option<String>,
     acceptance_criteria: Option<String>,
@@ -5261,17 +11226,13 @@ fn emit_bulk_result(updated: &[String], missing: &[String], json: bool) {
 }
 
 fn parse_before_date(value: &str) -> Result<NaiveDate> {
-    let trimmed = value.trim();
-    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
-        return Ok(date);
-    }
-    if let Some(days) = trimmed.strip_suffix('d') {
-        if let Ok(days) = days.parse::<i64>() {
-            let date = Local::now().date_naive() - Duration::days(days);
-            return Ok(date);
-        }
-    }
-    Err(anyhow::anyhow!("Invalid date format: {}", value))
+    parse_archive_before(value, Local::now().naive_local()).ok_or_else(|| {
+        anyhow::anyhow!(
+            "Invalid date format: {} (expected {})",
+            value,
+            TIME_REF_FORMATS
+        )
+    })
 }
 
 fn prompts_disabled() -> bool {
@@ -5299,10 +11260,10 @@ fn maybe_prompt_migration(resolution: &BacklogResolution) -> Result<PathBuf> {
         return Ok(resolution.state_root.clone());
     }
     if prompts_disabled() || !io::stdin().is_terminal() {
-        eprintln!(
+        logging::warn(format!(
             "Legacy repo layout detected at {}. Run `workmesh --root . migrate --to split` to move to tasks/ + .workmesh/.",
             resolution.state_root.display()
-        );
+        ));
         return Ok(resolution.state_root.clone());
     }
     if confirm_migration(&resolution.state_root)? {
@@ -5314,6 +11275,228 @@ fn maybe_prompt_migration(resolution: &BacklogResolution) -> Result<PathBuf> {
     Ok(resolution.state_root.clone())
 }
 
+/// Use `value` when given; otherwise ask for it on an interactive terminal.
+fn wizard_field(label: &str, value: Option<String>) -> Result<String> {
+    if let Some(value) = value {
+        return Ok(value);
+    }
+    if prompts_disabled() || !io::stdin().is_terminal() {
+        return Ok(String::new());
+    }
+    eprint!("{}: ", label);
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(input.trim().to_string())
+}
+
+/// Ask a yes/no question; an empty answer takes `default`.
+fn wizard_confirm(label: &str, default: bool) -> Result<bool> {
+    eprint!("{} [{}] ", label, if default { "Y/n" } else { "y/N" });
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    Ok(match input.trim().to_lowercase().as_str() {
+        "" => default,
+        value => matches!(value, "y" | "yes"),
+    })
+}
+
+/// `WORKMESH_ROOT` export (or unset) line for `workmesh use`.
+fn shell_root_snippet(shell: ShellKind, root: Option<&str>) -> String {
+    match (shell, root) {
+        (ShellKind::Sh, Some(root)) => {
+            format!("export {}='{}'", ROOT_ENV, root.replace('\'', "'\\''"))
+        }
+        (ShellKind::Sh, None) => format!("unset {}", ROOT_ENV),
+        (ShellKind::Fish, Some(root)) => format!(
+            "set -gx {} '{}'",
+            ROOT_ENV,
+            root.replace('\\', "\\\\").replace('\'', "\\'")
+        ),
+        (ShellKind::Fish, None) => format!("set -e {}", ROOT_ENV),
+        (ShellKind::Powershell, Some(root)) => {
+            format!("$env:{} = '{}'", ROOT_ENV, root.replace('\'', "''"))
+        }
+        (ShellKind::Powershell, None) => format!("Remove-Item Env:{}", ROOT_ENV),
+    }
+}
+
+fn update_watchers(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task_id: &str,
+    as_user: Option<String>,
+    watch: bool,
+    json: bool,
+) -> Result<()> {
+    let user = as_user
+        .or_else(|| std::env::var("USER").ok())
+        .map(|value| normalize_user(&value))
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| die("--as is required when $USER is not set"));
+    let task =
+        find_task(tasks, task_id).unwrap_or_else(|| die(&format!("Task not found: {}", task_id)));
+    let path = task
+        .file_path
+        .as_ref()
+        .unwrap_or_else(|| die(&format!("Task file missing: {}", task.id)));
+    let mut watchers = task_watchers(task);
+    let changed = if watch {
+        let added = !watchers.contains(&user);
+        if added {
+            watchers.push(user.clone());
+        }
+        added
+    } else {
+        let before = watchers.len();
+        watchers.retain(|entry| *entry != user);
+        watchers.len() != before
+    };
+    if changed {
+        set_list_field(path, WATCHERS_FIELD, watchers.clone())?;
+        audit_event(
+            backlog_dir,
+            if watch { "watch" } else { "unwatch" },
+            Some(&task.id),
+            serde_json::json!({ "user": user }),
+        )?;
+        refresh_index_best_effort(backlog_dir);
+    }
+    if json {
+        let payload = serde_json::json!({
+            "id": task.id,
+            "watchers": watchers,
+            "changed": changed,
+        });
+        println!("{}", serde_json::to_string_pretty(&payload)?);
+    } else if !changed {
+        println!(
+            "{} {} watching {}",
+            user,
+            if watch { "is already" } else { "was not" },
+            task.id
+        );
+    } else {
+        println!(
+            "{} {} {}",
+            user,
+            if watch {
+                "now watches"
+            } else {
+                "stopped watching"
+            },
+            task.id
+        );
+    }
+    Ok(())
+}
+
+fn print_milestones(command: &MilestoneCommand, report: &MilestoneReport) {
+    if report.milestones.is_empty() {
+        println!("No milestones (set `milestone` in task front matter).");
+        return;
+    }
+    let finish = |milestone: &MilestoneProgress| match (
+        milestone.remaining,
+        milestone.projected_finish.as_deref(),
+    ) {
+        (0, Some(date)) => format!("finished {}", date),
+        (0, None) => "finished".to_string(),
+        (_, Some(date)) => format!("projected {}", date),
+        (_, None) => "no velocity".to_string(),
+    };
+    for milestone in &report.milestones {
+        match command {
+            MilestoneCommand::List { .. } => println!(
+                "{} | {} tasks | {:.0}% | {}",
+                milestone.name,
+                milestone.total,
+                milestone.percent_complete,
+                finish(milestone)
+            ),
+            _ => {
+                println!(
+                    "{} | {:.0}% | {}/{} done, {} remaining | {} per week ({}) | {}",
+                    milestone.name,
+                    milestone.percent_complete,
+                    milestone.done,
+                    milestone.total - milestone.cancelled,
+                    milestone.remaining,
+                    milestone.velocity_per_week,
+                    match milestone.velocity_source {
+                        VelocitySource::Milestone => "milestone",
+                        VelocitySource::Backlog => "backlog",
+                    },
+                    finish(milestone)
+                );
+                for task in &milestone.tasks {
+                    println!(
+                        "  {} | {} | {} | {}",
+                        task.id, task.status, task.priority, task.title
+                    );
+                }
+            }
+        }
+    }
+    if !matches!(command, MilestoneCommand::Show { .. }) && report.unassigned > 0 {
+        println!("{} open task(s) without a milestone", report.unassigned);
+    }
+}
+
+fn format_progress(progress: Option<f64>) -> String {
+    match progress {
+        Some(value) => format!("{:.0}%", value * 100.0),
+        None => "no data".to_string(),
+    }
+}
+
+fn parse_plantuml_cmd(cmd: Option<String>) -> Result<Option<Vec<String>>> {
+    let Some(cmd) = cmd else {
+        return Ok(None);
+    };
+    // `shell_words` is Unix-shell oriented and treats backslashes as escapes,
+    // which breaks Windows strings like `cmd /C C:\path\plantuml.cmd`.
+    // On Windows, keep parsing simple and predictable: whitespace-split.
+    if cfg!(windows) {
+        Ok(Some(
+            cmd.split_whitespace()
+                .map(|part| part.to_string())
+                .collect(),
+        ))
+    } else {
+        Ok(Some(shell_words::split(&cmd).map_err(anyhow::Error::msg)?))
+    }
+}
+
+fn gantt_text(tasks: &[Task], start: Option<&str>, zoom: i32, format: GanttFormatArg) -> String {
+    match format {
+        GanttFormatArg::Plantuml => plantuml_gantt(tasks, start, None, zoom, None, true),
+        GanttFormatArg::Mermaid => mermaid_gantt(tasks, start, None, None, true),
+    }
+}
+
+/// Whole days print without a fraction (`3`), partial ones with one decimal (`2.5`).
+fn format_days(days: f64) -> String {
+    if days.fract().abs() < 0.05 {
+        format!("{:.0}", days)
+    } else {
+        format!("{:.1}", days)
+    }
+}
+
+fn print_tree_node(node: &TreeNode, depth: usize) {
+    println!(
+        "{}{} [{}] {} ({})",
+        "  ".repeat(depth),
+        node.id,
+        node.kind,
+        node.title,
+        node.status
+    );
+    for child in &node.children {
+        print_tree_node(child, depth + 1);
+    }
+}
+
 fn confirm_migration(path: &Path) -> Result<bool> {
     eprint!(
         "Legacy repo layout found at {}. Migrate to tasks/ + .workmesh/? [y/N] ",
@@ -6124,7 +12307,7 @@ fn handle_workstream_command(
                         println!("Updated context in {}", target_path.display());
                     }
                     for warning in warnings {
-                        println!("warning: {}", warning);
+                        logging::warn(warning);
                     }
                 }
 
@@ -6509,7 +12692,7 @@ fn handle_workstream_command(
                     println!("Created git worktree at {}", created.path);
                 }
                 for warning in warnings {
-                    println!("warning: {}", warning);
+                    logging::warn(warning);
                 }
             }
         }
@@ -6784,7 +12967,7 @@ fn handle_worktree_command(repo_root: &Path, home: &Path, command: WorktreeComma
                     println!("Seeded context for new worktree.");
                 }
                 for warning in warnings {
-                    println!("warning: {}", warning);
+                    logging::warn(warning);
                 }
             }
         }
@@ -6815,11 +12998,8 @@ fn handle_worktree_command(repo_root: &Path, home: &Path, command: WorktreeComma
                 println!("source_branch: {}", outcome.plan.source_branch);
                 println!("target_branch: {}", outcome.plan.target_branch);
                 println!("dirty: {}", outcome.plan.dirty);
-                if !outcome.plan.warnings.is_empty() {
-                    println!("warnings:");
-                    for warning in &outcome.plan.warnings {
-                        println!("- {}", warning);
-                    }
+                for warning in &outcome.plan.warnings {
+                    logging::warn(warning);
                 }
                 println!("plan:");
                 for action in &outcome.plan.actions {
@@ -7058,6 +13238,9 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                 resolve_worktrees_dir_with_source(repo_root);
             let (auto_session_default, auto_session_default_source) =
                 resolve_auto_session_default_with_source(repo_root);
+            let (note_autolink, note_autolink_source) =
+                resolve_note_autolink_with_source(repo_root);
+            let (time_display, time_display_source) = resolve_time_display_with_source(repo_root);
             let (task_validation, task_validation_sources) =
                 resolve_task_validation_rules_with_source(repo_root);
 
@@ -7068,19 +13251,31 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                     "worktrees_default": worktrees_default,
                     "worktrees_dir": worktrees_dir.as_ref().map(|p| p.to_string_lossy().to_string()),
                     "auto_session_default": auto_session_default,
+                    "note_autolink": note_autolink,
+                    "time_display": time_display.as_str(),
                     "task_require_description": task_validation.require_description,
                     "task_require_acceptance_criteria": task_validation.require_acceptance_criteria,
                     "task_require_definition_of_done": task_validation.require_definition_of_done,
                     "task_require_outcome_based_definition_of_done": task_validation.require_outcome_based_definition_of_done,
+                    "task_require_implementation_notes": task_validation.require_implementation_notes,
+                    "task_require_acceptance_criteria_checked": task_validation.require_acceptance_criteria_checked,
+                    "task_require_linked_commit": task_validation.require_linked_commit,
+                    "task_require_review_approval": task_validation.require_review_approval,
                 },
                 "sources": {
                     "worktrees_default": worktrees_default_source,
                     "worktrees_dir": worktrees_dir_source,
                     "auto_session_default": auto_session_default_source,
+                    "note_autolink": note_autolink_source,
+                    "time_display": time_display_source,
                     "task_require_description": task_validation_sources.require_description,
                     "task_require_acceptance_criteria": task_validation_sources.require_acceptance_criteria,
                     "task_require_definition_of_done": task_validation_sources.require_definition_of_done,
                     "task_require_outcome_based_definition_of_done": task_validation_sources.require_outcome_based_definition_of_done,
+                    "task_require_implementation_notes": task_validation_sources.require_implementation_notes,
+                    "task_require_acceptance_criteria_checked": task_validation_sources.require_acceptance_criteria_checked,
+                    "task_require_linked_commit": task_validation_sources.require_linked_commit,
+                    "task_require_review_approval": task_validation_sources.require_review_approval,
                 },
                 "paths": {
                     "project_default": workmesh_core::config::config_path(repo_root),
@@ -7118,6 +13313,21 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                             value
                         );
                     }
+                    if let Some(value) = config.task_require_implementation_notes {
+                        println!("- task_require_implementation_notes: {} (project)", value);
+                    }
+                    if let Some(value) = config.task_require_acceptance_criteria_checked {
+                        println!(
+                            "- task_require_acceptance_criteria_checked: {} (project)",
+                            value
+                        );
+                    }
+                    if let Some(value) = config.task_require_linked_commit {
+                        println!("- task_require_linked_commit: {} (project)", value);
+                    }
+                    if let Some(value) = config.task_require_review_approval {
+                        println!("- task_require_review_approval: {} (project)", value);
+                    }
                     if let Some(value) = config.root_dir.as_ref() {
                         println!("- root_dir: {} (project, deprecated)", value);
                     }
@@ -7142,6 +13352,26 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                     task_validation.require_outcome_based_definition_of_done,
                     task_validation_sources.require_outcome_based_definition_of_done
                 );
+                println!(
+                    "- task_require_implementation_notes: {} ({})",
+                    task_validation.require_implementation_notes,
+                    task_validation_sources.require_implementation_notes
+                );
+                println!(
+                    "- task_require_acceptance_criteria_checked: {} ({})",
+                    task_validation.require_acceptance_criteria_checked,
+                    task_validation_sources.require_acceptance_criteria_checked
+                );
+                println!(
+                    "- task_require_linked_commit: {} ({})",
+                    task_validation.require_linked_commit,
+                    task_validation_sources.require_linked_commit
+                );
+                println!(
+                    "- task_require_review_approval: {} ({})",
+                    task_validation.require_review_approval,
+                    task_validation_sources.require_review_approval
+                );
                 if let Some(dir) = worktrees_dir.as_ref() {
                     println!(
                         "- worktrees_dir: {} ({})",
@@ -7162,6 +13392,15 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                         auto_session_default_source
                     );
                 }
+                println!(
+                    "- note_autolink: {} ({})",
+                    note_autolink, note_autolink_source
+                );
+                println!(
+                    "- time_display: {} ({})",
+                    time_display.as_str(),
+                    time_display_source
+                );
                 if let Some(project) = project.as_ref() {
                     println!();
                     println!(
@@ -7214,6 +13453,18 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                     });
                     config.auto_session_default = Some(parsed);
                 }
+                "note_autolink" => {
+                    let parsed = parse_boolish(value).unwrap_or_else(|| {
+                        die("Invalid bool value for note_autolink (expected true/false/1/0)");
+                    });
+                    config.note_autolink = Some(parsed);
+                }
+                "time_display" => {
+                    let parsed = TimeDisplay::parse(value).unwrap_or_else(|| {
+                        die("Invalid value for time_display (expected local/utc)");
+                    });
+                    config.time_display = Some(parsed.as_str().to_string());
+                }
                 "tasks_root" => {
                     if value.is_empty() {
                         die("tasks_root cannot be blank (use config unset to remove)");
@@ -7250,6 +13501,30 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                     });
                     config.task_require_outcome_based_definition_of_done = Some(parsed);
                 }
+                "task_require_implementation_notes" => {
+                    let parsed = parse_boolish(value).unwrap_or_else(|| {
+                        die("Invalid bool value for task_require_implementation_notes (expected true/false/1/0)");
+                    });
+                    config.task_require_implementation_notes = Some(parsed);
+                }
+                "task_require_acceptance_criteria_checked" => {
+                    let parsed = parse_boolish(value).unwrap_or_else(|| {
+                        die("Invalid bool value for task_require_acceptance_criteria_checked (expected true/false/1/0)");
+                    });
+                    config.task_require_acceptance_criteria_checked = Some(parsed);
+                }
+                "task_require_linked_commit" => {
+                    let parsed = parse_boolish(value).unwrap_or_else(|| {
+                        die("Invalid bool value for task_require_linked_commit (expected true/false/1/0)");
+                    });
+                    config.task_require_linked_commit = Some(parsed);
+                }
+                "task_require_review_approval" => {
+                    let parsed = parse_boolish(value).unwrap_or_else(|| {
+                        die("Invalid bool value for task_require_review_approval (expected true/false/1/0)");
+                    });
+                    config.task_require_review_approval = Some(parsed);
+                }
                 "root_dir" => {
                     if value.is_empty() {
                         die("root_dir cannot be blank (use config unset to remove)");
@@ -7299,6 +13574,8 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                 "worktrees_default" => config.worktrees_default = None,
                 "worktrees_dir" => config.worktrees_dir = None,
                 "auto_session_default" => config.auto_session_default = None,
+                "note_autolink" => config.note_autolink = None,
+                "time_display" => config.time_display = None,
                 "tasks_root" => config.tasks_root = None,
                 "state_root" => config.state_root = None,
                 "task_require_description" => config.task_require_description = None,
@@ -7309,6 +13586,14 @@ fn handle_config_command(repo_root: &Path, command: &ConfigCommand) -> Result<()
                 "task_require_outcome_based_definition_of_done" => {
                     config.task_require_outcome_based_definition_of_done = None
                 }
+                "task_require_implementation_notes" => {
+                    config.task_require_implementation_notes = None
+                }
+                "task_require_acceptance_criteria_checked" => {
+                    config.task_require_acceptance_criteria_checked = None
+                }
+                "task_require_linked_commit" => config.task_require_linked_commit = None,
+                "task_require_review_approval" => config.task_require_review_approval = None,
                 "root_dir" => config.root_dir = None,
                 "do_not_migrate" => config.do_not_migrate = None,
                 _ => die(&format!("Unknown config key: {}", key)),
@@ -7498,6 +13783,28 @@ fn handle_context_command(
                 println!("(no {} to clear)", state_key);
             }
         }
+        ContextCommand::Pack { budget, epic, json } => {
+            let Some(budget) = parse_token_budget(&budget) else {
+                die(&format!(
+                    "Invalid budget: {} (expected 4000, 4000-tokens, or 4k)",
+                    budget
+                ));
+            };
+            let tasks = load_tasks(backlog_dir);
+            let context = infer_context_state(repo_root, backlog_dir);
+            let pack = build_context_pack(
+                backlog_dir,
+                &tasks,
+                context.as_ref(),
+                &resolve_task_validation_rules(repo_root),
+                &ContextPackOptions { budget, epic },
+            );
+            if json {
+                println!("{}", serde_json::to_string_pretty(&pack)?);
+            } else {
+                print!("{}", render_context_pack_markdown(&pack));
+            }
+        }
     }
 
     Ok(())
@@ -7556,11 +13863,8 @@ fn handle_migrate_workflow(root: &Path, command: &MigrateCommand) -> Result<()>
                         step.reason
                     );
                 }
-                if !plan.warnings.is_empty() {
-                    println!("Warnings:");
-                    for warning in plan.warnings {
-                        println!("- {}", warning);
-                    }
+                for warning in plan.warnings {
+                    logging::warn(warning);
                 }
             }
         }
@@ -7610,11 +13914,8 @@ fn handle_migrate_workflow(root: &Path, command: &MigrateCommand) -> Result<()>
                         println!("- {}", path);
                     }
                 }
-                if !result.warnings.is_empty() {
-                    println!("Warnings:");
-                    for warning in result.warnings {
-                        println!("- {}", warning);
-                    }
+                for warning in result.warnings {
+                    logging::warn(warning);
                 }
             }
         }
@@ -7655,14 +13956,229 @@ fn infer_context_state(repo_root: &Path, backlog_dir: &Path) -> Option<ContextSt
         scope.mode = ContextScopeMode::Epic;
         scope.epic_id = Some(epic_id);
     }
-    Some(ContextState {
-        version: 1,
-        project_id: inferred_project,
-        objective: None,
-        workstream_id: None,
-        scope,
-        updated_at: None,
-    })
+    Some(ContextState {
+        version: 1,
+        project_id: inferred_project,
+        objective: None,
+        workstream_id: None,
+        scope,
+        updated_at: None,
+    })
+}
+
+fn pick_label(task: &Task) -> String {
+    let mut label = format!("{} | {} | {}", task.id, task.status, task.title);
+    if !task.labels.is_empty() {
+        label.push_str(&format!(" [{}]", task.labels.join(", ")));
+    }
+    label
+}
+
+/// Map `--then` words (`set-status Done`, `label-add ux`, `note text...`) onto a bulk command.
+fn pick_then_command(words: &[String], tasks: Vec<String>, json: bool) -> BulkCommand {
+    let action = words[0].replace('_', "-").to_lowercase();
+    let rest = &words[1..];
+    let joined = rest.join(" ");
+    let single = |name: &str| -> String {
+        if joined.trim().is_empty() {
+            die(&format!("--then {} needs a {}", action, name));
+        }
+        joined.clone()
+    };
+    match action.as_str() {
+        "set-status" => BulkCommand::SetStatus {
+            tasks,
+            status: single("status"),
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        "set-field" => {
+            if rest.len() < 2 {
+                die("--then set-field needs a field and a value");
+            }
+            BulkCommand::SetField {
+                tasks,
+                field: rest[0].clone(),
+                value: rest[1..].join(" "),
+                touch: false,
+                no_touch: false,
+                json,
+            }
+        }
+        "label-add" => BulkCommand::LabelAdd {
+            tasks,
+            label: single("label"),
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        "label-remove" => BulkCommand::LabelRemove {
+            tasks,
+            label: single("label"),
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        "dep-add" => BulkCommand::DepAdd {
+            tasks,
+            dependency: single("dependency"),
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        "dep-remove" => BulkCommand::DepRemove {
+            tasks,
+            dependency: single("dependency"),
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        "note" => BulkCommand::Note {
+            tasks,
+            note: single("note"),
+            section: NoteSection::Notes,
+            touch: false,
+            no_touch: false,
+            json,
+        },
+        other => die(&format!(
+            "Unknown --then action: {} (expected set-status, set-field, label-add, label-remove, \
+dep-add, dep-remove, note)",
+            other
+        )),
+    }
+}
+
+fn handle_bulk_command(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task_rules: &workmesh_core::config::TaskValidationRules,
+    command: BulkCommand,
+    auto_checkpoint: bool,
+    auto_session: bool,
+) -> Result<()> {
+    match command {
+        BulkCommand::SetStatus {
+            tasks: task_ids,
+            status,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_set_status(
+            backlog_dir,
+            tasks,
+            task_rules,
+            task_ids,
+            status,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::SetField {
+            tasks: task_ids,
+            field,
+            value,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_set_field(
+            backlog_dir,
+            tasks,
+            task_rules,
+            task_ids,
+            field,
+            value,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::LabelAdd {
+            tasks: task_ids,
+            label,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_label_add(
+            backlog_dir,
+            tasks,
+            task_ids,
+            label,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::LabelRemove {
+            tasks: task_ids,
+            label,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_label_remove(
+            backlog_dir,
+            tasks,
+            task_ids,
+            label,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::DepAdd {
+            tasks: task_ids,
+            dependency,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_dep_add(
+            backlog_dir,
+            tasks,
+            task_ids,
+            dependency,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::DepRemove {
+            tasks: task_ids,
+            dependency,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_dep_remove(
+            backlog_dir,
+            tasks,
+            task_ids,
+            dependency,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+        BulkCommand::Note {
+            tasks: task_ids,
+            note,
+            section,
+            touch,
+            no_touch,
+            json,
+        } => handle_bulk_note(
+            backlog_dir,
+            tasks,
+            task_ids,
+            note,
+            section,
+            effective_touch(touch, no_touch),
+            json,
+            auto_checkpoint,
+            auto_session,
+        )?,
+    }
+    Ok(())
 }
 
 fn handle_bulk_set_status(
@@ -7738,6 +14254,9 @@ fn handle_bulk_set_field(
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
+        if field == "title" {
+            rename_task_file_for_title(path, &task.title)?;
+        }
         audit_event(
             backlog_dir,
             "bulk_set_field",
@@ -7772,11 +14291,7 @@ fn handle_bulk_label_add(
         let path = task.file_path.as_ref().unwrap_or_else(|| {
             die(&format!("Task not found: {}", task.id));
         });
-        let mut current = task.labels.clone();
-        if !current.contains(&label) {
-            current.push(label.clone());
-        }
-        set_list_field(path, "labels", current)?;
+        edit_list_field(path, "labels", &label, true)?;
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
@@ -7814,9 +14329,7 @@ fn handle_bulk_label_remove(
         let path = task.file_path.as_ref().unwrap_or_else(|| {
             die(&format!("Task not found: {}", task.id));
         });
-        let mut current = task.labels.clone();
-        current.retain(|entry| entry != &label);
-        set_list_field(path, "labels", current)?;
+        edit_list_field(path, "labels", &label, false)?;
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
@@ -7854,11 +14367,7 @@ fn handle_bulk_dep_add(
         let path = task.file_path.as_ref().unwrap_or_else(|| {
             die(&format!("Task not found: {}", task.id));
         });
-        let mut current = task.dependencies.clone();
-        if !current.contains(&dependency) {
-            current.push(dependency.clone());
-        }
-        set_list_field(path, "dependencies", current)?;
+        edit_list_field(path, "dependencies", &dependency, true)?;
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
@@ -7896,9 +14405,7 @@ fn handle_bulk_dep_remove(
         let path = task.file_path.as_ref().unwrap_or_else(|| {
             die(&format!("Task not found: {}", task.id));
         });
-        let mut current = task.dependencies.clone();
-        current.retain(|entry| entry != &dependency);
-        set_list_field(path, "dependencies", current)?;
+        edit_list_field(path, "dependencies", &dependency, false)?;
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
@@ -7932,22 +14439,22 @@ fn handle_bulk_note(
         die("No tasks provided");
     }
     let (selected, missing) = select_tasks_with_missing(tasks, &ids);
+    let details = note_audit_details(backlog_dir, tasks, section.as_str(), &note);
     let mut updated = Vec::new();
     for task in selected {
         let path = task.file_path.as_ref().unwrap_or_else(|| {
             die(&format!("Task not found: {}", task.id));
         });
-        let new_body = append_note(&task.body, &note, section.as_str());
-        update_body(path, &new_body)?;
+        append_task_note(path, &note, section.as_str())?;
         if touch {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
         }
-        audit_event(
-            backlog_dir,
-            "bulk_note",
-            Some(&task.id),
-            serde_json::json!({ "section": section.as_str(), "note": note }),
-        )?;
+        let linked = autolink_note(backlog_dir, tasks, task, &note)?;
+        let mut details = details.clone();
+        if !linked.is_empty() {
+            details["relates_to"] = serde_json::json!(linked);
+        }
+        audit_event(backlog_dir, "bulk_note", Some(&task.id), details)?;
         updated.push(task.id.clone());
     }
     refresh_index_best_effort(backlog_dir);
@@ -7960,6 +14467,160 @@ fn best_practices_text() -> &'static str {
     "workmesh best practices\n\nTask quality:\n- Fill `Description`, `Acceptance Criteria`, and `Definition of Done` for every task.\n- `Definition of Done` must include outcome-based completion criteria, not only hygiene checks.\n- `Done` transitions are gated: tasks must satisfy quality requirements before completion.\n\nDependencies:\n- Add dependencies whenever a task is blocked by other work.\n- Prefer explicit task ids (task-042) over vague references.\n- Update dependencies as status changes to avoid stale blockers.\n- Use validate to catch missing or broken dependency chains.\n\nDerived files:\n- Ignore derived artifacts like `.workmesh/.index/` and `.workmesh/.audit.log` in git.\n- If they show up as changes, rebuild/refresh and do not commit them.\n\nLabels:\n- Use labels to group work (docs, infra, ops).\n- Keep labels short and consistent.\n\nNotes:\n- Capture blockers or decisions in notes for future context.\n"
 }
 
+/// Task ids a mutating command writes to, checked against active freezes before dispatch.
+fn mutation_task_ids(command: &Command) -> Vec<String> {
+    match command {
+        Command::SetStatus { task_id, .. }
+        | Command::Claim { task_id, .. }
+        | Command::Release { task_id, .. }
+        | Command::SetField { task_id, .. }
+        | Command::LabelAdd { task_id, .. }
+        | Command::LabelRemove { task_id, .. }
+        | Command::DepAdd { task_id, .. }
+        | Command::DepRemove { task_id, .. }
+        | Command::Note { task_id, .. }
+        | Command::SetBody { task_id, .. }
+        | Command::SetSection { task_id, .. }
+        | Command::Remove { task_id, .. }
+        | Command::Split { task_id, .. }
+        | Command::Move {
+            old_id: task_id, ..
+        } => vec![task_id.clone()],
+        Command::AddDiscovered { from, .. } => vec![from.clone()],
+        Command::BulkSetStatus { tasks, .. }
+        | Command::BulkSetField { tasks, .. }
+        | Command::BulkLabelAdd { tasks, .. }
+        | Command::BulkLabelRemove { tasks, .. }
+        | Command::BulkDepAdd { tasks, .. }
+        | Command::BulkDepRemove { tasks, .. }
+        | Command::BulkNote { tasks, .. } => tasks.clone(),
+        Command::Bulk { command } => match command {
+            BulkCommand::SetStatus { tasks, .. }
+            | BulkCommand::SetField { tasks, .. }
+            | BulkCommand::LabelAdd { tasks, .. }
+            | BulkCommand::LabelRemove { tasks, .. }
+            | BulkCommand::DepAdd { tasks, .. }
+            | BulkCommand::DepRemove { tasks, .. }
+            | BulkCommand::Note { tasks, .. } => tasks.clone(),
+        },
+        _ => Vec::new(),
+    }
+}
+
+fn freeze_target(epic: Option<String>, phase: Option<String>) -> (FreezeKind, String) {
+    match (epic, phase) {
+        (Some(epic), _) => (FreezeKind::Epic, epic),
+        (None, Some(phase)) => (FreezeKind::Phase, phase),
+        (None, None) => die("Pass --epic or --phase"),
+    }
+}
+
+/// Lease `task` to `owner` (adding them as an assignee) and record a `claim` audit event.
+fn claim_task(
+    backlog_dir: &Path,
+    task: &Task,
+    owner: &str,
+    minutes: Option<i64>,
+    touch: bool,
+) -> Result<Lease> {
+    let path = task
+        .file_path
+        .as_ref()
+        .unwrap_or_else(|| die(&format!("Task not found: {}", task.id)));
+    let mut assignee = task.assignee.clone();
+    if !assignee.iter().any(|value| value == owner) {
+        assignee.push(owner.to_string());
+        set_list_field(path, "assignee", assignee)?;
+    }
+    let lease = Lease {
+        owner: owner.to_string(),
+        acquired_at: Some(now_timestamp()),
+        expires_at: minutes.map(timestamp_plus_minutes),
+    };
+    update_lease_fields(path, Some(&lease))?;
+    if touch {
+        update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+    }
+    audit_event(
+        backlog_dir,
+        "claim",
+        Some(&task.id),
+        serde_json::json!({
+            "owner": lease.owner.clone(),
+            "expires_at": lease.expires_at.clone(),
+        }),
+    )?;
+    Ok(lease)
+}
+
+/// Shared by `autotag` and `lint --autotag`: propose (or apply) labels and print the report.
+fn run_autotag(
+    backlog_dir: &Path,
+    selected: &[Task],
+    apply: bool,
+    json: bool,
+    auto_checkpoint: bool,
+    auto_session: bool,
+) -> Result<AutotagReport> {
+    let repo_root = repo_root_from_backlog(backlog_dir);
+    let rules = compile_rules(&resolve_autotag_config(&repo_root))?;
+    if rules.is_empty() {
+        logging::warn("no [autotag] rules configured");
+    }
+    let report = autotag_tasks(selected, &rules, apply);
+    if !report.applied.is_empty() {
+        let labels: serde_json::Map<String, serde_json::Value> = report
+            .proposals
+            .iter()
+            .filter(|proposal| report.applied.contains(&proposal.task_id))
+            .map(|proposal| (proposal.task_id.clone(), serde_json::json!(proposal.labels)))
+            .collect();
+        audit_event(
+            backlog_dir,
+            "autotag",
+            None,
+            serde_json::json!({ "labels": labels }),
+        )?;
+        refresh_index_best_effort(backlog_dir);
+        maybe_auto_checkpoint(backlog_dir, auto_checkpoint, auto_session);
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(report);
+    }
+    for proposal in &report.proposals {
+        let reasons: Vec<String> = proposal
+            .matches
+            .iter()
+            .map(|found| format!("{} ({:?})", found.label, found.matched))
+            .collect();
+        println!(
+            "{} {}: {}",
+            proposal.task_id,
+            if apply { "tagged" } else { "would tag" },
+            reasons.join(", ")
+        );
+    }
+    for warning in &report.warnings {
+        println!("WARN: {}", warning);
+    }
+    println!(
+        "autotag: {} untagged task(s) scanned, {} proposal(s), {} applied",
+        report.scanned,
+        report.proposals.len(),
+        report.applied.len()
+    );
+    Ok(report)
+}
+
+fn dependency_field(soft: bool) -> &'static str {
+    if soft {
+        SOFT_DEPENDENCIES_FIELD
+    } else {
+        "dependencies"
+    }
+}
+
 fn update_list_field(
     backlog_dir: &Path,
     tasks: &[Task],
@@ -7975,20 +14636,8 @@ fn update_list_field(
     let path = task.file_path.as_ref().unwrap_or_else(|| {
         die(&format!("Task not found: {}", task_id));
     });
-    let mut current = match field {
-        "labels" => task.labels.clone(),
-        "dependencies" => task.dependencies.clone(),
-        _ => Vec::new(),
-    };
     let value = value.trim();
-    if add {
-        if !current.contains(&value.to_string()) {
-            current.push(value.to_string());
-        }
-    } else {
-        current.retain(|entry| entry != value);
-    }
-    set_list_field(path, field, current)?;
+    edit_list_field(path, field, value, add)?;
     if touch {
         update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
     }
@@ -7997,6 +14646,8 @@ fn update_list_field(
         ("labels", false) => "label_remove",
         ("dependencies", true) => "dependency_add",
         ("dependencies", false) => "dependency_remove",
+        (SOFT_DEPENDENCIES_FIELD, true) => "soft_dependency_add",
+        (SOFT_DEPENDENCIES_FIELD, false) => "soft_dependency_remove",
         _ => "update_list",
     };
     audit_event(
@@ -8061,6 +14712,135 @@ fn read_render_configuration(args: &RenderInputArgs) -> Result<Option<serde_json
     Ok(Some(value))
 }
 
+/// Capability manifest built from the clap definitions, for generating tool wrappers.
+fn cli_capabilities(repo_root: &Path) -> serde_json::Value {
+    let cli = Cli::command();
+    let global_args: Vec<serde_json::Value> = cli
+        .get_arguments()
+        .filter(|arg| !is_builtin_arg(arg))
+        .map(arg_capability)
+        .collect();
+    let mut commands = Vec::new();
+    for sub in cli.get_subcommands() {
+        collect_command_capabilities(sub, &mut Vec::new(), &mut commands);
+    }
+    serde_json::json!({
+        "name": cli.get_name(),
+        "version": version::FULL,
+        "global_args": global_args,
+        "commands": commands,
+        "aliases": resolve_aliases(repo_root),
+        "output_schemas": output_schemas(),
+    })
+}
+
+fn collect_command_capabilities(
+    command: &clap::Command,
+    parents: &mut Vec<String>,
+    out: &mut Vec<serde_json::Value>,
+) {
+    parents.push(command.get_name().to_string());
+    let path = parents.join(" ");
+    let args: Vec<serde_json::Value> = command
+        .get_arguments()
+        .filter(|arg| !is_builtin_arg(arg) && !arg.is_global_set())
+        .map(arg_capability)
+        .collect();
+    let json_output = command
+        .get_arguments()
+        .any(|arg| matches!(arg.get_long(), Some("json") | Some("pretty")));
+    out.push(serde_json::json!({
+        "path": path,
+        "about": command.get_about().map(|about| about.to_string()),
+        "has_subcommands": command.has_subcommands(),
+        "args": args,
+        "json_output": json_output,
+        "output_schema": command_output_schema(&path),
+    }));
+    for sub in command.get_subcommands() {
+        collect_command_capabilities(sub, parents, out);
+    }
+    parents.pop();
+}
+
+fn arg_capability(arg: &clap::Arg) -> serde_json::Value {
+    let value_type = match arg.get_action() {
+        ArgAction::SetTrue | ArgAction::SetFalse => "bool",
+        ArgAction::Count => "count",
+        _ => "string",
+    };
+    // Flags report `true`/`false` as possible values; only value-taking args carry enums.
+    let possible_values: Vec<String> = if value_type == "string" {
+        arg.get_possible_values()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| value.get_name().to_string())
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let defaults: Vec<String> = arg
+        .get_default_values()
+        .iter()
+        .map(|value| value.to_string_lossy().to_string())
+        .collect();
+    serde_json::json!({
+        "name": arg.get_id().as_str(),
+        "long": arg.get_long(),
+        "short": arg.get_short(),
+        "positional": arg.is_positional(),
+        "required": arg.is_required_set(),
+        "multiple": matches!(arg.get_action(), ArgAction::Append),
+        "type": value_type,
+        "possible_values": possible_values,
+        "default": defaults.first(),
+        "help": arg.get_help().map(|help| help.to_string()),
+    })
+}
+
+fn is_builtin_arg(arg: &clap::Arg) -> bool {
+    matches!(arg.get_id().as_str(), "help" | "version")
+}
+
+/// Named schema (see `output_schemas`) printed by a command's `--json` flag, if shared.
+fn command_output_schema(path: &str) -> Option<&'static str> {
+    match path {
+        "list" | "ready" | "next-tasks" | "export" => Some("task_list"),
+        "show" | "next" => Some("task"),
+        "graph-export" => Some("graph"),
+        "metrics" => Some("metrics"),
+        _ => None,
+    }
+}
+
+fn render_capabilities_text(capabilities: &serde_json::Value) -> String {
+    let mut out = String::new();
+    let commands = capabilities["commands"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    for command in commands {
+        let flags: Vec<String> = command["args"]
+            .as_array()
+            .map(|args| {
+                args.iter()
+                    .map(|arg| match arg["long"].as_str() {
+                        Some(long) => format!("--{}", long),
+                        None => format!("<{}>", arg["name"].as_str().unwrap_or("value")),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        out.push_str(command["path"].as_str().unwrap_or_default());
+        if !flags.is_empty() {
+            out.push(' ');
+            out.push_str(&flags.join(" "));
+        }
+        out.push('\n');
+    }
+    out
+}
+
 fn render_tool_name(command: &RenderCommand) -> &'static str {
     match command {
         RenderCommand::Table(_) => "render_table",
@@ -8095,6 +14875,184 @@ fn render_args(command: &RenderCommand) -> &RenderInputArgs {
     }
 }
 
+fn print_change_previews(preview: &[ChangePreview]) {
+    for entry in preview {
+        println!(
+            "#{} {} {} [{}]",
+            entry.index, entry.action, entry.task_id, entry.target
+        );
+        if let Some(old) = entry.old.as_deref().filter(|old| !old.is_empty()) {
+            for line in old.lines() {
+                println!("  - {}", line);
+            }
+        }
+        for line in entry.new.lines() {
+            println!("  + {}", line);
+        }
+        if let Some(error) = &entry.error {
+            println!("  ! {}", error);
+        }
+    }
+}
+
+fn handle_bundle_review_command(command: &BundleCommand) -> Result<()> {
+    match command {
+        BundleCommand::View {
+            file,
+            task,
+            extract,
+            json,
+        } => {
+            let bundle = read_bundle(file)?;
+            let parsed = bundle_tasks(file, &bundle);
+            let Some(task_id) = task else {
+                if *json {
+                    println!("{}", serde_json::to_string_pretty(&bundle)?);
+                    return Ok(());
+                }
+                println!(
+                    "Bundle created {} by {}: {} task(s), {} annotation(s)",
+                    bundle.created_at,
+                    bundle.created_by.as_deref().unwrap_or("unknown"),
+                    bundle.tasks.len(),
+                    bundle.annotations.len()
+                );
+                for task in &parsed {
+                    println!("{}", render_task_line(task));
+                }
+                return Ok(());
+            };
+            let bundled = bundle
+                .tasks
+                .iter()
+                .find(|item| item.id.eq_ignore_ascii_case(task_id.trim()))
+                .unwrap_or_else(|| die(&format!("Task not in bundle: {}", task_id)));
+            let annotations: Vec<&Annotation> = bundle
+                .annotations
+                .iter()
+                .filter(|annotation| annotation.task_id == bundled.id)
+                .collect();
+            let extracted = match extract {
+                Some(dir) => extract_attachments(bundled, dir)?,
+                None => Vec::new(),
+            };
+            if *json {
+                let payload = serde_json::json!({
+                    "task": parsed
+                        .iter()
+                        .find(|task| task.id == bundled.id)
+                        .map(|task| task_to_json_value(task, true)),
+                    "history": bundled.history,
+                    "attachments": bundled
+                        .attachments
+                        .iter()
+                        .map(|attachment| attachment.path.as_str())
+                        .collect::<Vec<_>>(),
+                    "annotations": annotations,
+                    "extracted": extracted,
+                });
+                println!("{}", serde_json::to_string_pretty(&payload)?);
+                return Ok(());
+            }
+            println!("{}", bundled.content.trim_end());
+            println!();
+            println!("History:");
+            for event in &bundled.history {
+                println!(
+                    "- {} {} {}",
+                    TimeDisplay::default().render(&event.timestamp),
+                    event.actor.as_deref().unwrap_or("-"),
+                    event.action
+                );
+            }
+            if !bundled.attachments.is_empty() {
+                println!("Attachments:");
+                for attachment in &bundled.attachments {
+                    println!("- {}", attachment.path);
+                }
+            }
+            if !annotations.is_empty() {
+                println!("Annotations:");
+                for annotation in annotations {
+                    println!(
+                        "- [{}] {} {}: {}",
+                        annotation.id,
+                        annotation.at,
+                        annotation.author.as_deref().unwrap_or("-"),
+                        annotation.note
+                    );
+                }
+            }
+            for path in &extracted {
+                println!("Extracted {}", path.display());
+            }
+        }
+        BundleCommand::Annotate {
+            file,
+            task_id,
+            note,
+            as_user,
+            json,
+        } => {
+            if note.trim().is_empty() {
+                die("Annotation note must not be empty");
+            }
+            let mut bundle = read_bundle(file)?;
+            let author = as_user
+                .clone()
+                .or_else(|| std::env::var("USER").ok())
+                .map(|value| normalize_user(&value))
+                .filter(|value| !value.is_empty());
+            let annotation = annotate_bundle(&mut bundle, task_id, note, author)?;
+            write_bundle(file, &bundle)?;
+            if *json {
+                println!("{}", serde_json::to_string_pretty(&annotation)?);
+            } else {
+                println!("Annotated {} ({})", annotation.task_id, annotation.id);
+            }
+        }
+        BundleCommand::Create { .. } | BundleCommand::Apply { .. } => {
+            unreachable!("bundle create/apply need the backlog")
+        }
+    }
+    Ok(())
+}
+
+/// Fetch the remote backlog, then run the matching local read-only command against the cached
+/// checkout.
+fn handle_remote_command(command: &RemoteCommand) -> Result<()> {
+    let (name, query) = match command {
+        RemoteCommand::List(query) => ("list", query),
+        RemoteCommand::Board(query) => ("board", query),
+        RemoteCommand::Blockers(query) => ("blockers", query),
+    };
+    let home = resolve_workmesh_home()?;
+    let checkout =
+        fetch_remote_backlog(&home, &query.repo, query.git_ref.as_deref(), query.offline)?;
+    logging::info(format!(
+        "{}{} at {} ({})",
+        checkout.repo,
+        checkout
+            .git_ref
+            .as_deref()
+            .map(|git_ref| format!("@{}", git_ref))
+            .unwrap_or_default(),
+        checkout.head.as_deref().unwrap_or("unknown"),
+        if checkout.fetched {
+            "fetched"
+        } else {
+            "cached"
+        }
+    ));
+    let argv = ["workmesh", name]
+        .into_iter()
+        .map(String::from)
+        .chain(query.args.iter().cloned());
+    let mut inner = Cli::try_parse_from(argv).unwrap_or_else(|err| err.exit());
+    inner.root = checkout.path;
+    run(inner)
+}
+
 fn handle_render_command(command: &RenderCommand) -> Result<()> {
     let args = render_args(command);
     let mut payload = serde_json::Map::new();
@@ -8130,7 +15088,12 @@ fn audit_event(
     task_id: Option<&str>,
     details: serde_json::Value,
 ) -> Result<()> {
-    let actor = std::env::var("USER").ok();
+    let actor = std::env::var("USER").ok().map(|user| {
+        aliased_actor(
+            user,
+            &resolve_actor_aliases(&repo_root_from_backlog(backlog_dir)),
+        )
+    });
     let event = AuditEvent {
         timestamp: now_timestamp(),
         actor,
@@ -8142,6 +15105,77 @@ fn audit_event(
     Ok(())
 }
 
+/// Apply policies before the command runs when `[policies] auto = true`.
+fn maybe_auto_apply_policies(backlog_dir: &Path, repo_root: &Path) -> Result<()> {
+    let config = resolve_policies(repo_root);
+    if !config.auto.unwrap_or(false) || config.rules.is_empty() {
+        return Ok(());
+    }
+    let report = run_policies(backlog_dir, &config.rules, Local::now().naive_local(), true)?;
+    if !report.actions.is_empty() {
+        audit_policy_actions(backlog_dir, &report)?;
+        refresh_index_best_effort(backlog_dir);
+        logging::info(format!(
+            "policies: applied {} action(s)",
+            report.actions.len()
+        ));
+    }
+    Ok(())
+}
+
+fn audit_policy_actions(backlog_dir: &Path, report: &PolicyRunReport) -> Result<()> {
+    for action in &report.actions {
+        audit_event(
+            backlog_dir,
+            "policy",
+            Some(&action.task_id),
+            serde_json::json!({
+                "policy": action.policy,
+                "reason": action.reason,
+                "add_labels": action.add_labels,
+                "remove_labels": action.remove_labels,
+                "set_status": action.set_status,
+            }),
+        )?;
+    }
+    Ok(())
+}
+
+/// Note audit details, with validated `@mentions` recorded so they route to inboxes.
+fn note_audit_details(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    section: &str,
+    note: &str,
+) -> serde_json::Value {
+    let mut details = serde_json::json!({ "section": section, "note": note });
+    let teams = resolve_teams(&repo_root_from_backlog(backlog_dir));
+    let mentions = resolve_mentions(note, tasks, &teams);
+    for unknown in &mentions.unknown {
+        logging::warn(format!(
+            "@{} is not a known assignee, reviewer, or team; not notified",
+            unknown
+        ));
+    }
+    if !mentions.users.is_empty() {
+        details["mentions"] = serde_json::json!(mentions.users);
+    }
+    details
+}
+
+/// Relate `task` to the task ids `note` mentions, unless `note_autolink = false`.
+fn autolink_note(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task: &Task,
+    note: &str,
+) -> Result<Vec<String>> {
+    if !resolve_note_autolink(&repo_root_from_backlog(backlog_dir)) {
+        return Ok(Vec::new());
+    }
+    Ok(link_note_references(task, tasks, note)?)
+}
+
 fn auto_checkpoint_enabled(cli: &Cli) -> bool {
     if cli.auto_checkpoint {
         return true;
@@ -8186,26 +15220,146 @@ fn env_flag_true(name: &str) -> bool {
 }
 
 fn maybe_auto_checkpoint(backlog_dir: &Path, auto_checkpoint: bool, auto_session: bool) {
-    if auto_checkpoint {
-        let tasks = load_tasks(backlog_dir);
-        let options = CheckpointOptions {
-            project_id: None,
-            checkpoint_id: None,
-            audit_limit: 10,
-        };
-        let _ = write_checkpoint(backlog_dir, &tasks, &options);
+    if let Err(err) = checkpoint_triggers::after_mutation(backlog_dir, auto_checkpoint) {
+        logging::debug(format!("auto checkpoint skipped: {}", err));
     }
 
     if auto_session {
-        let _ = auto_update_current_session(backlog_dir);
+        if let Err(err) = auto_update_current_session(backlog_dir) {
+            logging::debug(format!("auto session update skipped: {}", err));
+        }
+    }
+}
+
+fn render_bench_report(report: &BenchReport, baseline: Option<&BenchReport>) -> String {
+    let mut out = format!(
+        "workmesh {} on {}, median of {} run(s)\n",
+        report.version, report.platform, report.runs
+    );
+    if let Some(baseline) = baseline {
+        out.push_str(&format!(
+            "baseline: workmesh {} on {}\n",
+            baseline.version, baseline.platform
+        ));
+    }
+    out.push_str(&format!("{:<16}", "step"));
+    for size in &report.sizes {
+        out.push_str(&format!(" {:>20}", format!("{} tasks", size.tasks)));
+    }
+    out.push('\n');
+    for step in BENCH_STEPS {
+        let label = if *step == "mutation" {
+            "mutation (op)"
+        } else {
+            step
+        };
+        out.push_str(&format!("{:<16}", label));
+        for size in &report.sizes {
+            let Some(ms) = report.per_op_ms(size.tasks, step) else {
+                out.push_str(&format!(" {:>20}", "-"));
+                continue;
+            };
+            let mut cell = format!("{:.2}ms", ms);
+            if let Some(before) = baseline.and_then(|b| b.per_op_ms(size.tasks, step)) {
+                if before > 0.0 {
+                    cell.push_str(&format!(" ({:+.0}%)", (ms - before) / before * 100.0));
+                }
+            }
+            out.push_str(&format!(" {:>20}", cell));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+fn render_selftest_report(report: &SelftestReport) -> String {
+    let mut out = String::new();
+    for result in &report.results {
+        let label = match result.outcome {
+            StepOutcome::Pass => "ok",
+            StepOutcome::Fail => "FAIL",
+            StepOutcome::Missing => "missing",
+            StepOutcome::Updated => "updated",
+        };
+        out.push_str(&format!("{:<8} {}/{}", label, result.fixture, result.step));
+        if let Some(diff) = result.diff.as_deref() {
+            out.push_str(&format!(" at {}", diff));
+        }
+        out.push('\n');
+    }
+    let failed = report
+        .results
+        .iter()
+        .filter(|result| !matches!(result.outcome, StepOutcome::Pass | StepOutcome::Updated))
+        .count();
+    out.push_str(&format!(
+        "selftest {} ({} fixture(s), {} step(s), {} failed) workmesh {} on {}\n",
+        if report.ok { "passed" } else { "failed" },
+        report.fixtures,
+        report.results.len(),
+        failed,
+        report.version,
+        report.platform
+    ));
+    out
+}
+
+fn render_backlog_diff(diff: &BacklogDiff) -> String {
+    if diff.is_empty() {
+        return "No backlog changes.\n".to_string();
+    }
+    let mut out = String::new();
+    for task in &diff.added {
+        out.push_str(&format!("+ {} {} [{}]\n", task.id, task.title, task.status));
+    }
+    for task in &diff.removed {
+        out.push_str(&format!("- {} {} [{}]\n", task.id, task.title, task.status));
+    }
+    for task in &diff.changed {
+        out.push_str(&format!("~ {} {}\n", task.id, task.title));
+        for change in &task.changes {
+            if change.field == "body" {
+                out.push_str("    body: changed\n");
+            } else {
+                out.push_str(&format!(
+                    "    {}: {} -> {}\n",
+                    change.field,
+                    diff_value(&change.old),
+                    diff_value(&change.new)
+                ));
+            }
+        }
+    }
+    for edge in &diff.dependencies_added {
+        out.push_str(&format!("+ dep {} -> {}\n", edge.task_id, edge.depends_on));
+    }
+    for edge in &diff.dependencies_removed {
+        out.push_str(&format!("- dep {} -> {}\n", edge.task_id, edge.depends_on));
+    }
+    out
+}
+
+fn diff_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::Null => "(none)".to_string(),
+        serde_json::Value::String(text) => text.clone(),
+        other => other.to_string(),
     }
 }
 
 fn refresh_index_best_effort(backlog_dir: &Path) {
-    let _ = refresh_index(backlog_dir);
+    if let Err(err) = refresh_index(backlog_dir) {
+        logging::debug(format!("index refresh skipped: {}", err));
+    }
+}
+
+fn refresh_archive_index_best_effort(backlog_dir: &Path) {
+    if let Err(err) = refresh_archive_index(backlog_dir) {
+        logging::debug(format!("archive index refresh skipped: {}", err));
+    }
 }
 
 fn die(message: &str) -> ! {
-    eprintln!("{}", message);
+    logging::error(message);
     std::process::exit(1);
 }
@@ -0,0 +1,218 @@
+//! Line-based multi-select picker for `workmesh pick`. Prompts go to the given writer (stderr in
+//! the CLI) so stdout only carries the selected ids.
+
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+/// Rows shown per redraw; refine the filter to reach the rest.
+const PAGE: usize = 30;
+
+pub struct PickItem {
+    pub id: String,
+    pub label: String,
+}
+
+/// Skim-style fuzzy match: every query character must appear in order (case-insensitive).
+/// Consecutive runs and word starts score higher, gaps lower. `None` means no match.
+pub fn fuzzy_score(query: &str, text: &str) -> Option<i64> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = text.chars().flat_map(char::to_lowercase).collect();
+    let mut score = 0i64;
+    let mut next = 0usize;
+    let mut last_match: Option<usize> = None;
+    for (idx, ch) in text.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if *ch != query[next] {
+            continue;
+        }
+        score += 1;
+        match last_match {
+            Some(last) if last + 1 == idx => score += 5,
+            Some(last) => score -= ((idx - last - 1) as i64).min(5),
+            None => score -= (idx as i64).min(5),
+        }
+        if idx == 0 || !text[idx - 1].is_alphanumeric() {
+            score += 3;
+        }
+        last_match = Some(idx);
+        next += 1;
+    }
+    (next == query.len()).then_some(score)
+}
+
+/// Indexes of items matching `query`, best match first (ties keep item order).
+pub fn filter_items(items: &[PickItem], query: &str) -> Vec<usize> {
+    let mut scored: Vec<(i64, usize)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, item)| fuzzy_score(query, &item.label).map(|score| (score, idx)))
+        .collect();
+    scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+    scored.into_iter().map(|(_, idx)| idx).collect()
+}
+
+/// Parse `1 3 5-7,9` into zero-based positions below `visible`.
+pub fn parse_positions(input: &str, visible: usize) -> Result<Vec<usize>, String> {
+    let mut positions = Vec::new();
+    for part in input
+        .split(|ch: char| ch == ',' || ch.is_whitespace())
+        .filter(|part| !part.is_empty())
+    {
+        let (start, end) = match part.split_once('-') {
+            Some((start, end)) => (start, end),
+            None => (part, part),
+        };
+        let parse = |value: &str| -> Result<usize, String> {
+            value
+                .trim()
+                .parse::<usize>()
+                .ok()
+                .filter(|number| (1..=visible).contains(number))
+                .ok_or_else(|| format!("not a row number 1-{}: {}", visible, part))
+        };
+        let (start, end) = (parse(start)?, parse(end)?);
+        if start > end {
+            return Err(format!("empty range: {}", part));
+        }
+        positions.extend((start..=end).map(|number| number - 1));
+    }
+    Ok(positions)
+}
+
+/// Run the picker. Commands: `/text` filters, row numbers or ranges toggle, `*` toggles every
+/// visible row, an empty line (or EOF) accepts, `q` cancels (`None`). Selected ids come back in
+/// item order.
+pub fn run_picker<R: BufRead, W: Write>(
+    items: &[PickItem],
+    initial_query: &str,
+    input: &mut R,
+    out: &mut W,
+) -> io::Result<Option<Vec<String>>> {
+    let mut query = initial_query.trim().to_string();
+    let mut selected: BTreeSet<usize> = BTreeSet::new();
+    loop {
+        let visible: Vec<usize> = filter_items(items, &query).into_iter().take(PAGE).collect();
+        let total = filter_items(items, &query).len();
+        writeln!(
+            out,
+            "{} match(es){}, {} selected",
+            total,
+            if query.is_empty() {
+                String::new()
+            } else {
+                format!(" for \"{}\"", query)
+            },
+            selected.len()
+        )?;
+        for (row, idx) in visible.iter().enumerate() {
+            let mark = if selected.contains(idx) { 'x' } else { ' ' };
+            writeln!(out, "{:>3} [{}] {}", row + 1, mark, items[*idx].label)?;
+        }
+        if total > visible.len() {
+            writeln!(
+                out,
+                "    ... {} more; refine with /text",
+                total - visible.len()
+            )?;
+        }
+        write!(
+            out,
+            "/filter, 1 3 5-7 toggle, * all, enter accept, q cancel> "
+        )?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line.is_empty() {
+            break;
+        }
+        if line == "q" {
+            return Ok(None);
+        }
+        if let Some(rest) = line.strip_prefix('/') {
+            query = rest.trim().to_string();
+            continue;
+        }
+        if line == "*" {
+            if visible.iter().all(|idx| selected.contains(idx)) {
+                for idx in &visible {
+                    selected.remove(idx);
+                }
+            } else {
+                selected.extend(visible.iter().copied());
+            }
+            continue;
+        }
+        match parse_positions(line, visible.len()) {
+            Ok(positions) => {
+                for position in positions {
+                    let idx = visible[position];
+                    if !selected.remove(&idx) {
+                        selected.insert(idx);
+                    }
+                }
+            }
+            Err(err) => writeln!(out, "! {}", err)?,
+        }
+    }
+    Ok(Some(
+        selected
+            .into_iter()
+            .map(|idx| items[idx].id.clone())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<PickItem> {
+        [
+            "task-001 | To Do | Fix login",
+            "task-002 | To Do | Add logging",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(idx, label)| PickItem {
+            id: format!("task-00{}", idx + 1),
+            label: label.to_string(),
+        })
+        .collect()
+    }
+
+    #[test]
+    fn fuzzy_score_prefers_contiguous_word_matches() {
+        assert!(fuzzy_score("lgn", "Fix login").is_some());
+        assert!(fuzzy_score("xyz", "Fix login").is_none());
+        assert!(fuzzy_score("log", "logging").unwrap() > fuzzy_score("log", "l-o-g").unwrap());
+        assert_eq!(filter_items(&items(), "logging"), vec![1]);
+    }
+
+    #[test]
+    fn picker_filters_toggles_and_accepts() {
+        assert_eq!(parse_positions("1 3-4", 4), Ok(vec![0, 2, 3]));
+        assert!(parse_positions("5", 4).is_err());
+
+        let mut input = io::Cursor::new("/login\n1\n/\n*\n2\n\n");
+        let mut out = Vec::new();
+        let picked = run_picker(&items(), "", &mut input, &mut out).expect("picker");
+        assert_eq!(picked, Some(vec!["task-001".to_string()]));
+
+        let mut cancel = io::Cursor::new("*\nq\n");
+        let picked = run_picker(&items(), "", &mut cancel, &mut Vec::new()).expect("picker");
+        assert_eq!(picked, None);
+    }
+}
@@ -0,0 +1,69 @@
+//! Progress for long operations (`--progress text|json`). Like diagnostics, it goes to stderr so
+//! stdout keeps only the final report; `json` writes one `{"op","phase","done","total"}` object
+//! per line for wrappers and agent UIs.
+
+use std::io::Write;
+use std::sync::OnceLock;
+
+use clap::ValueEnum;
+use workmesh_core::progress::ProgressEvent;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum ProgressFormat {
+    /// `rekey: rewrite 120/845` lines
+    Text,
+    /// One JSON object per line: `{"op","phase","done","total"}`
+    Json,
+}
+
+static FORMAT: OnceLock<Option<ProgressFormat>> = OnceLock::new();
+
+/// Configure the process-wide progress output; `None` keeps long operations quiet.
+pub fn init(format: Option<ProgressFormat>) {
+    let _ = FORMAT.set(format);
+}
+
+/// Whether `--progress` was given.
+pub fn enabled() -> bool {
+    FORMAT.get().copied().flatten().is_some()
+}
+
+pub fn emit(event: &ProgressEvent) {
+    let Some(format) = FORMAT.get().copied().flatten() else {
+        return;
+    };
+    let _ = writeln!(std::io::stderr().lock(), "{}", format_line(format, event));
+}
+
+fn format_line(format: ProgressFormat, event: &ProgressEvent) -> String {
+    match format {
+        ProgressFormat::Json => serde_json::to_string(event).unwrap_or_default(),
+        ProgressFormat::Text => format!(
+            "{}: {} {}/{}",
+            event.op, event.phase, event.done, event.total
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_line_text_and_json() {
+        let event = ProgressEvent {
+            op: "rekey",
+            phase: "rewrite",
+            done: 120,
+            total: 845,
+        };
+        assert_eq!(
+            format_line(ProgressFormat::Text, &event),
+            "rekey: rewrite 120/845"
+        );
+        assert_eq!(
+            format_line(ProgressFormat::Json, &event),
+            r#"{"op":"rekey","phase":"rewrite","done":120,"total":845}"#
+        );
+    }
+}
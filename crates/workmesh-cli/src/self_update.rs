@@ -0,0 +1,110 @@
+//! `workmesh self-update`: replace the running binary with the newest release for this platform,
+//! and the `--check` report that `doctor --check-updates` reuses.
+
+use anyhow::{anyhow, Result};
+use serde_json::{json, Value};
+use workmesh_core::self_update::{
+    check_for_update, current_target, download_verified, replace_executable,
+    resolve_self_update_config, HttpReleaseSource, SelfUpdateConfig, UpdateChannel,
+};
+
+use crate::logging;
+
+pub fn run(channel: Option<UpdateChannel>, check: bool, json: bool) -> Result<()> {
+    let config = resolve_self_update_config();
+    let channel = resolve_channel(&config, channel)?;
+    let target = current_target().ok_or_else(|| {
+        anyhow!(
+            "no release builds for {}-{}",
+            std::env::consts::ARCH,
+            std::env::consts::OS
+        )
+    })?;
+    let source = HttpReleaseSource::new(&config);
+    let (report, release) = check_for_update(&source, channel, env!("CARGO_PKG_VERSION"), &target)?;
+
+    if check || !report.update_available {
+        if json {
+            println!("{}", serde_json::to_string_pretty(&report)?);
+        } else if report.update_available {
+            println!(
+                "Update available: {} -> {} ({} channel); run `workmesh self-update`",
+                report.current,
+                report.latest,
+                channel.as_str()
+            );
+        } else {
+            println!(
+                "workmesh {} is up to date ({} channel, latest {})",
+                report.current,
+                channel.as_str(),
+                report.latest
+            );
+        }
+        return Ok(());
+    }
+
+    let exe = std::env::current_exe()?;
+    // Follow a symlinked install (e.g. a package manager shim) to the real binary.
+    let exe = exe.canonicalize().unwrap_or(exe);
+    logging::info(format!(
+        "self-update: downloading {} {}",
+        release.tag,
+        report.asset.as_deref().unwrap_or("")
+    ));
+    let binary = download_verified(&source, &release, &target, config.public_key.as_deref())?;
+    replace_executable(&exe, &binary)?;
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "updated": true,
+                "from": report.current,
+                "to": report.latest,
+                "tag": report.tag,
+                "channel": channel,
+                "path": exe.to_string_lossy(),
+                "signature_verified": config.public_key.is_some(),
+            }))?
+        );
+    } else {
+        println!(
+            "Updated workmesh {} -> {} at {}",
+            report.current,
+            report.latest,
+            exe.display()
+        );
+    }
+    Ok(())
+}
+
+/// Update check for `doctor --check-updates`; failures are reported in the value rather than
+/// failing the whole report.
+pub fn check_report() -> Value {
+    let config = resolve_self_update_config();
+    let result = resolve_channel(&config, None).and_then(|channel| {
+        let target = current_target().ok_or_else(|| anyhow!("unsupported platform"))?;
+        let source = HttpReleaseSource::new(&config);
+        let (report, _) = check_for_update(&source, channel, env!("CARGO_PKG_VERSION"), &target)?;
+        Ok(serde_json::to_value(report)?)
+    });
+    result.unwrap_or_else(|err| json!({ "error": err.to_string() }))
+}
+
+fn resolve_channel(
+    config: &SelfUpdateConfig,
+    channel: Option<UpdateChannel>,
+) -> Result<UpdateChannel> {
+    if let Some(channel) = channel {
+        return Ok(channel);
+    }
+    match config.channel.as_deref() {
+        Some(value) => UpdateChannel::parse(value).ok_or_else(|| {
+            anyhow!(
+                "[self_update] channel must be stable or nightly, got {}",
+                value
+            )
+        }),
+        None => Ok(UpdateChannel::Stable),
+    }
+}
@@ -0,0 +1,175 @@
+//! `workmesh index-watch`: run a [`BacklogWatcher`] until interrupted and stream what it applies to the
+//! index. Events go to stdout (text, or one JSON object per line with `--json`) or, with
+//! `--socket`, as JSON lines to every client connected to a Unix socket.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use serde_json::json;
+use workmesh_core::index::{index_path, IndexChange};
+use workmesh_core::watch::BacklogWatcher;
+
+use crate::logging;
+
+pub fn run(
+    backlog_dir: &Path,
+    interval_ms: u64,
+    socket: Option<PathBuf>,
+    json: bool,
+) -> Result<()> {
+    let mut watcher = BacklogWatcher::new(backlog_dir)?;
+    let ready = json!({
+        "event": "ready",
+        "tasks": watcher.tracked(),
+        "index": index_path(backlog_dir).to_string_lossy(),
+    });
+    let mut sink = match socket {
+        Some(path) => {
+            logging::info(format!("watch: serving events on {}", path.display()));
+            Sink::socket(path)?
+        }
+        None => Sink::Stdout { json },
+    };
+    sink.send(&ready);
+    let interval = Duration::from_millis(interval_ms.max(50));
+    loop {
+        thread::sleep(interval);
+        sink.accept();
+        match watcher.poll() {
+            Ok(changes) => {
+                for change in &changes {
+                    sink.send(&change_json(change));
+                }
+            }
+            Err(err) => logging::warn(format!("watch: index update failed: {}", err)),
+        }
+    }
+}
+
+fn change_json(change: &IndexChange) -> serde_json::Value {
+    json!({ "event": change.event, "id": change.id, "path": change.path })
+}
+
+enum Sink {
+    Stdout {
+        json: bool,
+    },
+    #[cfg(unix)]
+    Socket(socket::Broadcast),
+}
+
+impl Sink {
+    #[cfg(unix)]
+    fn socket(path: PathBuf) -> Result<Self> {
+        Ok(Sink::Socket(socket::Broadcast::bind(path)?))
+    }
+
+    #[cfg(not(unix))]
+    fn socket(_path: PathBuf) -> Result<Self> {
+        anyhow::bail!("--socket needs Unix domain sockets, which this platform lacks")
+    }
+
+    fn accept(&mut self) {
+        #[cfg(unix)]
+        if let Sink::Socket(broadcast) = self {
+            broadcast.accept();
+        }
+    }
+
+    fn send(&mut self, event: &serde_json::Value) {
+        match self {
+            Sink::Stdout { json: true } => println!("{}", event),
+            Sink::Stdout { json: false } => println!("{}", text_line(event)),
+            #[cfg(unix)]
+            Sink::Socket(broadcast) => broadcast.send(event),
+        }
+        let _ = std::io::stdout().flush();
+    }
+}
+
+fn text_line(event: &serde_json::Value) -> String {
+    let field = |key: &str| event[key].as_str().unwrap_or_default().to_string();
+    if field("event") == "ready" {
+        return format!(
+            "Watching {} task files; index {}",
+            event["tasks"],
+            field("index")
+        );
+    }
+    format!("{} {} {}", field("event"), field("id"), field("path"))
+}
+
+#[cfg(unix)]
+mod socket {
+    use std::io::Write;
+    use std::os::unix::fs::FileTypeExt;
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use anyhow::{bail, Result};
+
+    /// Listening socket plus connected clients; a client that stops reading is dropped.
+    pub struct Broadcast {
+        listener: UnixListener,
+        path: PathBuf,
+        clients: Vec<UnixStream>,
+        /// Replayed to each new client so it knows the watcher is live.
+        greeting: Option<String>,
+    }
+
+    impl Broadcast {
+        pub fn bind(path: PathBuf) -> Result<Self> {
+            // A socket left behind by a killed watcher would make bind fail; anything else at
+            // the path is not ours to remove.
+            if let Ok(metadata) = std::fs::symlink_metadata(&path) {
+                if !metadata.file_type().is_socket() {
+                    bail!("{} exists and is not a socket", path.display());
+                }
+                std::fs::remove_file(&path)?;
+            }
+            let listener = UnixListener::bind(&path)?;
+            listener.set_nonblocking(true)?;
+            Ok(Self {
+                listener,
+                path,
+                clients: Vec::new(),
+                greeting: None,
+            })
+        }
+
+        /// Take every pending connection. Stops at `WouldBlock` (no one else is waiting); other
+        /// errors are retried on the next poll.
+        pub fn accept(&mut self) {
+            while let Ok((mut stream, _)) = self.listener.accept() {
+                // A stalled reader must not hold up index updates for long.
+                let _ = stream.set_write_timeout(Some(Duration::from_secs(1)));
+                let greeted = match &self.greeting {
+                    Some(line) => stream.write_all(line.as_bytes()).is_ok(),
+                    None => true,
+                };
+                if greeted {
+                    self.clients.push(stream);
+                }
+            }
+        }
+
+        pub fn send(&mut self, event: &serde_json::Value) {
+            let line = format!("{}\n", event);
+            if self.greeting.is_none() {
+                self.greeting = Some(line.clone());
+            }
+            self.clients
+                .retain_mut(|client| client.write_all(line.as_bytes()).is_ok());
+        }
+    }
+
+    impl Drop for Broadcast {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.path);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_workmesh"))
+}
+
+fn write_task(tasks_dir: &std::path::Path, id: &str, title: &str, status: &str) {
+    let content = format!(
+        "---\n\
+id: {id}\n\
+title: {title}\n\
+kind: task\n\
+status: {status}\n\
+priority: P2\n\
+phase: Phase1\n\
+dependencies: []\n\
+labels: []\n\
+assignee: []\n\
+---\n",
+        id = id,
+        title = title,
+        status = status
+    );
+    let filename = format!("{id} - {title}.md", id = id, title = title);
+    fs::write(tasks_dir.join(filename), content).expect("write task");
+}
+
+fn listed_ids(stdout: &[u8]) -> Vec<String> {
+    let payload: serde_json::Value = serde_json::from_slice(stdout).expect("json");
+    payload
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|task| task["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn configured_and_builtin_aliases_expand_before_parsing() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+    write_task(&tasks_dir, "task-002", "Beta", "Done");
+    fs::write(
+        temp.path().join(".workmesh.toml"),
+        "[aliases]\ntodo = \"list --status 'To Do'\"\n",
+    )
+    .expect("write config");
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("todo")
+        .arg("--json")
+        .output()
+        .expect("todo");
+    assert!(out.status.success());
+    assert_eq!(listed_ids(&out.stdout), vec!["task-001".to_string()]);
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("ls")
+        .arg("--json")
+        .output()
+        .expect("ls");
+    assert!(out.status.success());
+    assert_eq!(listed_ids(&out.stdout).len(), 2);
+}
@@ -0,0 +1,51 @@
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_workmesh"))
+}
+
+#[test]
+fn capabilities_json_lists_commands_flags_and_schemas() {
+    let temp = TempDir::new().expect("tempdir");
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("capabilities")
+        .arg("--json")
+        .output()
+        .expect("capabilities");
+    assert!(out.status.success());
+    let payload: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+
+    let commands = payload["commands"].as_array().expect("commands");
+    let list = commands
+        .iter()
+        .find(|command| command["path"] == "list")
+        .expect("list command");
+    assert_eq!(list["json_output"], true);
+    assert_eq!(list["output_schema"], "task_list");
+    assert!(commands
+        .iter()
+        .any(|command| command["path"] == "policies run"));
+
+    let board = commands
+        .iter()
+        .find(|command| command["path"] == "board")
+        .expect("board command");
+    let by = board["args"]
+        .as_array()
+        .expect("args")
+        .iter()
+        .find(|arg| arg["long"] == "by")
+        .expect("--by");
+    assert!(!by["possible_values"].as_array().expect("values").is_empty());
+
+    assert!(payload["global_args"]
+        .as_array()
+        .expect("global args")
+        .iter()
+        .any(|arg| arg["long"] == "root"));
+    assert_eq!(payload["output_schemas"]["task"]["type"], "object");
+}
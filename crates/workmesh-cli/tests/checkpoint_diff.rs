@@ -65,3 +65,55 @@ fn checkpoint_diff_shows_updates() {
     assert!(stdout.contains("Updated tasks"));
     assert!(stdout.contains("task-001"));
 }
+
+#[test]
+fn graph_diff_reports_dependency_edges_between_checkpoints() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("backlog");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do", None);
+    write_task(&tasks_dir, "task-002", "Beta", "To Do", None);
+
+    let run = |args: &[&str]| {
+        let output = bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run");
+        assert!(
+            output.status.success(),
+            "{:?}: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+
+    run(&["checkpoint", "--project", "alpha", "--id", "before"]);
+    run(&["dep-add", "task-002", "task-001"]);
+    run(&["checkpoint", "--project", "alpha", "--id", "after"]);
+
+    let stdout = run(&[
+        "graph-diff",
+        "--project",
+        "alpha",
+        "--from",
+        "before",
+        "--to",
+        "after",
+        "--json",
+    ]);
+    let report: serde_json::Value = serde_json::from_str(&stdout).expect("json");
+    assert_eq!(report["added"][0]["from"], "task-002");
+    assert_eq!(report["added"][0]["to"], "task-001");
+    assert_eq!(report["added"][0]["kind"], "dependency");
+    assert_eq!(report["removed"].as_array().expect("removed").len(), 0);
+    assert_eq!(report["changed_tasks"][0]["task_id"], "task-002");
+
+    run(&["dep-remove", "task-002", "task-001"]);
+    let stdout = run(&["graph-diff", "--project", "alpha", "--from", "after"]);
+    assert!(stdout.contains("Graph diff after -> current"));
+    assert!(stdout.contains("- task-002 -> task-001 (dependency)"));
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_workmesh"))
+}
+
+fn write_task(tasks_dir: &std::path::Path, id: &str, status: &str, dependencies: &str) {
+    let content = format!(
+        "---\n\
+id: {id}\n\
+title: Task {id}\n\
+kind: task\n\
+status: {status}\n\
+priority: P2\n\
+phase: Phase1\n\
+dependencies: [{dependencies}]\n\
+labels: []\n\
+assignee: []\n\
+---\n",
+        id = id,
+        status = status,
+        dependencies = dependencies
+    );
+    fs::write(tasks_dir.join(format!("{} - task.md", id)), content).expect("write task");
+}
+
+#[test]
+fn difftool_reports_semantic_changes_between_trees() {
+    let temp = TempDir::new().expect("tempdir");
+    let old = temp.path().join("left").join("workmesh").join("tasks");
+    let new = temp.path().join("right").join("workmesh").join("tasks");
+    fs::create_dir_all(&old).expect("old dir");
+    fs::create_dir_all(&new).expect("new dir");
+    write_task(&old, "task-001", "To Do", "");
+    write_task(&new, "task-001", "Done", "");
+    write_task(&new, "task-002", "To Do", "task-001");
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("difftool")
+        .arg(temp.path().join("left"))
+        .arg(temp.path().join("right"))
+        .output()
+        .expect("difftool");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("+ task-002 Task task-002 [To Do]"));
+    assert!(stdout.contains("~ task-001 Task task-001"));
+    assert!(stdout.contains("    status: To Do -> Done"));
+    assert!(stdout.contains("+ dep task-002 -> task-001"));
+}
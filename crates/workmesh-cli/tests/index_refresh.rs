@@ -1,5 +1,9 @@
 use std::fs;
-use std::process::Command;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
 
 use serde_json::Value;
 use tempfile::TempDir;
@@ -87,3 +91,53 @@ fn mutating_commands_refresh_index() {
     let status = read_index_status(&index_path, "task-001");
     assert_eq!(status, "Done");
 }
+
+#[test]
+fn index_watch_streams_changes_and_updates_index() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("backlog");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha");
+
+    let mut child = bin()
+        .arg("--root")
+        .arg(&backlog_dir)
+        .arg("index-watch")
+        .arg("--interval-ms")
+        .arg("50")
+        .arg("--json")
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .expect("spawn watch");
+    let stdout = child.stdout.take().expect("stdout");
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    let next_event = || -> Value {
+        let line = receiver
+            .recv_timeout(Duration::from_secs(10))
+            .expect("watch event");
+        serde_json::from_str(&line).expect("json line")
+    };
+
+    let ready = next_event();
+    assert_eq!(ready["event"], "ready");
+    assert_eq!(ready["tasks"], 1);
+
+    write_task(&tasks_dir, "task-002", "Beta");
+    let added = next_event();
+    let _ = child.kill();
+    let _ = child.wait();
+    assert_eq!(added["event"], "added");
+    assert_eq!(added["id"], "task-002");
+
+    let index_path = backlog_dir.join(".index").join("tasks.jsonl");
+    assert_eq!(read_index_status(&index_path, "task-002"), "To Do");
+}
@@ -0,0 +1,102 @@
+use std::fs;
+use std::process::Command;
+
+use tempfile::TempDir;
+
+fn bin() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_workmesh"))
+}
+
+#[test]
+fn errors_go_to_stderr_as_json_lines() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("--log-format")
+        .arg("json")
+        .arg("show")
+        .arg("task-404")
+        .output()
+        .expect("show");
+    assert!(!out.status.success());
+    assert!(out.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    let line = stderr.lines().last().expect("stderr line");
+    let value: serde_json::Value = serde_json::from_str(line).expect("json log line");
+    assert_eq!(value["level"], "error");
+    assert!(value["message"]
+        .as_str()
+        .expect("message")
+        .contains("task-404"));
+}
+
+#[test]
+fn log_level_error_silences_warnings() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("--log-level")
+        .arg("error")
+        .arg("list")
+        .arg("--json")
+        .output()
+        .expect("list");
+    assert!(out.status.success());
+    assert!(out.stderr.is_empty());
+    let payload: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert!(payload.is_array());
+}
+
+#[test]
+fn progress_json_streams_ndjson_events_on_stderr() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    for id in ["task-001", "task-002"] {
+        fs::write(
+            tasks_dir.join(format!("{} - t.md", id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+                 dependencies: []\nlabels: []\n---\n\nbody\n"
+            ),
+        )
+        .expect("write task");
+    }
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("--progress")
+        .arg("json")
+        .arg("index-rebuild")
+        .arg("--json")
+        .output()
+        .expect("index-rebuild");
+    assert!(out.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&out.stdout).expect("stdout json");
+    assert_eq!(summary["entries"], 2);
+    let events: Vec<serde_json::Value> = String::from_utf8_lossy(&out.stderr)
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("ndjson progress line"))
+        .collect();
+    let steps: Vec<(&str, u64, u64)> = events
+        .iter()
+        .map(|event| {
+            assert_eq!(event["op"], "index_rebuild");
+            (
+                event["phase"].as_str().expect("phase"),
+                event["done"].as_u64().expect("done"),
+                event["total"].as_u64().expect("total"),
+            )
+        })
+        .collect();
+    assert_eq!(steps, vec![("scan", 1, 2), ("scan", 2, 2), ("write", 1, 1)]);
+}
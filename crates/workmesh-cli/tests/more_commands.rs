@@ -95,6 +95,95 @@ fn common_read_commands_smoke() {
         .expect("graph-export");
     assert!(out.status.success());
 
+    // graph-export (mermaid, dot) + gantt (mermaid)
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("graph-export")
+        .arg("--format")
+        .arg("mermaid")
+        .output()
+        .expect("graph-export mermaid");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("flowchart LR\n"));
+    assert!(stdout.contains("t0[\"task-001 "));
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("graph-export")
+        .arg("--format")
+        .arg("dot")
+        .output()
+        .expect("graph-export dot");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("digraph workmesh {\n"));
+    assert!(stdout.contains("\"task-002\" [label=\"task-002\\nBeta\", fillcolor="));
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("gantt")
+        .arg("--format")
+        .arg("mermaid")
+        .arg("--start")
+        .arg("2026-01-05")
+        .output()
+        .expect("gantt mermaid");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("gantt\n    dateFormat YYYY-MM-DD\n"));
+    assert!(stdout.contains("    task-001 "));
+    assert!(!stdout.contains("@startgantt"));
+
+    // report html
+    let report_dir = temp.path().join("report");
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("report")
+        .arg("html")
+        .arg("--output")
+        .arg(&report_dir)
+        .arg("--json")
+        .output()
+        .expect("report html");
+    assert!(out.status.success());
+    let summary: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(summary["task_pages"], 2);
+    let index = fs::read_to_string(report_dir.join("index.html")).expect("index");
+    assert!(index.contains("<a href=\"tasks/task-001.html\">task-001</a> Alpha"));
+    assert!(report_dir.join("tasks").join("task-002.html").exists());
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("show")
+        .arg("task-001")
+        .arg("--format")
+        .arg("html")
+        .output()
+        .expect("show html");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("<!DOCTYPE html>"));
+    assert!(stdout.contains("task-001"));
+
+    // analytics cycle-time: task-002 is Done without audit history
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("analytics")
+        .arg("cycle-time")
+        .arg("--by")
+        .arg("priority")
+        .arg("--json")
+        .output()
+        .expect("analytics cycle-time");
+    assert!(out.status.success());
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["group"], "priority");
+    assert_eq!(report["untracked"], serde_json::json!(["task-002"]));
+
     // index verify should be ok after rebuild
     let out = bin()
         .arg("--root")
@@ -204,3 +293,1042 @@ fn common_write_commands_smoke() {
         .expect("release");
     assert!(out.status.success());
 }
+
+#[test]
+fn bench_reports_every_size_and_compares_with_baseline() {
+    let temp = TempDir::new().expect("tempdir");
+    let work = temp.path().join("bench");
+    let output = bin()
+        .args(["bench", "--tasks", "200", "--runs", "1", "--json", "--dir"])
+        .arg(&work)
+        .output()
+        .expect("bench");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    let sizes: Vec<u64> = report["sizes"]
+        .as_array()
+        .expect("sizes")
+        .iter()
+        .map(|size| size["tasks"].as_u64().expect("tasks"))
+        .collect();
+    assert_eq!(sizes, vec![20, 200]);
+    assert!(work.join("backlog-200").join("tasks").is_dir());
+
+    let baseline = temp.path().join("baseline.json");
+    fs::write(&baseline, &output.stdout).expect("baseline");
+    let output = bin()
+        .args(["bench", "--tasks", "20", "--runs", "1", "--baseline"])
+        .arg(&baseline)
+        .output()
+        .expect("bench");
+    assert!(output.status.success());
+    let text = String::from_utf8_lossy(&output.stdout);
+    assert!(text.contains("20 tasks"), "{}", text);
+    assert!(text.contains("mutation (op)"), "{}", text);
+    assert!(text.contains('%'), "{}", text);
+}
+
+#[test]
+fn stress_reports_no_lost_updates() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("backlog").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Real task", "To Do");
+    let output = bin()
+        .args([
+            "stress",
+            "--writers",
+            "2",
+            "--iterations",
+            "5",
+            "--json",
+            "--dir",
+        ])
+        .arg(temp.path())
+        .output()
+        .expect("stress");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json");
+    assert_eq!(report["writes"], 10);
+    assert_eq!(report["audit_events"], 10);
+    assert_eq!(report["lost_updates"].as_array().expect("lost").len(), 0);
+    assert_eq!(report["corrupted"].as_array().expect("corrupted").len(), 0);
+    let scratch = std::path::PathBuf::from(report["scratch_dir"].as_str().expect("scratch"));
+    assert!(scratch.starts_with(temp.path()));
+    assert!(!scratch.exists());
+    assert_eq!(fs::read_dir(&tasks_dir).expect("tasks").count(), 1);
+}
+
+#[test]
+fn children_show_and_board_roll_up_subtasks() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Epic", "In Progress");
+    write_task(&tasks_dir, "task-002", "Done child", "Done");
+    write_task(&tasks_dir, "task-003", "Open child", "To Do");
+    write_task(&tasks_dir, "task-004", "Grandchild", "Done");
+    for (task, parent) in [
+        ("task-002", "task-001"),
+        ("task-003", "task-001"),
+        ("task-004", "task-003"),
+    ] {
+        let out = bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(["set-field", task, "parent", parent])
+            .output()
+            .expect("set-field");
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["children", "task-001"])
+        .output()
+        .expect("children");
+    assert!(out.status.success());
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("task-001 Epic: 2/3 done\n"));
+    assert!(stdout.contains("  task-003 | To Do"));
+    assert!(!stdout.contains("task-004"));
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["children", "task-001", "--recursive", "--json"])
+        .output()
+        .expect("children json");
+    let payload: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(
+        payload["rollup"],
+        serde_json::json!({"done": 2, "total": 3})
+    );
+    let children: Vec<(&str, u64)> = payload["children"]
+        .as_array()
+        .expect("children")
+        .iter()
+        .map(|child| {
+            (
+                child["id"].as_str().unwrap_or(""),
+                child["depth"].as_u64().unwrap_or(0),
+            )
+        })
+        .collect();
+    assert_eq!(
+        children,
+        vec![("task-002", 1), ("task-003", 1), ("task-004", 2)]
+    );
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["show", "task-003"])
+        .output()
+        .expect("show");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("Parent: task-001"));
+    assert!(stdout.contains("Subtasks: 1/1 done"));
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("board")
+        .output()
+        .expect("board");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("| Epic [2/3 done]"));
+}
+
+#[test]
+fn critical_path_reports_chain_and_slack() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Design", "To Do");
+    write_task(&tasks_dir, "task-002", "Build", "To Do");
+    write_task(&tasks_dir, "task-003", "Docs", "To Do");
+    for (task, days) in [("task-001", "2"), ("task-002", "3"), ("task-003", "1")] {
+        let out = bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(["set-field", task, "estimate_days", days])
+            .output()
+            .expect("set-field");
+        assert!(out.status.success(), "{:?}", out);
+    }
+    for task in ["task-002", "task-003"] {
+        let out = bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(["dep-add", task, "task-001"])
+            .output()
+            .expect("dep-add");
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["critical-path", "--json"])
+        .output()
+        .expect("critical-path");
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["total_days"], 5.0);
+    assert_eq!(
+        report["critical_path"],
+        serde_json::json!(["task-001", "task-002"])
+    );
+    let docs = report["tasks"]
+        .as_array()
+        .expect("tasks")
+        .iter()
+        .find(|task| task["id"] == "task-003")
+        .expect("task-003");
+    assert_eq!(docs["slack"], 2.0);
+    assert_eq!(docs["critical"], false);
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .arg("critical-path")
+        .output()
+        .expect("critical-path text");
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.starts_with("Finish in 5 days: task-001 -> task-002\n"));
+    assert!(stdout.contains("  task-003   start     2 finish     3 slack     2 | Docs"));
+}
+
+#[test]
+fn note_links_referenced_tasks_unless_disabled() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+    write_task(&tasks_dir, "task-042", "Beta", "To Do");
+    write_task(&tasks_dir, "task-043", "Gamma", "To Do");
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args([
+            "note",
+            "task-001",
+            "Same root cause as task-042 (not task-999).",
+        ])
+        .output()
+        .expect("note");
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("Related task-001 to task-042"));
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["show", "task-001", "--json"])
+        .output()
+        .expect("show");
+    let task: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(
+        task["relationships"]["relates_to"],
+        serde_json::json!(["task-042"])
+    );
+
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args([
+            "config",
+            "set",
+            "--scope",
+            "project",
+            "--key",
+            "note_autolink",
+            "--value",
+            "false",
+        ])
+        .output()
+        .expect("config set");
+    assert!(out.status.success(), "{:?}", out);
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["note", "task-001", "Also see task-043."])
+        .output()
+        .expect("note");
+    assert!(out.status.success(), "{:?}", out);
+    assert!(!String::from_utf8_lossy(&out.stdout).contains("Related"));
+    let out = bin()
+        .arg("--root")
+        .arg(temp.path())
+        .args(["show", "task-001", "--json"])
+        .output()
+        .expect("show");
+    let task: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(
+        task["relationships"]["relates_to"],
+        serde_json::json!(["task-042"])
+    );
+}
+
+#[test]
+fn audit_verify_signatures_detects_tampering() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+
+    let out = bin()
+        .args(["audit", "keygen", "--json"])
+        .output()
+        .expect("keygen");
+    assert!(out.status.success(), "{:?}", out);
+    let keygen: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let key = keygen["key"].as_str().expect("key").to_string();
+    let public_key = keygen["public_key"]
+        .as_str()
+        .expect("public key")
+        .to_string();
+
+    for label in ["ops", "infra"] {
+        let out = bin()
+            .env("WORKMESH_AUDIT_KEY", &key)
+            .arg("--root")
+            .arg(temp.path())
+            .args(["label-add", "task-001", label])
+            .output()
+            .expect("label-add");
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let verify = |public_key: &str| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args([
+                "audit",
+                "verify-signatures",
+                "--public-key",
+                public_key,
+                "--json",
+            ])
+            .output()
+            .expect("verify")
+    };
+    let out = verify(&public_key);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["ok"], true);
+    assert_eq!(report["chained"], 2);
+    assert_eq!(report["verified"], 2);
+
+    let log = temp.path().join("workmesh").join(".audit.log");
+    let text = fs::read_to_string(&log).expect("log");
+    fs::write(&log, text.replacen("\"ops\"", "\"docs\"", 1)).expect("tamper");
+    let out = verify(&public_key);
+    assert_eq!(out.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["issues"][0]["kind"], "hash_mismatch");
+}
+
+#[test]
+fn token_create_list_and_revoke() {
+    let home = TempDir::new().expect("home");
+    let token = |args: &[&str]| {
+        bin()
+            .env("WORKMESH_HOME", home.path())
+            .arg("token")
+            .args(args)
+            .output()
+            .expect("token")
+    };
+
+    let out = token(&[
+        "create",
+        "--role",
+        "contributor",
+        "--scope",
+        "epic-12",
+        "--expires",
+        "30d",
+        "--name",
+        "ci",
+        "--json",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let created: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let id = created["id"].as_str().expect("id").to_string();
+    let secret = created["token"].as_str().expect("token");
+    assert!(secret.starts_with("wm_"));
+    assert_eq!(created["scopes"], serde_json::json!(["epic-12"]));
+    let stored = fs::read_to_string(home.path().join("tokens").join(format!("{id}.json")))
+        .expect("stored token");
+    assert!(!stored.contains(secret));
+
+    let out = token(&["list"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains(&format!("{id} | contributor | scope epic-12 | expires ")));
+
+    let out = token(&["revoke", &id]);
+    assert!(out.status.success(), "{:?}", out);
+    let out = token(&["list", "--json"]);
+    let listed: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(listed, serde_json::json!([]));
+    assert!(!token(&["revoke", &id]).status.success());
+}
+
+#[test]
+fn attach_lists_and_fetches_local_attachments() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Upload", "To Do");
+    let file = temp.path().join("trace.log");
+    fs::write(&file, "trace").expect("file");
+    let run = |args: &[&str]| {
+        bin()
+            .env("WORKMESH_HOME", temp.path().join("home"))
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&["attach", "task-001", file.to_str().expect("path"), "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let attached: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(attached["backend"], "local");
+    assert!(temp
+        .path()
+        .join("workmesh/attachments/task-001/trace.log")
+        .is_file());
+    assert!(!run(&["attach", "task-001", file.to_str().expect("path")])
+        .status
+        .success());
+
+    let out = run(&["attachments", "list", "task-001", "--json"]);
+    let listed: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(listed[0]["name"], "trace.log");
+    assert_eq!(listed[0]["size"], 5);
+
+    let out = run(&["attachments", "fetch", "task-001", "trace.log"]);
+    assert!(out.status.success(), "{:?}", out);
+    assert!(String::from_utf8_lossy(&out.stdout)
+        .trim()
+        .ends_with("trace.log"));
+    assert!(!run(&["attachments", "gc"]).status.success());
+}
+
+#[test]
+fn daemon_install_service_dry_run_prints_definition() {
+    let temp = TempDir::new().expect("tempdir");
+    fs::create_dir_all(temp.path().join("workmesh").join("tasks")).expect("tasks dir");
+    let home = temp.path().join("home");
+    let run = |args: &[&str]| {
+        bin()
+            .env("HOME", &home)
+            .env("XDG_CONFIG_HOME", home.join(".config"))
+            .env("XDG_STATE_HOME", home.join(".local/state"))
+            .env("LOCALAPPDATA", home.join("AppData/Local"))
+            .arg("--root")
+            .arg(temp.path())
+            .args(["daemon"])
+            .args(args)
+            .output()
+            .expect("daemon")
+    };
+
+    let out = run(&[
+        "install-service",
+        "--dry-run",
+        "--interval-ms",
+        "750",
+        "--json",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let payload: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let definition = payload["definition"].as_str().expect("definition");
+    assert!(definition.contains("index-watch"));
+    assert!(definition.contains("750"));
+    let name = payload["service"]["name"].as_str().expect("name");
+    assert!(name.starts_with("workmesh-"));
+    assert!(payload["service"]["definition_path"]
+        .as_str()
+        .expect("path")
+        .starts_with(home.to_str().expect("home")));
+    assert!(!std::path::Path::new(
+        payload["service"]["definition_path"]
+            .as_str()
+            .expect("path")
+    )
+    .exists());
+
+    let out = run(&["status", "--json"]);
+    let status: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(status["installed"], false);
+    assert_eq!(status["name"], name);
+}
+
+#[test]
+fn milestone_commands_aggregate_tasks_per_milestone() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Login", "Done");
+    write_task(&tasks_dir, "task-002", "Logout", "To Do");
+    write_task(&tasks_dir, "task-003", "Billing", "To Do");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+    for (task, milestone) in [("task-001", "v1.0"), ("task-002", "v1.0")] {
+        let out = run(&["set-field", task, "milestone", milestone]);
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let out = run(&["milestone", "progress", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["unassigned"], 1);
+    let v1 = &report["milestones"][0];
+    assert_eq!(v1["name"], "v1.0");
+    assert_eq!(
+        (v1["done"].as_u64(), v1["remaining"].as_u64()),
+        (Some(1), Some(1))
+    );
+    assert_eq!(v1["percent_complete"], 50.0);
+    assert!(v1.get("tasks").is_none());
+
+    let out = run(&["milestone", "show", "V1.0"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.starts_with("v1.0 | 50% | 1/2 done, 1 remaining"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("  task-002 | To Do | "), "{}", stdout);
+
+    let out = run(&["milestone", "list"]);
+    assert!(String::from_utf8_lossy(&out.stdout).contains("v1.0 | 2 tasks | 50% | "));
+    assert!(!run(&["milestone", "show", "v9"]).status.success());
+}
+
+#[test]
+fn soft_dependencies_warn_in_blockers_and_render_dotted_in_graph() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Design", "To Do");
+    write_task(&tasks_dir, "task-002", "Build", "To Do");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&["dep-add", "task-002", "task-001", "--soft"]);
+    assert!(out.status.success(), "{:?}", out);
+    let text = fs::read_to_string(tasks_dir.join("task-002 - Build.md")).expect("read");
+    assert!(text.contains("soft_dependencies:"), "{}", text);
+    assert!(text.contains("dependencies: []"), "{}", text);
+
+    let out = run(&["blockers", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["blocked_tasks"], serde_json::json!([]));
+    assert_eq!(report["soft_blocked"][0]["id"], "task-002");
+    assert_eq!(
+        report["soft_blocked"][0]["soft_dependencies"],
+        serde_json::json!(["task-001"])
+    );
+
+    let out = run(&["graph-export", "--format", "mermaid"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("t1 -.->|soft_blocked_by| t0"), "{}", stdout);
+
+    let out = run(&["dep-remove", "task-002", "task-001", "--soft"]);
+    assert!(out.status.success(), "{:?}", out);
+    let out = run(&["blockers", "--json"]);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["soft_blocked"], serde_json::json!([]));
+}
+
+#[test]
+fn autotag_proposes_and_applies_labels_from_config_rules() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Fix login crash", "To Do");
+    write_task(&tasks_dir, "task-002", "Write release notes", "To Do");
+    fs::write(
+        temp.path().join(".workmesh.toml"),
+        "[[autotag.rules]]\nlabel = \"bug\"\nkeywords = [\"crash\", \"regression\"]\n",
+    )
+    .expect("config");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&["autotag"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("task-001 would tag: bug (\"crash\")"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("task-002"), "{}", stdout);
+    let task_path = tasks_dir.join("task-001 - Fix login crash.md");
+    assert!(fs::read_to_string(&task_path)
+        .expect("read")
+        .contains("labels: []"));
+
+    // As a lint, open proposals fail the run until fixed.
+    assert!(!run(&["lint", "--autotag"]).status.success());
+    let out = run(&["lint", "--autotag", "--fix", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["applied"], serde_json::json!(["task-001"]));
+    let text = fs::read_to_string(&task_path).expect("read");
+    assert!(text.contains("labels: [bug]"), "{}", text);
+
+    let out = run(&["autotag", "--json"]);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["scanned"], 1);
+    assert_eq!(report["proposals"], serde_json::json!([]));
+}
+
+#[test]
+fn budget_status_compares_consumption_against_phase_budgets() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Design", "Done");
+    write_task(&tasks_dir, "task-002", "Build", "To Do");
+    fs::write(
+        temp.path().join(".workmesh.toml"),
+        "[budgets.phases.Phase1]\npoints = 5\nhours = 40\nstart = \"2026-01-01\"\n",
+    )
+    .expect("config");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+    for (task, field, value) in [
+        ("task-001", "story_points", "3"),
+        ("task-001", "estimate", "1d"),
+        ("task-002", "story_points", "5"),
+        ("task-002", "time_spent", "4h"),
+    ] {
+        let out = run(&["set-field", task, field, value]);
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let out = run(&["budget", "status", "--phase", "phase1", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let budget = &report["budgets"][0];
+    assert_eq!(budget["name"], "Phase1");
+    assert_eq!(budget["tasks"], 2);
+    let points = &budget["metrics"][0];
+    assert_eq!(
+        (points["consumed"].as_f64(), points["planned"].as_f64()),
+        (Some(3.0), Some(8.0))
+    );
+    assert_eq!(points["health"], "at_risk");
+    assert!(points["projected_overrun"].is_string());
+    let hours = &budget["metrics"][1];
+    assert_eq!(hours["consumed"], 12.0);
+    assert_eq!(hours["health"], "ok");
+
+    let out = run(&["budget", "status"]);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.starts_with("phase Phase1 | 2 tasks | since 2026-01-01"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("  pts: 3/5pts used (60%)"), "{}", stdout);
+    assert!(!run(&["budget", "status", "--initiative", "nope"])
+        .status
+        .success());
+}
+
+#[test]
+fn remote_list_queries_a_sparse_checkout_of_another_repo() {
+    let temp = TempDir::new().expect("tempdir");
+    let origin = temp.path().join("origin");
+    let tasks_dir = origin.join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    fs::create_dir_all(origin.join("src")).expect("src dir");
+    fs::write(origin.join("src").join("main.rs"), "fn main() {}\n").expect("src");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+    write_task(&tasks_dir, "task-002", "Beta", "Done");
+    for args in [
+        &["init", "-q", "-b", "main"][..],
+        &["add", "."],
+        &[
+            "-c",
+            "user.name=WorkMesh",
+            "-c",
+            "user.email=workmesh@example.com",
+            "commit",
+            "-q",
+            "-m",
+            "init",
+        ],
+    ] {
+        let ok = Command::new("git")
+            .args(args)
+            .current_dir(&origin)
+            .status()
+            .expect("git")
+            .success();
+        assert!(ok, "git {:?}", args);
+    }
+    let url = format!("file://{}", origin.display());
+    let home = temp.path().join("home");
+    let run = |args: &[&str]| {
+        bin()
+            .env("WORKMESH_HOME", &home)
+            .current_dir(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&[
+        "remote", "list", "--repo", &url, "--ref", "main", "--status", "To Do", "--json",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let tasks: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let ids: Vec<&str> = tasks
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|task| task["id"].as_str())
+        .collect();
+    assert_eq!(ids, vec!["task-001"]);
+
+    let checkouts: Vec<_> = fs::read_dir(home.join("remotes"))
+        .expect("cache")
+        .map(|entry| entry.expect("entry").path())
+        .collect();
+    assert_eq!(checkouts.len(), 1);
+    assert!(checkouts[0].join("workmesh").join("tasks").is_dir());
+    assert!(!checkouts[0].join("src").exists());
+
+    let out = run(&[
+        "remote",
+        "board",
+        "--repo",
+        &url,
+        "--ref",
+        "main",
+        "--offline",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    assert!(String::from_utf8_lossy(&out.stdout).contains("## Done (1)"));
+    assert!(
+        !run(&["remote", "blockers", "--repo", &url, "--ref", "nope"])
+            .status
+            .success()
+    );
+}
+
+#[test]
+fn remove_trashes_or_cancels_tasks_and_strips_dependencies() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+    write_task(&tasks_dir, "task-002", "Beta", "To Do");
+    write_task(&tasks_dir, "task-003", "Gamma", "To Do");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+    for dependency in ["task-001", "task-003"] {
+        let out = run(&["dep-add", "task-002", dependency]);
+        assert!(out.status.success(), "{:?}", out);
+    }
+
+    let out = run(&["remove", "task-001", "--dry-run"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("Would remove task-001 from task-002 dependencies"),
+        "{}",
+        stdout
+    );
+    assert!(tasks_dir.join("task-001 - Alpha.md").exists());
+
+    let out = run(&["remove", "task-001", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["mode"], "trash");
+    assert!(!tasks_dir.join("task-001 - Alpha.md").exists());
+    assert!(backlog_dir
+        .join("trash")
+        .join("task-001 - Alpha.md")
+        .is_file());
+    let tombstones =
+        fs::read_to_string(backlog_dir.join("trash").join("tombstones.jsonl")).expect("tombstones");
+    assert!(tombstones.contains("\"id\":\"task-001\""), "{}", tombstones);
+
+    let out = run(&["remove", "task-003", "--cancel"]);
+    assert!(out.status.success(), "{:?}", out);
+    let task_002 = fs::read_to_string(tasks_dir.join("task-002 - Beta.md")).expect("task-002");
+    assert!(task_002.contains("dependencies: []"), "{}", task_002);
+    let task_003 = fs::read_to_string(tasks_dir.join("task-003 - Gamma.md")).expect("task-003");
+    assert!(task_003.contains("status: Cancelled"), "{}", task_003);
+
+    let audit = fs::read_to_string(backlog_dir.join(".audit.log")).expect("audit");
+    assert_eq!(audit.matches("\"action\":\"task_remove\"").count(), 2);
+    assert!(!run(&["remove", "task-001"]).status.success());
+}
+
+#[test]
+fn move_renames_task_and_rewrites_references() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+    write_task(&tasks_dir, "task-002", "Beta", "To Do");
+    write_task(&tasks_dir, "task-003", "Gamma", "To Do");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+    let out = run(&["dep-add", "task-002", "task-001"]);
+    assert!(out.status.success(), "{:?}", out);
+
+    let out = run(&["move", "task-001", "task-auth-001", "--dry-run"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("Would move task-001 -> task-auth-001"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("Would rewrite task-002"), "{}", stdout);
+    assert!(!stdout.contains("task-003"), "{}", stdout);
+    assert!(tasks_dir.join("task-001 - Alpha.md").exists());
+
+    let out = run(&["move", "task-001", "task-auth-001", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    assert_eq!(report["touched"].as_array().map(Vec::len), Some(1));
+    assert!(!tasks_dir.join("task-001 - Alpha.md").exists());
+    let moved = fs::read_to_string(tasks_dir.join("task-auth-001 - Alpha.md")).expect("moved task");
+    assert!(moved.contains("id: task-auth-001"), "{}", moved);
+    let task_002 = fs::read_to_string(tasks_dir.join("task-002 - Beta.md")).expect("task-002");
+    assert!(task_002.contains("task-auth-001"), "{}", task_002);
+
+    let audit = fs::read_to_string(backlog_dir.join(".audit.log")).expect("audit");
+    assert!(audit.contains("\"action\":\"task_move\""), "{}", audit);
+    assert!(!run(&["move", "task-002", "task-003"]).status.success());
+}
+
+#[test]
+fn split_creates_subtasks_and_turns_the_original_into_an_epic() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("workmesh").join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Schema", "To Do");
+    write_task(&tasks_dir, "task-002", "Big feature", "To Do");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+    let out = run(&["dep-add", "task-002", "task-001"]);
+    assert!(out.status.success(), "{:?}", out);
+
+    let out = run(&[
+        "split",
+        "task-002",
+        "--into",
+        "Read path",
+        "Write path",
+        "--json",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let report: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let parts = report["parts"].as_array().expect("parts");
+    assert_eq!(parts.len(), 2);
+    for part in parts {
+        let text = fs::read_to_string(part["path"].as_str().expect("path")).expect("part");
+        assert!(text.contains("task-001"), "{}", text);
+        assert!(text.contains("parent:"), "{}", text);
+    }
+    let original = fs::read_to_string(tasks_dir.join("task-002 - Big feature.md")).expect("task");
+    assert!(original.contains("kind: epic"), "{}", original);
+
+    let out = run(&["children", "task-002"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("Read path"), "{}", stdout);
+    assert!(stdout.contains("Write path"), "{}", stdout);
+    assert!(!run(&["split", "task-002"]).status.success());
+}
+
+#[test]
+fn onboarding_new_creates_configured_checklist_and_status_tracks_it() {
+    let temp = TempDir::new().expect("tempdir");
+    fs::create_dir_all(temp.path().join("workmesh").join("tasks")).expect("tasks dir");
+    fs::write(
+        temp.path().join(".workmesh.toml"),
+        "[onboarding]\nlabel = \"newcomer\"\n\n\
+[[onboarding.items]]\nkey = \"laptop\"\ntitle = \"Laptop for {{who}}\"\n\n\
+[[onboarding.items]]\ntitle = \"Pair with a mentor\"\ndepends_on = [\"laptop\"]\n",
+    )
+    .expect("config");
+    let run = |args: &[&str]| {
+        bin()
+            .arg("--root")
+            .arg(temp.path())
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&["onboarding", "new", "--for", "alice", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let plan: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let items = plan["items"].as_array().expect("items");
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[0]["title"], "Laptop for alice");
+    assert_eq!(items[1]["dependencies"][0], items[0]["id"]);
+    let first = items[0]["id"].as_str().expect("id").to_string();
+
+    let out = run(&["show", &first, "--json"]);
+    let task: serde_json::Value = serde_json::from_slice(&out.stdout).expect("show json");
+    assert_eq!(task["assignee"][0], "alice");
+    assert_eq!(task["labels"][0], "newcomer");
+
+    assert!(!run(&["onboarding", "new", "--for", "@alice"])
+        .status
+        .success());
+    let out = run(&["set-status", &first, "Done"]);
+    assert!(out.status.success(), "{:?}", out);
+
+    let out = run(&["onboarding", "status", "--for", "Alice"]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(stdout.contains("1/2 done (50%)"), "{}", stdout);
+    assert!(stdout.contains("[>]"), "{}", stdout);
+}
+
+#[test]
+fn init_writes_config_hook_and_seed_task_without_prompting() {
+    let temp = TempDir::new().expect("tempdir");
+    let repo = temp.path().join("demo");
+    fs::create_dir_all(&repo).expect("repo dir");
+    for args in [
+        &["init", "-q", "-b", "main"][..],
+        &[
+            "-c",
+            "user.name=WorkMesh",
+            "-c",
+            "user.email=workmesh@example.com",
+            "commit",
+            "-q",
+            "--allow-empty",
+            "-m",
+            "init",
+        ],
+    ] {
+        let ok = Command::new("git")
+            .args(args)
+            .current_dir(&repo)
+            .status()
+            .expect("git")
+            .success();
+        assert!(ok, "git {:?}", args);
+    }
+    let run = |args: &[&str]| {
+        bin()
+            .env("WORKMESH_HOME", temp.path().join("home"))
+            .arg("--root")
+            .arg(&repo)
+            .args(args)
+            .output()
+            .expect("run")
+    };
+
+    let out = run(&[
+        "init",
+        "--statuses",
+        "Backlog,Doing,Done",
+        "--default-priority",
+        "P1",
+        "--initiative",
+        "auth",
+        "--hooks",
+        "--yes",
+    ]);
+    assert!(out.status.success(), "{:?}", out);
+    let stdout = String::from_utf8_lossy(&out.stdout);
+    assert!(
+        stdout.contains("config (workflow, default_priority, initiatives)"),
+        "{}",
+        stdout
+    );
+    assert!(stdout.contains("pre-commit hook |"), "{}", stdout);
+    assert!(stdout.contains("seed task |"), "{}", stdout);
+    assert!(stdout.contains("task-auth-001"), "{}", stdout);
+    assert!(repo.join("docs").join("projects").join("demo").is_dir());
+    let hook =
+        fs::read_to_string(repo.join(".git").join("hooks").join("pre-commit")).expect("hook");
+    assert!(hook.contains("workmesh validate"), "{}", hook);
+    let config = fs::read_to_string(repo.join(".workmesh.toml")).expect("config");
+    assert!(config.contains("default_priority = \"P1\""), "{}", config);
+
+    let out = run(&["add", "--title", "Second task", "--draft", "--json"]);
+    assert!(out.status.success(), "{:?}", out);
+    let created: serde_json::Value = serde_json::from_slice(&out.stdout).expect("json");
+    let path = created["path"].as_str().expect("path");
+    let task = fs::read_to_string(path).expect("task");
+    assert!(task.contains("priority: P1"), "{}", task);
+}
@@ -0,0 +1,87 @@
+use std::process::Command;
+
+use serde_json::Value;
+use tempfile::TempDir;
+
+fn bin() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_workmesh"));
+    command.env_remove("WORKMESH_ROOT");
+    command
+}
+
+fn write_task(dir: &std::path::Path, id: &str, title: &str) {
+    let content = format!(
+        "---\nid: {id}\ntitle: {title}\nkind: task\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\nassignee: []\n---\n\nBody\n",
+        id = id,
+        title = title
+    );
+    std::fs::write(
+        dir.join(format!("{} - {}.md", id, title.to_lowercase())),
+        content,
+    )
+    .expect("write task");
+}
+
+fn listed_ids(output: std::process::Output) -> Vec<String> {
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let parsed: Value = serde_json::from_slice(&output.stdout).expect("json");
+    parsed
+        .as_array()
+        .expect("array")
+        .iter()
+        .filter_map(|task| task["id"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[test]
+fn root_defaults_to_env_then_discovery_from_cwd() {
+    let repo = TempDir::new().expect("repo");
+    let tasks_dir = repo.path().join("workmesh").join("tasks");
+    std::fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha");
+    let nested = repo.path().join("src").join("module");
+    std::fs::create_dir_all(&nested).expect("nested dir");
+
+    let discovered = bin()
+        .current_dir(&nested)
+        .args(["list", "--json"])
+        .output()
+        .expect("list");
+    assert_eq!(listed_ids(discovered), vec!["task-001".to_string()]);
+
+    let elsewhere = TempDir::new().expect("elsewhere");
+    let from_env = bin()
+        .current_dir(elsewhere.path())
+        .env("WORKMESH_ROOT", repo.path())
+        .args(["list", "--json"])
+        .output()
+        .expect("list");
+    assert_eq!(listed_ids(from_env), vec!["task-001".to_string()]);
+
+    let snippet = bin()
+        .current_dir(&nested)
+        .args(["use", "--shell", "sh"])
+        .output()
+        .expect("use");
+    assert!(snippet.status.success());
+    let line = String::from_utf8_lossy(&snippet.stdout).trim().to_string();
+    let canonical = repo.path().canonicalize().expect("canonical");
+    assert_eq!(
+        line,
+        format!("export WORKMESH_ROOT='{}'", canonical.display())
+    );
+
+    let unset = bin()
+        .args(["use", "--unset"])
+        .output()
+        .expect("use --unset");
+    assert_eq!(
+        String::from_utf8_lossy(&unset.stdout).trim(),
+        "unset WORKMESH_ROOT"
+    );
+}
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime};
+use serde::Serialize;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::extract::epic_subtree;
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::timewindow::TimeWindow;
+
+/// Low to high; the first level means no activity in the period.
+const SPARK_LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActivityBucket {
+    Day,
+    Week,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityPoint {
+    /// `YYYY-MM-DD` for days, ISO week `YYYY-Www` for weeks.
+    pub period: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ActivityReport {
+    pub bucket: ActivityBucket,
+    /// Task ids in scope; empty means the whole backlog (including events without a task).
+    pub scope: Vec<String>,
+    pub total: usize,
+    /// Contiguous periods from the first event through today, zero-filled.
+    pub points: Vec<ActivityPoint>,
+    pub by_action: BTreeMap<String, usize>,
+    pub last_event: Option<String>,
+    /// Whole days since the last event in scope; large values flag abandoned work.
+    pub idle_days: Option<i64>,
+    pub sparkline: String,
+}
+
+/// Audit events per day/week for one task, an epic and its subtree, or the whole backlog,
+/// limited to events inside `window`.
+pub fn activity_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task_id: Option<&str>,
+    epic_id: Option<&str>,
+    bucket: ActivityBucket,
+    window: &TimeWindow,
+) -> ActivityReport {
+    let scope = activity_scope(tasks, task_id, epic_id);
+    let events: Vec<AuditEvent> = read_audit_events(backlog_dir)
+        .into_iter()
+        .filter(|event| window.contains_timestamp(&event.timestamp))
+        .collect();
+    activity_from_events(&events, &scope, bucket, Local::now().naive_local())
+}
+
+/// Ids covered by `--task` or `--epic` (the epic plus its descendants); empty for everything.
+pub fn activity_scope(tasks: &[Task], task_id: Option<&str>, epic_id: Option<&str>) -> Vec<String> {
+    if let Some(epic_id) = epic_id {
+        return match tasks
+            .iter()
+            .find(|task| task.id.eq_ignore_ascii_case(epic_id))
+        {
+            Some(epic) => epic_subtree(tasks, epic)
+                .into_iter()
+                .map(|task| task.id.clone())
+                .collect(),
+            None => vec![epic_id.to_string()],
+        };
+    }
+    task_id.map(|id| vec![id.to_string()]).unwrap_or_default()
+}
+
+fn activity_from_events(
+    events: &[AuditEvent],
+    scope: &[String],
+    bucket: ActivityBucket,
+    now: NaiveDateTime,
+) -> ActivityReport {
+    let wanted: HashSet<String> = scope.iter().map(|id| id.to_lowercase()).collect();
+    let mut counts: BTreeMap<NaiveDate, usize> = BTreeMap::new();
+    let mut by_action: BTreeMap<String, usize> = BTreeMap::new();
+    let mut last: Option<NaiveDateTime> = None;
+    for event in events {
+        if !wanted.is_empty() {
+            let in_scope = event
+                .task_id
+                .as_deref()
+                .is_some_and(|id| wanted.contains(&id.to_lowercase()));
+            if !in_scope {
+                continue;
+            }
+        }
+        let Some(time) = parse_task_date(&event.timestamp) else {
+            continue;
+        };
+        *counts.entry(period_start(time.date(), bucket)).or_default() += 1;
+        *by_action.entry(event.action.clone()).or_default() += 1;
+        last = Some(last.map_or(time, |last| last.max(time)));
+    }
+
+    let mut points = Vec::new();
+    if let Some(first) = counts.keys().next().copied() {
+        let end = period_start(now.date(), bucket).max(*counts.keys().last().unwrap_or(&first));
+        let step = match bucket {
+            ActivityBucket::Day => Duration::days(1),
+            ActivityBucket::Week => Duration::weeks(1),
+        };
+        let mut current = first;
+        while current <= end {
+            points.push(ActivityPoint {
+                period: period_label(current, bucket),
+                count: counts.get(&current).copied().unwrap_or(0),
+            });
+            current += step;
+        }
+    }
+
+    ActivityReport {
+        bucket,
+        scope: scope.to_vec(),
+        total: counts.values().sum(),
+        sparkline: sparkline(&points),
+        points,
+        by_action,
+        last_event: last.map(|time| time.format("%Y-%m-%d %H:%M").to_string()),
+        idle_days: last.map(|time| (now.date() - time.date()).num_days().max(0)),
+    }
+}
+
+fn period_start(date: NaiveDate, bucket: ActivityBucket) -> NaiveDate {
+    match bucket {
+        ActivityBucket::Day => date,
+        ActivityBucket::Week => {
+            date - Duration::days(i64::from(date.weekday().num_days_from_monday()))
+        }
+    }
+}
+
+fn period_label(start: NaiveDate, bucket: ActivityBucket) -> String {
+    match bucket {
+        ActivityBucket::Day => start.format("%Y-%m-%d").to_string(),
+        ActivityBucket::Week => {
+            let week = start.iso_week();
+            format!("{}-W{:02}", week.year(), week.week())
+        }
+    }
+}
+
+/// One character per period, scaled to the busiest period.
+pub fn sparkline(points: &[ActivityPoint]) -> String {
+    let max = points.iter().map(|point| point.count).max().unwrap_or(0);
+    points
+        .iter()
+        .map(|point| {
+            if point.count == 0 || max == 0 {
+                return SPARK_LEVELS[0];
+            }
+            let top = SPARK_LEVELS.len() - 1;
+            // Any activity shows at least the second level so it never reads as idle.
+            let level = 1 + (point.count * (top - 1)).div_ceil(max);
+            SPARK_LEVELS[level.min(top)]
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn event(timestamp: &str, action: &str, task_id: Option<&str>) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: action.to_string(),
+            task_id: task_id.map(str::to_string),
+            details: json!({}),
+        }
+    }
+
+    #[test]
+    fn activity_buckets_days_and_weeks_with_zero_fill() {
+        let events = vec![
+            event("2026-03-02 09:00", "add_task", Some("task-001")),
+            event("2026-03-02 10:00", "set_status", Some("task-001")),
+            event("2026-03-04 10:00", "add_note", Some("task-001")),
+            event("2026-03-04 11:00", "add_task", Some("task-002")),
+            event("2026-03-05 11:00", "checkpoint", None),
+        ];
+        let now = parse_task_date("2026-03-10 12:00").expect("now");
+
+        let scoped =
+            activity_from_events(&events, &["task-001".to_string()], ActivityBucket::Day, now);
+        assert_eq!(scoped.total, 3);
+        let counts: Vec<usize> = scoped.points.iter().map(|p| p.count).collect();
+        assert_eq!(counts, vec![2, 0, 1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(scoped.points[0].period, "2026-03-02");
+        assert_eq!(scoped.sparkline, "█▁▅▁▁▁▁▁▁");
+        assert_eq!(scoped.last_event.as_deref(), Some("2026-03-04 10:00"));
+        assert_eq!(scoped.idle_days, Some(6));
+        assert_eq!(scoped.by_action.get("add_task"), Some(&1));
+
+        let weekly = activity_from_events(&events, &[], ActivityBucket::Week, now);
+        assert_eq!(weekly.total, 5);
+        let labels: Vec<(&str, usize)> = weekly
+            .points
+            .iter()
+            .map(|p| (p.period.as_str(), p.count))
+            .collect();
+        assert_eq!(labels, vec![("2026-W10", 5), ("2026-W11", 0)]);
+    }
+
+    #[test]
+    fn activity_without_events_is_empty() {
+        let now = parse_task_date("2026-03-10 12:00").expect("now");
+        let report = activity_from_events(&[], &[], ActivityBucket::Day, now);
+        assert!(report.points.is_empty());
+        assert_eq!(report.sparkline, "");
+        assert_eq!(report.idle_days, None);
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+
+/// Short forms available without any configuration.
+pub const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("ls", "list"),
+    ("st", "set-status"),
+    ("nx", "next"),
+    ("rdy", "ready"),
+    ("cl", "claim"),
+    ("rl", "release"),
+    ("bd", "board"),
+];
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AliasError {
+    #[error("Alias '{name}' is empty")]
+    Empty { name: String },
+    #[error("Alias '{name}' has invalid quoting: {message}")]
+    Quoting { name: String, message: String },
+}
+
+/// Built-in short forms, overridden by global `[aliases]`, overridden by project `[aliases]`.
+pub fn resolve_aliases(repo_root: &Path) -> BTreeMap<String, String> {
+    let mut aliases: BTreeMap<String, String> = BUILTIN_ALIASES
+        .iter()
+        .map(|(name, expansion)| (name.to_string(), expansion.to_string()))
+        .collect();
+    let configured = [
+        load_global_config().and_then(|config| config.aliases),
+        load_config(repo_root).and_then(|config| config.aliases),
+    ];
+    for table in configured.into_iter().flatten() {
+        for (name, expansion) in table {
+            aliases.insert(name.trim().to_string(), expansion);
+        }
+    }
+    aliases
+}
+
+/// Split the alias for `name` into argv words, or `None` when `name` is not an alias.
+///
+/// Expansion is a single step: an alias cannot refer to another alias.
+pub fn expand_alias(
+    aliases: &BTreeMap<String, String>,
+    name: &str,
+) -> Result<Option<Vec<String>>, AliasError> {
+    let Some(expansion) = aliases.get(name) else {
+        return Ok(None);
+    };
+    let words = shell_words::split(expansion).map_err(|err| AliasError::Quoting {
+        name: name.to_string(),
+        message: err.to_string(),
+    })?;
+    if words.is_empty() {
+        return Err(AliasError::Empty {
+            name: name.to_string(),
+        });
+    }
+    Ok(Some(words))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    #[test]
+    fn expand_alias_splits_words_and_rejects_empty() {
+        let mut aliases = BTreeMap::new();
+        aliases.insert("ls".to_string(), "list --limit 30".to_string());
+        aliases.insert("todo".to_string(), "list --status 'To Do'".to_string());
+        aliases.insert("blank".to_string(), "  ".to_string());
+
+        assert_eq!(
+            expand_alias(&aliases, "ls").expect("ls"),
+            Some(vec![
+                "list".to_string(),
+                "--limit".to_string(),
+                "30".to_string()
+            ])
+        );
+        assert_eq!(
+            expand_alias(&aliases, "todo").expect("todo"),
+            Some(vec![
+                "list".to_string(),
+                "--status".to_string(),
+                "To Do".to_string()
+            ])
+        );
+        assert_eq!(expand_alias(&aliases, "show").expect("show"), None);
+        assert_eq!(
+            expand_alias(&aliases, "blank"),
+            Err(AliasError::Empty {
+                name: "blank".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn project_aliases_override_builtins() {
+        let temp = TempDir::new().expect("tempdir");
+        std::fs::write(
+            temp.path().join(".workmesh.toml"),
+            "[aliases]\nls = \"list --limit 30\"\nst = \"set-status\"\n",
+        )
+        .expect("write config");
+
+        let aliases = resolve_aliases(temp.path());
+        assert_eq!(
+            aliases.get("ls").map(String::as_str),
+            Some("list --limit 30")
+        );
+        assert_eq!(aliases.get("bd").map(String::as_str), Some("board"));
+    }
+}
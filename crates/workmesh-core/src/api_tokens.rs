@@ -0,0 +1,346 @@
+//! Scoped API tokens (`~/.workmesh/tokens/`).
+//!
+//! `token create` generates a random bearer token, prints it once, and stores only its hash
+//! (see [`token_hash`]) with a role, optional task scopes, and an optional expiry, one JSON file
+//! per token. A server that accepts bearer tokens looks the presented token up with
+//! [`find_token`]; a match grants the stored role. Scopes limit mutations to the named tasks and
+//! every task under them by `parent`, so an integration can only touch the subtree it owns.
+//! Hashes listed under `[roles.tokens]` keep working and are never scoped.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use chrono::{DateTime, Duration, Local, TimeZone};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::resolve_workmesh_home_dir;
+use crate::roles::{token_hash, Grant, Role};
+use crate::storage::{atomic_write_text, StorageError};
+use crate::task::Task;
+use crate::timestamps::{format_timestamp, parse_stored_timestamp};
+use crate::timewindow::parse_timestamp_ref;
+use crate::views::scope_ids_for_epic;
+
+/// Prefix of generated tokens, so they are easy to spot in logs and secret scanners.
+pub const TOKEN_PREFIX: &str = "wm_";
+const SECRET_LEN: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum ApiTokenError {
+    #[error("WorkMesh home directory not found (set WORKMESH_HOME)")]
+    NoHome,
+    #[error("Token not found: {0}")]
+    NotFound(String),
+    #[error("Failed to generate a token")]
+    Random,
+    #[error("Invalid expiry: {0} (expected 12h, 30d, 8w, YYYY-MM-DD, or RFC3339)")]
+    InvalidExpiry(String),
+    #[error("{0}")]
+    Storage(#[from] StorageError),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiToken {
+    /// `tok-` plus the start of the hash; safe to show and used by `token revoke`.
+    pub id: String,
+    /// `sha256:<hex>` of the token.
+    pub hash: String,
+    pub role: Role,
+    /// Task ids (lowercased) whose subtrees the token may change; empty means unrestricted.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub created_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<String>,
+}
+
+impl ApiToken {
+    pub fn is_expired(&self, now: DateTime<Local>) -> bool {
+        self.expires_at
+            .as_deref()
+            .and_then(parse_stored_timestamp)
+            .is_some_and(|expires| expires <= now)
+    }
+
+    pub fn grant(&self, identity: Option<String>) -> Grant {
+        Grant {
+            role: self.role,
+            source: "api_token",
+            identity,
+        }
+    }
+
+    /// Reject a mutation of any task in `ids` that falls outside the token's scopes.
+    pub fn check_scope<S: AsRef<str>>(&self, tasks: &[Task], ids: &[S]) -> Result<(), ScopeError> {
+        if self.scopes.is_empty() {
+            return Ok(());
+        }
+        let mut allowed = std::collections::HashSet::new();
+        for scope in &self.scopes {
+            allowed.extend(scope_ids_for_epic(tasks, scope));
+        }
+        let outside: Vec<String> = ids
+            .iter()
+            .map(|id| id.as_ref().trim().to_string())
+            .filter(|id| !allowed.contains(&id.to_lowercase()))
+            .collect();
+        if ids.is_empty() || !outside.is_empty() {
+            return Err(ScopeError {
+                token: self.id.clone(),
+                scopes: self.scopes.clone(),
+                outside,
+            });
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Error)]
+#[error("{}", self.message())]
+pub struct ScopeError {
+    pub token: String,
+    pub scopes: Vec<String>,
+    /// Requested task ids outside the scopes; empty when the call names no task at all.
+    pub outside: Vec<String>,
+}
+
+impl ScopeError {
+    fn message(&self) -> String {
+        if self.outside.is_empty() {
+            format!(
+                "Token {} is scoped to {} and may only change tasks it names",
+                self.token,
+                self.scopes.join(", ")
+            )
+        } else {
+            format!(
+                "Token {} is scoped to {}; outside scope: {}",
+                self.token,
+                self.scopes.join(", "),
+                self.outside.join(", ")
+            )
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "ok": false,
+            "error": "forbidden",
+            "code": "scope_denied",
+            "message": self.to_string(),
+            "token": self.token,
+            "scopes": self.scopes,
+            "outside": self.outside,
+        })
+    }
+}
+
+pub fn tokens_dir() -> Option<PathBuf> {
+    resolve_workmesh_home_dir().map(|home| home.join("tokens"))
+}
+
+/// `12h`, `30d`, `8w` from `now`, or an absolute date/timestamp.
+pub fn parse_expiry(value: &str, now: DateTime<Local>) -> Result<DateTime<Local>, ApiTokenError> {
+    let invalid = || ApiTokenError::InvalidExpiry(value.to_string());
+    let trimmed = value.trim().to_lowercase();
+    let split = trimmed
+        .find(|ch: char| !ch.is_ascii_digit())
+        .unwrap_or(trimmed.len());
+    if split > 0 {
+        let (number, unit) = trimmed.split_at(split);
+        let number: i64 = number.parse().map_err(|_| invalid())?;
+        let span = match unit {
+            "h" => Some(Duration::hours(number)),
+            "d" => Some(Duration::days(number)),
+            "w" => Some(Duration::weeks(number)),
+            _ => None,
+        };
+        if let Some(span) = span {
+            return Ok(now + span);
+        }
+    }
+    parse_timestamp_ref(value)
+        .and_then(|naive| Local.from_local_datetime(&naive).earliest())
+        .ok_or_else(invalid)
+}
+
+/// Generate a token, store its hash in `dir`, and return the record with the token itself.
+pub fn create_token(
+    dir: &Path,
+    role: Role,
+    scopes: &[String],
+    expires_at: Option<DateTime<Local>>,
+    name: Option<&str>,
+) -> Result<(ApiToken, String), ApiTokenError> {
+    let mut bytes = [0u8; SECRET_LEN];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| ApiTokenError::Random)?;
+    let secret = format!("{}{}", TOKEN_PREFIX, URL_SAFE_NO_PAD.encode(bytes));
+    let hash = token_hash(&secret);
+    let digest = hash.trim_start_matches("sha256:");
+    let mut scopes: Vec<String> = scopes
+        .iter()
+        .map(|scope| scope.trim().to_lowercase())
+        .filter(|scope| !scope.is_empty())
+        .collect();
+    scopes.sort();
+    scopes.dedup();
+    let token = ApiToken {
+        id: format!("tok-{}", &digest[..12]),
+        hash,
+        role,
+        scopes,
+        name: name
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+        created_at: format_timestamp(Local::now()),
+        expires_at: expires_at.map(format_timestamp),
+    };
+    fs::create_dir_all(dir)?;
+    atomic_write_text(
+        &dir.join(format!("{}.json", token.id)),
+        &serde_json::to_string_pretty(&token)?,
+    )?;
+    Ok((token, secret))
+}
+
+/// Stored tokens, expired ones included, oldest first.
+pub fn list_tokens(dir: &Path) -> Vec<ApiToken> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut tokens: Vec<ApiToken> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|raw| serde_json::from_str(&raw).ok())
+        .collect();
+    tokens.sort_by(|a, b| {
+        a.created_at
+            .cmp(&b.created_at)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    tokens
+}
+
+/// Delete the token with `id`.
+pub fn revoke_token(dir: &Path, id: &str) -> Result<ApiToken, ApiTokenError> {
+    let token = list_tokens(dir)
+        .into_iter()
+        .find(|token| token.id.eq_ignore_ascii_case(id.trim()))
+        .ok_or_else(|| ApiTokenError::NotFound(id.trim().to_string()))?;
+    fs::remove_file(dir.join(format!("{}.json", token.id)))?;
+    Ok(token)
+}
+
+/// The stored, unexpired token matching `secret`.
+pub fn find_token(dir: &Path, secret: &str, now: DateTime<Local>) -> Option<ApiToken> {
+    if secret.trim().is_empty() {
+        return None;
+    }
+    let hash = token_hash(secret);
+    list_tokens(dir)
+        .into_iter()
+        .find(|token| token.hash.eq_ignore_ascii_case(&hash) && !token.is_expired(now))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use tempfile::TempDir;
+
+    fn write_task(dir: &Path, id: &str, parent: &str) {
+        fs::write(
+            dir.join(format!("{id} - task.md")),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+                 relationships:\n  parent: [{parent}]\n---\n\nBody\n"
+            ),
+        )
+        .expect("write task");
+    }
+
+    #[test]
+    fn tokens_are_stored_hashed_and_expire() {
+        let temp = TempDir::new().expect("tempdir");
+        let dir = temp.path().join("tokens");
+        let now = Local::now();
+        let (token, secret) = create_token(
+            &dir,
+            Role::Contributor,
+            &["Epic-12".to_string()],
+            Some(parse_expiry("30d", now).expect("expiry")),
+            Some("ci"),
+        )
+        .expect("create");
+        assert!(secret.starts_with(TOKEN_PREFIX));
+        assert_eq!(token.scopes, vec!["epic-12".to_string()]);
+        let stored = fs::read_to_string(dir.join(format!("{}.json", token.id))).expect("file");
+        assert!(!stored.contains(&secret));
+
+        assert_eq!(find_token(&dir, &secret, now), Some(token.clone()));
+        assert_eq!(find_token(&dir, "wm_wrong", now), None);
+        assert_eq!(find_token(&dir, &secret, now + Duration::days(31)), None);
+        assert_eq!(token.grant(None).source, "api_token");
+
+        assert!(parse_expiry("2026-12-31", now).is_ok());
+        assert!(parse_expiry("soon", now).is_err());
+
+        assert_eq!(revoke_token(&dir, &token.id).expect("revoke"), token);
+        assert!(list_tokens(&dir).is_empty());
+        assert!(matches!(
+            revoke_token(&dir, &token.id),
+            Err(ApiTokenError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn scoped_tokens_only_change_their_subtree() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(&tasks_dir, "epic-12", "");
+        write_task(&tasks_dir, "task-001", "epic-12");
+        write_task(&tasks_dir, "task-002", "task-001");
+        write_task(&tasks_dir, "task-003", "");
+        let tasks = load_tasks(&backlog);
+        let (token, _) = create_token(
+            &temp.path().join("tokens"),
+            Role::Contributor,
+            &["epic-12".to_string()],
+            None,
+            None,
+        )
+        .expect("create");
+
+        assert!(token.check_scope(&tasks, &["epic-12", "TASK-002"]).is_ok());
+        let err = token
+            .check_scope(&tasks, &["task-001", "task-003"])
+            .unwrap_err();
+        assert_eq!(err.outside, vec!["task-003".to_string()]);
+        assert_eq!(err.to_json()["code"], "scope_denied");
+        let none: [&str; 0] = [];
+        assert!(token.check_scope(&tasks, &none).is_err());
+
+        let unscoped = ApiToken {
+            scopes: Vec::new(),
+            ..token
+        };
+        assert!(unscoped.check_scope(&tasks, &none).is_ok());
+    }
+}
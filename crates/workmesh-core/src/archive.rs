@@ -2,9 +2,17 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use chrono::{Datelike, Local, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::task::{archive_root_for_root, Task};
+use crate::index::{index_path, refresh_archive_index, verify_index};
+use crate::storage::write_string_atomic;
+use crate::task::{archive_root_for_root, parse_task_file, Task};
+use crate::task_ops::now_timestamp;
+use crate::timewindow::{parse_time_ref, parse_timestamp_ref};
+
+/// Moves per journal update when callers do not pick a chunk size.
+pub const DEFAULT_ARCHIVE_CHUNK_SIZE: usize = 200;
 
 #[derive(Debug, Error)]
 pub enum ArchiveError {
@@ -12,6 +20,21 @@ pub enum ArchiveError {
     MissingPath(String),
     #[error("Failed to move task: {0}")]
     Io(#[from] std::io::Error),
+    #[error("Failed to write archive journal: {0}")]
+    Journal(#[from] serde_json::Error),
+    #[error(
+        "An interrupted archive run is pending ({}); resume it with `archive --resume` or delete \
+the journal to abandon it",
+        .0.display()
+    )]
+    Pending(PathBuf),
+}
+
+/// An archive `before` cutoff: a date or any [`parse_time_ref`] form (`30d`, `2w`,
+/// `yesterday`, RFC3339), resolved against `now` exactly like `--since` and taken as a local
+/// calendar date.
+pub fn parse_archive_before(value: &str, now: NaiveDateTime) -> Option<NaiveDate> {
+    parse_time_ref(value, now).map(|time| time.date())
 }
 
 #[derive(Debug, Clone)]
@@ -21,21 +44,88 @@ pub struct ArchiveOptions {
     pub statuses: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArchiveMove {
+    pub task_id: String,
+    pub from: PathBuf,
+    pub to: PathBuf,
+}
+
 #[derive(Debug, Clone)]
 pub struct ArchiveResult {
     pub archived: Vec<String>,
     pub skipped: Vec<String>,
     pub archive_dir: PathBuf,
+    pub moves: Vec<ArchiveMove>,
 }
 
+/// Planned moves of an in-flight run, rewritten after every chunk. A run killed mid-chunk is
+/// resumed from the plan: moves whose source is gone and whose target exists count as done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveJournal {
+    pub started_at: String,
+    pub completed: usize,
+    pub moves: Vec<ArchiveMove>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ArchiveProgress {
+    pub chunk: usize,
+    pub chunks: usize,
+    pub done: usize,
+    pub total: usize,
+    pub archived: usize,
+    pub skipped: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ArchiveVerification {
+    pub ok: bool,
+    pub checked: usize,
+    /// Archived files that no longer parse as tasks.
+    pub unparsable: Vec<String>,
+    /// Archived task ids whose file is missing from the archive.
+    pub missing: Vec<String>,
+    /// Archived task ids whose source file is still under `tasks/`.
+    pub left_behind: Vec<String>,
+    /// Archived task ids absent from the archive index.
+    pub not_indexed: Vec<String>,
+    /// Task index problems (`missing:`/`stale:`/`extra:` paths) after the run.
+    pub index_issues: Vec<String>,
+}
+
+pub fn archive_journal_path(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join(".archive-journal.json")
+}
+
+pub fn load_archive_journal(backlog_dir: &Path) -> Option<ArchiveJournal> {
+    let raw = fs::read_to_string(archive_journal_path(backlog_dir)).ok()?;
+    serde_json::from_str(&raw).ok()
+}
+
+fn write_journal(backlog_dir: &Path, journal: &ArchiveJournal) -> Result<(), ArchiveError> {
+    let raw = serde_json::to_string_pretty(journal)?;
+    write_string_atomic(&archive_journal_path(backlog_dir), &raw)?;
+    Ok(())
+}
+
+/// Archive matching tasks in one pass (no progress reporting).
 pub fn archive_tasks(
     backlog_dir: &Path,
     tasks: &[Task],
     options: &ArchiveOptions,
 ) -> Result<ArchiveResult, ArchiveError> {
+    let moves = plan_archive(backlog_dir, tasks, options)?;
+    run_archive(backlog_dir, moves, DEFAULT_ARCHIVE_CHUNK_SIZE, |_| {})
+}
+
+/// Moves for tasks matching `options`, targeting `archive/<YYYY-MM>/` by last update.
+pub fn plan_archive(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    options: &ArchiveOptions,
+) -> Result<Vec<ArchiveMove>, ArchiveError> {
     let archive_root = archive_root_for_root(backlog_dir);
-    let mut archived = Vec::new();
-    let skipped = Vec::new();
     let allowed_statuses = if options.statuses.is_empty() {
         default_archive_statuses()
             .iter()
@@ -49,6 +139,7 @@ pub fn archive_tasks(
             .collect::<std::collections::HashSet<_>>()
     };
 
+    let mut moves = Vec::new();
     for task in tasks {
         if !allowed_statuses.contains(&normalize_status(&task.status)) {
             continue;
@@ -62,25 +153,179 @@ pub fn archive_tasks(
             .as_ref()
             .ok_or_else(|| ArchiveError::MissingPath(task.id.clone()))?;
         let month_dir = format!("{:04}-{:02}", task_date.year(), task_date.month());
-        let target_dir = archive_root.join(month_dir);
-        fs::create_dir_all(&target_dir)?;
-        let target = target_dir.join(
+        let target = archive_root.join(month_dir).join(
             path.file_name()
                 .unwrap_or_default()
                 .to_string_lossy()
                 .to_string(),
         );
-        fs::rename(path, &target)?;
-        archived.push(task.id.clone());
+        moves.push(ArchiveMove {
+            task_id: task.id.clone(),
+            from: path.clone(),
+            to: target,
+        });
+    }
+    Ok(moves)
+}
+
+/// Execute `moves` in chunks of `chunk_size`, journaling progress so an interrupted run can be
+/// resumed. Refuses to start while another run's journal is pending.
+pub fn run_archive<F>(
+    backlog_dir: &Path,
+    moves: Vec<ArchiveMove>,
+    chunk_size: usize,
+    on_progress: F,
+) -> Result<ArchiveResult, ArchiveError>
+where
+    F: FnMut(&ArchiveProgress),
+{
+    let journal_path = archive_journal_path(backlog_dir);
+    if journal_path.exists() {
+        return Err(ArchiveError::Pending(journal_path));
+    }
+    let journal = ArchiveJournal {
+        started_at: now_timestamp(),
+        completed: 0,
+        moves,
+    };
+    execute_journal(backlog_dir, journal, chunk_size, on_progress)
+}
+
+/// Finish the run recorded in the journal; `None` when no run is pending.
+pub fn resume_archive<F>(
+    backlog_dir: &Path,
+    chunk_size: usize,
+    on_progress: F,
+) -> Result<Option<ArchiveResult>, ArchiveError>
+where
+    F: FnMut(&ArchiveProgress),
+{
+    let Some(journal) = load_archive_journal(backlog_dir) else {
+        return Ok(None);
+    };
+    execute_journal(backlog_dir, journal, chunk_size, on_progress).map(Some)
+}
+
+fn execute_journal<F>(
+    backlog_dir: &Path,
+    mut journal: ArchiveJournal,
+    chunk_size: usize,
+    mut on_progress: F,
+) -> Result<ArchiveResult, ArchiveError>
+where
+    F: FnMut(&ArchiveProgress),
+{
+    let chunk_size = chunk_size.max(1);
+    let total = journal.moves.len();
+    let chunks = total.div_ceil(chunk_size);
+    let mut archived = Vec::new();
+    let mut skipped = Vec::new();
+    if total > 0 {
+        write_journal(backlog_dir, &journal)?;
+    }
+
+    let moves = journal.moves.clone();
+    for (chunk, batch) in moves.chunks(chunk_size).enumerate() {
+        for step in batch {
+            match (step.from.exists(), step.to.exists()) {
+                (true, false) => {
+                    if let Some(parent) = step.to.parent() {
+                        fs::create_dir_all(parent)?;
+                    }
+                    fs::rename(&step.from, &step.to)?;
+                    archived.push(step.task_id.clone());
+                }
+                // Moved before an interruption.
+                (false, true) => archived.push(step.task_id.clone()),
+                // Never clobber an existing archive file; a vanished source has nothing to move.
+                _ => skipped.push(step.task_id.clone()),
+            }
+        }
+        journal.completed += batch.len();
+        write_journal(backlog_dir, &journal)?;
+        on_progress(&ArchiveProgress {
+            chunk: chunk + 1,
+            chunks,
+            done: journal.completed,
+            total,
+            archived: archived.len(),
+            skipped: skipped.len(),
+        });
     }
 
+    let _ = fs::remove_file(archive_journal_path(backlog_dir));
+    let successful: std::collections::HashSet<&String> = archived.iter().collect();
     Ok(ArchiveResult {
+        moves: journal
+            .moves
+            .into_iter()
+            .filter(|step| successful.contains(&step.task_id))
+            .collect(),
         archived,
         skipped,
-        archive_dir: archive_root,
+        archive_dir: archive_root_for_root(backlog_dir),
     })
 }
 
+/// Check that every moved file parses at its new location, nothing was left in `tasks/`, the
+/// archive index lists each task, and the task index matches the remaining tasks.
+pub fn verify_archive(backlog_dir: &Path, result: &ArchiveResult) -> ArchiveVerification {
+    let mut report = ArchiveVerification::default();
+    for step in &result.moves {
+        report.checked += 1;
+        if step.from.exists() {
+            report.left_behind.push(step.task_id.clone());
+        }
+        if !step.to.exists() {
+            report.missing.push(step.task_id.clone());
+        } else if parse_task_file(&step.to).is_err() {
+            report.unparsable.push(step.to.display().to_string());
+        }
+    }
+
+    match refresh_archive_index(backlog_dir) {
+        Ok(entries) => {
+            let indexed: std::collections::HashSet<String> = entries
+                .iter()
+                .map(|entry| entry.id.to_lowercase())
+                .collect();
+            report.not_indexed = result
+                .moves
+                .iter()
+                .filter(|step| step.to.exists())
+                .filter(|step| !indexed.contains(&step.task_id.to_lowercase()))
+                .map(|step| step.task_id.clone())
+                .collect();
+        }
+        Err(err) => report.index_issues.push(format!("archive index: {}", err)),
+    }
+    // Repos that never built a task index have nothing to drift.
+    if index_path(backlog_dir).exists() {
+        match verify_index(backlog_dir) {
+            Ok(index) => {
+                let issues = [
+                    ("missing", index.missing),
+                    ("stale", index.stale),
+                    ("extra", index.extra),
+                ];
+                for (kind, paths) in issues {
+                    report
+                        .index_issues
+                        .extend(paths.into_iter().map(|path| format!("{}: {}", kind, path)));
+                }
+            }
+            Err(err) => report.index_issues.push(format!("task index: {}", err)),
+        }
+    }
+
+    report.ok = report.unparsable.is_empty()
+        && report.missing.is_empty()
+        && report.left_behind.is_empty()
+        && report.not_indexed.is_empty()
+        && report.index_issues.is_empty();
+    report
+}
+
 pub fn default_archive_statuses() -> &'static [&'static str] {
     &["Done", "Cancelled", "Canceled", "Won't Do", "Wont Do"]
 }
@@ -93,14 +338,10 @@ fn normalize_status(value: &str) -> String {
         .collect()
 }
 
+/// The local calendar date of a stored date or timestamp, so the `before` cutoff compares
+/// like with like whatever zone the task was last edited in.
 fn parse_task_date(value: &str) -> Option<NaiveDate> {
-    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
-        return Some(date);
-    }
-    if let Ok(date_time) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M") {
-        return Some(date_time.date());
-    }
-    None
+    parse_timestamp_ref(value).map(|time| time.date())
 }
 
 fn task_date(task: &Task) -> Option<NaiveDate> {
@@ -246,4 +487,79 @@ mod tests {
             });
         assert!(todo_still_present);
     }
+
+    #[test]
+    fn archive_resumes_interrupted_run_and_verifies() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        for (id, title) in [
+            ("task-001", "First"),
+            ("task-002", "Second"),
+            ("task-003", "Third"),
+        ] {
+            create_task_file(&tasks_dir, id, title, "Done", "P2", "Phase1", &[], &[], &[])
+                .expect("create");
+        }
+        let mut tasks = load_tasks(&backlog_dir);
+        for task in &mut tasks {
+            task.updated_date = Some("2024-01-15 10:00".to_string());
+        }
+        let options = ArchiveOptions {
+            before: NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            statuses: Vec::new(),
+        };
+        let moves = plan_archive(&backlog_dir, &tasks, &options).expect("plan");
+        assert_eq!(moves.len(), 3);
+
+        // Simulate a run killed after its first move.
+        write_journal(
+            &backlog_dir,
+            &ArchiveJournal {
+                started_at: now_timestamp(),
+                completed: 0,
+                moves: moves.clone(),
+            },
+        )
+        .expect("journal");
+        fs::create_dir_all(moves[0].to.parent().unwrap()).expect("archive dir");
+        fs::rename(&moves[0].from, &moves[0].to).expect("first move");
+        assert!(matches!(
+            archive_tasks(&backlog_dir, &tasks, &options),
+            Err(ArchiveError::Pending(_))
+        ));
+
+        let mut progress = Vec::new();
+        let result = resume_archive(&backlog_dir, 2, |step| progress.push(step.done))
+            .expect("resume")
+            .expect("pending run");
+        assert_eq!(progress, vec![2, 3]);
+        assert_eq!(result.archived.len(), 3);
+        assert!(load_archive_journal(&backlog_dir).is_none());
+
+        let verification = verify_archive(&backlog_dir, &result);
+        assert!(verification.ok, "{:?}", verification);
+        assert_eq!(verification.checked, 3);
+    }
+
+    #[test]
+    fn archive_cutoffs_and_task_dates_read_every_stored_form() {
+        let now = NaiveDate::from_ymd_opt(2026, 3, 15)
+            .and_then(|date| date.and_hms_opt(9, 30, 0))
+            .expect("now");
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(parse_archive_before("30d", now), date(2026, 2, 13));
+        assert_eq!(parse_archive_before("yesterday", now), date(2026, 3, 14));
+        assert_eq!(parse_archive_before("2026-01-31", now), date(2026, 1, 31));
+        assert_eq!(parse_archive_before("soon", now), None);
+
+        assert_eq!(parse_task_date("2026-02-01 10:00"), date(2026, 2, 1));
+        let stored = "2026-02-01T10:00:00+00:00";
+        let local = chrono::DateTime::parse_from_rfc3339(stored)
+            .expect("rfc3339")
+            .with_timezone(&chrono::Local)
+            .date_naive();
+        assert_eq!(parse_task_date(stored), Some(local));
+    }
 }
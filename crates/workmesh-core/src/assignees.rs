@@ -0,0 +1,164 @@
+//! Bulk handle changes: `assignee rename old new` rewrites every place a task names a person
+//! (assignee lists, lease owners, watchers) so a renamed account keeps its work.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::inbox::{normalize_user, WATCHERS_FIELD};
+use crate::task::{parse_list_value, Task, TaskParseError};
+use crate::task_ops::{now_timestamp, set_list_field, update_task_field, FieldValue};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssigneeRenameChange {
+    pub id: String,
+    pub title: String,
+    pub path: Option<PathBuf>,
+    /// Front matter fields that name the old handle: `assignee`, `lease_owner`, `watchers`.
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AssigneeRenameReport {
+    pub from: String,
+    pub to: String,
+    pub applied: bool,
+    pub changes: Vec<AssigneeRenameChange>,
+}
+
+/// Replace `from` with `to` in `list`, keeping other entries in place and dropping a duplicate
+/// when `to` is already listed. `None` when `from` is not in the list.
+fn renamed_list(list: &[String], from: &str, to: &str) -> Option<Vec<String>> {
+    if !list.iter().any(|entry| normalize_user(entry) == from) {
+        return None;
+    }
+    let mut out: Vec<String> = Vec::new();
+    for entry in list {
+        let next = if normalize_user(entry) == from {
+            to.to_string()
+        } else {
+            entry.clone()
+        };
+        if !out
+            .iter()
+            .any(|seen| normalize_user(seen) == normalize_user(&next))
+        {
+            out.push(next);
+        }
+    }
+    Some(out)
+}
+
+/// Rename handle `from` to `to` across `tasks`. Handles match case-insensitively and with or
+/// without a leading `@`. Nothing is written unless `apply`; applied changes touch
+/// `updated_date`.
+pub fn rename_assignee(
+    tasks: &[Task],
+    from: &str,
+    to: &str,
+    apply: bool,
+) -> Result<AssigneeRenameReport, TaskParseError> {
+    let old = normalize_user(from);
+    let new = to.trim().trim_start_matches('@').trim().to_string();
+    let mut changes = Vec::new();
+    for task in tasks {
+        let assignee = renamed_list(&task.assignee, &old, &new);
+        let watchers = renamed_list(
+            &parse_list_value(task.extra.get(WATCHERS_FIELD)),
+            &old,
+            &new,
+        );
+        let lease = task
+            .lease
+            .as_ref()
+            .is_some_and(|lease| normalize_user(&lease.owner) == old);
+        let mut fields = Vec::new();
+        if assignee.is_some() {
+            fields.push("assignee".to_string());
+        }
+        if lease {
+            fields.push("lease_owner".to_string());
+        }
+        if watchers.is_some() {
+            fields.push(WATCHERS_FIELD.to_string());
+        }
+        if fields.is_empty() {
+            continue;
+        }
+        if apply {
+            if let Some(path) = task.file_path.as_deref() {
+                if let Some(list) = assignee {
+                    set_list_field(path, "assignee", list)?;
+                }
+                if lease {
+                    update_task_field(path, "lease_owner", Some(FieldValue::Scalar(new.clone())))?;
+                }
+                if let Some(list) = watchers {
+                    set_list_field(path, WATCHERS_FIELD, list)?;
+                }
+                update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+            }
+        }
+        changes.push(AssigneeRenameChange {
+            id: task.id.clone(),
+            title: task.title.clone(),
+            path: task.file_path.clone(),
+            fields,
+        });
+    }
+    Ok(AssigneeRenameReport {
+        from: old,
+        to: new,
+        applied: apply,
+        changes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn rename_previews_then_rewrites_assignees_leases_and_watchers() {
+        let temp = TempDir::new().expect("tempdir");
+        let tasks_dir = temp.path().join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::write(
+            tasks_dir.join("task-001 - a.md"),
+            "---\nid: task-001\ntitle: A\nstatus: In Progress\npriority: P2\nphase: Phase1\n\
+             dependencies: []\nlabels: []\nassignee: [Alice, bob]\nwatchers: [\"@alice\"]\n\
+             lease_owner: alice\n---\n\nbody\n",
+        )
+        .expect("write");
+        fs::write(
+            tasks_dir.join("task-002 - b.md"),
+            "---\nid: task-002\ntitle: B\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+             dependencies: []\nlabels: []\nassignee: [bob]\n---\n\nbody\n",
+        )
+        .expect("write");
+        let backlog = temp.path().join("workmesh");
+
+        let preview = rename_assignee(&load_tasks(&backlog), "@alice", "bob", false).expect("dry");
+        assert_eq!(preview.changes.len(), 1);
+        assert_eq!(
+            preview.changes[0].fields,
+            vec!["assignee", "lease_owner", "watchers"]
+        );
+        assert_eq!(load_tasks(&backlog)[0].assignee, vec!["Alice", "bob"]);
+
+        rename_assignee(&load_tasks(&backlog), "alice", "bob", true).expect("apply");
+        let task = &load_tasks(&backlog)[0];
+        assert_eq!(task.assignee, vec!["bob"]);
+        assert_eq!(
+            task.lease.as_ref().map(|lease| lease.owner.as_str()),
+            Some("bob")
+        );
+        assert_eq!(
+            parse_list_value(task.extra.get(WATCHERS_FIELD)),
+            vec!["bob"]
+        );
+    }
+}
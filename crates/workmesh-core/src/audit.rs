@@ -1,10 +1,15 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use thiserror::Error;
 
-use crate::storage::{append_jsonl_locked_with_key, ResourceKey, StorageError};
+use crate::audit_signing::{chained_line, AuditKey, AuditSigningError};
+use crate::inbox::normalize_user;
+use crate::storage::{append_line_after_last_with_key, ResourceKey, StorageError};
+use crate::timestamps::cmp_timestamps;
+use crate::timewindow::TimeWindow;
 
 #[derive(Debug, Error)]
 pub enum AuditError {
@@ -12,6 +17,8 @@ pub enum AuditError {
     Storage(#[from] StorageError),
     #[error("Failed to serialize audit event: {0}")]
     Serialize(#[from] serde_json::Error),
+    #[error(transparent)]
+    Signing(#[from] AuditSigningError),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -23,25 +30,47 @@ pub struct AuditEvent {
     pub details: Value,
 }
 
+/// Per-actor totals over the audit log (`workmesh audit actors`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ActorStats {
+    /// `USER` for CLI changes, `mcp:<client>` for MCP clients that identified themselves.
+    pub actor: String,
+    pub events: usize,
+    pub tasks: usize,
+    pub first_at: String,
+    pub last_at: String,
+    /// MCP connections (`mcp_session` in event details) seen for this actor.
+    pub sessions: usize,
+    pub actions: BTreeMap<String, usize>,
+}
+
+/// `actor` after `[actor_aliases]`; keys match case-insensitively, with or without `@`.
+pub fn aliased_actor(actor: String, aliases: &HashMap<String, String>) -> String {
+    let key = normalize_user(&actor);
+    aliases
+        .iter()
+        .find(|(from, _)| normalize_user(from) == key)
+        .map(|(_, to)| to.clone())
+        .unwrap_or(actor)
+}
+
 pub fn audit_log_path(backlog_dir: &Path) -> PathBuf {
     backlog_dir.join(".audit.log")
 }
 
+/// Append `event`, chained to the previous line and signed when an audit key is configured
+/// (see [`crate::audit_signing`]).
 pub fn append_audit_event(backlog_dir: &Path, event: &AuditEvent) -> Result<(), AuditError> {
-    let path = audit_log_path(backlog_dir);
-    let line = serde_json::to_string(event)?;
-    append_jsonl_locked_with_key(
-        &path,
-        &line,
+    let key = AuditKey::from_env()?;
+    append_line_after_last_with_key(
+        &audit_log_path(backlog_dir),
         &ResourceKey::repo_local(backlog_dir, "audit.log"),
-    )?;
-    Ok(())
+        |previous| Ok::<_, AuditError>(chained_line(previous, event, key.as_ref())?),
+    )
 }
 
-pub fn read_recent_audit_events(backlog_dir: &Path, limit: usize) -> Vec<AuditEvent> {
-    if limit == 0 {
-        return Vec::new();
-    }
+/// Every parseable event in the audit log, oldest first.
+pub fn read_audit_events(backlog_dir: &Path) -> Vec<AuditEvent> {
     let path = audit_log_path(backlog_dir);
     let content = match std::fs::read_to_string(path) {
         Ok(content) => content,
@@ -53,8 +82,143 @@ pub fn read_recent_audit_events(backlog_dir: &Path, limit: usize) -> Vec<AuditEv
             events.push(event);
         }
     }
+    events
+}
+
+/// Size of the audit log in bytes (0 when missing); a cursor for `read_audit_events_from`.
+pub fn audit_log_len(backlog_dir: &Path) -> u64 {
+    std::fs::metadata(audit_log_path(backlog_dir))
+        .map(|meta| meta.len())
+        .unwrap_or(0)
+}
+
+/// Events appended after byte `offset` (as returned by `audit_log_len` earlier).
+pub fn read_audit_events_from(backlog_dir: &Path, offset: u64) -> Vec<AuditEvent> {
+    let Ok(bytes) = std::fs::read(audit_log_path(backlog_dir)) else {
+        return Vec::new();
+    };
+    let start = usize::try_from(offset).unwrap_or(usize::MAX);
+    let Some(tail) = bytes.get(start..) else {
+        return Vec::new();
+    };
+    String::from_utf8_lossy(tail)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<AuditEvent>(line).ok())
+        .collect()
+}
+
+pub fn read_recent_audit_events(backlog_dir: &Path, limit: usize) -> Vec<AuditEvent> {
+    if limit == 0 {
+        return Vec::new();
+    }
+    let mut events = read_audit_events(backlog_dir);
     if events.len() <= limit {
         return events;
     }
     events.split_off(events.len() - limit)
 }
+
+/// Group events by actor (missing actors count as `unknown`), most active first. Events outside
+/// `window` are skipped.
+pub fn actor_stats(events: &[AuditEvent], window: &TimeWindow) -> Vec<ActorStats> {
+    #[derive(Default)]
+    struct Acc {
+        events: usize,
+        tasks: BTreeSet<String>,
+        sessions: BTreeSet<String>,
+        first_at: String,
+        last_at: String,
+        actions: BTreeMap<String, usize>,
+    }
+    let mut by_actor: BTreeMap<String, Acc> = BTreeMap::new();
+    for event in events {
+        if !window.contains_timestamp(&event.timestamp) {
+            continue;
+        }
+        let actor = event
+            .actor
+            .as_deref()
+            .map(str::trim)
+            .filter(|actor| !actor.is_empty())
+            .unwrap_or("unknown");
+        let acc = by_actor.entry(actor.to_string()).or_default();
+        acc.events += 1;
+        if let Some(task_id) = event.task_id.as_deref() {
+            acc.tasks.insert(task_id.to_lowercase());
+        }
+        if let Some(session) = event.details.get("mcp_session").and_then(Value::as_str) {
+            acc.sessions.insert(session.to_string());
+        }
+        if acc.first_at.is_empty() || cmp_timestamps(&event.timestamp, &acc.first_at).is_lt() {
+            acc.first_at = event.timestamp.clone();
+        }
+        if acc.last_at.is_empty() || cmp_timestamps(&event.timestamp, &acc.last_at).is_gt() {
+            acc.last_at = event.timestamp.clone();
+        }
+        *acc.actions.entry(event.action.clone()).or_insert(0) += 1;
+    }
+    let mut stats: Vec<ActorStats> = by_actor
+        .into_iter()
+        .map(|(actor, acc)| ActorStats {
+            actor,
+            events: acc.events,
+            tasks: acc.tasks.len(),
+            first_at: acc.first_at,
+            last_at: acc.last_at,
+            sessions: acc.sessions.len(),
+            actions: acc.actions,
+        })
+        .collect();
+    stats.sort_by(|a, b| b.events.cmp(&a.events).then_with(|| a.actor.cmp(&b.actor)));
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::policies::parse_task_date;
+
+    fn event(actor: Option<&str>, at: &str, action: &str, details: Value) -> AuditEvent {
+        AuditEvent {
+            timestamp: at.to_string(),
+            actor: actor.map(str::to_string),
+            action: action.to_string(),
+            task_id: Some("task-001".to_string()),
+            details,
+        }
+    }
+
+    #[test]
+    fn actor_stats_attribute_events_to_each_client() {
+        let events = vec![
+            event(Some("luis"), "2026-01-01 09:00", "set_status", Value::Null),
+            event(
+                Some("mcp:claude-code"),
+                "2026-01-02 10:00",
+                "note",
+                serde_json::json!({"mcp_session": "a"}),
+            ),
+            event(
+                Some("mcp:claude-code"),
+                "2026-01-03 10:00",
+                "note",
+                serde_json::json!({"mcp_session": "b"}),
+            ),
+            event(None, "2026-01-03 11:00", "set_field", Value::Null),
+        ];
+        let stats = actor_stats(&events, &TimeWindow::default());
+        assert_eq!(stats[0].actor, "mcp:claude-code");
+        assert_eq!(stats[0].events, 2);
+        assert_eq!(stats[0].sessions, 2);
+        assert_eq!(stats[0].first_at, "2026-01-02 10:00");
+        assert_eq!(stats[0].actions.get("note"), Some(&2));
+        assert!(stats.iter().any(|entry| entry.actor == "unknown"));
+
+        let window = TimeWindow {
+            since: parse_task_date("2026-01-03 00:00"),
+            until: None,
+        };
+        let recent = actor_stats(&events, &window);
+        assert_eq!(recent.iter().map(|entry| entry.events).sum::<usize>(), 2);
+    }
+}
@@ -0,0 +1,591 @@
+//! Tamper evidence for the audit log (`.audit.log`).
+//!
+//! Every appended event carries a `chain` object: `prev` is the `hash` of the line before it and
+//! `hash` is SHA-256 over `prev` and the event JSON, so editing, reordering, or deleting a
+//! historical line breaks the chain at that point. With a key configured, each `hash` is also
+//! signed (HMAC-SHA256 or Ed25519), so a rewritten log cannot simply be re-chained.
+//!
+//! The key comes from `WORKMESH_AUDIT_KEY`, or from the output of `WORKMESH_AUDIT_KEY_CMD` (for
+//! example a keychain lookup). `workmesh audit verify-signatures` walks the log and reports every
+//! break. Removing lines from the end is only detectable against a `head` hash recorded elsewhere.
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::audit::{audit_log_path, AuditEvent};
+
+/// Signing key: `hmac:<base64>` or `ed25519:<base64 seed>` (a bare value is an HMAC key).
+pub const AUDIT_KEY_ENV: &str = "WORKMESH_AUDIT_KEY";
+/// Command printing the signing key, used when `WORKMESH_AUDIT_KEY` is unset.
+pub const AUDIT_KEY_CMD_ENV: &str = "WORKMESH_AUDIT_KEY_CMD";
+
+/// `prev` of the first chained event.
+const GENESIS: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+const MIN_HMAC_KEY_LEN: usize = 16;
+const ED25519_SEED_LEN: usize = 32;
+const ED25519_PUBLIC_KEY_LEN: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum AuditSigningError {
+    #[error("Invalid {AUDIT_KEY_ENV}: {0}")]
+    InvalidKey(String),
+    #[error("Invalid public key: {0}")]
+    InvalidPublicKey(String),
+    #[error("{AUDIT_KEY_CMD_ENV} failed: {0}")]
+    KeyCommand(String),
+    #[error("Failed to generate audit key")]
+    Generate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditKeyAlgorithm {
+    Hmac,
+    Ed25519,
+}
+
+impl AuditKeyAlgorithm {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AuditKeyAlgorithm::Hmac => "hmac-sha256",
+            AuditKeyAlgorithm::Ed25519 => "ed25519",
+        }
+    }
+}
+
+pub enum AuditKey {
+    Hmac(hmac::Key, Vec<u8>),
+    Ed25519(Ed25519KeyPair),
+}
+
+impl std::fmt::Debug for AuditKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "AuditKey({}, {})",
+            self.algorithm().as_str(),
+            self.key_id()
+        )
+    }
+}
+
+impl AuditKey {
+    pub fn parse(value: &str) -> Result<Self, AuditSigningError> {
+        let value = value.trim();
+        let (scheme, encoded) = match value.split_once(':') {
+            Some((scheme, encoded)) => (scheme.trim().to_lowercase(), encoded.trim()),
+            None => ("hmac".to_string(), value),
+        };
+        let bytes = STANDARD
+            .decode(encoded)
+            .map_err(|_| AuditSigningError::InvalidKey("key is not base64".to_string()))?;
+        match scheme.as_str() {
+            "hmac" => {
+                if bytes.len() < MIN_HMAC_KEY_LEN {
+                    return Err(AuditSigningError::InvalidKey(format!(
+                        "HMAC key must be at least {} bytes",
+                        MIN_HMAC_KEY_LEN
+                    )));
+                }
+                Ok(AuditKey::Hmac(
+                    hmac::Key::new(hmac::HMAC_SHA256, &bytes),
+                    bytes,
+                ))
+            }
+            "ed25519" => {
+                if bytes.len() != ED25519_SEED_LEN {
+                    return Err(AuditSigningError::InvalidKey(format!(
+                        "Ed25519 seed must be {} bytes",
+                        ED25519_SEED_LEN
+                    )));
+                }
+                Ed25519KeyPair::from_seed_unchecked(&bytes)
+                    .map(AuditKey::Ed25519)
+                    .map_err(|err| AuditSigningError::InvalidKey(err.to_string()))
+            }
+            other => Err(AuditSigningError::InvalidKey(format!(
+                "unknown scheme `{}` (expected hmac or ed25519)",
+                other
+            ))),
+        }
+    }
+
+    /// `WORKMESH_AUDIT_KEY`, else the output of `WORKMESH_AUDIT_KEY_CMD`; `Ok(None)` when neither
+    /// is set.
+    pub fn from_env() -> Result<Option<Self>, AuditSigningError> {
+        if let Ok(value) = std::env::var(AUDIT_KEY_ENV) {
+            if !value.trim().is_empty() {
+                return Self::parse(&value).map(Some);
+            }
+        }
+        match std::env::var(AUDIT_KEY_CMD_ENV) {
+            Ok(cmd) if !cmd.trim().is_empty() => Self::parse(&key_from_command(&cmd)?).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    pub fn algorithm(&self) -> AuditKeyAlgorithm {
+        match self {
+            AuditKey::Hmac(..) => AuditKeyAlgorithm::Hmac,
+            AuditKey::Ed25519(_) => AuditKeyAlgorithm::Ed25519,
+        }
+    }
+
+    /// Short fingerprint recorded with each signature, so verifiers can tell keys apart.
+    pub fn key_id(&self) -> String {
+        match self {
+            AuditKey::Hmac(_, bytes) => fingerprint(bytes),
+            AuditKey::Ed25519(pair) => fingerprint(pair.public_key().as_ref()),
+        }
+    }
+
+    /// Base64 public key for Ed25519 keys; HMAC keys have none.
+    pub fn public_key(&self) -> Option<String> {
+        match self {
+            AuditKey::Hmac(..) => None,
+            AuditKey::Ed25519(pair) => Some(STANDARD.encode(pair.public_key().as_ref())),
+        }
+    }
+
+    fn sign(&self, message: &[u8]) -> String {
+        match self {
+            AuditKey::Hmac(key, _) => STANDARD.encode(hmac::sign(key, message).as_ref()),
+            AuditKey::Ed25519(pair) => STANDARD.encode(pair.sign(message).as_ref()),
+        }
+    }
+}
+
+/// Decode a base64 Ed25519 public key, as printed by `audit keygen`.
+pub fn parse_public_key(value: &str) -> Result<Vec<u8>, AuditSigningError> {
+    STANDARD
+        .decode(value.trim())
+        .ok()
+        .filter(|bytes| bytes.len() == ED25519_PUBLIC_KEY_LEN)
+        .ok_or_else(|| {
+            AuditSigningError::InvalidPublicKey(format!(
+                "expected base64 of {} bytes",
+                ED25519_PUBLIC_KEY_LEN
+            ))
+        })
+}
+
+/// A fresh key for `WORKMESH_AUDIT_KEY`, with its scheme prefix.
+pub fn generate_audit_key(algorithm: AuditKeyAlgorithm) -> Result<String, AuditSigningError> {
+    let (scheme, len) = match algorithm {
+        AuditKeyAlgorithm::Hmac => ("hmac", 32),
+        AuditKeyAlgorithm::Ed25519 => ("ed25519", ED25519_SEED_LEN),
+    };
+    let mut bytes = vec![0u8; len];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| AuditSigningError::Generate)?;
+    Ok(format!("{}:{}", scheme, STANDARD.encode(bytes)))
+}
+
+/// The `chain` object stored on each audit line.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChainLink {
+    pub prev: String,
+    pub hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sig: Option<String>,
+}
+
+/// The log line for `event`, chained to `previous_line` and signed when `key` is given.
+pub(crate) fn chained_line(
+    previous_line: Option<&str>,
+    event: &AuditEvent,
+    key: Option<&AuditKey>,
+) -> Result<String, serde_json::Error> {
+    let mut value = serde_json::to_value(event)?;
+    let prev = previous_line
+        .and_then(line_chain)
+        .map(|link| link.hash)
+        .unwrap_or_else(|| GENESIS.to_string());
+    let hash = chain_hash(&prev, &serde_json::to_string(&value)?);
+    let link = ChainLink {
+        alg: key.map(|key| key.algorithm().as_str().to_string()),
+        key_id: key.map(AuditKey::key_id),
+        sig: key.map(|key| key.sign(hash.as_bytes())),
+        prev,
+        hash,
+    };
+    if let Value::Object(map) = &mut value {
+        map.insert("chain".to_string(), serde_json::to_value(link)?);
+    }
+    serde_json::to_string(&value)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditIssue {
+    /// 1-based line number in `.audit.log`.
+    pub line: usize,
+    /// `unparseable`, `unchained`, `broken_link`, `hash_mismatch`, `unsigned`, or `bad_signature`.
+    pub kind: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditVerifyReport {
+    pub ok: bool,
+    pub events: usize,
+    /// Lines written before chaining existed; they precede the first chained line.
+    pub legacy: usize,
+    pub chained: usize,
+    /// Signatures checked and found valid.
+    pub verified: usize,
+    /// Signatures that could not be checked: no matching key was available.
+    pub unverified: usize,
+    /// `hash` of the last chained line; record it elsewhere to detect truncation later.
+    pub head: Option<String>,
+    pub key_ids: BTreeSet<String>,
+    pub issues: Vec<AuditIssue>,
+}
+
+/// Walk the audit log checking the hash chain and, where a key is at hand, the signatures.
+/// HMAC signatures need the signing key; Ed25519 signatures need the key or its `public_key`.
+pub fn verify_audit_log(
+    backlog_dir: &Path,
+    key: Option<&AuditKey>,
+    public_key: Option<&[u8]>,
+) -> AuditVerifyReport {
+    let content = std::fs::read_to_string(audit_log_path(backlog_dir)).unwrap_or_default();
+    let own_public = key.and_then(|key| match key {
+        AuditKey::Ed25519(pair) => Some(pair.public_key().as_ref().to_vec()),
+        AuditKey::Hmac(..) => None,
+    });
+    let mut report = AuditVerifyReport {
+        ok: true,
+        events: 0,
+        legacy: 0,
+        chained: 0,
+        verified: 0,
+        unverified: 0,
+        head: None,
+        key_ids: BTreeSet::new(),
+        issues: Vec::new(),
+    };
+    let issue = |line: usize, kind: &str, message: String| AuditIssue {
+        line,
+        kind: kind.to_string(),
+        message,
+    };
+    let mut previous: Option<ChainLink> = None;
+    let mut seen_signature = false;
+    for (index, line) in content.lines().enumerate() {
+        let number = index + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        report.events += 1;
+        let Ok(Value::Object(mut map)) = serde_json::from_str::<Value>(line) else {
+            report.issues.push(issue(
+                number,
+                "unparseable",
+                "line is not a JSON object".to_string(),
+            ));
+            previous = None;
+            continue;
+        };
+        let Some(link) = map
+            .remove("chain")
+            .and_then(|value| serde_json::from_value::<ChainLink>(value).ok())
+        else {
+            if report.chained == 0 {
+                report.legacy += 1;
+            } else {
+                report.issues.push(issue(
+                    number,
+                    "unchained",
+                    "event has no chain after chaining started".to_string(),
+                ));
+            }
+            previous = None;
+            continue;
+        };
+        report.chained += 1;
+
+        let expected_prev = previous
+            .as_ref()
+            .map(|link| link.hash.as_str())
+            .unwrap_or(GENESIS);
+        if link.prev != expected_prev {
+            report.issues.push(issue(
+                number,
+                "broken_link",
+                "prev does not match the line before it (lines removed, reordered, or inserted)"
+                    .to_string(),
+            ));
+        }
+        let body = serde_json::to_string(&Value::Object(map)).unwrap_or_default();
+        if chain_hash(&link.prev, &body) != link.hash {
+            report.issues.push(issue(
+                number,
+                "hash_mismatch",
+                "event content was changed after it was written".to_string(),
+            ));
+        }
+
+        match link.sig.as_deref() {
+            None if seen_signature => report.issues.push(issue(
+                number,
+                "unsigned",
+                "signature missing after signing started".to_string(),
+            )),
+            None => {}
+            Some(sig) => {
+                seen_signature = true;
+                if let Some(key_id) = &link.key_id {
+                    report.key_ids.insert(key_id.clone());
+                }
+                match check_signature(&link, sig, key, own_public.as_deref().or(public_key)) {
+                    Some(true) => report.verified += 1,
+                    Some(false) => report.issues.push(issue(
+                        number,
+                        "bad_signature",
+                        "signature does not match the event hash".to_string(),
+                    )),
+                    None => report.unverified += 1,
+                }
+            }
+        }
+        report.head = Some(link.hash.clone());
+        previous = Some(link);
+    }
+    report.ok = report.issues.is_empty();
+    report
+}
+
+/// `Some(valid)` when a key for the signature's algorithm is available, `None` otherwise.
+fn check_signature(
+    link: &ChainLink,
+    sig: &str,
+    key: Option<&AuditKey>,
+    public_key: Option<&[u8]>,
+) -> Option<bool> {
+    let sig = STANDARD.decode(sig).ok();
+    match link.alg.as_deref() {
+        Some("hmac-sha256") => {
+            let Some(AuditKey::Hmac(hmac_key, bytes)) = key else {
+                return None;
+            };
+            if link
+                .key_id
+                .as_ref()
+                .is_some_and(|id| *id != fingerprint(bytes))
+            {
+                return None;
+            }
+            Some(sig.is_some_and(|sig| hmac::verify(hmac_key, link.hash.as_bytes(), &sig).is_ok()))
+        }
+        Some("ed25519") => {
+            let public_key = public_key?;
+            if link
+                .key_id
+                .as_ref()
+                .is_some_and(|id| *id != fingerprint(public_key))
+            {
+                return None;
+            }
+            Some(sig.is_some_and(|sig| {
+                UnparsedPublicKey::new(&ED25519, public_key)
+                    .verify(link.hash.as_bytes(), &sig)
+                    .is_ok()
+            }))
+        }
+        _ => None,
+    }
+}
+
+fn line_chain(line: &str) -> Option<ChainLink> {
+    let mut value: Value = serde_json::from_str(line).ok()?;
+    serde_json::from_value(value.get_mut("chain")?.take()).ok()
+}
+
+fn chain_hash(prev: &str, body: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(prev.as_bytes());
+    hasher.update(b"\n");
+    hasher.update(body.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+fn fingerprint(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))[..16].to_string()
+}
+
+/// Run the key command once per process; keychain lookups can be slow or prompt.
+fn key_from_command(cmd: &str) -> Result<String, AuditSigningError> {
+    static CACHE: Mutex<Option<(String, String)>> = Mutex::new(None);
+    let mut cache = CACHE.lock().unwrap_or_else(|err| err.into_inner());
+    if let Some((cached_cmd, key)) = cache.as_ref() {
+        if cached_cmd == cmd {
+            return Ok(key.clone());
+        }
+    }
+    let argv =
+        shell_words::split(cmd).map_err(|err| AuditSigningError::KeyCommand(err.to_string()))?;
+    let Some((program, args)) = argv.split_first() else {
+        return Err(AuditSigningError::KeyCommand("empty command".to_string()));
+    };
+    let output = Command::new(program)
+        .args(args)
+        .output()
+        .map_err(|err| AuditSigningError::KeyCommand(err.to_string()))?;
+    if !output.status.success() {
+        return Err(AuditSigningError::KeyCommand(format!(
+            "exited with {}",
+            output.status
+        )));
+    }
+    let key = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    *cache = Some((cmd.to_string(), key.clone()));
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn event(action: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: "2026-03-01 10:00".to_string(),
+            actor: Some("alice".to_string()),
+            action: action.to_string(),
+            task_id: Some("task-001".to_string()),
+            details: serde_json::json!({ "note": "ok", "estimate": 2.5 }),
+        }
+    }
+
+    fn write_log(backlog: &Path, key: Option<&AuditKey>, actions: &[&str]) {
+        let mut lines: Vec<String> = Vec::new();
+        for action in actions {
+            let line =
+                chained_line(lines.last().map(String::as_str), &event(action), key).expect("line");
+            lines.push(line);
+        }
+        fs::create_dir_all(backlog).expect("dir");
+        fs::write(audit_log_path(backlog), lines.join("\n") + "\n").expect("write");
+    }
+
+    fn kinds(report: &AuditVerifyReport) -> Vec<(usize, &str)> {
+        report
+            .issues
+            .iter()
+            .map(|issue| (issue.line, issue.kind.as_str()))
+            .collect()
+    }
+
+    #[test]
+    fn chain_detects_edits_and_removed_lines() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        fs::create_dir_all(&backlog).expect("dir");
+        fs::write(
+            audit_log_path(&backlog),
+            "{\"timestamp\":\"2026-01-01 09:00\",\"actor\":null,\"action\":\"legacy\",\"task_id\":null,\"details\":{}}\n",
+        )
+        .expect("legacy");
+        let legacy = fs::read_to_string(audit_log_path(&backlog)).expect("read");
+        let mut lines = vec![legacy.trim_end().to_string()];
+        for action in ["create", "set_status", "note"] {
+            lines.push(
+                chained_line(lines.last().map(String::as_str), &event(action), None).unwrap(),
+            );
+        }
+        fs::write(audit_log_path(&backlog), lines.join("\n") + "\n").expect("write");
+
+        let report = verify_audit_log(&backlog, None, None);
+        assert!(report.ok, "{:?}", report.issues);
+        assert_eq!((report.events, report.legacy, report.chained), (4, 1, 3));
+        assert_eq!(report.head, line_chain(&lines[3]).map(|link| link.hash));
+
+        let edited = lines[2].replace("set_status", "set_priority");
+        let tampered = [lines[0].as_str(), &lines[1], &edited, &lines[3]].join("\n");
+        fs::write(audit_log_path(&backlog), tampered).expect("write");
+        assert_eq!(
+            kinds(&verify_audit_log(&backlog, None, None)),
+            vec![(3, "hash_mismatch")]
+        );
+
+        let removed = [lines[0].as_str(), &lines[1], &lines[3]].join("\n");
+        fs::write(audit_log_path(&backlog), removed).expect("write");
+        assert_eq!(
+            kinds(&verify_audit_log(&backlog, None, None)),
+            vec![(3, "broken_link")]
+        );
+    }
+
+    #[test]
+    fn signatures_verify_with_key_or_public_key() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let key = AuditKey::parse(&generate_audit_key(AuditKeyAlgorithm::Ed25519).unwrap())
+            .expect("ed25519");
+        write_log(&backlog, Some(&key), &["create", "note"]);
+
+        let report = verify_audit_log(&backlog, Some(&key), None);
+        assert!(report.ok);
+        assert_eq!(report.verified, 2);
+        assert_eq!(report.key_ids.iter().next(), Some(&key.key_id()));
+
+        let public = parse_public_key(&key.public_key().unwrap()).expect("public key");
+        assert_eq!(verify_audit_log(&backlog, None, Some(&public)).verified, 2);
+        let unchecked = verify_audit_log(&backlog, None, None);
+        assert!(unchecked.ok);
+        assert_eq!(unchecked.unverified, 2);
+
+        // A re-chained forgery without the key fails the signature check.
+        let text = fs::read_to_string(audit_log_path(&backlog)).expect("read");
+        let first = text.lines().next().unwrap();
+        let forged = chained_line(Some(first), &event("forged"), None).unwrap();
+        let mut forged: Value = serde_json::from_str(&forged).unwrap();
+        forged["chain"]["sig"] = Value::String(STANDARD.encode([0u8; 64]));
+        forged["chain"]["alg"] = Value::String("ed25519".to_string());
+        fs::write(audit_log_path(&backlog), format!("{}\n{}\n", first, forged)).expect("write");
+        assert_eq!(
+            kinds(&verify_audit_log(&backlog, Some(&key), None)),
+            vec![(2, "bad_signature")]
+        );
+
+        let hmac =
+            AuditKey::parse(&generate_audit_key(AuditKeyAlgorithm::Hmac).unwrap()).expect("hmac");
+        write_log(&backlog, Some(&hmac), &["create"]);
+        assert_eq!(verify_audit_log(&backlog, Some(&hmac), None).verified, 1);
+        let other =
+            AuditKey::parse(&generate_audit_key(AuditKeyAlgorithm::Hmac).unwrap()).expect("hmac");
+        assert_eq!(verify_audit_log(&backlog, Some(&other), None).unverified, 1);
+    }
+
+    #[test]
+    fn parse_rejects_bad_keys() {
+        assert!(AuditKey::parse("hmac:c2hvcnQ=").is_err());
+        assert!(AuditKey::parse("ed25519:c2hvcnQ=").is_err());
+        assert!(AuditKey::parse("rsa:c2hvcnQ=").is_err());
+        assert!(AuditKey::parse("not base64!").is_err());
+        assert!(parse_public_key("c2hvcnQ=").is_err());
+        let bare = STANDARD.encode([7u8; 32]);
+        assert_eq!(
+            AuditKey::parse(&bare).expect("bare").algorithm(),
+            AuditKeyAlgorithm::Hmac
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Keyword→label rules for untagged tasks (`autotag [--apply]`, `lint --autotag [--fix]`).
+//!
+//! Each `[[autotag.rules]]` entry names a label and the keywords (whole words, any case) or
+//! regex that earn it, matched against the title and body. Only tasks without labels are
+//! considered, so hand-picked labels are never second-guessed.
+
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+use crate::task::Task;
+use crate::task_ops::set_list_field;
+
+#[derive(Debug, Error)]
+pub enum AutotagError {
+    #[error("Invalid autotag rule for label {label:?}: {source}")]
+    Regex { label: String, source: regex::Error },
+    #[error("Autotag rule for label {0:?} has neither keywords nor pattern")]
+    EmptyRule(String),
+}
+
+/// `[autotag]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutotagConfig {
+    #[serde(default)]
+    pub rules: Vec<AutotagRuleConfig>,
+}
+
+/// One `[[autotag.rules]]` entry: `label` plus `keywords`, `pattern`, or both.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutotagRuleConfig {
+    pub label: String,
+    /// Whole words or phrases, matched case-insensitively.
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    /// Regex matched as written; add `(?i)` for case-insensitive.
+    pub pattern: Option<String>,
+}
+
+/// Project `[autotag]` wins over global `[autotag]`.
+pub fn resolve_autotag_config(repo_root: &Path) -> AutotagConfig {
+    load_config(repo_root)
+        .and_then(|config| config.autotag)
+        .or_else(|| load_global_config().and_then(|config| config.autotag))
+        .unwrap_or_default()
+}
+
+/// A compiled rule.
+#[derive(Debug, Clone)]
+pub struct AutotagRule {
+    pub label: String,
+    keywords: Option<Regex>,
+    pattern: Option<Regex>,
+}
+
+impl AutotagRule {
+    /// The first keyword or pattern match in `text`.
+    fn find<'t>(&self, text: &'t str) -> Option<&'t str> {
+        self.keywords
+            .iter()
+            .chain(&self.pattern)
+            .find_map(|regex| regex.find(text))
+            .map(|found| found.as_str())
+    }
+}
+
+/// Compile `[[autotag.rules]]`; rules with a blank label are skipped.
+pub fn compile_rules(config: &AutotagConfig) -> Result<Vec<AutotagRule>, AutotagError> {
+    let mut rules = Vec::new();
+    for rule in &config.rules {
+        let label = rule.label.trim();
+        if label.is_empty() {
+            continue;
+        }
+        let compile = |pattern: &str| {
+            Regex::new(pattern).map_err(|source| AutotagError::Regex {
+                label: label.to_string(),
+                source,
+            })
+        };
+        let words: Vec<String> = rule
+            .keywords
+            .iter()
+            .map(|word| word.trim())
+            .filter(|word| !word.is_empty())
+            .map(regex::escape)
+            .collect();
+        let keywords = if words.is_empty() {
+            None
+        } else {
+            Some(compile(&format!(r"(?i)\b(?:{})\b", words.join("|")))?)
+        };
+        let pattern = match rule.pattern.as_deref().map(str::trim) {
+            Some(pattern) if !pattern.is_empty() => Some(compile(pattern)?),
+            _ => None,
+        };
+        if keywords.is_none() && pattern.is_none() {
+            return Err(AutotagError::EmptyRule(label.to_string()));
+        }
+        rules.push(AutotagRule {
+            label: label.to_string(),
+            keywords,
+            pattern,
+        });
+    }
+    Ok(rules)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutotagMatch {
+    pub label: String,
+    /// The text that matched, as written in the task.
+    pub matched: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AutotagProposal {
+    pub task_id: String,
+    pub title: String,
+    /// Proposed labels in rule order.
+    pub labels: Vec<String>,
+    pub matches: Vec<AutotagMatch>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct AutotagReport {
+    /// Untagged tasks checked against the rules.
+    pub scanned: usize,
+    pub proposals: Vec<AutotagProposal>,
+    /// Task ids whose labels were written by `apply`.
+    pub applied: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Labels `rules` would give `task`, or `None` when it already has labels or nothing matches.
+pub fn propose_labels(task: &Task, rules: &[AutotagRule]) -> Option<AutotagProposal> {
+    if !task.labels.is_empty() {
+        return None;
+    }
+    let text = format!("{}\n{}", task.title, task.body);
+    let mut labels: Vec<String> = Vec::new();
+    let mut matches = Vec::new();
+    for rule in rules {
+        if labels
+            .iter()
+            .any(|label| label.eq_ignore_ascii_case(&rule.label))
+        {
+            continue;
+        }
+        if let Some(matched) = rule.find(&text) {
+            labels.push(rule.label.clone());
+            matches.push(AutotagMatch {
+                label: rule.label.clone(),
+                matched: matched.to_string(),
+            });
+        }
+    }
+    (!labels.is_empty()).then(|| AutotagProposal {
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        labels,
+        matches,
+    })
+}
+
+/// Propose labels for every untagged task in `tasks`; with `apply`, write them to `labels`.
+/// Write failures become warnings so one bad file does not stop the run.
+pub fn autotag_tasks(tasks: &[Task], rules: &[AutotagRule], apply: bool) -> AutotagReport {
+    let mut report = AutotagReport::default();
+    let mut ordered: Vec<&Task> = tasks.iter().filter(|task| task.labels.is_empty()).collect();
+    ordered.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then(a.id.cmp(&b.id)));
+    for task in ordered {
+        report.scanned += 1;
+        let Some(proposal) = propose_labels(task, rules) else {
+            continue;
+        };
+        if apply {
+            match task.file_path.as_ref() {
+                Some(path) => match set_list_field(path, "labels", proposal.labels.clone()) {
+                    Ok(()) => report.applied.push(task.id.clone()),
+                    Err(err) => report
+                        .warnings
+                        .push(format!("{} not tagged: {}", task.id, err)),
+                },
+                None => report
+                    .warnings
+                    .push(format!("{} has no file path; not tagged", task.id)),
+            }
+        }
+        report.proposals.push(proposal);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, title: &str, body: &str, labels: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: title.to_string(),
+            status: "To Do".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: Default::default(),
+            file_path: None,
+            body: body.to_string(),
+        }
+    }
+
+    #[test]
+    fn proposes_labels_from_keywords_and_patterns_for_untagged_tasks() {
+        let config: AutotagConfig = toml::from_str(
+            r#"
+            [[rules]]
+            label = "security"
+            keywords = ["password", "auth token"]
+
+            [[rules]]
+            label = "bug"
+            pattern = "(?i)\\b(crash|regression)"
+
+            [[rules]]
+            label = "ui"
+            keywords = ["css"]
+            "#,
+        )
+        .expect("toml");
+        let rules = compile_rules(&config).expect("rules");
+        let tasks = vec![
+            task("task-002", "Rotate Auth Token", "Crashes on expiry.", &[]),
+            task("task-001", "Hash passwords", "", &[]),
+            task("task-003", "Password reset", "", &["security"]),
+            task("task-004", "Tidy docs", "", &[]),
+        ];
+
+        let report = autotag_tasks(&tasks, &rules, true);
+        assert_eq!(report.scanned, 3);
+        // Whole words only: "passwords" does not match "password".
+        assert_eq!(report.proposals.len(), 1);
+        let proposal = &report.proposals[0];
+        assert_eq!(proposal.task_id, "task-002");
+        assert_eq!(proposal.labels, vec!["security", "bug"]);
+        assert_eq!(proposal.matches[0].matched, "Auth Token");
+        assert_eq!(proposal.matches[1].matched, "Crash");
+        assert!(report.applied.is_empty());
+        assert_eq!(
+            report.warnings,
+            vec!["task-002 has no file path; not tagged"]
+        );
+
+        let err = compile_rules(&AutotagConfig {
+            rules: vec![AutotagRuleConfig {
+                label: "empty".to_string(),
+                ..Default::default()
+            }],
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("neither keywords nor pattern"));
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::task::{load_tasks_from_dir_recursive, parse_task_file, Task};
+use crate::task_ops::task_to_json_value;
+
+/// Fields that are reported elsewhere (dependency edges) or that only reflect file layout.
+const SKIPPED_FIELDS: &[&str] = &["path", "dependencies", "extra"];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskRef {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldChange {
+    /// Front matter key; custom fields are reported as `extra.<key>`.
+    pub field: String,
+    pub old: Value,
+    pub new: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskChange {
+    pub id: String,
+    pub title: String,
+    pub changes: Vec<FieldChange>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct DependencyEdge {
+    pub task_id: String,
+    pub depends_on: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BacklogDiff {
+    pub added: Vec<TaskRef>,
+    pub removed: Vec<TaskRef>,
+    pub changed: Vec<TaskChange>,
+    pub dependencies_added: Vec<DependencyEdge>,
+    pub dependencies_removed: Vec<DependencyEdge>,
+}
+
+impl BacklogDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty()
+            && self.removed.is_empty()
+            && self.changed.is_empty()
+            && self.dependencies_added.is_empty()
+            && self.dependencies_removed.is_empty()
+    }
+}
+
+/// Load every task under `path`, which may be a backlog tree, a `tasks/` directory, a single
+/// task file, or missing (git passes `/dev/null` or an absent path for added/removed files).
+pub fn load_diff_side(path: &Path) -> Vec<Task> {
+    if path.is_dir() {
+        load_tasks_from_dir_recursive(path)
+    } else {
+        parse_task_file(path)
+            .map(|task| vec![task])
+            .unwrap_or_default()
+    }
+}
+
+pub fn diff_backlog_paths(old: &Path, new: &Path) -> BacklogDiff {
+    diff_tasks(&load_diff_side(old), &load_diff_side(new))
+}
+
+pub fn diff_tasks(old: &[Task], new: &[Task]) -> BacklogDiff {
+    let old_by_id = by_id(old);
+    let new_by_id = by_id(new);
+    let mut diff = BacklogDiff::default();
+
+    for (key, task) in &old_by_id {
+        if !new_by_id.contains_key(key) {
+            diff.removed.push(task_ref(task));
+        }
+    }
+    for (key, task) in &new_by_id {
+        match old_by_id.get(key) {
+            None => diff.added.push(task_ref(task)),
+            Some(previous) => {
+                let changes = field_changes(previous, task);
+                if !changes.is_empty() {
+                    diff.changed.push(TaskChange {
+                        id: task.id.clone(),
+                        title: task.title.clone(),
+                        changes,
+                    });
+                }
+            }
+        }
+    }
+
+    let old_edges = edges(old);
+    let new_edges = edges(new);
+    diff.dependencies_added = new_edges.difference(&old_edges).cloned().collect();
+    diff.dependencies_removed = old_edges.difference(&new_edges).cloned().collect();
+    diff
+}
+
+fn by_id(tasks: &[Task]) -> BTreeMap<String, &Task> {
+    tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task))
+        .collect()
+}
+
+fn task_ref(task: &Task) -> TaskRef {
+    TaskRef {
+        id: task.id.clone(),
+        title: task.title.clone(),
+        status: task.status.clone(),
+    }
+}
+
+fn field_changes(old: &Task, new: &Task) -> Vec<FieldChange> {
+    let old_value = task_to_json_value(old, true);
+    let new_value = task_to_json_value(new, true);
+    let mut changes = Vec::new();
+    compare_objects(&old_value, &new_value, "", SKIPPED_FIELDS, &mut changes);
+    compare_objects(
+        &old_value["extra"],
+        &new_value["extra"],
+        "extra.",
+        &[],
+        &mut changes,
+    );
+    changes
+}
+
+fn compare_objects(
+    old: &Value,
+    new: &Value,
+    prefix: &str,
+    skip: &[&str],
+    changes: &mut Vec<FieldChange>,
+) {
+    let empty = serde_json::Map::new();
+    let old = old.as_object().unwrap_or(&empty);
+    let new = new.as_object().unwrap_or(&empty);
+    let keys: BTreeSet<&String> = old.keys().chain(new.keys()).collect();
+    for key in keys {
+        if skip.contains(&key.as_str()) {
+            continue;
+        }
+        let old_field = old.get(key).cloned().unwrap_or(Value::Null);
+        let new_field = new.get(key).cloned().unwrap_or(Value::Null);
+        if old_field != new_field {
+            changes.push(FieldChange {
+                field: format!("{}{}", prefix, key),
+                old: old_field,
+                new: new_field,
+            });
+        }
+    }
+}
+
+fn edges(tasks: &[Task]) -> BTreeSet<DependencyEdge> {
+    tasks
+        .iter()
+        .flat_map(|task| {
+            task.dependencies.iter().map(|dep| DependencyEdge {
+                task_id: task.id.clone(),
+                depends_on: dep.clone(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_task(dir: &Path, id: &str, status: &str, deps: &str, extra: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: P2\nphase: Phase1\n\
+dependencies: [{deps}]\nlabels: []\n{extra}---\n\nBody\n"
+        );
+        fs::write(dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn diff_reports_added_removed_changed_and_edges() {
+        let temp = TempDir::new().expect("tempdir");
+        let old = temp.path().join("old").join("workmesh").join("tasks");
+        let new = temp.path().join("new").join("workmesh").join("tasks");
+        fs::create_dir_all(&old).expect("old");
+        fs::create_dir_all(&new).expect("new");
+        write_task(&old, "task-001", "To Do", "", "");
+        write_task(&old, "task-002", "To Do", "task-001", "");
+        write_task(&new, "task-001", "Done", "", "estimate: 4h\n");
+        write_task(&new, "task-003", "To Do", "task-001", "");
+
+        let diff = diff_backlog_paths(&temp.path().join("old"), &temp.path().join("new"));
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0].id, "task-003");
+        assert_eq!(diff.removed[0].id, "task-002");
+        assert_eq!(diff.changed.len(), 1);
+        let fields: Vec<&str> = diff.changed[0]
+            .changes
+            .iter()
+            .map(|change| change.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["status", "extra.estimate"]);
+        assert_eq!(diff.dependencies_added[0].task_id, "task-003");
+        assert_eq!(diff.dependencies_removed[0].task_id, "task-002");
+    }
+
+    #[test]
+    fn missing_side_is_an_empty_backlog() {
+        let temp = TempDir::new().expect("tempdir");
+        write_task(temp.path(), "task-001", "To Do", "", "");
+        let file = temp.path().join("task-001 - task.md");
+
+        let diff = diff_backlog_paths(Path::new("/dev/null"), &file);
+        assert_eq!(diff.added.len(), 1);
+        assert!(diff.removed.is_empty() && diff.changed.is_empty());
+        assert!(diff_backlog_paths(&file, &file).is_empty());
+    }
+}
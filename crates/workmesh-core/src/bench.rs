@@ -0,0 +1,314 @@
+//! `workmesh bench`: time the operations users wait on (load with and without the task cache,
+//! filter, index rebuild, a status change) over synthetic backlogs of increasing size.
+//!
+//! Backlogs come from [`generate_backlog`], which is deterministic, so two releases benchmarked on
+//! the same machine see identical input and a saved `--json` report can serve as the baseline
+//! for the next run.
+
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::index::{rebuild_index, refresh_index, IndexError};
+use crate::task::{load_tasks, tasks_dir_for_root, TaskParseError};
+use crate::task_cache::task_cache_path;
+use crate::task_ops::{filter_tasks, update_task_field, FieldValue};
+
+/// Steps timed for each size, in run order.
+pub const BENCH_STEPS: &[&str] = &["load_cold", "load", "filter", "index_rebuild", "mutation"];
+
+pub const DEFAULT_BENCH_TASKS: usize = 10_000;
+pub const DEFAULT_BENCH_RUNS: usize = 3;
+
+/// Status changes timed per run of the `mutation` step (fewer on tiny backlogs).
+const MUTATIONS_PER_RUN: usize = 20;
+
+const STATUSES: &[&str] = &[
+    "To Do",
+    "To Do",
+    "To Do",
+    "To Do",
+    "In Progress",
+    "Blocked",
+    "Done",
+    "Done",
+    "Done",
+];
+const LABELS: &[&str] = &["api", "ui", "docs", "infra", "payments", "auth", "search"];
+
+#[derive(Debug, Error)]
+pub enum BenchError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Index(#[from] IndexError),
+    #[error(transparent)]
+    Task(#[from] TaskParseError),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchStep {
+    pub step: String,
+    /// Median over runs.
+    pub median_ms: f64,
+    /// Operations timed per run: 1, or the number of status changes for `mutation`.
+    pub ops: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchSize {
+    pub tasks: usize,
+    pub steps: Vec<BenchStep>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BenchReport {
+    pub version: String,
+    pub platform: String,
+    pub runs: usize,
+    pub sizes: Vec<BenchSize>,
+}
+
+impl BenchReport {
+    /// Per-operation median for `step` at `tasks`, if this report measured it.
+    pub fn per_op_ms(&self, tasks: usize, step: &str) -> Option<f64> {
+        self.sizes
+            .iter()
+            .find(|size| size.tasks == tasks)?
+            .steps
+            .iter()
+            .find(|entry| entry.step == step)
+            .map(|entry| entry.median_ms / entry.ops.max(1) as f64)
+    }
+}
+
+/// Sizes benchmarked for `--tasks max`: two orders of magnitude below it, then `max` itself.
+pub fn bench_sizes(max: usize) -> Vec<usize> {
+    let mut sizes: Vec<usize> = [max / 100, max / 10, max]
+        .into_iter()
+        .filter(|size| *size >= 10)
+        .collect();
+    sizes.dedup();
+    if sizes.is_empty() {
+        sizes.push(max.max(1));
+    }
+    sizes
+}
+
+/// Write `count` synthetic tasks into `backlog_dir/tasks`, with the status, priority, label and
+/// dependency mix of a lived-in backlog. The same `count` always produces the same files.
+pub fn generate_backlog(backlog_dir: &Path, count: usize) -> Result<(), BenchError> {
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    fs::create_dir_all(&tasks_dir)?;
+    let mut seed: u64 = 0x5eed;
+    let mut next = move || {
+        seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (seed >> 33) as usize
+    };
+    for n in 1..=count {
+        let id = format!("task-{:05}", n);
+        let status = STATUSES[next() % STATUSES.len()];
+        let priority = format!("P{}", next() % 5);
+        let phase = format!("Phase{}", 1 + next() % 4);
+        let labels = [LABELS[next() % LABELS.len()], LABELS[next() % LABELS.len()]];
+        let dependencies = if n > 1 && next() % 3 == 0 {
+            format!("[task-{:05}]", 1 + next() % (n - 1))
+        } else {
+            "[]".to_string()
+        };
+        let title = format!("Synthetic {} task {}", labels[0], n);
+        let text = format!(
+            "---\n\
+id: {id}\n\
+uid: 01BENCH{n:019}\n\
+title: {title}\n\
+kind: task\n\
+status: {status}\n\
+priority: {priority}\n\
+phase: {phase}\n\
+dependencies: {dependencies}\n\
+labels: [{label_a}, {label_b}]\n\
+assignee: []\n\
+created_date: 2026-01-01 09:00\n\
+updated_date: 2026-01-02 09:00\n\
+---\n\n\
+Description:\n\
+--------------------------------------------------\n\
+- Generated by workmesh bench for {label_a} work item {n}.\n\n\
+Acceptance Criteria:\n\
+--------------------------------------------------\n\
+- The {label_b} behavior is covered.\n\n\
+Definition of Done:\n\
+--------------------------------------------------\n\
+- Description goals met.\n\
+- Acceptance criteria satisfied.\n",
+            label_a = labels[0],
+            label_b = labels[1],
+        );
+        fs::write(tasks_dir.join(format!("{} - {}.md", id, title)), text)?;
+    }
+    Ok(())
+}
+
+/// Generate a backlog of each size under `work_dir` and time every step `runs` times.
+/// `on_size` is called as each size finishes, so callers can report progress.
+pub fn run_bench(
+    work_dir: &Path,
+    sizes: &[usize],
+    runs: usize,
+    mut on_size: impl FnMut(&BenchSize),
+) -> Result<BenchReport, BenchError> {
+    let runs = runs.max(1);
+    let mut results = Vec::new();
+    for &tasks in sizes {
+        let backlog_dir = work_dir.join(format!("backlog-{}", tasks));
+        if backlog_dir.exists() {
+            fs::remove_dir_all(&backlog_dir)?;
+        }
+        generate_backlog(&backlog_dir, tasks)?;
+        let size = bench_size(&backlog_dir, tasks, runs)?;
+        on_size(&size);
+        results.push(size);
+    }
+    Ok(BenchReport {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        runs,
+        sizes: results,
+    })
+}
+
+fn bench_size(backlog_dir: &Path, tasks: usize, runs: usize) -> Result<BenchSize, BenchError> {
+    let mut steps = Vec::new();
+    let cache_path = task_cache_path(backlog_dir);
+    steps.push(timed("load_cold", 1, runs, || {
+        let _ = fs::remove_file(&cache_path);
+        load_tasks(backlog_dir);
+        Ok(())
+    })?);
+    steps.push(timed("load", 1, runs, || {
+        load_tasks(backlog_dir);
+        Ok(())
+    })?);
+
+    let loaded = load_tasks(backlog_dir);
+    let status = vec!["To Do".to_string(), "In Progress".to_string()];
+    let labels = vec!["payments".to_string()];
+    steps.push(timed("filter", 1, runs, || {
+        filter_tasks(
+            &loaded,
+            Some(&status),
+            None,
+            None,
+            None,
+            Some(&labels),
+            None,
+            Some(true),
+            None,
+            Some("synthetic"),
+        );
+        Ok(())
+    })?);
+
+    steps.push(timed("index_rebuild", 1, runs, || {
+        rebuild_index(backlog_dir)?;
+        Ok(())
+    })?);
+
+    // A CLI status change: rewrite the file, then the incremental index refresh.
+    let paths: Vec<_> = loaded
+        .iter()
+        .filter_map(|task| task.file_path.clone())
+        .take(MUTATIONS_PER_RUN)
+        .collect();
+    let mut round = 0usize;
+    steps.push(timed("mutation", paths.len(), runs, || {
+        round += 1;
+        let status = if round % 2 == 1 {
+            "In Progress"
+        } else {
+            "To Do"
+        };
+        for path in &paths {
+            update_task_field(path, "status", Some(FieldValue::Scalar(status.to_string())))?;
+            refresh_index(backlog_dir)?;
+        }
+        Ok(())
+    })?);
+
+    Ok(BenchSize { tasks, steps })
+}
+
+fn timed(
+    step: &str,
+    ops: usize,
+    runs: usize,
+    mut work: impl FnMut() -> Result<(), BenchError>,
+) -> Result<BenchStep, BenchError> {
+    let mut samples = Vec::with_capacity(runs);
+    for _ in 0..runs {
+        let start = Instant::now();
+        work()?;
+        samples.push(start.elapsed().as_secs_f64() * 1000.0);
+    }
+    samples.sort_by(|a, b| a.total_cmp(b));
+    Ok(BenchStep {
+        step: step.to_string(),
+        median_ms: samples[samples.len() / 2],
+        ops,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn bench_sizes_ladder_up_to_max() {
+        assert_eq!(bench_sizes(10_000), vec![100, 1_000, 10_000]);
+        assert_eq!(bench_sizes(500), vec![50, 500]);
+        assert_eq!(bench_sizes(5), vec![5]);
+    }
+
+    #[test]
+    fn generated_backlog_is_deterministic_and_loadable() {
+        let temp = TempDir::new().expect("tempdir");
+        let first = temp.path().join("a");
+        let second = temp.path().join("b");
+        generate_backlog(&first, 40).expect("generate");
+        generate_backlog(&second, 40).expect("generate");
+
+        let tasks = load_tasks(&first);
+        assert_eq!(tasks.len(), 40);
+        assert!(tasks.iter().any(|task| !task.dependencies.is_empty()));
+        assert!(tasks.iter().any(|task| task.status == "Done"));
+        let again = load_tasks(&second);
+        let pairs = tasks.iter().zip(again.iter());
+        assert!(pairs.into_iter().all(|(a, b)| a.id == b.id
+            && a.status == b.status
+            && a.labels == b.labels
+            && a.dependencies == b.dependencies));
+    }
+
+    #[test]
+    fn run_bench_times_every_step_for_every_size() {
+        let temp = TempDir::new().expect("tempdir");
+        let mut seen = Vec::new();
+        let report =
+            run_bench(temp.path(), &[10, 30], 1, |size| seen.push(size.tasks)).expect("bench");
+        assert_eq!(seen, vec![10, 30]);
+        for size in &report.sizes {
+            let steps: Vec<&str> = size.steps.iter().map(|step| step.step.as_str()).collect();
+            assert_eq!(steps, BENCH_STEPS);
+        }
+        assert_eq!(report.sizes[1].steps[4].ops, 20);
+        assert!(report.per_op_ms(30, "mutation").is_some());
+        assert!(report.per_op_ms(99, "load").is_none());
+    }
+}
@@ -0,0 +1,325 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use chrono::{Local, NaiveDateTime};
+use serde::Serialize;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::policies::parse_task_date;
+use crate::task::Task;
+
+/// Status assumed before a task's first recorded status change.
+const INITIAL_STATUS: &str = "To Do";
+
+/// Hours a task spent in each scheduling state, reconstructed from the audit log.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TimeInState {
+    /// Not started and at least one dependency unfinished (or status `Blocked`).
+    pub blocked_hours: f64,
+    /// Not started with every dependency finished.
+    pub ready_hours: f64,
+    pub in_progress_hours: f64,
+}
+
+impl TimeInState {
+    fn add(&mut self, state: State, hours: f64) {
+        match state {
+            State::Blocked => self.blocked_hours += hours,
+            State::Ready => self.ready_hours += hours,
+            State::InProgress => self.in_progress_hours += hours,
+        }
+    }
+
+    pub fn total_hours(&self) -> f64 {
+        self.blocked_hours + self.ready_hours + self.in_progress_hours
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskTimeInState {
+    pub task_id: String,
+    pub title: String,
+    pub status: String,
+    /// When tracking starts: the `add_task` event, else `created_date`, else the first status
+    /// change.
+    pub start: String,
+    /// When the task was closed, if it is.
+    pub end: Option<String>,
+    #[serde(flatten)]
+    pub time: TimeInState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Blocked,
+    Ready,
+    InProgress,
+}
+
+/// Status changes per task (lowercase id), oldest first, plus `add_task` times.
+pub struct StatusTimelines {
+    changes: HashMap<String, Vec<(NaiveDateTime, String)>>,
+    created: HashMap<String, NaiveDateTime>,
+}
+
+impl StatusTimelines {
+    pub fn from_events(events: &[AuditEvent]) -> Self {
+        let mut changes: HashMap<String, Vec<(NaiveDateTime, String)>> = HashMap::new();
+        let mut created: HashMap<String, NaiveDateTime> = HashMap::new();
+        for event in events {
+            let (Some(task_id), Some(time)) =
+                (event.task_id.as_deref(), parse_task_date(&event.timestamp))
+            else {
+                continue;
+            };
+            let task_id = task_id.to_lowercase();
+            if event.action == "add_task" {
+                created.entry(task_id.clone()).or_insert(time);
+            }
+            if let Some(status) = event.details["status"].as_str() {
+                if matches!(
+                    event.action.as_str(),
+                    "set_status" | "bulk_set_status" | "add_task"
+                ) {
+                    changes
+                        .entry(task_id)
+                        .or_default()
+                        .push((time, status.trim().to_string()));
+                }
+            }
+        }
+        for timeline in changes.values_mut() {
+            timeline.sort_by_key(|(time, _)| *time);
+        }
+        Self { changes, created }
+    }
+
+    /// Status at `time`, or `None` when the task has no recorded changes.
+    fn status_at(&self, task_id: &str, time: NaiveDateTime) -> Option<&str> {
+        let timeline = self.changes.get(task_id)?;
+        Some(
+            timeline
+                .iter()
+                .take_while(|(changed, _)| *changed <= time)
+                .last()
+                .map_or(INITIAL_STATUS, |(_, status)| status.as_str()),
+        )
+    }
+}
+
+/// Per-task blocked/ready/in-progress hours for every task with timing data, most blocked first.
+pub fn blocked_time_report(backlog_dir: &Path, tasks: &[Task]) -> Vec<TaskTimeInState> {
+    let timelines = StatusTimelines::from_events(&read_audit_events(backlog_dir));
+    let now = Local::now().naive_local();
+    let mut report: Vec<TaskTimeInState> = tasks
+        .iter()
+        .filter_map(|task| task_time_in_state(task, tasks, &timelines, now))
+        .collect();
+    report.sort_by(|a, b| {
+        b.time
+            .blocked_hours
+            .partial_cmp(&a.time.blocked_hours)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.task_id.cmp(&b.task_id))
+    });
+    report
+}
+
+/// Timing for a single task (e.g. `show --json`).
+pub fn blocked_time_for_task(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task: &Task,
+) -> Option<TaskTimeInState> {
+    let timelines = StatusTimelines::from_events(&read_audit_events(backlog_dir));
+    task_time_in_state(task, tasks, &timelines, Local::now().naive_local())
+}
+
+/// Walk the task's life from its start until it closes (or `now`), classifying each interval.
+///
+/// Dependencies use the current `dependencies` list; a dependency counts as finished from its
+/// recorded move to a closed status. Dependencies without history use their current status,
+/// and unknown ids (e.g. archived) count as finished.
+pub fn task_time_in_state(
+    task: &Task,
+    tasks: &[Task],
+    timelines: &StatusTimelines,
+    now: NaiveDateTime,
+) -> Option<TaskTimeInState> {
+    let id = task.id.to_lowercase();
+    let own_changes = timelines.changes.get(&id);
+    let start = timelines
+        .created
+        .get(&id)
+        .copied()
+        .or_else(|| task.created_date.as_deref().and_then(parse_task_date))
+        .or_else(|| own_changes.and_then(|changes| changes.first().map(|(time, _)| *time)))?;
+
+    let deps: Vec<(String, Option<&Task>)> = task
+        .dependencies
+        .iter()
+        .map(|dep| {
+            let dep = dep.to_lowercase();
+            let found = tasks
+                .iter()
+                .find(|candidate| candidate.id.to_lowercase() == dep);
+            (dep, found)
+        })
+        .collect();
+
+    let mut boundaries: BTreeSet<NaiveDateTime> = BTreeSet::new();
+    boundaries.insert(start);
+    boundaries.insert(now.max(start));
+    for key in std::iter::once(&id).chain(deps.iter().map(|(dep, _)| dep)) {
+        if let Some(changes) = timelines.changes.get(key) {
+            boundaries.extend(
+                changes
+                    .iter()
+                    .map(|(time, _)| *time)
+                    .filter(|time| *time > start && *time < now),
+            );
+        }
+    }
+
+    let own_status = |time: NaiveDateTime| -> String {
+        timelines
+            .status_at(&id, time)
+            .unwrap_or(task.status.as_str())
+            .to_string()
+    };
+    let dep_closed = |dep: &str, found: Option<&Task>, time: NaiveDateTime| -> bool {
+        match timelines.status_at(dep, time) {
+            Some(status) => is_closed_status(status),
+            None => found.is_none_or(|dep_task| is_closed_status(&dep_task.status)),
+        }
+    };
+
+    let mut time = TimeInState::default();
+    let mut end = None;
+    let points: Vec<NaiveDateTime> = boundaries.into_iter().collect();
+    for window in points.windows(2) {
+        let (from, to) = (window[0], window[1]);
+        let status = own_status(from);
+        if is_closed_status(&status) {
+            end = Some(from);
+            break;
+        }
+        let state = if status.eq_ignore_ascii_case("in progress") {
+            State::InProgress
+        } else if status.eq_ignore_ascii_case("blocked")
+            || deps
+                .iter()
+                .any(|(dep, found)| !dep_closed(dep, *found, from))
+        {
+            State::Blocked
+        } else {
+            State::Ready
+        };
+        time.add(state, (to - from).num_seconds() as f64 / 3600.0);
+    }
+    if end.is_none() && is_closed_status(&own_status(now)) {
+        end = points.last().copied();
+    }
+
+    Some(TaskTimeInState {
+        task_id: task.id.clone(),
+        title: task.title.clone(),
+        status: task.status.clone(),
+        start: start.format("%Y-%m-%d %H:%M").to_string(),
+        end: end.map(|time| time.format("%Y-%m-%d %H:%M").to_string()),
+        time,
+    })
+}
+
+fn is_closed_status(status: &str) -> bool {
+    let status = status.trim();
+    status.eq_ignore_ascii_case("done") || status.eq_ignore_ascii_case("cancelled")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn task(id: &str, status: &str, deps: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: deps.iter().map(|dep| dep.to_string()).collect(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: Default::default(),
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    fn event(timestamp: &str, action: &str, task_id: &str, status: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: action.to_string(),
+            task_id: Some(task_id.to_string()),
+            details: json!({ "status": status }),
+        }
+    }
+
+    #[test]
+    fn time_in_state_splits_blocked_ready_and_in_progress() {
+        let tasks = vec![
+            task("task-001", "Done", &[]),
+            task("task-002", "Done", &["task-001"]),
+        ];
+        let events = vec![
+            event("2026-03-02 09:00", "add_task", "task-001", "To Do"),
+            event("2026-03-02 09:00", "add_task", "task-002", "To Do"),
+            event("2026-03-02 10:00", "set_status", "task-001", "In Progress"),
+            // task-002 is blocked until its dependency is done at 14:00.
+            event("2026-03-02 14:00", "set_status", "task-001", "Done"),
+            event("2026-03-02 16:00", "set_status", "task-002", "In Progress"),
+            event("2026-03-02 20:00", "set_status", "task-002", "Done"),
+        ];
+        let timelines = StatusTimelines::from_events(&events);
+        let now = parse_task_date("2026-03-05 00:00").expect("now");
+
+        let second = task_time_in_state(&tasks[1], &tasks, &timelines, now).expect("timing");
+        assert_eq!(second.time.blocked_hours, 5.0);
+        assert_eq!(second.time.ready_hours, 2.0);
+        assert_eq!(second.time.in_progress_hours, 4.0);
+        assert_eq!(second.end.as_deref(), Some("2026-03-02 20:00"));
+
+        let first = task_time_in_state(&tasks[0], &tasks, &timelines, now).expect("timing");
+        assert_eq!(first.time.blocked_hours, 0.0);
+        assert_eq!(first.time.ready_hours, 1.0);
+        assert_eq!(first.time.in_progress_hours, 4.0);
+    }
+
+    #[test]
+    fn open_task_accrues_until_now_and_untracked_tasks_are_skipped() {
+        let tasks = vec![
+            task("task-001", "To Do", &[]),
+            task("task-002", "To Do", &["task-001"]),
+            task("task-003", "To Do", &[]),
+        ];
+        let events = vec![event("2026-03-02 00:00", "add_task", "task-002", "To Do")];
+        let timelines = StatusTimelines::from_events(&events);
+        let now = parse_task_date("2026-03-03 00:00").expect("now");
+
+        let open = task_time_in_state(&tasks[1], &tasks, &timelines, now).expect("timing");
+        assert_eq!(open.time.blocked_hours, 24.0);
+        assert_eq!(open.end, None);
+        assert!(task_time_in_state(&tasks[2], &tasks, &timelines, now).is_none());
+    }
+}
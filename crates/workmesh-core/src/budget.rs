@@ -0,0 +1,417 @@
+//! Point and hour budgets per initiative or phase (`[budgets]`), with burn tracking.
+//!
+//! Points come from `story_points` (or `points`) and count as consumed once a task is Done.
+//! Hours consumed are the logged `actual`/`time_spent`, falling back to the estimate for Done
+//! tasks without a log; planned hours are the larger of estimate and log on every task that is
+//! not Cancelled. Burn rate is consumption since the budget `start` (default: the earliest
+//! `created_date` in scope), and a budget whose plan exceeds it gets the date the burn crosses it.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::calibration::parse_duration_hours;
+use crate::config::{load_config, load_global_config};
+use crate::gantt::estimate_days;
+use crate::health::first_extra;
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::task_ops::is_done;
+
+/// Front matter keys holding story points.
+pub const POINTS_FIELDS: &[&str] = &["story_points", "points"];
+const ESTIMATE_FIELDS: &[&str] = &["estimate"];
+const ACTUAL_FIELDS: &[&str] = &["actual", "time_spent"];
+const HOURS_PER_DAY: f64 = 8.0;
+
+/// `[budgets]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+///
+/// ```toml
+/// [budgets.initiatives.auth]
+/// points = 40
+/// hours = 120
+/// start = "2026-03-01"
+///
+/// [budgets.phases.Phase1]
+/// hours = 80
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetsConfig {
+    #[serde(default)]
+    pub initiatives: BTreeMap<String, BudgetLimit>,
+    #[serde(default)]
+    pub phases: BTreeMap<String, BudgetLimit>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BudgetLimit {
+    pub points: Option<f64>,
+    pub hours: Option<f64>,
+    /// `YYYY-MM-DD` the budget started burning; burn rate is measured from here.
+    pub start: Option<String>,
+}
+
+/// Project `[budgets]` wins over global `[budgets]`.
+pub fn resolve_budgets_config(repo_root: &Path) -> BudgetsConfig {
+    load_config(repo_root)
+        .and_then(|config| config.budgets)
+        .or_else(|| load_global_config().and_then(|config| config.budgets))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetScope {
+    Initiative,
+    Phase,
+}
+
+impl BudgetScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetScope::Initiative => "initiative",
+            BudgetScope::Phase => "phase",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BudgetUnit {
+    Points,
+    Hours,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BudgetHealth {
+    /// The plan fits the budget.
+    Ok,
+    /// The plan exceeds the budget, but consumption has not yet.
+    AtRisk,
+    /// Consumption already exceeds the budget.
+    Over,
+}
+
+impl BudgetHealth {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BudgetHealth::Ok => "ok",
+            BudgetHealth::AtRisk => "at_risk",
+            BudgetHealth::Over => "over",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetMetric {
+    pub unit: BudgetUnit,
+    pub budget: f64,
+    pub consumed: f64,
+    pub planned: f64,
+    /// Budget minus consumed; negative once over.
+    pub remaining: f64,
+    pub percent_used: f64,
+    pub burn_per_week: f64,
+    pub health: BudgetHealth,
+    /// `YYYY-MM-DD` the burn rate crosses the budget, for at-risk budgets that are burning.
+    pub projected_overrun: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetStatus {
+    pub scope: BudgetScope,
+    pub name: String,
+    pub tasks: usize,
+    /// `YYYY-MM-DD` burn is measured from, when known.
+    pub start: Option<String>,
+    pub metrics: Vec<BudgetMetric>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BudgetReport {
+    pub budgets: Vec<BudgetStatus>,
+    pub warnings: Vec<String>,
+}
+
+/// Story points on `task`, when set to a number.
+pub fn task_points(task: &Task) -> Option<f64> {
+    first_extra(task, POINTS_FIELDS)
+        .and_then(|value| value.trim().parse::<f64>().ok())
+        .filter(|points| points.is_finite() && *points >= 0.0)
+}
+
+/// Estimated hours from `estimate`, else `estimate_days` at 8h per day.
+pub fn task_estimate_hours(task: &Task) -> Option<f64> {
+    first_extra(task, ESTIMATE_FIELDS)
+        .and_then(|value| parse_duration_hours(&value))
+        .or_else(|| estimate_days(task).map(|days| days * HOURS_PER_DAY))
+}
+
+/// Logged hours from `actual` or `time_spent`.
+pub fn task_logged_hours(task: &Task) -> Option<f64> {
+    first_extra(task, ACTUAL_FIELDS).and_then(|value| parse_duration_hours(&value))
+}
+
+/// Burn status of every configured budget, initiatives first, each sorted by name.
+pub fn budget_report(config: &BudgetsConfig, tasks: &[Task], now: NaiveDateTime) -> BudgetReport {
+    let mut budgets = Vec::new();
+    let mut warnings = Vec::new();
+    let scopes = [
+        (BudgetScope::Initiative, &config.initiatives),
+        (BudgetScope::Phase, &config.phases),
+    ];
+    for (scope, limits) in scopes {
+        for (name, limit) in limits {
+            let members: Vec<&Task> = tasks
+                .iter()
+                .filter(|task| match scope {
+                    BudgetScope::Initiative => task
+                        .initiative
+                        .as_deref()
+                        .is_some_and(|value| value.trim().eq_ignore_ascii_case(name)),
+                    BudgetScope::Phase => task.phase.trim().eq_ignore_ascii_case(name),
+                })
+                .collect();
+            if limit.points.is_none() && limit.hours.is_none() {
+                warnings.push(format!(
+                    "{} {} budget has neither points nor hours",
+                    scope.as_str(),
+                    name
+                ));
+                continue;
+            }
+            let start = match limit.start.as_deref() {
+                Some(raw) => match NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d") {
+                    Ok(date) => date.and_hms_opt(0, 0, 0),
+                    Err(_) => {
+                        warnings.push(format!(
+                            "{} {} budget start is not YYYY-MM-DD: {}",
+                            scope.as_str(),
+                            name,
+                            raw
+                        ));
+                        None
+                    }
+                },
+                None => members
+                    .iter()
+                    .filter_map(|task| task.created_date.as_deref())
+                    .filter_map(parse_task_date)
+                    .min(),
+            };
+            budgets.push(budget_status(scope, name, limit, &members, start, now));
+        }
+    }
+    BudgetReport { budgets, warnings }
+}
+
+fn budget_status(
+    scope: BudgetScope,
+    name: &str,
+    limit: &BudgetLimit,
+    members: &[&Task],
+    start: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+) -> BudgetStatus {
+    let active: Vec<&Task> = members
+        .iter()
+        .copied()
+        .filter(|task| !task.status.trim().eq_ignore_ascii_case("cancelled"))
+        .collect();
+    let mut metrics = Vec::new();
+    if let Some(budget) = limit.points {
+        let planned: f64 = active.iter().filter_map(|task| task_points(task)).sum();
+        let consumed: f64 = active
+            .iter()
+            .filter(|task| is_done(task))
+            .filter_map(|task| task_points(task))
+            .sum();
+        metrics.push(metric(
+            BudgetUnit::Points,
+            budget,
+            consumed,
+            planned,
+            start,
+            now,
+        ));
+    }
+    if let Some(budget) = limit.hours {
+        let mut planned = 0.0;
+        let mut consumed = 0.0;
+        for task in &active {
+            let estimate = task_estimate_hours(task);
+            let logged = task_logged_hours(task);
+            planned += estimate.unwrap_or(0.0).max(logged.unwrap_or(0.0));
+            consumed += match (logged, is_done(task)) {
+                (Some(hours), _) => hours,
+                (None, true) => estimate.unwrap_or(0.0),
+                (None, false) => 0.0,
+            };
+        }
+        metrics.push(metric(
+            BudgetUnit::Hours,
+            budget,
+            consumed,
+            planned,
+            start,
+            now,
+        ));
+    }
+    BudgetStatus {
+        scope,
+        name: name.to_string(),
+        tasks: members.len(),
+        start: start.map(|at| at.format("%Y-%m-%d").to_string()),
+        metrics,
+    }
+}
+
+fn metric(
+    unit: BudgetUnit,
+    budget: f64,
+    consumed: f64,
+    planned: f64,
+    start: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+) -> BudgetMetric {
+    let burn_per_day = start
+        .map(|start| (now - start).num_days().max(1) as f64)
+        .map(|days| consumed / days)
+        .unwrap_or(0.0);
+    let health = if consumed > budget {
+        BudgetHealth::Over
+    } else if planned > budget {
+        BudgetHealth::AtRisk
+    } else {
+        BudgetHealth::Ok
+    };
+    let projected_overrun = (health == BudgetHealth::AtRisk && burn_per_day > 0.0).then(|| {
+        let days = ((budget - consumed) / burn_per_day).ceil() as i64;
+        (now + Duration::days(days)).format("%Y-%m-%d").to_string()
+    });
+    BudgetMetric {
+        unit,
+        budget,
+        consumed: round1(consumed),
+        planned: round1(planned),
+        remaining: round1(budget - consumed),
+        percent_used: if budget > 0.0 {
+            round1(consumed * 100.0 / budget)
+        } else {
+            0.0
+        },
+        burn_per_week: round1(burn_per_day * 7.0),
+        health,
+        projected_overrun,
+    }
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, status: &str, initiative: &str, extra: &[(&str, &str)]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: Some(initiative.to_string()),
+            created_date: Some("2026-03-01 09:00".to_string()),
+            updated_date: None,
+            extra: extra
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        key.to_string(),
+                        serde_yaml::Value::String(value.to_string()),
+                    )
+                })
+                .collect(),
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    #[test]
+    fn budget_report_tracks_burn_and_projects_overrun() {
+        let config: BudgetsConfig = toml::from_str(
+            r#"
+            [initiatives.auth]
+            points = 10
+            hours = 40
+
+            [phases.phase1]
+            hours = 100
+            start = "2026-03-15"
+
+            [initiatives.empty]
+            "#,
+        )
+        .expect("toml");
+        let tasks = vec![
+            task(
+                "task-001",
+                "Done",
+                "auth",
+                &[("story_points", "5"), ("estimate", "2d")],
+            ),
+            task(
+                "task-002",
+                "In Progress",
+                "auth",
+                &[("points", "8"), ("actual", "8h"), ("estimate", "1w")],
+            ),
+            task("task-003", "Cancelled", "auth", &[("story_points", "13")]),
+            task("task-004", "To Do", "billing", &[("estimate_days", "1")]),
+        ];
+        let now = parse_task_date("2026-03-29 12:00").expect("now");
+
+        let report = budget_report(&config, &tasks, now);
+        assert_eq!(
+            report.warnings,
+            vec!["initiative empty budget has neither points nor hours"]
+        );
+        let auth = &report.budgets[0];
+        assert_eq!(
+            (auth.scope, auth.name.as_str()),
+            (BudgetScope::Initiative, "auth")
+        );
+        assert_eq!(auth.tasks, 3);
+        assert_eq!(auth.start.as_deref(), Some("2026-03-01"));
+
+        let points = &auth.metrics[0];
+        assert_eq!((points.consumed, points.planned), (5.0, 13.0));
+        assert_eq!(points.health, BudgetHealth::AtRisk);
+        // 5 points in 28 days: the remaining 5 take another 28.
+        assert_eq!(points.projected_overrun.as_deref(), Some("2026-04-26"));
+
+        let hours = &auth.metrics[1];
+        // Done task-001 burns its 16h estimate, task-002 its logged 8h; the plan is 16h + 40h.
+        assert_eq!((hours.consumed, hours.planned), (24.0, 56.0));
+        assert_eq!(hours.percent_used, 60.0);
+        assert_eq!(hours.burn_per_week, 6.0);
+
+        let phase = &report.budgets[1];
+        assert_eq!(phase.tasks, 4);
+        assert_eq!(phase.metrics[0].health, BudgetHealth::Ok);
+        assert_eq!(phase.metrics[0].planned, 64.0);
+        assert_eq!(phase.metrics[0].projected_overrun, None);
+    }
+}
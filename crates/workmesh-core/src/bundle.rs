@@ -0,0 +1,491 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::audit::{append_audit_event, read_audit_events, AuditError, AuditEvent};
+use crate::extract::epic_subtree;
+use crate::storage::write_string_atomic_locked;
+use crate::task::{parse_task_text, Task, TaskParseError};
+use crate::task_ops::{append_task_note, now_timestamp};
+
+pub const BUNDLE_FORMAT: &str = "workmesh-bundle";
+pub const BUNDLE_VERSION: u32 = 1;
+/// Audit detail key marking a note that came from a bundle annotation.
+const ANNOTATION_DETAIL: &str = "bundle_annotation";
+
+#[derive(Debug, Error)]
+pub enum BundleError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid bundle: {0}")]
+    Invalid(String),
+    #[error("Task not in bundle: {0}")]
+    TaskNotInBundle(String),
+    #[error("No tasks matched the selection")]
+    Empty,
+    #[error("Failed to update task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+}
+
+/// A self-contained, single-file snapshot of selected tasks for offline review.
+///
+/// Everything except `annotations` is written once by [`create_bundle`]; reviewers only append
+/// annotations, which [`apply_bundle`] turns into notes back home.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bundle {
+    pub format: String,
+    pub version: u32,
+    pub created_at: String,
+    #[serde(default)]
+    pub created_by: Option<String>,
+    pub tasks: Vec<BundledTask>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledTask {
+    pub id: String,
+    pub file_name: String,
+    /// Task file as stored on disk (confidential bodies stay encrypted).
+    pub content: String,
+    /// SHA-256 of `content`, used to flag tasks that changed after bundling.
+    pub sha256: String,
+    #[serde(default)]
+    pub history: Vec<AuditEvent>,
+    #[serde(default)]
+    pub attachments: Vec<BundledAttachment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundledAttachment {
+    /// Path relative to `attachments/<task-id>/`.
+    pub path: String,
+    /// Base64 file contents.
+    pub data: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Annotation {
+    pub id: String,
+    pub task_id: String,
+    #[serde(default)]
+    pub author: Option<String>,
+    pub at: String,
+    pub note: String,
+}
+
+/// Which tasks go into a bundle; every given filter must match. Empty selects everything.
+#[derive(Debug, Clone, Default)]
+pub struct BundleSelection {
+    pub ids: Vec<String>,
+    pub statuses: Vec<String>,
+    pub labels: Vec<String>,
+    /// Epic id; selects the epic and its subtree.
+    pub epic: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleApplyEntry {
+    pub annotation_id: String,
+    pub task_id: String,
+    /// `applied`, `pending` (dry-run), `already_applied`, or `missing_task`.
+    pub state: &'static str,
+    /// The local task changed since the bundle was created.
+    pub task_changed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BundleApplyReport {
+    pub apply: bool,
+    pub entries: Vec<BundleApplyEntry>,
+}
+
+pub fn select_bundle_tasks<'a>(tasks: &'a [Task], selection: &BundleSelection) -> Vec<&'a Task> {
+    let lower = |values: &[String]| -> HashSet<String> {
+        values
+            .iter()
+            .map(|value| value.trim().to_lowercase())
+            .collect()
+    };
+    let ids = lower(&selection.ids);
+    let statuses = lower(&selection.statuses);
+    let labels = lower(&selection.labels);
+    let epic_ids: Option<HashSet<String>> = selection.epic.as_deref().map(|epic| {
+        tasks
+            .iter()
+            .find(|task| task.id.eq_ignore_ascii_case(epic.trim()))
+            .map(|root| {
+                epic_subtree(tasks, root)
+                    .into_iter()
+                    .map(|task| task.id.to_lowercase())
+                    .collect()
+            })
+            .unwrap_or_default()
+    });
+    tasks
+        .iter()
+        .filter(|task| ids.is_empty() || ids.contains(&task.id.to_lowercase()))
+        .filter(|task| statuses.is_empty() || statuses.contains(&task.status.to_lowercase()))
+        .filter(|task| {
+            labels.is_empty()
+                || task
+                    .labels
+                    .iter()
+                    .any(|label| labels.contains(&label.to_lowercase()))
+        })
+        .filter(|task| {
+            epic_ids
+                .as_ref()
+                .is_none_or(|set| set.contains(&task.id.to_lowercase()))
+        })
+        .collect()
+}
+
+/// Package `selected` tasks with their audit history and attachments.
+pub fn create_bundle(
+    backlog_dir: &Path,
+    selected: &[&Task],
+    created_by: Option<String>,
+) -> Result<Bundle, BundleError> {
+    if selected.is_empty() {
+        return Err(BundleError::Empty);
+    }
+    let events = read_audit_events(backlog_dir);
+    let mut tasks = Vec::new();
+    for task in selected {
+        let Some(path) = task.file_path.as_ref() else {
+            continue;
+        };
+        let content = fs::read_to_string(path)?;
+        let id = task.id.to_lowercase();
+        let history = events
+            .iter()
+            .filter(|event| {
+                event
+                    .task_id
+                    .as_deref()
+                    .is_some_and(|task_id| task_id.to_lowercase() == id)
+            })
+            .cloned()
+            .collect();
+        let mut attachments = Vec::new();
+        collect_attachments(
+            &backlog_dir.join("attachments").join(&task.id),
+            Path::new(""),
+            &mut attachments,
+        )?;
+        tasks.push(BundledTask {
+            id: task.id.clone(),
+            file_name: path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_else(|| format!("{}.md", task.id)),
+            sha256: sha256_hex(&content),
+            content,
+            history,
+            attachments,
+        });
+    }
+    Ok(Bundle {
+        format: BUNDLE_FORMAT.to_string(),
+        version: BUNDLE_VERSION,
+        created_at: now_timestamp(),
+        created_by,
+        tasks,
+        annotations: Vec::new(),
+    })
+}
+
+fn collect_attachments(
+    dir: &Path,
+    relative: &Path,
+    out: &mut Vec<BundledAttachment>,
+) -> std::io::Result<()> {
+    let Ok(entries) = fs::read_dir(dir.join(relative)) else {
+        return Ok(());
+    };
+    let mut entries: Vec<_> = entries.filter_map(|entry| entry.ok()).collect();
+    entries.sort_by_key(|entry| entry.file_name());
+    for entry in entries {
+        let child = relative.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            collect_attachments(dir, &child, out)?;
+        } else {
+            out.push(BundledAttachment {
+                path: child.to_string_lossy().replace('\\', "/"),
+                data: STANDARD.encode(fs::read(entry.path())?),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn write_bundle(path: &Path, bundle: &Bundle) -> Result<(), BundleError> {
+    let text = serde_json::to_string_pretty(bundle)
+        .map_err(|err| BundleError::Invalid(err.to_string()))?;
+    write_string_atomic_locked(path, &text)?;
+    Ok(())
+}
+
+pub fn read_bundle(path: &Path) -> Result<Bundle, BundleError> {
+    let bundle: Bundle = serde_json::from_str(&fs::read_to_string(path)?)
+        .map_err(|err| BundleError::Invalid(err.to_string()))?;
+    if bundle.format != BUNDLE_FORMAT {
+        return Err(BundleError::Invalid(format!(
+            "unexpected format {:?}",
+            bundle.format
+        )));
+    }
+    if bundle.version > BUNDLE_VERSION {
+        return Err(BundleError::Invalid(format!(
+            "version {} is newer than supported version {}",
+            bundle.version, BUNDLE_VERSION
+        )));
+    }
+    Ok(bundle)
+}
+
+/// Parsed tasks from the bundle, for read-only display. `file_path` points inside the bundle.
+pub fn bundle_tasks(bundle_path: &Path, bundle: &Bundle) -> Vec<Task> {
+    bundle
+        .tasks
+        .iter()
+        .filter_map(|bundled| {
+            parse_task_text(&bundled.content, &bundle_path.join(&bundled.file_name)).ok()
+        })
+        .collect()
+}
+
+/// Add a reviewer note to the bundle (the only change a bundle accepts).
+pub fn annotate_bundle(
+    bundle: &mut Bundle,
+    task_id: &str,
+    note: &str,
+    author: Option<String>,
+) -> Result<Annotation, BundleError> {
+    let task = bundle
+        .tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(task_id.trim()))
+        .ok_or_else(|| BundleError::TaskNotInBundle(task_id.to_string()))?;
+    let at = now_timestamp();
+    let id = sha256_hex(&format!(
+        "{}|{}|{}|{}|{}",
+        task.id,
+        author.as_deref().unwrap_or(""),
+        at,
+        bundle.annotations.len(),
+        note
+    ))[..12]
+        .to_string();
+    let annotation = Annotation {
+        id,
+        task_id: task.id.clone(),
+        author,
+        at,
+        note: note.trim().to_string(),
+    };
+    bundle.annotations.push(annotation.clone());
+    Ok(annotation)
+}
+
+/// Merge bundle annotations into local tasks as notes. Idempotent: annotations already applied
+/// (recorded in the audit log) are skipped. Dry-run unless `apply`.
+pub fn apply_bundle(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    bundle: &Bundle,
+    apply: bool,
+) -> Result<BundleApplyReport, BundleError> {
+    let applied: HashSet<String> = read_audit_events(backlog_dir)
+        .iter()
+        .filter_map(|event| {
+            event
+                .details
+                .get(ANNOTATION_DETAIL)?
+                .as_str()
+                .map(str::to_string)
+        })
+        .collect();
+    let mut entries = Vec::new();
+    for annotation in &bundle.annotations {
+        let local = tasks
+            .iter()
+            .find(|task| task.id.eq_ignore_ascii_case(&annotation.task_id));
+        let task_changed = match (
+            local.and_then(|task| task.file_path.as_ref()),
+            bundle
+                .tasks
+                .iter()
+                .find(|bundled| bundled.id.eq_ignore_ascii_case(&annotation.task_id)),
+        ) {
+            (Some(path), Some(bundled)) => fs::read_to_string(path)
+                .map(|content| sha256_hex(&content) != bundled.sha256)
+                .unwrap_or(true),
+            _ => false,
+        };
+        let state = match local {
+            None => "missing_task",
+            Some(_) if applied.contains(&annotation.id) => "already_applied",
+            Some(_) if !apply => "pending",
+            Some(task) => {
+                let Some(path) = task.file_path.as_ref() else {
+                    return Err(BundleError::Invalid(format!("{} has no file", task.id)));
+                };
+                let note = match annotation.author.as_deref() {
+                    Some(author) => format!("[review @{}] {}", author, annotation.note),
+                    None => format!("[review] {}", annotation.note),
+                };
+                append_task_note(path, &note, "notes")?;
+                append_audit_event(
+                    backlog_dir,
+                    &AuditEvent {
+                        timestamp: now_timestamp(),
+                        actor: annotation.author.clone(),
+                        action: "note".to_string(),
+                        task_id: Some(task.id.clone()),
+                        details: serde_json::json!({
+                            "note": note,
+                            "section": "notes",
+                            ANNOTATION_DETAIL: annotation.id,
+                            "annotated_at": annotation.at,
+                        }),
+                    },
+                )?;
+                "applied"
+            }
+        };
+        entries.push(BundleApplyEntry {
+            annotation_id: annotation.id.clone(),
+            task_id: annotation.task_id.clone(),
+            state,
+            task_changed,
+        });
+    }
+    Ok(BundleApplyReport { apply, entries })
+}
+
+/// Restore a bundled attachment's bytes.
+pub fn decode_attachment(attachment: &BundledAttachment) -> Result<Vec<u8>, BundleError> {
+    STANDARD
+        .decode(&attachment.data)
+        .map_err(|err| BundleError::Invalid(format!("attachment {}: {}", attachment.path, err)))
+}
+
+/// Write a bundled task's attachments under `dest/<task-id>/` (for `bundle view --extract`).
+pub fn extract_attachments(
+    bundled: &BundledTask,
+    dest: &Path,
+) -> Result<Vec<PathBuf>, BundleError> {
+    let mut written = Vec::new();
+    for attachment in &bundled.attachments {
+        let relative = Path::new(&attachment.path);
+        if relative.is_absolute() || relative.components().any(|part| part.as_os_str() == "..") {
+            return Err(BundleError::Invalid(format!(
+                "unsafe attachment path: {}",
+                attachment.path
+            )));
+        }
+        let target = dest.join(&bundled.id).join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&target, decode_attachment(attachment)?)?;
+        written.push(target);
+    }
+    Ok(written)
+}
+
+fn sha256_hex(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+    use crate::task_ops::create_task_file;
+
+    #[test]
+    fn bundle_round_trip_carries_history_attachments_and_annotations() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        for (id, label) in [("task-001", "review"), ("task-002", "other")] {
+            create_task_file(
+                &tasks_dir,
+                id,
+                "Sample",
+                "To Do",
+                "P2",
+                "Phase1",
+                &[],
+                &[label.to_string()],
+                &[],
+            )
+            .expect("task");
+        }
+        let attachments = backlog.join("attachments/task-001/shots");
+        fs::create_dir_all(&attachments).expect("attachments");
+        fs::write(attachments.join("a.png"), [0u8, 159, 146, 150]).expect("attachment");
+        append_audit_event(
+            &backlog,
+            &AuditEvent {
+                timestamp: "2026-03-01 10:00".to_string(),
+                actor: Some("luis".to_string()),
+                action: "set_status".to_string(),
+                task_id: Some("task-001".to_string()),
+                details: serde_json::json!({"status": "To Do"}),
+            },
+        )
+        .expect("audit");
+
+        let tasks = load_tasks(&backlog);
+        let selection = BundleSelection {
+            labels: vec!["Review".to_string()],
+            ..BundleSelection::default()
+        };
+        let selected = select_bundle_tasks(&tasks, &selection);
+        let bundle = create_bundle(&backlog, &selected, Some("luis".to_string())).expect("bundle");
+        assert_eq!(bundle.tasks.len(), 1);
+        assert_eq!(bundle.tasks[0].history.len(), 1);
+        assert_eq!(bundle.tasks[0].attachments[0].path, "shots/a.png");
+
+        let file = temp.path().join("review.bundle");
+        write_bundle(&file, &bundle).expect("write");
+        let mut reviewed = read_bundle(&file).expect("read");
+        assert_eq!(bundle_tasks(&file, &reviewed)[0].id, "task-001");
+        assert!(annotate_bundle(&mut reviewed, "task-002", "nope", None).is_err());
+        annotate_bundle(&mut reviewed, "task-001", "Ship it", Some("ana".into())).expect("note");
+
+        let dry = apply_bundle(&backlog, &tasks, &reviewed, false).expect("dry");
+        assert_eq!(dry.entries[0].state, "pending");
+        let applied = apply_bundle(&backlog, &tasks, &reviewed, true).expect("apply");
+        assert_eq!(applied.entries[0].state, "applied");
+        let body = &load_tasks(&backlog)[0].body;
+        assert!(body.contains("[review @ana] Ship it"));
+        let again = apply_bundle(&backlog, &load_tasks(&backlog), &reviewed, true).expect("again");
+        assert_eq!(again.entries[0].state, "already_applied");
+        assert!(again.entries[0].task_changed);
+
+        let out = temp.path().join("out");
+        let written = extract_attachments(&reviewed.tasks[0], &out).expect("extract");
+        assert_eq!(
+            fs::read(&written[0]).expect("read"),
+            vec![0u8, 159, 146, 150]
+        );
+    }
+}
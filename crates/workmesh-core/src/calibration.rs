@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use chrono::{Datelike, Duration, NaiveDateTime, Weekday};
+use serde::Serialize;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::health::{first_extra, is_closed};
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::task_ops::is_done;
+
+/// Front matter keys holding a time estimate (`4h`, `2d`, `1w`; a bare number is hours).
+const ESTIMATE_FIELDS: &[&str] = &["estimate"];
+/// Front matter keys holding logged effort, in the same units as estimates.
+const ACTUAL_FIELDS: &[&str] = &["actual", "time_spent"];
+const HOURS_PER_DAY: f64 = 8.0;
+const DAYS_PER_WEEK: f64 = 5.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalibrationGroup {
+    Overall,
+    Assignee,
+    Label,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActualSource {
+    /// `actual`/`time_spent` front matter.
+    Logged,
+    /// Weekday time from first start to done in the audit log, 8h per day.
+    Audit,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationSample {
+    pub task_id: String,
+    pub estimate_hours: f64,
+    pub actual_hours: f64,
+    pub source: ActualSource,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationBucket {
+    pub key: String,
+    pub samples: Vec<CalibrationSample>,
+    pub estimate_hours: f64,
+    pub actual_hours: f64,
+    /// `actual / estimate` over all samples; above 1.0 means work runs over estimate.
+    pub bias: f64,
+    pub median_ratio: f64,
+    /// Remaining estimate on open tasks in this bucket.
+    pub open_estimate_hours: f64,
+    /// `open_estimate_hours * bias`.
+    pub adjusted_open_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CalibrationReport {
+    pub buckets: Vec<CalibrationBucket>,
+    /// Done tasks with an estimate but no measurable actual.
+    pub skipped: Vec<String>,
+}
+
+pub fn calibration_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    group: CalibrationGroup,
+) -> CalibrationReport {
+    calibration_from_events(tasks, &read_audit_events(backlog_dir), group)
+}
+
+fn calibration_from_events(
+    tasks: &[Task],
+    events: &[AuditEvent],
+    group: CalibrationGroup,
+) -> CalibrationReport {
+    let spans = work_spans(events);
+    let mut samples: BTreeMap<String, Vec<CalibrationSample>> = BTreeMap::new();
+    let mut open: BTreeMap<String, f64> = BTreeMap::new();
+    let mut skipped = Vec::new();
+
+    for task in tasks {
+        let Some(estimate) = first_extra(task, ESTIMATE_FIELDS)
+            .as_deref()
+            .and_then(parse_duration_hours)
+            .filter(|hours| *hours > 0.0)
+        else {
+            continue;
+        };
+        let keys = group_keys(task, group);
+        if !is_closed(task) {
+            for key in keys {
+                *open.entry(key).or_insert(0.0) += estimate;
+            }
+            continue;
+        }
+        if !is_done(task) {
+            continue;
+        }
+        let logged = first_extra(task, ACTUAL_FIELDS)
+            .as_deref()
+            .and_then(parse_duration_hours)
+            .map(|hours| (hours, ActualSource::Logged));
+        let measured = spans
+            .get(&task.id.to_lowercase())
+            .map(|(start, end)| (working_hours(*start, *end), ActualSource::Audit));
+        let Some((actual, source)) = logged.or(measured).filter(|(hours, _)| *hours > 0.0) else {
+            skipped.push(task.id.clone());
+            continue;
+        };
+        for key in keys {
+            samples.entry(key).or_default().push(CalibrationSample {
+                task_id: task.id.clone(),
+                estimate_hours: estimate,
+                actual_hours: actual,
+                source,
+            });
+        }
+    }
+
+    let mut keys: Vec<String> = samples.keys().chain(open.keys()).cloned().collect();
+    keys.sort();
+    keys.dedup();
+    let buckets = keys
+        .into_iter()
+        .map(|key| {
+            let samples = samples.remove(&key).unwrap_or_default();
+            let estimate_hours: f64 = samples.iter().map(|sample| sample.estimate_hours).sum();
+            let actual_hours: f64 = samples.iter().map(|sample| sample.actual_hours).sum();
+            let bias = if estimate_hours > 0.0 {
+                actual_hours / estimate_hours
+            } else {
+                1.0
+            };
+            let mut ratios: Vec<f64> = samples
+                .iter()
+                .map(|sample| sample.actual_hours / sample.estimate_hours)
+                .collect();
+            ratios.sort_by(|a, b| a.total_cmp(b));
+            let open_estimate_hours = open.get(&key).copied().unwrap_or(0.0);
+            CalibrationBucket {
+                key,
+                samples,
+                estimate_hours,
+                actual_hours,
+                bias,
+                median_ratio: median(&ratios).unwrap_or(1.0),
+                open_estimate_hours,
+                adjusted_open_hours: open_estimate_hours * bias,
+            }
+        })
+        .collect();
+
+    CalibrationReport { buckets, skipped }
+}
+
+/// Parse `90m`, `4h`, `1.5d`, `2w` into working hours (8h days, 5-day weeks).
+pub fn parse_duration_hours(value: &str) -> Option<f64> {
+    let value = value.trim().to_lowercase();
+    let split = value
+        .find(|ch: char| !(ch.is_ascii_digit() || ch == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let factor = match unit.trim() {
+        "" | "h" | "hr" | "hrs" | "hour" | "hours" => 1.0,
+        "m" | "min" | "mins" | "minutes" => 1.0 / 60.0,
+        "d" | "day" | "days" => HOURS_PER_DAY,
+        "w" | "wk" | "week" | "weeks" => HOURS_PER_DAY * DAYS_PER_WEEK,
+        _ => return None,
+    };
+    Some(number * factor)
+}
+
+fn group_keys(task: &Task, group: CalibrationGroup) -> Vec<String> {
+    let values: Vec<String> = match group {
+        CalibrationGroup::Overall => return vec!["all".to_string()],
+        CalibrationGroup::Assignee => task.assignee.clone(),
+        CalibrationGroup::Label => task.labels.clone(),
+    };
+    if values.is_empty() {
+        vec!["(none)".to_string()]
+    } else {
+        values
+    }
+}
+
+/// First move into "In Progress" to the last move into "Done", per task.
+fn work_spans(events: &[AuditEvent]) -> HashMap<String, (NaiveDateTime, NaiveDateTime)> {
+    let mut starts: HashMap<String, NaiveDateTime> = HashMap::new();
+    let mut spans = HashMap::new();
+    for event in events {
+        if !matches!(event.action.as_str(), "set_status" | "bulk_set_status") {
+            continue;
+        }
+        let (Some(task_id), Some(status)) =
+            (event.task_id.as_deref(), event.details["status"].as_str())
+        else {
+            continue;
+        };
+        let Some(time) = parse_task_date(&event.timestamp) else {
+            continue;
+        };
+        let task_id = task_id.to_lowercase();
+        let status = status.trim().to_lowercase();
+        if status == "in progress" {
+            let start = starts.entry(task_id).or_insert(time);
+            *start = (*start).min(time);
+        } else if status == "done" {
+            if let Some(start) = starts.get(&task_id) {
+                spans.insert(task_id, (*start, time));
+            }
+        }
+    }
+    spans
+}
+
+/// Weekday hours between two times, scaled so a full weekday counts as one 8h working day.
+fn working_hours(start: NaiveDateTime, end: NaiveDateTime) -> f64 {
+    let mut cursor = start;
+    let mut minutes = 0i64;
+    while cursor < end {
+        let next_midnight = (cursor.date() + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight");
+        let slice_end = next_midnight.min(end);
+        if !matches!(cursor.weekday(), Weekday::Sat | Weekday::Sun) {
+            minutes += (slice_end - cursor).num_minutes();
+        }
+        cursor = slice_end;
+    }
+    minutes as f64 / 60.0 * HOURS_PER_DAY / 24.0
+}
+
+fn median(sorted: &[f64]) -> Option<f64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let mid = sorted.len() / 2;
+    Some(if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn task(id: &str, status: &str, assignee: &str, extra: &[(&str, &str)]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: vec![assignee.to_string()],
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: extra
+                .iter()
+                .map(|(key, value)| {
+                    (
+                        key.to_string(),
+                        serde_yaml::Value::String(value.to_string()),
+                    )
+                })
+                .collect(),
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    fn status_event(timestamp: &str, task_id: &str, status: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: "set_status".to_string(),
+            task_id: Some(task_id.to_string()),
+            details: json!({ "status": status }),
+        }
+    }
+
+    #[test]
+    fn parse_duration_hours_accepts_units() {
+        assert_eq!(parse_duration_hours("4h"), Some(4.0));
+        assert_eq!(parse_duration_hours("1.5d"), Some(12.0));
+        assert_eq!(parse_duration_hours("1w"), Some(40.0));
+        assert_eq!(parse_duration_hours("30m"), Some(0.5));
+        assert_eq!(parse_duration_hours("3"), Some(3.0));
+        assert_eq!(parse_duration_hours("soon"), None);
+    }
+
+    #[test]
+    fn working_hours_skips_weekends() {
+        // Friday 00:00 -> Monday 00:00 is one weekday.
+        let start = parse_task_date("2026-01-02 00:00").expect("start");
+        let end = parse_task_date("2026-01-05 00:00").expect("end");
+        assert_eq!(working_hours(start, end), 8.0);
+    }
+
+    #[test]
+    fn calibration_groups_bias_and_forecast_by_assignee() {
+        let tasks = vec![
+            task(
+                "task-001",
+                "Done",
+                "alice",
+                &[("estimate", "4h"), ("actual", "6h")],
+            ),
+            task("task-002", "Done", "alice", &[("estimate", "1d")]),
+            task("task-003", "Done", "bob", &[("estimate", "2h")]),
+            task("task-004", "To Do", "alice", &[("estimate", "10h")]),
+        ];
+        // task-002: Monday 00:00 -> Tuesday 12:00 = 1.5 weekdays = 12 working hours.
+        let events = vec![
+            status_event("2026-01-05 00:00", "task-002", "In Progress"),
+            status_event("2026-01-06 12:00", "task-002", "Done"),
+        ];
+        let report = calibration_from_events(&tasks, &events, CalibrationGroup::Assignee);
+
+        assert_eq!(report.skipped, vec!["task-003".to_string()]);
+        assert_eq!(report.buckets.len(), 1);
+        let alice = &report.buckets[0];
+        assert_eq!(alice.key, "alice");
+        assert_eq!(alice.samples.len(), 2);
+        assert_eq!(alice.samples[0].source, ActualSource::Logged);
+        assert_eq!(alice.samples[1].source, ActualSource::Audit);
+        assert_eq!(alice.estimate_hours, 12.0);
+        assert_eq!(alice.actual_hours, 18.0);
+        assert_eq!(alice.bias, 1.5);
+        assert_eq!(alice.median_ratio, 1.5);
+        assert_eq!(alice.open_estimate_hours, 10.0);
+        assert_eq!(alice.adjusted_open_hours, 15.0);
+    }
+}
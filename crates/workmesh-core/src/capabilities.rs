@@ -0,0 +1,141 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde_json::{json, Map, Value};
+
+use crate::metrics::BacklogMetrics;
+use crate::task::{Lease, Relationships, Task};
+use crate::task_ops::{graph_export, task_to_json_value};
+
+/// Placeholder key for map-shaped fields in schema samples.
+pub const MAP_KEY: &str = "<key>";
+
+/// JSON Schema for the `--json` payloads shared by several commands.
+///
+/// Schemas are derived from the serializers themselves (a fully-populated sample is
+/// serialized and its shape recorded), so they cannot drift from the real output.
+pub fn output_schemas() -> BTreeMap<&'static str, Value> {
+    let task = sample_task();
+    let mut metrics = BacklogMetrics::default();
+    metrics.tasks_by_status.insert(MAP_KEY.to_string(), 1);
+    metrics.index_age_seconds = Some(0);
+    metrics.mutations_by_action.insert(MAP_KEY.to_string(), 1);
+
+    let mut schemas = BTreeMap::new();
+    schemas.insert("task", json_shape(&task_to_json_value(&task, true)));
+    schemas.insert(
+        "task_list",
+        json!({ "type": "array", "items": json_shape(&task_to_json_value(&task, true)) }),
+    );
+    schemas.insert("graph", json_shape(&graph_export(&[task])));
+    schemas.insert(
+        "metrics",
+        json_shape(&serde_json::to_value(&metrics).unwrap_or(Value::Null)),
+    );
+    schemas
+}
+
+/// Infer a JSON Schema from a sample value.
+///
+/// Objects keyed by data rather than field names (status names, actions) are marked in
+/// samples with a single [`MAP_KEY`] entry and become `additionalProperties`.
+pub fn json_shape(sample: &Value) -> Value {
+    match sample {
+        Value::Null => json!({ "type": "null" }),
+        Value::Bool(_) => json!({ "type": "boolean" }),
+        Value::Number(number) if number.is_f64() => json!({ "type": "number" }),
+        Value::Number(_) => json!({ "type": "integer" }),
+        Value::String(_) => json!({ "type": "string" }),
+        Value::Array(items) => match items.first() {
+            Some(first) => json!({ "type": "array", "items": json_shape(first) }),
+            None => json!({ "type": "array" }),
+        },
+        Value::Object(map) if is_data_keyed(map) => {
+            let first = map.values().next().cloned().unwrap_or(Value::Null);
+            json!({ "type": "object", "additionalProperties": json_shape(&first) })
+        }
+        Value::Object(map) => {
+            let properties: Map<String, Value> = map
+                .iter()
+                .map(|(key, value)| (key.clone(), json_shape(value)))
+                .collect();
+            json!({ "type": "object", "properties": properties })
+        }
+    }
+}
+
+fn is_data_keyed(map: &Map<String, Value>) -> bool {
+    map.len() == 1 && map.contains_key(MAP_KEY)
+}
+
+fn sample_task() -> Task {
+    Task {
+        id: "task-001".to_string(),
+        uid: Some("01J00000000000000000000000".to_string()),
+        kind: "task".to_string(),
+        title: "Sample".to_string(),
+        status: "To Do".to_string(),
+        priority: "P2".to_string(),
+        phase: "Phase1".to_string(),
+        dependencies: vec!["task-000".to_string()],
+        labels: vec!["label".to_string()],
+        assignee: vec!["user".to_string()],
+        relationships: Relationships {
+            blocked_by: vec!["task-000".to_string()],
+            parent: vec!["task-000".to_string()],
+            child: vec!["task-002".to_string()],
+            discovered_from: vec!["task-000".to_string()],
+            relates_to: vec![],
+        },
+        lease: Some(Lease {
+            owner: "user".to_string(),
+            acquired_at: Some("2026-01-01 10:00".to_string()),
+            expires_at: Some("2026-01-01 11:00".to_string()),
+        }),
+        project: Some("project".to_string()),
+        initiative: Some("initiative".to_string()),
+        created_date: Some("2026-01-01 10:00".to_string()),
+        updated_date: Some("2026-01-01 10:00".to_string()),
+        extra: HashMap::from([(
+            MAP_KEY.to_string(),
+            serde_yaml::Value::String("value".to_string()),
+        )]),
+        file_path: Some("tasks/task-001 - Sample.md".into()),
+        body: "## Notes\n".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_shape_describes_nested_values() {
+        let sample = json!({
+            "id": "task-001",
+            "count": 2,
+            "labels": ["a"],
+            "by_status": { MAP_KEY: 1 },
+        });
+        let schema = json_shape(&sample);
+        assert_eq!(schema["properties"]["id"]["type"], "string");
+        assert_eq!(schema["properties"]["count"]["type"], "integer");
+        assert_eq!(schema["properties"]["labels"]["items"]["type"], "string");
+        assert_eq!(
+            schema["properties"]["by_status"]["additionalProperties"]["type"],
+            "integer"
+        );
+    }
+
+    #[test]
+    fn output_schemas_cover_task_fields() {
+        let schemas = output_schemas();
+        let task = &schemas["task"]["properties"];
+        for key in ["id", "title", "status", "dependencies", "labels"] {
+            assert!(task.get(key).is_some(), "missing {}", key);
+        }
+        assert_eq!(schemas["task_list"]["type"], "array");
+        assert!(schemas["metrics"]["properties"]["tasks_by_status"]
+            .get("additionalProperties")
+            .is_some());
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::{Duration, Local, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+use ulid::Ulid;
+
+use crate::audit::{audit_log_len, read_audit_events_from, AuditEvent};
+use crate::backlog_diff::{diff_tasks, BacklogDiff};
+use crate::policies::parse_task_date;
+use crate::storage::write_string_atomic;
+use crate::task::{load_tasks, parse_task_text, Task};
+use crate::task_ops::now_timestamp;
+
+/// Cursors older than this, or beyond the newest `MAX_CURSORS`, are pruned.
+const CURSOR_TTL_DAYS: i64 = 7;
+const MAX_CURSORS: usize = 50;
+const CURSOR_PREFIX: &str = "wmc-";
+
+#[derive(Debug, Error)]
+pub enum ChangesError {
+    #[error("Unknown or expired cursor: {0} (call again without a cursor to start over)")]
+    UnknownCursor(String),
+    #[error("Failed to access cursor state: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to encode cursor state: {0}")]
+    Serialize(#[from] serde_json::Error),
+}
+
+/// What the backlog looked like when a cursor was issued: the audit log length and the raw
+/// text of every task file (sealed confidential bodies stay sealed).
+#[derive(Debug, Serialize, Deserialize)]
+struct CursorSnapshot {
+    cursor: String,
+    created_at: String,
+    audit_offset: u64,
+    tasks: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangeFeed {
+    /// Cursor the caller passed, if any.
+    pub cursor: Option<String>,
+    /// Pass this on the next call. Unchanged when nothing happened since `cursor`.
+    pub next_cursor: String,
+    /// When `cursor` was issued.
+    pub since: Option<String>,
+    /// No cursor was given: this call only establishes a baseline.
+    pub baseline: bool,
+    pub events: Vec<AuditEvent>,
+    /// Added/removed/changed tasks and dependency edges. Body edits are reported as a `body`
+    /// field change with null values; fetch the task to read the new text.
+    #[serde(flatten)]
+    pub diff: BacklogDiff,
+}
+
+impl ChangeFeed {
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty() && self.diff.is_empty()
+    }
+}
+
+pub fn cursors_dir(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join(".cursors")
+}
+
+fn cursor_path(backlog_dir: &Path, cursor: &str) -> Option<PathBuf> {
+    let id = cursor.trim();
+    let valid = id.starts_with(CURSOR_PREFIX)
+        && id.chars().all(|ch| ch.is_ascii_alphanumeric() || ch == '-');
+    valid.then(|| cursors_dir(backlog_dir).join(format!("{}.json", id)))
+}
+
+fn current_task_texts(backlog_dir: &Path) -> BTreeMap<String, String> {
+    load_tasks(backlog_dir)
+        .into_iter()
+        .filter_map(|task| {
+            let path = task.file_path?;
+            let text = fs::read_to_string(&path).ok()?;
+            let key = path
+                .strip_prefix(backlog_dir)
+                .unwrap_or(&path)
+                .to_string_lossy()
+                .replace('\\', "/");
+            Some((key, text))
+        })
+        .collect()
+}
+
+fn parse_texts(backlog_dir: &Path, texts: &BTreeMap<String, String>) -> Vec<Task> {
+    texts
+        .iter()
+        .filter_map(|(key, text)| parse_task_text(text, &backlog_dir.join(key)).ok())
+        .collect()
+}
+
+fn load_snapshot(backlog_dir: &Path, cursor: &str) -> Result<CursorSnapshot, ChangesError> {
+    let unknown = || ChangesError::UnknownCursor(cursor.to_string());
+    let path = cursor_path(backlog_dir, cursor).ok_or_else(unknown)?;
+    let raw = fs::read_to_string(&path).map_err(|_| unknown())?;
+    serde_json::from_str(&raw).map_err(|_| unknown())
+}
+
+fn save_snapshot(
+    backlog_dir: &Path,
+    audit_offset: u64,
+    tasks: BTreeMap<String, String>,
+) -> Result<String, ChangesError> {
+    let cursor = format!(
+        "{}{}",
+        CURSOR_PREFIX,
+        Ulid::new().to_string().to_lowercase()
+    );
+    let snapshot = CursorSnapshot {
+        cursor: cursor.clone(),
+        created_at: now_timestamp(),
+        audit_offset,
+        tasks,
+    };
+    let dir = cursors_dir(backlog_dir);
+    fs::create_dir_all(&dir)?;
+    let path = dir.join(format!("{}.json", cursor));
+    write_string_atomic(&path, &serde_json::to_string(&snapshot)?)?;
+    prune_cursors(&dir, Local::now().naive_local());
+    Ok(cursor)
+}
+
+/// Best-effort cleanup; ULID names sort by creation time.
+fn prune_cursors(dir: &Path, now: NaiveDateTime) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    let cutoff = now - Duration::days(CURSOR_TTL_DAYS);
+    let excess = files.len().saturating_sub(MAX_CURSORS);
+    for (idx, path) in files.iter().enumerate() {
+        let expired = fs::read_to_string(path)
+            .ok()
+            .and_then(|raw| serde_json::from_str::<CursorSnapshot>(&raw).ok())
+            .and_then(|snapshot| parse_task_date(&snapshot.created_at))
+            .is_some_and(|created| created < cutoff);
+        if idx < excess || expired {
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+fn blank_body_changes(diff: &mut BacklogDiff) {
+    for task in &mut diff.changed {
+        for change in &mut task.changes {
+            if change.field == "body" {
+                change.old = Value::Null;
+                change.new = Value::Null;
+            }
+        }
+    }
+}
+
+fn retain_tasks(feed: &mut ChangeFeed, ids: &BTreeSet<String>) {
+    let keep = |id: &str| ids.contains(&id.to_lowercase());
+    feed.events
+        .retain(|event| event.task_id.as_deref().is_some_and(keep));
+    feed.diff.added.retain(|task| keep(&task.id));
+    feed.diff.removed.retain(|task| keep(&task.id));
+    feed.diff.changed.retain(|task| keep(&task.id));
+    feed.diff
+        .dependencies_added
+        .retain(|edge| keep(&edge.task_id));
+    feed.diff
+        .dependencies_removed
+        .retain(|edge| keep(&edge.task_id));
+}
+
+/// Audit events and task diffs since `cursor` (a baseline when `None`), optionally limited to
+/// `task_ids`. A new cursor is issued only when something changed, so idle polling is cheap.
+pub fn changes_since(
+    backlog_dir: &Path,
+    cursor: Option<&str>,
+    task_ids: &[String],
+) -> Result<ChangeFeed, ChangesError> {
+    let audit_offset = audit_log_len(backlog_dir);
+    let current = current_task_texts(backlog_dir);
+    let cursor = cursor.map(str::trim).filter(|value| !value.is_empty());
+
+    let Some(cursor) = cursor else {
+        let next_cursor = save_snapshot(backlog_dir, audit_offset, current)?;
+        return Ok(ChangeFeed {
+            cursor: None,
+            next_cursor,
+            since: None,
+            baseline: true,
+            events: Vec::new(),
+            diff: BacklogDiff::default(),
+        });
+    };
+
+    let snapshot = load_snapshot(backlog_dir, cursor)?;
+    // A truncated or rotated audit log restarts from its beginning.
+    let from = if audit_offset < snapshot.audit_offset {
+        0
+    } else {
+        snapshot.audit_offset
+    };
+    let events = read_audit_events_from(backlog_dir, from);
+    let mut diff = diff_tasks(
+        &parse_texts(backlog_dir, &snapshot.tasks),
+        &parse_texts(backlog_dir, &current),
+    );
+    blank_body_changes(&mut diff);
+
+    let mut feed = ChangeFeed {
+        cursor: Some(cursor.to_string()),
+        next_cursor: cursor.to_string(),
+        since: Some(snapshot.created_at),
+        baseline: false,
+        events,
+        diff,
+    };
+    if !feed.is_empty() || audit_offset != snapshot.audit_offset {
+        feed.next_cursor = save_snapshot(backlog_dir, audit_offset, current)?;
+    }
+    if !task_ids.is_empty() {
+        let ids: BTreeSet<String> = task_ids.iter().map(|id| id.to_lowercase()).collect();
+        retain_tasks(&mut feed, &ids);
+    }
+    Ok(feed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::append_audit_event;
+    use tempfile::TempDir;
+
+    fn write_task(tasks_dir: &Path, id: &str, status: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\n---\n\nBody\n"
+        );
+        fs::write(tasks_dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn cursor_reports_events_and_task_diffs_once() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write_task(&tasks_dir, "task-001", "To Do");
+        write_task(&tasks_dir, "task-002", "To Do");
+
+        let baseline = changes_since(&backlog_dir, None, &[]).expect("baseline");
+        assert!(baseline.baseline && baseline.is_empty());
+
+        let idle = changes_since(&backlog_dir, Some(&baseline.next_cursor), &[]).expect("idle");
+        assert!(idle.is_empty());
+        assert_eq!(idle.next_cursor, baseline.next_cursor);
+
+        write_task(&tasks_dir, "task-001", "Done");
+        write_task(&tasks_dir, "task-003", "To Do");
+        append_audit_event(
+            &backlog_dir,
+            &AuditEvent {
+                timestamp: now_timestamp(),
+                actor: None,
+                action: "set_status".to_string(),
+                task_id: Some("task-001".to_string()),
+                details: serde_json::json!({"status": "Done"}),
+            },
+        )
+        .expect("audit");
+
+        let feed = changes_since(&backlog_dir, Some(&baseline.next_cursor), &[]).expect("feed");
+        assert_eq!(feed.events.len(), 1);
+        assert_eq!(feed.diff.added[0].id, "task-003");
+        assert_eq!(feed.diff.changed[0].id, "task-001");
+        assert_ne!(feed.next_cursor, baseline.next_cursor);
+
+        let scoped = changes_since(
+            &backlog_dir,
+            Some(&baseline.next_cursor),
+            &["task-003".to_string()],
+        )
+        .expect("scoped");
+        assert!(scoped.events.is_empty() && scoped.diff.changed.is_empty());
+        assert_eq!(scoped.diff.added.len(), 1);
+
+        let after = changes_since(&backlog_dir, Some(&feed.next_cursor), &[]).expect("after");
+        assert!(after.is_empty());
+
+        let err = changes_since(&backlog_dir, Some("wmc-../../etc"), &[]).unwrap_err();
+        assert!(matches!(err, ChangesError::UnknownCursor(_)));
+    }
+}
@@ -0,0 +1,282 @@
+//! Automatic checkpoints after mutations, shared by the CLI and the MCP server.
+//!
+//! Without `[checkpoint.triggers]`, `--auto-checkpoint` (or `WORKMESH_AUTO_CHECKPOINT`) writes a
+//! checkpoint after every mutation. With triggers configured, [`after_mutation`] reads the audit
+//! events appended since it last ran and checkpoints only when a rule fires: every N mutations,
+//! every M minutes, when a task moves to Done, or when a task is claimed.
+
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, FixedOffset, Local};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::audit::{audit_log_len, audit_log_path, read_audit_events_from, AuditEvent};
+use crate::project::repo_root_from_backlog;
+use crate::session::{
+    resolve_checkpoint_config, write_checkpoint, CheckpointOptions, CheckpointResult, SessionError,
+};
+use crate::storage::{read_modify_write_json, StorageError};
+use crate::task::load_tasks;
+use crate::task_ops::now_timestamp;
+use crate::timestamps::parse_stored_timestamp;
+
+/// Audit entries shown in an automatic checkpoint.
+const AUTO_CHECKPOINT_AUDIT_LIMIT: usize = 10;
+
+/// `[checkpoint.triggers]`: rules for automatic checkpoints. Any rule that fires writes one.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointTriggers {
+    /// Once this many mutations (audit events) have accumulated since the last checkpoint.
+    pub every_mutations: Option<u32>,
+    /// On the first mutation at least this many minutes after the last checkpoint.
+    pub every_minutes: Option<u32>,
+    /// When a task moves to Done (`set-status` or `bulk set-status`).
+    #[serde(default)]
+    pub on_done: bool,
+    /// When a task is claimed.
+    #[serde(default)]
+    pub on_claim: bool,
+}
+
+impl CheckpointTriggers {
+    pub fn is_empty(&self) -> bool {
+        self.every_mutations.unwrap_or(0) == 0
+            && self.every_minutes.unwrap_or(0) == 0
+            && !self.on_done
+            && !self.on_claim
+    }
+}
+
+/// Progress toward the next triggered checkpoint, at `<state_root>/.checkpoint-triggers.json`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TriggerState {
+    /// Audit log bytes already counted.
+    pub audit_offset: u64,
+    /// Mutations since the last triggered checkpoint.
+    pub mutations: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_checkpoint_at: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum CheckpointTriggerError {
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+    #[error(transparent)]
+    Session(#[from] SessionError),
+}
+
+pub fn trigger_state_path(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join(".checkpoint-triggers.json")
+}
+
+/// The rule that fires for `events`, given `state` already counts them; `None` when none does.
+pub fn fired_trigger(
+    triggers: &CheckpointTriggers,
+    state: &TriggerState,
+    events: &[AuditEvent],
+    now: DateTime<FixedOffset>,
+) -> Option<&'static str> {
+    if triggers.on_done && events.iter().any(is_done_transition) {
+        return Some("on_done");
+    }
+    if triggers.on_claim && events.iter().any(|event| event.action == "claim") {
+        return Some("on_claim");
+    }
+    if let Some(every) = triggers.every_mutations.filter(|every| *every > 0) {
+        if state.mutations >= every {
+            return Some("every_mutations");
+        }
+    }
+    if let Some(minutes) = triggers.every_minutes.filter(|minutes| *minutes > 0) {
+        if events.is_empty() {
+            return None;
+        }
+        let due = match state
+            .last_checkpoint_at
+            .as_deref()
+            .and_then(parse_stored_timestamp)
+        {
+            Some(last) => (now - last).num_minutes() >= i64::from(minutes),
+            None => true,
+        };
+        if due {
+            return Some("every_minutes");
+        }
+    }
+    None
+}
+
+/// Run after a mutation has been written and audited. Returns the checkpoint, if one was written.
+pub fn after_mutation(
+    backlog_dir: &Path,
+    auto_checkpoint: bool,
+) -> Result<Option<CheckpointResult>, CheckpointTriggerError> {
+    let triggers = resolve_checkpoint_config(&repo_root_from_backlog(backlog_dir))
+        .triggers
+        .filter(|triggers| !triggers.is_empty());
+    let Some(triggers) = triggers else {
+        if !auto_checkpoint {
+            return Ok(None);
+        }
+        return Ok(Some(auto_checkpoint_now(backlog_dir)?));
+    };
+
+    let len = audit_log_len(backlog_dir);
+    let now = Local::now().fixed_offset();
+    let mut fired = None;
+    read_modify_write_json(
+        &trigger_state_path(backlog_dir),
+        |current: Option<TriggerState>| {
+            // Start counting at the mutation that just ran; a shrunken log was rewritten, so
+            // the old offset no longer points at an event boundary.
+            let mut state = match current {
+                Some(state) if state.audit_offset <= len => state,
+                previous => TriggerState {
+                    audit_offset: last_event_offset(backlog_dir),
+                    last_checkpoint_at: previous.and_then(|state| state.last_checkpoint_at),
+                    ..TriggerState::default()
+                },
+            };
+            let events = read_audit_events_from(backlog_dir, state.audit_offset);
+            state.audit_offset = len;
+            state.mutations = state
+                .mutations
+                .saturating_add(u32::try_from(events.len()).unwrap_or(u32::MAX));
+            fired = fired_trigger(&triggers, &state, &events, now);
+            if fired.is_some() {
+                state.mutations = 0;
+                state.last_checkpoint_at = Some(now_timestamp());
+            }
+            Ok(state)
+        },
+    )?;
+    match fired {
+        Some(_) => Ok(Some(auto_checkpoint_now(backlog_dir)?)),
+        None => Ok(None),
+    }
+}
+
+fn auto_checkpoint_now(backlog_dir: &Path) -> Result<CheckpointResult, SessionError> {
+    let tasks = load_tasks(backlog_dir);
+    write_checkpoint(
+        backlog_dir,
+        &tasks,
+        &CheckpointOptions::new(AUTO_CHECKPOINT_AUDIT_LIMIT),
+    )
+}
+
+fn is_done_transition(event: &AuditEvent) -> bool {
+    matches!(event.action.as_str(), "set_status" | "bulk_set_status")
+        && event
+            .details
+            .get("status")
+            .and_then(|status| status.as_str())
+            .is_some_and(|status| status.trim().eq_ignore_ascii_case("done"))
+}
+
+/// Byte offset where the last audit line starts (0 for an empty or missing log).
+fn last_event_offset(backlog_dir: &Path) -> u64 {
+    let Ok(bytes) = std::fs::read(audit_log_path(backlog_dir)) else {
+        return 0;
+    };
+    let body = bytes.strip_suffix(b"\n").unwrap_or(&bytes);
+    body.iter()
+        .rposition(|byte| *byte == b'\n')
+        .map_or(0, |index| index as u64 + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use crate::audit::append_audit_event;
+
+    fn event(action: &str, details: serde_json::Value) -> AuditEvent {
+        AuditEvent {
+            timestamp: now_timestamp(),
+            actor: Some("test".to_string()),
+            action: action.to_string(),
+            task_id: Some("task-001".to_string()),
+            details,
+        }
+    }
+
+    #[test]
+    fn rules_fire_on_done_claim_count_and_interval() {
+        let now = Local::now().fixed_offset();
+        let set_done = [event("set_status", serde_json::json!({ "status": "Done" }))];
+        let edit = [event("label_add", serde_json::json!({ "label": "x" }))];
+        let on_done = CheckpointTriggers {
+            on_done: true,
+            ..CheckpointTriggers::default()
+        };
+        let state = TriggerState::default();
+        assert_eq!(
+            fired_trigger(&on_done, &state, &set_done, now),
+            Some("on_done")
+        );
+        assert_eq!(fired_trigger(&on_done, &state, &edit, now), None);
+
+        let every_three = CheckpointTriggers {
+            every_mutations: Some(3),
+            ..CheckpointTriggers::default()
+        };
+        let counted = |mutations| TriggerState {
+            mutations,
+            ..TriggerState::default()
+        };
+        assert_eq!(fired_trigger(&every_three, &counted(2), &edit, now), None);
+        assert_eq!(
+            fired_trigger(&every_three, &counted(3), &edit, now),
+            Some("every_mutations")
+        );
+
+        let hourly = CheckpointTriggers {
+            every_minutes: Some(60),
+            ..CheckpointTriggers::default()
+        };
+        let recent = TriggerState {
+            last_checkpoint_at: Some(now_timestamp()),
+            ..TriggerState::default()
+        };
+        assert_eq!(fired_trigger(&hourly, &recent, &edit, now), None);
+        let later = now + chrono::Duration::minutes(61);
+        assert_eq!(
+            fired_trigger(&hourly, &recent, &edit, later),
+            Some("every_minutes")
+        );
+    }
+
+    #[test]
+    fn after_mutation_counts_audit_events_between_checkpoints() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        fs::create_dir_all(backlog_dir.join("tasks")).expect("tasks dir");
+        fs::write(
+            temp.path().join(".workmesh.toml"),
+            "[checkpoint.triggers]\nevery_mutations = 2\non_claim = true\n",
+        )
+        .expect("config");
+        let mutate = |action: &str| {
+            append_audit_event(&backlog_dir, &event(action, serde_json::json!({}))).expect("audit");
+            after_mutation(&backlog_dir, false).expect("dispatch")
+        };
+
+        assert!(mutate("label_add").is_none());
+        assert!(mutate("label_add").is_some());
+        assert!(mutate("note").is_none());
+        assert!(mutate("claim").is_some());
+        let state: TriggerState = serde_json::from_str(
+            &fs::read_to_string(trigger_state_path(&backlog_dir)).expect("state"),
+        )
+        .expect("json");
+        assert_eq!(state.mutations, 0);
+        assert_eq!(state.audit_offset, audit_log_len(&backlog_dir));
+    }
+}
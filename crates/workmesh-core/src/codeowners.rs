@@ -0,0 +1,445 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::health::{first_extra, is_closed};
+use crate::project::repo_root_from_backlog;
+use crate::task::{Task, TaskParseError};
+use crate::task_ops::{update_task_field, FieldValue};
+use crate::todos::TODO_SOURCE_FIELD;
+
+/// Where GitHub/GitLab look for the ownership file, in lookup order.
+const CODEOWNERS_LOCATIONS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+/// Owner handle prefixes dropped when deriving a label (`@org/team-payments` -> `payments`).
+const TEAM_PREFIXES: &[&str] = &["team-", "team_"];
+
+#[derive(Debug, Clone)]
+pub struct CodeownersRule {
+    pub pattern: String,
+    pub owners: Vec<String>,
+    regex: Regex,
+}
+
+impl CodeownersRule {
+    pub fn matches(&self, path: &str) -> bool {
+        self.regex.is_match(path.trim_start_matches('/'))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OwnershipSuggestion {
+    pub task_id: String,
+    pub title: String,
+    /// Repo-relative files the task references (body, `todo_source`, linked commits).
+    pub files: Vec<String>,
+    pub owners: Vec<String>,
+    /// Labels to add (derived from owners, minus labels the task already has).
+    pub add_labels: Vec<String>,
+    /// Assignee to set when the task has none.
+    pub assignee: Option<String>,
+}
+
+impl OwnershipSuggestion {
+    pub fn has_changes(&self) -> bool {
+        !self.add_labels.is_empty() || self.assignee.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct OwnershipReport {
+    pub codeowners: Option<PathBuf>,
+    pub suggestions: Vec<OwnershipSuggestion>,
+    /// Open tasks that reference files no CODEOWNERS rule covers.
+    pub unowned: Vec<String>,
+}
+
+pub fn find_codeowners(repo_root: &Path) -> Option<PathBuf> {
+    CODEOWNERS_LOCATIONS
+        .iter()
+        .map(|location| repo_root.join(location))
+        .find(|path| path.is_file())
+}
+
+pub fn parse_codeowners(text: &str) -> Vec<CodeownersRule> {
+    text.lines()
+        .filter_map(|line| {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let mut parts = line.split_whitespace();
+            let pattern = parts.next()?.to_string();
+            let owners: Vec<String> = parts.map(str::to_string).collect();
+            let regex = Regex::new(&pattern_to_regex(&pattern)).ok()?;
+            Some(CodeownersRule {
+                pattern,
+                owners,
+                regex,
+            })
+        })
+        .collect()
+}
+
+/// Owners for `path`; the last matching rule wins, as in GitHub. A rule with no owners unsets
+/// ownership for the paths it matches.
+pub fn owners_for<'a>(rules: &'a [CodeownersRule], path: &str) -> &'a [String] {
+    rules
+        .iter()
+        .rev()
+        .find(|rule| rule.matches(path))
+        .map(|rule| rule.owners.as_slice())
+        .unwrap_or(&[])
+}
+
+/// `@acme/team-payments` -> `payments`, `@alice` -> `alice`, `dev@example.com` -> `dev`.
+pub fn owner_label(owner: &str) -> String {
+    let handle = owner_handle(owner);
+    let name = handle.split('@').next().unwrap_or(&handle);
+    TEAM_PREFIXES
+        .iter()
+        .find_map(|prefix| name.strip_prefix(prefix))
+        .unwrap_or(name)
+        .to_lowercase()
+}
+
+/// `@acme/team-payments` -> `team-payments`, `@alice` -> `alice`.
+pub fn owner_handle(owner: &str) -> String {
+    let owner = owner.trim().trim_start_matches('@');
+    owner.rsplit('/').next().unwrap_or(owner).to_string()
+}
+
+/// Infer labels and a default assignee for open tasks from the files they touch.
+///
+/// Backlog files are ignored, since commits that mention a task usually edit the task too.
+pub fn ownership_report(backlog_dir: &Path, tasks: &[Task]) -> OwnershipReport {
+    let repo_root = repo_root_from_backlog(backlog_dir);
+    let backlog_prefix = backlog_dir
+        .strip_prefix(&repo_root)
+        .ok()
+        .map(|relative| format!("{}/", relative.to_string_lossy().replace('\\', "/")))
+        .filter(|prefix| prefix != "/");
+    let Some(codeowners) = find_codeowners(&repo_root) else {
+        return OwnershipReport::default();
+    };
+    let rules = parse_codeowners(&fs::read_to_string(&codeowners).unwrap_or_default());
+    let mut report = OwnershipReport {
+        codeowners: Some(codeowners),
+        ..OwnershipReport::default()
+    };
+
+    for task in tasks.iter().filter(|task| !is_closed(task)) {
+        let mut files = task_file_refs(&repo_root, task);
+        if let Some(prefix) = backlog_prefix.as_deref() {
+            files.retain(|file| !file.starts_with(prefix));
+        }
+        if files.is_empty() {
+            continue;
+        }
+        let mut owners: Vec<String> = Vec::new();
+        for file in &files {
+            for owner in owners_for(&rules, file) {
+                if !owners.contains(owner) {
+                    owners.push(owner.clone());
+                }
+            }
+        }
+        if owners.is_empty() {
+            report.unowned.push(task.id.clone());
+            continue;
+        }
+        let existing: BTreeSet<String> = task.labels.iter().map(|l| l.to_lowercase()).collect();
+        let mut add_labels: Vec<String> = Vec::new();
+        for label in owners.iter().map(|owner| owner_label(owner)) {
+            if !existing.contains(&label) && !add_labels.contains(&label) {
+                add_labels.push(label);
+            }
+        }
+        let assignee = if task.assignee.is_empty() {
+            owners.first().map(|owner| owner_handle(owner))
+        } else {
+            None
+        };
+        report.suggestions.push(OwnershipSuggestion {
+            task_id: task.id.clone(),
+            title: task.title.clone(),
+            files: files.into_iter().collect(),
+            owners,
+            add_labels,
+            assignee,
+        });
+    }
+    report
+}
+
+/// Write the suggested labels and assignees; returns the number of tasks updated.
+pub fn apply_ownership(tasks: &[Task], report: &OwnershipReport) -> Result<usize, TaskParseError> {
+    let mut updated = 0;
+    for suggestion in report.suggestions.iter().filter(|s| s.has_changes()) {
+        let Some(task) = tasks.iter().find(|task| task.id == suggestion.task_id) else {
+            continue;
+        };
+        let Some(path) = task.file_path.as_ref() else {
+            continue;
+        };
+        if !suggestion.add_labels.is_empty() {
+            let mut labels = task.labels.clone();
+            labels.extend(suggestion.add_labels.iter().cloned());
+            update_task_field(path, "labels", Some(FieldValue::List(labels)))?;
+        }
+        if let Some(assignee) = suggestion.assignee.as_ref() {
+            update_task_field(
+                path,
+                "assignee",
+                Some(FieldValue::List(vec![assignee.clone()])),
+            )?;
+        }
+        updated += 1;
+    }
+    Ok(updated)
+}
+
+/// Repo-relative files a task points at: paths in its body that exist, its `todo_source`, and
+/// files changed by commits whose message mentions the task id.
+pub fn task_file_refs(repo_root: &Path, task: &Task) -> BTreeSet<String> {
+    let mut files = task_local_file_refs(repo_root, task);
+    files.extend(commit_files(repo_root, &task.id));
+    files
+}
+
+/// [`task_file_refs`] without the commit lookup.
+pub(crate) fn task_local_file_refs(repo_root: &Path, task: &Task) -> BTreeSet<String> {
+    let mut files = BTreeSet::new();
+    if let Some(source) = first_extra(task, &[TODO_SOURCE_FIELD]) {
+        let file = source
+            .rsplit_once(':')
+            .map_or(source.as_str(), |(file, _)| file);
+        files.insert(file.to_string());
+    }
+    let path_re = Regex::new(r"[A-Za-z0-9_.\-]+(?:/[A-Za-z0-9_.\-]+)+").expect("regex");
+    for candidate in path_re.find_iter(&task.body) {
+        let candidate = candidate.as_str().trim_end_matches('.');
+        if repo_root.join(candidate).exists() {
+            files.insert(candidate.to_string());
+        }
+    }
+    files
+}
+
+fn commit_files(repo_root: &Path, task_id: &str) -> Vec<String> {
+    let grep = format!("--grep={}", task_id);
+    let Ok(output) = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args([
+            "log",
+            "--all",
+            "-i",
+            "-F",
+            grep.as_str(),
+            "--name-only",
+            "--format=%x1e%B%x1f",
+        ])
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    // `--grep` is a substring match; re-check whole ids so task-1 skips task-12 and task-1-foo.
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.split('\u{1e}')
+        .filter_map(|record| record.split_once('\u{1f}'))
+        .filter(|(message, _)| mentions_task_id(message, task_id))
+        .flat_map(|(_, names)| names.lines().map(str::trim).filter(|name| !name.is_empty()))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Short hashes of commits (any branch) whose message mentions `task_id` as a whole id.
+pub(crate) fn commits_mentioning(repo_root: &Path, task_id: &str) -> Vec<String> {
+    let grep = format!("--grep={}", task_id);
+    let Ok(output) = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args([
+            "log",
+            "--all",
+            "-i",
+            "-F",
+            grep.as_str(),
+            "--format=%x1e%h%x1f%B",
+        ])
+        .output()
+    else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    text.split('\u{1e}')
+        .filter_map(|record| record.split_once('\u{1f}'))
+        .filter(|(_, message)| mentions_task_id(message, task_id))
+        .map(|(hash, _)| hash.trim().to_string())
+        .collect()
+}
+
+/// Files changed by commits mentioning each of `task_ids` (lowercased), from one `git log` pass.
+pub(crate) fn commit_file_index(
+    repo_root: &Path,
+    task_ids: &HashSet<String>,
+) -> HashMap<String, BTreeSet<String>> {
+    let mut index: HashMap<String, BTreeSet<String>> = HashMap::new();
+    let Ok(output) = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["log", "--all", "--name-only", "--format=%x1e%B%x1f"])
+        .output()
+    else {
+        return index;
+    };
+    if !output.status.success() {
+        return index;
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    for (message, names) in text
+        .split('\u{1e}')
+        .filter_map(|record| record.split_once('\u{1f}'))
+    {
+        let names: Vec<&str> = names
+            .lines()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .collect();
+        for word in id_words().find_iter(message) {
+            let id = word.as_str().to_lowercase();
+            if task_ids.contains(&id) {
+                let files = index.entry(id).or_default();
+                files.extend(names.iter().map(|name| name.to_string()));
+            }
+        }
+    }
+    index
+}
+
+/// Id-like words in commit messages. Hyphens and underscores stay inside a word, so `task-1`
+/// never matches within `task-1-foo` or `task-12`.
+fn id_words() -> &'static Regex {
+    static WORDS: OnceLock<Regex> = OnceLock::new();
+    WORDS.get_or_init(|| Regex::new(r"[A-Za-z0-9][A-Za-z0-9_\-]*[A-Za-z0-9]").expect("regex"))
+}
+
+fn mentions_task_id(message: &str, task_id: &str) -> bool {
+    id_words()
+        .find_iter(message)
+        .any(|word| word.as_str().eq_ignore_ascii_case(task_id))
+}
+
+/// Translate a CODEOWNERS (gitignore-style) pattern into an anchored regex over repo paths.
+fn pattern_to_regex(pattern: &str) -> String {
+    let anchored = pattern.starts_with('/') || pattern.trim_end_matches('/').contains('/');
+    let body = pattern.trim_start_matches('/').trim_end_matches('/');
+    let mut out = String::from(if anchored { "^" } else { "^(?:.*/)?" });
+    let chars: Vec<char> = body.chars().collect();
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '*' if chars.get(index + 1) == Some(&'*') => {
+                if chars.get(index + 2) == Some(&'/') {
+                    out.push_str("(?:.*/)?");
+                    index += 3;
+                } else {
+                    out.push_str(".*");
+                    index += 2;
+                }
+                continue;
+            }
+            '*' => out.push_str("[^/]*"),
+            '?' => out.push_str("[^/]"),
+            ch => out.push_str(&regex::escape(&ch.to_string())),
+        }
+        index += 1;
+    }
+    out.push_str("(?:/.*)?$");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::parse_task_file;
+    use tempfile::TempDir;
+
+    #[test]
+    fn codeowners_patterns_follow_gitignore_rules_and_last_match_wins() {
+        let rules = parse_codeowners(
+            "# owners\n\
+*.md @docs\n\
+/src/payments/ @acme/team-payments\n\
+src/**/api.rs @alice\n\
+build/ \n",
+        );
+        assert_eq!(owners_for(&rules, "README.md"), ["@docs"]);
+        assert_eq!(owners_for(&rules, "guide/intro.md"), ["@docs"]);
+        assert_eq!(
+            owners_for(&rules, "src/payments/charge.rs"),
+            ["@acme/team-payments"]
+        );
+        assert_eq!(owners_for(&rules, "src/payments/v2/api.rs"), ["@alice"]);
+        assert!(owners_for(&rules, "lib/payments/charge.rs").is_empty());
+        assert!(owners_for(&rules, "build/out.md").is_empty());
+        assert_eq!(owner_label("@acme/team-payments"), "payments");
+        assert_eq!(owner_handle("@acme/team-payments"), "team-payments");
+        assert_eq!(owner_label("dev@example.com"), "dev");
+    }
+
+    #[test]
+    fn commit_mentions_match_whole_task_ids_only() {
+        assert!(mentions_task_id("Fix retries (TASK-1)", "task-1"));
+        assert!(mentions_task_id("task-1: done", "task-1"));
+        assert!(!mentions_task_id("Refs task-1-foo", "task-1"));
+        assert!(!mentions_task_id("Refs task-12", "task-1"));
+        assert!(!mentions_task_id("Refs subtask-1", "task-1"));
+        assert!(!mentions_task_id("Refs task-1_b", "task-1"));
+    }
+
+    #[test]
+    fn ownership_report_suggests_labels_and_assignee_from_referenced_files() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        fs::create_dir_all(repo.join(".github")).expect("github dir");
+        fs::create_dir_all(repo.join("src/payments")).expect("src dir");
+        fs::write(repo.join("src/payments/charge.rs"), "fn charge() {}\n").expect("source");
+        fs::write(
+            repo.join(".github/CODEOWNERS"),
+            "/src/payments/ @acme/team-payments\n",
+        )
+        .expect("codeowners");
+        let tasks_dir = repo.join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        let task_path = tasks_dir.join("task-001 - charge.md");
+        fs::write(
+            &task_path,
+            "---\nid: task-001\ntitle: Charge retries\nstatus: To Do\nlabels: [backend]\n\
+assignee: []\n---\n\nRetry failures in src/payments/charge.rs.\n",
+        )
+        .expect("task");
+        let tasks = vec![parse_task_file(&task_path).expect("parse")];
+
+        let report = ownership_report(&repo.join("workmesh"), &tasks);
+        assert_eq!(report.suggestions.len(), 1);
+        let suggestion = &report.suggestions[0];
+        assert_eq!(suggestion.files, vec!["src/payments/charge.rs"]);
+        assert_eq!(suggestion.add_labels, vec!["payments"]);
+        assert_eq!(suggestion.assignee.as_deref(), Some("team-payments"));
+
+        assert_eq!(apply_ownership(&tasks, &report).expect("apply"), 1);
+        let task = parse_task_file(&task_path).expect("reparse");
+        assert_eq!(task.labels, vec!["backend", "payments"]);
+        assert_eq!(task.assignee, vec!["team-payments"]);
+    }
+}
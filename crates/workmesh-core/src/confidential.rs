@@ -0,0 +1,300 @@
+use std::path::Path;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+use ring::rand::{SecureRandom, SystemRandom};
+use serde_yaml::Value;
+use thiserror::Error;
+
+use crate::encoding::decode_text;
+use crate::task::{parse_front_matter, split_front_matter, Task, TaskParseError};
+
+/// Environment variable holding the base64-encoded 32-byte key for confidential tasks.
+pub const CONFIDENTIAL_KEY_ENV: &str = "WORKMESH_KEY";
+/// Front matter flag that marks a task body (description, notes, sections) for encryption.
+pub const CONFIDENTIAL_FIELD: &str = "confidential";
+/// Body shown in list/show/export output when the key is unavailable.
+pub const REDACTED_BODY: &str = "[confidential: body encrypted; set WORKMESH_KEY to decrypt]";
+
+const ARMOR_BEGIN: &str = "-----BEGIN WORKMESH CONFIDENTIAL-----";
+const ARMOR_END: &str = "-----END WORKMESH CONFIDENTIAL-----";
+const KEY_LEN: usize = 32;
+
+#[derive(Debug, Error)]
+pub enum ConfidentialError {
+    #[error("Confidential task requires WORKMESH_KEY to be set")]
+    MissingKey,
+    #[error("Invalid WORKMESH_KEY: expected base64 of 32 bytes")]
+    InvalidKey,
+    #[error("Failed to decrypt confidential body (wrong key or corrupted payload)")]
+    Decrypt,
+    #[error("Failed to encrypt confidential body")]
+    Encrypt,
+    #[error("Confidential body is encrypted; set WORKMESH_KEY to edit it")]
+    SealedBody,
+}
+
+impl From<ConfidentialError> for TaskParseError {
+    fn from(err: ConfidentialError) -> Self {
+        TaskParseError::Invalid(err.to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct ConfidentialKey([u8; KEY_LEN]);
+
+impl std::fmt::Debug for ConfidentialKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ConfidentialKey(..)")
+    }
+}
+
+impl ConfidentialKey {
+    pub fn from_base64(value: &str) -> Result<Self, ConfidentialError> {
+        let bytes = STANDARD
+            .decode(value.trim())
+            .map_err(|_| ConfidentialError::InvalidKey)?;
+        let bytes: [u8; KEY_LEN] = bytes
+            .try_into()
+            .map_err(|_| ConfidentialError::InvalidKey)?;
+        Ok(Self(bytes))
+    }
+
+    /// Read the key from `WORKMESH_KEY`; `Ok(None)` when unset or empty.
+    pub fn from_env() -> Result<Option<Self>, ConfidentialError> {
+        match std::env::var(CONFIDENTIAL_KEY_ENV) {
+            Ok(value) if !value.trim().is_empty() => Self::from_base64(&value).map(Some),
+            _ => Ok(None),
+        }
+    }
+
+    fn aead(&self) -> Result<LessSafeKey, ConfidentialError> {
+        let unbound = UnboundKey::new(&CHACHA20_POLY1305, &self.0)
+            .map_err(|_| ConfidentialError::InvalidKey)?;
+        Ok(LessSafeKey::new(unbound))
+    }
+}
+
+/// Generate a fresh random key, base64-encoded for `WORKMESH_KEY`.
+pub fn generate_key() -> Result<String, ConfidentialError> {
+    let mut bytes = [0u8; KEY_LEN];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| ConfidentialError::Encrypt)?;
+    Ok(STANDARD.encode(bytes))
+}
+
+pub fn is_sealed_body(body: &str) -> bool {
+    body.trim_start().starts_with(ARMOR_BEGIN)
+}
+
+pub fn is_confidential_front(front: &str) -> bool {
+    is_confidential_flag(parse_front_matter(front).get(CONFIDENTIAL_FIELD))
+}
+
+/// Whether a parsed task is marked `confidential: true` (its body may be plaintext after load).
+pub fn is_confidential_task(task: &Task) -> bool {
+    is_confidential_flag(task.extra.get(CONFIDENTIAL_FIELD))
+}
+
+fn is_confidential_flag(value: Option<&Value>) -> bool {
+    match value {
+        Some(Value::Bool(value)) => *value,
+        Some(Value::String(value)) => {
+            matches!(value.trim().to_lowercase().as_str(), "true" | "yes" | "1")
+        }
+        _ => false,
+    }
+}
+
+pub fn seal_body(body: &str, key: &ConfidentialKey) -> Result<String, ConfidentialError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| ConfidentialError::Encrypt)?;
+    let mut in_out = body.as_bytes().to_vec();
+    key.aead()?
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut in_out,
+        )
+        .map_err(|_| ConfidentialError::Encrypt)?;
+    let mut payload = nonce.to_vec();
+    payload.extend(in_out);
+    let encoded = STANDARD.encode(payload);
+
+    let mut lines = vec![ARMOR_BEGIN.to_string()];
+    for chunk in encoded.as_bytes().chunks(76) {
+        lines.push(String::from_utf8_lossy(chunk).to_string());
+    }
+    lines.push(ARMOR_END.to_string());
+    Ok(format!("{}\n", lines.join("\n")))
+}
+
+pub fn open_body(body: &str, key: &ConfidentialKey) -> Result<String, ConfidentialError> {
+    let encoded: String = body
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && *line != ARMOR_BEGIN && *line != ARMOR_END)
+        .collect();
+    let payload = STANDARD
+        .decode(encoded)
+        .map_err(|_| ConfidentialError::Decrypt)?;
+    if payload.len() < NONCE_LEN {
+        return Err(ConfidentialError::Decrypt);
+    }
+    let (nonce, ciphertext) = payload.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| ConfidentialError::Decrypt)?;
+    let mut in_out = ciphertext.to_vec();
+    let plain = key
+        .aead()?
+        .open_in_place(nonce, Aad::empty(), &mut in_out)
+        .map_err(|_| ConfidentialError::Decrypt)?;
+    String::from_utf8(plain.to_vec()).map_err(|_| ConfidentialError::Decrypt)
+}
+
+/// Body as seen by readers: plaintext for key holders, a placeholder for everyone else.
+pub fn reveal_body(body: &str, key: Option<&ConfidentialKey>) -> String {
+    if !is_sealed_body(body) {
+        return body.to_string();
+    }
+    key.and_then(|key| open_body(body, key).ok())
+        .unwrap_or_else(|| REDACTED_BODY.to_string())
+}
+
+/// Full task file text as seen by readers (front matter untouched, body revealed or redacted).
+pub fn reveal_task_text(text: &str, key: Option<&ConfidentialKey>) -> String {
+    match split_front_matter(text) {
+        Ok((front, body)) if is_sealed_body(&body) => {
+            format!("---\n{}\n---\n{}", front, reveal_body(&body, key))
+        }
+        _ => text.to_string(),
+    }
+}
+
+/// Read a task file for display, decrypting confidential bodies when `WORKMESH_KEY` is set.
+pub fn read_task_text(path: &Path) -> std::io::Result<String> {
+    let text = decode_text(&std::fs::read(path)?).text;
+    let key = ConfidentialKey::from_env().ok().flatten();
+    Ok(reveal_task_text(&text, key.as_ref()))
+}
+
+/// Apply a text mutation to a task file that may carry a confidential body.
+///
+/// Key holders edit the plaintext and the result is re-sealed. Without the key, front matter
+/// edits still work, but any change to the sealed body is rejected instead of corrupting it.
+pub fn mutate_confidential_text<F>(
+    text: &str,
+    key: Option<&ConfidentialKey>,
+    mutator: F,
+) -> Result<String, TaskParseError>
+where
+    F: FnOnce(&str) -> Result<String, TaskParseError>,
+{
+    let Ok((front, body)) = split_front_matter(text) else {
+        return mutator(text);
+    };
+    if is_sealed_body(&body) {
+        if let Some(plain) = key.and_then(|key| open_body(&body, key).ok()) {
+            let opened = format!("---\n{}\n---\n{}", front, plain);
+            return seal_task_text(&mutator(&opened)?, key);
+        }
+        let updated = mutator(text)?;
+        let (_, updated_body) = split_front_matter(&updated)?;
+        if updated_body.trim() != body.trim() {
+            return Err(ConfidentialError::SealedBody.into());
+        }
+        return Ok(updated);
+    }
+    seal_task_text(&mutator(text)?, key)
+}
+
+/// Encrypt the body of a task marked `confidential: true` (no-op otherwise or when already sealed).
+pub fn seal_task_text(text: &str, key: Option<&ConfidentialKey>) -> Result<String, TaskParseError> {
+    let Ok((front, body)) = split_front_matter(text) else {
+        return Ok(text.to_string());
+    };
+    if !is_confidential_front(&front) || is_sealed_body(&body) {
+        return Ok(text.to_string());
+    }
+    let key = key.ok_or(ConfidentialError::MissingKey)?;
+    Ok(format!("---\n{}\n---\n{}", front, seal_body(&body, key)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_key() -> ConfidentialKey {
+        ConfidentialKey::from_base64(&generate_key().expect("key")).expect("parse key")
+    }
+
+    #[test]
+    fn seal_and_open_round_trip() {
+        let key = test_key();
+        let sealed = seal_body("\nSecret plan\nNotes:\n- a\n", &key).expect("seal");
+        assert!(is_sealed_body(&sealed));
+        assert!(!sealed.contains("Secret"));
+        assert_eq!(
+            open_body(&sealed, &key).expect("open"),
+            "\nSecret plan\nNotes:\n- a\n"
+        );
+        assert!(open_body(&sealed, &test_key()).is_err());
+    }
+
+    #[test]
+    fn reveal_body_redacts_without_key() {
+        let key = test_key();
+        let sealed = seal_body("Secret", &key).expect("seal");
+        assert_eq!(reveal_body(&sealed, None), REDACTED_BODY);
+        assert_eq!(reveal_body(&sealed, Some(&key)), "Secret");
+        assert_eq!(reveal_body("Plain", None), "Plain");
+
+        let text = format!("---\nid: task-001\n---\n{}", sealed);
+        assert_eq!(
+            reveal_task_text(&text, None),
+            format!("---\nid: task-001\n---\n{}", REDACTED_BODY)
+        );
+    }
+
+    #[test]
+    fn seal_task_text_only_touches_confidential_tasks() {
+        let key = test_key();
+        let plain = "---\nid: task-001\n---\nBody\n";
+        assert_eq!(seal_task_text(plain, None).expect("plain"), plain);
+
+        let marked = "---\nid: task-001\nconfidential: true\n---\nBody\n";
+        assert!(seal_task_text(marked, None).is_err());
+        let sealed = seal_task_text(marked, Some(&key)).expect("sealed");
+        assert!(sealed.starts_with("---\nid: task-001\nconfidential: true\n---\n"));
+        assert!(!sealed.contains("Body"));
+    }
+
+    #[test]
+    fn mutate_without_key_allows_front_matter_but_not_body_edits() {
+        let key = test_key();
+        let sealed = seal_task_text(
+            "---\nid: task-001\nstatus: To Do\nconfidential: true\n---\nBody\n",
+            Some(&key),
+        )
+        .expect("sealed");
+
+        let updated = mutate_confidential_text(&sealed, None, |text| {
+            Ok(text.replace("status: To Do", "status: Done"))
+        })
+        .expect("front matter edit");
+        assert!(updated.contains("status: Done"));
+
+        let err = mutate_confidential_text(&sealed, None, |text| Ok(format!("{}extra\n", text)));
+        assert!(err.is_err());
+
+        let edited = mutate_confidential_text(&sealed, Some(&key), |text| {
+            Ok(text.replace("Body", "Body edited"))
+        })
+        .expect("keyed edit");
+        let (_, body) = split_front_matter(&edited).expect("split");
+        assert_eq!(reveal_body(&body, Some(&key)).trim(), "Body edited");
+    }
+}
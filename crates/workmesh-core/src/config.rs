@@ -5,6 +5,23 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::attachments::AttachmentsConfig;
+use crate::autotag::AutotagConfig;
+use crate::budget::BudgetsConfig;
+use crate::import::jira::JiraConfig;
+use crate::onboarding::OnboardingConfig;
+use crate::policies::PoliciesConfig;
+use crate::roles::RolesConfig;
+use crate::self_update::SelfUpdateConfig;
+use crate::session::CheckpointConfig;
+use crate::sync::github::GithubConfig;
+use crate::task_ops::TaskFilesConfig;
+use crate::taskwarrior::TaskwarriorConfig;
+use crate::telemetry::TelemetryConfig;
+use crate::timestamps::TimeDisplay;
+use crate::title_lint::TitleLintConfig;
+use crate::workflow::WorkflowConfig;
+
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("Config IO error: {0}")]
@@ -31,6 +48,14 @@ pub struct WorkmeshConfig {
     pub task_require_definition_of_done: Option<bool>,
     /// Whether Definition of Done must include outcome-based criteria instead of hygiene-only items.
     pub task_require_outcome_based_definition_of_done: Option<bool>,
+    /// Whether tasks need a substantive Implementation Notes section before Done.
+    pub task_require_implementation_notes: Option<bool>,
+    /// Whether every Acceptance Criteria item must be checked (`- [x]`) before Done.
+    pub task_require_acceptance_criteria_checked: Option<bool>,
+    /// Whether tasks need a linked commit (`commits:` or a git commit naming the id) before Done.
+    pub task_require_linked_commit: Option<bool>,
+    /// Whether tasks need a review approval (`approved_by:`) before Done.
+    pub task_require_review_approval: Option<bool>,
     pub do_not_migrate: Option<bool>,
     /// Default behavior for promoting worktree-based parallel workflows.
     /// true = promote worktrees by default, false = suppress default worktree guidance.
@@ -48,6 +73,67 @@ pub struct WorkmeshConfig {
     pub initiatives: Option<Vec<String>>,
     /// Map of git branch name -> initiative slug frozen for that branch
     pub branch_initiatives: Option<HashMap<String, String>>,
+    /// Role-based access for MCP/HTTP surfaces (`[roles]` table).
+    pub roles: Option<RolesConfig>,
+    /// Team name -> member identities, used to expand `@team` mentions.
+    pub teams: Option<HashMap<String, Vec<String>>>,
+    /// Whether notes that name other task ids (`task-042`) link them under
+    /// `relationships.relates_to` (default true).
+    pub note_autolink: Option<bool>,
+    /// Zone timestamps are shown in by text output: `local` (default) or `utc`.
+    pub time_display: Option<String>,
+    /// Priority `add` gives a task when neither `--priority` nor a template sets one (default P2).
+    pub default_priority: Option<String>,
+    /// Automatic task policies (`[policies]` table).
+    pub policies: Option<PoliciesConfig>,
+    /// OpenTelemetry export (`[telemetry]` table).
+    pub telemetry: Option<TelemetryConfig>,
+    /// Command aliases (`[aliases]` table), e.g. `ls = "list --limit 30"`.
+    pub aliases: Option<HashMap<String, String>>,
+    /// Status state machine (`[workflow]` table).
+    pub workflow: Option<WorkflowConfig>,
+    /// Checkpoint sections and Markdown template (`[checkpoint]` table).
+    pub checkpoint: Option<CheckpointConfig>,
+    /// Embedding provider for `semsearch` (`[semsearch]` table; needs the `semsearch` feature).
+    pub semsearch: Option<SemsearchConfig>,
+    /// Field mapping for the Taskwarrior import/export bridge (`[taskwarrior]` table).
+    pub taskwarrior: Option<TaskwarriorConfig>,
+    /// Task title style checks for `lint --titles` (`[title_lint]` table).
+    pub title_lint: Option<TitleLintConfig>,
+    /// Keyword -> label rules for `autotag` and `lint --autotag` (`[autotag]` table).
+    pub autotag: Option<AutotagConfig>,
+    /// Point and hour budgets per initiative and phase for `budget status` (`[budgets]` table).
+    pub budgets: Option<BudgetsConfig>,
+    /// Checklist used by `onboarding new` (`[onboarding]` table).
+    pub onboarding: Option<OnboardingConfig>,
+    /// Old handle -> new handle (`[actor_aliases]` table), applied to CLI audit actors so a
+    /// renamed user's events are recorded under the new handle.
+    pub actor_aliases: Option<HashMap<String, String>>,
+    /// Repository and label mapping for `github import`/`github export` (`[github]` table).
+    pub github: Option<GithubConfig>,
+    /// Status and priority mapping for `import --format jira-csv` (`[jira]` table).
+    pub jira: Option<JiraConfig>,
+    /// Task filename template and rename behavior (`[task_files]` table).
+    pub task_files: Option<TaskFilesConfig>,
+    /// Release source and verification key for `self-update` (`[self_update]` table; honored
+    /// in the global config only).
+    pub self_update: Option<SelfUpdateConfig>,
+    /// Object storage for large task attachments (`[attachments]` table).
+    pub attachments: Option<AttachmentsConfig>,
+}
+
+/// `[semsearch]` table. Lives here rather than in `semsearch` so configs parse the same way
+/// whether or not the feature is compiled in.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SemsearchConfig {
+    /// `hash` (local, default) or `http` (OpenAI-compatible embeddings endpoint).
+    pub provider: Option<String>,
+    /// Full embeddings URL for `http`, e.g. `http://localhost:11434/v1/embeddings`.
+    pub endpoint: Option<String>,
+    pub model: Option<String>,
+    /// Environment variable holding the bearer token for `http`.
+    pub api_key_env: Option<String>,
+    pub timeout_ms: Option<u64>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -56,6 +142,10 @@ pub struct TaskValidationRules {
     pub require_acceptance_criteria: bool,
     pub require_definition_of_done: bool,
     pub require_outcome_based_definition_of_done: bool,
+    pub require_implementation_notes: bool,
+    pub require_acceptance_criteria_checked: bool,
+    pub require_linked_commit: bool,
+    pub require_review_approval: bool,
 }
 
 impl Default for TaskValidationRules {
@@ -65,6 +155,10 @@ impl Default for TaskValidationRules {
             require_acceptance_criteria: true,
             require_definition_of_done: true,
             require_outcome_based_definition_of_done: true,
+            require_implementation_notes: false,
+            require_acceptance_criteria_checked: false,
+            require_linked_commit: false,
+            require_review_approval: false,
         }
     }
 }
@@ -75,6 +169,10 @@ pub struct TaskValidationRuleSources {
     pub require_acceptance_criteria: &'static str,
     pub require_definition_of_done: &'static str,
     pub require_outcome_based_definition_of_done: &'static str,
+    pub require_implementation_notes: &'static str,
+    pub require_acceptance_criteria_checked: &'static str,
+    pub require_linked_commit: &'static str,
+    pub require_review_approval: &'static str,
 }
 
 pub fn config_filename_candidates() -> [&'static str; 2] {
@@ -222,6 +320,81 @@ pub fn resolve_auto_session_default(repo_root: &Path) -> Option<bool> {
     resolve_auto_session_default_with_source(repo_root).0
 }
 
+/// Whether note insertion links referenced task ids; project wins over global, default on.
+pub fn resolve_note_autolink_with_source(repo_root: &Path) -> (bool, &'static str) {
+    resolve_bool_with_source(
+        load_config(repo_root).and_then(|config| config.note_autolink),
+        load_global_config().and_then(|config| config.note_autolink),
+        true,
+    )
+}
+
+pub fn resolve_note_autolink(repo_root: &Path) -> bool {
+    resolve_note_autolink_with_source(repo_root).0
+}
+
+/// `time_display`; project wins over global, unrecognized values are ignored, default local.
+pub fn resolve_time_display_with_source(repo_root: &Path) -> (TimeDisplay, &'static str) {
+    let parse = |config: Option<WorkmeshConfig>| {
+        config
+            .and_then(|config| config.time_display)
+            .and_then(|value| TimeDisplay::parse(&value))
+    };
+    if let Some(value) = parse(load_config(repo_root)) {
+        return (value, "project");
+    }
+    if let Some(value) = parse(load_global_config()) {
+        return (value, "global");
+    }
+    (TimeDisplay::default(), "default")
+}
+
+pub fn resolve_time_display(repo_root: &Path) -> TimeDisplay {
+    resolve_time_display_with_source(repo_root).0
+}
+
+/// `default_priority`; project wins over global, blank values are ignored, default P2.
+pub fn resolve_default_priority(repo_root: &Path) -> String {
+    let pick = |config: Option<WorkmeshConfig>| {
+        config
+            .and_then(|config| config.default_priority)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    pick(load_config(repo_root))
+        .or_else(|| pick(load_global_config()))
+        .unwrap_or_else(|| "P2".to_string())
+}
+
+/// Project `[teams]` wins over global `[teams]`.
+pub fn resolve_teams(repo_root: &Path) -> HashMap<String, Vec<String>> {
+    load_config(repo_root)
+        .and_then(|config| config.teams)
+        .or_else(|| load_global_config().and_then(|config| config.teams))
+        .unwrap_or_default()
+}
+
+/// Project `[actor_aliases]` wins over global `[actor_aliases]`.
+pub fn resolve_actor_aliases(repo_root: &Path) -> HashMap<String, String> {
+    load_config(repo_root)
+        .and_then(|config| config.actor_aliases)
+        .or_else(|| load_global_config().and_then(|config| config.actor_aliases))
+        .unwrap_or_default()
+}
+
+/// Record `from -> to` in the project `[actor_aliases]`, repointing aliases that led to `from`.
+pub fn update_actor_alias(repo_root: &Path, from: &str, to: &str) -> Result<PathBuf, ConfigError> {
+    let mut config = load_config(repo_root).unwrap_or_default();
+    let aliases = config.actor_aliases.get_or_insert_with(HashMap::new);
+    for target in aliases.values_mut() {
+        if target == from {
+            *target = to.to_string();
+        }
+    }
+    aliases.insert(from.to_string(), to.to_string());
+    write_config(repo_root, &config)
+}
+
 fn resolve_bool_with_source(
     project_value: Option<bool>,
     global_value: Option<bool>,
@@ -278,6 +451,44 @@ pub fn resolve_task_validation_rules_with_source(
                 .and_then(|cfg| cfg.task_require_outcome_based_definition_of_done),
             true,
         );
+    let (require_implementation_notes, require_implementation_notes_source) =
+        resolve_bool_with_source(
+            project
+                .as_ref()
+                .and_then(|cfg| cfg.task_require_implementation_notes),
+            global
+                .as_ref()
+                .and_then(|cfg| cfg.task_require_implementation_notes),
+            false,
+        );
+    let (require_acceptance_criteria_checked, require_acceptance_criteria_checked_source) =
+        resolve_bool_with_source(
+            project
+                .as_ref()
+                .and_then(|cfg| cfg.task_require_acceptance_criteria_checked),
+            global
+                .as_ref()
+                .and_then(|cfg| cfg.task_require_acceptance_criteria_checked),
+            false,
+        );
+    let (require_linked_commit, require_linked_commit_source) = resolve_bool_with_source(
+        project
+            .as_ref()
+            .and_then(|cfg| cfg.task_require_linked_commit),
+        global
+            .as_ref()
+            .and_then(|cfg| cfg.task_require_linked_commit),
+        false,
+    );
+    let (require_review_approval, require_review_approval_source) = resolve_bool_with_source(
+        project
+            .as_ref()
+            .and_then(|cfg| cfg.task_require_review_approval),
+        global
+            .as_ref()
+            .and_then(|cfg| cfg.task_require_review_approval),
+        false,
+    );
 
     (
         TaskValidationRules {
@@ -285,6 +496,10 @@ pub fn resolve_task_validation_rules_with_source(
             require_acceptance_criteria,
             require_definition_of_done,
             require_outcome_based_definition_of_done,
+            require_implementation_notes,
+            require_acceptance_criteria_checked,
+            require_linked_commit,
+            require_review_approval,
         },
         TaskValidationRuleSources {
             require_description: require_description_source,
@@ -292,6 +507,10 @@ pub fn resolve_task_validation_rules_with_source(
             require_definition_of_done: require_definition_of_done_source,
             require_outcome_based_definition_of_done:
                 require_outcome_based_definition_of_done_source,
+            require_implementation_notes: require_implementation_notes_source,
+            require_acceptance_criteria_checked: require_acceptance_criteria_checked_source,
+            require_linked_commit: require_linked_commit_source,
+            require_review_approval: require_review_approval_source,
         },
     )
 }
@@ -346,6 +565,10 @@ pub fn update_do_not_migrate(
         || config
             .task_require_outcome_based_definition_of_done
             .is_some()
+        || config.task_require_implementation_notes.is_some()
+        || config.task_require_acceptance_criteria_checked.is_some()
+        || config.task_require_linked_commit.is_some()
+        || config.task_require_review_approval.is_some()
         || config.worktrees_default.is_some()
         || config
             .worktrees_dir
@@ -362,7 +585,41 @@ pub fn update_do_not_migrate(
             .branch_initiatives
             .as_ref()
             .map(|values| !values.is_empty())
-            .unwrap_or(false);
+            .unwrap_or(false)
+        || config.roles.is_some()
+        || config
+            .teams
+            .as_ref()
+            .map(|values| !values.is_empty())
+            .unwrap_or(false)
+        || config.note_autolink.is_some()
+        || config.time_display.is_some()
+        || config.default_priority.is_some()
+        || config.policies.is_some()
+        || config.telemetry.is_some()
+        || config
+            .aliases
+            .as_ref()
+            .map(|values| !values.is_empty())
+            .unwrap_or(false)
+        || config.workflow.is_some()
+        || config.checkpoint.is_some()
+        || config.semsearch.is_some()
+        || config.taskwarrior.is_some()
+        || config.title_lint.is_some()
+        || config.autotag.is_some()
+        || config.budgets.is_some()
+        || config.onboarding.is_some()
+        || config
+            .actor_aliases
+            .as_ref()
+            .map(|values| !values.is_empty())
+            .unwrap_or(false)
+        || config.github.is_some()
+        || config.jira.is_some()
+        || config.task_files.is_some()
+        || config.self_update.is_some()
+        || config.attachments.is_some();
     if !value && !has_other_fields {
         let path = config_path(repo_root);
         if path.exists() {
@@ -433,12 +690,38 @@ mod tests {
             task_require_acceptance_criteria: Some(true),
             task_require_definition_of_done: Some(true),
             task_require_outcome_based_definition_of_done: Some(true),
+            task_require_implementation_notes: None,
+            task_require_acceptance_criteria_checked: None,
+            task_require_linked_commit: None,
+            task_require_review_approval: None,
             do_not_migrate: Some(true),
             worktrees_default: Some(true),
             worktrees_dir: None,
             auto_session_default: Some(true),
             initiatives: None,
             branch_initiatives: None,
+            roles: None,
+            teams: None,
+            note_autolink: None,
+            time_display: None,
+            default_priority: None,
+            policies: None,
+            telemetry: None,
+            aliases: None,
+            workflow: None,
+            checkpoint: None,
+            semsearch: None,
+            taskwarrior: None,
+            title_lint: None,
+            autotag: None,
+            budgets: None,
+            onboarding: None,
+            actor_aliases: None,
+            github: None,
+            jira: None,
+            task_files: None,
+            self_update: None,
+            attachments: None,
         };
         write_config(temp.path(), &config).expect("write config");
         let loaded = load_config(temp.path()).expect("load config");
@@ -468,12 +751,38 @@ mod tests {
             task_require_acceptance_criteria: None,
             task_require_definition_of_done: None,
             task_require_outcome_based_definition_of_done: None,
+            task_require_implementation_notes: None,
+            task_require_acceptance_criteria_checked: None,
+            task_require_linked_commit: None,
+            task_require_review_approval: None,
             do_not_migrate: Some(true),
             worktrees_default: None,
             worktrees_dir: None,
             auto_session_default: None,
             initiatives: None,
             branch_initiatives: None,
+            roles: None,
+            teams: None,
+            note_autolink: None,
+            time_display: None,
+            default_priority: None,
+            policies: None,
+            telemetry: None,
+            aliases: None,
+            workflow: None,
+            checkpoint: None,
+            semsearch: None,
+            taskwarrior: None,
+            title_lint: None,
+            autotag: None,
+            budgets: None,
+            onboarding: None,
+            actor_aliases: None,
+            github: None,
+            jira: None,
+            task_files: None,
+            self_update: None,
+            attachments: None,
         };
         let path = write_config(temp.path(), &config).expect("write config");
         assert!(path.exists());
@@ -492,12 +801,38 @@ mod tests {
             task_require_acceptance_criteria: None,
             task_require_definition_of_done: None,
             task_require_outcome_based_definition_of_done: None,
+            task_require_implementation_notes: None,
+            task_require_acceptance_criteria_checked: None,
+            task_require_linked_commit: None,
+            task_require_review_approval: None,
             do_not_migrate: Some(true),
             worktrees_default: Some(false),
             worktrees_dir: None,
             auto_session_default: None,
             initiatives: None,
             branch_initiatives: None,
+            roles: None,
+            teams: None,
+            note_autolink: None,
+            time_display: None,
+            default_priority: None,
+            policies: None,
+            telemetry: None,
+            aliases: None,
+            workflow: None,
+            checkpoint: None,
+            semsearch: None,
+            taskwarrior: None,
+            title_lint: None,
+            autotag: None,
+            budgets: None,
+            onboarding: None,
+            actor_aliases: None,
+            github: None,
+            jira: None,
+            task_files: None,
+            self_update: None,
+            attachments: None,
         };
         let path = write_config(temp.path(), &config).expect("write config");
         assert!(path.exists());
@@ -0,0 +1,365 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::config::TaskValidationRules;
+use crate::context::{ContextScopeMode, ContextState};
+use crate::extract::epic_subtree;
+use crate::health::is_closed;
+use crate::task::Task;
+use crate::task_ops::{extract_section_content, recommend_next_tasks_with_context_and_rules};
+use crate::truth::{list_truths, TruthQuery, TruthState};
+
+/// Rough characters-per-token ratio; good enough for budgeting without a tokenizer.
+const CHARS_PER_TOKEN: usize = 4;
+/// Ready tasks considered before the budget is applied.
+const READY_TASK_LIMIT: usize = 10;
+/// Description excerpt kept per ready task, in characters.
+const BODY_EXCERPT_CHARS: usize = 400;
+/// Accepted truths considered for the decisions section.
+const DECISION_LIMIT: usize = 5;
+
+#[derive(Debug, Clone)]
+pub struct ContextPackOptions {
+    /// Approximate token budget for the whole pack.
+    pub budget: usize,
+    /// Epic to scope to; falls back to the context epic scope.
+    pub epic: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextPackSection {
+    /// `context`, `ready`, `blockers`, or `decisions` (also the priority order).
+    pub name: String,
+    pub title: String,
+    pub items: Vec<String>,
+    /// Items dropped because the budget ran out.
+    pub omitted: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ContextPack {
+    pub budget: usize,
+    pub estimated_tokens: usize,
+    pub epic: Option<String>,
+    pub truncated: bool,
+    pub sections: Vec<ContextPackSection>,
+}
+
+/// Parse `4000`, `4000-tokens`, `4000 tokens`, or `4k`.
+pub fn parse_token_budget(value: &str) -> Option<usize> {
+    let lower = value.trim().to_lowercase();
+    let number = lower
+        .strip_suffix("tokens")
+        .map(|rest| rest.trim_end_matches(['-', ' ']))
+        .unwrap_or(&lower);
+    let budget = match number.strip_suffix('k') {
+        Some(thousands) => thousands.trim().parse::<usize>().ok()? * 1000,
+        None => number.trim().parse::<usize>().ok()?,
+    };
+    (budget > 0).then_some(budget)
+}
+
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Assemble context, ready tasks, blockers, and recent decisions, in that priority order, until
+/// the budget is spent. A ready task that does not fit whole is kept as its summary line.
+pub fn build_context_pack(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    context: Option<&ContextState>,
+    rules: &TaskValidationRules,
+    options: &ContextPackOptions,
+) -> ContextPack {
+    let epic_id = options.epic.clone().or_else(|| {
+        context
+            .filter(|state| state.scope.mode == ContextScopeMode::Epic)
+            .and_then(|state| state.scope.epic_id.clone())
+    });
+    let epic = epic_id
+        .as_deref()
+        .and_then(|id| tasks.iter().find(|task| task.id.eq_ignore_ascii_case(id)));
+    let scope: Option<HashSet<String>> = epic.map(|epic| {
+        epic_subtree(tasks, epic)
+            .into_iter()
+            .map(|task| task.id.to_lowercase())
+            .collect()
+    });
+    let in_scope = |task: &Task| {
+        scope
+            .as_ref()
+            .map(|ids| ids.contains(&task.id.to_lowercase()))
+            .unwrap_or(true)
+    };
+
+    let mut context_items = Vec::new();
+    if let Some(project) = context.and_then(|state| state.project_id.as_deref()) {
+        context_items.push(format!("Project: {}", project));
+    }
+    if let Some(objective) = context.and_then(|state| state.objective.as_deref()) {
+        context_items.push(format!("Objective: {}", objective));
+    }
+    match (epic, epic_id.as_deref()) {
+        (Some(epic), _) => {
+            let members: Vec<&Task> = tasks
+                .iter()
+                .filter(|task| in_scope(task) && task.id != epic.id)
+                .collect();
+            let done = members.iter().filter(|task| is_closed(task)).count();
+            context_items.push(format!(
+                "Epic: {} | {} | {}/{} done | {}",
+                epic.id,
+                epic.status,
+                done,
+                members.len(),
+                epic.title
+            ));
+        }
+        (None, Some(id)) => context_items.push(format!("Epic: {} (not found)", id)),
+        (None, None) => {}
+    }
+    if let Some(state) = context.filter(|state| state.scope.mode == ContextScopeMode::Tasks) {
+        context_items.push(format!("Scope tasks: {}", state.scope.task_ids.join(", ")));
+    }
+
+    let ready_items: Vec<String> =
+        recommend_next_tasks_with_context_and_rules(tasks, context, rules)
+            .into_iter()
+            .filter(|task| in_scope(task))
+            .take(READY_TASK_LIMIT)
+            .map(|task| {
+                let excerpt = body_excerpt(task);
+                if excerpt.is_empty() {
+                    pack_task_line(task)
+                } else {
+                    format!("{}\n{}", pack_task_line(task), excerpt)
+                }
+            })
+            .collect();
+
+    let blocker_items: Vec<String> = tasks
+        .iter()
+        .filter(|task| in_scope(task) && !is_closed(task))
+        .filter_map(|task| {
+            let open: Vec<String> = task
+                .dependencies
+                .iter()
+                .chain(task.relationships.blocked_by.iter())
+                .filter_map(|dep| {
+                    match tasks
+                        .iter()
+                        .find(|other| other.id.eq_ignore_ascii_case(dep))
+                    {
+                        Some(other) if is_closed(other) => None,
+                        Some(other) => Some(format!("{} [{}]", other.id, other.status)),
+                        None => Some(format!("{} [missing]", dep)),
+                    }
+                })
+                .collect();
+            (!open.is_empty())
+                .then(|| format!("{} {} blocked by {}", task.id, task.title, open.join(", ")))
+        })
+        .collect();
+
+    let decision_items: Vec<String> = list_truths(
+        backlog_dir,
+        &TruthQuery {
+            states: vec![TruthState::Accepted],
+            limit: Some(DECISION_LIMIT),
+            ..TruthQuery::default()
+        },
+    )
+    .unwrap_or_default()
+    .into_iter()
+    .map(|record| format!("{}: {} - {}", record.id, record.title, record.statement))
+    .collect();
+
+    let candidates = [
+        ("context", "Context", context_items),
+        ("ready", "Ready tasks", ready_items),
+        ("blockers", "Blockers", blocker_items),
+        ("decisions", "Recent decisions", decision_items),
+    ];
+
+    let mut used = estimate_tokens(PACK_TITLE) + 1;
+    let mut truncated = false;
+    let mut sections = Vec::new();
+    for (name, title, items) in candidates {
+        if items.is_empty() {
+            continue;
+        }
+        let mut section = ContextPackSection {
+            name: name.to_string(),
+            title: title.to_string(),
+            items: Vec::new(),
+            omitted: 0,
+        };
+        let header = estimate_tokens(&format!("## {}", title)) + 1;
+        for item in items {
+            let header_cost = if section.items.is_empty() { header } else { 0 };
+            let summary = item.lines().next().unwrap_or_default().to_string();
+            let shortened = summary != item;
+            let fitted = [(item, false), (summary, shortened)]
+                .into_iter()
+                .map(|(text, cut)| (estimate_tokens(&text) + 1 + header_cost, text, cut))
+                .find(|(cost, _, _)| used + cost <= options.budget);
+            match fitted {
+                Some((cost, text, cut)) => {
+                    truncated |= cut;
+                    used += cost;
+                    section.items.push(text);
+                }
+                None => section.omitted += 1,
+            }
+        }
+        truncated |= section.omitted > 0;
+        if !section.items.is_empty() || section.omitted > 0 {
+            sections.push(section);
+        }
+    }
+
+    ContextPack {
+        budget: options.budget,
+        estimated_tokens: used,
+        epic: epic.map(|epic| epic.id.clone()).or(epic_id),
+        truncated,
+        sections,
+    }
+}
+
+const PACK_TITLE: &str = "# Context pack";
+
+pub fn render_context_pack_markdown(pack: &ContextPack) -> String {
+    let mut lines = vec![PACK_TITLE.to_string(), String::new()];
+    let mut budget_line = format!("Budget: ~{}/{} tokens", pack.estimated_tokens, pack.budget);
+    if pack.truncated {
+        budget_line.push_str(" (truncated)");
+    }
+    lines.push(budget_line);
+    for section in &pack.sections {
+        lines.push(String::new());
+        lines.push(format!("## {}", section.title));
+        for item in &section.items {
+            let mut item_lines = item.lines();
+            if let Some(first) = item_lines.next() {
+                lines.push(format!("- {}", first));
+            }
+            for rest in item_lines {
+                lines.push(format!("  {}", rest));
+            }
+        }
+        if section.omitted > 0 {
+            lines.push(format!("- ... {} more omitted", section.omitted));
+        }
+    }
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+fn pack_task_line(task: &Task) -> String {
+    format!(
+        "{} | {} | {} | {}",
+        task.id, task.status, task.priority, task.title
+    )
+}
+
+/// Description section (or the whole body), whitespace-collapsed and cut to
+/// [`BODY_EXCERPT_CHARS`].
+fn body_excerpt(task: &Task) -> String {
+    let source = extract_section_content(&task.body, "Description")
+        .filter(|text| !text.trim().is_empty())
+        .unwrap_or_else(|| task.body.clone());
+    let collapsed = source.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.chars().count() <= BODY_EXCERPT_CHARS {
+        return collapsed;
+    }
+    let cut: String = collapsed.chars().take(BODY_EXCERPT_CHARS).collect();
+    format!("{}...", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    fn write_task(dir: &Path, id: &str, kind: &str, extra: &str, body: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: Task {id}\nkind: {kind}\nstatus: To Do\npriority: P2\n\
+phase: Phase1\ndependencies: []\nlabels: []\n{extra}---\n\n## Description\n{body}\n"
+        );
+        fs::write(dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn parse_token_budget_accepts_suffixes() {
+        assert_eq!(parse_token_budget("4000-tokens"), Some(4000));
+        assert_eq!(parse_token_budget("4000 tokens"), Some(4000));
+        assert_eq!(parse_token_budget("4k"), Some(4000));
+        assert_eq!(parse_token_budget("1200"), Some(1200));
+        assert_eq!(parse_token_budget("0"), None);
+        assert_eq!(parse_token_budget("lots"), None);
+    }
+
+    #[test]
+    fn pack_scopes_to_epic_and_respects_budget() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(&tasks_dir, "task-001", "epic", "", "Epic body");
+        write_task(
+            &tasks_dir,
+            "task-002",
+            "task",
+            "relationships:\n  parent: [task-001]\n",
+            &"Long description. ".repeat(60),
+        );
+        write_task(
+            &tasks_dir,
+            "task-003",
+            "task",
+            "relationships:\n  parent: [task-001]\n  blocked_by: [task-002]\n",
+            "Blocked work",
+        );
+        write_task(&tasks_dir, "task-004", "task", "", "Unrelated");
+        let tasks = load_tasks(&backlog);
+        let rules = TaskValidationRules {
+            require_description: false,
+            require_acceptance_criteria: false,
+            require_definition_of_done: false,
+            require_outcome_based_definition_of_done: false,
+            ..TaskValidationRules::default()
+        };
+
+        let options = ContextPackOptions {
+            budget: 4000,
+            epic: Some("task-001".to_string()),
+        };
+        let pack = build_context_pack(&backlog, &tasks, None, &rules, &options);
+        assert!(!pack.truncated);
+        let markdown = render_context_pack_markdown(&pack);
+        assert!(markdown.contains("- Epic: task-001 | To Do | 0/2 done | Task task-001"));
+        assert!(markdown.contains("- task-003 Task task-003 blocked by task-002 [To Do]"));
+        assert!(markdown.contains("- task-002 | To Do | P2 | Task task-002\n  Long description."));
+        assert!(markdown.contains("Long...\n"));
+        assert!(!markdown.contains("task-004"));
+        assert!(pack.estimated_tokens <= pack.budget);
+
+        let options = ContextPackOptions {
+            budget: 40,
+            epic: Some("task-001".to_string()),
+        };
+        let pack = build_context_pack(&backlog, &tasks, None, &rules, &options);
+        assert!(pack.truncated);
+        assert!(pack.estimated_tokens <= 40);
+        assert_eq!(pack.sections[0].name, "context");
+        assert!(pack.sections.iter().any(|section| section.omitted > 0));
+    }
+}
@@ -0,0 +1,340 @@
+//! Cycle time and lead time of done tasks, reconstructed from the audit log.
+//!
+//! Lead time runs from creation (the `add_task` event, else `created_date`) to the last move
+//! into Done; cycle time runs from the first move into In Progress to that same move. Both are
+//! calendar hours. Only tasks that are Done now are measured, so reopened work counts once it
+//! closes again, rework included.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::task_ops::is_done;
+use crate::timewindow::TimeWindow;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CycleTimeGroup {
+    Overall,
+    Label,
+    Phase,
+    Priority,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskCycleTime {
+    pub task_id: String,
+    pub title: String,
+    /// Stored timestamps, as written in the audit log or front matter.
+    pub created: Option<String>,
+    pub started: Option<String>,
+    pub done: String,
+    pub lead_hours: Option<f64>,
+    pub cycle_hours: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DurationStats {
+    pub count: usize,
+    pub p50_hours: f64,
+    pub p90_hours: f64,
+    pub mean_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleTimeBucket {
+    pub key: String,
+    pub tasks: usize,
+    /// `None` when no task in the bucket has a known creation time.
+    pub lead: Option<DurationStats>,
+    /// `None` when no task in the bucket was moved to In Progress.
+    pub cycle: Option<DurationStats>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CycleTimeReport {
+    pub group: CycleTimeGroup,
+    pub buckets: Vec<CycleTimeBucket>,
+    /// Measured tasks, most recently done first.
+    pub tasks: Vec<TaskCycleTime>,
+    /// Done tasks whose move to Done is not in the audit log.
+    pub untracked: Vec<String>,
+}
+
+/// Cycle and lead time for done tasks, limited to tasks done inside `window`.
+pub fn cycle_time_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    group: CycleTimeGroup,
+    window: &TimeWindow,
+) -> CycleTimeReport {
+    cycle_time_from_events(tasks, &read_audit_events(backlog_dir), group, window)
+}
+
+fn cycle_time_from_events(
+    tasks: &[Task],
+    events: &[AuditEvent],
+    group: CycleTimeGroup,
+    window: &TimeWindow,
+) -> CycleTimeReport {
+    let lifecycles = lifecycles(events);
+    let mut measured: Vec<(NaiveDateTime, &Task, TaskCycleTime)> = Vec::new();
+    let mut untracked = Vec::new();
+
+    for task in tasks.iter().filter(|task| is_done(task)) {
+        let lifecycle = lifecycles.get(&task.id.to_lowercase());
+        let Some((done_at, done)) = lifecycle.and_then(|life| life.done.clone()) else {
+            untracked.push(task.id.clone());
+            continue;
+        };
+        if !window.contains(done_at) {
+            continue;
+        }
+        let created = lifecycle.and_then(|life| life.created.clone()).or_else(|| {
+            let stored = task.created_date.clone()?;
+            parse_task_date(&stored).map(|at| (at, stored))
+        });
+        let started = lifecycle
+            .and_then(|life| life.started.clone())
+            .filter(|(at, _)| *at <= done_at);
+        measured.push((
+            done_at,
+            task,
+            TaskCycleTime {
+                task_id: task.id.clone(),
+                title: task.title.clone(),
+                lead_hours: created.as_ref().and_then(|(at, _)| hours(*at, done_at)),
+                cycle_hours: started.as_ref().and_then(|(at, _)| hours(*at, done_at)),
+                created: created.map(|(_, stored)| stored),
+                started: started.map(|(_, stored)| stored),
+                done,
+            },
+        ));
+    }
+    measured.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.2.task_id.cmp(&b.2.task_id)));
+
+    let mut grouped: BTreeMap<String, Vec<&TaskCycleTime>> = BTreeMap::new();
+    for (_, task, entry) in &measured {
+        for key in group_keys(task, group) {
+            grouped.entry(key).or_default().push(entry);
+        }
+    }
+    let buckets = grouped
+        .into_iter()
+        .map(|(key, entries)| CycleTimeBucket {
+            key,
+            tasks: entries.len(),
+            lead: duration_stats(entries.iter().filter_map(|entry| entry.lead_hours)),
+            cycle: duration_stats(entries.iter().filter_map(|entry| entry.cycle_hours)),
+        })
+        .collect();
+
+    CycleTimeReport {
+        group,
+        buckets,
+        tasks: measured.into_iter().map(|(_, _, entry)| entry).collect(),
+        untracked,
+    }
+}
+
+/// Creation, first start, and last completion of one task, each with its stored timestamp.
+#[derive(Default)]
+pub(crate) struct Lifecycle {
+    pub(crate) created: Option<(NaiveDateTime, String)>,
+    pub(crate) started: Option<(NaiveDateTime, String)>,
+    pub(crate) done: Option<(NaiveDateTime, String)>,
+}
+
+pub(crate) fn lifecycles(events: &[AuditEvent]) -> HashMap<String, Lifecycle> {
+    let mut lifecycles: HashMap<String, Lifecycle> = HashMap::new();
+    for event in events {
+        let (Some(task_id), Some(at)) =
+            (event.task_id.as_deref(), parse_task_date(&event.timestamp))
+        else {
+            continue;
+        };
+        let life = lifecycles.entry(task_id.to_lowercase()).or_default();
+        let stamp = (at, event.timestamp.clone());
+        if event.action == "add_task" && life.created.as_ref().is_none_or(|(first, _)| at < *first)
+        {
+            life.created = Some(stamp.clone());
+        }
+        if !matches!(
+            event.action.as_str(),
+            "set_status" | "bulk_set_status" | "add_task"
+        ) {
+            continue;
+        }
+        let Some(status) = event.details["status"].as_str() else {
+            continue;
+        };
+        match status.trim().to_lowercase().as_str() {
+            "in progress" if life.started.as_ref().is_none_or(|(first, _)| at < *first) => {
+                life.started = Some(stamp);
+            }
+            "done" if life.done.as_ref().is_none_or(|(last, _)| at >= *last) => {
+                life.done = Some(stamp);
+            }
+            _ => {}
+        }
+    }
+    lifecycles
+}
+
+fn group_keys(task: &Task, group: CycleTimeGroup) -> Vec<String> {
+    let values: Vec<String> = match group {
+        CycleTimeGroup::Overall => return vec!["all".to_string()],
+        CycleTimeGroup::Label => task.labels.clone(),
+        CycleTimeGroup::Phase => vec![task.phase.trim().to_string()],
+        CycleTimeGroup::Priority => vec![task.priority.trim().to_string()],
+    };
+    let values: Vec<String> = values
+        .into_iter()
+        .filter(|value| !value.is_empty())
+        .collect();
+    if values.is_empty() {
+        vec!["(none)".to_string()]
+    } else {
+        values
+    }
+}
+
+/// Calendar hours from `start` to `end`; `None` when the clock ran backwards.
+fn hours(start: NaiveDateTime, end: NaiveDateTime) -> Option<f64> {
+    let minutes = (end - start).num_minutes();
+    (minutes >= 0).then(|| minutes as f64 / 60.0)
+}
+
+fn duration_stats(values: impl Iterator<Item = f64>) -> Option<DurationStats> {
+    let mut values: Vec<f64> = values.collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+    Some(DurationStats {
+        count: values.len(),
+        p50_hours: percentile(&values, 0.5),
+        p90_hours: percentile(&values, 0.9),
+        mean_hours: values.iter().sum::<f64>() / values.len() as f64,
+    })
+}
+
+/// Nearest-rank percentile of a sorted, non-empty slice.
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn task(id: &str, status: &str, priority: &str, labels: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: priority.to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: labels.iter().map(|label| label.to_string()).collect(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: Default::default(),
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    fn event(timestamp: &str, action: &str, task_id: &str, status: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: action.to_string(),
+            task_id: Some(task_id.to_string()),
+            details: json!({ "status": status }),
+        }
+    }
+
+    #[test]
+    fn cycle_time_reconstructs_lifecycles_and_percentiles() {
+        let mut legacy = task("task-003", "Done", "P2", &[]);
+        legacy.created_date = Some("2026-01-01".to_string());
+        let tasks = vec![
+            task("task-001", "Done", "P1", &["api"]),
+            task("task-002", "Done", "P2", &["api", "ui"]),
+            legacy,
+            task("task-004", "Done", "P2", &[]),
+            task("task-005", "In Progress", "P1", &["api"]),
+        ];
+        let events = vec![
+            event("2026-01-05 09:00", "add_task", "task-001", "To Do"),
+            event("2026-01-05 10:00", "set_status", "task-001", "In Progress"),
+            event("2026-01-05 12:00", "set_status", "task-001", "Done"),
+            event("2026-01-05T09:00:00Z", "add_task", "task-002", "To Do"),
+            event("2026-01-06 09:00", "set_status", "task-002", "In Progress"),
+            event("2026-01-06 21:00", "set_status", "task-002", "Done"),
+            // Reopened and closed again: the last Done wins, the first start stays.
+            event("2026-01-07 09:00", "set_status", "task-002", "In Progress"),
+            event("2026-01-07 09:00", "set_status", "task-002", "Done"),
+            event("2026-01-02 00:00", "set_status", "task-003", "Done"),
+            event("2026-01-05 10:00", "set_status", "task-005", "In Progress"),
+        ];
+
+        let report = cycle_time_from_events(
+            &tasks,
+            &events,
+            CycleTimeGroup::Label,
+            &TimeWindow::default(),
+        );
+        assert_eq!(report.untracked, vec!["task-004".to_string()]);
+        let ids: Vec<&str> = report.tasks.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-002", "task-001", "task-003"]);
+        assert_eq!(report.tasks[1].lead_hours, Some(3.0));
+        assert_eq!(report.tasks[1].cycle_hours, Some(2.0));
+        assert_eq!(report.tasks[0].cycle_hours, Some(24.0));
+        assert_eq!(report.tasks[0].done, "2026-01-07 09:00");
+        assert_eq!(report.tasks[2].created.as_deref(), Some("2026-01-01"));
+        assert_eq!(report.tasks[2].lead_hours, Some(24.0));
+        assert_eq!(report.tasks[2].cycle_hours, None);
+
+        let keys: Vec<&str> = report.buckets.iter().map(|b| b.key.as_str()).collect();
+        assert_eq!(keys, vec!["(none)", "api", "ui"]);
+        let api = &report.buckets[1];
+        assert_eq!(api.tasks, 2);
+        let cycle = api.cycle.as_ref().expect("cycle");
+        assert_eq!((cycle.p50_hours, cycle.p90_hours), (2.0, 24.0));
+        assert_eq!(cycle.mean_hours, 13.0);
+        assert!(report.buckets[0].cycle.is_none());
+
+        let window = TimeWindow {
+            since: parse_task_date("2026-01-06 00:00"),
+            until: None,
+        };
+        let report = cycle_time_from_events(&tasks, &events, CycleTimeGroup::Priority, &window);
+        let keys: Vec<&str> = report.buckets.iter().map(|b| b.key.as_str()).collect();
+        assert_eq!(keys, vec!["P2"]);
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        assert_eq!(percentile(&values, 0.5), 5.0);
+        assert_eq!(percentile(&values, 0.9), 9.0);
+        assert_eq!(percentile(&[4.0], 0.9), 4.0);
+    }
+}
@@ -11,16 +11,20 @@ use crate::config::{
 };
 use crate::context::{context_path, load_context};
 use crate::focus::focus_path;
+use crate::freeze::{load_freezes, FrozenSet};
 use crate::global_sessions::{
     rebuild_sessions_index, recover_sessions_events, sessions_current_path, sessions_events_path,
 };
 use crate::index::index_path;
+use crate::service::{service_spec, service_status, ServicePlatform};
 use crate::skills::{detect_user_agents_in_home, embedded_skill_ids, SkillAgent};
 use crate::storage::read_versioned_or_legacy_json;
+use crate::task::load_tasks;
 use crate::truth::{
     rebuild_truth_projection, recover_truth_events, truth_events_path, truth_store_status,
     validate_truth_store,
 };
+use crate::watch::DEFAULT_WATCH_INTERVAL_MS;
 use crate::worktrees::worktrees_registry_path;
 
 fn layout_name(layout: BacklogLayout) -> &'static str {
@@ -519,6 +523,27 @@ pub fn doctor_report_with_options(
             "validation_ok": status.validation_ok,
         })
     });
+    let freezes = {
+        let freezes = load_freezes(&backlog_dir);
+        let frozen = if freezes.is_empty() {
+            FrozenSet::default()
+        } else {
+            FrozenSet::new(&load_tasks(&backlog_dir), &freezes)
+        };
+        freezes
+            .iter()
+            .map(|freeze| {
+                json!({
+                    "kind": freeze.kind,
+                    "scope": freeze.scope,
+                    "reason": freeze.reason,
+                    "by": freeze.by,
+                    "at": freeze.at,
+                    "tasks": frozen.count_for(freeze),
+                })
+            })
+            .collect::<Vec<_>>()
+    };
     let storage_fix = if fix_storage {
         if resolution.is_some() {
             Some(apply_storage_fixes(&backlog_dir, global_home.as_ref()))
@@ -535,6 +560,20 @@ pub fn doctor_report_with_options(
     let storage =
         storage_integrity_report(&backlog_dir, global_home.as_ref(), storage_fix.as_ref());
 
+    // Only the install location matters for status, so the program path is a placeholder when
+    // the running binary cannot be located.
+    let service = resolution.as_ref().and_then(|_| {
+        let program = std::env::current_exe().unwrap_or_else(|_| PathBuf::from("workmesh"));
+        service_spec(
+            ServicePlatform::current(),
+            &repo_root,
+            &program,
+            DEFAULT_WATCH_INTERVAL_MS,
+        )
+        .ok()
+        .map(|spec| service_status(&spec))
+    });
+
     let versions = match running_binary {
         "workmesh" => json!({
             "workmesh": env!("CARGO_PKG_VERSION"),
@@ -600,7 +639,9 @@ pub fn doctor_report_with_options(
         "legacy_focus": legacy_focus,
         "index": index,
         "truth": truth,
+        "freezes": freezes,
         "storage": storage,
+        "service": service,
         "versions": versions,
         "skills": skills,
         "notes": [
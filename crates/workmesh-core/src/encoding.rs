@@ -0,0 +1,153 @@
+//! Decoding task files written by other tools.
+//!
+//! WorkMesh writes UTF-8, but backlogs imported from elsewhere carry UTF-16 exports (often with a
+//! byte-order mark) and Windows-1252/Latin-1 files. Text is decoded by BOM first, then as UTF-8,
+//! then by a UTF-16 heuristic, and finally as Windows-1252, which accepts any byte sequence.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    Utf8,
+    Utf8Bom,
+    Utf16Le,
+    Utf16Be,
+    Windows1252,
+}
+
+impl fmt::Display for TextEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TextEncoding::Utf8 => "UTF-8",
+            TextEncoding::Utf8Bom => "UTF-8 with a byte-order mark",
+            TextEncoding::Utf16Le => "UTF-16LE",
+            TextEncoding::Utf16Be => "UTF-16BE",
+            TextEncoding::Windows1252 => "Windows-1252 (Latin-1)",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedText {
+    pub text: String,
+    pub encoding: TextEncoding,
+}
+
+pub fn decode_text(bytes: &[u8]) -> DecodedText {
+    let decoded = |text: String, encoding| DecodedText { text, encoding };
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        return decoded(
+            String::from_utf8_lossy(rest).into_owned(),
+            TextEncoding::Utf8Bom,
+        );
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFF, 0xFE]) {
+        return decoded(utf16(rest, u16::from_le_bytes), TextEncoding::Utf16Le);
+    }
+    if let Some(rest) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        return decoded(utf16(rest, u16::from_be_bytes), TextEncoding::Utf16Be);
+    }
+    let utf8 = std::str::from_utf8(bytes).ok();
+    // NUL bytes in valid UTF-8 usually mean BOM-less UTF-16 of plain ASCII.
+    if let Some(text) = utf8.filter(|_| !bytes.contains(&0)) {
+        return decoded(text.to_string(), TextEncoding::Utf8);
+    }
+    match (utf16_without_bom(bytes), utf8) {
+        (Some(TextEncoding::Utf16Be), _) => {
+            decoded(utf16(bytes, u16::from_be_bytes), TextEncoding::Utf16Be)
+        }
+        (Some(_), _) => decoded(utf16(bytes, u16::from_le_bytes), TextEncoding::Utf16Le),
+        (None, Some(text)) => decoded(text.to_string(), TextEncoding::Utf8),
+        (None, None) => decoded(
+            bytes.iter().map(|byte| windows_1252(*byte)).collect(),
+            TextEncoding::Windows1252,
+        ),
+    }
+}
+
+fn utf16(bytes: &[u8], unit: fn([u8; 2]) -> u16) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| unit([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16_lossy(&units)
+}
+
+/// Mostly-ASCII text in UTF-16 has a NUL in every other byte; which half says the byte order.
+fn utf16_without_bom(bytes: &[u8]) -> Option<TextEncoding> {
+    let sample = &bytes[..bytes.len().min(512) & !1];
+    if sample.len() < 4 {
+        return None;
+    }
+    let pairs = sample.len() / 2;
+    let even_nuls = sample.iter().step_by(2).filter(|byte| **byte == 0).count();
+    let odd_nuls = sample
+        .iter()
+        .skip(1)
+        .step_by(2)
+        .filter(|byte| **byte == 0)
+        .count();
+    if odd_nuls * 10 >= pairs * 8 && even_nuls * 10 <= pairs {
+        Some(TextEncoding::Utf16Le)
+    } else if even_nuls * 10 >= pairs * 8 && odd_nuls * 10 <= pairs {
+        Some(TextEncoding::Utf16Be)
+    } else {
+        None
+    }
+}
+
+/// Windows-1252 is Latin-1 except for printable characters in 0x80-0x9F.
+fn windows_1252(byte: u8) -> char {
+    const HIGH: [u16; 32] = [
+        0x20AC, 0x0081, 0x201A, 0x0192, 0x201E, 0x2026, 0x2020, 0x2021, 0x02C6, 0x2030, 0x0160,
+        0x2039, 0x0152, 0x008D, 0x017D, 0x008F, 0x0090, 0x2018, 0x2019, 0x201C, 0x201D, 0x2022,
+        0x2013, 0x2014, 0x02DC, 0x2122, 0x0161, 0x203A, 0x0153, 0x009D, 0x017E, 0x0178,
+    ];
+    match byte {
+        0x80..=0x9F => char::from_u32(HIGH[(byte - 0x80) as usize] as u32).unwrap_or('\u{FFFD}'),
+        _ => byte as char,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str, bom: bool) -> Vec<u8> {
+        let mut bytes = if bom { vec![0xFF, 0xFE] } else { Vec::new() };
+        bytes.extend(text.encode_utf16().flat_map(|unit| unit.to_le_bytes()));
+        bytes
+    }
+
+    #[test]
+    fn decode_text_detects_legacy_encodings() {
+        let text = "---\ntitle: Café “quoted”\n---\n";
+        assert_eq!(decode_text(text.as_bytes()).encoding, TextEncoding::Utf8);
+
+        let mut bom = vec![0xEF, 0xBB, 0xBF];
+        bom.extend_from_slice(text.as_bytes());
+        assert_eq!(
+            decode_text(&bom),
+            DecodedText {
+                text: text.to_string(),
+                encoding: TextEncoding::Utf8Bom
+            }
+        );
+
+        for bytes in [utf16le(text, true), utf16le(text, false)] {
+            let decoded = decode_text(&bytes);
+            assert_eq!(decoded.encoding, TextEncoding::Utf16Le);
+            assert_eq!(decoded.text, text);
+        }
+        let be: Vec<u8> = text
+            .encode_utf16()
+            .flat_map(|unit| unit.to_be_bytes())
+            .collect();
+        assert_eq!(decode_text(&be).text, text);
+
+        let cp1252 = b"---\ntitle: Caf\xe9 \x93quoted\x94\n---\n";
+        let decoded = decode_text(cp1252);
+        assert_eq!(decoded.encoding, TextEncoding::Windows1252);
+        assert_eq!(decoded.text, text);
+    }
+}
@@ -0,0 +1,300 @@
+//! `epic_brief`: a compact orientation summary of one epic for agents.
+//!
+//! Status counts, the tasks blocking the most work, the next ready tasks, and the latest
+//! activity, kept to roughly 1-2 KB of JSON so an agent can orient itself without pulling the
+//! whole subtree.
+
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::audit::read_audit_events;
+use crate::config::TaskValidationRules;
+use crate::extract::epic_subtree;
+use crate::health::is_closed;
+use crate::task::Task;
+use crate::task_ops::{is_epic, recommend_next_tasks_with_context_and_rules};
+use crate::timestamps::cmp_timestamps;
+
+/// Ready tasks listed in the brief.
+const NEXT_READY_LIMIT: usize = 5;
+/// Blockers listed in the brief.
+const TOP_BLOCKER_LIMIT: usize = 3;
+/// Titles are cut to this many characters to keep the brief small.
+const TITLE_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BriefTask {
+    pub id: String,
+    pub title: String,
+    pub priority: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BriefBlocker {
+    pub id: String,
+    pub status: String,
+    /// Open tasks in the epic waiting on it.
+    pub blocks: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BriefActivity {
+    pub at: String,
+    pub task_id: String,
+    /// Audit action (e.g. `set_status`), or `updated` when only `updated_date` is known.
+    pub action: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EpicBrief {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    /// Closed (Done or Cancelled) work items under the epic; nested epics are not counted.
+    pub done: usize,
+    pub total: usize,
+    pub counts: BTreeMap<String, usize>,
+    pub top_blockers: Vec<BriefBlocker>,
+    pub next_ready: Vec<BriefTask>,
+    pub last_activity: Option<BriefActivity>,
+}
+
+/// Brief for `epic_id` (any task with a subtree works), or `None` when the task does not exist.
+pub fn epic_brief(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    epic_id: &str,
+    rules: &TaskValidationRules,
+) -> Option<EpicBrief> {
+    let epic = tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(epic_id.trim()))?;
+    let subtree = epic_subtree(tasks, epic);
+    let scope: HashSet<String> = subtree.iter().map(|task| task.id.to_lowercase()).collect();
+    let members: Vec<&Task> = subtree
+        .iter()
+        .copied()
+        .filter(|task| task.id != epic.id && !is_epic(task))
+        .collect();
+
+    let mut counts = BTreeMap::new();
+    for task in &members {
+        *counts.entry(task.status.trim().to_string()).or_insert(0) += 1;
+    }
+
+    let mut blocks: BTreeMap<String, usize> = BTreeMap::new();
+    for task in members.iter().filter(|task| !is_closed(task)) {
+        let refs: HashSet<String> = task
+            .dependencies
+            .iter()
+            .chain(task.relationships.blocked_by.iter())
+            .map(|dep| dep.trim().to_lowercase())
+            .collect();
+        for dep in refs {
+            let open = tasks
+                .iter()
+                .find(|other| other.id.to_lowercase() == dep)
+                .map(|other| !is_closed(other))
+                .unwrap_or(true);
+            if open {
+                *blocks.entry(dep).or_insert(0) += 1;
+            }
+        }
+    }
+    let mut top_blockers: Vec<BriefBlocker> = blocks
+        .into_iter()
+        .map(|(dep, count)| {
+            let found = tasks.iter().find(|task| task.id.to_lowercase() == dep);
+            BriefBlocker {
+                id: found.map(|task| task.id.clone()).unwrap_or(dep),
+                status: found
+                    .map(|task| task.status.clone())
+                    .unwrap_or_else(|| "missing".to_string()),
+                blocks: count,
+            }
+        })
+        .collect();
+    // Stable: ties keep id order from the BTreeMap.
+    top_blockers.sort_by_key(|blocker| std::cmp::Reverse(blocker.blocks));
+    top_blockers.truncate(TOP_BLOCKER_LIMIT);
+
+    let next_ready = recommend_next_tasks_with_context_and_rules(tasks, None, rules)
+        .into_iter()
+        .filter(|task| scope.contains(&task.id.to_lowercase()) && !is_epic(task))
+        .take(NEXT_READY_LIMIT)
+        .map(|task| BriefTask {
+            id: task.id.clone(),
+            title: short_title(&task.title),
+            priority: task.priority.clone(),
+        })
+        .collect();
+
+    let last_activity = read_audit_events(backlog_dir)
+        .into_iter()
+        .filter_map(|event| {
+            let task_id = event.task_id?;
+            if !scope.contains(&task_id.to_lowercase()) {
+                return None;
+            }
+            Some(BriefActivity {
+                at: event.timestamp,
+                task_id,
+                action: event.action,
+            })
+        })
+        .max_by(|a, b| cmp_timestamps(&a.at, &b.at))
+        .or_else(|| {
+            subtree
+                .iter()
+                .filter_map(|task| {
+                    task.updated_date.as_ref().map(|at| BriefActivity {
+                        at: at.clone(),
+                        task_id: task.id.clone(),
+                        action: "updated".to_string(),
+                    })
+                })
+                .max_by(|a, b| cmp_timestamps(&a.at, &b.at))
+        });
+
+    Some(EpicBrief {
+        id: epic.id.clone(),
+        title: short_title(&epic.title),
+        status: epic.status.clone(),
+        done: members.iter().filter(|task| is_closed(task)).count(),
+        total: members.len(),
+        counts,
+        top_blockers,
+        next_ready,
+        last_activity,
+    })
+}
+
+pub fn render_epic_brief_text(brief: &EpicBrief) -> String {
+    let mut lines = vec![format!(
+        "{} | {} | {}/{} done | {}",
+        brief.id, brief.status, brief.done, brief.total, brief.title
+    )];
+    let counts: Vec<String> = brief
+        .counts
+        .iter()
+        .map(|(status, count)| format!("{} {}", status, count))
+        .collect();
+    if !counts.is_empty() {
+        lines.push(format!("Status: {}", counts.join(", ")));
+    }
+    if !brief.top_blockers.is_empty() {
+        let blockers: Vec<String> = brief
+            .top_blockers
+            .iter()
+            .map(|b| format!("{} [{}] blocks {}", b.id, b.status, b.blocks))
+            .collect();
+        lines.push(format!("Top blockers: {}", blockers.join(", ")));
+    }
+    if brief.next_ready.is_empty() {
+        lines.push("Next ready: (none)".to_string());
+    } else {
+        lines.push("Next ready:".to_string());
+        for task in &brief.next_ready {
+            lines.push(format!("- {} {} {}", task.id, task.priority, task.title));
+        }
+    }
+    if let Some(activity) = &brief.last_activity {
+        lines.push(format!(
+            "Last activity: {} {} {}",
+            activity.at, activity.task_id, activity.action
+        ));
+    }
+    lines.join("\n")
+}
+
+fn short_title(title: &str) -> String {
+    let title = title.trim();
+    if title.chars().count() <= TITLE_CHARS {
+        return title.to_string();
+    }
+    let cut: String = title.chars().take(TITLE_CHARS - 3).collect();
+    format!("{}...", cut.trim_end())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::audit::{append_audit_event, AuditEvent};
+    use crate::task::load_tasks;
+
+    fn write_task(dir: &Path, id: &str, kind: &str, status: &str, extra: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: Task {id} with a deliberately long title that keeps going past the limit\n\
+kind: {kind}\nstatus: {status}\npriority: P2\nphase: Phase1\nlabels: []\n{extra}---\n\n## Description\nWork\n"
+        );
+        fs::write(dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn brief_summarizes_epic_compactly() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(&tasks_dir, "task-001", "epic", "In Progress", "");
+        write_task(&tasks_dir, "task-002", "task", "Done", "parent: task-001\n");
+        write_task(
+            &tasks_dir,
+            "task-003",
+            "task",
+            "To Do",
+            "parent: task-001\n",
+        );
+        for n in 4..40 {
+            write_task(
+                &tasks_dir,
+                &format!("task-{:03}", n),
+                "task",
+                "To Do",
+                "parent: task-001\ndependencies: [task-003]\n",
+            );
+        }
+        write_task(&tasks_dir, "task-099", "task", "To Do", "");
+        append_audit_event(
+            &backlog,
+            &AuditEvent {
+                timestamp: "2026-03-01 10:00".to_string(),
+                actor: None,
+                action: "set_status".to_string(),
+                task_id: Some("task-002".to_string()),
+                details: serde_json::json!({}),
+            },
+        )
+        .expect("audit");
+        let tasks = load_tasks(&backlog);
+        let rules = TaskValidationRules {
+            require_description: false,
+            require_acceptance_criteria: false,
+            require_definition_of_done: false,
+            require_outcome_based_definition_of_done: false,
+            ..TaskValidationRules::default()
+        };
+
+        let brief = epic_brief(&backlog, &tasks, "TASK-001", &rules).expect("brief");
+        assert_eq!((brief.done, brief.total), (1, 38));
+        assert_eq!(brief.counts["To Do"], 37);
+        assert_eq!(brief.top_blockers[0].id, "task-003");
+        assert_eq!(brief.top_blockers[0].blocks, 36);
+        let ready: Vec<&str> = brief.next_ready.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ready, vec!["task-003"]);
+        assert!(brief.title.ends_with("..."));
+        let activity = brief.last_activity.as_ref().expect("activity");
+        assert_eq!(activity.task_id, "task-002");
+
+        let json = serde_json::to_string(&brief).expect("json");
+        assert!(json.len() < 2048, "brief is {} bytes", json.len());
+        assert!(render_epic_brief_text(&brief).contains("Top blockers: task-003 [To Do] blocks 36"));
+        assert!(epic_brief(&backlog, &tasks, "task-404", &rules).is_none());
+    }
+}
@@ -0,0 +1,576 @@
+use std::collections::{BTreeSet, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::audit::{append_audit_event, audit_log_path, AuditError, AuditEvent};
+use crate::backlog::resolve_backlog;
+use crate::confidential::{mutate_confidential_text, ConfidentialKey};
+use crate::merge::task_references;
+use crate::task::{
+    archive_root_for_root, load_tasks_with_archive, read_task_file_text, split_front_matter, Task,
+    TaskParseError,
+};
+use crate::task_ops::{update_body, update_task_field, FieldValue};
+
+/// Status written into tombstones so extracted work drops out of ready/active views.
+pub const TOMBSTONE_STATUS: &str = "Cancelled";
+
+#[derive(Debug, Error)]
+pub enum ExtractError {
+    #[error("Provide exactly one selector: --epic or --label")]
+    MissingSelector,
+    #[error("Epic not found: {0}")]
+    EpicNotFound(String),
+    #[error("No tasks matched the selector")]
+    Empty,
+    #[error("Destination already has a backlog: {0} (use merge-backlog instead)")]
+    DestinationNotEmpty(PathBuf),
+    #[error("Failed to rewrite task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to write extracted files: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExtractOptions {
+    /// Epic task id; the epic plus every descendant (child links and parent back-links) is moved.
+    pub epic: Option<String>,
+    /// Label selector; every task carrying the label is moved.
+    pub label: Option<String>,
+    /// Destination repo root. A fresh `workmesh/` layout is created there.
+    pub dest: PathBuf,
+    /// Write files and tombstones (otherwise dry-run).
+    pub apply: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractedTask {
+    pub id: String,
+    pub source_path: PathBuf,
+    pub target_path: PathBuf,
+    pub archived: bool,
+    pub attachments: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractReport {
+    pub ok: bool,
+    pub apply: bool,
+    pub selector: String,
+    pub dest_state_root: PathBuf,
+    pub tasks: Vec<ExtractedTask>,
+    pub audit_events: usize,
+    /// Ids outside the extracted set that extracted tasks still reference.
+    pub external_refs: Vec<String>,
+    /// Remaining tasks that reference extracted ids (they will point at tombstones).
+    pub referenced_by: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Move an epic subtree (or a labelled slice) of the backlog into a fresh layout at `dest`.
+///
+/// Task files, archived entries, matching audit events, and `attachments/<task-id>/` folders are
+/// copied to `<dest>/workmesh/`. Each source task is then rewritten in place as a tombstone
+/// (`status: Cancelled`, `extracted_to: <dest>`) so remaining references still resolve.
+pub fn extract_backlog(
+    backlog_dir: &Path,
+    options: &ExtractOptions,
+) -> Result<ExtractReport, ExtractError> {
+    let tasks = load_tasks_with_archive(backlog_dir);
+    let (selector, selected) = select_tasks(&tasks, options)?;
+    if selected.is_empty() {
+        return Err(ExtractError::Empty);
+    }
+
+    let dest_state_root = options.dest.join("workmesh");
+    if let Ok(existing) = resolve_backlog(&options.dest) {
+        if !load_tasks_with_archive(&existing.state_root).is_empty() {
+            return Err(ExtractError::DestinationNotEmpty(existing.state_root));
+        }
+    }
+    let dest_tasks_dir = dest_state_root.join("tasks");
+    let dest_archive_root = dest_state_root.join("archive");
+    let source_archive_root = archive_root_for_root(backlog_dir);
+
+    let selected_ids: HashSet<String> = selected.iter().map(|t| t.id.to_lowercase()).collect();
+    let mut external_refs = BTreeSet::new();
+    for task in &selected {
+        for reference in task_references(task) {
+            if !selected_ids.contains(&reference.to_lowercase()) {
+                external_refs.insert(reference);
+            }
+        }
+    }
+    let mut referenced_by = BTreeSet::new();
+    for task in &tasks {
+        if selected_ids.contains(&task.id.to_lowercase()) {
+            continue;
+        }
+        if task_references(task)
+            .iter()
+            .any(|reference| selected_ids.contains(&reference.to_lowercase()))
+        {
+            referenced_by.insert(task.id.clone());
+        }
+    }
+
+    let mut warnings = Vec::new();
+    if !external_refs.is_empty() {
+        warnings.push(format!(
+            "Extracted tasks reference ids that stay behind: {}",
+            external_refs.iter().cloned().collect::<Vec<_>>().join(", ")
+        ));
+    }
+
+    let mut extracted = Vec::new();
+    for task in &selected {
+        let Some(source_path) = task.file_path.clone() else {
+            warnings.push(format!("{} has no file path; skipped", task.id));
+            continue;
+        };
+        let archived = source_path.starts_with(&source_archive_root);
+        let target_dir = if archived {
+            let relative_parent = source_path
+                .parent()
+                .and_then(|parent| parent.strip_prefix(&source_archive_root).ok())
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            dest_archive_root.join(relative_parent)
+        } else {
+            dest_tasks_dir.clone()
+        };
+        let target_path = target_dir.join(source_path.file_name().unwrap_or_default());
+        let attachments_dir = backlog_dir.join("attachments").join(&task.id);
+        let attachments = attachments_dir.is_dir().then_some(attachments_dir);
+
+        extracted.push(ExtractedTask {
+            id: task.id.clone(),
+            source_path,
+            target_path,
+            archived,
+            attachments,
+        });
+    }
+
+    if options.apply {
+        // A tombstone that cannot be written (e.g. a sealed body without WORKMESH_KEY) must stop
+        // the run before anything is copied, not halfway through the loop.
+        for task in &extracted {
+            check_tombstone(&task.source_path)?;
+        }
+        for task in &extracted {
+            if let Some(parent) = task.target_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(&task.source_path, &task.target_path)?;
+            if let Some(dir) = task.attachments.as_ref() {
+                copy_dir_all(dir, &dest_state_root.join("attachments").join(&task.id))?;
+            }
+        }
+        for task in &extracted {
+            write_tombstone(&task.source_path, &dest_state_root)?;
+        }
+        for dir in extracted
+            .iter()
+            .filter_map(|task| task.attachments.as_ref())
+        {
+            fs::remove_dir_all(dir)?;
+        }
+    }
+    if options.apply && !dest_tasks_dir.exists() {
+        // Keep the destination a valid layout even when only archived tasks were moved.
+        fs::create_dir_all(&dest_tasks_dir)?;
+    }
+    let audit_events =
+        copy_audit_events(backlog_dir, &dest_state_root, &selected_ids, options.apply)?;
+
+    Ok(ExtractReport {
+        ok: true,
+        apply: options.apply,
+        selector,
+        dest_state_root,
+        tasks: extracted,
+        audit_events,
+        external_refs: external_refs.into_iter().collect(),
+        referenced_by: referenced_by.into_iter().collect(),
+        warnings,
+    })
+}
+
+fn select_tasks<'a>(
+    tasks: &'a [Task],
+    options: &ExtractOptions,
+) -> Result<(String, Vec<&'a Task>), ExtractError> {
+    let epic = options
+        .epic
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty());
+    let label = options
+        .label
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty());
+    match (epic, label) {
+        (Some(epic), None) => {
+            let root = tasks
+                .iter()
+                .find(|t| t.id.eq_ignore_ascii_case(epic))
+                .ok_or_else(|| ExtractError::EpicNotFound(epic.to_string()))?;
+            Ok((format!("epic:{}", root.id), epic_subtree(tasks, root)))
+        }
+        (None, Some(label)) => {
+            let selected = tasks
+                .iter()
+                .filter(|t| t.labels.iter().any(|l| l.eq_ignore_ascii_case(label)))
+                .collect();
+            Ok((format!("label:{}", label), selected))
+        }
+        _ => Err(ExtractError::MissingSelector),
+    }
+}
+
+/// The epic plus all descendants, following `child` links and inverse `parent` links.
+pub(crate) fn epic_subtree<'a>(tasks: &'a [Task], root: &'a Task) -> Vec<&'a Task> {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut queue = vec![root.id.to_lowercase()];
+    while let Some(current) = queue.pop() {
+        if !seen.insert(current.clone()) {
+            continue;
+        }
+        if let Some(task) = tasks.iter().find(|t| t.id.to_lowercase() == current) {
+            queue.extend(task.relationships.child.iter().map(|c| c.to_lowercase()));
+        }
+        for task in tasks {
+            if task
+                .relationships
+                .parent
+                .iter()
+                .any(|p| p.to_lowercase() == current)
+            {
+                queue.push(task.id.to_lowercase());
+            }
+        }
+    }
+    let mut selected: Vec<&Task> = tasks
+        .iter()
+        .filter(|t| seen.contains(&t.id.to_lowercase()))
+        .collect();
+    selected.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then_with(|| a.id.cmp(&b.id)));
+    selected
+}
+
+/// Fail if the tombstone body could not be written to `path` (sealed or unsealable body).
+fn check_tombstone(path: &Path) -> Result<(), ExtractError> {
+    let text = read_task_file_text(path)?;
+    let key = ConfidentialKey::from_env().map_err(TaskParseError::from)?;
+    mutate_confidential_text(&text, key.as_ref(), |text| {
+        let (front, _body) = split_front_matter(text)?;
+        Ok(format!("---\n{}\n---\n", front))
+    })?;
+    Ok(())
+}
+
+fn write_tombstone(path: &Path, dest_state_root: &Path) -> Result<(), ExtractError> {
+    update_task_field(path, "status", Some(FieldValue::from(TOMBSTONE_STATUS)))?;
+    update_task_field(
+        path,
+        "extracted_to",
+        Some(FieldValue::Scalar(
+            dest_state_root.to_string_lossy().to_string(),
+        )),
+    )?;
+    let body = format!(
+        "\nExtracted to `{}`. This tombstone keeps existing references resolvable.\n",
+        dest_state_root.display()
+    );
+    update_body(path, &body)?;
+    Ok(())
+}
+
+fn copy_audit_events(
+    backlog_dir: &Path,
+    dest_state_root: &Path,
+    selected_ids: &HashSet<String>,
+    apply: bool,
+) -> Result<usize, ExtractError> {
+    let Ok(content) = fs::read_to_string(audit_log_path(backlog_dir)) else {
+        return Ok(0);
+    };
+    let mut count = 0usize;
+    for line in content.lines() {
+        let Ok(event) = serde_json::from_str::<AuditEvent>(line) else {
+            continue;
+        };
+        let matches = event
+            .task_id
+            .as_ref()
+            .map(|id| selected_ids.contains(&id.to_lowercase()))
+            .unwrap_or(false);
+        if !matches {
+            continue;
+        }
+        if apply {
+            append_audit_event(dest_state_root, &event)?;
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+fn copy_dir_all(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_ops::{create_task_file, set_relationship_field};
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        create_task_file(
+            &tasks_dir,
+            "task-001",
+            "Epic",
+            "To Do",
+            "P2",
+            "Phase1",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("epic");
+        create_task_file(
+            &tasks_dir,
+            "task-002",
+            "Child",
+            "To Do",
+            "P2",
+            "Phase1",
+            &["task-003".to_string()],
+            &["split".to_string()],
+            &[],
+        )
+        .expect("child");
+        create_task_file(
+            &tasks_dir,
+            "task-003",
+            "Other",
+            "To Do",
+            "P2",
+            "Phase1",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("other");
+        create_task_file(
+            &tasks_dir,
+            "task-004",
+            "Dependent",
+            "To Do",
+            "P2",
+            "Phase1",
+            &["task-002".to_string()],
+            &[],
+            &[],
+        )
+        .expect("dependent");
+        let child = load_tasks_with_archive(&backlog)
+            .into_iter()
+            .find(|t| t.id == "task-002")
+            .expect("child task");
+        set_relationship_field(
+            child.file_path.as_ref().expect("path"),
+            "parent",
+            vec!["task-001".to_string()],
+        )
+        .expect("parent");
+        (temp, backlog)
+    }
+
+    #[test]
+    fn extract_requires_single_selector() {
+        let (temp, backlog) = setup();
+        let err = extract_backlog(
+            &backlog,
+            &ExtractOptions {
+                dest: temp.path().join("dest"),
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExtractError::MissingSelector));
+    }
+
+    #[test]
+    fn extract_epic_dry_run_reports_subtree_without_writing() {
+        let (temp, backlog) = setup();
+        let dest = temp.path().join("dest");
+        let report = extract_backlog(
+            &backlog,
+            &ExtractOptions {
+                epic: Some("task-001".to_string()),
+                dest: dest.clone(),
+                ..Default::default()
+            },
+        )
+        .expect("extract");
+        let ids: Vec<_> = report.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, vec!["task-001", "task-002"]);
+        assert_eq!(report.external_refs, vec!["task-003".to_string()]);
+        assert_eq!(report.referenced_by, vec!["task-004".to_string()]);
+        assert!(!dest.exists());
+    }
+
+    #[test]
+    fn extract_apply_moves_tasks_audit_and_leaves_tombstones() {
+        let (temp, backlog) = setup();
+        let attachments = backlog.join("attachments").join("task-002");
+        fs::create_dir_all(&attachments).expect("attachments");
+        fs::write(attachments.join("diagram.txt"), "x").expect("attachment");
+        for task_id in ["task-002", "task-003"] {
+            append_audit_event(
+                &backlog,
+                &AuditEvent {
+                    timestamp: "2026-01-01 00:00".to_string(),
+                    actor: None,
+                    action: "set_status".to_string(),
+                    task_id: Some(task_id.to_string()),
+                    details: serde_json::json!({}),
+                },
+            )
+            .expect("audit");
+        }
+
+        let dest = temp.path().join("dest");
+        let report = extract_backlog(
+            &backlog,
+            &ExtractOptions {
+                label: Some("split".to_string()),
+                dest: dest.clone(),
+                apply: true,
+                ..Default::default()
+            },
+        )
+        .expect("extract");
+        assert_eq!(report.tasks.len(), 1);
+        assert_eq!(report.audit_events, 1);
+
+        let moved = load_tasks_with_archive(&dest.join("workmesh"));
+        assert_eq!(moved.len(), 1);
+        assert_eq!(moved[0].id, "task-002");
+        assert_eq!(moved[0].status, "To Do");
+        assert!(dest
+            .join("workmesh")
+            .join("attachments")
+            .join("task-002")
+            .join("diagram.txt")
+            .exists());
+        assert!(!attachments.exists());
+        let dest_audit = fs::read_to_string(audit_log_path(&dest.join("workmesh"))).expect("log");
+        assert_eq!(dest_audit.lines().count(), 1);
+
+        let tombstone = load_tasks_with_archive(&backlog)
+            .into_iter()
+            .find(|t| t.id == "task-002")
+            .expect("tombstone");
+        assert_eq!(tombstone.status, TOMBSTONE_STATUS);
+        assert!(tombstone.extra.contains_key("extracted_to"));
+        assert!(tombstone.body.contains("Extracted to"));
+    }
+
+    #[test]
+    fn extract_checks_every_tombstone_before_copying() {
+        let (temp, backlog) = setup();
+        let attachments = backlog.join("attachments").join("task-001");
+        fs::create_dir_all(&attachments).expect("attachments");
+        fs::write(attachments.join("diagram.txt"), "x").expect("attachment");
+        let sealed = load_tasks_with_archive(&backlog)
+            .into_iter()
+            .find(|t| t.id == "task-002")
+            .and_then(|t| t.file_path)
+            .expect("child path");
+        let (front, _body) =
+            split_front_matter(&fs::read_to_string(&sealed).expect("read")).expect("front");
+        fs::write(
+            &sealed,
+            format!(
+                "---\n{}\nconfidential: true\n---\n-----BEGIN WORKMESH CONFIDENTIAL-----\nAAAA\n-----END WORKMESH CONFIDENTIAL-----\n",
+                front
+            ),
+        )
+        .expect("seal");
+
+        let dest = temp.path().join("dest");
+        let err = extract_backlog(
+            &backlog,
+            &ExtractOptions {
+                epic: Some("task-001".to_string()),
+                dest: dest.clone(),
+                apply: true,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExtractError::Task(_)));
+        assert!(!dest.exists());
+        assert!(attachments.join("diagram.txt").exists());
+        let epic = load_tasks_with_archive(&backlog)
+            .into_iter()
+            .find(|t| t.id == "task-001")
+            .expect("epic");
+        assert_eq!(epic.status, "To Do");
+    }
+
+    #[test]
+    fn extract_refuses_populated_destination() {
+        let (temp, backlog) = setup();
+        let dest = temp.path().join("dest");
+        let dest_tasks = dest.join("workmesh").join("tasks");
+        fs::create_dir_all(&dest_tasks).expect("dest tasks");
+        create_task_file(
+            &dest_tasks,
+            "task-001",
+            "Existing",
+            "To Do",
+            "P2",
+            "Phase1",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("existing");
+        let err = extract_backlog(
+            &backlog,
+            &ExtractOptions {
+                epic: Some("task-001".to_string()),
+                dest,
+                apply: true,
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+        assert!(matches!(err, ExtractError::DestinationNotEmpty(_)));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 
@@ -6,7 +6,9 @@ use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
 use crate::task::{Task, TaskParseError};
-use crate::task_ops::{canonical_task_filename, set_list_field, update_task_field, FieldValue};
+use crate::task_ops::{
+    canonical_task_filename, set_list_field, task_filename_template, update_task_field, FieldValue,
+};
 
 #[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "snake_case")]
@@ -185,6 +187,7 @@ pub fn fix_task_filenames(
     apply: bool,
 ) -> Result<FilenameFixReport, TaskParseError> {
     let mut report = FilenameFixReport::default();
+    let mut templates: HashMap<PathBuf, String> = HashMap::new();
     let mut sorted: Vec<&Task> = tasks.iter().collect();
     sorted.sort_by_key(|task| {
         (
@@ -232,10 +235,16 @@ pub fn fix_task_filenames(
             continue;
         };
 
-        let expected_path = path
-            .parent()
-            .unwrap_or_else(|| std::path::Path::new("."))
-            .join(canonical_task_filename(&task.id, &task.title, uid));
+        let dir = path.parent().unwrap_or_else(|| std::path::Path::new("."));
+        let template = templates
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| task_filename_template(dir));
+        let expected_path = dir.join(canonical_task_filename(
+            template,
+            &task.id,
+            &task.title,
+            uid,
+        ));
 
         if expected_path == *path {
             continue;
@@ -338,6 +338,7 @@ mod tests {
                 parent: vec!["task-main-200".to_string()],
                 child: vec![],
                 discovered_from: vec![],
+                relates_to: vec![],
             },
             lease: None,
             project: Some("alpha".to_string()),
@@ -0,0 +1,281 @@
+//! Freeze mode: an epic subtree or a whole phase can be frozen while it is under review or on
+//! hold. Frozen tasks reject mutations unless the caller overrides, and drop out of next/ready
+//! recommendations until the freeze is lifted.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::storage::{read_modify_write_json, StorageError};
+use crate::task::Task;
+use crate::task_ops::now_timestamp;
+use crate::views::scope_ids_for_epic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FreezeKind {
+    /// The epic and every task under it (transitively, by `parent`).
+    Epic,
+    /// Every task whose `phase` matches (case-insensitive).
+    Phase,
+}
+
+impl FreezeKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            FreezeKind::Epic => "epic",
+            FreezeKind::Phase => "phase",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Freeze {
+    pub kind: FreezeKind,
+    /// Epic task id or phase name.
+    pub scope: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub by: Option<String>,
+    pub at: String,
+}
+
+impl Freeze {
+    fn same_scope(&self, kind: FreezeKind, scope: &str) -> bool {
+        self.kind == kind && self.scope.eq_ignore_ascii_case(scope.trim())
+    }
+
+    /// `epic epic-12 (release review)`, for messages.
+    pub fn describe(&self) -> String {
+        match self.reason.as_deref() {
+            Some(reason) => format!("{} {} ({})", self.kind.as_str(), self.scope, reason),
+            None => format!("{} {}", self.kind.as_str(), self.scope),
+        }
+    }
+}
+
+/// Freezes stored at `<state_root>/.freezes.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FreezeState {
+    #[serde(default)]
+    pub freezes: Vec<Freeze>,
+}
+
+#[derive(Debug, Error)]
+pub enum FreezeError {
+    #[error("Task {id} is frozen: {freeze}")]
+    Frozen { id: String, freeze: String },
+}
+
+pub fn freezes_path(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join(".freezes.json")
+}
+
+pub fn load_freezes(backlog_dir: &Path) -> Vec<Freeze> {
+    std::fs::read_to_string(freezes_path(backlog_dir))
+        .ok()
+        .and_then(|raw| serde_json::from_str::<FreezeState>(&raw).ok())
+        .map(|state| state.freezes)
+        .unwrap_or_default()
+}
+
+/// Freeze `scope`; freezing an already frozen scope replaces its reason and author.
+pub fn freeze_scope(
+    backlog_dir: &Path,
+    kind: FreezeKind,
+    scope: &str,
+    reason: Option<&str>,
+    by: Option<&str>,
+) -> Result<Freeze, StorageError> {
+    let freeze = Freeze {
+        kind,
+        scope: scope.trim().to_string(),
+        reason: reason
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string),
+        by: by.map(str::to_string),
+        at: now_timestamp(),
+    };
+    let entry = freeze.clone();
+    read_modify_write_json(&freezes_path(backlog_dir), |current| {
+        let mut state: FreezeState = current.unwrap_or_default();
+        state
+            .freezes
+            .retain(|existing| !existing.same_scope(kind, &entry.scope));
+        state.freezes.push(entry);
+        Ok(state)
+    })?;
+    Ok(freeze)
+}
+
+/// Lift the freeze on `scope`; `None` when it was not frozen.
+pub fn unfreeze_scope(
+    backlog_dir: &Path,
+    kind: FreezeKind,
+    scope: &str,
+) -> Result<Option<Freeze>, StorageError> {
+    let mut lifted = None;
+    read_modify_write_json(&freezes_path(backlog_dir), |current| {
+        let mut state: FreezeState = current.unwrap_or_default();
+        if let Some(pos) = state
+            .freezes
+            .iter()
+            .position(|existing| existing.same_scope(kind, scope))
+        {
+            lifted = Some(state.freezes.remove(pos));
+        }
+        Ok(state)
+    })?;
+    Ok(lifted)
+}
+
+/// Frozen task ids (lowercased) with the freeze that covers each.
+#[derive(Debug, Clone, Default)]
+pub struct FrozenSet {
+    by_id: HashMap<String, Freeze>,
+    /// Tasks covered by each freeze, overlaps included.
+    counts: Vec<(Freeze, usize)>,
+}
+
+impl FrozenSet {
+    pub fn new(tasks: &[Task], freezes: &[Freeze]) -> Self {
+        let mut by_id = HashMap::new();
+        let mut counts = Vec::new();
+        for freeze in freezes {
+            let ids: Vec<String> = match freeze.kind {
+                FreezeKind::Epic => scope_ids_for_epic(tasks, &freeze.scope)
+                    .into_iter()
+                    .collect(),
+                FreezeKind::Phase => tasks
+                    .iter()
+                    .filter(|task| task.phase.trim().eq_ignore_ascii_case(freeze.scope.trim()))
+                    .map(|task| task.id.to_lowercase())
+                    .collect(),
+            };
+            counts.push((freeze.clone(), ids.len()));
+            for id in ids {
+                by_id.entry(id).or_insert_with(|| freeze.clone());
+            }
+        }
+        Self { by_id, counts }
+    }
+
+    pub fn load(backlog_dir: &Path, tasks: &[Task]) -> Self {
+        Self::new(tasks, &load_freezes(backlog_dir))
+    }
+
+    pub fn get(&self, id: &str) -> Option<&Freeze> {
+        self.by_id.get(&id.trim().to_lowercase())
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.get(id).is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+
+    /// Lowercased ids of every frozen task.
+    pub fn ids(&self) -> HashSet<String> {
+        self.by_id.keys().cloned().collect()
+    }
+
+    /// Tasks covered by `freeze` (an epic freeze counts the epic itself).
+    pub fn count_for(&self, freeze: &Freeze) -> usize {
+        self.counts
+            .iter()
+            .find(|(covering, _)| covering.same_scope(freeze.kind, &freeze.scope))
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Reject a mutation that touches any frozen task in `ids`.
+    pub fn check<S: AsRef<str>>(&self, ids: &[S]) -> Result<(), FreezeError> {
+        for id in ids {
+            if let Some(freeze) = self.get(id.as_ref()) {
+                return Err(FreezeError::Frozen {
+                    id: id.as_ref().trim().to_string(),
+                    freeze: freeze.describe(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_task(dir: &Path, id: &str, phase: &str, parent: &str) {
+        fs::write(
+            dir.join(format!("{} - t.md", id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: To Do\npriority: P2\nphase: {phase}\n\
+                 dependencies: []\nlabels: []\nassignee: []\nrelationships:\n  parent: [{parent}]\n\
+                 ---\n\nbody\n"
+            ),
+        )
+        .expect("write");
+    }
+
+    #[test]
+    fn freezes_cover_epic_subtrees_and_phases_until_lifted() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(&tasks_dir, "task-001", "Phase1", "");
+        write_task(&tasks_dir, "task-002", "Phase1", "task-001");
+        write_task(&tasks_dir, "task-003", "Phase1", "task-002");
+        write_task(&tasks_dir, "task-004", "Phase2", "");
+        let tasks = load_tasks(&backlog);
+
+        freeze_scope(
+            &backlog,
+            FreezeKind::Epic,
+            "TASK-001",
+            Some("release review"),
+            None,
+        )
+        .expect("freeze");
+        let frozen = FrozenSet::load(&backlog, &tasks);
+        assert!(frozen.contains("task-003"));
+        assert!(!frozen.contains("task-004"));
+        assert_eq!(frozen.count_for(&load_freezes(&backlog)[0]), 3);
+        let err = frozen.check(&["task-004", "task-002"]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Task task-002 is frozen: epic TASK-001 (release review)"
+        );
+
+        freeze_scope(&backlog, FreezeKind::Phase, "phase2", None, Some("alice")).expect("phase");
+        freeze_scope(&backlog, FreezeKind::Phase, "Phase2", None, Some("bob")).expect("again");
+        assert_eq!(load_freezes(&backlog).len(), 2);
+        let frozen = FrozenSet::load(&backlog, &tasks);
+        assert!(frozen.contains("task-004"));
+        freeze_scope(&backlog, FreezeKind::Phase, "Phase1", None, None).expect("overlap");
+        let overlapping = FrozenSet::load(&backlog, &tasks);
+        assert_eq!(overlapping.count_for(&load_freezes(&backlog)[2]), 3);
+        unfreeze_scope(&backlog, FreezeKind::Phase, "Phase1").expect("lift overlap");
+
+        let lifted = unfreeze_scope(&backlog, FreezeKind::Epic, "task-001").expect("unfreeze");
+        assert!(lifted.is_some());
+        assert!(unfreeze_scope(&backlog, FreezeKind::Epic, "task-001")
+            .expect("again")
+            .is_none());
+        let frozen = FrozenSet::load(&backlog, &tasks);
+        assert!(frozen.check(&["task-002"]).is_ok());
+        assert_eq!(
+            frozen.get("task-004").and_then(|f| f.by.as_deref()),
+            Some("bob")
+        );
+    }
+}
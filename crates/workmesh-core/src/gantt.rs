@@ -5,9 +5,12 @@ use std::process::{Command, Stdio};
 
 use chrono::{Local, NaiveDate};
 use regex::Regex;
+use serde::Serialize;
+use serde_yaml::Value;
 use thiserror::Error;
 
 use crate::task::Task;
+use crate::task_ops::soft_dependencies;
 
 pub const DEFAULT_PHASE_ORDER: [&str; 7] = [
     "Preflight",
@@ -31,6 +34,14 @@ pub fn default_phase_durations() -> HashMap<&'static str, i32> {
     ])
 }
 
+/// Front matter key holding a task's estimated duration in (possibly fractional) days.
+pub const ESTIMATE_DAYS_FIELD: &str = "estimate_days";
+
+/// Border color for tasks on the critical path; the fill keeps the status color.
+const CRITICAL_COLOR: &str = "Fuchsia";
+/// Soft dependency links are amber; PlantUML still starts the task after its target.
+const SOFT_DEPENDENCY_ARROW: &str = "-[#d97706]->";
+
 fn status_color_map() -> HashMap<&'static str, &'static str> {
     HashMap::from([
         ("done", "green"),
@@ -62,21 +73,19 @@ pub fn plantuml_gantt(
     let task_list: Vec<&Task> = tasks.iter().collect();
     let phases = group_by_phase(&task_list);
     let order = phase_order_list(phases.keys().cloned().collect(), phase_order);
-    let mut durations: HashMap<String, i32> = default_phase_durations()
-        .into_iter()
-        .map(|(key, value)| (key.to_string(), value))
-        .collect();
-    if let Some(overrides) = phase_durations {
-        for (key, value) in overrides {
-            durations.insert(key, value);
-        }
-    }
+    let durations = merged_phase_durations(phase_durations);
     let done_ids: HashSet<String> = task_list
         .iter()
         .filter(|task| task.status.trim().eq_ignore_ascii_case("done"))
         .map(|task| task.id.to_lowercase())
         .collect();
     let color_map = status_color_map();
+    let critical: HashSet<String> = critical_path(tasks, Some(&durations))
+        .tasks
+        .into_iter()
+        .filter(|task| task.critical)
+        .map(|task| task.id.to_lowercase())
+        .collect();
 
     let mut lines = vec![
         "@startgantt".to_string(),
@@ -99,12 +108,19 @@ pub fn plantuml_gantt(
         for task in sorted_items {
             let title = safe_title(task.title.as_str());
             let label = format!("{} {}", task.id, title);
-            let duration = duration_for_task(task, &durations);
+            let duration = chart_duration(task, &durations);
             let days = if duration == 1 { "day" } else { "days" };
             lines.push(format!("[{}] lasts {} {}", label, duration, days));
             let status_key = status_key(task, &done_ids);
             let color = color_map.get(status_key.as_str()).unwrap_or(&"white");
-            lines.push(format!("[{}] is colored in {}", label, color));
+            if critical.contains(&task.id.to_lowercase()) {
+                lines.push(format!(
+                    "[{}] is colored in {}/{}",
+                    label, color, CRITICAL_COLOR
+                ));
+            } else {
+                lines.push(format!("[{}] is colored in {}", label, color));
+            }
         }
         lines.push(String::new());
     }
@@ -122,7 +138,13 @@ pub fn plantuml_gantt(
             );
         }
         for task in &task_list {
-            for dep in &task.dependencies {
+            let soft = soft_dependencies(task);
+            let edges = task
+                .dependencies
+                .iter()
+                .map(|dep| (dep, "-->"))
+                .chain(soft.iter().map(|dep| (dep, SOFT_DEPENDENCY_ARROW)));
+            for (dep, arrow) in edges {
                 if dep.trim().is_empty() {
                     continue;
                 }
@@ -134,7 +156,7 @@ pub fn plantuml_gantt(
                     .get(&task.id.to_lowercase())
                     .cloned()
                     .unwrap_or_else(|| task.id.clone());
-                lines.push(format!("[{}] --> [{}]", dep_label, task_label));
+                lines.push(format!("[{}] {} [{}]", dep_label, arrow, task_label));
             }
         }
     }
@@ -143,6 +165,262 @@ pub fn plantuml_gantt(
     lines.join("\n") + "\n"
 }
 
+/// Mermaid `gantt` text with the same phases, durations and critical path as
+/// [`plantuml_gantt`], for Markdown renderers (GitHub, GitLab) that draw Mermaid natively.
+/// Tasks with dependencies start `after` them; the rest start on the project start date.
+/// Soft dependencies do not move a task and are listed as `%%` comments under it.
+pub fn mermaid_gantt(
+    tasks: &[Task],
+    start: Option<&str>,
+    phase_order: Option<&[String]>,
+    phase_durations: Option<HashMap<String, i32>>,
+    include_dependencies: bool,
+) -> String {
+    let start_str = start_to_iso(start);
+    let task_list: Vec<&Task> = tasks.iter().collect();
+    let phases = group_by_phase(&task_list);
+    let order = phase_order_list(phases.keys().cloned().collect(), phase_order);
+    let durations = merged_phase_durations(phase_durations);
+    let critical: HashSet<String> = critical_path(tasks, Some(&durations))
+        .tasks
+        .into_iter()
+        .filter(|task| task.critical)
+        .map(|task| task.id.to_lowercase())
+        .collect();
+    // Mermaid ids cannot hold every character a task id can, so refer to tasks by position.
+    let ids: HashMap<String, String> = task_list
+        .iter()
+        .enumerate()
+        .map(|(idx, task)| (task.id.to_lowercase(), format!("t{}", idx)))
+        .collect();
+
+    let mut lines = vec![
+        "gantt".to_string(),
+        "    dateFormat YYYY-MM-DD".to_string(),
+        "    axisFormat %m-%d".to_string(),
+    ];
+    for phase in order {
+        let items = phases.get(&phase).cloned().unwrap_or_default();
+        if items.is_empty() {
+            continue;
+        }
+        lines.push(format!("    section {}", mermaid_text(&phase)));
+        let mut sorted_items = items;
+        sorted_items.sort_by_key(|task| task.id_num());
+        for task in sorted_items {
+            let key = task.id.to_lowercase();
+            let mut tags: Vec<String> = Vec::new();
+            match task.status.trim().to_lowercase().as_str() {
+                "done" => tags.push("done".to_string()),
+                "in progress" => tags.push("active".to_string()),
+                _ => {}
+            }
+            if critical.contains(&key) {
+                tags.push("crit".to_string());
+            }
+            tags.push(ids[&key].clone());
+            let after: Vec<&str> = if include_dependencies {
+                task.dependencies
+                    .iter()
+                    .filter_map(|dep| ids.get(&dep.trim().to_lowercase()))
+                    .map(String::as_str)
+                    .collect()
+            } else {
+                Vec::new()
+            };
+            if after.is_empty() {
+                tags.push(start_str.clone());
+            } else {
+                tags.push(format!("after {}", after.join(" ")));
+            }
+            tags.push(format!("{}d", chart_duration(task, &durations)));
+            lines.push(format!(
+                "    {} {} :{}",
+                task.id,
+                mermaid_text(&safe_title(task.title.as_str())),
+                tags.join(", ")
+            ));
+            if include_dependencies {
+                let soft = soft_dependencies(task);
+                if !soft.is_empty() {
+                    lines.push(format!("    %% {} soft after {}", task.id, soft.join(" ")));
+                }
+            }
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduledTask {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    /// `estimate_days`, or the phase-based gantt duration when the task has no estimate.
+    pub duration_days: f64,
+    pub estimated: bool,
+    pub earliest_start: f64,
+    pub earliest_finish: f64,
+    pub latest_start: f64,
+    pub latest_finish: f64,
+    /// Days the task can slip without delaying the finish.
+    pub slack: f64,
+    /// Zero slack: any delay pushes out the finish.
+    pub critical: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CriticalPathReport {
+    /// Days from now until every open task is finished.
+    pub total_days: f64,
+    /// Longest dependency chain, first task first.
+    pub critical_path: Vec<String>,
+    /// Open tasks in dependency order.
+    pub tasks: Vec<ScheduledTask>,
+    /// Open tasks left out because they are caught in (or behind) a dependency cycle.
+    pub unscheduled: Vec<String>,
+}
+
+/// A task's `estimate_days`, when it is a positive number.
+pub fn estimate_days(task: &Task) -> Option<f64> {
+    let days = match task.extra.get(ESTIMATE_DAYS_FIELD)? {
+        Value::Number(number) => number.as_f64()?,
+        Value::String(text) => text.trim().parse::<f64>().ok()?,
+        _ => return None,
+    };
+    (days.is_finite() && days > 0.0).then_some(days)
+}
+
+/// Schedule the remaining (not Done) tasks as soon as their dependencies allow and find the
+/// critical path: the chain of tasks with no slack. Done and unknown dependencies are treated
+/// as finished. Durations match the gantt chart: `estimate_days`, else the phase duration.
+pub fn critical_path(
+    tasks: &[Task],
+    phase_durations: Option<&HashMap<String, i32>>,
+) -> CriticalPathReport {
+    let mut durations: HashMap<String, i32> = default_phase_durations()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    if let Some(overrides) = phase_durations {
+        durations.extend(overrides.iter().map(|(k, v)| (k.clone(), *v)));
+    }
+    let mut open: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| !task.id.is_empty() && !task.status.trim().eq_ignore_ascii_case("done"))
+        .collect();
+    open.sort_by_key(|task| (task.id_num(), task.id.to_lowercase()));
+    let index: HashMap<String, usize> = open
+        .iter()
+        .enumerate()
+        .map(|(i, task)| (task.id.to_lowercase(), i))
+        .collect();
+    let preds: Vec<Vec<usize>> = open
+        .iter()
+        .map(|task| {
+            let mut deps: Vec<usize> = task
+                .dependencies
+                .iter()
+                .filter_map(|dep| index.get(&dep.trim().to_lowercase()).copied())
+                .collect();
+            deps.sort();
+            deps.dedup();
+            deps
+        })
+        .collect();
+    let mut succs: Vec<Vec<usize>> = vec![Vec::new(); open.len()];
+    for (i, deps) in preds.iter().enumerate() {
+        for dep in deps {
+            succs[*dep].push(i);
+        }
+    }
+
+    // Kahn's algorithm, lowest id first so the order is stable.
+    let mut remaining: Vec<usize> = preds.iter().map(Vec::len).collect();
+    let mut ready: std::collections::BTreeSet<usize> =
+        (0..open.len()).filter(|i| remaining[*i] == 0).collect();
+    let mut order = Vec::with_capacity(open.len());
+    while let Some(i) = ready.pop_first() {
+        order.push(i);
+        for next in &succs[i] {
+            remaining[*next] -= 1;
+            if remaining[*next] == 0 {
+                ready.insert(*next);
+            }
+        }
+    }
+
+    let duration: Vec<f64> = open
+        .iter()
+        .map(|task| {
+            estimate_days(task).unwrap_or_else(|| duration_for_task(task, &durations) as f64)
+        })
+        .collect();
+    let mut earliest_start = vec![0.0_f64; open.len()];
+    let mut earliest_finish = vec![0.0_f64; open.len()];
+    for &i in &order {
+        earliest_start[i] = preds[i]
+            .iter()
+            .map(|dep| earliest_finish[*dep])
+            .fold(0.0, f64::max);
+        earliest_finish[i] = earliest_start[i] + duration[i];
+    }
+    let total_days = order
+        .iter()
+        .map(|i| earliest_finish[*i])
+        .fold(0.0, f64::max);
+    let mut latest_finish = vec![total_days; open.len()];
+    for &i in order.iter().rev() {
+        latest_finish[i] = succs[i]
+            .iter()
+            .map(|next| latest_finish[*next] - duration[*next])
+            .fold(total_days, f64::min);
+    }
+    let slack = |i: usize| latest_finish[i] - earliest_finish[i];
+    let is_critical = |i: usize| slack(i).abs() < 1e-9;
+
+    // Walk back from the critical task that finishes last through critical predecessors.
+    let mut path = Vec::new();
+    let mut current = order
+        .iter()
+        .copied()
+        .filter(|i| is_critical(*i) && (earliest_finish[*i] - total_days).abs() < 1e-9)
+        .min();
+    while let Some(i) = current {
+        path.push(open[i].id.clone());
+        current = preds[i].iter().copied().find(|dep| {
+            is_critical(*dep) && (earliest_finish[*dep] - earliest_start[i]).abs() < 1e-9
+        });
+    }
+    path.reverse();
+
+    let scheduled: HashSet<usize> = order.iter().copied().collect();
+    CriticalPathReport {
+        total_days,
+        critical_path: path,
+        tasks: order
+            .iter()
+            .map(|&i| ScheduledTask {
+                id: open[i].id.clone(),
+                title: open[i].title.clone(),
+                status: open[i].status.clone(),
+                duration_days: duration[i],
+                estimated: estimate_days(open[i]).is_some(),
+                earliest_start: earliest_start[i],
+                earliest_finish: earliest_finish[i],
+                latest_start: latest_finish[i] - duration[i],
+                latest_finish: latest_finish[i],
+                slack: slack(i),
+                critical: is_critical(i),
+            })
+            .collect(),
+        unscheduled: (0..open.len())
+            .filter(|i| !scheduled.contains(i))
+            .map(|i| open[i].id.clone())
+            .collect(),
+    }
+}
+
 pub fn write_text_file(path: &Path, content: &str) -> Result<PathBuf, std::io::Error> {
     let path = expand_user(path);
     if let Some(parent) = path.parent() {
@@ -157,6 +435,17 @@ pub fn render_plantuml_svg(
     cmd: Option<Vec<String>>,
     jar_path: Option<&Path>,
     env_map: Option<HashMap<String, String>>,
+) -> Result<String, PlantumlRenderError> {
+    let svg = render_plantuml(source, cmd, jar_path, env_map)?;
+    Ok(strip_timegrid(&svg))
+}
+
+/// Any PlantUML diagram as SVG, without the gantt-specific clean-up of [`render_plantuml_svg`].
+pub fn render_plantuml(
+    source: &str,
+    cmd: Option<Vec<String>>,
+    jar_path: Option<&Path>,
+    env_map: Option<HashMap<String, String>>,
 ) -> Result<String, PlantumlRenderError> {
     let command = resolve_plantuml_command(cmd, jar_path, env_map.as_ref())?;
     let mut process = Command::new(&command[0])
@@ -181,8 +470,7 @@ pub fn render_plantuml_svg(
         };
         return Err(PlantumlRenderError::RenderFailed(message));
     }
-    let svg = String::from_utf8_lossy(&output.stdout).to_string();
-    Ok(strip_timegrid(&svg))
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
 }
 
 fn resolve_plantuml_command(
@@ -303,6 +591,27 @@ fn safe_title(title: &str) -> String {
     title.replace('[', "(").replace(']', ")")
 }
 
+fn merged_phase_durations(overrides: Option<HashMap<String, i32>>) -> HashMap<String, i32> {
+    let mut durations: HashMap<String, i32> = default_phase_durations()
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+    durations.extend(overrides.unwrap_or_default());
+    durations
+}
+
+/// Whole days a task spans on the chart: `estimate_days` rounded up, else the phase duration.
+fn chart_duration(task: &Task, durations: &HashMap<String, i32>) -> i32 {
+    estimate_days(task)
+        .map(|days| days.ceil().max(1.0) as i32)
+        .unwrap_or_else(|| duration_for_task(task, durations))
+}
+
+/// `:` starts a Mermaid gantt task's metadata and `;` ends a statement.
+fn mermaid_text(text: &str) -> String {
+    text.replace(':', " -").replace(';', ",")
+}
+
 fn duration_for_task(task: &Task, durations: &HashMap<String, i32>) -> i32 {
     let phase = task.phase.trim();
     let phase = if phase.is_empty() { "Unphased" } else { phase };
@@ -500,6 +809,121 @@ mod tests {
         assert!(text.contains("[task-001 First] --> [task-002 (no title)]"));
     }
 
+    #[test]
+    fn mermaid_gantt_renders_sections_dependencies_and_critical_tasks() {
+        let t1 = task("task-001", "First", "To Do", "Phase1", &[]);
+        let t2 = task("task-002", "", "In Progress", "Phase1", &["task-001"]);
+        let t3 = task("task-010", "Later: soon; maybe", "Done", "Phase2", &[]);
+        let text = mermaid_gantt(&[t1, t2, t3], Some("2026-01-01"), None, None, true);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "gantt",
+                "    dateFormat YYYY-MM-DD",
+                "    axisFormat %m-%d",
+                "    section Phase1",
+                "    task-001 First :crit, t0, 2026-01-01, 2d",
+                "    task-002 (no title) :active, crit, t1, after t0, 3d",
+                "    section Phase2",
+                "    task-010 Later - soon, maybe :done, t2, 2026-01-01, 3d",
+            ]
+        );
+    }
+
+    #[test]
+    fn gantt_charts_draw_soft_dependencies_apart_from_hard_ones() {
+        let t1 = task("task-001", "First", "To Do", "Phase1", &[]);
+        let mut t2 = task("task-002", "Second", "To Do", "Phase1", &[]);
+        t2.extra.insert(
+            crate::task_ops::SOFT_DEPENDENCIES_FIELD.to_string(),
+            Value::String("task-001".to_string()),
+        );
+        let tasks = [t1, t2];
+        let plantuml = plantuml_gantt(&tasks, Some("2026-01-01"), None, 1, None, true);
+        assert!(plantuml.contains("[task-001 First] -[#d97706]-> [task-002 Second]"));
+        assert!(!plantuml.contains("[task-001 First] --> [task-002 Second]"));
+        let mermaid = mermaid_gantt(&tasks, Some("2026-01-01"), None, None, true);
+        assert!(!mermaid.contains("after t0"));
+        assert!(mermaid.contains("    %% task-002 soft after task-001\n"));
+    }
+
+    fn estimated(mut task: Task, days: &str) -> Task {
+        let value = serde_yaml::from_str(days).expect("yaml");
+        task.extra.insert(ESTIMATE_DAYS_FIELD.to_string(), value);
+        task
+    }
+
+    #[test]
+    fn critical_path_schedules_by_estimate_and_reports_slack() {
+        // 001 -> 002 -> 004 (3 + 2 + 1 days) and 001 -> 003 -> 004 (3 + 0.5 + 1 days).
+        let tasks = vec![
+            estimated(
+                task("task-001", "Design", "In Progress", "Phase1", &[]),
+                "3",
+            ),
+            estimated(
+                task("task-002", "Build", "To Do", "Phase1", &["task-001"]),
+                "2",
+            ),
+            estimated(
+                task("task-003", "Docs", "To Do", "Phase1", &["task-001"]),
+                "'0.5'",
+            ),
+            estimated(
+                task(
+                    "task-004",
+                    "Ship",
+                    "To Do",
+                    "Phase1",
+                    &["task-002", "task-003"],
+                ),
+                "1",
+            ),
+            task("task-005", "Done already", "Done", "Phase1", &[]),
+            task("task-006", "Unestimated", "To Do", "Phase2", &["task-005"]),
+        ];
+        let report = critical_path(&tasks, None);
+        assert_eq!(report.total_days, 6.0);
+        assert_eq!(
+            report.critical_path,
+            vec!["task-001", "task-002", "task-004"]
+        );
+        assert!(report.unscheduled.is_empty());
+        let by_id: HashMap<&str, &ScheduledTask> = report
+            .tasks
+            .iter()
+            .map(|task| (task.id.as_str(), task))
+            .collect();
+        assert!(!by_id.contains_key("task-005"));
+        assert_eq!(by_id["task-003"].earliest_start, 3.0);
+        assert_eq!(by_id["task-003"].slack, 1.5);
+        assert!(!by_id["task-003"].critical);
+        // No estimate: the gantt duration (Phase2's 3 days plus 1 per dependency); the Done
+        // dependency is already satisfied.
+        assert_eq!(by_id["task-006"].duration_days, 4.0);
+        assert!(!by_id["task-006"].estimated);
+        assert_eq!(by_id["task-006"].slack, 2.0);
+
+        let text = plantuml_gantt(&tasks, Some("2026-01-01"), None, 1, None, false);
+        assert!(text.contains("[task-002 Build] lasts 2 days"));
+        assert!(text.contains("[task-003 Docs] lasts 1 day"));
+        assert!(text.contains("[task-002 Build] is colored in red/Fuchsia"));
+        assert!(text.contains("[task-003 Docs] is colored in red\n"));
+    }
+
+    #[test]
+    fn critical_path_leaves_dependency_cycles_unscheduled() {
+        let tasks = vec![
+            task("task-001", "A", "To Do", "Phase1", &["task-002"]),
+            task("task-002", "B", "To Do", "Phase1", &["task-001"]),
+            task("task-003", "C", "To Do", "Phase1", &[]),
+        ];
+        let report = critical_path(&tasks, None);
+        assert_eq!(report.unscheduled, vec!["task-001", "task-002"]);
+        assert_eq!(report.critical_path, vec!["task-003"]);
+    }
+
     #[test]
     fn start_to_iso_returns_valid_iso_date() {
         assert_eq!(start_to_iso(Some("2026-02-01")), "2026-02-01");
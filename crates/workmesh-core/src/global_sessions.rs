@@ -80,6 +80,9 @@ pub struct AgentSession {
     pub worktree: Option<WorktreeBinding>,
     #[serde(default)]
     pub truth_refs: Vec<String>,
+    /// MCP client (`name/version`) that saved the session; `None` for CLI sessions.
+    #[serde(default)]
+    pub client: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
@@ -408,6 +411,7 @@ mod tests {
             handoff: None,
             worktree: None,
             truth_refs: Vec::new(),
+            client: None,
         }
     }
 
@@ -0,0 +1,397 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::storage::write_string_atomic_locked;
+use crate::task::{parse_list_value, tasks_dir_for_root, Task};
+use crate::task_ops::{is_done, now_timestamp};
+
+/// Task front-matter field holding `<goal-id>/<kr-id>` links.
+pub const KEY_RESULTS_FIELD: &str = "key_results";
+
+#[derive(Debug, Error)]
+pub enum GoalError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+    #[error("Goal not found: {0}")]
+    NotFound(String),
+    #[error("Goal already exists: {0}")]
+    Exists(String),
+    #[error("Key result not found: {0}")]
+    KeyResultNotFound(String),
+    #[error("Invalid key result reference (expected <goal-id>/<kr-id>): {0}")]
+    InvalidRef(String),
+}
+
+/// A goal with measurable key results, stored at `<backlog>/goals/<id>.yaml`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goal {
+    pub id: String,
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    #[serde(default)]
+    pub key_results: Vec<KeyResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeyResult {
+    /// `kr-1`, `kr-2`, ... within the goal.
+    pub id: String,
+    pub title: String,
+    /// Metric value at which the key result counts as fully achieved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    /// Baseline the metric starts from (defaults to 0).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    /// Manually reported readings, oldest first; the last one is the current value.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub readings: Vec<MetricReading>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricReading {
+    pub value: f64,
+    pub at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl KeyResult {
+    pub fn current(&self) -> Option<f64> {
+        self.readings.last().map(|reading| reading.value)
+    }
+
+    /// Share of the way from `start` to `target`, clamped to `0.0..=1.0`.
+    pub fn metric_progress(&self) -> Option<f64> {
+        let target = self.target?;
+        let current = self.current()?;
+        let start = self.start.unwrap_or(0.0);
+        if (target - start).abs() < f64::EPSILON {
+            return Some(if current >= target { 1.0 } else { 0.0 });
+        }
+        Some(((current - start) / (target - start)).clamp(0.0, 1.0))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct KeyResultStatus {
+    pub id: String,
+    pub title: String,
+    pub tasks_done: usize,
+    pub tasks_total: usize,
+    pub linked_tasks: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub task_progress: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metric_progress: Option<f64>,
+    /// Mean of the task and metric progress that are available; `None` when neither is.
+    pub progress: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GoalStatus {
+    pub id: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub due: Option<String>,
+    /// Mean progress of key results that have any signal.
+    pub progress: Option<f64>,
+    pub key_results: Vec<KeyResultStatus>,
+}
+
+/// Goals live next to `tasks/` (e.g. `workmesh/goals/`).
+pub fn goals_dir(backlog_dir: &Path) -> PathBuf {
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    tasks_dir
+        .parent()
+        .unwrap_or(tasks_dir.as_path())
+        .join("goals")
+}
+
+pub fn goal_path(backlog_dir: &Path, goal_id: &str) -> PathBuf {
+    goals_dir(backlog_dir).join(format!("{}.yaml", goal_id.trim().to_lowercase()))
+}
+
+/// Every readable goal, ordered by id. Unparseable files are skipped.
+pub fn load_goals(backlog_dir: &Path) -> Vec<Goal> {
+    let Ok(entries) = fs::read_dir(goals_dir(backlog_dir)) else {
+        return Vec::new();
+    };
+    let mut goals: Vec<Goal> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "yaml"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|text| serde_yaml::from_str(&text).ok())
+        .collect();
+    goals.sort_by(|a: &Goal, b: &Goal| a.id.cmp(&b.id));
+    goals
+}
+
+pub fn load_goal(backlog_dir: &Path, goal_id: &str) -> Result<Goal, GoalError> {
+    let path = goal_path(backlog_dir, goal_id);
+    if !path.is_file() {
+        return Err(GoalError::NotFound(goal_id.to_string()));
+    }
+    Ok(serde_yaml::from_str(&fs::read_to_string(path)?)?)
+}
+
+pub fn save_goal(backlog_dir: &Path, goal: &Goal) -> Result<PathBuf, GoalError> {
+    let path = goal_path(backlog_dir, &goal.id);
+    fs::create_dir_all(goals_dir(backlog_dir))?;
+    write_string_atomic_locked(&path, &serde_yaml::to_string(goal)?)?;
+    Ok(path)
+}
+
+/// `goal-001`, `goal-002`, ... after the highest existing numbered goal.
+pub fn next_goal_id(backlog_dir: &Path) -> String {
+    let max = load_goals(backlog_dir)
+        .iter()
+        .filter_map(|goal| goal.id.strip_prefix("goal-")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    format!("goal-{:03}", max + 1)
+}
+
+pub fn create_goal(backlog_dir: &Path, goal: &Goal) -> Result<PathBuf, GoalError> {
+    if goal_path(backlog_dir, &goal.id).exists() {
+        return Err(GoalError::Exists(goal.id.clone()));
+    }
+    save_goal(backlog_dir, goal)
+}
+
+/// Append a key result and return its id.
+pub fn add_key_result(
+    backlog_dir: &Path,
+    goal_id: &str,
+    title: &str,
+    target: Option<f64>,
+    start: Option<f64>,
+    unit: Option<String>,
+) -> Result<String, GoalError> {
+    let mut goal = load_goal(backlog_dir, goal_id)?;
+    let next = goal
+        .key_results
+        .iter()
+        .filter_map(|kr| kr.id.strip_prefix("kr-")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let id = format!("kr-{}", next);
+    goal.key_results.push(KeyResult {
+        id: id.clone(),
+        title: title.trim().to_string(),
+        target,
+        start,
+        unit,
+        readings: Vec::new(),
+    });
+    save_goal(backlog_dir, &goal)?;
+    Ok(id)
+}
+
+/// Record a manually measured metric value for a key result.
+pub fn report_metric(
+    backlog_dir: &Path,
+    reference: &str,
+    value: f64,
+    note: Option<String>,
+) -> Result<KeyResult, GoalError> {
+    let (goal_id, kr_id) = parse_key_result_ref(reference)?;
+    let mut goal = load_goal(backlog_dir, &goal_id)?;
+    let kr = goal
+        .key_results
+        .iter_mut()
+        .find(|kr| kr.id.eq_ignore_ascii_case(&kr_id))
+        .ok_or_else(|| GoalError::KeyResultNotFound(reference.to_string()))?;
+    kr.readings.push(MetricReading {
+        value,
+        at: now_timestamp(),
+        note,
+    });
+    let updated = kr.clone();
+    save_goal(backlog_dir, &goal)?;
+    Ok(updated)
+}
+
+/// Split `<goal-id>/<kr-id>` into lowercase parts.
+pub fn parse_key_result_ref(reference: &str) -> Result<(String, String), GoalError> {
+    match reference.trim().split_once('/') {
+        Some((goal, kr)) if !goal.trim().is_empty() && !kr.trim().is_empty() => {
+            Ok((goal.trim().to_lowercase(), kr.trim().to_lowercase()))
+        }
+        _ => Err(GoalError::InvalidRef(reference.to_string())),
+    }
+}
+
+/// Fail unless `reference` names an existing key result.
+pub fn ensure_key_result(backlog_dir: &Path, reference: &str) -> Result<(), GoalError> {
+    let (goal_id, kr_id) = parse_key_result_ref(reference)?;
+    let goal = load_goal(backlog_dir, &goal_id)?;
+    if goal
+        .key_results
+        .iter()
+        .any(|kr| kr.id.eq_ignore_ascii_case(&kr_id))
+    {
+        Ok(())
+    } else {
+        Err(GoalError::KeyResultNotFound(reference.to_string()))
+    }
+}
+
+/// Key result links declared in the task's `key_results` front matter, lowercased.
+pub fn task_key_results(task: &Task) -> Vec<String> {
+    parse_list_value(task.extra.get(KEY_RESULTS_FIELD))
+        .into_iter()
+        .map(|value| value.to_lowercase())
+        .collect()
+}
+
+/// Progress for one goal: linked task completion plus reported metrics per key result.
+///
+/// Cancelled tasks are ignored; they neither help nor hurt progress.
+pub fn goal_status(goal: &Goal, tasks: &[Task]) -> GoalStatus {
+    let key_results: Vec<KeyResultStatus> = goal
+        .key_results
+        .iter()
+        .map(|kr| {
+            let reference = format!("{}/{}", goal.id, kr.id).to_lowercase();
+            let linked: Vec<&Task> = tasks
+                .iter()
+                .filter(|task| !task.status.trim().eq_ignore_ascii_case("cancelled"))
+                .filter(|task| task_key_results(task).contains(&reference))
+                .collect();
+            let done = linked.iter().filter(|task| is_done(task)).count();
+            let task_progress = (!linked.is_empty()).then(|| done as f64 / linked.len() as f64);
+            let metric_progress = kr.metric_progress();
+            let signals: Vec<f64> = [task_progress, metric_progress]
+                .into_iter()
+                .flatten()
+                .collect();
+            KeyResultStatus {
+                id: kr.id.clone(),
+                title: kr.title.clone(),
+                tasks_done: done,
+                tasks_total: linked.len(),
+                linked_tasks: linked.iter().map(|task| task.id.clone()).collect(),
+                task_progress,
+                current: kr.current(),
+                target: kr.target,
+                unit: kr.unit.clone(),
+                metric_progress,
+                progress: mean(&signals),
+            }
+        })
+        .collect();
+    let progresses: Vec<f64> = key_results.iter().filter_map(|kr| kr.progress).collect();
+    GoalStatus {
+        id: goal.id.clone(),
+        title: goal.title.clone(),
+        owner: goal.owner.clone(),
+        due: goal.due.clone(),
+        progress: mean(&progresses),
+        key_results,
+    }
+}
+
+fn mean(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        None
+    } else {
+        Some(values.iter().sum::<f64>() / values.len() as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    #[test]
+    fn goal_status_combines_task_completion_and_metrics() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        let seeds = [
+            ("task-001", "Done"),
+            ("task-002", "To Do"),
+            ("task-003", "Cancelled"),
+        ];
+        for (id, status) in seeds {
+            fs::write(
+                tasks_dir.join(format!("{} - t.md", id)),
+                format!(
+                    "---\nid: {}\ntitle: T\nstatus: {}\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\nkey_results: [goal-001/kr-1]\n---\n",
+                    id, status
+                ),
+            )
+            .expect("task");
+        }
+
+        let id = next_goal_id(&backlog);
+        assert_eq!(id, "goal-001");
+        let goal = Goal {
+            id: id.clone(),
+            title: "Faster onboarding".to_string(),
+            description: None,
+            owner: Some("luis".to_string()),
+            due: None,
+            key_results: Vec::new(),
+        };
+        let path = create_goal(&backlog, &goal).expect("create");
+        assert!(path.ends_with("workmesh/goals/goal-001.yaml"));
+        assert!(matches!(
+            create_goal(&backlog, &goal),
+            Err(GoalError::Exists(_))
+        ));
+
+        let kr1 = add_key_result(&backlog, &id, "Ship the wizard", None, None, None).expect("kr");
+        let kr2 = add_key_result(
+            &backlog,
+            &id,
+            "Time to first task",
+            Some(5.0),
+            Some(30.0),
+            Some("min".to_string()),
+        )
+        .expect("kr");
+        assert_eq!((kr1.as_str(), kr2.as_str()), ("kr-1", "kr-2"));
+        report_metric(&backlog, "goal-001/kr-2", 20.0, None).expect("report");
+        assert!(report_metric(&backlog, "goal-001/kr-9", 1.0, None).is_err());
+
+        let tasks = load_tasks(&backlog);
+        let status = goal_status(&load_goal(&backlog, &id).expect("load"), &tasks);
+        let first = &status.key_results[0];
+        assert_eq!((first.tasks_done, first.tasks_total), (1, 2));
+        assert_eq!(first.progress, Some(0.5));
+        let second = &status.key_results[1];
+        assert_eq!(second.tasks_total, 0);
+        assert!((second.metric_progress.expect("metric") - 0.4).abs() < 1e-9);
+        assert!((status.progress.expect("progress") - 0.45).abs() < 1e-9);
+    }
+}
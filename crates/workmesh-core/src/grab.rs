@@ -0,0 +1,239 @@
+//! `grab`: a weighted-random handful of small, old ready tasks to fill idle time between bigger
+//! pieces of work. Older tasks and smaller estimates are more likely to be picked.
+
+use std::collections::HashSet;
+
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::calibration::parse_duration_hours;
+use crate::config::TaskValidationRules;
+use crate::health::first_extra;
+use crate::task::Task;
+use crate::task_ops::{is_epic, is_lease_active, ready_tasks_with_rules};
+use crate::timewindow::parse_timestamp_ref;
+
+pub const DEFAULT_GRAB_COUNT: usize = 3;
+
+/// Estimates at or below this many hours count as "small" and keep at least half their weight.
+const SMALL_TASK_HOURS: f64 = 4.0;
+/// Assumed size of a task without an `estimate`: a day, so estimated small tasks win.
+const UNESTIMATED_HOURS: f64 = 8.0;
+
+#[derive(Debug, Clone, Default)]
+pub struct GrabOptions {
+    /// Every label must be present (case-insensitive).
+    pub labels: Vec<String>,
+    pub count: usize,
+    /// Fixed seed for a reproducible draw; otherwise seeded from the clock.
+    pub seed: Option<u64>,
+    /// Lowercased task ids never drawn (e.g. frozen subtrees).
+    pub exclude: HashSet<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrabPick {
+    pub id: String,
+    pub title: String,
+    pub priority: String,
+    pub labels: Vec<String>,
+    pub age_days: i64,
+    pub estimate_hours: Option<f64>,
+    /// Relative chance of being drawn; only meaningful against the other candidates.
+    pub weight: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrabResult {
+    pub seed: u64,
+    pub candidates: usize,
+    pub picks: Vec<GrabPick>,
+}
+
+/// SplitMix64: tiny and good enough for picking chores; not for anything security related.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in (0, 1].
+    fn next_unit(&mut self) -> f64 {
+        ((self.next_u64() >> 11) as f64 + 1.0) / (1u64 << 53) as f64
+    }
+}
+
+fn clock_seed() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// Days since the task was created (falling back to its last update); 0 when undated.
+fn age_days(task: &Task, today: NaiveDate) -> i64 {
+    task.created_date
+        .as_deref()
+        .or(task.updated_date.as_deref())
+        .and_then(parse_timestamp_ref)
+        .map(|created| (today - created.date()).num_days().max(0))
+        .unwrap_or(0)
+}
+
+/// Grows with age (logarithmically, so ancient tasks do not drown out everything else) and
+/// shrinks with the estimate.
+fn grab_weight(age_days: i64, estimate_hours: Option<f64>) -> f64 {
+    let hours = estimate_hours.unwrap_or(UNESTIMATED_HOURS).max(0.0);
+    (1.0 + (1.0 + age_days as f64).ln()) / (1.0 + hours / SMALL_TASK_HOURS)
+}
+
+/// Draw up to `options.count` distinct ready, unleased, non-epic tasks, weighted by
+/// [`grab_weight`] (Efraimidis-Spirakis sampling without replacement).
+pub fn grab_tasks(
+    tasks: &[Task],
+    rules: &TaskValidationRules,
+    options: &GrabOptions,
+    today: NaiveDate,
+) -> GrabResult {
+    let seed = options.seed.unwrap_or_else(clock_seed);
+    let mut rng = SplitMix64(seed);
+    let candidates: Vec<GrabPick> = ready_tasks_with_rules(tasks, rules)
+        .into_iter()
+        .filter(|task| !is_epic(task) && !is_lease_active(task))
+        .filter(|task| !options.exclude.contains(&task.id.to_lowercase()))
+        .filter(|task| {
+            options.labels.iter().all(|wanted| {
+                task.labels
+                    .iter()
+                    .any(|label| label.eq_ignore_ascii_case(wanted.trim()))
+            })
+        })
+        .map(|task| {
+            let age_days = age_days(task, today);
+            let estimate_hours =
+                first_extra(task, &["estimate"]).and_then(|value| parse_duration_hours(&value));
+            GrabPick {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                priority: task.priority.clone(),
+                labels: task.labels.clone(),
+                age_days,
+                estimate_hours,
+                weight: grab_weight(age_days, estimate_hours),
+            }
+        })
+        .collect();
+    let total = candidates.len();
+    let mut keyed: Vec<(f64, GrabPick)> = candidates
+        .into_iter()
+        .map(|pick| (rng.next_unit().powf(1.0 / pick.weight), pick))
+        .collect();
+    keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+    GrabResult {
+        seed,
+        candidates: total,
+        picks: keyed
+            .into_iter()
+            .take(options.count)
+            .map(|(_, pick)| pick)
+            .collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write_task(dir: &std::path::Path, id: &str, extra: &str) {
+        fs::write(
+            dir.join(format!("{} - t.md", id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: To Do\npriority: P3\nphase: Phase1\n\
+                 dependencies: []\nlabels: [chore]\nassignee: []\n{extra}---\n\n\
+                 Description:\n--------------------------------------------------\n- d\n\n\
+                 Acceptance Criteria:\n--------------------------------------------------\n- a\n\n\
+                 Definition of Done:\n--------------------------------------------------\n- Outcome verified by tests\n"
+            ),
+        )
+        .expect("write");
+    }
+
+    #[test]
+    fn grab_favours_old_small_tasks_and_is_seedable() {
+        assert!(grab_weight(365, Some(1.0)) > grab_weight(1, Some(1.0)));
+        assert!(grab_weight(30, Some(1.0)) > grab_weight(30, Some(16.0)));
+        assert!(grab_weight(30, Some(2.0)) > grab_weight(30, None));
+
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(
+            &tasks_dir,
+            "task-001",
+            "estimate: 1h\ncreated_date: 2025-01-01 09:00\n",
+        );
+        write_task(
+            &tasks_dir,
+            "task-002",
+            "estimate: 3d\ncreated_date: 2026-03-01 09:00\n",
+        );
+        write_task(&tasks_dir, "task-003", "lease_owner: bob\n");
+        write_task(&tasks_dir, "task-004", "");
+        let tasks = load_tasks(&backlog);
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).expect("today");
+
+        let options = GrabOptions {
+            labels: vec!["CHORE".to_string()],
+            count: 2,
+            seed: Some(7),
+            ..GrabOptions::default()
+        };
+        let first = grab_tasks(&tasks, &TaskValidationRules::default(), &options, today);
+        assert_eq!(first.candidates, 3, "leased task is excluded");
+        assert_eq!(first.picks.len(), 2);
+        let again = grab_tasks(&tasks, &TaskValidationRules::default(), &options, today);
+        let ids = |result: &GrabResult| {
+            result
+                .picks
+                .iter()
+                .map(|pick| pick.id.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(&first), ids(&again));
+
+        // The old one-hour task wins most draws.
+        let wins = (0..200)
+            .filter(|seed| {
+                let options = GrabOptions {
+                    count: 1,
+                    seed: Some(*seed),
+                    ..GrabOptions::default()
+                };
+                grab_tasks(&tasks, &TaskValidationRules::default(), &options, today).picks[0].id
+                    == "task-001"
+            })
+            .count();
+        assert!(wins > 100, "task-001 won {} of 200 draws", wins);
+
+        let none = GrabOptions {
+            labels: vec!["docs".to_string()],
+            count: 3,
+            seed: Some(1),
+            ..GrabOptions::default()
+        };
+        assert!(
+            grab_tasks(&tasks, &TaskValidationRules::default(), &none, today)
+                .picks
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Dependency churn between checkpoints (`graph-diff --from <ckpt> [--to <ckpt>]`).
+//!
+//! Checkpoints record the blocking edge set (`dependencies`, `soft_dependencies`, and
+//! `relationships.blocked_by`); diffing two sets shows which edges were added or removed and
+//! which tasks ended up blocked by something different.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::task::Task;
+use crate::task_ops::soft_dependencies;
+
+pub const EDGE_DEPENDENCY: &str = "dependency";
+pub const EDGE_SOFT_DEPENDENCY: &str = "soft_dependency";
+pub const EDGE_BLOCKED_BY: &str = "blocked_by";
+
+/// `from` waits on `to`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub from: String,
+    pub to: String,
+    pub kind: String,
+}
+
+impl GraphEdge {
+    fn key(&self) -> (String, String, String) {
+        (
+            self.from.to_lowercase(),
+            self.to.to_lowercase(),
+            self.kind.clone(),
+        )
+    }
+
+    pub fn line(&self) -> String {
+        format!("{} -> {} ({})", self.from, self.to, self.kind)
+    }
+}
+
+/// Edges gained and lost by one task.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct BlockingChange {
+    pub task_id: String,
+    pub added: Vec<GraphEdge>,
+    pub removed: Vec<GraphEdge>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GraphDiff {
+    pub from: String,
+    pub to: String,
+    pub added: Vec<GraphEdge>,
+    pub removed: Vec<GraphEdge>,
+    /// Tasks whose blockers changed, in id order.
+    pub changed_tasks: Vec<BlockingChange>,
+}
+
+/// The blocking edge set of `tasks`, sorted and de-duplicated (ids compared case-insensitively).
+pub fn dependency_edges(tasks: &[Task]) -> Vec<GraphEdge> {
+    let mut seen = BTreeSet::new();
+    let mut edges = Vec::new();
+    for task in tasks {
+        let lists = [
+            (EDGE_DEPENDENCY, task.dependencies.clone()),
+            (EDGE_SOFT_DEPENDENCY, soft_dependencies(task)),
+            (EDGE_BLOCKED_BY, task.relationships.blocked_by.clone()),
+        ];
+        for (kind, targets) in lists {
+            for target in targets {
+                let target = target.trim();
+                if target.is_empty() {
+                    continue;
+                }
+                let edge = GraphEdge {
+                    from: task.id.clone(),
+                    to: target.to_string(),
+                    kind: kind.to_string(),
+                };
+                if seen.insert(edge.key()) {
+                    edges.push(edge);
+                }
+            }
+        }
+    }
+    edges.sort();
+    edges
+}
+
+/// Compare two edge sets; `from_label`/`to_label` name the snapshots in the report.
+pub fn graph_diff(
+    from_label: &str,
+    from_edges: &[GraphEdge],
+    to_label: &str,
+    to_edges: &[GraphEdge],
+) -> GraphDiff {
+    let before: BTreeSet<_> = from_edges.iter().map(GraphEdge::key).collect();
+    let after: BTreeSet<_> = to_edges.iter().map(GraphEdge::key).collect();
+    let mut added: Vec<GraphEdge> = to_edges
+        .iter()
+        .filter(|edge| !before.contains(&edge.key()))
+        .cloned()
+        .collect();
+    let mut removed: Vec<GraphEdge> = from_edges
+        .iter()
+        .filter(|edge| !after.contains(&edge.key()))
+        .cloned()
+        .collect();
+    added.sort();
+    removed.sort();
+
+    let mut by_task: BTreeMap<String, BlockingChange> = BTreeMap::new();
+    for (edge, gained) in added
+        .iter()
+        .map(|edge| (edge, true))
+        .chain(removed.iter().map(|edge| (edge, false)))
+    {
+        let change = by_task
+            .entry(edge.from.to_lowercase())
+            .or_insert_with(|| BlockingChange {
+                task_id: edge.from.clone(),
+                added: Vec::new(),
+                removed: Vec::new(),
+            });
+        if gained {
+            change.added.push(edge.clone());
+        } else {
+            change.removed.push(edge.clone());
+        }
+    }
+
+    GraphDiff {
+        from: from_label.to_string(),
+        to: to_label.to_string(),
+        added,
+        removed,
+        changed_tasks: by_task.into_values().collect(),
+    }
+}
+
+pub fn render_graph_diff(diff: &GraphDiff) -> String {
+    let mut lines = vec![
+        format!("Graph diff {} -> {}", diff.from, diff.to),
+        format!("Edges: +{} -{}", diff.added.len(), diff.removed.len()),
+    ];
+    for edge in &diff.added {
+        lines.push(format!("+ {}", edge.line()));
+    }
+    for edge in &diff.removed {
+        lines.push(format!("- {}", edge.line()));
+    }
+    if diff.changed_tasks.is_empty() {
+        lines.push("No blocking changes.".to_string());
+        return lines.join("\n");
+    }
+    lines.push(String::new());
+    lines.push(format!(
+        "Tasks with changed blockers: {}",
+        diff.changed_tasks.len()
+    ));
+    for change in &diff.changed_tasks {
+        let mut parts: Vec<String> = change
+            .added
+            .iter()
+            .map(|edge| format!("+{} ({})", edge.to, edge.kind))
+            .collect();
+        parts.extend(
+            change
+                .removed
+                .iter()
+                .map(|edge| format!("-{} ({})", edge.to, edge.kind)),
+        );
+        lines.push(format!("- {}: {}", change.task_id, parts.join(", ")));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edge(from: &str, to: &str, kind: &str) -> GraphEdge {
+        GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            kind: kind.to_string(),
+        }
+    }
+
+    #[test]
+    fn diff_reports_added_removed_edges_grouped_by_task() {
+        let before = vec![
+            edge("task-002", "task-001", EDGE_DEPENDENCY),
+            edge("task-003", "task-001", EDGE_BLOCKED_BY),
+        ];
+        let after = vec![
+            edge("TASK-002", "task-001", EDGE_DEPENDENCY),
+            edge("task-003", "task-002", EDGE_DEPENDENCY),
+            edge("task-004", "task-003", EDGE_SOFT_DEPENDENCY),
+        ];
+
+        let diff = graph_diff("a", &before, "b", &after);
+        assert_eq!(
+            diff.added,
+            vec![
+                edge("task-003", "task-002", EDGE_DEPENDENCY),
+                edge("task-004", "task-003", EDGE_SOFT_DEPENDENCY),
+            ]
+        );
+        assert_eq!(
+            diff.removed,
+            vec![edge("task-003", "task-001", EDGE_BLOCKED_BY)]
+        );
+        let ids: Vec<&str> = diff
+            .changed_tasks
+            .iter()
+            .map(|change| change.task_id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["task-003", "task-004"]);
+        assert_eq!(diff.changed_tasks[0].added.len(), 1);
+        assert_eq!(diff.changed_tasks[0].removed.len(), 1);
+
+        let text = render_graph_diff(&diff);
+        assert!(text.contains("Edges: +2 -1"));
+        assert!(text.contains("- task-003: +task-002 (dependency), -task-001 (blocked_by)"));
+    }
+}
@@ -0,0 +1,391 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::Path;
+
+use chrono::{Duration, NaiveDateTime};
+use serde::Serialize;
+
+use crate::audit::read_audit_events;
+use crate::extract::epic_subtree;
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::task_ops::is_epic;
+
+/// Front matter keys that count as an estimate.
+const ESTIMATE_FIELDS: &[&str] = &["estimate", "points", "story_points"];
+/// Front matter keys that hold a due date (`YYYY-MM-DD`).
+pub(crate) const DUE_FIELDS: &[&str] = &["due", "due_date"];
+
+#[derive(Debug, Clone, Copy)]
+pub struct HealthOptions {
+    /// Open tasks not updated for this many days count as stale.
+    pub stale_days: i64,
+    /// Audit events from the last this many days count as churn.
+    pub churn_days: i64,
+}
+
+impl Default for HealthOptions {
+    fn default() -> Self {
+        Self {
+            stale_days: 30,
+            churn_days: 14,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthLevel {
+    Green,
+    Yellow,
+    Red,
+}
+
+impl HealthLevel {
+    pub fn from_score(score: u32) -> Self {
+        match score {
+            75.. => HealthLevel::Green,
+            50..=74 => HealthLevel::Yellow,
+            _ => HealthLevel::Red,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HealthLevel::Green => "green",
+            HealthLevel::Yellow => "yellow",
+            HealthLevel::Red => "red",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct HealthFactors {
+    pub total: usize,
+    pub open: usize,
+    pub blocked: usize,
+    pub blocked_ratio: f64,
+    pub stale: usize,
+    pub stale_ratio: f64,
+    pub overdue: usize,
+    pub overdue_ratio: f64,
+    /// Audit events touching the group's tasks within the churn window.
+    pub churn: usize,
+    pub churn_per_task: f64,
+    pub unestimated: usize,
+    pub unestimated_ratio: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthGroup {
+    /// `epic` or `initiative`.
+    pub kind: &'static str,
+    pub key: String,
+    pub title: Option<String>,
+    /// 0 (unhealthy) to 100 (healthy).
+    pub score: u32,
+    pub level: HealthLevel,
+    pub factors: HealthFactors,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthReport {
+    pub generated_at: String,
+    pub stale_days: i64,
+    pub churn_days: i64,
+    pub groups: Vec<HealthGroup>,
+}
+
+/// Health score for every epic (its descendants) and every initiative (tasks tagged with it).
+pub fn health_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    now: NaiveDateTime,
+    options: HealthOptions,
+) -> HealthReport {
+    let churn_since = now - Duration::days(options.churn_days);
+    let churn_events: Vec<String> = read_audit_events(backlog_dir)
+        .into_iter()
+        .filter(|event| {
+            parse_task_date(&event.timestamp)
+                .map(|at| at >= churn_since)
+                .unwrap_or(false)
+        })
+        .filter_map(|event| event.task_id.map(|id| id.to_lowercase()))
+        .collect();
+    let closed: HashSet<String> = tasks
+        .iter()
+        .filter(|task| is_closed(task))
+        .map(|task| task.id.to_lowercase())
+        .collect();
+
+    let mut groups = Vec::new();
+    for epic in tasks.iter().filter(|task| is_epic(task)) {
+        let members: Vec<&Task> = epic_subtree(tasks, epic)
+            .into_iter()
+            // Nested epics are scored as their own group.
+            .filter(|task| !is_epic(task))
+            .collect();
+        let members = if members.is_empty() {
+            vec![epic]
+        } else {
+            members
+        };
+        groups.push(score_group(
+            "epic",
+            epic.id.clone(),
+            Some(epic.title.clone()),
+            &members,
+            &closed,
+            &churn_events,
+            now,
+            options,
+        ));
+    }
+
+    let mut initiatives: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+    for task in tasks {
+        if let Some(initiative) = task
+            .initiative
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+        {
+            initiatives
+                .entry(initiative.to_lowercase())
+                .or_default()
+                .push(task);
+        }
+    }
+    for (initiative, members) in initiatives {
+        groups.push(score_group(
+            "initiative",
+            initiative,
+            None,
+            &members,
+            &closed,
+            &churn_events,
+            now,
+            options,
+        ));
+    }
+
+    HealthReport {
+        generated_at: now.format("%Y-%m-%d %H:%M").to_string(),
+        stale_days: options.stale_days,
+        churn_days: options.churn_days,
+        groups,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn score_group(
+    kind: &'static str,
+    key: String,
+    title: Option<String>,
+    members: &[&Task],
+    closed: &HashSet<String>,
+    churn_events: &[String],
+    now: NaiveDateTime,
+    options: HealthOptions,
+) -> HealthGroup {
+    let open: Vec<&&Task> = members.iter().filter(|task| !is_closed(task)).collect();
+    let member_ids: HashSet<String> = members.iter().map(|task| task.id.to_lowercase()).collect();
+    let stale_before = now - Duration::days(options.stale_days);
+    let today = now.date();
+
+    let blocked = open.iter().filter(|task| is_blocked(task, closed)).count();
+    let stale = open
+        .iter()
+        .filter(|task| {
+            task.updated_date
+                .as_deref()
+                .or(task.created_date.as_deref())
+                .and_then(parse_task_date)
+                .map(|touched| touched < stale_before)
+                .unwrap_or(false)
+        })
+        .count();
+    let overdue = open
+        .iter()
+        .filter(|task| {
+            first_extra(task, DUE_FIELDS)
+                .and_then(|due| parse_task_date(&due))
+                .map(|due| due.date() < today)
+                .unwrap_or(false)
+        })
+        .count();
+    let unestimated = open
+        .iter()
+        .filter(|task| first_extra(task, ESTIMATE_FIELDS).is_none())
+        .count();
+    let churn = churn_events
+        .iter()
+        .filter(|id| member_ids.contains(*id))
+        .count();
+
+    let ratio = |count: usize| {
+        if open.is_empty() {
+            0.0
+        } else {
+            count as f64 / open.len() as f64
+        }
+    };
+    let factors = HealthFactors {
+        total: members.len(),
+        open: open.len(),
+        blocked,
+        blocked_ratio: ratio(blocked),
+        stale,
+        stale_ratio: ratio(stale),
+        overdue,
+        overdue_ratio: ratio(overdue),
+        churn,
+        churn_per_task: churn as f64 / members.len().max(1) as f64,
+        unestimated,
+        unestimated_ratio: ratio(unestimated),
+    };
+    let score = health_score(&factors);
+    HealthGroup {
+        kind,
+        key,
+        title,
+        score,
+        level: HealthLevel::from_score(score),
+        factors,
+    }
+}
+
+/// Weighted penalties out of 100: blocked 30, stale 25, overdue 25, unestimated 10, and
+/// churn 10 (saturating at five events per task in the window).
+pub fn health_score(factors: &HealthFactors) -> u32 {
+    let churn = (factors.churn_per_task / 5.0).min(1.0);
+    let penalty = factors.blocked_ratio * 30.0
+        + factors.stale_ratio * 25.0
+        + factors.overdue_ratio * 25.0
+        + factors.unestimated_ratio * 10.0
+        + churn * 10.0;
+    (100.0 - penalty).round().clamp(0.0, 100.0) as u32
+}
+
+pub(crate) fn is_closed(task: &Task) -> bool {
+    let status = task.status.trim();
+    status.eq_ignore_ascii_case("done") || status.eq_ignore_ascii_case("cancelled")
+}
+
+pub(crate) fn is_blocked(task: &Task, closed: &HashSet<String>) -> bool {
+    task.status.trim().eq_ignore_ascii_case("blocked")
+        || task
+            .dependencies
+            .iter()
+            .chain(task.relationships.blocked_by.iter())
+            .any(|dep| !closed.contains(&dep.to_lowercase()))
+}
+
+pub(crate) fn first_extra(task: &Task, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match task.extra.get(*key) {
+        Some(serde_yaml::Value::String(value)) if !value.trim().is_empty() => {
+            Some(value.trim().to_string())
+        }
+        Some(serde_yaml::Value::Number(value)) => Some(value.to_string()),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use crate::task_ops::{create_task_file, set_relationship_field, update_task_field};
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        std::fs::create_dir_all(&tasks_dir).expect("tasks");
+        let epic = create_task_file(
+            &tasks_dir,
+            "task-001",
+            "Epic",
+            "In Progress",
+            "P1",
+            "Phase1",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("epic");
+        update_task_field(&epic, "kind", Some("epic".into())).expect("kind");
+        for (id, deps, updated, extra) in [
+            (
+                "task-002",
+                vec![],
+                "2026-06-10 10:00",
+                Some(("estimate", "3")),
+            ),
+            (
+                "task-003",
+                vec!["task-002".to_string()],
+                "2026-01-01 10:00",
+                Some(("due", "2026-05-01")),
+            ),
+        ] {
+            let path = create_task_file(
+                &tasks_dir,
+                id,
+                "Child",
+                "To Do",
+                "P2",
+                "Phase1",
+                &deps,
+                &[],
+                &[],
+            )
+            .expect("child");
+            set_relationship_field(&path, "parent", vec!["task-001".to_string()]).expect("parent");
+            update_task_field(&path, "initiative", Some("billing".into())).expect("initiative");
+            update_task_field(&path, "updated_date", Some(updated.into())).expect("date");
+            if let Some((key, value)) = extra {
+                update_task_field(&path, key, Some(value.into())).expect("extra");
+            }
+        }
+        (temp, backlog)
+    }
+
+    #[test]
+    fn health_scores_epics_and_initiatives_with_factors() {
+        let (_temp, backlog) = setup();
+        let tasks = load_tasks(&backlog);
+        let now = parse_task_date("2026-06-15 10:00").expect("now");
+        let report = health_report(&backlog, &tasks, now, HealthOptions::default());
+
+        assert_eq!(report.groups.len(), 2);
+        let epic = &report.groups[0];
+        assert_eq!((epic.kind, epic.key.as_str()), ("epic", "task-001"));
+        assert_eq!(epic.factors.open, 2);
+        assert_eq!(epic.factors.blocked, 1);
+        assert_eq!(epic.factors.stale, 1);
+        assert_eq!(epic.factors.overdue, 1);
+        assert_eq!(epic.factors.unestimated, 1);
+        // 100 - (15 + 12.5 + 12.5 + 5) = 55
+        assert_eq!(epic.score, 55);
+        assert_eq!(epic.level, HealthLevel::Yellow);
+
+        let initiative = &report.groups[1];
+        assert_eq!(
+            (initiative.kind, initiative.key.as_str()),
+            ("initiative", "billing")
+        );
+        assert_eq!(initiative.score, epic.score);
+    }
+
+    #[test]
+    fn health_level_thresholds() {
+        assert_eq!(HealthLevel::from_score(100), HealthLevel::Green);
+        assert_eq!(HealthLevel::from_score(75), HealthLevel::Green);
+        assert_eq!(HealthLevel::from_score(74), HealthLevel::Yellow);
+        assert_eq!(HealthLevel::from_score(49), HealthLevel::Red);
+        assert_eq!(health_score(&HealthFactors::default()), 100);
+    }
+}
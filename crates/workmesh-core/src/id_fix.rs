@@ -5,7 +5,9 @@ use std::path::{Path, PathBuf};
 use regex::Regex;
 
 use crate::task::{Task, TaskParseError};
-use crate::task_ops::{update_task_field, FieldValue};
+use crate::task_ops::{
+    canonical_task_filename, task_filename_template, update_task_field, FieldValue,
+};
 
 #[derive(Debug, Clone)]
 pub struct FixIdsOptions {
@@ -83,17 +85,29 @@ fn next_free_legacy_dup_id(old_id: &str, used: &HashSet<String>) -> String {
     }
 }
 
-fn rename_task_file(
-    old_path: &Path,
-    old_id: &str,
-    new_id: &str,
-) -> Result<PathBuf, TaskParseError> {
+/// Pick a collision-free replacement for `old_id`, staying inside its namespace when possible.
+pub(crate) fn next_free_task_id(old_id: &str, used: &HashSet<String>) -> String {
+    if let Some((prefix, _num)) = parse_namespaced(old_id) {
+        next_free_namespaced_id(&prefix, used)
+    } else {
+        next_free_legacy_dup_id(old_id, used)
+    }
+}
+
+fn rename_task_file(old_path: &Path, task: &Task, new_id: &str) -> Result<PathBuf, TaskParseError> {
+    let old_id = task.id.as_str();
     let file_name = old_path
         .file_name()
         .and_then(|s| s.to_str())
         .unwrap_or("")
         .to_string();
-    let new_file_name = if file_name
+    let template = task_filename_template(old_path.parent().unwrap_or_else(|| Path::new(".")));
+    let uid = task.uid.as_deref().unwrap_or("");
+    let new_file_name = if file_name == canonical_task_filename(&template, old_id, &task.title, uid)
+    {
+        // Named by the `[task_files]` template: render it again with the new id.
+        canonical_task_filename(&template, new_id, &task.title, uid)
+    } else if file_name
         .to_lowercase()
         .starts_with(&format!("{} ", old_id.to_lowercase()))
         || file_name
@@ -179,11 +193,7 @@ pub fn fix_duplicate_task_ids(
                 continue;
             };
 
-            let new_id = if let Some((prefix, _num)) = parse_namespaced(&old_id) {
-                next_free_namespaced_id(&prefix, &used)
-            } else {
-                next_free_legacy_dup_id(&old_id, &used)
-            };
+            let new_id = next_free_task_id(&old_id, &used);
             used.insert(new_id.to_lowercase());
 
             let mut new_path = old_path.to_path_buf();
@@ -192,7 +202,7 @@ pub fn fix_duplicate_task_ids(
                 update_task_field(old_path, "id", Some(FieldValue::Scalar(new_id.clone())))?;
 
                 // Keep the filename aligned with the id.
-                new_path = rename_task_file(old_path, &old_id, &new_id)?;
+                new_path = rename_task_file(old_path, task, &new_id)?;
             }
 
             changes.push(FixIdsChange {
@@ -0,0 +1,55 @@
+//! One-way imports of existing backlogs from other trackers (`workmesh import --format ...`).
+
+pub mod jira;
+
+/// Split CSV text (RFC 4180: quoted fields, `""` escapes, line breaks inside quotes) into rows.
+/// Blank lines are dropped; a leading UTF-8 BOM is ignored.
+pub fn parse_csv(raw: &str) -> Vec<Vec<String>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = raw.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                field.push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => row.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|value| !value.is_empty()) {
+                    rows.push(std::mem::take(&mut row));
+                }
+                row.clear();
+            }
+            _ => field.push(ch),
+        }
+    }
+    row.push(field);
+    if row.iter().any(|value| !value.is_empty()) {
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_csv_handles_quotes_escapes_and_multiline_fields() {
+        let rows = parse_csv("\u{feff}a,b,c\r\n\"x, y\",\"say \"\"hi\"\"\",\"one\ntwo\"\n\n1,,3");
+        assert_eq!(
+            rows,
+            vec![
+                vec!["a", "b", "c"],
+                vec!["x, y", "say \"hi\"", "one\ntwo"],
+                vec!["1", "", "3"],
+            ]
+        );
+    }
+}
@@ -0,0 +1,599 @@
+//! `import --format jira-csv`: Jira's "Export CSV (all fields)" into task files.
+//!
+//! Epics become `kind: epic` documents, and both epic links and sub-task parents become
+//! `relationships.parent`, so `board --epic` and `tree` show the Jira hierarchy. "Blocks" issue
+//! links become dependencies. Every task records its `jira_key`; keys already in the backlog are
+//! reported as existing and left untouched, so re-importing a newer export only adds new issues.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::parse_csv;
+use crate::config::{load_config, load_global_config};
+use crate::health::first_extra;
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_epic_file, create_task_file_with_sections, set_relationship_field, update_task_field,
+    EpicSectionContent, FieldValue, TaskSectionContent,
+};
+use crate::timestamps::format_timestamp;
+
+/// Front matter key holding the Jira issue key (`PROJ-123`).
+pub const JIRA_KEY_FIELD: &str = "jira_key";
+/// Front matter key holding the Jira issue type (`Story`, `Bug`, `Sub-task`, ...).
+pub const JIRA_TYPE_FIELD: &str = "jira_type";
+/// Front matter key holding the issue's latest sprint.
+pub const SPRINT_FIELD: &str = "sprint";
+
+const DEFAULT_PHASE: &str = "Phase1";
+const DEFAULT_PRIORITY: &str = "P2";
+const DEFAULT_STATUS: &str = "To Do";
+
+#[derive(Debug, Error)]
+pub enum JiraError {
+    #[error("Invalid Jira CSV: {0}")]
+    Csv(String),
+    #[error("Failed to create task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to reserve task id: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// `[jira]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JiraConfig {
+    /// Jira status -> WorkMesh status, e.g. `"In Review" = "In Progress"`; matched
+    /// case-insensitively before the built-in workflow defaults.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub statuses: BTreeMap<String, String>,
+    /// Jira priority -> WorkMesh priority. Defaults: Highest `P0`, High `P1`, Medium `P2`,
+    /// Low `P3`, Lowest `P4`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub priorities: BTreeMap<String, String>,
+    /// Phase for imported tasks (default `Phase1`).
+    pub phase: Option<String>,
+}
+
+impl JiraConfig {
+    /// `None` when neither the config nor the defaults know the status.
+    fn import_status(&self, jira: &str) -> Option<String> {
+        if let Some((_, mapped)) = self
+            .statuses
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(jira.trim()))
+        {
+            return Some(mapped.clone());
+        }
+        let status = match jira.trim().to_lowercase().as_str() {
+            ""
+            | "to do"
+            | "todo"
+            | "open"
+            | "new"
+            | "backlog"
+            | "selected for development"
+            | "reopened" => "To Do",
+            "in progress" | "in review" | "in development" | "review" | "code review"
+            | "in testing" | "testing" | "qa" => "In Progress",
+            "blocked" | "on hold" => "Blocked",
+            "done" | "closed" | "resolved" | "complete" | "completed" => "Done",
+            "won't do" | "won't fix" | "cancelled" | "canceled" | "rejected" | "duplicate" => {
+                "Cancelled"
+            }
+            _ => return None,
+        };
+        Some(status.to_string())
+    }
+
+    fn import_priority(&self, jira: &str) -> String {
+        if let Some((_, mapped)) = self
+            .priorities
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(jira.trim()))
+        {
+            return mapped.clone();
+        }
+        match jira.trim().to_lowercase().as_str() {
+            "highest" | "blocker" => "P0",
+            "high" | "critical" | "major" => "P1",
+            "low" | "minor" => "P3",
+            "lowest" | "trivial" => "P4",
+            _ => DEFAULT_PRIORITY,
+        }
+        .to_string()
+    }
+}
+
+/// Project `[jira]` wins over global `[jira]`.
+pub fn resolve_jira_config(repo_root: &Path) -> JiraConfig {
+    load_config(repo_root)
+        .and_then(|config| config.jira)
+        .or_else(|| load_global_config().and_then(|config| config.jira))
+        .unwrap_or_default()
+}
+
+/// One row of a Jira CSV export. Repeated columns (labels, sprints, links) are collected.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct JiraIssue {
+    pub key: String,
+    /// Numeric id; newer exports reference parents by id.
+    pub id: Option<String>,
+    pub issue_type: String,
+    pub summary: String,
+    pub status: String,
+    pub priority: String,
+    pub assignee: Option<String>,
+    pub labels: Vec<String>,
+    pub sprints: Vec<String>,
+    /// Parent key or id (sub-tasks, and epics' children in team-managed projects).
+    pub parent: Option<String>,
+    /// Epic key from the classic "Epic Link" field.
+    pub epic_link: Option<String>,
+    /// Keys of the issues this one is blocked by ("Inward issue link (Blocks)").
+    pub blocked_by: Vec<String>,
+    /// Keys of the issues this one blocks ("Outward issue link (Blocks)").
+    pub blocks: Vec<String>,
+    pub description: String,
+    pub created: Option<String>,
+    pub updated: Option<String>,
+    pub due: Option<String>,
+    pub story_points: Option<String>,
+}
+
+impl JiraIssue {
+    pub fn is_epic(&self) -> bool {
+        self.issue_type.trim().eq_ignore_ascii_case("epic")
+    }
+}
+
+/// Parse a Jira CSV export. Column names are matched case-insensitively; only `Summary` and
+/// `Issue key` are required.
+pub fn parse_jira_csv(raw: &str) -> Result<Vec<JiraIssue>, JiraError> {
+    let mut rows = parse_csv(raw).into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(Vec::new());
+    };
+    let mut columns: HashMap<String, Vec<usize>> = HashMap::new();
+    for (idx, name) in header.iter().enumerate() {
+        columns
+            .entry(name.trim().to_lowercase())
+            .or_default()
+            .push(idx);
+    }
+    for required in ["summary", "issue key"] {
+        if !columns.contains_key(required) {
+            return Err(JiraError::Csv(format!("missing `{}` column", required)));
+        }
+    }
+    let issues = rows
+        .map(|row| {
+            let all = |names: &[&str]| -> Vec<String> {
+                names
+                    .iter()
+                    .filter_map(|name| columns.get(*name))
+                    .flatten()
+                    .filter_map(|idx| row.get(*idx))
+                    .map(|value| value.trim().to_string())
+                    .filter(|value| !value.is_empty())
+                    .collect()
+            };
+            let first = |names: &[&str]| all(names).into_iter().next();
+            JiraIssue {
+                key: first(&["issue key"]).unwrap_or_default(),
+                id: first(&["issue id"]),
+                issue_type: first(&["issue type"]).unwrap_or_default(),
+                summary: first(&["summary"]).unwrap_or_default(),
+                status: first(&["status"]).unwrap_or_default(),
+                priority: first(&["priority"]).unwrap_or_default(),
+                assignee: first(&["assignee"]),
+                labels: all(&["labels"]),
+                sprints: all(&["sprint"]),
+                parent: first(&["parent key", "parent", "parent id"]),
+                epic_link: first(&["custom field (epic link)", "epic link"]),
+                blocked_by: all(&["inward issue link (blocks)"]),
+                blocks: all(&["outward issue link (blocks)"]),
+                description: first(&["description"]).unwrap_or_default(),
+                created: first(&["created"]).and_then(|value| jira_timestamp(&value)),
+                updated: first(&["updated"]).and_then(|value| jira_timestamp(&value)),
+                due: first(&["due date", "due"]).and_then(|value| jira_date(&value)),
+                story_points: first(&[
+                    "custom field (story points)",
+                    "custom field (story point estimate)",
+                    "story points",
+                ]),
+            }
+        })
+        .collect();
+    Ok(issues)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct JiraImportItem {
+    pub key: String,
+    pub issue_type: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    /// Set once the task is written (`--apply`), or when it already exists in the backlog.
+    pub task_id: Option<String>,
+    pub task_path: Option<PathBuf>,
+    /// Epic or parent issue (task id once resolved, else the Jira key).
+    pub parent: Option<String>,
+    /// Blocking issues (task ids once resolved, else Jira keys).
+    pub dependencies: Vec<String>,
+    pub sprint: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct JiraImportReport {
+    pub apply: bool,
+    /// New tasks (created when `apply` is set).
+    pub imported: Vec<JiraImportItem>,
+    /// Issues whose key is already in the backlog; left untouched.
+    pub existing: Vec<JiraImportItem>,
+    /// Unknown statuses, rows without a key, and links to issues outside the export.
+    pub warnings: Vec<String>,
+}
+
+/// Import Jira issues. Dry-run unless `apply`; issues already imported (matched by `jira_key`)
+/// are reported as existing and never rewritten, so re-running is safe.
+///
+/// Mapping: summary -> title, issue type -> `jira_type` (epics become `kind: epic`), status and
+/// priority via `[jira]` or the defaults, labels -> labels, assignee -> assignee, last sprint ->
+/// `sprint`, story points -> `story_points`, epic link or parent -> `relationships.parent`,
+/// "Blocks" links -> dependencies, created/updated/due -> `created_date`/`updated_date`/`due_date`.
+pub fn import_jira(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    issues: &[JiraIssue],
+    config: &JiraConfig,
+    initiative: &str,
+    apply: bool,
+) -> Result<JiraImportReport, JiraError> {
+    let mut by_key: HashMap<String, String> = tasks
+        .iter()
+        .filter_map(|task| {
+            first_extra(task, &[JIRA_KEY_FIELD]).map(|key| (key.to_uppercase(), task.id.clone()))
+        })
+        .collect();
+    let key_for_id: HashMap<&str, &str> = issues
+        .iter()
+        .filter_map(|issue| Some((issue.id.as_deref()?, issue.key.as_str())))
+        .collect();
+    let resolve_ref = |reference: &str| -> String {
+        key_for_id
+            .get(reference)
+            .copied()
+            .unwrap_or(reference)
+            .to_uppercase()
+    };
+    let mut blocked_by: HashMap<String, Vec<String>> = HashMap::new();
+    for issue in issues {
+        let key = issue.key.to_uppercase();
+        for blocker in &issue.blocked_by {
+            blocked_by
+                .entry(key.clone())
+                .or_default()
+                .push(resolve_ref(blocker));
+        }
+        for blocked in &issue.blocks {
+            blocked_by
+                .entry(resolve_ref(blocked))
+                .or_default()
+                .push(key.clone());
+        }
+    }
+
+    let mut report = JiraImportReport {
+        apply,
+        ..JiraImportReport::default()
+    };
+    let mut pending: Vec<(&JiraIssue, JiraImportItem)> = Vec::new();
+    for issue in issues {
+        if issue.key.is_empty() {
+            report.warnings.push(format!(
+                "Skipped a row without an issue key: {}",
+                issue.summary
+            ));
+            continue;
+        }
+        let key = issue.key.to_uppercase();
+        let status = config.import_status(&issue.status).unwrap_or_else(|| {
+            report.warnings.push(format!(
+                "{}: unknown status '{}' imported as {} (map it under [jira] statuses)",
+                key, issue.status, DEFAULT_STATUS
+            ));
+            DEFAULT_STATUS.to_string()
+        });
+        let mut dependencies = blocked_by.remove(&key).unwrap_or_default();
+        dependencies.dedup();
+        let entry = JiraImportItem {
+            title: if issue.summary.is_empty() {
+                "(no title)".to_string()
+            } else {
+                issue.summary.clone()
+            },
+            issue_type: issue.issue_type.clone(),
+            status,
+            priority: config.import_priority(&issue.priority),
+            task_id: by_key.get(&key).cloned(),
+            task_path: None,
+            parent: issue
+                .epic_link
+                .as_deref()
+                .or(issue.parent.as_deref())
+                .map(&resolve_ref),
+            dependencies,
+            sprint: issue.sprints.last().cloned(),
+            key,
+        };
+        if entry.task_id.is_some() {
+            report.existing.push(entry);
+        } else {
+            pending.push((issue, entry));
+        }
+    }
+
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let phase = config.phase.as_deref().unwrap_or(DEFAULT_PHASE);
+    let mut known: Vec<Task> = tasks.to_vec();
+    if apply {
+        for (issue, entry) in pending.iter_mut() {
+            let task_id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+            let assignee: Vec<String> = issue.assignee.iter().cloned().collect();
+            let path = if issue.is_epic() {
+                create_epic_file(
+                    &tasks_dir,
+                    &task_id,
+                    &entry.title,
+                    &entry.status,
+                    &entry.priority,
+                    phase,
+                    &issue.labels,
+                    &assignee,
+                    &epic_sections(issue, &entry.key),
+                )?
+            } else {
+                create_task_file_with_sections(
+                    &tasks_dir,
+                    &task_id,
+                    &entry.title,
+                    &entry.status,
+                    &entry.priority,
+                    phase,
+                    &[],
+                    &issue.labels,
+                    &assignee,
+                    &task_sections(issue, &entry.key),
+                )?
+            };
+            write_import_fields(&path, issue, entry)?;
+            known.push(parse_task_file(&path)?);
+            by_key.insert(entry.key.clone(), task_id.clone());
+            entry.task_id = Some(task_id);
+            entry.task_path = Some(path);
+        }
+    }
+    // Links are resolved after every issue has an id so imports can point at each other. Keys
+    // outside the backlog and the export stay in the report but are never written.
+    let exported: HashMap<String, ()> = issues
+        .iter()
+        .map(|issue| (issue.key.to_uppercase(), ()))
+        .collect();
+    for (_, entry) in pending.iter_mut() {
+        let mut missing = Vec::new();
+        let mut resolve = |key: &String| match by_key.get(key) {
+            Some(task_id) => Some(task_id.clone()),
+            None => {
+                if !exported.contains_key(key) {
+                    missing.push(key.clone());
+                }
+                None
+            }
+        };
+        let parent = entry.parent.as_ref().and_then(&mut resolve);
+        let dependencies: Vec<String> =
+            entry.dependencies.iter().filter_map(&mut resolve).collect();
+        if let Some(path) = &entry.task_path {
+            if let Some(parent) = &parent {
+                set_relationship_field(path, "parent", vec![parent.clone()])?;
+            }
+            if !dependencies.is_empty() {
+                update_task_field(path, "dependencies", Some(FieldValue::List(dependencies)))?;
+            }
+        }
+        for key in missing {
+            report.warnings.push(format!(
+                "{}: links to {}, which is neither in the export nor in the backlog",
+                entry.key, key
+            ));
+        }
+        entry.parent = entry
+            .parent
+            .take()
+            .map(|key| by_key.get(&key).cloned().unwrap_or(key));
+        entry.dependencies = entry
+            .dependencies
+            .drain(..)
+            .map(|key| by_key.get(&key).cloned().unwrap_or(key))
+            .collect();
+    }
+    report.imported = pending.into_iter().map(|(_, entry)| entry).collect();
+    Ok(report)
+}
+
+fn description(issue: &JiraIssue, key: &str) -> String {
+    let body = issue.description.trim();
+    let body = if body.is_empty() {
+        issue.summary.trim()
+    } else {
+        body
+    };
+    format!("{}\n\nImported from Jira {}.", body, key)
+}
+
+fn task_sections(issue: &JiraIssue, key: &str) -> TaskSectionContent {
+    TaskSectionContent {
+        description: description(issue, key),
+        acceptance_criteria: format!("- {}", issue.summary.trim()),
+        definition_of_done: "- Outcome verified and task marked Done.".to_string(),
+    }
+}
+
+fn epic_sections(issue: &JiraIssue, key: &str) -> EpicSectionContent {
+    EpicSectionContent {
+        problem: description(issue, key),
+        outcomes: format!("- {}", issue.summary.trim()),
+        scope: format!("- Issues linked to {} in Jira.", key),
+        non_goals: "- Not recorded in Jira.".to_string(),
+    }
+}
+
+fn write_import_fields(
+    path: &Path,
+    issue: &JiraIssue,
+    entry: &JiraImportItem,
+) -> Result<(), TaskParseError> {
+    let mut fields: Vec<(&str, String)> = vec![(JIRA_KEY_FIELD, entry.key.clone())];
+    if !issue.issue_type.is_empty() {
+        fields.push((JIRA_TYPE_FIELD, issue.issue_type.clone()));
+    }
+    let optional = [
+        (SPRINT_FIELD, entry.sprint.clone()),
+        ("story_points", issue.story_points.clone()),
+        ("created_date", issue.created.clone()),
+        ("updated_date", issue.updated.clone()),
+        ("due_date", issue.due.clone()),
+    ];
+    fields.extend(
+        optional
+            .into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value))),
+    );
+    for (key, value) in fields {
+        update_task_field(path, key, Some(FieldValue::Scalar(value)))?;
+    }
+    Ok(())
+}
+
+/// Jira's export formats (`12/Mar/24 10:15 AM`, ISO) -> a stored RFC3339 timestamp. ISO values
+/// with an offset keep their instant; the others are the exporter's local time.
+fn jira_timestamp(value: &str) -> Option<String> {
+    const FORMATS: [&str; 4] = [
+        "%d/%b/%y %I:%M %p",
+        "%d/%b/%Y %I:%M %p",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+    ];
+    if let Ok(time) = DateTime::parse_from_str(value.trim(), "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(format_timestamp(time));
+    }
+    FORMATS
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value.trim(), format).ok())
+        .and_then(|date| Local.from_local_datetime(&date).earliest())
+        .map(format_timestamp)
+        .or_else(|| jira_date(value))
+}
+
+/// `12/Mar/24` (or any timestamp form) -> `2024-03-12`.
+fn jira_date(value: &str) -> Option<String> {
+    let value = value.trim();
+    ["%d/%b/%y", "%d/%b/%Y", "%Y-%m-%d"]
+        .iter()
+        .find_map(|format| NaiveDate::parse_from_str(value, format).ok())
+        .or_else(|| {
+            let (date, _) = value.split_once(' ')?;
+            ["%d/%b/%y", "%d/%b/%Y", "%Y-%m-%d"]
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(date, format).ok())
+        })
+        .map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+    use crate::task_ops::is_epic;
+    use crate::timestamps::TimeDisplay;
+
+    const EXPORT: &str = "Summary,Issue key,Issue id,Issue Type,Status,Priority,Assignee,\
+Labels,Labels,Sprint,Sprint,Custom field (Epic Link),Parent,Inward issue link (Blocks),Created,\
+Description\n\
+Checkout revamp,SHOP-1,1001,Epic,In Progress,High,,web,,,,,,,01/Mar/24 9:05 AM,Rebuild checkout\n\
+Card form,SHOP-2,1002,Story,To Do,Medium,Jane Doe,web,payments,Sprint 3,Sprint 4,SHOP-1,,SHOP-3,\
+02/Mar/24 2:30 PM,\"Fields:\n- number\"\n\
+Tokenize cards,SHOP-3,1003,Story,In Review,Highest,,,,Sprint 4,,SHOP-1,,SHOP-99,,\n\
+Validate CVC,SHOP-4,1004,Sub-task,Triage,Low,,,,,,,1002,,,\n";
+
+    #[test]
+    fn jira_csv_import_preserves_hierarchy_and_skips_known_keys() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        fs::create_dir_all(backlog.join("tasks")).expect("tasks");
+        let issues = parse_jira_csv(EXPORT).expect("parse");
+        assert_eq!(issues.len(), 4);
+        assert_eq!(issues[1].labels, vec!["web", "payments"]);
+        let created = issues[1].created.as_deref().expect("created");
+        assert_eq!(TimeDisplay::Local.render(created), "2024-03-02 14:30");
+
+        let config = JiraConfig::default();
+        let dry = import_jira(&backlog, &[], &issues, &config, "shop", false).expect("dry");
+        assert_eq!(dry.imported.len(), 4);
+        assert!(load_tasks(&backlog).is_empty());
+        assert!(dry.warnings.iter().any(|w| w.contains("'Triage'")));
+        assert!(dry.warnings.iter().any(|w| w.contains("SHOP-99")));
+
+        let report = import_jira(&backlog, &[], &issues, &config, "shop", true).expect("apply");
+        let ids: Vec<&str> = report
+            .imported
+            .iter()
+            .filter_map(|item| item.task_id.as_deref())
+            .collect();
+        assert_eq!(
+            ids,
+            vec![
+                "task-shop-001",
+                "task-shop-002",
+                "task-shop-003",
+                "task-shop-004"
+            ]
+        );
+        let tasks = load_tasks(&backlog);
+        let task = |id: &str| tasks.iter().find(|task| task.id == id).expect("task");
+        assert!(is_epic(task("task-shop-001")));
+        let card = task("task-shop-002");
+        assert_eq!(card.relationships.parent, vec!["task-shop-001"]);
+        assert_eq!(card.dependencies, vec!["task-shop-003"]);
+        assert_eq!(card.assignee, vec!["Jane Doe"]);
+        assert_eq!(
+            first_extra(card, &[SPRINT_FIELD]).as_deref(),
+            Some("Sprint 4")
+        );
+        assert!(card.body.contains("- number"));
+        let tokenize = task("task-shop-003");
+        assert_eq!(
+            (tokenize.status.as_str(), tokenize.priority.as_str()),
+            ("In Progress", "P0")
+        );
+        assert!(tokenize.dependencies.is_empty());
+        assert_eq!(
+            task("task-shop-004").relationships.parent,
+            vec!["task-shop-002"]
+        );
+
+        let again = import_jira(&backlog, &tasks, &issues, &config, "shop", true).expect("again");
+        assert!(again.imported.is_empty());
+        assert_eq!(again.existing.len(), 4);
+    }
+}
@@ -0,0 +1,567 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::mentions::event_mentions;
+use crate::storage::{read_modify_write_json, StorageError};
+use crate::task::{parse_list_value, Task};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InboxKind {
+    Assigned,
+    ReviewRequested,
+    QuestionAnswered,
+    BlockerCleared,
+    Mentioned,
+    /// Status change, comment, or new blocker on a task the user watches.
+    Watched,
+}
+
+impl InboxKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            InboxKind::Assigned => "assigned",
+            InboxKind::ReviewRequested => "review_requested",
+            InboxKind::QuestionAnswered => "question_answered",
+            InboxKind::BlockerCleared => "blocker_cleared",
+            InboxKind::Mentioned => "mentioned",
+            InboxKind::Watched => "watched",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InboxItem {
+    /// Stable id (derived from the audit log position) used for read tracking.
+    pub id: String,
+    pub kind: InboxKind,
+    pub task_id: String,
+    pub timestamp: String,
+    pub actor: Option<String>,
+    pub summary: String,
+    pub read: bool,
+    #[serde(skip)]
+    pub event_index: usize,
+}
+
+/// Per-user read state stored at `<state_root>/.inbox/<user>.json`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InboxState {
+    /// Item ids explicitly marked read.
+    #[serde(default)]
+    pub read: BTreeSet<String>,
+    /// Every item produced by the first `read_through` audit events is read.
+    #[serde(default)]
+    pub read_through: usize,
+}
+
+pub fn inbox_dir(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join(".inbox")
+}
+
+pub fn inbox_state_path(backlog_dir: &Path, user: &str) -> PathBuf {
+    let safe: String = normalize_user(user)
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    inbox_dir(backlog_dir).join(format!("{}.json", safe))
+}
+
+/// Task front-matter list of users subscribed to a task's changes.
+pub const WATCHERS_FIELD: &str = "watchers";
+
+/// Normalized watchers of `task`.
+pub fn task_watchers(task: &Task) -> Vec<String> {
+    parse_list_value(task.extra.get(WATCHERS_FIELD))
+        .iter()
+        .map(|entry| normalize_user(entry))
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+/// Users are matched case-insensitively, with or without a leading `@`.
+pub fn normalize_user(value: &str) -> String {
+    value.trim().trim_start_matches('@').trim().to_lowercase()
+}
+
+pub fn load_inbox_state(backlog_dir: &Path, user: &str) -> InboxState {
+    std::fs::read_to_string(inbox_state_path(backlog_dir, user))
+        .ok()
+        .and_then(|raw| serde_json::from_str(&raw).ok())
+        .unwrap_or_default()
+}
+
+/// Inbox items for `user`, newest first, computed from the audit log and current task state.
+pub fn build_inbox(backlog_dir: &Path, user: &str, tasks: &[Task]) -> Vec<InboxItem> {
+    let events = read_audit_events(backlog_dir);
+    let state = load_inbox_state(backlog_dir, user);
+    let mut items = inbox_items(&events, user, tasks);
+    for item in &mut items {
+        item.read = item.event_index < state.read_through || state.read.contains(&item.id);
+    }
+    items.reverse();
+    items
+}
+
+pub fn mark_inbox_read(
+    backlog_dir: &Path,
+    user: &str,
+    ids: &[String],
+) -> Result<InboxState, StorageError> {
+    read_modify_write_json(&inbox_state_path(backlog_dir, user), |current| {
+        let mut state: InboxState = current.unwrap_or_default();
+        state
+            .read
+            .extend(ids.iter().map(|id| id.trim().to_string()));
+        Ok(state)
+    })
+}
+
+pub fn mark_inbox_all_read(backlog_dir: &Path, user: &str) -> Result<InboxState, StorageError> {
+    let consumed = read_audit_events(backlog_dir).len();
+    read_modify_write_json(&inbox_state_path(backlog_dir, user), |_| {
+        Ok(InboxState {
+            read: BTreeSet::new(),
+            read_through: consumed,
+        })
+    })
+}
+
+fn inbox_items(events: &[AuditEvent], user: &str, tasks: &[Task]) -> Vec<InboxItem> {
+    let user = normalize_user(user);
+    let tasks_by_id: HashMap<String, &Task> =
+        tasks.iter().map(|t| (t.id.to_lowercase(), t)).collect();
+    let mut asked: HashSet<String> = HashSet::new();
+    let mut items = Vec::new();
+
+    for (index, event) in events.iter().enumerate() {
+        let Some(task_id) = event.task_id.as_deref() else {
+            continue;
+        };
+        let task_lc = task_id.to_lowercase();
+        let from_self = event
+            .actor
+            .as_deref()
+            .map(|actor| normalize_user(actor) == user)
+            .unwrap_or(false);
+        let before = items.len();
+        let mut push = |kind: InboxKind, item_task: &str, summary: String| {
+            items.push(InboxItem {
+                id: item_id(index, event, kind, item_task),
+                kind,
+                task_id: item_task.to_string(),
+                timestamp: event.timestamp.clone(),
+                actor: event.actor.clone(),
+                summary,
+                read: false,
+                event_index: index,
+            });
+        };
+
+        match event.action.as_str() {
+            "set_field" | "bulk_set_field" if !from_self => {
+                let field = detail_str(&event.details, "field").to_lowercase();
+                let value = detail_str(&event.details, "value");
+                if !list_contains_user(&value, &user) {
+                    // Not addressed to the user; watchers may still hear about it below.
+                } else if matches!(field.as_str(), "assignee" | "assignees") {
+                    push(
+                        InboxKind::Assigned,
+                        task_id,
+                        format!("{} assigned to you", task_id),
+                    );
+                } else if matches!(field.as_str(), "reviewer" | "reviewers") {
+                    push(
+                        InboxKind::ReviewRequested,
+                        task_id,
+                        format!("Review requested on {}", task_id),
+                    );
+                }
+            }
+            "set_status" | "bulk_set_status" => {
+                let status = detail_str(&event.details, "status");
+                let lowered = status.to_lowercase();
+                if lowered.contains("review") && !from_self {
+                    let is_reviewer = tasks_by_id
+                        .get(&task_lc)
+                        .map(|task| task_reviewers(task).contains(&user))
+                        .unwrap_or(false);
+                    if is_reviewer {
+                        push(
+                            InboxKind::ReviewRequested,
+                            task_id,
+                            format!("{} moved to {}", task_id, status),
+                        );
+                    }
+                }
+                if lowered == "done" {
+                    for task in tasks {
+                        if !is_assigned_to(task, &user) || task.status.eq_ignore_ascii_case("done")
+                        {
+                            continue;
+                        }
+                        let blocked = task
+                            .dependencies
+                            .iter()
+                            .chain(task.relationships.blocked_by.iter())
+                            .any(|dep| dep.eq_ignore_ascii_case(task_id));
+                        if blocked {
+                            push(
+                                InboxKind::BlockerCleared,
+                                &task.id,
+                                format!("Blocker {} on {} is Done", task_id, task.id),
+                            );
+                        }
+                    }
+                }
+            }
+            "note" | "bulk_note" => {
+                let note = detail_str(&event.details, "note");
+                if from_self {
+                    if note.contains('?') {
+                        asked.insert(task_lc.clone());
+                    }
+                } else if event_mentions(event).contains(&user) {
+                    asked.remove(&task_lc);
+                    push(
+                        InboxKind::Mentioned,
+                        task_id,
+                        format!("Mentioned on {}: {}", task_id, truncate(&note, 80)),
+                    );
+                } else if asked.remove(&task_lc) {
+                    push(
+                        InboxKind::QuestionAnswered,
+                        task_id,
+                        format!("Reply on {}: {}", task_id, truncate(&note, 80)),
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        // Watchers hear about changes nothing more specific already reported.
+        let watching = tasks_by_id
+            .get(&task_lc)
+            .is_some_and(|task| task_watchers(task).contains(&user));
+        if watching && !from_self && items.len() == before {
+            if let Some(summary) = watch_summary(event, task_id) {
+                items.push(InboxItem {
+                    id: item_id(index, event, InboxKind::Watched, task_id),
+                    kind: InboxKind::Watched,
+                    task_id: task_id.to_string(),
+                    timestamp: event.timestamp.clone(),
+                    actor: event.actor.clone(),
+                    summary,
+                    read: false,
+                    event_index: index,
+                });
+            }
+        }
+    }
+    items
+}
+
+/// What a watcher is told about `event`; `None` for changes watchers do not follow.
+fn watch_summary(event: &AuditEvent, task_id: &str) -> Option<String> {
+    match event.action.as_str() {
+        "set_status" | "bulk_set_status" => {
+            let status = detail_str(&event.details, "status");
+            if status.trim().eq_ignore_ascii_case("blocked") {
+                Some(format!("{} is now blocked", task_id))
+            } else {
+                Some(format!("{} moved to {}", task_id, status))
+            }
+        }
+        "note" | "bulk_note" => Some(format!(
+            "Comment on {}: {}",
+            task_id,
+            truncate(&detail_str(&event.details, "note"), 80)
+        )),
+        "set_field" | "bulk_set_field" => {
+            let field = detail_str(&event.details, "field").to_lowercase();
+            let value = detail_str(&event.details, "value");
+            let gained_blockers = matches!(field.as_str(), "dependencies" | "blocked_by")
+                && !parse_list_value(Some(&serde_yaml::Value::String(value.clone()))).is_empty();
+            gained_blockers.then(|| format!("{} is now blocked by {}", task_id, value))
+        }
+        _ => None,
+    }
+}
+
+fn item_id(index: usize, event: &AuditEvent, kind: InboxKind, task_id: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(index.to_string().as_bytes());
+    hasher.update(b"|");
+    hasher.update(event.timestamp.as_bytes());
+    hasher.update(b"|");
+    hasher.update(kind.as_str().as_bytes());
+    hasher.update(b"|");
+    hasher.update(task_id.to_lowercase().as_bytes());
+    let digest = format!("{:x}", hasher.finalize());
+    digest[..12].to_string()
+}
+
+fn detail_str(details: &Value, key: &str) -> String {
+    match details.get(key) {
+        Some(Value::String(value)) => value.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(other) => other.to_string(),
+    }
+}
+
+fn list_contains_user(raw: &str, user: &str) -> bool {
+    parse_list_value(Some(&serde_yaml::Value::String(raw.to_string())))
+        .iter()
+        .any(|entry| normalize_user(entry) == user)
+}
+
+fn is_assigned_to(task: &Task, user: &str) -> bool {
+    task.assignee
+        .iter()
+        .any(|entry| normalize_user(entry) == user)
+}
+
+fn task_reviewers(task: &Task) -> Vec<String> {
+    ["reviewer", "reviewers"]
+        .iter()
+        .flat_map(|key| parse_list_value(task.extra.get(*key)))
+        .map(|entry| normalize_user(&entry))
+        .collect()
+}
+
+fn truncate(value: &str, max: usize) -> String {
+    let trimmed = value.trim();
+    if trimmed.chars().count() <= max {
+        return trimmed.to_string();
+    }
+    let mut out: String = trimmed.chars().take(max).collect();
+    out.push('…');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::append_audit_event;
+    use crate::task::load_tasks;
+    use crate::task_ops::{create_task_file, update_task_field, FieldValue};
+    use tempfile::TempDir;
+
+    fn event(actor: &str, action: &str, task_id: &str, details: Value) -> AuditEvent {
+        AuditEvent {
+            timestamp: "2026-01-01 10:00".to_string(),
+            actor: Some(actor.to_string()),
+            action: action.to_string(),
+            task_id: Some(task_id.to_string()),
+            details,
+        }
+    }
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        std::fs::create_dir_all(&tasks_dir).expect("tasks");
+        create_task_file(
+            &tasks_dir,
+            "task-001",
+            "Blocker",
+            "Done",
+            "P2",
+            "Phase1",
+            &[],
+            &[],
+            &[],
+        )
+        .expect("blocker");
+        let path = create_task_file(
+            &tasks_dir,
+            "task-002",
+            "Mine",
+            "To Do",
+            "P2",
+            "Phase1",
+            &["task-001".to_string()],
+            &[],
+            &["alice".to_string()],
+        )
+        .expect("mine");
+        update_task_field(
+            &path,
+            "reviewers",
+            Some(FieldValue::List(vec!["alice".to_string()])),
+        )
+        .expect("reviewers");
+
+        let events = [
+            event(
+                "bob",
+                "set_field",
+                "task-002",
+                serde_json::json!({"field": "assignee", "value": "[alice]"}),
+            ),
+            event(
+                "alice",
+                "set_field",
+                "task-002",
+                serde_json::json!({"field": "assignee", "value": "alice"}),
+            ),
+            event(
+                "bob",
+                "set_status",
+                "task-002",
+                serde_json::json!({"status": "In Review"}),
+            ),
+            event(
+                "alice",
+                "note",
+                "task-002",
+                serde_json::json!({"note": "Which API?"}),
+            ),
+            event(
+                "bob",
+                "note",
+                "task-002",
+                serde_json::json!({"note": "Use v2"}),
+            ),
+            event(
+                "bob",
+                "set_status",
+                "task-001",
+                serde_json::json!({"status": "Done"}),
+            ),
+        ];
+        for event in &events {
+            append_audit_event(&backlog, event).expect("audit");
+        }
+        (temp, backlog)
+    }
+
+    #[test]
+    fn inbox_collects_relevant_events_newest_first() {
+        let (_temp, backlog) = setup();
+        let tasks = load_tasks(&backlog);
+        let items = build_inbox(&backlog, "@Alice", &tasks);
+        let kinds: Vec<_> = items.iter().map(|item| item.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                InboxKind::BlockerCleared,
+                InboxKind::QuestionAnswered,
+                InboxKind::ReviewRequested,
+                InboxKind::Assigned,
+            ]
+        );
+        assert_eq!(items[0].task_id, "task-002");
+        assert!(items.iter().all(|item| !item.read));
+        assert!(build_inbox(&backlog, "carol", &tasks).is_empty());
+    }
+
+    #[test]
+    fn watchers_hear_about_status_comments_and_new_blockers() {
+        let (_temp, backlog) = setup();
+        let path = load_tasks(&backlog)
+            .into_iter()
+            .find(|task| task.id == "task-001")
+            .and_then(|task| task.file_path)
+            .expect("task-001");
+        update_task_field(
+            &path,
+            WATCHERS_FIELD,
+            Some(FieldValue::List(vec!["@Carol".to_string()])),
+        )
+        .expect("watchers");
+        for event in [
+            event(
+                "carol",
+                "note",
+                "task-001",
+                serde_json::json!({"note": "mine"}),
+            ),
+            event(
+                "bob",
+                "note",
+                "task-001",
+                serde_json::json!({"note": "Looks good"}),
+            ),
+            event(
+                "bob",
+                "set_field",
+                "task-001",
+                serde_json::json!({"field": "dependencies", "value": "[task-002]"}),
+            ),
+            event(
+                "bob",
+                "set_field",
+                "task-001",
+                serde_json::json!({"field": "phase"}),
+            ),
+        ] {
+            append_audit_event(&backlog, &event).expect("audit");
+        }
+
+        let tasks = load_tasks(&backlog);
+        assert_eq!(task_watchers(&tasks[0]), vec!["carol".to_string()]);
+        let summaries: Vec<String> = build_inbox(&backlog, "carol", &tasks)
+            .into_iter()
+            .inspect(|item| assert_eq!(item.kind, InboxKind::Watched))
+            .map(|item| item.summary)
+            .collect();
+        assert_eq!(
+            summaries,
+            vec![
+                "task-001 is now blocked by [task-002]".to_string(),
+                "Comment on task-001: Looks good".to_string(),
+                "task-001 moved to Done".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn inbox_read_tracking_persists_under_state_root() {
+        let (_temp, backlog) = setup();
+        let tasks = load_tasks(&backlog);
+        let items = build_inbox(&backlog, "alice", &tasks);
+        mark_inbox_read(&backlog, "alice", &[items[0].id.clone()]).expect("mark");
+        assert!(inbox_state_path(&backlog, "alice").starts_with(backlog.join(".inbox")));
+
+        let items = build_inbox(&backlog, "alice", &tasks);
+        assert!(items[0].read);
+        assert!(!items[1].read);
+
+        mark_inbox_all_read(&backlog, "alice").expect("mark all");
+        assert!(build_inbox(&backlog, "alice", &tasks)
+            .iter()
+            .all(|item| item.read));
+    }
+
+    #[test]
+    fn inbox_routes_mentions_from_other_actors() {
+        let (_temp, backlog) = setup();
+        append_audit_event(
+            &backlog,
+            &event(
+                "bob",
+                "note",
+                "task-001",
+                serde_json::json!({"note": "@alice FYI"}),
+            ),
+        )
+        .expect("audit");
+        let tasks = load_tasks(&backlog);
+        let items = build_inbox(&backlog, "alice", &tasks);
+        assert_eq!(items[0].kind, InboxKind::Mentioned);
+        assert_eq!(items[0].task_id, "task-001");
+    }
+}
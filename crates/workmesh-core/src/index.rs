@@ -8,11 +8,12 @@ use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use thiserror::Error;
 
+use crate::progress::{tick, ProgressEvent};
 use crate::project::repo_root_from_backlog;
 use crate::storage::{
     atomic_write_text, with_resource_lock, ResourceKey, StorageError, DEFAULT_LOCK_TIMEOUT,
 };
-use crate::task::{load_tasks, Task};
+use crate::task::{archive_root_for_root, load_tasks, parse_task_file, tasks_dir_for_root, Task};
 
 #[derive(Debug, Error)]
 pub enum IndexError {
@@ -57,6 +58,24 @@ pub struct RelationshipsIndex {
 pub struct IndexSummary {
     pub path: String,
     pub entries: usize,
+    /// Set by [`refresh_index`].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<IndexRefreshStats>,
+}
+
+/// How much of the backlog [`refresh_index`] had to read.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct IndexRefreshStats {
+    /// Task files found in the tasks directory.
+    pub scanned: usize,
+    /// Same mtime as indexed: entry reused without opening the file.
+    pub skipped: usize,
+    /// Hashed but unchanged (mtime moved, or too recent to trust): entry reused.
+    pub rehashed: usize,
+    /// New or edited files that were parsed again.
+    pub reparsed: usize,
+    /// Entries dropped because their file is gone or no longer parses.
+    pub removed: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -75,53 +94,317 @@ pub fn index_path(backlog_dir: &Path) -> PathBuf {
     index_dir(backlog_dir).join("tasks.jsonl")
 }
 
+/// Archived tasks live outside `tasks.jsonl`; this index keeps them searchable without parsing
+/// every archived file.
+pub fn archive_index_path(backlog_dir: &Path) -> PathBuf {
+    index_dir(backlog_dir).join("archive.jsonl")
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ArchiveIndexEntry {
+    pub id: String,
+    pub uid: Option<String>,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    pub labels: Vec<String>,
+    pub assignee: Vec<String>,
+    pub updated_date: Option<String>,
+    /// Path relative to the archive root, `/`-separated.
+    pub path: String,
+    pub mtime: i64,
+    /// Lowercased title and body, matched by `archive search` like `list --search`.
+    pub text: String,
+}
+
+/// Bring the archive index in line with the archive directory and return its entries.
+///
+/// Only files whose mtime changed (or that are new) are parsed; the index is rewritten only when
+/// something changed.
+pub fn refresh_archive_index(backlog_dir: &Path) -> Result<Vec<ArchiveIndexEntry>, IndexError> {
+    let archive_root = archive_root_for_root(backlog_dir);
+    let path = archive_index_path(backlog_dir);
+    let existing: HashMap<String, ArchiveIndexEntry> = if path.exists() {
+        read_jsonl::<ArchiveIndexEntry>(&path)?
+            .into_iter()
+            .map(|entry| (entry.path.clone(), entry))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let mut files = Vec::new();
+    collect_markdown_files(&archive_root, &mut files);
+    let mut changed = !path.exists() || files.len() != existing.len();
+    let mut entries = Vec::with_capacity(files.len());
+    for file in files {
+        let rel = file
+            .strip_prefix(&archive_root)
+            .unwrap_or(&file)
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mtime = file_mtime(&file)?;
+        if let Some(entry) = existing.get(&rel).filter(|entry| entry.mtime == mtime) {
+            entries.push(entry.clone());
+            continue;
+        }
+        changed = true;
+        let Ok(task) = parse_task_file(&file) else {
+            continue;
+        };
+        entries.push(build_archive_entry(&task, rel, mtime));
+    }
+    entries.sort_by(|a, b| (&a.id, &a.path).cmp(&(&b.id, &b.path)));
+
+    if changed {
+        let lines = entries
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<_>, _>>()?;
+        let payload = lines
+            .iter()
+            .map(|line| format!("{}\n", line))
+            .collect::<String>();
+        let key = ResourceKey::repo_local(backlog_dir, "index.archive");
+        with_resource_lock(&key, DEFAULT_LOCK_TIMEOUT, || {
+            atomic_write_text(&path, &payload)?;
+            Ok(())
+        })?;
+    }
+    Ok(entries)
+}
+
+/// Look up an archived task by id through the archive index, parsing only its file.
+pub fn find_archived_task(backlog_dir: &Path, task_id: &str) -> Result<Option<Task>, IndexError> {
+    let target = task_id.trim().to_lowercase();
+    let archive_root = archive_root_for_root(backlog_dir);
+    let entry = refresh_archive_index(backlog_dir)?
+        .into_iter()
+        .find(|entry| entry.id.to_lowercase() == target);
+    Ok(entry.and_then(|entry| parse_task_file(&archive_root.join(&entry.path)).ok()))
+}
+
+/// Archived tasks whose title or body contains `query` (case-insensitive).
+pub fn search_archive(
+    backlog_dir: &Path,
+    query: &str,
+) -> Result<Vec<ArchiveIndexEntry>, IndexError> {
+    let needle = query.trim().to_lowercase();
+    Ok(refresh_archive_index(backlog_dir)?
+        .into_iter()
+        .filter(|entry| entry.text.contains(&needle) || entry.id.to_lowercase() == needle)
+        .collect())
+}
+
+fn build_archive_entry(task: &Task, rel_path: String, mtime: i64) -> ArchiveIndexEntry {
+    ArchiveIndexEntry {
+        id: task.id.clone(),
+        uid: task.uid.clone(),
+        title: task.title.clone(),
+        status: task.status.clone(),
+        priority: task.priority.clone(),
+        labels: task.labels.clone(),
+        assignee: task.assignee.clone(),
+        updated_date: task.updated_date.clone(),
+        path: rel_path,
+        mtime,
+        text: format!("{}\n{}", task.title, task.body).to_lowercase(),
+    }
+}
+
+fn collect_markdown_files(dir: &Path, out: &mut Vec<PathBuf>) {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.filter_map(|entry| entry.ok()) {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_markdown_files(&path, out);
+        } else if path.extension().map(|ext| ext == "md").unwrap_or(false) {
+            out.push(path);
+        }
+    }
+}
+
 pub fn rebuild_index(backlog_dir: &Path) -> Result<IndexSummary, IndexError> {
-    let entries = build_entries(backlog_dir)?;
+    rebuild_index_with_progress(backlog_dir, |_| {})
+}
+
+/// [`rebuild_index`], reporting the `scan` phase (one step per task file) and the `write` phase.
+pub fn rebuild_index_with_progress<F>(
+    backlog_dir: &Path,
+    mut on_progress: F,
+) -> Result<IndexSummary, IndexError>
+where
+    F: FnMut(&ProgressEvent),
+{
+    let entries = build_entries(backlog_dir, &mut on_progress)?;
     let path = index_path(backlog_dir);
     write_index(backlog_dir, &path, &entries)?;
+    tick(&mut on_progress, "index_rebuild", "write", 1, 1);
     Ok(IndexSummary {
         path: path.to_string_lossy().to_string(),
         entries: entries.len(),
+        stats: None,
     })
 }
 
+/// Bring the index in line with the tasks directory, reparsing only what changed.
+///
+/// Files whose mtime matches their entry (and predates the index) are not opened; other files are
+/// hashed and parsed only when the content differs. Nothing is written when the index is already current.
 pub fn refresh_index(backlog_dir: &Path) -> Result<IndexSummary, IndexError> {
     let path = index_path(backlog_dir);
     if !path.exists() {
-        return rebuild_index(backlog_dir);
+        let mut summary = rebuild_index(backlog_dir)?;
+        summary.stats = Some(IndexRefreshStats {
+            scanned: summary.entries,
+            reparsed: summary.entries,
+            ..IndexRefreshStats::default()
+        });
+        return Ok(summary);
     }
-    let mut entries = read_index(&path)?;
-    let mut entry_map: HashMap<String, IndexEntry> = entries
-        .drain(..)
+    let mut indexed: HashMap<String, IndexEntry> = read_index(&path)?
+        .into_iter()
         .map(|entry| (entry.path.clone(), entry))
         .collect();
 
-    let tasks = load_tasks(backlog_dir);
-    let mut seen = HashSet::new();
+    // Like git's racy-clean check: on filesystems with coarse mtimes, a file edited right after
+    // the index was written can keep the indexed mtime, so only older files are trusted.
+    let index_mtime = file_mtime(&path)?;
     let repo_root = repo_root_from_backlog(backlog_dir);
-    for task in tasks {
-        let Some(task_path) = task.file_path.as_ref() else {
+    let mut stats = IndexRefreshStats::default();
+    let mut dirty = false;
+    let mut entries = Vec::new();
+    for task_path in task_file_paths(&tasks_dir_for_root(backlog_dir)) {
+        stats.scanned += 1;
+        let rel = normalize_rel_path(&repo_root, backlog_dir, &task_path);
+        let mtime = file_mtime(&task_path)?;
+        let previous = indexed.remove(&rel);
+        if let Some(entry) = previous
+            .as_ref()
+            .filter(|entry| entry.mtime == mtime && mtime < index_mtime)
+        {
+            stats.skipped += 1;
+            entries.push(entry.clone());
             continue;
-        };
-        let mtime = file_mtime(task_path)?;
-        let hash = hash_file(task_path)?;
-        let rel = normalize_rel_path(&repo_root, backlog_dir, task_path);
-        let updated = build_entry(&task, rel.clone(), mtime, hash);
-        entry_map.insert(rel.clone(), updated);
-        seen.insert(rel);
+        }
+        let hash = hash_file(&task_path)?;
+        if let Some(entry) = previous.as_ref().filter(|entry| entry.hash == hash) {
+            stats.rehashed += 1;
+            dirty |= entry.mtime != mtime;
+            entries.push(IndexEntry {
+                mtime,
+                ..entry.clone()
+            });
+            continue;
+        }
+        match parse_task_file(&task_path) {
+            Ok(task) => {
+                stats.reparsed += 1;
+                entries.push(build_entry(&task, rel, mtime, hash));
+            }
+            Err(_) if previous.is_some() => stats.removed += 1,
+            Err(_) => {}
+        }
     }
+    stats.removed += indexed.len();
 
-    entry_map.retain(|key, _| seen.contains(key));
-    let mut updated_entries: Vec<IndexEntry> = entry_map.into_values().collect();
-    sort_entries(&mut updated_entries);
-    write_index(backlog_dir, &path, &updated_entries)?;
+    if dirty || stats.reparsed + stats.removed > 0 {
+        sort_entries(&mut entries);
+        write_index(backlog_dir, &path, &entries)?;
+    }
 
     Ok(IndexSummary {
         path: path.to_string_lossy().to_string(),
-        entries: updated_entries.len(),
+        entries: entries.len(),
+        stats: Some(stats),
     })
 }
 
+/// Task files `load_tasks` would read, in the same order.
+fn task_file_paths(tasks_dir: &Path) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = match fs::read_dir(tasks_dir) {
+        Ok(read_dir) => read_dir
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().map(|ext| ext == "md").unwrap_or(false))
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    paths.sort();
+    paths
+}
+
+/// What [`apply_index_changes`] did to one task file's entry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IndexChange {
+    /// `added`, `modified`, or `removed`.
+    pub event: &'static str,
+    pub id: String,
+    /// Repo-relative path, as stored in the index.
+    pub path: String,
+}
+
+/// Update the index entries of just `paths` (task files created, edited, or deleted since the
+/// last refresh) instead of reparsing the whole backlog. A file whose content hash is unchanged
+/// is skipped; a file that no longer exists or parses drops out of the index.
+pub fn apply_index_changes(
+    backlog_dir: &Path,
+    paths: &[PathBuf],
+) -> Result<Vec<IndexChange>, IndexError> {
+    let path = index_path(backlog_dir);
+    if !path.exists() {
+        rebuild_index(backlog_dir)?;
+    }
+    let mut entry_map: HashMap<String, IndexEntry> = read_index(&path)?
+        .into_iter()
+        .map(|entry| (entry.path.clone(), entry))
+        .collect();
+
+    let repo_root = repo_root_from_backlog(backlog_dir);
+    let mut changes = Vec::new();
+    for task_path in paths {
+        let rel = normalize_rel_path(&repo_root, backlog_dir, task_path);
+        let parsed = if task_path.is_file() {
+            parse_task_file(task_path).ok()
+        } else {
+            None
+        };
+        let Some(task) = parsed else {
+            if let Some(old) = entry_map.remove(&rel) {
+                changes.push(IndexChange {
+                    event: "removed",
+                    id: old.id,
+                    path: rel,
+                });
+            }
+            continue;
+        };
+        let hash = hash_file(task_path)?;
+        let event = match entry_map.get(&rel) {
+            Some(old) if old.hash == hash => continue,
+            Some(_) => "modified",
+            None => "added",
+        };
+        let entry = build_entry(&task, rel.clone(), file_mtime(task_path)?, hash);
+        changes.push(IndexChange {
+            event,
+            id: entry.id.clone(),
+            path: rel.clone(),
+        });
+        entry_map.insert(rel, entry);
+    }
+
+    if !changes.is_empty() {
+        let mut entries: Vec<IndexEntry> = entry_map.into_values().collect();
+        sort_entries(&mut entries);
+        write_index(backlog_dir, &path, &entries)?;
+    }
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(changes)
+}
+
 pub fn verify_index(backlog_dir: &Path) -> Result<IndexReport, IndexError> {
     let path = index_path(backlog_dir);
     if !path.exists() {
@@ -179,11 +462,16 @@ pub fn verify_index(backlog_dir: &Path) -> Result<IndexReport, IndexError> {
     })
 }
 
-fn build_entries(backlog_dir: &Path) -> Result<Vec<IndexEntry>, IndexError> {
+fn build_entries<F>(backlog_dir: &Path, on_progress: &mut F) -> Result<Vec<IndexEntry>, IndexError>
+where
+    F: FnMut(&ProgressEvent),
+{
     let tasks = load_tasks(backlog_dir);
+    let total = tasks.len();
     let mut entries = Vec::new();
     let repo_root = repo_root_from_backlog(backlog_dir);
-    for task in tasks {
+    for (done, task) in tasks.into_iter().enumerate() {
+        tick(on_progress, "index_rebuild", "scan", done + 1, total);
         let Some(task_path) = task.file_path.as_ref() else {
             continue;
         };
@@ -245,6 +533,10 @@ fn normalize_rel_path(repo_root: &Path, backlog_dir: &Path, task_path: &Path) ->
 }
 
 fn read_index(path: &Path) -> Result<Vec<IndexEntry>, IndexError> {
+    read_jsonl(path)
+}
+
+fn read_jsonl<T: serde::de::DeserializeOwned>(path: &Path) -> Result<Vec<T>, IndexError> {
     let file = File::open(path)?;
     let reader = BufReader::new(file);
     let mut entries = Vec::new();
@@ -253,8 +545,7 @@ fn read_index(path: &Path) -> Result<Vec<IndexEntry>, IndexError> {
         if line.trim().is_empty() {
             continue;
         }
-        let entry: IndexEntry = serde_json::from_str(&line)?;
-        entries.push(entry);
+        entries.push(serde_json::from_str(&line)?);
     }
     Ok(entries)
 }
@@ -0,0 +1,345 @@
+//! First-run setup behind `workmesh init`: project config, a pre-commit hook, and the seeded
+//! backlog in one pass. The CLI gathers the answers (prompting on a terminal); this module only
+//! writes files and reports each one.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::config::{config_path, load_config, write_config, ConfigError};
+use crate::initiative::{best_effort_git_branch, ensure_branch_initiative_with_hint};
+use crate::quickstart::{quickstart, QuickstartError, QuickstartOptions};
+use crate::workflow::WorkflowConfig;
+
+/// Marker line identifying a hook written by `init`; other hooks are never overwritten.
+pub const HOOK_MARKER: &str = "# Installed by `workmesh init`.";
+
+/// Body of the pre-commit hook: block commits while task files are invalid or leak secrets.
+pub const PRE_COMMIT_HOOK: &str = "#!/bin/sh
+# Installed by `workmesh init`.
+workmesh validate >/dev/null || { echo \"workmesh: validate failed (run \\`workmesh validate\\`)\" >&2; exit 1; }
+workmesh lint >/dev/null || { echo \"workmesh: lint findings (run \\`workmesh lint\\`)\" >&2; exit 1; }
+";
+
+#[derive(Debug, Error)]
+pub enum InitError {
+    #[error("Failed to write setup files: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error(transparent)]
+    Quickstart(#[from] QuickstartError),
+    #[error("Failed to reserve initiative: {0}")]
+    Initiative(anyhow::Error),
+}
+
+/// Answers collected by the wizard.
+#[derive(Debug, Clone, Default)]
+pub struct InitOptions {
+    pub project_id: String,
+    pub name: Option<String>,
+    /// Workflow statuses in order; empty leaves `[workflow]` alone.
+    pub statuses: Vec<String>,
+    pub default_priority: Option<String>,
+    /// Initiative key hint for the current branch (ids look like `task-<key>-001`).
+    pub initiative: Option<String>,
+    /// `[task_files] template`, e.g. `{id} - {title}`.
+    pub filename_template: Option<String>,
+    pub hooks: bool,
+    /// Create project docs, task/state roots, and a seed task (same as `quickstart`).
+    pub seed: bool,
+}
+
+/// One file `init` wrote or deliberately left alone.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct InitEntry {
+    pub path: PathBuf,
+    pub what: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct InitReport {
+    pub written: Vec<InitEntry>,
+    pub skipped: Vec<InitEntry>,
+    /// Initiative key for the current branch, when one was reserved.
+    pub initiative: Option<String>,
+}
+
+/// Apply `options` to the repo at `repo_root`. Safe to re-run: config keys are overwritten with
+/// the new answers, existing docs and tasks are kept, and foreign hooks are skipped.
+pub fn init_repo(repo_root: &Path, options: &InitOptions) -> Result<InitReport, InitError> {
+    let mut report = InitReport::default();
+
+    let mut config = load_config(repo_root).unwrap_or_default();
+    let mut keys = Vec::new();
+    let statuses: Vec<String> = options
+        .statuses
+        .iter()
+        .map(|status| status.trim().to_string())
+        .filter(|status| !status.is_empty())
+        .collect();
+    if !statuses.is_empty() {
+        config.workflow = Some(linear_workflow(&statuses));
+        keys.push("workflow");
+    }
+    if let Some(priority) = trimmed(options.default_priority.as_deref()) {
+        config.default_priority = Some(priority);
+        keys.push("default_priority");
+    }
+    if let Some(template) = trimmed(options.filename_template.as_deref()) {
+        let mut task_files = config.task_files.take().unwrap_or_default();
+        task_files.template = Some(template);
+        config.task_files = Some(task_files);
+        keys.push("task_files");
+    }
+    if !keys.is_empty() {
+        write_config(repo_root, &config)?;
+    }
+
+    let initiative = trimmed(options.initiative.as_deref());
+    if options.seed {
+        let result = quickstart(
+            repo_root,
+            &options.project_id,
+            options.name.as_deref(),
+            initiative.as_deref(),
+            &QuickstartOptions::default(),
+        )?;
+        report.written.push(InitEntry {
+            path: result.project_dir,
+            what: "project docs".to_string(),
+        });
+        report.written.push(InitEntry {
+            path: result.tasks_root,
+            what: "tasks root".to_string(),
+        });
+        report.written.push(InitEntry {
+            path: result.state_root,
+            what: "state root".to_string(),
+        });
+        if let Some(task) = result.created_task {
+            report.written.push(InitEntry {
+                path: task,
+                what: "seed task".to_string(),
+            });
+        }
+        report.initiative = best_effort_git_branch(repo_root).and_then(|branch| {
+            load_config(repo_root)?
+                .branch_initiatives?
+                .get(&branch)
+                .cloned()
+        });
+    } else if let Some(hint) = initiative.as_deref() {
+        match best_effort_git_branch(repo_root) {
+            Some(branch) => {
+                let key = ensure_branch_initiative_with_hint(repo_root, &branch, Some(hint))
+                    .map_err(InitError::Initiative)?;
+                report.initiative = Some(key);
+            }
+            None => report.skipped.push(InitEntry {
+                path: config_path(repo_root),
+                what: "initiative (no git branch yet; the first `add` picks one)".to_string(),
+            }),
+        }
+    }
+    if report.initiative.is_some() {
+        keys.push("initiatives");
+    }
+    if !keys.is_empty() {
+        report.written.insert(
+            0,
+            InitEntry {
+                path: config_path(repo_root),
+                what: format!("config ({})", keys.join(", ")),
+            },
+        );
+    }
+
+    if options.hooks {
+        match install_pre_commit_hook(repo_root)? {
+            Ok(path) => report.written.push(InitEntry {
+                path,
+                what: "pre-commit hook".to_string(),
+            }),
+            Err(skipped) => report.skipped.push(skipped),
+        }
+    }
+
+    Ok(report)
+}
+
+/// `statuses` as a straight line: each status moves forward to the next and back to the
+/// previous one; the last status is terminal.
+pub fn linear_workflow(statuses: &[String]) -> WorkflowConfig {
+    let mut transitions = BTreeMap::new();
+    let last = statuses.len().saturating_sub(1);
+    for (index, status) in statuses.iter().enumerate().take(last) {
+        let mut targets = vec![statuses[index + 1].clone()];
+        if index > 0 {
+            targets.push(statuses[index - 1].clone());
+        }
+        transitions.insert(status.clone(), targets);
+    }
+    WorkflowConfig {
+        statuses: statuses.to_vec(),
+        transitions,
+    }
+}
+
+/// Write `PRE_COMMIT_HOOK` into the repo's hooks dir. The inner `Err` explains a skip (not a git
+/// repo, or a pre-commit hook that `init` did not write).
+pub fn install_pre_commit_hook(
+    repo_root: &Path,
+) -> Result<Result<PathBuf, InitEntry>, std::io::Error> {
+    let Some(hooks_dir) = git_hooks_dir(repo_root) else {
+        return Ok(Err(InitEntry {
+            path: repo_root.join(".git"),
+            what: "pre-commit hook (not a git repository)".to_string(),
+        }));
+    };
+    let path = hooks_dir.join("pre-commit");
+    if let Ok(existing) = fs::read_to_string(&path) {
+        if !existing.contains(HOOK_MARKER) {
+            return Ok(Err(InitEntry {
+                path,
+                what: "pre-commit hook (existing hook kept)".to_string(),
+            }));
+        }
+    }
+    fs::create_dir_all(&hooks_dir)?;
+    fs::write(&path, PRE_COMMIT_HOOK)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(Ok(path))
+}
+
+fn git_hooks_dir(repo_root: &Path) -> Option<PathBuf> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["rev-parse", "--git-path", "hooks"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if value.is_empty() {
+        return None;
+    }
+    let path = PathBuf::from(value);
+    Some(if path.is_absolute() {
+        path
+    } else {
+        repo_root.join(path)
+    })
+}
+
+fn trimmed(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    #[test]
+    fn init_writes_config_hook_and_seed_backlog() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        for args in [
+            &["init", "-q", "-b", "main"][..],
+            &[
+                "-c",
+                "user.name=WorkMesh",
+                "-c",
+                "user.email=workmesh@example.com",
+                "commit",
+                "-q",
+                "--allow-empty",
+                "-m",
+                "init",
+            ],
+        ] {
+            let ok = Command::new("git")
+                .args(args)
+                .current_dir(repo)
+                .status()
+                .map(|status| status.success())
+                .unwrap_or(false);
+            assert!(ok, "git {:?}", args);
+        }
+
+        let report = init_repo(
+            repo,
+            &InitOptions {
+                project_id: "demo".to_string(),
+                statuses: vec!["Backlog".into(), "Doing".into(), "Done".into()],
+                default_priority: Some("P1".to_string()),
+                initiative: Some("auth".to_string()),
+                filename_template: Some("{id} - {slug}".to_string()),
+                hooks: true,
+                seed: true,
+                ..Default::default()
+            },
+        )
+        .expect("init");
+
+        let config = load_config(repo).expect("config");
+        let workflow = config.workflow.expect("workflow");
+        assert_eq!(workflow.statuses, vec!["Backlog", "Doing", "Done"]);
+        assert_eq!(workflow.transitions["Doing"], vec!["Done", "Backlog"]);
+        assert!(!workflow.transitions.contains_key("Done"));
+        assert_eq!(config.default_priority.as_deref(), Some("P1"));
+        assert_eq!(
+            config
+                .task_files
+                .and_then(|files| files.template)
+                .as_deref(),
+            Some("{id} - {slug}")
+        );
+        assert_eq!(report.initiative.as_deref(), Some("auth"));
+
+        let hook = repo.join(".git").join("hooks").join("pre-commit");
+        assert!(fs::read_to_string(&hook)
+            .expect("hook")
+            .contains(HOOK_MARKER));
+        assert!(report.written.iter().any(|entry| entry.path == hook));
+        let seed = report
+            .written
+            .iter()
+            .find(|entry| entry.what == "seed task")
+            .expect("seed task");
+        assert!(seed.path.is_file());
+        assert!(report.written[0].what.contains("initiatives"));
+
+        fs::write(&hook, "#!/bin/sh\nexit 0\n").expect("foreign hook");
+        let again = init_repo(
+            repo,
+            &InitOptions {
+                project_id: "demo".to_string(),
+                hooks: true,
+                ..Default::default()
+            },
+        )
+        .expect("re-run");
+        assert!(again.written.is_empty());
+        assert_eq!(again.skipped.len(), 1);
+        assert_eq!(
+            fs::read_to_string(&hook).expect("hook"),
+            "#!/bin/sh\nexit 0\n"
+        );
+    }
+}
@@ -1,11 +1,16 @@
-use std::path::Path;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 
 use anyhow::{anyhow, Result};
 use sha2::{Digest, Sha256};
 
 use crate::config::{load_config, write_config, WorkmeshConfig};
-use crate::task::Task;
+use crate::storage::{
+    atomic_write_text, storage_error_to_io, with_resource_lock, ResourceKey, DEFAULT_LOCK_TIMEOUT,
+};
+use crate::task::{load_tasks_with_archive, Task};
 
 pub fn best_effort_git_branch(repo_root: &Path) -> Option<String> {
     if let Ok(override_branch) = std::env::var("WORKMESH_BRANCH") {
@@ -281,18 +286,20 @@ fn split_hint_words(raw: &str) -> Vec<String> {
     words
 }
 
-pub fn next_namespaced_task_id(tasks: &[Task], initiative: &str) -> String {
+fn normalize_initiative(initiative: &str) -> String {
     let init = initiative.trim().to_lowercase();
-    let init = if init.is_empty() {
+    if init.is_empty() {
         "work".to_string()
     } else {
         init
-    };
-    let prefix = format!("task-{}-", init);
+    }
+}
+
+fn max_namespaced_number(tasks: &[Task], prefix: &str) -> i32 {
     let mut max_num = 0i32;
     for task in tasks {
         let id = task.id.trim().to_lowercase();
-        if !id.starts_with(&prefix) {
+        if !id.starts_with(prefix) {
             continue;
         }
         let rest = &id[prefix.len()..];
@@ -301,7 +308,48 @@ pub fn next_namespaced_task_id(tasks: &[Task], initiative: &str) -> String {
             max_num = max_num.max(n);
         }
     }
-    format!("{}{:03}", prefix, max_num + 1)
+    max_num
+}
+
+pub fn next_namespaced_task_id(tasks: &[Task], initiative: &str) -> String {
+    let prefix = format!("task-{}-", normalize_initiative(initiative));
+    format!("{}{:03}", prefix, max_namespaced_number(tasks, &prefix) + 1)
+}
+
+/// Last number handed out per initiative, under `<backlog>/.ids/`.
+pub fn task_id_counter_path(backlog_dir: &Path, initiative: &str) -> PathBuf {
+    backlog_dir
+        .join(".ids")
+        .join(format!("{}.last", normalize_initiative(initiative)))
+}
+
+/// Like [`next_namespaced_task_id`], but safe for concurrent creators (parallel agents, CLI and
+/// MCP at once): under a per-initiative lock it rescans the task files on disk (archive
+/// included) and bumps a counter of ids already handed out, so an id reserved by another
+/// process that has not written its file yet is never reused. `tasks` adds ids known only to
+/// the caller. A reserved id whose file is never written leaves a gap.
+pub fn reserve_namespaced_task_id(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    initiative: &str,
+) -> io::Result<String> {
+    let init = normalize_initiative(initiative);
+    let prefix = format!("task-{}-", init);
+    let counter = task_id_counter_path(backlog_dir, &init);
+    let key = ResourceKey::repo_local(backlog_dir, &format!("task_ids.{}", init));
+    with_resource_lock(&key, DEFAULT_LOCK_TIMEOUT, || {
+        let on_disk = load_tasks_with_archive(backlog_dir);
+        let used =
+            max_namespaced_number(tasks, &prefix).max(max_namespaced_number(&on_disk, &prefix));
+        let reserved = fs::read_to_string(&counter)
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i32>().ok())
+            .unwrap_or(0);
+        let next = used.max(reserved) + 1;
+        atomic_write_text(&counter, &format!("{}\n", next))?;
+        Ok(format!("{}{:03}", prefix, next))
+    })
+    .map_err(storage_error_to_io)
 }
 
 fn slugify(raw: &str) -> Option<String> {
@@ -486,4 +534,54 @@ mod tests {
         );
         assert_eq!(next_namespaced_task_id(&tasks, "new"), "task-new-001");
     }
+
+    #[test]
+    fn reserve_namespaced_task_id_never_collides_across_concurrent_creators() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        fs::write(
+            tasks_dir.join("task-login-004 - existing.md"),
+            "---\nid: task-login-004\ntitle: Existing\nstatus: To Do\npriority: P2\n\
+phase: Phase1\ndependencies: []\nlabels: []\n---\n",
+        )
+        .expect("write task");
+
+        let (creators, per_creator) = (6usize, 5usize);
+        let mut ids: Vec<String> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..creators)
+                .map(|_| {
+                    scope.spawn(|| {
+                        (0..per_creator)
+                            .map(|_| {
+                                reserve_namespaced_task_id(&backlog_dir, &[], "login")
+                                    .expect("reserve")
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("join"))
+                .collect()
+        });
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), creators * per_creator);
+        assert_eq!(ids.first().map(String::as_str), Some("task-login-005"));
+        assert_eq!(ids.last().map(String::as_str), Some("task-login-034"));
+
+        fs::write(
+            tasks_dir.join("task-login-050 - manual.md"),
+            "---\nid: task-login-050\ntitle: Manual\nstatus: To Do\npriority: P2\n\
+phase: Phase1\ndependencies: []\nlabels: []\n---\n",
+        )
+        .expect("write task");
+        assert_eq!(
+            reserve_namespaced_task_id(&backlog_dir, &[], "LOGIN").expect("reserve"),
+            "task-login-051"
+        );
+    }
 }
@@ -1,31 +1,99 @@
 //! Core domain types for WorkMesh.
 
+pub mod activity;
+pub mod aliases;
+pub mod api_tokens;
 pub mod archive;
+pub mod assignees;
+pub mod attachments;
 pub mod audit;
+pub mod audit_signing;
+pub mod autotag;
 pub mod backlog;
+pub mod backlog_diff;
+pub mod bench;
+pub mod blocked_time;
 pub mod bootstrap;
+pub mod budget;
+pub mod bundle;
+pub mod calibration;
+pub mod capabilities;
+pub mod changes;
+pub mod checkpoint_triggers;
+pub mod codeowners;
+pub mod confidential;
 pub mod config;
 pub mod context;
+pub mod context_pack;
+pub mod cycle_time;
 pub mod doctor;
+pub mod encoding;
+pub mod epic_brief;
+pub mod extract;
 pub mod fix;
 pub mod focus;
+pub mod freeze;
 pub mod gantt;
 pub mod global_sessions;
+pub mod goals;
+pub mod grab;
+pub mod graph_diff;
+pub mod health;
 pub mod id_fix;
+pub mod import;
+pub mod inbox;
 pub mod index;
+pub mod init;
 pub mod initiative;
+pub mod lint;
+pub mod mentions;
+pub mod merge;
+pub mod metrics;
 pub mod migration;
 pub mod migration_audit;
+pub mod milestone;
+pub mod obsidian;
+pub mod onboarding;
+pub mod policies;
+pub mod postmerge;
+pub mod progress;
 pub mod project;
+pub mod proposals;
 pub mod quickstart;
+pub mod recurrence;
 pub mod rekey;
+pub mod related;
+pub mod reminders;
+pub mod remote;
+pub mod report;
+pub mod roles;
+pub mod self_update;
+pub mod selftest;
+#[cfg(feature = "semsearch")]
+pub mod semsearch;
+pub mod service;
 pub mod session;
 pub mod skills;
+pub mod split;
 pub mod storage;
+pub mod stress;
+pub mod sync;
 pub mod task;
+pub mod task_cache;
 pub mod task_ops;
+pub mod taskwarrior;
+pub mod telemetry;
+pub mod template;
+pub mod timestamps;
+pub mod timewindow;
+pub mod title_lint;
+pub mod todos;
+pub mod transitions;
+pub mod trash;
 pub mod truth;
 pub mod views;
+pub mod watch;
+pub mod workflow;
 pub mod workstreams;
 pub mod worktrees;
 
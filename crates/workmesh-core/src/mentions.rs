@@ -0,0 +1,274 @@
+use std::collections::{BTreeSet, HashMap};
+use std::path::Path;
+
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::inbox::normalize_user;
+use crate::task::{parse_list_value, Task};
+use crate::timewindow::parse_timestamp_ref;
+
+/// Result of checking the `@name` tokens of a note against known users and teams.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct MentionResolution {
+    /// Users to notify, with team mentions expanded to their members.
+    pub users: Vec<String>,
+    /// Teams that were mentioned.
+    pub teams: Vec<String>,
+    /// Mentions that match no assignee, reviewer, or team; these are not routed.
+    pub unknown: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MentionHit {
+    pub task_id: String,
+    pub title: Option<String>,
+    pub timestamp: String,
+    pub actor: Option<String>,
+    pub section: Option<String>,
+    pub note: String,
+}
+
+/// `@name` tokens in `text`, lowercased and deduplicated in order of first appearance.
+///
+/// A mention must start the text or follow a non-name character, so email addresses are ignored.
+pub fn parse_mentions(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut mentions = Vec::new();
+    let mut index = 0;
+    while index < chars.len() {
+        if chars[index] != '@' || (index > 0 && is_name_char(chars[index - 1])) {
+            index += 1;
+            continue;
+        }
+        let start = index + 1;
+        let mut end = start;
+        while end < chars.len() && is_name_char(chars[end]) {
+            end += 1;
+        }
+        let name: String = chars[start..end].iter().collect();
+        let name = name.trim_end_matches(['.', '-']).to_lowercase();
+        if !name.is_empty() && !mentions.contains(&name) {
+            mentions.push(name);
+        }
+        index = end;
+    }
+    mentions
+}
+
+/// Users that can be mentioned: every assignee, reviewer, and watcher plus team members.
+pub fn known_users(tasks: &[Task], teams: &HashMap<String, Vec<String>>) -> BTreeSet<String> {
+    let mut users = BTreeSet::new();
+    for task in tasks {
+        users.extend(task.assignee.iter().map(|entry| normalize_user(entry)));
+        for key in ["reviewer", "reviewers", "watchers"] {
+            users.extend(
+                parse_list_value(task.extra.get(key))
+                    .iter()
+                    .map(|entry| normalize_user(entry)),
+            );
+        }
+    }
+    for members in teams.values() {
+        users.extend(members.iter().map(|member| normalize_user(member)));
+    }
+    users.remove("");
+    users
+}
+
+pub fn resolve_mentions(
+    text: &str,
+    tasks: &[Task],
+    teams: &HashMap<String, Vec<String>>,
+) -> MentionResolution {
+    let known = known_users(tasks, teams);
+    let teams_lc: HashMap<String, &Vec<String>> = teams
+        .iter()
+        .map(|(name, members)| (normalize_user(name), members))
+        .collect();
+    let mut resolution = MentionResolution::default();
+    for mention in parse_mentions(text) {
+        if let Some(members) = teams_lc.get(&mention) {
+            for member in members.iter().map(|member| normalize_user(member)) {
+                if !member.is_empty() && !resolution.users.contains(&member) {
+                    resolution.users.push(member);
+                }
+            }
+            resolution.teams.push(mention);
+        } else if known.contains(&mention) {
+            if !resolution.users.contains(&mention) {
+                resolution.users.push(mention);
+            }
+        } else {
+            resolution.unknown.push(mention);
+        }
+    }
+    resolution
+}
+
+/// Users a note event notifies: the recorded `mentions` list when present, else its `@` tokens.
+pub(crate) fn event_mentions(event: &AuditEvent) -> Vec<String> {
+    if let Some(Value::Array(values)) = event.details.get("mentions") {
+        return values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(normalize_user)
+            .collect();
+    }
+    event
+        .details
+        .get("note")
+        .and_then(Value::as_str)
+        .map(parse_mentions)
+        .unwrap_or_default()
+}
+
+/// Notes mentioning `user`, newest first, optionally limited to events at or after `since`.
+pub fn mentions_for(
+    backlog_dir: &Path,
+    user: &str,
+    tasks: &[Task],
+    since: Option<NaiveDateTime>,
+) -> Vec<MentionHit> {
+    let user = normalize_user(user);
+    let titles: HashMap<String, &str> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task.title.as_str()))
+        .collect();
+    let mut hits: Vec<MentionHit> = read_audit_events(backlog_dir)
+        .into_iter()
+        .filter(|event| matches!(event.action.as_str(), "note" | "bulk_note"))
+        .filter(|event| match (since, parse_timestamp(&event.timestamp)) {
+            (Some(since), Some(at)) => at >= since,
+            _ => true,
+        })
+        .filter(|event| event_mentions(event).contains(&user))
+        .filter_map(|event| {
+            let task_id = event.task_id.clone()?;
+            Some(MentionHit {
+                title: titles
+                    .get(&task_id.to_lowercase())
+                    .map(|title| title.to_string()),
+                timestamp: event.timestamp.clone(),
+                actor: event.actor.clone(),
+                section: event
+                    .details
+                    .get("section")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                note: event
+                    .details
+                    .get("note")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                task_id,
+            })
+        })
+        .collect();
+    hits.reverse();
+    hits
+}
+
+fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
+    parse_timestamp_ref(value)
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::append_audit_event;
+    use crate::task::load_tasks;
+    use crate::task_ops::create_task_file;
+    use tempfile::TempDir;
+
+    #[test]
+    fn parse_mentions_skips_emails_and_trailing_punctuation() {
+        assert_eq!(
+            parse_mentions("@Alice please ask bob@example.com, cc @bob. and @alice again"),
+            vec!["alice", "bob"]
+        );
+        assert!(parse_mentions("no mentions @ all").is_empty());
+    }
+
+    #[test]
+    fn resolve_mentions_expands_teams_and_flags_unknown() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        std::fs::create_dir_all(&tasks_dir).expect("tasks");
+        create_task_file(
+            &tasks_dir,
+            "task-001",
+            "Mine",
+            "To Do",
+            "P2",
+            "Phase1",
+            &[],
+            &[],
+            &["alice".to_string()],
+        )
+        .expect("task");
+        let tasks = load_tasks(&backlog);
+        let mut teams = HashMap::new();
+        teams.insert(
+            "Platform".to_string(),
+            vec!["carol".to_string(), "alice".to_string()],
+        );
+
+        let resolution = resolve_mentions("@alice @platform @nobody", &tasks, &teams);
+        assert_eq!(resolution.users, vec!["alice", "carol"]);
+        assert_eq!(resolution.teams, vec!["platform"]);
+        assert_eq!(resolution.unknown, vec!["nobody"]);
+    }
+
+    #[test]
+    fn mentions_for_reads_recorded_mentions_newest_first() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path();
+        for (timestamp, task_id, details) in [
+            (
+                "2026-01-01 09:00",
+                "task-001",
+                serde_json::json!({"section": "notes", "note": "old @alice"}),
+            ),
+            (
+                "2026-02-01 09:00",
+                "task-002",
+                serde_json::json!({"note": "team ping", "mentions": ["alice", "carol"]}),
+            ),
+            (
+                "2026-02-02 09:00",
+                "task-003",
+                serde_json::json!({"note": "for @bob"}),
+            ),
+        ] {
+            append_audit_event(
+                backlog,
+                &AuditEvent {
+                    timestamp: timestamp.to_string(),
+                    actor: Some("bob".to_string()),
+                    action: "note".to_string(),
+                    task_id: Some(task_id.to_string()),
+                    details,
+                },
+            )
+            .expect("audit");
+        }
+
+        let all = mentions_for(backlog, "@Alice", &[], None);
+        let ids: Vec<&str> = all.iter().map(|hit| hit.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-002", "task-001"]);
+
+        let since = parse_timestamp("2026-01-15 00:00");
+        let recent = mentions_for(backlog, "alice", &[], since);
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].task_id, "task-002");
+    }
+}
@@ -0,0 +1,537 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::audit::{append_audit_event, audit_log_path, AuditError, AuditEvent};
+use crate::backlog::{resolve_backlog, BacklogError};
+use crate::id_fix::next_free_task_id;
+use crate::progress::{tick, ProgressEvent};
+use crate::rekey::{normalize_rekey_mapping, rekeyed_file_name, rewrite_task_text};
+use crate::storage::{with_path_lock, write_string_atomic};
+use crate::task::{
+    archive_root_for_root, load_tasks_with_archive, tasks_dir_for_root, Task, TaskParseError,
+};
+use crate::task_ops::task_filename_template;
+
+#[derive(Debug, Error)]
+pub enum MergeError {
+    #[error(transparent)]
+    Backlog(#[from] BacklogError),
+    #[error("Source backlog is the same as the target backlog: {0}")]
+    SameBacklog(PathBuf),
+    #[error("Failed to rewrite task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to write merged files: {0}")]
+    Io(#[from] std::io::Error),
+    #[error(transparent)]
+    Audit(#[from] AuditError),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MergeBacklogOptions {
+    /// Namespace inserted into every imported id (`task-001` + `other-` -> `task-other-001`).
+    /// When unset, only ids that collide with the target backlog are rekeyed.
+    pub prefix: Option<String>,
+    /// Write files and audit events (otherwise dry-run).
+    pub apply: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergedTask {
+    pub old_id: String,
+    pub new_id: String,
+    pub source_path: PathBuf,
+    pub target_path: PathBuf,
+    pub archived: bool,
+    pub rekeyed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MergeBacklogReport {
+    pub ok: bool,
+    pub apply: bool,
+    pub source_state_root: PathBuf,
+    pub source_tasks_root: PathBuf,
+    pub prefix: Option<String>,
+    pub tasks: Vec<MergedTask>,
+    /// Old id -> new id for every imported task whose id changed.
+    pub mapping: BTreeMap<String, String>,
+    /// Source ids that collided with ids already present in the target backlog.
+    pub collisions: Vec<String>,
+    pub audit_events: usize,
+    pub skipped: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Import tasks, archived tasks, and audit history from another backlog.
+///
+/// The source backlog is left untouched. Imported ids are rekeyed (prefix and/or collision
+/// resolution) with the same rewrite rules as `rekey apply`, so dependencies, relationships, and
+/// free-text mentions inside the imported tasks keep pointing at the right work.
+pub fn merge_backlog(
+    backlog_dir: &Path,
+    source_root: &Path,
+    options: &MergeBacklogOptions,
+) -> Result<MergeBacklogReport, MergeError> {
+    merge_backlog_with_progress(backlog_dir, source_root, options, |_| {})
+}
+
+/// [`merge_backlog`], reporting the `copy` phase (one step per imported task) and the `audit`
+/// phase.
+pub fn merge_backlog_with_progress<F>(
+    backlog_dir: &Path,
+    source_root: &Path,
+    options: &MergeBacklogOptions,
+    mut on_progress: F,
+) -> Result<MergeBacklogReport, MergeError>
+where
+    F: FnMut(&ProgressEvent),
+{
+    let source = resolve_backlog(source_root)?;
+    let source_dir = source.state_root.clone();
+    if same_path(&source_dir, backlog_dir) {
+        return Err(MergeError::SameBacklog(source_dir));
+    }
+
+    let target_tasks_dir = tasks_dir_for_root(backlog_dir);
+    let target_archive_root = archive_root_for_root(backlog_dir);
+    let source_archive_root = archive_root_for_root(&source_dir);
+    let source_template = task_filename_template(&tasks_dir_for_root(&source_dir));
+
+    let mut source_tasks = load_tasks_with_archive(&source_dir);
+    source_tasks.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then_with(|| a.id.cmp(&b.id)));
+    let target_ids: HashSet<String> = load_tasks_with_archive(backlog_dir)
+        .into_iter()
+        .map(|task| task.id.to_lowercase())
+        .collect();
+
+    let prefix = options
+        .prefix
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+
+    let mut warnings = Vec::new();
+    let mut skipped = Vec::new();
+
+    // Drop duplicate ids in the source: the mapping is keyed by id, so they cannot be told apart.
+    let mut seen_source = HashSet::new();
+    let mut unique_tasks: Vec<&Task> = Vec::new();
+    for task in &source_tasks {
+        if task.id.trim().is_empty() {
+            continue;
+        }
+        if !seen_source.insert(task.id.to_lowercase()) {
+            warnings.push(format!(
+                "Duplicate id '{}' in source backlog; skipped {} (run `fix ids` in the source first)",
+                task.id,
+                display_path(task.file_path.as_deref())
+            ));
+            skipped.push(task.id.clone());
+            continue;
+        }
+        unique_tasks.push(task);
+    }
+
+    let desired: Vec<String> = unique_tasks
+        .iter()
+        .map(|task| prefixed_id(&task.id, prefix.as_deref()))
+        .collect();
+    let mut reserved: HashSet<String> = target_ids.clone();
+    reserved.extend(desired.iter().map(|id| id.to_lowercase()));
+
+    let mut assigned: HashSet<String> = HashSet::new();
+    let mut mapping_raw: HashMap<String, String> = HashMap::new();
+    let mut mapping = BTreeMap::new();
+    let mut collisions = Vec::new();
+    let mut planned: Vec<(&Task, String)> = Vec::new();
+    for (task, wanted) in unique_tasks.iter().zip(desired) {
+        let wanted_lc = wanted.to_lowercase();
+        let new_id = if target_ids.contains(&wanted_lc) || assigned.contains(&wanted_lc) {
+            collisions.push(task.id.clone());
+            let next = next_free_task_id(&wanted, &reserved);
+            reserved.insert(next.to_lowercase());
+            next
+        } else {
+            wanted
+        };
+        assigned.insert(new_id.to_lowercase());
+        if new_id != task.id {
+            mapping_raw.insert(task.id.clone(), new_id.clone());
+            mapping.insert(task.id.clone(), new_id.clone());
+        }
+        planned.push((*task, new_id));
+    }
+    // Flag references that point outside the imported set (and are not satisfied by the target).
+    for (task, _) in &planned {
+        for reference in task_references(task) {
+            let key = reference.to_lowercase();
+            if !seen_source.contains(&key) && !target_ids.contains(&key) {
+                warnings.push(format!(
+                    "{} references {} which exists in neither backlog",
+                    task.id, reference
+                ));
+            }
+        }
+    }
+
+    let mut merged = Vec::new();
+    for (task, new_id) in planned {
+        let Some(source_path) = task.file_path.clone() else {
+            skipped.push(task.id.clone());
+            continue;
+        };
+        let archived = source_path.starts_with(&source_archive_root);
+        let target_dir = if archived {
+            let relative_parent = source_path
+                .parent()
+                .and_then(|parent| parent.strip_prefix(&source_archive_root).ok())
+                .map(Path::to_path_buf)
+                .unwrap_or_default();
+            target_archive_root.join(relative_parent)
+        } else {
+            target_tasks_dir.clone()
+        };
+        let file_name = source_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let rekeyed = new_id != task.id;
+        let target_name = if rekeyed {
+            rekeyed_file_name(&source_template, &file_name, task, &new_id).unwrap_or(file_name)
+        } else {
+            file_name
+        };
+        let target_path = target_dir.join(target_name);
+        if target_path.exists() {
+            warnings.push(format!(
+                "Refusing to overwrite existing file: {}",
+                target_path.display()
+            ));
+            skipped.push(task.id.clone());
+            continue;
+        }
+        merged.push(MergedTask {
+            old_id: task.id.clone(),
+            new_id,
+            source_path,
+            target_path,
+            archived,
+            rekeyed,
+        });
+    }
+
+    // A skipped task was never imported, so references to it must not be rewritten to its new id.
+    let imported: HashSet<String> = merged
+        .iter()
+        .map(|task| task.old_id.to_lowercase())
+        .collect();
+    mapping_raw.retain(|old_id, _| imported.contains(&old_id.to_lowercase()));
+    mapping.retain(|old_id, _| imported.contains(&old_id.to_lowercase()));
+    let mapping_lc = normalize_rekey_mapping(&mapping_raw);
+
+    if options.apply {
+        let total = merged.len();
+        for (done, task) in merged.iter().enumerate() {
+            tick(&mut on_progress, "merge_backlog", "copy", done + 1, total);
+            let text = fs::read_to_string(&task.source_path)?;
+            let updated =
+                rewrite_task_text(&text, &task.old_id, &mapping_lc, false)?.unwrap_or(text);
+            if let Some(parent) = task.target_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            with_path_lock(&task.target_path, || -> Result<(), MergeError> {
+                if task.target_path.exists() {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::AlreadyExists,
+                        format!("{} appeared during merge", task.target_path.display()),
+                    )
+                    .into());
+                }
+                write_string_atomic(&task.target_path, &updated)?;
+                Ok(())
+            })?;
+        }
+    }
+
+    let audit_events = import_audit_events(backlog_dir, &source_dir, &mapping_lc, options.apply)?;
+    tick(&mut on_progress, "merge_backlog", "audit", 1, 1);
+
+    Ok(MergeBacklogReport {
+        ok: true,
+        apply: options.apply,
+        source_state_root: source_dir,
+        source_tasks_root: source.tasks_root,
+        prefix,
+        tasks: merged,
+        mapping,
+        collisions,
+        audit_events,
+        skipped,
+        warnings,
+    })
+}
+
+/// Insert a namespace after the leading `task-` (or prepend it for non-standard ids).
+pub fn prefixed_id(id: &str, prefix: Option<&str>) -> String {
+    let Some(prefix) = prefix else {
+        return id.to_string();
+    };
+    let lowered = id.to_lowercase();
+    if lowered.starts_with(&format!("task-{}", prefix.to_lowercase())) {
+        return id.to_string();
+    }
+    if lowered.starts_with("task-") {
+        return format!("{}{}{}", &id[..5], prefix, &id[5..]);
+    }
+    format!("{}{}", prefix, id)
+}
+
+fn import_audit_events(
+    backlog_dir: &Path,
+    source_dir: &Path,
+    mapping_lc: &HashMap<String, String>,
+    apply: bool,
+) -> Result<usize, MergeError> {
+    let Ok(content) = fs::read_to_string(audit_log_path(source_dir)) else {
+        return Ok(0);
+    };
+    let mut count = 0usize;
+    for line in content.lines() {
+        let Ok(mut event) = serde_json::from_str::<AuditEvent>(line) else {
+            continue;
+        };
+        if let Some(task_id) = event.task_id.as_ref() {
+            if let Some(new_id) = mapping_lc.get(&task_id.to_lowercase()) {
+                event.task_id = Some(new_id.clone());
+            }
+        }
+        if let Some(details) = event.details.as_object_mut() {
+            details.insert(
+                "merged_from".to_string(),
+                serde_json::Value::String(source_dir.to_string_lossy().to_string()),
+            );
+        }
+        if apply {
+            append_audit_event(backlog_dir, &event)?;
+        }
+        count += 1;
+    }
+    Ok(count)
+}
+
+pub(crate) fn task_references(task: &Task) -> Vec<String> {
+    let mut refs = Vec::new();
+    refs.extend(task.dependencies.iter().cloned());
+    refs.extend(task.relationships.blocked_by.iter().cloned());
+    refs.extend(task.relationships.parent.iter().cloned());
+    refs.extend(task.relationships.child.iter().cloned());
+    refs.extend(task.relationships.discovered_from.iter().cloned());
+    refs.sort();
+    refs.dedup();
+    refs
+}
+
+fn same_path(a: &Path, b: &Path) -> bool {
+    let a = a.canonicalize().unwrap_or_else(|_| a.to_path_buf());
+    let b = b.canonicalize().unwrap_or_else(|_| b.to_path_buf());
+    a == b
+}
+
+fn display_path(path: Option<&Path>) -> String {
+    path.map(|p| p.display().to_string())
+        .unwrap_or_else(|| "(unknown path)".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::read_recent_audit_events;
+    use crate::task::{load_tasks, parse_task_file};
+    use crate::task_ops::{create_task_file, now_timestamp};
+    use tempfile::TempDir;
+
+    fn mk_backlog(root: &Path) -> PathBuf {
+        let backlog_dir = root.join("workmesh");
+        fs::create_dir_all(backlog_dir.join("tasks")).expect("tasks dir");
+        backlog_dir
+    }
+
+    fn mk_task(backlog_dir: &Path, id: &str, title: &str, deps: &[&str]) -> PathBuf {
+        let deps: Vec<String> = deps.iter().map(|d| d.to_string()).collect();
+        create_task_file(
+            &backlog_dir.join("tasks"),
+            id,
+            title,
+            "To Do",
+            "P2",
+            "Phase1",
+            &deps,
+            &[],
+            &[],
+        )
+        .expect("create task")
+    }
+
+    #[test]
+    fn prefixed_id_inserts_namespace_after_task_prefix() {
+        assert_eq!(prefixed_id("task-001", Some("other-")), "task-other-001");
+        assert_eq!(
+            prefixed_id("task-other-001", Some("other-")),
+            "task-other-001"
+        );
+        assert_eq!(prefixed_id("bug-7", Some("other-")), "other-bug-7");
+        assert_eq!(prefixed_id("task-001", None), "task-001");
+    }
+
+    #[test]
+    fn merge_dry_run_reports_collisions_without_writing() {
+        let temp = TempDir::new().expect("tempdir");
+        let target = mk_backlog(&temp.path().join("target"));
+        let source = mk_backlog(&temp.path().join("source"));
+        mk_task(&target, "task-001", "Existing", &[]);
+        mk_task(&source, "task-001", "Imported", &[]);
+        mk_task(&source, "task-002", "Follow up", &["task-001"]);
+
+        let report =
+            merge_backlog(&target, &source, &MergeBacklogOptions::default()).expect("merge report");
+        assert!(!report.apply);
+        assert_eq!(report.collisions, vec!["task-001".to_string()]);
+        assert_eq!(
+            report.mapping.get("task-001").map(String::as_str),
+            Some("task-001-dup2")
+        );
+        assert_eq!(load_tasks(&target).len(), 1);
+    }
+
+    #[test]
+    fn merge_apply_rekeys_collisions_and_rewrites_dependencies() {
+        let temp = TempDir::new().expect("tempdir");
+        let target = mk_backlog(&temp.path().join("target"));
+        let source = mk_backlog(&temp.path().join("source"));
+        mk_task(&target, "task-001", "Existing", &[]);
+        mk_task(&source, "task-001", "Imported", &[]);
+        mk_task(&source, "task-002", "Follow up", &["task-001"]);
+        append_audit_event(
+            &source,
+            &AuditEvent {
+                timestamp: now_timestamp(),
+                actor: Some("tester".to_string()),
+                action: "set_status".to_string(),
+                task_id: Some("task-001".to_string()),
+                details: serde_json::json!({ "status": "In Progress" }),
+            },
+        )
+        .expect("audit");
+
+        let report = merge_backlog(
+            &target,
+            &source,
+            &MergeBacklogOptions {
+                prefix: None,
+                apply: true,
+            },
+        )
+        .expect("merge");
+        assert_eq!(report.tasks.len(), 2);
+        assert_eq!(report.audit_events, 1);
+
+        let follow_up = report
+            .tasks
+            .iter()
+            .find(|t| t.old_id == "task-002")
+            .expect("follow up");
+        let task = parse_task_file(&follow_up.target_path).expect("parse");
+        assert_eq!(task.dependencies, vec!["task-001-dup2".to_string()]);
+
+        let ids: HashSet<String> = load_tasks(&target).into_iter().map(|t| t.id).collect();
+        assert!(ids.contains("task-001"));
+        assert!(ids.contains("task-001-dup2"));
+        assert!(ids.contains("task-002"));
+
+        let events = read_recent_audit_events(&target, 10);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].task_id.as_deref(), Some("task-001-dup2"));
+        assert!(events[0].details.get("merged_from").is_some());
+    }
+
+    #[test]
+    fn merge_drops_skipped_tasks_from_the_mapping() {
+        let temp = TempDir::new().expect("tempdir");
+        let target = mk_backlog(&temp.path().join("target"));
+        let source = mk_backlog(&temp.path().join("source"));
+        mk_task(&target, "task-001", "Existing", &[]);
+        mk_task(&source, "task-001", "Imported", &[]);
+        mk_task(&source, "task-002", "Follow up", &["task-001"]);
+
+        let plan = merge_backlog(&target, &source, &MergeBacklogOptions::default()).expect("plan");
+        let rekeyed = plan
+            .tasks
+            .iter()
+            .find(|t| t.old_id == "task-001")
+            .expect("rekeyed task");
+        fs::write(&rekeyed.target_path, "not a task").expect("occupy target path");
+
+        let report = merge_backlog(
+            &target,
+            &source,
+            &MergeBacklogOptions {
+                prefix: None,
+                apply: true,
+            },
+        )
+        .expect("merge");
+        assert_eq!(report.skipped, vec!["task-001".to_string()]);
+        assert!(report.mapping.is_empty());
+        let follow_up = report
+            .tasks
+            .iter()
+            .find(|t| t.old_id == "task-002")
+            .expect("follow up");
+        let task = parse_task_file(&follow_up.target_path).expect("parse");
+        assert_eq!(task.dependencies, vec!["task-001".to_string()]);
+    }
+
+    #[test]
+    fn merge_with_prefix_namespaces_every_imported_task() {
+        let temp = TempDir::new().expect("tempdir");
+        let target = mk_backlog(&temp.path().join("target"));
+        let source = mk_backlog(&temp.path().join("source"));
+        mk_task(&source, "task-001", "Alpha", &[]);
+        mk_task(&source, "task-002", "Beta", &["task-001"]);
+
+        let report = merge_backlog(
+            &target,
+            &source,
+            &MergeBacklogOptions {
+                prefix: Some("other-".to_string()),
+                apply: true,
+            },
+        )
+        .expect("merge");
+        assert!(report.collisions.is_empty());
+        let tasks = load_tasks(&target);
+        let beta = tasks
+            .iter()
+            .find(|t| t.id == "task-other-002")
+            .expect("beta");
+        assert_eq!(beta.dependencies, vec!["task-other-001".to_string()]);
+        // Source is left untouched.
+        assert!(load_tasks(&source).iter().any(|t| t.id == "task-001"));
+    }
+
+    #[test]
+    fn merge_refuses_same_backlog() {
+        let temp = TempDir::new().expect("tempdir");
+        let target = mk_backlog(temp.path());
+        mk_task(&target, "task-001", "Alpha", &[]);
+        let err = merge_backlog(&target, &target, &MergeBacklogOptions::default())
+            .expect_err("same backlog");
+        assert!(matches!(err, MergeError::SameBacklog(_)));
+    }
+}
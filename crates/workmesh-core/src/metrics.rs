@@ -0,0 +1,256 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write as _;
+use std::path::Path;
+use std::time::SystemTime;
+
+use chrono::Local;
+use serde::Serialize;
+
+use crate::audit::read_audit_events;
+use crate::blocked_time::{task_time_in_state, StatusTimelines};
+use crate::config::TaskValidationRules;
+use crate::health::{is_blocked, is_closed};
+use crate::index::{index_path, verify_index};
+use crate::task::Task;
+use crate::task_ops::{is_done, ready_tasks_with_rules};
+use crate::timewindow::TimeWindow;
+use crate::transitions::block_reason_counts;
+
+/// Backlog gauges and audit counters, as exposed in Prometheus text format.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BacklogMetrics {
+    pub tasks_by_status: BTreeMap<String, usize>,
+    pub ready: usize,
+    pub blocked: usize,
+    pub index_present: bool,
+    pub index_fresh: bool,
+    pub index_age_seconds: Option<u64>,
+    /// Audit events per action since the log started (monotonic while the log is kept), or
+    /// inside the requested window.
+    pub mutations_by_action: BTreeMap<String, usize>,
+    /// Cycle-time breakdown: hours done tasks spent `blocked`, `ready`, and `in_progress`,
+    /// summed over all done tasks with audit history.
+    pub cycle_time_hours: BTreeMap<String, f64>,
+    /// Times tasks were moved to `Blocked`, by reason (from `set-status --reason`).
+    pub block_reasons: BTreeMap<String, usize>,
+}
+
+pub fn collect_backlog_metrics(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    rules: &TaskValidationRules,
+    window: &TimeWindow,
+) -> BacklogMetrics {
+    let mut tasks_by_status = BTreeMap::new();
+    for task in tasks {
+        let status = task.status.trim();
+        let status = if status.is_empty() { "(none)" } else { status };
+        *tasks_by_status.entry(status.to_string()).or_insert(0) += 1;
+    }
+    let closed: HashSet<String> = tasks
+        .iter()
+        .filter(|task| is_closed(task))
+        .map(|task| task.id.to_lowercase())
+        .collect();
+    let blocked = tasks
+        .iter()
+        .filter(|task| !is_closed(task) && is_blocked(task, &closed))
+        .count();
+
+    let events = read_audit_events(backlog_dir);
+    let mut mutations_by_action = BTreeMap::new();
+    for event in events
+        .iter()
+        .filter(|event| window.contains_timestamp(&event.timestamp))
+    {
+        *mutations_by_action.entry(event.action.clone()).or_insert(0) += 1;
+    }
+
+    let timelines = StatusTimelines::from_events(&events);
+    let now = Local::now().naive_local();
+    let mut cycle_time_hours = BTreeMap::new();
+    for task in tasks.iter().filter(|task| is_done(task)) {
+        if let Some(timing) = task_time_in_state(task, tasks, &timelines, now) {
+            for (state, hours) in [
+                ("blocked", timing.time.blocked_hours),
+                ("ready", timing.time.ready_hours),
+                ("in_progress", timing.time.in_progress_hours),
+            ] {
+                *cycle_time_hours.entry(state.to_string()).or_insert(0.0) += hours;
+            }
+        }
+    }
+
+    let index_file = index_path(backlog_dir);
+    let index_age_seconds = std::fs::metadata(&index_file)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .map(|age| age.as_secs());
+
+    BacklogMetrics {
+        tasks_by_status,
+        ready: ready_tasks_with_rules(tasks, rules).len(),
+        blocked,
+        index_present: index_file.exists(),
+        index_fresh: verify_index(backlog_dir)
+            .map(|report| report.ok)
+            .unwrap_or(false),
+        index_age_seconds,
+        mutations_by_action,
+        cycle_time_hours,
+        block_reasons: block_reason_counts(tasks)
+            .into_iter()
+            .map(|entry| (entry.reason, entry.count))
+            .collect(),
+    }
+}
+
+/// Prometheus text exposition format (0.0.4), served at `/metrics` by `workmesh serve` and
+/// printed by the `metrics` command for the node_exporter textfile collector.
+pub fn render_prometheus(metrics: &BacklogMetrics) -> String {
+    let mut out = String::new();
+    header(&mut out, "workmesh_tasks", "gauge", "Tasks by status.");
+    for (status, count) in &metrics.tasks_by_status {
+        let _ = writeln!(
+            out,
+            "workmesh_tasks{{status=\"{}\"}} {}",
+            escape_label(status),
+            count
+        );
+    }
+    header(
+        &mut out,
+        "workmesh_tasks_ready",
+        "gauge",
+        "Tasks ready to start (dependencies done, no active lease).",
+    );
+    let _ = writeln!(out, "workmesh_tasks_ready {}", metrics.ready);
+    header(
+        &mut out,
+        "workmesh_tasks_blocked",
+        "gauge",
+        "Open tasks with unfinished dependencies or blockers.",
+    );
+    let _ = writeln!(out, "workmesh_tasks_blocked {}", metrics.blocked);
+    header(
+        &mut out,
+        "workmesh_index_fresh",
+        "gauge",
+        "1 when the task index matches the task files.",
+    );
+    let _ = writeln!(
+        out,
+        "workmesh_index_fresh {}",
+        u8::from(metrics.index_fresh)
+    );
+    if let Some(age) = metrics.index_age_seconds {
+        header(
+            &mut out,
+            "workmesh_index_age_seconds",
+            "gauge",
+            "Seconds since the task index was last written.",
+        );
+        let _ = writeln!(out, "workmesh_index_age_seconds {}", age);
+    }
+    header(
+        &mut out,
+        "workmesh_mutations_total",
+        "counter",
+        "Audit log events by action.",
+    );
+    for (action, count) in &metrics.mutations_by_action {
+        let _ = writeln!(
+            out,
+            "workmesh_mutations_total{{action=\"{}\"}} {}",
+            escape_label(action),
+            count
+        );
+    }
+    if !metrics.cycle_time_hours.is_empty() {
+        header(
+            &mut out,
+            "workmesh_cycle_time_hours",
+            "gauge",
+            "Hours done tasks spent blocked, ready, and in progress (from the audit log).",
+        );
+        for (state, hours) in &metrics.cycle_time_hours {
+            let _ = writeln!(
+                out,
+                "workmesh_cycle_time_hours{{state=\"{}\"}} {}",
+                escape_label(state),
+                hours
+            );
+        }
+    }
+    if !metrics.block_reasons.is_empty() {
+        header(
+            &mut out,
+            "workmesh_block_reasons_total",
+            "counter",
+            "Transitions to Blocked by recorded reason.",
+        );
+        for (reason, count) in &metrics.block_reasons {
+            let _ = writeln!(
+                out,
+                "workmesh_block_reasons_total{{reason=\"{}\"}} {}",
+                escape_label(reason),
+                count
+            );
+        }
+    }
+    out
+}
+
+fn header(out: &mut String, name: &str, kind: &str, help: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_prometheus_emits_gauges_and_counters() {
+        let mut metrics = BacklogMetrics {
+            ready: 2,
+            blocked: 1,
+            index_fresh: true,
+            ..BacklogMetrics::default()
+        };
+        metrics.tasks_by_status.insert("To Do".to_string(), 3);
+        metrics.tasks_by_status.insert("Say \"hi\"".to_string(), 1);
+        metrics
+            .mutations_by_action
+            .insert("set_status".to_string(), 4);
+
+        let text = render_prometheus(&metrics);
+        assert!(text.contains("# TYPE workmesh_tasks gauge\n"));
+        assert!(text.contains("workmesh_tasks{status=\"To Do\"} 3\n"));
+        assert!(text.contains("workmesh_tasks{status=\"Say \\\"hi\\\"\"} 1\n"));
+        assert!(text.contains("workmesh_tasks_ready 2\n"));
+        assert!(text.contains("workmesh_tasks_blocked 1\n"));
+        assert!(text.contains("workmesh_index_fresh 1\n"));
+        assert!(!text.contains("workmesh_index_age_seconds"));
+        assert!(text.contains("# TYPE workmesh_mutations_total counter\n"));
+        assert!(text.contains("workmesh_mutations_total{action=\"set_status\"} 4\n"));
+        assert!(!text.contains("workmesh_cycle_time_hours"));
+
+        metrics.cycle_time_hours.insert("blocked".to_string(), 5.5);
+        let text = render_prometheus(&metrics);
+        assert!(text.contains("workmesh_cycle_time_hours{state=\"blocked\"} 5.5\n"));
+        assert!(!text.contains("workmesh_block_reasons_total"));
+
+        metrics.block_reasons.insert("upstream API".to_string(), 2);
+        let text = render_prometheus(&metrics);
+        assert!(text.contains("workmesh_block_reasons_total{reason=\"upstream API\"} 2\n"));
+    }
+}
@@ -0,0 +1,311 @@
+//! Milestones: tasks name theirs in the `milestone` front matter field, and the report
+//! aggregates completion per milestone and projects a finish date from recent velocity.
+//!
+//! Velocity is the number of tasks that moved into Done within the trailing window (by the audit
+//! log, else `updated_date`), per week. A milestone with no completions in the window borrows
+//! the backlog-wide velocity, so new milestones still get a projection. Cancelled tasks count
+//! neither as done nor as remaining.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::{Duration, NaiveDateTime};
+use serde::Serialize;
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::cycle_time::lifecycles;
+use crate::health::first_extra;
+use crate::policies::parse_task_date;
+use crate::task::Task;
+use crate::task_ops::is_done;
+
+/// Front matter key naming a task's milestone.
+pub const MILESTONE_FIELD: &str = "milestone";
+/// Trailing days of completions used for velocity.
+pub const DEFAULT_VELOCITY_WINDOW_DAYS: i64 = 28;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VelocitySource {
+    Milestone,
+    Backlog,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneTask {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    pub assignee: Vec<String>,
+    /// When the task last moved into Done, for done tasks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneProgress {
+    pub name: String,
+    pub total: usize,
+    pub done: usize,
+    pub cancelled: usize,
+    pub remaining: usize,
+    /// Done over total minus cancelled, 0-100.
+    pub percent_complete: f64,
+    /// Tasks completed per week over the window.
+    pub velocity_per_week: f64,
+    pub velocity_source: VelocitySource,
+    /// `YYYY-MM-DD`; the last completion when nothing remains, `None` without any velocity.
+    pub projected_finish: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<MilestoneTask>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MilestoneReport {
+    pub window_days: i64,
+    pub backlog_velocity_per_week: f64,
+    pub milestones: Vec<MilestoneProgress>,
+    /// Open tasks without a milestone.
+    pub unassigned: usize,
+}
+
+pub fn task_milestone(task: &Task) -> Option<String> {
+    first_extra(task, &[MILESTONE_FIELD])
+}
+
+/// Progress of every milestone named by `tasks`, sorted by name, each with its tasks.
+pub fn milestone_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    window_days: i64,
+    now: NaiveDateTime,
+) -> MilestoneReport {
+    milestone_from_events(tasks, &read_audit_events(backlog_dir), window_days, now)
+}
+
+fn milestone_from_events(
+    tasks: &[Task],
+    events: &[AuditEvent],
+    window_days: i64,
+    now: NaiveDateTime,
+) -> MilestoneReport {
+    let lifecycles = lifecycles(events);
+    let window_days = window_days.max(1);
+    let window_start = now - Duration::days(window_days);
+    let weeks = window_days as f64 / 7.0;
+    let done_at = |task: &Task| -> Option<(NaiveDateTime, String)> {
+        if !is_done(task) {
+            return None;
+        }
+        lifecycles
+            .get(&task.id.to_lowercase())
+            .and_then(|life| life.done.clone())
+            .or_else(|| {
+                let stored = task.updated_date.clone()?;
+                parse_task_date(&stored).map(|at| (at, stored))
+            })
+    };
+    let in_window = |at: &NaiveDateTime| *at > window_start && *at <= now;
+
+    let mut backlog_recent = 0usize;
+    let mut unassigned = 0usize;
+    // Keyed by lowercase name; the first spelling seen is displayed.
+    let mut grouped: BTreeMap<String, (String, Vec<Member>)> = BTreeMap::new();
+    for task in tasks {
+        let done = done_at(task);
+        if done.as_ref().is_some_and(|(at, _)| in_window(at)) {
+            backlog_recent += 1;
+        }
+        let Some(name) = task_milestone(task) else {
+            if !is_done(task) && !is_cancelled(task) {
+                unassigned += 1;
+            }
+            continue;
+        };
+        grouped
+            .entry(name.to_lowercase())
+            .or_insert_with(|| (name.clone(), Vec::new()))
+            .1
+            .push((task, done));
+    }
+    let backlog_velocity = round1(backlog_recent as f64 / weeks);
+
+    let milestones = grouped
+        .into_values()
+        .map(|(name, mut members)| {
+            members.sort_by(|a, b| a.0.id_num().cmp(&b.0.id_num()).then(a.0.id.cmp(&b.0.id)));
+            let total = members.len();
+            let done = members.iter().filter(|(task, _)| is_done(task)).count();
+            let cancelled = members
+                .iter()
+                .filter(|(task, _)| is_cancelled(task))
+                .count();
+            let remaining = total - done - cancelled;
+            let countable = total - cancelled;
+            let percent_complete = if countable == 0 {
+                100.0
+            } else {
+                round1(done as f64 * 100.0 / countable as f64)
+            };
+            let recent = members
+                .iter()
+                .filter(|(_, done)| done.as_ref().is_some_and(|(at, _)| in_window(at)))
+                .count();
+            let (velocity, velocity_source) = if recent > 0 {
+                (round1(recent as f64 / weeks), VelocitySource::Milestone)
+            } else {
+                (backlog_velocity, VelocitySource::Backlog)
+            };
+            let projected_finish = if remaining == 0 {
+                members
+                    .iter()
+                    .filter_map(|(_, done)| done.as_ref().map(|(at, _)| *at))
+                    .max()
+            } else if velocity > 0.0 {
+                let days = (remaining as f64 / velocity * 7.0).ceil() as i64;
+                Some(now + Duration::days(days))
+            } else {
+                None
+            }
+            .map(|at| at.format("%Y-%m-%d").to_string());
+            MilestoneProgress {
+                name,
+                total,
+                done,
+                cancelled,
+                remaining,
+                percent_complete,
+                velocity_per_week: velocity,
+                velocity_source,
+                projected_finish,
+                tasks: members
+                    .into_iter()
+                    .map(|(task, done)| MilestoneTask {
+                        id: task.id.clone(),
+                        title: task.title.clone(),
+                        status: task.status.clone(),
+                        priority: task.priority.clone(),
+                        assignee: task.assignee.clone(),
+                        done_at: done.map(|(_, stored)| stored),
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    MilestoneReport {
+        window_days,
+        backlog_velocity_per_week: backlog_velocity,
+        milestones,
+        unassigned,
+    }
+}
+
+/// A milestone task with its last move into Done.
+type Member<'a> = (&'a Task, Option<(NaiveDateTime, String)>);
+
+fn is_cancelled(task: &Task) -> bool {
+    task.status.trim().eq_ignore_ascii_case("cancelled")
+}
+
+fn round1(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn task(id: &str, status: &str, milestone: Option<&str>) -> Task {
+        let mut extra = std::collections::HashMap::new();
+        if let Some(milestone) = milestone {
+            extra.insert(
+                MILESTONE_FIELD.to_string(),
+                serde_yaml::Value::String(milestone.to_string()),
+            );
+        }
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra,
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    fn done_event(timestamp: &str, task_id: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: "set_status".to_string(),
+            task_id: Some(task_id.to_string()),
+            details: json!({ "status": "Done" }),
+        }
+    }
+
+    #[test]
+    fn milestone_progress_projects_finish_from_velocity() {
+        let mut legacy = task("task-005", "Done", Some("v2"));
+        legacy.updated_date = Some("2026-03-01 10:00".to_string());
+        let tasks = vec![
+            task("task-001", "Done", Some("v1")),
+            task("task-002", "Done", Some("V1")),
+            task("task-003", "To Do", Some("v1")),
+            task("task-004", "Cancelled", Some("v1")),
+            legacy,
+            task("task-006", "In Progress", Some("v3")),
+            task("task-007", "To Do", None),
+        ];
+        let events = vec![
+            done_event("2026-03-20 09:00", "task-001"),
+            done_event("2026-03-25 09:00", "task-002"),
+        ];
+        let now = parse_task_date("2026-03-28 12:00").expect("now");
+
+        let report = milestone_from_events(&tasks, &events, 28, now);
+        assert_eq!(report.unassigned, 1);
+        // task-001, task-002, and task-005 (by updated_date) closed in the last 28 days.
+        assert_eq!(report.backlog_velocity_per_week, 0.8);
+        let names: Vec<&str> = report.milestones.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["v1", "v2", "v3"]);
+
+        let v1 = &report.milestones[0];
+        assert_eq!(
+            (v1.total, v1.done, v1.cancelled, v1.remaining),
+            (4, 2, 1, 1)
+        );
+        assert_eq!(v1.percent_complete, 66.7);
+        assert_eq!(v1.velocity_per_week, 0.5);
+        assert_eq!(v1.velocity_source, VelocitySource::Milestone);
+        assert_eq!(v1.projected_finish.as_deref(), Some("2026-04-11"));
+        assert_eq!(v1.tasks[1].done_at.as_deref(), Some("2026-03-25 09:00"));
+
+        let v2 = &report.milestones[1];
+        assert_eq!(v2.percent_complete, 100.0);
+        assert_eq!(v2.projected_finish.as_deref(), Some("2026-03-01"));
+
+        let v3 = &report.milestones[2];
+        assert_eq!(v3.velocity_source, VelocitySource::Backlog);
+        assert_eq!(v3.projected_finish.as_deref(), Some("2026-04-06"));
+
+        let quiet = milestone_from_events(&tasks[2..4], &[], 28, now);
+        assert_eq!(quiet.milestones[0].projected_finish, None);
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::Path;
+
+use chrono::Local;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::task::Task;
+
+/// Folder (inside the vault) holding one note per task.
+pub const OBSIDIAN_TASKS_DIR: &str = "tasks";
+/// Dashboard note written at the vault root.
+pub const OBSIDIAN_DASHBOARD: &str = "Dashboard.md";
+
+#[derive(Debug, Error)]
+pub enum ObsidianExportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ObsidianExportSummary {
+    pub output: String,
+    pub notes: usize,
+    pub dashboard: String,
+}
+
+#[derive(Serialize)]
+struct NoteFrontMatter<'a> {
+    id: &'a str,
+    title: &'a str,
+    aliases: Vec<&'a str>,
+    status: &'a str,
+    priority: &'a str,
+    phase: &'a str,
+    kind: &'a str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    labels: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    assignee: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    epic: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    dependencies: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    updated: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct DashboardFrontMatter {
+    workmesh: &'static str,
+    generated_at: String,
+    total: usize,
+    by_status: BTreeMap<String, usize>,
+}
+
+/// Write the backlog as an Obsidian vault under `output`.
+///
+/// Each task becomes `tasks/<id>.md` (file names only depend on the id, so renaming a task
+/// keeps links intact), with Dataview-friendly front matter and `[[...]]` links to its epic,
+/// dependencies, and children. `Dashboard.md` summarizes the backlog. Re-running overwrites
+/// notes in place; other files in the vault are left alone.
+pub fn export_obsidian(
+    tasks: &[Task],
+    output: &Path,
+) -> Result<ObsidianExportSummary, ObsidianExportError> {
+    let tasks_dir = output.join(OBSIDIAN_TASKS_DIR);
+    fs::create_dir_all(&tasks_dir)?;
+
+    let parents = parent_map(tasks);
+    let mut children: HashMap<String, Vec<&Task>> = HashMap::new();
+    for task in tasks {
+        if let Some(parent) = parents.get(&task.id.to_lowercase()) {
+            children
+                .entry(parent.to_lowercase())
+                .or_default()
+                .push(task);
+        }
+    }
+    let titles: HashMap<String, &Task> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task))
+        .collect();
+    let link = |id: &str| match titles.get(&id.to_lowercase()) {
+        Some(task) => wiki_link(task),
+        None => format!("[[{}]]", note_name(id)),
+    };
+
+    for task in tasks {
+        let epic = parents.get(&task.id.to_lowercase()).map(|id| link(id));
+        let dependencies: Vec<String> = task.dependencies.iter().map(|id| link(id)).collect();
+        let front = NoteFrontMatter {
+            id: &task.id,
+            title: &task.title,
+            aliases: vec![task.title.as_str()],
+            status: &task.status,
+            priority: &task.priority,
+            phase: &task.phase,
+            kind: &task.kind,
+            labels: task.labels.clone(),
+            tags: task.labels.iter().map(|label| tag(label)).collect(),
+            assignee: task.assignee.clone(),
+            epic: epic.clone(),
+            dependencies: dependencies.clone(),
+            created: task.created_date.as_deref(),
+            updated: task.updated_date.as_deref(),
+        };
+        let mut note = format!(
+            "---\n{}---\n\n# {}\n\n",
+            serde_yaml::to_string(&front)?,
+            task.title
+        );
+        if let Some(epic) = &epic {
+            note.push_str(&format!("Epic: {}\n", epic));
+        }
+        if !dependencies.is_empty() {
+            note.push_str(&format!("Depends on: {}\n", dependencies.join(", ")));
+        }
+        if let Some(kids) = children.get(&task.id.to_lowercase()) {
+            note.push_str("\n## Children\n");
+            for kid in kids {
+                note.push_str(&format!("- {} ({})\n", wiki_link(kid), kid.status));
+            }
+        }
+        note.push('\n');
+        note.push_str(task.body.trim());
+        note.push('\n');
+        fs::write(tasks_dir.join(format!("{}.md", note_name(&task.id))), note)?;
+    }
+
+    let dashboard = output.join(OBSIDIAN_DASHBOARD);
+    fs::write(&dashboard, render_dashboard(tasks)?)?;
+
+    Ok(ObsidianExportSummary {
+        output: output.display().to_string(),
+        notes: tasks.len(),
+        dashboard: dashboard.display().to_string(),
+    })
+}
+
+fn render_dashboard(tasks: &[Task]) -> Result<String, ObsidianExportError> {
+    let mut by_status: BTreeMap<String, Vec<&Task>> = BTreeMap::new();
+    for task in tasks {
+        let status = if task.status.trim().is_empty() {
+            "(none)".to_string()
+        } else {
+            task.status.trim().to_string()
+        };
+        by_status.entry(status).or_default().push(task);
+    }
+    let front = DashboardFrontMatter {
+        workmesh: "dashboard",
+        generated_at: Local::now().format("%Y-%m-%d %H:%M").to_string(),
+        total: tasks.len(),
+        by_status: by_status
+            .iter()
+            .map(|(status, tasks)| (status.clone(), tasks.len()))
+            .collect(),
+    };
+    let mut lines = vec![
+        format!("---\n{}---", serde_yaml::to_string(&front)?),
+        String::new(),
+        "# WorkMesh Dashboard".to_string(),
+        String::new(),
+        "## Open work (Dataview)".to_string(),
+        String::new(),
+        "```dataview".to_string(),
+        format!(
+            "TABLE status, priority, phase, epic FROM \"{}\" WHERE status != \"Done\"",
+            OBSIDIAN_TASKS_DIR
+        ),
+        "SORT priority ASC".to_string(),
+        "```".to_string(),
+    ];
+    for (status, tasks) in &by_status {
+        lines.push(String::new());
+        lines.push(format!("## {} ({})", status, tasks.len()));
+        lines.push(String::new());
+        let mut tasks = tasks.clone();
+        tasks.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then_with(|| a.id.cmp(&b.id)));
+        for task in tasks {
+            lines.push(format!("- {} `{}`", wiki_link(task), task.priority));
+        }
+    }
+    lines.push(String::new());
+    Ok(lines.join("\n"))
+}
+
+/// child id (lowercase) -> parent id, from either side of the parent/child relationship.
+fn parent_map(tasks: &[Task]) -> HashMap<String, String> {
+    let mut parents = HashMap::new();
+    for task in tasks {
+        for child in &task.relationships.child {
+            parents
+                .entry(child.to_lowercase())
+                .or_insert_with(|| task.id.clone());
+        }
+    }
+    for task in tasks {
+        if let Some(parent) = task.relationships.parent.first() {
+            parents.insert(task.id.to_lowercase(), parent.clone());
+        }
+    }
+    parents
+}
+
+fn wiki_link(task: &Task) -> String {
+    let title = task.title.trim().replace(['|', '[', ']'], " ");
+    if title.is_empty() {
+        format!("[[{}]]", note_name(&task.id))
+    } else {
+        format!("[[{}|{}]]", note_name(&task.id), title)
+    }
+}
+
+/// File-name-safe note name for a task id.
+pub(crate) fn note_name(id: &str) -> String {
+    id.trim()
+        .chars()
+        .map(|ch| match ch {
+            '/' | '\\' | ':' | '#' | '^' | '|' | '[' | ']' | '?' | '*' | '"' | '<' | '>' => '-',
+            other => other,
+        })
+        .collect()
+}
+
+fn tag(label: &str) -> String {
+    label
+        .trim()
+        .chars()
+        .map(|ch| if ch.is_whitespace() { '-' } else { ch })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    #[test]
+    fn export_obsidian_writes_linked_notes_and_dashboard() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::write(
+            tasks_dir.join("task-001 - epic.md"),
+            "---\nid: task-001\ntitle: Auth epic\nkind: epic\nstatus: In Progress\n\
+priority: P1\nphase: Phase1\ndependencies: []\nlabels: [auth]\n---\n\nEpic body\n",
+        )
+        .expect("epic");
+        fs::write(
+            tasks_dir.join("task-002 - login.md"),
+            "---\nid: task-002\ntitle: Login form\nstatus: To Do\npriority: P2\n\
+phase: Phase1\ndependencies: [task-003]\nlabels: [auth, needs review]\n\
+relationships:\n  parent: [task-001]\n---\n\nLogin body\n",
+        )
+        .expect("login");
+        fs::write(
+            tasks_dir.join("task-003 - api.md"),
+            "---\nid: task-003\ntitle: Session API\nstatus: Done\npriority: P2\n\
+phase: Phase1\ndependencies: []\nlabels: []\n---\n\nAPI body\n",
+        )
+        .expect("api");
+        let tasks = load_tasks(&backlog);
+
+        let vault = temp.path().join("vault");
+        let summary = export_obsidian(&tasks, &vault).expect("export");
+        assert_eq!(summary.notes, 3);
+
+        let login = fs::read_to_string(vault.join("tasks/task-002.md")).expect("note");
+        assert!(login.contains("\nepic: ") && login.contains("[[task-001|Auth epic]]"));
+        assert!(login.contains("Depends on: [[task-003|Session API]]"));
+        assert!(login.contains("- needs-review"));
+        assert!(login.trim_end().ends_with("Login body"));
+
+        let epic = fs::read_to_string(vault.join("tasks/task-001.md")).expect("epic note");
+        assert!(epic.contains("## Children\n- [[task-002|Login form]] (To Do)"));
+
+        let dashboard = fs::read_to_string(vault.join(OBSIDIAN_DASHBOARD)).expect("dashboard");
+        assert!(dashboard.contains("workmesh: dashboard"));
+        assert!(dashboard.contains("```dataview"));
+        assert!(dashboard.contains("## Done (1)\n\n- [[task-003|Session API]] `P2`"));
+    }
+}
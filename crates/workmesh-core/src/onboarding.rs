@@ -0,0 +1,481 @@
+//! Contributor onboarding checklists (`onboarding new --for <user>`, `onboarding status`).
+//!
+//! `onboarding new` creates an epic plus one task per `[[onboarding.items]]` entry (or the
+//! built-in access / docs / first-issue checklist), all assigned to the newcomer, parented to the
+//! epic, and chained through `depends_on`. Every created task carries `onboarding: <user>`, which
+//! is how `onboarding status` finds the checklist again; after that they are ordinary tasks.
+
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+use crate::health::{first_extra, is_closed};
+use crate::inbox::normalize_user;
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_epic_file, create_task_file_with_sections, is_epic, set_relationship_field,
+    update_task_field, EpicSectionContent, FieldValue, TaskSectionContent,
+};
+
+/// Front matter field naming the newcomer on the epic and every checklist task.
+pub const ONBOARDING_FIELD: &str = "onboarding";
+pub const DEFAULT_ONBOARDING_LABEL: &str = "onboarding";
+
+#[derive(Debug, Error)]
+pub enum OnboardingError {
+    #[error("Onboarding needs a user (--for <user>)")]
+    MissingUser,
+    #[error("{user} already has an open onboarding epic: {epic_id}")]
+    AlreadyOnboarding { user: String, epic_id: String },
+    #[error("Onboarding item {item:?} depends on unknown item {key:?}")]
+    UnknownDependency { item: String, key: String },
+    #[error("Onboarding items must have a title")]
+    EmptyTitle,
+    #[error("Task error: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// `[onboarding]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+/// `{{who}}` in any text is replaced with the newcomer's handle.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnboardingConfig {
+    /// Epic title (default `Onboard {{who}}`).
+    pub title: Option<String>,
+    /// Label added to the epic and every item (default `onboarding`).
+    pub label: Option<String>,
+    pub priority: Option<String>,
+    pub phase: Option<String>,
+    /// Checklist items in order; empty means the built-in checklist.
+    #[serde(default)]
+    pub items: Vec<OnboardingItemConfig>,
+}
+
+/// One `[[onboarding.items]]` entry.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnboardingItemConfig {
+    /// Name other items use in `depends_on` (default: the item's position, `1`, `2`, ...).
+    pub key: Option<String>,
+    pub title: String,
+    pub description: Option<String>,
+    pub acceptance_criteria: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Keys of earlier items that must be done first.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+/// Project `[onboarding]` wins over global `[onboarding]`.
+pub fn resolve_onboarding_config(repo_root: &Path) -> OnboardingConfig {
+    load_config(repo_root)
+        .and_then(|config| config.onboarding)
+        .or_else(|| load_global_config().and_then(|config| config.onboarding))
+        .unwrap_or_default()
+}
+
+/// The checklist used when `[onboarding]` lists no items.
+pub fn default_onboarding_items() -> Vec<OnboardingItemConfig> {
+    let item =
+        |key: &str, title: &str, description: &str, depends_on: &[&str]| OnboardingItemConfig {
+            key: Some(key.to_string()),
+            title: title.to_string(),
+            description: Some(description.to_string()),
+            acceptance_criteria: None,
+            labels: Vec::new(),
+            depends_on: depends_on.iter().map(|key| key.to_string()).collect(),
+        };
+    vec![
+        item(
+            "access",
+            "Set up access for {{who}}",
+            "Repository, CI, chat, and issue tracker access for {{who}}.",
+            &[],
+        ),
+        item(
+            "docs",
+            "Read the project docs ({{who}})",
+            "README, architecture notes, and contribution guidelines.",
+            &["access"],
+        ),
+        item(
+            "first-issue",
+            "Ship a first issue ({{who}})",
+            "Pick a small task, open a pull request, and get it merged.",
+            &["docs"],
+        ),
+    ]
+}
+
+fn fill(text: &str, who: &str) -> String {
+    text.replace("{{who}}", who)
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OnboardingCreatedTask {
+    pub key: String,
+    pub id: String,
+    pub title: String,
+    pub path: PathBuf,
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OnboardingPlan {
+    pub user: String,
+    pub epic_id: String,
+    pub epic_title: String,
+    pub epic_path: PathBuf,
+    pub items: Vec<OnboardingCreatedTask>,
+}
+
+/// Create the onboarding epic and its checklist tasks for `user`. Ids are reserved in
+/// `initiative`'s namespace. Fails while `user` still has an open onboarding epic.
+pub fn create_onboarding(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    initiative: &str,
+    config: &OnboardingConfig,
+    user: &str,
+) -> Result<OnboardingPlan, OnboardingError> {
+    let who = user.trim().trim_start_matches('@').trim().to_string();
+    if who.is_empty() {
+        return Err(OnboardingError::MissingUser);
+    }
+    if let Some(epic) = onboarding_epics(tasks, Some(&who))
+        .into_iter()
+        .find(|epic| !is_closed(epic))
+    {
+        return Err(OnboardingError::AlreadyOnboarding {
+            user: who,
+            epic_id: epic.id.clone(),
+        });
+    }
+
+    let items = if config.items.is_empty() {
+        default_onboarding_items()
+    } else {
+        config.items.clone()
+    };
+    let keys: Vec<String> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            item.key
+                .as_deref()
+                .map(str::trim)
+                .filter(|key| !key.is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| (index + 1).to_string())
+        })
+        .collect();
+    for (index, item) in items.iter().enumerate() {
+        if item.title.trim().is_empty() {
+            return Err(OnboardingError::EmptyTitle);
+        }
+        for key in &item.depends_on {
+            if !keys[..index].iter().any(|known| known == key.trim()) {
+                return Err(OnboardingError::UnknownDependency {
+                    item: keys[index].clone(),
+                    key: key.trim().to_string(),
+                });
+            }
+        }
+    }
+
+    let label = config
+        .label
+        .as_deref()
+        .map(str::trim)
+        .filter(|label| !label.is_empty())
+        .unwrap_or(DEFAULT_ONBOARDING_LABEL)
+        .to_string();
+    let priority = config.priority.as_deref().unwrap_or("P2");
+    let phase = config.phase.as_deref().unwrap_or("Phase1");
+    let assignee = vec![who.clone()];
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let mut known: Vec<Task> = tasks.to_vec();
+
+    let epic_title = fill(config.title.as_deref().unwrap_or("Onboard {{who}}"), &who);
+    let epic_id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+    let checklist: Vec<String> = items
+        .iter()
+        .map(|item| format!("- {}", fill(&item.title, &who)))
+        .collect();
+    let epic_path = create_epic_file(
+        &tasks_dir,
+        &epic_id,
+        &epic_title,
+        "To Do",
+        priority,
+        phase,
+        std::slice::from_ref(&label),
+        &assignee,
+        &EpicSectionContent {
+            problem: fill(
+                "{{who}} is new to the project and needs a guided start.",
+                &who,
+            ),
+            outcomes: checklist.join("\n"),
+            scope: "The checklist tasks under this epic.".to_string(),
+            non_goals: "Project work beyond the first issue.".to_string(),
+        },
+    )?;
+    update_task_field(
+        &epic_path,
+        ONBOARDING_FIELD,
+        Some(FieldValue::Scalar(who.clone())),
+    )?;
+    known.push(parse_task_file(&epic_path)?);
+
+    let mut ids: HashMap<String, String> = HashMap::new();
+    let mut created = Vec::new();
+    for (item, key) in items.iter().zip(&keys) {
+        let title = fill(&item.title, &who);
+        let dependencies: Vec<String> = item
+            .depends_on
+            .iter()
+            .filter_map(|dep| ids.get(dep.trim()).cloned())
+            .collect();
+        let mut labels = vec![label.clone()];
+        for extra in &item.labels {
+            if !labels.iter().any(|known| known.eq_ignore_ascii_case(extra)) {
+                labels.push(extra.trim().to_string());
+            }
+        }
+        let id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+        let path = create_task_file_with_sections(
+            &tasks_dir,
+            &id,
+            &title,
+            "To Do",
+            priority,
+            phase,
+            &dependencies,
+            &labels,
+            &assignee,
+            &TaskSectionContent {
+                description: fill(item.description.as_deref().unwrap_or(&item.title), &who),
+                acceptance_criteria: item
+                    .acceptance_criteria
+                    .as_deref()
+                    .map(|text| fill(text, &who))
+                    .unwrap_or_else(|| format!("- {}", title)),
+                definition_of_done: fill("- Confirmed done by {{who}}", &who),
+            },
+        )?;
+        update_task_field(
+            &path,
+            ONBOARDING_FIELD,
+            Some(FieldValue::Scalar(who.clone())),
+        )?;
+        set_relationship_field(&path, "parent", vec![epic_id.clone()])?;
+        known.push(parse_task_file(&path)?);
+        ids.insert(key.clone(), id.clone());
+        created.push(OnboardingCreatedTask {
+            key: key.clone(),
+            id,
+            title,
+            path,
+            dependencies,
+        });
+    }
+    set_relationship_field(
+        &epic_path,
+        "child",
+        created.iter().map(|item| item.id.clone()).collect(),
+    )?;
+
+    Ok(OnboardingPlan {
+        user: who,
+        epic_id,
+        epic_title,
+        epic_path,
+        items: created,
+    })
+}
+
+fn onboarding_user(task: &Task) -> Option<String> {
+    first_extra(task, &[ONBOARDING_FIELD])
+}
+
+/// Onboarding epics, optionally only `user`'s, in id order.
+fn onboarding_epics<'a>(tasks: &'a [Task], user: Option<&str>) -> Vec<&'a Task> {
+    let wanted = user.map(normalize_user);
+    let mut epics: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| is_epic(task))
+        .filter(|task| match (onboarding_user(task), wanted.as_deref()) {
+            (Some(who), Some(wanted)) => normalize_user(&who) == wanted,
+            (Some(_), None) => true,
+            (None, _) => false,
+        })
+        .collect();
+    epics.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then(a.id.cmp(&b.id)));
+    epics
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct OnboardingItemStatus {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub done: bool,
+    /// Open, and every dependency is done.
+    pub ready: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OnboardingStatus {
+    pub user: String,
+    pub epic_id: String,
+    pub epic_title: String,
+    pub epic_status: String,
+    pub done: usize,
+    pub total: usize,
+    pub percent: f64,
+    pub items: Vec<OnboardingItemStatus>,
+}
+
+/// Progress of every onboarding checklist (or only `user`'s), oldest first.
+pub fn onboarding_status(tasks: &[Task], user: Option<&str>) -> Vec<OnboardingStatus> {
+    let closed: HashSet<String> = tasks
+        .iter()
+        .filter(|task| is_closed(task))
+        .map(|task| task.id.to_lowercase())
+        .collect();
+    onboarding_epics(tasks, user)
+        .into_iter()
+        .map(|epic| {
+            let epic_key = epic.id.to_lowercase();
+            let mut members: Vec<&Task> = tasks
+                .iter()
+                .filter(|task| !is_epic(task))
+                .filter(|task| {
+                    task.relationships
+                        .parent
+                        .iter()
+                        .any(|parent| parent.to_lowercase() == epic_key)
+                })
+                .collect();
+            members.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then(a.id.cmp(&b.id)));
+            let items: Vec<OnboardingItemStatus> = members
+                .into_iter()
+                .map(|task| {
+                    let done = is_closed(task);
+                    OnboardingItemStatus {
+                        id: task.id.clone(),
+                        title: task.title.clone(),
+                        status: task.status.clone(),
+                        done,
+                        ready: !done
+                            && task
+                                .dependencies
+                                .iter()
+                                .all(|dep| closed.contains(&dep.to_lowercase())),
+                    }
+                })
+                .collect();
+            let done = items.iter().filter(|item| item.done).count();
+            let total = items.len();
+            OnboardingStatus {
+                user: onboarding_user(epic).unwrap_or_default(),
+                epic_id: epic.id.clone(),
+                epic_title: epic.title.clone(),
+                epic_status: epic.status.clone(),
+                done,
+                total,
+                percent: if total == 0 {
+                    0.0
+                } else {
+                    (done as f64 * 1000.0 / total as f64).round() / 10.0
+                },
+                items,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    #[test]
+    fn creates_linked_checklist_and_reports_progress() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        fs::create_dir_all(backlog_dir.join("tasks")).expect("tasks dir");
+
+        let plan = create_onboarding(
+            &backlog_dir,
+            &[],
+            "login",
+            &OnboardingConfig::default(),
+            "@Alice",
+        )
+        .expect("create");
+        assert_eq!(plan.user, "Alice");
+        assert_eq!(plan.epic_title, "Onboard Alice");
+        let keys: Vec<&str> = plan.items.iter().map(|item| item.key.as_str()).collect();
+        assert_eq!(keys, vec!["access", "docs", "first-issue"]);
+        assert_eq!(plan.items[1].dependencies, vec![plan.items[0].id.clone()]);
+        assert_eq!(plan.items[2].title, "Ship a first issue (Alice)");
+
+        let tasks = load_tasks(&backlog_dir);
+        assert_eq!(tasks.len(), 4);
+        let access = tasks
+            .iter()
+            .find(|task| task.id == plan.items[0].id)
+            .expect("access");
+        assert_eq!(access.assignee, vec!["Alice"]);
+        assert_eq!(access.relationships.parent, vec![plan.epic_id.clone()]);
+        assert!(access.labels.contains(&"onboarding".to_string()));
+
+        let err = create_onboarding(
+            &backlog_dir,
+            &tasks,
+            "login",
+            &OnboardingConfig::default(),
+            "alice",
+        )
+        .unwrap_err();
+        assert!(matches!(err, OnboardingError::AlreadyOnboarding { .. }));
+
+        update_task_field(
+            access.file_path.as_ref().expect("path"),
+            "status",
+            Some(FieldValue::from("Done")),
+        )
+        .expect("done");
+        let status = onboarding_status(&load_tasks(&backlog_dir), Some("ALICE"));
+        assert_eq!(status.len(), 1);
+        assert_eq!((status[0].done, status[0].total), (1, 3));
+        assert_eq!(status[0].percent, 33.3);
+        let ready: Vec<bool> = status[0].items.iter().map(|item| item.ready).collect();
+        assert_eq!(ready, vec![false, true, false]);
+        assert!(onboarding_status(&load_tasks(&backlog_dir), Some("bob")).is_empty());
+
+        let bad = OnboardingConfig {
+            items: vec![OnboardingItemConfig {
+                title: "Pair with a mentor".to_string(),
+                depends_on: vec!["nope".to_string()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let err = create_onboarding(&backlog_dir, &[], "login", &bad, "bob").unwrap_err();
+        assert!(matches!(err, OnboardingError::UnknownDependency { .. }));
+    }
+}
@@ -0,0 +1,324 @@
+use std::path::{Path, PathBuf};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{load_config, load_global_config};
+use crate::task::{load_tasks, parse_task_file, Task, TaskParseError};
+use crate::task_ops::{update_task_field, FieldValue};
+use crate::timewindow::parse_timestamp_ref;
+
+/// `[policies]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PoliciesConfig {
+    /// Apply policies at the start of every CLI command (default: only via `policies run`).
+    pub auto: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<PolicyRule>,
+}
+
+/// One `[[policies.rules]]` entry: match conditions (all must hold) plus actions.
+///
+/// Example: P3 tasks untouched for 90 days get label `stale` and status `Deferred`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PolicyRule {
+    pub name: String,
+    /// Match any of these priorities (empty = any priority).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub priority: Vec<String>,
+    /// Match any of these statuses (empty = any status except Done).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub status: Vec<String>,
+    /// Match tasks carrying any of these labels (empty = any labels).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    /// Match tasks whose `updated_date` (falling back to `created_date`) is this many days old.
+    pub untouched_days: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add_labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove_labels: Vec<String>,
+    pub set_status: Option<String>,
+}
+
+/// A change a policy would make (or made) to one task. Only real changes are reported.
+#[derive(Debug, Clone, Serialize)]
+pub struct PolicyAction {
+    pub policy: String,
+    pub task_id: String,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub add_labels: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub remove_labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub set_status: Option<String>,
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PolicyRunReport {
+    pub ok: bool,
+    pub apply: bool,
+    pub evaluated_at: String,
+    pub rules: usize,
+    pub actions: Vec<PolicyAction>,
+}
+
+/// Project `[policies]` wins over global `[policies]`.
+pub fn resolve_policies(repo_root: &Path) -> PoliciesConfig {
+    load_config(repo_root)
+        .and_then(|config| config.policies)
+        .or_else(|| load_global_config().and_then(|config| config.policies))
+        .unwrap_or_default()
+}
+
+pub fn evaluate_policies(
+    tasks: &[Task],
+    rules: &[PolicyRule],
+    now: NaiveDateTime,
+) -> Vec<PolicyAction> {
+    let mut actions = Vec::new();
+    for rule in rules {
+        for task in tasks {
+            let Some(reason) = rule_matches(rule, task, now) else {
+                continue;
+            };
+            let add_labels: Vec<String> = rule
+                .add_labels
+                .iter()
+                .filter(|label| !has_label(task, label))
+                .cloned()
+                .collect();
+            let remove_labels: Vec<String> = rule
+                .remove_labels
+                .iter()
+                .filter(|label| has_label(task, label))
+                .cloned()
+                .collect();
+            let set_status = rule
+                .set_status
+                .as_ref()
+                .filter(|status| !task.status.trim().eq_ignore_ascii_case(status.trim()))
+                .cloned();
+            if add_labels.is_empty() && remove_labels.is_empty() && set_status.is_none() {
+                continue;
+            }
+            actions.push(PolicyAction {
+                policy: rule.name.clone(),
+                task_id: task.id.clone(),
+                reason,
+                add_labels,
+                remove_labels,
+                set_status,
+                path: task.file_path.clone(),
+            });
+        }
+    }
+    actions
+}
+
+/// Write the actions to task files. Each file is re-read first so several policies can stack.
+pub fn apply_policy_actions(actions: &[PolicyAction]) -> Result<(), TaskParseError> {
+    for action in actions {
+        let Some(path) = action.path.as_ref() else {
+            continue;
+        };
+        if !action.add_labels.is_empty() || !action.remove_labels.is_empty() {
+            let task = parse_task_file(path)?;
+            let mut labels: Vec<String> = task
+                .labels
+                .into_iter()
+                .filter(|label| {
+                    !action
+                        .remove_labels
+                        .iter()
+                        .any(|remove| remove.eq_ignore_ascii_case(label))
+                })
+                .collect();
+            for label in &action.add_labels {
+                if !labels
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(label))
+                {
+                    labels.push(label.clone());
+                }
+            }
+            update_task_field(path, "labels", Some(FieldValue::List(labels)))?;
+        }
+        if let Some(status) = action.set_status.as_deref() {
+            update_task_field(path, "status", Some(status.into()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Evaluate `rules` against the active backlog; write the changes only when `apply` is set.
+pub fn run_policies(
+    backlog_dir: &Path,
+    rules: &[PolicyRule],
+    now: NaiveDateTime,
+    apply: bool,
+) -> Result<PolicyRunReport, TaskParseError> {
+    let tasks = load_tasks(backlog_dir);
+    let actions = evaluate_policies(&tasks, rules, now);
+    if apply {
+        apply_policy_actions(&actions)?;
+    }
+    Ok(PolicyRunReport {
+        ok: true,
+        apply,
+        evaluated_at: now.format("%Y-%m-%d %H:%M").to_string(),
+        rules: rules.len(),
+        actions,
+    })
+}
+
+fn rule_matches(rule: &PolicyRule, task: &Task, now: NaiveDateTime) -> Option<String> {
+    let mut reasons = Vec::new();
+    if rule.status.is_empty() {
+        if task.status.trim().eq_ignore_ascii_case("done") {
+            return None;
+        }
+    } else if !matches_any(&rule.status, &task.status) {
+        return None;
+    }
+    if !rule.priority.is_empty() {
+        if !matches_any(&rule.priority, &task.priority) {
+            return None;
+        }
+        reasons.push(format!("priority {}", task.priority));
+    }
+    if !rule.labels.is_empty() && !rule.labels.iter().any(|label| has_label(task, label)) {
+        return None;
+    }
+    if let Some(days) = rule.untouched_days {
+        let touched = task
+            .updated_date
+            .as_deref()
+            .or(task.created_date.as_deref())
+            .and_then(parse_task_date)?;
+        let idle = (now - touched).num_days();
+        if idle < days as i64 {
+            return None;
+        }
+        reasons.push(format!("untouched for {} days", idle));
+    }
+    if reasons.is_empty() {
+        reasons.push("matched".to_string());
+    }
+    Some(reasons.join(", "))
+}
+
+fn matches_any(values: &[String], value: &str) -> bool {
+    values
+        .iter()
+        .any(|candidate| candidate.trim().eq_ignore_ascii_case(value.trim()))
+}
+
+fn has_label(task: &Task, label: &str) -> bool {
+    task.labels
+        .iter()
+        .any(|existing| existing.eq_ignore_ascii_case(label.trim()))
+}
+
+/// A stored date or timestamp in local time; RFC3339 values written in another zone are
+/// converted, so comparisons see the same instant wherever the task was edited.
+pub(crate) fn parse_task_date(value: &str) -> Option<NaiveDateTime> {
+    parse_timestamp_ref(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task_ops::create_task_file;
+    use tempfile::TempDir;
+
+    fn stale_rule() -> PolicyRule {
+        PolicyRule {
+            name: "stale-p3".to_string(),
+            priority: vec!["P3".to_string()],
+            untouched_days: Some(90),
+            add_labels: vec!["stale".to_string()],
+            set_status: Some("Deferred".to_string()),
+            ..PolicyRule::default()
+        }
+    }
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        std::fs::create_dir_all(&tasks_dir).expect("tasks");
+        for (id, priority, updated) in [
+            ("task-001", "P3", "2026-01-01 10:00"),
+            ("task-002", "P3", "2026-06-01 10:00"),
+            ("task-003", "P1", "2026-01-01 10:00"),
+        ] {
+            let path = create_task_file(
+                &tasks_dir,
+                id,
+                "Task",
+                "To Do",
+                priority,
+                "Phase1",
+                &[],
+                &[],
+                &[],
+            )
+            .expect("task");
+            update_task_field(&path, "updated_date", Some(updated.into())).expect("date");
+        }
+        (temp, backlog)
+    }
+
+    fn now() -> NaiveDateTime {
+        parse_task_date("2026-06-15 10:00").expect("now")
+    }
+
+    #[test]
+    fn dry_run_reports_only_matching_tasks() {
+        let (_temp, backlog) = setup();
+        let report = run_policies(&backlog, &[stale_rule()], now(), false).expect("run");
+        let ids: Vec<&str> = report.actions.iter().map(|a| a.task_id.as_str()).collect();
+        assert_eq!(ids, vec!["task-001"]);
+        assert_eq!(report.actions[0].add_labels, vec!["stale"]);
+        assert_eq!(report.actions[0].set_status.as_deref(), Some("Deferred"));
+
+        let tasks = load_tasks(&backlog);
+        assert!(tasks.iter().all(|task| task.status == "To Do"));
+    }
+
+    #[test]
+    fn apply_writes_changes_and_is_idempotent() {
+        let (_temp, backlog) = setup();
+        let report = run_policies(&backlog, &[stale_rule()], now(), true).expect("run");
+        assert_eq!(report.actions.len(), 1);
+
+        let tasks = load_tasks(&backlog);
+        let task = tasks.iter().find(|t| t.id == "task-001").expect("task");
+        assert_eq!(task.status, "Deferred");
+        assert_eq!(task.labels, vec!["stale"]);
+
+        let again = run_policies(&backlog, &[stale_rule()], now(), true).expect("rerun");
+        assert!(again.actions.is_empty());
+    }
+
+    #[test]
+    fn policies_config_parses_from_toml() {
+        let text = r#"
+auto = true
+
+[[rules]]
+name = "stale-p3"
+priority = ["P3"]
+untouched_days = 90
+add_labels = ["stale"]
+set_status = "Deferred"
+"#;
+        let config: PoliciesConfig = toml::from_str(text).expect("parse");
+        assert_eq!(config.auto, Some(true));
+        assert_eq!(config.rules, vec![stale_rule()]);
+    }
+}
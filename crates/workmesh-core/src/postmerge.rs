@@ -0,0 +1,475 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+
+/// Front matter fields whose values are lists; divergent edits are suggested as a union.
+const LIST_FIELDS: &[&str] = &["dependencies", "labels", "assignee", "blocked_by"];
+/// Fields that churn on every edit and are not worth reporting.
+const IGNORED_FIELDS: &[&str] = &["updated_date", "lease"];
+
+/// The two sides of the merge being inspected.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct MergeParents {
+    pub ours: String,
+    pub theirs: String,
+    pub base: String,
+    /// True while `MERGE_HEAD` exists (conflicted merge not yet committed).
+    pub in_progress: bool,
+}
+
+/// Last commit that touched a field line on one side, from `git blame`.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct BlameInfo {
+    pub commit: String,
+    pub author: String,
+    pub time: i64,
+}
+
+/// A front matter field both sides changed away from the merge base, to different values.
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldConflict {
+    pub task_id: String,
+    pub path: PathBuf,
+    pub field: String,
+    pub base: Option<String>,
+    pub ours: Option<String>,
+    pub theirs: Option<String>,
+    /// Value in the working tree after the merge.
+    pub current: Option<String>,
+    pub ours_blame: Option<BlameInfo>,
+    pub theirs_blame: Option<BlameInfo>,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConflictReport {
+    pub parents: Option<MergeParents>,
+    pub conflicts: Vec<FieldConflict>,
+    /// Task files that still contain `<<<<<<<` conflict markers.
+    pub unresolved_markers: Vec<PathBuf>,
+    pub warnings: Vec<String>,
+}
+
+/// Parents of the merge to inspect: an in-progress merge (`HEAD` + `MERGE_HEAD`) first,
+/// otherwise `merge_rev` (default `HEAD`) when it is a merge commit.
+pub fn detect_merge_parents(repo_root: &Path, merge_rev: Option<&str>) -> Option<MergeParents> {
+    let (ours, theirs, in_progress) = match (merge_rev, rev_parse(repo_root, "MERGE_HEAD")) {
+        (None, Some(merge_head)) => (rev_parse(repo_root, "HEAD")?, merge_head, true),
+        _ => {
+            let rev = merge_rev.unwrap_or("HEAD");
+            let ours = rev_parse(repo_root, &format!("{}^1", rev))?;
+            let theirs = rev_parse(repo_root, &format!("{}^2", rev))?;
+            (ours, theirs, false)
+        }
+    };
+    let base = git(repo_root, &["merge-base", &ours, &theirs])?;
+    Some(MergeParents {
+        ours,
+        theirs,
+        base,
+        in_progress,
+    })
+}
+
+/// Compare task front matter across the merge parents and flag fields that diverged.
+pub fn conflict_report(
+    repo_root: &Path,
+    tasks_dir: &Path,
+    merge_rev: Option<&str>,
+) -> ConflictReport {
+    let mut report = ConflictReport {
+        unresolved_markers: files_with_markers(tasks_dir),
+        ..ConflictReport::default()
+    };
+    let Some(parents) = detect_merge_parents(repo_root, merge_rev) else {
+        let subject = match merge_rev {
+            Some(rev) => format!("{} is not a merge commit", rev),
+            None => "No merge in progress and HEAD is not a merge commit".to_string(),
+        };
+        report
+            .warnings
+            .push(format!("{}; skipping conflict report", subject));
+        return report;
+    };
+    let Ok(tasks_rel) = tasks_dir.strip_prefix(repo_root) else {
+        report.warnings.push(format!(
+            "Tasks directory {} is outside the repository; skipping conflict report",
+            tasks_dir.display()
+        ));
+        report.parents = Some(parents);
+        return report;
+    };
+    let tasks_rel = tasks_rel.to_string_lossy().replace('\\', "/");
+
+    let ours_changed = changed_files(repo_root, &parents.base, &parents.ours, &tasks_rel);
+    let theirs_changed = changed_files(repo_root, &parents.base, &parents.theirs, &tasks_rel);
+    for path in ours_changed.intersection(&theirs_changed) {
+        let Some(ours_text) = show_file(repo_root, &parents.ours, path) else {
+            continue;
+        };
+        let Some(theirs_text) = show_file(repo_root, &parents.theirs, path) else {
+            continue;
+        };
+        let base = show_file(repo_root, &parents.base, path)
+            .map(|text| front_matter_fields(&text))
+            .unwrap_or_default();
+        let ours = front_matter_fields(&ours_text);
+        let theirs = front_matter_fields(&theirs_text);
+        let current = fs::read_to_string(repo_root.join(path))
+            .map(|text| front_matter_fields(&text))
+            .unwrap_or_default();
+        let task_id = [&current, &ours, &theirs]
+            .iter()
+            .find_map(|fields| fields.get("id").map(|field| field.value.clone()))
+            .unwrap_or_else(|| path.clone());
+
+        let keys: BTreeSet<&String> = ours.keys().chain(theirs.keys()).collect();
+        for key in keys {
+            if IGNORED_FIELDS.contains(&key.as_str()) {
+                continue;
+            }
+            let base_value = base.get(key).map(|field| field.value.clone());
+            let ours_field = ours.get(key);
+            let theirs_field = theirs.get(key);
+            let ours_value = ours_field.map(|field| field.value.clone());
+            let theirs_value = theirs_field.map(|field| field.value.clone());
+            if ours_value == theirs_value || ours_value == base_value || theirs_value == base_value
+            {
+                continue;
+            }
+            let ours_blame =
+                ours_field.and_then(|field| blame_line(repo_root, &parents.ours, path, field.line));
+            let theirs_blame = theirs_field
+                .and_then(|field| blame_line(repo_root, &parents.theirs, path, field.line));
+            let suggestion = suggest_resolution(
+                key,
+                ours_value.as_deref(),
+                theirs_value.as_deref(),
+                ours_blame.as_ref(),
+                theirs_blame.as_ref(),
+            );
+            report.conflicts.push(FieldConflict {
+                task_id: task_id.clone(),
+                path: PathBuf::from(path),
+                field: key.clone(),
+                base: base_value,
+                ours: ours_value,
+                theirs: theirs_value,
+                current: current.get(key).map(|field| field.value.clone()),
+                ours_blame,
+                theirs_blame,
+                suggestion,
+            });
+        }
+    }
+    report.parents = Some(parents);
+    report
+}
+
+/// Suggest how to settle a divergent field: the furthest-along status, the union of list
+/// fields, otherwise the side changed most recently.
+pub fn suggest_resolution(
+    field: &str,
+    ours: Option<&str>,
+    theirs: Option<&str>,
+    ours_blame: Option<&BlameInfo>,
+    theirs_blame: Option<&BlameInfo>,
+) -> String {
+    let (Some(ours), Some(theirs)) = (ours, theirs) else {
+        return match (ours, theirs) {
+            (Some(value), None) => format!("keep ours ({}); theirs removed the field", value),
+            (None, Some(value)) => format!("keep theirs ({}); ours removed the field", value),
+            _ => "nothing to resolve".to_string(),
+        };
+    };
+    if field == "status" {
+        return if status_rank(theirs) > status_rank(ours) {
+            format!("keep theirs ({}); it is further along", theirs)
+        } else if status_rank(ours) > status_rank(theirs) {
+            format!("keep ours ({}); it is further along", ours)
+        } else {
+            newer_side(ours, theirs, ours_blame, theirs_blame)
+        };
+    }
+    if LIST_FIELDS.contains(&field) {
+        let mut union: Vec<String> = Vec::new();
+        for item in list_items(ours).into_iter().chain(list_items(theirs)) {
+            if !union.iter().any(|seen| seen.eq_ignore_ascii_case(&item)) {
+                union.push(item);
+            }
+        }
+        return format!("merge both sides: [{}]", union.join(", "));
+    }
+    newer_side(ours, theirs, ours_blame, theirs_blame)
+}
+
+fn newer_side(
+    ours: &str,
+    theirs: &str,
+    ours_blame: Option<&BlameInfo>,
+    theirs_blame: Option<&BlameInfo>,
+) -> String {
+    match (ours_blame, theirs_blame) {
+        (Some(ours_blame), Some(theirs_blame)) if theirs_blame.time > ours_blame.time => format!(
+            "keep theirs ({}); changed more recently by {}",
+            theirs, theirs_blame.author
+        ),
+        (Some(ours_blame), _) => format!(
+            "keep ours ({}); changed more recently by {}",
+            ours, ours_blame.author
+        ),
+        _ => format!("review manually: ours={} theirs={}", ours, theirs),
+    }
+}
+
+fn status_rank(status: &str) -> u8 {
+    match status.trim().to_lowercase().as_str() {
+        "done" | "closed" | "cancelled" | "canceled" => 3,
+        "review" | "in review" => 2,
+        "in progress" | "doing" => 1,
+        _ => 0,
+    }
+}
+
+fn list_items(value: &str) -> Vec<String> {
+    value
+        .lines()
+        .flat_map(|line| line.split(','))
+        .map(|item| {
+            item.trim()
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .trim_start_matches('-')
+                .trim()
+                .trim_matches(|ch| ch == '"' || ch == '\'')
+                .to_string()
+        })
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrontMatterField {
+    /// Raw value, including indented continuation lines (block lists).
+    value: String,
+    /// 1-based line number of the `key:` line in the file.
+    line: usize,
+}
+
+/// Top-level front matter keys with their raw values, without a YAML round-trip so that
+/// line numbers stay available for blame.
+fn front_matter_fields(text: &str) -> BTreeMap<String, FrontMatterField> {
+    let mut fields: BTreeMap<String, FrontMatterField> = BTreeMap::new();
+    let mut lines = text.lines().enumerate();
+    match lines.next() {
+        Some((_, first)) if first.trim() == "---" => {}
+        _ => return fields,
+    }
+    let mut current: Option<String> = None;
+    for (idx, line) in lines {
+        if line.trim() == "---" {
+            break;
+        }
+        let continuation = line.starts_with(' ') || line.starts_with('\t') || line.starts_with('-');
+        if continuation {
+            if let Some(field) = current.as_ref().and_then(|key| fields.get_mut(key)) {
+                if !field.value.is_empty() {
+                    field.value.push('\n');
+                }
+                field.value.push_str(line.trim());
+            }
+            continue;
+        }
+        let Some((key, value)) = line.split_once(':') else {
+            current = None;
+            continue;
+        };
+        let key = key.trim().to_string();
+        fields.insert(
+            key.clone(),
+            FrontMatterField {
+                value: value.trim().to_string(),
+                line: idx + 1,
+            },
+        );
+        current = Some(key);
+    }
+    fields
+}
+
+fn files_with_markers(tasks_dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(tasks_dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("md"))
+        .filter(|path| {
+            fs::read_to_string(path)
+                .map(|text| text.lines().any(|line| line.starts_with("<<<<<<< ")))
+                .unwrap_or(false)
+        })
+        .collect();
+    paths.sort();
+    paths
+}
+
+fn changed_files(repo_root: &Path, from: &str, to: &str, dir: &str) -> BTreeSet<String> {
+    git(repo_root, &["diff", "--name-only", from, to, "--", dir])
+        .map(|output| {
+            output
+                .lines()
+                .filter(|line| line.ends_with(".md"))
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn show_file(repo_root: &Path, rev: &str, path: &str) -> Option<String> {
+    git(repo_root, &["show", &format!("{}:{}", rev, path)])
+}
+
+fn blame_line(repo_root: &Path, rev: &str, path: &str, line: usize) -> Option<BlameInfo> {
+    let range = format!("{},{}", line, line);
+    let output = git(
+        repo_root,
+        &["blame", "--porcelain", "-L", &range, rev, "--", path],
+    )?;
+    let commit = output.split_whitespace().next()?.to_string();
+    let mut author = String::new();
+    let mut time = 0;
+    for line in output.lines() {
+        if let Some(value) = line.strip_prefix("author ") {
+            author = value.to_string();
+        } else if let Some(value) = line.strip_prefix("author-time ") {
+            time = value.trim().parse().unwrap_or(0);
+        }
+    }
+    Some(BlameInfo {
+        commit,
+        author,
+        time,
+    })
+}
+
+fn rev_parse(repo_root: &Path, rev: &str) -> Option<String> {
+    git(repo_root, &["rev-parse", "--verify", "--quiet", rev])
+}
+
+fn git(repo_root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(
+        String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        let ok = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false);
+        assert!(ok, "git {:?}", args);
+    }
+
+    fn task_text(status: &str, labels: &str) -> String {
+        format!(
+            "---\nid: task-001\ntitle: Alpha\nstatus: {}\nlabels: [{}]\n---\n\nBody\n",
+            status, labels
+        )
+    }
+
+    #[test]
+    fn front_matter_fields_keep_line_numbers_and_block_lists() {
+        let text = "---\nid: task-001\nlabels:\n  - a\n  - b\nstatus: To Do\n---\nbody\n";
+        let fields = front_matter_fields(text);
+        assert_eq!(fields["id"].line, 2);
+        assert_eq!(fields["labels"].value, "- a\n- b");
+        assert_eq!(fields["status"].line, 6);
+        assert_eq!(list_items(&fields["labels"].value), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn suggest_resolution_prefers_progress_and_unions_lists() {
+        assert_eq!(
+            suggest_resolution("status", Some("In Progress"), Some("Done"), None, None),
+            "keep theirs (Done); it is further along"
+        );
+        assert_eq!(
+            suggest_resolution("labels", Some("[a, b]"), Some("[b, c]"), None, None),
+            "merge both sides: [a, b, c]"
+        );
+    }
+
+    #[test]
+    fn conflict_report_flags_fields_changed_on_both_sides() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        run_git(repo, &["init", "-q", "-b", "main"]);
+        run_git(repo, &["config", "user.email", "workmesh@example.com"]);
+        run_git(repo, &["config", "user.name", "WorkMesh"]);
+        let tasks_dir = repo.join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        let task_path = tasks_dir.join("task-001 - Alpha.md");
+
+        fs::write(&task_path, task_text("To Do", "a")).expect("write");
+        run_git(repo, &["add", "."]);
+        run_git(repo, &["commit", "-q", "-m", "base"]);
+        run_git(repo, &["checkout", "-q", "-b", "feature"]);
+        fs::write(&task_path, task_text("Done", "a, b")).expect("write");
+        run_git(repo, &["commit", "-q", "-am", "feature"]);
+        run_git(repo, &["checkout", "-q", "main"]);
+        fs::write(&task_path, task_text("In Progress", "a, c")).expect("write");
+        run_git(repo, &["commit", "-q", "-am", "main"]);
+        // The merge conflicts; settle on "ours" so a merge commit exists.
+        let _ = Command::new("git")
+            .args(["merge", "-q", "feature"])
+            .current_dir(repo)
+            .output();
+        run_git(repo, &["checkout", "--ours", "--", "."]);
+        run_git(repo, &["add", "."]);
+        run_git(repo, &["commit", "-q", "-m", "merge"]);
+
+        let report = conflict_report(repo, &tasks_dir, None);
+        assert!(report.parents.is_some());
+        assert!(report.unresolved_markers.is_empty());
+        let fields: Vec<&str> = report
+            .conflicts
+            .iter()
+            .map(|conflict| conflict.field.as_str())
+            .collect();
+        assert_eq!(fields, vec!["labels", "status"]);
+        let status = &report.conflicts[1];
+        assert_eq!(status.task_id, "task-001");
+        assert_eq!(status.current.as_deref(), Some("In Progress"));
+        assert_eq!(status.suggestion, "keep theirs (Done); it is further along");
+        assert_eq!(
+            status
+                .theirs_blame
+                .as_ref()
+                .map(|blame| blame.author.as_str()),
+            Some("WorkMesh")
+        );
+    }
+}
@@ -0,0 +1,73 @@
+//! Step counts reported by long operations (rekey, archive, fix, index rebuild, backlog merges)
+//! so front ends can draw a progress bar instead of waiting on a silent command.
+
+use serde::Serialize;
+
+/// Callbacks hear about roughly this many steps per phase, however large the backlog.
+const STEPS_PER_PHASE: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ProgressEvent {
+    /// Operation, e.g. `rekey` or `index_rebuild`.
+    pub op: &'static str,
+    /// Stage within the operation, e.g. `scan` or `write`.
+    pub phase: &'static str,
+    pub done: usize,
+    pub total: usize,
+}
+
+/// Report `done` of `total` unless it is too close to the previous report to matter: the first
+/// and last steps always go out, the rest at about 1% intervals.
+pub(crate) fn tick<F>(
+    on_progress: &mut F,
+    op: &'static str,
+    phase: &'static str,
+    done: usize,
+    total: usize,
+) where
+    F: FnMut(&ProgressEvent),
+{
+    let stride = (total / STEPS_PER_PHASE).max(1);
+    if done <= 1 || done >= total || done.is_multiple_of(stride) {
+        on_progress(&ProgressEvent {
+            op,
+            phase,
+            done,
+            total,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tick_thins_large_phases_but_keeps_first_and_last_steps() {
+        let mut seen = Vec::new();
+        for done in 1..=1000 {
+            tick(
+                &mut |event: &ProgressEvent| seen.push(event.done),
+                "op",
+                "scan",
+                done,
+                1000,
+            );
+        }
+        assert_eq!(seen.len(), 101);
+        assert_eq!(seen.first(), Some(&1));
+        assert_eq!(seen.last(), Some(&1000));
+
+        let mut small = Vec::new();
+        for done in 1..=3 {
+            tick(
+                &mut |event: &ProgressEvent| small.push(event.done),
+                "op",
+                "scan",
+                done,
+                3,
+            );
+        }
+        assert_eq!(small, vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,548 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::config::TaskValidationRules;
+use crate::storage::write_string_atomic_locked;
+use crate::task::{tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    append_task_note, ensure_can_set_status_with_rules, extract_section_content, now_timestamp,
+    rename_task_file_for_title, replace_section, section_name_for_field, set_list_field,
+    task_to_json_value, update_body, update_task_field, update_task_field_or_section,
+};
+
+#[derive(Debug, Error)]
+pub enum ProposalError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid change on line {line}: {message}")]
+    InvalidLine { line: usize, message: String },
+    #[error("Proposal has no changes")]
+    Empty,
+    #[error("Proposal not found: {0}")]
+    NotFound(String),
+    #[error("Proposal {id} is already {state}")]
+    AlreadyDecided { id: String, state: &'static str },
+    #[error("Change {index} on {task_id}: {message}")]
+    Rejected {
+        index: usize,
+        task_id: String,
+        message: String,
+    },
+    #[error("Failed to update task: {0}")]
+    Task(#[from] TaskParseError),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProposalState {
+    Proposed,
+    Accepted,
+    Rejected,
+}
+
+impl ProposalState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProposalState::Proposed => "proposed",
+            ProposalState::Accepted => "accepted",
+            ProposalState::Rejected => "rejected",
+        }
+    }
+}
+
+/// One line of a proposal file, e.g. `{"op":"set_field","task_id":"task-001","field":"status",
+/// "value":"Done"}`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ProposedChange {
+    SetField {
+        task_id: String,
+        field: String,
+        value: String,
+    },
+    AddNote {
+        task_id: String,
+        note: String,
+        #[serde(default = "default_note_section")]
+        section: String,
+    },
+    SetSection {
+        task_id: String,
+        section: String,
+        content: String,
+    },
+    AddLabel {
+        task_id: String,
+        label: String,
+    },
+    AddDependency {
+        task_id: String,
+        dependency: String,
+    },
+}
+
+fn default_note_section() -> String {
+    "notes".to_string()
+}
+
+impl ProposedChange {
+    pub fn task_id(&self) -> &str {
+        match self {
+            ProposedChange::SetField { task_id, .. }
+            | ProposedChange::AddNote { task_id, .. }
+            | ProposedChange::SetSection { task_id, .. }
+            | ProposedChange::AddLabel { task_id, .. }
+            | ProposedChange::AddDependency { task_id, .. } => task_id,
+        }
+    }
+
+    /// Audit action recorded when the change is accepted (same as the direct command's).
+    pub fn action(&self) -> &'static str {
+        match self {
+            ProposedChange::SetField { .. } => "set_field",
+            ProposedChange::AddNote { .. } => "note",
+            ProposedChange::SetSection { .. } => "set_section",
+            ProposedChange::AddLabel { .. } => "label_add",
+            ProposedChange::AddDependency { .. } => "dependency_add",
+        }
+    }
+}
+
+/// A batch of agent-submitted changes awaiting a human decision, stored at
+/// `<backlog>/proposals/<id>.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Proposal {
+    pub id: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub actor: Option<String>,
+    /// File the changes were read from.
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub summary: Option<String>,
+    pub state: ProposalState,
+    pub changes: Vec<ProposedChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decided_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decided_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Before/after view of one change against the current backlog.
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangePreview {
+    pub index: usize,
+    pub task_id: String,
+    pub action: &'static str,
+    /// Field, section, or list the change touches.
+    pub target: String,
+    pub old: Option<String>,
+    pub new: String,
+    /// Why the change cannot be applied as-is (missing task, status gate, ...).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AppliedChange {
+    pub task_id: String,
+    pub action: &'static str,
+    pub details: Value,
+}
+
+pub fn proposals_dir(backlog_dir: &Path) -> PathBuf {
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    tasks_dir
+        .parent()
+        .unwrap_or(tasks_dir.as_path())
+        .join("proposals")
+}
+
+fn proposal_path(backlog_dir: &Path, id: &str) -> PathBuf {
+    proposals_dir(backlog_dir).join(format!("{}.json", id.trim().to_lowercase()))
+}
+
+/// Parse a JSONL change file; blank lines and `#` comments are ignored.
+pub fn parse_proposed_changes(text: &str) -> Result<Vec<ProposedChange>, ProposalError> {
+    let mut changes = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let change: ProposedChange =
+            serde_json::from_str(line).map_err(|err| ProposalError::InvalidLine {
+                line: idx + 1,
+                message: err.to_string(),
+            })?;
+        if change.task_id().trim().is_empty() {
+            return Err(ProposalError::InvalidLine {
+                line: idx + 1,
+                message: "task_id is empty".to_string(),
+            });
+        }
+        changes.push(change);
+    }
+    if changes.is_empty() {
+        return Err(ProposalError::Empty);
+    }
+    Ok(changes)
+}
+
+/// Every readable proposal, ordered by id.
+pub fn load_proposals(backlog_dir: &Path) -> Vec<Proposal> {
+    let Ok(entries) = fs::read_dir(proposals_dir(backlog_dir)) else {
+        return Vec::new();
+    };
+    let mut proposals: Vec<Proposal> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|text| serde_json::from_str(&text).ok())
+        .collect();
+    proposals.sort_by(|a: &Proposal, b: &Proposal| a.id.cmp(&b.id));
+    proposals
+}
+
+pub fn load_proposal(backlog_dir: &Path, id: &str) -> Result<Proposal, ProposalError> {
+    let path = proposal_path(backlog_dir, id);
+    if !path.exists() {
+        return Err(ProposalError::NotFound(id.to_string()));
+    }
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn save_proposal(backlog_dir: &Path, proposal: &Proposal) -> Result<PathBuf, ProposalError> {
+    fs::create_dir_all(proposals_dir(backlog_dir))?;
+    let path = proposal_path(backlog_dir, &proposal.id);
+    write_string_atomic_locked(&path, &serde_json::to_string_pretty(proposal)?)?;
+    Ok(path)
+}
+
+fn next_proposal_id(backlog_dir: &Path) -> String {
+    let max = load_proposals(backlog_dir)
+        .iter()
+        .filter_map(|proposal| proposal.id.strip_prefix("prop-")?.parse::<u32>().ok())
+        .max()
+        .unwrap_or(0);
+    format!("prop-{:03}", max + 1)
+}
+
+/// Queue `changes` for review instead of writing them to tasks.
+pub fn submit_proposal(
+    backlog_dir: &Path,
+    changes: Vec<ProposedChange>,
+    actor: Option<String>,
+    source: Option<String>,
+    summary: Option<String>,
+) -> Result<Proposal, ProposalError> {
+    if changes.is_empty() {
+        return Err(ProposalError::Empty);
+    }
+    let proposal = Proposal {
+        id: next_proposal_id(backlog_dir),
+        created_at: now_timestamp(),
+        actor,
+        source,
+        summary,
+        state: ProposalState::Proposed,
+        changes,
+        decided_at: None,
+        decided_by: None,
+        reason: None,
+    };
+    save_proposal(backlog_dir, &proposal)?;
+    Ok(proposal)
+}
+
+fn find<'a>(tasks: &'a [Task], id: &str) -> Option<&'a Task> {
+    tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(id.trim()))
+}
+
+fn field_text(task: &Task, field: &str) -> Option<String> {
+    if let Some(section) = section_name_for_field(field) {
+        return extract_section_content(&task.body, &section);
+    }
+    let json = task_to_json_value(task, false);
+    let value = json
+        .get(field)
+        .or_else(|| json.get("extra").and_then(|extra| extra.get(field)))?;
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        Value::Array(items) => Some(
+            items
+                .iter()
+                .map(|item| {
+                    item.as_str()
+                        .map_or_else(|| item.to_string(), str::to_string)
+                })
+                .collect::<Vec<_>>()
+                .join(", "),
+        ),
+        other => Some(other.to_string()),
+    }
+}
+
+fn preview_change(
+    tasks: &[Task],
+    index: usize,
+    change: &ProposedChange,
+    rules: &TaskValidationRules,
+) -> ChangePreview {
+    let task = find(tasks, change.task_id());
+    let (target, old, new) = match change {
+        ProposedChange::SetField { field, value, .. } => (
+            field.clone(),
+            task.and_then(|task| field_text(task, field)),
+            value.clone(),
+        ),
+        ProposedChange::AddNote { note, section, .. } => {
+            (section.clone(), None, note.trim().to_string())
+        }
+        ProposedChange::SetSection {
+            section, content, ..
+        } => (
+            section.clone(),
+            task.and_then(|task| extract_section_content(&task.body, section)),
+            content.clone(),
+        ),
+        ProposedChange::AddLabel { label, .. } => (
+            "labels".to_string(),
+            task.map(|task| task.labels.join(", ")),
+            label.trim().to_string(),
+        ),
+        ProposedChange::AddDependency { dependency, .. } => (
+            "dependencies".to_string(),
+            task.map(|task| task.dependencies.join(", ")),
+            dependency.trim().to_string(),
+        ),
+    };
+    let error = match (task, change) {
+        (None, _) => Some("task not found".to_string()),
+        (Some(task), ProposedChange::SetField { field, value, .. })
+            if field.trim().eq_ignore_ascii_case("status") =>
+        {
+            ensure_can_set_status_with_rules(tasks, task, value, rules).err()
+        }
+        (Some(task), ProposedChange::SetField { field, .. })
+            if field.trim().eq_ignore_ascii_case("id") || field.trim() == "uid" =>
+        {
+            Some(format!("{} cannot be changed by a proposal", task.id))
+        }
+        (_, ProposedChange::AddDependency { dependency, .. })
+            if find(tasks, dependency).is_none() =>
+        {
+            Some(format!("dependency not found: {}", dependency.trim()))
+        }
+        _ => None,
+    };
+    ChangePreview {
+        index,
+        task_id: task
+            .map(|task| task.id.clone())
+            .unwrap_or_else(|| change.task_id().to_string()),
+        action: change.action(),
+        target,
+        old,
+        new,
+        error,
+    }
+}
+
+/// Diff-style preview of every change against the current tasks.
+pub fn preview_proposal(
+    tasks: &[Task],
+    proposal: &Proposal,
+    rules: &TaskValidationRules,
+) -> Vec<ChangePreview> {
+    proposal
+        .changes
+        .iter()
+        .enumerate()
+        .map(|(idx, change)| preview_change(tasks, idx + 1, change, rules))
+        .collect()
+}
+
+/// Write one change to its task file (touching `updated_date`) and return the audit entry the
+/// direct command would have recorded. `tasks` must reflect the files on disk.
+pub fn apply_proposed_change(
+    tasks: &[Task],
+    index: usize,
+    change: &ProposedChange,
+    rules: &TaskValidationRules,
+) -> Result<AppliedChange, ProposalError> {
+    let preview = preview_change(tasks, index, change, rules);
+    let rejected = |message: String| ProposalError::Rejected {
+        index,
+        task_id: preview.task_id.clone(),
+        message,
+    };
+    if let Some(error) = preview.error.clone() {
+        return Err(rejected(error));
+    }
+    let task = find(tasks, change.task_id()).ok_or_else(|| rejected("task not found".into()))?;
+    let path = task
+        .file_path
+        .as_ref()
+        .ok_or_else(|| rejected("task file missing".into()))?;
+    let details = match change {
+        ProposedChange::SetField { field, value, .. } => {
+            update_task_field_or_section(path, field, Some(value))?;
+            serde_json::json!({ "field": field, "value": value })
+        }
+        ProposedChange::AddNote { note, section, .. } => {
+            append_task_note(path, note, section)?;
+            serde_json::json!({ "section": section, "note": note })
+        }
+        ProposedChange::SetSection {
+            section, content, ..
+        } => {
+            update_body(path, &replace_section(&task.body, section, content))?;
+            serde_json::json!({ "section": section, "length": content.len() })
+        }
+        ProposedChange::AddLabel { label, .. } => {
+            let mut labels = task.labels.clone();
+            if !labels.iter().any(|entry| entry == label.trim()) {
+                labels.push(label.trim().to_string());
+            }
+            set_list_field(path, "labels", labels)?;
+            serde_json::json!({ "field": "labels", "value": label.trim() })
+        }
+        ProposedChange::AddDependency { dependency, .. } => {
+            let mut dependencies = task.dependencies.clone();
+            if !dependencies.iter().any(|entry| entry == dependency.trim()) {
+                dependencies.push(dependency.trim().to_string());
+            }
+            set_list_field(path, "dependencies", dependencies)?;
+            serde_json::json!({ "field": "dependencies", "value": dependency.trim() })
+        }
+    };
+    update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+    if matches!(change, ProposedChange::SetField { field, .. } if field == "title") {
+        rename_task_file_for_title(path, &task.title)?;
+    }
+    Ok(AppliedChange {
+        task_id: task.id.clone(),
+        action: change.action(),
+        details,
+    })
+}
+
+/// Record the human decision; only pending proposals can be decided.
+pub fn decide_proposal(
+    backlog_dir: &Path,
+    id: &str,
+    state: ProposalState,
+    decided_by: Option<String>,
+    reason: Option<String>,
+) -> Result<Proposal, ProposalError> {
+    let mut proposal = load_proposal(backlog_dir, id)?;
+    ensure_pending(&proposal)?;
+    proposal.state = state;
+    proposal.decided_at = Some(now_timestamp());
+    proposal.decided_by = decided_by;
+    proposal.reason = reason;
+    save_proposal(backlog_dir, &proposal)?;
+    Ok(proposal)
+}
+
+pub fn ensure_pending(proposal: &Proposal) -> Result<(), ProposalError> {
+    if proposal.state != ProposalState::Proposed {
+        return Err(ProposalError::AlreadyDecided {
+            id: proposal.id.clone(),
+            state: proposal.state.as_str(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+    use crate::task_ops::create_task_file;
+
+    #[test]
+    fn proposals_queue_preview_and_apply_changes() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        for id in ["task-001", "task-002"] {
+            create_task_file(
+                &tasks_dir,
+                id,
+                "Sample",
+                "To Do",
+                "P2",
+                "Phase1",
+                &[],
+                &[],
+                &[],
+            )
+            .expect("task");
+        }
+        let changes = parse_proposed_changes(
+            "# agent edits\n\
+             {\"op\":\"set_field\",\"task_id\":\"task-001\",\"field\":\"priority\",\"value\":\"P1\"}\n\
+             {\"op\":\"add_note\",\"task_id\":\"task-001\",\"note\":\"Found the root cause\"}\n\
+             {\"op\":\"add_dependency\",\"task_id\":\"task-002\",\"dependency\":\"task-001\"}\n",
+        )
+        .expect("parse");
+        assert!(matches!(
+            parse_proposed_changes("{\"op\":\"rewrite\",\"task_id\":\"task-001\"}"),
+            Err(ProposalError::InvalidLine { line: 1, .. })
+        ));
+
+        let proposal =
+            submit_proposal(&backlog, changes, Some("agent".into()), None, None).expect("submit");
+        assert_eq!(proposal.id, "prop-001");
+        let tasks = load_tasks(&backlog);
+        let rules = TaskValidationRules::default();
+        let preview = preview_proposal(&tasks, &proposal, &rules);
+        assert_eq!(preview[0].old.as_deref(), Some("P2"));
+        assert_eq!(preview[0].new, "P1");
+        assert!(preview.iter().all(|entry| entry.error.is_none()));
+        // Nothing is written until accepted.
+        assert_eq!(load_tasks(&backlog)[0].priority, "P2");
+
+        for (idx, change) in proposal.changes.iter().enumerate() {
+            let tasks = load_tasks(&backlog);
+            let applied = apply_proposed_change(&tasks, idx + 1, change, &rules).expect("apply");
+            assert_eq!(applied.action, change.action());
+        }
+        let tasks = load_tasks(&backlog);
+        assert_eq!(tasks[0].priority, "P1");
+        assert!(tasks[0].body.contains("Found the root cause"));
+        assert_eq!(tasks[1].dependencies, vec!["task-001".to_string()]);
+
+        decide_proposal(
+            &backlog,
+            "prop-001",
+            ProposalState::Accepted,
+            Some("luis".into()),
+            None,
+        )
+        .expect("accept");
+        assert!(matches!(
+            decide_proposal(&backlog, "prop-001", ProposalState::Rejected, None, None),
+            Err(ProposalError::AlreadyDecided { .. })
+        ));
+    }
+}
@@ -0,0 +1,649 @@
+//! Recurring tasks: a task with `recurrence: weekly` (or an RFC 5545 `RRULE`) in front matter is a
+//! series; `recur run` materializes each due occurrence as a new task that points back at it.
+//!
+//! Occurrences are anchored on the series' `recurrence_start`, else its `due` date, else its
+//! `created_date`. Instances carry `recurrence_of` and `recurrence_date`, and the series records
+//! the last date it produced in `recurrence_last`, so re-running never duplicates an occurrence.
+
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+use crate::health::{first_extra, is_closed, DUE_FIELDS};
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{load_tasks_with_archive, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{create_task_file_with_body, update_task_field, FieldValue};
+use crate::timewindow::parse_timestamp_ref;
+
+pub const RECURRENCE_FIELD: &str = "recurrence";
+pub const RECURRENCE_START_FIELD: &str = "recurrence_start";
+pub const RECURRENCE_LAST_FIELD: &str = "recurrence_last";
+pub const RECURRENCE_OF_FIELD: &str = "recurrence_of";
+pub const RECURRENCE_DATE_FIELD: &str = "recurrence_date";
+
+/// Stop expanding a rule after this many periods, whatever its `COUNT` / `UNTIL`.
+const MAX_PERIODS: i64 = 10_000;
+
+#[derive(Debug, Error)]
+pub enum RecurrenceError {
+    #[error("Invalid recurrence '{rule}': {reason}")]
+    Invalid { rule: String, reason: String },
+    #[error("Task error: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// The supported RRULE subset: `FREQ`, `INTERVAL`, `BYDAY` (plain weekdays), `BYMONTHDAY`,
+/// `COUNT`, and `UNTIL`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Recurrence {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<Weekday>,
+    pub by_month_day: Vec<i32>,
+    pub count: Option<u32>,
+    pub until: Option<NaiveDate>,
+}
+
+impl Recurrence {
+    fn every(freq: Frequency, interval: u32) -> Self {
+        Recurrence {
+            freq,
+            interval,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            count: None,
+            until: None,
+        }
+    }
+
+    /// Parse a shorthand (`daily`, `weekdays`, `weekly`, `biweekly`, `monthly`, `yearly`) or an
+    /// RRULE such as `FREQ=WEEKLY;BYDAY=MO,TH` (an `RRULE:` prefix is accepted).
+    pub fn parse(rule: &str) -> Result<Self, RecurrenceError> {
+        let invalid = |reason: &str| RecurrenceError::Invalid {
+            rule: rule.to_string(),
+            reason: reason.to_string(),
+        };
+        let trimmed = rule.trim();
+        match trimmed.to_lowercase().as_str() {
+            "daily" => return Ok(Self::every(Frequency::Daily, 1)),
+            "weekdays" => {
+                let mut weekdays = Self::every(Frequency::Weekly, 1);
+                weekdays.by_day = vec![
+                    Weekday::Mon,
+                    Weekday::Tue,
+                    Weekday::Wed,
+                    Weekday::Thu,
+                    Weekday::Fri,
+                ];
+                return Ok(weekdays);
+            }
+            "weekly" => return Ok(Self::every(Frequency::Weekly, 1)),
+            "biweekly" | "fortnightly" => return Ok(Self::every(Frequency::Weekly, 2)),
+            "monthly" => return Ok(Self::every(Frequency::Monthly, 1)),
+            "quarterly" => return Ok(Self::every(Frequency::Monthly, 3)),
+            "yearly" | "annually" => return Ok(Self::every(Frequency::Yearly, 1)),
+            _ => {}
+        }
+        let body = trimmed
+            .strip_prefix("RRULE:")
+            .or_else(|| trimmed.strip_prefix("rrule:"))
+            .unwrap_or(trimmed);
+        let mut freq = None;
+        let mut recurrence = Self::every(Frequency::Daily, 1);
+        for part in body.split(';').filter(|part| !part.trim().is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(&format!("expected KEY=VALUE, got '{}'", part)))?;
+            let value = value.trim();
+            match key.trim().to_uppercase().as_str() {
+                "FREQ" => {
+                    freq = Some(match value.to_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        other => return Err(invalid(&format!("unsupported FREQ {}", other))),
+                    })
+                }
+                "INTERVAL" => {
+                    recurrence.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(|| invalid("INTERVAL must be a positive integer"))?
+                }
+                "BYDAY" => {
+                    recurrence.by_day = value
+                        .split(',')
+                        .map(|day| parse_weekday(day.trim()))
+                        .collect::<Option<Vec<_>>>()
+                        .ok_or_else(|| invalid("BYDAY takes MO,TU,WE,TH,FR,SA,SU"))?
+                }
+                "BYMONTHDAY" => {
+                    recurrence.by_month_day = value
+                        .split(',')
+                        .map(|day| day.trim().parse::<i32>().ok())
+                        .collect::<Option<Vec<_>>>()
+                        .filter(|days| days.iter().all(|day| (1..=31).contains(&day.abs())))
+                        .ok_or_else(|| invalid("BYMONTHDAY takes days 1..31 or -31..-1"))?
+                }
+                "COUNT" => {
+                    recurrence.count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|count| *count > 0)
+                            .ok_or_else(|| invalid("COUNT must be a positive integer"))?,
+                    )
+                }
+                "UNTIL" => {
+                    let date = value.get(..8).unwrap_or(value);
+                    recurrence.until = Some(
+                        NaiveDate::parse_from_str(date, "%Y%m%d")
+                            .or_else(|_| NaiveDate::parse_from_str(value, "%Y-%m-%d"))
+                            .map_err(|_| invalid("UNTIL must be YYYYMMDD[THHMMSSZ]"))?,
+                    )
+                }
+                other => return Err(invalid(&format!("unsupported rule part {}", other))),
+            }
+        }
+        recurrence.freq = freq.ok_or_else(|| invalid("missing FREQ"))?;
+        if recurrence.count.is_some() && recurrence.until.is_some() {
+            return Err(invalid("COUNT and UNTIL cannot both be set"));
+        }
+        Ok(recurrence)
+    }
+
+    /// Candidate dates in the `period`-th period after `start`, in order.
+    fn period_dates(&self, start: NaiveDate, period: i64) -> Vec<NaiveDate> {
+        let step = period * i64::from(self.interval);
+        let mut dates = match self.freq {
+            Frequency::Daily => {
+                let date = start + Duration::days(step);
+                if self.by_day.is_empty() || self.by_day.contains(&date.weekday()) {
+                    vec![date]
+                } else {
+                    Vec::new()
+                }
+            }
+            Frequency::Weekly => {
+                let monday = start
+                    - Duration::days(i64::from(start.weekday().num_days_from_monday()))
+                    + Duration::weeks(step);
+                let days = if self.by_day.is_empty() {
+                    vec![start.weekday()]
+                } else {
+                    self.by_day.clone()
+                };
+                days.iter()
+                    .map(|day| monday + Duration::days(i64::from(day.num_days_from_monday())))
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let months = i64::from(start.month0()) + step;
+                let year = start.year() + months.div_euclid(12) as i32;
+                let month = months.rem_euclid(12) as u32 + 1;
+                let days = if self.by_month_day.is_empty() {
+                    vec![start.day() as i32]
+                } else {
+                    self.by_month_day.clone()
+                };
+                days.iter()
+                    .filter_map(|day| month_day(year, month, *day))
+                    .collect()
+            }
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(start.year() + step as i32, start.month(), start.day())
+                    .into_iter()
+                    .collect()
+            }
+        };
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+
+    /// Occurrences from `start` (inclusive) through `through` (inclusive), honouring `COUNT`
+    /// and `UNTIL`. `COUNT` counts from `start`, so it is stable however often this is called.
+    pub fn occurrences(&self, start: NaiveDate, through: NaiveDate) -> Vec<NaiveDate> {
+        let last = self.until.map_or(through, |until| until.min(through));
+        let mut out = Vec::new();
+        let mut seen = 0u32;
+        for period in 0..MAX_PERIODS {
+            // Weekly periods start on the Monday before `start`, so only stop once a whole
+            // period lies past the end.
+            if self.period_floor(start, period) > last {
+                break;
+            }
+            for date in self
+                .period_dates(start, period)
+                .into_iter()
+                .filter(|date| *date >= start)
+            {
+                if self.count.is_some_and(|count| seen >= count) {
+                    return out;
+                }
+                seen += 1;
+                if date <= last {
+                    out.push(date);
+                }
+            }
+        }
+        out
+    }
+
+    /// Earliest date any occurrence of `period` can fall on.
+    fn period_floor(&self, start: NaiveDate, period: i64) -> NaiveDate {
+        let step = period * i64::from(self.interval);
+        match self.freq {
+            Frequency::Daily => start + Duration::days(step),
+            Frequency::Weekly => {
+                start - Duration::days(i64::from(start.weekday().num_days_from_monday()))
+                    + Duration::weeks(step)
+            }
+            Frequency::Monthly => {
+                let months = i64::from(start.month0()) + step;
+                NaiveDate::from_ymd_opt(
+                    start.year() + months.div_euclid(12) as i32,
+                    months.rem_euclid(12) as u32 + 1,
+                    1,
+                )
+                .unwrap_or(NaiveDate::MAX)
+            }
+            Frequency::Yearly => {
+                NaiveDate::from_ymd_opt(start.year() + step as i32, 1, 1).unwrap_or(NaiveDate::MAX)
+            }
+        }
+    }
+
+    /// First occurrence strictly after `after`, if the rule has one within a few years.
+    pub fn next_after(&self, start: NaiveDate, after: NaiveDate) -> Option<NaiveDate> {
+        let horizon = after.max(start) + Duration::days(366 * 5);
+        self.occurrences(start, horizon)
+            .into_iter()
+            .find(|date| *date > after)
+    }
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value.to_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    })
+}
+
+/// Day `day` of the month, counting from the end when negative; `None` when the month is too
+/// short (RFC 5545 skips such occurrences rather than clamping them).
+fn month_day(year: i32, month: u32, day: i32) -> Option<NaiveDate> {
+    if day > 0 {
+        return NaiveDate::from_ymd_opt(year, month, day as u32);
+    }
+    let next_month = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+    let date = next_month + Duration::days(i64::from(day));
+    (date.month() == month).then_some(date)
+}
+
+fn serialize_date<S: Serializer>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(date)
+}
+
+fn serialize_optional_date<S: Serializer>(
+    date: &Option<NaiveDate>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match date {
+        Some(date) => serializer.collect_str(date),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn extra_date(task: &Task, keys: &[&str]) -> Option<NaiveDate> {
+    first_extra(task, keys)
+        .and_then(|value| parse_timestamp_ref(&value))
+        .map(|time| time.date())
+}
+
+/// A recurring task with its parsed rule and anchor.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecurringSeries {
+    pub id: String,
+    pub title: String,
+    pub rule: String,
+    #[serde(serialize_with = "serialize_date")]
+    pub start: NaiveDate,
+    #[serde(serialize_with = "serialize_optional_date")]
+    pub last: Option<NaiveDate>,
+    /// The next occurrence `recur run` would create: overdue ones first, then upcoming.
+    #[serde(serialize_with = "serialize_optional_date")]
+    pub next: Option<NaiveDate>,
+    #[serde(skip)]
+    pub recurrence: Recurrence,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct InvalidSeries {
+    pub id: String,
+    pub rule: String,
+    pub error: String,
+}
+
+/// Open tasks with a `recurrence` field. Closing a series task (`Done` / `Cancelled`) stops it.
+pub fn recurring_series(
+    tasks: &[Task],
+    today: NaiveDate,
+) -> (Vec<RecurringSeries>, Vec<InvalidSeries>) {
+    let mut series = Vec::new();
+    let mut invalid = Vec::new();
+    for task in tasks.iter().filter(|task| !is_closed(task)) {
+        let Some(rule) = first_extra(task, &[RECURRENCE_FIELD]) else {
+            continue;
+        };
+        let recurrence = match Recurrence::parse(&rule) {
+            Ok(recurrence) => recurrence,
+            Err(err) => {
+                invalid.push(InvalidSeries {
+                    id: task.id.clone(),
+                    rule,
+                    error: err.to_string(),
+                });
+                continue;
+            }
+        };
+        let start = extra_date(task, &[RECURRENCE_START_FIELD])
+            .or_else(|| extra_date(task, DUE_FIELDS))
+            .or_else(|| {
+                task.created_date
+                    .as_deref()
+                    .and_then(parse_timestamp_ref)
+                    .map(|time| time.date())
+            })
+            .unwrap_or(today);
+        let mut entry = RecurringSeries {
+            id: task.id.clone(),
+            title: task.title.clone(),
+            rule,
+            start,
+            last: extra_date(task, &[RECURRENCE_LAST_FIELD]),
+            next: None,
+            recurrence,
+        };
+        entry.next = due_dates(&entry, today).first().copied().or_else(|| {
+            entry
+                .recurrence
+                .next_after(start, entry.last.unwrap_or(today).max(today))
+        });
+        series.push(entry);
+    }
+    series.sort_by(|a, b| a.id.cmp(&b.id));
+    (series, invalid)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecurringInstance {
+    /// Allocated id; `None` on a dry run.
+    pub id: Option<String>,
+    pub path: Option<PathBuf>,
+    pub series: String,
+    #[serde(serialize_with = "serialize_date")]
+    pub date: NaiveDate,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExistingInstance {
+    pub id: String,
+    pub series: String,
+    #[serde(serialize_with = "serialize_date")]
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RecurrenceRun {
+    #[serde(serialize_with = "serialize_date")]
+    pub through: NaiveDate,
+    pub dry_run: bool,
+    pub created: Vec<RecurringInstance>,
+    /// Due occurrences skipped because an instance for them already exists.
+    pub existing: Vec<ExistingInstance>,
+    pub invalid: Vec<InvalidSeries>,
+}
+
+/// Occurrences of `series` that are due by `through` and not yet materialized. A series that
+/// has never run only produces its latest due occurrence, so adding `recurrence:` to an old task
+/// does not backfill its history; after that every occurrence since `recurrence_last` is caught up.
+fn due_dates(series: &RecurringSeries, through: NaiveDate) -> Vec<NaiveDate> {
+    let dates = series.recurrence.occurrences(series.start, through);
+    match series.last {
+        Some(last) => dates.into_iter().filter(|date| *date > last).collect(),
+        None => dates.last().copied().into_iter().collect(),
+    }
+}
+
+/// Materialize every due occurrence (through `through`, inclusive) as a new `To Do` task with the
+/// series' priority, phase, labels, assignee, and body, titled `<title> (<date>)` and due on the
+/// occurrence date. Ids are reserved in `initiative`'s namespace.
+pub fn run_recurrences(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    initiative: &str,
+    through: NaiveDate,
+    dry_run: bool,
+) -> Result<RecurrenceRun, RecurrenceError> {
+    let (series, invalid) = recurring_series(tasks, through);
+    // (series id, occurrence date) -> instance id, archive included.
+    let mut materialized: HashMap<(String, NaiveDate), String> = HashMap::new();
+    for task in load_tasks_with_archive(backlog_dir).iter().chain(tasks) {
+        if let (Some(of), Some(date)) = (
+            first_extra(task, &[RECURRENCE_OF_FIELD]),
+            extra_date(task, &[RECURRENCE_DATE_FIELD]),
+        ) {
+            materialized
+                .entry((of.to_lowercase(), date))
+                .or_insert_with(|| task.id.clone());
+        }
+    }
+
+    let mut run = RecurrenceRun {
+        through,
+        dry_run,
+        created: Vec::new(),
+        existing: Vec::new(),
+        invalid,
+    };
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    for entry in &series {
+        let Some(task) = tasks.iter().find(|task| task.id == entry.id) else {
+            continue;
+        };
+        let dates = due_dates(entry, through);
+        for date in &dates {
+            let key = (entry.id.to_lowercase(), *date);
+            if let Some(id) = materialized.get(&key) {
+                run.existing.push(ExistingInstance {
+                    id: id.clone(),
+                    series: entry.id.clone(),
+                    date: *date,
+                });
+                continue;
+            }
+            let title = format!("{} ({})", task.title, date.format("%Y-%m-%d"));
+            if dry_run {
+                run.created.push(RecurringInstance {
+                    id: None,
+                    path: None,
+                    series: entry.id.clone(),
+                    date: *date,
+                    title,
+                });
+                continue;
+            }
+            let id = reserve_namespaced_task_id(backlog_dir, tasks, initiative)?;
+            let path = create_task_file_with_body(
+                &tasks_dir,
+                &id,
+                &title,
+                "To Do",
+                &task.priority,
+                &task.phase,
+                &[],
+                &task.labels,
+                &task.assignee,
+                task.body.trim_start_matches('\n'),
+            )?;
+            let day = date.format("%Y-%m-%d").to_string();
+            for (key, value) in [
+                (RECURRENCE_OF_FIELD, entry.id.clone()),
+                (RECURRENCE_DATE_FIELD, day.clone()),
+                ("due", day),
+            ] {
+                update_task_field(&path, key, Some(FieldValue::Scalar(value)))?;
+            }
+            materialized.insert(key, id.clone());
+            run.created.push(RecurringInstance {
+                id: Some(id),
+                path: Some(path),
+                series: entry.id.clone(),
+                date: *date,
+                title,
+            });
+        }
+        let newest = dates.last().copied();
+        if !dry_run {
+            if let (Some(newest), Some(path)) = (newest, task.file_path.as_deref()) {
+                if entry.last.is_none_or(|last| newest > last) {
+                    update_task_field(
+                        path,
+                        RECURRENCE_LAST_FIELD,
+                        Some(FieldValue::Scalar(newest.format("%Y-%m-%d").to_string())),
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("date")
+    }
+
+    #[test]
+    fn rules_expand_to_expected_dates() {
+        let start = date("2026-01-31");
+        let monthly = Recurrence::parse("monthly").expect("monthly");
+        assert_eq!(
+            monthly.occurrences(start, date("2026-05-31")),
+            vec![date("2026-01-31"), date("2026-03-31"), date("2026-05-31")]
+        );
+        let last_day = Recurrence::parse("FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=3").expect("rrule");
+        assert_eq!(
+            last_day.occurrences(start, date("2026-12-31")),
+            vec![date("2026-01-31"), date("2026-02-28"), date("2026-03-31")]
+        );
+        // 2026-03-04 is a Wednesday.
+        let twice = Recurrence::parse("RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE").expect("rrule");
+        assert_eq!(
+            twice.occurrences(date("2026-03-04"), date("2026-03-18")),
+            vec![date("2026-03-04"), date("2026-03-16"), date("2026-03-18")]
+        );
+        let until = Recurrence::parse("FREQ=DAILY;UNTIL=20260306T000000Z").expect("rrule");
+        assert_eq!(
+            until
+                .occurrences(date("2026-03-04"), date("2026-04-01"))
+                .len(),
+            3
+        );
+        assert_eq!(
+            Recurrence::parse("weekly")
+                .expect("weekly")
+                .next_after(date("2026-03-04"), date("2026-03-04")),
+            Some(date("2026-03-11"))
+        );
+        assert!(Recurrence::parse("FREQ=HOURLY").is_err());
+        assert!(Recurrence::parse("BYDAY=MO").is_err());
+        assert!(Recurrence::parse("whenever").is_err());
+    }
+
+    #[test]
+    fn run_materializes_due_instances_once() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::write(
+            tasks_dir.join("task-001 - standup notes.md"),
+            "---\nid: task-001\ntitle: Standup notes\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+             dependencies: []\nlabels: [ops]\nassignee: []\nrecurrence: weekly\n\
+             recurrence_start: 2026-03-02\n---\n\nDescription:\n--------------------------------------------------\n- Collect notes\n",
+        )
+        .expect("write");
+
+        let tasks = load_tasks(&backlog);
+        let run = run_recurrences(&backlog, &tasks, "ops", date("2026-03-18"), false).expect("run");
+        assert_eq!(
+            run.created.len(),
+            1,
+            "first run only creates the latest due occurrence"
+        );
+        assert_eq!(run.created[0].date, date("2026-03-16"));
+        assert_eq!(run.created[0].title, "Standup notes (2026-03-16)");
+
+        let tasks = load_tasks(&backlog);
+        let instance = tasks
+            .iter()
+            .find(|task| task.id != "task-001")
+            .expect("instance");
+        assert_eq!(
+            first_extra(instance, &[RECURRENCE_OF_FIELD]).as_deref(),
+            Some("task-001")
+        );
+        assert_eq!(
+            first_extra(instance, &["due"]).as_deref(),
+            Some("2026-03-16")
+        );
+        assert_eq!(instance.labels, vec!["ops".to_string()]);
+        assert!(instance.body.contains("- Collect notes"));
+
+        let again =
+            run_recurrences(&backlog, &tasks, "ops", date("2026-03-18"), false).expect("run");
+        assert!(again.created.is_empty());
+
+        let later =
+            run_recurrences(&backlog, &tasks, "ops", date("2026-04-01"), true).expect("dry run");
+        let dates: Vec<NaiveDate> = later.created.iter().map(|item| item.date).collect();
+        assert_eq!(dates, vec![date("2026-03-23"), date("2026-03-30")]);
+        assert!(later.created.iter().all(|item| item.id.is_none()));
+        assert_eq!(load_tasks(&backlog).len(), 2);
+    }
+}
@@ -5,9 +5,14 @@ use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 
+use crate::attachments::{list_attachments, local_attachments_dir, move_local_attachments};
+use crate::progress::{tick, ProgressEvent};
+use crate::storage::{with_path_lock, write_string_atomic};
 use crate::task::{load_tasks, load_tasks_with_archive, Task};
 use crate::task::{split_front_matter, TaskParseError};
-use crate::task_ops::graph_export;
+use crate::task_ops::{
+    canonical_task_filename, graph_export, task_filename_template, SOFT_DEPENDENCIES_FIELD,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct RekeyPromptOptions {
@@ -116,6 +121,7 @@ pub fn render_rekey_prompt(backlog_dir: &Path, options: RekeyPromptOptions) -> S
                     "parent": t.relationships.parent,
                     "child": t.relationships.child,
                     "discovered_from": t.relationships.discovered_from,
+                    "relates_to": t.relationships.relates_to,
                 },
                 "path": t.file_path,
                 "body": body,
@@ -287,10 +293,12 @@ fn rewrite_known_ref_fields(
 ) -> usize {
     let list_keys = [
         "dependencies",
+        SOFT_DEPENDENCIES_FIELD,
         "blocked_by",
         "parent",
         "child",
         "discovered_from",
+        "relates_to",
     ];
     let mut changed = 0usize;
 
@@ -303,11 +311,17 @@ fn rewrite_known_ref_fields(
         }
     }
 
-    // relationships: { blocked_by, parent, child, discovered_from }
+    // relationships: { blocked_by, parent, child, discovered_from, relates_to }
     let rel_key = Value::String("relationships".to_string());
     if let Some(rel) = map.get_mut(&rel_key) {
         if let Value::Mapping(rel_map) = rel {
-            for key in ["blocked_by", "parent", "child", "discovered_from"] {
+            for key in [
+                "blocked_by",
+                "parent",
+                "child",
+                "discovered_from",
+                "relates_to",
+            ] {
                 let k = Value::String(key.to_string());
                 if let Some(value) = rel_map.get_mut(&k) {
                     if let Value::Sequence(seq) = value {
@@ -321,27 +335,27 @@ fn rewrite_known_ref_fields(
     changed
 }
 
-fn rename_task_file_prefix(
-    old_path: &Path,
-    old_id: &str,
-    new_id: &str,
-) -> Result<Option<PathBuf>, TaskParseError> {
+/// Path `task`'s file moves to once its id becomes `new_id`, or `None` when the name stays.
+fn rekeyed_task_path(old_path: &Path, task: &Task, new_id: &str) -> Option<PathBuf> {
     let file_name = old_path
         .file_name()
         .and_then(|s| s.to_str())
         .unwrap_or("")
         .to_string();
-    if !file_name.starts_with(old_id) {
-        return Ok(None);
-    }
-    let new_file_name = format!("{}{}", new_id, &file_name[old_id.len()..]);
-    let new_path = old_path
-        .parent()
-        .unwrap_or_else(|| Path::new("."))
-        .join(new_file_name);
-    if new_path == old_path {
+    let dir = old_path.parent().unwrap_or_else(|| Path::new("."));
+    let template = task_filename_template(dir);
+    let new_path = dir.join(rekeyed_file_name(&template, &file_name, task, new_id)?);
+    (new_path != old_path).then_some(new_path)
+}
+
+fn rename_task_file_for_rekey(
+    old_path: &Path,
+    task: &Task,
+    new_id: &str,
+) -> Result<Option<PathBuf>, TaskParseError> {
+    let Some(new_path) = rekeyed_task_path(old_path, task, new_id) else {
         return Ok(None);
-    }
+    };
     if new_path.exists() {
         return Err(TaskParseError::Invalid(format!(
             "Refusing to overwrite existing file: {}",
@@ -352,6 +366,95 @@ fn rename_task_file_prefix(
     Ok(Some(new_path))
 }
 
+/// Filename for `task` once its id becomes `new_id`. A file named by the `[task_files]` template
+/// is re-rendered with the new id; otherwise a leading old id is swapped
+/// (`task-001 - title.md`).
+///
+/// Returns `None` when the filename follows neither.
+pub(crate) fn rekeyed_file_name(
+    template: &str,
+    file_name: &str,
+    task: &Task,
+    new_id: &str,
+) -> Option<String> {
+    let uid = task.uid.as_deref().unwrap_or("");
+    if file_name == canonical_task_filename(template, &task.id, &task.title, uid) {
+        return Some(canonical_task_filename(template, new_id, &task.title, uid));
+    }
+    if !file_name.starts_with(&task.id) {
+        return None;
+    }
+    Some(format!("{}{}", new_id, &file_name[task.id.len()..]))
+}
+
+/// Lowercase mapping keys (old ids) and trim values so lookups are case-insensitive.
+pub(crate) fn normalize_rekey_mapping(
+    mapping: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut mapping_lc: HashMap<String, String> = HashMap::new();
+    for (old, new_id) in mapping {
+        let old_key = old.trim().to_lowercase();
+        if old_key.is_empty() {
+            continue;
+        }
+        mapping_lc.insert(old_key, new_id.trim().to_string());
+    }
+    mapping_lc
+}
+
+/// Apply a rekey mapping to the raw text of one task file.
+///
+/// Rewrites the task's own `id` (when `own_id` is mapped), structured references, and (unless
+/// `strict`) free-text body mentions. Returns `None` when nothing needs to change.
+pub(crate) fn rewrite_task_text(
+    text: &str,
+    own_id: &str,
+    mapping_lc: &HashMap<String, String>,
+    strict: bool,
+) -> Result<Option<String>, TaskParseError> {
+    let (front, body) = split_front_matter(text)?;
+    let mut map = parse_front_matter_tolerant(&front);
+
+    // Rewrite structured references first.
+    let structured_changes = rewrite_known_ref_fields(&mut map, mapping_lc);
+
+    // Optionally rewrite free-text body references.
+    let (new_body, body_changes) = if strict {
+        (body.clone(), 0usize)
+    } else {
+        rewrite_body_text(&body, mapping_lc)
+    };
+
+    // Rekey the task's own id if present in mapping.
+    let mut renamed = false;
+    if let Some(new_id) = mapping_lc.get(&own_id.to_lowercase()) {
+        map.insert(
+            Value::String("id".to_string()),
+            Value::String(new_id.clone()),
+        );
+        renamed = true;
+    }
+
+    let needs_front_rewrite = renamed || structured_changes > 0;
+    let needs_body_rewrite = body_changes > 0;
+    if !(needs_front_rewrite || needs_body_rewrite) {
+        return Ok(None);
+    }
+
+    let rendered_front = if needs_front_rewrite {
+        let yaml_value = Value::Mapping(map);
+        yaml_to_string_without_doc_marker(&yaml_value)?
+    } else {
+        front
+    };
+
+    Ok(Some(format!(
+        "---\n{}\n---\n{}",
+        rendered_front.trim_end(),
+        new_body
+    )))
+}
+
 fn is_id_char(byte: u8) -> bool {
     byte.is_ascii_alphanumeric() || byte == b'-' || byte == b'_'
 }
@@ -404,17 +507,23 @@ pub fn rekey_apply(
     request: &RekeyRequest,
     options: RekeyApplyOptions,
 ) -> Result<RekeyReport, TaskParseError> {
+    rekey_apply_with_progress(backlog_dir, request, options, |_| {})
+}
+
+/// [`rekey_apply`], reporting the `rewrite` phase (one step per task file) when applying.
+pub fn rekey_apply_with_progress<F>(
+    backlog_dir: &Path,
+    request: &RekeyRequest,
+    options: RekeyApplyOptions,
+    mut on_progress: F,
+) -> Result<RekeyReport, TaskParseError>
+where
+    F: FnMut(&ProgressEvent),
+{
     let mut tasks = load_tasks_for_rekey(backlog_dir, options.include_archive);
     tasks.sort_by_key(|t| t.id_num());
 
-    let mut mapping_lc: HashMap<String, String> = HashMap::new();
-    for (old, new_id) in &request.mapping {
-        let old_key = old.trim().to_lowercase();
-        if old_key.is_empty() {
-            continue;
-        }
-        mapping_lc.insert(old_key, new_id.trim().to_string());
-    }
+    let mapping_lc = normalize_rekey_mapping(&request.mapping);
 
     let existing_ids: HashSet<String> = tasks.iter().map(|t| t.id.to_lowercase()).collect();
     let mut warnings = Vec::new();
@@ -483,7 +592,8 @@ pub fn rekey_apply(
 
     // Apply to every task file: update structured references; update id for mapped tasks.
     let mut applied: Vec<RekeyChange> = Vec::new();
-    for task in &tasks {
+    for (done, task) in tasks.iter().enumerate() {
+        tick(&mut on_progress, "rekey", "rewrite", done + 1, tasks.len());
         let old_id = task.id.clone();
         let path = task
             .file_path
@@ -492,52 +602,20 @@ pub fn rekey_apply(
 
         let text =
             fs::read_to_string(&path).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
-        let (front, body) = split_front_matter(&text)?;
-        let mut map = parse_front_matter_tolerant(&front);
-
-        // Rewrite structured references first.
-        let structured_changes = rewrite_known_ref_fields(&mut map, &mapping_lc);
-
-        // Optionally rewrite free-text body references.
-        let (new_body, body_changes) = if options.strict {
-            (body.clone(), 0usize)
-        } else {
-            rewrite_body_text(&body, &mapping_lc)
-        };
-
-        // Rekey the task's own id if present in mapping.
-        let mut renamed = false;
-        let mut new_path = None;
-        if let Some(new_id) = mapping_lc.get(&old_id.to_lowercase()) {
-            map.insert(
-                Value::String("id".to_string()),
-                Value::String(new_id.clone()),
-            );
-            renamed = true;
-        }
-
-        let needs_front_rewrite = renamed || structured_changes > 0;
-        let needs_body_rewrite = body_changes > 0;
-        if !(needs_front_rewrite || needs_body_rewrite) {
+        let Some(updated) = rewrite_task_text(&text, &old_id, &mapping_lc, options.strict)? else {
             continue;
-        }
-
-        let rendered_front = if needs_front_rewrite {
-            let yaml_value = Value::Mapping(map);
-            yaml_to_string_without_doc_marker(&yaml_value)?
-        } else {
-            front
         };
-
-        let updated = format!("---\n{}\n---\n{}", rendered_front.trim_end(), new_body);
         if updated != text {
             fs::write(&path, updated).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
         }
 
+        let mut new_path = None;
+        let renamed = mapping_lc.contains_key(&old_id.to_lowercase());
+
         // Rename file if the id changed.
         if renamed {
             let new_id = mapping_lc.get(&old_id.to_lowercase()).expect("mapped");
-            new_path = rename_task_file_prefix(&path, &old_id, new_id)?;
+            new_path = rename_task_file_for_rekey(&path, task, new_id)?;
         }
 
         if renamed {
@@ -566,6 +644,165 @@ pub fn rekey_apply(
     })
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MoveTaskOptions {
+    pub apply: bool,
+    /// Also rewrite free-text mentions in task bodies (structured references are always rewritten).
+    pub rewrite_body: bool,
+}
+
+/// A task file whose references change because of a move.
+#[derive(Debug, Clone, Serialize)]
+pub struct MoveTouchedFile {
+    pub task_id: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MoveReport {
+    pub old_id: String,
+    pub new_id: String,
+    pub apply: bool,
+    pub rewrite_body: bool,
+    pub path: PathBuf,
+    pub new_path: Option<PathBuf>,
+    /// New location of the task's `attachments/<id>/` folder, when it has one.
+    pub attachments_dir: Option<PathBuf>,
+    /// Other task files (archive included) that mention the old id.
+    pub touched: Vec<MoveTouchedFile>,
+}
+
+/// Rename a single task (`move <old-id> <new-id>`): the single-entry form of `rekey apply`.
+///
+/// Archived tasks are always included so nothing keeps pointing at the old id. Local attachments
+/// move with the task; tasks with remote attachments are refused, since their objects are keyed
+/// by the old id. Without `options.apply` nothing is written; the report lists every file that
+/// would change.
+pub fn move_task(
+    backlog_dir: &Path,
+    old_id: &str,
+    new_id: &str,
+    options: MoveTaskOptions,
+) -> Result<MoveReport, TaskParseError> {
+    let new_id = new_id.trim();
+    if new_id.is_empty() || new_id.contains(|c: char| c.is_whitespace() || c == '/') {
+        return Err(TaskParseError::Invalid(format!(
+            "Invalid task id: '{}'",
+            new_id
+        )));
+    }
+    let tasks = load_tasks_for_rekey(backlog_dir, true);
+    let task = tasks
+        .iter()
+        .find(|t| t.id.eq_ignore_ascii_case(old_id.trim()))
+        .ok_or_else(|| TaskParseError::Invalid(format!("Task not found: {}", old_id.trim())))?;
+    if task.id == new_id {
+        return Err(TaskParseError::Invalid(format!(
+            "{} already has that id",
+            task.id
+        )));
+    }
+    if !task.id.eq_ignore_ascii_case(new_id)
+        && tasks.iter().any(|t| t.id.eq_ignore_ascii_case(new_id))
+    {
+        return Err(TaskParseError::Invalid(format!(
+            "Task id already exists: {}",
+            new_id
+        )));
+    }
+    let path = task
+        .file_path
+        .clone()
+        .ok_or_else(|| TaskParseError::Invalid(format!("Missing path for {}", task.id)))?;
+    let new_path = rekeyed_task_path(&path, task, new_id);
+    if let Some(target) = &new_path {
+        // A case-only rename may "exist" already on case-insensitive filesystems.
+        if target.exists() && !task.id.eq_ignore_ascii_case(new_id) {
+            return Err(TaskParseError::Invalid(format!(
+                "Refusing to overwrite existing file: {}",
+                target.display()
+            )));
+        }
+    }
+
+    if list_attachments(backlog_dir, task)
+        .iter()
+        .any(|attachment| attachment.backend != "local")
+    {
+        return Err(TaskParseError::Invalid(format!(
+            "{} has remote attachments keyed by its id; moving it would orphan them",
+            task.id
+        )));
+    }
+    let attachments_dir = local_attachments_dir(backlog_dir, &task.id)
+        .is_dir()
+        .then(|| local_attachments_dir(backlog_dir, new_id));
+    if let Some(target) = &attachments_dir {
+        if target.exists() && !task.id.eq_ignore_ascii_case(new_id) {
+            return Err(TaskParseError::Invalid(format!(
+                "Refusing to overwrite existing attachments: {}",
+                target.display()
+            )));
+        }
+    }
+
+    let mapping_lc = HashMap::from([(task.id.to_lowercase(), new_id.to_string())]);
+    let mut rewrites = Vec::new();
+    let mut touched = Vec::new();
+    for other in &tasks {
+        let Some(other_path) = other.file_path.as_ref() else {
+            continue;
+        };
+        let text = fs::read_to_string(other_path)
+            .map_err(|err| TaskParseError::Invalid(err.to_string()))?;
+        let Some(updated) =
+            rewrite_task_text(&text, &other.id, &mapping_lc, !options.rewrite_body)?
+        else {
+            continue;
+        };
+        if updated == text {
+            continue;
+        }
+        if other_path != &path {
+            touched.push(MoveTouchedFile {
+                task_id: other.id.clone(),
+                path: other_path.clone(),
+            });
+        }
+        rewrites.push((other_path.clone(), other.id.clone()));
+    }
+
+    if options.apply {
+        for (target, task_id) in &rewrites {
+            // Re-read under the lock so concurrent edits since the scan are kept.
+            with_path_lock(target, || {
+                let text = fs::read_to_string(target)?;
+                if let Some(updated) =
+                    rewrite_task_text(&text, task_id, &mapping_lc, !options.rewrite_body)?
+                {
+                    write_string_atomic(target, &updated)?;
+                }
+                Ok::<(), TaskParseError>(())
+            })?;
+        }
+        if let Some(target) = &new_path {
+            with_path_lock(&path, || fs::rename(&path, target))?;
+        }
+        move_local_attachments(backlog_dir, &task.id, new_id)?;
+    }
+
+    Ok(MoveReport {
+        old_id: task.id.clone(),
+        new_id: new_id.to_string(),
+        apply: options.apply,
+        rewrite_body: options.rewrite_body,
+        path,
+        new_path,
+        attachments_dir,
+        touched,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -766,4 +1003,115 @@ Body mentions task-001 and task-002.\n";
             parse_rekey_request("{\"mapping\": {\"task-001\": \"task-main-001\"}}").expect("parse");
         assert!(!req.strict);
     }
+
+    #[test]
+    fn move_task_dry_run_lists_touched_files_then_applies() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        let a = write_task(&tasks_dir, "task-001", "Alpha", &[], &[]);
+        let b = write_task(&tasks_dir, "task-002", "Beta", &["task-001"], &[]);
+        let c = write_task(&tasks_dir, "task-003", "Gamma", &[], &["task-001"]);
+        write_task(&tasks_dir, "task-004", "Delta", &[], &[]);
+        let mut gamma = fs::read_to_string(&c).expect("read gamma");
+        gamma.push_str("See task-001.\n");
+        fs::write(&c, gamma).expect("write gamma");
+
+        assert!(move_task(&backlog_dir, "task-001", "task-004", Default::default()).is_err());
+
+        let dry = move_task(
+            &backlog_dir,
+            "TASK-001",
+            "task-auth-001",
+            Default::default(),
+        )
+        .expect("dry run");
+        assert!(!dry.apply);
+        let touched: Vec<&str> = dry.touched.iter().map(|t| t.task_id.as_str()).collect();
+        assert_eq!(touched, vec!["task-002", "task-003"]);
+        assert!(a.exists());
+        assert!(fs::read_to_string(&b).expect("read").contains("task-001"));
+
+        let report = move_task(
+            &backlog_dir,
+            "task-001",
+            "task-auth-001",
+            MoveTaskOptions {
+                apply: true,
+                rewrite_body: false,
+            },
+        )
+        .expect("move");
+        let new_path = report.new_path.expect("renamed");
+        assert!(!a.exists());
+        assert!(fs::read_to_string(&new_path)
+            .expect("read")
+            .contains("id: task-auth-001"));
+        assert!(fs::read_to_string(&b)
+            .expect("read")
+            .contains("task-auth-001"));
+        let gamma = fs::read_to_string(&c).expect("read");
+        assert!(gamma.contains("- task-auth-001"));
+        assert!(gamma.contains("See task-001."));
+        fs::write(&c, gamma.replace("See task-001.", "See task-auth-001.")).expect("write");
+
+        let report = move_task(
+            &backlog_dir,
+            "task-auth-001",
+            "task-auth-002",
+            MoveTaskOptions {
+                apply: true,
+                rewrite_body: true,
+            },
+        )
+        .expect("move with body");
+        assert_eq!(report.touched.len(), 2);
+        assert!(fs::read_to_string(&c)
+            .expect("read")
+            .contains("See task-auth-002."));
+    }
+
+    #[test]
+    fn move_task_carries_local_attachments_and_refuses_remote_ones() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write_task(&tasks_dir, "task-001", "Alpha", &[], &[]);
+        let remote = write_task(&tasks_dir, "task-002", "Beta", &[], &[]);
+        let old_dir = local_attachments_dir(&backlog_dir, "task-001");
+        fs::create_dir_all(&old_dir).expect("attachments dir");
+        fs::write(old_dir.join("trace.log"), "trace").expect("attachment");
+        let text = fs::read_to_string(&remote).expect("read");
+        fs::write(
+            &remote,
+            text.replacen(
+                "---\n\nBody",
+                "attachments:\n  - name: dump.bin\n    url: s3://bucket/wm/task-002/abc-dump.bin\n---\n\nBody",
+                1,
+            ),
+        )
+        .expect("remote attachment");
+
+        let apply = MoveTaskOptions {
+            apply: true,
+            rewrite_body: false,
+        };
+        let err = move_task(&backlog_dir, "task-002", "task-beta-002", apply).unwrap_err();
+        assert!(err.to_string().contains("remote attachments"));
+        assert!(remote.exists());
+
+        let report = move_task(&backlog_dir, "task-001", "task-alpha-001", apply).expect("move");
+        let new_dir = report.attachments_dir.expect("attachments moved");
+        assert_eq!(
+            new_dir,
+            local_attachments_dir(&backlog_dir, "task-alpha-001")
+        );
+        assert!(!old_dir.exists());
+        assert_eq!(
+            fs::read_to_string(new_dir.join("trace.log")).expect("moved"),
+            "trace"
+        );
+    }
 }
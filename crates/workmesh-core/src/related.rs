@@ -0,0 +1,241 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::codeowners::{commit_file_index, task_local_file_refs};
+use crate::task::Task;
+
+/// Default number of related tasks returned by `show --related`.
+pub const DEFAULT_RELATED_LIMIT: usize = 5;
+/// Text similarity (0..1) at which two tasks count as related on wording alone.
+const SIMILARITY_THRESHOLD: f32 = 0.25;
+const LABEL_WEIGHT: f32 = 0.5;
+const FILE_WEIGHT: f32 = 1.0;
+const SIMILARITY_WEIGHT: f32 = 3.0;
+
+/// Words that every task body shares (section headings) or that carry no meaning.
+const STOPWORDS: &[&str] = &[
+    "the",
+    "and",
+    "for",
+    "with",
+    "that",
+    "this",
+    "from",
+    "into",
+    "are",
+    "was",
+    "not",
+    "but",
+    "all",
+    "can",
+    "should",
+    "when",
+    "then",
+    "have",
+    "has",
+    "description",
+    "acceptance",
+    "criteria",
+    "definition",
+    "done",
+    "notes",
+    "task",
+];
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RelatedTask {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub score: f32,
+    pub shared_labels: Vec<String>,
+    /// Files both tasks reference (body paths, `todo_source`, or commits mentioning the task).
+    pub shared_files: Vec<String>,
+    /// Jaccard similarity of title/body words, 0..1.
+    pub similarity: f32,
+}
+
+/// Tasks adjacent to `target`: shared labels, shared files, or similar wording (likely
+/// duplicates), best first.
+pub fn related_tasks(
+    repo_root: &Path,
+    tasks: &[Task],
+    target: &Task,
+    limit: usize,
+) -> Vec<RelatedTask> {
+    let task_ids: HashSet<String> = tasks.iter().map(|task| task.id.to_lowercase()).collect();
+    let commit_index = commit_file_index(repo_root, &task_ids);
+    let files_for = |task: &Task| -> BTreeSet<String> {
+        let mut files = task_local_file_refs(repo_root, task);
+        if let Some(committed) = commit_index.get(&task.id.to_lowercase()) {
+            files.extend(committed.iter().cloned());
+        }
+        files
+    };
+
+    let target_labels: BTreeSet<String> = target
+        .labels
+        .iter()
+        .map(|label| label.to_lowercase())
+        .collect();
+    let target_files = files_for(target);
+    let target_words = words(target);
+
+    let mut related: Vec<RelatedTask> = tasks
+        .iter()
+        .filter(|task| !task.id.eq_ignore_ascii_case(&target.id))
+        .filter_map(|task| {
+            let shared_labels: Vec<String> = task
+                .labels
+                .iter()
+                .filter(|label| target_labels.contains(&label.to_lowercase()))
+                .cloned()
+                .collect();
+            let shared_files: Vec<String> = if target_files.is_empty() {
+                Vec::new()
+            } else {
+                files_for(task)
+                    .intersection(&target_files)
+                    .cloned()
+                    .collect()
+            };
+            let similarity = jaccard(&target_words, &words(task));
+            if shared_labels.is_empty()
+                && shared_files.is_empty()
+                && similarity < SIMILARITY_THRESHOLD
+            {
+                return None;
+            }
+            let score = shared_labels.len() as f32 * LABEL_WEIGHT
+                + shared_files.len() as f32 * FILE_WEIGHT
+                + similarity * SIMILARITY_WEIGHT;
+            Some(RelatedTask {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                score,
+                shared_labels,
+                shared_files,
+                similarity,
+            })
+        })
+        .collect();
+    related.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    related.truncate(limit);
+    related
+}
+
+/// One-line reason list, e.g. `labels: auth | files: src/login.rs | similar 0.42`.
+pub fn related_reasons(related: &RelatedTask) -> String {
+    let mut reasons = Vec::new();
+    if !related.shared_labels.is_empty() {
+        reasons.push(format!("labels: {}", related.shared_labels.join(", ")));
+    }
+    if !related.shared_files.is_empty() {
+        reasons.push(format!("files: {}", related.shared_files.join(", ")));
+    }
+    if related.similarity >= SIMILARITY_THRESHOLD {
+        reasons.push(format!("similar {:.2}", related.similarity));
+    }
+    reasons.join(" | ")
+}
+
+fn words(task: &Task) -> HashSet<String> {
+    let text = format!("{}\n{}", task.title, task.body).to_lowercase();
+    text.split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| word.chars().count() > 2 && !STOPWORDS.contains(word))
+        .map(str::to_string)
+        .collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let shared = a.intersection(b).count();
+    shared as f32 / (a.len() + b.len() - shared) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    fn write_task(dir: &Path, id: &str, title: &str, labels: &str, body: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: {title}\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: [{labels}]\n---\n\n## Description\n{body}\n"
+        );
+        fs::write(dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn related_tasks_rank_labels_files_and_wording() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        let tasks_dir = repo.join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::create_dir_all(repo.join("src")).expect("src");
+        fs::write(repo.join("src/login.rs"), "fn login() {}\n").expect("source");
+
+        write_task(
+            &tasks_dir,
+            "task-001",
+            "Fix flaky login test",
+            "auth",
+            "See src/login.rs",
+        );
+        write_task(
+            &tasks_dir,
+            "task-002",
+            "Login test flaky on CI",
+            "",
+            "Flaky login test",
+        );
+        write_task(
+            &tasks_dir,
+            "task-003",
+            "Refactor login",
+            "",
+            "Touches src/login.rs",
+        );
+        write_task(
+            &tasks_dir,
+            "task-004",
+            "Session expiry",
+            "auth",
+            "Tokens expire",
+        );
+        write_task(
+            &tasks_dir,
+            "task-005",
+            "Billing copy",
+            "billing",
+            "Pricing page",
+        );
+        let tasks = load_tasks(&repo.join("workmesh"));
+        let target = tasks
+            .iter()
+            .find(|task| task.id == "task-001")
+            .expect("target");
+
+        let related = related_tasks(repo, &tasks, target, DEFAULT_RELATED_LIMIT);
+        let ids: Vec<&str> = related.iter().map(|task| task.id.as_str()).collect();
+        // Shared file plus some overlap outranks a wording-only near duplicate.
+        assert_eq!(ids, vec!["task-003", "task-002", "task-004"]);
+        assert_eq!(related[0].shared_files, vec!["src/login.rs"]);
+        assert_eq!(related_reasons(&related[1]), "similar 0.50");
+        assert_eq!(related[2].shared_labels, vec!["auth"]);
+    }
+}
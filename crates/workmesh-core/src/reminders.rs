@@ -0,0 +1,187 @@
+//! Due-soon and overdue reminders for open tasks with a `due` / `due_date` field, shown by
+//! `reminders`, `resume`, `session resume`, and checkpoints.
+
+use std::collections::HashSet;
+use std::path::Path;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::backlog::resolve_backlog_dir;
+use crate::context::load_context;
+use crate::global_sessions::AgentSession;
+use crate::health::{first_extra, is_closed, DUE_FIELDS};
+use crate::policies::parse_task_date;
+use crate::task::{load_tasks, Task};
+use crate::views::{scope_ids_for_epic, scope_ids_from_context};
+
+/// Tasks due within this many days (or already overdue) are surfaced.
+pub const DEFAULT_DUE_SOON_DAYS: i64 = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReminderState {
+    Overdue,
+    DueToday,
+    DueSoon,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    pub due: String,
+    /// Days until the due date; negative when overdue.
+    pub days_left: i64,
+    pub state: ReminderState,
+}
+
+impl Reminder {
+    pub fn line(&self) -> String {
+        let when = match self.state {
+            ReminderState::Overdue => format!("overdue {}d", -self.days_left),
+            ReminderState::DueToday => "due today".to_string(),
+            ReminderState::DueSoon => format!("due in {}d", self.days_left),
+        };
+        format!(
+            "{} | {} ({}) | {} | {} | {}",
+            self.id, when, self.due, self.status, self.priority, self.title
+        )
+    }
+}
+
+/// Open tasks that are overdue or due within `within_days` of `today`, optionally limited to
+/// `scope` (lowercase ids). Most urgent first, then priority and id.
+pub fn due_reminders(
+    tasks: &[Task],
+    scope: Option<&HashSet<String>>,
+    today: NaiveDate,
+    within_days: i64,
+) -> Vec<Reminder> {
+    let mut reminders: Vec<Reminder> = tasks
+        .iter()
+        .filter(|task| !is_closed(task))
+        .filter(|task| scope.is_none_or(|ids| ids.contains(&task.id.to_lowercase())))
+        .filter_map(|task| {
+            let due = first_extra(task, DUE_FIELDS)?;
+            let days_left = (parse_task_date(&due)?.date() - today).num_days();
+            let state = match days_left {
+                d if d < 0 => ReminderState::Overdue,
+                0 => ReminderState::DueToday,
+                d if d <= within_days => ReminderState::DueSoon,
+                _ => return None,
+            };
+            Some(Reminder {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                priority: task.priority.clone(),
+                due,
+                days_left,
+                state,
+            })
+        })
+        .collect();
+    reminders.sort_by(|a, b| {
+        a.days_left
+            .cmp(&b.days_left)
+            .then_with(|| a.priority.to_lowercase().cmp(&b.priority.to_lowercase()))
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    reminders
+}
+
+/// Reminders within the repo's current context scope (epic or task list), or the whole backlog
+/// when no scope is set.
+pub fn context_due_reminders(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    today: NaiveDate,
+    within_days: i64,
+) -> Vec<Reminder> {
+    let scope = load_context(backlog_dir)
+        .ok()
+        .flatten()
+        .and_then(|context| scope_ids_from_context(tasks, &context));
+    due_reminders(tasks, scope.as_ref(), today, within_days)
+}
+
+/// Reminders for a saved session: its epic, else its working set, in the session's repo.
+/// Empty when the repo is gone or has no backlog.
+pub fn session_due_reminders(
+    session: &AgentSession,
+    today: NaiveDate,
+    within_days: i64,
+) -> Vec<Reminder> {
+    let Some(backlog_dir) = session
+        .repo_root
+        .as_deref()
+        .and_then(|root| resolve_backlog_dir(Path::new(root)).ok())
+    else {
+        return Vec::new();
+    };
+    let tasks = load_tasks(&backlog_dir);
+    let scope = match session
+        .epic_id
+        .as_deref()
+        .filter(|id| !id.trim().is_empty())
+    {
+        Some(epic_id) => Some(scope_ids_for_epic(&tasks, epic_id)),
+        None if !session.working_set.is_empty() => Some(
+            session
+                .working_set
+                .iter()
+                .map(|id| id.trim().to_lowercase())
+                .collect(),
+        ),
+        None => None,
+    };
+    due_reminders(&tasks, scope.as_ref(), today, within_days)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::parse_task_text;
+    use std::path::PathBuf;
+
+    fn task(id: &str, status: &str, due: Option<&str>) -> Task {
+        let due = due
+            .map(|value| format!("due: {}\n", value))
+            .unwrap_or_default();
+        let text = format!(
+            "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\n{due}---\n"
+        );
+        parse_task_text(&text, &PathBuf::from(format!("{id}.md"))).expect("parse")
+    }
+
+    #[test]
+    fn due_reminders_flag_overdue_today_and_soon_within_scope() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 15).expect("date");
+        let tasks = vec![
+            task("task-001", "To Do", Some("2026-10-17")),
+            task("task-002", "In Progress", Some("2026-10-13")),
+            task("task-003", "To Do", Some("2026-10-15")),
+            task("task-004", "Done", Some("2026-10-01")),
+            task("task-005", "To Do", Some("2026-11-30")),
+            task("task-006", "To Do", None),
+        ];
+
+        let reminders = due_reminders(&tasks, None, today, DEFAULT_DUE_SOON_DAYS);
+        let ids: Vec<&str> = reminders.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["task-002", "task-003", "task-001"]);
+        assert_eq!(reminders[0].state, ReminderState::Overdue);
+        assert_eq!(reminders[0].days_left, -2);
+        assert!(reminders[0].line().contains("overdue 2d"));
+        assert_eq!(reminders[1].state, ReminderState::DueToday);
+        assert_eq!(reminders[2].state, ReminderState::DueSoon);
+
+        let scope: HashSet<String> = ["task-001".to_string()].into_iter().collect();
+        let scoped = due_reminders(&tasks, Some(&scope), today, DEFAULT_DUE_SOON_DAYS);
+        assert_eq!(scoped.len(), 1);
+        assert_eq!(scoped[0].id, "task-001");
+    }
+}
@@ -0,0 +1,244 @@
+//! Read-only views of another repo's backlog without cloning it (`remote list|board|blockers`).
+//!
+//! The remote is cloned shallow (`--depth 1`), blobless and sparse, so only the `workmesh/` and
+//! `.workmesh/` trees (plus root files such as `.workmesh.toml`) are ever downloaded. Checkouts
+//! are cached under `~/.workmesh/remotes/` keyed by URL and ref, and refreshed on each use.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+/// Directories materialized by the sparse checkout.
+pub const REMOTE_SPARSE_PATHS: &[&str] = &["workmesh", ".workmesh"];
+
+/// A cached sparse checkout of a remote backlog.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct RemoteCheckout {
+    pub repo: String,
+    /// Branch or tag; `None` means the remote's default branch.
+    pub git_ref: Option<String>,
+    pub path: PathBuf,
+    pub head: Option<String>,
+    /// False when `offline` reused the cache without contacting the remote.
+    pub fetched: bool,
+}
+
+/// `<home>/remotes/<name>-<hash>`, where the hash covers the URL and ref.
+pub fn remote_cache_dir(home: &Path, repo: &str, git_ref: Option<&str>) -> PathBuf {
+    let mut hasher = Sha256::new();
+    hasher.update(repo.trim().as_bytes());
+    hasher.update(b"\n");
+    hasher.update(git_ref.unwrap_or("").trim().as_bytes());
+    let digest = hasher.finalize();
+    let hash: String = digest[..6].iter().map(|b| format!("{:02x}", b)).collect();
+    let name: String = repo
+        .trim()
+        .trim_end_matches('/')
+        .rsplit(['/', ':'])
+        .next()
+        .unwrap_or("repo")
+        .trim_end_matches(".git")
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let name = if name.is_empty() { "repo" } else { &name };
+    home.join("remotes").join(format!("{}-{}", name, hash))
+}
+
+/// Clone or refresh the sparse checkout of `repo` at `git_ref` under `home`. With `offline`, an
+/// existing cache is returned untouched (and a missing one is an error).
+pub fn fetch_remote_backlog(
+    home: &Path,
+    repo: &str,
+    git_ref: Option<&str>,
+    offline: bool,
+) -> Result<RemoteCheckout> {
+    let repo = repo.trim();
+    if repo.is_empty() {
+        return Err(anyhow!("remote repo URL is empty"));
+    }
+    let git_ref = git_ref.map(str::trim).filter(|value| !value.is_empty());
+    let path = remote_cache_dir(home, repo, git_ref);
+    let cached = path.join(".git").is_dir();
+
+    if offline {
+        if !cached {
+            return Err(anyhow!(
+                "no cached checkout of {} (run once without --offline)",
+                repo
+            ));
+        }
+    } else if cached {
+        let fetch_ref = git_ref.unwrap_or("HEAD");
+        git_run(&path, &["fetch", "-q", "--depth", "1", "origin", fetch_ref])?;
+        git_run(&path, &["reset", "-q", "--hard", "FETCH_HEAD"])?;
+    } else {
+        clone_sparse(repo, git_ref, &path)?;
+    }
+
+    Ok(RemoteCheckout {
+        repo: repo.to_string(),
+        git_ref: git_ref.map(str::to_string),
+        head: git_output(&path, &["rev-parse", "HEAD"]),
+        path,
+        fetched: !offline,
+    })
+}
+
+fn clone_sparse(repo: &str, git_ref: Option<&str>, path: &Path) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("create remote cache dir {}", parent.display()))?;
+    }
+    let mut cmd = Command::new("git");
+    cmd.args([
+        "clone",
+        "-q",
+        "--depth",
+        "1",
+        "--filter=blob:none",
+        "--sparse",
+        "--no-tags",
+    ]);
+    if let Some(git_ref) = git_ref {
+        cmd.arg("--branch").arg(git_ref);
+    }
+    let output = cmd
+        .arg(repo)
+        .arg(path)
+        .output()
+        .with_context(|| format!("run git clone {}", repo))?;
+    if !output.status.success() {
+        let _ = fs::remove_dir_all(path);
+        return Err(anyhow!(
+            "git clone {} failed: {}",
+            repo,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    let mut args = vec!["sparse-checkout", "set"];
+    args.extend_from_slice(REMOTE_SPARSE_PATHS);
+    if let Err(err) = git_run(path, &args) {
+        let _ = fs::remove_dir_all(path);
+        return Err(err);
+    }
+    Ok(())
+}
+
+fn git_run(repo_root: &Path, args: &[&str]) -> Result<()> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(args)
+        .output()
+        .with_context(|| format!("run git {:?} under {}", args, repo_root.display()))?;
+    if !output.status.success() {
+        return Err(anyhow!(
+            "git {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(())
+}
+
+fn git_output(repo_root: &Path, args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(args)
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    fn run_git(dir: &Path, args: &[&str]) {
+        let ok = Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .status()
+            .map(|status| status.success())
+            .unwrap_or(false);
+        assert!(ok, "git {:?}", args);
+    }
+
+    #[test]
+    fn fetches_only_the_backlog_tree_and_refreshes_the_cache() {
+        let temp = TempDir::new().expect("tempdir");
+        let origin = temp.path().join("origin");
+        let home = temp.path().join("home");
+        fs::create_dir_all(origin.join("workmesh").join("tasks")).expect("tasks dir");
+        fs::create_dir_all(origin.join("src")).expect("src dir");
+        run_git(&origin, &["init", "-q", "-b", "main"]);
+        run_git(&origin, &["config", "user.email", "workmesh@example.com"]);
+        run_git(&origin, &["config", "user.name", "WorkMesh"]);
+        fs::write(origin.join("src").join("lib.rs"), "fn main() {}\n").expect("write");
+        fs::write(
+            origin
+                .join("workmesh")
+                .join("tasks")
+                .join("task-001 - Alpha.md"),
+            "---\nid: task-001\ntitle: Alpha\nstatus: To Do\n---\n",
+        )
+        .expect("write");
+        run_git(&origin, &["add", "."]);
+        run_git(&origin, &["commit", "-q", "-m", "init"]);
+        let url = format!("file://{}", origin.display());
+
+        let checkout = fetch_remote_backlog(&home, &url, Some("main"), false).expect("fetch");
+        assert!(checkout.path.starts_with(home.join("remotes")));
+        assert!(checkout
+            .path
+            .join("workmesh/tasks/task-001 - Alpha.md")
+            .is_file());
+        assert!(!checkout.path.join("src").exists());
+        assert!(checkout.head.is_some());
+
+        fs::write(
+            origin
+                .join("workmesh")
+                .join("tasks")
+                .join("task-002 - Beta.md"),
+            "---\nid: task-002\ntitle: Beta\nstatus: To Do\n---\n",
+        )
+        .expect("write");
+        run_git(&origin, &["add", "."]);
+        run_git(&origin, &["commit", "-q", "-m", "beta"]);
+
+        let offline = fetch_remote_backlog(&home, &url, Some("main"), true).expect("offline");
+        assert!(!offline.fetched);
+        assert!(!offline
+            .path
+            .join("workmesh/tasks/task-002 - Beta.md")
+            .exists());
+
+        let refreshed = fetch_remote_backlog(&home, &url, Some("main"), false).expect("refresh");
+        assert_eq!(refreshed.path, checkout.path);
+        assert!(refreshed
+            .path
+            .join("workmesh/tasks/task-002 - Beta.md")
+            .is_file());
+        assert_ne!(refreshed.head, checkout.head);
+
+        assert!(fetch_remote_backlog(&home, &url, Some("other"), true).is_err());
+    }
+}
@@ -0,0 +1,1037 @@
+//! `report html`: a static HTML report of the backlog.
+//!
+//! `index.html` holds stats, the board (one column per status), blocked work, and per-epic
+//! progress; `tasks/<id>.html` is one page per task with its Markdown body rendered to HTML.
+//! Every page is filled from the same layout template and carries its CSS inline, so the output
+//! directory opens from disk or any static host with nothing else to copy.
+//!
+//! Bodies can embed diagrams and images. ```` ```mermaid ```` blocks are drawn in the browser by
+//! Mermaid's script, which only pages with a diagram load. ```` ```plantuml ```` blocks become
+//! inline SVG through the PlantUML CLI that `gantt-svg` uses, and stay code when it is missing.
+//! Local `![alt](path)` images are copied into `assets/`.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use regex::{Captures, Regex};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::gantt::render_plantuml;
+use crate::health::is_closed;
+use crate::obsidian::note_name;
+use crate::task::Task;
+use crate::task_ops::{is_epic, now_timestamp, parent_links, status_counts, subtask_rollups};
+use crate::timestamps::TimeDisplay;
+use crate::views::{blockers_report_with_context, board_lanes, BoardBy};
+
+/// Landing page, written at the output root.
+pub const REPORT_INDEX: &str = "index.html";
+/// Folder (inside the output) holding one page per task.
+pub const REPORT_TASKS_DIR: &str = "tasks";
+/// Folder (inside the output) holding images copied from task bodies.
+pub const REPORT_ASSETS_DIR: &str = "assets";
+
+#[derive(Debug, Error)]
+pub enum ReportError {
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HtmlReportSummary {
+    pub output: String,
+    pub index: String,
+    pub task_pages: usize,
+}
+
+/// Settings shared by `report html` and `show --format html`.
+#[derive(Debug, Clone, Default)]
+pub struct HtmlOptions {
+    pub time_display: TimeDisplay,
+    /// PlantUML command for diagram blocks; `None` looks up `WORKMESH_PLANTUML_CMD`, then
+    /// `WORKMESH_PLANTUML_JAR` (or `plantuml_jar`), then `plantuml` on `PATH`.
+    pub plantuml_cmd: Option<Vec<String>>,
+    pub plantuml_jar: Option<PathBuf>,
+}
+
+/// Page layout shared by the index and task pages; see [`fill_template`].
+const LAYOUT: &str = r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>{{title}}</title>
+<style>{{style}}</style>
+</head>
+<body>
+<header>{{header}}</header>
+<main>
+{{content}}
+</main>
+<footer>Generated {{generated_at}}</footer>
+{{scripts}}
+</body>
+</html>
+"#;
+
+const STYLE: &str = "
+body { font-family: system-ui, sans-serif; margin: 0; color: #1f2937; background: #f9fafb; }
+header, footer { padding: 0.75rem 1.5rem; background: #111827; color: #f9fafb; }
+header a { color: inherit; font-weight: 600; text-decoration: none; }
+footer { background: none; color: #6b7280; font-size: 0.85rem; }
+main { padding: 1rem 1.5rem; max-width: 80rem; }
+a { color: #1d4ed8; }
+table { border-collapse: collapse; margin: 0.5rem 0; }
+th, td { text-align: left; padding: 0.25rem 0.75rem; border-bottom: 1px solid #e5e7eb; }
+.stats { display: flex; flex-wrap: wrap; gap: 2rem; }
+.board { display: flex; gap: 1rem; overflow-x: auto; align-items: flex-start; }
+.lane { min-width: 14rem; background: #eef2f7; border-radius: 6px; padding: 0.5rem; }
+.lane h3 { margin: 0.25rem 0 0.5rem; font-size: 1rem; }
+.lane ul { list-style: none; margin: 0; padding: 0; }
+.card { background: #fff; border-radius: 4px; padding: 0.4rem 0.5rem; margin-bottom: 0.4rem;
+  box-shadow: 0 1px 2px rgba(0, 0, 0, 0.08); }
+.count, .muted { color: #6b7280; }
+.badge { display: inline-block; padding: 0 0.4rem; border-radius: 3px; background: #e5e7eb;
+  font-size: 0.8rem; }
+.status-in-progress { background: #dbeafe; color: #1d4ed8; }
+.status-blocked { background: #fee2e2; color: #b91c1c; }
+.status-done { background: #dcfce7; color: #15803d; }
+.missing { color: #b91c1c; text-decoration: line-through; }
+progress { width: 12rem; }
+pre { background: #f3f4f6; padding: 0.75rem; overflow-x: auto; }
+blockquote { border-left: 3px solid #d1d5db; margin-left: 0; padding-left: 1rem; color: #4b5563; }
+pre.mermaid { background: none; }
+article img, figure.diagram svg { max-width: 100%; height: auto; }
+";
+
+/// Loaded only by pages with a Mermaid diagram. Offline, the diagram source stays readable.
+const MERMAID_SCRIPT: &str = r#"<script type="module">
+import mermaid from "https://cdn.jsdelivr.net/npm/mermaid@11/dist/mermaid.esm.min.mjs";
+mermaid.initialize({ startOnLoad: true });
+</script>"#;
+
+/// Write the report for `tasks` under `output`. Re-running overwrites the generated pages;
+/// other files in `output` are left alone.
+pub fn write_html_report(
+    tasks: &[Task],
+    output: &Path,
+    options: &HtmlOptions,
+) -> Result<HtmlReportSummary, ReportError> {
+    let tasks_dir = output.join(REPORT_TASKS_DIR);
+    fs::create_dir_all(&tasks_dir)?;
+    let generated_at = options.time_display.render(&now_timestamp());
+    let report = Report::new(tasks, options.time_display, true);
+
+    let header = format!("<a href=\"../{}\">WorkMesh report</a>", REPORT_INDEX);
+    for task in tasks {
+        let embeds = Embeds {
+            assets: Some((
+                output.join(REPORT_ASSETS_DIR),
+                format!("../{}", REPORT_ASSETS_DIR),
+            )),
+            ..Embeds::for_task(task, options)
+        };
+        let page = html_page(
+            &format!("{} {}", task.id, task.title),
+            &header,
+            &report.task_page(task, &embeds),
+            &generated_at,
+        );
+        fs::write(
+            tasks_dir.join(format!("{}.html", note_name(&task.id))),
+            page,
+        )?;
+    }
+
+    let index = output.join(REPORT_INDEX);
+    let header = format!("<a href=\"{}\">WorkMesh report</a>", REPORT_INDEX);
+    let page = html_page(
+        "Backlog report",
+        &header,
+        &report.index_page(),
+        &generated_at,
+    );
+    fs::write(&index, page)?;
+
+    Ok(HtmlReportSummary {
+        output: output.display().to_string(),
+        index: index.display().to_string(),
+        task_pages: tasks.len(),
+    })
+}
+
+/// `task` as a standalone page (`show --format html`). With no report around it, other tasks
+/// are plain ids and local images are linked by `file://` URL.
+pub fn task_html_page(tasks: &[Task], task: &Task, options: &HtmlOptions) -> String {
+    let report = Report::new(tasks, options.time_display, false);
+    html_page(
+        &format!("{} {}", task.id, task.title),
+        "WorkMesh",
+        &report.task_page(task, &Embeds::for_task(task, options)),
+        &options.time_display.render(&now_timestamp()),
+    )
+}
+
+fn html_page(title: &str, header: &str, content: &str, generated_at: &str) -> String {
+    let scripts = if content.contains("<pre class=\"mermaid\">") {
+        MERMAID_SCRIPT
+    } else {
+        ""
+    };
+    fill_template(
+        LAYOUT,
+        &[
+            ("title", &escape_html(title)),
+            ("style", STYLE),
+            ("header", header),
+            ("content", content),
+            ("generated_at", &escape_html(generated_at)),
+            ("scripts", scripts),
+        ],
+    )
+}
+
+/// Replace each `{{name}}` in `template` with its value. Values are inserted as-is (escape them
+/// first) and are not scanned again, so task text cannot inject placeholders. Unknown names are
+/// left in place.
+pub fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let Some(end) = after.find("}}") else {
+            rest = &rest[start..];
+            break;
+        };
+        match values.iter().find(|(name, _)| *name == &after[..end]) {
+            Some((_, value)) => out.push_str(value),
+            None => out.push_str(&rest[start..start + end + 4]),
+        }
+        rest = &after[end + 2..];
+    }
+    out.push_str(rest);
+    out
+}
+
+struct Report<'a> {
+    tasks: &'a [Task],
+    by_id: HashMap<String, &'a Task>,
+    /// child id (lowercase) -> parent id (lowercase)
+    parents: HashMap<String, String>,
+    time_display: TimeDisplay,
+    /// Whether other tasks link to their pages (false on a standalone page).
+    links: bool,
+}
+
+impl<'a> Report<'a> {
+    fn new(tasks: &'a [Task], time_display: TimeDisplay, links: bool) -> Self {
+        Self {
+            tasks,
+            by_id: tasks
+                .iter()
+                .map(|task| (task.id.to_lowercase(), task))
+                .collect(),
+            parents: parent_links(tasks),
+            time_display,
+            links,
+        }
+    }
+
+    /// Link to the page of task `id`, relative to `prefix` (`tasks/` from the index, empty from
+    /// a task page). Ids without a task render struck through.
+    fn link(&self, id: &str, prefix: &str) -> String {
+        match self.by_id.get(&id.trim().to_lowercase()) {
+            Some(task) if !self.links => escape_html(&task.id),
+            Some(task) => format!(
+                "<a href=\"{}{}.html\">{}</a>",
+                prefix,
+                url_path(&note_name(&task.id)),
+                escape_html(&task.id)
+            ),
+            None => format!("<span class=\"missing\">{}</span>", escape_html(id.trim())),
+        }
+    }
+
+    fn index_page(&self) -> String {
+        let mut html = vec!["<h1>Backlog report</h1>".to_string()];
+        html.push(self.stats_section());
+        html.push(self.board_section());
+        html.push(self.blockers_section());
+        html.push(self.epics_section());
+        html.join("\n")
+    }
+
+    fn stats_section(&self) -> String {
+        let total = self.tasks.len();
+        let closed = self.tasks.iter().filter(|task| is_closed(task)).count();
+        let percent = (closed * 100).checked_div(total).unwrap_or(0);
+        let mut priorities: BTreeMap<String, usize> = BTreeMap::new();
+        for task in self.tasks {
+            *priorities.entry(or_none(&task.priority)).or_default() += 1;
+        }
+        let table = |heading: &str, rows: Vec<(String, usize)>| {
+            let mut html = format!("<table><tr><th>{}</th><th>Tasks</th></tr>", heading);
+            for (key, count) in rows {
+                html.push_str(&format!(
+                    "<tr><td>{}</td><td>{}</td></tr>",
+                    escape_html(&key),
+                    count
+                ));
+            }
+            html.push_str("</table>");
+            html
+        };
+        format!(
+            "<section id=\"stats\"><h2>Stats</h2>\n<p>{} tasks, {} closed ({}%)</p>\n\
+<div class=\"stats\">{}{}</div></section>",
+            total,
+            closed,
+            percent,
+            table("Status", status_counts(self.tasks)),
+            table("Priority", priorities.into_iter().collect()),
+        )
+    }
+
+    fn board_section(&self) -> String {
+        let mut html =
+            vec!["<section id=\"board\"><h2>Board</h2>\n<div class=\"board\">".to_string()];
+        for (lane, tasks) in board_lanes(self.tasks, BoardBy::Status, None) {
+            html.push(format!(
+                "<div class=\"lane\"><h3>{} <span class=\"count\">{}</span></h3><ul>",
+                escape_html(&lane),
+                tasks.len()
+            ));
+            for task in tasks {
+                let assignees: Vec<String> = task
+                    .assignee
+                    .iter()
+                    .map(|name| format!("@{}", escape_html(name)))
+                    .collect();
+                html.push(format!(
+                    "<li class=\"card\">{} {} <span class=\"badge\">{}</span> <span class=\"muted\">{}</span></li>",
+                    self.link(&task.id, "tasks/"),
+                    escape_html(&task.title),
+                    escape_html(&task.priority),
+                    assignees.join(" ")
+                ));
+            }
+            html.push("</ul></div>".to_string());
+        }
+        html.push("</div></section>".to_string());
+        html.join("\n")
+    }
+
+    fn blockers_section(&self) -> String {
+        let report = blockers_report_with_context(self.tasks, None, None);
+        let mut html = vec!["<section id=\"blockers\"><h2>Blockers</h2>".to_string()];
+        if report.blocked_tasks.is_empty() {
+            html.push("<p>No blocked tasks.</p>".to_string());
+        } else {
+            html.push(
+                "<table><tr><th>Task</th><th>Status</th><th>Blocked by</th></tr>".to_string(),
+            );
+            for entry in &report.blocked_tasks {
+                let blockers: Vec<String> = entry
+                    .blockers
+                    .iter()
+                    .chain(&entry.missing_refs)
+                    .map(|id| self.link(id, "tasks/"))
+                    .collect();
+                html.push(format!(
+                    "<tr><td>{} {}</td><td>{}</td><td>{}</td></tr>",
+                    self.link(&entry.id, "tasks/"),
+                    escape_html(&entry.title),
+                    status_badge(&entry.status),
+                    blockers.join(", ")
+                ));
+            }
+            html.push("</table>".to_string());
+            let top: Vec<String> = report
+                .top_blockers
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{} ({})",
+                        self.link(&entry.id, "tasks/"),
+                        entry.blocked_count
+                    )
+                })
+                .collect();
+            if !top.is_empty() {
+                html.push(format!("<p>Top blockers: {}</p>", top.join(", ")));
+            }
+        }
+        html.push("</section>".to_string());
+        html.join("\n")
+    }
+
+    fn epics_section(&self) -> String {
+        let rollups = subtask_rollups(self.tasks);
+        let mut html = vec!["<section id=\"epics\"><h2>Epic progress</h2>".to_string()];
+        let epics: Vec<&Task> = self.tasks.iter().filter(|task| is_epic(task)).collect();
+        if epics.is_empty() {
+            html.push("<p>No epics.</p>".to_string());
+        } else {
+            html.push(
+                "<table><tr><th>Epic</th><th>Status</th><th>Progress</th><th></th></tr>"
+                    .to_string(),
+            );
+            for epic in epics {
+                let rollup = rollups.get(&epic.id.to_lowercase());
+                let (done, total) = rollup.map(|r| (r.done, r.total)).unwrap_or((0, 0));
+                html.push(format!(
+                    "<tr><td>{} {}</td><td>{}</td><td><progress max=\"{}\" value=\"{}\"></progress></td><td>{}/{} done</td></tr>",
+                    self.link(&epic.id, "tasks/"),
+                    escape_html(&epic.title),
+                    status_badge(&epic.status),
+                    total.max(1),
+                    done,
+                    done,
+                    total
+                ));
+            }
+            html.push("</table>".to_string());
+        }
+        html.push("</section>".to_string());
+        html.join("\n")
+    }
+
+    fn task_page(&self, task: &Task, embeds: &Embeds) -> String {
+        let links = |ids: &[String]| {
+            ids.iter()
+                .map(|id| self.link(id, ""))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        let mut rows: Vec<(&str, String)> = vec![
+            ("Status", status_badge(&task.status)),
+            ("Priority", escape_html(&task.priority)),
+            ("Phase", escape_html(&task.phase)),
+            ("Kind", escape_html(&task.kind)),
+        ];
+        if !task.assignee.is_empty() {
+            rows.push(("Assignee", escape_html(&task.assignee.join(", "))));
+        }
+        if !task.labels.is_empty() {
+            rows.push(("Labels", escape_html(&task.labels.join(", "))));
+        }
+        if let Some(parent) = self.parents.get(&task.id.to_lowercase()) {
+            rows.push(("Parent", self.link(parent, "")));
+        }
+        if !task.dependencies.is_empty() {
+            rows.push(("Dependencies", links(&task.dependencies)));
+        }
+        if !task.relationships.blocked_by.is_empty() {
+            rows.push(("Blocked by", links(&task.relationships.blocked_by)));
+        }
+        if !task.relationships.relates_to.is_empty() {
+            rows.push(("Related", links(&task.relationships.relates_to)));
+        }
+        for (label, value) in [
+            ("Created", &task.created_date),
+            ("Updated", &task.updated_date),
+        ] {
+            if let Some(value) = value {
+                rows.push((label, escape_html(&self.time_display.render(value))));
+            }
+        }
+
+        let mut html = vec![format!(
+            "<h1>{} {}</h1>",
+            escape_html(&task.id),
+            escape_html(&task.title)
+        )];
+        html.push("<table class=\"meta\">".to_string());
+        for (label, value) in rows {
+            html.push(format!("<tr><th>{}</th><td>{}</td></tr>", label, value));
+        }
+        html.push("</table>".to_string());
+
+        let id = task.id.to_lowercase();
+        let children: Vec<&Task> = self
+            .tasks
+            .iter()
+            .filter(|child| self.parents.get(&child.id.to_lowercase()) == Some(&id))
+            .collect();
+        if !children.is_empty() {
+            html.push("<h2>Subtasks</h2>".to_string());
+            if let Some(rollup) = subtask_rollups(self.tasks).get(&id) {
+                html.push(format!("<p>{}</p>", rollup.label()));
+            }
+            html.push("<ul>".to_string());
+            for child in children {
+                html.push(format!(
+                    "<li>{} {} {}</li>",
+                    self.link(&child.id, ""),
+                    escape_html(&child.title),
+                    status_badge(&child.status)
+                ));
+            }
+            html.push("</ul>".to_string());
+        }
+
+        html.push(format!(
+            "<article>\n{}\n</article>",
+            markdown_to_html(&task.body, embeds)
+        ));
+        html.join("\n")
+    }
+}
+
+fn status_badge(status: &str) -> String {
+    let slug: String = status
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|ch| if ch.is_ascii_alphanumeric() { ch } else { '-' })
+        .collect();
+    format!(
+        "<span class=\"badge status-{}\">{}</span>",
+        slug,
+        escape_html(&or_none(status))
+    )
+}
+
+fn or_none(value: &str) -> String {
+    match value.trim() {
+        "" => "(none)".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+pub fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Percent-encode a page name for use in an `href`.
+fn url_path(name: &str) -> String {
+    name.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                (byte as char).to_string()
+            }
+            other => format!("%{:02X}", other),
+        })
+        .collect()
+}
+
+/// How diagram blocks and `![alt](src)` images in a task body are rendered.
+#[derive(Debug, Clone, Default)]
+pub struct Embeds {
+    pub plantuml_cmd: Option<Vec<String>>,
+    pub plantuml_jar: Option<PathBuf>,
+    /// Directory relative image paths resolve against (the task file's directory).
+    pub base_dir: Option<PathBuf>,
+    /// Directory local images are copied into, and the `src` prefix that reaches it from the
+    /// page. Without it, local images are linked by `file://` URL.
+    pub assets: Option<(PathBuf, String)>,
+}
+
+impl Embeds {
+    pub fn for_task(task: &Task, options: &HtmlOptions) -> Self {
+        Self {
+            plantuml_cmd: options.plantuml_cmd.clone(),
+            plantuml_jar: options.plantuml_jar.clone(),
+            base_dir: task
+                .file_path
+                .as_deref()
+                .and_then(Path::parent)
+                .map(Path::to_path_buf),
+            assets: None,
+        }
+    }
+
+    fn code_block(&self, lang: &str, code: &str) -> String {
+        match lang {
+            "mermaid" => format!("<pre class=\"mermaid\">{}</pre>", escape_html(code)),
+            "plantuml" | "puml" => match self.plantuml_svg(code) {
+                Some(svg) => format!("<figure class=\"diagram\">{}</figure>", svg),
+                None => format!("<pre><code>{}</code></pre>", escape_html(code)),
+            },
+            _ => format!("<pre><code>{}</code></pre>", escape_html(code)),
+        }
+    }
+
+    fn plantuml_svg(&self, code: &str) -> Option<String> {
+        let source = if code.trim_start().starts_with("@start") {
+            code.to_string()
+        } else {
+            format!("@startuml\n{}\n@enduml", code)
+        };
+        let svg = render_plantuml(
+            &source,
+            self.plantuml_cmd.clone(),
+            self.plantuml_jar.as_deref(),
+            None,
+        )
+        .ok()?;
+        // Drop the XML prolog so the SVG can sit inline in the page.
+        let start = svg.find("<svg")?;
+        Some(svg[start..].trim_end().to_string())
+    }
+
+    fn image(&self, alt: &str, src: &str) -> String {
+        match self.image_src(src) {
+            Some(src) => format!(
+                "<img src=\"{}\" alt=\"{}\">",
+                escape_html(&src),
+                escape_html(alt)
+            ),
+            None => escape_html(alt),
+        }
+    }
+
+    /// `src` as the page should reference it: URLs and paths that are not files as written,
+    /// local files copied into the assets directory or as `file://` URLs. Unsafe schemes get
+    /// no image at all.
+    fn image_src(&self, src: &str) -> Option<String> {
+        if !safe_url(src) {
+            return None;
+        }
+        if src.contains(':') {
+            return Some(src.to_string());
+        }
+        let path = match &self.base_dir {
+            Some(base) => base.join(src),
+            None => PathBuf::from(src),
+        };
+        if !path.is_file() {
+            return Some(src.to_string());
+        }
+        match &self.assets {
+            Some((dir, href)) => Some(
+                copy_asset(&path, dir)
+                    .map(|name| format!("{}/{}", href, url_path(&name)))
+                    .unwrap_or_else(|_| src.to_string()),
+            ),
+            None => Some(file_url(&path)),
+        }
+    }
+}
+
+/// Copy an image into `dir` as `<content hash>-<file name>`, so equal names from different
+/// folders do not collide and repeated images are stored once.
+fn copy_asset(path: &Path, dir: &Path) -> std::io::Result<String> {
+    let bytes = fs::read(path)?;
+    let hash = format!("{:x}", Sha256::digest(&bytes));
+    let file = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = format!("{}-{}", &hash[..12], file);
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(&name), bytes)?;
+    Ok(name)
+}
+
+fn file_url(path: &Path) -> String {
+    let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+    let text = path.to_string_lossy().replace('\\', "/");
+    let encoded: Vec<String> = text.split('/').map(url_path).collect();
+    let encoded = encoded.join("/");
+    if encoded.starts_with('/') {
+        format!("file://{}", encoded)
+    } else {
+        format!("file:///{}", encoded)
+    }
+}
+
+/// Render the Markdown subset task bodies use: ATX headings, paragraphs, `-`/`*`/`1.` lists
+/// (with `[ ]`/`[x]` checkboxes), `>` quotes, fenced code, rules, and inline code, emphasis,
+/// links and images. Raw HTML is escaped rather than passed through, and nested lists are
+/// flattened. Fences tagged `mermaid` or `plantuml` are diagrams (see [`Embeds`]).
+pub fn markdown_to_html(markdown: &str, embeds: &Embeds) -> String {
+    let mut out = MarkdownWriter {
+        inline: InlineRules::new(),
+        embeds,
+        html: Vec::new(),
+        paragraph: Vec::new(),
+        quote: Vec::new(),
+        list: None,
+    };
+    // Info string (lowercased language) and lines of the open fence.
+    let mut fence: Option<(String, Vec<&str>)> = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim();
+        if let Some((lang, code)) = fence.as_mut() {
+            if trimmed.starts_with("```") {
+                out.html.push(embeds.code_block(lang, &code.join("\n")));
+                fence = None;
+            } else {
+                code.push(line);
+            }
+            continue;
+        }
+        if let Some(info) = trimmed.strip_prefix("```") {
+            out.close_all();
+            let lang = info.split_whitespace().next().unwrap_or("").to_lowercase();
+            fence = Some((lang, Vec::new()));
+        } else if trimmed.is_empty() {
+            out.close_all();
+        } else if let Some(rest) = trimmed.strip_prefix('>') {
+            out.close_paragraph();
+            out.close_list();
+            out.quote.push(rest.trim().to_string());
+        } else if is_rule(trimmed) {
+            out.close_all();
+            out.html.push("<hr>".to_string());
+        } else if let Some((level, text)) = heading(trimmed) {
+            out.close_all();
+            let text = out.inline(text);
+            out.html.push(format!("<h{0}>{1}</h{0}>", level, text));
+        } else if let Some((kind, item)) = list_item(trimmed) {
+            out.close_paragraph();
+            out.close_quote();
+            if out.list != Some(kind) {
+                out.close_list();
+                out.html.push(format!("<{}>", kind));
+                out.list = Some(kind);
+            }
+            let item = match checkbox(item) {
+                Some((checked, text)) => format!(
+                    "<input type=\"checkbox\" disabled{}> {}",
+                    if checked { " checked" } else { "" },
+                    out.inline(text)
+                ),
+                None => out.inline(item),
+            };
+            out.html.push(format!("<li>{}</li>", item));
+        } else {
+            out.close_list();
+            out.close_quote();
+            out.paragraph.push(trimmed.to_string());
+        }
+    }
+    if let Some((lang, code)) = fence {
+        out.html.push(embeds.code_block(&lang, &code.join("\n")));
+    }
+    out.close_all();
+    out.html.join("\n")
+}
+
+struct MarkdownWriter<'a> {
+    inline: InlineRules,
+    embeds: &'a Embeds,
+    html: Vec<String>,
+    paragraph: Vec<String>,
+    quote: Vec<String>,
+    /// Open list element (`ul` or `ol`).
+    list: Option<&'static str>,
+}
+
+impl MarkdownWriter<'_> {
+    fn inline(&self, text: &str) -> String {
+        self.inline.render(text, self.embeds)
+    }
+
+    fn close_paragraph(&mut self) {
+        if !self.paragraph.is_empty() {
+            let text = self.inline(&self.paragraph.join(" "));
+            self.html.push(format!("<p>{}</p>", text));
+            self.paragraph.clear();
+        }
+    }
+
+    fn close_quote(&mut self) {
+        if !self.quote.is_empty() {
+            let text = self.inline(&self.quote.join(" "));
+            self.html
+                .push(format!("<blockquote><p>{}</p></blockquote>", text));
+            self.quote.clear();
+        }
+    }
+
+    fn close_list(&mut self) {
+        if let Some(kind) = self.list.take() {
+            self.html.push(format!("</{}>", kind));
+        }
+    }
+
+    fn close_all(&mut self) {
+        self.close_paragraph();
+        self.close_quote();
+        self.close_list();
+    }
+}
+
+struct InlineRules {
+    image: Regex,
+    link: Regex,
+    strong: Regex,
+    emphasis: Regex,
+}
+
+impl InlineRules {
+    fn new() -> Self {
+        Self {
+            image: Regex::new(r"!\[([^\]]*)\]\(([^)\s]+)\)").expect("regex"),
+            link: Regex::new(r"\[([^\]]+)\]\(([^)\s]+)\)").expect("regex"),
+            strong: Regex::new(r"\*\*([^*]+)\*\*").expect("regex"),
+            emphasis: Regex::new(r"\*([^*\s][^*]*)\*").expect("regex"),
+        }
+    }
+
+    /// Inline Markdown to HTML; code spans are escaped and otherwise left alone.
+    fn render(&self, text: &str, embeds: &Embeds) -> String {
+        let mut out = String::new();
+        let mut rest = text;
+        while let Some(start) = rest.find('`') {
+            let Some(len) = rest[start + 1..].find('`') else {
+                break;
+            };
+            out.push_str(&self.render_images(&rest[..start], embeds));
+            out.push_str(&format!(
+                "<code>{}</code>",
+                escape_html(&rest[start + 1..start + 1 + len])
+            ));
+            rest = &rest[start + len + 2..];
+        }
+        out.push_str(&self.render_images(rest, embeds));
+        out
+    }
+
+    /// Images are resolved from the raw path, before escaping.
+    fn render_images(&self, text: &str, embeds: &Embeds) -> String {
+        let mut out = String::new();
+        let mut last = 0;
+        for caps in self.image.captures_iter(text) {
+            let whole = caps.get(0).expect("match");
+            out.push_str(&self.render_text(&text[last..whole.start()]));
+            out.push_str(&embeds.image(&caps[1], &caps[2]));
+            last = whole.end();
+        }
+        out.push_str(&self.render_text(&text[last..]));
+        out
+    }
+
+    fn render_text(&self, text: &str) -> String {
+        let text = escape_html(text);
+        let text = self.link.replace_all(&text, |caps: &Captures| {
+            if safe_url(&caps[2]) {
+                format!("<a href=\"{}\">{}</a>", &caps[2], &caps[1])
+            } else {
+                caps[1].to_string()
+            }
+        });
+        let text = self.strong.replace_all(&text, "<strong>$1</strong>");
+        self.emphasis.replace_all(&text, "<em>$1</em>").into_owned()
+    }
+}
+
+/// Relative links and http(s)/mailto only, so a task body cannot smuggle in `javascript:`.
+fn safe_url(url: &str) -> bool {
+    let lower = url.to_lowercase();
+    match lower.find(':') {
+        Some(colon) if !lower[..colon].contains('/') => ["http:", "https:", "mailto:"]
+            .iter()
+            .any(|scheme| lower.starts_with(scheme)),
+        _ => true,
+    }
+}
+
+fn is_rule(line: &str) -> bool {
+    let chars: Vec<char> = line.chars().filter(|ch| !ch.is_whitespace()).collect();
+    chars.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|mark| chars.iter().all(|ch| ch == mark))
+}
+
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|ch| *ch == '#').count();
+    let text = line[level..].strip_prefix(' ')?;
+    (1..=6).contains(&level).then(|| (level, text.trim()))
+}
+
+fn list_item(line: &str) -> Option<(&'static str, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(marker) {
+            return Some(("ul", item.trim()));
+        }
+    }
+    let digits = line.chars().take_while(|ch| ch.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = &line[digits..];
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(|item| ("ol", item.trim()))
+}
+
+fn checkbox(item: &str) -> Option<(bool, &str)> {
+    if let Some(text) = item.strip_prefix("[ ] ") {
+        return Some((false, text));
+    }
+    item.strip_prefix("[x] ")
+        .or_else(|| item.strip_prefix("[X] "))
+        .map(|text| (true, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    #[test]
+    fn markdown_to_html_renders_task_bodies_and_escapes_html() {
+        let body = "## Description\nLogin *must* use **OAuth**,\nsee [docs](https://example.com/a?b=1&c=2).\n\n\
+- [x] Form renders\n- [ ] Errors `<shown>`\n\n1. First\n2. Second\n\n> Quoted\n\n\
+```\nlet a = <b>;\n```\n\n---\n<script>alert(1)</script> [bad](javascript:void)\n\n\
+```Mermaid\ngraph LR\n  A --> B\n```\n![logo](https://example.com/logo.png) ![x](javascript:void) ![y](missing.png)";
+        let html = markdown_to_html(body, &Embeds::default());
+        let expected = vec![
+            "<h2>Description</h2>",
+            "<p>Login <em>must</em> use <strong>OAuth</strong>, see <a href=\"https://example.com/a?b=1&amp;c=2\">docs</a>.</p>",
+            "<ul>",
+            "<li><input type=\"checkbox\" disabled checked> Form renders</li>",
+            "<li><input type=\"checkbox\" disabled> Errors <code>&lt;shown&gt;</code></li>",
+            "</ul>",
+            "<ol>",
+            "<li>First</li>",
+            "<li>Second</li>",
+            "</ol>",
+            "<blockquote><p>Quoted</p></blockquote>",
+            "<pre><code>let a = &lt;b&gt;;</code></pre>",
+            "<hr>",
+            "<p>&lt;script&gt;alert(1)&lt;/script&gt; bad</p>",
+            "<pre class=\"mermaid\">graph LR",
+            "  A --&gt; B</pre>",
+            "<p><img src=\"https://example.com/logo.png\" alt=\"logo\"> x <img src=\"missing.png\" alt=\"y\"></p>",
+        ];
+        assert_eq!(html.lines().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn fill_template_does_not_rescan_values() {
+        assert_eq!(
+            fill_template(
+                "<h1>{{title}}</h1>{{body}}{{other}}{{",
+                &[("title", "{{body}}"), ("body", "x")]
+            ),
+            "<h1>{{body}}</h1>x{{other}}{{"
+        );
+    }
+
+    #[test]
+    fn write_html_report_writes_index_and_linked_task_pages() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::write(
+            tasks_dir.join("task-001 - epic.md"),
+            "---\nid: task-001\ntitle: Auth epic\nkind: epic\nstatus: In Progress\n\
+priority: P1\nphase: Phase1\ndependencies: []\nlabels: []\n---\n\nEpic body\n",
+        )
+        .expect("epic");
+        fs::write(
+            tasks_dir.join("task-002 - login.md"),
+            "---\nid: task-002\ntitle: Login <form>\nstatus: To Do\npriority: P2\n\
+phase: Phase1\ndependencies: [task-003]\nlabels: []\n\
+relationships:\n  parent: [task-001]\n---\n\n## Description\nBuild the **form**.\n",
+        )
+        .expect("login");
+        fs::write(
+            tasks_dir.join("task-003 - api.md"),
+            "---\nid: task-003\ntitle: Session API\nstatus: Done\npriority: P2\n\
+phase: Phase1\ndependencies: []\nlabels: []\nrelationships:\n  parent: [task-001]\n---\n\nAPI body\n",
+        )
+        .expect("api");
+        fs::write(
+            tasks_dir.join("task-004 - docs.md"),
+            "---\nid: task-004\ntitle: Docs\nstatus: To Do\npriority: P3\n\
+phase: Phase1\ndependencies: [task-002]\nlabels: []\n---\n\n\
+![flow](img/flow.png)\n\n```plantuml\nAlice -> Bob\n```\n\n```mermaid\ngraph LR\n```\n",
+        )
+        .expect("docs");
+        fs::create_dir_all(tasks_dir.join("img")).expect("img dir");
+        fs::write(tasks_dir.join("img/flow.png"), b"png").expect("image");
+        let tasks = load_tasks(&backlog);
+
+        let output = temp.path().join("report");
+        let options = HtmlOptions {
+            time_display: TimeDisplay::Utc,
+            // Stands in for PlantUML: prints an SVG with an XML prolog.
+            plantuml_cmd: Some(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "cat >/dev/null; printf '<?xml version=\"1.0\"?><svg>uml</svg>'".to_string(),
+            ]),
+            plantuml_jar: None,
+        };
+        let summary = write_html_report(&tasks, &output, &options).expect("report");
+        assert_eq!(summary.task_pages, 4);
+
+        let index = fs::read_to_string(output.join(REPORT_INDEX)).expect("index");
+        assert!(index.starts_with("<!DOCTYPE html>") && index.contains("<style>"));
+        assert!(index.contains("<p>4 tasks, 1 closed (25%)</p>"));
+        assert!(index.contains("<h3>To Do <span class=\"count\">2</span></h3>"));
+        assert!(index.contains("<a href=\"tasks/task-002.html\">task-002</a> Login &lt;form&gt;"));
+        assert!(index.contains(
+            "<td><a href=\"tasks/task-004.html\">task-004</a> Docs</td><td><span class=\"badge status-to-do\">To Do</span></td><td><a href=\"tasks/task-002.html\">task-002</a></td>"
+        ));
+        assert!(index.contains("<progress max=\"2\" value=\"1\"></progress></td><td>1/2 done"));
+        assert!(!index.contains("<script"));
+
+        let login = fs::read_to_string(output.join("tasks/task-002.html")).expect("login page");
+        assert!(login.contains("<a href=\"../index.html\">WorkMesh report</a>"));
+        assert!(login
+            .contains("<tr><th>Parent</th><td><a href=\"task-001.html\">task-001</a></td></tr>"));
+        assert!(login.contains("<h2>Description</h2>\n<p>Build the <strong>form</strong>.</p>"));
+
+        let epic = fs::read_to_string(output.join("tasks/task-001.html")).expect("epic page");
+        assert!(epic.contains("<h2>Subtasks</h2>\n<p>1/2 done</p>"));
+
+        let docs = fs::read_to_string(output.join("tasks/task-004.html")).expect("docs page");
+        let asset = fs::read_dir(output.join(REPORT_ASSETS_DIR))
+            .expect("assets")
+            .map(|entry| {
+                entry
+                    .expect("entry")
+                    .file_name()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(asset.len(), 1);
+        assert!(asset[0].ends_with("-flow.png"));
+        assert!(docs.contains(&format!(
+            "<img src=\"../assets/{}\" alt=\"flow\">",
+            asset[0]
+        )));
+        if cfg!(unix) {
+            assert!(docs.contains("<figure class=\"diagram\"><svg>uml</svg></figure>"));
+        }
+        assert!(docs.contains("<pre class=\"mermaid\">graph LR</pre>"));
+        assert!(docs.contains("<script type=\"module\">"));
+
+        // Standalone: no links to pages that do not exist, images by file URL.
+        let task = tasks
+            .iter()
+            .find(|task| task.id == "task-004")
+            .expect("task");
+        let page = task_html_page(&tasks, task, &HtmlOptions::default());
+        assert!(page.contains("<header>WorkMesh</header>"));
+        assert!(page.contains("<tr><th>Dependencies</th><td>task-002</td></tr>"));
+        assert!(page.contains("<img src=\"file://") && page.contains("/img/flow.png\""));
+    }
+}
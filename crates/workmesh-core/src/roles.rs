@@ -0,0 +1,279 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+
+/// Fields only maintainers may change when `protected_fields` is not configured.
+pub const DEFAULT_PROTECTED_FIELDS: &[&str] = &["id", "uid", "confidential"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    /// Read-only tools.
+    Viewer,
+    /// Task mutations, except protected fields.
+    Contributor,
+    /// Everything, including rekey/migrate/archive/merge and config changes.
+    Maintainer,
+}
+
+impl Role {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Role::Viewer => "viewer",
+            Role::Contributor => "contributor",
+            Role::Maintainer => "maintainer",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "viewer" | "read" | "reader" => Some(Role::Viewer),
+            "contributor" | "write" | "writer" => Some(Role::Contributor),
+            "maintainer" | "admin" => Some(Role::Maintainer),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// `[roles]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+///
+/// When no roles table exists, every caller is a maintainer (the historical behavior).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RolesConfig {
+    /// Role for callers that match no identity or token. Defaults to `viewer`.
+    pub default: Option<Role>,
+    /// Identity name -> role.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub identities: BTreeMap<String, Role>,
+    /// `sha256:<hex>` of a bearer token -> role (see `token_hash`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tokens: BTreeMap<String, Role>,
+    /// Front matter fields that only maintainers may edit.
+    pub protected_fields: Option<Vec<String>>,
+}
+
+impl RolesConfig {
+    pub fn protected_fields(&self) -> Vec<String> {
+        match self.protected_fields.as_ref() {
+            Some(fields) => fields.iter().map(|f| f.trim().to_lowercase()).collect(),
+            None => DEFAULT_PROTECTED_FIELDS
+                .iter()
+                .map(|f| f.to_string())
+                .collect(),
+        }
+    }
+}
+
+/// Who is calling: an identity name and/or a bearer token presented to the server.
+#[derive(Debug, Clone, Default)]
+pub struct Caller {
+    pub identity: Option<String>,
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct Grant {
+    pub role: Role,
+    /// `unconfigured`, `token`, `identity`, or `default`.
+    pub source: &'static str,
+    pub identity: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Error)]
+#[error("{tool} requires role {required} (caller has {role}): {reason}")]
+pub struct AuthorizationError {
+    pub tool: String,
+    pub role: Role,
+    pub required: Role,
+    pub identity: Option<String>,
+    pub reason: String,
+}
+
+impl AuthorizationError {
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "ok": false,
+            "error": "forbidden",
+            "code": "authorization_denied",
+            "message": self.to_string(),
+            "tool": self.tool,
+            "role": self.role,
+            "required_role": self.required,
+            "identity": self.identity,
+            "reason": self.reason,
+        })
+    }
+}
+
+pub fn token_hash(token: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(token.trim().as_bytes());
+    format!("sha256:{:x}", hasher.finalize())
+}
+
+/// Project `[roles]` wins over global `[roles]`.
+pub fn resolve_roles_config(repo_root: &Path) -> Option<RolesConfig> {
+    load_config(repo_root)
+        .and_then(|config| config.roles)
+        .or_else(|| load_global_config().and_then(|config| config.roles))
+}
+
+pub fn resolve_grant(config: Option<&RolesConfig>, caller: &Caller) -> Grant {
+    let identity = caller
+        .identity
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string);
+    let Some(config) = config else {
+        return Grant {
+            role: Role::Maintainer,
+            source: "unconfigured",
+            identity,
+        };
+    };
+    if let Some(token) = caller.token.as_deref().filter(|t| !t.trim().is_empty()) {
+        let hashed = token_hash(token);
+        if let Some(role) = config
+            .tokens
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(&hashed))
+            .map(|(_, role)| *role)
+        {
+            return Grant {
+                role,
+                source: "token",
+                identity,
+            };
+        }
+    }
+    if let Some(name) = identity.as_deref() {
+        if let Some(role) = config
+            .identities
+            .iter()
+            .find(|(key, _)| key.trim().eq_ignore_ascii_case(name))
+            .map(|(_, role)| *role)
+        {
+            return Grant {
+                role,
+                source: "identity",
+                identity,
+            };
+        }
+    }
+    Grant {
+        role: config.default.unwrap_or(Role::Viewer),
+        source: "default",
+        identity,
+    }
+}
+
+pub fn authorize(
+    grant: &Grant,
+    tool: &str,
+    required: Role,
+    reason: &str,
+) -> Result<(), AuthorizationError> {
+    if grant.role >= required {
+        return Ok(());
+    }
+    Err(AuthorizationError {
+        tool: tool.to_string(),
+        role: grant.role,
+        required,
+        identity: grant.identity.clone(),
+        reason: reason.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RolesConfig {
+        let mut config = RolesConfig::default();
+        config
+            .identities
+            .insert("alice".to_string(), Role::Maintainer);
+        config
+            .tokens
+            .insert(token_hash("bot-secret"), Role::Contributor);
+        config
+    }
+
+    #[test]
+    fn unconfigured_callers_are_maintainers() {
+        let grant = resolve_grant(None, &Caller::default());
+        assert_eq!(grant.role, Role::Maintainer);
+        assert_eq!(grant.source, "unconfigured");
+    }
+
+    #[test]
+    fn token_then_identity_then_default() {
+        let config = config();
+        let by_token = resolve_grant(
+            Some(&config),
+            &Caller {
+                identity: Some("alice".to_string()),
+                token: Some("bot-secret".to_string()),
+            },
+        );
+        assert_eq!(
+            (by_token.role, by_token.source),
+            (Role::Contributor, "token")
+        );
+
+        let by_identity = resolve_grant(
+            Some(&config),
+            &Caller {
+                identity: Some("Alice".to_string()),
+                token: Some("wrong".to_string()),
+            },
+        );
+        assert_eq!(
+            (by_identity.role, by_identity.source),
+            (Role::Maintainer, "identity")
+        );
+
+        let fallback = resolve_grant(Some(&config), &Caller::default());
+        assert_eq!((fallback.role, fallback.source), (Role::Viewer, "default"));
+    }
+
+    #[test]
+    fn authorize_reports_structured_error() {
+        let grant = resolve_grant(Some(&config()), &Caller::default());
+        assert!(authorize(&grant, "list_tasks", Role::Viewer, "read").is_ok());
+        let err = authorize(&grant, "rekey_apply", Role::Maintainer, "admin tool").unwrap_err();
+        let json = err.to_json();
+        assert_eq!(json["code"], "authorization_denied");
+        assert_eq!(json["role"], "viewer");
+        assert_eq!(json["required_role"], "maintainer");
+    }
+
+    #[test]
+    fn roles_config_parses_from_toml() {
+        let text = r#"
+default = "contributor"
+protected_fields = ["id", "priority"]
+
+[identities]
+alice = "maintainer"
+"#;
+        let config: RolesConfig = toml::from_str(text).expect("parse");
+        assert_eq!(config.default, Some(Role::Contributor));
+        assert_eq!(config.identities.get("alice"), Some(&Role::Maintainer));
+        assert_eq!(config.protected_fields(), vec!["id", "priority"]);
+    }
+}
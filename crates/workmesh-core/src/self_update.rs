@@ -0,0 +1,526 @@
+//! `workmesh self-update`: find the newest release on GitHub, download the binary built for this
+//! platform, verify it, and swap it in place of the running executable.
+//!
+//! Releases publish a bare binary per target (`workmesh-<target>[.exe]`) next to a
+//! `<asset>.sha256` checksum and, when the release job has a signing key, an Ed25519
+//! `<asset>.sig`. The checksum is always required. With `[self_update] public_key` set, a valid
+//! signature is required too.
+//!
+//! Only the global config is read: a cloned repository must not be able to point updates at a
+//! different release source or key.
+
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use ring::signature::{UnparsedPublicKey, ED25519};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::load_global_config;
+
+const DEFAULT_REPO: &str = "luislobo/workmesh";
+const DEFAULT_API_URL: &str = "https://api.github.com";
+const DEFAULT_TIMEOUT_MS: u64 = 60_000;
+/// Upper bound for a downloaded binary; release builds are far below it.
+const MAX_DOWNLOAD_BYTES: u64 = 256 * 1024 * 1024;
+
+#[derive(Debug, Error)]
+pub enum SelfUpdateError {
+    #[error("Self-update misconfigured: {0}")]
+    Config(String),
+    #[error("Release request to {url} failed: {message}")]
+    Request { url: String, message: String },
+    #[error("Release {tag} has no {asset} asset; install this platform manually")]
+    MissingAsset { tag: String, asset: String },
+    #[error("Verification of {asset} failed: {reason}")]
+    Verification { asset: String, reason: String },
+    #[error("Failed to replace executable: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// `[self_update]` table in `~/.workmesh/config.toml`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SelfUpdateConfig {
+    /// `owner/name` publishing releases (default `luislobo/workmesh`).
+    pub repo: Option<String>,
+    /// REST API base (default `https://api.github.com`).
+    pub api_url: Option<String>,
+    /// `stable` (default) or `nightly`.
+    pub channel: Option<String>,
+    /// Base64 Ed25519 public key; when set, releases must carry a valid `.sig`.
+    pub public_key: Option<String>,
+    pub timeout_ms: Option<u64>,
+}
+
+/// The global `[self_update]` table; project config is ignored on purpose.
+pub fn resolve_self_update_config() -> SelfUpdateConfig {
+    load_global_config()
+        .and_then(|config| config.self_update)
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UpdateChannel {
+    /// Latest published release.
+    Stable,
+    /// Newest release, pre-releases included.
+    Nightly,
+}
+
+impl UpdateChannel {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "stable" => Some(Self::Stable),
+            "nightly" => Some(Self::Nightly),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Nightly => "nightly",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    pub tag: String,
+    pub prerelease: bool,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+impl Release {
+    pub fn from_json(value: &Value) -> Option<Self> {
+        let assets = value
+            .get("assets")
+            .and_then(Value::as_array)
+            .map(|assets| {
+                assets
+                    .iter()
+                    .filter_map(|asset| {
+                        Some(ReleaseAsset {
+                            name: asset.get("name")?.as_str()?.to_string(),
+                            url: asset.get("browser_download_url")?.as_str()?.to_string(),
+                        })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            tag: value.get("tag_name")?.as_str()?.to_string(),
+            prerelease: value
+                .get("prerelease")
+                .and_then(Value::as_bool)
+                .unwrap_or(false),
+            assets,
+        })
+    }
+
+    pub fn asset(&self, name: &str) -> Option<&ReleaseAsset> {
+        self.assets.iter().find(|asset| asset.name == name)
+    }
+}
+
+/// The release lookups and downloads self-update needs, so tests can swap in a fake.
+pub trait ReleaseSource {
+    fn latest_release(&self, channel: UpdateChannel) -> Result<Release, SelfUpdateError>;
+    fn download(&self, url: &str) -> Result<Vec<u8>, SelfUpdateError>;
+}
+
+pub struct HttpReleaseSource {
+    pub api_url: String,
+    pub repo: String,
+    agent: ureq::Agent,
+}
+
+impl HttpReleaseSource {
+    pub fn new(config: &SelfUpdateConfig) -> Self {
+        let timeout = Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+        Self {
+            api_url: config
+                .api_url
+                .clone()
+                .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            repo: config
+                .repo
+                .clone()
+                .filter(|repo| !repo.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_REPO.to_string()),
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+        }
+    }
+
+    fn get_json(&self, path: &str) -> Result<Value, SelfUpdateError> {
+        let url = format!("{}/repos/{}{}", self.api_url, self.repo, path);
+        let request_error = |message: String| SelfUpdateError::Request {
+            url: url.clone(),
+            message,
+        };
+        let body = self
+            .agent
+            .get(&url)
+            .set("Accept", "application/vnd.github+json")
+            .set("X-GitHub-Api-Version", "2022-11-28")
+            .set("User-Agent", "workmesh")
+            .call()
+            .map_err(|err| request_error(err.to_string()))?
+            .into_string()
+            .map_err(|err| request_error(err.to_string()))?;
+        serde_json::from_str(&body).map_err(|err| request_error(err.to_string()))
+    }
+}
+
+impl ReleaseSource for HttpReleaseSource {
+    fn latest_release(&self, channel: UpdateChannel) -> Result<Release, SelfUpdateError> {
+        let not_release = |url: String| SelfUpdateError::Request {
+            url,
+            message: "response has no release".to_string(),
+        };
+        match channel {
+            UpdateChannel::Stable => {
+                let value = self.get_json("/releases/latest")?;
+                Release::from_json(&value)
+                    .ok_or_else(|| not_release(format!("{}/releases/latest", self.repo)))
+            }
+            UpdateChannel::Nightly => {
+                let value = self.get_json("/releases?per_page=20")?;
+                value
+                    .as_array()
+                    .into_iter()
+                    .flatten()
+                    .filter(|release| release.get("draft").and_then(Value::as_bool) != Some(true))
+                    .find_map(Release::from_json)
+                    .ok_or_else(|| not_release(format!("{}/releases", self.repo)))
+            }
+        }
+    }
+
+    fn download(&self, url: &str) -> Result<Vec<u8>, SelfUpdateError> {
+        let request_error = |message: String| SelfUpdateError::Request {
+            url: url.to_string(),
+            message,
+        };
+        let response = self
+            .agent
+            .get(url)
+            .set("User-Agent", "workmesh")
+            .call()
+            .map_err(|err| request_error(err.to_string()))?;
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_DOWNLOAD_BYTES)
+            .read_to_end(&mut bytes)
+            .map_err(|err| request_error(err.to_string()))?;
+        Ok(bytes)
+    }
+}
+
+/// Rust target triple of the release build matching this binary, e.g. `x86_64-apple-darwin`.
+pub fn current_target() -> Option<String> {
+    let suffix = match std::env::consts::OS {
+        "linux" => "unknown-linux-gnu",
+        "macos" => "apple-darwin",
+        "windows" => "pc-windows-msvc",
+        _ => return None,
+    };
+    Some(format!("{}-{}", std::env::consts::ARCH, suffix))
+}
+
+/// Release asset holding the bare binary for `target`.
+pub fn binary_asset_name(target: &str) -> String {
+    let ext = if target.contains("windows") {
+        ".exe"
+    } else {
+        ""
+    };
+    format!("workmesh-{}{}", target, ext)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateCheck {
+    pub channel: UpdateChannel,
+    pub current: String,
+    pub latest: String,
+    pub tag: String,
+    pub update_available: bool,
+    /// Binary asset for this platform, when the release has one.
+    pub asset: Option<String>,
+}
+
+/// Compare `current_version` with the newest release on `channel`.
+pub fn check_for_update(
+    source: &dyn ReleaseSource,
+    channel: UpdateChannel,
+    current_version: &str,
+    target: &str,
+) -> Result<(UpdateCheck, Release), SelfUpdateError> {
+    let release = source.latest_release(channel)?;
+    let latest = release.tag.trim_start_matches('v').to_string();
+    let asset_name = binary_asset_name(target);
+    let check = UpdateCheck {
+        channel,
+        current: current_version.to_string(),
+        update_available: is_newer(&latest, current_version),
+        latest,
+        tag: release.tag.clone(),
+        asset: release.asset(&asset_name).map(|asset| asset.name.clone()),
+    };
+    Ok((check, release))
+}
+
+/// Whether `candidate` is a later version than `current` (`1.2.3` or `1.2.3-pre`; a pre-release
+/// sorts before its release).
+pub fn is_newer(candidate: &str, current: &str) -> bool {
+    fn parse(version: &str) -> (Vec<u64>, Option<String>) {
+        let version = version.trim().trim_start_matches('v');
+        let version = version.split('+').next().unwrap_or(version);
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(pre.to_string())),
+            None => (version, None),
+        };
+        let numbers = core
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect();
+        (numbers, pre)
+    }
+    let (candidate_core, candidate_pre) = parse(candidate);
+    let (current_core, current_pre) = parse(current);
+    match candidate_core.cmp(&current_core) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Less => false,
+        std::cmp::Ordering::Equal => match (candidate_pre, current_pre) {
+            (None, Some(_)) => true,
+            (Some(candidate), Some(current)) => candidate > current,
+            _ => false,
+        },
+    }
+}
+
+/// Download the binary for `target` from `release` and check it against the published SHA-256
+/// and, when `public_key` is given, the Ed25519 signature.
+pub fn download_verified(
+    source: &dyn ReleaseSource,
+    release: &Release,
+    target: &str,
+    public_key: Option<&str>,
+) -> Result<Vec<u8>, SelfUpdateError> {
+    let asset_name = binary_asset_name(target);
+    let fetch = |name: &str| -> Result<Vec<u8>, SelfUpdateError> {
+        let asset = release
+            .asset(name)
+            .ok_or_else(|| SelfUpdateError::MissingAsset {
+                tag: release.tag.clone(),
+                asset: name.to_string(),
+            })?;
+        source.download(&asset.url)
+    };
+    let failed = |reason: String| SelfUpdateError::Verification {
+        asset: asset_name.clone(),
+        reason,
+    };
+
+    let binary = fetch(&asset_name)?;
+    let checksum = fetch(&format!("{}.sha256", asset_name))?;
+    let expected = String::from_utf8_lossy(&checksum)
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_lowercase();
+    let actual = format!("{:x}", Sha256::digest(&binary));
+    if expected != actual {
+        return Err(failed(format!(
+            "sha256 is {} but the release lists {}",
+            actual, expected
+        )));
+    }
+
+    if let Some(key) = public_key.filter(|key| !key.trim().is_empty()) {
+        let key = STANDARD
+            .decode(key.trim())
+            .map_err(|err| SelfUpdateError::Config(format!("public_key: {}", err)))?;
+        let signature = fetch(&format!("{}.sig", asset_name))?;
+        let signature = STANDARD
+            .decode(String::from_utf8_lossy(&signature).trim())
+            .map_err(|err| failed(format!("unreadable signature: {}", err)))?;
+        UnparsedPublicKey::new(&ED25519, &key)
+            .verify(&binary, &signature)
+            .map_err(|_| failed("signature does not match public_key".to_string()))?;
+    }
+    Ok(binary)
+}
+
+/// Put `binary` in place of `exe`. The new file is written next to it first and then renamed
+/// over it, so the executable is never half-written. Windows cannot overwrite a running
+/// executable, so there the old one is moved aside to `<name>.old` first.
+pub fn replace_executable(exe: &Path, binary: &[u8]) -> Result<(), SelfUpdateError> {
+    let dir = exe.parent().unwrap_or_else(|| Path::new("."));
+    let file_name = exe
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "workmesh".to_string());
+    let staged = dir.join(format!(".{}.update", file_name));
+    fs::write(&staged, binary)?;
+    if let Ok(metadata) = fs::metadata(exe) {
+        fs::set_permissions(&staged, metadata.permissions())?;
+    }
+    if cfg!(windows) {
+        let old: PathBuf = dir.join(format!("{}.old", file_name));
+        let _ = fs::remove_file(&old);
+        fs::rename(exe, &old)?;
+    }
+    if let Err(err) = fs::rename(&staged, exe) {
+        let _ = fs::remove_file(&staged);
+        return Err(err.into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use std::collections::HashMap;
+
+    struct FakeSource {
+        release: Release,
+        files: HashMap<String, Vec<u8>>,
+    }
+
+    impl ReleaseSource for FakeSource {
+        fn latest_release(&self, _channel: UpdateChannel) -> Result<Release, SelfUpdateError> {
+            Ok(self.release.clone())
+        }
+
+        fn download(&self, url: &str) -> Result<Vec<u8>, SelfUpdateError> {
+            self.files
+                .get(url)
+                .cloned()
+                .ok_or_else(|| SelfUpdateError::Request {
+                    url: url.to_string(),
+                    message: "404".to_string(),
+                })
+        }
+    }
+
+    fn fake(files: Vec<(&str, Vec<u8>)>) -> FakeSource {
+        FakeSource {
+            release: Release {
+                tag: "v0.4.0".to_string(),
+                prerelease: false,
+                assets: files
+                    .iter()
+                    .map(|(name, _)| ReleaseAsset {
+                        name: name.to_string(),
+                        url: format!("https://example.test/{}", name),
+                    })
+                    .collect(),
+            },
+            files: files
+                .into_iter()
+                .map(|(name, bytes)| (format!("https://example.test/{}", name), bytes))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn is_newer_orders_releases_and_prereleases() {
+        assert!(is_newer("0.3.10", "0.3.9"));
+        assert!(is_newer("v1.0.0", "0.9.9"));
+        assert!(!is_newer("0.3.9", "0.3.9"));
+        assert!(!is_newer("0.3.8", "0.3.9"));
+        assert!(is_newer("0.4.0", "0.4.0-nightly.1"));
+        assert!(!is_newer("0.4.0-nightly.1", "0.4.0"));
+        assert!(is_newer("0.4.0-nightly.2", "0.4.0-nightly.1"));
+    }
+
+    #[test]
+    fn download_verified_checks_checksum_and_signature() {
+        let target = "x86_64-unknown-linux-gnu";
+        let binary = b"new workmesh".to_vec();
+        let sum = format!("{:x}  workmesh-{}\n", Sha256::digest(&binary), target);
+        let rng = SystemRandom::new();
+        let pkcs8 = Ed25519KeyPair::generate_pkcs8(&rng).expect("key");
+        let pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).expect("pair");
+        let public_key = STANDARD.encode(pair.public_key().as_ref());
+        let signature = STANDARD.encode(pair.sign(&binary).as_ref());
+
+        let source = fake(vec![
+            ("workmesh-x86_64-unknown-linux-gnu", binary.clone()),
+            (
+                "workmesh-x86_64-unknown-linux-gnu.sha256",
+                sum.clone().into_bytes(),
+            ),
+            (
+                "workmesh-x86_64-unknown-linux-gnu.sig",
+                signature.into_bytes(),
+            ),
+        ]);
+        let (check, release) =
+            check_for_update(&source, UpdateChannel::Stable, "0.3.9", target).expect("check");
+        assert!(check.update_available);
+        assert_eq!(check.latest, "0.4.0");
+        let verified =
+            download_verified(&source, &release, target, Some(&public_key)).expect("verified");
+        assert_eq!(verified, binary);
+
+        let other =
+            Ed25519KeyPair::from_pkcs8(Ed25519KeyPair::generate_pkcs8(&rng).expect("key").as_ref())
+                .expect("pair");
+        let wrong_key = STANDARD.encode(other.public_key().as_ref());
+        let err =
+            download_verified(&source, &release, target, Some(&wrong_key)).expect_err("wrong key");
+        assert!(matches!(err, SelfUpdateError::Verification { .. }));
+
+        let tampered = fake(vec![
+            ("workmesh-x86_64-unknown-linux-gnu", b"evil".to_vec()),
+            ("workmesh-x86_64-unknown-linux-gnu.sha256", sum.into_bytes()),
+        ]);
+        let err = download_verified(&tampered, &tampered.release, target, None)
+            .expect_err("bad checksum");
+        assert!(matches!(err, SelfUpdateError::Verification { .. }));
+        let err = download_verified(&tampered, &tampered.release, "aarch64-apple-darwin", None)
+            .expect_err("missing asset");
+        assert!(matches!(err, SelfUpdateError::MissingAsset { .. }));
+    }
+
+    #[test]
+    fn replace_executable_swaps_file_and_keeps_permissions() {
+        let temp = tempfile::TempDir::new().expect("tempdir");
+        let exe = temp.path().join("workmesh");
+        fs::write(&exe, b"old").expect("write");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&exe, fs::Permissions::from_mode(0o755)).expect("chmod");
+        }
+        replace_executable(&exe, b"new").expect("replace");
+        assert_eq!(fs::read(&exe).expect("read"), b"new");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&exe).expect("metadata").permissions().mode();
+            assert_eq!(mode & 0o777, 0o755);
+        }
+        assert!(!temp.path().join(".workmesh.update").exists());
+    }
+}
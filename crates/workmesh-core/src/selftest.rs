@@ -0,0 +1,364 @@
+//! Golden-output regression check for the read pipeline (load, filter, board, blockers, gantt).
+//!
+//! A fixture is a directory holding `tasks/*.md` and `expected/<step>.json`. `selftest` runs
+//! each step over the fixture tasks and compares the JSON with the stored golden, so a build or
+//! platform can be checked before it is trusted with a real backlog. One fixture is compiled
+//! into the binary; `--against <dir>` runs fixtures from disk.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::gantt::plantuml_gantt;
+use crate::task::{load_tasks, parse_task_text, Task, TaskParseError};
+use crate::task_ops::filter_tasks;
+use crate::views::{blockers_report, board_lanes, BoardBy};
+
+/// Pipeline steps, in run order; each has an `expected/<step>.json` golden.
+pub const SELFTEST_STEPS: &[&str] = &["load", "filter", "board", "blockers", "gantt"];
+
+/// Fixed so gantt output does not depend on today's date.
+const GANTT_START: &str = "2026-01-05";
+
+const BUNDLED_NAME: &str = "basic";
+
+macro_rules! bundled {
+    ($($path:literal),* $(,)?) => {
+        &[$(($path, include_str!(concat!("../fixtures/selftest/basic/", $path)))),*]
+    };
+}
+
+const BUNDLED_FILES: &[(&str, &str)] = bundled!(
+    "tasks/task-001 - payments epic.md",
+    "tasks/task-002 - add card tokenization.md",
+    "tasks/task-003 - add refund endpoint.md",
+    "tasks/task-004 - design checkout page.md",
+    "tasks/task-005 - write payment docs.md",
+    "tasks/task-006 - wire checkout to refunds.md",
+    "expected/load.json",
+    "expected/filter.json",
+    "expected/board.json",
+    "expected/blockers.json",
+    "expected/gantt.json",
+);
+
+#[derive(Debug, Error)]
+pub enum SelftestError {
+    #[error("No fixtures found under {0} (expected <fixture>/tasks/)")]
+    NoFixtures(PathBuf),
+    #[error("Bundled fixtures cannot be updated; pass --against <dir>")]
+    UpdateBundled,
+    #[error("Invalid bundled fixture {path}: {source}")]
+    Bundled {
+        path: String,
+        source: TaskParseError,
+    },
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone)]
+pub struct Fixture {
+    pub name: String,
+    /// `None` for the fixture compiled into the binary.
+    pub dir: Option<PathBuf>,
+    pub tasks: Vec<Task>,
+    /// Step -> golden output; missing goldens are reported, not errors.
+    pub expected: BTreeMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StepOutcome {
+    Pass,
+    Fail,
+    Missing,
+    Updated,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StepResult {
+    pub fixture: String,
+    pub step: String,
+    pub outcome: StepOutcome,
+    /// First JSON path where actual and expected differ, e.g. `$.lanes[1].tasks[0]`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SelftestReport {
+    pub ok: bool,
+    pub version: String,
+    pub platform: String,
+    pub fixtures: usize,
+    pub results: Vec<StepResult>,
+}
+
+/// The fixture compiled into the binary.
+pub fn bundled_fixtures() -> Result<Vec<Fixture>, SelftestError> {
+    let mut tasks = Vec::new();
+    let mut expected = BTreeMap::new();
+    for (path, text) in BUNDLED_FILES {
+        if let Some(step) = path
+            .strip_prefix("expected/")
+            .and_then(|name| name.strip_suffix(".json"))
+        {
+            expected.insert(step.to_string(), serde_json::from_str(text)?);
+        } else {
+            let task = parse_task_text(text, Path::new(path)).map_err(|source| {
+                SelftestError::Bundled {
+                    path: path.to_string(),
+                    source,
+                }
+            })?;
+            tasks.push(task);
+        }
+    }
+    Ok(vec![Fixture {
+        name: BUNDLED_NAME.to_string(),
+        dir: None,
+        tasks,
+        expected,
+    }])
+}
+
+/// `dir` itself when it has `tasks/`, else each subdirectory that does, sorted by name.
+pub fn load_fixtures(dir: &Path) -> Result<Vec<Fixture>, SelftestError> {
+    let mut dirs = Vec::new();
+    if dir.join("tasks").is_dir() {
+        dirs.push(dir.to_path_buf());
+    } else if dir.is_dir() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.join("tasks").is_dir() {
+                dirs.push(path);
+            }
+        }
+        dirs.sort();
+    }
+    if dirs.is_empty() {
+        return Err(SelftestError::NoFixtures(dir.to_path_buf()));
+    }
+    dirs.into_iter()
+        .map(|fixture_dir| {
+            let mut expected = BTreeMap::new();
+            for step in SELFTEST_STEPS {
+                let path = fixture_dir.join("expected").join(format!("{}.json", step));
+                if path.is_file() {
+                    expected.insert(
+                        step.to_string(),
+                        serde_json::from_str(&fs::read_to_string(&path)?)?,
+                    );
+                }
+            }
+            Ok(Fixture {
+                name: fixture_dir
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string())
+                    .unwrap_or_else(|| fixture_dir.display().to_string()),
+                tasks: load_tasks(&fixture_dir),
+                dir: Some(fixture_dir),
+                expected,
+            })
+        })
+        .collect()
+}
+
+fn sorted_ids(tasks: Vec<&Task>) -> Vec<String> {
+    let mut ids: Vec<String> = tasks.into_iter().map(|task| task.id.clone()).collect();
+    ids.sort();
+    ids
+}
+
+/// Output of one pipeline step. Paths and dates are left out so goldens are portable.
+pub fn run_step(step: &str, tasks: &[Task]) -> Option<Value> {
+    let mut ordered: Vec<Task> = tasks.to_vec();
+    ordered.sort_by(|a, b| a.id_num().cmp(&b.id_num()).then_with(|| a.id.cmp(&b.id)));
+    let tasks = ordered.as_slice();
+    let filter = |status: Option<&str>, deps_ready: Option<bool>, blocked: Option<bool>| {
+        let status = status.map(|value| vec![value.to_string()]);
+        sorted_ids(filter_tasks(
+            tasks,
+            status.as_deref(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            deps_ready,
+            blocked,
+            None,
+        ))
+    };
+    let value = match step {
+        "load" => serde_json::json!(tasks
+            .iter()
+            .map(|task| serde_json::json!({
+                "id": task.id,
+                "title": task.title,
+                "kind": task.kind,
+                "status": task.status,
+                "priority": task.priority,
+                "phase": task.phase,
+                "dependencies": task.dependencies,
+                "labels": task.labels,
+                "assignee": task.assignee,
+                "parent": task.relationships.parent,
+                "blocked_by": task.relationships.blocked_by,
+            }))
+            .collect::<Vec<_>>()),
+        "filter" => serde_json::json!({
+            "to_do": filter(Some("To Do"), None, None),
+            "in_progress": filter(Some("In Progress"), None, None),
+            "deps_ready": filter(Some("To Do"), Some(true), None),
+            "blocked": filter(None, None, Some(true)),
+        }),
+        "board" => serde_json::json!([BoardBy::Status, BoardBy::Epic]
+            .into_iter()
+            .map(|by| {
+                let lanes: Vec<Value> = board_lanes(tasks, by, None)
+                    .into_iter()
+                    .map(|(lane, lane_tasks)| {
+                        let ids: Vec<&str> = lane_tasks.iter().map(|t| t.id.as_str()).collect();
+                        serde_json::json!({ "lane": lane, "tasks": ids })
+                    })
+                    .collect();
+                serde_json::json!({ "by": format!("{:?}", by).to_lowercase(), "lanes": lanes })
+            })
+            .collect::<Vec<_>>()),
+        "blockers" => serde_json::to_value(blockers_report(tasks, None, None)).ok()?,
+        "gantt" => serde_json::json!(
+            plantuml_gantt(tasks, Some(GANTT_START), None, 3, None, true)
+                .lines()
+                .collect::<Vec<_>>()
+        ),
+        _ => return None,
+    };
+    Some(value)
+}
+
+fn first_difference(path: &str, actual: &Value, expected: &Value) -> Option<String> {
+    match (actual, expected) {
+        (Value::Object(a), Value::Object(e)) => {
+            let mut keys: Vec<&String> = a.keys().chain(e.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            keys.into_iter().find_map(|key| {
+                let child = format!("{}.{}", path, key);
+                match (a.get(key), e.get(key)) {
+                    (Some(a), Some(e)) => first_difference(&child, a, e),
+                    _ => Some(child),
+                }
+            })
+        }
+        (Value::Array(a), Value::Array(e)) => a
+            .iter()
+            .zip(e)
+            .enumerate()
+            .find_map(|(idx, (a, e))| first_difference(&format!("{}[{}]", path, idx), a, e))
+            .or_else(|| {
+                (a.len() != e.len())
+                    .then(|| format!("{} (length {} != {})", path, a.len(), e.len()))
+            }),
+        _ if actual == expected => None,
+        _ => Some(format!("{}: got {} want {}", path, actual, expected)),
+    }
+}
+
+/// Run every step over every fixture. With `update`, write actual outputs as the new goldens
+/// (on-disk fixtures only).
+pub fn run_selftest(fixtures: &[Fixture], update: bool) -> Result<SelftestReport, SelftestError> {
+    if update && fixtures.iter().any(|fixture| fixture.dir.is_none()) {
+        return Err(SelftestError::UpdateBundled);
+    }
+    let mut results = Vec::new();
+    for fixture in fixtures {
+        for step in SELFTEST_STEPS {
+            let actual = run_step(step, &fixture.tasks).unwrap_or(Value::Null);
+            let (outcome, diff) = if let (true, Some(dir)) = (update, fixture.dir.as_ref()) {
+                let expected_dir = dir.join("expected");
+                fs::create_dir_all(&expected_dir)?;
+                let mut text = serde_json::to_string_pretty(&actual)?;
+                text.push('\n');
+                fs::write(expected_dir.join(format!("{}.json", step)), text)?;
+                (StepOutcome::Updated, None)
+            } else {
+                match fixture.expected.get(*step) {
+                    None => (StepOutcome::Missing, None),
+                    Some(expected) => match first_difference("$", &actual, expected) {
+                        None => (StepOutcome::Pass, None),
+                        Some(diff) => (StepOutcome::Fail, Some(diff)),
+                    },
+                }
+            };
+            results.push(StepResult {
+                fixture: fixture.name.clone(),
+                step: step.to_string(),
+                outcome,
+                diff,
+            });
+        }
+    }
+    Ok(SelftestReport {
+        ok: results
+            .iter()
+            .all(|result| matches!(result.outcome, StepOutcome::Pass | StepOutcome::Updated)),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        platform: format!("{}-{}", std::env::consts::OS, std::env::consts::ARCH),
+        fixtures: fixtures.len(),
+        results,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn bundled_fixture_matches_goldens_and_reports_drift() {
+        let fixtures = bundled_fixtures().expect("bundled");
+        let report = run_selftest(&fixtures, false).expect("selftest");
+        assert!(report.ok, "{:?}", report.results);
+        assert_eq!(report.results.len(), SELFTEST_STEPS.len());
+        assert!(matches!(
+            run_selftest(&fixtures, true),
+            Err(SelftestError::UpdateBundled)
+        ));
+
+        let temp = TempDir::new().expect("tempdir");
+        let dir = temp.path().join("basic");
+        fs::create_dir_all(dir.join("tasks")).expect("tasks");
+        for (path, text) in BUNDLED_FILES
+            .iter()
+            .filter(|(p, _)| p.starts_with("tasks/"))
+        {
+            fs::write(dir.join(path), text).expect("write task");
+        }
+        let fixtures = load_fixtures(temp.path()).expect("fixtures");
+        let report = run_selftest(&fixtures, false).expect("selftest");
+        assert!(report
+            .results
+            .iter()
+            .all(|result| result.outcome == StepOutcome::Missing));
+        run_selftest(&fixtures, true).expect("update");
+
+        let mut fixtures = load_fixtures(&dir).expect("fixtures");
+        assert!(run_selftest(&fixtures, false).expect("selftest").ok);
+        fixtures[0].tasks[1].status = "Done".to_string();
+        let report = run_selftest(&fixtures, false).expect("selftest");
+        let load = &report.results[0];
+        assert_eq!(load.outcome, StepOutcome::Fail);
+        assert_eq!(
+            load.diff.as_deref(),
+            Some("$[1].status: got \"Done\" want \"To Do\"")
+        );
+    }
+}
@@ -0,0 +1,473 @@
+//! Embedding-based task search (`semsearch` feature).
+//!
+//! Task titles and bodies are embedded by a pluggable [`EmbeddingProvider`] and cached per
+//! provider under `workmesh/.index/embeddings/`, keyed by a content hash so only changed tasks are
+//! re-embedded.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config, SemsearchConfig};
+use crate::index::index_dir;
+use crate::storage::{atomic_write_text, StorageError};
+use crate::task::Task;
+
+/// Dimensions of the built-in hashing provider.
+const HASH_DIMENSIONS: usize = 256;
+const DEFAULT_API_KEY_ENV: &str = "WORKMESH_EMBEDDINGS_API_KEY";
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+/// Texts per request for the HTTP provider.
+const HTTP_BATCH_SIZE: usize = 64;
+
+#[derive(Debug, Error)]
+pub enum SemsearchError {
+    #[error("Embedding cache IO failed: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Embedding cache write failed: {0}")]
+    Storage(#[from] StorageError),
+    #[error("Failed to parse embedding cache: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("Unknown embedding provider: {0} (expected hash or http)")]
+    UnknownProvider(String),
+    #[error("Embedding provider misconfigured: {0}")]
+    Config(String),
+    #[error("Embedding request to {url} failed: {message}")]
+    Request { url: String, message: String },
+}
+
+/// Turns texts into vectors. Implementations must return one vector per input, in order.
+pub trait EmbeddingProvider {
+    /// Stable identifier used for the cache file; changing it invalidates cached vectors.
+    fn id(&self) -> String;
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SemsearchError>;
+}
+
+/// Local, dependency-free provider: signed feature hashing of words and character trigrams.
+/// Catches shared vocabulary and word-form variants ("flaky"/"flakiness") without a model.
+#[derive(Debug, Clone)]
+pub struct HashingProvider {
+    pub dimensions: usize,
+}
+
+impl Default for HashingProvider {
+    fn default() -> Self {
+        Self {
+            dimensions: HASH_DIMENSIONS,
+        }
+    }
+}
+
+impl EmbeddingProvider for HashingProvider {
+    fn id(&self) -> String {
+        format!("hash-{}", self.dimensions)
+    }
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SemsearchError> {
+        Ok(texts.iter().map(|text| self.embed_one(text)).collect())
+    }
+}
+
+impl HashingProvider {
+    fn embed_one(&self, text: &str) -> Vec<f32> {
+        let mut vector = vec![0.0f32; self.dimensions];
+        let lower = text.to_lowercase();
+        for word in lower
+            .split(|ch: char| !ch.is_alphanumeric())
+            .filter(|word| word.chars().count() > 1)
+        {
+            self.add_feature(&mut vector, word, 1.0);
+            let padded: Vec<char> = format!("#{}#", word).chars().collect();
+            for gram in padded.windows(3) {
+                self.add_feature(&mut vector, &gram.iter().collect::<String>(), 0.5);
+            }
+        }
+        normalize(&mut vector);
+        vector
+    }
+
+    fn add_feature(&self, vector: &mut [f32], feature: &str, weight: f32) {
+        let digest = Sha256::digest(feature.as_bytes());
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        let value = u64::from_le_bytes(bytes);
+        let slot = (value % self.dimensions as u64) as usize;
+        let sign = if value >> 63 == 0 { 1.0 } else { -1.0 };
+        vector[slot] += sign * weight;
+    }
+}
+
+/// OpenAI-compatible `POST {endpoint}` with `{"model", "input": [...]}`.
+#[derive(Debug, Clone)]
+pub struct HttpProvider {
+    pub endpoint: String,
+    pub model: String,
+    pub api_key: Option<String>,
+    pub timeout: Duration,
+}
+
+impl EmbeddingProvider for HttpProvider {
+    fn id(&self) -> String {
+        format!("http-{}", self.model)
+    }
+
+    fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SemsearchError> {
+        let agent = ureq::AgentBuilder::new().timeout(self.timeout).build();
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(HTTP_BATCH_SIZE) {
+            let mut request = agent
+                .post(&self.endpoint)
+                .set("Content-Type", "application/json");
+            if let Some(key) = self.api_key.as_deref() {
+                request = request.set("Authorization", &format!("Bearer {}", key));
+            }
+            let payload = serde_json::json!({ "model": self.model, "input": batch });
+            let request_error = |message: String| SemsearchError::Request {
+                url: self.endpoint.clone(),
+                message,
+            };
+            let body = request
+                .send_string(&payload.to_string())
+                .map_err(|err| request_error(err.to_string()))?
+                .into_string()
+                .map_err(|err| request_error(err.to_string()))?;
+            let response: Value =
+                serde_json::from_str(&body).map_err(|err| request_error(err.to_string()))?;
+            let mut data: Vec<(usize, Vec<f32>)> = response["data"]
+                .as_array()
+                .ok_or_else(|| request_error("response has no data array".to_string()))?
+                .iter()
+                .enumerate()
+                .map(|(position, item)| {
+                    let index = item["index"]
+                        .as_u64()
+                        .map(|i| i as usize)
+                        .unwrap_or(position);
+                    let vector = item["embedding"]
+                        .as_array()
+                        .map(|values| {
+                            values
+                                .iter()
+                                .filter_map(|value| value.as_f64())
+                                .map(|value| value as f32)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (index, vector)
+                })
+                .collect();
+            if data.len() != batch.len() {
+                return Err(request_error(format!(
+                    "expected {} embeddings, got {}",
+                    batch.len(),
+                    data.len()
+                )));
+            }
+            data.sort_by_key(|(index, _)| *index);
+            vectors.extend(data.into_iter().map(|(_, vector)| vector));
+        }
+        Ok(vectors)
+    }
+}
+
+/// Project `[semsearch]` wins over global `[semsearch]`.
+pub fn resolve_semsearch_config(repo_root: &Path) -> SemsearchConfig {
+    load_config(repo_root)
+        .and_then(|config| config.semsearch)
+        .or_else(|| load_global_config().and_then(|config| config.semsearch))
+        .unwrap_or_default()
+}
+
+pub fn provider_from_config(
+    config: &SemsearchConfig,
+) -> Result<Box<dyn EmbeddingProvider>, SemsearchError> {
+    let provider = config
+        .provider
+        .as_deref()
+        .map(|value| value.trim().to_lowercase())
+        .unwrap_or_else(|| "hash".to_string());
+    match provider.as_str() {
+        "hash" => Ok(Box::new(HashingProvider::default())),
+        "http" => {
+            let endpoint = config
+                .endpoint
+                .clone()
+                .ok_or_else(|| SemsearchError::Config("[semsearch] endpoint is required".into()))?;
+            let model = config
+                .model
+                .clone()
+                .ok_or_else(|| SemsearchError::Config("[semsearch] model is required".into()))?;
+            let key_env = config.api_key_env.as_deref().unwrap_or(DEFAULT_API_KEY_ENV);
+            Ok(Box::new(HttpProvider {
+                endpoint,
+                model,
+                api_key: std::env::var(key_env).ok().filter(|key| !key.is_empty()),
+                timeout: Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS)),
+            }))
+        }
+        other => Err(SemsearchError::UnknownProvider(other.to_string())),
+    }
+}
+
+pub fn embeddings_dir(backlog_dir: &Path) -> PathBuf {
+    index_dir(backlog_dir).join("embeddings")
+}
+
+pub fn embeddings_path(backlog_dir: &Path, provider: &dyn EmbeddingProvider) -> PathBuf {
+    let name: String = provider
+        .id()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    embeddings_dir(backlog_dir).join(format!("{}.jsonl", name))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedEmbedding {
+    pub id: String,
+    /// SHA-256 of the embedded text.
+    pub hash: String,
+    pub vector: Vec<f32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SemsearchHit {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub score: f32,
+}
+
+/// Embed tasks whose text changed since the last run and drop tasks that no longer exist.
+pub fn refresh_embeddings(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    provider: &dyn EmbeddingProvider,
+) -> Result<Vec<CachedEmbedding>, SemsearchError> {
+    let path = embeddings_path(backlog_dir, provider);
+    let mut cached: HashMap<String, CachedEmbedding> = HashMap::new();
+    if path.exists() {
+        for line in fs::read_to_string(&path)?.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: CachedEmbedding = serde_json::from_str(line)?;
+            cached.insert(entry.id.to_lowercase(), entry);
+        }
+    }
+
+    let mut entries = Vec::with_capacity(tasks.len());
+    let mut pending: Vec<(String, String, String)> = Vec::new();
+    for task in tasks {
+        let text = task_text(task);
+        let hash = text_hash(&text);
+        match cached.get(&task.id.to_lowercase()) {
+            Some(entry) if entry.hash == hash => entries.push(entry.clone()),
+            _ => pending.push((task.id.clone(), hash, text)),
+        }
+    }
+    let changed = !pending.is_empty() || entries.len() != cached.len();
+    if !pending.is_empty() {
+        let texts: Vec<String> = pending.iter().map(|(_, _, text)| text.clone()).collect();
+        let vectors = provider.embed(&texts)?;
+        for ((id, hash, _), vector) in pending.into_iter().zip(vectors) {
+            entries.push(CachedEmbedding { id, hash, vector });
+        }
+    }
+    entries.sort_by(|a, b| a.id.cmp(&b.id));
+
+    if changed {
+        fs::create_dir_all(embeddings_dir(backlog_dir))?;
+        let mut payload = String::new();
+        for entry in &entries {
+            payload.push_str(&serde_json::to_string(entry)?);
+            payload.push('\n');
+        }
+        atomic_write_text(&path, &payload)?;
+    }
+    Ok(entries)
+}
+
+/// Tasks ranked by cosine similarity to `query`, best first.
+pub fn semantic_search(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    provider: &dyn EmbeddingProvider,
+    query: &str,
+    limit: usize,
+) -> Result<Vec<SemsearchHit>, SemsearchError> {
+    let entries = refresh_embeddings(backlog_dir, tasks, provider)?;
+    let query_vector = provider
+        .embed(&[query.to_string()])?
+        .into_iter()
+        .next()
+        .unwrap_or_default();
+    let by_id: HashMap<String, &Task> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task))
+        .collect();
+    let mut hits: Vec<SemsearchHit> = entries
+        .iter()
+        .filter_map(|entry| {
+            let task = by_id.get(&entry.id.to_lowercase())?;
+            Some(SemsearchHit {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                score: cosine(&query_vector, &entry.vector),
+            })
+        })
+        .filter(|hit| hit.score > 0.0)
+        .collect();
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+    hits.truncate(limit);
+    Ok(hits)
+}
+
+pub fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+fn task_text(task: &Task) -> String {
+    format!("{}\n{}", task.title, task.body)
+}
+
+fn text_hash(text: &str) -> String {
+    format!("{:x}", Sha256::digest(text.as_bytes()))
+}
+
+fn normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for value in vector.iter_mut() {
+            *value /= norm;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::Cell;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    struct CountingProvider {
+        inner: HashingProvider,
+        embedded: Cell<usize>,
+    }
+
+    impl EmbeddingProvider for CountingProvider {
+        fn id(&self) -> String {
+            "counting".to_string()
+        }
+
+        fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, SemsearchError> {
+            self.embedded.set(self.embedded.get() + texts.len());
+            self.inner.embed(texts)
+        }
+    }
+
+    fn write_task(dir: &Path, id: &str, title: &str, body: &str) {
+        let content = format!(
+            "---\nid: {id}\ntitle: {title}\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\n---\n\n{body}\n"
+        );
+        fs::write(dir.join(format!("{id} - task.md")), content).expect("write task");
+    }
+
+    #[test]
+    fn search_ranks_related_tasks_and_caches_vectors() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(
+            &tasks_dir,
+            "task-001",
+            "Login test is flaky",
+            "Retries on CI",
+        );
+        write_task(
+            &tasks_dir,
+            "task-002",
+            "Update billing copy",
+            "Pricing page text",
+        );
+        write_task(
+            &tasks_dir,
+            "task-003",
+            "Stabilize logins in tests",
+            "Flakiness in auth",
+        );
+        let tasks = load_tasks(&backlog);
+        let provider = CountingProvider {
+            inner: HashingProvider::default(),
+            embedded: Cell::new(0),
+        };
+
+        let hits =
+            semantic_search(&backlog, &tasks, &provider, "flaky login test", 10).expect("search");
+        assert_eq!(hits[0].id, "task-001");
+        let position = |id: &str| hits.iter().position(|hit| hit.id == id);
+        let related = position("task-003").expect("task-003");
+        assert!(related < position("task-002").unwrap_or(usize::MAX));
+        assert!(embeddings_path(&backlog, &provider).exists());
+        // Three tasks plus the query.
+        assert_eq!(provider.embedded.get(), 4);
+
+        semantic_search(&backlog, &tasks, &provider, "billing", 10).expect("search");
+        assert_eq!(provider.embedded.get(), 5);
+    }
+
+    #[test]
+    fn provider_from_config_validates_http_settings() {
+        let config = SemsearchConfig {
+            provider: Some("http".to_string()),
+            ..SemsearchConfig::default()
+        };
+        assert!(matches!(
+            provider_from_config(&config),
+            Err(SemsearchError::Config(_))
+        ));
+        let config = SemsearchConfig {
+            provider: Some("magic".to_string()),
+            ..SemsearchConfig::default()
+        };
+        assert!(matches!(
+            provider_from_config(&config),
+            Err(SemsearchError::UnknownProvider(_))
+        ));
+        let provider = provider_from_config(&SemsearchConfig::default()).expect("hash");
+        assert_eq!(provider.id(), "hash-256");
+    }
+}
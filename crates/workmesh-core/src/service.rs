@@ -0,0 +1,470 @@
+//! Run `index-watch` in the background as a per-user service.
+//!
+//! `daemon install-service` writes a systemd user unit (Linux), a launchd agent (macOS), or a
+//! logon scheduled task plus wrapper script (Windows) that runs
+//! `workmesh --root <repo> index-watch --json` and appends its output to a log in the platform's
+//! log location. Each repository gets its own service, named after the checkout directory and a
+//! hash of its path, so several checkouts can be watched side by side. `daemon status` and
+//! `doctor` report whether the service is installed and running, and its latest log line.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::resolve_user_home_dir;
+use crate::timestamps::format_timestamp;
+
+#[derive(Debug, Error)]
+pub enum ServiceError {
+    #[error("Home directory not found (set HOME)")]
+    NoHome,
+    #[error("Service IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("`{command}` failed: {message}")]
+    Command { command: String, message: String },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ServicePlatform {
+    /// systemd user unit.
+    Systemd,
+    /// launchd user agent.
+    Launchd,
+    /// Scheduled task started at logon.
+    Windows,
+}
+
+impl ServicePlatform {
+    pub fn current() -> Self {
+        if cfg!(windows) {
+            ServicePlatform::Windows
+        } else if cfg!(target_os = "macos") {
+            ServicePlatform::Launchd
+        } else {
+            ServicePlatform::Systemd
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ServicePlatform::Systemd => "systemd",
+            ServicePlatform::Launchd => "launchd",
+            ServicePlatform::Windows => "windows",
+        }
+    }
+}
+
+/// Where a repository's service lives and what it runs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ServiceSpec {
+    pub platform: ServicePlatform,
+    /// Unit name, launchd label, or scheduled task name.
+    pub name: String,
+    pub repo_root: PathBuf,
+    /// Unit file, plist, or wrapper script.
+    pub definition_path: PathBuf,
+    pub log_path: PathBuf,
+    pub program: PathBuf,
+    pub args: Vec<String>,
+}
+
+/// `workmesh-<dir>-<hash>`: readable, and unique per checkout path.
+pub fn service_name(repo_root: &Path) -> String {
+    let dir = repo_root
+        .file_name()
+        .map(|name| name.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    let slug: String = dir
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    let slug = slug.trim_matches('-');
+    let digest = Sha256::digest(repo_root.to_string_lossy().as_bytes());
+    let hash: String = digest[..4]
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    if slug.is_empty() {
+        format!("workmesh-{}", hash)
+    } else {
+        format!("workmesh-{}-{}", slug, hash)
+    }
+}
+
+fn env_dir(name: &str) -> Option<PathBuf> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+}
+
+fn local_app_data() -> Option<PathBuf> {
+    env_dir("LOCALAPPDATA")
+        .or_else(|| resolve_user_home_dir().map(|home| home.join("AppData").join("Local")))
+}
+
+/// `$XDG_STATE_HOME/workmesh/logs`, `~/Library/Logs/workmesh`, or
+/// `%LOCALAPPDATA%\workmesh\logs`.
+pub fn log_dir(platform: ServicePlatform) -> Option<PathBuf> {
+    match platform {
+        ServicePlatform::Systemd => env_dir("XDG_STATE_HOME")
+            .or_else(|| resolve_user_home_dir().map(|home| home.join(".local").join("state")))
+            .map(|dir| dir.join("workmesh").join("logs")),
+        ServicePlatform::Launchd => {
+            resolve_user_home_dir().map(|home| home.join("Library").join("Logs").join("workmesh"))
+        }
+        ServicePlatform::Windows => local_app_data().map(|dir| dir.join("workmesh").join("logs")),
+    }
+}
+
+fn definition_path(platform: ServicePlatform, name: &str) -> Option<PathBuf> {
+    match platform {
+        ServicePlatform::Systemd => env_dir("XDG_CONFIG_HOME")
+            .or_else(|| resolve_user_home_dir().map(|home| home.join(".config")))
+            .map(|dir| {
+                dir.join("systemd")
+                    .join("user")
+                    .join(format!("{}.service", name))
+            }),
+        ServicePlatform::Launchd => resolve_user_home_dir().map(|home| {
+            home.join("Library")
+                .join("LaunchAgents")
+                .join(format!("{}.plist", name))
+        }),
+        ServicePlatform::Windows => local_app_data().map(|dir| {
+            dir.join("workmesh")
+                .join("services")
+                .join(format!("{}.cmd", name))
+        }),
+    }
+}
+
+/// The service watching `repo_root` by running `program index-watch` every `interval_ms`.
+pub fn service_spec(
+    platform: ServicePlatform,
+    repo_root: &Path,
+    program: &Path,
+    interval_ms: u64,
+) -> Result<ServiceSpec, ServiceError> {
+    let name = service_name(repo_root);
+    let definition_path = definition_path(platform, &name).ok_or(ServiceError::NoHome)?;
+    let log_path = log_dir(platform)
+        .ok_or(ServiceError::NoHome)?
+        .join(format!("{}.log", name));
+    Ok(ServiceSpec {
+        platform,
+        name,
+        repo_root: repo_root.to_path_buf(),
+        definition_path,
+        log_path,
+        program: program.to_path_buf(),
+        args: vec![
+            "--root".to_string(),
+            repo_root.to_string_lossy().to_string(),
+            "index-watch".to_string(),
+            "--json".to_string(),
+            "--interval-ms".to_string(),
+            interval_ms.to_string(),
+        ],
+    })
+}
+
+/// Unit file, plist, or wrapper script for `spec`.
+pub fn render_definition(spec: &ServiceSpec) -> String {
+    let command: Vec<String> = std::iter::once(spec.program.to_string_lossy().to_string())
+        .chain(spec.args.iter().cloned())
+        .collect();
+    let repo = spec.repo_root.to_string_lossy();
+    let log = spec.log_path.to_string_lossy();
+    match spec.platform {
+        ServicePlatform::Systemd => {
+            let exec = command
+                .iter()
+                .map(|arg| systemd_quote(arg))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                "[Unit]\nDescription=WorkMesh index watcher for {repo}\n\n\
+                 [Service]\nExecStart={exec}\nWorkingDirectory={repo}\nRestart=on-failure\n\
+                 RestartSec=5\nStandardOutput=append:{log}\nStandardError=append:{log}\n\n\
+                 [Install]\nWantedBy=default.target\n"
+            )
+        }
+        ServicePlatform::Launchd => {
+            let arguments: String = command
+                .iter()
+                .map(|arg| format!("        <string>{}</string>\n", xml_escape(arg)))
+                .collect();
+            format!(
+                "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+                 <!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+                 \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n\
+                 <plist version=\"1.0\">\n<dict>\n\
+                 \x20   <key>Label</key>\n    <string>{label}</string>\n\
+                 \x20   <key>ProgramArguments</key>\n    <array>\n{arguments}    </array>\n\
+                 \x20   <key>WorkingDirectory</key>\n    <string>{repo}</string>\n\
+                 \x20   <key>RunAtLoad</key>\n    <true/>\n\
+                 \x20   <key>KeepAlive</key>\n    <dict>\n        <key>SuccessfulExit</key>\n        <false/>\n    </dict>\n\
+                 \x20   <key>StandardOutPath</key>\n    <string>{log}</string>\n\
+                 \x20   <key>StandardErrorPath</key>\n    <string>{log}</string>\n\
+                 </dict>\n</plist>\n",
+                label = xml_escape(&spec.name),
+                repo = xml_escape(&repo),
+                log = xml_escape(&log),
+            )
+        }
+        ServicePlatform::Windows => {
+            let exec = command
+                .iter()
+                .map(|arg| format!("\"{}\"", arg))
+                .collect::<Vec<_>>()
+                .join(" ");
+            format!(
+                "@echo off\r\ncd /d \"{repo}\"\r\n{exec} >> \"{log}\" 2>&1\r\n",
+                repo = repo,
+                exec = exec,
+                log = log
+            )
+        }
+    }
+}
+
+fn systemd_quote(arg: &str) -> String {
+    if !arg.is_empty()
+        && !arg
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\' | '%' | '$'))
+    {
+        return arg.to_string();
+    }
+    let escaped = arg
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('%', "%%")
+        .replace('$', "$$");
+    format!("\"{}\"", escaped)
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn run(program: &str, args: &[&str]) -> Result<String, ServiceError> {
+    let command = format!("{} {}", program, args.join(" "));
+    let output =
+        Command::new(program)
+            .args(args)
+            .output()
+            .map_err(|err| ServiceError::Command {
+                command: command.clone(),
+                message: err.to_string(),
+            })?;
+    if !output.status.success() {
+        return Err(ServiceError::Command {
+            command,
+            message: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        });
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Write the definition and register it; `start` also starts it now instead of at next login.
+/// Returns the commands that were run.
+pub fn install_service(spec: &ServiceSpec, start: bool) -> Result<Vec<String>, ServiceError> {
+    if let Some(parent) = spec.definition_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if let Some(parent) = spec.log_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&spec.definition_path, render_definition(spec))?;
+    let definition = spec.definition_path.to_string_lossy().to_string();
+    let unit = format!("{}.service", spec.name);
+    let task_command = format!("\"{}\"", definition);
+    let steps: Vec<(&str, Vec<&str>)> = match spec.platform {
+        ServicePlatform::Systemd => {
+            let mut enable = vec!["--user", "enable"];
+            if start {
+                enable.push("--now");
+            }
+            enable.push(&unit);
+            vec![
+                ("systemctl", vec!["--user", "daemon-reload"]),
+                ("systemctl", enable),
+            ]
+        }
+        ServicePlatform::Launchd if start => {
+            vec![("launchctl", vec!["load", "-w", definition.as_str()])]
+        }
+        ServicePlatform::Launchd => Vec::new(),
+        ServicePlatform::Windows => {
+            let mut steps = vec![(
+                "schtasks",
+                vec![
+                    "/Create",
+                    "/F",
+                    "/SC",
+                    "ONLOGON",
+                    "/TN",
+                    spec.name.as_str(),
+                    "/TR",
+                    task_command.as_str(),
+                ],
+            )];
+            if start {
+                steps.push(("schtasks", vec!["/Run", "/TN", spec.name.as_str()]));
+            }
+            steps
+        }
+    };
+    let mut ran = Vec::new();
+    for (program, args) in steps {
+        run(program, &args)?;
+        ran.push(format!("{} {}", program, args.join(" ")));
+    }
+    Ok(ran)
+}
+
+/// Stop and unregister the service and remove its definition. The log is kept.
+pub fn uninstall_service(spec: &ServiceSpec) -> Result<bool, ServiceError> {
+    if !spec.definition_path.exists() {
+        return Ok(false);
+    }
+    let definition = spec.definition_path.to_string_lossy().to_string();
+    let unit = format!("{}.service", spec.name);
+    // Stopping fails harmlessly when the service is not running.
+    match spec.platform {
+        ServicePlatform::Systemd => {
+            let _ = run("systemctl", &["--user", "disable", "--now", &unit]);
+        }
+        ServicePlatform::Launchd => {
+            let _ = run("launchctl", &["unload", "-w", &definition]);
+        }
+        ServicePlatform::Windows => {
+            let _ = run("schtasks", &["/End", "/TN", &spec.name]);
+            let _ = run("schtasks", &["/Delete", "/F", "/TN", &spec.name]);
+        }
+    }
+    fs::remove_file(&spec.definition_path)?;
+    if spec.platform == ServicePlatform::Systemd {
+        let _ = run("systemctl", &["--user", "daemon-reload"]);
+    }
+    Ok(true)
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ServiceStatus {
+    pub platform: ServicePlatform,
+    pub name: String,
+    pub installed: bool,
+    /// `None` when the service manager could not be asked.
+    pub running: Option<bool>,
+    pub definition_path: PathBuf,
+    pub log_path: PathBuf,
+    pub log_updated: Option<String>,
+    pub last_log_line: Option<String>,
+}
+
+/// Installed when its definition exists; the service manager is only asked when it is.
+pub fn service_status(spec: &ServiceSpec) -> ServiceStatus {
+    let installed = spec.definition_path.exists();
+    let running = if installed {
+        let unit = format!("{}.service", spec.name);
+        match spec.platform {
+            ServicePlatform::Systemd => Command::new("systemctl")
+                .args(["--user", "is-active", "--quiet", &unit])
+                .status()
+                .ok()
+                .map(|status| status.success()),
+            ServicePlatform::Launchd => run("launchctl", &["list", &spec.name])
+                .map(|out| out.contains("\"PID\""))
+                .ok(),
+            ServicePlatform::Windows => {
+                run("schtasks", &["/Query", "/TN", &spec.name, "/FO", "LIST"])
+                    .map(|out| out.contains("Running"))
+                    .ok()
+            }
+        }
+    } else {
+        None
+    };
+    let log_updated = fs::metadata(&spec.log_path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .map(|modified| format_timestamp(DateTime::<Local>::from(modified)));
+    let last_log_line = fs::read_to_string(&spec.log_path).ok().and_then(|text| {
+        text.lines()
+            .rev()
+            .find(|line| !line.trim().is_empty())
+            .map(str::to_string)
+    });
+    ServiceStatus {
+        platform: spec.platform,
+        name: spec.name.clone(),
+        installed,
+        running,
+        definition_path: spec.definition_path.clone(),
+        log_path: spec.log_path.clone(),
+        log_updated,
+        last_log_line,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(platform: ServicePlatform) -> ServiceSpec {
+        let repo = Path::new("/work/my repo");
+        ServiceSpec {
+            platform,
+            name: service_name(repo),
+            repo_root: repo.to_path_buf(),
+            definition_path: PathBuf::from("/tmp/def"),
+            log_path: PathBuf::from("/logs/watch.log"),
+            program: PathBuf::from("/usr/bin/workmesh"),
+            args: vec![
+                "--root".to_string(),
+                "/work/my repo".to_string(),
+                "index-watch".to_string(),
+            ],
+        }
+    }
+
+    #[test]
+    fn service_name_is_readable_and_unique_per_path() {
+        let name = service_name(Path::new("/work/My Repo"));
+        assert!(name.starts_with("workmesh-my-repo-"), "{}", name);
+        assert_ne!(name, service_name(Path::new("/other/My Repo")));
+    }
+
+    #[test]
+    fn definitions_run_index_watch_and_log_to_file() {
+        let unit = render_definition(&spec(ServicePlatform::Systemd));
+        assert!(unit.contains("ExecStart=/usr/bin/workmesh --root \"/work/my repo\" index-watch\n"));
+        assert!(unit.contains("StandardOutput=append:/logs/watch.log\n"));
+        assert!(unit.contains("WantedBy=default.target"));
+
+        let plist = render_definition(&spec(ServicePlatform::Launchd));
+        assert!(plist.contains("<string>/work/my repo</string>"));
+        assert!(
+            plist.contains("<key>StandardErrorPath</key>\n    <string>/logs/watch.log</string>")
+        );
+
+        let script = render_definition(&spec(ServicePlatform::Windows));
+        assert!(script.contains(
+            "\"/usr/bin/workmesh\" \"--root\" \"/work/my repo\" \"index-watch\" >> \"/logs/watch.log\" 2>&1"
+        ));
+    }
+}
@@ -8,9 +8,17 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::audit::{read_recent_audit_events, AuditEvent};
+use crate::checkpoint_triggers::CheckpointTriggers;
+use crate::config::{load_config, load_global_config};
+use crate::extract::epic_subtree;
+use crate::graph_diff::{dependency_edges, GraphEdge};
+use crate::health::is_closed;
 use crate::project::{ensure_project_docs, project_docs_dir, repo_root_from_backlog};
+use crate::reminders::{context_due_reminders, Reminder, DEFAULT_DUE_SOON_DAYS};
 use crate::task::Task;
-use crate::task_ops::{is_lease_active, ready_tasks};
+use crate::task_ops::{is_epic, is_lease_active, now_timestamp, ready_tasks};
+use crate::timewindow::{parse_time_ref, parse_timestamp_ref, TimeWindow};
+use crate::truth::{list_truths, TruthQuery, TruthRecord, TruthState};
 
 #[derive(Debug, Error)]
 pub enum SessionError {
@@ -20,6 +28,53 @@ pub enum SessionError {
     Project(#[from] crate::project::ProjectError),
     #[error("Failed to parse checkpoint: {0}")]
     Parse(#[from] serde_json::Error),
+    #[error("Unknown checkpoint section: {0}")]
+    UnknownSection(String),
+}
+
+/// Checkpoint Markdown sections, in render order. Each is also a `{{name}}` template placeholder.
+pub const CHECKPOINT_SECTIONS: &[&str] = &[
+    "current_task",
+    "ready",
+    "leases",
+    "due",
+    "git",
+    "git_detail",
+    "audit",
+    "epics",
+    "questions",
+    "working_set",
+    "decisions",
+];
+
+/// Sections rendered unless `[checkpoint]` or flags say otherwise.
+pub const DEFAULT_CHECKPOINT_SECTIONS: &[&str] = &[
+    "current_task",
+    "ready",
+    "leases",
+    "due",
+    "git",
+    "git_detail",
+    "audit",
+];
+
+/// Accepted truths listed under "Recent Decisions".
+const CHECKPOINT_DECISION_LIMIT: usize = 5;
+
+/// `[checkpoint]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CheckpointConfig {
+    /// Sections added to the defaults.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+    /// Sections removed from the defaults.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+    /// Markdown template (repo-relative or absolute path) with `{{section}}` placeholders.
+    pub template: Option<String>,
+    /// Rules for automatic checkpoints after mutations (`[checkpoint.triggers]`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triggers: Option<CheckpointTriggers>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -78,10 +133,46 @@ pub struct CheckpointSnapshot {
     pub current_task: Option<TaskSummary>,
     pub ready: Vec<TaskSummary>,
     pub leases: Vec<TaskSummary>,
+    /// Overdue and due-soon tasks in the context scope.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub due: Vec<Reminder>,
     pub git: GitSummary,
     pub changed_files: Vec<String>,
     pub top_level_dirs: Vec<String>,
     pub audit_events: Vec<AuditEvent>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub epics: Vec<EpicProgress>,
+    /// Proposed truths still waiting for a decision.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub open_questions: Vec<DecisionSummary>,
+    /// Task lines of the working-set file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub working_set: Vec<String>,
+    /// Most recently accepted truths.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decisions: Vec<DecisionSummary>,
+    /// Blocking edge set at checkpoint time; `None` for checkpoints written before edges were
+    /// recorded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<GraphEdge>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpicProgress {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    /// Closed descendants (Done or Cancelled).
+    pub done: usize,
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecisionSummary {
+    pub id: String,
+    pub title: String,
+    pub state: String,
+    pub updated_at: String,
 }
 
 #[derive(Debug, Clone)]
@@ -96,6 +187,25 @@ pub struct CheckpointOptions {
     pub project_id: Option<String>,
     pub checkpoint_id: Option<String>,
     pub audit_limit: usize,
+    /// Sections added on top of `[checkpoint]` (`--include`).
+    pub include: Vec<String>,
+    /// Sections removed after `[checkpoint]` and `include` (`--exclude`).
+    pub exclude: Vec<String>,
+    /// Template path overriding `[checkpoint].template` (`--template`).
+    pub template: Option<String>,
+}
+
+impl CheckpointOptions {
+    pub fn new(audit_limit: usize) -> Self {
+        Self {
+            project_id: None,
+            checkpoint_id: None,
+            audit_limit,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            template: None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -103,6 +213,20 @@ pub struct ResumeSummary {
     pub snapshot: CheckpointSnapshot,
     pub working_set: Option<String>,
     pub checkpoint_path: PathBuf,
+    /// Most recent session journal entries, oldest first.
+    pub journal: Vec<JournalEntry>,
+}
+
+/// Journal entries shown by `resume`.
+pub const RESUME_JOURNAL_ENTRIES: usize = 3;
+
+/// One `## <timestamp>` block of `updates/session-journal.md`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub timestamp: String,
+    pub task_id: Option<String>,
+    pub next: Option<String>,
+    pub note: Option<String>,
 }
 
 pub fn write_checkpoint(
@@ -112,6 +236,19 @@ pub fn write_checkpoint(
 ) -> Result<CheckpointResult, SessionError> {
     let repo_root = repo_root_from_backlog(backlog_dir);
     let project_id = resolve_project_id(&repo_root, tasks, options.project_id.as_deref());
+    let config = resolve_checkpoint_config(&repo_root);
+    let sections = resolve_checkpoint_sections(&config, options)?;
+    let template = match options.template.as_deref().or(config.template.as_deref()) {
+        Some(path) => Some(fs::read_to_string(repo_root.join(path))?),
+        None => None,
+    };
+    let wants = |section: &str| {
+        sections.iter().any(|name| name == section)
+            || template
+                .as_deref()
+                .map(|text| text.contains(&format!("{{{{{}}}}}", section)))
+                .unwrap_or(false)
+    };
     ensure_project_docs(&repo_root, &project_id, None)?;
 
     let updates_dir = project_docs_dir(&repo_root, &project_id).join("updates");
@@ -121,7 +258,7 @@ pub fn write_checkpoint(
         .checkpoint_id
         .clone()
         .unwrap_or_else(default_checkpoint_id);
-    let generated_at = Local::now().format("%Y-%m-%d %H:%M").to_string();
+    let generated_at = now_timestamp();
 
     let (git_summary, changed_files) = git_status(&repo_root);
     let top_level_dirs = top_level_dirs(&changed_files);
@@ -136,6 +273,43 @@ pub fn write_checkpoint(
         .into_iter()
         .map(task_to_summary)
         .collect::<Vec<_>>();
+    let due = if wants("due") {
+        context_due_reminders(
+            backlog_dir,
+            tasks,
+            Local::now().date_naive(),
+            DEFAULT_DUE_SOON_DAYS,
+        )
+    } else {
+        Vec::new()
+    };
+    let epics = if wants("epics") {
+        epic_progress(tasks)
+    } else {
+        Vec::new()
+    };
+    let open_questions = if wants("questions") {
+        truth_summaries(backlog_dir, &project_id, TruthState::Proposed, None)
+    } else {
+        Vec::new()
+    };
+    let decisions = if wants("decisions") {
+        truth_summaries(
+            backlog_dir,
+            &project_id,
+            TruthState::Accepted,
+            Some(CHECKPOINT_DECISION_LIMIT),
+        )
+    } else {
+        Vec::new()
+    };
+    let working_set = if wants("working_set") {
+        fs::read_to_string(working_set_path(&repo_root, &project_id))
+            .map(|text| working_set_lines(&text))
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
 
     let snapshot = CheckpointSnapshot {
         checkpoint_id: checkpoint_id.clone(),
@@ -146,10 +320,16 @@ pub fn write_checkpoint(
         current_task,
         ready,
         leases,
+        due,
         git: git_summary,
         changed_files,
         top_level_dirs,
         audit_events,
+        epics,
+        open_questions,
+        working_set,
+        decisions,
+        edges: Some(dependency_edges(tasks)),
     };
 
     let json_path = updates_dir.join(format!("checkpoint-{}.json", checkpoint_id));
@@ -159,7 +339,11 @@ pub fn write_checkpoint(
         &json_path,
         serde_json::to_string_pretty(&snapshot).unwrap_or_default(),
     )?;
-    fs::write(&markdown_path, render_checkpoint_markdown(&snapshot))?;
+    let markdown = match template.as_deref() {
+        Some(template) => render_checkpoint_template(&snapshot, template),
+        None => render_checkpoint_markdown(&snapshot, &sections),
+    };
+    fs::write(&markdown_path, markdown)?;
 
     Ok(CheckpointResult {
         snapshot,
@@ -168,6 +352,46 @@ pub fn write_checkpoint(
     })
 }
 
+/// Project `[checkpoint]` wins over global `[checkpoint]`.
+pub fn resolve_checkpoint_config(repo_root: &Path) -> CheckpointConfig {
+    load_config(repo_root)
+        .and_then(|config| config.checkpoint)
+        .or_else(|| load_global_config().and_then(|config| config.checkpoint))
+        .unwrap_or_default()
+}
+
+/// Defaults, then `[checkpoint]` include/exclude, then option include/exclude; render order is
+/// always that of [`CHECKPOINT_SECTIONS`].
+pub fn resolve_checkpoint_sections(
+    config: &CheckpointConfig,
+    options: &CheckpointOptions,
+) -> Result<Vec<String>, SessionError> {
+    let mut enabled: Vec<&str> = DEFAULT_CHECKPOINT_SECTIONS.to_vec();
+    let steps = [
+        (&config.include, true),
+        (&config.exclude, false),
+        (&options.include, true),
+        (&options.exclude, false),
+    ];
+    for (names, include) in steps {
+        for name in names {
+            let key = name.trim().to_lowercase().replace('-', "_");
+            let Some(section) = CHECKPOINT_SECTIONS.iter().find(|section| **section == key) else {
+                return Err(SessionError::UnknownSection(name.clone()));
+            };
+            enabled.retain(|existing| existing != section);
+            if include {
+                enabled.push(*section);
+            }
+        }
+    }
+    Ok(CHECKPOINT_SECTIONS
+        .iter()
+        .filter(|section| enabled.contains(*section))
+        .map(|section| section.to_string())
+        .collect())
+}
+
 pub fn load_checkpoint(
     repo_root: &Path,
     project_id: &str,
@@ -190,14 +414,15 @@ pub fn resume_summary(
     let Some((snapshot, path)) = load_checkpoint(repo_root, project_id, checkpoint_id)? else {
         return Ok(None);
     };
-    let working_set_path = project_docs_dir(repo_root, project_id)
-        .join("updates")
-        .join("working-set.md");
-    let working_set = fs::read_to_string(&working_set_path).ok();
+    let working_set = fs::read_to_string(working_set_path(repo_root, project_id)).ok();
+    let mut journal = read_session_journal(repo_root, project_id)?;
+    let keep_from = journal.len().saturating_sub(RESUME_JOURNAL_ENTRIES);
+    journal.drain(..keep_from);
     Ok(Some(ResumeSummary {
         snapshot,
         working_set,
         checkpoint_path: path,
+        journal,
     }))
 }
 
@@ -215,7 +440,7 @@ pub fn diff_since_checkpoint(
             .as_deref()
             .or(task.created_date.as_deref());
         if let (Some(updated), Some(checkpoint_time)) = (updated, checkpoint_time) {
-            if let Ok(updated_time) = NaiveDateTime::parse_from_str(updated, "%Y-%m-%d %H:%M") {
+            if let Some(updated_time) = parse_timestamp(updated) {
                 if updated_time >= checkpoint_time {
                     updated_tasks.push(task_to_summary(task));
                 }
@@ -276,6 +501,14 @@ pub fn render_resume(summary: &ResumeSummary) -> String {
         lines.push("- None".to_string());
     }
 
+    if !snapshot.due.is_empty() {
+        lines.push(String::new());
+        lines.push("Due soon / overdue:".to_string());
+        for item in &snapshot.due {
+            lines.push(format!("- {}", item.line()));
+        }
+    }
+
     lines.push(String::new());
     lines.push("Next actions:".to_string());
     if let Some(task) = snapshot.current_task.as_ref() {
@@ -294,6 +527,14 @@ pub fn render_resume(summary: &ResumeSummary) -> String {
         }
     }
 
+    if !summary.journal.is_empty() {
+        lines.push(String::new());
+        lines.push("Recent journal:".to_string());
+        for entry in &summary.journal {
+            lines.push(format!("- {}", journal_line(entry)));
+        }
+    }
+
     lines.join("\n")
 }
 
@@ -343,9 +584,10 @@ pub fn write_working_set(
     tasks: &[TaskSummary],
     note: Option<&str>,
 ) -> Result<PathBuf, SessionError> {
-    let updates_dir = project_docs_dir(repo_root, project_id).join("updates");
-    fs::create_dir_all(&updates_dir)?;
-    let path = updates_dir.join("working-set.md");
+    let path = working_set_path(repo_root, project_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     let mut lines = Vec::new();
     lines.push("# Working Set".to_string());
     lines.push(String::new());
@@ -367,6 +609,163 @@ pub fn write_working_set(
     Ok(path)
 }
 
+pub fn working_set_path(repo_root: &Path, project_id: &str) -> PathBuf {
+    project_docs_dir(repo_root, project_id)
+        .join("updates")
+        .join("working-set.md")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkingSetFlag {
+    /// No task with this id exists anymore.
+    Missing,
+    /// Done or Cancelled.
+    Closed,
+    /// The task has a lease, but it expired or lost its owner.
+    LeaseExpired,
+    /// Not In Progress and not leased: nobody is working on it.
+    Idle,
+    /// Status differs from the one recorded in the file.
+    StatusChanged,
+}
+
+impl WorkingSetFlag {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WorkingSetFlag::Missing => "missing",
+            WorkingSetFlag::Closed => "closed",
+            WorkingSetFlag::LeaseExpired => "lease_expired",
+            WorkingSetFlag::Idle => "idle",
+            WorkingSetFlag::StatusChanged => "status_changed",
+        }
+    }
+
+    /// Flags that drop an entry on `--refresh`; `StatusChanged` alone only updates the line.
+    pub fn is_stale(self) -> bool {
+        !matches!(self, WorkingSetFlag::StatusChanged)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkingSetEntryStatus {
+    pub id: String,
+    pub recorded_status: Option<String>,
+    pub current: Option<TaskSummary>,
+    pub flags: Vec<WorkingSetFlag>,
+}
+
+impl WorkingSetEntryStatus {
+    pub fn is_stale(&self) -> bool {
+        self.flags.iter().any(|flag| flag.is_stale())
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkingSetStatus {
+    pub path: PathBuf,
+    pub entries: Vec<WorkingSetEntryStatus>,
+    pub note: Option<String>,
+}
+
+impl WorkingSetStatus {
+    pub fn stale_count(&self) -> usize {
+        self.entries.iter().filter(|entry| entry.is_stale()).count()
+    }
+}
+
+/// Compare the stored working set against current task statuses and leases.
+///
+/// Returns `None` when no working set has been written for the project.
+pub fn working_set_status(
+    repo_root: &Path,
+    project_id: &str,
+    tasks: &[Task],
+) -> Result<Option<WorkingSetStatus>, SessionError> {
+    let path = working_set_path(repo_root, project_id);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let text = fs::read_to_string(&path)?;
+    let (recorded, note) = parse_working_set(&text);
+    let entries = recorded
+        .into_iter()
+        .map(|(id, recorded_status)| {
+            let task = tasks.iter().find(|task| task.id.eq_ignore_ascii_case(&id));
+            let mut flags = Vec::new();
+            match task {
+                None => flags.push(WorkingSetFlag::Missing),
+                Some(task) => {
+                    if is_closed(task) {
+                        flags.push(WorkingSetFlag::Closed);
+                    } else if task.lease.is_some() && !is_lease_active(task) {
+                        flags.push(WorkingSetFlag::LeaseExpired);
+                    } else if !is_lease_active(task)
+                        && !task.status.eq_ignore_ascii_case("in progress")
+                    {
+                        flags.push(WorkingSetFlag::Idle);
+                    }
+                    let changed = recorded_status
+                        .as_deref()
+                        .is_some_and(|status| !status.eq_ignore_ascii_case(&task.status));
+                    if changed {
+                        flags.push(WorkingSetFlag::StatusChanged);
+                    }
+                }
+            }
+            WorkingSetEntryStatus {
+                id,
+                recorded_status,
+                current: task.map(task_to_summary),
+                flags,
+            }
+        })
+        .collect();
+    Ok(Some(WorkingSetStatus {
+        path,
+        entries,
+        note,
+    }))
+}
+
+/// Rewrite the working set with current task lines, dropping stale entries and keeping the note.
+pub fn refresh_working_set(
+    repo_root: &Path,
+    project_id: &str,
+    status: &WorkingSetStatus,
+) -> Result<PathBuf, SessionError> {
+    let kept: Vec<TaskSummary> = status
+        .entries
+        .iter()
+        .filter(|entry| !entry.is_stale())
+        .filter_map(|entry| entry.current.clone())
+        .collect();
+    write_working_set(repo_root, project_id, &kept, status.note.as_deref())
+}
+
+/// Task lines (`- id | status | ...`) and the `## Notes` body of a working-set file.
+fn parse_working_set(text: &str) -> (Vec<(String, Option<String>)>, Option<String>) {
+    let (body, note) = match text.split_once("## Notes") {
+        Some((body, note)) => (body, Some(note.trim().to_string())),
+        None => (text, None),
+    };
+    let entries = body
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("- "))
+        .filter(|line| *line != "No active tasks")
+        .filter_map(|line| {
+            let mut parts = line.split(" | ");
+            let id = parts.next()?.trim().to_string();
+            if id.is_empty() {
+                return None;
+            }
+            let status = parts.next().map(|status| status.trim().to_string());
+            Some((id, status))
+        })
+        .collect();
+    (entries, note.filter(|note| !note.is_empty()))
+}
+
 pub fn append_session_journal(
     repo_root: &Path,
     project_id: &str,
@@ -374,9 +773,10 @@ pub fn append_session_journal(
     next_action: Option<&str>,
     note: Option<&str>,
 ) -> Result<PathBuf, SessionError> {
-    let updates_dir = project_docs_dir(repo_root, project_id).join("updates");
-    fs::create_dir_all(&updates_dir)?;
-    let path = updates_dir.join("session-journal.md");
+    let path = session_journal_path(repo_root, project_id);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
     let timestamp = Local::now().format("%Y-%m-%d %H:%M");
     let mut entry = Vec::new();
     entry.push(format!("## {}", timestamp));
@@ -408,6 +808,123 @@ pub fn append_session_journal(
     Ok(path)
 }
 
+pub fn session_journal_path(repo_root: &Path, project_id: &str) -> PathBuf {
+    project_docs_dir(repo_root, project_id)
+        .join("updates")
+        .join("session-journal.md")
+}
+
+/// Entries in file (chronological) order; a missing journal is empty.
+pub fn read_session_journal(
+    repo_root: &Path,
+    project_id: &str,
+) -> Result<Vec<JournalEntry>, SessionError> {
+    let path = session_journal_path(repo_root, project_id);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(parse_session_journal(&fs::read_to_string(&path)?))
+}
+
+pub fn parse_session_journal(text: &str) -> Vec<JournalEntry> {
+    let mut entries: Vec<JournalEntry> = Vec::new();
+    for line in text.lines() {
+        if let Some(timestamp) = line.strip_prefix("## ") {
+            entries.push(JournalEntry {
+                timestamp: timestamp.trim().to_string(),
+                task_id: None,
+                next: None,
+                note: None,
+            });
+            continue;
+        }
+        let Some(entry) = entries.last_mut() else {
+            continue;
+        };
+        if let Some(value) = line.strip_prefix("- Task: ") {
+            entry.task_id = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("- Next: ") {
+            entry.next = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("- Note: ") {
+            entry.note = Some(value.trim().to_string());
+        }
+    }
+    entries
+}
+
+/// Keep entries for `task_id` (case-insensitive) written inside `window`.
+pub fn filter_session_journal(
+    entries: Vec<JournalEntry>,
+    task_id: Option<&str>,
+    window: &TimeWindow,
+) -> Vec<JournalEntry> {
+    entries
+        .into_iter()
+        .filter(|entry| match task_id {
+            Some(task_id) => entry
+                .task_id
+                .as_deref()
+                .is_some_and(|id| id.eq_ignore_ascii_case(task_id.trim())),
+            None => true,
+        })
+        .filter(|entry| window.contains_timestamp(&entry.timestamp))
+        .collect()
+}
+
+/// Alias for [`parse_time_ref`], kept for existing callers.
+pub fn parse_since(value: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    parse_time_ref(value, now)
+}
+
+/// Chronological narrative grouped by day.
+pub fn render_session_journal_markdown(entries: &[JournalEntry]) -> String {
+    let mut lines = vec!["# Session Journal".to_string()];
+    let mut current_day: Option<&str> = None;
+    for entry in entries {
+        let (day, time) = entry
+            .timestamp
+            .split_once(' ')
+            .unwrap_or((entry.timestamp.as_str(), ""));
+        if current_day != Some(day) {
+            lines.push(String::new());
+            lines.push(format!("## {}", day));
+            lines.push(String::new());
+            current_day = Some(day);
+        }
+        let mut line = format!("- **{}**", time);
+        if let Some(task_id) = entry.task_id.as_deref() {
+            line.push_str(&format!(" `{}`", task_id));
+        }
+        if let Some(note) = entry.note.as_deref() {
+            line.push_str(&format!(" {}", note));
+        }
+        if let Some(next) = entry.next.as_deref() {
+            line.push_str(&format!(" _Next: {}_", next));
+        }
+        lines.push(line);
+    }
+    if entries.is_empty() {
+        lines.push(String::new());
+        lines.push("- No entries".to_string());
+    }
+    lines.join("\n")
+}
+
+/// `2026-03-01 10:00 task-042: note (next: ...)`
+pub fn journal_line(entry: &JournalEntry) -> String {
+    let mut line = entry.timestamp.clone();
+    if let Some(task_id) = entry.task_id.as_deref() {
+        line.push_str(&format!(" {}:", task_id));
+    }
+    if let Some(note) = entry.note.as_deref() {
+        line.push_str(&format!(" {}", note));
+    }
+    if let Some(next) = entry.next.as_deref() {
+        line.push_str(&format!(" (next: {})", next));
+    }
+    line
+}
+
 pub fn resolve_project_id(repo_root: &Path, tasks: &[Task], explicit: Option<&str>) -> String {
     if let Some(value) = explicit {
         let trimmed = value.trim();
@@ -677,7 +1194,7 @@ fn top_level_dirs(paths: &[String]) -> Vec<String> {
     dirs
 }
 
-fn render_checkpoint_markdown(snapshot: &CheckpointSnapshot) -> String {
+fn render_checkpoint_markdown(snapshot: &CheckpointSnapshot, sections: &[String]) -> String {
     let mut lines = Vec::new();
     lines.push(format!("# Checkpoint {}", snapshot.checkpoint_id));
     lines.push(String::new());
@@ -685,29 +1202,65 @@ fn render_checkpoint_markdown(snapshot: &CheckpointSnapshot) -> String {
     lines.push(format!("Project: {}", snapshot.project_id));
     lines.push(String::new());
 
-    lines.push("## Current Task".to_string());
-    if let Some(task) = snapshot.current_task.as_ref() {
-        lines.push(format!("- {}", task.line()));
-    } else {
-        lines.push("- None".to_string());
+    for section in sections {
+        let headings: &[&str] = match section.as_str() {
+            "current_task" => &["Current Task"],
+            "ready" => &["Ready Tasks"],
+            "leases" => &["Active Leases"],
+            // Only when something is actually due, so repos without due dates see no change.
+            "due" if snapshot.due.is_empty() => continue,
+            "due" => &["Due Soon / Overdue"],
+            "git" => &["Git Status"],
+            "git_detail" => &["Changed Files", "Top-level Directories"],
+            "audit" => &["Recent Audit Events"],
+            "epics" => &["Epic Progress"],
+            "questions" => &["Open Questions"],
+            "working_set" => &["Working Set"],
+            "decisions" => &["Recent Decisions"],
+            _ => continue,
+        };
+        let bodies = if section == "git_detail" {
+            vec![
+                bullet_list(&snapshot.changed_files),
+                bullet_list(&snapshot.top_level_dirs),
+            ]
+        } else {
+            vec![render_checkpoint_section(snapshot, section)]
+        };
+        for (heading, body) in headings.iter().zip(bodies) {
+            lines.push(format!("## {}", heading));
+            lines.push(body);
+            lines.push(String::new());
+        }
     }
-    lines.push(String::new());
 
-    lines.push("## Ready Tasks".to_string());
-    if snapshot.ready.is_empty() {
-        lines.push("- None".to_string());
-    } else {
-        for task in &snapshot.ready {
-            lines.push(format!("- {}", task.line()));
+    lines.join("\n")
+}
+
+/// Replace `{{checkpoint_id}}`, `{{generated_at}}`, `{{project_id}}` and every
+/// `{{<section>}}` with the section's bullet list; unknown placeholders are kept verbatim.
+fn render_checkpoint_template(snapshot: &CheckpointSnapshot, template: &str) -> String {
+    let mut output = template
+        .replace("{{checkpoint_id}}", &snapshot.checkpoint_id)
+        .replace("{{generated_at}}", &snapshot.generated_at)
+        .replace("{{project_id}}", &snapshot.project_id);
+    for section in CHECKPOINT_SECTIONS {
+        let placeholder = format!("{{{{{}}}}}", section);
+        if output.contains(&placeholder) {
+            output = output.replace(&placeholder, &render_checkpoint_section(snapshot, section));
         }
     }
-    lines.push(String::new());
+    output
+}
 
-    lines.push("## Active Leases".to_string());
-    if snapshot.leases.is_empty() {
-        lines.push("- None".to_string());
-    } else {
-        for task in &snapshot.leases {
+fn render_checkpoint_section(snapshot: &CheckpointSnapshot, section: &str) -> String {
+    match section {
+        "current_task" => match snapshot.current_task.as_ref() {
+            Some(task) => format!("- {}", task.line()),
+            None => "- None".to_string(),
+        },
+        "ready" => bullet_list(snapshot.ready.iter().map(|task| task.line())),
+        "leases" => bullet_list(snapshot.leases.iter().map(|task| {
             let owner = task
                 .lease
                 .as_ref()
@@ -718,75 +1271,159 @@ fn render_checkpoint_markdown(snapshot: &CheckpointSnapshot) -> String {
                 .as_ref()
                 .and_then(|lease| lease.expires_at.as_deref())
                 .unwrap_or("n/a");
-            lines.push(format!("- {} | {} | {}", task.id, owner, expires));
-        }
+            format!("{} | {} | {}", task.id, owner, expires)
+        })),
+        "due" => bullet_list(snapshot.due.iter().map(|item| item.line())),
+        "git" => render_git_status(&snapshot.git),
+        "git_detail" => bullet_list(&snapshot.changed_files),
+        "audit" => bullet_list(snapshot.audit_events.iter().map(|event| {
+            let task = event.task_id.as_deref().unwrap_or("-");
+            let actor = event.actor.as_deref().unwrap_or("-");
+            format!(
+                "{} | {} | {} | {}",
+                event.timestamp, event.action, task, actor
+            )
+        })),
+        "epics" => bullet_list(snapshot.epics.iter().map(|epic| {
+            format!(
+                "{} | {} | {}/{} done | {}",
+                epic.id, epic.status, epic.done, epic.total, epic.title
+            )
+        })),
+        "questions" => bullet_list(
+            snapshot
+                .open_questions
+                .iter()
+                .map(|question| format!("{} | {}", question.id, question.title)),
+        ),
+        "working_set" => bullet_list(&snapshot.working_set),
+        "decisions" => bullet_list(snapshot.decisions.iter().map(|decision| {
+            format!(
+                "{} | {} | {}",
+                decision.id, decision.updated_at, decision.title
+            )
+        })),
+        _ => String::new(),
     }
-    lines.push(String::new());
+}
 
-    lines.push("## Git Status".to_string());
-    if snapshot.git.available {
-        if let Some(branch) = snapshot.git.branch.as_deref() {
-            lines.push(format!("- Branch: {}", branch));
-        }
-        if let Some(upstream) = snapshot.git.upstream.as_deref() {
-            lines.push(format!("- Upstream: {}", upstream));
-        }
-        if let (Some(ahead), Some(behind)) = (snapshot.git.ahead, snapshot.git.behind) {
-            lines.push(format!("- Ahead/Behind: {}/{}", ahead, behind));
-        } else if let Some(ahead) = snapshot.git.ahead {
-            lines.push(format!("- Ahead: {}", ahead));
-        } else if let Some(behind) = snapshot.git.behind {
-            lines.push(format!("- Behind: {}", behind));
-        }
-        lines.push(format!(
-            "- Staged: {}, Unstaged: {}, Untracked: {}",
-            snapshot.git.staged, snapshot.git.unstaged, snapshot.git.untracked
-        ));
-    } else {
-        lines.push("- Git status unavailable".to_string());
+fn render_git_status(git: &GitSummary) -> String {
+    if !git.available {
+        return "- Git status unavailable".to_string();
     }
-    lines.push(String::new());
+    let mut lines = Vec::new();
+    if let Some(branch) = git.branch.as_deref() {
+        lines.push(format!("- Branch: {}", branch));
+    }
+    if let Some(upstream) = git.upstream.as_deref() {
+        lines.push(format!("- Upstream: {}", upstream));
+    }
+    if let (Some(ahead), Some(behind)) = (git.ahead, git.behind) {
+        lines.push(format!("- Ahead/Behind: {}/{}", ahead, behind));
+    } else if let Some(ahead) = git.ahead {
+        lines.push(format!("- Ahead: {}", ahead));
+    } else if let Some(behind) = git.behind {
+        lines.push(format!("- Behind: {}", behind));
+    }
+    lines.push(format!(
+        "- Staged: {}, Unstaged: {}, Untracked: {}",
+        git.staged, git.unstaged, git.untracked
+    ));
+    lines.join("\n")
+}
 
-    lines.push("## Changed Files".to_string());
-    if snapshot.changed_files.is_empty() {
-        lines.push("- None".to_string());
+/// `- item` per entry, or `- None`.
+fn bullet_list<I>(items: I) -> String
+where
+    I: IntoIterator,
+    I::Item: std::fmt::Display,
+{
+    let lines: Vec<String> = items
+        .into_iter()
+        .map(|item| format!("- {}", item))
+        .collect();
+    if lines.is_empty() {
+        "- None".to_string()
     } else {
-        for path in &snapshot.changed_files {
-            lines.push(format!("- {}", path));
-        }
+        lines.join("\n")
     }
-    lines.push(String::new());
+}
 
-    lines.push("## Top-level Directories".to_string());
-    if snapshot.top_level_dirs.is_empty() {
-        lines.push("- None".to_string());
-    } else {
-        for dir in &snapshot.top_level_dirs {
-            lines.push(format!("- {}", dir));
-        }
+fn epic_progress(tasks: &[Task]) -> Vec<EpicProgress> {
+    let mut epics: Vec<&Task> = tasks.iter().filter(|task| is_epic(task)).collect();
+    epics.sort_by_key(|task| task.id_num());
+    epics
+        .into_iter()
+        .map(|epic| {
+            let members: Vec<&Task> = epic_subtree(tasks, epic)
+                .into_iter()
+                // Nested epic documents anchor their own subtree; only count work items.
+                .filter(|task| !is_epic(task))
+                .collect();
+            EpicProgress {
+                id: epic.id.clone(),
+                title: epic.title.clone(),
+                status: epic.status.clone(),
+                done: members.iter().filter(|task| is_closed(task)).count(),
+                total: members.len(),
+            }
+        })
+        .collect()
+}
+
+/// Truths in `state` scoped to `project_id` (or unscoped), newest first; best-effort.
+fn truth_summaries(
+    backlog_dir: &Path,
+    project_id: &str,
+    state: TruthState,
+    limit: Option<usize>,
+) -> Vec<DecisionSummary> {
+    let query = TruthQuery {
+        states: vec![state],
+        ..TruthQuery::default()
+    };
+    let records = list_truths(backlog_dir, &query).unwrap_or_default();
+    let mut summaries: Vec<DecisionSummary> = records
+        .iter()
+        .filter(|record| {
+            record
+                .context
+                .project_id
+                .as_deref()
+                .map(|id| id.eq_ignore_ascii_case(project_id))
+                .unwrap_or(true)
+        })
+        .map(decision_summary)
+        .collect();
+    if let Some(limit) = limit {
+        summaries.truncate(limit);
     }
-    lines.push(String::new());
+    summaries
+}
 
-    lines.push("## Recent Audit Events".to_string());
-    if snapshot.audit_events.is_empty() {
-        lines.push("- None".to_string());
-    } else {
-        for event in &snapshot.audit_events {
-            let task = event.task_id.as_deref().unwrap_or("-");
-            let actor = event.actor.as_deref().unwrap_or("-");
-            lines.push(format!(
-                "- {} | {} | {} | {}",
-                event.timestamp, event.action, task, actor
-            ));
-        }
+fn decision_summary(record: &TruthRecord) -> DecisionSummary {
+    DecisionSummary {
+        id: record.id.clone(),
+        title: record.title.clone(),
+        state: record.state.as_str().to_string(),
+        updated_at: record.updated_at.clone(),
     }
-    lines.push(String::new());
+}
 
-    lines.join("\n")
+fn working_set_lines(text: &str) -> Vec<String> {
+    let body = text
+        .split_once("## Notes")
+        .map(|(body, _)| body)
+        .unwrap_or(text);
+    body.lines()
+        .filter_map(|line| line.trim().strip_prefix("- "))
+        .filter(|line| *line != "No active tasks")
+        .map(|line| line.to_string())
+        .collect()
 }
 
 fn parse_timestamp(value: &str) -> Option<NaiveDateTime> {
-    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").ok()
+    parse_timestamp_ref(value)
 }
 
 #[cfg(test)]
@@ -1056,6 +1693,7 @@ mod tests {
             current_task: None,
             ready: vec![],
             leases: vec![],
+            due: vec![],
             git: GitSummary {
                 available: false,
                 branch: None,
@@ -1069,6 +1707,11 @@ mod tests {
             changed_files: vec!["README.md".to_string()],
             top_level_dirs: vec![],
             audit_events: vec![],
+            epics: vec![],
+            open_questions: vec![],
+            working_set: vec![],
+            decisions: vec![],
+            edges: None,
         };
 
         let diff = diff_since_checkpoint(repo, &backlog, &tasks, &checkpoint);
@@ -1108,6 +1751,86 @@ mod tests {
         assert!(content.contains("Task: task-001"));
     }
 
+    #[test]
+    fn session_journal_parses_filters_and_renders() {
+        let text = "# Session Journal\n\n\
+## 2026-03-01 09:00\n- Task: task-042\n- Note: started parser\n\n\
+## 2026-03-02 10:30\n- Task: task-007\n- Next: review\n\n\
+## 2026-03-02 16:00\n- Task: task-042\n- Note: parser done\n- Next: ship\n";
+        let entries = parse_session_journal(text);
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[1].next.as_deref(), Some("review"));
+
+        let only_042 =
+            filter_session_journal(entries.clone(), Some("TASK-042"), &TimeWindow::default());
+        assert_eq!(only_042.len(), 2);
+        let now = parse_timestamp("2026-03-03 12:00").expect("now");
+        let window = TimeWindow::parse(Some("2d"), None, now).expect("window");
+        let recent = filter_session_journal(entries.clone(), None, &window);
+        assert_eq!(recent.len(), 2);
+        assert_eq!(
+            parse_since("2026-03-02", now),
+            parse_timestamp("2026-03-02 00:00")
+        );
+        assert!(parse_since("soon", now).is_none());
+
+        let markdown = render_session_journal_markdown(&entries);
+        assert!(markdown.contains("## 2026-03-02\n\n- **10:30** `task-007` _Next: review_"));
+        assert!(markdown.contains("- **16:00** `task-042` parser done _Next: ship_"));
+    }
+
+    #[test]
+    fn working_set_status_flags_stale_entries_and_refresh_drops_them() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        let expired = Lease {
+            owner: "agent".to_string(),
+            acquired_at: Some("2026-02-01 10:00".to_string()),
+            expires_at: Some("2026-02-01 11:00".to_string()),
+        };
+        let recorded = [
+            task("task-001", "Active", "In Progress", None, None, None),
+            task("task-002", "Shipped", "In Progress", None, None, None),
+            task("task-003", "Leased", "In Progress", None, None, None),
+            task("task-004", "Gone", "To Do", None, None, None),
+        ];
+        let summaries: Vec<TaskSummary> = recorded.iter().map(task_summary).collect();
+        write_working_set(repo, "p", &summaries, Some("keep going")).expect("working set");
+
+        let tasks = vec![
+            task("task-001", "Active", "In Progress", None, None, None),
+            task("task-002", "Shipped", "Done", None, None, None),
+            task("task-003", "Leased", "To Do", None, None, Some(expired)),
+        ];
+        let status = working_set_status(repo, "p", &tasks)
+            .expect("status")
+            .expect("working set exists");
+        let flags: Vec<Vec<WorkingSetFlag>> = status
+            .entries
+            .iter()
+            .map(|entry| entry.flags.clone())
+            .collect();
+        assert_eq!(
+            flags,
+            vec![
+                vec![],
+                vec![WorkingSetFlag::Closed, WorkingSetFlag::StatusChanged],
+                vec![WorkingSetFlag::LeaseExpired, WorkingSetFlag::StatusChanged],
+                vec![WorkingSetFlag::Missing],
+            ]
+        );
+        assert_eq!(status.stale_count(), 3);
+        assert_eq!(status.note.as_deref(), Some("keep going"));
+
+        refresh_working_set(repo, "p", &status).expect("refresh");
+        let refreshed = working_set_status(repo, "p", &tasks)
+            .expect("status")
+            .expect("working set exists");
+        assert_eq!(refreshed.entries.len(), 1);
+        assert_eq!(refreshed.entries[0].id, "task-001");
+        assert_eq!(refreshed.note.as_deref(), Some("keep going"));
+    }
+
     #[test]
     fn render_resume_and_diff_have_stable_defaults() {
         let snapshot = CheckpointSnapshot {
@@ -1119,6 +1842,7 @@ mod tests {
             current_task: None,
             ready: vec![],
             leases: vec![],
+            due: vec![],
             git: GitSummary {
                 available: false,
                 branch: None,
@@ -1132,16 +1856,28 @@ mod tests {
             changed_files: vec![],
             top_level_dirs: vec![],
             audit_events: vec![],
+            epics: vec![],
+            open_questions: vec![],
+            working_set: vec![],
+            decisions: vec![],
+            edges: None,
         };
         let summary = ResumeSummary {
             snapshot: snapshot.clone(),
             working_set: Some("- x\n".to_string()),
             checkpoint_path: PathBuf::from("checkpoint.json"),
+            journal: vec![JournalEntry {
+                timestamp: "2026-03-01 10:00".to_string(),
+                task_id: Some("task-042".to_string()),
+                next: Some("ship".to_string()),
+                note: None,
+            }],
         };
         let rendered = render_resume(&summary);
         assert!(rendered.contains("Resume from checkpoint x"));
         assert!(rendered.contains("Current task:"));
         assert!(rendered.contains("Working set:"));
+        assert!(rendered.contains("- 2026-03-01 10:00 task-042: (next: ship)"));
 
         let diff = DiffReport {
             checkpoint_id: "x".to_string(),
@@ -1160,4 +1896,80 @@ mod tests {
         assert!(parse_timestamp("2026-02-01 10:00").is_some());
         assert!(parse_timestamp("not-a-time").is_none());
     }
+
+    #[test]
+    fn checkpoint_sections_apply_config_then_flags() {
+        let config = CheckpointConfig {
+            include: vec!["epics".to_string(), "working-set".to_string()],
+            exclude: vec!["audit".to_string()],
+            template: None,
+            triggers: None,
+        };
+        let mut options = CheckpointOptions::new(10);
+        options.include = vec!["audit".to_string()];
+        options.exclude = vec!["git_detail".to_string(), "epics".to_string()];
+        let sections = resolve_checkpoint_sections(&config, &options).expect("sections");
+        assert_eq!(
+            sections,
+            vec![
+                "current_task",
+                "ready",
+                "leases",
+                "due",
+                "git",
+                "audit",
+                "working_set"
+            ]
+        );
+
+        options.include = vec!["nope".to_string()];
+        assert!(matches!(
+            resolve_checkpoint_sections(&config, &options),
+            Err(SessionError::UnknownSection(name)) if name == "nope"
+        ));
+    }
+
+    #[test]
+    fn write_checkpoint_renders_optional_sections_and_templates() {
+        let temp = TempDir::new().expect("tempdir");
+        let repo = temp.path();
+        let backlog = repo.join("workmesh");
+        fs::create_dir_all(&backlog).expect("backlog");
+        let mut epic = task("task-001", "Epic", "In Progress", None, Some("p"), None);
+        epic.kind = "epic".to_string();
+        let mut child = task("task-002", "Child", "Done", None, Some("p"), None);
+        child.relationships.parent = vec!["task-001".to_string()];
+        let open = task("task-003", "Open", "To Do", None, Some("p"), None);
+        let mut other = task("task-004", "Other", "To Do", None, Some("p"), None);
+        other.relationships.parent = vec!["task-001".to_string()];
+        let tasks = vec![epic, child, open, other];
+
+        let mut options = CheckpointOptions::new(5);
+        options.checkpoint_id = Some("sections".to_string());
+        options.include = vec!["epics".to_string()];
+        options.exclude = vec!["git".to_string(), "git_detail".to_string()];
+        let result = write_checkpoint(&backlog, &tasks, &options).expect("checkpoint");
+        let markdown = fs::read_to_string(&result.markdown_path).expect("markdown");
+        assert!(markdown.contains("## Epic Progress\n- task-001 | In Progress | 1/2 done | Epic"));
+        assert!(!markdown.contains("## Git Status"));
+        assert!(!markdown.contains("## Changed Files"));
+        assert_eq!(result.snapshot.epics[0].total, 2);
+
+        fs::write(
+            repo.join("handoff.md"),
+            "# Handoff {{checkpoint_id}}\n\nNow:\n{{current_task}}\n\n{{unknown}}\n",
+        )
+        .expect("template");
+        let mut options = CheckpointOptions::new(5);
+        options.checkpoint_id = Some("templated".to_string());
+        options.template = Some("handoff.md".to_string());
+        let result = write_checkpoint(&backlog, &tasks, &options).expect("checkpoint");
+        let markdown = fs::read_to_string(&result.markdown_path).expect("markdown");
+        assert_eq!(
+            markdown,
+            "# Handoff templated\n\nNow:\n- task-001 | In Progress | P2 | Phase1 | Epic\n\n\
+{{unknown}}\n"
+        );
+        assert!(result.snapshot.epics.is_empty());
+    }
 }
@@ -0,0 +1,201 @@
+//! Break an oversized task into subtasks (`split <task-id> --into "part A" "part B"`).
+//!
+//! Each part becomes a new task that inherits the original's priority, phase, labels,
+//! assignees, and dependencies, and is parented to the original. The original is turned into an
+//! epic that lists the parts as children, so roll-ups and `children` views pick them up.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::health::is_closed;
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_task_file_with_sections, now_timestamp, set_relationship_field, update_task_field,
+    FieldValue, TaskSectionContent,
+};
+
+#[derive(Debug, Error)]
+pub enum SplitError {
+    #[error("Task not found: {0}")]
+    NotFound(String),
+    #[error("Missing task path for {0}")]
+    MissingPath(String),
+    #[error("{0} is already {1}; only open tasks can be split")]
+    Closed(String, String),
+    #[error("Split needs at least one part (--into \"part A\" \"part B\")")]
+    NoParts,
+    #[error("Split parts must have a title")]
+    EmptyTitle,
+    #[error("Task error: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct SplitPart {
+    pub id: String,
+    pub title: String,
+    pub path: PathBuf,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SplitReport {
+    pub task_id: String,
+    pub path: PathBuf,
+    /// Kind the original had before it became an epic.
+    pub previous_kind: String,
+    /// Dependencies copied onto every part.
+    pub dependencies: Vec<String>,
+    pub parts: Vec<SplitPart>,
+}
+
+/// Create one task per entry of `parts` under `task_id` and convert `task_id` into their epic.
+/// `tasks` should include archived tasks so new ids never collide.
+pub fn split_task(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task_id: &str,
+    initiative: &str,
+    parts: &[String],
+) -> Result<SplitReport, SplitError> {
+    let task = tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(task_id.trim()))
+        .ok_or_else(|| SplitError::NotFound(task_id.trim().to_string()))?;
+    let path = task
+        .file_path
+        .clone()
+        .ok_or_else(|| SplitError::MissingPath(task.id.clone()))?;
+    if is_closed(task) {
+        return Err(SplitError::Closed(task.id.clone(), task.status.clone()));
+    }
+    let titles: Vec<String> = parts.iter().map(|part| part.trim().to_string()).collect();
+    if titles.is_empty() {
+        return Err(SplitError::NoParts);
+    }
+    if titles.iter().any(|title| title.is_empty()) {
+        return Err(SplitError::EmptyTitle);
+    }
+
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let mut known: Vec<Task> = tasks.to_vec();
+    let mut created = Vec::new();
+    for title in titles {
+        let id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+        let part_path = create_task_file_with_sections(
+            &tasks_dir,
+            &id,
+            &title,
+            "To Do",
+            &task.priority,
+            &task.phase,
+            &task.dependencies,
+            &task.labels,
+            &task.assignee,
+            &TaskSectionContent {
+                description: format!("Split out of {} ({}).", task.id, task.title),
+                acceptance_criteria: format!("- {}", title),
+                definition_of_done: format!("- {} is delivered as part of {}", title, task.id),
+            },
+        )?;
+        set_relationship_field(&part_path, "parent", vec![task.id.clone()])?;
+        known.push(parse_task_file(&part_path)?);
+        created.push(SplitPart {
+            id,
+            title,
+            path: part_path,
+        });
+    }
+
+    let mut children = task.relationships.child.clone();
+    for part in &created {
+        if !children
+            .iter()
+            .any(|child| child.eq_ignore_ascii_case(&part.id))
+        {
+            children.push(part.id.clone());
+        }
+    }
+    set_relationship_field(&path, "child", children)?;
+    update_task_field(&path, "kind", Some(FieldValue::from("epic")))?;
+    update_task_field(&path, "updated_date", Some(now_timestamp().into()))?;
+
+    Ok(SplitReport {
+        task_id: task.id.clone(),
+        path,
+        previous_kind: task.kind.clone(),
+        dependencies: task.dependencies.clone(),
+        parts: created,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+    use crate::task_ops::{child_tasks, is_epic};
+
+    #[test]
+    fn split_creates_children_with_inherited_fields_and_converts_original() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        fs::write(
+            tasks_dir.join("task-001 - Schema.md"),
+            "---\nid: task-001\ntitle: Schema\nkind: task\nstatus: Done\npriority: P2\nphase: Phase1\ndependencies: []\n---\n",
+        )
+        .expect("write");
+        fs::write(
+            tasks_dir.join("task-002 - Big feature.md"),
+            "---\nid: task-002\ntitle: Big feature\nkind: task\nstatus: In Progress\npriority: P1\nphase: Phase2\ndependencies: [task-001]\nlabels: [api]\nassignee: [alice]\n---\n",
+        )
+        .expect("write");
+        let tasks = load_tasks(&backlog_dir);
+
+        assert!(matches!(
+            split_task(&backlog_dir, &tasks, "task-001", "core", &["x".into()]),
+            Err(SplitError::Closed(..))
+        ));
+        assert!(matches!(
+            split_task(&backlog_dir, &tasks, "task-002", "core", &[]),
+            Err(SplitError::NoParts)
+        ));
+
+        let report = split_task(
+            &backlog_dir,
+            &tasks,
+            "TASK-002",
+            "core",
+            &["Read path".into(), "Write path".into()],
+        )
+        .expect("split");
+        assert_eq!(report.parts.len(), 2);
+        assert_eq!(report.previous_kind, "task");
+
+        let tasks = load_tasks(&backlog_dir);
+        let original = tasks.iter().find(|t| t.id == "task-002").expect("original");
+        assert!(is_epic(original));
+        assert_eq!(original.status, "In Progress");
+        let children = child_tasks(&tasks, "task-002");
+        assert_eq!(children.len(), 2);
+        for child in children {
+            assert_eq!(child.priority, "P1");
+            assert_eq!(child.phase, "Phase2");
+            assert_eq!(child.dependencies, vec!["task-001"]);
+            assert_eq!(child.labels, vec!["api"]);
+            assert_eq!(child.assignee, vec!["alice"]);
+            assert_eq!(child.relationships.parent, vec!["task-002"]);
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::fs::{self, File, OpenOptions};
-use std::io::{self, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::thread;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
@@ -10,7 +11,10 @@ use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use thiserror::Error;
 
 pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Start of every lock timeout message, including after conversion to `io::Error` or text.
+pub const LOCK_TIMEOUT_MESSAGE: &str = "timed out acquiring lock";
 const LOCK_POLL_INTERVAL: Duration = Duration::from_millis(25);
+static LOCK_RETRY: OnceLock<(u32, Duration)> = OnceLock::new();
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct VersionedState<T> {
@@ -206,6 +210,53 @@ pub fn append_jsonl_locked_with_key(
     })
 }
 
+/// Append the line `build` makes from the file's current last line, holding `resource_key`'s lock
+/// across both so concurrent writers cannot slip a line in between.
+pub fn append_line_after_last_with_key<E, F>(
+    path: &Path,
+    resource_key: &ResourceKey,
+    build: F,
+) -> Result<(), E>
+where
+    E: From<StorageError>,
+    F: FnOnce(Option<&str>) -> Result<String, E>,
+{
+    with_resource_lock_result(resource_key, DEFAULT_LOCK_TIMEOUT, || {
+        let last = read_last_line(path).map_err(StorageError::from)?;
+        let line = build(last.as_deref())?;
+        append_line_unchecked(path, &line).map_err(StorageError::from)?;
+        Ok(())
+    })
+}
+
+/// Last non-empty line of `path`, reading only the tail of large files; `None` when the file is
+/// missing or empty.
+pub fn read_last_line(path: &Path) -> io::Result<Option<String>> {
+    const TAIL: u64 = 64 * 1024;
+    let mut file = match File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(err),
+    };
+    let len = file.metadata()?.len();
+    let mut start = len.saturating_sub(TAIL);
+    loop {
+        file.seek(SeekFrom::Start(start))?;
+        let mut bytes = Vec::new();
+        file.read_to_end(&mut bytes)?;
+        let text = String::from_utf8_lossy(&bytes);
+        let trimmed = text.trim_end();
+        match trimmed.rfind('\n') {
+            Some(at) => return Ok(Some(trimmed[at + 1..].to_string())),
+            // The whole file is one line, or the tail window cut the last line: read it all.
+            None if start == 0 => {
+                return Ok(Some(trimmed.to_string()).filter(|line| !line.is_empty()))
+            }
+            None => start = 0,
+        }
+    }
+}
+
 pub fn read_modify_write_json<T, F>(path: &Path, merge_fn: F) -> Result<T, StorageError>
 where
     T: Serialize + DeserializeOwned,
@@ -359,17 +410,39 @@ pub fn truncate_jsonl_trailing_invalid(path: &Path) -> Result<usize, StorageErro
     Ok(removed)
 }
 
+/// Turn on retries for [`with_path_lock`]: after a lock timeout, wait `backoff * n` and try to
+/// acquire the lock again, up to `attempts` more times. Only acquisition is retried, never the
+/// write itself. Off by default; the MCP server enables it so a contended write waits instead
+/// of failing. The first call wins.
+pub fn enable_lock_retry(attempts: u32, backoff: Duration) {
+    let _ = LOCK_RETRY.set((attempts, backoff));
+}
+
 pub fn with_path_lock<T, E, F>(path: &Path, action: F) -> Result<T, E>
 where
     E: From<io::Error>,
     F: FnOnce() -> Result<T, E>,
 {
     let key = ResourceKey::path(path);
-    let _lock = acquire_lock(&key.lock_path(), DEFAULT_LOCK_TIMEOUT)
-        .map_err(|err| E::from(storage_error_to_io(err)))?;
+    let _lock =
+        acquire_lock_retrying(&key.lock_path()).map_err(|err| E::from(storage_error_to_io(err)))?;
     action()
 }
 
+fn acquire_lock_retrying(lock_path: &Path) -> Result<PathLock, StorageError> {
+    let (attempts, backoff) = LOCK_RETRY.get().copied().unwrap_or((0, Duration::ZERO));
+    let mut attempt = 0;
+    loop {
+        match acquire_lock(lock_path, DEFAULT_LOCK_TIMEOUT) {
+            Err(StorageError::LockTimeout { .. }) if attempt < attempts => {
+                attempt += 1;
+                thread::sleep(backoff * attempt);
+            }
+            result => return result,
+        }
+    }
+}
+
 pub fn with_path_lock_io<T, F>(path: &Path, action: F) -> io::Result<T>
 where
     F: FnOnce() -> io::Result<T>,
@@ -598,7 +671,7 @@ fn temp_path(path: &Path) -> PathBuf {
     parent.join(format!(".{}.{}.{}.tmp", stem, pid, nanos))
 }
 
-fn storage_error_to_io(err: StorageError) -> io::Error {
+pub(crate) fn storage_error_to_io(err: StorageError) -> io::Error {
     match err {
         StorageError::Io(err) => err,
         StorageError::Serialize { source, .. }
@@ -612,7 +685,8 @@ fn storage_error_to_io(err: StorageError) -> io::Error {
         } => io::Error::new(
             io::ErrorKind::TimedOut,
             format!(
-                "timed out acquiring lock {} after {}ms",
+                "{} {} after {}ms",
+                LOCK_TIMEOUT_MESSAGE,
                 lock_path.display(),
                 timeout_ms
             ),
@@ -621,6 +695,23 @@ fn storage_error_to_io(err: StorageError) -> io::Error {
     }
 }
 
+/// True when `err` (or anything in its source chain) is a lock timeout. Adapters use this to
+/// retry a write that lost the race for the advisory lock.
+pub fn is_lock_timeout(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut current = Some(err);
+    while let Some(err) = current {
+        if matches!(
+            err.downcast_ref::<StorageError>(),
+            Some(StorageError::LockTimeout { .. })
+        ) || err.to_string().contains(LOCK_TIMEOUT_MESSAGE)
+        {
+            return true;
+        }
+        current = err.source();
+    }
+    false
+}
+
 fn now_rfc3339() -> String {
     let now: DateTime<Utc> = Utc::now();
     now.to_rfc3339()
@@ -772,6 +863,10 @@ mod tests {
         barrier.wait();
         let contender = with_resource_lock(&key, Duration::from_millis(20), || Ok(()));
         assert!(matches!(contender, Err(StorageError::LockTimeout { .. })));
+        let err = contender.expect_err("timeout");
+        assert!(is_lock_timeout(&err));
+        assert!(is_lock_timeout(&storage_error_to_io(err)));
+        assert!(!is_lock_timeout(&io::Error::other("disk full")));
 
         holder.join().expect("join holder");
     }
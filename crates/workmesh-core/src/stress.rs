@@ -0,0 +1,264 @@
+//! `workmesh stress`: concurrent writers against a scratch backlog, to check the write model that
+//! lets the CLI and MCP server share a repo.
+//!
+//! Every writer adds its own labels to a small set of shared tasks through [`edit_list_field`],
+//! appends a note through [`append_task_note`], touches `updated_date`, and appends an audit
+//! event. Afterwards each task file must still parse and hold every label and note that was
+//! written, and the audit log must have one event per write.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::audit::{append_audit_event, read_audit_events, AuditEvent};
+use crate::storage::write_string_atomic_locked;
+use crate::task::parse_task_file;
+use crate::task_ops::{append_task_note, edit_list_field, now_timestamp, update_task_field};
+
+pub const DEFAULT_STRESS_WRITERS: usize = 4;
+pub const DEFAULT_STRESS_ITERATIONS: usize = 25;
+
+/// Shared tasks the writers contend on.
+const STRESS_TASKS: usize = 2;
+
+#[derive(Debug, Error)]
+pub enum StressError {
+    #[error("--writers and --iterations must be at least 1")]
+    InvalidOptions,
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StressReport {
+    /// Fresh directory the scratch backlog was seeded in, inside the requested work dir.
+    pub scratch_dir: PathBuf,
+    pub writers: usize,
+    pub iterations: usize,
+    pub writes: usize,
+    pub elapsed_ms: u128,
+    /// Writes that failed outright (for example a lock timeout), as `w<writer>-<iteration>: error`.
+    pub errors: Vec<String>,
+    /// Labels or notes that were written but are missing afterwards, as `task-id: label` or
+    /// `task-id: note <label>`.
+    pub lost_updates: Vec<String>,
+    /// Task files that no longer parse, with the parse error.
+    pub corrupted: Vec<String>,
+    pub audit_events: usize,
+}
+
+impl StressReport {
+    pub fn ok(&self) -> bool {
+        self.errors.is_empty()
+            && self.lost_updates.is_empty()
+            && self.corrupted.is_empty()
+            && self.audit_events == self.writes
+    }
+}
+
+/// Seed a scratch backlog in a new, uniquely named directory inside `work_dir` and run `writers` threads of `iterations` writes each.
+/// Writers open their own lock handles, so they contend exactly like separate processes.
+pub fn run_stress(
+    work_dir: &Path,
+    writers: usize,
+    iterations: usize,
+) -> Result<StressReport, StressError> {
+    if writers == 0 || iterations == 0 {
+        return Err(StressError::InvalidOptions);
+    }
+    // Never reuse or clear anything already in `work_dir`: it may be a real repo.
+    fs::create_dir_all(work_dir)?;
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos())
+        .unwrap_or_default();
+    let scratch_dir = work_dir.join(format!("workmesh-stress-{}-{}", std::process::id(), nanos));
+    fs::create_dir(&scratch_dir)?;
+    let backlog_dir = scratch_dir.join("backlog");
+    let tasks_dir = backlog_dir.join("tasks");
+    let mut paths = Vec::new();
+    for index in 1..=STRESS_TASKS {
+        let id = format!("task-{:03}", index);
+        let path = tasks_dir.join(format!("{} - stress.md", id));
+        write_string_atomic_locked(
+            &path,
+            &format!(
+                "---\nid: {}\ntitle: Stress target {}\nkind: task\nstatus: To Do\npriority: P2\nphase: Phase1\ndependencies: []\nlabels: []\n---\n\nDescription:\n- Concurrent write target\n",
+                id, index
+            ),
+        )?;
+        paths.push((id, path));
+    }
+
+    let started = Instant::now();
+    let handles: Vec<_> = (0..writers)
+        .map(|writer| {
+            let backlog_dir = backlog_dir.clone();
+            let paths = paths.clone();
+            thread::spawn(move || {
+                let mut errors = Vec::new();
+                for iteration in 0..iterations {
+                    let (id, path) = &paths[(writer + iteration) % paths.len()];
+                    let label = stress_label(writer, iteration);
+                    if let Err(err) = stress_write(&backlog_dir, id, path, &label) {
+                        errors.push(format!("{}: {}", label, err));
+                    }
+                }
+                errors
+            })
+        })
+        .collect();
+    let mut errors = Vec::new();
+    for handle in handles {
+        match handle.join() {
+            Ok(writer_errors) => errors.extend(writer_errors),
+            Err(_) => errors.push("writer thread panicked".to_string()),
+        }
+    }
+    let elapsed_ms = started.elapsed().as_millis();
+
+    let mut lost_updates = Vec::new();
+    let mut corrupted = Vec::new();
+    for (index, (id, path)) in paths.iter().enumerate() {
+        let task = match parse_task_file(path) {
+            Ok(task) => task,
+            Err(err) => {
+                corrupted.push(format!("{}: {}", path.display(), err));
+                continue;
+            }
+        };
+        let labels: BTreeSet<&str> = task.labels.iter().map(String::as_str).collect();
+        for writer in 0..writers {
+            for iteration in 0..iterations {
+                if (writer + iteration) % paths.len() != index {
+                    continue;
+                }
+                let label = stress_label(writer, iteration);
+                let failed = errors
+                    .iter()
+                    .any(|error| error.starts_with(&format!("{}:", label)));
+                if failed {
+                    continue;
+                }
+                if !labels.contains(label.as_str()) {
+                    lost_updates.push(format!("{}: {}", id, label));
+                }
+                if !task
+                    .body
+                    .lines()
+                    .any(|line| line.trim() == format!("- {}", stress_note(&label)))
+                {
+                    lost_updates.push(format!("{}: note {}", id, label));
+                }
+            }
+        }
+    }
+
+    Ok(StressReport {
+        scratch_dir,
+        writers,
+        iterations,
+        writes: writers * iterations,
+        elapsed_ms,
+        errors,
+        lost_updates,
+        corrupted,
+        audit_events: read_audit_events(&backlog_dir).len(),
+    })
+}
+
+pub fn render_stress_report(report: &StressReport) -> String {
+    let mut lines = vec![
+        format!(
+            "Stress: {} writers x {} writes ({} total) in {}ms",
+            report.writers, report.iterations, report.writes, report.elapsed_ms
+        ),
+        format!("Failed writes: {}", report.errors.len()),
+        format!("Lost updates: {}", report.lost_updates.len()),
+        format!("Corrupted files: {}", report.corrupted.len()),
+        format!("Audit events: {}/{}", report.audit_events, report.writes),
+    ];
+    for entry in report
+        .errors
+        .iter()
+        .chain(&report.lost_updates)
+        .chain(&report.corrupted)
+    {
+        lines.push(format!("- {}", entry));
+    }
+    if report.ok() {
+        lines.push("OK".to_string());
+    } else {
+        lines.push(format!(
+            "FAILED (scratch backlog kept in {})",
+            report.scratch_dir.display()
+        ));
+    }
+    lines.join("\n") + "\n"
+}
+
+fn stress_label(writer: usize, iteration: usize) -> String {
+    format!("w{}-{}", writer, iteration)
+}
+
+fn stress_note(label: &str) -> String {
+    format!("stress note {}", label)
+}
+
+fn stress_write(
+    backlog_dir: &Path,
+    id: &str,
+    path: &Path,
+    label: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    edit_list_field(path, "labels", label, true)?;
+    append_task_note(path, &stress_note(label), "notes")?;
+    update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+    append_audit_event(
+        backlog_dir,
+        &AuditEvent {
+            timestamp: now_timestamp(),
+            actor: Some("stress".to_string()),
+            action: "stress_write".to_string(),
+            task_id: Some(id.to_string()),
+            details: serde_json::json!({ "label": label }),
+        },
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    #[test]
+    fn concurrent_writers_lose_no_updates() {
+        let temp = TempDir::new().expect("tempdir");
+        let existing = temp.path().join("backlog").join("keep.md");
+        fs::create_dir_all(existing.parent().unwrap()).expect("backlog");
+        fs::write(&existing, "real backlog").expect("write");
+
+        let report = run_stress(temp.path(), 4, 10).expect("stress");
+        assert!(report.ok(), "{:?}", report);
+        assert_eq!(report.writes, 40);
+        assert_eq!(report.audit_events, 40);
+        let rendered = render_stress_report(&report);
+        assert!(rendered.contains("Lost updates: 0"));
+        assert!(rendered.ends_with("OK\n"));
+        assert!(report.scratch_dir.starts_with(temp.path()));
+        assert_ne!(report.scratch_dir, temp.path());
+        assert_eq!(fs::read_to_string(&existing).expect("read"), "real backlog");
+
+        assert!(matches!(
+            run_stress(temp.path(), 0, 10),
+            Err(StressError::InvalidOptions)
+        ));
+    }
+}
@@ -0,0 +1,3 @@
+//! Two-way bridges to external issue trackers.
+
+pub mod github;
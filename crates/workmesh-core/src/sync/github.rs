@@ -0,0 +1,978 @@
+//! GitHub Issues bridge: `github export` creates and updates issues from tasks, `github import`
+//! creates and updates tasks from issues.
+//!
+//! A linked task stores its issue number in `github_issue` and, in `github_sync`, a hash of the
+//! title/status/labels/assignees both sides agreed on at the last sync. Comparing each side
+//! against that hash tells which one changed since; when both did, the pair is a conflict and is
+//! reported instead of overwritten (unless forced).
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::confidential::is_confidential_task;
+use crate::config::{load_config, load_global_config};
+use crate::health::{first_extra, is_closed};
+use crate::inbox::normalize_user;
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_task_file_with_sections, now_timestamp, set_list_field, update_task_field, FieldValue,
+    TaskSectionContent,
+};
+
+/// Front matter key holding the linked issue number.
+pub const GITHUB_ISSUE_FIELD: &str = "github_issue";
+/// Front matter key holding the hash of the fields both sides agreed on at the last sync.
+pub const GITHUB_SYNC_FIELD: &str = "github_sync";
+
+const DEFAULT_API_URL: &str = "https://api.github.com";
+const DEFAULT_TOKEN_ENV: &str = "GITHUB_TOKEN";
+const DEFAULT_STATUS_PREFIX: &str = "status:";
+const DEFAULT_PHASE: &str = "Phase1";
+const DEFAULT_PRIORITY: &str = "P2";
+const DEFAULT_TIMEOUT_MS: u64 = 30_000;
+const PAGE_SIZE: usize = 100;
+/// Issue body used instead of the body of a `confidential: true` task.
+const CONFIDENTIAL_ISSUE_BODY: &str = "Details are kept in the confidential WorkMesh task.";
+
+#[derive(Debug, Error)]
+pub enum GithubError {
+    #[error("GitHub sync misconfigured: {0}")]
+    Config(String),
+    #[error("GitHub request to {url} failed: {message}")]
+    Request { url: String, message: String },
+    #[error("Failed to update task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to reserve task id: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// `[github]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GithubConfig {
+    /// `owner/name`; defaults to the `origin` remote when it points at GitHub.
+    pub repo: Option<String>,
+    /// REST API base (default `https://api.github.com`; set it for GitHub Enterprise).
+    pub api_url: Option<String>,
+    /// Environment variable holding the token (default `GITHUB_TOKEN`).
+    pub token_env: Option<String>,
+    /// Prefix of the labels that carry open statuses other than `To Do` (default `status:`).
+    pub status_label_prefix: Option<String>,
+    /// Phase for imported tasks (default `Phase1`).
+    pub phase: Option<String>,
+    pub timeout_ms: Option<u64>,
+}
+
+impl GithubConfig {
+    pub fn status_prefix(&self) -> &str {
+        self.status_label_prefix
+            .as_deref()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or(DEFAULT_STATUS_PREFIX)
+    }
+
+    pub fn token_env(&self) -> &str {
+        self.token_env
+            .as_deref()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or(DEFAULT_TOKEN_ENV)
+    }
+}
+
+/// Project `[github]` wins over global `[github]`.
+pub fn resolve_github_config(repo_root: &Path) -> GithubConfig {
+    load_config(repo_root)
+        .and_then(|config| config.github)
+        .or_else(|| load_global_config().and_then(|config| config.github))
+        .unwrap_or_default()
+}
+
+/// `owner/name` from a GitHub remote URL (`https://github.com/o/n.git`, `git@github.com:o/n`).
+pub fn parse_github_remote(url: &str) -> Option<String> {
+    let url = url.trim();
+    let path = url
+        .strip_prefix("git@github.com:")
+        .or_else(|| url.strip_prefix("ssh://git@github.com/"))
+        .or_else(|| url.strip_prefix("https://github.com/"))
+        .or_else(|| url.strip_prefix("http://github.com/"))?;
+    let path = path.trim_end_matches('/').trim_end_matches(".git");
+    let (owner, name) = path.split_once('/')?;
+    (!owner.is_empty() && !name.is_empty() && !name.contains('/'))
+        .then(|| format!("{}/{}", owner, name))
+}
+
+/// Best-effort `owner/name` of the `origin` remote.
+pub fn detect_github_repo(repo_root: &Path) -> Option<String> {
+    Command::new("git")
+        .arg("-C")
+        .arg(repo_root)
+        .args(["remote", "get-url", "origin"])
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| parse_github_remote(&String::from_utf8_lossy(&out.stdout)))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct GithubIssue {
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    /// `open` or `closed`.
+    pub state: String,
+    /// `completed` or `not_planned` for closed issues.
+    pub state_reason: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+    pub html_url: Option<String>,
+}
+
+impl GithubIssue {
+    /// Parse a REST issue object; `None` for pull requests, which share the issues endpoint.
+    pub fn from_json(value: &Value) -> Option<Self> {
+        if value.get("pull_request").is_some() {
+            return None;
+        }
+        let names = |key: &str, field: &str| -> Vec<String> {
+            value[key]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .filter_map(|item| item[field].as_str().or_else(|| item.as_str()))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Some(Self {
+            number: value["number"].as_u64()?,
+            title: value["title"].as_str().unwrap_or_default().to_string(),
+            body: value["body"].as_str().unwrap_or_default().to_string(),
+            state: value["state"].as_str().unwrap_or("open").to_string(),
+            state_reason: value["state_reason"].as_str().map(str::to_string),
+            labels: names("labels", "name"),
+            assignees: names("assignees", "login"),
+            html_url: value["html_url"].as_str().map(str::to_string),
+        })
+    }
+}
+
+/// The synced part of an issue. Labels include the status label of open, non-`To Do` tasks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IssueFields {
+    pub title: String,
+    pub state: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_reason: Option<String>,
+    pub labels: Vec<String>,
+    pub assignees: Vec<String>,
+}
+
+impl IssueFields {
+    /// Case-insensitive, order-insensitive form used for hashing and comparison.
+    fn normalized(&self) -> IssueFields {
+        let sorted = |values: &[String]| {
+            let mut values: Vec<String> = values
+                .iter()
+                .map(|value| value.trim().to_lowercase())
+                .collect();
+            values.sort();
+            values.dedup();
+            values
+        };
+        IssueFields {
+            title: self.title.trim().to_string(),
+            state: self.state.clone(),
+            state_reason: self.state_reason.clone(),
+            labels: sorted(&self.labels),
+            assignees: sorted(&self.assignees),
+        }
+    }
+
+    pub fn sync_hash(&self) -> String {
+        let encoded = serde_json::to_string(&self.normalized()).unwrap_or_default();
+        let digest = Sha256::digest(encoded.as_bytes());
+        digest[..8]
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    /// Names of the fields that differ: `title`, `status`, `labels`, `assignees`.
+    pub fn diff(&self, other: &IssueFields) -> Vec<String> {
+        let (a, b) = (self.normalized(), other.normalized());
+        let mut fields = Vec::new();
+        if a.title != b.title {
+            fields.push("title".to_string());
+        }
+        if a.state != b.state || a.state_reason != b.state_reason {
+            fields.push("status".to_string());
+        }
+        if a.labels != b.labels {
+            fields.push("labels".to_string());
+        }
+        if a.assignees != b.assignees {
+            fields.push("assignees".to_string());
+        }
+        fields
+    }
+}
+
+/// The GitHub REST calls the bridge needs, so tests can swap in a fake.
+pub trait GithubApi {
+    /// Every issue in the repo, open and closed, without pull requests.
+    fn list_issues(&self) -> Result<Vec<GithubIssue>, GithubError>;
+    fn create_issue(&self, fields: &IssueFields, body: &str) -> Result<GithubIssue, GithubError>;
+    fn update_issue(&self, number: u64, fields: &IssueFields) -> Result<GithubIssue, GithubError>;
+}
+
+pub struct HttpGithubApi {
+    pub api_url: String,
+    pub repo: String,
+    pub token: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl HttpGithubApi {
+    /// Reads the token from `token_env`; writes fail without one.
+    pub fn new(config: &GithubConfig, repo: &str) -> Self {
+        let timeout = Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS));
+        Self {
+            api_url: config
+                .api_url
+                .clone()
+                .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            repo: repo.to_string(),
+            token: std::env::var(config.token_env())
+                .ok()
+                .filter(|token| !token.trim().is_empty()),
+            agent: ureq::AgentBuilder::new().timeout(timeout).build(),
+        }
+    }
+
+    fn send(
+        &self,
+        method: &str,
+        path: &str,
+        payload: Option<&Value>,
+    ) -> Result<Value, GithubError> {
+        let url = format!("{}/repos/{}{}", self.api_url, self.repo, path);
+        let request_error = |message: String| GithubError::Request {
+            url: url.clone(),
+            message,
+        };
+        let mut request = self
+            .agent
+            .request(method, &url)
+            .set("Accept", "application/vnd.github+json")
+            .set("X-GitHub-Api-Version", "2022-11-28")
+            .set("User-Agent", "workmesh");
+        if let Some(token) = self.token.as_deref() {
+            request = request.set("Authorization", &format!("Bearer {}", token));
+        }
+        let response = match payload {
+            Some(payload) => request
+                .set("Content-Type", "application/json")
+                .send_string(&payload.to_string()),
+            None => request.call(),
+        };
+        let body = response
+            .map_err(|err| request_error(err.to_string()))?
+            .into_string()
+            .map_err(|err| request_error(err.to_string()))?;
+        serde_json::from_str(&body).map_err(|err| request_error(err.to_string()))
+    }
+
+    fn issue_from(&self, value: &Value) -> Result<GithubIssue, GithubError> {
+        GithubIssue::from_json(value).ok_or_else(|| GithubError::Request {
+            url: format!("{}/repos/{}/issues", self.api_url, self.repo),
+            message: "response is not an issue".to_string(),
+        })
+    }
+}
+
+impl GithubApi for HttpGithubApi {
+    fn list_issues(&self) -> Result<Vec<GithubIssue>, GithubError> {
+        let mut issues = Vec::new();
+        for page in 1.. {
+            let path = format!("/issues?state=all&per_page={}&page={}", PAGE_SIZE, page);
+            let items = self.send("GET", &path, None)?;
+            let items = items.as_array().cloned().unwrap_or_default();
+            issues.extend(items.iter().filter_map(GithubIssue::from_json));
+            if items.len() < PAGE_SIZE {
+                break;
+            }
+        }
+        Ok(issues)
+    }
+
+    fn create_issue(&self, fields: &IssueFields, body: &str) -> Result<GithubIssue, GithubError> {
+        let payload = serde_json::json!({
+            "title": fields.title,
+            "body": body,
+            "labels": fields.labels,
+            "assignees": fields.assignees,
+        });
+        let created = self.issue_from(&self.send("POST", "/issues", Some(&payload))?)?;
+        // New issues always start open; close them in a second call.
+        if fields.state == "closed" {
+            return self.update_issue(created.number, fields);
+        }
+        Ok(created)
+    }
+
+    fn update_issue(&self, number: u64, fields: &IssueFields) -> Result<GithubIssue, GithubError> {
+        let payload = serde_json::json!({
+            "title": fields.title,
+            "state": fields.state,
+            "state_reason": fields.state_reason,
+            "labels": fields.labels,
+            "assignees": fields.assignees,
+        });
+        let path = format!("/issues/{}", number);
+        self.issue_from(&self.send("PATCH", &path, Some(&payload))?)
+    }
+}
+
+/// How a task maps onto an issue. Done and Cancelled close the issue (`completed` /
+/// `not_planned`); any other status except `To Do` becomes a `status:<slug>` label.
+pub fn task_issue_fields(task: &Task, config: &GithubConfig) -> IssueFields {
+    let prefix = config.status_prefix();
+    let status = task.status.trim();
+    let mut labels: Vec<String> = task
+        .labels
+        .iter()
+        .filter(|label| !has_prefix(label, prefix))
+        .cloned()
+        .collect();
+    let (state, state_reason) = if status.eq_ignore_ascii_case("done") {
+        ("closed", Some("completed"))
+    } else if is_closed(task) {
+        ("closed", Some("not_planned"))
+    } else {
+        if !status.is_empty() && !status.eq_ignore_ascii_case("to do") {
+            labels.push(format!(
+                "{}{}",
+                prefix,
+                status.to_lowercase().replace(' ', "-")
+            ));
+        }
+        ("open", None)
+    };
+    IssueFields {
+        title: task.title.trim().to_string(),
+        state: state.to_string(),
+        state_reason: state_reason.map(str::to_string),
+        labels,
+        assignees: task
+            .assignee
+            .iter()
+            .map(|assignee| assignee.trim().trim_start_matches('@').to_string())
+            .filter(|assignee| !assignee.is_empty())
+            .collect(),
+    }
+}
+
+pub fn issue_fields(issue: &GithubIssue) -> IssueFields {
+    let closed = issue.state.eq_ignore_ascii_case("closed");
+    IssueFields {
+        title: issue.title.trim().to_string(),
+        state: if closed { "closed" } else { "open" }.to_string(),
+        // Issues closed before `state_reason` existed report none; treat them as completed.
+        state_reason: closed.then(|| {
+            if issue.state_reason.as_deref() == Some("not_planned") {
+                "not_planned".to_string()
+            } else {
+                "completed".to_string()
+            }
+        }),
+        labels: issue.labels.clone(),
+        assignees: issue.assignees.clone(),
+    }
+}
+
+/// Task status for an issue: the inverse of [`task_issue_fields`].
+pub fn issue_status(issue: &GithubIssue, config: &GithubConfig) -> String {
+    let fields = issue_fields(issue);
+    match fields.state_reason.as_deref() {
+        Some("not_planned") => return "Cancelled".to_string(),
+        Some(_) => return "Done".to_string(),
+        None => {}
+    }
+    let prefix = config.status_prefix();
+    issue
+        .labels
+        .iter()
+        .find(|label| has_prefix(label, prefix))
+        .map(|label| {
+            label[prefix.len()..]
+                .split(['-', ' '])
+                .filter(|word| !word.is_empty())
+                .map(capitalize)
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .filter(|status| !status.is_empty())
+        .unwrap_or_else(|| "To Do".to_string())
+}
+
+fn has_prefix(label: &str, prefix: &str) -> bool {
+    label.len() > prefix.len()
+        && label.is_char_boundary(prefix.len())
+        && label[..prefix.len()].eq_ignore_ascii_case(prefix)
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+pub fn linked_issue(task: &Task) -> Option<u64> {
+    first_extra(task, &[GITHUB_ISSUE_FIELD])
+        .and_then(|value| value.trim_start_matches('#').parse().ok())
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GithubSyncItem {
+    pub task_id: Option<String>,
+    pub issue: Option<u64>,
+    pub title: String,
+    /// Fields that differ between the task and the issue.
+    pub fields: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GithubSyncReport {
+    /// `export` or `import`.
+    pub direction: String,
+    pub repo: String,
+    pub apply: bool,
+    pub created: Vec<GithubSyncItem>,
+    pub updated: Vec<GithubSyncItem>,
+    pub unchanged: usize,
+    /// Changed only on the destination side; the opposite direction picks these up.
+    pub stale: Vec<GithubSyncItem>,
+    /// Changed on both sides since the last sync (or linked to a missing issue); left alone
+    /// unless forced.
+    pub conflicts: Vec<GithubSyncItem>,
+}
+
+enum Drift {
+    InSync,
+    /// Only the task changed since the last sync.
+    Task,
+    /// Only the issue changed since the last sync.
+    Issue,
+    Both,
+}
+
+fn drift(task_fields: &IssueFields, issue: &IssueFields, stored: Option<&str>) -> Drift {
+    let (local, remote) = (task_fields.sync_hash(), issue.sync_hash());
+    if local == remote {
+        return Drift::InSync;
+    }
+    match stored {
+        Some(stored) if stored == remote => Drift::Task,
+        Some(stored) if stored == local => Drift::Issue,
+        _ => Drift::Both,
+    }
+}
+
+fn item(
+    task: Option<&Task>,
+    issue: Option<u64>,
+    title: &str,
+    fields: Vec<String>,
+) -> GithubSyncItem {
+    GithubSyncItem {
+        task_id: task.map(|task| task.id.clone()),
+        issue,
+        title: title.to_string(),
+        fields,
+    }
+}
+
+fn write_link(path: &Path, number: u64, hash: &str) -> Result<(), TaskParseError> {
+    update_task_field(
+        path,
+        GITHUB_ISSUE_FIELD,
+        Some(FieldValue::Scalar(number.to_string())),
+    )?;
+    update_task_field(
+        path,
+        GITHUB_SYNC_FIELD,
+        Some(FieldValue::Scalar(hash.into())),
+    )
+}
+
+/// Issue body for a new issue. Confidential tasks never leave the repo in plaintext (nor as the
+/// sealed blob), so their issues get a placeholder.
+fn issue_body(task: &Task) -> String {
+    if is_confidential_task(task) {
+        return CONFIDENTIAL_ISSUE_BODY.to_string();
+    }
+    task.body.trim().to_string()
+}
+
+/// Push tasks to GitHub. Dry-run unless `apply`. Open unlinked tasks become new issues (closed
+/// ones are left out); linked tasks that changed since the last sync update their issue. The
+/// task body becomes the issue body only on creation (a placeholder for confidential tasks).
+pub fn export_github(
+    tasks: &[Task],
+    api: &dyn GithubApi,
+    config: &GithubConfig,
+    repo: &str,
+    apply: bool,
+    force: bool,
+) -> Result<GithubSyncReport, GithubError> {
+    let issues: HashMap<u64, GithubIssue> = api
+        .list_issues()?
+        .into_iter()
+        .map(|issue| (issue.number, issue))
+        .collect();
+    let mut report = GithubSyncReport {
+        direction: "export".to_string(),
+        repo: repo.to_string(),
+        apply,
+        ..GithubSyncReport::default()
+    };
+    for task in tasks {
+        let fields = task_issue_fields(task, config);
+        let Some(number) = linked_issue(task) else {
+            if is_closed(task) {
+                continue;
+            }
+            let mut entry = item(Some(task), None, &task.title, Vec::new());
+            if let (true, Some(path)) = (apply, task.file_path.as_deref()) {
+                let created = api.create_issue(&fields, &issue_body(task))?;
+                write_link(path, created.number, &issue_fields(&created).sync_hash())?;
+                entry.issue = Some(created.number);
+            }
+            report.created.push(entry);
+            continue;
+        };
+        let Some(issue) = issues.get(&number) else {
+            report.conflicts.push(item(
+                Some(task),
+                Some(number),
+                &task.title,
+                vec!["missing".to_string()],
+            ));
+            continue;
+        };
+        let remote = issue_fields(issue);
+        let stored = first_extra(task, &[GITHUB_SYNC_FIELD]);
+        let entry = item(Some(task), Some(number), &task.title, fields.diff(&remote));
+        match drift(&fields, &remote, stored.as_deref()) {
+            Drift::InSync => {
+                report.unchanged += 1;
+                refresh_sync_hash(task, &remote, stored.as_deref(), apply)?;
+                continue;
+            }
+            Drift::Task => {}
+            Drift::Both if force => {}
+            Drift::Issue => {
+                report.stale.push(entry);
+                continue;
+            }
+            Drift::Both => {
+                report.conflicts.push(entry);
+                continue;
+            }
+        }
+        if let (true, Some(path)) = (apply, task.file_path.as_deref()) {
+            let updated = api.update_issue(number, &fields)?;
+            write_link(path, number, &issue_fields(&updated).sync_hash())?;
+        }
+        report.updated.push(entry);
+    }
+    Ok(report)
+}
+
+/// Pull issues into the backlog. Dry-run unless `apply`. Open issues without a task become new
+/// tasks (closed ones are left out); linked issues that changed since the last sync update the
+/// task's title, status, labels, and assignees.
+#[allow(clippy::too_many_arguments)]
+pub fn import_github(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    api: &dyn GithubApi,
+    config: &GithubConfig,
+    repo: &str,
+    initiative: &str,
+    apply: bool,
+    force: bool,
+) -> Result<GithubSyncReport, GithubError> {
+    let by_issue: BTreeMap<u64, &Task> = tasks
+        .iter()
+        .filter_map(|task| linked_issue(task).map(|number| (number, task)))
+        .collect();
+    let mut report = GithubSyncReport {
+        direction: "import".to_string(),
+        repo: repo.to_string(),
+        apply,
+        ..GithubSyncReport::default()
+    };
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let mut known: Vec<Task> = tasks.to_vec();
+    let mut issues = api.list_issues()?;
+    issues.sort_by_key(|issue| issue.number);
+    for issue in &issues {
+        let remote = issue_fields(issue);
+        let Some(task) = by_issue.get(&issue.number).copied() else {
+            if remote.state == "closed" {
+                continue;
+            }
+            let mut entry = item(None, Some(issue.number), &issue.title, Vec::new());
+            if apply {
+                let task_id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+                let path = create_task_file_with_sections(
+                    &tasks_dir,
+                    &task_id,
+                    issue.title.trim(),
+                    &issue_status(issue, config),
+                    DEFAULT_PRIORITY,
+                    config.phase.as_deref().unwrap_or(DEFAULT_PHASE),
+                    &[],
+                    &task_labels(issue, config),
+                    &task_assignees(issue),
+                    &import_sections(issue),
+                )?;
+                write_link(&path, issue.number, &remote.sync_hash())?;
+                known.push(parse_task_file(&path)?);
+                entry.task_id = Some(task_id);
+            }
+            report.created.push(entry);
+            continue;
+        };
+        let fields = task_issue_fields(task, config);
+        let stored = first_extra(task, &[GITHUB_SYNC_FIELD]);
+        let entry = item(
+            Some(task),
+            Some(issue.number),
+            &issue.title,
+            fields.diff(&remote),
+        );
+        match drift(&fields, &remote, stored.as_deref()) {
+            Drift::InSync => {
+                report.unchanged += 1;
+                refresh_sync_hash(task, &remote, stored.as_deref(), apply)?;
+                continue;
+            }
+            Drift::Issue => {}
+            Drift::Both if force => {}
+            Drift::Task => {
+                report.stale.push(entry);
+                continue;
+            }
+            Drift::Both => {
+                report.conflicts.push(entry);
+                continue;
+            }
+        }
+        if let (true, Some(path)) = (apply, task.file_path.as_deref()) {
+            update_task_field(
+                path,
+                "title",
+                Some(FieldValue::Scalar(issue.title.trim().into())),
+            )?;
+            update_task_field(
+                path,
+                "status",
+                Some(FieldValue::Scalar(issue_status(issue, config))),
+            )?;
+            set_list_field(path, "labels", task_labels(issue, config))?;
+            set_list_field(path, "assignee", task_assignees(issue))?;
+            update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+            write_link(path, issue.number, &remote.sync_hash())?;
+        }
+        report.updated.push(entry);
+    }
+    Ok(report)
+}
+
+/// Both sides already match; record that so a later edit is attributed to the right side.
+fn refresh_sync_hash(
+    task: &Task,
+    remote: &IssueFields,
+    stored: Option<&str>,
+    apply: bool,
+) -> Result<(), TaskParseError> {
+    let hash = remote.sync_hash();
+    match (apply, task.file_path.as_deref()) {
+        (true, Some(path)) if stored != Some(hash.as_str()) => {
+            update_task_field(path, GITHUB_SYNC_FIELD, Some(FieldValue::Scalar(hash)))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn task_labels(issue: &GithubIssue, config: &GithubConfig) -> Vec<String> {
+    let prefix = config.status_prefix();
+    issue
+        .labels
+        .iter()
+        .filter(|label| !has_prefix(label, prefix))
+        .cloned()
+        .collect()
+}
+
+fn task_assignees(issue: &GithubIssue) -> Vec<String> {
+    issue
+        .assignees
+        .iter()
+        .filter(|login| !normalize_user(login).is_empty())
+        .cloned()
+        .collect()
+}
+
+fn import_sections(issue: &GithubIssue) -> TaskSectionContent {
+    let title = issue.title.trim();
+    let body = issue.body.trim();
+    let source = match issue.html_url.as_deref() {
+        Some(url) => format!("Imported from GitHub issue #{} ({}).", issue.number, url),
+        None => format!("Imported from GitHub issue #{}.", issue.number),
+    };
+    TaskSectionContent {
+        description: format!(
+            "{}\n\n{}",
+            if body.is_empty() { title } else { body },
+            source
+        ),
+        acceptance_criteria: format!("- {}", title),
+        definition_of_done: "- Outcome verified and task marked Done.".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::cell::RefCell;
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    /// In-memory repo: issues are numbered from 1 in creation order.
+    #[derive(Default)]
+    struct FakeGithub {
+        issues: RefCell<Vec<GithubIssue>>,
+    }
+
+    impl FakeGithub {
+        fn edit(&self, number: u64, change: impl FnOnce(&mut GithubIssue)) {
+            let mut issues = self.issues.borrow_mut();
+            change(
+                issues
+                    .iter_mut()
+                    .find(|i| i.number == number)
+                    .expect("issue"),
+            );
+        }
+
+        fn apply(issue: &mut GithubIssue, fields: &IssueFields) {
+            issue.title = fields.title.clone();
+            issue.state = fields.state.clone();
+            issue.state_reason = fields.state_reason.clone();
+            issue.labels = fields.labels.clone();
+            issue.assignees = fields.assignees.clone();
+        }
+    }
+
+    impl GithubApi for FakeGithub {
+        fn list_issues(&self) -> Result<Vec<GithubIssue>, GithubError> {
+            Ok(self.issues.borrow().clone())
+        }
+
+        fn create_issue(
+            &self,
+            fields: &IssueFields,
+            body: &str,
+        ) -> Result<GithubIssue, GithubError> {
+            let mut issues = self.issues.borrow_mut();
+            let mut issue = GithubIssue {
+                number: issues.len() as u64 + 1,
+                title: String::new(),
+                body: body.to_string(),
+                state: "open".to_string(),
+                state_reason: None,
+                labels: Vec::new(),
+                assignees: Vec::new(),
+                html_url: None,
+            };
+            Self::apply(&mut issue, fields);
+            issues.push(issue.clone());
+            Ok(issue)
+        }
+
+        fn update_issue(
+            &self,
+            number: u64,
+            fields: &IssueFields,
+        ) -> Result<GithubIssue, GithubError> {
+            let mut issues = self.issues.borrow_mut();
+            let issue = issues
+                .iter_mut()
+                .find(|issue| issue.number == number)
+                .expect("issue");
+            Self::apply(issue, fields);
+            Ok(issue.clone())
+        }
+    }
+
+    fn write_task(dir: &Path, id: &str, status: &str, labels: &str) {
+        fs::write(
+            dir.join(format!("{} - t.md", id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: P2\nphase: Phase1\n\
+                 dependencies: []\nlabels: [{labels}]\nassignee: [\"@alice\"]\n---\n\nBody of {id}\n"
+            ),
+        )
+        .expect("write");
+    }
+
+    #[test]
+    fn export_keeps_confidential_bodies_off_github() {
+        let temp = TempDir::new().expect("tempdir");
+        let tasks_dir = temp.path().join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        fs::write(
+            tasks_dir.join("task-001 - t.md"),
+            "---\nid: task-001\ntitle: Secret\nstatus: To Do\nconfidential: true\n---\n\
+             -----BEGIN WORKMESH CONFIDENTIAL-----\nAAAA\n-----END WORKMESH CONFIDENTIAL-----\n",
+        )
+        .expect("write");
+        let mut tasks = load_tasks(&temp.path().join("workmesh"));
+        // A key holder's load reveals the plaintext body; it still must not be published.
+        tasks[0].body = "\nSecret plan\n".to_string();
+        let github = FakeGithub::default();
+
+        export_github(
+            &tasks,
+            &github,
+            &GithubConfig::default(),
+            "o/r",
+            true,
+            false,
+        )
+        .expect("export");
+        let issue = github.issues.borrow()[0].clone();
+        assert_eq!(issue.body, CONFIDENTIAL_ISSUE_BODY);
+    }
+
+    #[test]
+    fn export_then_import_round_trips_and_reports_conflicts() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        let tasks_dir = backlog.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        write_task(&tasks_dir, "task-001", "In Progress", "docs");
+        write_task(&tasks_dir, "task-002", "To Do", "");
+        write_task(&tasks_dir, "task-003", "Done", "");
+        let github = FakeGithub::default();
+        let config = GithubConfig::default();
+
+        let dry = export_github(&load_tasks(&backlog), &github, &config, "o/r", false, false)
+            .expect("dry");
+        assert_eq!(dry.created.len(), 2, "closed tasks are not exported");
+        assert!(github.issues.borrow().is_empty());
+
+        export_github(&load_tasks(&backlog), &github, &config, "o/r", true, false).expect("export");
+        let issue = github.issues.borrow()[0].clone();
+        assert_eq!(issue.labels, vec!["docs", "status:in-progress"]);
+        assert_eq!(issue.assignees, vec!["alice"]);
+        assert_eq!(issue.body, "Body of task-001");
+        let tasks = load_tasks(&backlog);
+        assert_eq!(linked_issue(&tasks[0]), Some(1));
+        let again = export_github(&tasks, &github, &config, "o/r", true, false).expect("again");
+        assert_eq!((again.created.len(), again.unchanged), (0, 2));
+
+        // Closed on GitHub, relabelled locally: import sees the issue side, export the task side.
+        github.edit(1, |issue| {
+            issue.state = "closed".to_string();
+            issue.state_reason = Some("completed".to_string());
+        });
+        github.issues.borrow_mut().push(GithubIssue {
+            number: 9,
+            title: "Reported bug".to_string(),
+            body: "Steps".to_string(),
+            state: "open".to_string(),
+            state_reason: None,
+            labels: vec!["bug".to_string(), "status:blocked".to_string()],
+            assignees: vec!["bob".to_string()],
+            html_url: None,
+        });
+        let path = tasks[1].file_path.clone().expect("path");
+        set_list_field(&path, "labels", vec!["later".to_string()]).expect("label");
+
+        let pulled = import_github(
+            &backlog,
+            &load_tasks(&backlog),
+            &github,
+            &config,
+            "o/r",
+            "gh",
+            true,
+            false,
+        )
+        .expect("import");
+        assert_eq!(pulled.updated[0].fields, vec!["status"]);
+        assert_eq!(pulled.stale[0].task_id.as_deref(), Some("task-002"));
+        assert_eq!(pulled.created[0].task_id.as_deref(), Some("task-gh-001"));
+        let tasks = load_tasks(&backlog);
+        let done = tasks.iter().find(|t| t.id == "task-001").expect("001");
+        assert_eq!(done.status, "Done");
+        let bug = tasks.iter().find(|t| t.id == "task-gh-001").expect("bug");
+        assert_eq!(
+            (bug.status.as_str(), bug.labels.clone()),
+            ("Blocked", vec!["bug".to_string()])
+        );
+        assert_eq!(linked_issue(bug), Some(9));
+
+        // Both sides edit task-002: reported, not overwritten, until forced.
+        github.edit(2, |issue| issue.title = "Renamed upstream".to_string());
+        let pushed = export_github(&tasks, &github, &config, "o/r", true, false).expect("push");
+        assert_eq!(pushed.conflicts[0].fields, vec!["title", "labels"]);
+        assert_eq!(github.issues.borrow()[1].title, "Renamed upstream");
+        export_github(&tasks, &github, &config, "o/r", true, true).expect("force");
+        assert_eq!(github.issues.borrow()[1].labels, vec!["later"]);
+        assert_eq!(github.issues.borrow()[1].title, "Task task-002");
+    }
+
+    #[test]
+    fn remotes_and_issue_payloads_parse() {
+        for url in [
+            "https://github.com/acme/site.git",
+            "git@github.com:acme/site.git\n",
+            "ssh://git@github.com/acme/site",
+        ] {
+            assert_eq!(parse_github_remote(url).as_deref(), Some("acme/site"));
+        }
+        assert_eq!(parse_github_remote("https://gitlab.com/acme/site"), None);
+
+        let pr = serde_json::json!({"number": 3, "title": "PR", "pull_request": {}});
+        assert!(GithubIssue::from_json(&pr).is_none());
+        let issue = GithubIssue::from_json(&serde_json::json!({
+            "number": 4, "title": "Old", "state": "closed", "state_reason": null,
+            "labels": [{"name": "bug"}], "assignees": [{"login": "Bob"}], "body": null
+        }))
+        .expect("issue");
+        assert_eq!(issue_status(&issue, &GithubConfig::default()), "Done");
+        assert_eq!(issue.labels, vec!["bug"]);
+        assert_eq!(issue.assignees, vec!["Bob"]);
+    }
+}
@@ -1,14 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
 use regex::Regex;
+use serde::{Deserialize, Serialize};
 use serde_yaml::Value;
 use thiserror::Error;
 
 use crate::backlog::resolve_tasks_dir;
+use crate::confidential::{reveal_body, ConfidentialKey};
+use crate::encoding::{decode_text, TextEncoding};
+use crate::task_cache::load_tasks_cached;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Task {
     pub id: String,
     pub uid: Option<String>,
@@ -27,6 +31,8 @@ pub struct Task {
     pub created_date: Option<String>,
     pub updated_date: Option<String>,
     pub extra: HashMap<String, Value>,
+    /// Set from where the task was read; not part of the task cache.
+    #[serde(skip)]
     pub file_path: Option<PathBuf>,
     pub body: String,
 }
@@ -57,15 +63,18 @@ impl From<std::io::Error> for TaskParseError {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Relationships {
     pub blocked_by: Vec<String>,
     pub parent: Vec<String>,
     pub child: Vec<String>,
     pub discovered_from: Vec<String>,
+    /// Tasks referenced from this one's notes (see `note_autolink`).
+    #[serde(default)]
+    pub relates_to: Vec<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Lease {
     pub owner: String,
     pub acquired_at: Option<String>,
@@ -73,24 +82,39 @@ pub struct Lease {
 }
 
 pub fn split_front_matter(text: &str) -> Result<(String, String), TaskParseError> {
-    if !text.starts_with("---") {
-        return Err(TaskParseError::MissingFrontMatter);
-    }
+    split_front_matter_noted(text).map(|(front, body, _)| (front, body))
+}
+
+/// Split front matter from body, tolerating fence variants other tools write (a byte-order mark,
+/// blank lines before the opening `---`, a YAML `...` document end as the closing fence). Each
+/// tolerated variant is described in the returned notes.
+fn split_front_matter_noted(text: &str) -> Result<(String, String, Vec<String>), TaskParseError> {
+    let mut notes = Vec::new();
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
     let lines: Vec<&str> = text.lines().collect();
-    if lines.is_empty() || lines[0].trim() != "---" {
+    let start = lines
+        .iter()
+        .position(|line| !line.trim().is_empty())
+        .ok_or(TaskParseError::MissingFrontMatter)?;
+    if lines[start].trim() != "---" {
         return Err(TaskParseError::MissingFrontMatter);
     }
-    let mut end_idx = None;
-    for (idx, line) in lines.iter().enumerate().skip(1) {
-        if line.trim() == "---" {
-            end_idx = Some(idx);
-            break;
-        }
+    if start > 0 {
+        notes.push("blank lines before the opening `---`".to_string());
+    }
+    let end_idx = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| matches!(line.trim(), "---" | "..."))
+        .map(|(idx, _)| idx)
+        .ok_or(TaskParseError::MissingFrontMatterEnd)?;
+    if lines[end_idx].trim() == "..." {
+        notes.push("front matter closed with `...` instead of `---`".to_string());
     }
-    let end_idx = end_idx.ok_or(TaskParseError::MissingFrontMatterEnd)?;
-    let front = lines[1..end_idx].join("\n");
+    let front = lines[start + 1..end_idx].join("\n");
     let body = lines[end_idx + 1..].join("\n");
-    Ok((front, body))
+    Ok((front, body, notes))
 }
 
 pub fn parse_list_value(value: Option<&Value>) -> Vec<String> {
@@ -113,8 +137,54 @@ pub fn parse_list_value(value: Option<&Value>) -> Vec<String> {
 }
 
 pub fn parse_task_file(path: &Path) -> Result<Task, TaskParseError> {
-    let text = fs::read_to_string(path).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
-    let (front, body) = split_front_matter(&text)?;
+    let text = read_task_file_text(path)?;
+    parse_task_text(&text, path)
+}
+
+/// Read a task file as UTF-8 text, transcoding UTF-16 and Windows-1252 files.
+pub fn read_task_file_text(path: &Path) -> Result<String, TaskParseError> {
+    let bytes = fs::read(path).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
+    Ok(decode_text(&bytes).text)
+}
+
+/// What the lenient parser had to work around in a task file: a legacy encoding, fence variants
+/// and duplicate front matter keys. `validate` reports these as warnings; the error is why a
+/// file could not be loaded at all.
+pub fn task_file_warnings(path: &Path) -> Result<Vec<String>, TaskParseError> {
+    let bytes = fs::read(path).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
+    let decoded = decode_text(&bytes);
+    let mut notes = Vec::new();
+    if decoded.encoding != TextEncoding::Utf8 {
+        notes.push(format!(
+            "encoded as {}; read as UTF-8 and rewritten as UTF-8 on the next change",
+            decoded.encoding
+        ));
+    }
+    let (front, _, fence_notes) = split_front_matter_noted(&decoded.text)?;
+    notes.extend(fence_notes);
+    notes.extend(duplicate_front_matter_keys(&front).into_iter().map(|key| {
+        format!(
+            "duplicate front matter key `{}` (the last value is used)",
+            key
+        )
+    }));
+    Ok(notes)
+}
+
+/// Parse task markdown that did not come from disk; `path` is used for the id fallback and
+/// recorded as `file_path`.
+pub fn parse_task_text(text: &str, path: &Path) -> Result<Task, TaskParseError> {
+    let mut task = parse_task_text_sealed(text, path)?;
+    task.body = reveal_body(
+        &task.body,
+        ConfidentialKey::from_env().ok().flatten().as_ref(),
+    );
+    Ok(task)
+}
+
+/// [`parse_task_text`] without revealing a confidential body, so the result can be cached.
+pub(crate) fn parse_task_text_sealed(text: &str, path: &Path) -> Result<Task, TaskParseError> {
+    let (front, body) = split_front_matter(text)?;
 
     let data = parse_front_matter(&front);
 
@@ -203,6 +273,7 @@ pub fn parse_task_file(path: &Path) -> Result<Task, TaskParseError> {
         "parent",
         "child",
         "discovered_from",
+        "relates_to",
         "relationships",
         "lease",
         "lease_owner",
@@ -245,7 +316,7 @@ pub fn parse_task_file(path: &Path) -> Result<Task, TaskParseError> {
 
 pub fn load_tasks(backlog_dir: &Path) -> Vec<Task> {
     let tasks_dir = tasks_dir_for_root(backlog_dir);
-    load_tasks_from_dir(&tasks_dir)
+    load_tasks_from_dir(backlog_dir, &tasks_dir)
 }
 
 /// Load tasks from `tasks/` and, optionally, from `archive/` (recursively).
@@ -283,7 +354,7 @@ pub fn archive_root_for_root(root: &Path) -> PathBuf {
         .join("archive")
 }
 
-fn load_tasks_from_dir(tasks_dir: &Path) -> Vec<Task> {
+fn load_tasks_from_dir(backlog_dir: &Path, tasks_dir: &Path) -> Vec<Task> {
     let mut entries: Vec<PathBuf> = match fs::read_dir(tasks_dir) {
         Ok(read_dir) => read_dir
             .filter_map(|entry| entry.ok())
@@ -293,18 +364,18 @@ fn load_tasks_from_dir(tasks_dir: &Path) -> Vec<Task> {
         Err(_) => Vec::new(),
     };
     entries.sort();
+    load_tasks_cached(backlog_dir, tasks_dir, &entries)
+}
 
-    let mut tasks = Vec::new();
-    for path in entries {
-        match parse_task_file(&path) {
-            Ok(task) => tasks.push(task),
-            Err(_) => continue,
-        }
-    }
-    tasks
+/// Parse each path, skipping files that are not valid tasks.
+pub(crate) fn parse_task_files(paths: &[PathBuf]) -> Vec<Task> {
+    paths
+        .iter()
+        .filter_map(|path| parse_task_file(path).ok())
+        .collect()
 }
 
-fn load_tasks_from_dir_recursive(root: &Path) -> Vec<Task> {
+pub(crate) fn load_tasks_from_dir_recursive(root: &Path) -> Vec<Task> {
     let mut md_files = Vec::new();
     let mut stack = vec![root.to_path_buf()];
     while let Some(dir) = stack.pop() {
@@ -333,7 +404,14 @@ fn load_tasks_from_dir_recursive(root: &Path) -> Vec<Task> {
     tasks
 }
 
-fn parse_front_matter(front: &str) -> HashMap<String, Value> {
+pub(crate) fn parse_front_matter(front: &str) -> HashMap<String, Value> {
+    let deduped;
+    let front = if duplicate_front_matter_keys(front).is_empty() {
+        front
+    } else {
+        deduped = drop_shadowed_keys(front);
+        deduped.as_str()
+    };
     if let Ok(value) = serde_yaml::from_str::<Value>(front) {
         if let Value::Mapping(map) = value {
             let mut data = HashMap::new();
@@ -350,6 +428,51 @@ fn parse_front_matter(front: &str) -> HashMap<String, Value> {
     parse_front_matter_loose(front)
 }
 
+/// A top-level front matter key line (`key: ...` at column 0) and its key.
+fn top_level_key(line: &str) -> Option<&str> {
+    if line.starts_with([' ', '\t', '-', '#']) {
+        return None;
+    }
+    let (key, _) = line.split_once(':')?;
+    let key = key.trim().trim_matches(|c| c == '"' || c == '\'');
+    (!key.is_empty()).then_some(key)
+}
+
+/// Top-level keys that appear more than once, in first-seen order.
+fn duplicate_front_matter_keys(front: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut duplicates = Vec::new();
+    for key in front.lines().filter_map(top_level_key) {
+        if !seen.insert(key) && !duplicates.iter().any(|dup| dup == key) {
+            duplicates.push(key.to_string());
+        }
+    }
+    duplicates
+}
+
+/// Remove every occurrence of a repeated top-level key except the last, with its nested lines,
+/// so strict YAML parsing succeeds and the last value wins.
+fn drop_shadowed_keys(front: &str) -> String {
+    let lines: Vec<&str> = front.lines().collect();
+    let mut last: HashMap<&str, usize> = HashMap::new();
+    for (idx, line) in lines.iter().enumerate() {
+        if let Some(key) = top_level_key(line) {
+            last.insert(key, idx);
+        }
+    }
+    let mut kept = Vec::with_capacity(lines.len());
+    let mut skipping = false;
+    for (idx, line) in lines.iter().enumerate() {
+        if let Some(key) = top_level_key(line) {
+            skipping = last.get(key) != Some(&idx);
+        }
+        if !skipping {
+            kept.push(*line);
+        }
+    }
+    kept.join("\n")
+}
+
 fn parse_front_matter_loose(front: &str) -> HashMap<String, Value> {
     let mut data = HashMap::new();
     let lines: Vec<&str> = front.lines().collect();
@@ -469,6 +592,17 @@ fn value_to_string(value: &Value) -> Option<String> {
 }
 
 fn parse_relationships(data: &HashMap<String, Value>) -> Relationships {
+    let mut relationships = parse_relationship_lists(data);
+    // A top-level `parent` (what `set-field <id> parent <task-id>` writes) is the first-class
+    // field and wins over the `relationships.parent` list new tasks are created with.
+    let parent = parse_list_value(data.get("parent"));
+    if !parent.is_empty() {
+        relationships.parent = parent;
+    }
+    relationships
+}
+
+fn parse_relationship_lists(data: &HashMap<String, Value>) -> Relationships {
     if let Some(Value::Mapping(map)) = data.get("relationships") {
         let blocked_by = map
             .get(&Value::String("blocked_by".to_string()))
@@ -482,14 +616,22 @@ fn parse_relationships(data: &HashMap<String, Value>) -> Relationships {
         let discovered_from = map
             .get(&Value::String("discovered_from".to_string()))
             .and_then(|value| value_to_list(value));
-
-        if blocked_by.is_some() || parent.is_some() || child.is_some() || discovered_from.is_some()
+        let relates_to = map
+            .get(Value::String("relates_to".to_string()))
+            .and_then(value_to_list);
+
+        if blocked_by.is_some()
+            || parent.is_some()
+            || child.is_some()
+            || discovered_from.is_some()
+            || relates_to.is_some()
         {
             return Relationships {
                 blocked_by: blocked_by.unwrap_or_default(),
                 parent: parent.unwrap_or_default(),
                 child: child.unwrap_or_default(),
                 discovered_from: discovered_from.unwrap_or_default(),
+                relates_to: relates_to.unwrap_or_default(),
             };
         }
     }
@@ -499,6 +641,7 @@ fn parse_relationships(data: &HashMap<String, Value>) -> Relationships {
         parent: parse_list_value(data.get("parent")),
         child: parse_list_value(data.get("child")),
         discovered_from: parse_list_value(data.get("discovered_from")),
+        relates_to: parse_list_value(data.get("relates_to")),
     }
 }
 
@@ -665,6 +808,28 @@ mod tests {
         assert_eq!(task.relationships.discovered_from, vec!["task-005"]);
     }
 
+    #[test]
+    fn parse_task_file_prefers_top_level_parent() {
+        let temp = TempDir::new().expect("tempdir");
+        let file_path = temp.path().join("task-004 - parent.md");
+        let content = "---\n".to_string()
+            + "id: task-004\n"
+            + "title: Example\n"
+            + "status: To Do\n"
+            + "priority: P2\n"
+            + "phase: Phase1\n"
+            + "parent: task-000\n"
+            + "relationships:\n"
+            + "  blocked_by: [task-001]\n"
+            + "  parent: []\n"
+            + "---\n";
+        fs::write(&file_path, content).expect("write");
+
+        let task = parse_task_file(&file_path).expect("parse");
+        assert_eq!(task.relationships.parent, vec!["task-000"]);
+        assert_eq!(task.relationships.blocked_by, vec!["task-001"]);
+    }
+
     #[test]
     fn parse_task_file_reads_lease_mapping() {
         let temp = TempDir::new().expect("tempdir");
@@ -0,0 +1,325 @@
+//! Parsed-task cache behind [`load_tasks`](crate::task::load_tasks)
+//! (`.index/task-cache.jsonl`).
+//!
+//! Parsing front matter dominates load time in large backlogs. The cache keeps each parsed task
+//! with the size, mtime and SHA-256 of its file. A file is trusted by size and mtime when it is
+//! older than the cache itself (the racy-timestamp rule `index-refresh` uses); otherwise its hash
+//! is compared, and only files whose content changed are parsed again. The cache is rewritten
+//! only when an entry changed.
+//!
+//! Confidential bodies are cached sealed and revealed on load, so no plaintext reaches disk.
+//! Set `WORKMESH_TASK_CACHE=off` to bypass the cache.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use sha2::{Digest, Sha256};
+
+use crate::confidential::{reveal_body, ConfidentialKey};
+use crate::encoding::decode_text;
+use crate::index::index_dir;
+use crate::storage::atomic_write_text;
+use crate::task::{parse_task_files, parse_task_text_sealed, Task};
+
+/// `off`, `0` or `false` disables the cache.
+pub const TASK_CACHE_ENV: &str = "WORKMESH_TASK_CACHE";
+
+/// Bump when the cached `Task` shape or parsing changes; a mismatch discards the cache.
+const CACHE_FORMAT: u32 = 3;
+
+pub fn task_cache_path(backlog_dir: &Path) -> PathBuf {
+    index_dir(backlog_dir).join("task-cache.jsonl")
+}
+
+pub fn task_cache_enabled() -> bool {
+    !matches!(
+        std::env::var(TASK_CACHE_ENV)
+            .map(|value| value.trim().to_lowercase())
+            .as_deref(),
+        Ok("off" | "0" | "false")
+    )
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+struct CacheHeader {
+    format: u32,
+    /// Cache written by another workmesh version is discarded.
+    version: String,
+}
+
+impl CacheHeader {
+    fn current() -> Self {
+        Self {
+            format: CACHE_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    /// File name inside the tasks directory.
+    file: String,
+    len: u64,
+    mtime: i64,
+    hash: String,
+    task: Task,
+}
+
+/// Load the tasks in `paths` (sorted files of `tasks_dir`) through the cache of `backlog_dir`.
+pub(crate) fn load_tasks_cached(
+    backlog_dir: &Path,
+    tasks_dir: &Path,
+    paths: &[PathBuf],
+) -> Vec<Task> {
+    // A bare tasks directory has no state root to keep `.index/` in.
+    if !task_cache_enabled() || backlog_dir == tasks_dir || !backlog_dir.is_dir() {
+        return parse_task_files(paths);
+    }
+    let cache_path = task_cache_path(backlog_dir);
+    let (mut previous, cache_mtime) = read_cache(&cache_path);
+    let key = ConfidentialKey::from_env().ok().flatten();
+
+    let mut dirty = false;
+    let mut entries = Vec::with_capacity(paths.len());
+    let mut tasks = Vec::with_capacity(paths.len());
+    for path in paths {
+        let Some(file) = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+        else {
+            continue;
+        };
+        let Ok(metadata) = fs::metadata(path) else {
+            continue;
+        };
+        let len = metadata.len();
+        let mtime = to_unix_nanos(metadata.modified().unwrap_or(UNIX_EPOCH));
+        let cached = previous.remove(&file);
+
+        let entry = match cached {
+            Some(entry) if entry.len == len && entry.mtime == mtime && mtime < cache_mtime => {
+                Some(entry)
+            }
+            cached => {
+                let Ok(bytes) = fs::read(path) else {
+                    continue;
+                };
+                let hash = format!("{:x}", Sha256::digest(&bytes));
+                match cached.filter(|entry| entry.hash == hash) {
+                    Some(entry) => {
+                        dirty |= entry.len != len || entry.mtime != mtime;
+                        Some(CacheEntry {
+                            len,
+                            mtime,
+                            ..entry
+                        })
+                    }
+                    None => parse_task_text_sealed(&decode_text(&bytes).text, path)
+                        .ok()
+                        .map(|task| CacheEntry {
+                            file,
+                            len,
+                            mtime,
+                            hash,
+                            task,
+                        })
+                        .inspect(|entry| dirty |= cacheable(entry)),
+                }
+            }
+        };
+        // Not a valid task: skipped, exactly as an uncached load would.
+        let Some(entry) = entry else {
+            continue;
+        };
+        let mut task = entry.task.clone();
+        task.file_path = Some(path.clone());
+        task.body = reveal_body(&task.body, key.as_ref());
+        tasks.push(task);
+        if cacheable(&entry) {
+            entries.push(entry);
+        }
+    }
+
+    if dirty || !previous.is_empty() {
+        // Best effort: a read-only checkout still loads, just without the speedup.
+        let _ = write_cache(&cache_path, &entries);
+    }
+    tasks
+}
+
+fn read_cache(path: &Path) -> (HashMap<String, CacheEntry>, i64) {
+    let empty = (HashMap::new(), i64::MIN);
+    let Ok(text) = fs::read_to_string(path) else {
+        return empty;
+    };
+    let mut lines = text.lines();
+    let header = lines
+        .next()
+        .and_then(|line| serde_json::from_str::<CacheHeader>(line).ok());
+    if header != Some(CacheHeader::current()) {
+        return empty;
+    }
+    let cache_mtime = fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .map(to_unix_nanos)
+        .unwrap_or(i64::MIN);
+    let entries = lines
+        .filter_map(|line| serde_json::from_str::<CacheEntry>(line).ok())
+        .map(|entry| (entry.file.clone(), entry))
+        .collect();
+    (entries, cache_mtime)
+}
+
+fn write_cache(path: &Path, entries: &[CacheEntry]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut payload = serde_json::to_string(&CacheHeader::current())?;
+    payload.push('\n');
+    for entry in entries {
+        payload.push_str(&serde_json::to_string(entry)?);
+        payload.push('\n');
+    }
+    atomic_write_text(path, &payload)?;
+    Ok(())
+}
+
+/// Front matter JSON cannot represent exactly (tags, non-string keys) stays uncached, and
+/// such files are parsed on every load.
+fn cacheable(entry: &CacheEntry) -> bool {
+    entry.task.extra.values().all(json_exact)
+}
+
+/// Whether `value` survives a JSON round trip unchanged.
+fn json_exact(value: &Value) -> bool {
+    match value {
+        Value::Null | Value::Bool(_) | Value::String(_) => true,
+        Value::Number(number) => number.as_f64().map(f64::is_finite).unwrap_or(true),
+        Value::Sequence(items) => items.iter().all(json_exact),
+        Value::Mapping(map) => map
+            .iter()
+            .all(|(key, value)| key.is_string() && json_exact(value)),
+        Value::Tagged(_) => false,
+    }
+}
+
+fn to_unix_nanos(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_nanos() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use std::time::Duration;
+    use tempfile::TempDir;
+
+    fn write_task(tasks_dir: &Path, id: &str, status: &str, extra: &str) -> PathBuf {
+        let path = tasks_dir.join(format!("{} - cached.md", id));
+        fs::write(
+            &path,
+            format!(
+                "---\nid: {}\ntitle: Cached {}\nstatus: {}\npriority: P2\nphase: Phase1\n{}---\n\nBody of {}\n",
+                id, id, status, extra, id
+            ),
+        )
+        .expect("write");
+        path
+    }
+
+    fn age(path: &Path, secs: u64) {
+        let file = fs::File::options().write(true).open(path).expect("open");
+        file.set_modified(SystemTime::now() - Duration::from_secs(secs))
+            .expect("mtime");
+    }
+
+    fn cached_files(backlog_dir: &Path) -> Vec<String> {
+        let text = fs::read_to_string(task_cache_path(backlog_dir)).expect("cache");
+        text.lines()
+            .skip(1)
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).expect("entry");
+                value["file"].as_str().expect("file").to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn load_tasks_uses_cache_and_picks_up_changes() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        let first = write_task(&tasks_dir, "task-001", "To Do", "");
+        let second = write_task(&tasks_dir, "task-002", "To Do", "estimate: 3\n");
+        let tagged = write_task(&tasks_dir, "task-003", "To Do", "odd: !custom value\n");
+        age(&first, 120);
+        age(&second, 120);
+
+        let uncached = parse_task_files(&[first.clone(), second.clone(), tagged.clone()]);
+        let tasks = load_tasks(&backlog_dir);
+        assert_eq!(tasks.len(), 3);
+        assert_eq!(
+            cached_files(&backlog_dir),
+            vec!["task-001 - cached.md", "task-002 - cached.md"]
+        );
+        for (cached, parsed) in load_tasks(&backlog_dir).iter().zip(&uncached) {
+            assert_eq!(cached.id, parsed.id);
+            assert_eq!(cached.body, parsed.body);
+            assert_eq!(cached.extra, parsed.extra);
+            assert_eq!(cached.file_path, parsed.file_path);
+        }
+
+        // Nothing cacheable changed, so the cache is left alone even though task-003 is parsed.
+        let cache = task_cache_path(&backlog_dir);
+        age(&cache, 60);
+        let written = fs::metadata(&cache)
+            .and_then(|m| m.modified())
+            .expect("mtime");
+        load_tasks(&backlog_dir);
+        assert_eq!(
+            fs::metadata(&cache)
+                .and_then(|m| m.modified())
+                .expect("mtime"),
+            written
+        );
+
+        // Same size and an mtime older than the cache would be trusted, so change the size.
+        write_task(&tasks_dir, "task-001", "In Progress", "");
+        fs::remove_file(&second).expect("remove");
+        let tasks = load_tasks(&backlog_dir);
+        let statuses: Vec<(&str, &str)> = tasks
+            .iter()
+            .map(|task| (task.id.as_str(), task.status.as_str()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![("task-001", "In Progress"), ("task-003", "To Do")]
+        );
+        assert_eq!(cached_files(&backlog_dir), vec!["task-001 - cached.md"]);
+    }
+
+    #[test]
+    fn stale_or_foreign_cache_is_discarded() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks");
+        let path = write_task(&tasks_dir, "task-001", "To Do", "");
+        age(&path, 120);
+        load_tasks(&backlog_dir);
+
+        // A cache claiming a different status for the same file must not win after a version bump.
+        let cache = task_cache_path(&backlog_dir);
+        let text = fs::read_to_string(&cache).expect("cache");
+        let forged = text
+            .replace(env!("CARGO_PKG_VERSION"), "0.0.0")
+            .replace("\"To Do\"", "\"Done\"");
+        fs::write(&cache, forged).expect("forge");
+        assert_eq!(load_tasks(&backlog_dir)[0].status, "To Do");
+    }
+}
@@ -4,15 +4,25 @@ use std::path::{Path, PathBuf};
 
 use chrono::{Duration, Local, NaiveDateTime};
 use regex::Regex;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 
-use crate::config::TaskValidationRules;
+use crate::codeowners::commits_mentioning;
+use crate::confidential::{mutate_confidential_text, ConfidentialKey};
+use crate::config::{load_config, load_global_config, TaskValidationRules};
 use crate::context::{context_from_legacy_focus, ContextScopeMode, ContextState};
 use crate::focus::FocusState;
-use crate::project::{project_docs_dir, repo_root_from_backlog};
+use crate::health::is_closed;
+use crate::policies::parse_task_date;
+use crate::project::{project_docs_dir, repo_root_from_backlog, repo_root_from_state_root};
 use crate::storage::{with_path_lock, write_string_atomic, write_string_atomic_locked};
-use crate::task::{split_front_matter, Task, TaskParseError};
+use crate::task::{
+    parse_list_value, parse_task_file, read_task_file_text, split_front_matter, task_file_warnings,
+    tasks_dir_for_root, Task, TaskParseError,
+};
+use crate::timestamps::{cmp_timestamps, format_timestamp, parse_stored_timestamp};
+use crate::timewindow::parse_timestamp_ref;
+use crate::workflow::escape_dot;
 
 #[derive(Serialize)]
 struct GraphNode<'a> {
@@ -55,6 +65,15 @@ pub struct TaskSectionContent {
     pub definition_of_done: String,
 }
 
+/// Body of a `kind: epic` document; rendered as the [`EPIC_SECTIONS`].
+#[derive(Debug, Clone, Default)]
+pub struct EpicSectionContent {
+    pub problem: String,
+    pub outcomes: String,
+    pub scope: String,
+    pub non_goals: String,
+}
+
 impl TaskQualityReport {
     pub fn is_done_ready(&self) -> bool {
         self.missing_sections.is_empty()
@@ -66,6 +85,9 @@ impl TaskQualityReport {
 const DESCRIPTION_SECTION: &str = "Description";
 const ACCEPTANCE_CRITERIA_SECTION: &str = "Acceptance Criteria";
 const DEFINITION_OF_DONE_SECTION: &str = "Definition of Done";
+const IMPLEMENTATION_NOTES_SECTION: &str = "Implementation Notes";
+/// Sections an epic body carries instead of Description / Acceptance Criteria / Definition of Done.
+pub const EPIC_SECTIONS: [&str; 4] = ["Problem", "Outcomes", "Scope", "Non-goals"];
 const HYGIENE_DOD_ITEMS: [&str; 4] = [
     "code config committed",
     "code or config committed",
@@ -110,6 +132,10 @@ pub fn is_done(task: &Task) -> bool {
     task.status.trim().eq_ignore_ascii_case("done")
 }
 
+pub fn is_epic(task: &Task) -> bool {
+    task.kind.trim().eq_ignore_ascii_case("epic")
+}
+
 pub fn is_draft_status(status: &str) -> bool {
     let normalized = status.trim();
     normalized.eq_ignore_ascii_case("draft") || normalized.eq_ignore_ascii_case("needs refinement")
@@ -130,7 +156,8 @@ pub fn ensure_can_mark_done_with_rules(
     rules: &TaskValidationRules,
 ) -> Result<(), String> {
     ensure_task_quality_for_done_with_rules(task, rules)?;
-    if !task.kind.trim().eq_ignore_ascii_case("epic") {
+    ensure_done_definition(task, rules)?;
+    if !is_epic(task) {
         return Ok(());
     }
     let epic_id = task.id.to_lowercase();
@@ -213,6 +240,79 @@ pub fn ensure_can_mark_done_with_rules(
     ))
 }
 
+/// Reasons `task` fails the optional done rules (`task_require_implementation_notes`,
+/// `..._acceptance_criteria_checked`, `..._linked_commit`, `..._review_approval`). Epics are
+/// exempt; they are gated on their children instead.
+pub fn done_definition_failures(task: &Task, rules: &TaskValidationRules) -> Vec<String> {
+    let mut failures = Vec::new();
+    if is_epic(task) {
+        return failures;
+    }
+    if rules.require_implementation_notes
+        && !extract_section_content(&task.body, IMPLEMENTATION_NOTES_SECTION)
+            .is_some_and(|content| section_has_substantive_content(&content))
+    {
+        failures.push("Implementation Notes are empty".to_string());
+    }
+    if rules.require_acceptance_criteria_checked {
+        let content =
+            extract_section_content(&task.body, ACCEPTANCE_CRITERIA_SECTION).unwrap_or_default();
+        let unchecked: Vec<String> = content
+            .lines()
+            .filter_map(parse_bullet_item)
+            .filter(|item| !is_placeholder_text(item))
+            .filter(|item| !item.starts_with("[x]") && !item.starts_with("[X]"))
+            .map(|item| item.strip_prefix("[ ]").unwrap_or(item).trim().to_string())
+            .collect();
+        if !unchecked.is_empty() {
+            failures.push(format!(
+                "unchecked acceptance criteria: {}",
+                unchecked.join("; ")
+            ));
+        }
+    }
+    if rules.require_linked_commit
+        && parse_list_value(task.extra.get("commits")).is_empty()
+        && task
+            .file_path
+            .as_deref()
+            .and_then(Path::parent)
+            .is_none_or(|dir| commits_mentioning(dir, &task.id).is_empty())
+    {
+        failures.push(format!(
+            "no linked commit (add `commits:` or mention {} in a commit message)",
+            task.id
+        ));
+    }
+    if rules.require_review_approval && parse_list_value(task.extra.get("approved_by")).is_empty() {
+        let reviewers: Vec<String> = ["reviewer", "reviewers"]
+            .iter()
+            .flat_map(|key| parse_list_value(task.extra.get(*key)))
+            .collect();
+        if reviewers.is_empty() {
+            failures.push("no review approval (set `approved_by:`)".to_string());
+        } else {
+            failures.push(format!(
+                "no review approval from {} (set `approved_by:`)",
+                reviewers.join(", ")
+            ));
+        }
+    }
+    failures
+}
+
+fn ensure_done_definition(task: &Task, rules: &TaskValidationRules) -> Result<(), String> {
+    let failures = done_definition_failures(task, rules);
+    if failures.is_empty() {
+        return Ok(());
+    }
+    Err(format!(
+        "Refusing to mark {} Done until the definition of done is met ({})",
+        task.id,
+        failures.join("; ")
+    ))
+}
+
 pub fn evaluate_task_quality(task: &Task) -> TaskQualityReport {
     evaluate_task_quality_with_rules(task, &TaskValidationRules::default())
 }
@@ -225,6 +325,27 @@ pub fn evaluate_task_quality_with_rules(
     let mut incomplete_sections = Vec::new();
     let mut definition_of_done_hygiene_only = false;
 
+    if is_epic(task) {
+        // Epics follow their own schema; it is enforced whenever section checks are enabled.
+        let enforce = rules.require_description
+            || rules.require_acceptance_criteria
+            || rules.require_definition_of_done;
+        for section in EPIC_SECTIONS.iter().filter(|_| enforce) {
+            match extract_section_content(&task.body, section) {
+                None => missing_sections.push(section.to_string()),
+                Some(content) if !section_has_substantive_content(&content) => {
+                    incomplete_sections.push(section.to_string())
+                }
+                Some(_) => {}
+            }
+        }
+        return TaskQualityReport {
+            missing_sections,
+            incomplete_sections,
+            definition_of_done_hygiene_only,
+        };
+    }
+
     let required_sections = [
         (rules.require_description, DESCRIPTION_SECTION),
         (
@@ -342,11 +463,20 @@ pub fn normalize_task_required_sections(path: &Path) -> Result<Vec<String>, Task
     mutate_task_file(path, |text| {
         let (front, body) = split_front_matter(text)?;
         let mut updated_body = body.clone();
-        for section in [
-            DESCRIPTION_SECTION,
-            ACCEPTANCE_CRITERIA_SECTION,
-            DEFINITION_OF_DONE_SECTION,
-        ] {
+        let epic = serde_yaml::from_str::<serde_yaml::Value>(&front)
+            .ok()
+            .and_then(|value| value.get("kind")?.as_str().map(str::to_string))
+            .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("epic"));
+        let sections: &[&str] = if epic {
+            &EPIC_SECTIONS
+        } else {
+            &[
+                DESCRIPTION_SECTION,
+                ACCEPTANCE_CRITERIA_SECTION,
+                DEFINITION_OF_DONE_SECTION,
+            ]
+        };
+        for &section in sections {
             if extract_section_content(&updated_body, section).is_none() {
                 updated_body = replace_section(&updated_body, section, "- ");
                 added.push(section.to_string());
@@ -377,6 +507,42 @@ pub fn blockers_satisfied(task: &Task, done_ids: &HashSet<String>) -> bool {
     deps_ok && rel_ok && lease_ok
 }
 
+/// Front matter key for dependencies that should finish first but do not block the task.
+pub const SOFT_DEPENDENCIES_FIELD: &str = "soft_dependencies";
+/// Graph edge type for soft dependencies, next to the hard `blocked_by`.
+pub const SOFT_EDGE_TYPE: &str = "soft_blocked_by";
+
+/// Soft dependencies are ordering hints: `ready` and `blockers` report them as warnings while
+/// unfinished, never as blockers.
+pub fn soft_dependencies(task: &Task) -> Vec<String> {
+    parse_list_value(task.extra.get(SOFT_DEPENDENCIES_FIELD))
+}
+
+/// Soft dependencies of `task` not yet in `done_ids`, in front matter order.
+pub fn unmet_soft_dependencies(task: &Task, done_ids: &HashSet<String>) -> Vec<String> {
+    soft_dependencies(task)
+        .into_iter()
+        .filter(|dep| !done_ids.contains(&dep.to_lowercase()))
+        .collect()
+}
+
+/// Unmet soft dependencies keyed by lowercase task id, for open tasks that have any.
+pub fn soft_dependency_warnings(tasks: &[Task]) -> HashMap<String, Vec<String>> {
+    let done_ids: HashSet<String> = tasks
+        .iter()
+        .filter(|task| is_done(task))
+        .map(|task| task.id.to_lowercase())
+        .collect();
+    tasks
+        .iter()
+        .filter(|task| !is_closed(task))
+        .filter_map(|task| {
+            let unmet = unmet_soft_dependencies(task, &done_ids);
+            (!unmet.is_empty()).then(|| (task.id.to_lowercase(), unmet))
+        })
+        .collect()
+}
+
 pub fn filter_tasks<'a>(
     tasks: &'a [Task],
     status: Option<&[String]>,
@@ -447,17 +613,248 @@ pub fn filter_tasks<'a>(
     result
 }
 
-pub fn sort_tasks<'a>(mut tasks: Vec<&'a Task>, key: &str) -> Vec<&'a Task> {
-    match key {
-        "id" => tasks.sort_by_key(|task| task.id_num()),
-        "title" => tasks.sort_by_key(|task| task.title.to_lowercase()),
-        "kind" => tasks.sort_by_key(|task| task.kind.to_lowercase()),
-        "status" => tasks.sort_by_key(|task| task.status.to_lowercase()),
-        "phase" => tasks.sort_by_key(|task| task.phase.to_lowercase()),
-        "priority" => tasks.sort_by_key(|task| task.priority.to_lowercase()),
-        _ => {}
+/// Created/updated date window for `list`. `after` bounds are inclusive, `before` bounds
+/// exclusive.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DateFilter {
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub updated_after: Option<NaiveDateTime>,
+    pub updated_before: Option<NaiveDateTime>,
+}
+
+impl DateFilter {
+    pub fn is_empty(&self) -> bool {
+        self == &DateFilter::default()
     }
+}
+
+/// Keep tasks whose `created_date`/`updated_date` fall inside `filter`. A task without the
+/// date a bound refers to is dropped, since it cannot be shown to match.
+pub fn filter_tasks_by_dates<'a>(tasks: Vec<&'a Task>, filter: &DateFilter) -> Vec<&'a Task> {
+    if filter.is_empty() {
+        return tasks;
+    }
+    let within =
+        |value: Option<&str>, after: Option<NaiveDateTime>, before: Option<NaiveDateTime>| {
+            if after.is_none() && before.is_none() {
+                return true;
+            }
+            let Some(date) = value.and_then(parse_task_date) else {
+                return false;
+            };
+            after.is_none_or(|after| date >= after) && before.is_none_or(|before| date < before)
+        };
+    tasks
+        .into_iter()
+        .filter(|task| {
+            within(
+                task.created_date.as_deref(),
+                filter.created_after,
+                filter.created_before,
+            ) && within(
+                task.updated_date.as_deref(),
+                filter.updated_after,
+                filter.updated_before,
+            )
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+/// Typed comparison against a front-matter field, e.g. `estimate>=5` or `severity=S1`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldFilter {
+    pub field: String,
+    pub op: FieldOp,
+    pub value: String,
+}
+
+impl FieldFilter {
+    /// Parse `<field><op><value>` with op one of `=`, `!=`, `>`, `>=`, `<`, `<=`.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let re =
+            Regex::new(r"^\s*([A-Za-z0-9_.\-]+)\s*(>=|<=|!=|=|>|<)\s*(.*?)\s*$").expect("regex");
+        let caps = re.captures(raw)?;
+        let value = caps[3].to_string();
+        if value.is_empty() {
+            return None;
+        }
+        let op = match &caps[2] {
+            "=" => FieldOp::Eq,
+            "!=" => FieldOp::Ne,
+            ">" => FieldOp::Gt,
+            ">=" => FieldOp::Ge,
+            "<" => FieldOp::Lt,
+            _ => FieldOp::Le,
+        };
+        Some(FieldFilter {
+            field: caps[1].to_string(),
+            op,
+            value,
+        })
+    }
+
+    /// Numbers compare numerically, anything else case-insensitively (so ISO dates order
+    /// correctly). List fields match when any element matches; `!=` requires that none equal.
+    /// A task without the field only matches `!=`.
+    pub fn matches(&self, task: &Task) -> bool {
+        let values = field_values(task, &self.field);
+        if self.op == FieldOp::Ne {
+            return values
+                .iter()
+                .all(|value| compare_sort_values(value, &self.value) != std::cmp::Ordering::Equal);
+        }
+        values.iter().any(|value| {
+            let ordering = compare_sort_values(value, &self.value);
+            match self.op {
+                FieldOp::Eq => ordering == std::cmp::Ordering::Equal,
+                FieldOp::Gt => ordering == std::cmp::Ordering::Greater,
+                FieldOp::Ge => ordering != std::cmp::Ordering::Less,
+                FieldOp::Lt => ordering == std::cmp::Ordering::Less,
+                FieldOp::Le => ordering != std::cmp::Ordering::Greater,
+                FieldOp::Ne => unreachable!(),
+            }
+        })
+    }
+}
+
+/// Keep tasks matching every filter.
+pub fn filter_tasks_by_fields<'a>(tasks: Vec<&'a Task>, filters: &[FieldFilter]) -> Vec<&'a Task> {
     tasks
+        .into_iter()
+        .filter(|task| filters.iter().all(|filter| filter.matches(task)))
+        .collect()
+}
+
+fn field_values(task: &Task, field: &str) -> Vec<String> {
+    let list = |values: &[String]| -> Vec<String> {
+        values
+            .iter()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect()
+    };
+    match field {
+        "id" => vec![task.id.clone()],
+        "labels" => list(&task.labels),
+        "assignee" => list(&task.assignee),
+        "dependencies" => list(&task.dependencies),
+        _ => match task.extra.get(field) {
+            Some(serde_yaml::Value::Sequence(items)) => items
+                .iter()
+                .filter_map(|item| match item {
+                    serde_yaml::Value::String(value) => Some(value.trim().to_string()),
+                    serde_yaml::Value::Number(value) => Some(value.to_string()),
+                    _ => None,
+                })
+                .filter(|value| !value.is_empty())
+                .collect(),
+            _ => sort_value(task, field).into_iter().collect(),
+        },
+    }
+}
+
+/// Sort by a comma-separated key list such as `priority,-updated_date,due_date`.
+///
+/// A leading `-` sorts that key descending. Keys are built-in fields or any front-matter
+/// field; values that both parse as numbers compare numerically, values that both parse as
+/// timestamps compare by instant, and everything else compares case-insensitively. Tasks missing a key sort after those that have it. The sort is
+/// stable, so unknown keys leave the input order untouched.
+pub fn sort_tasks<'a>(tasks: Vec<&'a Task>, spec: &str) -> Vec<&'a Task> {
+    let keys: Vec<(&str, bool)> = spec
+        .split(',')
+        .map(str::trim)
+        .filter(|key| !key.is_empty())
+        .map(|key| match key.strip_prefix('-') {
+            Some(field) => (field.trim(), true),
+            None => (key.strip_prefix('+').unwrap_or(key).trim(), false),
+        })
+        .collect();
+    if keys.is_empty() {
+        return tasks;
+    }
+    let mut keyed: Vec<(Vec<Option<String>>, &'a Task)> = tasks
+        .into_iter()
+        .map(|task| {
+            let values = keys
+                .iter()
+                .map(|(field, _)| sort_value(task, field))
+                .collect();
+            (values, task)
+        })
+        .collect();
+    keyed.sort_by(|(left, _), (right, _)| {
+        for (idx, (_, descending)) in keys.iter().enumerate() {
+            let ordering = match (&left[idx], &right[idx]) {
+                (Some(a), Some(b)) => {
+                    let ordering = compare_sort_values(a, b);
+                    if *descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                }
+                (Some(_), None) => std::cmp::Ordering::Less,
+                (None, Some(_)) => std::cmp::Ordering::Greater,
+                (None, None) => std::cmp::Ordering::Equal,
+            };
+            if ordering != std::cmp::Ordering::Equal {
+                return ordering;
+            }
+        }
+        std::cmp::Ordering::Equal
+    });
+    keyed.into_iter().map(|(_, task)| task).collect()
+}
+
+fn sort_value(task: &Task, field: &str) -> Option<String> {
+    let non_empty = |value: &str| {
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    };
+    let joined = |values: &[String]| non_empty(&values.join(","));
+    match field {
+        "id" => Some(task.id_num().to_string()),
+        "title" => non_empty(&task.title),
+        "kind" => non_empty(&task.kind),
+        "status" => non_empty(&task.status),
+        "phase" => non_empty(&task.phase),
+        "priority" => non_empty(&task.priority),
+        "labels" => joined(&task.labels),
+        "assignee" => joined(&task.assignee),
+        "dependencies" => joined(&task.dependencies),
+        "project" => task.project.as_deref().and_then(non_empty),
+        "initiative" => task.initiative.as_deref().and_then(non_empty),
+        "created_date" => task.created_date.as_deref().and_then(non_empty),
+        "updated_date" => task.updated_date.as_deref().and_then(non_empty),
+        _ => match task.extra.get(field)? {
+            serde_yaml::Value::String(value) => non_empty(value),
+            serde_yaml::Value::Number(value) => Some(value.to_string()),
+            serde_yaml::Value::Bool(value) => Some(value.to_string()),
+            _ => None,
+        },
+    }
+}
+
+fn compare_sort_values(a: &str, b: &str) -> std::cmp::Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal),
+        // Stored dates mix offsets and the older naive form, so text order is not time order.
+        _ if parse_timestamp_ref(a).is_some() && parse_timestamp_ref(b).is_some() => {
+            cmp_timestamps(a, b)
+        }
+        _ => a.to_lowercase().cmp(&b.to_lowercase()),
+    }
 }
 
 pub fn render_task_line(task: &Task) -> String {
@@ -472,13 +869,13 @@ pub fn render_task_line(task: &Task) -> String {
     )
 }
 
+/// The current time as stored in front matter and the audit log (RFC3339 with offset).
 pub fn now_timestamp() -> String {
-    Local::now().format("%Y-%m-%d %H:%M").to_string()
+    format_timestamp(Local::now())
 }
 
 pub fn timestamp_plus_minutes(minutes: i64) -> String {
-    let future = Local::now() + Duration::minutes(minutes);
-    future.format("%Y-%m-%d %H:%M").to_string()
+    format_timestamp(Local::now() + Duration::minutes(minutes))
 }
 
 pub fn update_front_matter_value(
@@ -578,10 +975,114 @@ pub fn update_lease_fields(
     })
 }
 
+/// Replace `key` with `value` rendered as an indented YAML block (for structured fields such as
+/// lists of mappings that do not fit on one `key: value` line).
+pub fn update_front_matter_yaml(
+    text: &str,
+    key: &str,
+    value: &serde_yaml::Value,
+) -> Result<String, TaskParseError> {
+    let rendered =
+        serde_yaml::to_string(value).map_err(|err| TaskParseError::Invalid(err.to_string()))?;
+    let placeholder = format!("{}: []", key);
+    let staged = update_front_matter_value(text, key, Some(FieldValue::Scalar("[]".to_string())))?;
+    let block: String = rendered
+        .lines()
+        .map(|line| format!("\n  {}", line))
+        .collect();
+    let mut replaced = false;
+    let mut lines: Vec<String> = Vec::new();
+    for line in staged.lines() {
+        if !replaced && line == placeholder {
+            replaced = true;
+            lines.push(format!("{}:{}", key, block));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    let mut out = lines.join("\n");
+    if staged.ends_with('\n') {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Append `entry` to the YAML list stored under `key`, creating the list when missing.
+pub fn append_front_matter_list_entry(
+    path: &Path,
+    key: &str,
+    entry: serde_yaml::Value,
+) -> Result<(), TaskParseError> {
+    mutate_task_file(path, |text| {
+        let (front, _) = split_front_matter(text)?;
+        let data: serde_yaml::Mapping = serde_yaml::from_str(&front).unwrap_or_default();
+        let mut list = match data.get(key) {
+            Some(serde_yaml::Value::Sequence(items)) => items.clone(),
+            _ => Vec::new(),
+        };
+        list.push(entry);
+        update_front_matter_yaml(text, key, &serde_yaml::Value::Sequence(list))
+    })
+}
+
 pub fn set_list_field(path: &Path, key: &str, new_list: Vec<String>) -> Result<(), TaskParseError> {
     update_task_field(path, key, Some(FieldValue::List(new_list)))
 }
 
+/// Add `value` to (or remove it from) the list under `key` and return the new list. The current
+/// list is read under the file lock, so concurrent writers never drop each other's entries the
+/// way a `set_list_field` with a list computed from an earlier read can.
+pub fn edit_list_field(
+    path: &Path,
+    key: &str,
+    value: &str,
+    add: bool,
+) -> Result<Vec<String>, TaskParseError> {
+    let value = value.trim().to_string();
+    let mut updated = Vec::new();
+    mutate_task_file(path, |text| {
+        let (front, _) = split_front_matter(text)?;
+        let data: serde_yaml::Mapping = serde_yaml::from_str(&front).unwrap_or_default();
+        let mut list = parse_list_value(data.get(key));
+        if add {
+            if !list.contains(&value) {
+                list.push(value.clone());
+            }
+        } else {
+            list.retain(|entry| entry != &value);
+        }
+        updated = list.clone();
+        update_front_matter_value(text, key, Some(FieldValue::List(list)))
+    })?;
+    Ok(updated)
+}
+
+/// Replace one `relationships` list (`blocked_by`, `parent`, `child`, `discovered_from`,
+/// `relates_to`), keeping the others.
+pub fn set_relationship_field(
+    path: &Path,
+    kind: &str,
+    ids: Vec<String>,
+) -> Result<(), TaskParseError> {
+    mutate_task_file(path, |text| {
+        let (front, _) = split_front_matter(text)?;
+        let data: serde_yaml::Mapping = serde_yaml::from_str(&front).unwrap_or_default();
+        let mut relationships = match data.get("relationships") {
+            Some(serde_yaml::Value::Mapping(map)) => map.clone(),
+            _ => serde_yaml::Mapping::new(),
+        };
+        relationships.insert(
+            kind.into(),
+            serde_yaml::Value::Sequence(ids.into_iter().map(serde_yaml::Value::String).collect()),
+        );
+        update_front_matter_yaml(
+            text,
+            "relationships",
+            &serde_yaml::Value::Mapping(relationships),
+        )
+    })
+}
+
 pub fn update_task_field_or_section(
     path: &Path,
     key: &str,
@@ -599,6 +1100,52 @@ pub fn update_task_field_or_section(
     update_task_field(path, key, field_value)
 }
 
+/// Ids of other existing tasks named in `text` (e.g. `task-042`), in order of first appearance.
+///
+/// Ids match whole words, case-insensitively; tokens that are not task ids are ignored.
+pub fn referenced_task_ids(text: &str, tasks: &[Task], self_id: &str) -> Vec<String> {
+    let known: HashMap<String, &str> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task.id.as_str()))
+        .collect();
+    let mut found: Vec<String> = Vec::new();
+    for token in text.split(|c: char| !(c.is_alphanumeric() || c == '-' || c == '_')) {
+        let token = token.trim_matches(['-', '_']).to_lowercase();
+        if token.is_empty() || token.eq_ignore_ascii_case(self_id) {
+            continue;
+        }
+        if let Some(id) = known.get(&token) {
+            if !found.iter().any(|seen| seen == id) {
+                found.push(id.to_string());
+            }
+        }
+    }
+    found
+}
+
+/// Add the tasks `note` references to `task`'s `relationships.relates_to` and return the newly
+/// linked ids. Already related tasks are left alone, so repeated mentions do not rewrite the file.
+pub fn link_note_references(
+    task: &Task,
+    tasks: &[Task],
+    note: &str,
+) -> Result<Vec<String>, TaskParseError> {
+    let Some(path) = task.file_path.as_ref() else {
+        return Ok(Vec::new());
+    };
+    let existing = &task.relationships.relates_to;
+    let added: Vec<String> = referenced_task_ids(note, tasks, &task.id)
+        .into_iter()
+        .filter(|id| !existing.iter().any(|rel| rel.eq_ignore_ascii_case(id)))
+        .collect();
+    if !added.is_empty() {
+        let mut relates_to = existing.clone();
+        relates_to.extend(added.iter().cloned());
+        set_relationship_field(path, "relates_to", relates_to)?;
+    }
+    Ok(added)
+}
+
 pub fn append_note(body: &str, note: &str, section: &str) -> String {
     let mut lines: Vec<String> = body.lines().map(|line| line.to_string()).collect();
     let note_line = format!("- {}", note.trim());
@@ -698,6 +1245,19 @@ pub fn replace_section(body: &str, section: &str, content: &str) -> String {
     finalize_lines(new_lines)
 }
 
+/// Append a note to the task at `path`, reading the body under the file lock so concurrent
+/// notes all land (see [`append_note`] for `section`).
+pub fn append_task_note(path: &Path, note: &str, section: &str) -> Result<(), TaskParseError> {
+    mutate_task_file(path, |text| {
+        let (front, body) = split_front_matter(text)?;
+        Ok(format!(
+            "---\n{}\n---\n{}",
+            front,
+            append_note(&body, note, section)
+        ))
+    })
+}
+
 pub fn update_body(path: &Path, new_body: &str) -> Result<(), TaskParseError> {
     let next_body = new_body.to_string();
     mutate_task_file(path, |text| {
@@ -757,6 +1317,88 @@ pub fn create_task_file_with_sections(
     )
 }
 
+/// Create a task whose body is written verbatim (e.g. a rendered template).
+#[allow(clippy::too_many_arguments)]
+pub fn create_task_file_with_body(
+    tasks_dir: &Path,
+    task_id: &str,
+    title: &str,
+    status: &str,
+    priority: &str,
+    phase: &str,
+    dependencies: &[String],
+    labels: &[String],
+    assignee: &[String],
+    body: &str,
+) -> Result<PathBuf, TaskParseError> {
+    let uid = Ulid::new().to_string();
+    let path = new_task_path(tasks_dir, task_id, title, &uid)?;
+    let mut lines = task_front_matter_lines(
+        task_id,
+        &uid,
+        title,
+        "task",
+        status,
+        priority,
+        phase,
+        dependencies,
+        labels,
+        assignee,
+    );
+    lines.extend(normalize_section_content(body));
+    lines.push(String::new());
+    write_string_atomic_locked(&path, &lines.join("\n"))?;
+    Ok(path)
+}
+
+/// Create a `kind: epic` document whose body follows the [`EPIC_SECTIONS`] schema.
+#[allow(clippy::too_many_arguments)]
+pub fn create_epic_file(
+    tasks_dir: &Path,
+    task_id: &str,
+    title: &str,
+    status: &str,
+    priority: &str,
+    phase: &str,
+    labels: &[String],
+    assignee: &[String],
+    sections: &EpicSectionContent,
+) -> Result<PathBuf, TaskParseError> {
+    let uid = Ulid::new().to_string();
+    let path = new_task_path(tasks_dir, task_id, title, &uid)?;
+    let mut lines = task_front_matter_lines(
+        task_id,
+        &uid,
+        title,
+        "epic",
+        status,
+        priority,
+        phase,
+        &[],
+        labels,
+        assignee,
+    );
+    lines.extend(epic_body_lines(sections));
+    write_string_atomic_locked(&path, &lines.join("\n"))?;
+    Ok(path)
+}
+
+fn epic_body_lines(sections: &EpicSectionContent) -> Vec<String> {
+    let mut lines = Vec::new();
+    for (section, content) in EPIC_SECTIONS.iter().zip([
+        &sections.problem,
+        &sections.outcomes,
+        &sections.scope,
+        &sections.non_goals,
+    ]) {
+        lines.push(format!("{}:", section));
+        lines.push("--------------------------------------------------".to_string());
+        lines.extend(normalize_section_content(content));
+        lines.push(String::new());
+    }
+    lines
+}
+
 fn create_task_file_internal(
     tasks_dir: &Path,
     task_id: &str,
@@ -769,11 +1411,10 @@ fn create_task_file_internal(
     assignee: &[String],
     sections: Option<&TaskSectionContent>,
 ) -> Result<PathBuf, TaskParseError> {
-    // Filenames are part of the git merge surface. Include a short UID suffix to avoid collisions
-    // when multiple branches create tasks with the same numeric id.
+    // Filenames are part of the git merge surface. The default template includes a short UID
+    // suffix to avoid collisions when multiple branches create tasks with the same numeric id.
     let uid = Ulid::new().to_string();
-    let filename = canonical_task_filename(task_id, title, &uid);
-    let path = tasks_dir.join(filename);
+    let path = new_task_path(tasks_dir, task_id, title, &uid)?;
     let content = task_template(
         task_id,
         &uid,
@@ -790,10 +1431,107 @@ fn create_task_file_internal(
     Ok(path)
 }
 
-pub fn canonical_task_filename(task_id: &str, title: &str, uid: &str) -> String {
+/// Filename layout used when `[task_files] template` is unset.
+pub const DEFAULT_TASK_FILENAME_TEMPLATE: &str = "{id} - {title} - {uid}.md";
+
+/// Task file naming (`[task_files]` table).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskFilesConfig {
+    /// Filename template. Placeholders: `{id}`, `{title}` (cleaned title), `{slug}` (cleaned
+    /// title joined with `-`) and `{uid}` (first 8 uid characters). `.md` is appended if missing.
+    pub template: Option<String>,
+    /// Rename files that follow the template when their title changes (default true).
+    pub rename_on_title_change: Option<bool>,
+}
+
+/// Project `[task_files]` wins over global `[task_files]`.
+pub fn resolve_task_files_config(repo_root: &Path) -> TaskFilesConfig {
+    load_config(repo_root)
+        .and_then(|config| config.task_files)
+        .or_else(|| load_global_config().and_then(|config| config.task_files))
+        .unwrap_or_default()
+}
+
+/// The filename template in effect for task files stored under `dir`.
+pub fn task_filename_template(dir: &Path) -> String {
+    resolve_task_files_config(&repo_root_from_state_root(dir))
+        .template
+        .filter(|template| !template.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_TASK_FILENAME_TEMPLATE.to_string())
+}
+
+pub fn canonical_task_filename(template: &str, task_id: &str, title: &str, uid: &str) -> String {
     let uid_short: String = uid.chars().take(8).collect();
     let filename_title = slug_title(title);
-    format!("{} - {} - {}.md", task_id, filename_title, uid_short)
+    let rendered = template
+        .trim()
+        .replace("{id}", task_id)
+        .replace("{slug}", &filename_title.replace(' ', "-"))
+        .replace("{title}", &filename_title)
+        .replace("{uid}", &uid_short)
+        .replace(['/', '\\'], "-");
+    if rendered.ends_with(".md") {
+        rendered
+    } else {
+        format!("{}.md", rendered)
+    }
+}
+
+// Templates without `{uid}` can collide (e.g. the same id created on two branches), so never
+// write over an existing file.
+fn new_task_path(
+    tasks_dir: &Path,
+    task_id: &str,
+    title: &str,
+    uid: &str,
+) -> Result<PathBuf, TaskParseError> {
+    let template = task_filename_template(tasks_dir);
+    let path = tasks_dir.join(canonical_task_filename(&template, task_id, title, uid));
+    if path.exists() {
+        return Err(TaskParseError::Invalid(format!(
+            "Refusing to overwrite existing file: {}",
+            path.display()
+        )));
+    }
+    Ok(path)
+}
+
+/// Keep the filename in step with a title change: a file named by the template for `old_title`
+/// is renamed to the name for its current title. Hand-named files are left alone. Returns the new
+/// path when the file moved.
+pub fn rename_task_file_for_title(
+    path: &Path,
+    old_title: &str,
+) -> Result<Option<PathBuf>, TaskParseError> {
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
+    let config = resolve_task_files_config(&repo_root_from_state_root(dir));
+    if !config.rename_on_title_change.unwrap_or(true) {
+        return Ok(None);
+    }
+    let template = config
+        .template
+        .filter(|template| !template.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_TASK_FILENAME_TEMPLATE.to_string());
+    let task = parse_task_file(path)?;
+    let uid = task.uid.as_deref().unwrap_or("");
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or("");
+    if file_name != canonical_task_filename(&template, &task.id, old_title, uid) {
+        return Ok(None);
+    }
+    let target = dir.join(canonical_task_filename(
+        &template,
+        &task.id,
+        &task.title,
+        uid,
+    ));
+    if target == path || target.exists() {
+        return Ok(None);
+    }
+    fs::rename(path, &target)?;
+    Ok(Some(target))
 }
 
 fn mutate_task_file<F>(path: &Path, mutator: F) -> Result<(), TaskParseError>
@@ -801,8 +1539,9 @@ where
     F: FnOnce(&str) -> Result<String, TaskParseError>,
 {
     with_path_lock(path, || {
-        let text = fs::read_to_string(path)?;
-        let updated = mutator(&text)?;
+        let text = read_task_file_text(path)?;
+        let key = ConfidentialKey::from_env()?;
+        let updated = mutate_confidential_text(&text, key.as_ref(), mutator)?;
         write_string_atomic(path, &updated)?;
         Ok(())
     })
@@ -1003,10 +1742,147 @@ pub fn is_lease_active(task: &Task) -> bool {
     let Some(expires_at) = lease.expires_at.as_deref() else {
         return true;
     };
-    let Ok(expiry) = NaiveDateTime::parse_from_str(expires_at, "%Y-%m-%d %H:%M") else {
+    let Some(expiry) = parse_stored_timestamp(expires_at) else {
         return true;
     };
-    Local::now().naive_local() <= expiry
+    Local::now() <= expiry
+}
+
+/// child id (lowercase) -> parent id (lowercase), from either side of the parent/child link.
+///
+/// A task's own `parent` wins over another task listing it as a `child`; only the first
+/// `parent` entry counts.
+pub fn parent_links(tasks: &[Task]) -> HashMap<String, String> {
+    let mut parents = HashMap::new();
+    for task in tasks {
+        for child in &task.relationships.child {
+            parents
+                .entry(child.trim().to_lowercase())
+                .or_insert_with(|| task.id.to_lowercase());
+        }
+    }
+    for task in tasks {
+        if let Some(parent) = task.relationships.parent.first() {
+            parents.insert(task.id.to_lowercase(), parent.trim().to_lowercase());
+        }
+    }
+    parents
+}
+
+/// Direct subtasks of `task_id`, in id order.
+pub fn child_tasks<'a>(tasks: &'a [Task], task_id: &str) -> Vec<&'a Task> {
+    descendant_tasks(tasks, task_id)
+        .into_iter()
+        .filter(|(depth, _)| *depth == 1)
+        .map(|(_, task)| task)
+        .collect()
+}
+
+/// Every task below `task_id` with its depth (1 = direct child), depth-first in id order.
+/// A parent cycle is cut at the first task seen twice.
+pub fn descendant_tasks<'a>(tasks: &'a [Task], task_id: &str) -> Vec<(usize, &'a Task)> {
+    let parents = parent_links(tasks);
+    let mut children: HashMap<String, Vec<&Task>> = HashMap::new();
+    for task in tasks {
+        if let Some(parent) = parents.get(&task.id.to_lowercase()) {
+            children.entry(parent.clone()).or_default().push(task);
+        }
+    }
+    for kids in children.values_mut() {
+        kids.sort_by_key(|task| (task.id_num(), task.id.to_lowercase()));
+    }
+    let root = task_id.trim().to_lowercase();
+    let mut seen = HashSet::from([root.clone()]);
+    let mut out = Vec::new();
+    let mut stack: Vec<(usize, &Task)> = children
+        .get(&root)
+        .map(|kids| kids.iter().rev().map(|task| (1, *task)).collect())
+        .unwrap_or_default();
+    while let Some((depth, task)) = stack.pop() {
+        let id = task.id.to_lowercase();
+        if !seen.insert(id.clone()) {
+            continue;
+        }
+        out.push((depth, task));
+        if let Some(kids) = children.get(&id) {
+            stack.extend(kids.iter().rev().map(|kid| (depth + 1, *kid)));
+        }
+    }
+    out
+}
+
+/// Progress of a task's subtree: closed (Done or Cancelled) and total subtasks at every depth.
+/// Nested epics are containers and, as in the checkpoint and `health` rollups, not counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct SubtaskRollup {
+    pub done: usize,
+    pub total: usize,
+}
+
+impl SubtaskRollup {
+    /// `4/9 done`
+    pub fn label(&self) -> String {
+        format!("{}/{} done", self.done, self.total)
+    }
+}
+
+/// Roll-up for every task that has subtasks, keyed by lowercase id.
+pub fn subtask_rollups(tasks: &[Task]) -> HashMap<String, SubtaskRollup> {
+    let parents = parent_links(tasks);
+    let mut rollups: HashMap<String, SubtaskRollup> = HashMap::new();
+    for task in tasks.iter().filter(|task| !is_epic(task)) {
+        let id = task.id.to_lowercase();
+        let mut seen = HashSet::from([id.clone()]);
+        let mut current = id;
+        while let Some(parent) = parents.get(&current) {
+            if !seen.insert(parent.clone()) {
+                break;
+            }
+            let rollup = rollups
+                .entry(parent.clone())
+                .or_insert(SubtaskRollup { done: 0, total: 0 });
+            rollup.total += 1;
+            if is_closed(task) {
+                rollup.done += 1;
+            }
+            current = parent.clone();
+        }
+    }
+    rollups
+}
+
+/// Parent chains that loop back on themselves, each starting at its smallest id.
+pub fn parent_cycles(tasks: &[Task]) -> Vec<Vec<String>> {
+    let parents = parent_links(tasks);
+    let display: HashMap<String, &str> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task.id.as_str()))
+        .collect();
+    let mut cycles: HashSet<Vec<String>> = HashSet::new();
+    for task in tasks {
+        let mut path = vec![task.id.to_lowercase()];
+        while let Some(parent) = parents.get(path.last().expect("path")) {
+            if let Some(start) = path.iter().position(|id| id == parent) {
+                let mut cycle = path[start..].to_vec();
+                let min = (0..cycle.len()).min_by_key(|i| &cycle[*i]).unwrap_or(0);
+                cycle.rotate_left(min);
+                cycles.insert(cycle);
+                break;
+            }
+            path.push(parent.clone());
+        }
+    }
+    let shown = |id: &String| {
+        display
+            .get(id)
+            .map_or_else(|| id.clone(), |id| id.to_string())
+    };
+    let mut cycles: Vec<Vec<String>> = cycles
+        .into_iter()
+        .map(|cycle| cycle.iter().map(shown).collect())
+        .collect();
+    cycles.sort();
+    cycles
 }
 
 pub fn validate_tasks(tasks: &[Task], backlog_dir: Option<&Path>) -> ValidationResult {
@@ -1021,6 +1897,13 @@ pub fn validate_tasks_with_rules(
     let mut errors = Vec::new();
     let mut warnings = Vec::new();
     let repo_root = backlog_dir.map(repo_root_from_backlog);
+    // Only templates that carry `{id}` promise it in the filename.
+    let ids_in_filenames = repo_root
+        .as_deref()
+        .and_then(|root| resolve_task_files_config(root).template)
+        .filter(|template| !template.trim().is_empty())
+        .map(|template| template.contains("{id}"))
+        .unwrap_or(true);
     let ids: Vec<String> = tasks
         .iter()
         .filter(|task| !task.id.is_empty())
@@ -1107,7 +1990,7 @@ pub fn validate_tasks_with_rules(
                 missing.join(", ")
             ));
         }
-        if let Some(path) = &task.file_path {
+        if let Some(path) = task.file_path.as_ref().filter(|_| ids_in_filenames) {
             if !path
                 .file_stem()
                 .and_then(|s| s.to_str())
@@ -1127,6 +2010,11 @@ pub fn validate_tasks_with_rules(
                 errors.push(format!("{} depends on missing task {}", task.id, dep));
             }
         }
+        for parent in &task.relationships.parent {
+            if !existing_ids.contains(&parent.trim().to_lowercase()) {
+                warnings.push(format!("{} has missing parent {}", task.id, parent));
+            }
+        }
         if let (Some(project), Some(repo_root)) = (task.project.as_deref(), repo_root.as_ref()) {
             let docs_dir = project_docs_dir(repo_root, project);
             if !docs_dir.join("README.md").is_file() {
@@ -1176,10 +2064,89 @@ pub fn validate_tasks_with_rules(
             ));
         }
     }
+    for cycle in parent_cycles(tasks) {
+        errors.push(format!(
+            "Parent cycle: {} -> {}",
+            cycle.join(" -> "),
+            cycle[0]
+        ));
+    }
+    if let Some(backlog_dir) = backlog_dir {
+        warnings.extend(task_file_parse_warnings(&tasks_dir_for_root(backlog_dir)));
+    }
 
     ValidationResult { errors, warnings }
 }
 
+/// Task files the parser had to repair (encoding, fences, duplicate keys) or could not load at
+/// all, which `load_tasks` otherwise skips without a word.
+fn task_file_parse_warnings(tasks_dir: &Path) -> Vec<String> {
+    let Ok(read_dir) = fs::read_dir(tasks_dir) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "md").unwrap_or(false))
+        .collect();
+    paths.sort();
+    let mut warnings = Vec::new();
+    for path in paths {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        match task_file_warnings(&path) {
+            Ok(notes) => {
+                warnings.extend(notes.into_iter().map(|note| format!("{}: {}", name, note)))
+            }
+            Err(err) => warnings.push(format!("{}: not loaded as a task: {}", name, err)),
+        }
+    }
+    warnings
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupBy {
+    Status,
+    Label,
+    Assignee,
+}
+
+impl GroupBy {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "status" => Some(GroupBy::Status),
+            "label" | "labels" => Some(GroupBy::Label),
+            "assignee" | "assignees" => Some(GroupBy::Assignee),
+            _ => None,
+        }
+    }
+}
+
+/// Task counts per group, largest first. A task counts once for each of its labels or
+/// assignees; tasks with none land in `(none)`.
+pub fn group_counts(tasks: &[&Task], by: GroupBy) -> Vec<(String, usize)> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for task in tasks {
+        let keys: Vec<String> = match by {
+            GroupBy::Status => vec![task.status.trim().to_string()],
+            GroupBy::Label => task.labels.iter().map(|v| v.trim().to_string()).collect(),
+            GroupBy::Assignee => task.assignee.iter().map(|v| v.trim().to_string()).collect(),
+        };
+        let keys: Vec<String> = keys.into_iter().filter(|key| !key.is_empty()).collect();
+        if keys.is_empty() {
+            *counts.entry("(none)".to_string()).or_default() += 1;
+        }
+        for key in keys {
+            *counts.entry(key).or_default() += 1;
+        }
+    }
+    let mut counts: Vec<(String, usize)> = counts.into_iter().collect();
+    counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    counts
+}
+
 pub fn status_counts(tasks: &[Task]) -> Vec<(String, usize)> {
     let mut counts: Vec<(String, usize)> = Vec::new();
     for task in tasks {
@@ -1214,6 +2181,154 @@ pub fn graph_export(tasks: &[Task]) -> serde_json::Value {
         })
         .collect();
 
+    serde_json::json!({
+        "nodes": nodes,
+        "edges": graph_edges(tasks),
+    })
+}
+
+/// The same graph as [`graph_export`] as a Mermaid `flowchart`, for pasting into Markdown.
+/// Nodes are colored by status and soft dependencies are dotted; edges pointing at unknown ids
+/// get a bare node of their own.
+pub fn mermaid_graph(tasks: &[Task]) -> String {
+    // Task ids may contain characters Mermaid ids cannot, so nodes are named by position.
+    let mut ids: HashMap<String, String> = HashMap::new();
+    let mut out = String::from("flowchart LR\n");
+    for task in tasks {
+        let node = format!("t{}", ids.len());
+        let label = format!("{} {}", task.id, task.title.trim()).replace('"', "'");
+        out.push_str(&format!("    {}[\"{}\"]\n", node, label.trim()));
+        ids.entry(task.id.to_lowercase()).or_insert(node);
+    }
+    let mut missing = 0;
+    let mut edge_lines = Vec::new();
+    for edge in graph_edges(tasks) {
+        let mut node_for = |id: &str, out: &mut String| {
+            ids.entry(id.to_lowercase())
+                .or_insert_with(|| {
+                    let node = format!("x{}", missing);
+                    missing += 1;
+                    out.push_str(&format!("    {}[\"{}\"]\n", node, id.replace('"', "'")));
+                    node
+                })
+                .clone()
+        };
+        let from = node_for(&edge.from, &mut out);
+        let to = node_for(&edge.to, &mut out);
+        let arrow = if edge.edge_type == SOFT_EDGE_TYPE {
+            "-.->"
+        } else {
+            "-->"
+        };
+        edge_lines.push(format!("    {} {}|{}| {}", from, arrow, edge.edge_type, to));
+    }
+    for line in edge_lines {
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    let mut classes: HashMap<&str, Vec<&str>> = HashMap::new();
+    for task in tasks {
+        let Some((class, _, _)) = graph_status_style(task) else {
+            continue;
+        };
+        if let Some(node) = ids.get(&task.id.to_lowercase()) {
+            classes.entry(class).or_default().push(node);
+        }
+    }
+    for (class, fill, stroke) in GRAPH_STATUS_STYLES {
+        if let Some(nodes) = classes.get(class) {
+            out.push_str(&format!(
+                "    classDef {} fill:{},stroke:{}\n",
+                class, fill, stroke
+            ));
+            out.push_str(&format!("    class {} {}\n", nodes.join(","), class));
+        }
+    }
+    out
+}
+
+/// The same graph as [`graph_export`] in Graphviz DOT, for `dot -Tsvg` and other tooling.
+/// Nodes are filled by status, `blocked_by` edges to unfinished tasks are drawn thick and red,
+/// soft dependencies are dashed amber, and the other relationship kinds are dashed gray. Ids referenced but not found are dashed nodes.
+pub fn dot_graph(tasks: &[Task]) -> String {
+    let mut out = String::from("digraph workmesh {\n    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=\"rounded,filled\", fillcolor=white];\n");
+    let mut known: HashMap<String, &Task> = HashMap::new();
+    for task in tasks {
+        let label = format!("{}\n{}", task.id, task.title.trim());
+        let mut attrs = format!("label=\"{}\"", escape_dot(label.trim()));
+        if let Some((_, fill, stroke)) = graph_status_style(task) {
+            attrs.push_str(&format!(", fillcolor=\"{}\", color=\"{}\"", fill, stroke));
+        }
+        out.push_str(&format!("    \"{}\" [{}];\n", escape_dot(&task.id), attrs));
+        known.entry(task.id.to_lowercase()).or_insert(task);
+    }
+    let edges = graph_edges(tasks);
+    let mut missing: Vec<&str> = Vec::new();
+    for edge in &edges {
+        for id in [&edge.from, &edge.to] {
+            if !known.contains_key(&id.to_lowercase()) && !missing.contains(&id.as_str()) {
+                missing.push(id);
+            }
+        }
+    }
+    for id in missing {
+        out.push_str(&format!(
+            "    \"{}\" [style=\"rounded,dashed\"];\n",
+            escape_dot(id)
+        ));
+    }
+    for edge in &edges {
+        let attrs = match edge.edge_type.as_str() {
+            "blocked_by" => {
+                let open = known
+                    .get(&edge.to.to_lowercase())
+                    .map(|task| !is_closed(task))
+                    .unwrap_or(true);
+                if open {
+                    "label=\"blocked_by\", color=\"#b91c1c\", penwidth=2".to_string()
+                } else {
+                    "label=\"blocked_by\"".to_string()
+                }
+            }
+            SOFT_EDGE_TYPE => format!(
+                "label=\"{}\", style=dashed, color=\"#d97706\"",
+                SOFT_EDGE_TYPE
+            ),
+            other => format!("label=\"{}\", style=dashed, color=gray50", other),
+        };
+        out.push_str(&format!(
+            "    \"{}\" -> \"{}\" [{}];\n",
+            escape_dot(&edge.from),
+            escape_dot(&edge.to),
+            attrs
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Status classes shared by the Mermaid and DOT graphs: class name, fill and border color.
+const GRAPH_STATUS_STYLES: [(&str, &str, &str); 3] = [
+    ("active", "#dbeafe", "#1d4ed8"),
+    ("blocked", "#fee2e2", "#b91c1c"),
+    ("done", "#dcfce7", "#15803d"),
+];
+
+fn graph_status_style(task: &Task) -> Option<(&'static str, &'static str, &'static str)> {
+    let class = match task.status.trim().to_lowercase().as_str() {
+        "done" => "done",
+        "in progress" => "active",
+        "blocked" => "blocked",
+        _ => return None,
+    };
+    GRAPH_STATUS_STYLES
+        .into_iter()
+        .find(|(name, _, _)| *name == class)
+}
+
+fn graph_edges(tasks: &[Task]) -> Vec<GraphEdge> {
     let mut edges: Vec<GraphEdge> = Vec::new();
     let mut seen: HashSet<GraphEdge> = HashSet::new();
 
@@ -1236,6 +2351,9 @@ pub fn graph_export(tasks: &[Task]) -> serde_json::Value {
         for rel in &task.relationships.blocked_by {
             add_edge(rel, "blocked_by");
         }
+        for dep in soft_dependencies(task) {
+            add_edge(&dep, SOFT_EDGE_TYPE);
+        }
         for rel in &task.relationships.parent {
             add_edge(rel, "parent");
         }
@@ -1245,12 +2363,12 @@ pub fn graph_export(tasks: &[Task]) -> serde_json::Value {
         for rel in &task.relationships.discovered_from {
             add_edge(rel, "discovered_from");
         }
+        for rel in &task.relationships.relates_to {
+            add_edge(rel, "relates_to");
+        }
     }
 
-    serde_json::json!({
-        "nodes": nodes,
-        "edges": edges,
-    })
+    edges
 }
 
 pub fn tasks_to_json(tasks: &[Task], include_body: bool) -> String {
@@ -1337,6 +2455,7 @@ pub fn task_to_json_value(task: &Task, include_body: bool) -> serde_json::Value
             "parent": task.relationships.parent.clone(),
             "child": task.relationships.child.clone(),
             "discovered_from": task.relationships.discovered_from.clone(),
+            "relates_to": task.relationships.relates_to.clone(),
         }),
     );
     map.insert(
@@ -1408,7 +2527,7 @@ fn should_warn_missing_dependencies(task: &Task) -> bool {
     task.dependencies.is_empty()
 }
 
-fn extract_section_content(body: &str, section: &str) -> Option<String> {
+pub(crate) fn extract_section_content(body: &str, section: &str) -> Option<String> {
     let lines: Vec<&str> = body.lines().collect();
     let start_header = lines
         .iter()
@@ -1452,7 +2571,14 @@ fn line_is_section_boundary(line: &str) -> bool {
     }
     matches!(
         normalized.as_str(),
-        "description:" | "acceptance criteria:" | "definition of done:" | "notes:"
+        "description:"
+            | "acceptance criteria:"
+            | "definition of done:"
+            | "notes:"
+            | "problem:"
+            | "outcomes:"
+            | "scope:"
+            | "non-goals:"
     )
 }
 
@@ -1574,24 +2700,25 @@ fn decode_percent_escapes(input: &str) -> String {
     String::from_utf8_lossy(&decoded).to_string()
 }
 
-fn task_template(
+#[allow(clippy::too_many_arguments)]
+fn task_front_matter_lines(
     task_id: &str,
     uid: &str,
     title: &str,
+    kind: &str,
     status: &str,
     priority: &str,
     phase: &str,
     dependencies: &[String],
     labels: &[String],
     assignee: &[String],
-    sections: Option<&TaskSectionContent>,
-) -> String {
+) -> Vec<String> {
     let mut front = Vec::new();
     front.push("---".to_string());
     front.push(format!("id: {}", task_id));
     front.push(format!("uid: {}", uid));
     front.push(format!("title: {}", title));
-    front.push("kind: task".to_string());
+    front.push(format!("kind: {}", kind));
     front.push(format!("status: {}", status));
     front.push(format!("priority: {}", priority));
     front.push(format!("phase: {}", phase));
@@ -1614,6 +2741,33 @@ fn task_template(
     front.push("  discovered_from: []".to_string());
     front.push("---".to_string());
     front.push(String::new());
+    front
+}
+
+fn task_template(
+    task_id: &str,
+    uid: &str,
+    title: &str,
+    status: &str,
+    priority: &str,
+    phase: &str,
+    dependencies: &[String],
+    labels: &[String],
+    assignee: &[String],
+    sections: Option<&TaskSectionContent>,
+) -> String {
+    let mut front = task_front_matter_lines(
+        task_id,
+        uid,
+        title,
+        "task",
+        status,
+        priority,
+        phase,
+        dependencies,
+        labels,
+        assignee,
+    );
     match sections {
         Some(sections) => {
             front.push("Description:".to_string());
@@ -1672,17 +2826,7 @@ pub fn validate_task_creation_with_rules(
     rules: &TaskValidationRules,
 ) -> Result<String, String> {
     if draft {
-        let normalized = status.trim();
-        if normalized.is_empty()
-            || normalized.eq_ignore_ascii_case("to do")
-            || normalized.eq_ignore_ascii_case("draft")
-        {
-            return Ok("Draft".to_string());
-        }
-        if normalized.eq_ignore_ascii_case("needs refinement") {
-            return Ok("Needs Refinement".to_string());
-        }
-        return Err("Draft task creation must use Draft or Needs Refinement status".to_string());
+        return draft_creation_status(status, "task");
     }
     if is_draft_status(status) {
         return Err(
@@ -1732,6 +2876,64 @@ pub fn validate_task_creation_with_rules(
     Ok(status.trim().to_string())
 }
 
+fn draft_creation_status(status: &str, noun: &str) -> Result<String, String> {
+    let normalized = status.trim();
+    if normalized.is_empty()
+        || normalized.eq_ignore_ascii_case("to do")
+        || normalized.eq_ignore_ascii_case("draft")
+    {
+        return Ok("Draft".to_string());
+    }
+    if normalized.eq_ignore_ascii_case("needs refinement") {
+        return Ok("Needs Refinement".to_string());
+    }
+    Err(format!(
+        "Draft {} creation must use Draft or Needs Refinement status",
+        noun
+    ))
+}
+
+/// Like [`validate_task_creation_with_rules`], checking the epic schema instead.
+pub fn validate_epic_creation_with_rules(
+    status: &str,
+    draft: bool,
+    sections: &EpicSectionContent,
+    rules: &TaskValidationRules,
+) -> Result<String, String> {
+    if draft {
+        return draft_creation_status(status, "epic");
+    }
+    if is_draft_status(status) {
+        return Err(
+            "Use the explicit draft flag to create incomplete Draft or Needs Refinement epics"
+                .to_string(),
+        );
+    }
+    let epic = Task {
+        id: "task-temp".to_string(),
+        uid: None,
+        kind: "epic".to_string(),
+        title: "temp".to_string(),
+        status: status.trim().to_string(),
+        priority: "P2".to_string(),
+        phase: "Phase1".to_string(),
+        dependencies: Vec::new(),
+        labels: Vec::new(),
+        assignee: Vec::new(),
+        relationships: Default::default(),
+        lease: None,
+        project: None,
+        initiative: None,
+        created_date: None,
+        updated_date: None,
+        extra: HashMap::new(),
+        file_path: None,
+        body: epic_body_lines(sections).join("\n"),
+    };
+    ensure_task_quality_for_actionable_with_rules(&epic, rules)?;
+    Ok(status.trim().to_string())
+}
+
 fn is_dash_line(line: &str) -> bool {
     let stripped = line.trim();
     !stripped.is_empty() && stripped.chars().all(|c| c == '-') && stripped.len() >= 3
@@ -1760,6 +2962,10 @@ fn is_section_header(lines: &[String], idx: usize) -> bool {
             "acceptance criteria:",
             "definition of done:",
             "notes:",
+            "problem:",
+            "outcomes:",
+            "scope:",
+            "non-goals:",
         ];
         if known
             .iter()
@@ -1806,7 +3012,7 @@ fn replace_impl_notes(body: &str, content: &str) -> String {
     finalize_lines(lines)
 }
 
-fn section_name_for_field(field: &str) -> Option<String> {
+pub(crate) fn section_name_for_field(field: &str) -> Option<String> {
     let normalized = field
         .trim()
         .to_lowercase()
@@ -1854,8 +3060,28 @@ mod tests {
     use std::thread;
 
     use super::*;
+    use crate::task::load_tasks;
     use tempfile::TempDir;
 
+    fn complete_epic_body() -> String {
+        "Problem:\n\
+--------------------------------------------------\n\
+- Sign-in is scattered across services.\n\
+\n\
+Outcomes:\n\
+--------------------------------------------------\n\
+- One login flow for every client.\n\
+\n\
+Scope:\n\
+--------------------------------------------------\n\
+- Web and CLI login.\n\
+\n\
+Non-goals:\n\
+--------------------------------------------------\n\
+- SSO federation.\n"
+            .to_string()
+    }
+
     fn complete_task_body() -> String {
         "Description:\n\
 --------------------------------------------------\n\
@@ -1924,6 +3150,56 @@ Definition of Done:\n\
         assert!(updated.contains("- Test note"));
     }
 
+    #[test]
+    fn link_note_references_adds_relates_to_once() {
+        let temp = TempDir::new().expect("tempdir");
+        let tasks_dir = temp.path().join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        for (id, title) in [
+            ("task-001", "One"),
+            ("task-002", "Two"),
+            ("task-010", "Ten"),
+        ] {
+            create_task_file(
+                &tasks_dir,
+                id,
+                title,
+                "To Do",
+                "P2",
+                "Phase1",
+                &[],
+                &[],
+                &[],
+            )
+            .expect("create");
+        }
+        let tasks = load_tasks(temp.path());
+        let note = "Same bug as TASK-002; see task-002, task-404, task-0100 and task-001.";
+        assert_eq!(
+            referenced_task_ids(note, &tasks, "task-001"),
+            vec!["task-002"]
+        );
+
+        let linked = link_note_references(&tasks[0], &tasks, note).expect("link");
+        assert_eq!(linked, vec!["task-002"]);
+        let tasks = load_tasks(temp.path());
+        assert_eq!(tasks[0].relationships.relates_to, vec!["task-002"]);
+
+        let again = "task-002 again, now with task-010";
+        let linked = link_note_references(&tasks[0], &tasks, again).expect("link");
+        assert_eq!(linked, vec!["task-010"]);
+        let tasks = load_tasks(temp.path());
+        assert_eq!(
+            tasks[0].relationships.relates_to,
+            vec!["task-002", "task-010"]
+        );
+        assert!(graph_export(&tasks)["edges"]
+            .as_array()
+            .expect("edges")
+            .iter()
+            .any(|edge| edge["edge_type"] == "relates_to" && edge["to"] == "task-010"));
+    }
+
     #[test]
     fn create_task_file_writes_template() {
         let temp = TempDir::new().expect("tempdir");
@@ -2048,13 +3324,59 @@ Description:\n\
 --------------------------------------------------\n\
 - existing\n",
         )
-        .expect("write task");
+        .expect("write task");
+
+        let added = normalize_task_required_sections(&path).expect("normalize");
+        assert_eq!(added.len(), 2);
+        let updated = fs::read_to_string(&path).expect("read");
+        assert!(updated.contains("Acceptance Criteria:"));
+        assert!(updated.contains("Definition of Done:"));
+    }
+
+    #[test]
+    fn epic_documents_follow_the_epic_section_schema() {
+        let temp = TempDir::new().expect("tempdir");
+        let sections = EpicSectionContent {
+            problem: "Sign-in is scattered across services.".to_string(),
+            outcomes: "- One login flow".to_string(),
+            scope: "- Web and CLI".to_string(),
+            non_goals: String::new(),
+        };
+        let rules = TaskValidationRules::default();
+        let err = validate_epic_creation_with_rules("To Do", false, &sections, &rules)
+            .expect_err("non-goals empty");
+        assert!(err.contains("Non-goals"));
+
+        let path = create_epic_file(
+            temp.path(),
+            "task-010",
+            "Unified login",
+            "Draft",
+            "P1",
+            "Phase1",
+            &["auth".to_string()],
+            &[],
+            &sections,
+        )
+        .expect("create epic");
+        let epic = crate::task::parse_task_file(&path).expect("parse");
+        assert!(is_epic(&epic));
+        let report = evaluate_task_quality(&epic);
+        assert!(report.missing_sections.is_empty());
+        assert_eq!(report.incomplete_sections, vec!["Non-goals".to_string()]);
+        let result = validate_tasks(&[epic], None);
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.contains("task-010 has incomplete sections: Non-goals")));
 
+        fs::write(
+            &path,
+            "---\nid: task-010\ntitle: Unified login\nkind: epic\nstatus: Draft\n---\n\n",
+        )
+        .expect("rewrite");
         let added = normalize_task_required_sections(&path).expect("normalize");
-        assert_eq!(added.len(), 2);
-        let updated = fs::read_to_string(&path).expect("read");
-        assert!(updated.contains("Acceptance Criteria:"));
-        assert!(updated.contains("Definition of Done:"));
+        assert_eq!(added, EPIC_SECTIONS.map(str::to_string).to_vec());
     }
 
     #[test]
@@ -2192,6 +3514,7 @@ Description:\n\
                 parent: Vec::new(),
                 child: Vec::new(),
                 discovered_from: Vec::new(),
+                relates_to: vec![],
             },
             lease: None,
             project: None,
@@ -2286,6 +3609,7 @@ Description:\n\
                 parent: vec!["task-004".to_string()],
                 child: vec!["task-005".to_string()],
                 discovered_from: vec!["task-006".to_string()],
+                relates_to: vec![],
             },
             lease: None,
             project: None,
@@ -2318,6 +3642,98 @@ Description:\n\
             .any(|edge| edge["edge_type"] == "discovered_from" && edge["to"] == "task-006"));
     }
 
+    #[test]
+    fn mermaid_graph_renders_nodes_edges_and_status_classes() {
+        let mut second = hierarchy_task("task-002", "Done", Some("task-001"), &[]);
+        second.title = "Say \"hi\"".to_string();
+        second.dependencies = vec!["task-404".to_string()];
+        let tasks = vec![
+            hierarchy_task("task-001", "In Progress", None, &["task-002"]),
+            second,
+        ];
+        let text = mermaid_graph(&tasks);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "flowchart LR",
+                "    t0[\"task-001 task-001\"]",
+                "    t1[\"task-002 Say 'hi'\"]",
+                "    x0[\"task-404\"]",
+                "    t0 -->|child| t1",
+                "    t1 -->|blocked_by| x0",
+                "    t1 -->|parent| t0",
+                "    classDef active fill:#dbeafe,stroke:#1d4ed8",
+                "    class t0 active",
+                "    classDef done fill:#dcfce7,stroke:#15803d",
+                "    class t1 done",
+            ]
+        );
+    }
+
+    #[test]
+    fn dot_graph_colors_statuses_and_highlights_open_blockers() {
+        let mut second = hierarchy_task("task-002", "Done", Some("task-001"), &[]);
+        second.title = "Say \"hi\"".to_string();
+        let mut third = hierarchy_task("task-003", "To Do", None, &[]);
+        third.dependencies = vec![
+            "task-001".to_string(),
+            "task-002".to_string(),
+            "task-404".to_string(),
+        ];
+        let tasks = vec![
+            hierarchy_task("task-001", "In Progress", None, &[]),
+            second,
+            third,
+        ];
+        let text = dot_graph(&tasks);
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(
+            lines,
+            vec![
+                "digraph workmesh {",
+                "    rankdir=LR;",
+                r#"    node [shape=box, style="rounded,filled", fillcolor=white];"#,
+                r##"    "task-001" [label="task-001\ntask-001", fillcolor="#dbeafe", color="#1d4ed8"];"##,
+                r##"    "task-002" [label="task-002\nSay \"hi\"", fillcolor="#dcfce7", color="#15803d"];"##,
+                r#"    "task-003" [label="task-003\ntask-003"];"#,
+                r#"    "task-404" [style="rounded,dashed"];"#,
+                r#"    "task-002" -> "task-001" [label="parent", style=dashed, color=gray50];"#,
+                r##"    "task-003" -> "task-001" [label="blocked_by", color="#b91c1c", penwidth=2];"##,
+                r#"    "task-003" -> "task-002" [label="blocked_by"];"#,
+                r##"    "task-003" -> "task-404" [label="blocked_by", color="#b91c1c", penwidth=2];"##,
+                "}",
+            ]
+        );
+    }
+
+    #[test]
+    fn soft_dependencies_warn_without_blocking_and_render_dotted() {
+        let mut second = hierarchy_task("task-002", "To Do", None, &[]);
+        second.extra.insert(
+            SOFT_DEPENDENCIES_FIELD.to_string(),
+            serde_yaml::from_str("[task-001, task-003]").expect("yaml"),
+        );
+        let tasks = vec![
+            hierarchy_task("task-001", "In Progress", None, &[]),
+            second,
+            hierarchy_task("task-003", "Done", None, &[]),
+        ];
+        let ready: Vec<&str> = ready_tasks(&tasks).iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ready, vec!["task-002"]);
+        let warnings = soft_dependency_warnings(&tasks);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings["task-002"], vec!["task-001".to_string()]);
+
+        let mermaid = mermaid_graph(&tasks);
+        assert!(mermaid.contains("    t1 -.->|soft_blocked_by| t0"));
+        assert!(mermaid.contains("    t1 -.->|soft_blocked_by| t2"));
+        let dot = dot_graph(&tasks);
+        assert!(dot.contains(
+            r##""task-002" -> "task-001" [label="soft_blocked_by", style=dashed, color="#d97706"];"##
+        ));
+    }
+
     #[test]
     fn validate_allows_duplicate_ids_with_unique_uids() {
         let task_a = Task {
@@ -2421,6 +3837,101 @@ Description:\n\
             .any(|err| err.contains("Duplicate task uid")));
     }
 
+    fn hierarchy_task(id: &str, status: &str, parent: Option<&str>, child: &[&str]) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: vec!["core".to_string()],
+            assignee: Vec::new(),
+            relationships: crate::task::Relationships {
+                parent: parent.map(|p| vec![p.to_string()]).unwrap_or_default(),
+                child: child.iter().map(|c| c.to_string()).collect(),
+                ..Default::default()
+            },
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: complete_task_body(),
+        }
+    }
+
+    #[test]
+    fn hierarchy_traversal_rolls_up_subtasks() {
+        let tasks = vec![
+            hierarchy_task("task-001", "In Progress", None, &["task-003"]),
+            hierarchy_task("task-002", "Done", Some("task-001"), &[]),
+            hierarchy_task("task-003", "To Do", None, &[]),
+            hierarchy_task("task-004", "Done", Some("task-003"), &[]),
+            hierarchy_task("task-005", "To Do", Some("TASK-004"), &[]),
+            hierarchy_task("task-006", "Cancelled", Some("task-003"), &[]),
+        ];
+        let ids = |found: Vec<&Task>| found.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+        assert_eq!(
+            ids(child_tasks(&tasks, "task-001")),
+            vec!["task-002", "task-003"]
+        );
+        let descendants: Vec<(usize, &str)> = descendant_tasks(&tasks, "task-001")
+            .into_iter()
+            .map(|(depth, task)| (depth, task.id.as_str()))
+            .collect();
+        assert_eq!(
+            descendants,
+            vec![
+                (1, "task-002"),
+                (1, "task-003"),
+                (2, "task-004"),
+                (3, "task-005"),
+                (2, "task-006")
+            ]
+        );
+
+        let rollups = subtask_rollups(&tasks);
+        assert_eq!(rollups["task-001"], SubtaskRollup { done: 3, total: 5 });
+        assert_eq!(rollups["task-003"].label(), "2/3 done");
+        assert!(!rollups.contains_key("task-005"));
+        assert!(parent_cycles(&tasks).is_empty());
+        assert!(validate_tasks(&tasks, None)
+            .errors
+            .iter()
+            .all(|err| !err.contains("cycle")));
+    }
+
+    #[test]
+    fn validate_reports_parent_cycles_and_missing_parents() {
+        let tasks = vec![
+            hierarchy_task("task-001", "To Do", Some("task-003"), &[]),
+            hierarchy_task("task-002", "To Do", Some("task-001"), &[]),
+            hierarchy_task("task-003", "To Do", Some("task-002"), &[]),
+            hierarchy_task("task-004", "To Do", Some("task-002"), &[]),
+            hierarchy_task("task-005", "To Do", Some("task-404"), &[]),
+        ];
+        assert_eq!(
+            parent_cycles(&tasks),
+            vec![vec!["task-001", "task-003", "task-002"]]
+        );
+        // Traversal still terminates inside the loop.
+        assert_eq!(descendant_tasks(&tasks, "task-001").len(), 3);
+        assert_eq!(subtask_rollups(&tasks)["task-002"].total, 3);
+
+        let report = validate_tasks(&tasks, None);
+        assert!(report
+            .errors
+            .contains(&"Parent cycle: task-001 -> task-003 -> task-002 -> task-001".to_string()));
+        assert!(report
+            .warnings
+            .contains(&"task-005 has missing parent task-404".to_string()));
+    }
+
     #[test]
     fn filter_tasks_applies_common_filters_and_search() {
         let tasks = vec![
@@ -2619,6 +4130,255 @@ Description:\n\
         assert_eq!(sorted[1].id, "task-001");
     }
 
+    #[test]
+    fn group_counts_split_labels_and_bucket_missing_values() {
+        let base = Task {
+            id: "task-001".to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: "A".to_string(),
+            status: "To Do".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: vec!["api".to_string(), "auth".to_string()],
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: complete_task_body(),
+        };
+        let second = Task {
+            id: "task-002".to_string(),
+            status: "Done".to_string(),
+            labels: vec!["api".to_string()],
+            ..base.clone()
+        };
+        let third = Task {
+            id: "task-003".to_string(),
+            labels: Vec::new(),
+            ..base.clone()
+        };
+        let tasks = vec![&base, &second, &third];
+
+        assert_eq!(
+            group_counts(&tasks, GroupBy::Status),
+            vec![("To Do".to_string(), 2), ("Done".to_string(), 1)]
+        );
+        assert_eq!(
+            group_counts(&tasks, GroupBy::Label),
+            vec![
+                ("api".to_string(), 2),
+                ("(none)".to_string(), 1),
+                ("auth".to_string(), 1)
+            ]
+        );
+        assert_eq!(GroupBy::parse("labels"), Some(GroupBy::Label));
+        assert_eq!(GroupBy::parse("phase"), None);
+    }
+
+    #[test]
+    fn field_filters_compare_numbers_strings_and_lists() {
+        assert_eq!(
+            FieldFilter::parse("estimate >= 5"),
+            Some(FieldFilter {
+                field: "estimate".to_string(),
+                op: FieldOp::Ge,
+                value: "5".to_string(),
+            })
+        );
+        assert!(FieldFilter::parse("estimate>=").is_none());
+        assert!(FieldFilter::parse("no operator").is_none());
+
+        let base = Task {
+            id: "task-001".to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: "A".to_string(),
+            status: "To Do".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: vec!["auth".to_string(), "api".to_string()],
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: complete_task_body(),
+        };
+        let mut with_fields = base.clone();
+        with_fields.id = "task-002".to_string();
+        with_fields.labels = Vec::new();
+        with_fields
+            .extra
+            .insert("estimate".to_string(), serde_yaml::Value::from(10));
+        with_fields
+            .extra
+            .insert("score".to_string(), serde_yaml::Value::from(2.0));
+        with_fields.extra.insert(
+            "severity".to_string(),
+            serde_yaml::Value::String("S1".to_string()),
+        );
+        let tasks = vec![&base, &with_fields];
+        let ids = |raw: &[&str]| -> Vec<String> {
+            let filters: Vec<FieldFilter> = raw
+                .iter()
+                .map(|raw| FieldFilter::parse(raw).expect("filter"))
+                .collect();
+            filter_tasks_by_fields(tasks.clone(), &filters)
+                .iter()
+                .map(|task| task.id.clone())
+                .collect()
+        };
+
+        // 10 >= 5 numerically even though "10" < "5" as text.
+        assert_eq!(
+            ids(&["estimate>=5", "score<2.5", "severity=s1"]),
+            vec!["task-002"]
+        );
+        assert!(ids(&["estimate<5"]).is_empty());
+        assert_eq!(ids(&["labels=api"]), vec!["task-001"]);
+        assert_eq!(ids(&["severity!=S1"]), vec!["task-001"]);
+        assert_eq!(ids(&["priority=P2"]).len(), 2);
+    }
+
+    #[test]
+    fn filter_tasks_by_dates_applies_created_and_updated_windows() {
+        let base = Task {
+            id: "task-001".to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: "A".to_string(),
+            status: "To Do".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: Some("2026-01-01 10:00".to_string()),
+            updated_date: Some("2026-03-01 10:00".to_string()),
+            extra: HashMap::new(),
+            file_path: None,
+            body: complete_task_body(),
+        };
+        let recent = Task {
+            id: "task-002".to_string(),
+            created_date: Some("2026-02-20 09:00".to_string()),
+            updated_date: Some("2026-03-05 12:00".to_string()),
+            ..base.clone()
+        };
+        let undated = Task {
+            id: "task-003".to_string(),
+            created_date: None,
+            updated_date: None,
+            ..base.clone()
+        };
+        let at = |value: &str| {
+            NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").expect("timestamp")
+        };
+        let tasks = vec![&base, &recent, &undated];
+
+        let ids = |filter: &DateFilter| -> Vec<String> {
+            filter_tasks_by_dates(tasks.clone(), filter)
+                .iter()
+                .map(|task| task.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(&DateFilter::default()).len(), 3);
+        let updated = DateFilter {
+            updated_after: Some(at("2026-03-02 00:00")),
+            ..DateFilter::default()
+        };
+        assert_eq!(ids(&updated), vec!["task-002"]);
+        let created = DateFilter {
+            created_before: Some(at("2026-02-01 00:00")),
+            updated_before: Some(at("2026-04-01 00:00")),
+            ..DateFilter::default()
+        };
+        assert_eq!(ids(&created), vec!["task-001"]);
+    }
+
+    #[test]
+    fn sort_tasks_supports_multiple_keys_descending_and_custom_fields() {
+        let base = Task {
+            id: "task-001".to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: "A".to_string(),
+            status: "To Do".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: complete_task_body(),
+        };
+        let with = |id: &str, priority: &str, updated: Option<&str>, estimate: Option<i64>| {
+            let mut extra = HashMap::new();
+            if let Some(estimate) = estimate {
+                extra.insert("estimate".to_string(), serde_yaml::Value::from(estimate));
+            }
+            Task {
+                id: id.to_string(),
+                priority: priority.to_string(),
+                updated_date: updated.map(str::to_string),
+                extra,
+                ..base.clone()
+            }
+        };
+        let tasks = [
+            with("task-001", "P2", Some("2026-01-01 10:00"), Some(10)),
+            with("task-002", "P1", Some("2026-01-01 09:00"), Some(9)),
+            with("task-003", "P1", Some("2026-01-02 09:00"), None),
+            with("task-004", "P1", None, Some(2)),
+        ];
+        let refs: Vec<&Task> = tasks.iter().collect();
+
+        let sorted = sort_tasks(refs.clone(), "priority, -updated_date");
+        let ids: Vec<&str> = sorted.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, vec!["task-003", "task-002", "task-004", "task-001"]);
+
+        // Numeric-aware: 2 < 9 < 10, and tasks without the field go last.
+        let sorted = sort_tasks(refs.clone(), "estimate");
+        let ids: Vec<&str> = sorted.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, vec!["task-004", "task-002", "task-001", "task-003"]);
+
+        let sorted = sort_tasks(refs, "-id");
+        assert_eq!(sorted[0].id, "task-004");
+
+        // Offsets differ, so text order (001, 002, 003) is not time order.
+        let tasks = [
+            with("task-001", "P2", Some("2026-03-01T10:00:00+00:00"), None),
+            with("task-002", "P2", Some("2026-03-01T20:00:00-05:00"), None),
+            with("task-003", "P2", Some("2026-03-01T22:00:00+09:00"), None),
+        ];
+        let sorted = sort_tasks(tasks.iter().collect(), "updated_date");
+        let ids: Vec<&str> = sorted.iter().map(|task| task.id.as_str()).collect();
+        assert_eq!(ids, vec!["task-001", "task-003", "task-002"]);
+    }
+
     #[test]
     fn update_front_matter_value_can_remove_and_insert_fields() {
         let text = "---\nstatus: To Do\nlabels: [a, b]\n---\nBody\n";
@@ -2775,6 +4535,54 @@ Description:\n\
             .contains("untitled"));
     }
 
+    #[test]
+    fn task_files_template_names_new_files_and_follows_title_changes() {
+        let temp = TempDir::new().expect("tempdir");
+        fs::write(
+            temp.path().join(".workmesh.toml"),
+            "[task_files]\ntemplate = \"{id}--{slug}\"\n",
+        )
+        .expect("config");
+        let tasks_dir = temp.path().join("workmesh").join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        let create = || {
+            create_task_file(
+                &tasks_dir,
+                "task-001",
+                "Fix login bug",
+                "To Do",
+                "P2",
+                "Phase1",
+                &[],
+                &[],
+                &[],
+            )
+        };
+        let path = create().expect("create");
+        assert_eq!(path, tasks_dir.join("task-001--fix-login-bug.md"));
+        assert!(create().is_err(), "must not overwrite an existing file");
+
+        update_task_field(&path, "title", Some(FieldValue::Scalar("Fix SSO".into())))
+            .expect("title");
+        let renamed = rename_task_file_for_title(&path, "Fix login bug")
+            .expect("rename")
+            .expect("renamed");
+        assert_eq!(renamed, tasks_dir.join("task-001--fix-sso.md"));
+        assert!(!path.exists());
+
+        fs::rename(&renamed, tasks_dir.join("custom.md")).expect("hand rename");
+        update_task_field(
+            &tasks_dir.join("custom.md"),
+            "title",
+            Some(FieldValue::Scalar("Other".into())),
+        )
+        .expect("title");
+        assert_eq!(
+            rename_task_file_for_title(&tasks_dir.join("custom.md"), "Fix SSO").expect("rename"),
+            None
+        );
+    }
+
     #[test]
     fn validate_task_creation_requires_quality_unless_draft() {
         let incomplete = TaskSectionContent {
@@ -2801,6 +4609,7 @@ Description:\n\
             require_acceptance_criteria: false,
             require_definition_of_done: true,
             require_outcome_based_definition_of_done: false,
+            ..TaskValidationRules::default()
         };
 
         let status = validate_task_creation_with_rules("To Do", false, &incomplete, &rules)
@@ -2866,12 +4675,78 @@ Description:\n\
             require_acceptance_criteria: false,
             require_definition_of_done: false,
             require_outcome_based_definition_of_done: false,
+            ..TaskValidationRules::default()
         };
 
         ensure_can_set_status_with_rules(std::slice::from_ref(&task), &task, "To Do", &rules)
             .expect("relaxed rules should allow actionable status");
     }
 
+    #[test]
+    fn done_definition_rules_report_each_failure() {
+        let mut task = Task {
+            id: "task-102".to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: "Ship refunds".to_string(),
+            status: "In Progress".to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: "Acceptance Criteria:\n- [x] Refund endpoint exists\n- [ ] Refunds are audited\n\n## Implementation Notes\n- \n".to_string(),
+        };
+        task.extra.insert(
+            "reviewer".to_string(),
+            serde_yaml::Value::String("ana".to_string()),
+        );
+        let rules = TaskValidationRules {
+            require_description: false,
+            require_acceptance_criteria: false,
+            require_definition_of_done: false,
+            require_outcome_based_definition_of_done: false,
+            require_implementation_notes: true,
+            require_acceptance_criteria_checked: true,
+            require_linked_commit: true,
+            require_review_approval: true,
+        };
+
+        assert_eq!(
+            done_definition_failures(&task, &rules),
+            vec![
+                "Implementation Notes are empty".to_string(),
+                "unchecked acceptance criteria: Refunds are audited".to_string(),
+                "no linked commit (add `commits:` or mention task-102 in a commit message)"
+                    .to_string(),
+                "no review approval from ana (set `approved_by:`)".to_string(),
+            ]
+        );
+        let err =
+            ensure_can_set_status_with_rules(std::slice::from_ref(&task), &task, "Done", &rules)
+                .expect_err("should block");
+        assert!(err.contains("definition of done"));
+
+        task.body = "Acceptance Criteria:\n- [x] Refund endpoint exists\n- [X] Refunds are audited\n\n## Implementation Notes\n- Reused the ledger writer.\n".to_string();
+        for (key, value) in [("commits", "abc1234"), ("approved_by", "ana")] {
+            task.extra.insert(
+                key.to_string(),
+                serde_yaml::Value::String(value.to_string()),
+            );
+        }
+        ensure_can_set_status_with_rules(std::slice::from_ref(&task), &task, "Done", &rules)
+            .expect("all done rules met");
+        assert!(done_definition_failures(&task, &TaskValidationRules::default()).is_empty());
+    }
+
     #[test]
     fn next_task_picks_lowest_ready_task() {
         let tasks = vec![
@@ -3132,7 +5007,7 @@ Description:\n\
             updated_date: None,
             extra: HashMap::new(),
             file_path: None,
-            body: complete_task_body(),
+            body: complete_epic_body(),
         };
         let child = Task {
             id: "task-main-101".to_string(),
@@ -3150,6 +5025,7 @@ Description:\n\
                 parent: vec!["task-main-100".to_string()],
                 child: vec![],
                 discovered_from: vec![],
+                relates_to: vec![],
             },
             lease: None,
             project: None,
@@ -3186,7 +5062,7 @@ Description:\n\
             updated_date: None,
             extra: HashMap::new(),
             file_path: None,
-            body: complete_task_body(),
+            body: complete_epic_body(),
         };
         let child = Task {
             id: "task-main-201".to_string(),
@@ -3204,6 +5080,7 @@ Description:\n\
                 parent: vec!["task-main-200".to_string()],
                 child: vec![],
                 discovered_from: vec![],
+                relates_to: vec![],
             },
             lease: None,
             project: None,
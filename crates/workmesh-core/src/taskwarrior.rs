@@ -0,0 +1,586 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use chrono::{Local, NaiveDateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+use crate::health::{first_extra, is_closed};
+use crate::initiative::reserve_namespaced_task_id;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_task_file_with_sections, update_task_field, FieldValue, TaskSectionContent,
+};
+use crate::timestamps::{format_timestamp, parse_stored_timestamp};
+
+/// Front matter key holding the Taskwarrior UUID of an imported (or exported) task.
+pub const TASKWARRIOR_UUID_FIELD: &str = "taskwarrior_uuid";
+/// Front matter key holding Taskwarrior's urgency at import time.
+pub const TASKWARRIOR_URGENCY_FIELD: &str = "urgency";
+
+const DEFAULT_PROJECT_FIELD: &str = "project";
+const DEFAULT_PHASE: &str = "Phase1";
+const DEFAULT_PRIORITY: &str = "P2";
+
+#[derive(Debug, Error)]
+pub enum TaskwarriorError {
+    #[error("Invalid Taskwarrior JSON: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Failed to create task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to reserve task id: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// `[taskwarrior]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskwarriorConfig {
+    /// Taskwarrior priority (`H`, `M`, `L`, or `""` for none) -> WorkMesh priority.
+    /// Defaults: `H = "P1"`, `M = "P2"`, `L = "P3"`, none = `P2`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub priorities: BTreeMap<String, String>,
+    /// Extra Taskwarrior attributes (usually UDAs) -> front matter field, e.g.
+    /// `estimate = "estimate"`. Mapped both ways.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, String>,
+    /// Front matter field receiving the Taskwarrior project (default `project`).
+    pub project_field: Option<String>,
+    /// Phase for imported tasks (default `Phase1`).
+    pub phase: Option<String>,
+}
+
+impl TaskwarriorConfig {
+    fn project_field(&self) -> &str {
+        self.project_field
+            .as_deref()
+            .filter(|value| !value.trim().is_empty())
+            .unwrap_or(DEFAULT_PROJECT_FIELD)
+    }
+
+    fn import_priority(&self, tw: &str) -> String {
+        if let Some(mapped) = self.priorities.get(tw) {
+            return mapped.clone();
+        }
+        match tw {
+            "H" => "P1",
+            "M" => "P2",
+            "L" => "P3",
+            _ => DEFAULT_PRIORITY,
+        }
+        .to_string()
+    }
+
+    fn export_priority(&self, priority: &str) -> Option<String> {
+        if let Some((tw, _)) = self
+            .priorities
+            .iter()
+            .find(|(_, mapped)| mapped.eq_ignore_ascii_case(priority))
+        {
+            return (!tw.is_empty()).then(|| tw.clone());
+        }
+        match priority.trim().to_uppercase().as_str() {
+            "P0" | "P1" => Some("H".to_string()),
+            "P2" => Some("M".to_string()),
+            "" => None,
+            _ => Some("L".to_string()),
+        }
+    }
+}
+
+/// Project `[taskwarrior]` wins over global `[taskwarrior]`.
+pub fn resolve_taskwarrior_config(repo_root: &Path) -> TaskwarriorConfig {
+    load_config(repo_root)
+        .and_then(|config| config.taskwarrior)
+        .or_else(|| load_global_config().and_then(|config| config.taskwarrior))
+        .unwrap_or_default()
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskwarriorImportItem {
+    pub uuid: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    /// Set once the task is written (`--apply`), or when it already exists in the backlog.
+    pub task_id: Option<String>,
+    pub task_path: Option<PathBuf>,
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TaskwarriorImportReport {
+    pub apply: bool,
+    /// New tasks (created when `apply` is set).
+    pub imported: Vec<TaskwarriorImportItem>,
+    /// Tasks whose UUID is already in the backlog; left untouched.
+    pub existing: Vec<TaskwarriorImportItem>,
+    /// UUIDs skipped because they are deleted or recurring templates.
+    pub skipped: Vec<String>,
+}
+
+/// Parse `task export` output: a JSON array, or one JSON object per line (older versions).
+pub fn parse_taskwarrior_export(raw: &str) -> Result<Vec<Value>, TaskwarriorError> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Ok(Vec::new());
+    }
+    if trimmed.starts_with('[') {
+        return Ok(serde_json::from_str(trimmed)?);
+    }
+    trimmed
+        .lines()
+        .map(|line| line.trim().trim_end_matches(','))
+        .filter(|line| !line.is_empty())
+        .map(|line| serde_json::from_str(line).map_err(TaskwarriorError::from))
+        .collect()
+}
+
+/// Import Taskwarrior tasks. Dry-run unless `apply`; tasks already imported (matched by UUID)
+/// are reported as existing and never rewritten, so re-running is safe.
+///
+/// Mapping: description -> title, tags -> labels, project -> `project_field`, priority via
+/// `priorities`, depends -> dependencies (by UUID, including earlier imports and exports),
+/// urgency -> `urgency`, due -> `due_date`, entry -> `created_date`, annotations -> description,
+/// `fields` -> front matter.
+pub fn import_taskwarrior(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    items: &[Value],
+    config: &TaskwarriorConfig,
+    initiative: &str,
+    apply: bool,
+) -> Result<TaskwarriorImportReport, TaskwarriorError> {
+    let mut by_uuid: HashMap<String, String> = tasks
+        .iter()
+        .map(|task| (task_uuid(task), task.id.clone()))
+        .collect();
+    let mut report = TaskwarriorImportReport {
+        apply,
+        ..TaskwarriorImportReport::default()
+    };
+    let mut pending: Vec<(&Value, TaskwarriorImportItem)> = Vec::new();
+    for item in items {
+        let uuid = str_field(item, "uuid").unwrap_or_default().to_lowercase();
+        let status = str_field(item, "status").unwrap_or("pending");
+        if uuid.is_empty() || matches!(status, "deleted" | "recurring") {
+            report.skipped.push(uuid);
+            continue;
+        }
+        let entry = TaskwarriorImportItem {
+            title: str_field(item, "description")
+                .unwrap_or("(no title)")
+                .to_string(),
+            status: match status {
+                "completed" => "Done",
+                _ if item.get("start").is_some() => "In Progress",
+                _ => "To Do",
+            }
+            .to_string(),
+            priority: config.import_priority(str_field(item, "priority").unwrap_or("")),
+            task_id: by_uuid.get(&uuid).cloned(),
+            task_path: None,
+            dependencies: Vec::new(),
+            uuid,
+        };
+        if entry.task_id.is_some() {
+            report.existing.push(entry);
+        } else {
+            pending.push((item, entry));
+        }
+    }
+
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let mut known: Vec<Task> = tasks.to_vec();
+    if apply {
+        for (item, entry) in pending.iter_mut() {
+            let task_id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+            let path = create_task_file_with_sections(
+                &tasks_dir,
+                &task_id,
+                &entry.title,
+                &entry.status,
+                &entry.priority,
+                config.phase.as_deref().unwrap_or(DEFAULT_PHASE),
+                &[],
+                &string_list(item, "tags"),
+                &[],
+                &import_sections(item, &entry.uuid),
+            )?;
+            write_import_fields(&path, item, &entry.uuid, config)?;
+            known.push(parse_task_file(&path)?);
+            by_uuid.insert(entry.uuid.clone(), task_id.clone());
+            entry.task_id = Some(task_id);
+            entry.task_path = Some(path);
+        }
+    }
+    // Dependencies are resolved after every task has an id so imports can depend on each other.
+    // Unknown UUIDs stay in the report but are never written as dependencies.
+    for (item, entry) in pending.iter_mut() {
+        let uuids = depends_uuids(item);
+        let resolved: Vec<String> = uuids
+            .iter()
+            .filter_map(|uuid| by_uuid.get(uuid).cloned())
+            .collect();
+        if let (Some(path), false) = (&entry.task_path, resolved.is_empty()) {
+            update_task_field(path, "dependencies", Some(FieldValue::List(resolved)))?;
+        }
+        entry.dependencies = uuids
+            .into_iter()
+            .map(|uuid| by_uuid.get(&uuid).cloned().unwrap_or(uuid))
+            .collect();
+    }
+    report.imported = pending.into_iter().map(|(_, entry)| entry).collect();
+    Ok(report)
+}
+
+/// Render tasks as a `task import`-compatible JSON array.
+///
+/// UUIDs are the stored `taskwarrior_uuid` or a stable UUID derived from the task uid/id, so
+/// repeated exports update the same Taskwarrior tasks and dependencies line up.
+pub fn export_taskwarrior(tasks: &[Task], config: &TaskwarriorConfig) -> Vec<Value> {
+    let uuids: HashMap<String, String> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task_uuid(task)))
+        .collect();
+    tasks
+        .iter()
+        .map(|task| {
+            let mut item = serde_json::Map::new();
+            item.insert("uuid".into(), Value::from(task_uuid(task)));
+            item.insert("description".into(), Value::from(task.title.clone()));
+            let status = if task.status.eq_ignore_ascii_case("done") {
+                "completed"
+            } else if is_closed(task) {
+                "deleted"
+            } else {
+                "pending"
+            };
+            item.insert("status".into(), Value::from(status));
+            if task.status.eq_ignore_ascii_case("in progress") {
+                let start = task
+                    .updated_date
+                    .as_deref()
+                    .or(task.created_date.as_deref())
+                    .and_then(to_tw_date);
+                if let Some(start) = start {
+                    item.insert("start".into(), Value::from(start));
+                }
+            }
+            if let Some(entry) = task.created_date.as_deref().and_then(to_tw_date) {
+                item.insert("entry".into(), Value::from(entry));
+            }
+            if let Some(modified) = task.updated_date.as_deref().and_then(to_tw_date) {
+                item.insert("modified".into(), Value::from(modified));
+            }
+            let due = first_extra(task, &["due_date", "due"]).and_then(|value| to_tw_date(&value));
+            if let Some(due) = due {
+                item.insert("due".into(), Value::from(due));
+            }
+            if let Some(priority) = config.export_priority(&task.priority) {
+                item.insert("priority".into(), Value::from(priority));
+            }
+            if let Some(project) = project_value(task, config.project_field()) {
+                item.insert("project".into(), Value::from(project));
+            }
+            let tags: Vec<String> = task
+                .labels
+                .iter()
+                .map(|label| label.trim().replace(char::is_whitespace, "-"))
+                .filter(|label| !label.is_empty())
+                .collect();
+            if !tags.is_empty() {
+                item.insert("tags".into(), Value::from(tags));
+            }
+            let depends: Vec<String> = task
+                .dependencies
+                .iter()
+                .filter_map(|id| uuids.get(&id.to_lowercase()).cloned())
+                .collect();
+            if !depends.is_empty() {
+                item.insert("depends".into(), Value::from(depends));
+            }
+            for (tw_key, field) in &config.fields {
+                if let Some(value) = first_extra(task, &[field.as_str()]) {
+                    let value = value
+                        .parse::<f64>()
+                        .ok()
+                        .and_then(serde_json::Number::from_f64)
+                        .map(Value::Number)
+                        .unwrap_or(Value::String(value));
+                    item.insert(tw_key.clone(), value);
+                }
+            }
+            Value::Object(item)
+        })
+        .collect()
+}
+
+/// Stored `taskwarrior_uuid`, else a deterministic v4-shaped UUID from the task uid or id.
+pub fn task_uuid(task: &Task) -> String {
+    if let Some(uuid) = first_extra(task, &[TASKWARRIOR_UUID_FIELD]) {
+        return uuid.to_lowercase();
+    }
+    let seed = task.uid.as_deref().unwrap_or(&task.id).to_lowercase();
+    let digest = Sha256::digest(format!("workmesh:{}", seed).as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest[..16]);
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+fn import_sections(item: &Value, uuid: &str) -> TaskSectionContent {
+    let title = str_field(item, "description").unwrap_or("(no title)");
+    let mut description = format!("{}\n\nImported from Taskwarrior (`{}`).", title, uuid);
+    let annotations: Vec<&str> = item
+        .get("annotations")
+        .and_then(Value::as_array)
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|annotation| annotation.get("description").and_then(Value::as_str))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !annotations.is_empty() {
+        description.push_str("\n\nAnnotations:\n");
+        for annotation in annotations {
+            description.push_str(&format!("- {}\n", annotation));
+        }
+    }
+    TaskSectionContent {
+        description: description.trim_end().to_string(),
+        acceptance_criteria: format!("- {}", title),
+        definition_of_done: "- Outcome verified and task marked Done.".to_string(),
+    }
+}
+
+fn write_import_fields(
+    path: &Path,
+    item: &Value,
+    uuid: &str,
+    config: &TaskwarriorConfig,
+) -> Result<(), TaskParseError> {
+    let mut fields: Vec<(String, String)> = vec![(TASKWARRIOR_UUID_FIELD.into(), uuid.into())];
+    if let Some(project) = str_field(item, "project") {
+        fields.push((config.project_field().to_string(), project.to_string()));
+    }
+    if let Some(urgency) = item.get("urgency").and_then(Value::as_f64) {
+        fields.push((TASKWARRIOR_URGENCY_FIELD.into(), format_number(urgency)));
+    }
+    for (key, field) in [("due", "due_date"), ("entry", "created_date")] {
+        if let Some(date) = str_field(item, key).and_then(from_tw_date) {
+            fields.push((field.into(), date));
+        }
+    }
+    for (tw_key, field) in &config.fields {
+        let value = match item.get(tw_key) {
+            Some(Value::String(value)) => value.clone(),
+            Some(Value::Number(value)) => value.as_f64().map_or(value.to_string(), format_number),
+            _ => continue,
+        };
+        fields.push((field.clone(), value));
+    }
+    for (key, value) in fields {
+        update_task_field(path, &key, Some(FieldValue::Scalar(value)))?;
+    }
+    Ok(())
+}
+
+fn project_value(task: &Task, field: &str) -> Option<String> {
+    if field == DEFAULT_PROJECT_FIELD {
+        return task
+            .project
+            .clone()
+            .filter(|value| !value.trim().is_empty());
+    }
+    first_extra(task, &[field])
+}
+
+fn str_field<'a>(item: &'a Value, key: &str) -> Option<&'a str> {
+    item.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn string_list(item: &Value, key: &str) -> Vec<String> {
+    match item.get(key) {
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect(),
+        Some(Value::String(value)) => value
+            .split(',')
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// `depends` is an array in Taskwarrior 2.6+/3.x and a comma-separated string before that.
+fn depends_uuids(item: &Value) -> Vec<String> {
+    string_list(item, "depends")
+        .into_iter()
+        .map(|uuid| uuid.to_lowercase())
+        .collect()
+}
+
+/// `20260301T120000Z` (UTC) -> `2026-03-01T09:00:00-03:00` (local offset).
+fn from_tw_date(value: &str) -> Option<String> {
+    let naive = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ").ok()?;
+    Some(format_timestamp(
+        Utc.from_utc_datetime(&naive).with_timezone(&Local),
+    ))
+}
+
+/// Any stored timestamp (or a date, as local midnight) -> `20260301T120000Z`.
+fn to_tw_date(value: &str) -> Option<String> {
+    let time = match parse_stored_timestamp(value) {
+        Some(time) => time,
+        None => Local
+            .from_local_datetime(&crate::policies::parse_task_date(value)?)
+            .earliest()?
+            .fixed_offset(),
+    };
+    Some(
+        time.with_timezone(&Utc)
+            .format("%Y%m%dT%H%M%SZ")
+            .to_string(),
+    )
+}
+
+fn format_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{}", value as i64)
+    } else {
+        format!("{:.3}", value)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+    use crate::timestamps::TimeDisplay;
+
+    const EXPORT: &str = r#"[
+  {"uuid":"AAAAAAAA-0000-4000-8000-000000000001","description":"Write docs","status":"pending",
+   "project":"home.site","tags":["docs","writing"],"priority":"H","urgency":8.4,
+   "due":"20260310T000000Z","entry":"20260301T090000Z","estimate":3,
+   "depends":"aaaaaaaa-0000-4000-8000-000000000002",
+   "annotations":[{"entry":"20260301T091000Z","description":"Start with the README"}]},
+  {"uuid":"aaaaaaaa-0000-4000-8000-000000000002","description":"Pick theme","status":"pending",
+   "start":"20260302T080000Z"},
+  {"uuid":"aaaaaaaa-0000-4000-8000-000000000003","description":"Old idea","status":"deleted"}
+]"#;
+
+    fn config() -> TaskwarriorConfig {
+        TaskwarriorConfig {
+            fields: BTreeMap::from([("estimate".to_string(), "estimate".to_string())]),
+            ..TaskwarriorConfig::default()
+        }
+    }
+
+    #[test]
+    fn import_maps_fields_dependencies_and_is_idempotent() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        fs::create_dir_all(backlog.join("tasks")).expect("tasks");
+        let items = parse_taskwarrior_export(EXPORT).expect("parse");
+
+        let dry =
+            import_taskwarrior(&backlog, &[], &items, &config(), "home", false).expect("dry run");
+        assert_eq!(dry.imported.len(), 2);
+        assert_eq!(dry.skipped, vec!["aaaaaaaa-0000-4000-8000-000000000003"]);
+        assert!(load_tasks(&backlog).is_empty());
+
+        let report =
+            import_taskwarrior(&backlog, &[], &items, &config(), "home", true).expect("import");
+        let ids: Vec<&str> = report
+            .imported
+            .iter()
+            .filter_map(|item| item.task_id.as_deref())
+            .collect();
+        assert_eq!(ids, vec!["task-home-001", "task-home-002"]);
+        assert_eq!(report.imported[0].dependencies, vec!["task-home-002"]);
+
+        let tasks = load_tasks(&backlog);
+        let docs = tasks
+            .iter()
+            .find(|t| t.id == "task-home-001")
+            .expect("docs");
+        assert_eq!(docs.priority, "P1");
+        assert_eq!(docs.labels, vec!["docs", "writing"]);
+        assert_eq!(docs.project.as_deref(), Some("home.site"));
+        let created = docs.created_date.as_deref().expect("created");
+        assert_eq!(TimeDisplay::Utc.render(created), "2026-03-01 09:00 UTC");
+        assert_eq!(first_extra(docs, &["urgency"]).as_deref(), Some("8.4"));
+        assert_eq!(first_extra(docs, &["estimate"]).as_deref(), Some("3"));
+        assert!(docs.body.contains("- Start with the README"));
+        let theme = tasks
+            .iter()
+            .find(|t| t.id == "task-home-002")
+            .expect("theme");
+        assert_eq!(theme.status, "In Progress");
+
+        let again =
+            import_taskwarrior(&backlog, &tasks, &items, &config(), "home", true).expect("again");
+        assert!(again.imported.is_empty());
+        assert_eq!(again.existing.len(), 2);
+        assert_eq!(load_tasks(&backlog).len(), 2);
+    }
+
+    #[test]
+    fn export_round_trips_uuids_and_dependencies() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        fs::create_dir_all(backlog.join("tasks")).expect("tasks");
+        let items = parse_taskwarrior_export(EXPORT).expect("parse");
+        import_taskwarrior(&backlog, &[], &items, &config(), "home", true).expect("import");
+        let tasks = load_tasks(&backlog);
+
+        let exported = export_taskwarrior(&tasks, &config());
+        let docs = exported
+            .iter()
+            .find(|item| item["description"] == "Write docs")
+            .expect("docs");
+        assert_eq!(docs["uuid"], "aaaaaaaa-0000-4000-8000-000000000001");
+        assert_eq!(docs["priority"], "H");
+        assert_eq!(docs["project"], "home.site");
+        assert_eq!(docs["due"], "20260310T000000Z");
+        assert_eq!(docs["estimate"], 3.0);
+        assert_eq!(
+            docs["depends"],
+            serde_json::json!(["aaaaaaaa-0000-4000-8000-000000000002"])
+        );
+
+        let mut native = tasks[0].clone();
+        native.extra.remove(TASKWARRIOR_UUID_FIELD);
+        let uuid = task_uuid(&native);
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4");
+        assert_eq!(uuid, task_uuid(&native));
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use thiserror::Error;
+
+use crate::audit::AuditEvent;
+use crate::config::{load_config, load_global_config};
+use crate::timestamps::parse_stored_timestamp;
+
+/// Standard OpenTelemetry variable; overrides `[telemetry] otlp_endpoint`.
+pub const OTLP_ENDPOINT_ENV: &str = "OTEL_EXPORTER_OTLP_ENDPOINT";
+/// Standard OpenTelemetry variable; overrides `[telemetry] service_name`.
+pub const OTLP_SERVICE_NAME_ENV: &str = "OTEL_SERVICE_NAME";
+
+const DEFAULT_SERVICE_NAME: &str = "workmesh";
+const DEFAULT_TIMEOUT_MS: u64 = 2000;
+const SCOPE_NAME: &str = "workmesh";
+
+#[derive(Debug, Error)]
+pub enum TelemetryError {
+    #[error("OTLP export to {url} failed: {message}")]
+    Export { url: String, message: String },
+}
+
+/// `[telemetry]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TelemetryConfig {
+    /// OTLP/HTTP base URL (e.g. `http://localhost:4318`). Export is off when unset.
+    pub otlp_endpoint: Option<String>,
+    pub service_name: Option<String>,
+    /// Extra request headers (e.g. collector auth).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    pub timeout_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OtlpExporter {
+    pub endpoint: String,
+    pub service_name: String,
+    pub headers: BTreeMap<String, String>,
+    pub timeout: Duration,
+}
+
+/// A timed unit of work (CLI command or MCP tool call), exported as one span.
+#[derive(Debug, Clone)]
+pub struct CommandSpan {
+    pub name: String,
+    pub start: SystemTime,
+    pub end: SystemTime,
+    pub ok: bool,
+    pub attributes: Vec<(String, String)>,
+}
+
+/// Exporter for this repo, or `None` when no endpoint is configured.
+///
+/// Environment variables win over project config, which wins over global config.
+pub fn resolve_otlp_exporter(repo_root: &Path) -> Option<OtlpExporter> {
+    let config = load_config(repo_root)
+        .and_then(|config| config.telemetry)
+        .or_else(|| load_global_config().and_then(|config| config.telemetry))
+        .unwrap_or_default();
+    let endpoint = std::env::var(OTLP_ENDPOINT_ENV)
+        .ok()
+        .or(config.otlp_endpoint)
+        .map(|value| value.trim().trim_end_matches('/').to_string())
+        .filter(|value| !value.is_empty())?;
+    let service_name = std::env::var(OTLP_SERVICE_NAME_ENV)
+        .ok()
+        .or(config.service_name)
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| DEFAULT_SERVICE_NAME.to_string());
+    Some(OtlpExporter {
+        endpoint,
+        service_name,
+        headers: config.headers,
+        timeout: Duration::from_millis(config.timeout_ms.unwrap_or(DEFAULT_TIMEOUT_MS)),
+    })
+}
+
+impl OtlpExporter {
+    /// OTLP/JSON `ExportLogsServiceRequest` with one log record per audit event.
+    pub fn logs_payload(&self, events: &[AuditEvent]) -> Value {
+        let records: Vec<Value> = events.iter().map(log_record).collect();
+        json!({
+            "resourceLogs": [{
+                "resource": self.resource(),
+                "scopeLogs": [{ "scope": { "name": SCOPE_NAME }, "logRecords": records }],
+            }]
+        })
+    }
+
+    /// OTLP/JSON `ExportTraceServiceRequest`; each span gets its own trace.
+    pub fn spans_payload(&self, spans: &[CommandSpan]) -> Value {
+        let spans: Vec<Value> = spans.iter().map(span_value).collect();
+        json!({
+            "resourceSpans": [{
+                "resource": self.resource(),
+                "scopeSpans": [{ "scope": { "name": SCOPE_NAME }, "spans": spans }],
+            }]
+        })
+    }
+
+    pub fn export_audit_events(&self, events: &[AuditEvent]) -> Result<(), TelemetryError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        self.post("v1/logs", &self.logs_payload(events))
+    }
+
+    pub fn export_spans(&self, spans: &[CommandSpan]) -> Result<(), TelemetryError> {
+        if spans.is_empty() {
+            return Ok(());
+        }
+        self.post("v1/traces", &self.spans_payload(spans))
+    }
+
+    fn post(&self, signal_path: &str, payload: &Value) -> Result<(), TelemetryError> {
+        let url = format!("{}/{}", self.endpoint, signal_path);
+        let agent = ureq::AgentBuilder::new().timeout(self.timeout).build();
+        let mut request = agent.post(&url).set("Content-Type", "application/json");
+        for (key, value) in &self.headers {
+            request = request.set(key, value);
+        }
+        request
+            .send_string(&payload.to_string())
+            .map(|_| ())
+            .map_err(|err| TelemetryError::Export {
+                url,
+                message: err.to_string(),
+            })
+    }
+
+    fn resource(&self) -> Value {
+        json!({ "attributes": [attribute("service.name", &self.service_name)] })
+    }
+}
+
+fn log_record(event: &AuditEvent) -> Value {
+    let observed = unix_nanos(SystemTime::now());
+    let time = parse_stored_timestamp(&event.timestamp)
+        .and_then(|time| time.timestamp_nanos_opt())
+        .map(|nanos| nanos.max(0) as u64)
+        .unwrap_or(observed);
+    let mut attributes = vec![attribute("workmesh.action", &event.action)];
+    if let Some(task_id) = event.task_id.as_deref() {
+        attributes.push(attribute("workmesh.task_id", task_id));
+    }
+    if let Some(actor) = event.actor.as_deref() {
+        attributes.push(attribute("workmesh.actor", actor));
+    }
+    attributes.push(attribute("workmesh.details", &event.details.to_string()));
+    let body = match event.task_id.as_deref() {
+        Some(task_id) => format!("{} {}", event.action, task_id),
+        None => event.action.clone(),
+    };
+    json!({
+        "timeUnixNano": time.to_string(),
+        "observedTimeUnixNano": observed.to_string(),
+        "severityNumber": 9,
+        "severityText": "INFO",
+        "body": { "stringValue": body },
+        "attributes": attributes,
+    })
+}
+
+fn span_value(span: &CommandSpan) -> Value {
+    let attributes: Vec<Value> = span
+        .attributes
+        .iter()
+        .map(|(key, value)| attribute(key, value))
+        .collect();
+    // STATUS_CODE_OK / STATUS_CODE_ERROR
+    let status_code = if span.ok { 1 } else { 2 };
+    json!({
+        "traceId": hex(&ulid::Ulid::new().to_bytes()),
+        "spanId": hex(&ulid::Ulid::new().to_bytes()[8..]),
+        "name": span.name,
+        // SPAN_KIND_INTERNAL
+        "kind": 1,
+        "startTimeUnixNano": unix_nanos(span.start).to_string(),
+        "endTimeUnixNano": unix_nanos(span.end).to_string(),
+        "attributes": attributes,
+        "status": { "code": status_code },
+    })
+}
+
+fn attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+fn unix_nanos(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn exporter() -> OtlpExporter {
+        OtlpExporter {
+            endpoint: "http://localhost:4318".to_string(),
+            service_name: "workmesh-test".to_string(),
+            headers: BTreeMap::new(),
+            timeout: Duration::from_millis(10),
+        }
+    }
+
+    #[test]
+    fn logs_payload_maps_audit_events_to_records() {
+        let event = AuditEvent {
+            timestamp: "2026-01-01 10:00".to_string(),
+            actor: Some("alice".to_string()),
+            action: "set_status".to_string(),
+            task_id: Some("task-001".to_string()),
+            details: json!({"status": "Done"}),
+        };
+        let payload = exporter().logs_payload(&[event]);
+        let resource = &payload["resourceLogs"][0];
+        assert_eq!(
+            resource["resource"]["attributes"][0]["value"]["stringValue"],
+            "workmesh-test"
+        );
+        let record = &resource["scopeLogs"][0]["logRecords"][0];
+        assert_eq!(record["body"]["stringValue"], "set_status task-001");
+        let keys: Vec<&str> = record["attributes"]
+            .as_array()
+            .expect("attributes")
+            .iter()
+            .filter_map(|attr| attr["key"].as_str())
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                "workmesh.action",
+                "workmesh.task_id",
+                "workmesh.actor",
+                "workmesh.details"
+            ]
+        );
+    }
+
+    #[test]
+    fn spans_payload_has_otlp_ids_and_status() {
+        let start = UNIX_EPOCH + Duration::from_secs(1);
+        let span = CommandSpan {
+            name: "workmesh list".to_string(),
+            start,
+            end: start + Duration::from_millis(5),
+            ok: false,
+            attributes: vec![("workmesh.command".to_string(), "list".to_string())],
+        };
+        let payload = exporter().spans_payload(&[span]);
+        let span = &payload["resourceSpans"][0]["scopeSpans"][0]["spans"][0];
+        assert_eq!(span["traceId"].as_str().map(str::len), Some(32));
+        assert_eq!(span["spanId"].as_str().map(str::len), Some(16));
+        assert_eq!(span["startTimeUnixNano"], "1000000000");
+        assert_eq!(span["endTimeUnixNano"], "1005000000");
+        assert_eq!(span["status"]["code"], 2);
+    }
+
+    #[test]
+    fn telemetry_config_parses_from_toml() {
+        let text = r#"
+otlp_endpoint = "http://collector:4318"
+timeout_ms = 500
+
+[headers]
+authorization = "Bearer abc"
+"#;
+        let config: TelemetryConfig = toml::from_str(text).expect("parse");
+        assert_eq!(
+            config.otlp_endpoint.as_deref(),
+            Some("http://collector:4318")
+        );
+        assert_eq!(config.timeout_ms, Some(500));
+        assert_eq!(
+            config.headers.get("authorization").map(String::as_str),
+            Some("Bearer abc")
+        );
+    }
+}
@@ -0,0 +1,374 @@
+//! Reusable task templates: `<backlog>/templates/<name>.md` files holding front matter defaults
+//! (status, priority, phase, labels, assignee) and a body whose sections pre-fill new tasks.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::TaskValidationRules;
+use crate::storage::write_string_atomic_locked;
+use crate::task::{split_front_matter, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_task_file_with_body, extract_section_content, replace_section,
+    validate_task_creation_with_rules, FieldValue, TaskSectionContent,
+};
+
+const DEFAULT_STATUS: &str = "To Do";
+const DEFAULT_PRIORITY: &str = "P2";
+const DEFAULT_PHASE: &str = "Phase1";
+
+#[derive(Debug, Error)]
+pub enum TemplateError {
+    #[error("Template not found: {0}")]
+    NotFound(String),
+    #[error("Template already exists: {0} (use --force to overwrite)")]
+    Exists(PathBuf),
+    #[error("Invalid template name '{0}' (use letters, digits, '-' or '_')")]
+    InvalidName(String),
+    #[error("Invalid template front matter in {path}: {source}")]
+    FrontMatter {
+        path: PathBuf,
+        source: serde_yaml::Error,
+    },
+    #[error("{0}")]
+    Validation(String),
+    #[error("Task error: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct TaskTemplate {
+    /// File stem under `templates/`; used as `add --template <name>`.
+    #[serde(default)]
+    pub name: String,
+    /// One-line summary shown by `template list`.
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub phase: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub assignee: Vec<String>,
+    /// Task body; `{{id}}` and `{{title}}` are replaced when the template is applied.
+    #[serde(default)]
+    pub body: String,
+}
+
+/// Values given on the command line; each wins over the template's default, labels are merged.
+#[derive(Debug, Clone, Default)]
+pub struct TemplateOverrides {
+    pub status: Option<String>,
+    pub priority: Option<String>,
+    pub phase: Option<String>,
+    pub labels: Vec<String>,
+    pub assignee: Vec<String>,
+    pub dependencies: Vec<String>,
+    pub description: Option<String>,
+    pub acceptance_criteria: Option<String>,
+    pub definition_of_done: Option<String>,
+    pub draft: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TemplatedTask {
+    pub id: String,
+    pub path: PathBuf,
+    pub status: String,
+    pub template: String,
+}
+
+/// `templates/` next to the tasks directory (e.g. `workmesh/templates/`).
+pub fn templates_dir_for_root(backlog_dir: &Path) -> PathBuf {
+    let tasks_root = tasks_dir_for_root(backlog_dir);
+    tasks_root
+        .parent()
+        .unwrap_or(tasks_root.as_path())
+        .join("templates")
+}
+
+fn template_path(backlog_dir: &Path, name: &str) -> Result<PathBuf, TemplateError> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    if !valid {
+        return Err(TemplateError::InvalidName(name.to_string()));
+    }
+    Ok(templates_dir_for_root(backlog_dir).join(format!("{}.md", name)))
+}
+
+fn parse_template(path: &Path, text: &str) -> Result<TaskTemplate, TemplateError> {
+    let (front, body) = split_front_matter(text)?;
+    let mut template: TaskTemplate = if front.trim().is_empty() {
+        TaskTemplate::default()
+    } else {
+        serde_yaml::from_str(&front).map_err(|source| TemplateError::FrontMatter {
+            path: path.to_path_buf(),
+            source,
+        })?
+    };
+    template.name = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    template.body = body.trim_start_matches('\n').to_string();
+    Ok(template)
+}
+
+/// Every readable template, sorted by name; files with broken front matter are skipped.
+pub fn list_templates(backlog_dir: &Path) -> Vec<TaskTemplate> {
+    let Ok(entries) = fs::read_dir(templates_dir_for_root(backlog_dir)) else {
+        return Vec::new();
+    };
+    let mut templates: Vec<TaskTemplate> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .filter_map(|path| {
+            let text = fs::read_to_string(&path).ok()?;
+            parse_template(&path, &text).ok()
+        })
+        .collect();
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    templates
+}
+
+pub fn load_template(backlog_dir: &Path, name: &str) -> Result<TaskTemplate, TemplateError> {
+    let path = template_path(backlog_dir, name)?;
+    if !path.is_file() {
+        return Err(TemplateError::NotFound(name.to_string()));
+    }
+    parse_template(&path, &fs::read_to_string(&path)?)
+}
+
+/// Template body with the standard sections, each holding a `- ` placeholder.
+pub fn default_template_body() -> String {
+    ["Description", "Acceptance Criteria", "Definition of Done"]
+        .iter()
+        .map(|section| {
+            format!(
+                "{}:\n--------------------------------------------------\n- \n",
+                section
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Template seeded from an existing task's priority, phase, labels, and body.
+pub fn template_from_task(name: &str, task: &Task) -> TaskTemplate {
+    TaskTemplate {
+        name: name.to_string(),
+        description: Some(format!("From {}", task.id)),
+        status: None,
+        priority: Some(task.priority.clone()).filter(|value| !value.trim().is_empty()),
+        phase: Some(task.phase.clone()).filter(|value| !value.trim().is_empty()),
+        labels: task.labels.clone(),
+        assignee: Vec::new(),
+        body: task.body.trim_start_matches('\n').to_string(),
+    }
+}
+
+/// Write `template` to `templates/<name>.md`.
+pub fn write_template(
+    backlog_dir: &Path,
+    template: &TaskTemplate,
+    force: bool,
+) -> Result<PathBuf, TemplateError> {
+    let path = template_path(backlog_dir, &template.name)?;
+    if path.exists() && !force {
+        return Err(TemplateError::Exists(path));
+    }
+    let mut lines = vec!["---".to_string()];
+    if let Some(description) = template.description.as_deref() {
+        // JSON strings are valid YAML scalars, so free text with `:` or `#` round-trips.
+        lines.push(format!(
+            "description: {}",
+            serde_json::Value::String(description.to_string())
+        ));
+    }
+    for (key, value) in [
+        ("status", &template.status),
+        ("priority", &template.priority),
+        ("phase", &template.phase),
+    ] {
+        if let Some(value) = value.as_deref() {
+            lines.push(format!("{}: {}", key, value));
+        }
+    }
+    lines.push(format!(
+        "labels: {}",
+        FieldValue::List(template.labels.clone()).as_formatted()
+    ));
+    lines.push(format!(
+        "assignee: {}",
+        FieldValue::List(template.assignee.clone()).as_formatted()
+    ));
+    lines.push("---".to_string());
+    lines.push(String::new());
+    lines.push(template.body.trim_end_matches('\n').to_string());
+    lines.push(String::new());
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    write_string_atomic_locked(&path, &lines.join("\n"))?;
+    Ok(path)
+}
+
+/// Create task `task_id` from `template`. Section overrides replace the template's section of the
+/// same name; the result must pass the same quality gate as `add` unless `draft` is set.
+pub fn create_task_from_template(
+    backlog_dir: &Path,
+    template: &TaskTemplate,
+    task_id: &str,
+    title: &str,
+    overrides: &TemplateOverrides,
+    rules: &TaskValidationRules,
+) -> Result<TemplatedTask, TemplateError> {
+    let mut body = template
+        .body
+        .replace("{{id}}", task_id)
+        .replace("{{title}}", title);
+    for (section, content) in [
+        ("Description", &overrides.description),
+        ("Acceptance Criteria", &overrides.acceptance_criteria),
+        ("Definition of Done", &overrides.definition_of_done),
+    ] {
+        if let Some(content) = content.as_deref().filter(|value| !value.trim().is_empty()) {
+            body = replace_section(&body, section, content);
+        }
+    }
+    let section = |name: &str| extract_section_content(&body, name).unwrap_or_default();
+    let sections = TaskSectionContent {
+        description: section("Description"),
+        acceptance_criteria: section("Acceptance Criteria"),
+        definition_of_done: section("Definition of Done"),
+    };
+    let status = overrides
+        .status
+        .as_deref()
+        .or(template.status.as_deref())
+        .unwrap_or(DEFAULT_STATUS);
+    let status = validate_task_creation_with_rules(status, overrides.draft, &sections, rules)
+        .map_err(TemplateError::Validation)?;
+    let mut labels = template.labels.clone();
+    for label in &overrides.labels {
+        if !labels
+            .iter()
+            .any(|existing| existing.eq_ignore_ascii_case(label))
+        {
+            labels.push(label.clone());
+        }
+    }
+    let assignee = if overrides.assignee.is_empty() {
+        template.assignee.clone()
+    } else {
+        overrides.assignee.clone()
+    };
+    let path = create_task_file_with_body(
+        &tasks_dir_for_root(backlog_dir),
+        task_id,
+        title,
+        &status,
+        overrides
+            .priority
+            .as_deref()
+            .or(template.priority.as_deref())
+            .unwrap_or(DEFAULT_PRIORITY),
+        overrides
+            .phase
+            .as_deref()
+            .or(template.phase.as_deref())
+            .unwrap_or(DEFAULT_PHASE),
+        &overrides.dependencies,
+        &labels,
+        &assignee,
+        &body,
+    )?;
+    Ok(TemplatedTask {
+        id: task_id.to_string(),
+        path,
+        status,
+        template: template.name.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::load_tasks;
+    use tempfile::TempDir;
+
+    #[test]
+    fn templates_round_trip_and_stamp_tasks() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog = temp.path().join("workmesh");
+        fs::create_dir_all(backlog.join("tasks")).expect("tasks");
+        let template = TaskTemplate {
+            name: "bugfix".to_string(),
+            description: Some("Fix a reported bug".to_string()),
+            priority: Some("P1".to_string()),
+            phase: Some("Phase2".to_string()),
+            labels: vec!["bug".to_string()],
+            body: "Description:\n--------------------------------------------------\n- Fix {{title}}\n\n\
+                   Acceptance Criteria:\n--------------------------------------------------\n- Regression test covers the bug\n\n\
+                   Definition of Done:\n--------------------------------------------------\n- Fix released and verified by reporter\n"
+                .to_string(),
+            ..TaskTemplate::default()
+        };
+        let path = write_template(&backlog, &template, false).expect("write");
+        assert_eq!(path, backlog.join("templates").join("bugfix.md"));
+        assert!(matches!(
+            write_template(&backlog, &template, false),
+            Err(TemplateError::Exists(_))
+        ));
+        let loaded = load_template(&backlog, "bugfix").expect("load");
+        assert_eq!(loaded.labels, vec!["bug".to_string()]);
+        assert_eq!(list_templates(&backlog).len(), 1);
+        assert!(matches!(
+            load_template(&backlog, "../x"),
+            Err(TemplateError::InvalidName(_))
+        ));
+
+        let overrides = TemplateOverrides {
+            labels: vec!["payments".to_string()],
+            ..TemplateOverrides::default()
+        };
+        let created = create_task_from_template(
+            &backlog,
+            &loaded,
+            "task-001",
+            "Refund rounding",
+            &overrides,
+            &TaskValidationRules::default(),
+        )
+        .expect("create");
+        assert_eq!(created.status, "To Do");
+        let task = &load_tasks(&backlog)[0];
+        assert_eq!(task.priority, "P1");
+        assert_eq!(task.phase, "Phase2");
+        assert_eq!(task.labels, vec!["bug".to_string(), "payments".to_string()]);
+        assert!(task.body.contains("- Fix Refund rounding"));
+
+        let blank = TaskTemplate {
+            name: "blank".to_string(),
+            body: default_template_body(),
+            ..TaskTemplate::default()
+        };
+        let err = create_task_from_template(
+            &backlog,
+            &blank,
+            "task-002",
+            "Empty",
+            &TemplateOverrides::default(),
+            &TaskValidationRules::default(),
+        )
+        .expect_err("placeholders fail the quality gate");
+        assert!(err.to_string().contains("task quality requirements"));
+    }
+}
@@ -0,0 +1,128 @@
+//! Stored timestamps and how they are shown.
+//!
+//! New timestamps are written as RFC3339 with the writer's UTC offset
+//! (`2026-03-01T09:30:00-03:00`), so teammates in other time zones read the same instant.
+//! Backlogs written before that hold naive `YYYY-MM-DD HH:MM` values, which are read as local
+//! time. Text output renders either form in the `time_display` zone (local or UTC); JSON output
+//! keeps the stored value.
+
+use std::cmp::Ordering;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+
+use crate::timewindow::parse_timestamp_ref;
+
+/// `time` as a stored timestamp: RFC3339 to the second, with its offset.
+pub fn format_timestamp<Tz: TimeZone>(time: DateTime<Tz>) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    time.to_rfc3339_opts(SecondsFormat::Secs, false)
+}
+
+/// A stored timestamp as an instant. Naive values are taken as local time; date-only and
+/// unparseable values have no instant.
+pub fn parse_stored_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time);
+    }
+    if !value.contains(':') {
+        return None;
+    }
+    let naive: NaiveDateTime = parse_timestamp_ref(value)?;
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.fixed_offset())
+}
+
+/// Order two stored timestamps by instant. Text order is wrong once values carry different
+/// offsets or mix with the older naive form, so it is only the fallback for unparseable values.
+pub fn cmp_timestamps(a: &str, b: &str) -> Ordering {
+    match (parse_timestamp_ref(a), parse_timestamp_ref(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
+
+/// The zone timestamps are rendered in (`time_display` config key).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TimeDisplay {
+    #[default]
+    Local,
+    Utc,
+}
+
+impl TimeDisplay {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().as_str() {
+            "local" => Some(TimeDisplay::Local),
+            "utc" => Some(TimeDisplay::Utc),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimeDisplay::Local => "local",
+            TimeDisplay::Utc => "utc",
+        }
+    }
+
+    /// `2026-03-01 09:30` in local time or `2026-03-01 12:30 UTC`. Date-only and unparseable
+    /// values are returned unchanged.
+    pub fn render(&self, value: &str) -> String {
+        let Some(time) = parse_stored_timestamp(value) else {
+            return value.to_string();
+        };
+        match self {
+            TimeDisplay::Local => time
+                .with_timezone(&Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string(),
+            TimeDisplay::Utc => time
+                .with_timezone(&Utc)
+                .format("%Y-%m-%d %H:%M UTC")
+                .to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stored_timestamps_carry_an_offset_and_render_per_preference() {
+        let stored = format_timestamp(Local::now());
+        let parsed = DateTime::parse_from_rfc3339(&stored).expect("rfc3339");
+        assert_eq!(
+            parsed.offset().local_minus_utc(),
+            Local::now().offset().local_minus_utc()
+        );
+
+        let stored = "2026-03-01T09:30:00-03:00";
+        assert_eq!(TimeDisplay::Utc.render(stored), "2026-03-01 12:30 UTC");
+        let local = DateTime::parse_from_rfc3339(stored)
+            .expect("rfc3339")
+            .with_timezone(&Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        assert_eq!(TimeDisplay::Local.render(stored), local);
+
+        // Legacy naive values are local time; dates and junk pass through.
+        assert_eq!(
+            TimeDisplay::Local.render("2026-03-01 09:30"),
+            "2026-03-01 09:30"
+        );
+        assert_eq!(TimeDisplay::Utc.render("2026-03-01"), "2026-03-01");
+        assert_eq!(TimeDisplay::Utc.render("soon"), "soon");
+        assert_eq!(
+            cmp_timestamps("2026-03-01T09:30:00-03:00", "2026-03-01T13:00:00+01:00"),
+            Ordering::Greater
+        );
+        assert_eq!(TimeDisplay::parse(" UTC "), Some(TimeDisplay::Utc));
+        assert_eq!(TimeDisplay::parse("gmt"), None);
+    }
+}
@@ -0,0 +1,167 @@
+//! Shared `--since` / `--until` parsing so every time-based command reads the same formats.
+//!
+//! Windows are half-open: `since` is inclusive, `until` exclusive, so `--since yesterday
+//! --until today` covers exactly one calendar day.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime};
+use thiserror::Error;
+
+/// Accepted forms, for help text and error messages.
+pub const TIME_REF_FORMATS: &str =
+    "30m, 12h, 7d, 2w, now, today, yesterday, YYYY-MM-DD, YYYY-MM-DD HH:MM, or RFC3339";
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum TimeWindowError {
+    #[error("Invalid --{flag}: {value} (expected {TIME_REF_FORMATS})")]
+    Invalid { flag: &'static str, value: String },
+    #[error("Empty window: --since {since} is not before --until {until}")]
+    Empty { since: String, until: String },
+}
+
+/// An absolute timestamp as stored in task front matter, the audit log, and the journal:
+/// `YYYY-MM-DD`, `YYYY-MM-DD HH:MM[:SS]` (also with `T`), or RFC3339 (converted to local time).
+pub fn parse_timestamp_ref(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(value) {
+        return Some(time.with_timezone(&Local).naive_local());
+    }
+    for format in [
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%dT%H:%M:%S",
+    ] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(value, format) {
+            return Some(time);
+        }
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+/// A point in time relative to `now` (`30m`, `12h`, `7d`, `2w`, `now`, `today`, `yesterday`)
+/// or any form [`parse_timestamp_ref`] accepts. `today`/`yesterday` mean midnight.
+pub fn parse_time_ref(value: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let lowered = value.trim().to_lowercase();
+    let midnight = now.date().and_hms_opt(0, 0, 0)?;
+    match lowered.as_str() {
+        "" => return None,
+        "now" => return Some(now),
+        "today" => return Some(midnight),
+        "yesterday" => return Some(midnight - Duration::days(1)),
+        _ => {}
+    }
+    if let Some(time) = parse_timestamp_ref(value) {
+        return Some(time);
+    }
+    let unit = lowered.chars().last()?;
+    let number: i64 = lowered[..lowered.len() - unit.len_utf8()].parse().ok()?;
+    let span = match unit {
+        'm' => Duration::minutes(number),
+        'h' => Duration::hours(number),
+        'd' => Duration::days(number),
+        'w' => Duration::weeks(number),
+        _ => return None,
+    };
+    Some(now - span)
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TimeWindow {
+    pub since: Option<NaiveDateTime>,
+    pub until: Option<NaiveDateTime>,
+}
+
+impl TimeWindow {
+    /// Parse `--since` / `--until` values; either may be omitted.
+    pub fn parse(
+        since: Option<&str>,
+        until: Option<&str>,
+        now: NaiveDateTime,
+    ) -> Result<Self, TimeWindowError> {
+        let bound = |flag: &'static str, value: Option<&str>| {
+            value
+                .map(|value| {
+                    parse_time_ref(value, now).ok_or_else(|| TimeWindowError::Invalid {
+                        flag,
+                        value: value.to_string(),
+                    })
+                })
+                .transpose()
+        };
+        let window = TimeWindow {
+            since: bound("since", since)?,
+            until: bound("until", until)?,
+        };
+        if let (Some(since), Some(until)) = (window.since, window.until) {
+            if since >= until {
+                return Err(TimeWindowError::Empty {
+                    since: since.format("%Y-%m-%d %H:%M").to_string(),
+                    until: until.format("%Y-%m-%d %H:%M").to_string(),
+                });
+            }
+        }
+        Ok(window)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.since.is_none() && self.until.is_none()
+    }
+
+    pub fn contains(&self, at: NaiveDateTime) -> bool {
+        self.since.is_none_or(|since| at >= since) && self.until.is_none_or(|until| at < until)
+    }
+
+    /// [`Self::contains`] for a stored timestamp. Unparseable timestamps only match an
+    /// unbounded window, since they cannot be shown to fall inside it.
+    pub fn contains_timestamp(&self, value: &str) -> bool {
+        if self.is_empty() {
+            return true;
+        }
+        parse_timestamp_ref(value).is_some_and(|at| self.contains(at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_refs_and_windows_share_one_grammar() {
+        let now = parse_timestamp_ref("2026-03-10 15:30").expect("now");
+        let at = |value: &str| parse_time_ref(value, now).map(|t| t.to_string());
+        assert_eq!(at("7d").as_deref(), Some("2026-03-03 15:30:00"));
+        assert_eq!(at("2W").as_deref(), Some("2026-02-24 15:30:00"));
+        assert_eq!(at("90m").as_deref(), Some("2026-03-10 14:00:00"));
+        assert_eq!(at("yesterday").as_deref(), Some("2026-03-09 00:00:00"));
+        assert_eq!(at("today").as_deref(), Some("2026-03-10 00:00:00"));
+        assert_eq!(at("2026-03-01").as_deref(), Some("2026-03-01 00:00:00"));
+        assert_eq!(
+            at("2026-03-01T08:15:00").as_deref(),
+            Some("2026-03-01 08:15:00")
+        );
+        assert!(at("2026-03-01T08:15:00Z").is_some());
+        assert_eq!(at("soon"), None);
+        assert_eq!(at("7x"), None);
+
+        let day = TimeWindow::parse(Some("yesterday"), Some("today"), now).expect("window");
+        assert!(day.contains_timestamp("2026-03-09 00:00"));
+        assert!(day.contains_timestamp("2026-03-09 23:59"));
+        assert!(!day.contains_timestamp("2026-03-10 00:00"));
+        assert!(!day.contains_timestamp("garbage"));
+        assert!(TimeWindow::default().contains_timestamp("garbage"));
+
+        assert_eq!(
+            TimeWindow::parse(Some("nope"), None, now),
+            Err(TimeWindowError::Invalid {
+                flag: "since",
+                value: "nope".to_string()
+            })
+        );
+        assert!(matches!(
+            TimeWindow::parse(Some("today"), Some("yesterday"), now),
+            Err(TimeWindowError::Empty { .. })
+        ));
+    }
+}
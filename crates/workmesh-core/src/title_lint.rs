@@ -0,0 +1,471 @@
+//! Opt-in style checks for task titles: length limits, sentence case, imperative mood, and
+//! duplicates, with a suggested rewrite per task (`lint --titles [--fix]`).
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::config::{load_config, load_global_config};
+use crate::task::{Task, TaskParseError};
+use crate::task_ops::{rename_task_file_for_title, update_task_field, FieldValue};
+
+pub const DEFAULT_MAX_TITLE_CHARS: usize = 80;
+pub const DEFAULT_MIN_TITLE_CHARS: usize = 8;
+/// Titles must not start with a lowercase letter.
+const DEFAULT_SENTENCE_CASE: &str = r"^[^\p{Ll}]";
+
+/// Leading verb forms flagged as not imperative, and the imperative used in suggestions.
+const VERB_FORMS: &[(&str, &str)] = &[
+    ("adding", "add"),
+    ("added", "add"),
+    ("adds", "add"),
+    ("changing", "change"),
+    ("changed", "change"),
+    ("changes", "change"),
+    ("cleaning", "clean"),
+    ("cleaned", "clean"),
+    ("cleans", "clean"),
+    ("creating", "create"),
+    ("created", "create"),
+    ("creates", "create"),
+    ("documenting", "document"),
+    ("documented", "document"),
+    ("fixing", "fix"),
+    ("fixed", "fix"),
+    ("fixes", "fix"),
+    ("handling", "handle"),
+    ("handled", "handle"),
+    ("handles", "handle"),
+    ("implementing", "implement"),
+    ("implemented", "implement"),
+    ("implements", "implement"),
+    ("improving", "improve"),
+    ("improved", "improve"),
+    ("improves", "improve"),
+    ("making", "make"),
+    ("made", "make"),
+    ("makes", "make"),
+    ("moving", "move"),
+    ("moved", "move"),
+    ("moves", "move"),
+    ("refactoring", "refactor"),
+    ("refactored", "refactor"),
+    ("refactors", "refactor"),
+    ("removing", "remove"),
+    ("removed", "remove"),
+    ("removes", "remove"),
+    ("renaming", "rename"),
+    ("renamed", "rename"),
+    ("renames", "rename"),
+    ("supporting", "support"),
+    ("supported", "support"),
+    ("supports", "support"),
+    ("updating", "update"),
+    ("updated", "update"),
+    ("updates", "update"),
+];
+
+#[derive(Debug, Error)]
+pub enum TitleLintError {
+    #[error("Invalid title_lint.{field} regex: {source}")]
+    Regex {
+        field: &'static str,
+        source: regex::Error,
+    },
+    #[error(transparent)]
+    Task(#[from] TaskParseError),
+}
+
+/// `[title_lint]` table in `.workmesh.toml` (project) or `~/.workmesh/config.toml` (global).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TitleLintConfig {
+    /// Longest allowed title, in characters (default 80).
+    pub max_length: Option<usize>,
+    /// Shortest allowed title, in characters (default 8).
+    pub min_length: Option<usize>,
+    /// Regex every title must match (default: no leading lowercase letter). `""` turns the
+    /// sentence-case check off, including the Title Case check.
+    pub sentence_case: Option<String>,
+    /// Regex flagging non-imperative titles (default: `Adding`/`Added`/`Adds` forms of common
+    /// verbs). `""` turns the check off.
+    pub non_imperative: Option<String>,
+    /// Flag tasks sharing a title (default true).
+    pub duplicates: Option<bool>,
+}
+
+/// Project `[title_lint]` wins over global `[title_lint]`.
+pub fn resolve_title_lint_config(repo_root: &Path) -> TitleLintConfig {
+    load_config(repo_root)
+        .and_then(|config| config.title_lint)
+        .or_else(|| load_global_config().and_then(|config| config.title_lint))
+        .unwrap_or_default()
+}
+
+/// Compiled `[title_lint]` settings.
+#[derive(Debug, Clone)]
+pub struct TitleLintRules {
+    pub max_length: usize,
+    pub min_length: usize,
+    pub sentence_case: Option<Regex>,
+    pub non_imperative: Option<Regex>,
+    pub duplicates: bool,
+}
+
+impl TitleLintRules {
+    pub fn from_config(config: &TitleLintConfig) -> Result<Self, TitleLintError> {
+        let default_imperative = format!(
+            r"(?i)^({})\b",
+            VERB_FORMS
+                .iter()
+                .map(|(form, _)| *form)
+                .collect::<Vec<_>>()
+                .join("|")
+        );
+        Ok(Self {
+            max_length: config.max_length.unwrap_or(DEFAULT_MAX_TITLE_CHARS),
+            min_length: config.min_length.unwrap_or(DEFAULT_MIN_TITLE_CHARS),
+            sentence_case: compile(
+                "sentence_case",
+                config.sentence_case.as_deref(),
+                DEFAULT_SENTENCE_CASE,
+            )?,
+            non_imperative: compile(
+                "non_imperative",
+                config.non_imperative.as_deref(),
+                &default_imperative,
+            )?,
+            duplicates: config.duplicates.unwrap_or(true),
+        })
+    }
+}
+
+impl Default for TitleLintRules {
+    fn default() -> Self {
+        Self::from_config(&TitleLintConfig::default()).expect("default title lint rules")
+    }
+}
+
+fn compile(
+    field: &'static str,
+    configured: Option<&str>,
+    default: &str,
+) -> Result<Option<Regex>, TitleLintError> {
+    let pattern = configured.unwrap_or(default);
+    if pattern.trim().is_empty() {
+        return Ok(None);
+    }
+    Regex::new(pattern)
+        .map(Some)
+        .map_err(|source| TitleLintError::Regex { field, source })
+}
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum TitleRule {
+    TooLong,
+    TooShort,
+    SentenceCase,
+    Imperative,
+    Punctuation,
+    Duplicate,
+}
+
+impl TitleRule {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TitleRule::TooLong => "too_long",
+            TitleRule::TooShort => "too_short",
+            TitleRule::SentenceCase => "sentence_case",
+            TitleRule::Imperative => "imperative",
+            TitleRule::Punctuation => "punctuation",
+            TitleRule::Duplicate => "duplicate",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TitleFinding {
+    pub task_id: String,
+    pub rule: TitleRule,
+    pub title: String,
+    pub message: String,
+    /// Rewritten title fixing what can be fixed mechanically; length and duplicate findings
+    /// need a human.
+    pub suggestion: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub struct TitleLintReport {
+    pub scanned: usize,
+    pub findings: Vec<TitleFinding>,
+    /// Task ids whose title was rewritten by `--fix`.
+    pub fixed: Vec<String>,
+    /// Findings the suggestions do not resolve.
+    pub unresolved: usize,
+    pub warnings: Vec<String>,
+}
+
+fn is_capitalized(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(char::is_uppercase)
+        && word.chars().count() > 1
+        && chars.all(char::is_lowercase)
+}
+
+/// "Add New Login Page": three or more capitalized words after the first and no lowercase
+/// word longer than three letters, so a couple of proper nouns do not trip it.
+fn looks_title_case(title: &str) -> bool {
+    let rest: Vec<&str> = title.split_whitespace().skip(1).collect();
+    let capitalized = rest.iter().filter(|word| is_capitalized(word)).count();
+    let lowercase = rest.iter().any(|word| {
+        word.chars().count() > 3 && word.chars().next().is_some_and(char::is_lowercase)
+    });
+    capitalized >= 3 && !lowercase
+}
+
+fn has_punctuation_issue(title: &str) -> bool {
+    let collapsed = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    collapsed != title || (title.ends_with('.') && !title.ends_with("..."))
+}
+
+/// Per-title findings (duplicates need the whole backlog; see `lint_titles`).
+pub fn lint_title(task_id: &str, title: &str, rules: &TitleLintRules) -> Vec<TitleFinding> {
+    let suggestion = suggest_title(title, rules);
+    let mut findings = Vec::new();
+    let mut finding = |rule: TitleRule, message: String, fixable: bool| {
+        findings.push(TitleFinding {
+            task_id: task_id.to_string(),
+            rule,
+            title: title.to_string(),
+            message,
+            suggestion: suggestion.clone().filter(|_| fixable),
+        });
+    };
+
+    let chars = title.trim().chars().count();
+    if chars > rules.max_length {
+        finding(
+            TitleRule::TooLong,
+            format!("{} characters (max {})", chars, rules.max_length),
+            false,
+        );
+    }
+    if chars < rules.min_length {
+        finding(
+            TitleRule::TooShort,
+            format!("{} characters (min {})", chars, rules.min_length),
+            false,
+        );
+    }
+    if let Some(pattern) = rules.sentence_case.as_ref() {
+        if !pattern.is_match(title.trim()) {
+            finding(
+                TitleRule::SentenceCase,
+                "does not match sentence_case".to_string(),
+                true,
+            );
+        } else if looks_title_case(title) {
+            finding(
+                TitleRule::SentenceCase,
+                "Title Case; use sentence case".to_string(),
+                true,
+            );
+        }
+    }
+    if let Some(pattern) = rules.non_imperative.as_ref() {
+        if pattern.is_match(title.trim()) {
+            finding(
+                TitleRule::Imperative,
+                "not imperative mood".to_string(),
+                true,
+            );
+        }
+    }
+    if has_punctuation_issue(title) {
+        finding(
+            TitleRule::Punctuation,
+            "extra whitespace or trailing period".to_string(),
+            true,
+        );
+    }
+    findings
+}
+
+/// Mechanical rewrite: collapse whitespace, drop a trailing period, swap a known leading verb
+/// form for its imperative, sentence-case Title Case words, and capitalize the first letter.
+/// `None` when nothing would change.
+pub fn suggest_title(title: &str, rules: &TitleLintRules) -> Option<String> {
+    let mut words: Vec<String> = title.split_whitespace().map(str::to_string).collect();
+    if let Some(last) = words.last_mut() {
+        if last.ends_with('.') && !last.ends_with("...") {
+            last.pop();
+        }
+    }
+    let joined = words.join(" ");
+    if let (Some(pattern), Some(first)) = (rules.non_imperative.as_ref(), words.first_mut()) {
+        if pattern.is_match(&joined) {
+            if let Some((_, base)) = VERB_FORMS
+                .iter()
+                .find(|(form, _)| form.eq_ignore_ascii_case(first))
+            {
+                *first = base.to_string();
+            }
+        }
+    }
+    if rules.sentence_case.is_some() {
+        if looks_title_case(&joined) {
+            for word in words.iter_mut().skip(1) {
+                if is_capitalized(word) {
+                    *word = word.to_lowercase();
+                }
+            }
+        }
+        if let Some(first) = words.first_mut() {
+            let mut chars = first.chars();
+            if let Some(head) = chars.next().filter(|c| c.is_lowercase()) {
+                *first = head.to_uppercase().chain(chars).collect();
+            }
+        }
+    }
+    let suggested = words.join(" ");
+    (!suggested.is_empty() && suggested != title).then_some(suggested)
+}
+
+fn duplicate_key(title: &str) -> String {
+    title
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches('.')
+        .to_lowercase()
+}
+
+/// Lint titles across `tasks`; with `fix`, write each task's suggestion back to its `title`
+/// (filenames are left alone; `fix filenames` renames them).
+pub fn lint_titles(
+    tasks: &[Task],
+    rules: &TitleLintRules,
+    fix: bool,
+) -> Result<TitleLintReport, TitleLintError> {
+    let mut report = TitleLintReport::default();
+    let mut ordered: Vec<&Task> = tasks.iter().collect();
+    ordered.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut by_title: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+    if rules.duplicates {
+        for task in &ordered {
+            by_title
+                .entry(duplicate_key(&task.title))
+                .or_default()
+                .push(task.id.as_str());
+        }
+    }
+
+    for task in ordered {
+        report.scanned += 1;
+        let mut findings = lint_title(&task.id, &task.title, rules);
+        let others: Vec<&str> = by_title
+            .get(&duplicate_key(&task.title))
+            .into_iter()
+            .flatten()
+            .copied()
+            .filter(|id| *id != task.id)
+            .collect();
+        if !others.is_empty() {
+            findings.push(TitleFinding {
+                task_id: task.id.clone(),
+                rule: TitleRule::Duplicate,
+                title: task.title.clone(),
+                message: format!("same title as {}", others.join(", ")),
+                suggestion: None,
+            });
+        }
+        if findings.is_empty() {
+            continue;
+        }
+
+        let suggestion = suggest_title(&task.title, rules);
+        let after = suggestion.as_deref().unwrap_or(&task.title);
+        let remaining = lint_title(&task.id, after, rules).len();
+        report.unresolved += remaining + usize::from(!others.is_empty());
+
+        if let (true, Some(title)) = (fix, suggestion) {
+            let Some(path) = task.file_path.as_ref() else {
+                report
+                    .warnings
+                    .push(format!("{} has no file path; not fixed", task.id));
+                report.findings.extend(findings);
+                continue;
+            };
+            match update_task_field(path, "title", Some(FieldValue::Scalar(title)))
+                .and_then(|()| rename_task_file_for_title(path, &task.title))
+            {
+                Ok(_) => report.fixed.push(task.id.clone()),
+                Err(err) => report
+                    .warnings
+                    .push(format!("{} not fixed: {}", task.id, err)),
+            }
+        }
+        report.findings.extend(findings);
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flags_style_issues_and_suggests_rewrites() {
+        let rules = TitleLintRules::default();
+        let rules_of = |title: &str| -> Vec<&'static str> {
+            lint_title("task-001", title, &rules)
+                .iter()
+                .map(|f| f.rule.as_str())
+                .collect()
+        };
+
+        assert!(rules_of("Add retry to webhook delivery").is_empty());
+        assert_eq!(
+            rules_of("adding retries  to webhooks."),
+            vec!["sentence_case", "imperative", "punctuation"]
+        );
+        assert_eq!(
+            suggest_title("adding retries  to webhooks.", &rules).as_deref(),
+            Some("Add retries to webhooks")
+        );
+        assert_eq!(rules_of("Add New Login Page Flow"), vec!["sentence_case"]);
+        assert_eq!(
+            suggest_title("Add New Login Page Flow", &rules).as_deref(),
+            Some("Add new login page flow")
+        );
+        assert_eq!(rules_of("Fix"), vec!["too_short"]);
+        assert_eq!(
+            rules_of(&format!("Add {}", "x".repeat(90))),
+            vec!["too_long"]
+        );
+        assert_eq!(suggest_title("Add retry to webhook delivery", &rules), None);
+
+        let relaxed = TitleLintRules::from_config(&TitleLintConfig {
+            sentence_case: Some(String::new()),
+            non_imperative: Some(r"^WIP\b".to_string()),
+            ..Default::default()
+        })
+        .expect("rules");
+        let found: Vec<_> = lint_title("task-001", "WIP adding retries", &relaxed)
+            .into_iter()
+            .map(|f| f.rule)
+            .collect();
+        assert_eq!(found, vec![TitleRule::Imperative]);
+
+        let err = TitleLintRules::from_config(&TitleLintConfig {
+            non_imperative: Some("(".to_string()),
+            ..Default::default()
+        })
+        .unwrap_err();
+        assert!(err.to_string().contains("title_lint.non_imperative"));
+    }
+}
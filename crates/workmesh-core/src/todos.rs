@@ -0,0 +1,518 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use regex::Regex;
+use serde::Serialize;
+use thiserror::Error;
+
+use crate::health::{first_extra, is_closed};
+use crate::initiative::reserve_namespaced_task_id;
+use crate::project::repo_root_from_backlog;
+use crate::storage::write_string_atomic;
+use crate::task::{parse_task_file, tasks_dir_for_root, Task, TaskParseError};
+use crate::task_ops::{
+    create_task_file_with_sections, update_task_field, FieldValue, TaskSectionContent,
+};
+
+/// Front matter key recording where a scanned TODO came from (`path:line` at creation time).
+pub const TODO_SOURCE_FIELD: &str = "todo_source";
+/// Inline marker tying a comment to its task, e.g. `// TODO: retry workmesh:task-work-012`.
+pub const TODO_MARKER_PREFIX: &str = "workmesh:";
+
+const SKIPPED_DIRS: &[&str] = &["target", "node_modules", "vendor", "dist", "build"];
+const MAX_FILE_BYTES: u64 = 1024 * 1024;
+const MAX_TITLE_CHARS: usize = 80;
+
+struct TodoPatterns {
+    /// A comment opener, then TODO/FIXME with an optional `(owner)` and colon. Anchored: it is
+    /// only tried where a comment can start (see [`find_todo`]).
+    todo: Regex,
+    marker: Regex,
+}
+
+impl TodoPatterns {
+    fn new() -> Self {
+        Self {
+            todo: Regex::new(
+                r"^(?://+|#+|/\*+|\*|--|<!--|;+)\s*(TODO|FIXME)\b(?:\([^)]*\))?:?\s*(.*)$",
+            )
+            .expect("regex"),
+            marker: Regex::new(r"workmesh:([A-Za-z0-9][A-Za-z0-9_.-]*)").expect("regex"),
+        }
+    }
+}
+
+/// Locate a TODO comment on `line`: the byte offset of its opener plus the match.
+///
+/// An opener only counts at the start of the line or after whitespace, and never inside a
+/// `"`, `'` or `` ` `` quoted string, so `let s = "// TODO";` is code, not a comment. A bare `*`
+/// (block comment continuation) must be the first thing on the line.
+fn find_todo<'a>(patterns: &TodoPatterns, line: &'a str) -> Option<(usize, regex::Captures<'a>)> {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    let mut prev: Option<char> = None;
+    for (pos, ch) in line.char_indices() {
+        if let Some(open) = quote {
+            if escaped {
+                escaped = false;
+            } else if ch == '\\' {
+                escaped = true;
+            } else if ch == open {
+                quote = None;
+            }
+        } else if matches!(ch, '"' | '\'' | '`') {
+            quote = Some(ch);
+        } else if prev.is_none_or(char::is_whitespace) {
+            let rest = &line[pos..];
+            let leading = line[..pos].trim().is_empty();
+            if rest.starts_with('*') && !rest.starts_with("*/") && !leading {
+                prev = Some(ch);
+                continue;
+            }
+            if let Some(captures) = patterns.todo.captures(rest) {
+                return Some((pos, captures));
+            }
+        }
+        prev = Some(ch);
+    }
+    None
+}
+
+#[derive(Debug, Error)]
+pub enum TodoError {
+    #[error("Failed to read or write source file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to create task: {0}")]
+    Task(#[from] TaskParseError),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TodoComment {
+    /// Path relative to the repo root, with `/` separators.
+    pub path: String,
+    /// 1-based line number.
+    pub line: usize,
+    pub kind: String,
+    pub text: String,
+    /// Task id from an inline `workmesh:<id>` marker.
+    pub task_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResolvedTodo {
+    pub task_id: String,
+    pub title: String,
+    pub status: String,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedTodoTask {
+    pub task_id: String,
+    pub task_path: PathBuf,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TodoScanReport {
+    pub apply: bool,
+    pub paths: Vec<String>,
+    /// Comments without a marker; `--apply` turns each into a task.
+    pub untracked: Vec<TodoComment>,
+    pub tracked: Vec<TodoComment>,
+    /// Open tasks created from TODOs under the scanned paths whose marker no longer exists.
+    pub resolved: Vec<ResolvedTodo>,
+    pub created: Vec<CreatedTodoTask>,
+}
+
+/// Scan `paths` (relative to the repo root; default: the whole repo) for TODO/FIXME comments.
+///
+/// Hidden directories, build output, and the backlog itself are skipped.
+pub fn scan_todos(backlog_dir: &Path, paths: &[PathBuf]) -> Vec<TodoComment> {
+    let repo_root = repo_root_from_backlog(backlog_dir);
+    let mut files = Vec::new();
+    for root in scan_roots(&repo_root, paths) {
+        collect_files(&root, backlog_dir, &mut files);
+    }
+    files.sort();
+    files.dedup();
+
+    let patterns = TodoPatterns::new();
+    let mut comments = Vec::new();
+    for file in files {
+        let Ok(text) = fs::read_to_string(&file) else {
+            continue;
+        };
+        let path = relative_path(&repo_root, &file);
+        for (index, line) in text.lines().enumerate() {
+            if let Some(comment) = parse_todo_line(&patterns, line) {
+                comments.push(TodoComment {
+                    path: path.clone(),
+                    line: index + 1,
+                    ..comment
+                });
+            }
+        }
+    }
+    comments
+}
+
+/// Build the scan report and, with `apply`, create tasks and insert markers for untracked TODOs.
+///
+/// New task ids are allocated in the `initiative` namespace (`task-<initiative>-NNN`).
+pub fn scan_todos_report(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    paths: &[PathBuf],
+    initiative: &str,
+    apply: bool,
+) -> Result<TodoScanReport, TodoError> {
+    let comments = scan_todos(backlog_dir, paths);
+    let scanned = scanned_prefixes(paths);
+    let markers: HashSet<String> = comments
+        .iter()
+        .filter_map(|comment| comment.task_id.as_deref())
+        .map(str::to_lowercase)
+        .collect();
+
+    let resolved = tasks
+        .iter()
+        .filter(|task| !is_closed(task) && !markers.contains(&task.id.to_lowercase()))
+        .filter_map(|task| {
+            let source = first_extra(task, &[TODO_SOURCE_FIELD])?;
+            let file = source
+                .rsplit_once(':')
+                .map_or(source.as_str(), |(file, _)| file);
+            if !is_under(file, &scanned) {
+                return None;
+            }
+            Some(ResolvedTodo {
+                task_id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                source,
+            })
+        })
+        .collect();
+
+    let (tracked, untracked): (Vec<_>, Vec<_>) = comments
+        .into_iter()
+        .partition(|comment| comment.task_id.is_some());
+
+    let created = if apply {
+        create_todo_tasks(backlog_dir, tasks, &untracked, initiative)?
+    } else {
+        Vec::new()
+    };
+
+    Ok(TodoScanReport {
+        apply,
+        paths: scanned,
+        untracked,
+        tracked,
+        resolved,
+        created,
+    })
+}
+
+fn create_todo_tasks(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    untracked: &[TodoComment],
+    initiative: &str,
+) -> Result<Vec<CreatedTodoTask>, TodoError> {
+    let repo_root = repo_root_from_backlog(backlog_dir);
+    let tasks_dir = tasks_dir_for_root(backlog_dir);
+    let mut known: Vec<Task> = tasks.to_vec();
+    let mut created = Vec::new();
+    for comment in untracked {
+        let task_id = reserve_namespaced_task_id(backlog_dir, &known, initiative)?;
+        let source = format!("{}:{}", comment.path, comment.line);
+        let sections = TaskSectionContent {
+            description: format!("{} at `{}`:\n\n> {}", comment.kind, source, comment.text),
+            acceptance_criteria: format!(
+                "- The {} comment is addressed and removed.",
+                comment.kind
+            ),
+            definition_of_done: "- Code change merged.".to_string(),
+        };
+        let task_path = create_task_file_with_sections(
+            &tasks_dir,
+            &task_id,
+            &todo_title(comment),
+            "To Do",
+            "P3",
+            "Phase1",
+            &[],
+            &[comment.kind.to_lowercase()],
+            &[],
+            &sections,
+        )?;
+        update_task_field(
+            &task_path,
+            TODO_SOURCE_FIELD,
+            Some(FieldValue::Scalar(source.clone())),
+        )?;
+        insert_marker(&repo_root.join(&comment.path), comment.line, &task_id)?;
+        known.push(parse_task_file(&task_path)?);
+        created.push(CreatedTodoTask {
+            task_id,
+            task_path,
+            source,
+        });
+    }
+    Ok(created)
+}
+
+/// Append `workmesh:<id>` to the comment on `line`, before its closing `*/` or `-->` if present.
+///
+/// Only that line changes; every other byte, including CRLF line endings, is kept as is.
+fn insert_marker(file: &Path, line: usize, task_id: &str) -> Result<(), TodoError> {
+    let text = fs::read_to_string(file)?;
+    let marker = format!("{}{}", TODO_MARKER_PREFIX, task_id);
+    let patterns = TodoPatterns::new();
+    let mut updated = String::with_capacity(text.len() + marker.len() + 1);
+    for (index, raw) in text.split_inclusive('\n').enumerate() {
+        let content = raw.trim_end_matches(['\n', '\r']);
+        let ending = &raw[content.len()..];
+        let comment = (index + 1 == line)
+            .then(|| find_todo(&patterns, content))
+            .flatten()
+            .and_then(|(pos, captures)| captures.get(1).map(|kind| pos + kind.start()));
+        let Some(comment_start) = comment else {
+            updated.push_str(raw);
+            continue;
+        };
+        let closer = ["*/", "-->"]
+            .iter()
+            .filter_map(|closer| content[comment_start..].find(closer))
+            .min()
+            .map(|offset| comment_start + offset);
+        match closer {
+            Some(at) => {
+                updated.push_str(content[..at].trim_end());
+                updated.push(' ');
+                updated.push_str(&marker);
+                updated.push(' ');
+                updated.push_str(&content[at..]);
+            }
+            None => {
+                updated.push_str(content.trim_end());
+                updated.push(' ');
+                updated.push_str(&marker);
+            }
+        }
+        updated.push_str(ending);
+    }
+    write_string_atomic(file, &updated)?;
+    Ok(())
+}
+
+fn parse_todo_line(patterns: &TodoPatterns, line: &str) -> Option<TodoComment> {
+    let (_, captures) = find_todo(patterns, line)?;
+    let kind = captures.get(1)?.as_str().to_string();
+    let rest = captures.get(2).map_or("", |m| m.as_str());
+    let task_id = patterns
+        .marker
+        .captures(rest)
+        .and_then(|m| m.get(1))
+        .map(|m| m.as_str().to_string());
+    let text = patterns.marker.replace_all(rest, "");
+    let text = text
+        .trim()
+        .trim_end_matches("*/")
+        .trim_end_matches("-->")
+        .trim()
+        .to_string();
+    Some(TodoComment {
+        path: String::new(),
+        line: 0,
+        kind,
+        text,
+        task_id,
+    })
+}
+
+/// Task titles are written unquoted into front matter, so keep them to plain single-line text.
+fn todo_title(comment: &TodoComment) -> String {
+    let cleaned: String = comment
+        .text
+        .replace(": ", " - ")
+        .trim_start_matches(|ch: char| !ch.is_alphanumeric())
+        .trim_end_matches(':')
+        .to_string();
+    if cleaned.is_empty() {
+        return format!("{} in {}:{}", comment.kind, comment.path, comment.line);
+    }
+    if cleaned.chars().count() <= MAX_TITLE_CHARS {
+        return cleaned;
+    }
+    let truncated: String = cleaned.chars().take(MAX_TITLE_CHARS - 3).collect();
+    format!("{}...", truncated.trim_end())
+}
+
+fn scan_roots(repo_root: &Path, paths: &[PathBuf]) -> Vec<PathBuf> {
+    if paths.is_empty() {
+        return vec![repo_root.to_path_buf()];
+    }
+    paths
+        .iter()
+        .map(|path| {
+            if path.is_absolute() {
+                path.clone()
+            } else {
+                repo_root.join(path)
+            }
+        })
+        .collect()
+}
+
+fn scanned_prefixes(paths: &[PathBuf]) -> Vec<String> {
+    paths
+        .iter()
+        .map(|path| {
+            path.components()
+                .filter(|component| matches!(component, Component::Normal(_)))
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .collect()
+}
+
+fn is_under(file: &str, prefixes: &[String]) -> bool {
+    prefixes.is_empty()
+        || prefixes.iter().any(|prefix| {
+            prefix.is_empty()
+                || file == prefix
+                || file.starts_with(&format!("{}/", prefix.trim_end_matches('/')))
+        })
+}
+
+fn collect_files(path: &Path, backlog_dir: &Path, out: &mut Vec<PathBuf>) {
+    if path.is_file() {
+        let small = fs::metadata(path)
+            .map(|meta| meta.len() <= MAX_FILE_BYTES)
+            .unwrap_or(false);
+        if small {
+            out.push(path.to_path_buf());
+        }
+        return;
+    }
+    if path == backlog_dir {
+        return;
+    }
+    let Ok(read_dir) = fs::read_dir(path) else {
+        return;
+    };
+    for entry in read_dir.filter_map(|entry| entry.ok()) {
+        let child = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if child.is_dir() && (name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_str())) {
+            continue;
+        }
+        collect_files(&child, backlog_dir, out);
+    }
+}
+
+fn relative_path(repo_root: &Path, file: &Path) -> String {
+    file.strip_prefix(repo_root)
+        .unwrap_or(file)
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy().to_string())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::task::load_tasks;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PathBuf) {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        fs::create_dir_all(backlog_dir.join("tasks")).expect("tasks dir");
+        fs::create_dir_all(temp.path().join("src")).expect("src dir");
+        (temp, backlog_dir)
+    }
+
+    #[test]
+    fn parse_todo_line_reads_kind_text_and_marker() {
+        let patterns = TodoPatterns::new();
+        let comment =
+            parse_todo_line(&patterns, "    // TODO(alice): retry on timeout").expect("todo");
+        assert_eq!(comment.kind, "TODO");
+        assert_eq!(comment.text, "retry on timeout");
+        assert!(comment.task_id.is_none());
+
+        let comment =
+            parse_todo_line(&patterns, "/* FIXME leaks workmesh:task-work-004 */").expect("fixme");
+        assert_eq!(comment.text, "leaks");
+        assert_eq!(comment.task_id.as_deref(), Some("task-work-004"));
+
+        assert!(parse_todo_line(&patterns, "let todo_list = TODO_COUNT;").is_none());
+        assert!(parse_todo_line(&patterns, r#"let s = "// TODO: x";"#).is_none());
+        assert!(parse_todo_line(&patterns, "let url = 'http://x#TODO';").is_none());
+        let comment = parse_todo_line(&patterns, r#"let s = "// TODO: x"; // TODO: real"#)
+            .expect("trailing comment");
+        assert_eq!(comment.text, "real");
+    }
+
+    #[test]
+    fn insert_marker_keeps_code_and_line_endings_intact() {
+        let temp = TempDir::new().expect("tempdir");
+        let file = temp.path().join("lib.rs");
+        fs::write(
+            &file,
+            "let s = \"// TODO: x\";\r\n/* TODO: y */ call();\r\nlet t = 1;  \r\n",
+        )
+        .expect("write");
+        insert_marker(&file, 1, "task-work-001").expect("no comment");
+        insert_marker(&file, 2, "task-work-002").expect("block comment");
+        assert_eq!(
+            fs::read_to_string(&file).expect("read"),
+            "let s = \"// TODO: x\";\r\n/* TODO: y workmesh:task-work-002 */ call();\r\nlet t = 1;  \r\n"
+        );
+    }
+
+    #[test]
+    fn apply_creates_tasks_inserts_markers_and_skips_tracked() {
+        let (temp, backlog_dir) = setup();
+        let source = temp.path().join("src").join("lib.rs");
+        fs::write(
+            &source,
+            "fn main() {\n    // TODO: handle errors\n    \
+// FIXME done workmesh:task-work-009\n}\n",
+        )
+        .expect("write source");
+        let paths = vec![PathBuf::from("src")];
+
+        let report =
+            scan_todos_report(&backlog_dir, &[], &paths, "work", true).expect("scan report");
+        assert_eq!(report.untracked.len(), 1);
+        assert_eq!(report.tracked.len(), 1);
+        assert_eq!(report.created[0].task_id, "task-work-001");
+        assert_eq!(report.created[0].source, "src/lib.rs:2");
+
+        let text = fs::read_to_string(&source).expect("read source");
+        assert!(text.contains("// TODO: handle errors workmesh:task-work-001\n"));
+        let task = parse_task_file(&report.created[0].task_path).expect("task");
+        assert_eq!(task.title, "handle errors");
+        assert_eq!(task.labels, vec!["todo"]);
+
+        let tasks = load_tasks(&backlog_dir);
+        let again = scan_todos_report(&backlog_dir, &tasks, &paths, "work", false).expect("rescan");
+        assert!(again.untracked.is_empty());
+        assert!(again.resolved.is_empty());
+
+        fs::write(&source, "fn main() {}\n").expect("remove todo");
+        let resolved =
+            scan_todos_report(&backlog_dir, &tasks, &paths, "work", false).expect("resolved");
+        assert_eq!(resolved.resolved.len(), 1);
+        assert_eq!(resolved.resolved[0].task_id, "task-work-001");
+    }
+}
@@ -0,0 +1,164 @@
+//! Structured status history. `set-status` appends `{from, to, at, actor, reason}` to the task's
+//! `transitions:` front matter list, so the reason for a change travels with the task file
+//! instead of living only in the (local, unversioned) audit log.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::task::{Task, TaskParseError};
+use crate::task_ops::{append_front_matter_list_entry, now_timestamp};
+
+pub const TRANSITIONS_FIELD: &str = "transitions";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub from: String,
+    pub to: String,
+    pub at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actor: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockReasonCount {
+    pub reason: String,
+    pub count: usize,
+    pub tasks: Vec<String>,
+}
+
+/// Recorded transitions, oldest first. Malformed entries are skipped.
+pub fn task_transitions(task: &Task) -> Vec<StatusTransition> {
+    match task.extra.get(TRANSITIONS_FIELD) {
+        Some(serde_yaml::Value::Sequence(items)) => items
+            .iter()
+            .filter_map(|item| serde_yaml::from_value(item.clone()).ok())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Append a transition to the task file. Blank actors and reasons are dropped.
+pub fn record_status_transition(
+    path: &Path,
+    from: &str,
+    to: &str,
+    actor: Option<&str>,
+    reason: Option<&str>,
+) -> Result<StatusTransition, TaskParseError> {
+    let non_empty = |value: Option<&str>| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    };
+    let transition = StatusTransition {
+        from: from.trim().to_string(),
+        to: to.trim().to_string(),
+        at: now_timestamp(),
+        actor: non_empty(actor),
+        reason: non_empty(reason),
+    };
+    let entry = serde_yaml::to_value(&transition)
+        .map_err(|err| TaskParseError::Invalid(err.to_string()))?;
+    append_front_matter_list_entry(path, TRANSITIONS_FIELD, entry)?;
+    Ok(transition)
+}
+
+fn is_blocked_status(status: &str) -> bool {
+    status
+        .chars()
+        .filter(|ch| ch.is_ascii_alphanumeric())
+        .collect::<String>()
+        .eq_ignore_ascii_case("blocked")
+}
+
+/// Reasons given when tasks moved to `Blocked`, most common first. Reasons are grouped
+/// case-insensitively and reported in their first-seen spelling.
+pub fn block_reason_counts(tasks: &[Task]) -> Vec<BlockReasonCount> {
+    let mut groups: BTreeMap<String, BlockReasonCount> = BTreeMap::new();
+    for task in tasks {
+        for transition in task_transitions(task) {
+            let Some(reason) = transition
+                .reason
+                .filter(|_| is_blocked_status(&transition.to))
+            else {
+                continue;
+            };
+            let entry = groups
+                .entry(reason.to_lowercase())
+                .or_insert_with(|| BlockReasonCount {
+                    reason: reason.clone(),
+                    count: 0,
+                    tasks: Vec::new(),
+                });
+            entry.count += 1;
+            if !entry.tasks.contains(&task.id) {
+                entry.tasks.push(task.id.clone());
+            }
+        }
+    }
+    let mut counts: Vec<BlockReasonCount> = groups.into_values().collect();
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.reason.cmp(&b.reason)));
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::task::parse_task_file;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn transitions_append_to_front_matter_and_aggregate_block_reasons() {
+        let temp = TempDir::new().expect("tempdir");
+        let path = temp.path().join("task-001 - api.md");
+        fs::write(
+            &path,
+            "---\nid: task-001\ntitle: API\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+dependencies: []\nlabels: []\n---\n\nBody\n",
+        )
+        .expect("write task");
+
+        record_status_transition(
+            &path,
+            "To Do",
+            "Blocked",
+            Some("ana"),
+            Some("Waiting on upstream API"),
+        )
+        .expect("record");
+        record_status_transition(&path, "Blocked", "In Progress", None, Some(" ")).expect("record");
+
+        let text = fs::read_to_string(&path).expect("read");
+        assert!(text.contains("transitions:\n  - from: To Do\n"));
+        assert!(text.ends_with("---\n\nBody\n"));
+
+        let task = parse_task_file(&path).expect("parse");
+        let transitions = task_transitions(&task);
+        assert_eq!(transitions.len(), 2);
+        assert_eq!(transitions[0].actor.as_deref(), Some("ana"));
+        assert_eq!(transitions[1].reason, None);
+
+        let mut other = task.clone();
+        other.id = "task-002".to_string();
+        if let Some(serde_yaml::Value::Sequence(items)) = other.extra.get_mut(TRANSITIONS_FIELD) {
+            for item in items.iter_mut() {
+                if let Some(map) = item.as_mapping_mut() {
+                    if map.contains_key("reason") {
+                        map.insert("reason".into(), "waiting on UPSTREAM api".into());
+                    }
+                }
+            }
+        }
+        let counts = block_reason_counts(&[task, other]);
+        assert_eq!(counts.len(), 1);
+        assert_eq!(counts[0].reason, "Waiting on upstream API");
+        assert_eq!(counts[0].count, 2);
+        assert_eq!(counts[0].tasks, vec!["task-001", "task-002"]);
+    }
+}
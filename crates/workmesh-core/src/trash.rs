@@ -0,0 +1,311 @@
+//! Safe task removal (`remove`): move the file into `<backlog>/trash/` and leave a tombstone
+//! record, or keep it in place as `Cancelled`. Either way the id is stripped from every other
+//! task's `dependencies` and `soft_dependencies`, so nothing stays blocked on work that is gone.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::storage::{append_jsonl_locked, StorageError};
+use crate::task::{Task, TaskParseError};
+use crate::task_ops::{
+    now_timestamp, set_list_field, soft_dependencies, update_task_field, FieldValue,
+    SOFT_DEPENDENCIES_FIELD,
+};
+
+/// Status written by `remove --cancel`.
+pub const CANCELLED_STATUS: &str = "Cancelled";
+
+#[derive(Debug, Error)]
+pub enum RemoveError {
+    #[error("Task not found: {0}")]
+    NotFound(String),
+    #[error("Missing task path for {0}")]
+    MissingPath(String),
+    #[error("{0} is already in the trash: {1}")]
+    AlreadyTrashed(String, PathBuf),
+    #[error("Failed to update task: {0}")]
+    Task(#[from] TaskParseError),
+    #[error("Failed to move task: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Failed to write tombstone: {0}")]
+    Storage(#[from] StorageError),
+    #[error("Failed to encode tombstone: {0}")]
+    Json(#[from] serde_json::Error),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RemoveMode {
+    /// Move the file to `trash/` and append a tombstone.
+    Trash,
+    /// Keep the file and set `status: Cancelled`.
+    Cancel,
+}
+
+impl RemoveMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RemoveMode::Trash => "trash",
+            RemoveMode::Cancel => "cancel",
+        }
+    }
+}
+
+/// One line of `trash/tombstones.jsonl`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Tombstone {
+    pub id: String,
+    #[serde(default)]
+    pub uid: Option<String>,
+    pub title: String,
+    pub status: String,
+    pub removed_at: String,
+    /// Where the task file lived before removal.
+    pub original_path: PathBuf,
+    pub trash_path: PathBuf,
+    /// Tasks whose dependency lists dropped this id.
+    #[serde(default)]
+    pub stripped_from: Vec<String>,
+}
+
+/// A dependency list edit made (or planned) on another task.
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct StrippedDependency {
+    pub task_id: String,
+    pub field: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoveReport {
+    pub task_id: String,
+    pub mode: RemoveMode,
+    /// False for a dry run: nothing was written.
+    pub applied: bool,
+    pub path: PathBuf,
+    pub trash_path: Option<PathBuf>,
+    pub stripped: Vec<StrippedDependency>,
+    /// Tasks that still mention the id outside dependency lists (relationships, parents, ...).
+    pub referenced_by: Vec<String>,
+}
+
+pub fn trash_dir(backlog_dir: &Path) -> PathBuf {
+    backlog_dir.join("trash")
+}
+
+pub fn tombstones_path(backlog_dir: &Path) -> PathBuf {
+    trash_dir(backlog_dir).join("tombstones.jsonl")
+}
+
+/// Every readable tombstone, oldest first.
+pub fn read_tombstones(backlog_dir: &Path) -> Vec<Tombstone> {
+    let Ok(content) = fs::read_to_string(tombstones_path(backlog_dir)) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect()
+}
+
+/// Remove `task_id` from the backlog. `tasks` should include archived tasks so their dependency
+/// lists are cleaned too. With `apply == false` the report describes what would happen.
+pub fn remove_task(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task_id: &str,
+    mode: RemoveMode,
+    apply: bool,
+) -> Result<RemoveReport, RemoveError> {
+    let task = tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(task_id.trim()))
+        .ok_or_else(|| RemoveError::NotFound(task_id.trim().to_string()))?;
+    let path = task
+        .file_path
+        .clone()
+        .ok_or_else(|| RemoveError::MissingPath(task.id.clone()))?;
+    let removed = task.id.to_lowercase();
+
+    let mut stripped = Vec::new();
+    let mut edits: Vec<(&Task, &str, Vec<String>)> = Vec::new();
+    let mut referenced_by = Vec::new();
+    for other in tasks.iter().filter(|other| other.id != task.id) {
+        for (field, current) in [
+            ("dependencies", other.dependencies.clone()),
+            (SOFT_DEPENDENCIES_FIELD, soft_dependencies(other)),
+        ] {
+            if !current.iter().any(|dep| dep.to_lowercase() == removed) {
+                continue;
+            }
+            let kept: Vec<String> = current
+                .into_iter()
+                .filter(|dep| dep.to_lowercase() != removed)
+                .collect();
+            stripped.push(StrippedDependency {
+                task_id: other.id.clone(),
+                field: field.to_string(),
+            });
+            edits.push((other, field, kept));
+        }
+        let relationships = &other.relationships;
+        if [
+            &relationships.blocked_by,
+            &relationships.parent,
+            &relationships.child,
+            &relationships.discovered_from,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|reference| reference.to_lowercase() == removed)
+        {
+            referenced_by.push(other.id.clone());
+        }
+    }
+
+    let trash_path = match mode {
+        RemoveMode::Trash => {
+            let file_name = path
+                .file_name()
+                .map(PathBuf::from)
+                .unwrap_or_else(|| PathBuf::from(format!("{}.md", task.id)));
+            let target = trash_dir(backlog_dir).join(file_name);
+            if target.exists() {
+                return Err(RemoveError::AlreadyTrashed(task.id.clone(), target));
+            }
+            Some(target)
+        }
+        RemoveMode::Cancel => None,
+    };
+
+    if apply {
+        for (other, field, kept) in &edits {
+            if let Some(other_path) = other.file_path.as_ref() {
+                set_list_field(other_path, field, kept.clone())?;
+            }
+        }
+        match &trash_path {
+            Some(target) => {
+                fs::create_dir_all(trash_dir(backlog_dir))?;
+                fs::rename(&path, target)?;
+                let mut stripped_from: Vec<String> =
+                    stripped.iter().map(|edit| edit.task_id.clone()).collect();
+                stripped_from.dedup();
+                let tombstone = Tombstone {
+                    id: task.id.clone(),
+                    uid: task.uid.clone(),
+                    title: task.title.clone(),
+                    status: task.status.clone(),
+                    removed_at: now_timestamp(),
+                    original_path: path.clone(),
+                    trash_path: target.clone(),
+                    stripped_from,
+                };
+                append_jsonl_locked(
+                    &tombstones_path(backlog_dir),
+                    &serde_json::to_string(&tombstone)?,
+                )?;
+            }
+            None => {
+                update_task_field(&path, "status", Some(FieldValue::from(CANCELLED_STATUS)))?;
+                update_task_field(&path, "updated_date", Some(now_timestamp().into()))?;
+            }
+        }
+    }
+
+    Ok(RemoveReport {
+        task_id: task.id.clone(),
+        mode,
+        applied: apply,
+        path,
+        trash_path,
+        stripped,
+        referenced_by,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tempfile::TempDir;
+
+    use crate::task::load_tasks;
+
+    fn write(tasks_dir: &Path, id: &str, extra: &str) {
+        fs::write(
+            tasks_dir.join(format!("{} - {}.md", id, id)),
+            format!(
+                "---\nid: {}\ntitle: {}\nstatus: To Do\npriority: P2\nphase: Phase1\n{}---\n",
+                id, id, extra
+            ),
+        )
+        .expect("write task");
+    }
+
+    #[test]
+    fn remove_trashes_task_strips_dependencies_and_records_tombstone() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write(&tasks_dir, "task-001", "dependencies: []\n");
+        write(
+            &tasks_dir,
+            "task-002",
+            "dependencies: [task-001, task-003]\nsoft_dependencies: [task-001]\n",
+        );
+        write(
+            &tasks_dir,
+            "task-003",
+            "dependencies: []\nparent: [task-001]\n",
+        );
+        let tasks = load_tasks(&backlog_dir);
+
+        let dry = remove_task(&backlog_dir, &tasks, "TASK-001", RemoveMode::Trash, false)
+            .expect("dry run");
+        assert!(!dry.applied);
+        assert_eq!(dry.stripped.len(), 2);
+        assert_eq!(dry.referenced_by, vec!["task-003"]);
+        assert!(dry.path.exists());
+        assert!(read_tombstones(&backlog_dir).is_empty());
+
+        let report =
+            remove_task(&backlog_dir, &tasks, "task-001", RemoveMode::Trash, true).expect("remove");
+        let trash_path = report.trash_path.expect("trash path");
+        assert!(trash_path.starts_with(trash_dir(&backlog_dir)));
+        assert!(trash_path.is_file());
+        assert!(!report.path.exists());
+
+        let remaining = load_tasks(&backlog_dir);
+        assert_eq!(remaining.len(), 2);
+        let task_002 = remaining.iter().find(|t| t.id == "task-002").expect("002");
+        assert_eq!(task_002.dependencies, vec!["task-003"]);
+        assert!(soft_dependencies(task_002).is_empty());
+
+        let tombstones = read_tombstones(&backlog_dir);
+        assert_eq!(tombstones.len(), 1);
+        assert_eq!(tombstones[0].id, "task-001");
+        assert_eq!(tombstones[0].stripped_from, vec!["task-002"]);
+
+        let report = remove_task(
+            &backlog_dir,
+            &remaining,
+            "task-003",
+            RemoveMode::Cancel,
+            true,
+        )
+        .expect("cancel");
+        assert!(report.trash_path.is_none());
+        let cancelled = load_tasks(&backlog_dir)
+            .into_iter()
+            .find(|t| t.id == "task-003")
+            .expect("003");
+        assert_eq!(cancelled.status, CANCELLED_STATUS);
+        assert!(load_tasks(&backlog_dir)
+            .iter()
+            .all(|t| !t.dependencies.contains(&"task-003".to_string())));
+    }
+}
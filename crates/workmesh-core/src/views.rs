@@ -5,6 +5,7 @@ use serde::{Deserialize, Serialize};
 use crate::context::{ContextScopeMode, ContextState};
 use crate::focus::FocusState;
 use crate::task::Task;
+use crate::task_ops::{is_epic, parent_links, unmet_soft_dependencies};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -12,6 +13,8 @@ pub enum BoardBy {
     Status,
     Phase,
     Priority,
+    /// One lane per epic document; tasks land in their nearest epic ancestor's lane.
+    Epic,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -42,6 +45,9 @@ pub fn board_lanes<'a>(
     by: BoardBy,
     scope_ids: Option<&HashSet<String>>,
 ) -> Vec<(String, Vec<&'a Task>)> {
+    if by == BoardBy::Epic {
+        return epic_lanes(tasks, scope_ids);
+    }
     let mut lanes: HashMap<String, (String, Vec<&Task>)> = HashMap::new();
     let mut first_seen: Vec<String> = Vec::new();
 
@@ -56,6 +62,7 @@ pub fn board_lanes<'a>(
             BoardBy::Status => task.status.trim(),
             BoardBy::Phase => task.phase.trim(),
             BoardBy::Priority => task.priority.trim(),
+            BoardBy::Epic => unreachable!("epic lanes are built separately"),
         };
         let display = if by == BoardBy::Status {
             canonical_status_name(raw_key)
@@ -118,6 +125,188 @@ pub fn board_lanes<'a>(
     out
 }
 
+/// Status lanes for a kanban board: workflow `statuses` first (in configured order, even when
+/// empty), then any other status `board_lanes` finds.
+pub fn status_columns<'a>(tasks: &'a [Task], statuses: &[String]) -> Vec<(String, Vec<&'a Task>)> {
+    let mut columns: Vec<(String, Vec<&Task>)> = statuses
+        .iter()
+        .map(|status| (status.clone(), Vec::new()))
+        .collect();
+    for (lane, lane_tasks) in board_lanes(tasks, BoardBy::Status, None) {
+        match columns
+            .iter_mut()
+            .find(|(status, _)| status.eq_ignore_ascii_case(&lane))
+        {
+            Some((_, column)) => column.extend(lane_tasks),
+            None => columns.push((lane, lane_tasks)),
+        }
+    }
+    columns
+}
+
+fn epic_lanes<'a>(
+    tasks: &'a [Task],
+    scope_ids: Option<&HashSet<String>>,
+) -> Vec<(String, Vec<&'a Task>)> {
+    let anchors = epic_anchors(tasks);
+    let mut lanes: HashMap<String, Vec<&Task>> = HashMap::new();
+    let mut none: Vec<&Task> = Vec::new();
+    for task in tasks {
+        if scope_ids.is_some_and(|scope| !scope.contains(&task.id.to_lowercase())) {
+            continue;
+        }
+        match anchors.get(&task.id.to_lowercase()) {
+            Some(epic) => lanes.entry(epic.to_lowercase()).or_default().push(task),
+            None => none.push(task),
+        }
+    }
+    let mut epics: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| is_epic(task) && lanes.contains_key(&task.id.to_lowercase()))
+        .collect();
+    epics.sort_by_key(|epic| stable_task_sort_key(epic));
+
+    let mut out = Vec::new();
+    for epic in epics {
+        if let Some(mut lane_tasks) = lanes.remove(&epic.id.to_lowercase()) {
+            // The epic document heads its own lane.
+            lane_tasks.sort_by_key(|t| (t.id != epic.id, stable_task_sort_key(t)));
+            out.push((format!("{} {}", epic.id, epic.title.trim()), lane_tasks));
+        }
+    }
+    if !none.is_empty() {
+        none.sort_by_key(|t| stable_task_sort_key(t));
+        out.push(("(none)".to_string(), none));
+    }
+    out
+}
+
+/// task id (lowercase) -> id of the epic document anchoring it.
+///
+/// An epic anchors itself; other tasks walk up their parent links to the nearest epic. Tasks
+/// with no epic ancestor are absent.
+pub fn epic_anchors(tasks: &[Task]) -> HashMap<String, String> {
+    let parents = parent_links(tasks);
+    let by_id: HashMap<String, &Task> = tasks
+        .iter()
+        .map(|task| (task.id.to_lowercase(), task))
+        .collect();
+    let mut anchors = HashMap::new();
+    for task in tasks {
+        let mut current = task.id.to_lowercase();
+        let mut seen = HashSet::new();
+        while seen.insert(current.clone()) {
+            if let Some(found) = by_id.get(&current).filter(|found| is_epic(found)) {
+                anchors.insert(task.id.to_lowercase(), found.id.clone());
+                break;
+            }
+            match parents.get(&current) {
+                Some(parent) => current = parent.clone(),
+                None => break,
+            }
+        }
+    }
+    anchors
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TreeNode {
+    pub id: String,
+    pub title: String,
+    pub kind: String,
+    pub status: String,
+    pub children: Vec<TreeNode>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EpicTree {
+    /// Top-level epics (no epic above them), each with its full subtree.
+    pub roots: Vec<TreeNode>,
+    /// Tasks that no epic anchors.
+    pub unanchored: Vec<String>,
+}
+
+/// Hierarchy rooted at epic documents (or at `root` when given), following parent/child links.
+pub fn epic_tree(tasks: &[Task], root: Option<&str>) -> EpicTree {
+    let parents = parent_links(tasks);
+    let mut children: HashMap<String, Vec<&Task>> = HashMap::new();
+    for task in tasks {
+        if let Some(parent) = parents.get(&task.id.to_lowercase()) {
+            children.entry(parent.clone()).or_default().push(task);
+        }
+    }
+    for kids in children.values_mut() {
+        kids.sort_by_key(|t| stable_task_sort_key(t));
+    }
+
+    if let Some(root) = root {
+        let roots = tasks
+            .iter()
+            .find(|task| task.id.eq_ignore_ascii_case(root.trim()))
+            .map(|task| vec![tree_node(task, &children, &mut HashSet::new())])
+            .unwrap_or_default();
+        return EpicTree {
+            roots,
+            unanchored: Vec::new(),
+        };
+    }
+
+    let anchors = epic_anchors(tasks);
+    let mut epics: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| is_epic(task))
+        .filter(|epic| {
+            // Top-level unless some ancestor is anchored by another epic.
+            parents
+                .get(&epic.id.to_lowercase())
+                .and_then(|parent| anchors.get(parent))
+                .is_none()
+        })
+        .collect();
+    epics.sort_by_key(|epic| stable_task_sort_key(epic));
+    let mut seen = HashSet::new();
+    let roots = epics
+        .into_iter()
+        .map(|epic| tree_node(epic, &children, &mut seen))
+        .collect();
+    let mut unanchored: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| !anchors.contains_key(&task.id.to_lowercase()))
+        .collect();
+    unanchored.sort_by_key(|t| stable_task_sort_key(t));
+    EpicTree {
+        roots,
+        unanchored: unanchored.into_iter().map(|task| task.id.clone()).collect(),
+    }
+}
+
+fn tree_node(
+    task: &Task,
+    children: &HashMap<String, Vec<&Task>>,
+    seen: &mut HashSet<String>,
+) -> TreeNode {
+    let id = task.id.to_lowercase();
+    let kids = if seen.insert(id.clone()) {
+        children
+            .get(&id)
+            .map(|kids| {
+                kids.iter()
+                    .map(|kid| tree_node(kid, children, seen))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    TreeNode {
+        id: task.id.clone(),
+        title: task.title.clone(),
+        kind: task.kind.clone(),
+        status: task.status.clone(),
+        children: kids,
+    }
+}
+
 fn is_done(task: &Task) -> bool {
     task.status.trim().eq_ignore_ascii_case("done")
 }
@@ -129,7 +318,7 @@ fn all_blocker_refs(task: &Task) -> Vec<String> {
     refs
 }
 
-fn scope_ids_for_epic(tasks: &[Task], epic_id: &str) -> HashSet<String> {
+pub(crate) fn scope_ids_for_epic(tasks: &[Task], epic_id: &str) -> HashSet<String> {
     let epic_lc = epic_id.trim().to_lowercase();
     let mut included: HashSet<String> = HashSet::new();
     included.insert(epic_lc.clone());
@@ -226,6 +415,15 @@ pub struct BlockedTaskEntry {
     pub missing_refs: Vec<String>,
 }
 
+/// An open task whose soft dependencies are unfinished; reported, never counted as blocked.
+#[derive(Debug, Clone, Serialize)]
+pub struct SoftBlockedEntry {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub soft_dependencies: Vec<String>,
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct TopBlockerEntry {
     pub id: String,
@@ -237,6 +435,7 @@ pub struct BlockersReport {
     pub scope: serde_json::Value,
     pub blocked_tasks: Vec<BlockedTaskEntry>,
     pub top_blockers: Vec<TopBlockerEntry>,
+    pub soft_blocked: Vec<SoftBlockedEntry>,
     pub warnings: Vec<String>,
 }
 
@@ -313,6 +512,7 @@ pub fn blockers_report_with_context(
     let by_id: HashMap<String, &Task> = tasks.iter().map(|t| (t.id.to_lowercase(), t)).collect();
 
     let mut blocked_tasks = Vec::new();
+    let mut soft_blocked = Vec::new();
     let mut blocker_counts: HashMap<String, usize> = HashMap::new();
 
     for task in tasks {
@@ -324,6 +524,15 @@ pub fn blockers_report_with_context(
         if is_done(task) {
             continue;
         }
+        let soft = unmet_soft_dependencies(task, &done_ids);
+        if !soft.is_empty() {
+            soft_blocked.push(SoftBlockedEntry {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                soft_dependencies: soft,
+            });
+        }
         let mut blockers = Vec::new();
         let mut missing = Vec::new();
         let mut seen_refs: HashSet<String> = HashSet::new();
@@ -403,6 +612,160 @@ pub fn blockers_report_with_context(
         scope,
         blocked_tasks,
         top_blockers,
+        soft_blocked,
+        warnings,
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToposortEntry {
+    pub id: String,
+    pub title: String,
+    pub status: String,
+    pub priority: String,
+    /// Wave index: every dependency sits in an earlier level.
+    pub level: usize,
+    /// Open in-scope tasks that must finish first.
+    pub depends_on: Vec<String>,
+    /// Open tasks outside the scope that also block this one.
+    pub waits_on: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ToposortReport {
+    pub scope: serde_json::Value,
+    /// Execution order, level by level.
+    pub order: Vec<ToposortEntry>,
+    /// Task ids per level; tasks within a level can run in parallel.
+    pub levels: Vec<Vec<String>>,
+    /// Open tasks caught in (or behind) a dependency cycle; they are left out of `order`.
+    pub cyclic: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Done and the archive's terminal statuses (Cancelled, Won't Do, ...) no longer block anything.
+fn is_closed(task: &Task) -> bool {
+    let normalize = |value: &str| -> String {
+        value
+            .chars()
+            .filter(|ch| ch.is_ascii_alphanumeric())
+            .map(|ch| ch.to_ascii_lowercase())
+            .collect()
+    };
+    let status = normalize(&task.status);
+    crate::archive::default_archive_statuses()
+        .iter()
+        .any(|terminal| normalize(terminal) == status)
+}
+
+/// Topological execution order of open, non-epic tasks (optionally one epic's subtree), grouped
+/// into levels that can be dispatched as parallel waves.
+pub fn toposort_report(tasks: &[Task], epic_id: Option<&str>) -> ToposortReport {
+    let mut warnings = Vec::new();
+    let epic = epic_id.map(str::trim).filter(|epic| !epic.is_empty());
+    let scope_ids = epic.map(|epic| {
+        if !tasks.iter().any(|t| t.id.eq_ignore_ascii_case(epic)) {
+            warnings.push(format!("epic not found: {}", epic));
+        }
+        scope_ids_for_epic(tasks, epic)
+    });
+    let by_id: HashMap<String, &Task> = tasks.iter().map(|t| (t.id.to_lowercase(), t)).collect();
+    let mut nodes: Vec<&Task> = tasks
+        .iter()
+        .filter(|task| !is_closed(task) && !is_epic(task))
+        .filter(|task| {
+            scope_ids
+                .as_ref()
+                .is_none_or(|scope| scope.contains(&task.id.to_lowercase()))
+        })
+        .collect();
+    nodes.sort_by_key(|task| (task.priority.to_lowercase(), stable_task_sort_key(task)));
+    let in_scope: HashSet<String> = nodes.iter().map(|task| task.id.to_lowercase()).collect();
+
+    let mut depends_on: HashMap<String, Vec<String>> = HashMap::new();
+    let mut waits_on: HashMap<String, Vec<String>> = HashMap::new();
+    for task in &nodes {
+        let key = task.id.to_lowercase();
+        let mut seen = HashSet::new();
+        for raw in all_blocker_refs(task) {
+            let lc = raw.trim().to_lowercase();
+            if lc.is_empty() || lc == key || !seen.insert(lc.clone()) {
+                continue;
+            }
+            match by_id.get(&lc) {
+                None => warnings.push(format!("{}: unknown dependency {}", task.id, raw.trim())),
+                Some(dep) if is_closed(dep) => {}
+                Some(dep) if in_scope.contains(&lc) => depends_on
+                    .entry(key.clone())
+                    .or_default()
+                    .push(dep.id.clone()),
+                Some(dep) => waits_on
+                    .entry(key.clone())
+                    .or_default()
+                    .push(dep.id.clone()),
+            }
+        }
+    }
+
+    // Kahn's algorithm, one level at a time; nodes stay in priority/id order within a level.
+    let mut level_of: HashMap<String, usize> = HashMap::new();
+    let mut levels: Vec<Vec<String>> = Vec::new();
+    loop {
+        let wave: Vec<&Task> = nodes
+            .iter()
+            .filter(|task| !level_of.contains_key(&task.id.to_lowercase()))
+            .filter(|task| {
+                depends_on
+                    .get(&task.id.to_lowercase())
+                    .into_iter()
+                    .flatten()
+                    .all(|dep| level_of.contains_key(&dep.to_lowercase()))
+            })
+            .copied()
+            .collect();
+        if wave.is_empty() {
+            break;
+        }
+        for task in &wave {
+            level_of.insert(task.id.to_lowercase(), levels.len());
+        }
+        levels.push(wave.iter().map(|task| task.id.clone()).collect());
+    }
+
+    let mut order = Vec::new();
+    for (level, ids) in levels.iter().enumerate() {
+        for id in ids {
+            let key = id.to_lowercase();
+            let task = by_id[&key];
+            order.push(ToposortEntry {
+                id: task.id.clone(),
+                title: task.title.clone(),
+                status: task.status.clone(),
+                priority: task.priority.clone(),
+                level,
+                depends_on: depends_on.remove(&key).unwrap_or_default(),
+                waits_on: waits_on.remove(&key).unwrap_or_default(),
+            });
+        }
+    }
+    let cyclic: Vec<String> = nodes
+        .iter()
+        .filter(|task| !level_of.contains_key(&task.id.to_lowercase()))
+        .map(|task| task.id.clone())
+        .collect();
+    if !cyclic.is_empty() {
+        warnings.push(format!("dependency cycle involving: {}", cyclic.join(", ")));
+    }
+
+    let scope = match epic {
+        Some(epic) => serde_json::json!({"type": "epic", "epic_id": epic}),
+        None => serde_json::json!({"type": "repo"}),
+    };
+    ToposortReport {
+        scope,
+        order,
+        levels,
+        cyclic,
         warnings,
     }
 }
@@ -429,6 +792,7 @@ mod tests {
                 parent: parents.iter().map(|s| s.to_string()).collect(),
                 child: vec![],
                 discovered_from: vec![],
+                relates_to: vec![],
             },
             lease: None,
             project: None,
@@ -441,6 +805,45 @@ mod tests {
         }
     }
 
+    #[test]
+    fn board_and_tree_anchor_on_epic_documents() {
+        let mut tasks = vec![
+            t("task-001", "Auth", "In Progress", &[], &[]),
+            t("task-002", "Login", "To Do", &[], &["task-001"]),
+            t("task-003", "Sessions", "To Do", &[], &["task-001"]),
+            t("task-004", "Refresh", "To Do", &[], &["task-003"]),
+            t("task-005", "Loose", "To Do", &[], &[]),
+        ];
+        tasks[0].kind = "epic".to_string();
+        tasks[2].kind = "epic".to_string();
+
+        let lanes = board_lanes(&tasks, BoardBy::Epic, None);
+        let summary: Vec<(String, Vec<String>)> = lanes
+            .iter()
+            .map(|(key, lane)| (key.clone(), lane.iter().map(|t| t.id.clone()).collect()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    "task-001 Auth".to_string(),
+                    vec!["task-001".into(), "task-002".into()]
+                ),
+                (
+                    "task-003 Sessions".to_string(),
+                    vec!["task-003".into(), "task-004".into()]
+                ),
+                ("(none)".to_string(), vec!["task-005".into()]),
+            ]
+        );
+
+        let tree = epic_tree(&tasks, None);
+        assert_eq!(tree.roots.len(), 1);
+        assert_eq!(tree.roots[0].children.len(), 2);
+        assert_eq!(tree.roots[0].children[1].children[0].id, "task-004");
+        assert_eq!(tree.unanchored, vec!["task-005".to_string()]);
+    }
+
     #[test]
     fn board_groups_by_status_in_canonical_lane_order() {
         let tasks = vec![
@@ -474,6 +877,24 @@ mod tests {
         assert_eq!(report.top_blockers[0].blocked_count, 1);
     }
 
+    #[test]
+    fn blockers_report_lists_soft_dependencies_separately() {
+        let mut tasks = vec![
+            t("task-001", "Design", "To Do", &[], &[]),
+            t("task-002", "Build", "To Do", &[], &[]),
+        ];
+        tasks[1].extra.insert(
+            crate::task_ops::SOFT_DEPENDENCIES_FIELD.to_string(),
+            serde_yaml::Value::String("task-001".to_string()),
+        );
+        let report = blockers_report(&tasks, None, None);
+        assert!(report.blocked_tasks.is_empty());
+        assert!(report.top_blockers.is_empty());
+        assert_eq!(report.soft_blocked.len(), 1);
+        assert_eq!(report.soft_blocked[0].id, "task-002");
+        assert_eq!(report.soft_blocked[0].soft_dependencies, vec!["task-001"]);
+    }
+
     #[test]
     fn board_lanes_phase_scope_and_blank_bucket() {
         let tasks = vec![
@@ -553,4 +974,30 @@ mod tests {
             vec!["task-missing-999".to_string()]
         );
     }
+
+    #[test]
+    fn toposort_groups_open_tasks_into_levels() {
+        let tasks = vec![
+            t("task-001", "Schema", "Done", &[], &[]),
+            t("task-002", "API", "To Do", &["task-001"], &[]),
+            t("task-003", "UI", "To Do", &["task-002"], &[]),
+            t("task-004", "Docs", "In Progress", &["task-002"], &[]),
+            t("task-005", "Spike", "To Do", &[], &[]),
+            t("task-006", "Loop A", "To Do", &["task-007"], &[]),
+            t("task-007", "Loop B", "To Do", &["task-006"], &[]),
+        ];
+        let report = toposort_report(&tasks, None);
+        assert_eq!(
+            report.levels,
+            vec![
+                vec!["task-002".to_string(), "task-005".to_string()],
+                vec!["task-003".to_string(), "task-004".to_string()],
+            ]
+        );
+        assert_eq!(report.order[2].depends_on, vec!["task-002".to_string()]);
+        assert_eq!(
+            report.cyclic,
+            vec!["task-006".to_string(), "task-007".to_string()]
+        );
+    }
 }
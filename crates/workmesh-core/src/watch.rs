@@ -0,0 +1,83 @@
+//! Keep `.index/tasks.jsonl` current while task files change underneath it
+//! (`workmesh index-watch`).
+//!
+//! Changes are found by re-statting the tasks directory (mtime and size) on each poll, which
+//! behaves the same on every platform and on network or container mounts where change
+//! notifications are unreliable. Only files that changed are parsed.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use crate::index::{apply_index_changes, refresh_index, IndexChange, IndexError};
+use crate::task::tasks_dir_for_root;
+
+pub const DEFAULT_WATCH_INTERVAL_MS: u64 = 500;
+
+type FileStamp = (SystemTime, u64);
+
+pub struct BacklogWatcher {
+    backlog_dir: PathBuf,
+    tasks_dir: PathBuf,
+    stamps: HashMap<PathBuf, FileStamp>,
+}
+
+impl BacklogWatcher {
+    /// Refresh the index once, catching up on edits made while nothing was watching, and
+    /// remember the current state of every task file.
+    pub fn new(backlog_dir: &Path) -> Result<Self, IndexError> {
+        refresh_index(backlog_dir)?;
+        let tasks_dir = tasks_dir_for_root(backlog_dir);
+        let stamps = stamp_task_files(&tasks_dir);
+        Ok(Self {
+            backlog_dir: backlog_dir.to_path_buf(),
+            tasks_dir,
+            stamps,
+        })
+    }
+
+    /// Number of task files being watched.
+    pub fn tracked(&self) -> usize {
+        self.stamps.len()
+    }
+
+    /// Apply whatever changed since the previous poll to the index and report it. Files that
+    /// were only touched (same content) produce no change.
+    pub fn poll(&mut self) -> Result<Vec<IndexChange>, IndexError> {
+        let current = stamp_task_files(&self.tasks_dir);
+        let mut changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, stamp)| self.stamps.get(*path) != Some(stamp))
+            .map(|(path, _)| path.clone())
+            .collect();
+        changed.extend(
+            self.stamps
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .cloned(),
+        );
+        self.stamps = current;
+        if changed.is_empty() {
+            return Ok(Vec::new());
+        }
+        changed.sort();
+        apply_index_changes(&self.backlog_dir, &changed)
+    }
+}
+
+fn stamp_task_files(tasks_dir: &Path) -> HashMap<PathBuf, FileStamp> {
+    let Ok(read_dir) = fs::read_dir(tasks_dir) else {
+        return HashMap::new();
+    };
+    read_dir
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|ext| ext == "md").unwrap_or(false))
+        .filter_map(|path| {
+            let metadata = fs::metadata(&path).ok()?;
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            Some((path, (modified, metadata.len())))
+        })
+        .collect()
+}
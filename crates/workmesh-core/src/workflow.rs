@@ -0,0 +1,443 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::path::Path;
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::audit::{read_audit_events, AuditEvent};
+use crate::config::{load_config, load_global_config};
+use crate::policies::parse_task_date;
+use crate::task::Task;
+
+/// `[workflow]` table: the status state machine tasks are expected to follow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    /// Statuses in display order; the first one is where new tasks start.
+    pub statuses: Vec<String>,
+    /// Allowed moves (`from -> [to, ...]`). Statuses without outgoing moves are terminal.
+    #[serde(default)]
+    pub transitions: BTreeMap<String, Vec<String>>,
+}
+
+impl Default for WorkflowConfig {
+    fn default() -> Self {
+        let statuses = ["To Do", "In Progress", "Done"];
+        Self {
+            statuses: statuses.iter().map(|status| status.to_string()).collect(),
+            transitions: BTreeMap::from([
+                ("To Do".to_string(), vec!["In Progress".to_string()]),
+                (
+                    "In Progress".to_string(),
+                    vec!["Done".to_string(), "To Do".to_string()],
+                ),
+            ]),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowState {
+    pub name: String,
+    /// Tasks currently in this status.
+    pub tasks: usize,
+    /// Mean time spent in this status over completed stays seen in the audit log.
+    pub avg_dwell_hours: Option<f64>,
+    pub dwell_samples: usize,
+    /// False when the status only appears in task files or audit data.
+    pub configured: bool,
+    pub initial: bool,
+    pub terminal: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowTransition {
+    pub from: String,
+    pub to: String,
+    /// Times this move appears in the audit log.
+    pub observed: usize,
+    /// False for moves that happened but are not in `[workflow] transitions`.
+    pub configured: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowDiagram {
+    pub states: Vec<WorkflowState>,
+    pub transitions: Vec<WorkflowTransition>,
+}
+
+/// Project `[workflow]` wins over global `[workflow]`; falls back to To Do/In Progress/Done.
+pub fn resolve_workflow(repo_root: &Path) -> WorkflowConfig {
+    load_config(repo_root)
+        .and_then(|config| config.workflow)
+        .or_else(|| load_global_config().and_then(|config| config.workflow))
+        .unwrap_or_default()
+}
+
+/// Annotate the configured workflow with live task counts and audit-derived dwell times.
+pub fn workflow_diagram(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    config: &WorkflowConfig,
+) -> WorkflowDiagram {
+    workflow_diagram_from_events(tasks, config, &read_audit_events(backlog_dir))
+}
+
+fn workflow_diagram_from_events(
+    tasks: &[Task],
+    config: &WorkflowConfig,
+    events: &[AuditEvent],
+) -> WorkflowDiagram {
+    let mut names: Vec<String> = config.statuses.clone();
+    for (from, targets) in &config.transitions {
+        for status in std::iter::once(from).chain(targets) {
+            push_status_name(&mut names, status.trim());
+        }
+    }
+    for task in tasks {
+        if !task.status.trim().is_empty() {
+            push_status_name(&mut names, task.status.trim());
+        }
+    }
+
+    // Per task: ordered (time, status) entries from the audit log.
+    let mut history: HashMap<String, Vec<(NaiveDateTime, String)>> = HashMap::new();
+    for event in events {
+        let (Some(task_id), Some(status)) = (event.task_id.as_deref(), status_of(event)) else {
+            continue;
+        };
+        let Some(time) = parse_task_date(&event.timestamp) else {
+            continue;
+        };
+        push_status_name(&mut names, &status);
+        history
+            .entry(task_id.to_lowercase())
+            .or_default()
+            .push((time, status));
+    }
+
+    let key = |status: &str| status.trim().to_lowercase();
+    let mut dwell: HashMap<String, (f64, usize)> = HashMap::new();
+    let mut observed: BTreeMap<(String, String), usize> = BTreeMap::new();
+    for entries in history.values_mut() {
+        entries.sort_by_key(|(time, _)| *time);
+        for pair in entries.windows(2) {
+            let ((start, from), (end, to)) = (&pair[0], &pair[1]);
+            if key(from) == key(to) {
+                continue;
+            }
+            let hours = (*end - *start).num_minutes().max(0) as f64 / 60.0;
+            let slot = dwell.entry(key(from)).or_insert((0.0, 0));
+            slot.0 += hours;
+            slot.1 += 1;
+            *observed.entry((key(from), key(to))).or_insert(0) += 1;
+        }
+    }
+    let configured_key: HashMap<String, &String> = config
+        .statuses
+        .iter()
+        .map(|status| (key(status), status))
+        .collect();
+    let display = |status_key: &str| -> String {
+        names
+            .iter()
+            .find(|name| key(name) == status_key)
+            .cloned()
+            .unwrap_or_else(|| status_key.to_string())
+    };
+
+    let mut transitions: Vec<WorkflowTransition> = Vec::new();
+    for (from, targets) in &config.transitions {
+        for to in targets {
+            transitions.push(WorkflowTransition {
+                from: display(&key(from)),
+                to: display(&key(to)),
+                observed: observed.remove(&(key(from), key(to))).unwrap_or(0),
+                configured: true,
+            });
+        }
+    }
+    for ((from, to), count) in observed {
+        transitions.push(WorkflowTransition {
+            from: display(&from),
+            to: display(&to),
+            observed: count,
+            configured: false,
+        });
+    }
+
+    let initial = config.statuses.first().map(|status| key(status));
+    let states = names
+        .iter()
+        .map(|name| {
+            let name_key = key(name);
+            let (total, samples) = dwell.get(&name_key).copied().unwrap_or((0.0, 0));
+            WorkflowState {
+                name: name.clone(),
+                tasks: tasks
+                    .iter()
+                    .filter(|task| key(&task.status) == name_key)
+                    .count(),
+                avg_dwell_hours: if samples > 0 {
+                    Some(total / samples as f64)
+                } else {
+                    None
+                },
+                dwell_samples: samples,
+                configured: configured_key.contains_key(&name_key),
+                initial: initial.as_deref() == Some(name_key.as_str()),
+                terminal: configured_key.contains_key(&name_key)
+                    && !config
+                        .transitions
+                        .iter()
+                        .any(|(from, targets)| key(from) == name_key && !targets.is_empty()),
+            }
+        })
+        .collect();
+
+    WorkflowDiagram {
+        states,
+        transitions,
+    }
+}
+
+fn push_status_name(names: &mut Vec<String>, status: &str) {
+    if !names.iter().any(|name| name.eq_ignore_ascii_case(status)) {
+        names.push(status.to_string());
+    }
+}
+
+/// New status recorded by an audit event, if the event changed or set one.
+fn status_of(event: &AuditEvent) -> Option<String> {
+    let field = match event.action.as_str() {
+        "set_status" | "bulk_set_status" | "add_task" | "add_discovered" => "status",
+        "policy" => "set_status",
+        _ => return None,
+    };
+    event.details[field]
+        .as_str()
+        .map(|status| status.trim().to_string())
+        .filter(|status| !status.is_empty())
+}
+
+pub fn render_mermaid(diagram: &WorkflowDiagram) -> String {
+    let ids = state_ids(diagram);
+    let mut out = String::from("stateDiagram-v2\n");
+    for state in &diagram.states {
+        let _ = writeln!(
+            out,
+            "    state \"{}\" as {}",
+            state_label(state, ", ").replace('"', "'"),
+            ids[&state.name]
+        );
+    }
+    for state in diagram.states.iter().filter(|state| state.initial) {
+        let _ = writeln!(out, "    [*] --> {}", ids[&state.name]);
+    }
+    for transition in &diagram.transitions {
+        let label = transition_label(transition);
+        let edge = format!("{} --> {}", ids[&transition.from], ids[&transition.to]);
+        if label.is_empty() {
+            let _ = writeln!(out, "    {}", edge);
+        } else {
+            let _ = writeln!(out, "    {} : {}", edge, label);
+        }
+    }
+    for state in diagram.states.iter().filter(|state| state.terminal) {
+        let _ = writeln!(out, "    {} --> [*]", ids[&state.name]);
+    }
+    out
+}
+
+pub fn render_dot(diagram: &WorkflowDiagram) -> String {
+    let ids = state_ids(diagram);
+    let mut out = String::from("digraph workflow {\n    rankdir=LR;\n");
+    out.push_str("    node [shape=box, style=rounded];\n");
+    for state in &diagram.states {
+        let mut attrs = format!("label=\"{}\"", escape_dot(&state_label(state, "\n")));
+        if state.terminal {
+            attrs.push_str(", peripheries=2");
+        }
+        if !state.configured {
+            attrs.push_str(", style=\"rounded,dashed\"");
+        }
+        let _ = writeln!(out, "    {} [{}];", ids[&state.name], attrs);
+    }
+    for transition in &diagram.transitions {
+        let mut attrs = Vec::new();
+        let label = transition_label(transition);
+        if !label.is_empty() {
+            attrs.push(format!("label=\"{}\"", escape_dot(&label)));
+        }
+        if !transition.configured {
+            attrs.push("style=dashed".to_string());
+            attrs.push("color=red".to_string());
+        }
+        let attrs = if attrs.is_empty() {
+            String::new()
+        } else {
+            format!(" [{}]", attrs.join(", "))
+        };
+        let _ = writeln!(
+            out,
+            "    {} -> {}{};",
+            ids[&transition.from], ids[&transition.to], attrs
+        );
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn state_ids(diagram: &WorkflowDiagram) -> HashMap<String, String> {
+    diagram
+        .states
+        .iter()
+        .enumerate()
+        .map(|(idx, state)| (state.name.clone(), format!("s{}", idx)))
+        .collect()
+}
+
+fn state_label(state: &WorkflowState, separator: &str) -> String {
+    let mut label = format!(
+        "{}{}{} task{}",
+        state.name,
+        separator,
+        state.tasks,
+        if state.tasks == 1 { "" } else { "s" }
+    );
+    if let Some(hours) = state.avg_dwell_hours {
+        let _ = write!(label, "{}avg {}", separator, format_hours(hours));
+    }
+    label
+}
+
+fn transition_label(transition: &WorkflowTransition) -> String {
+    match (transition.observed, transition.configured) {
+        (0, _) => String::new(),
+        (count, true) => count.to_string(),
+        (count, false) => format!("{} (not configured)", count),
+    }
+}
+
+fn format_hours(hours: f64) -> String {
+    if hours < 48.0 {
+        format!("{:.1}h", hours)
+    } else {
+        format!("{:.1}d", hours / 24.0)
+    }
+}
+
+pub(crate) fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn event(timestamp: &str, action: &str, task_id: &str, status: &str) -> AuditEvent {
+        AuditEvent {
+            timestamp: timestamp.to_string(),
+            actor: None,
+            action: action.to_string(),
+            task_id: Some(task_id.to_string()),
+            details: json!({ "status": status }),
+        }
+    }
+
+    fn task(id: &str, status: &str) -> Task {
+        Task {
+            id: id.to_string(),
+            uid: None,
+            kind: "task".to_string(),
+            title: id.to_string(),
+            status: status.to_string(),
+            priority: "P2".to_string(),
+            phase: "Phase1".to_string(),
+            dependencies: Vec::new(),
+            labels: Vec::new(),
+            assignee: Vec::new(),
+            relationships: Default::default(),
+            lease: None,
+            project: None,
+            initiative: None,
+            created_date: None,
+            updated_date: None,
+            extra: HashMap::new(),
+            file_path: None,
+            body: String::new(),
+        }
+    }
+
+    #[test]
+    fn diagram_counts_tasks_dwell_and_unconfigured_moves() {
+        let tasks = vec![task("task-001", "Done"), task("task-002", "Blocked")];
+        let events = vec![
+            event("2026-01-01 10:00", "add_task", "task-001", "To Do"),
+            event("2026-01-01 16:00", "set_status", "task-001", "In Progress"),
+            event("2026-01-02 04:00", "set_status", "task-001", "Done"),
+            event("2026-01-01 10:00", "add_task", "task-002", "To Do"),
+            event("2026-01-01 12:00", "set_status", "task-002", "Blocked"),
+        ];
+        let diagram = workflow_diagram_from_events(&tasks, &WorkflowConfig::default(), &events);
+
+        let todo = &diagram.states[0];
+        assert_eq!(todo.name, "To Do");
+        assert!(todo.initial);
+        assert_eq!(todo.dwell_samples, 2);
+        assert_eq!(todo.avg_dwell_hours, Some(4.0));
+        let done = diagram
+            .states
+            .iter()
+            .find(|s| s.name == "Done")
+            .expect("done");
+        assert!(done.terminal);
+        assert_eq!(done.tasks, 1);
+        let blocked = diagram
+            .states
+            .iter()
+            .find(|s| s.name == "Blocked")
+            .expect("blocked");
+        assert!(!blocked.configured);
+
+        let unexpected: Vec<(&str, &str)> = diagram
+            .transitions
+            .iter()
+            .filter(|t| !t.configured)
+            .map(|t| (t.from.as_str(), t.to.as_str()))
+            .collect();
+        assert_eq!(unexpected, vec![("To Do", "Blocked")]);
+        let progress = diagram
+            .transitions
+            .iter()
+            .find(|t| t.from == "In Progress" && t.to == "Done")
+            .expect("in progress -> done");
+        assert_eq!(progress.observed, 1);
+    }
+
+    #[test]
+    fn renders_mermaid_and_dot() {
+        let diagram = workflow_diagram_from_events(
+            &[task("task-001", "To Do")],
+            &WorkflowConfig::default(),
+            &[],
+        );
+        let mermaid = render_mermaid(&diagram);
+        assert!(mermaid.starts_with("stateDiagram-v2\n"));
+        assert!(mermaid.contains("    state \"To Do, 1 task\" as s0\n"));
+        assert!(mermaid.contains("    [*] --> s0\n"));
+        assert!(mermaid.contains("    s0 --> s1\n"));
+        assert!(mermaid.contains("    s2 --> [*]\n"));
+
+        let dot = render_dot(&diagram);
+        assert!(dot.starts_with("digraph workflow {\n"));
+        assert!(dot.contains("    s0 [label=\"To Do\\n1 task\"];\n"));
+        assert!(dot.contains("    s2 [label=\"Done\\n0 tasks\", peripheries=2];\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+}
@@ -1168,6 +1168,7 @@ mod tests {
                 repo_root: Some(repo_root.to_string_lossy().to_string()),
             }),
             truth_refs: Vec::new(),
+            client: None,
         };
 
         crate::global_sessions::append_session_saved(home, session.clone()).expect("save session");
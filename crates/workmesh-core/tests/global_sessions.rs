@@ -43,6 +43,7 @@ fn append_and_load_sessions_returns_latest_snapshots() {
         handoff: None,
         worktree: None,
         truth_refs: vec![],
+        client: None,
     };
     let s2 = AgentSession {
         id: id2.clone(),
@@ -61,6 +62,7 @@ fn append_and_load_sessions_returns_latest_snapshots() {
         handoff: None,
         worktree: None,
         truth_refs: vec![],
+        client: None,
     };
 
     append_session_saved(home, s1).expect("append s1");
@@ -105,6 +107,7 @@ fn rebuild_and_verify_sessions_index() {
         handoff: None,
         worktree: None,
         truth_refs: vec![],
+        client: None,
     };
     append_session_saved(home, session).expect("append");
 
@@ -1,10 +1,15 @@
 use std::fs;
 use std::path::Path;
+use std::time::{Duration, SystemTime};
 
 use serde_json::Value;
 use tempfile::TempDir;
 
-use workmesh_core::index::{index_path, rebuild_index, refresh_index, verify_index};
+use workmesh_core::index::{
+    archive_index_path, find_archived_task, index_path, rebuild_index, refresh_archive_index,
+    refresh_index, search_archive, verify_index, IndexRefreshStats,
+};
+use workmesh_core::watch::BacklogWatcher;
 
 fn write_task(tasks_dir: &Path, id: &str, title: &str) {
     let content = format!(
@@ -97,3 +102,141 @@ fn rebuild_refresh_verify_index() {
     let report = verify_index(&backlog_dir).expect("verify");
     assert!(report.ok);
 }
+
+fn set_mtime(path: &Path, secs_ago: u64) {
+    let file = fs::File::options().write(true).open(path).expect("open");
+    file.set_modified(SystemTime::now() - Duration::from_secs(secs_ago))
+        .expect("set mtime");
+}
+
+#[test]
+fn refresh_index_reparses_only_changed_files() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha");
+    write_task(&tasks_dir, "task-002", "Beta");
+    write_task(&tasks_dir, "task-003", "Gamma");
+    for entry in fs::read_dir(&tasks_dir).expect("read dir") {
+        set_mtime(&entry.expect("entry").path(), 300);
+    }
+
+    let summary = refresh_index(&backlog_dir).expect("first refresh");
+    assert_eq!(summary.stats.expect("stats").reparsed, 3);
+    let summary = refresh_index(&backlog_dir).expect("noop refresh");
+    assert_eq!(
+        summary.stats,
+        Some(IndexRefreshStats {
+            scanned: 3,
+            skipped: 3,
+            ..IndexRefreshStats::default()
+        })
+    );
+
+    let alpha = tasks_dir.join("task-001 - Alpha.md");
+    let content = fs::read_to_string(&alpha).expect("read");
+    fs::write(&alpha, content.replace("To Do", "Done")).expect("edit");
+    set_mtime(&alpha, 60);
+    set_mtime(&tasks_dir.join("task-002 - Beta.md"), 120);
+    fs::remove_file(tasks_dir.join("task-003 - Gamma.md")).expect("remove");
+    write_task(&tasks_dir, "task-004", "Delta");
+
+    let summary = refresh_index(&backlog_dir).expect("refresh");
+    assert_eq!(summary.entries, 3);
+    assert_eq!(
+        summary.stats,
+        Some(IndexRefreshStats {
+            scanned: 3,
+            skipped: 0,
+            rehashed: 1,
+            reparsed: 2,
+            removed: 1,
+        })
+    );
+    assert!(verify_index(&backlog_dir).expect("verify").ok);
+    let index = fs::read_to_string(index_path(&backlog_dir)).expect("index");
+    assert!(index.contains("\"status\":\"Done\""));
+}
+
+#[test]
+fn archive_index_finds_and_searches_archived_tasks() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    let archive_dir = backlog_dir.join("archive").join("2026-02");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    fs::create_dir_all(&archive_dir).expect("archive dir");
+
+    write_task(&tasks_dir, "task-001", "Active");
+    write_task(&archive_dir, "task-002", "Payments retry");
+    write_task(&archive_dir, "task-003", "Docs cleanup");
+
+    let entries = refresh_archive_index(&backlog_dir).expect("refresh archive");
+    assert_eq!(entries.len(), 2);
+    assert!(archive_index_path(&backlog_dir).exists());
+    assert_eq!(entries[0].path, "2026-02/task-002 - Payments retry.md");
+
+    let task = find_archived_task(&backlog_dir, "TASK-002")
+        .expect("lookup")
+        .expect("archived task");
+    assert_eq!(task.title, "Payments retry");
+    assert!(find_archived_task(&backlog_dir, "task-001")
+        .expect("lookup")
+        .is_none());
+
+    let matches = search_archive(&backlog_dir, "payments").expect("search");
+    let ids: Vec<&str> = matches.iter().map(|entry| entry.id.as_str()).collect();
+    assert_eq!(ids, vec!["task-002"]);
+
+    fs::remove_file(archive_dir.join("task-003 - Docs cleanup.md")).expect("remove");
+    write_task(&archive_dir, "task-004", "Payments audit");
+    let matches = search_archive(&backlog_dir, "PAYMENTS").expect("search");
+    let ids: Vec<&str> = matches.iter().map(|entry| entry.id.as_str()).collect();
+    assert_eq!(ids, vec!["task-002", "task-004"]);
+    assert_eq!(
+        refresh_archive_index(&backlog_dir).expect("refresh").len(),
+        2
+    );
+}
+
+#[test]
+fn watcher_applies_only_changed_files_to_the_index() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha");
+
+    let mut watcher = BacklogWatcher::new(&backlog_dir).expect("watch");
+    assert_eq!(watcher.tracked(), 1);
+    assert!(verify_index(&backlog_dir).expect("verify").ok);
+    assert!(watcher.poll().expect("poll").is_empty());
+
+    write_task(&tasks_dir, "task-002", "Beta");
+    let alpha = tasks_dir.join("task-001 - Alpha.md");
+    let content = fs::read_to_string(&alpha).expect("read");
+    fs::write(&alpha, content.replace("To Do", "In Progress")).expect("edit");
+    let changes = watcher.poll().expect("poll");
+    let events: Vec<(&str, &str)> = changes
+        .iter()
+        .map(|change| (change.event, change.id.as_str()))
+        .collect();
+    assert_eq!(
+        events,
+        vec![("modified", "task-001"), ("added", "task-002")]
+    );
+    assert!(verify_index(&backlog_dir).expect("verify").ok);
+
+    // Rewriting identical content is not a change.
+    let content = fs::read_to_string(&alpha).expect("read");
+    fs::write(&alpha, &content).expect("touch");
+    fs::remove_file(tasks_dir.join("task-002 - Beta.md")).expect("remove");
+    let changes = watcher.poll().expect("poll");
+    assert_eq!(changes.len(), 1);
+    assert_eq!(
+        (changes[0].event, changes[0].id.as_str()),
+        ("removed", "task-002")
+    );
+    assert!(verify_index(&backlog_dir).expect("verify").ok);
+}
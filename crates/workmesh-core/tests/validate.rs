@@ -1,5 +1,8 @@
-use workmesh_core::task::Task;
-use workmesh_core::task_ops::validate_tasks;
+use std::fs;
+
+use tempfile::TempDir;
+use workmesh_core::task::{load_tasks, Task};
+use workmesh_core::task_ops::{update_task_field, validate_tasks, FieldValue};
 
 fn complete_task_body() -> String {
     "Description:\n--------------------------------------------------\n- Example task.\n\nAcceptance Criteria:\n--------------------------------------------------\n- Expected behavior is validated.\n\nDefinition of Done:\n--------------------------------------------------\n- The task outcome is verified.\n- Code/config committed.\n".to_string()
@@ -42,3 +45,89 @@ fn validate_does_not_error_on_missing_dependencies() {
         .iter()
         .any(|warn| warn.contains("no dependencies listed")));
 }
+
+#[test]
+fn legacy_task_files_load_and_are_reported_by_validate() {
+    let temp = TempDir::new().expect("tempdir");
+    let backlog_dir = temp.path().join("workmesh");
+    let tasks_dir = backlog_dir.join("tasks");
+    fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    let front = |id: &str, title: &str| {
+        format!(
+            "id: {}\ntitle: {}\nstatus: To Do\npriority: P2\nphase: Phase1\ndependencies: []\n",
+            id, title
+        )
+    };
+
+    let mut utf16 = vec![0xFF, 0xFE];
+    let text = format!("---\r\n{}---\r\n\r\nNotes\r\n", front("task-001", "Café"));
+    utf16.extend(text.encode_utf16().flat_map(|unit| unit.to_le_bytes()));
+    fs::write(tasks_dir.join("task-001 - utf16.md"), &utf16).expect("utf16");
+    let mut latin1 = format!("---\n{}---\n", front("task-002", "Caf?")).into_bytes();
+    let at = latin1
+        .iter()
+        .position(|byte| *byte == b'?')
+        .expect("marker");
+    latin1[at] = 0xE9;
+    fs::write(tasks_dir.join("task-002 - latin1.md"), latin1).expect("latin1");
+    fs::write(
+        tasks_dir.join("task-003 - fences.md"),
+        format!("\n---\n{}...\nBody\n", front("task-003", "Dots")),
+    )
+    .expect("fences");
+    fs::write(
+        tasks_dir.join("task-004 - dup.md"),
+        format!(
+            "---\n{}labels:\n  - old\nstatus: Done\nlabels: [new]\n---\n",
+            front("task-004", "Dup")
+        ),
+    )
+    .expect("dup");
+    fs::write(tasks_dir.join("notes.md"), "Just notes, no front matter\n").expect("notes");
+
+    let tasks = load_tasks(&backlog_dir);
+    let loaded: Vec<(&str, &str, &str)> = tasks
+        .iter()
+        .map(|task| (task.id.as_str(), task.title.as_str(), task.status.as_str()))
+        .collect();
+    assert_eq!(
+        loaded,
+        vec![
+            ("task-001", "Café", "To Do"),
+            ("task-002", "Café", "To Do"),
+            ("task-003", "Dots", "To Do"),
+            ("task-004", "Dup", "Done"),
+        ]
+    );
+    assert_eq!(tasks[3].labels, vec!["new"]);
+
+    let report = validate_tasks(&tasks, Some(&backlog_dir));
+    let expect = [
+        "task-001 - utf16.md: encoded as UTF-16LE",
+        "task-002 - latin1.md: encoded as Windows-1252",
+        "task-003 - fences.md: blank lines before the opening `---`",
+        "task-003 - fences.md: front matter closed with `...`",
+        "task-004 - dup.md: duplicate front matter key `status`",
+        "task-004 - dup.md: duplicate front matter key `labels`",
+        "notes.md: not loaded as a task",
+    ];
+    for warning in expect {
+        assert!(
+            report.warnings.iter().any(|w| w.starts_with(warning)),
+            "missing {:?} in {:?}",
+            warning,
+            report.warnings
+        );
+    }
+
+    // The next change rewrites a legacy file as UTF-8.
+    let path = tasks_dir.join("task-001 - utf16.md");
+    update_task_field(
+        &path,
+        "status",
+        Some(FieldValue::Scalar("In Progress".to_string())),
+    )
+    .expect("update");
+    let text = fs::read_to_string(&path).expect("utf-8 now");
+    assert!(text.starts_with("---\nid: task-001\ntitle: Café\nstatus: In Progress"));
+}
@@ -0,0 +1,209 @@
+//! Short-TTL response cache for read-only MCP tools.
+//!
+//! Chatty agents call `list_tasks`/`board`/`show_task` repeatedly within seconds; each call would
+//! otherwise re-read and re-parse the whole backlog. Entries are keyed by backlog dir + tool +
+//! arguments and dropped when they expire, when any mutating tool runs through this server, or
+//! when the backlog's files change on disk (e.g. an edit from the CLI or an editor).
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+use rust_mcp_sdk::schema::CallToolResult;
+
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_millis(2000);
+
+/// Tools whose response depends only on backlog files and their arguments.
+const CACHEABLE_TOOLS: &[&str] = &[
+    "list_tasks",
+    "show_task",
+    "next_task",
+    "next_tasks",
+    "ready_tasks",
+    "board",
+    "blockers",
+    "toposort",
+    "reminders",
+    "stats",
+    "export_tasks",
+    "graph_export",
+    "issues_export",
+    "validate",
+    "inbox",
+    "mentions",
+    "health",
+    "context_pack",
+    "epic_brief",
+    "archive_search",
+];
+
+pub fn is_cacheable_tool(name: &str) -> bool {
+    CACHEABLE_TOOLS.contains(&name)
+}
+
+/// Cheap change detector: file count, total size, and newest mtime under the backlog dir.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct BacklogFingerprint {
+    files: u64,
+    bytes: u64,
+    latest: Option<SystemTime>,
+}
+
+pub(crate) fn fingerprint(backlog_dir: &Path) -> BacklogFingerprint {
+    let mut print = BacklogFingerprint::default();
+    let mut pending = vec![backlog_dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let Ok(meta) = entry.metadata() else {
+                continue;
+            };
+            if meta.is_dir() {
+                if entry.file_name() != ".git" {
+                    pending.push(entry.path());
+                }
+                continue;
+            }
+            print.files += 1;
+            print.bytes += meta.len();
+            if let Ok(modified) = meta.modified() {
+                print.latest = print.latest.max(Some(modified));
+            }
+        }
+    }
+    print
+}
+
+struct CacheEntry {
+    stored_at: Instant,
+    fingerprint: BacklogFingerprint,
+    result: CallToolResult,
+}
+
+pub struct ResponseCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl ResponseCache {
+    /// A zero TTL disables caching.
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.ttl.is_zero()
+    }
+
+    /// Cache key for a call: object keys serialize sorted, so argument order does not matter.
+    pub fn key(backlog_dir: &Path, tool: &str, args: &serde_json::Value) -> String {
+        format!("{}\u{0}{}\u{0}{}", backlog_dir.display(), tool, args)
+    }
+
+    pub fn get(&self, key: &str, backlog_dir: &Path) -> Option<CallToolResult> {
+        let mut entries = self.entries.lock().ok()?;
+        let entry = entries.get(key)?;
+        if entry.stored_at.elapsed() < self.ttl && entry.fingerprint == fingerprint(backlog_dir) {
+            return Some(entry.result.clone());
+        }
+        entries.remove(key);
+        None
+    }
+
+    /// Store a response; the fingerprint is taken after the call so reads that refresh the
+    /// index do not invalidate their own entry.
+    pub fn put(&self, key: String, backlog_dir: &Path, result: &CallToolResult) {
+        if !self.is_enabled() {
+            return;
+        }
+        let entry = CacheEntry {
+            stored_at: Instant::now(),
+            fingerprint: fingerprint(backlog_dir),
+            result: result.clone(),
+        };
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, entry| entry.stored_at.elapsed() < self.ttl);
+            entries.insert(key, entry);
+        }
+    }
+
+    pub fn invalidate(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl Default for ResponseCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL)
+    }
+}
+
+/// Backlog dir plus key for a cacheable call, or `None` when the call must run uncached.
+pub(crate) fn cache_slot(
+    cache: &ResponseCache,
+    tool: &str,
+    backlog_dir: Option<PathBuf>,
+    args: &serde_json::Value,
+) -> Option<(PathBuf, String)> {
+    if !cache.is_enabled() || !is_cacheable_tool(tool) {
+        return None;
+    }
+    let backlog_dir = backlog_dir?;
+    let key = ResponseCache::key(&backlog_dir, tool, args);
+    Some((backlog_dir, key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_mcp_sdk::schema::TextContent;
+    use tempfile::TempDir;
+
+    fn result(text: &str) -> CallToolResult {
+        CallToolResult::text_content(vec![TextContent::from(text.to_string())])
+    }
+
+    #[test]
+    fn cache_hits_until_files_change_or_invalidated() {
+        let temp = TempDir::new().expect("tempdir");
+        let dir = temp.path();
+        fs::write(dir.join("task-001.md"), "one").expect("write");
+
+        let cache = ResponseCache::new(Duration::from_secs(60));
+        let args = serde_json::json!({"status": "To Do"});
+        let (dir, key) =
+            cache_slot(&cache, "list_tasks", Some(dir.to_path_buf()), &args).expect("cacheable");
+        assert!(cache_slot(&cache, "set_status", Some(dir.clone()), &args).is_none());
+
+        cache.put(key.clone(), &dir, &result("first"));
+        assert_eq!(
+            cache
+                .get(&key, &dir)
+                .map(|hit| serde_json::to_value(hit).expect("json")),
+            Some(serde_json::to_value(result("first")).expect("json"))
+        );
+
+        fs::write(dir.join("task-002.md"), "two").expect("write");
+        assert!(cache.get(&key, &dir).is_none());
+
+        cache.put(key.clone(), &dir, &result("second"));
+        cache.invalidate();
+        assert!(cache.get(&key, &dir).is_none());
+
+        let disabled = ResponseCache::disabled();
+        assert!(cache_slot(&disabled, "list_tasks", Some(dir.clone()), &args).is_none());
+    }
+}
@@ -1,3 +1,10 @@
+mod cache;
+mod resources;
 mod tools;
 
-pub use tools::{build_server_details, tool_info_payload, McpContext, WorkmeshServerHandler};
+pub use cache::{ResponseCache, DEFAULT_CACHE_TTL};
+pub use resources::ResourceSubscriptions;
+
+pub use tools::{
+    build_server_details, tool_info_payload, McpClientIdentity, McpContext, WorkmeshServerHandler,
+};
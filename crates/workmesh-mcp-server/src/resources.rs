@@ -0,0 +1,285 @@
+//! MCP resources over the server's default backlog: `workmesh://board`, `workmesh://context`,
+//! and `workmesh://tasks/<id>`, so agents can pull task content without a tool call.
+//!
+//! Once the client has initialized, a poller watches the backlog the way the response cache
+//! does (file count, size, newest mtime). When it moves, the server sends
+//! `notifications/resources/list_changed` if tasks were added, removed, or retitled, and
+//! `notifications/resources/updated` for each subscribed resource whose content changed.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rust_mcp_sdk::schema::{Resource, ResourceTemplate, ResourceUpdatedNotificationParams};
+use rust_mcp_sdk::McpServer;
+
+use workmesh_core::context::{context_path, load_context};
+use workmesh_core::project::repo_root_from_backlog;
+use workmesh_core::task::{load_tasks, Task};
+use workmesh_core::task_ops::task_to_json_value;
+use workmesh_core::views::status_columns;
+use workmesh_core::watch::DEFAULT_WATCH_INTERVAL_MS;
+use workmesh_core::workflow::resolve_workflow;
+
+use crate::cache::{fingerprint, BacklogFingerprint};
+
+pub const BOARD_URI: &str = "workmesh://board";
+pub const CONTEXT_URI: &str = "workmesh://context";
+pub const TASK_URI_PREFIX: &str = "workmesh://tasks/";
+pub const TASK_URI_TEMPLATE: &str = "workmesh://tasks/{id}";
+
+const JSON: &str = "application/json";
+const POLL_INTERVAL: Duration = Duration::from_millis(DEFAULT_WATCH_INTERVAL_MS);
+
+/// The board, the context, and one entry per task.
+pub fn list_resources(backlog_dir: &Path) -> Vec<Resource> {
+    let mut resources = vec![
+        resource(
+            BOARD_URI,
+            "board",
+            "Tasks grouped into workflow status columns",
+        ),
+        resource(
+            CONTEXT_URI,
+            "context",
+            "Current project, objective, and scope",
+        ),
+    ];
+    resources.extend(
+        task_entries(&load_tasks(backlog_dir))
+            .into_iter()
+            .map(|(id, title)| Resource {
+                title: Some(title),
+                ..resource(
+                    &format!("{}{}", TASK_URI_PREFIX, id),
+                    &id,
+                    "Task with its body",
+                )
+            }),
+    );
+    resources
+}
+
+pub fn resource_templates() -> Vec<ResourceTemplate> {
+    vec![ResourceTemplate {
+        annotations: None,
+        description: Some("A task by id, with its body".to_string()),
+        icons: Vec::new(),
+        meta: None,
+        mime_type: Some(JSON.to_string()),
+        name: "task".to_string(),
+        title: None,
+        uri_template: TASK_URI_TEMPLATE.to_string(),
+    }]
+}
+
+/// JSON text of a resource, or `None` for an unknown URI or task.
+pub fn read_resource(backlog_dir: &Path, uri: &str) -> Option<String> {
+    let value = match uri {
+        BOARD_URI => {
+            let tasks = load_tasks(backlog_dir);
+            let statuses = resolve_workflow(&repo_root_from_backlog(backlog_dir)).statuses;
+            let columns: Vec<serde_json::Value> = status_columns(&tasks, &statuses)
+                .into_iter()
+                .map(|(status, lane_tasks)| {
+                    let summaries: Vec<serde_json::Value> = lane_tasks
+                        .into_iter()
+                        .map(|task| task_to_json_value(task, false))
+                        .collect();
+                    serde_json::json!({ "status": status, "tasks": summaries })
+                })
+                .collect();
+            serde_json::json!({ "columns": columns })
+        }
+        CONTEXT_URI => serde_json::json!({
+            "path": context_path(backlog_dir),
+            "context": load_context(backlog_dir).ok().flatten(),
+        }),
+        _ => {
+            let id = uri.strip_prefix(TASK_URI_PREFIX)?;
+            let tasks = load_tasks(backlog_dir);
+            let task = tasks.iter().find(|task| task.id.eq_ignore_ascii_case(id))?;
+            task_to_json_value(task, true)
+        }
+    };
+    serde_json::to_string_pretty(&value).ok()
+}
+
+/// Subscribed URIs with the content last sent for each, shared with the poller.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceSubscriptions {
+    entries: Arc<Mutex<HashMap<String, Option<String>>>>,
+}
+
+impl ResourceSubscriptions {
+    pub fn subscribe(&self, backlog_dir: &Path, uri: &str) {
+        let content = read_resource(backlog_dir, uri);
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(uri.to_string(), content);
+        }
+    }
+
+    pub fn unsubscribe(&self, uri: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(uri);
+        }
+    }
+
+    /// Re-read every subscribed resource and return the URIs whose content changed.
+    fn refresh(&self, backlog_dir: &Path) -> Vec<String> {
+        let Ok(mut entries) = self.entries.lock() else {
+            return Vec::new();
+        };
+        let mut updated: Vec<String> = entries
+            .iter_mut()
+            .filter_map(|(uri, last)| {
+                let content = read_resource(backlog_dir, uri);
+                (content != *last).then(|| {
+                    *last = content;
+                    uri.clone()
+                })
+            })
+            .collect();
+        updated.sort();
+        updated
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceChanges {
+    pub list_changed: bool,
+    pub updated: Vec<String>,
+}
+
+pub struct ResourceWatcher {
+    backlog_dir: PathBuf,
+    fingerprint: BacklogFingerprint,
+    tasks: Vec<(String, String)>,
+    subscriptions: ResourceSubscriptions,
+}
+
+impl ResourceWatcher {
+    pub fn new(backlog_dir: &Path, subscriptions: ResourceSubscriptions) -> Self {
+        Self {
+            backlog_dir: backlog_dir.to_path_buf(),
+            fingerprint: fingerprint(backlog_dir),
+            tasks: task_entries(&load_tasks(backlog_dir)),
+            subscriptions,
+        }
+    }
+
+    /// What changed since the previous poll; nothing is re-read while the files are untouched.
+    pub fn poll(&mut self) -> ResourceChanges {
+        let current = fingerprint(&self.backlog_dir);
+        if current == self.fingerprint {
+            return ResourceChanges::default();
+        }
+        self.fingerprint = current;
+        let tasks = task_entries(&load_tasks(&self.backlog_dir));
+        let list_changed = tasks != self.tasks;
+        self.tasks = tasks;
+        ResourceChanges {
+            list_changed,
+            updated: self.subscriptions.refresh(&self.backlog_dir),
+        }
+    }
+
+    /// Poll until the client goes away, sending a notification for each change.
+    pub fn spawn(mut self, runtime: Arc<dyn McpServer>) {
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                let changes = self.poll();
+                if changes.list_changed && runtime.notify_resource_list_changed(None).await.is_err()
+                {
+                    return;
+                }
+                for uri in changes.updated {
+                    let params = ResourceUpdatedNotificationParams { meta: None, uri };
+                    if runtime.notify_resource_updated(params).await.is_err() {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+}
+
+fn resource(uri: &str, name: &str, description: &str) -> Resource {
+    Resource {
+        annotations: None,
+        description: Some(description.to_string()),
+        icons: Vec::new(),
+        meta: None,
+        mime_type: Some(JSON.to_string()),
+        name: name.to_string(),
+        size: None,
+        title: None,
+        uri: uri.to_string(),
+    }
+}
+
+fn task_entries(tasks: &[Task]) -> Vec<(String, String)> {
+    let mut entries: Vec<(String, String)> = tasks
+        .iter()
+        .map(|task| (task.id.clone(), task.title.clone()))
+        .collect();
+    entries.sort();
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    fn write_task(tasks_dir: &Path, id: &str, title: &str, status: &str) {
+        fs::write(
+            tasks_dir.join(format!("{} - {}.md", id, title.to_lowercase())),
+            format!(
+                "---\nid: {}\ntitle: {}\nstatus: {}\npriority: P2\nphase: Phase1\ndependencies: []\nlabels: []\n---\n\nDescription:\n- {} body\n",
+                id, title, status, title
+            ),
+        )
+        .expect("write task");
+    }
+
+    #[test]
+    fn watcher_reports_new_tasks_and_subscribed_updates() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+
+        let uris: Vec<String> = list_resources(&backlog_dir)
+            .into_iter()
+            .map(|resource| resource.uri)
+            .collect();
+        assert_eq!(uris, [BOARD_URI, CONTEXT_URI, "workmesh://tasks/task-001"]);
+        let task = read_resource(&backlog_dir, "workmesh://tasks/TASK-001").expect("task");
+        assert!(task.contains("Alpha body"));
+        assert!(read_resource(&backlog_dir, "workmesh://tasks/task-404").is_none());
+        assert!(read_resource(&backlog_dir, "workmesh://other").is_none());
+
+        let subscriptions = ResourceSubscriptions::default();
+        subscriptions.subscribe(&backlog_dir, "workmesh://tasks/task-001");
+        let mut watcher = ResourceWatcher::new(&backlog_dir, subscriptions);
+        assert_eq!(watcher.poll(), ResourceChanges::default());
+
+        write_task(&tasks_dir, "task-002", "Beta", "To Do");
+        let changes = watcher.poll();
+        assert!(changes.list_changed);
+        assert!(changes.updated.is_empty());
+
+        fs::remove_file(tasks_dir.join("task-001 - alpha.md")).expect("remove");
+        write_task(&tasks_dir, "task-001", "Alpha", "In Progress");
+        let changes = watcher.poll();
+        assert!(!changes.list_changed);
+        assert_eq!(changes.updated, ["workmesh://tasks/task-001"]);
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::HashSet;
 use std::io::IsTerminal;
 use std::path::{Path, PathBuf};
-use std::sync::Once;
+use std::sync::{Once, OnceLock};
+use std::time::SystemTime;
 
 use async_trait::async_trait;
 use chrono::{Duration, Local, NaiveDate};
@@ -9,63 +10,119 @@ use rust_mcp_sdk::macros::{mcp_tool, JsonSchema};
 use rust_mcp_sdk::mcp_icon;
 use rust_mcp_sdk::schema::{
     schema_utils::CallToolError, CallToolRequestParams, CallToolResult, Implementation,
-    InitializeResult, ListToolsResult, PaginatedRequestParams, ProtocolVersion, RpcError,
-    ServerCapabilities, ServerCapabilitiesTools, TextContent,
+    InitializeRequestParams, InitializeResult, ListResourceTemplatesResult, ListResourcesResult,
+    ListToolsResult, PaginatedRequestParams, ProtocolVersion, ReadResourceContent,
+    ReadResourceRequestParams, ReadResourceResult, RpcError, ServerCapabilities,
+    ServerCapabilitiesResources, ServerCapabilitiesTools, SubscribeRequestParams, TextContent,
+    TextResourceContents, UnsubscribeRequestParams,
 };
 use rust_mcp_sdk::tool_box;
 use rust_mcp_sdk::{mcp_server::ServerHandler, McpServer};
 use serde::de::Error as _;
 use serde::{Deserialize, Deserializer, Serialize};
 
-use workmesh_core::archive::{archive_tasks, ArchiveOptions};
-use workmesh_core::audit::{append_audit_event, AuditEvent};
+use workmesh_core::api_tokens::{find_token, tokens_dir, ApiToken};
+use workmesh_core::archive::{archive_tasks, parse_archive_before, ArchiveOptions};
+use workmesh_core::attachments::delete_archived_objects;
+use workmesh_core::audit::{append_audit_event, audit_log_len, read_audit_events_from, AuditEvent};
+use workmesh_core::autotag::{autotag_tasks, compile_rules, resolve_autotag_config};
 use workmesh_core::backlog::{locate_backlog_dir, resolve_backlog};
 use workmesh_core::bootstrap::{bootstrap_repo, BootstrapOptions, BootstrapResult};
+use workmesh_core::budget::{budget_report, resolve_budgets_config, BudgetScope};
+use workmesh_core::calibration::{calibration_report, CalibrationGroup};
+use workmesh_core::changes::changes_since;
+use workmesh_core::checkpoint_triggers;
+use workmesh_core::confidential::read_task_text;
 use workmesh_core::config::{
-    resolve_auto_session_default, resolve_task_validation_rules,
-    resolve_task_validation_rules_with_source, resolve_worktrees_default,
+    resolve_auto_session_default, resolve_note_autolink, resolve_task_validation_rules,
+    resolve_task_validation_rules_with_source, resolve_teams, resolve_worktrees_default,
 };
 use workmesh_core::context::{
     clear_context, context_path, extract_task_id_from_branch, infer_project_id, load_context,
     save_context, ContextScope, ContextScopeMode, ContextState,
 };
+use workmesh_core::context_pack::{
+    build_context_pack, parse_token_budget, render_context_pack_markdown, ContextPackOptions,
+};
+use workmesh_core::cycle_time::{cycle_time_report, CycleTimeGroup};
 use workmesh_core::doctor::{doctor_report, doctor_report_with_options};
-use workmesh_core::fix::fix_task_filenames;
+use workmesh_core::epic_brief::{epic_brief, render_epic_brief_text};
+use workmesh_core::extract::{extract_backlog, ExtractOptions};
+use workmesh_core::fix::{fix_dependencies, fix_task_filenames};
 use workmesh_core::focus::load_focus;
-use workmesh_core::gantt::{plantuml_gantt, render_plantuml_svg, write_text_file};
+use workmesh_core::freeze::{freeze_scope, unfreeze_scope, FreezeKind, FrozenSet};
+use workmesh_core::gantt::{mermaid_gantt, plantuml_gantt, render_plantuml_svg, write_text_file};
 use workmesh_core::global_sessions::{
     append_session_saved, load_sessions_latest, new_session_id, now_rfc3339,
     read_current_session_id, resolve_workmesh_home, set_current_session, AgentSession,
     CheckpointRef, GitSnapshot, RecentChanges, WorktreeBinding,
 };
+use workmesh_core::grab::{grab_tasks, GrabOptions, DEFAULT_GRAB_COUNT};
+use workmesh_core::graph_diff::{dependency_edges, graph_diff, render_graph_diff};
+use workmesh_core::health::{health_report, HealthOptions};
 use workmesh_core::id_fix::{fix_duplicate_task_ids, FixIdsOptions};
-use workmesh_core::index::{rebuild_index, refresh_index, verify_index};
+use workmesh_core::inbox::{build_inbox, mark_inbox_all_read, mark_inbox_read, normalize_user};
+use workmesh_core::index::{
+    find_archived_task, rebuild_index, refresh_index, search_archive, verify_index,
+};
 use workmesh_core::initiative::{
-    best_effort_git_branch as core_git_branch, ensure_branch_initiative, next_namespaced_task_id,
+    best_effort_git_branch as core_git_branch, ensure_branch_initiative, reserve_namespaced_task_id,
 };
+use workmesh_core::lint::{lint_tasks, LintOptions};
+use workmesh_core::mentions::{mentions_for, resolve_mentions};
+use workmesh_core::merge::{merge_backlog, MergeBacklogOptions};
 use workmesh_core::migration::migrate_backlog;
 use workmesh_core::migration_audit::{
     apply_migration_plan, audit_deprecations, plan_migrations, MigrationApplyOptions,
     MigrationPlanOptions,
 };
+use workmesh_core::milestone::{milestone_report, DEFAULT_VELOCITY_WINDOW_DAYS};
+use workmesh_core::onboarding::{
+    create_onboarding, onboarding_status, resolve_onboarding_config, OnboardingError,
+};
+use workmesh_core::policies::{resolve_policies, run_policies};
+use workmesh_core::postmerge::conflict_report;
 use workmesh_core::project::{ensure_project_docs, repo_root_from_backlog};
 use workmesh_core::quickstart::{quickstart, QuickstartOptions};
 use workmesh_core::rekey::{
-    parse_rekey_request, rekey_apply, render_rekey_prompt, RekeyApplyOptions, RekeyPromptOptions,
+    move_task, parse_rekey_request, rekey_apply, render_rekey_prompt, MoveTaskOptions,
+    RekeyApplyOptions, RekeyPromptOptions,
+};
+use workmesh_core::related::{related_tasks, DEFAULT_RELATED_LIMIT};
+use workmesh_core::reminders::{
+    context_due_reminders, due_reminders, session_due_reminders, DEFAULT_DUE_SOON_DAYS,
+};
+use workmesh_core::roles::{
+    authorize, resolve_grant, resolve_roles_config, AuthorizationError, Caller, Role, RolesConfig,
 };
 use workmesh_core::session::{
-    append_session_journal, diff_since_checkpoint, render_diff, render_resume, resolve_project_id,
-    resume_summary, task_summary, write_checkpoint, write_working_set, CheckpointOptions,
+    append_session_journal, diff_since_checkpoint, filter_session_journal, load_checkpoint,
+    parse_since, read_session_journal, refresh_working_set, render_diff, render_resume,
+    render_session_journal_markdown, resolve_project_id, resume_summary, task_summary,
+    working_set_status, write_checkpoint, write_working_set, CheckpointOptions,
+};
+use workmesh_core::split::{split_task, SplitError};
+use workmesh_core::storage::enable_lock_retry;
+use workmesh_core::task::{
+    archive_root_for_root, load_tasks, load_tasks_with_archive, tasks_dir_for_root, Lease, Task,
 };
-use workmesh_core::task::{load_tasks, load_tasks_with_archive, tasks_dir_for_root, Lease, Task};
 use workmesh_core::task_ops::{
-    append_note, create_task_file_with_sections, ensure_can_set_status_with_rules, filter_tasks,
-    graph_export, is_lease_active, now_timestamp, ready_tasks_with_rules,
-    recommend_next_tasks_with_context_and_rules, render_task_line, replace_section, set_list_field,
-    sort_tasks, status_counts, task_to_json_value, tasks_to_jsonl, timestamp_plus_minutes,
-    update_body, update_lease_fields, update_task_field, update_task_field_or_section,
-    validate_task_creation_with_rules, validate_tasks_with_rules, FieldValue, TaskSectionContent,
+    append_task_note, create_task_file_with_sections, dot_graph, edit_list_field,
+    ensure_can_set_status_with_rules, filter_tasks, filter_tasks_by_dates, filter_tasks_by_fields,
+    graph_export, group_counts, is_lease_active, link_note_references, mermaid_graph,
+    now_timestamp, ready_tasks_with_rules, recommend_next_tasks_with_context_and_rules,
+    rename_task_file_for_title, render_task_line, replace_section, set_list_field,
+    soft_dependency_warnings, sort_tasks, status_counts, subtask_rollups, task_to_json_value,
+    tasks_to_jsonl, timestamp_plus_minutes, update_body, update_lease_fields, update_task_field,
+    update_task_field_or_section, validate_task_creation_with_rules, validate_tasks_with_rules,
+    DateFilter, FieldFilter, FieldValue, GroupBy, TaskSectionContent, SOFT_DEPENDENCIES_FIELD,
 };
+use workmesh_core::telemetry::{resolve_otlp_exporter, CommandSpan, OtlpExporter};
+use workmesh_core::timestamps::TimeDisplay;
+use workmesh_core::timewindow::{TimeWindow, TIME_REF_FORMATS};
+use workmesh_core::title_lint::{lint_titles, resolve_title_lint_config, TitleLintRules};
+use workmesh_core::transitions::record_status_transition;
+use workmesh_core::trash::{remove_task, RemoveError, RemoveMode};
 use workmesh_core::truth::{
     accept_truth, apply_truth_migration, list_truths, propose_truth, reject_truth, show_truth,
     supersede_truth, truth_migration_audit, truth_migration_plan, validate_truth_store,
@@ -73,8 +130,9 @@ use workmesh_core::truth::{
     TruthSupersedeInput, TruthTransitionInput,
 };
 use workmesh_core::views::{
-    blockers_report_with_context, board_lanes, scope_ids_from_context, BoardBy,
+    blockers_report_with_context, board_lanes, scope_ids_from_context, toposort_report, BoardBy,
 };
+use workmesh_core::workflow::{render_dot, render_mermaid, resolve_workflow, workflow_diagram};
 use workmesh_core::workstreams::{
     build_workstream_restore_plan, derive_unique_workstream_key,
     find_workstream_for_repo_by_worktree_path, list_workstreams_for_repo,
@@ -90,8 +148,13 @@ use workmesh_core::worktrees::{
 use workmesh_render::dispatch_tool as render_dispatch_tool;
 use workmesh_tools::{
     best_practice_hints, build_tool_info_payload, bulk_summary, default_verbose,
-    maybe_verbose_value, recommended_kinds, resolve_mcp_backlog_root, resolve_repo_root_input,
-    ROOT_REQUIRED_ERROR,
+    maybe_verbose_value, recommended_kinds, required_role, resolve_mcp_backlog_root,
+    resolve_repo_root_input, task_mutation_targets, ROOT_REQUIRED_ERROR,
+};
+
+use crate::cache::{cache_slot, ResponseCache};
+use crate::resources::{
+    list_resources, read_resource, resource_templates, ResourceSubscriptions, ResourceWatcher,
 };
 
 #[derive(Clone)]
@@ -99,6 +162,66 @@ pub struct McpContext {
     pub default_root: Option<PathBuf>,
     pub version_full: String,
     pub server_label: String,
+    /// Caller identity matched against `[roles.identities]`.
+    pub identity: Option<String>,
+    /// Bearer token matched (by sha256) against `[roles.tokens]`.
+    pub token: Option<String>,
+    /// MCP client from the `initialize` handshake; set per call by the server handler.
+    pub client: Option<McpClientIdentity>,
+}
+
+/// The agent behind an MCP connection, used to attribute audit events and sessions.
+#[derive(Clone, Debug)]
+pub struct McpClientIdentity {
+    pub name: String,
+    pub version: String,
+    /// Connection id, recorded as `mcp_session` in audit event details.
+    pub session_id: String,
+}
+
+impl McpClientIdentity {
+    /// `name/version`, as stored on saved sessions.
+    pub fn label(&self) -> String {
+        if self.version.is_empty() {
+            self.name.clone()
+        } else {
+            format!("{}/{}", self.name, self.version)
+        }
+    }
+}
+
+impl McpContext {
+    /// Audit actor: `mcp:<client-name>` once the client identified itself, plain `mcp` before.
+    pub fn actor(&self) -> String {
+        match &self.client {
+            Some(client) => format!("mcp:{}", client.name),
+            None => "mcp".to_string(),
+        }
+    }
+
+    fn with_client(&self, params: Option<InitializeRequestParams>) -> McpContext {
+        let mut context = self.clone();
+        context.client = params.and_then(|params| {
+            let name = params
+                .client_info
+                .name
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join("-");
+            (!name.is_empty()).then(|| McpClientIdentity {
+                name,
+                version: params.client_info.version.trim().to_string(),
+                session_id: connection_id().to_string(),
+            })
+        });
+        context
+    }
+}
+
+/// Each stdio server process serves exactly one MCP connection.
+fn connection_id() -> &'static str {
+    static ID: OnceLock<String> = OnceLock::new();
+    ID.get_or_init(new_session_id)
 }
 
 pub fn build_server_details(version_full: &str) -> InitializeResult {
@@ -118,6 +241,10 @@ pub fn build_server_details(version_full: &str) -> InitializeResult {
         },
         capabilities: ServerCapabilities {
             tools: Some(ServerCapabilitiesTools { list_changed: None }),
+            resources: Some(ServerCapabilitiesResources {
+                list_changed: Some(true),
+                subscribe: Some(true),
+            }),
             ..Default::default()
         },
         meta: None,
@@ -360,19 +487,12 @@ fn build_task_sections(
 }
 
 fn parse_before_date(value: &str) -> Result<NaiveDate, CallToolError> {
-    let trimmed = value.trim();
-    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
-        return Ok(date);
-    }
-    if let Some(days) = trimmed.strip_suffix('d') {
-        if let Ok(days) = days.parse::<i64>() {
-            return Ok(Local::now().date_naive() - Duration::days(days));
-        }
-    }
-    Err(CallToolError::from_message(format!(
-        "Invalid date format: {}",
-        value
-    )))
+    parse_archive_before(value, Local::now().naive_local()).ok_or_else(|| {
+        CallToolError::from_message(format!(
+            "Invalid date format: {} (expected {})",
+            value, TIME_REF_FORMATS
+        ))
+    })
 }
 
 fn parse_truth_state_list(values: Option<ListInput>) -> Result<Vec<TruthState>, CallToolError> {
@@ -437,6 +557,166 @@ fn resolve_root(context: &McpContext, root: Option<&str>) -> Result<PathBuf, ser
     resolve_mcp_backlog_root(context.default_root.as_deref(), root)
 }
 
+/// Enforce `[roles]` for a tool call before dispatch (no-op when roles are not configured).
+fn authorize_tool_call(
+    context: &McpContext,
+    params: &CallToolRequestParams,
+) -> Result<(), AuthorizationError> {
+    let args = params
+        .arguments
+        .clone()
+        .map(serde_json::Value::Object)
+        .unwrap_or(serde_json::Value::Null);
+    authorize_tool(context, &params.name, &args)
+}
+
+fn authorize_tool(
+    context: &McpContext,
+    name: &str,
+    args: &serde_json::Value,
+) -> Result<(), AuthorizationError> {
+    let repo_root = resolve_repo_root(context, args.get("root").and_then(|v| v.as_str()));
+    let config = resolve_roles_config(&repo_root);
+    let grant = match caller_api_token(context) {
+        Some(token) => token.grant(context.identity.clone()),
+        None => resolve_grant(
+            config.as_ref(),
+            &Caller {
+                identity: context.identity.clone(),
+                token: context.token.clone(),
+            },
+        ),
+    };
+    let protected = config
+        .as_ref()
+        .map(RolesConfig::protected_fields)
+        .unwrap_or_default();
+    let (required, reason) = required_role(name, args, &protected);
+    authorize(&grant, name, required, reason)
+}
+
+/// The caller's token from `~/.workmesh/tokens/`, when it is stored there and unexpired.
+fn caller_api_token(context: &McpContext) -> Option<ApiToken> {
+    let token = context.token.as_deref()?;
+    find_token(&tokens_dir()?, token, Local::now())
+}
+
+/// Reject a mutation by a scoped token that names no task or a task outside its scopes.
+fn scoped_tool_call(
+    context: &McpContext,
+    params: &CallToolRequestParams,
+) -> Option<serde_json::Value> {
+    let token = caller_api_token(context)?;
+    if token.scopes.is_empty() {
+        return None;
+    }
+    let args = params
+        .arguments
+        .clone()
+        .map(serde_json::Value::Object)
+        .unwrap_or(serde_json::Value::Null);
+    if required_role(&params.name, &args, &[]).0 == Role::Viewer {
+        return None;
+    }
+    let ids = task_mutation_targets(&params.name, &args);
+    let tasks = resolve_root(context, args.get("root").and_then(|v| v.as_str()))
+        .map(|backlog_dir| load_tasks(&backlog_dir))
+        .unwrap_or_default();
+    token
+        .check_scope(&tasks, &ids)
+        .err()
+        .map(|err| err.to_json())
+}
+
+/// Reject a task mutation inside a frozen epic or phase. `force_frozen=true` skips the check;
+/// `required_role` already limited that override to maintainers.
+fn frozen_tool_call(
+    context: &McpContext,
+    params: &CallToolRequestParams,
+) -> Option<serde_json::Value> {
+    let args = params.arguments.as_ref()?;
+    if args.get("force_frozen").and_then(|v| v.as_bool()) == Some(true) {
+        return None;
+    }
+    let args = serde_json::Value::Object(args.clone());
+    let ids = task_mutation_targets(&params.name, &args);
+    if ids.is_empty() {
+        return None;
+    }
+    let backlog_dir = resolve_root(context, args.get("root").and_then(|v| v.as_str())).ok()?;
+    let tasks = load_tasks(&backlog_dir);
+    FrozenSet::load(&backlog_dir, &tasks)
+        .check(&ids)
+        .err()
+        .map(|err| {
+            serde_json::json!({
+                "error": err.to_string(),
+                "hint": "A maintainer may pass force_frozen=true to override",
+            })
+        })
+}
+
+/// OTLP export for one tool call: a span plus the audit events the call appended.
+struct ToolTelemetry {
+    exporter: OtlpExporter,
+    tool: String,
+    backlog_dir: Option<PathBuf>,
+    audit_offset: u64,
+    started: SystemTime,
+}
+
+impl ToolTelemetry {
+    fn start(context: &McpContext, params: &CallToolRequestParams) -> Option<Self> {
+        let root = params
+            .arguments
+            .as_ref()
+            .and_then(|args| args.get("root"))
+            .and_then(|value| value.as_str());
+        let exporter = resolve_otlp_exporter(&resolve_repo_root(context, root))?;
+        let backlog_dir = resolve_root(context, root).ok();
+        let audit_offset = backlog_dir.as_deref().map(audit_log_len).unwrap_or(0);
+        Some(Self {
+            exporter,
+            tool: params.name.clone(),
+            backlog_dir,
+            audit_offset,
+            started: SystemTime::now(),
+        })
+    }
+
+    /// Export on a background thread so the collector never delays the tool response.
+    fn finish(self, ok: bool) {
+        let end = SystemTime::now();
+        std::thread::spawn(move || {
+            let events = self
+                .backlog_dir
+                .as_deref()
+                .map(|dir| read_audit_events_from(dir, self.audit_offset))
+                .unwrap_or_default();
+            let span = CommandSpan {
+                name: format!("mcp {}", self.tool),
+                start: self.started,
+                end,
+                ok,
+                attributes: vec![
+                    ("workmesh.tool".to_string(), self.tool.clone()),
+                    (
+                        "workmesh.audit_events".to_string(),
+                        events.len().to_string(),
+                    ),
+                ],
+            };
+            let exported = self
+                .exporter
+                .export_spans(&[span])
+                .and_then(|_| self.exporter.export_audit_events(&events));
+            if let Err(err) = exported {
+                eprintln!("warning: {}", err);
+            }
+        });
+    }
+}
+
 fn resolve_repo_root(context: &McpContext, root: Option<&str>) -> PathBuf {
     resolve_repo_root_input(context.default_root.as_deref(), root)
 }
@@ -511,14 +791,21 @@ fn call_render_tool<C: Serialize>(
 }
 
 fn audit_event(
+    context: &McpContext,
     backlog_dir: &Path,
     action: &str,
     task_id: Option<&str>,
-    details: serde_json::Value,
+    mut details: serde_json::Value,
 ) -> Result<(), CallToolError> {
+    if let (Some(client), Some(map)) = (&context.client, details.as_object_mut()) {
+        map.insert(
+            "mcp_session".to_string(),
+            serde_json::Value::String(client.session_id.clone()),
+        );
+    }
     let event = AuditEvent {
         timestamp: now_timestamp(),
-        actor: Some("mcp".to_string()),
+        actor: Some(context.actor()),
         action: action.to_string(),
         task_id: task_id.map(|value| value.to_string()),
         details,
@@ -526,6 +813,35 @@ fn audit_event(
     append_audit_event(backlog_dir, &event).map_err(CallToolError::new)
 }
 
+/// Note audit details with validated `@mentions`, plus the mentions that matched nobody.
+fn note_audit_details(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    section: &str,
+    note: &str,
+) -> (serde_json::Value, Vec<String>) {
+    let mut details = serde_json::json!({ "section": section, "note": note });
+    let teams = resolve_teams(&repo_root_from_backlog(backlog_dir));
+    let mentions = resolve_mentions(note, tasks, &teams);
+    if !mentions.users.is_empty() {
+        details["mentions"] = serde_json::json!(mentions.users);
+    }
+    (details, mentions.unknown)
+}
+
+/// Relate `task` to the task ids `note` mentions, unless `note_autolink = false`.
+fn autolink_note(
+    backlog_dir: &Path,
+    tasks: &[Task],
+    task: &Task,
+    note: &str,
+) -> Result<Vec<String>, CallToolError> {
+    if !resolve_note_autolink(&repo_root_from_backlog(backlog_dir)) {
+        return Ok(Vec::new());
+    }
+    link_note_references(task, tasks, note).map_err(CallToolError::new)
+}
+
 fn refresh_index_best_effort(backlog_dir: &Path) {
     let _ = refresh_index(backlog_dir);
 }
@@ -574,8 +890,11 @@ fn tool_catalog() -> Vec<serde_json::Value> {
         serde_json::json!({"name": "next_task", "summary": "Get the next context-relevant task (active/leased first, else next ready To Do)."}),
         serde_json::json!({"name": "next_tasks", "summary": "Get a deterministic list of next-task candidates (includes active work; context-aware)."}),
         serde_json::json!({"name": "ready_tasks", "summary": "List tasks with deps satisfied (ready work)."}),
+        serde_json::json!({"name": "grab_tasks", "summary": "Weighted-random pick of small, old ready tasks for filler work (claim=true leases them)."}),
         serde_json::json!({"name": "board", "summary": "Board (swimlanes) grouped by status/phase/priority (optionally context-scoped)."}),
         serde_json::json!({"name": "blockers", "summary": "Blocked work and top blockers (scoped to context epic by default)."}),
+        serde_json::json!({"name": "toposort", "summary": "Dependency-ordered execution levels of open tasks (tasks in a level can run in parallel)."}),
+        serde_json::json!({"name": "changes_since", "summary": "Audit events and task diffs since a cursor from a previous call (omit cursor for a baseline)."}),
         serde_json::json!({"name": "export_tasks", "summary": "Export all tasks as JSON."}),
         serde_json::json!({"name": "set_status", "summary": "Update task status."}),
         serde_json::json!({"name": "set_field", "summary": "Update a front matter field."}),
@@ -591,12 +910,17 @@ fn tool_catalog() -> Vec<serde_json::Value> {
         serde_json::json!({"name": "bulk_remove_dependency", "summary": "Bulk remove a dependency from tasks."}),
         serde_json::json!({"name": "bulk_add_note", "summary": "Bulk append a note to tasks."}),
         serde_json::json!({"name": "archive_tasks", "summary": "Archive terminal tasks into date-based folders (defaults: Done, Cancelled, Canceled, Won't Do, Wont Do)."}),
+        serde_json::json!({"name": "remove_task", "summary": "Move a task to trash/ with a tombstone (or mark it Cancelled) and strip it from dependency lists."}),
+        serde_json::json!({"name": "move_task", "summary": "Rename a task id and file, rewriting dependencies and relationships that reference it."}),
+        serde_json::json!({"name": "split_task", "summary": "Split a task into subtasks that inherit its fields; the original becomes their epic."}),
         serde_json::json!({"name": "migrate_backlog", "summary": "Migrate legacy backlog to workmesh/."}),
         serde_json::json!({"name": "migrate_audit", "summary": "Detect deprecated structures and produce migration findings."}),
         serde_json::json!({"name": "migrate_plan", "summary": "Build migration plan from findings."}),
         serde_json::json!({"name": "migrate_apply", "summary": "Apply migration plan (dry-run by default)."}),
         serde_json::json!({"name": "claim_task", "summary": "Claim a task lease."}),
         serde_json::json!({"name": "release_task", "summary": "Release a task lease."}),
+        serde_json::json!({"name": "freeze", "summary": "Freeze an epic subtree or phase: mutations need force_frozen=true from a maintainer; frozen tasks leave next/ready."}),
+        serde_json::json!({"name": "unfreeze", "summary": "Lift an epic or phase freeze."}),
         serde_json::json!({"name": "add_note", "summary": "Append a note to Notes or Implementation Notes."}),
         serde_json::json!({"name": "set_body", "summary": "Replace full task body (after front matter)."}),
         serde_json::json!({"name": "set_section", "summary": "Replace a named section in the task body."}),
@@ -607,24 +931,48 @@ fn tool_catalog() -> Vec<serde_json::Value> {
         serde_json::json!({"name": "validate", "summary": "Validate task metadata and dependencies."}),
         serde_json::json!({"name": "fix_ids", "summary": "Repair duplicate task ids after merges."}),
         serde_json::json!({"name": "fix_filenames", "summary": "Normalize non-canonical task filenames from task metadata."}),
-        serde_json::json!({"name": "graph_export", "summary": "Export task graph as JSON."}),
+        serde_json::json!({"name": "lint_tasks", "summary": "Flag secrets, pasted logs, and prompt-injection text in task bodies (redact=true rewrites them)."}),
+        serde_json::json!({"name": "lint_titles", "summary": "Check task titles for length, sentence case, imperative mood, and duplicates (fix=true applies suggestions)."}),
+        serde_json::json!({"name": "autotag", "summary": "Propose labels for untagged tasks from [autotag] keyword rules (apply=true writes them)."}),
+        serde_json::json!({"name": "graph_export", "summary": "Export task graph as JSON, Mermaid, or DOT."}),
         serde_json::json!({"name": "issues_export", "summary": "Export tasks as JSONL."}),
         serde_json::json!({"name": "index_rebuild", "summary": "Rebuild JSONL task index."}),
         serde_json::json!({"name": "index_refresh", "summary": "Refresh JSONL task index."}),
         serde_json::json!({"name": "index_verify", "summary": "Verify JSONL task index."}),
         serde_json::json!({"name": "checkpoint", "summary": "Write a session checkpoint (JSON + Markdown)."}),
         serde_json::json!({"name": "resume", "summary": "Resume from the latest checkpoint."}),
+        serde_json::json!({"name": "reminders", "summary": "Overdue and due-soon open tasks in the context scope."}),
         serde_json::json!({"name": "working_set", "summary": "Write the working set file."}),
         serde_json::json!({"name": "session_journal", "summary": "Append a session journal entry."}),
         serde_json::json!({"name": "checkpoint_diff", "summary": "Show changes since a checkpoint."}),
-        serde_json::json!({"name": "gantt_text", "summary": "Return PlantUML gantt text."}),
-        serde_json::json!({"name": "gantt_file", "summary": "Write PlantUML gantt to a file."}),
+        serde_json::json!({"name": "graph_diff", "summary": "Show dependency edges added/removed between checkpoints."}),
+        serde_json::json!({"name": "gantt_text", "summary": "Return PlantUML or Mermaid gantt text."}),
+        serde_json::json!({"name": "gantt_file", "summary": "Write PlantUML or Mermaid gantt to a file."}),
         serde_json::json!({"name": "gantt_svg", "summary": "Render gantt SVG via PlantUML."}),
         serde_json::json!({"name": "best_practices", "summary": "Return best practices guidance."}),
         serde_json::json!({"name": "help", "summary": "Show available tools and best practices."}),
         serde_json::json!({"name": "tool_info", "summary": "Show detailed usage for a specific tool."}),
         serde_json::json!({"name": "skill_content", "summary": "Return SKILL.md content for a repo skill."}),
         serde_json::json!({"name": "project_management_skill", "summary": "Return project management skill content (default: workmesh)."}),
+        serde_json::json!({"name": "merge_backlog", "summary": "Import another backlog (tasks, archive, audit) and rekey colliding ids."}),
+        serde_json::json!({"name": "extract", "summary": "Move an epic subtree or labelled tasks into a fresh backlog, leaving tombstones."}),
+        serde_json::json!({"name": "inbox", "summary": "Per-user inbox (assignments, review requests, answers, cleared blockers) from the audit log."}),
+        serde_json::json!({"name": "mentions", "summary": "List recent notes where a user was @mentioned."}),
+        serde_json::json!({"name": "policies_run", "summary": "Evaluate configured task policies (dry-run by default)."}),
+        serde_json::json!({"name": "health", "summary": "Composite health score per epic and initiative with underlying factors."}),
+        serde_json::json!({"name": "postmerge", "summary": "Repair the backlog after a git merge and report fields that diverged across parents."}),
+        serde_json::json!({"name": "workflow_show", "summary": "Render the status workflow as a Mermaid or DOT state diagram with counts and dwell times."}),
+        serde_json::json!({"name": "calibration", "summary": "Estimated vs actual effort with bias factors per assignee or label."}),
+        serde_json::json!({"name": "cycle_time", "summary": "Lead and cycle time p50/p90 of done tasks from the audit log, by label, phase, or priority."}),
+        serde_json::json!({"name": "milestones", "summary": "Per-milestone completion, velocity, and projected finish date from the `milestone` front matter field."}),
+        serde_json::json!({"name": "budget_status", "summary": "Point/hour budgets per initiative and phase from [budgets] config: consumed vs planned, burn rate, and projected overrun date."}),
+        serde_json::json!({"name": "onboarding_new", "summary": "Create an onboarding epic with checklist tasks ([onboarding] config) assigned to a newcomer."}),
+        serde_json::json!({"name": "onboarding_status", "summary": "Onboarding checklist progress per newcomer: done/total and the next ready items."}),
+        serde_json::json!({"name": "journal_list", "summary": "List session journal entries, filtered by task and age."}),
+        serde_json::json!({"name": "working_set_status", "summary": "Flag working-set entries that are Done, missing, idle, or no longer leased."}),
+        serde_json::json!({"name": "archive_search", "summary": "Search archived tasks through the archive index."}),
+        serde_json::json!({"name": "context_pack", "summary": "Token-budgeted pack of context, ready tasks, blockers, and decisions for prompts."}),
+        serde_json::json!({"name": "epic_brief", "summary": "Compact epic summary: status counts, top blockers, next ready tasks, last activity."}),
         serde_json::json!({"name": "render_table", "summary": "Render a table from array/object data."}),
         serde_json::json!({"name": "render_kv", "summary": "Render a key/value list."}),
         serde_json::json!({"name": "render_stats", "summary": "Render a compact stats block."}),
@@ -1190,9 +1538,22 @@ pub struct ListTasksTool {
     pub deps_satisfied: Option<bool>,
     pub blocked: Option<bool>,
     pub search: Option<String>,
+    /// Field comparisons such as `estimate>=5`, `score<2.5`, `severity=S1` (all must match).
+    pub fields: Option<ListInput>,
+    /// Date bounds: `YYYY-MM-DD` or relative (`7d`, `12h`, `2w`). `*_after` is inclusive.
+    pub created_after: Option<String>,
+    pub created_before: Option<String>,
+    pub updated_after: Option<String>,
+    pub updated_before: Option<String>,
+    /// Comma-separated sort keys, `-` prefix for descending (e.g. `priority,-updated_date`).
     #[serde(default = "default_sort")]
     pub sort: String,
     pub limit: Option<u32>,
+    /// Return `{"count": N}` instead of task rows.
+    #[serde(default)]
+    pub count: bool,
+    /// Return `{group: count}` instead of task rows: `status`, `label`, or `assignee`.
+    pub group_by: Option<String>,
     #[serde(default = "default_format")]
     pub format: String,
     #[serde(default)]
@@ -1208,6 +1569,12 @@ pub struct ShowTaskTool {
     pub format: String,
     #[serde(default = "default_include_body")]
     pub include_body: bool,
+    /// Fall back to the archive index when the task is not in the active backlog.
+    #[serde(default)]
+    pub include_archive: bool,
+    /// Add tasks sharing labels, files, or wording under `related`.
+    #[serde(default)]
+    pub include_related: bool,
 }
 
 #[mcp_tool(
@@ -1245,6 +1612,23 @@ pub struct ReadyTasksTool {
     pub limit: Option<u32>,
 }
 
+#[mcp_tool(
+    name = "grab_tasks",
+    description = "Pick a weighted-random sample of ready, unleased tasks for filler work; older tasks and smaller `estimate`s are more likely. labels must all match. claim=true leases the picks to owner."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct GrabTasksTool {
+    pub root: Option<String>,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub count: Option<u32>,
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub claim: bool,
+    pub owner: Option<String>,
+    pub minutes: Option<i64>,
+}
+
 #[mcp_tool(
     name = "board",
     description = "Board (swimlanes) grouped by status/phase/priority. Use --focus to scope to current context."
@@ -1255,7 +1639,7 @@ pub struct BoardTool {
     /// Include archived tasks under `workmesh/archive/` (recursively).
     #[serde(default)]
     pub all: bool,
-    /// Group lanes by: status|phase|priority
+    /// Group lanes by: status|phase|priority|epic
     #[serde(default = "default_board_by")]
     pub by: String,
     /// Scope to context epic subtree or explicit context task scope.
@@ -1281,6 +1665,17 @@ pub struct BlockersTool {
     pub format: String,
 }
 
+#[mcp_tool(
+    name = "toposort",
+    description = "Dependency-ordered execution levels of open tasks; tasks within a level can run in parallel."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ToposortTool {
+    pub root: Option<String>,
+    /// Limit to an epic and its descendants.
+    pub epic_id: Option<String>,
+}
+
 #[mcp_tool(name = "export_tasks", description = "Export all tasks as JSON.")]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct ExportTasksTool {
@@ -1302,6 +1697,8 @@ pub struct StatsTool {
 pub struct SetStatusTool {
     pub task_id: String,
     pub status: String,
+    /// Why the status changed (e.g. what blocks it); kept in the task's `transitions:` list.
+    pub reason: Option<String>,
     pub root: Option<String>,
     #[serde(default = "default_touch")]
     pub touch: bool,
@@ -1351,6 +1748,9 @@ pub struct RemoveLabelTool {
 pub struct AddDependencyTool {
     pub task_id: String,
     pub dependency: String,
+    /// Edit `soft_dependencies` instead: ordering hints that warn rather than block.
+    #[serde(default)]
+    pub soft: bool,
     pub root: Option<String>,
     #[serde(default = "default_touch")]
     pub touch: bool,
@@ -1366,6 +1766,9 @@ pub struct AddDependencyTool {
 pub struct RemoveDependencyTool {
     pub task_id: String,
     pub dependency: String,
+    /// Edit `soft_dependencies` instead: ordering hints that warn rather than block.
+    #[serde(default)]
+    pub soft: bool,
     pub root: Option<String>,
     #[serde(default = "default_touch")]
     pub touch: bool,
@@ -1486,6 +1889,52 @@ pub struct ArchiveTool {
     pub verbose: bool,
 }
 
+#[mcp_tool(
+    name = "remove_task",
+    description = "Remove a task: move its file to trash/ and record a tombstone, or with cancel=true set status Cancelled. Either way the id is stripped from other tasks' dependencies and soft_dependencies. dry_run=true reports without writing."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RemoveTaskTool {
+    pub task_id: String,
+    pub root: Option<String>,
+    #[serde(default)]
+    pub cancel: bool,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default = "default_verbose")]
+    pub verbose: bool,
+}
+
+#[mcp_tool(
+    name = "move_task",
+    description = "Rename a task id (and its file) and rewrite dependencies and relationships across the backlog, archive included. body=true also rewrites free-text mentions in task bodies. dry_run=true lists the touched files without writing."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct MoveTaskTool {
+    pub old_id: String,
+    pub new_id: String,
+    pub root: Option<String>,
+    #[serde(default)]
+    pub body: bool,
+    #[serde(default)]
+    pub dry_run: bool,
+    #[serde(default = "default_verbose")]
+    pub verbose: bool,
+}
+
+#[mcp_tool(
+    name = "split_task",
+    description = "Split a task into subtasks (one per title in `into`). Each subtask inherits priority, phase, labels, assignees, and dependencies and is parented to the original, which becomes an epic listing them as children."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct SplitTaskTool {
+    pub task_id: String,
+    pub into: Vec<String>,
+    pub root: Option<String>,
+    #[serde(default = "default_verbose")]
+    pub verbose: bool,
+}
+
 #[mcp_tool(
     name = "migrate_backlog",
     description = "Migrate legacy backlog to workmesh/ (compat tool)"
@@ -1561,6 +2010,28 @@ pub struct ReleaseTaskTool {
     pub verbose: bool,
 }
 
+#[mcp_tool(
+    name = "freeze",
+    description = "Freeze an epic subtree or a phase: its tasks reject mutations (unless force_frozen=true from a maintainer) and leave next/ready."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct FreezeTool {
+    pub root: Option<String>,
+    /// Epic task id whose subtree to freeze.
+    pub epic: Option<String>,
+    /// Phase name to freeze (when `epic` is not set).
+    pub phase: Option<String>,
+    pub reason: Option<String>,
+}
+
+#[mcp_tool(name = "unfreeze", description = "Lift a freeze set by freeze.")]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct UnfreezeTool {
+    pub root: Option<String>,
+    pub epic: Option<String>,
+    pub phase: Option<String>,
+}
+
 #[mcp_tool(
     name = "add_note",
     description = "Append a note to Notes or Implementation Notes."
@@ -1732,6 +2203,61 @@ pub struct FixFilenamesTool {
     pub apply: bool,
 }
 
+#[mcp_tool(
+    name = "changes_since",
+    description = "Audit events, task-level diffs, and new/removed tasks since an opaque cursor from a previous call. Omit cursor to get a baseline; pass next_cursor on the following poll."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ChangesSinceTool {
+    pub root: Option<String>,
+    pub cursor: Option<String>,
+    /// Only report these task ids
+    pub tasks: Option<ListInput>,
+}
+
+#[mcp_tool(
+    name = "lint_tasks",
+    description = "Flag probable secrets, long pasted logs, and prompt-injection text in task bodies (report-only unless redact=true)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct LintTasksTool {
+    pub root: Option<String>,
+    /// Task ids to scan (default: every task)
+    pub tasks: Option<ListInput>,
+    #[serde(default)]
+    pub redact: bool,
+    pub max_block_lines: Option<u64>,
+    pub max_line_chars: Option<u64>,
+}
+
+#[mcp_tool(
+    name = "lint_titles",
+    description = "Check task titles for length limits, sentence case, imperative mood, and duplicates using [title_lint] config (report-only unless fix=true)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct LintTitlesTool {
+    pub root: Option<String>,
+    /// Task ids to check (default: every task)
+    pub tasks: Option<ListInput>,
+    /// Rewrite titles to their suggestions.
+    #[serde(default)]
+    pub fix: bool,
+}
+
+#[mcp_tool(
+    name = "autotag",
+    description = "Propose labels for untagged tasks from [autotag] keyword/regex rules matched against title and body (report-only unless apply=true)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct AutotagTool {
+    pub root: Option<String>,
+    /// Task ids to check (default: every task)
+    pub tasks: Option<ListInput>,
+    /// Write the proposed labels.
+    #[serde(default)]
+    pub apply: bool,
+}
+
 #[mcp_tool(
     name = "rekey_prompt",
     description = "Generate an agent prompt to propose a task-id rekey mapping (and reference rewrites)."
@@ -1763,12 +2289,17 @@ pub struct RekeyApplyTool {
     pub mapping_json: String,
 }
 
-#[mcp_tool(name = "graph_export", description = "Export task graph as JSON.")]
+#[mcp_tool(
+    name = "graph_export",
+    description = "Export task graph as JSON, a Mermaid flowchart for Markdown, or Graphviz DOT (format: json|mermaid|dot)."
+)]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct GraphExportTool {
     pub root: Option<String>,
     #[serde(default)]
     pub pretty: bool,
+    /// json (default), mermaid, or dot.
+    pub format: Option<String>,
 }
 
 #[mcp_tool(name = "issues_export", description = "Export tasks as JSONL.")]
@@ -1807,6 +2338,12 @@ pub struct CheckpointTool {
     pub project: Option<String>,
     pub id: Option<String>,
     pub audit_limit: Option<u32>,
+    /// Extra sections: epics, questions, working_set, decisions (or any default section).
+    pub include: Option<ListInput>,
+    /// Sections to leave out, e.g. git_detail or audit.
+    pub exclude: Option<ListInput>,
+    /// Markdown template path with {{section}} placeholders.
+    pub template: Option<String>,
     #[serde(default = "default_format")]
     pub format: String,
 }
@@ -1821,6 +2358,20 @@ pub struct ResumeTool {
     pub format: String,
 }
 
+#[mcp_tool(
+    name = "reminders",
+    description = "Overdue and due-soon open tasks (by due/due_date) in the context scope."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct RemindersTool {
+    pub root: Option<String>,
+    /// Include tasks due within this many days (default 3).
+    pub within_days: Option<i64>,
+    /// Ignore the context scope and check the whole backlog.
+    #[serde(default)]
+    pub all: bool,
+}
+
 #[mcp_tool(name = "working_set", description = "Write the working set file.")]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct WorkingSetTool {
@@ -1860,6 +2411,22 @@ pub struct CheckpointDiffTool {
     pub format: String,
 }
 
+#[mcp_tool(
+    name = "graph_diff",
+    description = "Show dependency edges added/removed between two checkpoints (or a checkpoint and the current backlog)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct GraphDiffTool {
+    pub root: Option<String>,
+    pub project: Option<String>,
+    /// Checkpoint id or path to compare from.
+    pub from: String,
+    /// Checkpoint id or path to compare to; defaults to the current backlog.
+    pub to: Option<String>,
+    #[serde(default = "default_format")]
+    pub format: String,
+}
+
 #[mcp_tool(
     name = "session_save",
     description = "Save a global agent session (cross-repo continuity)."
@@ -1920,7 +2487,7 @@ pub struct BestPracticesTool {
 
 #[mcp_tool(
     name = "gantt_text",
-    description = "Return PlantUML gantt text for current tasks."
+    description = "Return gantt text for current tasks (format: plantuml|mermaid)."
 )]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct GanttTextTool {
@@ -1928,11 +2495,13 @@ pub struct GanttTextTool {
     pub start: Option<String>,
     #[serde(default = "default_zoom")]
     pub zoom: i32,
+    /// plantuml (default) or mermaid.
+    pub format: Option<String>,
 }
 
 #[mcp_tool(
     name = "gantt_file",
-    description = "Write PlantUML gantt text to a file and return the path."
+    description = "Write gantt text (format: plantuml|mermaid) to a file and return the path."
 )]
 #[derive(Debug, Deserialize, Serialize, JsonSchema)]
 pub struct GanttFileTool {
@@ -1941,6 +2510,8 @@ pub struct GanttFileTool {
     pub start: Option<String>,
     #[serde(default = "default_zoom")]
     pub zoom: i32,
+    /// plantuml (default) or mermaid.
+    pub format: Option<String>,
 }
 
 #[mcp_tool(
@@ -2131,24 +2702,282 @@ pub struct RenderTimelineTool {
     pub configuration: Option<TimelineConfigurationInput>,
 }
 
-fn default_sort() -> String {
-    "id".to_string()
-}
-
-fn default_board_by() -> String {
-    "status".to_string()
+#[mcp_tool(
+    name = "merge_backlog",
+    description = "Import another backlog's tasks, archive, and audit log; resolve id collisions via rekey (dry-run by default)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct MergeBacklogTool {
+    pub root: Option<String>,
+    /// Path to the other repo root or backlog directory.
+    pub from: String,
+    /// Namespace inserted into every imported id (e.g. `other-` -> `task-other-001`).
+    pub prefix: Option<String>,
+    #[serde(default)]
+    pub apply: bool,
 }
 
-fn default_format() -> String {
-    "json".to_string()
+#[mcp_tool(
+    name = "extract",
+    description = "Move an epic subtree (or labelled tasks) with archive entries, audit events, and attachments into a fresh backlog at dest, leaving tombstones (dry-run by default)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ExtractTool {
+    pub root: Option<String>,
+    /// Epic task id; the epic plus all descendants are moved.
+    pub epic: Option<String>,
+    /// Move every task carrying this label.
+    pub label: Option<String>,
+    /// Destination repo root.
+    pub dest: String,
+    #[serde(default)]
+    pub apply: bool,
 }
 
-fn default_text_format() -> String {
-    "text".to_string()
+#[mcp_tool(
+    name = "inbox",
+    description = "Per-user inbox computed from the audit log: assignments, review requests, answered questions, and cleared blockers. Defaults to the server identity; optionally marks items read."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct InboxTool {
+    pub root: Option<String>,
+    /// User to read the inbox for (defaults to the server identity).
+    pub user: Option<String>,
+    #[serde(default)]
+    pub unread: bool,
+    /// Item ids to mark read before listing.
+    #[serde(default)]
+    pub mark_read: Vec<String>,
+    #[serde(default)]
+    pub mark_all_read: bool,
+    pub limit: Option<u32>,
 }
 
-fn default_include_body() -> bool {
-    true
+#[mcp_tool(
+    name = "mentions",
+    description = "List notes where a user was @mentioned (newest first). Defaults to the server identity and the last 30 days (days=0 for all)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct MentionsTool {
+    pub root: Option<String>,
+    /// User to search for (defaults to the server identity).
+    pub user: Option<String>,
+    pub days: Option<u32>,
+    pub limit: Option<u32>,
+}
+
+#[mcp_tool(
+    name = "policies_run",
+    description = "Evaluate [policies] rules (e.g. P3 tasks untouched for 90 days get label stale and status Deferred). Dry-run by default; apply=true writes and audits each action."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct PoliciesRunTool {
+    pub root: Option<String>,
+    #[serde(default)]
+    pub apply: bool,
+}
+
+#[mcp_tool(
+    name = "health",
+    description = "Composite health score (0-100, green/yellow/red) per epic and initiative, with factors: blocked ratio, staleness, overdue count, audit churn, and unestimated ratio."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct HealthTool {
+    pub root: Option<String>,
+    /// Open tasks not updated for this many days count as stale (default 30).
+    pub stale_days: Option<i64>,
+    /// Audit events from the last N days count as churn (default 14).
+    pub churn_days: Option<i64>,
+}
+
+#[mcp_tool(
+    name = "postmerge",
+    description = "After a git merge: fix duplicate ids, drop dangling dependencies, rebuild the index, and report task fields that diverged across the merge parents (with git blame and suggested resolutions). Dry-run unless apply=true; the conflict report never edits files."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct PostmergeTool {
+    pub root: Option<String>,
+    #[serde(default)]
+    pub apply: bool,
+    /// Merge commit to inspect (default: the in-progress merge, else HEAD).
+    pub merge: Option<String>,
+}
+
+#[mcp_tool(
+    name = "workflow_show",
+    description = "Render the configured status workflow ([workflow] in config) as a state diagram (format: mermaid|dot), annotated with task counts per status and average dwell time from audit data. Returns the diagram text plus the underlying states and transitions."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct WorkflowShowTool {
+    pub root: Option<String>,
+    /// mermaid (default) or dot.
+    pub format: Option<String>,
+}
+
+#[mcp_tool(
+    name = "calibration",
+    description = "Compare estimated vs actual effort on done tasks (estimate/actual front matter, or In Progress -> Done time from the audit log), with bias factors per bucket and bias-adjusted remaining estimates for open tasks. by: assignee|label (default: overall)."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct CalibrationTool {
+    pub root: Option<String>,
+    /// assignee or label (default: one overall bucket).
+    pub by: Option<String>,
+}
+
+#[mcp_tool(
+    name = "cycle_time",
+    description = "Lead time (created -> done) and cycle time (first In Progress -> last Done) of done tasks, reconstructed from the audit log, with p50/p90/mean hours per bucket and per-task timestamps. by: label|phase|priority (default: overall). since/until filter on the done time (7d, 12h, yesterday, YYYY-MM-DD, or RFC3339); all includes archived tasks."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct CycleTimeTool {
+    pub root: Option<String>,
+    /// label, phase, or priority (default: one overall bucket).
+    pub by: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    /// Include archived tasks.
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[mcp_tool(
+    name = "milestones",
+    description = "Milestones named by the `milestone` front matter field: total/done/cancelled/remaining tasks, percent complete, completions per week over the trailing window_days (default 28; milestones without recent completions use the backlog-wide velocity), and projected_finish (YYYY-MM-DD). Pass name for one milestone with its tasks; all includes archived tasks."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct MilestonesTool {
+    pub root: Option<String>,
+    /// One milestone (case-insensitive); includes its tasks.
+    pub name: Option<String>,
+    pub window_days: Option<i64>,
+    /// Include archived tasks.
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[mcp_tool(
+    name = "budget_status",
+    description = "Point and hour budgets per initiative and phase from the [budgets] config. Per budget: tasks in scope and, per unit, budget, consumed (story_points of Done tasks; logged actual/time_spent hours, else the estimate of Done tasks), planned, remaining, percent_used, burn_per_week since the budget start, health (ok|at_risk|over), and projected_overrun (YYYY-MM-DD) for at-risk budgets. Filter with initiative or phase; all includes archived tasks."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct BudgetStatusTool {
+    pub root: Option<String>,
+    /// Only this initiative's budget (case-insensitive).
+    pub initiative: Option<String>,
+    /// Only this phase's budget (case-insensitive).
+    pub phase: Option<String>,
+    /// Include archived tasks.
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[mcp_tool(
+    name = "onboarding_new",
+    description = "Create an onboarding epic for a newcomer plus one task per [[onboarding.items]] entry (default checklist: access, docs reading, first issue). Tasks are assigned to the newcomer, parented to the epic, labelled onboarding, chained through depends_on, and carry onboarding: <user>. Fails while the user has an open onboarding epic."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct OnboardingNewTool {
+    pub root: Option<String>,
+    /// Newcomer's handle (a leading `@` is dropped).
+    pub user: String,
+}
+
+#[mcp_tool(
+    name = "onboarding_status",
+    description = "Progress of onboarding checklists: per newcomer the epic, done/total, percent, and each item with status, done, and ready (open with every dependency done). Filter with user; all includes archived tasks."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct OnboardingStatusTool {
+    pub root: Option<String>,
+    /// Only this newcomer (case-insensitive, `@` optional).
+    pub user: Option<String>,
+    /// Include archived tasks.
+    #[serde(default)]
+    pub all: bool,
+}
+
+#[mcp_tool(
+    name = "journal_list",
+    description = "Read the session journal (entries written by session_journal), oldest first. Filter by task id and by time window (since inclusive, until exclusive; each accepts 7d, 12h, yesterday, YYYY-MM-DD, or RFC3339). format=markdown returns a chronological narrative grouped by day."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct JournalListTool {
+    pub root: Option<String>,
+    pub project: Option<String>,
+    pub task: Option<String>,
+    pub since: Option<String>,
+    pub until: Option<String>,
+    #[serde(default = "default_format")]
+    pub format: String,
+}
+
+#[mcp_tool(
+    name = "working_set_status",
+    description = "Compare the stored working set against current task statuses and leases. Each entry gets flags: missing, closed, lease_expired, idle (not In Progress and not leased), status_changed. refresh=true rewrites the file without stale entries, keeping notes."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct WorkingSetStatusTool {
+    pub root: Option<String>,
+    pub project: Option<String>,
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+#[mcp_tool(
+    name = "archive_search",
+    description = "Search archived tasks (workmesh/archive) by case-insensitive title/body substring using the archive index; does not load the active backlog."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ArchiveSearchTool {
+    pub query: String,
+    pub root: Option<String>,
+    pub limit: Option<u32>,
+}
+
+#[mcp_tool(
+    name = "context_pack",
+    description = "Assemble the most relevant backlog content (context, top ready tasks with trimmed bodies, blockers, recent decisions) under an approximate token budget (e.g. 4000, 4000-tokens, 4k), dropping lower-priority items first. format=markdown returns one blob for prompt injection."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct ContextPackTool {
+    pub root: Option<String>,
+    pub budget: Option<String>,
+    pub epic: Option<String>,
+    #[serde(default = "default_format")]
+    pub format: String,
+}
+
+#[mcp_tool(
+    name = "epic_brief",
+    description = "Compact (~1-2 KB) orientation summary of an epic: progress and counts by status, top blockers, next 5 ready tasks, and last activity. Use it instead of pulling the whole subtree."
+)]
+#[derive(Debug, Deserialize, Serialize, JsonSchema)]
+pub struct EpicBriefTool {
+    pub root: Option<String>,
+    pub epic_id: String,
+    #[serde(default = "default_format")]
+    pub format: String,
+}
+
+fn default_sort() -> String {
+    "id".to_string()
+}
+
+fn default_board_by() -> String {
+    "status".to_string()
+}
+
+fn default_format() -> String {
+    "json".to_string()
+}
+
+fn default_text_format() -> String {
+    "text".to_string()
+}
+
+fn default_include_body() -> bool {
+    true
 }
 
 fn default_notes_section() -> String {
@@ -2245,8 +3074,10 @@ tool_box!(
         NextTaskTool,
         NextTasksTool,
         ReadyTasksTool,
+        GrabTasksTool,
         BoardTool,
         BlockersTool,
+        ToposortTool,
         ExportTasksTool,
         StatsTool,
         SetStatusTool,
@@ -2263,12 +3094,17 @@ tool_box!(
         BulkRemoveDependencyTool,
         BulkAddNoteTool,
         ArchiveTool,
+        RemoveTaskTool,
+        MoveTaskTool,
+        SplitTaskTool,
         MigrateTool,
         MigrateAuditTool,
         MigratePlanTool,
         MigrateApplyTool,
         ClaimTaskTool,
         ReleaseTaskTool,
+        FreezeTool,
+        UnfreezeTool,
         AddNoteTool,
         SetBodyTool,
         SetSectionTool,
@@ -2280,6 +3116,10 @@ tool_box!(
         ValidateTool,
         FixIdsTool,
         FixFilenamesTool,
+        LintTasksTool,
+        LintTitlesTool,
+        AutotagTool,
+        ChangesSinceTool,
         RekeyPromptTool,
         RekeyApplyTool,
         GraphExportTool,
@@ -2289,9 +3129,11 @@ tool_box!(
         IndexVerifyTool,
         CheckpointTool,
         ResumeTool,
+        RemindersTool,
         WorkingSetTool,
         SessionJournalTool,
         CheckpointDiffTool,
+        GraphDiffTool,
         SessionSaveTool,
         SessionListTool,
         SessionShowTool,
@@ -2304,6 +3146,25 @@ tool_box!(
         HelpTool,
         ToolInfoTool,
         ProjectManagementSkillTool,
+        MergeBacklogTool,
+        ExtractTool,
+        InboxTool,
+        MentionsTool,
+        PoliciesRunTool,
+        HealthTool,
+        PostmergeTool,
+        WorkflowShowTool,
+        CalibrationTool,
+        CycleTimeTool,
+        MilestonesTool,
+        BudgetStatusTool,
+        OnboardingNewTool,
+        OnboardingStatusTool,
+        JournalListTool,
+        WorkingSetStatusTool,
+        ArchiveSearchTool,
+        ContextPackTool,
+        EpicBriefTool,
         RenderTableTool,
         RenderKvTool,
         RenderStatsTool,
@@ -2319,12 +3180,108 @@ tool_box!(
     ]
 );
 
+/// Extra attempts to acquire a file lock that timed out (another CLI or MCP writer held it),
+/// with a linear backoff between attempts.
+const LOCK_RETRY_ATTEMPTS: u32 = 3;
+const LOCK_RETRY_BACKOFF: std::time::Duration = std::time::Duration::from_millis(200);
+
 pub struct WorkmeshServerHandler {
     pub context: McpContext,
+    /// Short-TTL cache for read-only tool responses (see `cache`).
+    pub cache: ResponseCache,
+    /// Resources the client subscribed to (see `resources`).
+    pub resources: ResourceSubscriptions,
+}
+
+impl WorkmeshServerHandler {
+    /// Default backlog for resources, after the caller passes the same `[roles]` check as `tool`.
+    fn resource_backlog(&self, runtime: &dyn McpServer, tool: &str) -> Result<PathBuf, RpcError> {
+        let context = self.context.with_client(runtime.client_info());
+        if let Err(denied) = authorize_tool(&context, tool, &serde_json::Value::Null) {
+            return Err(RpcError::invalid_request().with_message(&denied.reason));
+        }
+        resolve_root(&context, None).map_err(|err| {
+            RpcError::invalid_params()
+                .with_message(err["error"].as_str().unwrap_or(ROOT_REQUIRED_ERROR))
+        })
+    }
 }
 
 #[async_trait]
 impl ServerHandler for WorkmeshServerHandler {
+    async fn on_initialized(&self, runtime: std::sync::Arc<dyn McpServer>) {
+        if let Ok(backlog_dir) = resolve_root(&self.context, None) {
+            ResourceWatcher::new(&backlog_dir, self.resources.clone()).spawn(runtime);
+        }
+    }
+
+    async fn handle_list_resources_request(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        runtime: std::sync::Arc<dyn McpServer>,
+    ) -> Result<ListResourcesResult, RpcError> {
+        let backlog_dir = self.resource_backlog(runtime.as_ref(), "list_tasks")?;
+        Ok(ListResourcesResult {
+            meta: None,
+            next_cursor: None,
+            resources: list_resources(&backlog_dir),
+        })
+    }
+
+    async fn handle_list_resource_templates_request(
+        &self,
+        _params: Option<PaginatedRequestParams>,
+        _runtime: std::sync::Arc<dyn McpServer>,
+    ) -> Result<ListResourceTemplatesResult, RpcError> {
+        Ok(ListResourceTemplatesResult {
+            meta: None,
+            next_cursor: None,
+            resource_templates: resource_templates(),
+        })
+    }
+
+    async fn handle_read_resource_request(
+        &self,
+        params: ReadResourceRequestParams,
+        runtime: std::sync::Arc<dyn McpServer>,
+    ) -> Result<ReadResourceResult, RpcError> {
+        let backlog_dir = self.resource_backlog(runtime.as_ref(), "show_task")?;
+        let Some(text) = read_resource(&backlog_dir, &params.uri) else {
+            let message = format!("Resource not found: {}", params.uri);
+            return Err(RpcError::invalid_params().with_message(message));
+        };
+        Ok(ReadResourceResult {
+            contents: vec![ReadResourceContent::TextResourceContents(
+                TextResourceContents {
+                    meta: None,
+                    mime_type: Some("application/json".to_string()),
+                    text,
+                    uri: params.uri,
+                },
+            )],
+            meta: None,
+        })
+    }
+
+    async fn handle_subscribe_request(
+        &self,
+        params: SubscribeRequestParams,
+        runtime: std::sync::Arc<dyn McpServer>,
+    ) -> Result<rust_mcp_sdk::schema::Result, RpcError> {
+        let backlog_dir = self.resource_backlog(runtime.as_ref(), "show_task")?;
+        self.resources.subscribe(&backlog_dir, &params.uri);
+        Ok(rust_mcp_sdk::schema::Result::default())
+    }
+
+    async fn handle_unsubscribe_request(
+        &self,
+        params: UnsubscribeRequestParams,
+        _runtime: std::sync::Arc<dyn McpServer>,
+    ) -> Result<rust_mcp_sdk::schema::Result, RpcError> {
+        self.resources.unsubscribe(&params.uri);
+        Ok(rust_mcp_sdk::schema::Result::default())
+    }
+
     async fn handle_list_tools_request(
         &self,
         _params: Option<PaginatedRequestParams>,
@@ -2340,123 +3297,203 @@ impl ServerHandler for WorkmeshServerHandler {
     async fn handle_call_tool_request(
         &self,
         params: CallToolRequestParams,
-        _runtime: std::sync::Arc<dyn McpServer>,
+        runtime: std::sync::Arc<dyn McpServer>,
     ) -> Result<CallToolResult, CallToolError> {
-        let tool = WorkmeshTools::try_from(params).map_err(CallToolError::new)?;
-        match tool {
-            WorkmeshTools::VersionTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ReadmeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::DoctorTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ConfigShowTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ConfigSetTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ConfigUnsetTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BootstrapTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ContextShowTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ContextSetTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ContextClearTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamListTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamCreateTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamShowTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamSwitchTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamPauseTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamCloseTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamReopenTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamRenameTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamSetTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamDoctorTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkstreamRestoreTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeListTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeCreateTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeAdoptCloneTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeAttachTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeDetachTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorktreeDoctorTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthProposeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthAcceptTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthRejectTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthSupersedeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthShowTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthListTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthValidateTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthMigrateAuditTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthMigratePlanTool(tool) => tool.call(&self.context),
-            WorkmeshTools::TruthMigrateApplyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ListTasksTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ShowTaskTool(tool) => tool.call(&self.context),
-            WorkmeshTools::NextTaskTool(tool) => tool.call(&self.context),
-            WorkmeshTools::NextTasksTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ReadyTasksTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BoardTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BlockersTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ExportTasksTool(tool) => tool.call(&self.context),
-            WorkmeshTools::StatsTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SetStatusTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SetFieldTool(tool) => tool.call(&self.context),
-            WorkmeshTools::AddLabelTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RemoveLabelTool(tool) => tool.call(&self.context),
-            WorkmeshTools::AddDependencyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RemoveDependencyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkSetStatusTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkSetFieldTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkAddLabelTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkRemoveLabelTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkAddDependencyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkRemoveDependencyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BulkAddNoteTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ArchiveTool(tool) => tool.call(&self.context),
-            WorkmeshTools::MigrateTool(tool) => tool.call(&self.context),
-            WorkmeshTools::MigrateAuditTool(tool) => tool.call(&self.context),
-            WorkmeshTools::MigratePlanTool(tool) => tool.call(&self.context),
-            WorkmeshTools::MigrateApplyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ClaimTaskTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ReleaseTaskTool(tool) => tool.call(&self.context),
-            WorkmeshTools::AddNoteTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SetBodyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SetSectionTool(tool) => tool.call(&self.context),
-            WorkmeshTools::AddTaskTool(tool) => tool.call(&self.context),
-            WorkmeshTools::AddDiscoveredTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ProjectInitTool(tool) => tool.call(&self.context),
-            WorkmeshTools::QuickstartTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ValidateTool(tool) => tool.call(&self.context),
-            WorkmeshTools::FixIdsTool(tool) => tool.call(&self.context),
-            WorkmeshTools::FixFilenamesTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RekeyPromptTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RekeyApplyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::GraphExportTool(tool) => tool.call(&self.context),
-            WorkmeshTools::IssuesExportTool(tool) => tool.call(&self.context),
-            WorkmeshTools::IndexRebuildTool(tool) => tool.call(&self.context),
-            WorkmeshTools::IndexRefreshTool(tool) => tool.call(&self.context),
-            WorkmeshTools::IndexVerifyTool(tool) => tool.call(&self.context),
-            WorkmeshTools::CheckpointTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ResumeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::WorkingSetTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SessionJournalTool(tool) => tool.call(&self.context),
-            WorkmeshTools::CheckpointDiffTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SessionSaveTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SessionListTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SessionShowTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SessionResumeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::GanttTextTool(tool) => tool.call(&self.context),
-            WorkmeshTools::GanttFileTool(tool) => tool.call(&self.context),
-            WorkmeshTools::GanttSvgTool(tool) => tool.call(&self.context),
-            WorkmeshTools::BestPracticesTool(tool) => tool.call(&self.context),
-            WorkmeshTools::SkillContentTool(tool) => tool.call(&self.context),
-            WorkmeshTools::HelpTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ToolInfoTool(tool) => tool.call(&self.context),
-            WorkmeshTools::ProjectManagementSkillTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderTableTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderKvTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderStatsTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderListTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderProgressTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderTreeTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderDiffTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderLogsTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderAlertsTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderChartBarTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderSparklineTool(tool) => tool.call(&self.context),
-            WorkmeshTools::RenderTimelineTool(tool) => tool.call(&self.context),
+        let context = self.context.with_client(runtime.client_info());
+        if let Err(denied) = authorize_tool_call(&context, &params) {
+            return ok_json(denied.to_json());
+        }
+        if let Some(denied) = scoped_tool_call(&context, &params) {
+            return ok_json(denied);
+        }
+        if let Some(frozen) = frozen_tool_call(&context, &params) {
+            return ok_json(frozen);
+        }
+        let telemetry = ToolTelemetry::start(&context, &params);
+        let args = params
+            .arguments
+            .clone()
+            .map(serde_json::Value::Object)
+            .unwrap_or(serde_json::Value::Null);
+        let slot = cache_slot(
+            &self.cache,
+            &params.name,
+            resolve_root(&context, args.get("root").and_then(|v| v.as_str())).ok(),
+            &args,
+        );
+        if let Some((backlog_dir, key)) = slot.as_ref() {
+            if let Some(hit) = self.cache.get(key, backlog_dir) {
+                if let Some(telemetry) = telemetry {
+                    telemetry.finish(true);
+                }
+                return Ok(hit);
+            }
+        }
+        // Arguments matter: `grab_tasks claim=true`, `doctor fix_storage=true` and friends write.
+        let mutating = required_role(&params.name, &args, &[]).0 != Role::Viewer;
+        // Retry lock acquisition inside the writers, never the whole (non-idempotent) tool call.
+        enable_lock_retry(LOCK_RETRY_ATTEMPTS, LOCK_RETRY_BACKOFF);
+        let tool = WorkmeshTools::try_from(params.clone()).map_err(CallToolError::new)?;
+        let result = call_tool(tool, &context);
+        if mutating {
+            self.cache.invalidate();
+        } else if let (Some((backlog_dir, key)), Ok(response)) = (slot, result.as_ref()) {
+            self.cache.put(key, &backlog_dir, response);
+        }
+        if let Some(telemetry) = telemetry {
+            telemetry.finish(result.is_ok());
         }
+        result
+    }
+}
+
+/// Dispatch one parsed tool call.
+fn call_tool(tool: WorkmeshTools, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+    match tool {
+        WorkmeshTools::VersionTool(tool) => tool.call(context),
+        WorkmeshTools::ReadmeTool(tool) => tool.call(context),
+        WorkmeshTools::DoctorTool(tool) => tool.call(context),
+        WorkmeshTools::ConfigShowTool(tool) => tool.call(context),
+        WorkmeshTools::ConfigSetTool(tool) => tool.call(context),
+        WorkmeshTools::ConfigUnsetTool(tool) => tool.call(context),
+        WorkmeshTools::BootstrapTool(tool) => tool.call(context),
+        WorkmeshTools::ContextShowTool(tool) => tool.call(context),
+        WorkmeshTools::ContextSetTool(tool) => tool.call(context),
+        WorkmeshTools::ContextClearTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamListTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamCreateTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamShowTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamSwitchTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamPauseTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamCloseTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamReopenTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamRenameTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamSetTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamDoctorTool(tool) => tool.call(context),
+        WorkmeshTools::WorkstreamRestoreTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeListTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeCreateTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeAdoptCloneTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeAttachTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeDetachTool(tool) => tool.call(context),
+        WorkmeshTools::WorktreeDoctorTool(tool) => tool.call(context),
+        WorkmeshTools::TruthProposeTool(tool) => tool.call(context),
+        WorkmeshTools::TruthAcceptTool(tool) => tool.call(context),
+        WorkmeshTools::TruthRejectTool(tool) => tool.call(context),
+        WorkmeshTools::TruthSupersedeTool(tool) => tool.call(context),
+        WorkmeshTools::TruthShowTool(tool) => tool.call(context),
+        WorkmeshTools::TruthListTool(tool) => tool.call(context),
+        WorkmeshTools::TruthValidateTool(tool) => tool.call(context),
+        WorkmeshTools::TruthMigrateAuditTool(tool) => tool.call(context),
+        WorkmeshTools::TruthMigratePlanTool(tool) => tool.call(context),
+        WorkmeshTools::TruthMigrateApplyTool(tool) => tool.call(context),
+        WorkmeshTools::ListTasksTool(tool) => tool.call(context),
+        WorkmeshTools::ShowTaskTool(tool) => tool.call(context),
+        WorkmeshTools::NextTaskTool(tool) => tool.call(context),
+        WorkmeshTools::NextTasksTool(tool) => tool.call(context),
+        WorkmeshTools::ReadyTasksTool(tool) => tool.call(context),
+        WorkmeshTools::GrabTasksTool(tool) => tool.call(context),
+        WorkmeshTools::BoardTool(tool) => tool.call(context),
+        WorkmeshTools::BlockersTool(tool) => tool.call(context),
+        WorkmeshTools::ToposortTool(tool) => tool.call(context),
+        WorkmeshTools::ExportTasksTool(tool) => tool.call(context),
+        WorkmeshTools::StatsTool(tool) => tool.call(context),
+        WorkmeshTools::SetStatusTool(tool) => tool.call(context),
+        WorkmeshTools::SetFieldTool(tool) => tool.call(context),
+        WorkmeshTools::AddLabelTool(tool) => tool.call(context),
+        WorkmeshTools::RemoveLabelTool(tool) => tool.call(context),
+        WorkmeshTools::AddDependencyTool(tool) => tool.call(context),
+        WorkmeshTools::RemoveDependencyTool(tool) => tool.call(context),
+        WorkmeshTools::BulkSetStatusTool(tool) => tool.call(context),
+        WorkmeshTools::BulkSetFieldTool(tool) => tool.call(context),
+        WorkmeshTools::BulkAddLabelTool(tool) => tool.call(context),
+        WorkmeshTools::BulkRemoveLabelTool(tool) => tool.call(context),
+        WorkmeshTools::BulkAddDependencyTool(tool) => tool.call(context),
+        WorkmeshTools::BulkRemoveDependencyTool(tool) => tool.call(context),
+        WorkmeshTools::BulkAddNoteTool(tool) => tool.call(context),
+        WorkmeshTools::ArchiveTool(tool) => tool.call(context),
+        WorkmeshTools::RemoveTaskTool(tool) => tool.call(context),
+        WorkmeshTools::MoveTaskTool(tool) => tool.call(context),
+        WorkmeshTools::SplitTaskTool(tool) => tool.call(context),
+        WorkmeshTools::MigrateTool(tool) => tool.call(context),
+        WorkmeshTools::MigrateAuditTool(tool) => tool.call(context),
+        WorkmeshTools::MigratePlanTool(tool) => tool.call(context),
+        WorkmeshTools::MigrateApplyTool(tool) => tool.call(context),
+        WorkmeshTools::ClaimTaskTool(tool) => tool.call(context),
+        WorkmeshTools::ReleaseTaskTool(tool) => tool.call(context),
+        WorkmeshTools::FreezeTool(tool) => tool.call(context),
+        WorkmeshTools::UnfreezeTool(tool) => tool.call(context),
+        WorkmeshTools::AddNoteTool(tool) => tool.call(context),
+        WorkmeshTools::SetBodyTool(tool) => tool.call(context),
+        WorkmeshTools::SetSectionTool(tool) => tool.call(context),
+        WorkmeshTools::AddTaskTool(tool) => tool.call(context),
+        WorkmeshTools::AddDiscoveredTool(tool) => tool.call(context),
+        WorkmeshTools::ProjectInitTool(tool) => tool.call(context),
+        WorkmeshTools::QuickstartTool(tool) => tool.call(context),
+        WorkmeshTools::ValidateTool(tool) => tool.call(context),
+        WorkmeshTools::FixIdsTool(tool) => tool.call(context),
+        WorkmeshTools::FixFilenamesTool(tool) => tool.call(context),
+        WorkmeshTools::LintTasksTool(tool) => tool.call(context),
+        WorkmeshTools::LintTitlesTool(tool) => tool.call(context),
+        WorkmeshTools::AutotagTool(tool) => tool.call(context),
+        WorkmeshTools::ChangesSinceTool(tool) => tool.call(context),
+        WorkmeshTools::RekeyPromptTool(tool) => tool.call(context),
+        WorkmeshTools::RekeyApplyTool(tool) => tool.call(context),
+        WorkmeshTools::GraphExportTool(tool) => tool.call(context),
+        WorkmeshTools::IssuesExportTool(tool) => tool.call(context),
+        WorkmeshTools::IndexRebuildTool(tool) => tool.call(context),
+        WorkmeshTools::IndexRefreshTool(tool) => tool.call(context),
+        WorkmeshTools::IndexVerifyTool(tool) => tool.call(context),
+        WorkmeshTools::CheckpointTool(tool) => tool.call(context),
+        WorkmeshTools::ResumeTool(tool) => tool.call(context),
+        WorkmeshTools::RemindersTool(tool) => tool.call(context),
+        WorkmeshTools::WorkingSetTool(tool) => tool.call(context),
+        WorkmeshTools::SessionJournalTool(tool) => tool.call(context),
+        WorkmeshTools::CheckpointDiffTool(tool) => tool.call(context),
+        WorkmeshTools::GraphDiffTool(tool) => tool.call(context),
+        WorkmeshTools::SessionSaveTool(tool) => tool.call(context),
+        WorkmeshTools::SessionListTool(tool) => tool.call(context),
+        WorkmeshTools::SessionShowTool(tool) => tool.call(context),
+        WorkmeshTools::SessionResumeTool(tool) => tool.call(context),
+        WorkmeshTools::GanttTextTool(tool) => tool.call(context),
+        WorkmeshTools::GanttFileTool(tool) => tool.call(context),
+        WorkmeshTools::GanttSvgTool(tool) => tool.call(context),
+        WorkmeshTools::BestPracticesTool(tool) => tool.call(context),
+        WorkmeshTools::SkillContentTool(tool) => tool.call(context),
+        WorkmeshTools::HelpTool(tool) => tool.call(context),
+        WorkmeshTools::ToolInfoTool(tool) => tool.call(context),
+        WorkmeshTools::ProjectManagementSkillTool(tool) => tool.call(context),
+        WorkmeshTools::MergeBacklogTool(tool) => tool.call(context),
+        WorkmeshTools::ExtractTool(tool) => tool.call(context),
+        WorkmeshTools::InboxTool(tool) => tool.call(context),
+        WorkmeshTools::MentionsTool(tool) => tool.call(context),
+        WorkmeshTools::PoliciesRunTool(tool) => tool.call(context),
+        WorkmeshTools::HealthTool(tool) => tool.call(context),
+        WorkmeshTools::PostmergeTool(tool) => tool.call(context),
+        WorkmeshTools::WorkflowShowTool(tool) => tool.call(context),
+        WorkmeshTools::CalibrationTool(tool) => tool.call(context),
+        WorkmeshTools::CycleTimeTool(tool) => tool.call(context),
+        WorkmeshTools::MilestonesTool(tool) => tool.call(context),
+        WorkmeshTools::BudgetStatusTool(tool) => tool.call(context),
+        WorkmeshTools::OnboardingNewTool(tool) => tool.call(context),
+        WorkmeshTools::OnboardingStatusTool(tool) => tool.call(context),
+        WorkmeshTools::JournalListTool(tool) => tool.call(context),
+        WorkmeshTools::WorkingSetStatusTool(tool) => tool.call(context),
+        WorkmeshTools::ArchiveSearchTool(tool) => tool.call(context),
+        WorkmeshTools::ContextPackTool(tool) => tool.call(context),
+        WorkmeshTools::EpicBriefTool(tool) => tool.call(context),
+        WorkmeshTools::RenderTableTool(tool) => tool.call(context),
+        WorkmeshTools::RenderKvTool(tool) => tool.call(context),
+        WorkmeshTools::RenderStatsTool(tool) => tool.call(context),
+        WorkmeshTools::RenderListTool(tool) => tool.call(context),
+        WorkmeshTools::RenderProgressTool(tool) => tool.call(context),
+        WorkmeshTools::RenderTreeTool(tool) => tool.call(context),
+        WorkmeshTools::RenderDiffTool(tool) => tool.call(context),
+        WorkmeshTools::RenderLogsTool(tool) => tool.call(context),
+        WorkmeshTools::RenderAlertsTool(tool) => tool.call(context),
+        WorkmeshTools::RenderChartBarTool(tool) => tool.call(context),
+        WorkmeshTools::RenderSparklineTool(tool) => tool.call(context),
+        WorkmeshTools::RenderTimelineTool(tool) => tool.call(context),
     }
 }
 
@@ -2547,6 +3584,10 @@ impl ConfigShowTool {
             workmesh_core::config::resolve_worktrees_dir_with_source(&repo_root);
         let (auto_session_default, auto_session_default_source) =
             workmesh_core::config::resolve_auto_session_default_with_source(&repo_root);
+        let (note_autolink, note_autolink_source) =
+            workmesh_core::config::resolve_note_autolink_with_source(&repo_root);
+        let (time_display, time_display_source) =
+            workmesh_core::config::resolve_time_display_with_source(&repo_root);
         let (task_validation, task_validation_sources) =
             resolve_task_validation_rules_with_source(&repo_root);
 
@@ -2557,19 +3598,31 @@ impl ConfigShowTool {
                 "worktrees_default": worktrees_default,
                 "worktrees_dir": worktrees_dir.as_ref().map(|p| p.to_string_lossy().to_string()),
                 "auto_session_default": auto_session_default,
+                "note_autolink": note_autolink,
+                "time_display": time_display.as_str(),
                 "task_require_description": task_validation.require_description,
                 "task_require_acceptance_criteria": task_validation.require_acceptance_criteria,
                 "task_require_definition_of_done": task_validation.require_definition_of_done,
                 "task_require_outcome_based_definition_of_done": task_validation.require_outcome_based_definition_of_done,
+                "task_require_implementation_notes": task_validation.require_implementation_notes,
+                "task_require_acceptance_criteria_checked": task_validation.require_acceptance_criteria_checked,
+                "task_require_linked_commit": task_validation.require_linked_commit,
+                "task_require_review_approval": task_validation.require_review_approval,
             },
             "sources": {
                 "worktrees_default": worktrees_default_source,
                 "worktrees_dir": worktrees_dir_source,
                 "auto_session_default": auto_session_default_source,
+                "note_autolink": note_autolink_source,
+                "time_display": time_display_source,
                 "task_require_description": task_validation_sources.require_description,
                 "task_require_acceptance_criteria": task_validation_sources.require_acceptance_criteria,
                 "task_require_definition_of_done": task_validation_sources.require_definition_of_done,
                 "task_require_outcome_based_definition_of_done": task_validation_sources.require_outcome_based_definition_of_done,
+                "task_require_implementation_notes": task_validation_sources.require_implementation_notes,
+                "task_require_acceptance_criteria_checked": task_validation_sources.require_acceptance_criteria_checked,
+                "task_require_linked_commit": task_validation_sources.require_linked_commit,
+                "task_require_review_approval": task_validation_sources.require_review_approval,
             },
             "paths": {
                 "project_default": workmesh_core::config::config_path(&repo_root),
@@ -2612,6 +3665,27 @@ impl ConfigShowTool {
                         value
                     ));
                 }
+                if let Some(value) = config.task_require_implementation_notes {
+                    lines.push(format!(
+                        "- task_require_implementation_notes: {} (project)",
+                        value
+                    ));
+                }
+                if let Some(value) = config.task_require_acceptance_criteria_checked {
+                    lines.push(format!(
+                        "- task_require_acceptance_criteria_checked: {} (project)",
+                        value
+                    ));
+                }
+                if let Some(value) = config.task_require_linked_commit {
+                    lines.push(format!("- task_require_linked_commit: {} (project)", value));
+                }
+                if let Some(value) = config.task_require_review_approval {
+                    lines.push(format!(
+                        "- task_require_review_approval: {} (project)",
+                        value
+                    ));
+                }
                 if let Some(value) = config.root_dir.as_ref() {
                     lines.push(format!("- root_dir: {} (project, deprecated)", value));
                 }
@@ -2635,6 +3709,26 @@ impl ConfigShowTool {
                 task_validation.require_outcome_based_definition_of_done,
                 task_validation_sources.require_outcome_based_definition_of_done
             ));
+            lines.push(format!(
+                "- task_require_implementation_notes: {} ({})",
+                task_validation.require_implementation_notes,
+                task_validation_sources.require_implementation_notes
+            ));
+            lines.push(format!(
+                "- task_require_acceptance_criteria_checked: {} ({})",
+                task_validation.require_acceptance_criteria_checked,
+                task_validation_sources.require_acceptance_criteria_checked
+            ));
+            lines.push(format!(
+                "- task_require_linked_commit: {} ({})",
+                task_validation.require_linked_commit,
+                task_validation_sources.require_linked_commit
+            ));
+            lines.push(format!(
+                "- task_require_review_approval: {} ({})",
+                task_validation.require_review_approval,
+                task_validation_sources.require_review_approval
+            ));
             if let Some(dir) = worktrees_dir.as_ref() {
                 lines.push(format!(
                     "- worktrees_dir: {} ({})",
@@ -2723,6 +3817,23 @@ impl ConfigSetTool {
                 })?;
                 config.auto_session_default = Some(parsed);
             }
+            "note_autolink" => {
+                let parsed = parse_boolish(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid bool value for note_autolink (expected true/false/1/0)"
+                            .to_string(),
+                    )
+                })?;
+                config.note_autolink = Some(parsed);
+            }
+            "time_display" => {
+                let parsed = TimeDisplay::parse(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid value for time_display (expected local/utc)".to_string(),
+                    )
+                })?;
+                config.time_display = Some(parsed.as_str().to_string());
+            }
             "tasks_root" => {
                 if value.is_empty() {
                     return Err(CallToolError::from_message(
@@ -2775,6 +3886,42 @@ impl ConfigSetTool {
                 })?;
                 config.task_require_outcome_based_definition_of_done = Some(parsed);
             }
+            "task_require_implementation_notes" => {
+                let parsed = parse_boolish(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid bool value for task_require_implementation_notes (expected true/false/1/0)"
+                            .to_string(),
+                    )
+                })?;
+                config.task_require_implementation_notes = Some(parsed);
+            }
+            "task_require_acceptance_criteria_checked" => {
+                let parsed = parse_boolish(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid bool value for task_require_acceptance_criteria_checked (expected true/false/1/0)"
+                            .to_string(),
+                    )
+                })?;
+                config.task_require_acceptance_criteria_checked = Some(parsed);
+            }
+            "task_require_linked_commit" => {
+                let parsed = parse_boolish(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid bool value for task_require_linked_commit (expected true/false/1/0)"
+                            .to_string(),
+                    )
+                })?;
+                config.task_require_linked_commit = Some(parsed);
+            }
+            "task_require_review_approval" => {
+                let parsed = parse_boolish(value).ok_or_else(|| {
+                    CallToolError::from_message(
+                        "Invalid bool value for task_require_review_approval (expected true/false/1/0)"
+                            .to_string(),
+                    )
+                })?;
+                config.task_require_review_approval = Some(parsed);
+            }
             "root_dir" => {
                 if value.is_empty() {
                     return Err(CallToolError::from_message(
@@ -2849,6 +3996,8 @@ impl ConfigUnsetTool {
             "worktrees_default" => config.worktrees_default = None,
             "worktrees_dir" => config.worktrees_dir = None,
             "auto_session_default" => config.auto_session_default = None,
+            "note_autolink" => config.note_autolink = None,
+            "time_display" => config.time_display = None,
             "tasks_root" => config.tasks_root = None,
             "state_root" => config.state_root = None,
             "task_require_description" => config.task_require_description = None,
@@ -2857,6 +4006,12 @@ impl ConfigUnsetTool {
             "task_require_outcome_based_definition_of_done" => {
                 config.task_require_outcome_based_definition_of_done = None
             }
+            "task_require_implementation_notes" => config.task_require_implementation_notes = None,
+            "task_require_acceptance_criteria_checked" => {
+                config.task_require_acceptance_criteria_checked = None
+            }
+            "task_require_linked_commit" => config.task_require_linked_commit = None,
+            "task_require_review_approval" => config.task_require_review_approval = None,
             "root_dir" => config.root_dir = None,
             "do_not_migrate" => config.do_not_migrate = None,
             _ => {
@@ -2986,6 +4141,7 @@ fn call_context_set(
     let path = save_context(&backlog_dir, state.clone())
         .map_err(|err| CallToolError::from_message(err.to_string()))?;
     audit_event(
+        context,
         &backlog_dir,
         audit_action,
         state.scope.epic_id.as_deref(),
@@ -3046,7 +4202,13 @@ fn call_context_clear(
     let cleared =
         clear_context(&backlog_dir).map_err(|err| CallToolError::from_message(err.to_string()))?;
     if cleared {
-        audit_event(&backlog_dir, audit_action, None, serde_json::json!({}))?;
+        audit_event(
+            context,
+            &backlog_dir,
+            audit_action,
+            None,
+            serde_json::json!({}),
+        )?;
     }
     maybe_verbose_payload(
         verbose,
@@ -5020,12 +6182,13 @@ impl TruthProposeTool {
                             .map(|binding| binding.path.clone())
                     }),
                 },
-                actor: self.actor.clone().or_else(|| Some("mcp".to_string())),
+                actor: self.actor.clone().or_else(|| Some(context.actor())),
             },
         )
         .map_err(CallToolError::new)?;
 
         audit_event(
+            context,
             &backlog_dir,
             "truth_propose",
             None,
@@ -5060,11 +6223,12 @@ impl TruthAcceptTool {
             TruthTransitionInput {
                 truth_id: self.truth_id.clone(),
                 note: self.note.clone(),
-                actor: self.actor.clone().or_else(|| Some("mcp".to_string())),
+                actor: self.actor.clone().or_else(|| Some(context.actor())),
             },
         )
         .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "truth_accept",
             None,
@@ -5098,11 +6262,12 @@ impl TruthRejectTool {
             TruthTransitionInput {
                 truth_id: self.truth_id.clone(),
                 note: self.note.clone(),
-                actor: self.actor.clone().or_else(|| Some("mcp".to_string())),
+                actor: self.actor.clone().or_else(|| Some(context.actor())),
             },
         )
         .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "truth_reject",
             None,
@@ -5137,11 +6302,12 @@ impl TruthSupersedeTool {
                 truth_id: self.truth_id.clone(),
                 by_truth_id: self.by_truth_id.clone(),
                 reason: self.reason.clone(),
-                actor: self.actor.clone().or_else(|| Some("mcp".to_string())),
+                actor: self.actor.clone().or_else(|| Some(context.actor())),
             },
         )
         .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "truth_supersede",
             None,
@@ -5295,6 +6461,7 @@ impl TruthMigrateApplyTool {
             apply_truth_migration(&backlog_dir, &plan, !self.apply).map_err(CallToolError::new)?;
         if self.apply {
             audit_event(
+                context,
                 &backlog_dir,
                 "truth_migrate_apply",
                 None,
@@ -5326,12 +6493,65 @@ impl ListTasksTool {
             Ok(dir) => dir,
             Err(err) => return ok_json(err),
         };
-        let tasks = if self.all {
-            load_tasks_with_archive(&backlog_dir)
-        } else {
-            load_tasks(&backlog_dir)
-        };
-        let status = parse_list_input(self.status.clone());
+        let now = Local::now().naive_local();
+        let mut dates = DateFilter::default();
+        for (name, value, slot) in [
+            (
+                "created_after",
+                &self.created_after,
+                &mut dates.created_after,
+            ),
+            (
+                "created_before",
+                &self.created_before,
+                &mut dates.created_before,
+            ),
+            (
+                "updated_after",
+                &self.updated_after,
+                &mut dates.updated_after,
+            ),
+            (
+                "updated_before",
+                &self.updated_before,
+                &mut dates.updated_before,
+            ),
+        ] {
+            let Some(value) = value.as_deref() else {
+                continue;
+            };
+            match parse_since(value, now) {
+                Some(bound) => *slot = Some(bound),
+                None => {
+                    return ok_json(serde_json::json!({
+                        "error": format!(
+                            "Invalid {}: {} (expected {})",
+                            name, value, TIME_REF_FORMATS
+                        ),
+                    }))
+                }
+            }
+        }
+        let mut field_filters = Vec::new();
+        for raw in parse_list_input(self.fields.clone()) {
+            match FieldFilter::parse(&raw) {
+                Some(filter) => field_filters.push(filter),
+                None => {
+                    return ok_json(serde_json::json!({
+                        "error": format!(
+                            "Invalid field filter: {} (expected <field><op><value>, e.g. estimate>=5)",
+                            raw
+                        ),
+                    }))
+                }
+            }
+        }
+        let tasks = if self.all {
+            load_tasks_with_archive(&backlog_dir)
+        } else {
+            load_tasks(&backlog_dir)
+        };
+        let status = parse_list_input(self.status.clone());
         let kind = parse_list_input(self.kind.clone());
         let phase = parse_list_input(self.phase.clone());
         let priority = parse_list_input(self.priority.clone());
@@ -5368,6 +6588,26 @@ impl ListTasksTool {
             self.blocked,
             self.search.as_deref(),
         );
+        let filtered = filter_tasks_by_dates(filtered, &dates);
+        let filtered = filter_tasks_by_fields(filtered, &field_filters);
+        if let Some(raw) = self.group_by.as_deref() {
+            let Some(group_by) = GroupBy::parse(raw) else {
+                return ok_json(serde_json::json!({
+                    "error": format!(
+                        "Invalid group_by: {} (expected status, label, or assignee)",
+                        raw
+                    ),
+                }));
+            };
+            let map: serde_json::Map<String, serde_json::Value> = group_counts(&filtered, group_by)
+                .into_iter()
+                .map(|(key, count)| (key, serde_json::Value::from(count as u64)))
+                .collect();
+            return ok_json(serde_json::Value::Object(map));
+        }
+        if self.count {
+            return ok_json(serde_json::json!({ "count": filtered.len() }));
+        }
         let mut sorted = sort_tasks(filtered, &self.sort);
         if let Some(limit) = self.limit {
             sorted.truncate(limit as usize);
@@ -5408,7 +6648,12 @@ impl ShowTaskTool {
             Err(err) => return ok_json(err),
         };
         let tasks = load_tasks(&backlog_dir);
-        let task = find_task(&tasks, &self.task_id);
+        let archived = if self.include_archive && find_task(&tasks, &self.task_id).is_none() {
+            find_archived_task(&backlog_dir, &self.task_id).map_err(CallToolError::new)?
+        } else {
+            None
+        };
+        let task = find_task(&tasks, &self.task_id).or(archived.as_ref());
         let Some(task) = task else {
             return ok_json(
                 serde_json::json!({"error": format!("Task not found: {}", self.task_id)}),
@@ -5416,12 +6661,23 @@ impl ShowTaskTool {
         };
         if self.format == "text" {
             if let Some(path) = &task.file_path {
-                let content = std::fs::read_to_string(path).unwrap_or_default();
+                let content = read_task_text(path).unwrap_or_default();
                 return ok_text(content);
             }
             return ok_text(String::new());
         }
-        ok_json(task_to_json_value(task, self.include_body))
+        let mut value = task_to_json_value(task, self.include_body);
+        if let Some(rollup) = subtask_rollups(&tasks).get(&task.id.to_lowercase()) {
+            value["subtasks"] = serde_json::json!(rollup);
+        }
+        if self.include_related {
+            let repo_root = repo_root_from_backlog(&backlog_dir);
+            let related = related_tasks(&repo_root, &tasks, task, DEFAULT_RELATED_LIMIT);
+            if let Some(map) = value.as_object_mut() {
+                map.insert("related".to_string(), serde_json::json!(related));
+            }
+        }
+        ok_json(value)
     }
 }
 
@@ -5434,12 +6690,13 @@ impl NextTaskTool {
         let tasks = load_tasks(&backlog_dir);
         let context_state = load_context_state(&backlog_dir);
         let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let frozen = FrozenSet::load(&backlog_dir, &tasks);
         let recommended = recommend_next_tasks_with_context_and_rules(
             &tasks,
             context_state.as_ref(),
             &task_rules,
         );
-        let Some(task) = recommended.first() else {
+        let Some(task) = recommended.iter().find(|task| !frozen.contains(&task.id)) else {
             return ok_json(serde_json::json!({"error": "No ready tasks"}));
         };
         if self.format == "text" {
@@ -5458,11 +6715,13 @@ impl NextTasksTool {
         let tasks = load_tasks(&backlog_dir);
         let context_state = load_context_state(&backlog_dir);
         let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let frozen = FrozenSet::load(&backlog_dir, &tasks);
         let mut next_tasks = recommend_next_tasks_with_context_and_rules(
             &tasks,
             context_state.as_ref(),
             &task_rules,
         );
+        next_tasks.retain(|task| !frozen.contains(&task.id));
         if next_tasks.is_empty() {
             return ok_json(serde_json::json!({"error": "No ready tasks"}));
         }
@@ -5493,26 +6752,83 @@ impl ReadyTasksTool {
         };
         let tasks = load_tasks(&backlog_dir);
         let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let frozen = FrozenSet::load(&backlog_dir, &tasks);
         let mut ready = ready_tasks_with_rules(&tasks, &task_rules);
+        ready.retain(|task| !frozen.contains(&task.id));
         if let Some(limit) = self.limit {
             ready.truncate(limit as usize);
         }
+        let soft_warnings = soft_dependency_warnings(&tasks);
         if self.format == "text" {
             let body = ready
                 .iter()
-                .map(|task| render_task_line(task))
+                .map(|task| match soft_warnings.get(&task.id.to_lowercase()) {
+                    Some(unmet) => format!(
+                        "{}\n    soft dependency not done: {}",
+                        render_task_line(task),
+                        unmet.join(", ")
+                    ),
+                    None => render_task_line(task),
+                })
                 .collect::<Vec<_>>()
                 .join("\n");
             return ok_text(body);
         }
         let payload: Vec<serde_json::Value> = ready
             .iter()
-            .map(|task| task_to_json_value(task, false))
+            .map(|task| {
+                let mut value = task_to_json_value(task, false);
+                if let Some(unmet) = soft_warnings.get(&task.id.to_lowercase()) {
+                    value["soft_warnings"] = serde_json::json!(unmet);
+                }
+                value
+            })
             .collect();
         ok_json(serde_json::Value::Array(payload))
     }
 }
 
+impl GrabTasksTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let options = GrabOptions {
+            labels: self.labels.clone(),
+            count: self
+                .count
+                .map_or(DEFAULT_GRAB_COUNT, |count| count as usize),
+            seed: self.seed,
+            exclude: FrozenSet::load(&backlog_dir, &tasks).ids(),
+        };
+        let result = grab_tasks(&tasks, &task_rules, &options, Local::now().date_naive());
+        let mut claimed_by = None;
+        if self.claim && !result.picks.is_empty() {
+            let Some(owner) = self
+                .owner
+                .as_deref()
+                .filter(|owner| !owner.trim().is_empty())
+            else {
+                return ok_json(serde_json::json!({"error": "claim=true needs owner"}));
+            };
+            for pick in &result.picks {
+                if let Some(task) = find_task(&tasks, &pick.id) {
+                    claim_lease(context, &backlog_dir, task, owner, self.minutes, true)?;
+                }
+            }
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+            claimed_by = Some(owner.to_string());
+        }
+        let mut payload = serde_json::to_value(&result).unwrap_or_default();
+        payload["claimed_by"] = serde_json::json!(claimed_by);
+        ok_json(payload)
+    }
+}
+
 impl BoardTool {
     fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let backlog_dir = match resolve_root(context, self.root.as_deref()) {
@@ -5529,10 +6845,11 @@ impl BoardTool {
             "status" => BoardBy::Status,
             "phase" => BoardBy::Phase,
             "priority" => BoardBy::Priority,
+            "epic" => BoardBy::Epic,
             other => {
                 return ok_json(serde_json::json!({
                     "error": format!("Invalid board by: {}", other),
-                    "allowed": ["status","phase","priority"]
+                    "allowed": ["status","phase","priority","epic"]
                 }));
             }
         };
@@ -5546,6 +6863,8 @@ impl BoardTool {
             .as_ref()
             .and_then(|c| scope_ids_from_context(&tasks, c));
         let lanes = board_lanes(&tasks, by, scope_ids.as_ref());
+        let frozen = FrozenSet::load(&backlog_dir, &tasks);
+        let rollups = subtask_rollups(&tasks);
 
         if self.format == "text" {
             let mut out = String::new();
@@ -5553,6 +6872,12 @@ impl BoardTool {
                 out.push_str(&format!("## {} ({})\n", key, lane_tasks.len()));
                 for task in lane_tasks {
                     out.push_str(&render_task_line(task));
+                    if let Some(rollup) = rollups.get(&task.id.to_lowercase()) {
+                        out.push_str(&format!(" [{}]", rollup.label()));
+                    }
+                    if frozen.contains(&task.id) {
+                        out.push_str(" [frozen]");
+                    }
                     out.push('\n');
                 }
                 out.push('\n');
@@ -5565,7 +6890,16 @@ impl BoardTool {
             .map(|(key, lane_tasks)| {
                 let tasks_json: Vec<serde_json::Value> = lane_tasks
                     .into_iter()
-                    .map(|t| task_to_json_value(t, false))
+                    .map(|t| {
+                        let mut value = task_to_json_value(t, false);
+                        if frozen.contains(&t.id) {
+                            value["frozen"] = serde_json::Value::Bool(true);
+                        }
+                        if let Some(rollup) = rollups.get(&t.id.to_lowercase()) {
+                            value["subtasks"] = serde_json::json!(rollup);
+                        }
+                        value
+                    })
                     .collect();
                 serde_json::json!({
                     "lane": key,
@@ -5631,6 +6965,18 @@ impl BlockersTool {
                     out.push_str(&format!("- {} blocks {}\n", b.id, b.blocked_count));
                 }
             }
+            if !report.soft_blocked.is_empty() {
+                out.push_str("Soft dependencies (not blocking):\n");
+                for entry in &report.soft_blocked {
+                    out.push_str(&format!(
+                        "- {}: {} ({}) waits_on=[{}]\n",
+                        entry.id,
+                        entry.title,
+                        entry.status,
+                        entry.soft_dependencies.join(", ")
+                    ));
+                }
+            }
             return ok_text(out.trim_end().to_string());
         }
 
@@ -5701,16 +7047,25 @@ impl SetStatusTool {
             .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
         update_task_field(path, "status", Some(self.status.clone().into()))
             .map_err(CallToolError::new)?;
+        if self.reason.is_some() || !task.status.eq_ignore_ascii_case(&self.status) {
+            record_status_transition(
+                path,
+                &task.status,
+                &self.status,
+                Some(&context.actor()),
+                self.reason.as_deref(),
+            )
+            .map_err(CallToolError::new)?;
+        }
         if self.touch || is_done_status(&self.status) {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))
                 .map_err(CallToolError::new)?;
         }
-        audit_event(
-            &backlog_dir,
-            "set_status",
-            Some(&task.id),
-            serde_json::json!({ "status": self.status.clone() }),
-        )?;
+        let mut details = serde_json::json!({ "status": self.status.clone() });
+        if let Some(reason) = &self.reason {
+            details["reason"] = serde_json::Value::String(reason.clone());
+        }
+        audit_event(context, &backlog_dir, "set_status", Some(&task.id), details)?;
         refresh_index_best_effort(&backlog_dir);
         maybe_auto_checkpoint(&backlog_dir);
         maybe_verbose_payload(
@@ -5757,7 +7112,11 @@ impl SetFieldTool {
             update_task_field(path, "updated_date", Some(now_timestamp().into()))
                 .map_err(CallToolError::new)?;
         }
+        if self.field == "title" {
+            rename_task_file_for_title(path, &task.title).map_err(CallToolError::new)?;
+        }
         audit_event(
+            context,
             &backlog_dir,
             "set_field",
             Some(&task.id),
@@ -5815,7 +7174,11 @@ impl AddDependencyTool {
             context,
             self.root.as_deref(),
             &self.task_id,
-            "dependencies",
+            if self.soft {
+                SOFT_DEPENDENCIES_FIELD
+            } else {
+                "dependencies"
+            },
             &self.dependency,
             true,
             self.touch,
@@ -5830,7 +7193,11 @@ impl RemoveDependencyTool {
             context,
             self.root.as_deref(),
             &self.task_id,
-            "dependencies",
+            if self.soft {
+                SOFT_DEPENDENCIES_FIELD
+            } else {
+                "dependencies"
+            },
             &self.dependency,
             false,
             self.touch,
@@ -5870,6 +7237,7 @@ impl BulkSetStatusTool {
                     .map_err(CallToolError::new)?;
             }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_set_status",
                 Some(&task.id),
@@ -5917,7 +7285,11 @@ impl BulkSetFieldTool {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
+            if self.field == "title" {
+                rename_task_file_for_title(path, &task.title).map_err(CallToolError::new)?;
+            }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_set_field",
                 Some(&task.id),
@@ -5951,16 +7323,13 @@ impl BulkAddLabelTool {
                 .file_path
                 .as_ref()
                 .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-            let mut current = task.labels.clone();
-            if !current.contains(&self.label) {
-                current.push(self.label.clone());
-            }
-            set_list_field(path, "labels", current).map_err(CallToolError::new)?;
+            edit_list_field(path, "labels", &self.label, true).map_err(CallToolError::new)?;
             if self.touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_label_add",
                 Some(&task.id),
@@ -5994,14 +7363,13 @@ impl BulkRemoveLabelTool {
                 .file_path
                 .as_ref()
                 .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-            let mut current = task.labels.clone();
-            current.retain(|entry| entry != &self.label);
-            set_list_field(path, "labels", current).map_err(CallToolError::new)?;
+            edit_list_field(path, "labels", &self.label, false).map_err(CallToolError::new)?;
             if self.touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_label_remove",
                 Some(&task.id),
@@ -6035,16 +7403,14 @@ impl BulkAddDependencyTool {
                 .file_path
                 .as_ref()
                 .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-            let mut current = task.dependencies.clone();
-            if !current.contains(&self.dependency) {
-                current.push(self.dependency.clone());
-            }
-            set_list_field(path, "dependencies", current).map_err(CallToolError::new)?;
+            edit_list_field(path, "dependencies", &self.dependency, true)
+                .map_err(CallToolError::new)?;
             if self.touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_dependency_add",
                 Some(&task.id),
@@ -6078,14 +7444,14 @@ impl BulkRemoveDependencyTool {
                 .file_path
                 .as_ref()
                 .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-            let mut current = task.dependencies.clone();
-            current.retain(|entry| entry != &self.dependency);
-            set_list_field(path, "dependencies", current).map_err(CallToolError::new)?;
+            edit_list_field(path, "dependencies", &self.dependency, false)
+                .map_err(CallToolError::new)?;
             if self.touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
             audit_event(
+                context,
                 &backlog_dir,
                 "bulk_dependency_remove",
                 Some(&task.id),
@@ -6113,24 +7479,24 @@ impl BulkAddNoteTool {
             Err(err) => return ok_json(err),
         };
         let (selected, missing) = select_tasks_with_missing(&tasks, &ids);
+        let (details, _) = note_audit_details(&backlog_dir, &tasks, &self.section, &self.note);
         let mut updated = Vec::new();
         for task in selected {
             let path = task
                 .file_path
                 .as_ref()
                 .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-            let new_body = append_note(&task.body, &self.note, &self.section);
-            update_body(path, &new_body).map_err(CallToolError::new)?;
+            append_task_note(path, &self.note, &self.section).map_err(CallToolError::new)?;
             if self.touch {
                 update_task_field(path, "updated_date", Some(now_timestamp().into()))
                     .map_err(CallToolError::new)?;
             }
-            audit_event(
-                &backlog_dir,
-                "bulk_note",
-                Some(&task.id),
-                serde_json::json!({ "section": self.section.clone(), "note": self.note.clone() }),
-            )?;
+            let linked = autolink_note(&backlog_dir, &tasks, task, &self.note)?;
+            let mut details = details.clone();
+            if !linked.is_empty() {
+                details["relates_to"] = serde_json::json!(linked);
+            }
+            audit_event(context, &backlog_dir, "bulk_note", Some(&task.id), details)?;
             updated.push(task.id.clone());
         }
         refresh_index_best_effort(&backlog_dir);
@@ -6161,6 +7527,7 @@ impl ArchiveTool {
         .map_err(CallToolError::new)?;
         refresh_index_best_effort(&backlog_dir);
         maybe_auto_checkpoint(&backlog_dir);
+        let cleanup = delete_archived_objects(&backlog_dir, &result.archived);
         let status_filter = if statuses.is_empty() {
             workmesh_core::archive::default_archive_statuses()
                 .iter()
@@ -6176,14 +7543,181 @@ impl ArchiveTool {
                 "archived_count": result.archived.len(),
                 "skipped_count": result.skipped.len(),
                 "archive_dir": result.archive_dir,
-                "status_filter": status_filter
+                "status_filter": status_filter,
+                "attachments_deleted_count": cleanup.deleted.len(),
+                "warnings": cleanup.warnings
             }),
             serde_json::json!({
                 "archived": result.archived,
                 "skipped": result.skipped,
                 "archive_dir": result.archive_dir,
-                "status_filter": status_filter
+                "status_filter": status_filter,
+                "attachments_deleted": cleanup.deleted,
+                "warnings": cleanup.warnings
+            }),
+        )
+    }
+}
+
+impl RemoveTaskTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let mode = if self.cancel {
+            RemoveMode::Cancel
+        } else {
+            RemoveMode::Trash
+        };
+        let tasks = load_tasks_with_archive(&backlog_dir);
+        let report = match remove_task(&backlog_dir, &tasks, &self.task_id, mode, !self.dry_run) {
+            Ok(report) => report,
+            Err(err @ (RemoveError::NotFound(_) | RemoveError::AlreadyTrashed(..))) => {
+                return ok_json(serde_json::json!({"error": err.to_string()}))
+            }
+            Err(err) => return Err(CallToolError::new(err)),
+        };
+        if report.applied {
+            for edit in &report.stripped {
+                audit_event(
+                    context,
+                    &backlog_dir,
+                    "dependency_remove",
+                    Some(&edit.task_id),
+                    serde_json::json!({
+                        "field": edit.field,
+                        "value": report.task_id,
+                        "add": false,
+                        "reason": "task_remove",
+                    }),
+                )?;
+            }
+            audit_event(
+                context,
+                &backlog_dir,
+                "task_remove",
+                Some(&report.task_id),
+                serde_json::json!({
+                    "mode": mode.as_str(),
+                    "path": report.path,
+                    "trash_path": report.trash_path,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+        maybe_verbose_payload(
+            self.verbose,
+            serde_json::json!({
+                "ok": true,
+                "task_id": report.task_id,
+                "mode": mode.as_str(),
+                "applied": report.applied,
+                "stripped_count": report.stripped.len(),
+            }),
+            serde_json::to_value(&report).map_err(CallToolError::new)?,
+        )
+    }
+}
+
+impl MoveTaskTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let options = MoveTaskOptions {
+            apply: !self.dry_run,
+            rewrite_body: self.body,
+        };
+        let report = match move_task(&backlog_dir, &self.old_id, &self.new_id, options) {
+            Ok(report) => report,
+            Err(err) => return ok_json(serde_json::json!({"error": err.to_string()})),
+        };
+        if report.apply {
+            audit_event(
+                context,
+                &backlog_dir,
+                "task_move",
+                Some(&report.new_id),
+                serde_json::json!({
+                    "from": report.old_id,
+                    "to": report.new_id,
+                    "path": report.new_path.as_ref().unwrap_or(&report.path),
+                    "touched": report.touched.len(),
+                    "body": report.rewrite_body,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+        maybe_verbose_payload(
+            self.verbose,
+            serde_json::json!({
+                "ok": true,
+                "old_id": report.old_id,
+                "new_id": report.new_id,
+                "applied": report.apply,
+                "touched_count": report.touched.len(),
+            }),
+            serde_json::to_value(&report).map_err(CallToolError::new)?,
+        )
+    }
+}
+
+impl SplitTaskTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+        let initiative = ensure_branch_initiative(&repo_root, &branch)
+            .map_err(|e| CallToolError::from_message(e.to_string()))?;
+        let tasks = load_tasks_with_archive(&backlog_dir);
+        let report = match split_task(&backlog_dir, &tasks, &self.task_id, &initiative, &self.into)
+        {
+            Ok(report) => report,
+            Err(err @ (SplitError::Task(_) | SplitError::Io(_))) => {
+                return Err(CallToolError::new(err))
+            }
+            Err(err) => return ok_json(serde_json::json!({"error": err.to_string()})),
+        };
+        for part in &report.parts {
+            audit_event(
+                context,
+                &backlog_dir,
+                "add_task",
+                Some(&part.id),
+                serde_json::json!({
+                    "title": part.title,
+                    "status": "To Do",
+                    "split_from": report.task_id,
+                }),
+            )?;
+        }
+        audit_event(
+            context,
+            &backlog_dir,
+            "task_split",
+            Some(&report.task_id),
+            serde_json::json!({
+                "parts": report.parts.iter().map(|part| &part.id).collect::<Vec<_>>(),
+                "previous_kind": report.previous_kind,
+            }),
+        )?;
+        refresh_index_best_effort(&backlog_dir);
+        maybe_auto_checkpoint(&backlog_dir);
+        maybe_verbose_payload(
+            self.verbose,
+            serde_json::json!({
+                "ok": true,
+                "task_id": report.task_id,
+                "parts": report.parts.iter().map(|part| &part.id).collect::<Vec<_>>(),
             }),
+            serde_json::to_value(&report).map_err(CallToolError::new)?,
         )
     }
 }
@@ -6276,34 +7810,13 @@ impl ClaimTaskTool {
                 serde_json::json!({"error": format!("Task not found: {}", self.task_id)}),
             );
         };
-        let path = task
-            .file_path
-            .as_ref()
-            .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-        let mut assignee = task.assignee.clone();
-        if !assignee.iter().any(|value| value == &self.owner) {
-            assignee.push(self.owner.clone());
-            set_list_field(path, "assignee", assignee).map_err(CallToolError::new)?;
-        }
-        let expires_at = self.minutes.map(timestamp_plus_minutes);
-        let lease = Lease {
-            owner: self.owner.clone(),
-            acquired_at: Some(now_timestamp()),
-            expires_at,
-        };
-        update_lease_fields(path, Some(&lease)).map_err(CallToolError::new)?;
-        if self.touch {
-            update_task_field(path, "updated_date", Some(now_timestamp().into()))
-                .map_err(CallToolError::new)?;
-        }
-        audit_event(
+        let lease = claim_lease(
+            context,
             &backlog_dir,
-            "claim",
-            Some(&task.id),
-            serde_json::json!({
-                "owner": lease.owner.clone(),
-                "expires_at": lease.expires_at.clone(),
-            }),
+            task,
+            &self.owner,
+            self.minutes,
+            self.touch,
         )?;
         refresh_index_best_effort(&backlog_dir);
         maybe_auto_checkpoint(&backlog_dir);
@@ -6320,6 +7833,47 @@ impl ClaimTaskTool {
     }
 }
 
+/// Lease `task` to `owner` (adding them as an assignee) and record a `claim` audit event.
+fn claim_lease(
+    context: &McpContext,
+    backlog_dir: &Path,
+    task: &Task,
+    owner: &str,
+    minutes: Option<i64>,
+    touch: bool,
+) -> Result<Lease, CallToolError> {
+    let path = task
+        .file_path
+        .as_ref()
+        .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
+    let mut assignee = task.assignee.clone();
+    if !assignee.iter().any(|value| value == owner) {
+        assignee.push(owner.to_string());
+        set_list_field(path, "assignee", assignee).map_err(CallToolError::new)?;
+    }
+    let lease = Lease {
+        owner: owner.to_string(),
+        acquired_at: Some(now_timestamp()),
+        expires_at: minutes.map(timestamp_plus_minutes),
+    };
+    update_lease_fields(path, Some(&lease)).map_err(CallToolError::new)?;
+    if touch {
+        update_task_field(path, "updated_date", Some(now_timestamp().into()))
+            .map_err(CallToolError::new)?;
+    }
+    audit_event(
+        context,
+        backlog_dir,
+        "claim",
+        Some(&task.id),
+        serde_json::json!({
+            "owner": lease.owner.clone(),
+            "expires_at": lease.expires_at.clone(),
+        }),
+    )?;
+    Ok(lease)
+}
+
 impl ReleaseTaskTool {
     fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let backlog_dir = match resolve_root(context, self.root.as_deref()) {
@@ -6343,6 +7897,7 @@ impl ReleaseTaskTool {
                 .map_err(CallToolError::new)?;
         }
         audit_event(
+            context,
             &backlog_dir,
             "release",
             Some(&task.id),
@@ -6358,53 +7913,149 @@ impl ReleaseTaskTool {
     }
 }
 
-impl AddNoteTool {
+fn freeze_target(
+    epic: Option<&str>,
+    phase: Option<&str>,
+) -> Result<(FreezeKind, String), serde_json::Value> {
+    match (epic, phase) {
+        (Some(epic), _) => Ok((FreezeKind::Epic, epic.to_string())),
+        (None, Some(phase)) => Ok((FreezeKind::Phase, phase.to_string())),
+        (None, None) => Err(serde_json::json!({"error": "Pass epic or phase"})),
+    }
+}
+
+impl FreezeTool {
     fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let backlog_dir = match resolve_root(context, self.root.as_deref()) {
             Ok(dir) => dir,
             Err(err) => return ok_json(err),
         };
-        let tasks = load_tasks(&backlog_dir);
-        let task = find_task(&tasks, &self.task_id);
-        let Some(task) = task else {
-            return ok_json(
-                serde_json::json!({"error": format!("Task not found: {}", self.task_id)}),
-            );
+        let (kind, scope) = match freeze_target(self.epic.as_deref(), self.phase.as_deref()) {
+            Ok(target) => target,
+            Err(err) => return ok_json(err),
         };
-        let path = task
-            .file_path
-            .as_ref()
-            .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-        let section_key = if self.section == "notes" {
-            "notes"
-        } else {
-            "impl"
+        let tasks = load_tasks(&backlog_dir);
+        let scope = match kind {
+            FreezeKind::Epic => match find_task(&tasks, &scope) {
+                Some(task) => task.id.clone(),
+                None => {
+                    return ok_json(
+                        serde_json::json!({"error": format!("Task not found: {}", scope)}),
+                    )
+                }
+            },
+            FreezeKind::Phase => scope,
         };
-        let new_body = append_note(&task.body, &self.note, section_key);
-        update_body(path, &new_body).map_err(CallToolError::new)?;
-        if self.touch {
-            update_task_field(path, "updated_date", Some(now_timestamp().into()))
-                .map_err(CallToolError::new)?;
-        }
+        let freeze = freeze_scope(
+            &backlog_dir,
+            kind,
+            &scope,
+            self.reason.as_deref(),
+            context.identity.as_deref(),
+        )
+        .map_err(CallToolError::new)?;
+        let count = FrozenSet::load(&backlog_dir, &tasks).count_for(&freeze);
         audit_event(
+            context,
             &backlog_dir,
-            "note",
-            Some(&task.id),
-            serde_json::json!({ "section": self.section.clone(), "note": self.note.clone() }),
-        )?;
-        refresh_index_best_effort(&backlog_dir);
-        maybe_auto_checkpoint(&backlog_dir);
-        maybe_verbose_payload(
-            self.verbose,
-            serde_json::json!({"ok": true, "id": task.id, "section": self.section}),
+            "freeze",
+            None,
             serde_json::json!({
-                "ok": true,
-                "id": task.id,
-                "section": self.section,
-                "task": refreshed_task_value(&backlog_dir, &task.id)
+                "kind": kind.as_str(),
+                "scope": freeze.scope,
+                "reason": freeze.reason,
+                "tasks": count,
             }),
-        )
-    }
+        )?;
+        maybe_auto_checkpoint(&backlog_dir);
+        let mut payload = serde_json::to_value(&freeze).unwrap_or_default();
+        payload["tasks"] = serde_json::json!(count);
+        ok_json(payload)
+    }
+}
+
+impl UnfreezeTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let (kind, scope) = match freeze_target(self.epic.as_deref(), self.phase.as_deref()) {
+            Ok(target) => target,
+            Err(err) => return ok_json(err),
+        };
+        let lifted = unfreeze_scope(&backlog_dir, kind, &scope).map_err(CallToolError::new)?;
+        if let Some(freeze) = lifted.as_ref() {
+            audit_event(
+                context,
+                &backlog_dir,
+                "unfreeze",
+                None,
+                serde_json::json!({ "kind": kind.as_str(), "scope": freeze.scope }),
+            )?;
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+        ok_json(serde_json::json!({
+            "kind": kind.as_str(),
+            "scope": scope,
+            "lifted": lifted,
+        }))
+    }
+}
+
+impl AddNoteTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let task = find_task(&tasks, &self.task_id);
+        let Some(task) = task else {
+            return ok_json(
+                serde_json::json!({"error": format!("Task not found: {}", self.task_id)}),
+            );
+        };
+        let path = task
+            .file_path
+            .as_ref()
+            .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
+        let section_key = if self.section == "notes" {
+            "notes"
+        } else {
+            "impl"
+        };
+        append_task_note(path, &self.note, section_key).map_err(CallToolError::new)?;
+        if self.touch {
+            update_task_field(path, "updated_date", Some(now_timestamp().into()))
+                .map_err(CallToolError::new)?;
+        }
+        let linked = autolink_note(&backlog_dir, &tasks, task, &self.note)?;
+        let (mut details, unknown_mentions) =
+            note_audit_details(&backlog_dir, &tasks, &self.section, &self.note);
+        if !linked.is_empty() {
+            details["relates_to"] = serde_json::json!(linked);
+        }
+        audit_event(context, &backlog_dir, "note", Some(&task.id), details)?;
+        refresh_index_best_effort(&backlog_dir);
+        maybe_auto_checkpoint(&backlog_dir);
+        let mut minimal = serde_json::json!({"ok": true, "id": task.id, "section": self.section});
+        let mut detailed = serde_json::json!({
+            "ok": true,
+            "id": task.id,
+            "section": self.section,
+            "task": refreshed_task_value(&backlog_dir, &task.id)
+        });
+        if !unknown_mentions.is_empty() {
+            minimal["unknown_mentions"] = serde_json::json!(unknown_mentions);
+            detailed["unknown_mentions"] = serde_json::json!(unknown_mentions);
+        }
+        if !linked.is_empty() {
+            minimal["relates_to"] = serde_json::json!(linked);
+            detailed["relates_to"] = serde_json::json!(linked);
+        }
+        maybe_verbose_payload(self.verbose, minimal, detailed)
+    }
 }
 
 impl SetBodyTool {
@@ -6430,6 +8081,7 @@ impl SetBodyTool {
                 .map_err(CallToolError::new)?;
         }
         audit_event(
+            context,
             &backlog_dir,
             "set_body",
             Some(&task.id),
@@ -6469,6 +8121,7 @@ impl SetSectionTool {
                 .map_err(CallToolError::new)?;
         }
         audit_event(
+            context,
             &backlog_dir,
             "set_section",
             Some(&task.id),
@@ -6504,7 +8157,8 @@ impl AddTaskTool {
                 let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
                 let initiative = ensure_branch_initiative(&repo_root, &branch)
                     .map_err(|e| CallToolError::from_message(e.to_string()))?;
-                next_namespaced_task_id(&tasks, &initiative)
+                reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)
+                    .map_err(|e| CallToolError::from_message(e.to_string()))?
             }
         };
         let labels = parse_list_input(self.labels.clone());
@@ -6533,6 +8187,7 @@ impl AddTaskTool {
         )
         .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "add_task",
             Some(&task_id),
@@ -6589,7 +8244,8 @@ impl AddDiscoveredTool {
                 let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
                 let initiative = ensure_branch_initiative(&repo_root, &branch)
                     .map_err(|e| CallToolError::from_message(e.to_string()))?;
-                next_namespaced_task_id(&tasks, &initiative)
+                reserve_namespaced_task_id(&backlog_dir, &tasks, &initiative)
+                    .map_err(|e| CallToolError::from_message(e.to_string()))?
             }
         };
         let labels = parse_list_input(self.labels.clone());
@@ -6624,6 +8280,7 @@ impl AddDiscoveredTool {
         )
         .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "add_discovered",
             Some(&task_id),
@@ -6662,6 +8319,7 @@ impl ProjectInitTool {
         let path = ensure_project_docs(&repo_root, &self.project_id, self.name.as_deref())
             .map_err(CallToolError::new)?;
         audit_event(
+            context,
             &backlog_dir,
             "project_init",
             None,
@@ -6784,6 +8442,7 @@ impl FixIdsTool {
 
         if self.apply {
             audit_event(
+                context,
                 &backlog_dir,
                 "fix_ids",
                 None,
@@ -6819,6 +8478,7 @@ impl FixFilenamesTool {
 
         if self.apply {
             audit_event(
+                context,
                 &backlog_dir,
                 "fix_filenames",
                 None,
@@ -6840,6 +8500,195 @@ impl FixFilenamesTool {
     }
 }
 
+impl LintTasksTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let ids = normalize_task_ids(parse_list_input(self.tasks.clone()));
+        let (selected, missing): (Vec<Task>, Vec<String>) = if ids.is_empty() {
+            (tasks.clone(), Vec::new())
+        } else {
+            let (selected, missing) = select_tasks_with_missing(&tasks, &ids);
+            (selected.into_iter().cloned().collect(), missing)
+        };
+        let defaults = LintOptions::default();
+        let options = LintOptions {
+            max_block_lines: self
+                .max_block_lines
+                .map(|value| value as usize)
+                .unwrap_or(defaults.max_block_lines),
+            max_line_chars: self
+                .max_line_chars
+                .map(|value| value as usize)
+                .unwrap_or(defaults.max_line_chars),
+        };
+        let report = lint_tasks(&selected, &options, self.redact).map_err(CallToolError::new)?;
+
+        if !report.redacted.is_empty() {
+            audit_event(
+                context,
+                &backlog_dir,
+                "lint_redact",
+                None,
+                serde_json::json!({ "tasks": report.redacted }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+
+        ok_json(serde_json::json!({
+            "ok": report.findings.is_empty() || (self.redact && report.warnings.is_empty()),
+            "redact": self.redact,
+            "scanned": report.scanned,
+            "findings": report.findings,
+            "redacted": report.redacted,
+            "missing": missing,
+            "warnings": report.warnings,
+        }))
+    }
+}
+
+impl ChangesSinceTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let ids = normalize_task_ids(parse_list_input(self.tasks.clone()));
+        match changes_since(&backlog_dir, self.cursor.as_deref(), &ids) {
+            Ok(feed) => ok_json(serde_json::to_value(feed).unwrap_or_default()),
+            Err(err) => ok_json(serde_json::json!({"ok": false, "error": err.to_string()})),
+        }
+    }
+}
+
+impl RemindersTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let today = Local::now().date_naive();
+        let within_days = self.within_days.unwrap_or(DEFAULT_DUE_SOON_DAYS);
+        let reminders = if self.all {
+            due_reminders(&tasks, None, today, within_days)
+        } else {
+            context_due_reminders(&backlog_dir, &tasks, today, within_days)
+        };
+        ok_json(serde_json::json!({ "reminders": reminders }))
+    }
+}
+
+impl ToposortTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let report = toposort_report(&tasks, self.epic_id.as_deref());
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl LintTitlesTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let ids = normalize_task_ids(parse_list_input(self.tasks.clone()));
+        let (selected, missing): (Vec<Task>, Vec<String>) = if ids.is_empty() {
+            (tasks.clone(), Vec::new())
+        } else {
+            let (selected, missing) = select_tasks_with_missing(&tasks, &ids);
+            (selected.into_iter().cloned().collect(), missing)
+        };
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let rules = TitleLintRules::from_config(&resolve_title_lint_config(&repo_root))
+            .map_err(CallToolError::new)?;
+        let report = lint_titles(&selected, &rules, self.fix).map_err(CallToolError::new)?;
+
+        if !report.fixed.is_empty() {
+            audit_event(
+                context,
+                &backlog_dir,
+                "lint_fix_titles",
+                None,
+                serde_json::json!({ "tasks": report.fixed }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+
+        ok_json(serde_json::json!({
+            "ok": report.findings.is_empty() || (self.fix && report.unresolved == 0),
+            "fix": self.fix,
+            "scanned": report.scanned,
+            "findings": report.findings,
+            "fixed": report.fixed,
+            "unresolved": report.unresolved,
+            "missing": missing,
+            "warnings": report.warnings,
+        }))
+    }
+}
+
+impl AutotagTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let ids = normalize_task_ids(parse_list_input(self.tasks.clone()));
+        let (selected, missing): (Vec<Task>, Vec<String>) = if ids.is_empty() {
+            (tasks.clone(), Vec::new())
+        } else {
+            let (selected, missing) = select_tasks_with_missing(&tasks, &ids);
+            (selected.into_iter().cloned().collect(), missing)
+        };
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let rules =
+            compile_rules(&resolve_autotag_config(&repo_root)).map_err(CallToolError::new)?;
+        let report = autotag_tasks(&selected, &rules, self.apply);
+
+        if !report.applied.is_empty() {
+            let labels: serde_json::Map<String, serde_json::Value> = report
+                .proposals
+                .iter()
+                .filter(|proposal| report.applied.contains(&proposal.task_id))
+                .map(|proposal| (proposal.task_id.clone(), serde_json::json!(proposal.labels)))
+                .collect();
+            audit_event(
+                context,
+                &backlog_dir,
+                "autotag",
+                None,
+                serde_json::json!({ "labels": labels }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+
+        ok_json(serde_json::json!({
+            "ok": report.warnings.is_empty(),
+            "apply": self.apply,
+            "rules": rules.len(),
+            "scanned": report.scanned,
+            "proposals": report.proposals,
+            "applied": report.applied,
+            "missing": missing,
+            "warnings": report.warnings,
+        }))
+    }
+}
+
 impl RekeyPromptTool {
     fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let backlog_dir = match resolve_root(context, self.root.as_deref()) {
@@ -6882,6 +8731,7 @@ impl RekeyApplyTool {
 
         if self.apply {
             audit_event(
+                context,
                 &backlog_dir,
                 "rekey_apply",
                 None,
@@ -6902,6 +8752,22 @@ impl GraphExportTool {
             Err(err) => return ok_json(err),
         };
         let tasks = load_tasks(&backlog_dir);
+        let format = self
+            .format
+            .as_deref()
+            .unwrap_or("json")
+            .trim()
+            .to_lowercase();
+        match format.as_str() {
+            "json" => {}
+            "mermaid" => return ok_text(mermaid_graph(&tasks)),
+            "dot" => return ok_text(dot_graph(&tasks)),
+            other => {
+                return ok_json(serde_json::json!({
+                    "error": format!("Unknown format: {} (expected json, mermaid or dot)", other),
+                }))
+            }
+        }
         let graph = graph_export(&tasks);
         if self.pretty {
             ok_text(serde_json::to_string_pretty(&graph).unwrap_or_else(|_| "{}".to_string()))
@@ -6966,7 +8832,10 @@ impl CheckpointTool {
         let options = CheckpointOptions {
             project_id: self.project.clone(),
             checkpoint_id: self.id.clone(),
-            audit_limit: self.audit_limit.unwrap_or(20) as usize,
+            include: parse_list_input(self.include.clone()),
+            exclude: parse_list_input(self.exclude.clone()),
+            template: self.template.clone(),
+            ..CheckpointOptions::new(self.audit_limit.unwrap_or(20) as usize)
         };
         let result =
             write_checkpoint(&backlog_dir, &tasks, &options).map_err(CallToolError::new)?;
@@ -6993,9 +8862,12 @@ impl ResumeTool {
         let project_id = resolve_project_id(&repo_root, &tasks, self.project.as_deref());
         let summary = resume_summary(&repo_root, &project_id, self.id.as_deref())
             .map_err(CallToolError::new)?;
-        let Some(summary) = summary else {
+        let Some(mut summary) = summary else {
             return ok_text("No checkpoint found".to_string());
         };
+        let today = Local::now().date_naive();
+        summary.snapshot.due =
+            context_due_reminders(&backlog_dir, &tasks, today, DEFAULT_DUE_SOON_DAYS);
         if self.format == "text" {
             return ok_text(render_resume(&summary));
         }
@@ -7072,8 +8944,50 @@ impl CheckpointDiffTool {
     }
 }
 
+impl GraphDiffTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let project_id = resolve_project_id(&repo_root, &tasks, self.project.as_deref());
+        let mut sides = Vec::new();
+        for id in [Some(self.from.as_str()), self.to.as_deref()]
+            .into_iter()
+            .flatten()
+        {
+            let Some((snapshot, _)) =
+                load_checkpoint(&repo_root, &project_id, Some(id)).map_err(CallToolError::new)?
+            else {
+                return ok_json(
+                    serde_json::json!({"error": format!("Checkpoint not found: {}", id)}),
+                );
+            };
+            let Some(edges) = snapshot.edges else {
+                return ok_json(serde_json::json!({
+                    "error": format!(
+                        "Checkpoint {} has no dependency edges (written before graph-diff support)",
+                        snapshot.checkpoint_id
+                    )
+                }));
+            };
+            sides.push((snapshot.checkpoint_id, edges));
+        }
+        if sides.len() == 1 {
+            sides.push(("current".to_string(), dependency_edges(&tasks)));
+        }
+        let report = graph_diff(&sides[0].0, &sides[0].1, &sides[1].0, &sides[1].1);
+        if self.format == "text" {
+            return ok_text(render_graph_diff(&report));
+        }
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
 impl SessionSaveTool {
-    fn call(&self, _context: &McpContext) -> Result<CallToolResult, CallToolError> {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let home =
             resolve_workmesh_home().map_err(|err| CallToolError::from_message(err.to_string()))?;
 
@@ -7191,6 +9105,7 @@ impl SessionSaveTool {
             recent_changes,
             handoff: None,
             truth_refs,
+            client: context.client.as_ref().map(McpClientIdentity::label),
         };
 
         append_session_saved(&home, session.clone())
@@ -7307,13 +9222,20 @@ impl SessionResumeTool {
             .find(|s| s.id == id)
             .ok_or_else(|| CallToolError::from_message("Session not found"))?;
         let script = resume_script(&session);
+        let due = session_due_reminders(&session, Local::now().date_naive(), DEFAULT_DUE_SOON_DAYS);
         if self.format == "text" {
             let mut body = render_session_detail(&session);
+            if !due.is_empty() {
+                body.push_str("\n\nDue soon / overdue:");
+                for item in &due {
+                    body.push_str(&format!("\n- {}", item.line()));
+                }
+            }
             body.push_str("\n\nSuggested resume:\n");
             body.push_str(&script.join("\n"));
             return ok_text(body);
         }
-        ok_json(serde_json::json!({ "session": session, "resume_script": script }))
+        ok_json(serde_json::json!({ "session": session, "resume_script": script, "due": due }))
     }
 }
 
@@ -7345,8 +9267,16 @@ impl GanttTextTool {
             Err(err) => return ok_json(err),
         };
         let tasks = load_tasks(&backlog_dir);
-        let text = plantuml_gantt(&tasks, self.start.as_deref(), None, self.zoom, None, true);
-        ok_text(text)
+        let text = gantt_text(
+            &tasks,
+            self.start.as_deref(),
+            self.zoom,
+            self.format.as_deref(),
+        );
+        match text {
+            Ok(text) => ok_text(text),
+            Err(err) => ok_json(err),
+        }
     }
 }
 
@@ -7357,12 +9287,36 @@ impl GanttFileTool {
             Err(err) => return ok_json(err),
         };
         let tasks = load_tasks(&backlog_dir);
-        let text = plantuml_gantt(&tasks, self.start.as_deref(), None, self.zoom, None, true);
+        let text = gantt_text(
+            &tasks,
+            self.start.as_deref(),
+            self.zoom,
+            self.format.as_deref(),
+        );
+        let text = match text {
+            Ok(text) => text,
+            Err(err) => return ok_json(err),
+        };
         let path = write_text_file(Path::new(&self.output), &text).map_err(CallToolError::new)?;
         ok_json(serde_json::json!({"ok": true, "path": path}))
     }
 }
 
+fn gantt_text(
+    tasks: &[Task],
+    start: Option<&str>,
+    zoom: i32,
+    format: Option<&str>,
+) -> Result<String, serde_json::Value> {
+    match format.unwrap_or("plantuml").trim().to_lowercase().as_str() {
+        "plantuml" => Ok(plantuml_gantt(tasks, start, None, zoom, None, true)),
+        "mermaid" => Ok(mermaid_gantt(tasks, start, None, None, true)),
+        other => Err(serde_json::json!({
+            "error": format!("Unknown format: {} (expected plantuml or mermaid)", other),
+        })),
+    }
+}
+
 impl GanttSvgTool {
     fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
         let backlog_dir = match resolve_root(context, self.root.as_deref()) {
@@ -7909,20 +9863,8 @@ fn update_list_field(
         .file_path
         .as_ref()
         .ok_or_else(|| CallToolError::from_message("Missing task path"))?;
-    let mut current = match field {
-        "labels" => task.labels.clone(),
-        "dependencies" => task.dependencies.clone(),
-        _ => Vec::new(),
-    };
     let value = value.trim();
-    if add {
-        if !current.contains(&value.to_string()) {
-            current.push(value.to_string());
-        }
-    } else {
-        current.retain(|entry| entry != value);
-    }
-    set_list_field(path, field, current.clone()).map_err(CallToolError::new)?;
+    let current = edit_list_field(path, field, value, add).map_err(CallToolError::new)?;
     if touch {
         update_task_field(path, "updated_date", Some(now_timestamp().into()))
             .map_err(CallToolError::new)?;
@@ -7932,9 +9874,12 @@ fn update_list_field(
         ("labels", false) => "label_remove",
         ("dependencies", true) => "dependency_add",
         ("dependencies", false) => "dependency_remove",
+        (SOFT_DEPENDENCIES_FIELD, true) => "soft_dependency_add",
+        (SOFT_DEPENDENCIES_FIELD, false) => "soft_dependency_remove",
         _ => "update_list",
     };
     audit_event(
+        context,
         &backlog_dir,
         action,
         Some(&task.id),
@@ -8054,17 +9999,10 @@ fn parse_boolish(value: &str) -> Option<bool> {
 }
 
 fn maybe_auto_checkpoint(backlog_dir: &Path) {
-    let tasks = load_tasks(backlog_dir);
-    if auto_checkpoint_enabled() {
-        let options = CheckpointOptions {
-            project_id: None,
-            checkpoint_id: None,
-            audit_limit: 10,
-        };
-        let _ = write_checkpoint(backlog_dir, &tasks, &options);
-    }
+    let _ = checkpoint_triggers::after_mutation(backlog_dir, auto_checkpoint_enabled());
 
     if auto_session_enabled(backlog_dir) {
+        let tasks = load_tasks(backlog_dir);
         let _ = auto_update_current_session(backlog_dir, &tasks);
     }
 }
@@ -8137,6 +10075,7 @@ fn auto_update_current_session(backlog_dir: &Path, tasks: &[Task]) -> Result<(),
         handoff: existing.handoff.clone(),
         worktree,
         truth_refs,
+        client: existing.client.clone(),
     };
 
     append_session_saved(&home, updated.clone())?;
@@ -8144,18 +10083,655 @@ fn auto_update_current_session(backlog_dir: &Path, tasks: &[Task]) -> Result<(),
     Ok(())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl MergeBacklogTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let report = merge_backlog(
+            &backlog_dir,
+            Path::new(self.from.trim()),
+            &MergeBacklogOptions {
+                prefix: self.prefix.clone(),
+                apply: self.apply,
+            },
+        )
+        .map_err(CallToolError::new)?;
 
-    use std::ffi::OsString;
-    use std::sync::{Mutex, OnceLock};
-    use tempfile::TempDir;
+        if self.apply {
+            audit_event(
+                context,
+                &backlog_dir,
+                "merge_backlog",
+                None,
+                serde_json::json!({
+                    "from": report.source_state_root,
+                    "imported": report.tasks.len(),
+                    "rekeyed": report.mapping.len(),
+                    "audit_events": report.audit_events,
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
 
-    fn with_env_lock<T>(f: impl FnOnce() -> T) -> T {
-        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
-        let lock = LOCK.get_or_init(|| Mutex::new(()));
-        let _guard = lock.lock().expect("env lock");
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl ExtractTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let report = extract_backlog(
+            &backlog_dir,
+            &ExtractOptions {
+                epic: self.epic.clone(),
+                label: self.label.clone(),
+                dest: PathBuf::from(self.dest.trim()),
+                apply: self.apply,
+            },
+        )
+        .map_err(CallToolError::new)?;
+
+        if self.apply {
+            audit_event(
+                context,
+                &backlog_dir,
+                "extract",
+                None,
+                serde_json::json!({
+                    "selector": report.selector,
+                    "dest": report.dest_state_root,
+                    "tasks": report.tasks.iter().map(|t| t.id.clone()).collect::<Vec<_>>(),
+                }),
+            )?;
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl InboxTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let Some(user) = self
+            .user
+            .clone()
+            .or_else(|| context.identity.clone())
+            .filter(|value| !value.trim().is_empty())
+        else {
+            return ok_json(serde_json::json!({"error": "user is required (no server identity)"}));
+        };
+        if self.mark_all_read {
+            mark_inbox_all_read(&backlog_dir, &user).map_err(CallToolError::new)?;
+        } else if !self.mark_read.is_empty() {
+            mark_inbox_read(&backlog_dir, &user, &self.mark_read).map_err(CallToolError::new)?;
+        }
+        let tasks = load_tasks_with_archive(&backlog_dir);
+        let mut items = build_inbox(&backlog_dir, &user, &tasks);
+        let unread_count = items.iter().filter(|item| !item.read).count();
+        if self.unread {
+            items.retain(|item| !item.read);
+        }
+        if let Some(limit) = self.limit {
+            items.truncate(limit as usize);
+        }
+        ok_json(serde_json::json!({
+            "user": normalize_user(&user),
+            "unread": unread_count,
+            "items": items,
+        }))
+    }
+}
+
+impl MentionsTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let Some(user) = self
+            .user
+            .clone()
+            .or_else(|| context.identity.clone())
+            .filter(|value| !value.trim().is_empty())
+        else {
+            return ok_json(serde_json::json!({"error": "user is required (no server identity)"}));
+        };
+        let days = self.days.unwrap_or(30);
+        let since = (days > 0).then(|| Local::now().naive_local() - Duration::days(days as i64));
+        let tasks = load_tasks_with_archive(&backlog_dir);
+        let mut hits = mentions_for(&backlog_dir, &user, &tasks, since);
+        if let Some(limit) = self.limit {
+            hits.truncate(limit as usize);
+        }
+        ok_json(serde_json::json!({
+            "user": normalize_user(&user),
+            "mentions": hits,
+        }))
+    }
+}
+
+impl PoliciesRunTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let config = resolve_policies(&repo_root_from_backlog(&backlog_dir));
+        let report = run_policies(
+            &backlog_dir,
+            &config.rules,
+            Local::now().naive_local(),
+            self.apply,
+        )
+        .map_err(CallToolError::new)?;
+        if self.apply && !report.actions.is_empty() {
+            for action in &report.actions {
+                audit_event(
+                    context,
+                    &backlog_dir,
+                    "policy",
+                    Some(&action.task_id),
+                    serde_json::json!({
+                        "policy": action.policy,
+                        "reason": action.reason,
+                        "add_labels": action.add_labels,
+                        "remove_labels": action.remove_labels,
+                        "set_status": action.set_status,
+                    }),
+                )?;
+            }
+            refresh_index_best_effort(&backlog_dir);
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl HealthTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let defaults = HealthOptions::default();
+        let report = health_report(
+            &backlog_dir,
+            &tasks,
+            Local::now().naive_local(),
+            HealthOptions {
+                stale_days: self.stale_days.unwrap_or(defaults.stale_days),
+                churn_days: self.churn_days.unwrap_or(defaults.churn_days),
+            },
+        );
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl PostmergeTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        // Report first: the id fixer renames files the report diffs against.
+        let conflicts = conflict_report(
+            &repo_root_from_backlog(&backlog_dir),
+            &tasks_dir_for_root(&backlog_dir),
+            self.merge.as_deref(),
+        );
+        let tasks = load_tasks(&backlog_dir);
+        let ids = fix_duplicate_task_ids(&backlog_dir, &tasks, FixIdsOptions { apply: self.apply })
+            .map_err(CallToolError::new)?;
+        let tasks = load_tasks(&backlog_dir);
+        let deps = fix_dependencies(&tasks, self.apply).map_err(CallToolError::new)?;
+        let index = if self.apply {
+            Some(rebuild_index(&backlog_dir).map_err(CallToolError::new)?)
+        } else {
+            None
+        };
+
+        if self.apply {
+            audit_event(
+                context,
+                &backlog_dir,
+                "postmerge",
+                None,
+                serde_json::json!({
+                    "ids": ids.changes.len(),
+                    "deps": deps.fixed,
+                    "conflicts": conflicts.conflicts.len(),
+                    "unresolved_markers": conflicts.unresolved_markers.len(),
+                }),
+            )?;
+            maybe_auto_checkpoint(&backlog_dir);
+        }
+
+        ok_json(serde_json::json!({
+            "ok": true,
+            "apply": self.apply,
+            "ids": ids.changes.iter().map(|c| serde_json::json!({
+                "old_id": c.old_id,
+                "new_id": c.new_id,
+                "old_path": c.old_path,
+                "new_path": c.new_path,
+            })).collect::<Vec<_>>(),
+            "deps": deps,
+            "index": index,
+            "conflicts": conflicts,
+            "warnings": ids.warnings,
+        }))
+    }
+}
+
+impl WorkflowShowTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let format = self
+            .format
+            .as_deref()
+            .unwrap_or("mermaid")
+            .trim()
+            .to_lowercase();
+        let config = resolve_workflow(&repo_root_from_backlog(&backlog_dir));
+        let diagram = workflow_diagram(&backlog_dir, &load_tasks(&backlog_dir), &config);
+        let rendered = match format.as_str() {
+            "mermaid" => render_mermaid(&diagram),
+            "dot" => render_dot(&diagram),
+            other => {
+                return ok_json(serde_json::json!({
+                    "error": format!("Unknown format: {} (expected mermaid or dot)", other),
+                }))
+            }
+        };
+        ok_json(serde_json::json!({
+            "format": format,
+            "diagram": rendered,
+            "states": diagram.states,
+            "transitions": diagram.transitions,
+        }))
+    }
+}
+
+impl CalibrationTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let group = match self
+            .by
+            .as_deref()
+            .map(|by| by.trim().to_lowercase())
+            .as_deref()
+        {
+            None | Some("") => CalibrationGroup::Overall,
+            Some("assignee") => CalibrationGroup::Assignee,
+            Some("label") => CalibrationGroup::Label,
+            Some(other) => {
+                return ok_json(serde_json::json!({
+                    "error": format!("Unknown grouping: {} (expected assignee or label)", other),
+                }))
+            }
+        };
+        let report = calibration_report(&backlog_dir, &load_tasks(&backlog_dir), group);
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl CycleTimeTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let group = match self
+            .by
+            .as_deref()
+            .map(|by| by.trim().to_lowercase())
+            .as_deref()
+        {
+            None | Some("") => CycleTimeGroup::Overall,
+            Some("label") => CycleTimeGroup::Label,
+            Some("phase") => CycleTimeGroup::Phase,
+            Some("priority") => CycleTimeGroup::Priority,
+            Some(other) => {
+                return ok_json(serde_json::json!({
+                    "error": format!(
+                        "Unknown grouping: {} (expected label, phase, or priority)",
+                        other
+                    ),
+                }))
+            }
+        };
+        let window = match TimeWindow::parse(
+            self.since.as_deref(),
+            self.until.as_deref(),
+            Local::now().naive_local(),
+        ) {
+            Ok(window) => window,
+            Err(err) => return ok_json(serde_json::json!({ "error": err.to_string() })),
+        };
+        let tasks = if self.all {
+            load_tasks_with_archive(&backlog_dir)
+        } else {
+            load_tasks(&backlog_dir)
+        };
+        let report = cycle_time_report(&backlog_dir, &tasks, group, &window);
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl MilestonesTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = if self.all {
+            load_tasks_with_archive(&backlog_dir)
+        } else {
+            load_tasks(&backlog_dir)
+        };
+        let mut report = milestone_report(
+            &backlog_dir,
+            &tasks,
+            self.window_days.unwrap_or(DEFAULT_VELOCITY_WINDOW_DAYS),
+            Local::now().naive_local(),
+        );
+        let Some(name) = self
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+        else {
+            for milestone in &mut report.milestones {
+                milestone.tasks.clear();
+            }
+            return ok_json(serde_json::to_value(report).unwrap_or_default());
+        };
+        match report
+            .milestones
+            .into_iter()
+            .find(|milestone| milestone.name.eq_ignore_ascii_case(name))
+        {
+            Some(milestone) => ok_json(serde_json::to_value(milestone).unwrap_or_default()),
+            None => ok_json(serde_json::json!({
+                "error": format!("Milestone not found: {}", name),
+            })),
+        }
+    }
+}
+
+impl BudgetStatusTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = if self.all {
+            load_tasks_with_archive(&backlog_dir)
+        } else {
+            load_tasks(&backlog_dir)
+        };
+        let config = resolve_budgets_config(&repo_root_from_backlog(&backlog_dir));
+        let mut report = budget_report(&config, &tasks, Local::now().naive_local());
+        let wanted = [
+            (BudgetScope::Initiative, self.initiative.as_deref()),
+            (BudgetScope::Phase, self.phase.as_deref()),
+        ]
+        .into_iter()
+        .find_map(|(scope, name)| {
+            name.map(str::trim)
+                .filter(|name| !name.is_empty())
+                .map(|name| (scope, name))
+        });
+        if let Some((scope, name)) = wanted {
+            report
+                .budgets
+                .retain(|budget| budget.scope == scope && budget.name.eq_ignore_ascii_case(name));
+            if report.budgets.is_empty() {
+                return ok_json(serde_json::json!({
+                    "error": format!("No {} budget configured: {}", scope.as_str(), name),
+                }));
+            }
+        }
+        ok_json(serde_json::to_value(report).unwrap_or_default())
+    }
+}
+
+impl OnboardingNewTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let branch = core_git_branch(&repo_root).unwrap_or_else(|| "work".to_string());
+        let initiative = ensure_branch_initiative(&repo_root, &branch)
+            .map_err(|e| CallToolError::from_message(e.to_string()))?;
+        let config = resolve_onboarding_config(&repo_root);
+        let tasks = load_tasks_with_archive(&backlog_dir);
+        let plan = match create_onboarding(&backlog_dir, &tasks, &initiative, &config, &self.user) {
+            Ok(plan) => plan,
+            Err(err @ (OnboardingError::Task(_) | OnboardingError::Io(_))) => {
+                return Err(CallToolError::new(err))
+            }
+            Err(err) => return ok_json(serde_json::json!({"error": err.to_string()})),
+        };
+        audit_event(
+            context,
+            &backlog_dir,
+            "add_task",
+            Some(&plan.epic_id),
+            serde_json::json!({
+                "title": plan.epic_title,
+                "status": "To Do",
+                "kind": "epic",
+                "onboarding": plan.user,
+            }),
+        )?;
+        for item in &plan.items {
+            audit_event(
+                context,
+                &backlog_dir,
+                "add_task",
+                Some(&item.id),
+                serde_json::json!({
+                    "title": item.title,
+                    "status": "To Do",
+                    "onboarding": plan.user,
+                }),
+            )?;
+        }
+        refresh_index_best_effort(&backlog_dir);
+        maybe_auto_checkpoint(&backlog_dir);
+        ok_json(serde_json::to_value(plan).unwrap_or_default())
+    }
+}
+
+impl OnboardingStatusTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = if self.all {
+            load_tasks_with_archive(&backlog_dir)
+        } else {
+            load_tasks(&backlog_dir)
+        };
+        let statuses = onboarding_status(&tasks, self.user.as_deref());
+        ok_json(serde_json::to_value(statuses).unwrap_or_default())
+    }
+}
+
+impl JournalListTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let project_id = resolve_project_id(&repo_root, &tasks, self.project.as_deref());
+        let window = match TimeWindow::parse(
+            self.since.as_deref(),
+            self.until.as_deref(),
+            Local::now().naive_local(),
+        ) {
+            Ok(window) => window,
+            Err(err) => return ok_json(serde_json::json!({ "error": err.to_string() })),
+        };
+        let entries = read_session_journal(&repo_root, &project_id).map_err(CallToolError::new)?;
+        let entries = filter_session_journal(entries, self.task.as_deref(), &window);
+        if self.format == "markdown" || self.format == "text" {
+            return ok_text(render_session_journal_markdown(&entries));
+        }
+        ok_json(serde_json::json!({ "project_id": project_id, "entries": entries }))
+    }
+}
+
+impl WorkingSetStatusTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let repo_root = repo_root_from_backlog(&backlog_dir);
+        let project_id = resolve_project_id(&repo_root, &tasks, self.project.as_deref());
+        let status =
+            working_set_status(&repo_root, &project_id, &tasks).map_err(CallToolError::new)?;
+        let Some(status) = status else {
+            return ok_json(serde_json::json!({
+                "error": format!("No working set for project {}", project_id),
+            }));
+        };
+        let refreshed = if self.refresh {
+            refresh_working_set(&repo_root, &project_id, &status).map_err(CallToolError::new)?;
+            true
+        } else {
+            false
+        };
+        let mut payload = serde_json::to_value(&status).unwrap_or_default();
+        payload["stale"] = serde_json::json!(status.stale_count());
+        payload["refreshed"] = serde_json::json!(refreshed);
+        ok_json(payload)
+    }
+}
+
+impl ArchiveSearchTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let mut matches = search_archive(&backlog_dir, &self.query).map_err(CallToolError::new)?;
+        if let Some(limit) = self.limit {
+            matches.truncate(limit as usize);
+        }
+        let archive_root = archive_root_for_root(&backlog_dir);
+        let payload: Vec<serde_json::Value> = matches
+            .iter()
+            .map(|entry| {
+                serde_json::json!({
+                    "id": entry.id,
+                    "title": entry.title,
+                    "status": entry.status,
+                    "priority": entry.priority,
+                    "labels": entry.labels,
+                    "assignee": entry.assignee,
+                    "updated_date": entry.updated_date,
+                    "path": archive_root.join(&entry.path),
+                })
+            })
+            .collect();
+        ok_json(serde_json::Value::Array(payload))
+    }
+}
+
+impl ContextPackTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let budget_text = self.budget.as_deref().unwrap_or("4000-tokens");
+        let Some(budget) = parse_token_budget(budget_text) else {
+            return ok_json(serde_json::json!({
+                "error": format!(
+                    "Invalid budget: {} (expected 4000, 4000-tokens, or 4k)",
+                    budget_text
+                ),
+            }));
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let context_state = load_context_state(&backlog_dir);
+        let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let pack = build_context_pack(
+            &backlog_dir,
+            &tasks,
+            context_state.as_ref(),
+            &task_rules,
+            &ContextPackOptions {
+                budget,
+                epic: self.epic.clone(),
+            },
+        );
+        if self.format == "markdown" || self.format == "text" {
+            return ok_text(render_context_pack_markdown(&pack));
+        }
+        ok_json(serde_json::to_value(pack).unwrap_or_default())
+    }
+}
+
+impl EpicBriefTool {
+    fn call(&self, context: &McpContext) -> Result<CallToolResult, CallToolError> {
+        let backlog_dir = match resolve_root(context, self.root.as_deref()) {
+            Ok(dir) => dir,
+            Err(err) => return ok_json(err),
+        };
+        let tasks = load_tasks(&backlog_dir);
+        let task_rules = resolve_task_validation_rules(&repo_root_from_backlog(&backlog_dir));
+        let Some(brief) = epic_brief(&backlog_dir, &tasks, &self.epic_id, &task_rules) else {
+            return ok_json(
+                serde_json::json!({"error": format!("Task not found: {}", self.epic_id)}),
+            );
+        };
+        if self.format == "text" {
+            return ok_text(render_epic_brief_text(&brief));
+        }
+        ok_json(serde_json::to_value(brief).unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::ffi::OsString;
+    use std::sync::{Mutex, OnceLock};
+    use tempfile::TempDir;
+
+    fn with_env_lock<T>(f: impl FnOnce() -> T) -> T {
+        static LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let lock = LOCK.get_or_init(|| Mutex::new(()));
+        let _guard = lock.lock().expect("env lock");
         f()
     }
 
@@ -8294,10 +10870,103 @@ Definition of Done:\n\
             default_root: Some(repo_root.clone()),
             version_full: "test".to_string(),
             server_label: "workmesh-mcp".to_string(),
+            identity: None,
+            token: None,
+            client: None,
         };
         (temp, root_arg, context)
     }
 
+    #[test]
+    fn mcp_roles_gate_tools_by_identity() {
+        let (_temp, root_arg, mut context) = init_repo();
+        let repo_root = PathBuf::from(&root_arg);
+        std::fs::write(
+            repo_root.join(".workmesh.toml"),
+            "[roles]\ndefault = \"viewer\"\n\n[roles.identities]\nbot = \"contributor\"\nlead = \"maintainer\"\n",
+        )
+        .expect("config");
+        let args = serde_json::json!({ "root": root_arg });
+
+        assert!(authorize_tool(&context, "list_tasks", &args).is_ok());
+        let denied = authorize_tool(&context, "set_status", &args).unwrap_err();
+        assert_eq!(denied.to_json()["code"], "authorization_denied");
+        assert_eq!(denied.to_json()["required_role"], "contributor");
+
+        context.identity = Some("bot".to_string());
+        assert!(authorize_tool(&context, "set_status", &args).is_ok());
+        let protected = serde_json::json!({ "root": root_arg, "field": "id" });
+        assert!(authorize_tool(&context, "set_field", &protected).is_err());
+        assert!(authorize_tool(&context, "rekey_apply", &args).is_err());
+
+        context.identity = Some("lead".to_string());
+        assert!(authorize_tool(&context, "rekey_apply", &args).is_ok());
+    }
+
+    #[test]
+    fn mcp_scoped_api_token_limits_mutations_to_its_subtree() {
+        with_env_lock(|| {
+            let _env = EnvGuard::capture();
+            let home = TempDir::new().expect("home tempdir");
+            std::env::set_var("WORKMESH_HOME", home.path());
+
+            let (_temp, root_arg, mut context) = init_repo();
+            let repo_root = PathBuf::from(&root_arg);
+            std::fs::write(
+                repo_root.join(".workmesh.toml"),
+                "[roles]\ndefault = \"viewer\"\n",
+            )
+            .expect("config");
+            let tasks_dir = repo_root.join("workmesh").join("tasks");
+            for (id, parent) in [("epic-12", ""), ("task-001", "epic-12"), ("task-003", "")] {
+                std::fs::write(
+                    tasks_dir.join(format!("{id} - task.md")),
+                    format!(
+                        "---\nid: {id}\ntitle: {id}\nstatus: To Do\npriority: P2\nphase: Phase1\n\
+                         relationships:\n  parent: [{parent}]\n---\n\nBody\n"
+                    ),
+                )
+                .expect("task");
+            }
+            let (_, secret) = workmesh_core::api_tokens::create_token(
+                &tokens_dir().expect("tokens dir"),
+                Role::Contributor,
+                &["epic-12".to_string()],
+                None,
+                None,
+            )
+            .expect("token");
+            context.token = Some(secret);
+
+            let args = serde_json::json!({ "root": root_arg });
+            assert!(authorize_tool(&context, "set_status", &args).is_ok());
+            assert!(authorize_tool(&context, "rekey_apply", &args).is_err());
+
+            let call = |name: &str, task_id: Option<&str>| {
+                let mut args = serde_json::Map::new();
+                args.insert("root".to_string(), serde_json::json!(root_arg));
+                if let Some(task_id) = task_id {
+                    args.insert("task_id".to_string(), serde_json::json!(task_id));
+                }
+                scoped_tool_call(
+                    &context,
+                    &CallToolRequestParams {
+                        arguments: Some(args),
+                        meta: None,
+                        name: name.to_string(),
+                        task: None,
+                    },
+                )
+            };
+            assert!(call("set_status", Some("task-001")).is_none());
+            assert!(call("list_tasks", None).is_none());
+            let denied = call("set_status", Some("task-003")).expect("outside scope");
+            assert_eq!(denied["code"], "scope_denied");
+            assert_eq!(denied["outside"], serde_json::json!(["task-003"]));
+            assert!(call("add_task", None).is_some());
+        });
+    }
+
     #[test]
     fn mcp_bootstrap_initializes_new_repo() {
         let temp = TempDir::new().expect("tempdir");
@@ -8306,6 +10975,9 @@ Definition of Done:\n\
             default_root: Some(temp.path().to_path_buf()),
             version_full: "test".to_string(),
             server_label: "workmesh-mcp".to_string(),
+            identity: None,
+            token: None,
+            client: None,
         };
 
         let result = BootstrapTool {
@@ -8434,8 +11106,15 @@ Definition of Done:\n\
             deps_satisfied: None,
             blocked: None,
             search: None,
+            fields: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
             sort: "id".to_string(),
             limit: None,
+            count: false,
+            group_by: None,
             format: "json".to_string(),
             include_hints: false,
         }
@@ -8466,8 +11145,15 @@ Definition of Done:\n\
                 deps_satisfied: None,
                 blocked: None,
                 search: None,
+                fields: None,
+                created_after: None,
+                created_before: None,
+                updated_after: None,
+                updated_before: None,
                 sort: "id".to_string(),
                 limit: None,
+                count: false,
+                group_by: None,
                 format: "json".to_string(),
                 include_hints: false,
             }
@@ -8558,6 +11244,7 @@ Definition of Done:\n\
         let tool = SetStatusTool {
             task_id: "task-001".to_string(),
             status: "In Progress".to_string(),
+            reason: None,
             root: Some(root_arg),
             touch: true,
             verbose: false,
@@ -8576,8 +11263,15 @@ Definition of Done:\n\
             deps_satisfied: None,
             blocked: None,
             search: None,
+            fields: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
             sort: "id".to_string(),
             limit: None,
+            count: false,
+            group_by: None,
             format: "json".to_string(),
             include_hints: false,
         }
@@ -8595,6 +11289,43 @@ Definition of Done:\n\
         assert!(task.get("updated_date").unwrap().as_str().is_some());
     }
 
+    #[test]
+    fn mcp_audit_events_are_attributed_to_the_client() {
+        let (temp, root_arg, context) = init_repo();
+        let tasks_dir = temp.path().join("workmesh").join("tasks");
+        write_task(&tasks_dir, "task-001", "Active", "To Do");
+        let context = context.with_client(Some(InitializeRequestParams {
+            capabilities: Default::default(),
+            client_info: Implementation {
+                name: "claude code".into(),
+                version: "1.2.0".into(),
+                title: None,
+                description: None,
+                icons: vec![],
+                website_url: None,
+            },
+            protocol_version: ProtocolVersion::V2025_11_25.into(),
+            meta: None,
+        }));
+        assert_eq!(context.actor(), "mcp:claude-code");
+
+        SetStatusTool {
+            task_id: "task-001".to_string(),
+            status: "In Progress".to_string(),
+            reason: None,
+            root: Some(root_arg),
+            touch: true,
+            verbose: false,
+        }
+        .call(&context)
+        .expect("set status");
+
+        let events = read_audit_events_from(&temp.path().join("workmesh"), 0);
+        let event = events.last().expect("audit event");
+        assert_eq!(event.actor.as_deref(), Some("mcp:claude-code"));
+        assert_eq!(event.details["mcp_session"].as_str(), Some(connection_id()));
+    }
+
     #[test]
     fn mcp_add_task_creates_markdown_file() {
         let (temp, root_arg, context) = init_repo();
@@ -8633,8 +11364,15 @@ Definition of Done:\n\
             deps_satisfied: None,
             blocked: None,
             search: Some("New task".to_string()),
+            fields: None,
+            created_after: None,
+            created_before: None,
+            updated_after: None,
+            updated_before: None,
             sort: "id".to_string(),
             limit: None,
+            count: false,
+            group_by: None,
             format: "json".to_string(),
             include_hints: false,
         }
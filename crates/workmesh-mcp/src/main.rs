@@ -1,6 +1,7 @@
 mod version;
 
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
 use rust_mcp_sdk::error::SdkResult;
@@ -9,7 +10,10 @@ use rust_mcp_sdk::{
     McpServer, StdioTransport, ToMcpServerHandler, TransportOptions,
 };
 
-use workmesh_mcp_server::{build_server_details, McpContext, WorkmeshServerHandler};
+use workmesh_mcp_server::{
+    build_server_details, McpContext, ResourceSubscriptions, ResponseCache, WorkmeshServerHandler,
+    DEFAULT_CACHE_TTL,
+};
 
 #[derive(Parser)]
 #[command(name = "workmesh-mcp", version = version::FULL)]
@@ -17,6 +21,16 @@ struct Args {
     /// Default backlog root for MCP tool calls.
     #[arg(long)]
     root: Option<PathBuf>,
+    /// Caller identity for `[roles]` checks (defaults to WORKMESH_IDENTITY).
+    #[arg(long)]
+    identity: Option<String>,
+    /// Bearer token for `[roles]` checks (defaults to WORKMESH_TOKEN).
+    #[arg(long)]
+    token: Option<String>,
+    /// Cache read-only tool responses this long; 0 disables (defaults to
+    /// WORKMESH_MCP_CACHE_TTL_MS, else 2000).
+    #[arg(long)]
+    cache_ttl_ms: Option<u64>,
 }
 
 #[tokio::main]
@@ -25,13 +39,30 @@ async fn main() -> SdkResult<()> {
 
     let server_details = build_server_details(version::FULL);
 
+    let cache_ttl = args
+        .cache_ttl_ms
+        .or_else(|| {
+            std::env::var("WORKMESH_MCP_CACHE_TTL_MS")
+                .ok()
+                .and_then(|value| value.trim().parse().ok())
+        })
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_CACHE_TTL);
+
     let transport = StdioTransport::new(TransportOptions::default())?;
     let handler = WorkmeshServerHandler {
         context: McpContext {
             default_root: args.root,
             version_full: version::FULL.to_string(),
             server_label: "workmesh-mcp".to_string(),
+            identity: args
+                .identity
+                .or_else(|| std::env::var("WORKMESH_IDENTITY").ok()),
+            token: args.token.or_else(|| std::env::var("WORKMESH_TOKEN").ok()),
+            client: None,
         },
+        cache: ResponseCache::new(cache_ttl),
+        resources: ResourceSubscriptions::default(),
     };
 
     let server = server_runtime::create_server(McpServerOptions {
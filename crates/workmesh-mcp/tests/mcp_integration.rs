@@ -4,12 +4,14 @@ use tempfile::TempDir;
 
 use rust_mcp_sdk::schema::{
     CallToolRequestParams, ClientCapabilities, Implementation, InitializeRequestParams,
-    LATEST_PROTOCOL_VERSION,
+    NotificationParams, ReadResourceContent, ReadResourceRequestParams,
+    ResourceUpdatedNotificationParams, RpcError, SubscribeRequestParams, LATEST_PROTOCOL_VERSION,
 };
 use rust_mcp_sdk::{
     mcp_client::{client_runtime, ClientHandler, McpClientOptions},
     McpClient, StdioTransport, ToMcpClientHandler, TransportOptions,
 };
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use serial_test::serial;
@@ -45,6 +47,47 @@ struct NoopClientHandler;
 #[async_trait]
 impl ClientHandler for NoopClientHandler {}
 
+/// Records resource notifications as `list_changed` or the updated URI.
+#[derive(Clone, Default)]
+struct NotificationRecorder(Arc<Mutex<Vec<String>>>);
+
+impl NotificationRecorder {
+    async fn wait_for(&self, expected: &str) {
+        for _ in 0..100 {
+            if self.0.lock().unwrap().iter().any(|seen| seen == expected) {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        }
+        panic!(
+            "no {} notification, got {:?}",
+            expected,
+            self.0.lock().unwrap()
+        );
+    }
+}
+
+#[async_trait]
+impl ClientHandler for NotificationRecorder {
+    async fn handle_resource_list_changed_notification(
+        &self,
+        _params: Option<NotificationParams>,
+        _runtime: &dyn McpClient,
+    ) -> Result<(), RpcError> {
+        self.0.lock().unwrap().push("list_changed".to_string());
+        Ok(())
+    }
+
+    async fn handle_resource_updated_notification(
+        &self,
+        params: ResourceUpdatedNotificationParams,
+        _runtime: &dyn McpClient,
+    ) -> Result<(), RpcError> {
+        self.0.lock().unwrap().push(params.uri);
+        Ok(())
+    }
+}
+
 fn client_details() -> InitializeRequestParams {
     InitializeRequestParams {
         capabilities: ClientCapabilities::default(),
@@ -732,3 +775,82 @@ async fn mcp_truth_tools_smoke() {
 
     client.shut_down().await.expect("shutdown");
 }
+
+#[tokio::test]
+#[serial]
+async fn mcp_resources_read_and_notify_on_backlog_changes() {
+    let temp = TempDir::new().expect("tempdir");
+    let tasks_dir = temp.path().join("backlog").join("tasks");
+    std::fs::create_dir_all(&tasks_dir).expect("tasks dir");
+    write_task(&tasks_dir, "task-001", "Alpha", "To Do");
+
+    let server_bin = env!("CARGO_BIN_EXE_workmesh-mcp");
+    let transport = StdioTransport::create_with_server_launch(
+        server_bin,
+        vec!["--root".to_string(), temp.path().display().to_string()],
+        Some(coverage_safe_env()),
+        test_transport_options(),
+    )
+    .expect("transport");
+    let recorder = NotificationRecorder::default();
+    let client = client_runtime::create_client(McpClientOptions {
+        client_details: client_details(),
+        transport,
+        handler: recorder.clone().to_mcp_client_handler(),
+        task_store: None,
+        server_task_store: None,
+    });
+    client.clone().start().await.expect("start client");
+
+    let listed = client
+        .request_resource_list(None)
+        .await
+        .expect("list resources");
+    let uris: Vec<&str> = listed
+        .resources
+        .iter()
+        .map(|resource| resource.uri.as_str())
+        .collect();
+    assert_eq!(
+        uris,
+        [
+            "workmesh://board",
+            "workmesh://context",
+            "workmesh://tasks/task-001"
+        ]
+    );
+
+    let read = client
+        .request_resource_read(ReadResourceRequestParams {
+            meta: None,
+            uri: "workmesh://tasks/task-001".to_string(),
+        })
+        .await
+        .expect("read task");
+    let ReadResourceContent::TextResourceContents(content) = &read.contents[0] else {
+        panic!("expected text contents");
+    };
+    let task: serde_json::Value = serde_json::from_str(&content.text).expect("json");
+    assert_eq!(task["status"], "To Do");
+    assert!(client
+        .request_resource_read(ReadResourceRequestParams {
+            meta: None,
+            uri: "workmesh://tasks/task-404".to_string(),
+        })
+        .await
+        .is_err());
+
+    client
+        .request_resource_subscription(SubscribeRequestParams {
+            meta: None,
+            uri: "workmesh://tasks/task-001".to_string(),
+        })
+        .await
+        .expect("subscribe");
+    write_task(&tasks_dir, "task-001", "Alpha", "In Progress");
+    recorder.wait_for("workmesh://tasks/task-001").await;
+    write_task(&tasks_dir, "task-002", "Beta", "To Do");
+    recorder.wait_for("list_changed").await;
+
+    client.shut_down().await.expect("shutdown");
+}
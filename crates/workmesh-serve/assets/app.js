@@ -0,0 +1,155 @@
+// WorkMesh web board: talks to the JSON API served by `workmesh serve`.
+"use strict";
+
+const $ = (id) => document.getElementById(id);
+
+function escapeHtml(value) {
+  return String(value ?? "").replace(/[&<>"']/g, (ch) => ({
+    "&": "&amp;", "<": "&lt;", ">": "&gt;", '"': "&quot;", "'": "&#39;",
+  })[ch]);
+}
+
+let statuses = [];
+
+async function request(url, options = {}) {
+  const token = sessionStorage.getItem("workmesh-token");
+  const headers = { "Content-Type": "application/json" };
+  if (token) {
+    headers.Authorization = `Bearer ${token}`;
+  }
+  const response = await fetch(url, { ...options, headers });
+  const body = await response.json();
+  if (!response.ok) {
+    throw new Error(body.message || body.error || response.statusText);
+  }
+  return body;
+}
+
+const getJson = (url) => request(url);
+const postJson = (url, payload) =>
+  request(url, { method: "POST", body: JSON.stringify(payload) });
+
+function card(task) {
+  const meta = [task.priority, task.phase, (task.labels || []).join(", ")]
+    .filter(Boolean)
+    .join(" · ");
+  return `<button class="card" type="button" data-id="${escapeHtml(task.id)}">`
+    + `<span class="id">${escapeHtml(task.id)}</span>${escapeHtml(task.title)}`
+    + `<span class="meta">${escapeHtml(meta)}</span></button>`;
+}
+
+async function loadBoard() {
+  const board = await getJson("/api/board");
+  statuses = board.columns.map((column) => column.status);
+  $("board").innerHTML = board.columns.map((column) =>
+    `<div class="column"><h2>${escapeHtml(column.status)} (${column.tasks.length})</h2>`
+    + column.tasks.map(card).join("")
+    + "</div>"
+  ).join("");
+}
+
+async function search(query) {
+  const results = $("results");
+  if (!query.trim()) {
+    results.hidden = true;
+    results.innerHTML = "";
+    return;
+  }
+  const tasks = await getJson(`/api/tasks?q=${encodeURIComponent(query)}`);
+  results.hidden = false;
+  results.innerHTML = tasks.length
+    ? tasks.map(card).join("")
+    : `<p class="empty">No tasks match "${escapeHtml(query)}".</p>`;
+}
+
+async function showTask(id) {
+  const task = await getJson(`/api/tasks/${encodeURIComponent(id)}`);
+  const list = (values) => (values && values.length ? values.join(", ") : "-");
+  const lease = task.lease ? task.lease.owner : "-";
+  const rows = [
+    ["Status", task.status],
+    ["Priority", task.priority],
+    ["Phase", task.phase],
+    ["Kind", task.kind],
+    ["Assignee", list(task.assignee)],
+    ["Labels", list(task.labels)],
+    ["Dependencies", list(task.dependencies)],
+    ["Lease", lease],
+    ["Updated", task.updated_date || "-"],
+  ];
+  const options = statuses.map((status) =>
+    `<option${status === task.status ? " selected" : ""}>${escapeHtml(status)}</option>`).join("");
+  $("detail-body").innerHTML = `<h2>${escapeHtml(task.id)} ${escapeHtml(task.title)}</h2>`
+    + "<dl>" + rows.map(([key, value]) =>
+      `<dt>${key}</dt><dd>${escapeHtml(value)}</dd>`).join("") + "</dl>"
+    + `<form id="status-form"><select name="status">${options}</select>`
+    + "<button type=\"submit\">Set status</button></form>"
+    + "<form id=\"note-form\"><input type=\"text\" name=\"note\" placeholder=\"Add a note\">"
+    + "<button type=\"submit\">Add note</button></form>"
+    + "<p id=\"detail-error\" class=\"error\" hidden></p>"
+    + `<pre>${escapeHtml(task.body || "")}</pre>`;
+  $("detail").hidden = false;
+  $("status-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    write(task.id, "status", { status: event.target.status.value });
+  });
+  $("note-form").addEventListener("submit", (event) => {
+    event.preventDefault();
+    if (event.target.note.value.trim()) {
+      write(task.id, "notes", { note: event.target.note.value });
+    }
+  });
+}
+
+async function write(id, action, payload) {
+  try {
+    await postJson(`/api/tasks/${encodeURIComponent(id)}/${action}`, payload);
+    await Promise.all([loadBoard(), showTask(id)]);
+  } catch (error) {
+    $("detail-error").hidden = false;
+    $("detail-error").textContent = error.message;
+  }
+}
+
+function report(error) {
+  $("results").hidden = false;
+  $("results").innerHTML = `<p class="empty">${escapeHtml(error.message)}</p>`;
+}
+
+document.addEventListener("click", (event) => {
+  const target = event.target.closest(".card");
+  if (target) {
+    showTask(target.dataset.id).catch(report);
+  }
+});
+$("close").addEventListener("click", () => { $("detail").hidden = true; });
+document.addEventListener("keydown", (event) => {
+  if (event.key === "Escape") {
+    $("detail").hidden = true;
+  }
+});
+$("reload").addEventListener("click", () => {
+  loadBoard().catch(report);
+  search($("search").value).catch(report);
+});
+
+$("token").addEventListener("click", () => {
+  const token = prompt("Bearer token for writes (leave empty to clear)",
+    sessionStorage.getItem("workmesh-token") || "");
+  if (token === null) {
+    return;
+  }
+  if (token.trim()) {
+    sessionStorage.setItem("workmesh-token", token.trim());
+  } else {
+    sessionStorage.removeItem("workmesh-token");
+  }
+});
+
+let pending;
+$("search").addEventListener("input", (event) => {
+  clearTimeout(pending);
+  pending = setTimeout(() => search(event.target.value).catch(report), 200);
+});
+
+loadBoard().catch(report);
@@ -0,0 +1,438 @@
+//! Request routing. `handle` maps a request to a reply without touching a socket, so the API
+//! can be tested directly.
+
+use std::path::Path;
+
+use workmesh_core::config::resolve_task_validation_rules;
+use workmesh_core::metrics::{collect_backlog_metrics, render_prometheus};
+use workmesh_core::project::repo_root_from_backlog;
+use workmesh_core::task::{load_tasks, Task};
+use workmesh_core::task_ops::{filter_tasks, task_to_json_value};
+use workmesh_core::timewindow::TimeWindow;
+use workmesh_core::views::status_columns;
+use workmesh_core::workflow::resolve_workflow;
+
+use crate::writes;
+use crate::ServeOptions;
+
+const INDEX_HTML: &str = include_str!("../assets/index.html");
+const APP_JS: &str = include_str!("../assets/app.js");
+const STYLE_CSS: &str = include_str!("../assets/style.css");
+
+const JSON: &str = "application/json";
+const PROMETHEUS: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// The parts of an HTTP request the API looks at.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ApiRequest<'a> {
+    pub method: &'a str,
+    /// Path plus query string.
+    pub url: &'a str,
+    pub body: &'a str,
+    pub content_type: Option<&'a str>,
+    /// Bearer token from the `Authorization` header.
+    pub token: Option<&'a str>,
+    /// The `Host` header, port included.
+    pub host: Option<&'a str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reply {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: String,
+}
+
+impl Reply {
+    pub(crate) fn json(status: u16, value: serde_json::Value) -> Self {
+        Self {
+            status,
+            content_type: JSON,
+            body: value.to_string(),
+        }
+    }
+
+    pub(crate) fn error(status: u16, message: &str) -> Self {
+        Self::json(status, serde_json::json!({ "error": message }))
+    }
+
+    fn asset(content_type: &'static str, body: &str) -> Self {
+        Self {
+            status: 200,
+            content_type,
+            body: body.to_string(),
+        }
+    }
+}
+
+/// Route one request. `options.ui` enables the embedded page at `/`.
+pub fn handle(backlog_dir: &Path, request: &ApiRequest, options: &ServeOptions) -> Reply {
+    // A DNS-rebinding page reaches this server under its own host name; only answer to names
+    // that mean this machine or that the operator listed.
+    if !request.host.is_some_and(|host| allowed_host(host, options)) {
+        return Reply::error(403, "Host not allowed");
+    }
+    let ui = options.ui;
+    let (path, query) = request.url.split_once('?').unwrap_or((request.url, ""));
+    if let Some((task_id, action)) = path
+        .strip_prefix("/api/tasks/")
+        .and_then(|rest| rest.split_once('/'))
+    {
+        if request.method != "POST" {
+            return Reply::error(405, "Method not allowed");
+        }
+        // Browsers only send JSON cross-origin after a CORS preflight, which this server never
+        // approves, so other sites cannot write through a local server.
+        if !request
+            .content_type
+            .is_some_and(|value| value.trim().starts_with(JSON))
+        {
+            return Reply::error(415, "Content-Type must be application/json");
+        }
+        return writes::handle(
+            backlog_dir,
+            &decode(task_id),
+            action,
+            request.body,
+            request.token,
+        );
+    }
+    if request.method != "GET" {
+        return Reply::error(405, "Method not allowed");
+    }
+    // Reads are authorized as the matching MCP tool, like writes.
+    let tool = match path {
+        "/api/board" => Some("board"),
+        "/api/tasks" => Some("list_tasks"),
+        _ if path.starts_with("/api/tasks/") => Some("show_task"),
+        "/metrics" => Some("stats"),
+        _ => None,
+    };
+    if let Some(tool) = tool {
+        let access = writes::Access::resolve(backlog_dir, request.token);
+        let mut tasks = load_tasks(backlog_dir);
+        if let Err(reply) = access.authorize(tool, &serde_json::json!({}), &tasks, &[]) {
+            return reply;
+        }
+        access.redact_confidential(&mut tasks);
+        return match path {
+            "/api/board" => board(backlog_dir, &tasks),
+            "/api/tasks" => list(&tasks, &parse_query(query)),
+            "/metrics" => metrics(backlog_dir, &tasks),
+            _ => show(&tasks, &decode(&path[11..])),
+        };
+    }
+    match path {
+        "/" | "/index.html" if ui => Reply::asset("text/html; charset=utf-8", INDEX_HTML),
+        "/app.js" if ui => Reply::asset("text/javascript; charset=utf-8", APP_JS),
+        "/style.css" if ui => Reply::asset("text/css; charset=utf-8", STYLE_CSS),
+        _ => Reply::error(404, "Not found"),
+    }
+}
+
+/// `host` (a `Host` header, optional port) is the bind address, a loopback name, or one of
+/// `options.allowed_hosts`.
+fn allowed_host(host: &str, options: &ServeOptions) -> bool {
+    let name = host_name(host);
+    ["localhost", "127.0.0.1", "::1"]
+        .into_iter()
+        .chain(std::iter::once(host_name(&options.host)))
+        .chain(
+            options
+                .allowed_hosts
+                .iter()
+                .map(|allowed| host_name(allowed)),
+        )
+        .any(|allowed| !allowed.is_empty() && name.eq_ignore_ascii_case(allowed))
+}
+
+/// Strip the port and IPv6 brackets from a host; a bare IPv6 address is kept whole.
+fn host_name(host: &str) -> &str {
+    let host = host.trim();
+    match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or(rest),
+        None if host.matches(':').count() > 1 => host,
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    }
+}
+
+/// Workflow status columns with body-less task summaries.
+fn board(backlog_dir: &Path, tasks: &[Task]) -> Reply {
+    let statuses = resolve_workflow(&repo_root_from_backlog(backlog_dir)).statuses;
+    let columns: Vec<serde_json::Value> = status_columns(tasks, &statuses)
+        .into_iter()
+        .map(|(status, lane_tasks)| {
+            serde_json::json!({
+                "status": status,
+                "tasks": summaries(lane_tasks),
+            })
+        })
+        .collect();
+    Reply::json(200, serde_json::json!({ "columns": columns }))
+}
+
+/// `?q=` searches title and body; `status` and `label` take comma-separated values.
+fn list(tasks: &[Task], query: &[(String, String)]) -> Reply {
+    let values = |key: &str| -> Option<Vec<String>> {
+        let values: Vec<String> = query
+            .iter()
+            .filter(|(name, _)| name == key)
+            .flat_map(|(_, value)| value.split(','))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .collect();
+        (!values.is_empty()).then_some(values)
+    };
+    let status = values("status");
+    let label = values("label");
+    let search = values("q").map(|terms| terms.join(" "));
+    let matches = filter_tasks(
+        tasks,
+        status.as_deref(),
+        None,
+        None,
+        None,
+        label.as_deref(),
+        None,
+        None,
+        None,
+        search.as_deref(),
+    );
+    Reply::json(200, serde_json::Value::Array(summaries(matches)))
+}
+
+fn show(tasks: &[Task], task_id: &str) -> Reply {
+    match tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(task_id))
+    {
+        Some(task) => Reply::json(200, task_to_json_value(task, true)),
+        None => Reply::error(404, &format!("Task not found: {}", task_id)),
+    }
+}
+
+/// The `metrics` command's Prometheus output; audit counters cover the whole log.
+fn metrics(backlog_dir: &Path, tasks: &[Task]) -> Reply {
+    let rules = resolve_task_validation_rules(&repo_root_from_backlog(backlog_dir));
+    let metrics = collect_backlog_metrics(backlog_dir, tasks, &rules, &TimeWindow::default());
+    Reply {
+        status: 200,
+        content_type: PROMETHEUS,
+        body: render_prometheus(&metrics),
+    }
+}
+
+fn summaries(tasks: Vec<&Task>) -> Vec<serde_json::Value> {
+    tasks
+        .into_iter()
+        .map(|task| task_to_json_value(task, false))
+        .collect()
+}
+
+fn parse_query(query: &str) -> Vec<(String, String)> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode(key), decode(value))
+        })
+        .collect()
+}
+
+/// Percent-decode a URL component (`+` is a space).
+fn decode(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'+' => out.push(b' '),
+            b'%' => match bytes
+                .get(index + 1..index + 3)
+                .and_then(|hex| std::str::from_utf8(hex).ok())
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                Some(byte) => {
+                    out.push(byte);
+                    index += 2;
+                }
+                None => out.push(b'%'),
+            },
+            byte => out.push(byte),
+        }
+        index += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    fn write_task(tasks_dir: &Path, id: &str, title: &str, status: &str, labels: &str) {
+        fs::write(
+            tasks_dir.join(format!("{} - {}.md", id, title.to_lowercase())),
+            format!(
+                "---\nid: {}\ntitle: {}\nstatus: {}\npriority: P2\nphase: Phase1\ndependencies: []\nlabels: [{}]\n---\n\nDescription:\n- {} body\n",
+                id, title, status, labels, title
+            ),
+        )
+        .expect("write task");
+    }
+
+    fn options(ui: bool) -> ServeOptions {
+        ServeOptions {
+            ui,
+            ..Default::default()
+        }
+    }
+
+    fn get(backlog_dir: &Path, url: &str, ui: bool) -> Reply {
+        let request = ApiRequest {
+            method: "GET",
+            url,
+            host: Some("localhost:8080"),
+            ..Default::default()
+        };
+        handle(backlog_dir, &request, &options(ui))
+    }
+
+    #[test]
+    fn api_serves_board_search_detail_and_ui_only_when_enabled() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write_task(&tasks_dir, "task-001", "Alpha", "To Do", "api");
+        write_task(&tasks_dir, "task-002", "Beta", "In Progress", "ui");
+
+        let board = get(&backlog_dir, "/api/board", false);
+        assert_eq!(board.status, 200);
+        let board: serde_json::Value = serde_json::from_str(&board.body).expect("json");
+        let columns = board["columns"].as_array().expect("columns");
+        assert_eq!(columns[0]["status"], "To Do");
+        assert_eq!(columns[0]["tasks"][0]["id"], "task-001");
+        assert!(columns[0]["tasks"][0].get("body").is_none());
+
+        let found = get(&backlog_dir, "/api/tasks?q=beta+body", false);
+        let found: serde_json::Value = serde_json::from_str(&found.body).expect("json");
+        assert_eq!(found.as_array().expect("list").len(), 1);
+        assert_eq!(found[0]["id"], "task-002");
+        let found = get(&backlog_dir, "/api/tasks?status=To%20Do", false);
+        let found: serde_json::Value = serde_json::from_str(&found.body).expect("json");
+        assert_eq!(found[0]["id"], "task-001");
+
+        let detail = get(&backlog_dir, "/api/tasks/TASK-002", false);
+        assert_eq!(detail.status, 200);
+        assert!(detail.body.contains("Beta body"));
+        assert_eq!(get(&backlog_dir, "/api/tasks/task-404", false).status, 404);
+        let metrics = get(&backlog_dir, "/metrics", false);
+        assert_eq!(metrics.status, 200);
+        assert!(metrics
+            .content_type
+            .starts_with("text/plain; version=0.0.4"));
+        assert!(
+            metrics.body.contains("workmesh_tasks{status=\"To Do\"} 1"),
+            "{}",
+            metrics.body
+        );
+        assert_eq!(
+            handle(
+                &backlog_dir,
+                &ApiRequest {
+                    method: "POST",
+                    url: "/api/tasks/task-001",
+                    host: Some("127.0.0.1:8080"),
+                    ..Default::default()
+                },
+                &options(false)
+            )
+            .status,
+            405
+        );
+
+        assert_eq!(get(&backlog_dir, "/", false).status, 404);
+        let page = get(&backlog_dir, "/", true);
+        assert_eq!(page.status, 200);
+        assert!(page.content_type.starts_with("text/html"));
+        assert_eq!(get(&backlog_dir, "/app.js", true).status, 200);
+
+        let rebound = |host: Option<&'static str>, bind: &str| {
+            let request = ApiRequest {
+                method: "GET",
+                url: "/api/tasks/task-002",
+                host,
+                ..Default::default()
+            };
+            let options = ServeOptions {
+                host: bind.to_string(),
+                allowed_hosts: vec!["board.example".to_string(), "192.168.1.20".to_string()],
+                ..Default::default()
+            };
+            handle(&backlog_dir, &request, &options).status
+        };
+        assert_eq!(rebound(Some("evil.example:8080"), "127.0.0.1"), 403);
+        assert_eq!(rebound(Some("localhost.evil.example"), "127.0.0.1"), 403);
+        assert_eq!(rebound(None, "127.0.0.1"), 403);
+        assert_eq!(rebound(Some("LOCALHOST"), "127.0.0.1"), 200);
+        assert_eq!(rebound(Some("board.lan:9000"), "board.lan"), 200);
+        assert_eq!(rebound(Some("[::1]:8080"), "::1"), 200);
+        assert_eq!(rebound(Some("[::1]:8080"), "127.0.0.1"), 200);
+        assert_eq!(rebound(Some("192.168.1.20:8080"), "0.0.0.0"), 200);
+        assert_eq!(rebound(Some("BOARD.example"), "0.0.0.0"), 200);
+        assert_eq!(rebound(Some("192.168.1.21:8080"), "0.0.0.0"), 403);
+    }
+
+    #[test]
+    fn reads_redact_confidential_bodies_without_a_token() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        // Stands in for a body decrypted at load time with `WORKMESH_KEY` set.
+        fs::write(
+            tasks_dir.join("task-001 - secret.md"),
+            "---\nid: task-001\ntitle: Secret\nstatus: To Do\npriority: P2\nphase: Phase1\ndependencies: []\nlabels: []\nconfidential: true\n---\n\nDescription:\n- launch codes\n",
+        )
+        .expect("write task");
+        let read = |url: &str, token: Option<&str>| {
+            let request = ApiRequest {
+                method: "GET",
+                url,
+                token,
+                host: Some("localhost:8080"),
+                ..Default::default()
+            };
+            handle(&backlog_dir, &request, &options(false))
+        };
+
+        let detail = read("/api/tasks/task-001", None);
+        assert_eq!(detail.status, 200);
+        let detail: serde_json::Value = serde_json::from_str(&detail.body).expect("json");
+        assert_eq!(detail["body"], writes::REDACTED_BODY);
+        assert_eq!(read("/api/tasks?q=launch", None).body, "[]");
+
+        fs::write(
+            temp.path().join(".workmesh.toml"),
+            format!(
+                "[roles]\ndefault = \"viewer\"\n\n[roles.tokens]\n\"{}\" = \"viewer\"\n",
+                workmesh_core::roles::token_hash("reader-secret")
+            ),
+        )
+        .expect("config");
+        assert!(!read("/api/tasks/task-001", Some("wrong"))
+            .body
+            .contains("launch codes"));
+        let detail = read("/api/tasks/task-001", Some("reader-secret"));
+        assert_eq!(detail.status, 200);
+        assert!(detail.body.contains("launch codes"), "{}", detail.body);
+        assert!(read("/api/tasks?q=launch", Some("reader-secret"))
+            .body
+            .contains("task-001"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Local HTTP server behind `workmesh serve`: a JSON API over the backlog (reads, plus status,
+//! claim, release, and notes writes), Prometheus `/metrics`, and, with `--ui`, a single-page
+//! board whose assets are embedded in the binary.
+//!
+//! Requests are answered one at a time from a fresh `load_tasks`, so the page always reflects
+//! the Markdown files on disk.
+
+pub mod api;
+mod writes;
+
+use std::io::Read;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use tiny_http::{Header, Response, Server};
+
+pub use api::{handle, ApiRequest, Reply};
+pub use writes::MAX_BODY_BYTES;
+
+pub const DEFAULT_HOST: &str = "127.0.0.1";
+pub const DEFAULT_PORT: u16 = 8080;
+
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    pub host: String,
+    pub port: u16,
+    /// Serve the embedded web board at `/`.
+    pub ui: bool,
+    /// Extra `Host` header names to answer to, besides the bind address and loopback names.
+    pub allowed_hosts: Vec<String>,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            host: DEFAULT_HOST.to_string(),
+            port: DEFAULT_PORT,
+            ui: false,
+            allowed_hosts: Vec::new(),
+        }
+    }
+}
+
+pub struct WorkmeshServer {
+    server: Server,
+    backlog_dir: PathBuf,
+    options: ServeOptions,
+}
+
+impl WorkmeshServer {
+    /// Bind the listener; port `0` picks a free port (see `local_addr`).
+    pub fn bind(backlog_dir: &Path, options: &ServeOptions) -> Result<Self> {
+        let server = Server::http((options.host.as_str(), options.port)).map_err(|err| {
+            anyhow!(
+                "Failed to listen on {}:{}: {}",
+                options.host,
+                options.port,
+                err
+            )
+        })?;
+        Ok(Self {
+            server,
+            backlog_dir: backlog_dir.to_path_buf(),
+            options: options.clone(),
+        })
+    }
+
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Answer requests until the process is interrupted.
+    pub fn run(self) {
+        for mut request in self.server.incoming_requests() {
+            let mut body = String::new();
+            let read = request
+                .as_reader()
+                .take(MAX_BODY_BYTES)
+                .read_to_string(&mut body);
+            let header = |name: &str| {
+                request
+                    .headers()
+                    .iter()
+                    .find(|header| header.field.as_str().as_str().eq_ignore_ascii_case(name))
+                    .map(|header| header.value.as_str().to_string())
+            };
+            let content_type = header("Content-Type");
+            let host = header("Host");
+            let token = header("Authorization")
+                .and_then(|value| value.strip_prefix("Bearer ").map(str::to_string))
+                .map(|token| token.trim().to_string());
+            let reply = match read {
+                Ok(_) => handle(
+                    &self.backlog_dir,
+                    &ApiRequest {
+                        method: request.method().as_str(),
+                        url: request.url(),
+                        body: &body,
+                        content_type: content_type.as_deref(),
+                        token: token.as_deref(),
+                        host: host.as_deref(),
+                    },
+                    &self.options,
+                ),
+                Err(_) => Reply {
+                    status: 400,
+                    content_type: "application/json",
+                    body: serde_json::json!({ "error": "Request body must be UTF-8" }).to_string(),
+                },
+            };
+            let content_type =
+                Header::from_bytes("Content-Type", reply.content_type).expect("static header");
+            let response = Response::from_string(reply.body)
+                .with_status_code(reply.status)
+                .with_header(content_type);
+            // The client may have gone away; nothing to do about it here.
+            let _ = request.respond(response);
+        }
+    }
+}
@@ -0,0 +1,479 @@
+//! `POST /api/tasks/<id>/<action>`: status, claim, release, and notes.
+//!
+//! Each action is authorized as the matching MCP tool (`set_status`, `claim_task`,
+//! `release_task`, `add_note`), so `[roles]`, API tokens, token scopes, and freezes apply the
+//! same way over HTTP. Unlike MCP stdio, an unconfigured repo does not make every caller a
+//! maintainer: without `[roles]` or a stored API token, HTTP callers are viewers. Writes go
+//! through `task_ops` and record one audit event with `source: http`. [`Access`] also authorizes
+//! reads and decides who may see confidential bodies.
+
+use std::path::Path;
+
+use chrono::Local;
+use serde_json::Value;
+
+use workmesh_core::api_tokens::{find_token, tokens_dir, ApiToken};
+use workmesh_core::audit::{append_audit_event, AuditEvent};
+use workmesh_core::confidential::is_confidential_task;
+use workmesh_core::config::{resolve_note_autolink, resolve_task_validation_rules, resolve_teams};
+use workmesh_core::freeze::FrozenSet;
+use workmesh_core::index::refresh_index;
+use workmesh_core::mentions::resolve_mentions;
+use workmesh_core::project::repo_root_from_backlog;
+use workmesh_core::roles::{
+    authorize, resolve_grant, resolve_roles_config, Caller, Grant, Role, RolesConfig,
+};
+use workmesh_core::task::{load_tasks, Lease, Task};
+use workmesh_core::task_ops::{
+    append_task_note, edit_list_field, ensure_can_set_status_with_rules, link_note_references,
+    now_timestamp, task_to_json_value, timestamp_plus_minutes, update_lease_fields,
+    update_task_field,
+};
+use workmesh_core::transitions::record_status_transition;
+use workmesh_tools::required_role;
+
+use crate::api::Reply;
+
+/// Body shown in place of a confidential task's body to callers without a token.
+pub(crate) const REDACTED_BODY: &str = "(confidential: pass a token to read this body)\n";
+
+/// Largest request body accepted, in bytes.
+pub const MAX_BODY_BYTES: u64 = 1024 * 1024;
+
+/// The MCP tool an action is authorized as.
+fn tool_name(action: &str) -> Option<&'static str> {
+    match action {
+        "status" => Some("set_status"),
+        "claim" => Some("claim_task"),
+        "release" => Some("release_task"),
+        "notes" => Some("add_note"),
+        _ => None,
+    }
+}
+
+pub(crate) fn handle(
+    backlog_dir: &Path,
+    task_id: &str,
+    action: &str,
+    body: &str,
+    token: Option<&str>,
+) -> Reply {
+    let Some(tool) = tool_name(action) else {
+        return Reply::error(404, "Not found");
+    };
+    let args: Value = if body.trim().is_empty() {
+        Value::Object(Default::default())
+    } else {
+        match serde_json::from_str(body) {
+            Ok(value @ Value::Object(_)) => value,
+            _ => return Reply::error(400, "Request body must be a JSON object"),
+        }
+    };
+    let tasks = load_tasks(backlog_dir);
+    let Some(task) = tasks
+        .iter()
+        .find(|task| task.id.eq_ignore_ascii_case(task_id))
+    else {
+        return Reply::error(404, &format!("Task not found: {}", task_id));
+    };
+    let access = Access::resolve(backlog_dir, token);
+    if let Err(reply) = access.authorize(tool, &args, &tasks, &[task.id.as_str()]) {
+        return reply;
+    }
+    if let Err(err) = FrozenSet::load(backlog_dir, &tasks).check(&[task.id.as_str()]) {
+        return Reply::json(
+            409,
+            serde_json::json!({ "error": err.to_string(), "code": "frozen" }),
+        );
+    }
+
+    let writer = Writer {
+        backlog_dir,
+        tasks: &tasks,
+        task,
+        actor: match &access.api_token {
+            Some(api_token) => format!("http:{}", api_token.id),
+            None => "http".to_string(),
+        },
+    };
+    let result = match action {
+        "status" => writer.set_status(&args),
+        "claim" => writer.claim(&args),
+        "release" => writer.release(),
+        _ => writer.note(&args),
+    };
+    if let Err(reply) = result {
+        return reply;
+    }
+    let _ = refresh_index(backlog_dir);
+    let mut tasks = load_tasks(backlog_dir);
+    access.redact_confidential(&mut tasks);
+    match tasks
+        .iter()
+        .find(|updated| updated.id.eq_ignore_ascii_case(&task.id))
+    {
+        Some(updated) => Reply::json(
+            200,
+            serde_json::json!({ "ok": true, "task": task_to_json_value(updated, true) }),
+        ),
+        None => Reply::error(500, &format!("Task disappeared: {}", task.id)),
+    }
+}
+
+/// Who an HTTP caller is: their role, and the stored API token they presented, if any.
+pub(crate) struct Access {
+    grant: Grant,
+    api_token: Option<ApiToken>,
+    protected: Vec<String>,
+}
+
+impl Access {
+    pub(crate) fn resolve(backlog_dir: &Path, token: Option<&str>) -> Self {
+        let config = resolve_roles_config(&repo_root_from_backlog(backlog_dir));
+        let api_token = token.and_then(|token| find_token(&tokens_dir()?, token, Local::now()));
+        let grant = match &api_token {
+            Some(api_token) => api_token.grant(None),
+            // Anything that can reach the port gets here, so no roles table means read-only.
+            None if config.is_none() => Grant {
+                role: Role::Viewer,
+                source: "unconfigured",
+                identity: None,
+            },
+            None => resolve_grant(
+                config.as_ref(),
+                &Caller {
+                    identity: None,
+                    token: token.map(str::to_string),
+                },
+            ),
+        };
+        let protected = config
+            .as_ref()
+            .map(RolesConfig::protected_fields)
+            .unwrap_or_default();
+        Self {
+            grant,
+            api_token,
+            protected,
+        }
+    }
+
+    /// Check the caller's role for `tool` and, for a scoped token, that `ids` are in scope.
+    pub(crate) fn authorize(
+        &self,
+        tool: &str,
+        args: &Value,
+        tasks: &[Task],
+        ids: &[&str],
+    ) -> Result<(), Reply> {
+        let (required, reason) = required_role(tool, args, &self.protected);
+        authorize(&self.grant, tool, required, reason)
+            .map_err(|err| Reply::json(403, err.to_json()))?;
+        if let Some(api_token) = &self.api_token {
+            api_token
+                .check_scope(tasks, ids)
+                .map_err(|err| Reply::json(403, err.to_json()))?;
+        }
+        Ok(())
+    }
+
+    /// Replace the bodies of confidential tasks the caller may not read. Only callers who
+    /// presented a known token see them, and a scoped token only within its scope.
+    pub(crate) fn redact_confidential(&self, tasks: &mut [Task]) {
+        let hidden: Vec<bool> = tasks
+            .iter()
+            .map(|task| {
+                is_confidential_task(task)
+                    && !match (&self.api_token, self.grant.source) {
+                        (Some(api_token), _) => {
+                            api_token.check_scope(tasks, &[task.id.as_str()]).is_ok()
+                        }
+                        (None, source) => source == "token",
+                    }
+            })
+            .collect();
+        for (task, hidden) in tasks.iter_mut().zip(hidden) {
+            if hidden {
+                task.body = REDACTED_BODY.to_string();
+            }
+        }
+    }
+}
+
+struct Writer<'a> {
+    backlog_dir: &'a Path,
+    tasks: &'a [Task],
+    task: &'a Task,
+    actor: String,
+}
+
+impl Writer<'_> {
+    fn path(&self) -> Result<&Path, Reply> {
+        self.task
+            .file_path
+            .as_deref()
+            .ok_or_else(|| Reply::error(404, &format!("Task not found: {}", self.task.id)))
+    }
+
+    fn set_status(&self, args: &Value) -> Result<(), Reply> {
+        let status = required_str(args, "status")?;
+        let reason = optional_str(args, "reason");
+        let rules = resolve_task_validation_rules(&repo_root_from_backlog(self.backlog_dir));
+        ensure_can_set_status_with_rules(self.tasks, self.task, status, &rules)
+            .map_err(|err| Reply::error(409, &err))?;
+        let path = self.path()?;
+        update_task_field(path, "status", Some(status.to_string().into())).map_err(internal)?;
+        if reason.is_some() || !self.task.status.eq_ignore_ascii_case(status) {
+            record_status_transition(
+                path,
+                &self.task.status,
+                status,
+                Some(self.actor.as_str()),
+                reason,
+            )
+            .map_err(internal)?;
+        }
+        self.touch(path)?;
+        let mut details = serde_json::json!({ "status": status });
+        if let Some(reason) = reason {
+            details["reason"] = Value::String(reason.to_string());
+        }
+        self.audit("set_status", details)
+    }
+
+    fn claim(&self, args: &Value) -> Result<(), Reply> {
+        let owner = required_str(args, "owner")?;
+        let minutes = match args.get("minutes") {
+            None | Some(Value::Null) => None,
+            Some(value) => Some(
+                value
+                    .as_i64()
+                    .ok_or_else(|| Reply::error(400, "minutes must be an integer"))?,
+            ),
+        };
+        let path = self.path()?;
+        edit_list_field(path, "assignee", owner, true).map_err(internal)?;
+        let lease = Lease {
+            owner: owner.to_string(),
+            acquired_at: Some(now_timestamp()),
+            expires_at: minutes.map(timestamp_plus_minutes),
+        };
+        update_lease_fields(path, Some(&lease)).map_err(internal)?;
+        self.touch(path)?;
+        self.audit(
+            "claim",
+            serde_json::json!({ "owner": lease.owner, "expires_at": lease.expires_at }),
+        )
+    }
+
+    fn release(&self) -> Result<(), Reply> {
+        let path = self.path()?;
+        update_lease_fields(path, None).map_err(internal)?;
+        self.touch(path)?;
+        self.audit("release", serde_json::json!({}))
+    }
+
+    fn note(&self, args: &Value) -> Result<(), Reply> {
+        let note = required_str(args, "note")?;
+        let section = optional_str(args, "section").unwrap_or("notes");
+        if !matches!(section, "notes" | "impl") {
+            return Err(Reply::error(400, "section must be notes or impl"));
+        }
+        let path = self.path()?;
+        append_task_note(path, note, section).map_err(internal)?;
+        self.touch(path)?;
+        let repo_root = repo_root_from_backlog(self.backlog_dir);
+        let mut details = serde_json::json!({ "section": section, "note": note });
+        let mentions = resolve_mentions(note, self.tasks, &resolve_teams(&repo_root));
+        if !mentions.users.is_empty() {
+            details["mentions"] = serde_json::json!(mentions.users);
+        }
+        if resolve_note_autolink(&repo_root) {
+            let linked = link_note_references(self.task, self.tasks, note).map_err(internal)?;
+            if !linked.is_empty() {
+                details["relates_to"] = serde_json::json!(linked);
+            }
+        }
+        self.audit("note", details)
+    }
+
+    fn touch(&self, path: &Path) -> Result<(), Reply> {
+        update_task_field(path, "updated_date", Some(now_timestamp().into())).map_err(internal)
+    }
+
+    fn audit(&self, action: &str, mut details: Value) -> Result<(), Reply> {
+        details["source"] = Value::String("http".to_string());
+        append_audit_event(
+            self.backlog_dir,
+            &AuditEvent {
+                timestamp: now_timestamp(),
+                actor: Some(self.actor.clone()),
+                action: action.to_string(),
+                task_id: Some(self.task.id.clone()),
+                details,
+            },
+        )
+        .map_err(internal)
+    }
+}
+
+fn required_str<'a>(args: &'a Value, key: &str) -> Result<&'a str, Reply> {
+    optional_str(args, key).ok_or_else(|| Reply::error(400, &format!("Missing field: {}", key)))
+}
+
+fn optional_str<'a>(args: &'a Value, key: &str) -> Option<&'a str> {
+    args.get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+fn internal(err: impl std::fmt::Display) -> Reply {
+    Reply::error(500, &err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+    use workmesh_core::audit::read_audit_events;
+    use workmesh_core::roles::token_hash;
+
+    use crate::api::{handle as route, ApiRequest};
+    use crate::ServeOptions;
+
+    fn post(backlog_dir: &Path, url: &str, body: &str, token: Option<&str>) -> (u16, Value) {
+        let reply = route(
+            backlog_dir,
+            &ApiRequest {
+                method: "POST",
+                url,
+                body,
+                content_type: Some("application/json"),
+                token,
+                host: Some("localhost:8080"),
+            },
+            &ServeOptions::default(),
+        );
+        (
+            reply.status,
+            serde_json::from_str(&reply.body).expect("json"),
+        )
+    }
+
+    #[test]
+    fn writes_update_tasks_audit_and_honor_roles() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        for (id, title) in [("task-001", "Alpha"), ("task-002", "Beta")] {
+            fs::write(
+                tasks_dir.join(format!("{} - {}.md", id, title.to_lowercase())),
+                format!(
+                    "---\nid: {}\ntitle: {}\nstatus: To Do\npriority: P2\nphase: Phase1\ndependencies: []\nlabels: []\n---\n\n## Description\n- {}\n\n## Acceptance Criteria\n- Works\n\n## Definition of Done\n- Shipped\n",
+                    id, title, title
+                ),
+            )
+            .expect("write task");
+        }
+
+        let (status, reply) = post(
+            &backlog_dir,
+            "/api/tasks/task-001/status",
+            r#"{"status": "In Progress"}"#,
+            None,
+        );
+        assert_eq!(status, 403, "unconfigured callers are viewers");
+        assert_eq!(reply["role"], "viewer");
+
+        fs::write(
+            temp.path().join(".workmesh.toml"),
+            format!(
+                "[roles]\ndefault = \"viewer\"\n\n[roles.tokens]\n\"{}\" = \"contributor\"\n",
+                token_hash("bot-secret")
+            ),
+        )
+        .expect("config");
+        let token = Some("bot-secret");
+        let (status, reply) = post(
+            &backlog_dir,
+            "/api/tasks/task-001/status",
+            r#"{"status": "In Progress"}"#,
+            token,
+        );
+        assert_eq!(status, 200, "{}", reply);
+        assert_eq!(reply["task"]["status"], "In Progress");
+
+        let (status, _) = post(&backlog_dir, "/api/tasks/task-001/claim", "{}", token);
+        assert_eq!(status, 400);
+        let (status, reply) = post(
+            &backlog_dir,
+            "/api/tasks/task-001/claim",
+            r#"{"owner": "bot", "minutes": 30}"#,
+            token,
+        );
+        assert_eq!(status, 200, "{}", reply);
+        assert_eq!(reply["task"]["lease"]["owner"], "bot");
+
+        let (status, reply) = post(
+            &backlog_dir,
+            "/api/tasks/task-001/notes",
+            r#"{"note": "waiting on the schema"}"#,
+            token,
+        );
+        assert_eq!(status, 200, "{}", reply);
+        assert!(reply["task"]["body"]
+            .as_str()
+            .expect("body")
+            .contains("- waiting on the schema"));
+
+        assert_eq!(
+            post(&backlog_dir, "/api/tasks/task-001/status", "[", token).0,
+            400
+        );
+        assert_eq!(
+            post(&backlog_dir, "/api/tasks/task-001/rename", "{}", token).0,
+            404
+        );
+        assert_eq!(
+            post(&backlog_dir, "/api/tasks/task-404/release", "", token).0,
+            404
+        );
+        let text_plain = route(
+            &backlog_dir,
+            &ApiRequest {
+                method: "POST",
+                url: "/api/tasks/task-001/release",
+                content_type: Some("text/plain"),
+                host: Some("localhost:8080"),
+                ..Default::default()
+            },
+            &ServeOptions::default(),
+        );
+        assert_eq!(text_plain.status, 415);
+
+        let events = read_audit_events(&backlog_dir);
+        let actions: Vec<&str> = events.iter().map(|event| event.action.as_str()).collect();
+        assert_eq!(actions, vec!["set_status", "claim", "note"]);
+        assert!(events.iter().all(
+            |event| event.actor.as_deref() == Some("http") && event.details["source"] == "http"
+        ));
+
+        let (status, reply) = post(&backlog_dir, "/api/tasks/task-001/release", "", None);
+        assert_eq!(status, 403);
+        assert_eq!(reply["code"], "authorization_denied");
+        assert_eq!(reply["tool"], "release_task");
+        let (status, reply) = post(
+            &backlog_dir,
+            "/api/tasks/task-001/release",
+            "",
+            Some("bot-secret"),
+        );
+        assert_eq!(status, 200, "{}", reply);
+        assert!(reply["task"]["lease"].is_null());
+    }
+}
@@ -5,6 +5,7 @@ use workmesh_core::backlog::{
     locate_backlog_dir, resolve_backlog, resolve_backlog_dir, BacklogError,
 };
 use workmesh_core::project::repo_root_from_backlog;
+use workmesh_core::roles::Role;
 
 pub const ROOT_REQUIRED_ERROR: &str =
     "root is required for MCP calls unless the server is started within a repo containing tasks/ or backlog/tasks";
@@ -58,6 +59,9 @@ pub fn tool_catalog() -> Vec<Value> {
         serde_json::json!({"name": "doctor", "summary": "Diagnostics report for repo layout, context, index, skills, and versions."}),
         serde_json::json!({"name": "fix_ids", "summary": "Repair duplicate task ids after merges."}),
         serde_json::json!({"name": "fix_filenames", "summary": "Normalize non-canonical task filenames from task metadata."}),
+        serde_json::json!({"name": "lint_tasks", "summary": "Flag secrets, pasted logs, and prompt-injection text in task bodies (redact=true rewrites them)."}),
+        serde_json::json!({"name": "lint_titles", "summary": "Check task titles for length, sentence case, imperative mood, and duplicates (fix=true applies suggestions)."}),
+        serde_json::json!({"name": "autotag", "summary": "Propose labels for untagged tasks from [autotag] keyword rules (apply=true writes them)."}),
         serde_json::json!({"name": "bootstrap", "summary": "Bootstrap WorkMesh by detecting repo state and applying setup/migration."}),
         serde_json::json!({"name": "config_show", "summary": "Show project/global config and effective defaults."}),
         serde_json::json!({"name": "config_set", "summary": "Set a WorkMesh config key in project or global scope."}),
@@ -95,12 +99,15 @@ pub fn tool_catalog() -> Vec<Value> {
         serde_json::json!({"name": "list_tasks", "summary": "List tasks with optional filters."}),
         serde_json::json!({"name": "show_task", "summary": "Show a single task by id."}),
         serde_json::json!({"name": "ready_tasks", "summary": "List ready tasks (deps satisfied, status To Do)."}),
+        serde_json::json!({"name": "grab_tasks", "summary": "Weighted-random pick of small, old ready tasks for filler work (claim=true leases them)."}),
         serde_json::json!({"name": "next_task", "summary": "Return the next context-relevant task."}),
         serde_json::json!({"name": "next_tasks", "summary": "Recommend next work items ordered by context and readiness."}),
         serde_json::json!({"name": "stats", "summary": "Return counts by status."}),
         serde_json::json!({"name": "board", "summary": "Board (swimlanes) grouped by status/phase/priority."}),
         serde_json::json!({"name": "blockers", "summary": "Show blocked work and top blockers."}),
+        serde_json::json!({"name": "toposort", "summary": "Dependency-ordered execution levels of open tasks."}),
         serde_json::json!({"name": "validate", "summary": "Validate task metadata and dependencies."}),
+        serde_json::json!({"name": "changes_since", "summary": "Audit events and task diffs since a cursor from a previous call (omit cursor for a baseline)."}),
         serde_json::json!({"name": "export_tasks", "summary": "Export all tasks as JSON."}),
         serde_json::json!({"name": "set_status", "summary": "Set task status."}),
         serde_json::json!({"name": "set_field", "summary": "Set a front matter field value."}),
@@ -117,19 +124,26 @@ pub fn tool_catalog() -> Vec<Value> {
         serde_json::json!({"name": "bulk_add_note", "summary": "Bulk append a note to tasks."}),
         serde_json::json!({"name": "claim_task", "summary": "Claim a task lease."}),
         serde_json::json!({"name": "release_task", "summary": "Release a task lease."}),
+        serde_json::json!({"name": "freeze", "summary": "Freeze an epic subtree or phase: mutations need force_frozen=true from a maintainer; frozen tasks leave next/ready."}),
+        serde_json::json!({"name": "unfreeze", "summary": "Lift an epic or phase freeze."}),
         serde_json::json!({"name": "add_note", "summary": "Append a note to Notes or Implementation Notes."}),
         serde_json::json!({"name": "set_body", "summary": "Replace full task body (all content after front matter)."}),
         serde_json::json!({"name": "set_section", "summary": "Replace a named section in the task body."}),
         serde_json::json!({"name": "add_task", "summary": "Create a new task file with actionable content or explicit draft status."}),
         serde_json::json!({"name": "add_discovered", "summary": "Create a discovered task with actionable content or explicit draft status."}),
         serde_json::json!({"name": "archive_tasks", "summary": "Archive terminal tasks into date-based folders."}),
+        serde_json::json!({"name": "remove_task", "summary": "Move a task to trash/ with a tombstone (or mark it Cancelled) and strip it from dependency lists."}),
+        serde_json::json!({"name": "move_task", "summary": "Rename a task id and file, rewriting dependencies and relationships that reference it."}),
+        serde_json::json!({"name": "split_task", "summary": "Split a task into subtasks that inherit its fields; the original becomes their epic."}),
         serde_json::json!({"name": "migrate_backlog", "summary": "Migrate legacy backlog to workmesh/."}),
         serde_json::json!({"name": "migrate_audit", "summary": "Detect deprecated structures and report migration findings."}),
         serde_json::json!({"name": "migrate_plan", "summary": "Build migration plan from audit findings."}),
         serde_json::json!({"name": "migrate_apply", "summary": "Apply migration plan."}),
         serde_json::json!({"name": "checkpoint", "summary": "Write a session checkpoint."}),
         serde_json::json!({"name": "resume", "summary": "Resume from the latest checkpoint."}),
+        serde_json::json!({"name": "reminders", "summary": "Overdue and due-soon open tasks."}),
         serde_json::json!({"name": "checkpoint_diff", "summary": "Show changes since a checkpoint."}),
+        serde_json::json!({"name": "graph_diff", "summary": "Show dependency edges added/removed between checkpoints."}),
         serde_json::json!({"name": "session_save", "summary": "Save a global agent session."}),
         serde_json::json!({"name": "session_list", "summary": "List global agent sessions."}),
         serde_json::json!({"name": "session_show", "summary": "Show a global agent session."}),
@@ -152,6 +166,25 @@ pub fn tool_catalog() -> Vec<Value> {
         serde_json::json!({"name": "gantt_text", "summary": "Return PlantUML gantt text for current tasks."}),
         serde_json::json!({"name": "gantt_file", "summary": "Write PlantUML gantt text to a file and return the path."}),
         serde_json::json!({"name": "gantt_svg", "summary": "Render gantt SVG via PlantUML; return SVG or a file path."}),
+        serde_json::json!({"name": "merge_backlog", "summary": "Import another backlog (tasks, archive, audit) and rekey colliding ids."}),
+        serde_json::json!({"name": "extract", "summary": "Move an epic subtree or labelled tasks into a fresh backlog, leaving tombstones."}),
+        serde_json::json!({"name": "inbox", "summary": "Per-user inbox (assignments, review requests, answers, cleared blockers) from the audit log."}),
+        serde_json::json!({"name": "mentions", "summary": "List recent notes where a user was @mentioned."}),
+        serde_json::json!({"name": "policies_run", "summary": "Evaluate configured task policies (dry-run by default)."}),
+        serde_json::json!({"name": "health", "summary": "Composite health score per epic and initiative with underlying factors."}),
+        serde_json::json!({"name": "postmerge", "summary": "Repair the backlog after a git merge and report fields that diverged across parents."}),
+        serde_json::json!({"name": "workflow_show", "summary": "Render the status workflow as a Mermaid or DOT state diagram with counts and dwell times."}),
+        serde_json::json!({"name": "calibration", "summary": "Estimated vs actual effort with bias factors per assignee or label."}),
+        serde_json::json!({"name": "cycle_time", "summary": "Lead and cycle time p50/p90 of done tasks from the audit log, by label, phase, or priority."}),
+        serde_json::json!({"name": "milestones", "summary": "Per-milestone completion, velocity, and projected finish date from the `milestone` front matter field."}),
+        serde_json::json!({"name": "budget_status", "summary": "Point/hour budgets per initiative and phase from [budgets] config: consumed vs planned, burn rate, and projected overrun date."}),
+        serde_json::json!({"name": "onboarding_new", "summary": "Create an onboarding epic with checklist tasks ([onboarding] config) assigned to a newcomer."}),
+        serde_json::json!({"name": "onboarding_status", "summary": "Onboarding checklist progress per newcomer: done/total and the next ready items."}),
+        serde_json::json!({"name": "journal_list", "summary": "List session journal entries, filtered by task and age."}),
+        serde_json::json!({"name": "working_set_status", "summary": "Flag working-set entries that are Done, missing, idle, or no longer leased."}),
+        serde_json::json!({"name": "archive_search", "summary": "Search archived tasks through the archive index."}),
+        serde_json::json!({"name": "context_pack", "summary": "Token-budgeted pack of context, ready tasks, blockers, and decisions for prompts."}),
+        serde_json::json!({"name": "epic_brief", "summary": "Compact epic summary: status counts, top blockers, next ready tasks, last activity."}),
         serde_json::json!({"name": "render_table", "summary": "Render a table from array/object data."}),
         serde_json::json!({"name": "render_kv", "summary": "Render a key/value list."}),
         serde_json::json!({"name": "render_stats", "summary": "Render a compact stats block."}),
@@ -185,6 +218,13 @@ pub fn tool_examples(name: &str) -> Vec<Value> {
         "ready_tasks" => vec![
             serde_json::json!({"tool": "ready_tasks", "arguments": { "format": "json", "limit": 10 }}),
         ],
+        "freeze" => vec![
+            serde_json::json!({"tool": "freeze", "arguments": { "epic": "task-012", "reason": "release review" }}),
+            serde_json::json!({"tool": "freeze", "arguments": { "phase": "Phase2" }}),
+        ],
+        "grab_tasks" => vec![
+            serde_json::json!({"tool": "grab_tasks", "arguments": { "labels": ["chore"], "count": 3, "claim": true, "owner": "agent-1" }}),
+        ],
         "workstream_list" => {
             vec![serde_json::json!({"tool": "workstream_list", "arguments": { "format": "json" }})]
         }
@@ -352,6 +392,9 @@ pub fn supports_verbose_response(name: &str) -> bool {
             | "bulk_remove_dependency"
             | "bulk_add_note"
             | "archive_tasks"
+            | "remove_task"
+            | "move_task"
+            | "split_task"
             | "migrate_backlog"
             | "migrate_apply"
             | "claim_task"
@@ -365,6 +408,189 @@ pub fn supports_verbose_response(name: &str) -> bool {
     )
 }
 
+/// Tools that only read state; viewers may call them.
+pub fn is_read_only_tool(name: &str) -> bool {
+    name.starts_with("render_")
+        || matches!(
+            name,
+            "version"
+                | "readme"
+                | "doctor"
+                | "config_show"
+                | "context_show"
+                | "workstream_list"
+                | "workstream_show"
+                | "workstream_doctor"
+                | "workstream_restore"
+                | "worktree_list"
+                | "worktree_doctor"
+                | "truth_show"
+                | "truth_list"
+                | "truth_validate"
+                | "truth_migrate_audit"
+                | "truth_migrate_plan"
+                | "list_tasks"
+                | "show_task"
+                | "ready_tasks"
+                | "next_task"
+                | "next_tasks"
+                | "grab_tasks"
+                | "stats"
+                | "board"
+                | "blockers"
+                | "toposort"
+                | "validate"
+                | "lint_tasks"
+                | "lint_titles"
+                | "autotag"
+                | "changes_since"
+                | "export_tasks"
+                | "graph_export"
+                | "issues_export"
+                | "index_verify"
+                | "migrate_audit"
+                | "migrate_plan"
+                | "rekey_prompt"
+                | "resume"
+                | "working_set_status"
+                | "reminders"
+                | "checkpoint_diff"
+                | "graph_diff"
+                | "session_list"
+                | "session_show"
+                | "best_practices"
+                | "inbox"
+                | "mentions"
+                | "health"
+                | "workflow_show"
+                | "calibration"
+                | "cycle_time"
+                | "milestones"
+                | "budget_status"
+                | "onboarding_status"
+                | "journal_list"
+                | "context_pack"
+                | "epic_brief"
+                | "archive_search"
+                | "help"
+                | "tool_info"
+                | "skill_content"
+                | "project_management_skill"
+                | "gantt_text"
+                | "gantt_svg"
+        )
+}
+
+/// Tools that rewrite ids, move files in bulk, or change repo configuration.
+pub fn is_maintainer_tool(name: &str) -> bool {
+    matches!(
+        name,
+        "rekey_apply"
+            | "migrate_backlog"
+            | "migrate_apply"
+            | "truth_migrate_apply"
+            | "archive_tasks"
+            | "remove_task"
+            | "move_task"
+            | "fix_ids"
+            | "fix_filenames"
+            | "merge_backlog"
+            | "postmerge"
+            | "extract"
+            | "config_set"
+            | "config_unset"
+            | "bootstrap"
+            | "quickstart"
+            | "freeze"
+            | "unfreeze"
+    )
+}
+
+/// Task ids a mutating tool call writes to, checked against epic/phase freezes.
+pub fn task_mutation_targets(name: &str, args: &Value) -> Vec<String> {
+    let key = match name.trim() {
+        "set_status" | "set_field" | "add_label" | "remove_label" | "add_dependency"
+        | "remove_dependency" | "claim_task" | "release_task" | "add_note" | "set_body"
+        | "set_section" | "remove_task" | "split_task" => "task_id",
+        "move_task" => "old_id",
+        "add_discovered" => "from",
+        "bulk_set_status"
+        | "bulk_set_field"
+        | "bulk_add_label"
+        | "bulk_remove_label"
+        | "bulk_add_dependency"
+        | "bulk_remove_dependency"
+        | "bulk_add_note" => "tasks",
+        _ => return Vec::new(),
+    };
+    match args.get(key) {
+        Some(Value::String(value)) => value
+            .split(',')
+            .map(str::trim)
+            .filter(|id| !id.is_empty())
+            .map(str::to_string)
+            .collect(),
+        Some(Value::Array(values)) => values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(|id| id.trim().to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Minimum role for a tool call, plus a short reason for authorization errors.
+///
+/// Contributors may mutate tasks, but `set_field`/`bulk_set_field` on a protected field,
+/// `force_frozen=true`, and `doctor` with `fix_storage=true` escalate to maintainer. `lint_tasks` is read-only unless
+/// `redact=true`, `lint_titles` unless `fix=true`, `autotag` unless `apply=true`, `grab_tasks` unless
+/// `claim=true`, and `working_set_status` unless `refresh=true`.
+pub fn required_role(
+    name: &str,
+    args: &Value,
+    protected_fields: &[String],
+) -> (Role, &'static str) {
+    let name = name.trim();
+    if is_maintainer_tool(name) {
+        return (Role::Maintainer, "maintainer-only tool");
+    }
+    if matches!(name, "set_field" | "bulk_set_field") {
+        let field = args
+            .get("field")
+            .and_then(Value::as_str)
+            .map(|field| field.trim().to_lowercase())
+            .unwrap_or_default();
+        if protected_fields.iter().any(|protected| protected == &field) {
+            return (Role::Maintainer, "protected field");
+        }
+    }
+    if args.get("force_frozen").and_then(Value::as_bool) == Some(true) {
+        return (Role::Maintainer, "frozen task override");
+    }
+    if name == "doctor" && args.get("fix_storage").and_then(Value::as_bool) == Some(true) {
+        return (Role::Maintainer, "storage remediation");
+    }
+    if name == "lint_tasks" && args.get("redact").and_then(Value::as_bool) == Some(true) {
+        return (Role::Contributor, "redaction rewrites task bodies");
+    }
+    if name == "lint_titles" && args.get("fix").and_then(Value::as_bool) == Some(true) {
+        return (Role::Contributor, "fix rewrites task titles");
+    }
+    if name == "autotag" && args.get("apply").and_then(Value::as_bool) == Some(true) {
+        return (Role::Contributor, "apply writes task labels");
+    }
+    if name == "grab_tasks" && args.get("claim").and_then(Value::as_bool) == Some(true) {
+        return (Role::Contributor, "claim leases the picked tasks");
+    }
+    if name == "working_set_status" && args.get("refresh").and_then(Value::as_bool) == Some(true) {
+        return (Role::Contributor, "refresh rewrites the working set");
+    }
+    if is_read_only_tool(name) {
+        return (Role::Viewer, "read-only tool");
+    }
+    (Role::Contributor, "mutating tool")
+}
+
 pub fn placeholder_tool_definition(name: &str) -> Value {
     serde_json::json!({
         "name": name,
@@ -517,6 +743,26 @@ pub fn resolve_cli_repo_root(root: &Path) -> PathBuf {
         .unwrap_or_else(|_| repo_root_from_backlog(root))
 }
 
+/// Environment variable holding the default CLI `--root` (typically set per shell).
+pub const ROOT_ENV: &str = "WORKMESH_ROOT";
+
+/// Root for a CLI invocation: `--root`, else `WORKMESH_ROOT`, else the repo owning the nearest
+/// backlog above `cwd`, else `cwd` itself (so `bootstrap` and `quickstart` work in a fresh repo).
+pub fn resolve_cli_root(root: Option<PathBuf>, env_root: Option<&str>, cwd: &Path) -> PathBuf {
+    if let Some(root) = root {
+        return root;
+    }
+    if let Some(env_root) = env_root.and_then(trimmed_non_empty) {
+        return PathBuf::from(env_root);
+    }
+    match locate_backlog_dir(cwd) {
+        Ok(backlog_dir) => resolve_backlog(&backlog_dir)
+            .map(|resolution| resolution.repo_root)
+            .unwrap_or(backlog_dir),
+        Err(_) => cwd.to_path_buf(),
+    }
+}
+
 fn trimmed_non_empty(value: &str) -> Option<&str> {
     let trimmed = value.trim();
     if trimmed.is_empty() {
@@ -550,6 +796,75 @@ mod tests {
             .any(|note| { note.as_str().unwrap_or_default().contains("verbose=true") }));
     }
 
+    #[test]
+    fn required_role_escalates_protected_fields() {
+        let protected = vec!["id".to_string()];
+        let (role, _) = required_role("list_tasks", &serde_json::json!({}), &protected);
+        assert_eq!(role, Role::Viewer);
+        let (role, _) = required_role(
+            "set_field",
+            &serde_json::json!({"field": "priority"}),
+            &protected,
+        );
+        assert_eq!(role, Role::Contributor);
+        let (role, reason) =
+            required_role("set_field", &serde_json::json!({"field": "ID"}), &protected);
+        assert_eq!((role, reason), (Role::Maintainer, "protected field"));
+        let (role, _) = required_role("rekey_apply", &serde_json::json!({}), &protected);
+        assert_eq!(role, Role::Maintainer);
+        let (role, _) = required_role("lint_tasks", &serde_json::json!({}), &protected);
+        assert_eq!(role, Role::Viewer);
+        let (role, _) = required_role(
+            "lint_tasks",
+            &serde_json::json!({"redact": true}),
+            &protected,
+        );
+        assert_eq!(role, Role::Contributor);
+        let (role, _) = required_role("lint_titles", &serde_json::json!({"fix": true}), &protected);
+        assert_eq!(role, Role::Contributor);
+        let (role, _) = required_role("grab_tasks", &serde_json::json!({}), &protected);
+        assert_eq!(role, Role::Viewer);
+        let (role, _) = required_role(
+            "grab_tasks",
+            &serde_json::json!({"claim": true}),
+            &protected,
+        );
+        assert_eq!(role, Role::Contributor);
+        let (role, _) = required_role("working_set_status", &serde_json::json!({}), &protected);
+        assert_eq!(role, Role::Viewer);
+        let (role, _) = required_role(
+            "working_set_status",
+            &serde_json::json!({"refresh": true}),
+            &protected,
+        );
+        assert_eq!(role, Role::Contributor);
+        let (role, reason) = required_role(
+            "remove_task",
+            &serde_json::json!({"task_id": "task-001"}),
+            &protected,
+        );
+        assert_eq!((role, reason), (Role::Maintainer, "maintainer-only tool"));
+        let (role, reason) = required_role(
+            "move_task",
+            &serde_json::json!({"old_id": "task-001", "new_id": "task-002"}),
+            &protected,
+        );
+        assert_eq!((role, reason), (Role::Maintainer, "maintainer-only tool"));
+        let (role, reason) = required_role(
+            "set_status",
+            &serde_json::json!({"task_id": "task-001", "force_frozen": true}),
+            &protected,
+        );
+        assert_eq!((role, reason), (Role::Maintainer, "frozen task override"));
+        assert_eq!(
+            task_mutation_targets("bulk_add_note", &serde_json::json!({"tasks": "a, b"})),
+            vec!["a", "b"]
+        );
+        assert!(
+            task_mutation_targets("show_task", &serde_json::json!({"task_id": "a"})).is_empty()
+        );
+    }
+
     #[test]
     fn cli_repo_root_prefers_repo_root() {
         let temp = std::env::temp_dir().join(format!("workmesh-tools-{}", std::process::id()));
@@ -559,4 +874,33 @@ mod tests {
         assert_eq!(resolve_cli_repo_root(&temp), temp);
         let _ = std::fs::remove_dir_all(&temp);
     }
+
+    #[test]
+    fn cli_root_prefers_flag_then_env_then_discovery() {
+        let temp = std::env::temp_dir().join(format!("workmesh-root-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&temp);
+        let nested = temp.join("src").join("deep");
+        std::fs::create_dir_all(temp.join("workmesh").join("tasks")).expect("tasks dir");
+        std::fs::create_dir_all(&nested).expect("nested dir");
+        let flag = PathBuf::from("/elsewhere");
+
+        assert_eq!(
+            resolve_cli_root(Some(flag.clone()), Some("/env"), &nested),
+            flag
+        );
+        assert_eq!(
+            resolve_cli_root(None, Some("/env"), &nested),
+            PathBuf::from("/env")
+        );
+        let discovered = resolve_cli_root(None, Some("  "), &nested);
+        assert_eq!(
+            discovered.canonicalize().expect("discovered"),
+            temp.canonicalize().expect("temp")
+        );
+        let outside = std::env::temp_dir().join(format!("workmesh-none-{}", std::process::id()));
+        std::fs::create_dir_all(&outside).expect("outside dir");
+        assert_eq!(resolve_cli_root(None, None, &outside), outside);
+        let _ = std::fs::remove_dir_all(&temp);
+        let _ = std::fs::remove_dir_all(&outside);
+    }
 }
@@ -0,0 +1,392 @@
+//! Board state and actions. Everything here is terminal-agnostic so it can be driven by tests;
+//! `ui` only draws an `App` and `run` only feeds it keys.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Result};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use workmesh_core::audit::{aliased_actor, append_audit_event, AuditEvent};
+use workmesh_core::config::{resolve_actor_aliases, resolve_task_validation_rules};
+use workmesh_core::freeze::FrozenSet;
+use workmesh_core::index::refresh_index;
+use workmesh_core::project::repo_root_from_backlog;
+use workmesh_core::task::{load_tasks, Lease, Task};
+use workmesh_core::task_ops::{
+    ensure_can_set_status_with_rules, now_timestamp, set_list_field, timestamp_plus_minutes,
+    update_lease_fields, update_task_field,
+};
+use workmesh_core::transitions::record_status_transition;
+use workmesh_core::views::status_columns;
+use workmesh_core::workflow::resolve_workflow;
+
+#[derive(Debug, Clone, Default)]
+pub struct UiOptions {
+    /// Lease owner used by claim (`c`).
+    pub owner: String,
+    /// Lease length for claims; `None` leaves the lease open-ended.
+    pub lease_minutes: Option<i64>,
+    /// Allow mutating tasks inside a frozen epic or phase.
+    pub force_frozen: bool,
+}
+
+/// One kanban column: a status and the ids of the tasks in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    pub status: String,
+    pub tasks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum View {
+    Board,
+    /// Detail of the selected task; the value is the body scroll offset.
+    Detail(u16),
+    Help,
+}
+
+pub struct App {
+    pub backlog_dir: PathBuf,
+    pub options: UiOptions,
+    pub tasks: Vec<Task>,
+    pub columns: Vec<Column>,
+    pub column: usize,
+    pub row: usize,
+    pub view: View,
+    /// Result of the last action, shown in the footer.
+    pub message: Option<String>,
+    pub quit: bool,
+}
+
+impl App {
+    pub fn load(backlog_dir: &Path, options: UiOptions) -> Self {
+        let mut app = Self {
+            backlog_dir: backlog_dir.to_path_buf(),
+            options,
+            tasks: Vec::new(),
+            columns: Vec::new(),
+            column: 0,
+            row: 0,
+            view: View::Board,
+            message: None,
+            quit: false,
+        };
+        app.reload();
+        app
+    }
+
+    /// Re-read tasks from disk and rebuild the columns, keeping the selected task when it still
+    /// exists.
+    pub fn reload(&mut self) {
+        let selected = self.selected().map(|task| task.id.clone());
+        self.tasks = load_tasks(&self.backlog_dir);
+        let statuses = resolve_workflow(&repo_root_from_backlog(&self.backlog_dir)).statuses;
+        self.columns = board_columns(&self.tasks, &statuses);
+        match selected {
+            Some(id) => self.select(&id),
+            None => self.clamp(),
+        }
+    }
+
+    pub fn selected(&self) -> Option<&Task> {
+        let id = self.columns.get(self.column)?.tasks.get(self.row)?;
+        self.tasks.iter().find(|task| &task.id == id)
+    }
+
+    fn select(&mut self, id: &str) {
+        for (column, lane) in self.columns.iter().enumerate() {
+            if let Some(row) = lane.tasks.iter().position(|task| task == id) {
+                self.column = column;
+                self.row = row;
+                return;
+            }
+        }
+        self.clamp();
+    }
+
+    fn clamp(&mut self) {
+        self.column = self.column.min(self.columns.len().saturating_sub(1));
+        let len = self
+            .columns
+            .get(self.column)
+            .map(|lane| lane.tasks.len())
+            .unwrap_or(0);
+        self.row = self.row.min(len.saturating_sub(1));
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+            self.quit = true;
+            return;
+        }
+        match self.view {
+            View::Board => self.board_key(key),
+            View::Detail(scroll) => match key.code {
+                KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => self.view = View::Board,
+                KeyCode::Down | KeyCode::Char('j') => {
+                    self.view = View::Detail(scroll.saturating_add(1))
+                }
+                KeyCode::Up | KeyCode::Char('k') => {
+                    self.view = View::Detail(scroll.saturating_sub(1))
+                }
+                _ => {}
+            },
+            View::Help => self.view = View::Board,
+        }
+    }
+
+    fn board_key(&mut self, key: KeyEvent) {
+        let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Left if shift => self.move_selected(-1),
+            KeyCode::Right if shift => self.move_selected(1),
+            KeyCode::Char('<') | KeyCode::Char('H') => self.move_selected(-1),
+            KeyCode::Char('>') | KeyCode::Char('L') => self.move_selected(1),
+            KeyCode::Left | KeyCode::Char('h') => {
+                self.column = self.column.saturating_sub(1);
+                self.clamp();
+            }
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.column += 1;
+                self.clamp();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.row = self.row.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.row += 1;
+                self.clamp();
+            }
+            KeyCode::Enter if self.selected().is_some() => self.view = View::Detail(0),
+            KeyCode::Char('c') => self.claim_selected(),
+            KeyCode::Char('r') => self.release_selected(),
+            KeyCode::Char('g') | KeyCode::F(5) => {
+                self.reload();
+                self.message = Some("Reloaded".to_string());
+            }
+            KeyCode::Char('?') => self.view = View::Help,
+            _ => {}
+        }
+    }
+
+    /// Move the selected task to the previous (`-1`) or next (`1`) column's status.
+    pub fn move_selected(&mut self, delta: isize) {
+        let Some(target) = self
+            .column
+            .checked_add_signed(delta)
+            .and_then(|column| self.columns.get(column))
+            .map(|lane| lane.status.clone())
+        else {
+            return;
+        };
+        self.act(|app, task| app.set_status(task, &target));
+    }
+
+    pub fn claim_selected(&mut self) {
+        self.act(|app, task| app.claim(task));
+    }
+
+    pub fn release_selected(&mut self) {
+        self.act(|app, task| app.release(task));
+    }
+
+    /// Run a mutation on the selected task, then reload and report the outcome in the footer.
+    fn act(&mut self, action: impl FnOnce(&Self, &Task) -> Result<String>) {
+        let Some(task) = self.selected().cloned() else {
+            return;
+        };
+        let result = self
+            .ensure_not_frozen(&task)
+            .and_then(|_| action(self, &task));
+        self.message = Some(match result {
+            Ok(message) => {
+                let _ = refresh_index(&self.backlog_dir);
+                message
+            }
+            Err(err) => format!("Error: {}", err),
+        });
+        self.reload();
+    }
+
+    fn ensure_not_frozen(&self, task: &Task) -> Result<()> {
+        if self.options.force_frozen {
+            return Ok(());
+        }
+        FrozenSet::load(&self.backlog_dir, &self.tasks)
+            .check(&[task.id.as_str()])
+            .map_err(|err| anyhow!("{} (restart with --force-frozen to override)", err))
+    }
+
+    fn set_status(&self, task: &Task, status: &str) -> Result<String> {
+        let rules = resolve_task_validation_rules(&repo_root_from_backlog(&self.backlog_dir));
+        ensure_can_set_status_with_rules(&self.tasks, task, status, &rules)
+            .map_err(|err| anyhow!(err))?;
+        let path = task_path(task)?;
+        update_task_field(path, "status", Some(status.to_string().into()))?;
+        if !task.status.eq_ignore_ascii_case(status) {
+            let actor = std::env::var("USER").ok();
+            record_status_transition(path, &task.status, status, actor.as_deref(), None)?;
+        }
+        update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+        self.audit(
+            "set_status",
+            &task.id,
+            serde_json::json!({ "status": status, "source": "ui" }),
+        )?;
+        Ok(format!("Updated {} status -> {}", task.id, status))
+    }
+
+    fn claim(&self, task: &Task) -> Result<String> {
+        let owner = self.options.owner.trim();
+        if owner.is_empty() {
+            return Err(anyhow!("No lease owner (restart with --owner <name>)"));
+        }
+        let path = task_path(task)?;
+        if !task.assignee.iter().any(|value| value == owner) {
+            let mut assignee = task.assignee.clone();
+            assignee.push(owner.to_string());
+            set_list_field(path, "assignee", assignee)?;
+        }
+        let lease = Lease {
+            owner: owner.to_string(),
+            acquired_at: Some(now_timestamp()),
+            expires_at: self.options.lease_minutes.map(timestamp_plus_minutes),
+        };
+        update_lease_fields(path, Some(&lease))?;
+        update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+        self.audit(
+            "claim",
+            &task.id,
+            serde_json::json!({
+                "owner": lease.owner,
+                "expires_at": lease.expires_at,
+                "source": "ui",
+            }),
+        )?;
+        Ok(format!("Claimed {} lease -> {}", task.id, owner))
+    }
+
+    fn release(&self, task: &Task) -> Result<String> {
+        let path = task_path(task)?;
+        update_lease_fields(path, None)?;
+        update_task_field(path, "updated_date", Some(now_timestamp().into()))?;
+        self.audit("release", &task.id, serde_json::json!({ "source": "ui" }))?;
+        Ok(format!("Released {} lease", task.id))
+    }
+
+    fn audit(&self, action: &str, task_id: &str, details: serde_json::Value) -> Result<()> {
+        let aliases = resolve_actor_aliases(&repo_root_from_backlog(&self.backlog_dir));
+        let event = AuditEvent {
+            timestamp: now_timestamp(),
+            actor: std::env::var("USER")
+                .ok()
+                .map(|user| aliased_actor(user, &aliases)),
+            action: action.to_string(),
+            task_id: Some(task_id.to_string()),
+            details,
+        };
+        append_audit_event(&self.backlog_dir, &event)?;
+        Ok(())
+    }
+}
+
+/// One column per status, in `status_columns` order.
+pub fn board_columns(tasks: &[Task], statuses: &[String]) -> Vec<Column> {
+    status_columns(tasks, statuses)
+        .into_iter()
+        .map(|(status, lane_tasks)| Column {
+            status,
+            tasks: lane_tasks.iter().map(|task| task.id.clone()).collect(),
+        })
+        .collect()
+}
+
+fn task_path(task: &Task) -> Result<&Path> {
+    task.file_path
+        .as_deref()
+        .ok_or_else(|| anyhow!("Task not found: {}", task.id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+
+    use tempfile::TempDir;
+    use workmesh_core::audit::read_audit_events;
+
+    fn write_task(tasks_dir: &Path, id: &str, status: &str) {
+        fs::write(
+            tasks_dir.join(format!("{} - {}.md", id, id)),
+            format!(
+                "---\nid: {id}\ntitle: Task {id}\nstatus: {status}\npriority: P2\nphase: Phase1\ndependencies: []\n---\n\n## Description\n- Something\n\n## Acceptance Criteria\n- Works\n\n## Definition of Done\n- Shipped\n"
+            ),
+        )
+        .expect("write task");
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn keys_navigate_columns_and_write_status_claim_and_release() {
+        let temp = TempDir::new().expect("tempdir");
+        let backlog_dir = temp.path().join("workmesh");
+        let tasks_dir = backlog_dir.join("tasks");
+        fs::create_dir_all(&tasks_dir).expect("tasks dir");
+        write_task(&tasks_dir, "task-001", "To Do");
+        write_task(&tasks_dir, "task-002", "To Do");
+        write_task(&tasks_dir, "task-003", "Blocked");
+
+        let mut app = App::load(
+            &backlog_dir,
+            UiOptions {
+                owner: "alice".to_string(),
+                ..Default::default()
+            },
+        );
+        let statuses: Vec<&str> = app
+            .columns
+            .iter()
+            .map(|column| column.status.as_str())
+            .collect();
+        assert_eq!(statuses, vec!["To Do", "In Progress", "Done", "Blocked"]);
+
+        app.handle_key(key(KeyCode::Down));
+        assert_eq!(
+            app.selected().map(|task| task.id.as_str()),
+            Some("task-002")
+        );
+        app.handle_key(key(KeyCode::Char('>')));
+        assert_eq!(app.column, 1);
+        assert_eq!(
+            app.selected().map(|task| task.status.as_str()),
+            Some("In Progress")
+        );
+
+        app.handle_key(key(KeyCode::Char('c')));
+        let task = app.selected().expect("selected");
+        assert_eq!(
+            task.lease.as_ref().map(|lease| lease.owner.as_str()),
+            Some("alice")
+        );
+        assert_eq!(task.assignee, vec!["alice"]);
+        app.handle_key(key(KeyCode::Char('r')));
+        assert!(app.selected().expect("selected").lease.is_none());
+
+        app.handle_key(key(KeyCode::Enter));
+        assert_eq!(app.view, View::Detail(0));
+        app.handle_key(key(KeyCode::Esc));
+        assert_eq!(app.view, View::Board);
+
+        let actions: Vec<String> = read_audit_events(&backlog_dir)
+            .into_iter()
+            .map(|event| event.action)
+            .collect();
+        assert_eq!(actions, vec!["set_status", "claim", "release"]);
+
+        app.handle_key(key(KeyCode::Char('q')));
+        assert!(app.quit);
+    }
+}
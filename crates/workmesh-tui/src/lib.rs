@@ -0,0 +1,37 @@
+//! Interactive terminal board behind `workmesh ui` (CLI feature `tui`).
+//!
+//! Columns come from the workflow statuses plus `board_lanes`; status moves, claims, and releases
+//! go through the same `task_ops` writers and audit log as the matching CLI commands.
+
+pub mod app;
+pub mod ui;
+
+use std::path::Path;
+use std::time::Duration;
+
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyEventKind};
+
+pub use app::{board_columns, App, Column, UiOptions, View};
+
+/// Take over the terminal until the user quits. The terminal is restored on every exit path.
+pub fn run(backlog_dir: &Path, options: UiOptions) -> Result<()> {
+    let mut app = App::load(backlog_dir, options);
+    let mut terminal = ratatui::init();
+    let result = (|| -> Result<()> {
+        while !app.quit {
+            terminal.draw(|frame| ui::draw(frame, &app))?;
+            if !event::poll(Duration::from_millis(500))? {
+                continue;
+            }
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    app.handle_key(key);
+                }
+            }
+        }
+        Ok(())
+    })();
+    ratatui::restore();
+    result
+}
@@ -0,0 +1,179 @@
+//! Drawing. Reads an `App`, never mutates it.
+
+use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::Frame;
+
+use workmesh_core::task::Task;
+use workmesh_core::task_ops::is_lease_active;
+
+use crate::app::{App, View};
+
+pub const HELP: &[(&str, &str)] = &[
+    ("←/→ h/l", "select column"),
+    ("↑/↓ j/k", "select task"),
+    ("</> H/L, Shift+←/→", "move task to previous/next status"),
+    ("c", "claim (lease to --owner)"),
+    ("r", "release lease"),
+    ("Enter", "task detail (Esc to close)"),
+    ("g, F5", "reload from disk"),
+    ("q, Esc", "quit"),
+];
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [main, footer] =
+        Layout::vertical([Constraint::Min(3), Constraint::Length(1)]).areas(frame.area());
+    draw_board(frame, app, main);
+    let status = app
+        .message
+        .clone()
+        .unwrap_or_else(|| "? help  q quit".to_string());
+    frame.render_widget(Paragraph::new(status), footer);
+
+    match app.view {
+        View::Board => {}
+        View::Detail(scroll) => {
+            if let Some(task) = app.selected() {
+                let area = centered(main, 80, 90);
+                frame.render_widget(Clear, area);
+                frame.render_widget(
+                    Paragraph::new(detail_lines(task))
+                        .block(
+                            Block::default()
+                                .borders(Borders::ALL)
+                                .title(task.id.clone()),
+                        )
+                        .wrap(Wrap { trim: false })
+                        .scroll((scroll, 0)),
+                    area,
+                );
+            }
+        }
+        View::Help => {
+            let area = centered(main, 60, 50);
+            let lines: Vec<Line> = HELP
+                .iter()
+                .map(|(keys, what)| {
+                    Line::from(vec![
+                        Span::styled(format!("{:<20}", keys), Style::default().fg(Color::Cyan)),
+                        Span::raw(*what),
+                    ])
+                })
+                .collect();
+            frame.render_widget(Clear, area);
+            frame.render_widget(
+                Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title("Keys")),
+                area,
+            );
+        }
+    }
+}
+
+fn draw_board(frame: &mut Frame, app: &App, area: Rect) {
+    if app.columns.is_empty() {
+        frame.render_widget(Paragraph::new("No tasks"), area);
+        return;
+    }
+    let areas = Layout::horizontal(
+        app.columns
+            .iter()
+            .map(|_| Constraint::Ratio(1, app.columns.len() as u32)),
+    )
+    .split(area);
+    for (index, (column, rect)) in app.columns.iter().zip(areas.iter()).enumerate() {
+        let focused = index == app.column;
+        let items: Vec<ListItem> = column
+            .tasks
+            .iter()
+            .filter_map(|id| app.tasks.iter().find(|task| &task.id == id))
+            .map(|task| ListItem::new(card_line(task)))
+            .collect();
+        let border = if focused {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        let list = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(border)
+                    .title(format!("{} ({})", column.status, column.tasks.len())),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default();
+        if focused && !column.tasks.is_empty() {
+            state.select(Some(app.row));
+        }
+        frame.render_stateful_widget(list, *rect, &mut state);
+    }
+}
+
+fn card_line(task: &Task) -> Line<'static> {
+    let mut spans = vec![
+        Span::styled(task.id.clone(), Style::default().fg(Color::Cyan)),
+        Span::raw(format!(" {} ", task.priority)),
+        Span::raw(task.title.clone()),
+    ];
+    if let Some(lease) = task.lease.as_ref().filter(|_| is_lease_active(task)) {
+        spans.push(Span::styled(
+            format!(" @{}", lease.owner),
+            Style::default().fg(Color::Green),
+        ));
+    }
+    Line::from(spans)
+}
+
+fn detail_lines(task: &Task) -> Vec<Line<'static>> {
+    let list = |values: &[String]| {
+        if values.is_empty() {
+            "-".to_string()
+        } else {
+            values.join(", ")
+        }
+    };
+    let mut lines = vec![
+        Line::styled(
+            task.title.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Line::raw(format!(
+            "Status: {}  Priority: {}  Phase: {}  Kind: {}",
+            task.status, task.priority, task.phase, task.kind
+        )),
+        Line::raw(format!("Assignee: {}", list(&task.assignee))),
+        Line::raw(format!("Labels: {}", list(&task.labels))),
+        Line::raw(format!("Dependencies: {}", list(&task.dependencies))),
+        Line::raw(format!(
+            "Lease: {}",
+            task.lease
+                .as_ref()
+                .map(|lease| match &lease.expires_at {
+                    Some(expires) => format!("{} (until {})", lease.owner, expires),
+                    None => lease.owner.clone(),
+                })
+                .unwrap_or_else(|| "-".to_string())
+        )),
+        Line::raw(""),
+    ];
+    lines.extend(task.body.lines().map(|line| Line::raw(line.to_string())));
+    lines
+}
+
+fn centered(area: Rect, percent_x: u16, percent_y: u16) -> Rect {
+    let [_, middle, _] = Layout::vertical([
+        Constraint::Percentage((100 - percent_y) / 2),
+        Constraint::Percentage(percent_y),
+        Constraint::Percentage((100 - percent_y) / 2),
+    ])
+    .areas(area);
+    let [_, center, _] = Layout::horizontal([
+        Constraint::Percentage((100 - percent_x) / 2),
+        Constraint::Percentage(percent_x),
+        Constraint::Percentage((100 - percent_x) / 2),
+    ])
+    .areas(middle);
+    center
+}